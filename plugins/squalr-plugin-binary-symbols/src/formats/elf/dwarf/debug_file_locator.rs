@@ -0,0 +1,150 @@
+use std::path::{Path, PathBuf};
+
+const SYSTEM_DEBUG_ROOT: &str = "/usr/lib/debug";
+const BUILD_ID_DIRECTORY_NAME: &str = ".build-id";
+const DEBUG_FILE_EXTENSION: &str = "debug";
+
+/// Produces candidate paths for separate debug files, following the same search order as GDB: build-id links under each
+/// debug root, then `.gnu_debuglink` names next to the module, in its `.debug` directory, and mirrored under each debug root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DebugFileLocator {
+    debug_roots: Vec<PathBuf>,
+}
+
+impl DebugFileLocator {
+    pub(crate) fn new(debug_roots: Vec<PathBuf>) -> Self {
+        Self { debug_roots }
+    }
+
+    pub(crate) fn build_id_candidates(
+        &self,
+        build_id: &[u8],
+    ) -> Vec<PathBuf> {
+        let Some((first_byte, remaining_bytes)) = build_id.split_first() else {
+            return Vec::new();
+        };
+        let build_id_file_name = format!("{}.{}", encode_hex(remaining_bytes), DEBUG_FILE_EXTENSION);
+
+        self.debug_roots
+            .iter()
+            .map(|debug_root| {
+                debug_root
+                    .join(BUILD_ID_DIRECTORY_NAME)
+                    .join(encode_hex(&[*first_byte]))
+                    .join(&build_id_file_name)
+            })
+            .collect()
+    }
+
+    pub(crate) fn debug_link_candidates(
+        &self,
+        module_file_path: &Path,
+        debug_link: &str,
+    ) -> Vec<PathBuf> {
+        let module_directory = module_file_path.parent().unwrap_or_else(|| Path::new(""));
+        let mut candidates = vec![
+            module_directory.join(debug_link),
+            module_directory.join(".debug").join(debug_link),
+        ];
+
+        candidates.extend(
+            self.debug_roots
+                .iter()
+                .map(|debug_root| join_mirrored_path(debug_root, module_directory).join(debug_link)),
+        );
+        candidates.retain(|candidate| candidate != module_file_path);
+
+        candidates
+    }
+
+    /// Split DWARF names its `.dwo` file relative to the compilation directory recorded in the skeleton unit. Builds are
+    /// often moved after compiling, so the module's own directory is tried as a fallback.
+    pub(crate) fn dwo_candidates(
+        &self,
+        module_file_path: &Path,
+        compilation_directory: Option<&str>,
+        dwo_name: &str,
+    ) -> Vec<PathBuf> {
+        let dwo_path = Path::new(dwo_name);
+
+        if dwo_path.is_absolute() {
+            return vec![dwo_path.to_path_buf()];
+        }
+
+        let module_directory = module_file_path.parent().unwrap_or_else(|| Path::new(""));
+        let mut candidates = Vec::new();
+
+        if let Some(compilation_directory) = compilation_directory {
+            candidates.push(Path::new(compilation_directory).join(dwo_path));
+        }
+
+        candidates.push(module_directory.join(dwo_path));
+
+        if let Some(dwo_file_name) = dwo_path.file_name() {
+            candidates.push(module_directory.join(dwo_file_name));
+        }
+
+        candidates.dedup();
+
+        candidates
+    }
+}
+
+impl Default for DebugFileLocator {
+    fn default() -> Self {
+        Self::new(vec![PathBuf::from(SYSTEM_DEBUG_ROOT)])
+    }
+}
+
+fn join_mirrored_path(
+    debug_root: &Path,
+    module_directory: &Path,
+) -> PathBuf {
+    module_directory
+        .components()
+        .filter(|component| matches!(component, std::path::Component::Normal(_)))
+        .fold(debug_root.to_path_buf(), |mirrored_path, component| mirrored_path.join(component))
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugFileLocator;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn build_id_candidates_split_the_first_byte_into_a_directory() {
+        let debug_file_locator = DebugFileLocator::default();
+
+        assert_eq!(
+            debug_file_locator.build_id_candidates(&[0xAB, 0xCD, 0xEF]),
+            vec![PathBuf::from("/usr/lib/debug/.build-id/ab/cdef.debug")]
+        );
+        assert!(debug_file_locator.build_id_candidates(&[]).is_empty());
+    }
+
+    #[test]
+    fn debug_link_and_dwo_candidates_follow_gdb_search_order() {
+        let debug_file_locator = DebugFileLocator::default();
+
+        assert_eq!(
+            debug_file_locator.debug_link_candidates(Path::new("/opt/game/bin/game"), "game.debug"),
+            vec![
+                PathBuf::from("/opt/game/bin/game.debug"),
+                PathBuf::from("/opt/game/bin/.debug/game.debug"),
+                PathBuf::from("/usr/lib/debug/opt/game/bin/game.debug"),
+            ]
+        );
+        assert_eq!(
+            debug_file_locator.dwo_candidates(Path::new("/opt/game/bin/game"), Some("/build"), "obj/player.dwo"),
+            vec![
+                PathBuf::from("/build/obj/player.dwo"),
+                PathBuf::from("/opt/game/bin/obj/player.dwo"),
+                PathBuf::from("/opt/game/bin/player.dwo"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,218 @@
+/// Bounds-checked cursor over a DWARF section. Every read fails with a descriptive error instead of panicking on truncated input.
+#[derive(Clone, Debug)]
+pub(crate) struct DwarfByteReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    is_big_endian: bool,
+}
+
+impl<'a> DwarfByteReader<'a> {
+    pub(crate) fn new(
+        bytes: &'a [u8],
+        is_big_endian: bool,
+    ) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            is_big_endian,
+        }
+    }
+
+    pub(crate) fn new_at(
+        bytes: &'a [u8],
+        position: u64,
+        is_big_endian: bool,
+    ) -> Result<Self, String> {
+        let position = usize::try_from(position).map_err(|_| String::from("DWARF offset does not fit in memory."))?;
+
+        if position > bytes.len() {
+            return Err(format!("DWARF offset 0x{:X} is outside of a 0x{:X} byte section.", position, bytes.len()));
+        }
+
+        Ok(Self {
+            bytes,
+            position,
+            is_big_endian,
+        })
+    }
+
+    pub(crate) fn position(&self) -> u64 {
+        self.position as u64
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    pub(crate) fn read_bytes(
+        &mut self,
+        length: u64,
+    ) -> Result<&'a [u8], String> {
+        let length = usize::try_from(length).map_err(|_| String::from("DWARF block length does not fit in memory."))?;
+        let end_position = self
+            .position
+            .checked_add(length)
+            .filter(|end_position| *end_position <= self.bytes.len())
+            .ok_or_else(|| format!("DWARF data is truncated at offset 0x{:X}.", self.position))?;
+        let bytes = &self.bytes[self.position..end_position];
+
+        self.position = end_position;
+
+        Ok(bytes)
+    }
+
+    pub(crate) fn skip(
+        &mut self,
+        length: u64,
+    ) -> Result<(), String> {
+        self.read_bytes(length).map(|_| ())
+    }
+
+    pub(crate) fn read_u8(&mut self) -> Result<u8, String> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub(crate) fn read_u16(&mut self) -> Result<u16, String> {
+        self.read_unsigned(2).map(|value| value as u16)
+    }
+
+    pub(crate) fn read_u32(&mut self) -> Result<u32, String> {
+        self.read_unsigned(4).map(|value| value as u32)
+    }
+
+    pub(crate) fn read_u64(&mut self) -> Result<u64, String> {
+        self.read_unsigned(8)
+    }
+
+    /// Reads an unsigned integer of 1 to 8 bytes in the section byte order.
+    pub(crate) fn read_unsigned(
+        &mut self,
+        size_in_bytes: u64,
+    ) -> Result<u64, String> {
+        if !(1..=8).contains(&size_in_bytes) {
+            return Err(format!("Unsupported {size_in_bytes}-byte DWARF integer."));
+        }
+
+        let bytes = self.read_bytes(size_in_bytes)?;
+        let value = if self.is_big_endian {
+            bytes
+                .iter()
+                .fold(0_u64, |value, byte| (value << 8) | u64::from(*byte))
+        } else {
+            bytes
+                .iter()
+                .rev()
+                .fold(0_u64, |value, byte| (value << 8) | u64::from(*byte))
+        };
+
+        Ok(value)
+    }
+
+    pub(crate) fn read_uleb128(&mut self) -> Result<u64, String> {
+        let mut value = 0_u64;
+        let mut shift = 0_u32;
+
+        loop {
+            let byte = self.read_u8()?;
+
+            if shift < 64 {
+                value |= u64::from(byte & 0x7F) << shift;
+            }
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+
+            shift = shift.saturating_add(7);
+        }
+    }
+
+    pub(crate) fn read_sleb128(&mut self) -> Result<i64, String> {
+        let mut value = 0_i64;
+        let mut shift = 0_u32;
+
+        loop {
+            let byte = self.read_u8()?;
+
+            if shift < 64 {
+                value |= i64::from(byte & 0x7F) << shift;
+            }
+
+            shift = shift.saturating_add(7);
+
+            if byte & 0x80 == 0 {
+                if shift < 64 && byte & 0x40 != 0 {
+                    value |= -1_i64 << shift;
+                }
+
+                return Ok(value);
+            }
+        }
+    }
+
+    pub(crate) fn read_c_string(&mut self) -> Result<&'a [u8], String> {
+        let remaining_bytes = &self.bytes[self.position.min(self.bytes.len())..];
+        let Some(terminator_index) = remaining_bytes.iter().position(|byte| *byte == 0) else {
+            return Err(format!("Unterminated DWARF string at offset 0x{:X}.", self.position));
+        };
+
+        self.position += terminator_index + 1;
+
+        Ok(&remaining_bytes[..terminator_index])
+    }
+
+    /// Reads a unit length, returning the length and whether the unit uses the 64-bit DWARF format.
+    pub(crate) fn read_initial_length(&mut self) -> Result<(u64, bool), String> {
+        let unit_length = self.read_u32()?;
+
+        match unit_length {
+            0xFFFF_FFFF => Ok((self.read_u64()?, true)),
+            0xFFFF_FFF0..=0xFFFF_FFFE => Err(format!("Reserved DWARF unit length 0x{unit_length:X}.")),
+            _ => Ok((u64::from(unit_length), false)),
+        }
+    }
+
+    pub(crate) fn read_offset(
+        &mut self,
+        is_dwarf64: bool,
+    ) -> Result<u64, String> {
+        if is_dwarf64 { self.read_u64() } else { self.read_u32().map(u64::from) }
+    }
+}
+
+/// Reads a null-terminated string at an offset into a string section such as `.debug_str`.
+pub(crate) fn read_section_string(
+    section_bytes: &[u8],
+    offset: u64,
+) -> Option<String> {
+    let offset = usize::try_from(offset).ok()?;
+    let string_bytes = section_bytes.get(offset..)?;
+    let terminator_index = string_bytes.iter().position(|byte| *byte == 0)?;
+
+    Some(String::from_utf8_lossy(&string_bytes[..terminator_index]).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DwarfByteReader;
+
+    #[test]
+    fn reads_leb128_values() {
+        let bytes = [0xE5, 0x8E, 0x26, 0x7F, 0x80, 0x7F];
+        let mut reader = DwarfByteReader::new(&bytes, false);
+
+        assert_eq!(reader.read_uleb128(), Ok(624_485));
+        assert_eq!(reader.read_sleb128(), Ok(-1));
+        assert_eq!(reader.read_sleb128(), Ok(-128));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn reads_integers_in_section_byte_order() {
+        let bytes = [0x12, 0x34, 0x56, 0x78];
+
+        assert_eq!(DwarfByteReader::new(&bytes, false).read_u32(), Ok(0x7856_3412));
+        assert_eq!(DwarfByteReader::new(&bytes, true).read_u32(), Ok(0x1234_5678));
+        assert!(DwarfByteReader::new(&bytes, false).read_u64().is_err());
+    }
+}
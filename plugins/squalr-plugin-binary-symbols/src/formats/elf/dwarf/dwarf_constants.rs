@@ -0,0 +1,129 @@
+pub(crate) const DW_UT_COMPILE: u8 = 0x01;
+pub(crate) const DW_UT_TYPE: u8 = 0x02;
+pub(crate) const DW_UT_PARTIAL: u8 = 0x03;
+pub(crate) const DW_UT_SKELETON: u8 = 0x04;
+pub(crate) const DW_UT_SPLIT_COMPILE: u8 = 0x05;
+pub(crate) const DW_UT_SPLIT_TYPE: u8 = 0x06;
+
+pub(crate) const DW_TAG_ARRAY_TYPE: u64 = 0x01;
+pub(crate) const DW_TAG_CLASS_TYPE: u64 = 0x02;
+pub(crate) const DW_TAG_ENUMERATION_TYPE: u64 = 0x04;
+pub(crate) const DW_TAG_LEXICAL_BLOCK: u64 = 0x0B;
+pub(crate) const DW_TAG_MEMBER: u64 = 0x0D;
+pub(crate) const DW_TAG_POINTER_TYPE: u64 = 0x0F;
+pub(crate) const DW_TAG_REFERENCE_TYPE: u64 = 0x10;
+pub(crate) const DW_TAG_COMPILE_UNIT: u64 = 0x11;
+pub(crate) const DW_TAG_STRUCTURE_TYPE: u64 = 0x13;
+pub(crate) const DW_TAG_SUBROUTINE_TYPE: u64 = 0x15;
+pub(crate) const DW_TAG_TYPEDEF: u64 = 0x16;
+pub(crate) const DW_TAG_UNION_TYPE: u64 = 0x17;
+pub(crate) const DW_TAG_INHERITANCE: u64 = 0x1C;
+pub(crate) const DW_TAG_PTR_TO_MEMBER_TYPE: u64 = 0x1F;
+pub(crate) const DW_TAG_SUBRANGE_TYPE: u64 = 0x21;
+pub(crate) const DW_TAG_BASE_TYPE: u64 = 0x24;
+pub(crate) const DW_TAG_CONST_TYPE: u64 = 0x26;
+pub(crate) const DW_TAG_SUBPROGRAM: u64 = 0x2E;
+pub(crate) const DW_TAG_VARIABLE: u64 = 0x34;
+pub(crate) const DW_TAG_VOLATILE_TYPE: u64 = 0x35;
+pub(crate) const DW_TAG_RESTRICT_TYPE: u64 = 0x37;
+pub(crate) const DW_TAG_NAMESPACE: u64 = 0x39;
+pub(crate) const DW_TAG_UNSPECIFIED_TYPE: u64 = 0x3B;
+pub(crate) const DW_TAG_PARTIAL_UNIT: u64 = 0x3C;
+pub(crate) const DW_TAG_SHARED_TYPE: u64 = 0x40;
+pub(crate) const DW_TAG_TYPE_UNIT: u64 = 0x41;
+pub(crate) const DW_TAG_RVALUE_REFERENCE_TYPE: u64 = 0x42;
+pub(crate) const DW_TAG_ATOMIC_TYPE: u64 = 0x47;
+pub(crate) const DW_TAG_SKELETON_UNIT: u64 = 0x4A;
+pub(crate) const DW_TAG_IMMUTABLE_TYPE: u64 = 0x4B;
+
+pub(crate) const DW_AT_LOCATION: u64 = 0x02;
+pub(crate) const DW_AT_NAME: u64 = 0x03;
+pub(crate) const DW_AT_BYTE_SIZE: u64 = 0x0B;
+pub(crate) const DW_AT_LOW_PC: u64 = 0x11;
+pub(crate) const DW_AT_HIGH_PC: u64 = 0x12;
+pub(crate) const DW_AT_COMP_DIR: u64 = 0x1B;
+pub(crate) const DW_AT_ABSTRACT_ORIGIN: u64 = 0x31;
+pub(crate) const DW_AT_UPPER_BOUND: u64 = 0x2F;
+pub(crate) const DW_AT_LOWER_BOUND: u64 = 0x22;
+pub(crate) const DW_AT_COUNT: u64 = 0x37;
+pub(crate) const DW_AT_DATA_MEMBER_LOCATION: u64 = 0x38;
+pub(crate) const DW_AT_DECLARATION: u64 = 0x3C;
+pub(crate) const DW_AT_ENCODING: u64 = 0x3E;
+pub(crate) const DW_AT_EXTERNAL: u64 = 0x3F;
+pub(crate) const DW_AT_SPECIFICATION: u64 = 0x47;
+pub(crate) const DW_AT_TYPE: u64 = 0x49;
+pub(crate) const DW_AT_DATA_BIT_OFFSET: u64 = 0x6B;
+pub(crate) const DW_AT_LINKAGE_NAME: u64 = 0x6E;
+pub(crate) const DW_AT_STR_OFFSETS_BASE: u64 = 0x72;
+pub(crate) const DW_AT_ADDR_BASE: u64 = 0x73;
+pub(crate) const DW_AT_DWO_NAME: u64 = 0x76;
+pub(crate) const DW_AT_MIPS_LINKAGE_NAME: u64 = 0x2007;
+pub(crate) const DW_AT_GNU_DWO_NAME: u64 = 0x2130;
+pub(crate) const DW_AT_GNU_DWO_ID: u64 = 0x2131;
+pub(crate) const DW_AT_GNU_ADDR_BASE: u64 = 0x2133;
+
+pub(crate) const DW_FORM_ADDR: u64 = 0x01;
+pub(crate) const DW_FORM_BLOCK2: u64 = 0x03;
+pub(crate) const DW_FORM_BLOCK4: u64 = 0x04;
+pub(crate) const DW_FORM_DATA2: u64 = 0x05;
+pub(crate) const DW_FORM_DATA4: u64 = 0x06;
+pub(crate) const DW_FORM_DATA8: u64 = 0x07;
+pub(crate) const DW_FORM_STRING: u64 = 0x08;
+pub(crate) const DW_FORM_BLOCK: u64 = 0x09;
+pub(crate) const DW_FORM_BLOCK1: u64 = 0x0A;
+pub(crate) const DW_FORM_DATA1: u64 = 0x0B;
+pub(crate) const DW_FORM_FLAG: u64 = 0x0C;
+pub(crate) const DW_FORM_SDATA: u64 = 0x0D;
+pub(crate) const DW_FORM_STRP: u64 = 0x0E;
+pub(crate) const DW_FORM_UDATA: u64 = 0x0F;
+pub(crate) const DW_FORM_REF_ADDR: u64 = 0x10;
+pub(crate) const DW_FORM_REF1: u64 = 0x11;
+pub(crate) const DW_FORM_REF2: u64 = 0x12;
+pub(crate) const DW_FORM_REF4: u64 = 0x13;
+pub(crate) const DW_FORM_REF8: u64 = 0x14;
+pub(crate) const DW_FORM_REF_UDATA: u64 = 0x15;
+pub(crate) const DW_FORM_INDIRECT: u64 = 0x16;
+pub(crate) const DW_FORM_SEC_OFFSET: u64 = 0x17;
+pub(crate) const DW_FORM_EXPRLOC: u64 = 0x18;
+pub(crate) const DW_FORM_FLAG_PRESENT: u64 = 0x19;
+pub(crate) const DW_FORM_STRX: u64 = 0x1A;
+pub(crate) const DW_FORM_ADDRX: u64 = 0x1B;
+pub(crate) const DW_FORM_REF_SUP4: u64 = 0x1C;
+pub(crate) const DW_FORM_STRP_SUP: u64 = 0x1D;
+pub(crate) const DW_FORM_DATA16: u64 = 0x1E;
+pub(crate) const DW_FORM_LINE_STRP: u64 = 0x1F;
+pub(crate) const DW_FORM_REF_SIG8: u64 = 0x20;
+pub(crate) const DW_FORM_IMPLICIT_CONST: u64 = 0x21;
+pub(crate) const DW_FORM_LOCLISTX: u64 = 0x22;
+pub(crate) const DW_FORM_RNGLISTX: u64 = 0x23;
+pub(crate) const DW_FORM_REF_SUP8: u64 = 0x24;
+pub(crate) const DW_FORM_STRX1: u64 = 0x25;
+pub(crate) const DW_FORM_STRX2: u64 = 0x26;
+pub(crate) const DW_FORM_STRX3: u64 = 0x27;
+pub(crate) const DW_FORM_STRX4: u64 = 0x28;
+pub(crate) const DW_FORM_ADDRX1: u64 = 0x29;
+pub(crate) const DW_FORM_ADDRX2: u64 = 0x2A;
+pub(crate) const DW_FORM_ADDRX3: u64 = 0x2B;
+pub(crate) const DW_FORM_ADDRX4: u64 = 0x2C;
+pub(crate) const DW_FORM_GNU_ADDR_INDEX: u64 = 0x1F01;
+pub(crate) const DW_FORM_GNU_STR_INDEX: u64 = 0x1F02;
+pub(crate) const DW_FORM_GNU_REF_ALT: u64 = 0x1F20;
+pub(crate) const DW_FORM_GNU_STRP_ALT: u64 = 0x1F21;
+
+pub(crate) const DW_ATE_BOOLEAN: u64 = 0x02;
+pub(crate) const DW_ATE_FLOAT: u64 = 0x04;
+pub(crate) const DW_ATE_SIGNED: u64 = 0x05;
+pub(crate) const DW_ATE_SIGNED_CHAR: u64 = 0x06;
+pub(crate) const DW_ATE_UNSIGNED: u64 = 0x07;
+pub(crate) const DW_ATE_UNSIGNED_CHAR: u64 = 0x08;
+pub(crate) const DW_ATE_UTF: u64 = 0x10;
+
+pub(crate) const DW_OP_ADDR: u8 = 0x03;
+pub(crate) const DW_OP_CONST1U: u8 = 0x08;
+pub(crate) const DW_OP_CONST2U: u8 = 0x0A;
+pub(crate) const DW_OP_CONST4U: u8 = 0x0C;
+pub(crate) const DW_OP_CONST8U: u8 = 0x0E;
+pub(crate) const DW_OP_CONSTU: u8 = 0x10;
+pub(crate) const DW_OP_PLUS_UCONST: u8 = 0x23;
+pub(crate) const DW_OP_ADDRX: u8 = 0xA1;
+pub(crate) const DW_OP_GNU_ADDR_INDEX: u8 = 0xFB;
@@ -0,0 +1,850 @@
+use crate::formats::elf::dwarf::dwarf_byte_reader::{DwarfByteReader, read_section_string};
+use crate::formats::elf::dwarf::dwarf_constants::*;
+use crate::formats::elf::dwarf::elf_debug_image::ElfDebugImage;
+use std::collections::HashMap;
+
+/// The DWARF sections of one object file. Split DWARF objects (`.dwo`) carry the same sections with a `.dwo` suffix and
+/// borrow the address table of the skeleton unit that referenced them.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DwarfSectionSet {
+    pub(crate) debug_info: Vec<u8>,
+    pub(crate) debug_types: Vec<u8>,
+    pub(crate) debug_abbrev: Vec<u8>,
+    pub(crate) debug_str: Vec<u8>,
+    pub(crate) debug_line_str: Vec<u8>,
+    pub(crate) debug_str_offsets: Vec<u8>,
+    pub(crate) debug_addr: Vec<u8>,
+    pub(crate) is_big_endian: bool,
+    pub(crate) is_split: bool,
+}
+
+impl DwarfSectionSet {
+    pub(crate) fn from_elf_debug_image(elf_debug_image: &ElfDebugImage) -> Result<Self, String> {
+        Self::read_sections(elf_debug_image, "", false)
+    }
+
+    pub(crate) fn from_split_elf_debug_image(elf_debug_image: &ElfDebugImage) -> Result<Self, String> {
+        Self::read_sections(elf_debug_image, ".dwo", true)
+    }
+
+    fn read_sections(
+        elf_debug_image: &ElfDebugImage,
+        section_suffix: &str,
+        is_split: bool,
+    ) -> Result<Self, String> {
+        let read_section = |section_name: &str| -> Result<Vec<u8>, String> {
+            Ok(elf_debug_image
+                .section_bytes(&format!("{section_name}{section_suffix}"))?
+                .map(|section_bytes| section_bytes.into_owned())
+                .unwrap_or_default())
+        };
+
+        Ok(Self {
+            debug_info: read_section(".debug_info")?,
+            debug_types: read_section(".debug_types")?,
+            debug_abbrev: read_section(".debug_abbrev")?,
+            debug_str: read_section(".debug_str")?,
+            debug_line_str: read_section(".debug_line_str")?,
+            debug_str_offsets: read_section(".debug_str_offsets")?,
+            debug_addr: read_section(".debug_addr")?,
+            is_big_endian: elf_debug_image.is_big_endian(),
+            is_split,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) enum DwarfInfoSection {
+    Info,
+    Types,
+}
+
+/// Identifies a DIE across every loaded object. Offsets are only unique within one section of one object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct DwarfDieKey {
+    object_index: usize,
+    section: DwarfInfoSection,
+    offset: u64,
+}
+
+impl DwarfDieKey {
+    pub(crate) fn get_offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum DwarfAttributeValue {
+    Unsigned(u64),
+    Signed(i64),
+    Address(u64),
+    AddressIndex(u64),
+    InlineString(String),
+    StringOffset(u64),
+    LineStringOffset(u64),
+    StringIndex(u64),
+    Reference(DwarfDieKey),
+    TypeSignature(u64),
+    Block(Vec<u8>),
+    Flag(bool),
+    SectionOffset(u64),
+    Unsupported,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct DwarfDie {
+    tag: u64,
+    unit_index: usize,
+    parent: Option<DwarfDieKey>,
+    attributes: Vec<(u64, DwarfAttributeValue)>,
+    children: Vec<DwarfDieKey>,
+}
+
+impl DwarfDie {
+    pub(crate) fn get_tag(&self) -> u64 {
+        self.tag
+    }
+
+    pub(crate) fn get_parent(&self) -> Option<DwarfDieKey> {
+        self.parent
+    }
+
+    pub(crate) fn get_children(&self) -> &[DwarfDieKey] {
+        &self.children
+    }
+
+    pub(crate) fn get_attribute(
+        &self,
+        attribute_name: u64,
+    ) -> Option<&DwarfAttributeValue> {
+        self.attributes
+            .iter()
+            .find(|(name, _)| *name == attribute_name)
+            .map(|(_, value)| value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DwarfUnit {
+    object_index: usize,
+    section: DwarfInfoSection,
+    unit_offset: u64,
+    version: u16,
+    unit_type: u8,
+    address_size: u8,
+    is_dwarf64: bool,
+    root_die_key: Option<DwarfDieKey>,
+    str_offsets_base: u64,
+    addr_base: u64,
+}
+
+impl DwarfUnit {
+    pub(crate) fn get_address_size(&self) -> u8 {
+        self.address_size
+    }
+
+    pub(crate) fn is_skeleton(&self) -> bool {
+        self.unit_type == DW_UT_SKELETON
+    }
+}
+
+/// A split DWARF reference left in the main object by `-gsplit-dwarf`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DwarfSkeletonUnit {
+    pub(crate) dwo_name: String,
+    pub(crate) compilation_directory: Option<String>,
+    pub(crate) addr_base: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct DwarfAbbreviation {
+    tag: u64,
+    has_children: bool,
+    attribute_specs: Vec<DwarfAttributeSpec>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct DwarfAttributeSpec {
+    name: u64,
+    form: u64,
+    implicit_const: i64,
+}
+
+/// Parsed DIE trees for a module and any split DWARF objects it references.
+#[derive(Debug, Default)]
+pub(crate) struct DwarfDebugInfo {
+    objects: Vec<DwarfSectionSet>,
+    units: Vec<DwarfUnit>,
+    dies: HashMap<DwarfDieKey, DwarfDie>,
+    type_units_by_signature: HashMap<u64, DwarfDieKey>,
+}
+
+impl DwarfDebugInfo {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses every unit in `.debug_info` and `.debug_types`. `inherited_addr_base` carries the skeleton unit's address base
+    /// into split objects, whose own units do not record one.
+    pub(crate) fn add_object(
+        &mut self,
+        dwarf_section_set: DwarfSectionSet,
+        inherited_addr_base: Option<u64>,
+    ) -> Result<(), String> {
+        let object_index = self.objects.len();
+
+        self.objects.push(dwarf_section_set);
+
+        for section in [DwarfInfoSection::Info, DwarfInfoSection::Types] {
+            let section_length = match section {
+                DwarfInfoSection::Info => self.objects[object_index].debug_info.len(),
+                DwarfInfoSection::Types => self.objects[object_index].debug_types.len(),
+            } as u64;
+            let mut unit_offset = 0_u64;
+
+            while unit_offset < section_length {
+                unit_offset = self.parse_unit(object_index, section, unit_offset, inherited_addr_base)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn get_units(&self) -> &[DwarfUnit] {
+        &self.units
+    }
+
+    pub(crate) fn get_die(
+        &self,
+        die_key: DwarfDieKey,
+    ) -> Option<&DwarfDie> {
+        self.dies.get(&die_key)
+    }
+
+    pub(crate) fn get_unit(
+        &self,
+        die_key: DwarfDieKey,
+    ) -> Option<&DwarfUnit> {
+        self.dies
+            .get(&die_key)
+            .and_then(|die| self.units.get(die.unit_index))
+    }
+
+    pub(crate) fn get_unit_root(
+        &self,
+        dwarf_unit: &DwarfUnit,
+    ) -> Option<DwarfDieKey> {
+        dwarf_unit.root_die_key
+    }
+
+    pub(crate) fn get_skeleton_units(&self) -> Vec<DwarfSkeletonUnit> {
+        self.units
+            .iter()
+            .filter_map(|dwarf_unit| {
+                let root_die_key = dwarf_unit.root_die_key?;
+                let dwo_name = self
+                    .get_string(root_die_key, DW_AT_DWO_NAME)
+                    .or_else(|| self.get_string(root_die_key, DW_AT_GNU_DWO_NAME))?;
+
+                Some(DwarfSkeletonUnit {
+                    dwo_name,
+                    compilation_directory: self.get_string(root_die_key, DW_AT_COMP_DIR),
+                    addr_base: dwarf_unit.addr_base,
+                })
+            })
+            .collect()
+    }
+
+    pub(crate) fn get_string(
+        &self,
+        die_key: DwarfDieKey,
+        attribute_name: u64,
+    ) -> Option<String> {
+        let die = self.dies.get(&die_key)?;
+        let dwarf_unit = self.units.get(die.unit_index)?;
+        let dwarf_section_set = self.objects.get(dwarf_unit.object_index)?;
+
+        match die.get_attribute(attribute_name)? {
+            DwarfAttributeValue::InlineString(string) => Some(string.clone()),
+            DwarfAttributeValue::StringOffset(string_offset) => read_section_string(&dwarf_section_set.debug_str, *string_offset),
+            DwarfAttributeValue::LineStringOffset(string_offset) => read_section_string(&dwarf_section_set.debug_line_str, *string_offset),
+            DwarfAttributeValue::StringIndex(string_index) => {
+                let offset_size = if dwarf_unit.is_dwarf64 { 8 } else { 4 };
+                let entry_offset = dwarf_unit
+                    .str_offsets_base
+                    .checked_add(string_index.checked_mul(offset_size)?)?;
+                let string_offset = DwarfByteReader::new_at(&dwarf_section_set.debug_str_offsets, entry_offset, dwarf_section_set.is_big_endian)
+                    .ok()?
+                    .read_unsigned(offset_size)
+                    .ok()?;
+
+                read_section_string(&dwarf_section_set.debug_str, string_offset)
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_unsigned(
+        &self,
+        die_key: DwarfDieKey,
+        attribute_name: u64,
+    ) -> Option<u64> {
+        match self.dies.get(&die_key)?.get_attribute(attribute_name)? {
+            DwarfAttributeValue::Unsigned(value) => Some(*value),
+            DwarfAttributeValue::Signed(value) => u64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_signed(
+        &self,
+        die_key: DwarfDieKey,
+        attribute_name: u64,
+    ) -> Option<i64> {
+        match self.dies.get(&die_key)?.get_attribute(attribute_name)? {
+            DwarfAttributeValue::Unsigned(value) => i64::try_from(*value).ok(),
+            DwarfAttributeValue::Signed(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_flag(
+        &self,
+        die_key: DwarfDieKey,
+        attribute_name: u64,
+    ) -> bool {
+        matches!(
+            self.dies
+                .get(&die_key)
+                .and_then(|die| die.get_attribute(attribute_name)),
+            Some(DwarfAttributeValue::Flag(true))
+        )
+    }
+
+    /// Follows a reference attribute, including `DW_FORM_ref_sig8` references into type units.
+    pub(crate) fn get_reference(
+        &self,
+        die_key: DwarfDieKey,
+        attribute_name: u64,
+    ) -> Option<DwarfDieKey> {
+        match self.dies.get(&die_key)?.get_attribute(attribute_name)? {
+            DwarfAttributeValue::Reference(referenced_die_key) => Some(*referenced_die_key),
+            DwarfAttributeValue::TypeSignature(type_signature) => self.type_units_by_signature.get(type_signature).copied(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_address(
+        &self,
+        die_key: DwarfDieKey,
+        attribute_name: u64,
+    ) -> Option<u64> {
+        match self.dies.get(&die_key)?.get_attribute(attribute_name)? {
+            DwarfAttributeValue::Address(address) => Some(*address),
+            DwarfAttributeValue::AddressIndex(address_index) => self.resolve_address_index(die_key, *address_index),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_block(
+        &self,
+        die_key: DwarfDieKey,
+        attribute_name: u64,
+    ) -> Option<&[u8]> {
+        match self.dies.get(&die_key)?.get_attribute(attribute_name)? {
+            DwarfAttributeValue::Block(block) => Some(block),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_big_endian(
+        &self,
+        die_key: DwarfDieKey,
+    ) -> bool {
+        self.get_unit(die_key)
+            .and_then(|dwarf_unit| self.objects.get(dwarf_unit.object_index))
+            .is_some_and(|dwarf_section_set| dwarf_section_set.is_big_endian)
+    }
+
+    /// Resolves an index into `.debug_addr`. Split objects carry a copy of the skeleton object's address table.
+    pub(crate) fn resolve_address_index(
+        &self,
+        die_key: DwarfDieKey,
+        address_index: u64,
+    ) -> Option<u64> {
+        let dwarf_unit = self.get_unit(die_key)?;
+        let dwarf_section_set = self.objects.get(dwarf_unit.object_index)?;
+        let address_size = u64::from(dwarf_unit.address_size);
+        let entry_offset = dwarf_unit
+            .addr_base
+            .checked_add(address_index.checked_mul(address_size)?)?;
+
+        DwarfByteReader::new_at(&dwarf_section_set.debug_addr, entry_offset, dwarf_section_set.is_big_endian)
+            .ok()?
+            .read_unsigned(address_size)
+            .ok()
+    }
+
+    fn parse_unit(
+        &mut self,
+        object_index: usize,
+        section: DwarfInfoSection,
+        unit_offset: u64,
+        inherited_addr_base: Option<u64>,
+    ) -> Result<u64, String> {
+        let dwarf_section_set = &self.objects[object_index];
+        let section_bytes = match section {
+            DwarfInfoSection::Info => &dwarf_section_set.debug_info,
+            DwarfInfoSection::Types => &dwarf_section_set.debug_types,
+        };
+        let is_big_endian = dwarf_section_set.is_big_endian;
+        let is_split = dwarf_section_set.is_split;
+        let mut reader = DwarfByteReader::new_at(section_bytes, unit_offset, is_big_endian)?;
+        let (unit_length, is_dwarf64) = reader.read_initial_length()?;
+        let unit_end = reader
+            .position()
+            .checked_add(unit_length)
+            .filter(|unit_end| *unit_end <= section_bytes.len() as u64)
+            .ok_or_else(|| format!("DWARF unit at 0x{unit_offset:X} extends past the end of its section."))?;
+        let version = reader.read_u16()?;
+
+        if !(2..=5).contains(&version) {
+            return Ok(unit_end);
+        }
+
+        let mut type_signature_and_offset = None;
+        let (unit_type, address_size, abbreviation_offset) = if version >= 5 {
+            let unit_type = reader.read_u8()?;
+            let address_size = reader.read_u8()?;
+            let abbreviation_offset = reader.read_offset(is_dwarf64)?;
+
+            match unit_type {
+                DW_UT_SKELETON | DW_UT_SPLIT_COMPILE => reader.skip(8)?,
+                DW_UT_TYPE | DW_UT_SPLIT_TYPE => {
+                    type_signature_and_offset = Some((reader.read_u64()?, reader.read_offset(is_dwarf64)?));
+                }
+                DW_UT_COMPILE | DW_UT_PARTIAL => {}
+                _ => return Ok(unit_end),
+            }
+
+            (unit_type, address_size, abbreviation_offset)
+        } else {
+            let abbreviation_offset = reader.read_offset(is_dwarf64)?;
+            let address_size = reader.read_u8()?;
+
+            if section == DwarfInfoSection::Types {
+                type_signature_and_offset = Some((reader.read_u64()?, reader.read_offset(is_dwarf64)?));
+            }
+
+            (
+                if section == DwarfInfoSection::Types { DW_UT_TYPE } else { DW_UT_COMPILE },
+                address_size,
+                abbreviation_offset,
+            )
+        };
+        let abbreviations = parse_abbreviation_table(&dwarf_section_set.debug_abbrev, abbreviation_offset, is_big_endian)?;
+        let unit_index = self.units.len();
+        let mut dwarf_unit = DwarfUnit {
+            object_index,
+            section,
+            unit_offset,
+            version,
+            unit_type,
+            address_size,
+            is_dwarf64,
+            root_die_key: None,
+            str_offsets_base: if is_split && version >= 5 { if is_dwarf64 { 16 } else { 8 } } else { 0 },
+            addr_base: inherited_addr_base.unwrap_or(0),
+        };
+        let mut parsed_dies = Vec::new();
+        let mut parent_stack: Vec<DwarfDieKey> = Vec::new();
+
+        while reader.position() < unit_end {
+            let die_offset = reader.position();
+            let abbreviation_code = reader.read_uleb128()?;
+
+            if abbreviation_code == 0 {
+                parent_stack.pop();
+                continue;
+            }
+
+            let Some(abbreviation) = abbreviations.get(&abbreviation_code) else {
+                return Err(format!("DWARF DIE at 0x{die_offset:X} uses unknown abbreviation {abbreviation_code}."));
+            };
+            let mut attributes = Vec::with_capacity(abbreviation.attribute_specs.len());
+
+            for attribute_spec in &abbreviation.attribute_specs {
+                let attribute_value = read_attribute_value(&mut reader, attribute_spec.form, attribute_spec.implicit_const, &dwarf_unit)?;
+
+                attributes.push((attribute_spec.name, attribute_value));
+            }
+
+            let die_key = DwarfDieKey {
+                object_index,
+                section,
+                offset: die_offset,
+            };
+
+            if dwarf_unit.root_die_key.is_none() {
+                dwarf_unit.root_die_key = Some(die_key);
+
+                for (attribute_name, attribute_value) in &attributes {
+                    match (*attribute_name, attribute_value) {
+                        (DW_AT_STR_OFFSETS_BASE, DwarfAttributeValue::SectionOffset(base) | DwarfAttributeValue::Unsigned(base)) => {
+                            dwarf_unit.str_offsets_base = *base;
+                        }
+                        (DW_AT_ADDR_BASE | DW_AT_GNU_ADDR_BASE, DwarfAttributeValue::SectionOffset(base) | DwarfAttributeValue::Unsigned(base)) => {
+                            dwarf_unit.addr_base = *base;
+                        }
+                        (DW_AT_GNU_DWO_ID, _) if dwarf_unit.unit_type == DW_UT_COMPILE && !is_split => {
+                            dwarf_unit.unit_type = DW_UT_SKELETON;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            parsed_dies.push((
+                die_key,
+                DwarfDie {
+                    tag: abbreviation.tag,
+                    unit_index,
+                    parent: parent_stack.last().copied(),
+                    attributes,
+                    children: Vec::new(),
+                },
+            ));
+
+            if abbreviation.has_children {
+                parent_stack.push(die_key);
+            }
+        }
+
+        if let Some((type_signature, type_offset)) = type_signature_and_offset {
+            self.type_units_by_signature
+                .entry(type_signature)
+                .or_insert(DwarfDieKey {
+                    object_index,
+                    section,
+                    offset: unit_offset.saturating_add(type_offset),
+                });
+        }
+
+        let mut children_by_parent: HashMap<DwarfDieKey, Vec<DwarfDieKey>> = HashMap::new();
+
+        for (die_key, die) in &parsed_dies {
+            if let Some(parent_die_key) = die.parent {
+                children_by_parent
+                    .entry(parent_die_key)
+                    .or_default()
+                    .push(*die_key);
+            }
+        }
+
+        for (die_key, mut die) in parsed_dies {
+            die.children = children_by_parent.remove(&die_key).unwrap_or_default();
+            self.dies.insert(die_key, die);
+        }
+
+        self.units.push(dwarf_unit);
+
+        Ok(unit_end)
+    }
+}
+
+fn parse_abbreviation_table(
+    debug_abbrev: &[u8],
+    abbreviation_offset: u64,
+    is_big_endian: bool,
+) -> Result<HashMap<u64, DwarfAbbreviation>, String> {
+    let mut reader = DwarfByteReader::new_at(debug_abbrev, abbreviation_offset, is_big_endian)?;
+    let mut abbreviations = HashMap::new();
+
+    loop {
+        let abbreviation_code = reader.read_uleb128()?;
+
+        if abbreviation_code == 0 {
+            return Ok(abbreviations);
+        }
+
+        let tag = reader.read_uleb128()?;
+        let has_children = reader.read_u8()? != 0;
+        let mut attribute_specs = Vec::new();
+
+        loop {
+            let name = reader.read_uleb128()?;
+            let form = reader.read_uleb128()?;
+
+            if name == 0 && form == 0 {
+                break;
+            }
+
+            let implicit_const = if form == DW_FORM_IMPLICIT_CONST { reader.read_sleb128()? } else { 0 };
+
+            attribute_specs.push(DwarfAttributeSpec { name, form, implicit_const });
+        }
+
+        abbreviations.insert(
+            abbreviation_code,
+            DwarfAbbreviation {
+                tag,
+                has_children,
+                attribute_specs,
+            },
+        );
+    }
+}
+
+fn read_attribute_value(
+    reader: &mut DwarfByteReader<'_>,
+    form: u64,
+    implicit_const: i64,
+    dwarf_unit: &DwarfUnit,
+) -> Result<DwarfAttributeValue, String> {
+    let unit_reference = |relative_offset: u64| {
+        DwarfAttributeValue::Reference(DwarfDieKey {
+            object_index: dwarf_unit.object_index,
+            section: dwarf_unit.section,
+            offset: dwarf_unit.unit_offset.saturating_add(relative_offset),
+        })
+    };
+    let block = |reader: &mut DwarfByteReader<'_>, length: u64| {
+        reader
+            .read_bytes(length)
+            .map(|bytes| DwarfAttributeValue::Block(bytes.to_vec()))
+    };
+
+    Ok(match form {
+        DW_FORM_ADDR => DwarfAttributeValue::Address(reader.read_unsigned(u64::from(dwarf_unit.address_size))?),
+        DW_FORM_BLOCK1 => {
+            let length = u64::from(reader.read_u8()?);
+            block(reader, length)?
+        }
+        DW_FORM_BLOCK2 => {
+            let length = u64::from(reader.read_u16()?);
+            block(reader, length)?
+        }
+        DW_FORM_BLOCK4 => {
+            let length = u64::from(reader.read_u32()?);
+            block(reader, length)?
+        }
+        DW_FORM_BLOCK | DW_FORM_EXPRLOC => {
+            let length = reader.read_uleb128()?;
+            block(reader, length)?
+        }
+        DW_FORM_DATA1 => DwarfAttributeValue::Unsigned(u64::from(reader.read_u8()?)),
+        DW_FORM_DATA2 => DwarfAttributeValue::Unsigned(u64::from(reader.read_u16()?)),
+        // Before DWARF 4, `data4`/`data8` could also encode section offsets; both read the same way.
+        DW_FORM_DATA4 => DwarfAttributeValue::Unsigned(u64::from(reader.read_u32()?)),
+        DW_FORM_DATA8 => DwarfAttributeValue::Unsigned(reader.read_u64()?),
+        DW_FORM_DATA16 => {
+            reader.skip(16)?;
+            DwarfAttributeValue::Unsupported
+        }
+        DW_FORM_SDATA => DwarfAttributeValue::Signed(reader.read_sleb128()?),
+        DW_FORM_UDATA => DwarfAttributeValue::Unsigned(reader.read_uleb128()?),
+        DW_FORM_IMPLICIT_CONST => DwarfAttributeValue::Signed(implicit_const),
+        DW_FORM_STRING => DwarfAttributeValue::InlineString(String::from_utf8_lossy(reader.read_c_string()?).into_owned()),
+        DW_FORM_STRP => DwarfAttributeValue::StringOffset(reader.read_offset(dwarf_unit.is_dwarf64)?),
+        DW_FORM_LINE_STRP => DwarfAttributeValue::LineStringOffset(reader.read_offset(dwarf_unit.is_dwarf64)?),
+        DW_FORM_STRX | DW_FORM_GNU_STR_INDEX => DwarfAttributeValue::StringIndex(reader.read_uleb128()?),
+        DW_FORM_STRX1 => DwarfAttributeValue::StringIndex(reader.read_unsigned(1)?),
+        DW_FORM_STRX2 => DwarfAttributeValue::StringIndex(reader.read_unsigned(2)?),
+        DW_FORM_STRX3 => DwarfAttributeValue::StringIndex(reader.read_unsigned(3)?),
+        DW_FORM_STRX4 => DwarfAttributeValue::StringIndex(reader.read_unsigned(4)?),
+        DW_FORM_ADDRX | DW_FORM_GNU_ADDR_INDEX => DwarfAttributeValue::AddressIndex(reader.read_uleb128()?),
+        DW_FORM_ADDRX1 => DwarfAttributeValue::AddressIndex(reader.read_unsigned(1)?),
+        DW_FORM_ADDRX2 => DwarfAttributeValue::AddressIndex(reader.read_unsigned(2)?),
+        DW_FORM_ADDRX3 => DwarfAttributeValue::AddressIndex(reader.read_unsigned(3)?),
+        DW_FORM_ADDRX4 => DwarfAttributeValue::AddressIndex(reader.read_unsigned(4)?),
+        DW_FORM_REF1 => unit_reference(reader.read_unsigned(1)?),
+        DW_FORM_REF2 => unit_reference(reader.read_unsigned(2)?),
+        DW_FORM_REF4 => unit_reference(reader.read_unsigned(4)?),
+        DW_FORM_REF8 => unit_reference(reader.read_unsigned(8)?),
+        DW_FORM_REF_UDATA => unit_reference(reader.read_uleb128()?),
+        DW_FORM_REF_ADDR => {
+            let referenced_offset = if dwarf_unit.version <= 2 {
+                reader.read_unsigned(u64::from(dwarf_unit.address_size))?
+            } else {
+                reader.read_offset(dwarf_unit.is_dwarf64)?
+            };
+
+            DwarfAttributeValue::Reference(DwarfDieKey {
+                object_index: dwarf_unit.object_index,
+                section: DwarfInfoSection::Info,
+                offset: referenced_offset,
+            })
+        }
+        DW_FORM_REF_SIG8 => DwarfAttributeValue::TypeSignature(reader.read_u64()?),
+        DW_FORM_SEC_OFFSET => DwarfAttributeValue::SectionOffset(reader.read_offset(dwarf_unit.is_dwarf64)?),
+        DW_FORM_LOCLISTX | DW_FORM_RNGLISTX => DwarfAttributeValue::SectionOffset(reader.read_uleb128()?),
+        DW_FORM_FLAG => DwarfAttributeValue::Flag(reader.read_u8()? != 0),
+        DW_FORM_FLAG_PRESENT => DwarfAttributeValue::Flag(true),
+        // Supplementary and `dwz` alternate files are not loaded, so references into them are skipped.
+        DW_FORM_REF_SUP4 => {
+            reader.skip(4)?;
+            DwarfAttributeValue::Unsupported
+        }
+        DW_FORM_REF_SUP8 => {
+            reader.skip(8)?;
+            DwarfAttributeValue::Unsupported
+        }
+        DW_FORM_STRP_SUP | DW_FORM_GNU_REF_ALT | DW_FORM_GNU_STRP_ALT => {
+            reader.read_offset(dwarf_unit.is_dwarf64)?;
+            DwarfAttributeValue::Unsupported
+        }
+        DW_FORM_INDIRECT => {
+            let indirect_form = reader.read_uleb128()?;
+            let indirect_implicit_const = if indirect_form == DW_FORM_IMPLICIT_CONST {
+                reader.read_sleb128()?
+            } else {
+                implicit_const
+            };
+
+            read_attribute_value(reader, indirect_form, indirect_implicit_const, dwarf_unit)?
+        }
+        _ => return Err(format!("Unsupported DWARF attribute form 0x{form:X}.")),
+    })
+}
+
+/// Builds a DWARF 4 compile unit for a C++ `Game::Player` struct, a `Game::g_player` global, and a `Game::tick` function.
+/// Returns the `.debug_info` and `.debug_abbrev` section contents.
+#[cfg(test)]
+pub(crate) fn build_test_dwarf_sections() -> (Vec<u8>, Vec<u8>) {
+    const UNIT_HEADER_SIZE: usize = 11;
+
+    let debug_abbrev = vec![
+        1, 0x11, 1, 0x03, 0x08, 0, 0, // compile_unit: name
+        2, 0x39, 1, 0x03, 0x08, 0, 0, // namespace: name
+        3, 0x13, 1, 0x03, 0x08, 0x0B, 0x0B, 0, 0, // structure_type: name, byte_size
+        4, 0x0D, 0, 0x03, 0x08, 0x49, 0x13, 0x38, 0x0B, 0, 0, // member: name, type, data_member_location
+        5, 0x24, 0, 0x03, 0x08, 0x0B, 0x0B, 0x3E, 0x0B, 0, 0, // base_type: name, byte_size, encoding
+        6, 0x0F, 0, 0x0B, 0x0B, 0x49, 0x13, 0, 0, // pointer_type: byte_size, type
+        7, 0x34, 0, 0x03, 0x08, 0x49, 0x13, 0x02, 0x18, 0, 0, // variable: name, type, location
+        8, 0x2E, 0, 0x03, 0x08, 0x11, 0x01, 0x12, 0x06, 0, 0, // subprogram: name, low_pc, high_pc
+        9, 0x01, 1, 0x49, 0x13, 0, 0, // array_type: type
+        10, 0x21, 0, 0x37, 0x0B, 0, 0, // subrange_type: count
+        0,
+    ];
+    let mut die_bytes = Vec::new();
+    let mut die_offsets = HashMap::new();
+    let mut reference_patches = Vec::new();
+    let mut begin_die = |die_bytes: &mut Vec<u8>, label: &'static str, abbreviation_code: u8| {
+        die_offsets.insert(label, (UNIT_HEADER_SIZE + die_bytes.len()) as u32);
+        die_bytes.push(abbreviation_code);
+    };
+    let push_string = |die_bytes: &mut Vec<u8>, string: &str| {
+        die_bytes.extend_from_slice(string.as_bytes());
+        die_bytes.push(0);
+    };
+    let mut push_reference = |die_bytes: &mut Vec<u8>, label: &'static str| {
+        reference_patches.push((die_bytes.len(), label));
+        die_bytes.extend_from_slice(&[0; 4]);
+    };
+
+    begin_die(&mut die_bytes, "unit", 1);
+    push_string(&mut die_bytes, "game.cpp");
+    begin_die(&mut die_bytes, "namespace", 2);
+    push_string(&mut die_bytes, "Game");
+    begin_die(&mut die_bytes, "player", 3);
+    push_string(&mut die_bytes, "Player");
+    die_bytes.push(0x18);
+    begin_die(&mut die_bytes, "health", 4);
+    push_string(&mut die_bytes, "health");
+    push_reference(&mut die_bytes, "int");
+    die_bytes.push(0x00);
+    begin_die(&mut die_bytes, "name", 4);
+    push_string(&mut die_bytes, "name");
+    push_reference(&mut die_bytes, "char_array");
+    die_bytes.push(0x04);
+    begin_die(&mut die_bytes, "next", 4);
+    push_string(&mut die_bytes, "next");
+    push_reference(&mut die_bytes, "player_pointer");
+    die_bytes.push(0x10);
+    die_bytes.push(0);
+    begin_die(&mut die_bytes, "g_player", 7);
+    push_string(&mut die_bytes, "g_player");
+    push_reference(&mut die_bytes, "player");
+    die_bytes.push(9);
+    die_bytes.push(DW_OP_ADDR);
+    die_bytes.extend_from_slice(&0x4000_u64.to_le_bytes());
+    begin_die(&mut die_bytes, "tick", 8);
+    push_string(&mut die_bytes, "tick");
+    die_bytes.extend_from_slice(&0x1000_u64.to_le_bytes());
+    die_bytes.extend_from_slice(&0x40_u32.to_le_bytes());
+    die_bytes.push(0);
+    begin_die(&mut die_bytes, "int", 5);
+    push_string(&mut die_bytes, "int");
+    die_bytes.extend_from_slice(&[4, DW_ATE_SIGNED as u8]);
+    begin_die(&mut die_bytes, "char", 5);
+    push_string(&mut die_bytes, "char");
+    die_bytes.extend_from_slice(&[1, DW_ATE_SIGNED_CHAR as u8]);
+    begin_die(&mut die_bytes, "char_array", 9);
+    push_reference(&mut die_bytes, "char");
+    begin_die(&mut die_bytes, "char_array_range", 10);
+    die_bytes.push(8);
+    die_bytes.push(0);
+    begin_die(&mut die_bytes, "player_pointer", 6);
+    die_bytes.push(8);
+    push_reference(&mut die_bytes, "player");
+    die_bytes.push(0);
+
+    for (patch_position, label) in reference_patches {
+        die_bytes[patch_position..patch_position + 4].copy_from_slice(&die_offsets[label].to_le_bytes());
+    }
+
+    let mut debug_info = Vec::new();
+
+    debug_info.extend_from_slice(&((UNIT_HEADER_SIZE - 4 + die_bytes.len()) as u32).to_le_bytes());
+    debug_info.extend_from_slice(&4_u16.to_le_bytes());
+    debug_info.extend_from_slice(&0_u32.to_le_bytes());
+    debug_info.push(8);
+    debug_info.extend_from_slice(&die_bytes);
+
+    (debug_info, debug_abbrev)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DwarfDebugInfo, DwarfSectionSet, build_test_dwarf_sections};
+    use crate::formats::elf::dwarf::dwarf_constants::{DW_AT_NAME, DW_TAG_COMPILE_UNIT, DW_TAG_NAMESPACE};
+
+    #[test]
+    fn parses_die_tree_with_parents_and_children() {
+        let (debug_info, debug_abbrev) = build_test_dwarf_sections();
+        let mut dwarf_debug_info = DwarfDebugInfo::new();
+
+        dwarf_debug_info
+            .add_object(
+                DwarfSectionSet {
+                    debug_info,
+                    debug_abbrev,
+                    ..DwarfSectionSet::default()
+                },
+                None,
+            )
+            .expect("Expected test DWARF to parse.");
+
+        let dwarf_unit = &dwarf_debug_info.get_units()[0];
+        let unit_root_die_key = dwarf_debug_info
+            .get_unit_root(dwarf_unit)
+            .expect("Expected a unit root DIE.");
+        let unit_root_die = dwarf_debug_info
+            .get_die(unit_root_die_key)
+            .expect("Expected unit root DIE.");
+        let namespace_die_key = unit_root_die.get_children()[0];
+
+        assert_eq!(dwarf_debug_info.get_units().len(), 1);
+        assert_eq!(dwarf_unit.get_address_size(), 8);
+        assert_eq!(unit_root_die.get_tag(), DW_TAG_COMPILE_UNIT);
+        assert_eq!(unit_root_die.get_children().len(), 5);
+        assert_eq!(dwarf_debug_info.get_string(unit_root_die_key, DW_AT_NAME), Some(String::from("game.cpp")));
+        assert_eq!(
+            dwarf_debug_info
+                .get_die(namespace_die_key)
+                .map(|namespace_die| (namespace_die.get_tag(), namespace_die.get_parent())),
+            Some((DW_TAG_NAMESPACE, Some(unit_root_die_key)))
+        );
+        assert!(dwarf_debug_info.get_skeleton_units().is_empty());
+    }
+}
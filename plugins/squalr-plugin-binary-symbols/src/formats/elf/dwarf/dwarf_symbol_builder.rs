@@ -0,0 +1,1139 @@
+use crate::formats::elf::dwarf::dwarf_byte_reader::DwarfByteReader;
+use crate::formats::elf::dwarf::dwarf_constants::*;
+use crate::formats::elf::dwarf::dwarf_debug_info::{DwarfDebugInfo, DwarfDieKey};
+use squalr_engine_api::{
+    registries::symbols::struct_layout_descriptor::StructLayoutDescriptor,
+    structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::container_type::ContainerType,
+        pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize,
+        projects::symbol_layouts::symbol_layout_field_materializer::{SymbolLayoutFieldMaterializer, SymbolLayoutPositionedField},
+        structs::{
+            symbolic_field_definition::SymbolicFieldDefinition,
+            symbolic_struct_definition::{SymbolicLayoutKind, SymbolicStructDefinition},
+        },
+    },
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+const STRING_UTF8_NULL_TERMINATED_DATA_TYPE_ID: &str = "string_utf8{null_terminated}";
+
+/// A global or static variable with a fixed link-time address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DwarfGlobalVariable {
+    pub(crate) display_name: String,
+    pub(crate) virtual_address: u64,
+    pub(crate) symbol_type_id: String,
+    pub(crate) size_in_bytes: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DwarfFunction {
+    pub(crate) display_name: String,
+    pub(crate) virtual_address: u64,
+    pub(crate) size_in_bytes: u64,
+}
+
+/// Everything recovered from a module's debug info, expressed in symbol catalog terms but not yet placed in a module.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DwarfSymbols {
+    pub(crate) struct_layout_descriptors: Vec<StructLayoutDescriptor>,
+    pub(crate) global_variables: Vec<DwarfGlobalVariable>,
+    pub(crate) functions: Vec<DwarfFunction>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum DwarfFieldType {
+    Value {
+        data_type_id: String,
+        size_in_bytes: u64,
+        is_character: bool,
+    },
+    Pointer {
+        pointee_data_type_id: String,
+        pointer_size: PointerScanPointerSize,
+    },
+    Array {
+        element_type: Box<DwarfFieldType>,
+        element_count: Option<u64>,
+    },
+    Opaque {
+        size_in_bytes: Option<u64>,
+    },
+}
+
+/// A field's data type, container, and total size once a DWARF type has been flattened into Squalr's field model.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct DwarfFieldShape {
+    data_type_id: String,
+    container_type: ContainerType,
+    size_in_bytes: u64,
+}
+
+impl DwarfFieldShape {
+    fn to_symbol_type_id(&self) -> String {
+        format!("{}{}", self.data_type_id, self.container_type)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PendingLayoutField {
+    offset: u64,
+    field_name: String,
+    field_shape: DwarfFieldShape,
+}
+
+#[derive(Clone, Debug)]
+struct PendingLayout {
+    layout_id: String,
+    layout_kind: SymbolicLayoutKind,
+    size_in_bytes: u64,
+    fields: Vec<PendingLayoutField>,
+}
+
+/// Converts parsed DIE trees into struct layouts, global variables, and functions.
+pub(crate) struct DwarfSymbolBuilder<'a> {
+    dwarf_debug_info: &'a DwarfDebugInfo,
+    field_types: HashMap<DwarfDieKey, Option<DwarfFieldType>>,
+    resolving_field_types: HashSet<DwarfDieKey>,
+    layout_ids: HashMap<DwarfDieKey, String>,
+    typedef_names_by_record: HashMap<DwarfDieKey, DwarfDieKey>,
+    pending_layouts: BTreeMap<String, PendingLayout>,
+    record_layout_ids: HashSet<String>,
+    global_variables: BTreeMap<u64, DwarfGlobalVariable>,
+    global_variable_shapes: BTreeMap<u64, DwarfFieldShape>,
+    functions: BTreeMap<u64, DwarfFunction>,
+}
+
+impl<'a> DwarfSymbolBuilder<'a> {
+    pub(crate) fn build(dwarf_debug_info: &'a DwarfDebugInfo) -> DwarfSymbols {
+        let mut dwarf_symbol_builder = Self {
+            dwarf_debug_info,
+            field_types: HashMap::new(),
+            resolving_field_types: HashSet::new(),
+            layout_ids: HashMap::new(),
+            typedef_names_by_record: HashMap::new(),
+            pending_layouts: BTreeMap::new(),
+            record_layout_ids: HashSet::new(),
+            global_variables: BTreeMap::new(),
+            global_variable_shapes: BTreeMap::new(),
+            functions: BTreeMap::new(),
+        };
+        let unit_root_die_keys = dwarf_debug_info
+            .get_units()
+            .iter()
+            .filter(|dwarf_unit| !dwarf_unit.is_skeleton())
+            .filter_map(|dwarf_unit| dwarf_debug_info.get_unit_root(dwarf_unit))
+            .collect::<Vec<_>>();
+
+        for unit_root_die_key in &unit_root_die_keys {
+            dwarf_symbol_builder.collect_typedef_names(*unit_root_die_key);
+        }
+
+        for unit_root_die_key in &unit_root_die_keys {
+            dwarf_symbol_builder.visit_unit(*unit_root_die_key);
+        }
+
+        dwarf_symbol_builder.finish()
+    }
+
+    /// Anonymous records declared through `typedef struct { ... } Name;` take the typedef name as their layout id.
+    fn collect_typedef_names(
+        &mut self,
+        unit_root_die_key: DwarfDieKey,
+    ) {
+        let mut pending_die_keys = vec![unit_root_die_key];
+
+        while let Some(die_key) = pending_die_keys.pop() {
+            let Some(die) = self.dwarf_debug_info.get_die(die_key) else {
+                continue;
+            };
+
+            if die.get_tag() == DW_TAG_TYPEDEF
+                && let Some(record_die_key) = self.dwarf_debug_info.get_reference(die_key, DW_AT_TYPE)
+                && self.is_record(record_die_key)
+                && self
+                    .dwarf_debug_info
+                    .get_string(record_die_key, DW_AT_NAME)
+                    .is_none()
+            {
+                self.typedef_names_by_record
+                    .entry(record_die_key)
+                    .or_insert(die_key);
+            }
+
+            if is_scope_tag(die.get_tag()) {
+                pending_die_keys.extend(die.get_children().iter().rev());
+            }
+        }
+    }
+
+    fn visit_unit(
+        &mut self,
+        unit_root_die_key: DwarfDieKey,
+    ) {
+        let mut pending_die_keys = vec![unit_root_die_key];
+
+        while let Some(die_key) = pending_die_keys.pop() {
+            let Some(die) = self.dwarf_debug_info.get_die(die_key) else {
+                continue;
+            };
+
+            match die.get_tag() {
+                DW_TAG_STRUCTURE_TYPE | DW_TAG_CLASS_TYPE | DW_TAG_UNION_TYPE => {
+                    if !self.dwarf_debug_info.get_flag(die_key, DW_AT_DECLARATION) {
+                        self.build_record_layout(die_key);
+                    }
+                }
+                DW_TAG_VARIABLE => {
+                    self.add_global_variable(die_key);
+                    continue;
+                }
+                DW_TAG_SUBPROGRAM => self.add_function(die_key),
+                _ => {}
+            }
+
+            if is_scope_tag(die.get_tag()) || die.get_tag() == DW_TAG_SUBPROGRAM || die.get_tag() == DW_TAG_LEXICAL_BLOCK {
+                pending_die_keys.extend(die.get_children().iter().rev());
+            }
+        }
+    }
+
+    fn build_record_layout(
+        &mut self,
+        record_die_key: DwarfDieKey,
+    ) {
+        let layout_id = self.get_layout_id(record_die_key);
+
+        if self.pending_layouts.contains_key(&layout_id) {
+            return;
+        }
+
+        let Some(size_in_bytes) = self
+            .dwarf_debug_info
+            .get_unsigned(record_die_key, DW_AT_BYTE_SIZE)
+        else {
+            return;
+        };
+        let Some(record_die) = self.dwarf_debug_info.get_die(record_die_key) else {
+            return;
+        };
+        let layout_kind = if record_die.get_tag() == DW_TAG_UNION_TYPE {
+            SymbolicLayoutKind::Union
+        } else {
+            SymbolicLayoutKind::Struct
+        };
+        let mut fields = Vec::new();
+
+        for (member_index, member_die_key) in record_die.get_children().iter().copied().enumerate() {
+            let Some(member_die) = self.dwarf_debug_info.get_die(member_die_key) else {
+                continue;
+            };
+            let is_base_class = member_die.get_tag() == DW_TAG_INHERITANCE;
+
+            if member_die.get_tag() != DW_TAG_MEMBER && !is_base_class {
+                continue;
+            }
+
+            // Static data members are declarations only; their storage is described by a separate variable DIE.
+            if self
+                .dwarf_debug_info
+                .get_flag(member_die_key, DW_AT_DECLARATION)
+                || self.dwarf_debug_info.get_flag(member_die_key, DW_AT_EXTERNAL)
+            {
+                continue;
+            }
+
+            let Some(member_type_die_key) = self.dwarf_debug_info.get_reference(member_die_key, DW_AT_TYPE) else {
+                continue;
+            };
+            let Some(field_shape) = self
+                .resolve_field_type(member_type_die_key)
+                .and_then(|field_type| flatten_field_type(&field_type))
+            else {
+                continue;
+            };
+            let offset = if layout_kind == SymbolicLayoutKind::Union {
+                0
+            } else {
+                match self.resolve_member_offset(member_die_key, &field_shape) {
+                    Some(offset) => offset,
+                    None => continue,
+                }
+            };
+            let field_name = if is_base_class {
+                format!("base_{}", sanitize_layout_id_component(&field_shape.data_type_id))
+            } else {
+                self.dwarf_debug_info
+                    .get_string(member_die_key, DW_AT_NAME)
+                    .unwrap_or_else(|| format!("anonymous_{member_index}"))
+            };
+
+            fields.push(PendingLayoutField {
+                offset,
+                field_name,
+                field_shape,
+            });
+        }
+
+        self.pending_layouts.insert(
+            layout_id.clone(),
+            PendingLayout {
+                layout_id,
+                layout_kind,
+                size_in_bytes,
+                fields,
+            },
+        );
+    }
+
+    /// Bitfields share a storage unit, so they resolve to the byte offset of the unit that holds their first bit.
+    fn resolve_member_offset(
+        &self,
+        member_die_key: DwarfDieKey,
+        field_shape: &DwarfFieldShape,
+    ) -> Option<u64> {
+        if let Some(data_bit_offset) = self
+            .dwarf_debug_info
+            .get_unsigned(member_die_key, DW_AT_DATA_BIT_OFFSET)
+        {
+            let storage_size_in_bytes = field_shape.size_in_bytes.max(1);
+
+            return Some((data_bit_offset / 8) / storage_size_in_bytes * storage_size_in_bytes);
+        }
+
+        if let Some(member_offset) = self
+            .dwarf_debug_info
+            .get_unsigned(member_die_key, DW_AT_DATA_MEMBER_LOCATION)
+        {
+            return Some(member_offset);
+        }
+
+        match self
+            .dwarf_debug_info
+            .get_block(member_die_key, DW_AT_DATA_MEMBER_LOCATION)
+        {
+            Some(location_expression) => evaluate_member_location(location_expression, self.dwarf_debug_info.is_big_endian(member_die_key)),
+            None => Some(0),
+        }
+    }
+
+    fn add_global_variable(
+        &mut self,
+        variable_die_key: DwarfDieKey,
+    ) {
+        let Some(virtual_address) = self.resolve_static_address(variable_die_key) else {
+            return;
+        };
+
+        if self.global_variables.contains_key(&virtual_address) {
+            return;
+        }
+
+        let declaration_die_key = self
+            .dwarf_debug_info
+            .get_reference(variable_die_key, DW_AT_SPECIFICATION)
+            .unwrap_or(variable_die_key);
+        let Some(variable_name) = self
+            .dwarf_debug_info
+            .get_string(variable_die_key, DW_AT_NAME)
+            .or_else(|| {
+                self.dwarf_debug_info
+                    .get_string(declaration_die_key, DW_AT_NAME)
+            })
+        else {
+            return;
+        };
+        let Some(variable_type_die_key) = self
+            .dwarf_debug_info
+            .get_reference(variable_die_key, DW_AT_TYPE)
+            .or_else(|| {
+                self.dwarf_debug_info
+                    .get_reference(declaration_die_key, DW_AT_TYPE)
+            })
+        else {
+            return;
+        };
+        let Some(field_shape) = self
+            .resolve_field_type(variable_type_die_key)
+            .and_then(|field_type| flatten_field_type(&field_type))
+            .filter(|field_shape| field_shape.size_in_bytes > 0)
+        else {
+            return;
+        };
+
+        self.global_variables.insert(
+            virtual_address,
+            DwarfGlobalVariable {
+                display_name: self.get_qualified_display_name(declaration_die_key, &variable_name),
+                virtual_address,
+                symbol_type_id: field_shape.to_symbol_type_id(),
+                size_in_bytes: field_shape.size_in_bytes,
+            },
+        );
+        self.global_variable_shapes.insert(virtual_address, field_shape);
+    }
+
+    /// Only locations that are a single `DW_OP_addr`/`DW_OP_addrx` describe fixed storage. Thread-local and register
+    /// locations append further operations and are skipped.
+    fn resolve_static_address(
+        &self,
+        variable_die_key: DwarfDieKey,
+    ) -> Option<u64> {
+        let location_expression = self
+            .dwarf_debug_info
+            .get_block(variable_die_key, DW_AT_LOCATION)?;
+        let address_size = u64::from(
+            self.dwarf_debug_info
+                .get_unit(variable_die_key)?
+                .get_address_size(),
+        );
+        let mut expression_reader = DwarfByteReader::new(location_expression, self.dwarf_debug_info.is_big_endian(variable_die_key));
+        let virtual_address = match expression_reader.read_u8().ok()? {
+            DW_OP_ADDR => expression_reader.read_unsigned(address_size).ok()?,
+            DW_OP_ADDRX | DW_OP_GNU_ADDR_INDEX => {
+                let address_index = expression_reader.read_uleb128().ok()?;
+
+                self.dwarf_debug_info
+                    .resolve_address_index(variable_die_key, address_index)?
+            }
+            _ => return None,
+        };
+
+        expression_reader.is_empty().then_some(virtual_address)
+    }
+
+    fn add_function(
+        &mut self,
+        function_die_key: DwarfDieKey,
+    ) {
+        let Some(virtual_address) = self
+            .dwarf_debug_info
+            .get_address(function_die_key, DW_AT_LOW_PC)
+        else {
+            return;
+        };
+
+        if self.functions.contains_key(&virtual_address) {
+            return;
+        }
+
+        let declaration_die_key = self
+            .dwarf_debug_info
+            .get_reference(function_die_key, DW_AT_SPECIFICATION)
+            .or_else(|| {
+                self.dwarf_debug_info
+                    .get_reference(function_die_key, DW_AT_ABSTRACT_ORIGIN)
+            })
+            .unwrap_or(function_die_key);
+        let declaration_die_key = self
+            .dwarf_debug_info
+            .get_reference(declaration_die_key, DW_AT_SPECIFICATION)
+            .unwrap_or(declaration_die_key);
+        let Some(function_name) = self
+            .dwarf_debug_info
+            .get_string(function_die_key, DW_AT_NAME)
+            .or_else(|| {
+                self.dwarf_debug_info
+                    .get_string(declaration_die_key, DW_AT_NAME)
+            })
+            .or_else(|| {
+                self.dwarf_debug_info
+                    .get_string(function_die_key, DW_AT_LINKAGE_NAME)
+            })
+            .or_else(|| {
+                self.dwarf_debug_info
+                    .get_string(function_die_key, DW_AT_MIPS_LINKAGE_NAME)
+            })
+        else {
+            return;
+        };
+        let size_in_bytes = match self
+            .dwarf_debug_info
+            .get_address(function_die_key, DW_AT_HIGH_PC)
+        {
+            Some(end_address) => end_address.saturating_sub(virtual_address),
+            None => self
+                .dwarf_debug_info
+                .get_unsigned(function_die_key, DW_AT_HIGH_PC)
+                .unwrap_or(1),
+        };
+
+        self.functions.insert(
+            virtual_address,
+            DwarfFunction {
+                display_name: self.get_qualified_display_name(declaration_die_key, &function_name),
+                virtual_address,
+                size_in_bytes: size_in_bytes.max(1),
+            },
+        );
+    }
+
+    fn resolve_field_type(
+        &mut self,
+        type_die_key: DwarfDieKey,
+    ) -> Option<DwarfFieldType> {
+        if let Some(field_type) = self.field_types.get(&type_die_key) {
+            return field_type.clone();
+        }
+
+        if !self.resolving_field_types.insert(type_die_key) {
+            return None;
+        }
+
+        let field_type = self.resolve_field_type_uncached(type_die_key);
+
+        self.resolving_field_types.remove(&type_die_key);
+        self.field_types.insert(type_die_key, field_type.clone());
+
+        field_type
+    }
+
+    fn resolve_field_type_uncached(
+        &mut self,
+        type_die_key: DwarfDieKey,
+    ) -> Option<DwarfFieldType> {
+        let type_die = self.dwarf_debug_info.get_die(type_die_key)?;
+        let byte_size = self
+            .dwarf_debug_info
+            .get_unsigned(type_die_key, DW_AT_BYTE_SIZE);
+
+        match type_die.get_tag() {
+            DW_TAG_BASE_TYPE => Some(self.map_base_type(type_die_key, byte_size.unwrap_or(0))),
+            DW_TAG_TYPEDEF
+            | DW_TAG_CONST_TYPE
+            | DW_TAG_VOLATILE_TYPE
+            | DW_TAG_RESTRICT_TYPE
+            | DW_TAG_ATOMIC_TYPE
+            | DW_TAG_IMMUTABLE_TYPE
+            | DW_TAG_SHARED_TYPE => {
+                let aliased_type_die_key = self.dwarf_debug_info.get_reference(type_die_key, DW_AT_TYPE)?;
+
+                self.resolve_field_type(aliased_type_die_key)
+            }
+            DW_TAG_POINTER_TYPE | DW_TAG_REFERENCE_TYPE | DW_TAG_RVALUE_REFERENCE_TYPE => {
+                let address_size = byte_size.unwrap_or_else(|| {
+                    self.dwarf_debug_info
+                        .get_unit(type_die_key)
+                        .map(|dwarf_unit| u64::from(dwarf_unit.get_address_size()))
+                        .unwrap_or(0)
+                });
+                let Some(pointer_size) = map_pointer_size(address_size, self.dwarf_debug_info.is_big_endian(type_die_key)) else {
+                    return Some(DwarfFieldType::Opaque {
+                        size_in_bytes: Some(address_size),
+                    });
+                };
+                let pointee_data_type_id = match self.dwarf_debug_info.get_reference(type_die_key, DW_AT_TYPE) {
+                    Some(pointee_die_key) => self.resolve_pointee_data_type_id(pointee_die_key),
+                    None => String::from("u8"),
+                };
+
+                Some(DwarfFieldType::Pointer {
+                    pointee_data_type_id,
+                    pointer_size,
+                })
+            }
+            DW_TAG_STRUCTURE_TYPE | DW_TAG_CLASS_TYPE | DW_TAG_UNION_TYPE => {
+                let layout_id = self.get_layout_id(type_die_key);
+
+                Some(DwarfFieldType::Value {
+                    data_type_id: layout_id,
+                    size_in_bytes: byte_size.unwrap_or(0),
+                    is_character: false,
+                })
+            }
+            DW_TAG_ENUMERATION_TYPE => match self.dwarf_debug_info.get_reference(type_die_key, DW_AT_TYPE) {
+                Some(underlying_type_die_key) => self.resolve_field_type(underlying_type_die_key),
+                None => Some(map_integer_type(
+                    byte_size.unwrap_or(0),
+                    false,
+                    self.dwarf_debug_info.is_big_endian(type_die_key),
+                )),
+            },
+            DW_TAG_ARRAY_TYPE => {
+                let element_type_die_key = self.dwarf_debug_info.get_reference(type_die_key, DW_AT_TYPE)?;
+                let mut element_type = self.resolve_field_type(element_type_die_key)?;
+                let dimension_counts = type_die
+                    .get_children()
+                    .iter()
+                    .filter(|child_die_key| {
+                        self.dwarf_debug_info
+                            .get_die(**child_die_key)
+                            .is_some_and(|child_die| child_die.get_tag() == DW_TAG_SUBRANGE_TYPE)
+                    })
+                    .map(|subrange_die_key| self.resolve_subrange_count(*subrange_die_key))
+                    .collect::<Vec<_>>();
+
+                for dimension_count in dimension_counts.iter().rev() {
+                    element_type = DwarfFieldType::Array {
+                        element_type: Box::new(element_type),
+                        element_count: *dimension_count,
+                    };
+                }
+
+                if dimension_counts.is_empty() {
+                    element_type = DwarfFieldType::Array {
+                        element_type: Box::new(element_type),
+                        element_count: None,
+                    };
+                }
+
+                Some(element_type)
+            }
+            DW_TAG_SUBROUTINE_TYPE => Some(DwarfFieldType::Opaque { size_in_bytes: None }),
+            DW_TAG_UNSPECIFIED_TYPE | DW_TAG_PTR_TO_MEMBER_TYPE => Some(DwarfFieldType::Opaque { size_in_bytes: byte_size }),
+            _ => Some(DwarfFieldType::Opaque { size_in_bytes: byte_size }),
+        }
+    }
+
+    fn resolve_subrange_count(
+        &self,
+        subrange_die_key: DwarfDieKey,
+    ) -> Option<u64> {
+        if let Some(element_count) = self
+            .dwarf_debug_info
+            .get_unsigned(subrange_die_key, DW_AT_COUNT)
+        {
+            return Some(element_count);
+        }
+
+        let upper_bound = self
+            .dwarf_debug_info
+            .get_signed(subrange_die_key, DW_AT_UPPER_BOUND)?;
+        let lower_bound = self
+            .dwarf_debug_info
+            .get_signed(subrange_die_key, DW_AT_LOWER_BOUND)
+            .unwrap_or(0);
+
+        u64::try_from(upper_bound.saturating_sub(lower_bound).saturating_add(1)).ok()
+    }
+
+    fn resolve_pointee_data_type_id(
+        &mut self,
+        pointee_die_key: DwarfDieKey,
+    ) -> String {
+        match self.resolve_field_type(pointee_die_key) {
+            Some(DwarfFieldType::Value { data_type_id, .. }) => data_type_id,
+            Some(DwarfFieldType::Pointer { pointer_size, .. }) => pointer_size.to_data_type_ref().get_data_type_id().to_string(),
+            Some(DwarfFieldType::Array { element_type, .. }) => match flatten_field_type(&element_type) {
+                Some(element_shape) if element_shape.container_type == ContainerType::None => element_shape.data_type_id,
+                _ => String::from("u8"),
+            },
+            Some(DwarfFieldType::Opaque { .. }) | None => String::from("u8"),
+        }
+    }
+
+    fn map_base_type(
+        &self,
+        base_type_die_key: DwarfDieKey,
+        size_in_bytes: u64,
+    ) -> DwarfFieldType {
+        let is_big_endian = self.dwarf_debug_info.is_big_endian(base_type_die_key);
+        let encoding = self
+            .dwarf_debug_info
+            .get_unsigned(base_type_die_key, DW_AT_ENCODING)
+            .unwrap_or(0);
+        let is_character = matches!(encoding, DW_ATE_SIGNED_CHAR | DW_ATE_UNSIGNED_CHAR)
+            && self
+                .dwarf_debug_info
+                .get_string(base_type_die_key, DW_AT_NAME)
+                .is_some_and(|type_name| type_name == "char");
+
+        match (encoding, size_in_bytes) {
+            (DW_ATE_BOOLEAN, 1) => value_type("bool8", 1),
+            (DW_ATE_BOOLEAN, 4) => value_type("bool32", 4),
+            (DW_ATE_FLOAT, 4) => value_type(if is_big_endian { "f32be" } else { "f32" }, 4),
+            (DW_ATE_FLOAT, 8) => value_type(if is_big_endian { "f64be" } else { "f64" }, 8),
+            (DW_ATE_FLOAT, _) => DwarfFieldType::Opaque {
+                size_in_bytes: Some(size_in_bytes),
+            },
+            (DW_ATE_SIGNED | DW_ATE_SIGNED_CHAR, _) => with_character_flag(map_integer_type(size_in_bytes, true, is_big_endian), is_character),
+            (DW_ATE_BOOLEAN | DW_ATE_UNSIGNED | DW_ATE_UNSIGNED_CHAR | DW_ATE_UTF, _) => {
+                with_character_flag(map_integer_type(size_in_bytes, false, is_big_endian), is_character)
+            }
+            _ => DwarfFieldType::Opaque {
+                size_in_bytes: Some(size_in_bytes),
+            },
+        }
+    }
+
+    /// Layout ids are the record's scope-qualified name joined with `.`, matching ids like `linux.elf.Elf64_Ehdr`.
+    fn get_layout_id(
+        &mut self,
+        record_die_key: DwarfDieKey,
+    ) -> String {
+        if let Some(layout_id) = self.layout_ids.get(&record_die_key) {
+            return layout_id.clone();
+        }
+
+        let record_name = self
+            .dwarf_debug_info
+            .get_string(record_die_key, DW_AT_NAME)
+            .or_else(|| {
+                self.typedef_names_by_record
+                    .get(&record_die_key)
+                    .and_then(|typedef_die_key| self.dwarf_debug_info.get_string(*typedef_die_key, DW_AT_NAME))
+            });
+        let scope_die_key = match record_name {
+            Some(_) => self
+                .typedef_names_by_record
+                .get(&record_die_key)
+                .copied()
+                .filter(|_| {
+                    self.dwarf_debug_info
+                        .get_string(record_die_key, DW_AT_NAME)
+                        .is_none()
+                })
+                .unwrap_or(record_die_key),
+            None => record_die_key,
+        };
+        let layout_name = record_name
+            .map(|record_name| sanitize_layout_id_component(&record_name))
+            .unwrap_or_else(|| format!("anonymous_{:X}", record_die_key.get_offset()));
+        let parent_die_key = self
+            .dwarf_debug_info
+            .get_die(scope_die_key)
+            .and_then(|scope_die| scope_die.get_parent());
+        let layout_id = match parent_die_key {
+            Some(parent_die_key) if self.is_record(parent_die_key) => format!("{}.{}", self.get_layout_id(parent_die_key), layout_name),
+            Some(parent_die_key) => {
+                let mut scope_names = self.get_namespace_names(parent_die_key);
+
+                scope_names.push(layout_name);
+                scope_names.join(".")
+            }
+            None => layout_name,
+        };
+
+        self.record_layout_ids.insert(layout_id.clone());
+        self.layout_ids.insert(record_die_key, layout_id.clone());
+
+        layout_id
+    }
+
+    fn get_namespace_names(
+        &self,
+        scope_die_key: DwarfDieKey,
+    ) -> Vec<String> {
+        let mut namespace_names = Vec::new();
+        let mut current_die_key = Some(scope_die_key);
+
+        while let Some(die_key) = current_die_key {
+            let Some(die) = self.dwarf_debug_info.get_die(die_key) else {
+                break;
+            };
+
+            if die.get_tag() == DW_TAG_NAMESPACE {
+                namespace_names.push(
+                    self.dwarf_debug_info
+                        .get_string(die_key, DW_AT_NAME)
+                        .map(|namespace_name| sanitize_layout_id_component(&namespace_name))
+                        .unwrap_or_else(|| String::from("anonymous_namespace")),
+                );
+            }
+
+            current_die_key = die.get_parent();
+        }
+
+        namespace_names.reverse();
+        namespace_names
+    }
+
+    /// Display names keep the source spelling, qualified with `::` by their enclosing namespaces and classes.
+    fn get_qualified_display_name(
+        &self,
+        declaration_die_key: DwarfDieKey,
+        symbol_name: &str,
+    ) -> String {
+        let mut scope_names = Vec::new();
+        let mut current_die_key = self
+            .dwarf_debug_info
+            .get_die(declaration_die_key)
+            .and_then(|die| die.get_parent());
+
+        while let Some(die_key) = current_die_key {
+            let Some(die) = self.dwarf_debug_info.get_die(die_key) else {
+                break;
+            };
+
+            if matches!(
+                die.get_tag(),
+                DW_TAG_NAMESPACE | DW_TAG_STRUCTURE_TYPE | DW_TAG_CLASS_TYPE | DW_TAG_UNION_TYPE | DW_TAG_SUBPROGRAM
+            ) && let Some(scope_name) = self.dwarf_debug_info.get_string(die_key, DW_AT_NAME)
+            {
+                scope_names.push(scope_name);
+            }
+
+            current_die_key = die.get_parent();
+        }
+
+        scope_names.reverse();
+        scope_names.push(symbol_name.to_string());
+        scope_names.join("::")
+    }
+
+    fn is_record(
+        &self,
+        die_key: DwarfDieKey,
+    ) -> bool {
+        self.dwarf_debug_info
+            .get_die(die_key)
+            .is_some_and(|die| matches!(die.get_tag(), DW_TAG_STRUCTURE_TYPE | DW_TAG_CLASS_TYPE | DW_TAG_UNION_TYPE))
+    }
+
+    /// Materializes pending layouts. Pointers to records that were only ever declared fall back to byte pointers so no
+    /// field references a layout that does not exist.
+    fn finish(mut self) -> DwarfSymbols {
+        let defined_layout_ids = self.pending_layouts.keys().cloned().collect::<HashSet<_>>();
+        let record_layout_ids = std::mem::take(&mut self.record_layout_ids);
+        let resolve_data_type_id = |data_type_id: &str| -> String {
+            if record_layout_ids.contains(data_type_id) && !defined_layout_ids.contains(data_type_id) {
+                String::from("u8")
+            } else {
+                data_type_id.to_string()
+            }
+        };
+        let struct_layout_descriptors = self
+            .pending_layouts
+            .values()
+            .filter_map(|pending_layout| {
+                let mut positioned_fields = Vec::new();
+                let mut next_free_offset = 0_u64;
+
+                for pending_field in &pending_layout.fields {
+                    let field_end = pending_field
+                        .offset
+                        .saturating_add(pending_field.field_shape.size_in_bytes);
+
+                    if pending_layout.layout_kind == SymbolicLayoutKind::Struct {
+                        if pending_field.offset < next_free_offset || field_end > pending_layout.size_in_bytes {
+                            continue;
+                        }
+
+                        next_free_offset = field_end;
+                    }
+
+                    positioned_fields.push(SymbolLayoutPositionedField::new(
+                        pending_field.offset,
+                        pending_field.field_shape.size_in_bytes,
+                        SymbolicFieldDefinition::new_named(
+                            pending_field.field_name.clone(),
+                            DataTypeRef::new(&resolve_data_type_id(&pending_field.field_shape.data_type_id)),
+                            pending_field.field_shape.container_type,
+                        ),
+                    ));
+                }
+
+                let materialized_fields = SymbolLayoutFieldMaterializer::materialize_positioned_fields(
+                    pending_layout.layout_kind,
+                    Some(pending_layout.size_in_bytes),
+                    positioned_fields,
+                )
+                .ok()?;
+
+                Some(StructLayoutDescriptor::new(
+                    pending_layout.layout_id.clone(),
+                    SymbolicStructDefinition::new_with_layout_kind(pending_layout.layout_id.clone(), pending_layout.layout_kind, materialized_fields)
+                        .with_declared_size_in_bytes(Some(pending_layout.size_in_bytes)),
+                ))
+            })
+            .collect();
+        let global_variables = self
+            .global_variables
+            .into_values()
+            .map(|mut global_variable| {
+                if let Some(field_shape) = self
+                    .global_variable_shapes
+                    .get(&global_variable.virtual_address)
+                {
+                    global_variable.symbol_type_id = DwarfFieldShape {
+                        data_type_id: resolve_data_type_id(&field_shape.data_type_id),
+                        container_type: field_shape.container_type,
+                        size_in_bytes: field_shape.size_in_bytes,
+                    }
+                    .to_symbol_type_id();
+                }
+
+                global_variable
+            })
+            .collect();
+
+        DwarfSymbols {
+            struct_layout_descriptors,
+            global_variables,
+            functions: self.functions.into_values().collect(),
+        }
+    }
+}
+
+fn flatten_field_type(field_type: &DwarfFieldType) -> Option<DwarfFieldShape> {
+    match field_type {
+        DwarfFieldType::Value {
+            data_type_id, size_in_bytes, ..
+        } => Some(DwarfFieldShape {
+            data_type_id: data_type_id.clone(),
+            container_type: ContainerType::None,
+            size_in_bytes: *size_in_bytes,
+        }),
+        DwarfFieldType::Pointer {
+            pointee_data_type_id,
+            pointer_size,
+        } => Some(DwarfFieldShape {
+            data_type_id: pointee_data_type_id.clone(),
+            container_type: ContainerType::Pointer(*pointer_size),
+            size_in_bytes: pointer_size.get_size_in_bytes(),
+        }),
+        DwarfFieldType::Array { .. } => {
+            let mut element_type = field_type;
+            let mut element_count = Some(1_u64);
+            let mut dimension_count = 0;
+
+            while let DwarfFieldType::Array {
+                element_type: inner_element_type,
+                element_count: inner_element_count,
+            } = element_type
+            {
+                element_count = element_count
+                    .zip(*inner_element_count)
+                    .map(|(outer, inner)| outer.saturating_mul(inner));
+                element_type = inner_element_type;
+                dimension_count += 1;
+            }
+
+            let total_size = |element_size_in_bytes: u64| element_count.map_or(0, |element_count| element_count.saturating_mul(element_size_in_bytes));
+            let array_container = || element_count.map_or(ContainerType::Array, ContainerType::ArrayFixed);
+
+            match element_type {
+                DwarfFieldType::Value {
+                    is_character: true,
+                    size_in_bytes,
+                    ..
+                } if dimension_count == 1 => Some(DwarfFieldShape {
+                    data_type_id: String::from(STRING_UTF8_NULL_TERMINATED_DATA_TYPE_ID),
+                    container_type: array_container(),
+                    size_in_bytes: total_size(*size_in_bytes),
+                }),
+                DwarfFieldType::Value {
+                    data_type_id, size_in_bytes, ..
+                } => Some(DwarfFieldShape {
+                    data_type_id: data_type_id.clone(),
+                    container_type: array_container(),
+                    size_in_bytes: total_size(*size_in_bytes),
+                }),
+                DwarfFieldType::Pointer {
+                    pointee_data_type_id,
+                    pointer_size,
+                } => Some(DwarfFieldShape {
+                    data_type_id: pointee_data_type_id.clone(),
+                    container_type: match element_count {
+                        Some(element_count) => ContainerType::PointerArrayFixed(*pointer_size, element_count),
+                        None => ContainerType::PointerArray(*pointer_size),
+                    },
+                    size_in_bytes: total_size(pointer_size.get_size_in_bytes()),
+                }),
+                DwarfFieldType::Opaque {
+                    size_in_bytes: Some(size_in_bytes),
+                } if *size_in_bytes > 0 => Some(DwarfFieldShape {
+                    data_type_id: String::from("u8"),
+                    container_type: ContainerType::ArrayFixed(total_size(*size_in_bytes)),
+                    size_in_bytes: total_size(*size_in_bytes),
+                })
+                .filter(|field_shape| field_shape.size_in_bytes > 0),
+                DwarfFieldType::Array { .. } | DwarfFieldType::Opaque { .. } => None,
+            }
+        }
+        DwarfFieldType::Opaque {
+            size_in_bytes: Some(size_in_bytes),
+        } if *size_in_bytes > 0 => Some(DwarfFieldShape {
+            data_type_id: String::from("u8"),
+            container_type: ContainerType::ArrayFixed(*size_in_bytes),
+            size_in_bytes: *size_in_bytes,
+        }),
+        DwarfFieldType::Opaque { .. } => None,
+    }
+}
+
+/// Member locations are usually constants, but older producers emit `DW_OP_plus_uconst` expressions.
+fn evaluate_member_location(
+    location_expression: &[u8],
+    is_big_endian: bool,
+) -> Option<u64> {
+    let mut expression_reader = DwarfByteReader::new(location_expression, is_big_endian);
+    let member_offset = match expression_reader.read_u8().ok()? {
+        DW_OP_PLUS_UCONST | DW_OP_CONSTU => expression_reader.read_uleb128().ok()?,
+        DW_OP_CONST1U => expression_reader.read_unsigned(1).ok()?,
+        DW_OP_CONST2U => expression_reader.read_unsigned(2).ok()?,
+        DW_OP_CONST4U => expression_reader.read_unsigned(4).ok()?,
+        DW_OP_CONST8U => expression_reader.read_unsigned(8).ok()?,
+        _ => return None,
+    };
+
+    Some(member_offset)
+}
+
+fn map_integer_type(
+    size_in_bytes: u64,
+    is_signed: bool,
+    is_big_endian: bool,
+) -> DwarfFieldType {
+    if !matches!(size_in_bytes, 1 | 2 | 4 | 8) {
+        return DwarfFieldType::Opaque {
+            size_in_bytes: Some(size_in_bytes),
+        };
+    }
+
+    let sign_prefix = if is_signed { "i" } else { "u" };
+    let endian_suffix = if is_big_endian && size_in_bytes > 1 { "be" } else { "" };
+
+    value_type(&format!("{}{}{}", sign_prefix, size_in_bytes * 8, endian_suffix), size_in_bytes)
+}
+
+fn map_pointer_size(
+    address_size: u64,
+    is_big_endian: bool,
+) -> Option<PointerScanPointerSize> {
+    match (address_size, is_big_endian) {
+        (4, false) => Some(PointerScanPointerSize::Pointer32),
+        (4, true) => Some(PointerScanPointerSize::Pointer32be),
+        (8, false) => Some(PointerScanPointerSize::Pointer64),
+        (8, true) => Some(PointerScanPointerSize::Pointer64be),
+        _ => None,
+    }
+}
+
+fn value_type(
+    data_type_id: &str,
+    size_in_bytes: u64,
+) -> DwarfFieldType {
+    DwarfFieldType::Value {
+        data_type_id: data_type_id.to_string(),
+        size_in_bytes,
+        is_character: false,
+    }
+}
+
+fn with_character_flag(
+    field_type: DwarfFieldType,
+    is_character: bool,
+) -> DwarfFieldType {
+    match field_type {
+        DwarfFieldType::Value {
+            data_type_id, size_in_bytes, ..
+        } => DwarfFieldType::Value {
+            data_type_id,
+            size_in_bytes,
+            is_character,
+        },
+        other_field_type => other_field_type,
+    }
+}
+
+fn is_scope_tag(tag: u64) -> bool {
+    matches!(
+        tag,
+        DW_TAG_COMPILE_UNIT
+            | DW_TAG_PARTIAL_UNIT
+            | DW_TAG_TYPE_UNIT
+            | DW_TAG_SKELETON_UNIT
+            | DW_TAG_NAMESPACE
+            | DW_TAG_STRUCTURE_TYPE
+            | DW_TAG_CLASS_TYPE
+            | DW_TAG_UNION_TYPE
+    )
+}
+
+/// Template arguments and operators produce characters that the field and resolver syntax reserve, so layout id
+/// components keep only identifier characters.
+fn sanitize_layout_id_component(name: &str) -> String {
+    let mut sanitized_name = String::with_capacity(name.len());
+
+    for name_character in name.chars() {
+        if name_character.is_ascii_alphanumeric() || name_character == '_' {
+            sanitized_name.push(name_character);
+        } else if !sanitized_name.ends_with('_') {
+            sanitized_name.push('_');
+        }
+    }
+
+    let sanitized_name = sanitized_name.trim_matches('_');
+
+    if sanitized_name.is_empty() {
+        String::from("anonymous")
+    } else {
+        sanitized_name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DwarfFunction, DwarfGlobalVariable, DwarfSymbolBuilder, sanitize_layout_id_component};
+    use crate::formats::elf::dwarf::dwarf_debug_info::{DwarfDebugInfo, DwarfSectionSet, build_test_dwarf_sections};
+    use squalr_engine_api::structures::{data_values::container_type::ContainerType, pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize};
+
+    #[test]
+    fn builds_layouts_globals_and_functions_from_dies() {
+        let (debug_info, debug_abbrev) = build_test_dwarf_sections();
+        let mut dwarf_debug_info = DwarfDebugInfo::new();
+
+        dwarf_debug_info
+            .add_object(
+                DwarfSectionSet {
+                    debug_info,
+                    debug_abbrev,
+                    ..DwarfSectionSet::default()
+                },
+                None,
+            )
+            .expect("Expected test DWARF to parse.");
+
+        let dwarf_symbols = DwarfSymbolBuilder::build(&dwarf_debug_info);
+        let player_layout_descriptor = dwarf_symbols
+            .struct_layout_descriptors
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == "Game.Player")
+            .expect("Expected Game.Player layout.");
+        let player_layout_definition = player_layout_descriptor.get_struct_layout_definition();
+        let find_field = |field_name: &str| {
+            player_layout_definition
+                .get_fields()
+                .iter()
+                .find(|field_definition| field_definition.get_field_name() == field_name)
+                .unwrap_or_else(|| panic!("Expected `{field_name}` field."))
+        };
+
+        assert_eq!(dwarf_symbols.struct_layout_descriptors.len(), 1);
+        assert_eq!(player_layout_definition.get_declared_size_in_bytes(), Some(0x18));
+        assert_eq!(find_field("health").get_data_type_ref().get_data_type_id(), "i32");
+        assert_eq!(find_field("name").get_data_type_ref().get_data_type_id(), "string_utf8{null_terminated}");
+        assert_eq!(find_field("name").get_container_type(), ContainerType::ArrayFixed(8));
+        assert_eq!(find_field("next").get_data_type_ref().get_data_type_id(), "Game.Player");
+        assert_eq!(
+            find_field("next").get_container_type(),
+            ContainerType::Pointer(PointerScanPointerSize::Pointer64)
+        );
+        assert_eq!(
+            dwarf_symbols.global_variables,
+            vec![DwarfGlobalVariable {
+                display_name: String::from("Game::g_player"),
+                virtual_address: 0x4000,
+                symbol_type_id: String::from("Game.Player"),
+                size_in_bytes: 0x18,
+            }]
+        );
+        assert_eq!(
+            dwarf_symbols.functions,
+            vec![DwarfFunction {
+                display_name: String::from("Game::tick"),
+                virtual_address: 0x1000,
+                size_in_bytes: 0x40,
+            }]
+        );
+    }
+
+    #[test]
+    fn sanitize_layout_id_component_strips_template_syntax() {
+        assert_eq!(sanitize_layout_id_component("Array<Player *, 4>"), "Array_Player_4");
+        assert_eq!(sanitize_layout_id_component("operator<"), "operator");
+        assert_eq!(sanitize_layout_id_component("<>"), "anonymous");
+    }
+}
@@ -0,0 +1,167 @@
+use crate::formats::elf::dwarf::debug_file_locator::DebugFileLocator;
+use crate::formats::elf::dwarf::dwarf_debug_info::{DwarfDebugInfo, DwarfSectionSet};
+use crate::formats::elf::dwarf::dwarf_symbol_builder::{DwarfSymbolBuilder, DwarfSymbols};
+use crate::formats::elf::dwarf::elf_debug_image::ElfDebugImage;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Loads DWARF debug info for a module image on disk, following separate debug files and split DWARF objects.
+#[derive(Clone, Debug)]
+pub(crate) struct DwarfSymbolLoader {
+    debug_file_locator: DebugFileLocator,
+}
+
+impl DwarfSymbolLoader {
+    pub(crate) fn new(debug_file_locator: DebugFileLocator) -> Self {
+        Self { debug_file_locator }
+    }
+
+    /// Returns `None` when neither the module nor any separate debug file carries `.debug_info`.
+    pub(crate) fn load(
+        &self,
+        module_file_path: &Path,
+    ) -> Result<Option<DwarfSymbols>, String> {
+        let module_image = read_elf_debug_image(module_file_path)?;
+        let Some(debug_image) = self.resolve_debug_image(module_file_path, module_image) else {
+            return Ok(None);
+        };
+        let dwarf_section_set = DwarfSectionSet::from_elf_debug_image(&debug_image)?;
+        let skeleton_debug_addr = dwarf_section_set.debug_addr.clone();
+        let mut dwarf_debug_info = DwarfDebugInfo::new();
+
+        dwarf_debug_info.add_object(dwarf_section_set, None)?;
+
+        let mut loaded_dwo_paths = HashSet::new();
+
+        for skeleton_unit in dwarf_debug_info.get_skeleton_units() {
+            let dwo_candidates =
+                self.debug_file_locator
+                    .dwo_candidates(module_file_path, skeleton_unit.compilation_directory.as_deref(), &skeleton_unit.dwo_name);
+
+            // A missing or unreadable `.dwo` only loses that unit's types, so the remaining units still load.
+            let Some((dwo_path, mut split_section_set)) = dwo_candidates.into_iter().find_map(|dwo_candidate| {
+                let split_section_set = read_elf_debug_image(&dwo_candidate)
+                    .and_then(|dwo_image| DwarfSectionSet::from_split_elf_debug_image(&dwo_image))
+                    .ok()?;
+
+                Some((dwo_candidate, split_section_set))
+            }) else {
+                continue;
+            };
+
+            if !loaded_dwo_paths.insert(dwo_path) {
+                continue;
+            }
+
+            if split_section_set.debug_addr.is_empty() {
+                split_section_set.debug_addr = skeleton_debug_addr.clone();
+            }
+
+            let _ = dwarf_debug_info.add_object(split_section_set, Some(skeleton_unit.addr_base));
+        }
+
+        Ok(Some(DwarfSymbolBuilder::build(&dwarf_debug_info)))
+    }
+
+    /// Stripped modules keep only a build-id note and a `.gnu_debuglink` name pointing at their separate debug file.
+    fn resolve_debug_image(
+        &self,
+        module_file_path: &Path,
+        module_image: ElfDebugImage,
+    ) -> Option<ElfDebugImage> {
+        if module_image.has_debug_info() {
+            return Some(module_image);
+        }
+
+        let mut debug_file_candidates: Vec<PathBuf> = Vec::new();
+
+        if let Some(build_id) = module_image.build_id() {
+            debug_file_candidates.extend(self.debug_file_locator.build_id_candidates(&build_id));
+        }
+
+        if let Some(debug_link) = module_image.debug_link() {
+            debug_file_candidates.extend(
+                self.debug_file_locator
+                    .debug_link_candidates(module_file_path, &debug_link),
+            );
+        }
+
+        debug_file_candidates
+            .iter()
+            .filter(|debug_file_candidate| debug_file_candidate.is_file())
+            .filter_map(|debug_file_candidate| read_elf_debug_image(debug_file_candidate).ok())
+            .find(|debug_image| debug_image.has_debug_info())
+    }
+}
+
+impl Default for DwarfSymbolLoader {
+    fn default() -> Self {
+        Self::new(DebugFileLocator::default())
+    }
+}
+
+fn read_elf_debug_image(file_path: &Path) -> Result<ElfDebugImage, String> {
+    let file_bytes = std::fs::read(file_path).map_err(|error| format!("Failed to read `{}`: {}", file_path.display(), error))?;
+
+    ElfDebugImage::parse(file_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DwarfSymbolLoader;
+    use crate::formats::elf::dwarf::debug_file_locator::DebugFileLocator;
+    use crate::formats::elf::dwarf::dwarf_debug_info::build_test_dwarf_sections;
+    use crate::formats::elf::dwarf::elf_debug_image::build_test_elf_file;
+
+    const SHT_PROGBITS: u32 = 1;
+    const SHT_NOTE: u32 = 7;
+
+    #[test]
+    fn load_follows_build_id_to_separate_debug_file() {
+        let temp_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let debug_root = temp_directory.path().join("debug");
+        let module_file_path = temp_directory.path().join("game");
+        let debug_file_path = debug_root.join(".build-id").join("ab").join("cdef.debug");
+        let (debug_info, debug_abbrev) = build_test_dwarf_sections();
+        let mut build_id_note = Vec::new();
+
+        build_id_note.extend_from_slice(&4_u32.to_le_bytes());
+        build_id_note.extend_from_slice(&3_u32.to_le_bytes());
+        build_id_note.extend_from_slice(&3_u32.to_le_bytes());
+        build_id_note.extend_from_slice(b"GNU\0");
+        build_id_note.extend_from_slice(&[0xAB, 0xCD, 0xEF, 0x00]);
+
+        std::fs::create_dir_all(debug_file_path.parent().expect("Expected build-id directory.")).expect("Expected build-id directory to be created.");
+        std::fs::write(&module_file_path, build_test_elf_file(&[(".note.gnu.build-id", SHT_NOTE, build_id_note)])).expect("Expected module to be written.");
+        std::fs::write(
+            &debug_file_path,
+            build_test_elf_file(&[
+                (".debug_info", SHT_PROGBITS, debug_info),
+                (".debug_abbrev", SHT_PROGBITS, debug_abbrev),
+            ]),
+        )
+        .expect("Expected debug file to be written.");
+
+        let dwarf_symbols = DwarfSymbolLoader::new(DebugFileLocator::new(vec![debug_root]))
+            .load(&module_file_path)
+            .expect("Expected debug info to load.")
+            .expect("Expected debug info to be found through the build-id.");
+
+        assert_eq!(dwarf_symbols.global_variables[0].display_name, "Game::g_player");
+        assert_eq!(dwarf_symbols.functions[0].display_name, "Game::tick");
+    }
+
+    #[test]
+    fn load_returns_none_without_debug_info() {
+        let temp_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let module_file_path = temp_directory.path().join("game");
+
+        std::fs::write(&module_file_path, build_test_elf_file(&[(".text", SHT_PROGBITS, vec![0xC3])])).expect("Expected module to be written.");
+
+        let dwarf_symbols = DwarfSymbolLoader::new(DebugFileLocator::new(vec![temp_directory.path().join("debug")]))
+            .load(&module_file_path)
+            .expect("Expected stripped module to load.");
+
+        assert!(dwarf_symbols.is_none());
+    }
+}
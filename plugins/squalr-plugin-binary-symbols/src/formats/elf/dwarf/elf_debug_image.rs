@@ -0,0 +1,376 @@
+use crate::formats::elf::dwarf::dwarf_byte_reader::{DwarfByteReader, read_section_string};
+use std::borrow::Cow;
+
+const ELF_CLASS64: u8 = 2;
+const ELF_DATA_BIG_ENDIAN: u8 = 2;
+const SHT_NOTE: u32 = 7;
+const SHT_NOBITS: u32 = 8;
+const SHF_COMPRESSED: u64 = 0x800;
+const ELFCOMPRESS_ZLIB: u32 = 1;
+const NT_GNU_BUILD_ID: u32 = 3;
+const ZDEBUG_MAGIC: &[u8] = b"ZLIB";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ElfDebugSection {
+    name: String,
+    section_type: u32,
+    flags: u64,
+    file_offset: u64,
+    size_in_bytes: u64,
+}
+
+/// An ELF file read from disk. Unlike the loaded image, the file still carries its non-allocated sections, which is where
+/// debug info lives.
+#[derive(Clone, Debug)]
+pub(crate) struct ElfDebugImage {
+    file_bytes: Vec<u8>,
+    is_64_bit: bool,
+    is_big_endian: bool,
+    sections: Vec<ElfDebugSection>,
+}
+
+impl ElfDebugImage {
+    pub(crate) fn parse(file_bytes: Vec<u8>) -> Result<Self, String> {
+        if !file_bytes.starts_with(b"\x7FELF") || file_bytes.len() < 16 {
+            return Err(String::from("File is not an ELF image."));
+        }
+
+        let is_64_bit = file_bytes[4] == ELF_CLASS64;
+        let is_big_endian = file_bytes[5] == ELF_DATA_BIG_ENDIAN;
+        let mut header_reader = DwarfByteReader::new_at(&file_bytes, if is_64_bit { 0x28 } else { 0x20 }, is_big_endian)?;
+        let section_header_offset = if is_64_bit {
+            header_reader.read_u64()?
+        } else {
+            u64::from(header_reader.read_u32()?)
+        };
+
+        header_reader.skip(10)?;
+
+        let section_header_entry_size = u64::from(header_reader.read_u16()?);
+        let section_header_count = u64::from(header_reader.read_u16()?);
+        let section_header_string_table_index = u64::from(header_reader.read_u16()?);
+        let mut raw_sections = Vec::new();
+
+        for section_index in 0..section_header_count {
+            let section_header_position = section_header_offset.saturating_add(section_index.saturating_mul(section_header_entry_size));
+            let mut section_reader = DwarfByteReader::new_at(&file_bytes, section_header_position, is_big_endian)?;
+            let name_offset = section_reader.read_u32()?;
+            let section_type = section_reader.read_u32()?;
+            let (flags, file_offset, size_in_bytes) = if is_64_bit {
+                let flags = section_reader.read_u64()?;
+
+                section_reader.skip(8)?;
+
+                (flags, section_reader.read_u64()?, section_reader.read_u64()?)
+            } else {
+                let flags = u64::from(section_reader.read_u32()?);
+
+                section_reader.skip(4)?;
+
+                (flags, u64::from(section_reader.read_u32()?), u64::from(section_reader.read_u32()?))
+            };
+
+            raw_sections.push((name_offset, section_type, flags, file_offset, size_in_bytes));
+        }
+
+        let section_name_table = raw_sections
+            .get(section_header_string_table_index as usize)
+            .and_then(|(_, _, _, file_offset, size_in_bytes)| slice_file_range(&file_bytes, *file_offset, *size_in_bytes))
+            .unwrap_or_default();
+        let sections = raw_sections
+            .iter()
+            .map(|(name_offset, section_type, flags, file_offset, size_in_bytes)| ElfDebugSection {
+                name: read_section_string(section_name_table, u64::from(*name_offset)).unwrap_or_default(),
+                section_type: *section_type,
+                flags: *flags,
+                file_offset: *file_offset,
+                size_in_bytes: *size_in_bytes,
+            })
+            .collect();
+
+        Ok(Self {
+            file_bytes,
+            is_64_bit,
+            is_big_endian,
+            sections,
+        })
+    }
+
+    pub(crate) fn is_big_endian(&self) -> bool {
+        self.is_big_endian
+    }
+
+    pub(crate) fn has_debug_info(&self) -> bool {
+        self.find_section(".debug_info")
+            .is_some_and(|section| section.section_type != SHT_NOBITS && section.size_in_bytes > 0)
+    }
+
+    /// Returns the contents of a section, transparently inflating `SHF_COMPRESSED` and legacy `.zdebug_*` sections.
+    pub(crate) fn section_bytes(
+        &self,
+        section_name: &str,
+    ) -> Result<Option<Cow<'_, [u8]>>, String> {
+        let Some(section) = self.find_section(section_name) else {
+            return Ok(None);
+        };
+
+        if section.section_type == SHT_NOBITS {
+            return Ok(None);
+        }
+
+        let Some(raw_bytes) = slice_file_range(&self.file_bytes, section.file_offset, section.size_in_bytes) else {
+            return Err(format!("Section `{}` extends past the end of the file.", section.name));
+        };
+
+        if section.flags & SHF_COMPRESSED != 0 {
+            return self.inflate_compressed_section(section, raw_bytes).map(Some);
+        }
+
+        if section.name.starts_with(".zdebug") {
+            return inflate_zdebug_section(section, raw_bytes).map(Some);
+        }
+
+        Ok(Some(Cow::Borrowed(raw_bytes)))
+    }
+
+    /// Reads the `NT_GNU_BUILD_ID` note, which names the matching file under `/usr/lib/debug/.build-id/`.
+    pub(crate) fn build_id(&self) -> Option<Vec<u8>> {
+        self.sections
+            .iter()
+            .filter(|section| section.section_type == SHT_NOTE)
+            .filter_map(|section| slice_file_range(&self.file_bytes, section.file_offset, section.size_in_bytes))
+            .find_map(|note_bytes| find_gnu_build_id(note_bytes, self.is_big_endian))
+    }
+
+    /// Reads the file name stored in `.gnu_debuglink`.
+    pub(crate) fn debug_link(&self) -> Option<String> {
+        let debug_link_bytes = self.section_bytes(".gnu_debuglink").ok()??;
+
+        read_section_string(&debug_link_bytes, 0).filter(|debug_link| !debug_link.is_empty())
+    }
+
+    fn find_section(
+        &self,
+        section_name: &str,
+    ) -> Option<&ElfDebugSection> {
+        let zdebug_name = section_name
+            .strip_prefix(".debug")
+            .map(|section_suffix| format!(".zdebug{section_suffix}"));
+
+        self.sections
+            .iter()
+            .find(|section| section.name == section_name)
+            .or_else(|| {
+                zdebug_name.as_ref().and_then(|zdebug_name| {
+                    self.sections
+                        .iter()
+                        .find(|section| &section.name == zdebug_name)
+                })
+            })
+    }
+
+    fn inflate_compressed_section(
+        &self,
+        section: &ElfDebugSection,
+        raw_bytes: &[u8],
+    ) -> Result<Cow<'_, [u8]>, String> {
+        let mut compression_header_reader = DwarfByteReader::new(raw_bytes, self.is_big_endian);
+        let compression_type = compression_header_reader.read_u32()?;
+        let (uncompressed_size, compressed_data_offset) = if self.is_64_bit {
+            compression_header_reader.skip(4)?;
+
+            (compression_header_reader.read_u64()?, 24)
+        } else {
+            (u64::from(compression_header_reader.read_u32()?), 12)
+        };
+
+        if compression_type != ELFCOMPRESS_ZLIB {
+            return Err(format!("Section `{}` uses unsupported compression type {}.", section.name, compression_type));
+        }
+
+        inflate_zlib(section, &raw_bytes[compressed_data_offset.min(raw_bytes.len())..], uncompressed_size).map(Cow::Owned)
+    }
+}
+
+fn inflate_zdebug_section<'a>(
+    section: &ElfDebugSection,
+    raw_bytes: &[u8],
+) -> Result<Cow<'a, [u8]>, String> {
+    if !raw_bytes.starts_with(ZDEBUG_MAGIC) || raw_bytes.len() < 12 {
+        return Err(format!("Section `{}` is missing its zlib header.", section.name));
+    }
+
+    let uncompressed_size = DwarfByteReader::new_at(raw_bytes, 4, true)?.read_u64()?;
+
+    inflate_zlib(section, &raw_bytes[12..], uncompressed_size).map(Cow::Owned)
+}
+
+fn inflate_zlib(
+    section: &ElfDebugSection,
+    compressed_bytes: &[u8],
+    uncompressed_size: u64,
+) -> Result<Vec<u8>, String> {
+    let uncompressed_size = usize::try_from(uncompressed_size).map_err(|_| format!("Section `{}` is too large to inflate.", section.name))?;
+    let inflated_bytes = miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(compressed_bytes, uncompressed_size)
+        .map_err(|error| format!("Failed to inflate section `{}`: {:?}", section.name, error.status))?;
+
+    Ok(inflated_bytes)
+}
+
+fn find_gnu_build_id(
+    note_bytes: &[u8],
+    is_big_endian: bool,
+) -> Option<Vec<u8>> {
+    let mut note_reader = DwarfByteReader::new(note_bytes, is_big_endian);
+
+    while !note_reader.is_empty() {
+        let name_size = u64::from(note_reader.read_u32().ok()?);
+        let descriptor_size = u64::from(note_reader.read_u32().ok()?);
+        let note_type = note_reader.read_u32().ok()?;
+        let note_name = note_reader.read_bytes(align_to_four(name_size)).ok()?;
+        let descriptor = note_reader.read_bytes(align_to_four(descriptor_size)).ok()?;
+
+        if note_type == NT_GNU_BUILD_ID && note_name.starts_with(b"GNU\0") {
+            return Some(descriptor[..descriptor_size as usize].to_vec());
+        }
+    }
+
+    None
+}
+
+fn align_to_four(size_in_bytes: u64) -> u64 {
+    size_in_bytes.saturating_add(3) & !3
+}
+
+fn slice_file_range(
+    file_bytes: &[u8],
+    offset: u64,
+    size_in_bytes: u64,
+) -> Option<&[u8]> {
+    let start = usize::try_from(offset).ok()?;
+    let end = start.checked_add(usize::try_from(size_in_bytes).ok()?)?;
+
+    file_bytes.get(start..end)
+}
+
+/// Builds a little-endian ELF64 file holding only the given sections, for tests that exercise on-disk debug info.
+#[cfg(test)]
+pub(crate) fn build_test_elf_file(sections: &[(&str, u32, Vec<u8>)]) -> Vec<u8> {
+    const SECTION_HEADER_SIZE: usize = 64;
+
+    let mut section_name_table = vec![0_u8];
+    let mut section_name_offsets = Vec::new();
+
+    for (section_name, _, _) in sections {
+        section_name_offsets.push(section_name_table.len() as u32);
+        section_name_table.extend_from_slice(section_name.as_bytes());
+        section_name_table.push(0);
+    }
+
+    let section_name_table_name_offset = section_name_table.len() as u32;
+
+    section_name_table.extend_from_slice(b".shstrtab\0");
+
+    let mut file_bytes = vec![0_u8; 64];
+    let mut section_ranges = Vec::new();
+
+    for (_, _, section_bytes) in sections {
+        section_ranges.push((file_bytes.len() as u64, section_bytes.len() as u64));
+        file_bytes.extend_from_slice(section_bytes);
+    }
+
+    let section_name_table_range = (file_bytes.len() as u64, section_name_table.len() as u64);
+
+    file_bytes.extend_from_slice(&section_name_table);
+
+    while !file_bytes.len().is_multiple_of(8) {
+        file_bytes.push(0);
+    }
+
+    let section_header_offset = file_bytes.len() as u64;
+    let section_header_count = sections.len() + 2;
+    let push_section_header = |file_bytes: &mut Vec<u8>, name_offset: u32, section_type: u32, range: (u64, u64)| {
+        let mut section_header = vec![0_u8; SECTION_HEADER_SIZE];
+
+        section_header[0..4].copy_from_slice(&name_offset.to_le_bytes());
+        section_header[4..8].copy_from_slice(&section_type.to_le_bytes());
+        section_header[24..32].copy_from_slice(&range.0.to_le_bytes());
+        section_header[32..40].copy_from_slice(&range.1.to_le_bytes());
+        file_bytes.extend_from_slice(&section_header);
+    };
+
+    push_section_header(&mut file_bytes, 0, 0, (0, 0));
+
+    for ((_, section_type, _), (section_name_offset, section_range)) in sections
+        .iter()
+        .zip(section_name_offsets.iter().zip(section_ranges))
+    {
+        push_section_header(&mut file_bytes, *section_name_offset, *section_type, section_range);
+    }
+
+    push_section_header(&mut file_bytes, section_name_table_name_offset, 3, section_name_table_range);
+
+    file_bytes[0..4].copy_from_slice(b"\x7FELF");
+    file_bytes[4] = ELF_CLASS64;
+    file_bytes[5] = 1;
+    file_bytes[6] = 1;
+    file_bytes[0x28..0x30].copy_from_slice(&section_header_offset.to_le_bytes());
+    file_bytes[0x3A..0x3C].copy_from_slice(&(SECTION_HEADER_SIZE as u16).to_le_bytes());
+    file_bytes[0x3C..0x3E].copy_from_slice(&(section_header_count as u16).to_le_bytes());
+    file_bytes[0x3E..0x40].copy_from_slice(&((section_header_count - 1) as u16).to_le_bytes());
+
+    file_bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ElfDebugImage, SHF_COMPRESSED, SHT_NOTE, build_test_elf_file};
+
+    #[test]
+    fn reads_build_id_and_debug_link() {
+        let mut build_id_note = Vec::new();
+
+        build_id_note.extend_from_slice(&4_u32.to_le_bytes());
+        build_id_note.extend_from_slice(&3_u32.to_le_bytes());
+        build_id_note.extend_from_slice(&3_u32.to_le_bytes());
+        build_id_note.extend_from_slice(b"GNU\0");
+        build_id_note.extend_from_slice(&[0xAB, 0xCD, 0xEF, 0x00]);
+
+        let elf_file_bytes = build_test_elf_file(&[
+            (".note.gnu.build-id", SHT_NOTE, build_id_note),
+            (".gnu_debuglink", 1, b"game.debug\0\0\x12\x34\x56\x78".to_vec()),
+        ]);
+        let elf_debug_image = ElfDebugImage::parse(elf_file_bytes).expect("Expected test ELF file to parse.");
+
+        assert_eq!(elf_debug_image.build_id(), Some(vec![0xAB, 0xCD, 0xEF]));
+        assert_eq!(elf_debug_image.debug_link(), Some(String::from("game.debug")));
+        assert!(!elf_debug_image.has_debug_info());
+    }
+
+    #[test]
+    fn inflates_compressed_debug_sections() {
+        let section_contents = b"compressed debug info".to_vec();
+        let mut compressed_section = Vec::new();
+
+        compressed_section.extend_from_slice(&1_u32.to_le_bytes());
+        compressed_section.extend_from_slice(&0_u32.to_le_bytes());
+        compressed_section.extend_from_slice(&(section_contents.len() as u64).to_le_bytes());
+        compressed_section.extend_from_slice(&1_u64.to_le_bytes());
+        compressed_section.extend_from_slice(&miniz_oxide::deflate::compress_to_vec_zlib(&section_contents, 6));
+
+        let mut elf_file_bytes = build_test_elf_file(&[(".debug_info", 1, compressed_section)]);
+        let section_header_offset = u64::from_le_bytes(elf_file_bytes[0x28..0x30].try_into().unwrap()) as usize;
+        let debug_info_flags_offset = section_header_offset + 64 + 8;
+
+        elf_file_bytes[debug_info_flags_offset..debug_info_flags_offset + 8].copy_from_slice(&SHF_COMPRESSED.to_le_bytes());
+
+        let elf_debug_image = ElfDebugImage::parse(elf_file_bytes).expect("Expected test ELF file to parse.");
+        let debug_info_bytes = elf_debug_image
+            .section_bytes(".debug_info")
+            .expect("Expected compressed section to inflate.")
+            .expect("Expected .debug_info section.");
+
+        assert!(elf_debug_image.has_debug_info());
+        assert_eq!(debug_info_bytes.as_ref(), section_contents.as_slice());
+    }
+}
@@ -0,0 +1,12 @@
+mod debug_file_locator;
+mod dwarf_byte_reader;
+mod dwarf_constants;
+mod dwarf_debug_info;
+mod dwarf_symbol_builder;
+mod dwarf_symbol_loader;
+mod elf_debug_image;
+
+pub(crate) use dwarf_symbol_builder::DwarfSymbols;
+#[cfg(test)]
+pub(crate) use dwarf_symbol_builder::{DwarfFunction, DwarfGlobalVariable};
+pub(crate) use dwarf_symbol_loader::DwarfSymbolLoader;
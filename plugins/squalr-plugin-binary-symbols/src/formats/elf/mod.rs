@@ -1,3 +1,4 @@
+mod dwarf;
 mod populate_elf_symbols_action;
 
 pub(crate) use populate_elf_symbols_action::PopulateElfSymbolsAction;
@@ -1,3 +1,4 @@
+use crate::formats::elf::dwarf::{DwarfSymbolLoader, DwarfSymbols};
 use squalr_engine_api::{
     plugins::{
         PluginPermission,
@@ -217,12 +218,19 @@ impl SymbolTreeAction for PopulateElfSymbolsAction {
         let module_name = module_name.clone();
         let module_name_for_update = module_name.clone();
         let elf_header_layout = analyze_elf_header_layout(services.process_memory(), &module_name)?;
+        let dwarf_symbols = load_dwarf_symbols(services.process_memory(), &module_name);
         let data_type_size_by_id = collect_data_type_size_by_id(services.data_type_registry());
 
         services.symbol_store().write_catalog(
             "populate ELF symbols",
             Box::new(move |project_symbol_catalog| {
-                populate_elf_symbols(project_symbol_catalog, &module_name_for_update, &elf_header_layout, &data_type_size_by_id)
+                populate_elf_symbols(
+                    project_symbol_catalog,
+                    &module_name_for_update,
+                    &elf_header_layout,
+                    dwarf_symbols.as_ref(),
+                    &data_type_size_by_id,
+                )
             }),
         )?;
         services.symbol_tree_window().request_refresh();
@@ -234,14 +242,32 @@ impl SymbolTreeAction for PopulateElfSymbolsAction {
     }
 }
 
+/// Debug info is optional. Modules without an on-disk image, or whose debug info cannot be read, still get their header symbols.
+fn load_dwarf_symbols(
+    process_memory_store: &dyn ProcessMemoryStore,
+    module_name: &str,
+) -> Option<DwarfSymbols> {
+    let module_file_path = process_memory_store
+        .resolve_module_file_path(module_name)
+        .ok()??;
+
+    DwarfSymbolLoader::default().load(&module_file_path).ok()?
+}
+
 fn populate_elf_symbols(
     project_symbol_catalog: &mut ProjectSymbolCatalog,
     module_name: &str,
     elf_header_layout: &ElfHeaderLayout,
+    dwarf_symbols: Option<&DwarfSymbols>,
     data_type_size_by_id: &BTreeMap<String, u64>,
 ) -> Result<(), String> {
     upsert_elf_struct_layout_descriptors(project_symbol_catalog, elf_header_layout)?;
-    upsert_elf_module_fields(project_symbol_catalog, module_name, elf_header_layout, data_type_size_by_id)
+
+    if let Some(dwarf_symbols) = dwarf_symbols {
+        upsert_dwarf_struct_layout_descriptors(project_symbol_catalog, dwarf_symbols);
+    }
+
+    upsert_elf_module_fields(project_symbol_catalog, module_name, elf_header_layout, dwarf_symbols, data_type_size_by_id)
 }
 
 fn upsert_elf_struct_layout_descriptors(
@@ -279,6 +305,19 @@ fn upsert_elf_struct_layout_descriptors(
     Ok(())
 }
 
+fn upsert_dwarf_struct_layout_descriptors(
+    project_symbol_catalog: &mut ProjectSymbolCatalog,
+    dwarf_symbols: &DwarfSymbols,
+) {
+    let mut struct_layout_descriptors = project_symbol_catalog.get_struct_layout_descriptors().to_vec();
+
+    for struct_layout_descriptor in &dwarf_symbols.struct_layout_descriptors {
+        upsert_struct_layout_descriptor(&mut struct_layout_descriptors, struct_layout_descriptor.clone());
+    }
+
+    project_symbol_catalog.set_struct_layout_descriptors(struct_layout_descriptors);
+}
+
 fn upsert_struct_layout_descriptor(
     struct_layout_descriptors: &mut Vec<StructLayoutDescriptor>,
     new_struct_layout_descriptor: StructLayoutDescriptor,
@@ -298,9 +337,10 @@ fn upsert_elf_module_fields(
     project_symbol_catalog: &mut ProjectSymbolCatalog,
     module_name: &str,
     elf_header_layout: &ElfHeaderLayout,
+    dwarf_symbols: Option<&DwarfSymbols>,
     data_type_size_by_id: &BTreeMap<String, u64>,
 ) -> Result<(), String> {
-    let desired_module_fields = build_desired_elf_module_fields(elf_header_layout)?;
+    let desired_module_fields = build_desired_elf_module_fields(elf_header_layout, dwarf_symbols)?;
     let minimum_size = desired_module_fields
         .iter()
         .filter_map(|desired_module_field| {
@@ -326,13 +366,74 @@ fn upsert_elf_module_fields(
     upsert_module_root_layout_fields(project_symbol_catalog, module_name, &desired_module_fields, module_size, data_type_size_by_id)
 }
 
-fn build_desired_elf_module_fields(elf_header_layout: &ElfHeaderLayout) -> Result<Vec<DesiredModuleField>, String> {
-    Ok(vec![DesiredModuleField {
+fn build_desired_elf_module_fields(
+    elf_header_layout: &ElfHeaderLayout,
+    dwarf_symbols: Option<&DwarfSymbols>,
+) -> Result<Vec<DesiredModuleField>, String> {
+    let mut desired_module_fields = vec![DesiredModuleField {
         display_name: String::from("ELF Headers"),
         offset: 0,
         struct_layout_id: elf_header_layout.root_layout_id.clone(),
         size_in_bytes: elf_header_layout.headers_size_in_bytes()?,
-    }])
+    }];
+
+    if let Some(dwarf_symbols) = dwarf_symbols {
+        desired_module_fields.extend(build_dwarf_module_fields(elf_header_layout, dwarf_symbols));
+    }
+
+    Ok(desired_module_fields)
+}
+
+/// Global variables keep their DWARF type. Functions have no value type, so they cover their code range as a byte array.
+/// Symbols that would overlap the ELF headers or an earlier symbol are dropped, since module fields cannot overlap.
+fn build_dwarf_module_fields(
+    elf_header_layout: &ElfHeaderLayout,
+    dwarf_symbols: &DwarfSymbols,
+) -> Vec<DesiredModuleField> {
+    let global_variable_fields = dwarf_symbols
+        .global_variables
+        .iter()
+        .filter_map(|global_variable| {
+            Some(DesiredModuleField {
+                display_name: global_variable.display_name.clone(),
+                offset: virtual_address_to_module_offset(elf_header_layout, global_variable.virtual_address)?,
+                struct_layout_id: global_variable.symbol_type_id.clone(),
+                size_in_bytes: global_variable.size_in_bytes,
+            })
+        });
+    let function_fields = dwarf_symbols.functions.iter().filter_map(|function| {
+        Some(DesiredModuleField {
+            display_name: function.display_name.clone(),
+            offset: virtual_address_to_module_offset(elf_header_layout, function.virtual_address)?,
+            struct_layout_id: format!("u8[{}]", function.size_in_bytes),
+            size_in_bytes: function.size_in_bytes,
+        })
+    });
+    let mut candidate_module_fields = global_variable_fields
+        .chain(function_fields)
+        .collect::<Vec<_>>();
+    let mut next_free_offset = elf_header_layout.headers_size_in_bytes().unwrap_or(0);
+    let mut dwarf_module_fields = Vec::new();
+
+    candidate_module_fields.sort_by_key(|candidate_module_field| candidate_module_field.offset);
+
+    for candidate_module_field in candidate_module_fields {
+        let Some(field_end_offset) = candidate_module_field
+            .offset
+            .checked_add(candidate_module_field.size_in_bytes)
+        else {
+            continue;
+        };
+
+        if candidate_module_field.offset < next_free_offset {
+            continue;
+        }
+
+        next_free_offset = field_end_offset;
+        dwarf_module_fields.push(candidate_module_field);
+    }
+
+    dwarf_module_fields
 }
 
 fn upsert_module_fields_in_module(
@@ -2207,6 +2308,7 @@ mod tests {
         DT_HASH, DT_NEEDED, DT_NULL, DT_SONAME, DT_STRSZ, DT_STRTAB, DT_SYMENT, DT_SYMTAB, ELF_HEADER64_ID, ELF_PROGRAM_HEADER64_ID, ElfByteOrder,
         ElfHeaderKind, PT_DYNAMIC, PT_INTERP, PT_LOAD, PopulateElfSymbolsAction, analyze_elf_header_layout, populate_elf_symbols, sanitize_identifier,
     };
+    use crate::formats::elf::dwarf::{DwarfFunction, DwarfGlobalVariable, DwarfSymbols};
     use squalr_engine_api::{
         plugins::symbol_tree::symbol_tree_action::{
             DataTypeRegistryStore, ProcessMemoryStore, ProjectSymbolStore, SymbolTreeAction, SymbolTreeActionContext, SymbolTreeActionSelection,
@@ -2487,7 +2589,7 @@ mod tests {
         let mut project_symbol_catalog = ProjectSymbolCatalog::new_with_modules_and_symbol_claims(vec![symbol_module], Vec::new(), Vec::new());
         let elf_header_layout = analyze_elf_header_layout(&TestProcessMemoryStore::new(), "squalr").expect("Expected ELF layout.");
 
-        populate_elf_symbols(&mut project_symbol_catalog, "squalr", &elf_header_layout, &default_data_type_size_by_id())
+        populate_elf_symbols(&mut project_symbol_catalog, "squalr", &elf_header_layout, None, &default_data_type_size_by_id())
             .expect("Expected ELF symbol population to replace u8[] root field.");
 
         let fields = project_symbol_catalog
@@ -2517,7 +2619,7 @@ mod tests {
         );
         let elf_header_layout = analyze_elf_header_layout(&TestProcessMemoryStore::new(), "squalr").expect("Expected ELF layout.");
 
-        populate_elf_symbols(&mut project_symbol_catalog, "squalr", &elf_header_layout, &default_data_type_size_by_id())
+        populate_elf_symbols(&mut project_symbol_catalog, "squalr", &elf_header_layout, None, &default_data_type_size_by_id())
             .expect("Expected ELF symbol population to update module root layout.");
 
         let module_root_layout_definition = project_symbol_catalog
@@ -2535,6 +2637,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn populate_elf_symbols_adds_dwarf_globals_and_functions() {
+        let mut project_symbol_catalog =
+            ProjectSymbolCatalog::new_with_modules_and_symbol_claims(vec![ProjectSymbolModule::new(String::from("squalr"), 0x2000)], Vec::new(), Vec::new());
+        let elf_header_layout = analyze_elf_header_layout(&TestProcessMemoryStore::new(), "squalr").expect("Expected ELF layout.");
+        let dwarf_symbols = DwarfSymbols {
+            struct_layout_descriptors: vec![StructLayoutDescriptor::new(
+                String::from("Game.Player"),
+                SymbolicStructDefinition::new(String::from("Game.Player"), Vec::new()).with_declared_size_in_bytes(Some(0x18)),
+            )],
+            global_variables: vec![DwarfGlobalVariable {
+                display_name: String::from("Game::g_player"),
+                virtual_address: 0x4000,
+                symbol_type_id: String::from("Game.Player"),
+                size_in_bytes: 0x18,
+            }],
+            functions: vec![
+                DwarfFunction {
+                    display_name: String::from("Game::tick"),
+                    virtual_address: 0x1000,
+                    size_in_bytes: 0x40,
+                },
+                DwarfFunction {
+                    display_name: String::from("Game::tick_inlined"),
+                    virtual_address: 0x1010,
+                    size_in_bytes: 0x10,
+                },
+            ],
+        };
+
+        populate_elf_symbols(
+            &mut project_symbol_catalog,
+            "squalr",
+            &elf_header_layout,
+            Some(&dwarf_symbols),
+            &default_data_type_size_by_id(),
+        )
+        .expect("Expected ELF symbol population to add DWARF symbols.");
+
+        let symbol_module = project_symbol_catalog
+            .find_symbol_module("squalr")
+            .expect("Expected module to exist.");
+        let module_fields = symbol_module
+            .get_fields()
+            .iter()
+            .map(|module_field| {
+                (
+                    module_field.get_display_name().to_string(),
+                    module_field.get_offset(),
+                    module_field.get_struct_layout_id().to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert!(project_symbol_catalog.contains_struct_layout_id("Game.Player"));
+        assert_eq!(
+            module_fields,
+            vec![
+                (String::from("ELF Headers"), 0, String::from("linux.elf.squalr.headers")),
+                (String::from("Game::tick"), 0x1000, String::from("u8[64]")),
+                (String::from("Game::g_player"), 0x4000, String::from("Game.Player")),
+            ]
+        );
+        assert!(symbol_module.get_size() >= 0x4018);
+    }
+
     #[test]
     fn section_headers_are_skipped_when_not_readable() {
         let mut process_memory_store = TestProcessMemoryStore::new();
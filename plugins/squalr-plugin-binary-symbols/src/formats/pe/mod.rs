@@ -1,3 +1,4 @@
+mod pdb;
 mod populate_pe_symbols_action;
 
 pub(crate) use populate_pe_symbols_action::PopulatePeSymbolsAction;
@@ -0,0 +1,77 @@
+use crate::formats::pe::pdb::pdb_byte_reader::PdbByteReader;
+
+const RSDS_SIGNATURE: &[u8; 4] = b"RSDS";
+
+/// The `RSDS` CodeView record from a PE debug directory. It names the PDB written at link time and the GUID and age that
+/// a PDB must carry to describe this exact build.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CodeViewPdbReference {
+    pub(crate) guid: [u8; 16],
+    pub(crate) age: u32,
+    pub(crate) pdb_path: String,
+}
+
+impl CodeViewPdbReference {
+    pub(crate) fn parse(code_view_bytes: &[u8]) -> Result<Self, String> {
+        let mut code_view_reader = PdbByteReader::new(code_view_bytes);
+
+        if code_view_reader.read_bytes(4)? != RSDS_SIGNATURE {
+            return Err(String::from("CodeView debug record is not an RSDS record."));
+        }
+
+        let mut guid = [0_u8; 16];
+
+        guid.copy_from_slice(code_view_reader.read_bytes(16)?);
+
+        let age = code_view_reader.read_u32()?;
+        let pdb_path = code_view_reader.read_c_string()?;
+
+        Ok(Self { guid, age, pdb_path })
+    }
+
+    /// The recorded path is usually a Windows path from the build machine, so both separators are accepted.
+    pub(crate) fn pdb_file_name(&self) -> &str {
+        self.pdb_path
+            .rsplit(['\\', '/'])
+            .next()
+            .unwrap_or(&self.pdb_path)
+    }
+
+    /// Symbol stores index PDBs by their GUID, in its registry text order without separators, followed by the age in hex.
+    pub(crate) fn symbol_store_key(&self) -> String {
+        let guid = &self.guid;
+
+        format!(
+            "{:08X}{:04X}{:04X}{}{:X}",
+            u32::from_le_bytes([guid[0], guid[1], guid[2], guid[3]]),
+            u16::from_le_bytes([guid[4], guid[5]]),
+            u16::from_le_bytes([guid[6], guid[7]]),
+            guid[8..]
+                .iter()
+                .map(|guid_byte| format!("{guid_byte:02X}"))
+                .collect::<String>(),
+            self.age
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodeViewPdbReference;
+    use crate::formats::pe::pdb::pdb_file::{TEST_PDB_AGE, TEST_PDB_GUID};
+
+    #[test]
+    fn parse_reads_rsds_record_and_formats_symbol_store_key() {
+        let mut code_view_bytes = b"RSDS".to_vec();
+
+        code_view_bytes.extend_from_slice(&TEST_PDB_GUID);
+        code_view_bytes.extend_from_slice(&TEST_PDB_AGE.to_le_bytes());
+        code_view_bytes.extend_from_slice(b"C:\\build\\Release\\game.pdb\0");
+
+        let code_view_pdb_reference = CodeViewPdbReference::parse(&code_view_bytes).expect("Expected RSDS record to parse.");
+
+        assert_eq!(code_view_pdb_reference.pdb_file_name(), "game.pdb");
+        assert_eq!(code_view_pdb_reference.symbol_store_key(), "123456789ABCDEF00123456789ABCDEF2");
+        assert!(CodeViewPdbReference::parse(b"NB10\0\0\0\0").is_err());
+    }
+}
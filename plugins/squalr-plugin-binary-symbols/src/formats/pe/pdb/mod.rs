@@ -0,0 +1,14 @@
+mod code_view_pdb_reference;
+mod msf_file;
+mod pdb_byte_reader;
+mod pdb_constants;
+mod pdb_file;
+mod pdb_file_locator;
+mod pdb_symbol_builder;
+mod pdb_symbol_loader;
+
+pub(crate) use code_view_pdb_reference::CodeViewPdbReference;
+pub(crate) use pdb_symbol_builder::PdbSymbols;
+#[cfg(test)]
+pub(crate) use pdb_symbol_builder::{PdbFunction, PdbGlobalVariable};
+pub(crate) use pdb_symbol_loader::PdbSymbolLoader;
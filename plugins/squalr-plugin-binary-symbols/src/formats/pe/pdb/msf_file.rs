@@ -0,0 +1,206 @@
+use crate::formats::pe::pdb::pdb_byte_reader::PdbByteReader;
+
+const MSF_MAGIC: &[u8; 32] = b"Microsoft C/C++ MSF 7.00\r\n\x1ADS\0\0\0";
+const NIL_STREAM_SIZE: u32 = u32::MAX;
+
+/// Multi-stream file container that every PDB 7.0 file is stored in. Streams are scattered across fixed-size blocks and
+/// reassembled through the stream directory.
+#[derive(Clone, Debug)]
+pub(crate) struct MsfFile {
+    file_bytes: Vec<u8>,
+    block_size: usize,
+    stream_blocks: Vec<Option<(u32, Vec<u32>)>>,
+}
+
+impl MsfFile {
+    pub(crate) fn parse(file_bytes: Vec<u8>) -> Result<Self, String> {
+        if !file_bytes.starts_with(MSF_MAGIC) {
+            return Err(String::from("File is not an MSF 7.0 PDB."));
+        }
+
+        let mut super_block_reader = PdbByteReader::new_at(&file_bytes, MSF_MAGIC.len())?;
+        let block_size = super_block_reader.read_u32()? as usize;
+        let _free_block_map_block = super_block_reader.read_u32()?;
+        let _block_count = super_block_reader.read_u32()?;
+        let directory_size = super_block_reader.read_u32()? as usize;
+        let _unknown = super_block_reader.read_u32()?;
+        let block_map_block = super_block_reader.read_u32()? as usize;
+
+        if !matches!(block_size, 512 | 1024 | 2048 | 4096 | 8192 | 16384 | 32768) {
+            return Err(format!("Unsupported MSF block size {block_size}."));
+        }
+
+        let directory_block_count = directory_size.div_ceil(block_size);
+        let block_map_bytes = read_block(&file_bytes, block_size, block_map_block)?;
+        let mut block_map_reader = PdbByteReader::new(block_map_bytes);
+        let directory_blocks = (0..directory_block_count)
+            .map(|_| block_map_reader.read_u32())
+            .collect::<Result<Vec<_>, _>>()?;
+        let directory_bytes = gather_blocks(&file_bytes, block_size, &directory_blocks, directory_size)?;
+        let mut directory_reader = PdbByteReader::new(&directory_bytes);
+        let stream_count = directory_reader.read_u32()? as usize;
+
+        if stream_count > directory_reader.remaining_len() / 4 {
+            return Err(String::from("MSF stream directory declares more streams than it can hold."));
+        }
+
+        let stream_sizes = (0..stream_count)
+            .map(|_| directory_reader.read_u32())
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut stream_blocks = Vec::with_capacity(stream_count);
+
+        for stream_size in stream_sizes {
+            if stream_size == NIL_STREAM_SIZE {
+                stream_blocks.push(None);
+                continue;
+            }
+
+            let block_indices = (0..(stream_size as usize).div_ceil(block_size))
+                .map(|_| directory_reader.read_u32())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            stream_blocks.push(Some((stream_size, block_indices)));
+        }
+
+        Ok(Self {
+            file_bytes,
+            block_size,
+            stream_blocks,
+        })
+    }
+
+    /// Returns `None` for nil streams and for stream indices that the directory does not contain.
+    pub(crate) fn read_stream(
+        &self,
+        stream_index: usize,
+    ) -> Result<Option<Vec<u8>>, String> {
+        let Some(Some((stream_size, block_indices))) = self.stream_blocks.get(stream_index) else {
+            return Ok(None);
+        };
+
+        gather_blocks(&self.file_bytes, self.block_size, block_indices, *stream_size as usize).map(Some)
+    }
+}
+
+fn read_block(
+    file_bytes: &[u8],
+    block_size: usize,
+    block_index: usize,
+) -> Result<&[u8], String> {
+    let block_start = block_index
+        .checked_mul(block_size)
+        .ok_or_else(|| format!("MSF block {block_index} is out of range."))?;
+
+    file_bytes
+        .get(block_start..block_start.saturating_add(block_size).min(file_bytes.len()))
+        .filter(|block_bytes| !block_bytes.is_empty())
+        .ok_or_else(|| format!("MSF block {block_index} is outside of the file."))
+}
+
+fn gather_blocks(
+    file_bytes: &[u8],
+    block_size: usize,
+    block_indices: &[u32],
+    byte_count: usize,
+) -> Result<Vec<u8>, String> {
+    let mut stream_bytes = Vec::with_capacity(byte_count);
+
+    for block_index in block_indices {
+        let block_bytes = read_block(file_bytes, block_size, *block_index as usize)?;
+        let copy_length = block_bytes.len().min(byte_count - stream_bytes.len());
+
+        stream_bytes.extend_from_slice(&block_bytes[..copy_length]);
+    }
+
+    if stream_bytes.len() < byte_count {
+        return Err(String::from("MSF stream is truncated."));
+    }
+
+    Ok(stream_bytes)
+}
+
+/// Lays streams out back to back in 512 byte blocks, with the directory and its block map at the end.
+#[cfg(test)]
+pub(crate) fn build_test_msf_file(streams: &[Option<Vec<u8>>]) -> Vec<u8> {
+    const TEST_BLOCK_SIZE: usize = 512;
+
+    fn append_blocks(
+        file_bytes: &mut Vec<u8>,
+        bytes: &[u8],
+    ) -> Vec<u32> {
+        bytes
+            .chunks(TEST_BLOCK_SIZE)
+            .map(|chunk| {
+                let block_index = (file_bytes.len() / TEST_BLOCK_SIZE) as u32;
+
+                file_bytes.extend_from_slice(chunk);
+                file_bytes.resize(file_bytes.len().next_multiple_of(TEST_BLOCK_SIZE), 0);
+
+                block_index
+            })
+            .collect()
+    }
+
+    // Block 0 is the super block; blocks 1 and 2 are free block maps in real files.
+    let mut file_bytes = vec![0_u8; TEST_BLOCK_SIZE * 3];
+    let mut directory_bytes = Vec::new();
+    let mut stream_block_indices = Vec::new();
+
+    directory_bytes.extend_from_slice(&(streams.len() as u32).to_le_bytes());
+
+    for stream in streams {
+        let stream_size = stream
+            .as_ref()
+            .map_or(NIL_STREAM_SIZE, |stream| stream.len() as u32);
+
+        directory_bytes.extend_from_slice(&stream_size.to_le_bytes());
+        stream_block_indices.extend(
+            stream
+                .as_ref()
+                .map(|stream| append_blocks(&mut file_bytes, stream))
+                .unwrap_or_default(),
+        );
+    }
+
+    for block_index in stream_block_indices {
+        directory_bytes.extend_from_slice(&block_index.to_le_bytes());
+    }
+
+    let directory_blocks = append_blocks(&mut file_bytes, &directory_bytes);
+    let block_map_bytes = directory_blocks
+        .iter()
+        .flat_map(|block_index| block_index.to_le_bytes())
+        .collect::<Vec<_>>();
+    let block_map_block = append_blocks(&mut file_bytes, &block_map_bytes)[0];
+    let block_count = (file_bytes.len() / TEST_BLOCK_SIZE) as u32;
+
+    file_bytes[..MSF_MAGIC.len()].copy_from_slice(MSF_MAGIC);
+    file_bytes[32..36].copy_from_slice(&(TEST_BLOCK_SIZE as u32).to_le_bytes());
+    file_bytes[36..40].copy_from_slice(&1_u32.to_le_bytes());
+    file_bytes[40..44].copy_from_slice(&block_count.to_le_bytes());
+    file_bytes[44..48].copy_from_slice(&(directory_bytes.len() as u32).to_le_bytes());
+    file_bytes[52..56].copy_from_slice(&block_map_block.to_le_bytes());
+
+    file_bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MsfFile, build_test_msf_file};
+
+    #[test]
+    fn read_stream_reassembles_streams_spanning_several_blocks() {
+        let large_stream = (0..1500_u32).map(|value| value as u8).collect::<Vec<_>>();
+        let msf_file = MsfFile::parse(build_test_msf_file(&[Some(vec![1, 2, 3]), None, Some(large_stream.clone())])).expect("Expected MSF file to parse.");
+
+        assert_eq!(msf_file.read_stream(0), Ok(Some(vec![1, 2, 3])));
+        assert_eq!(msf_file.read_stream(1), Ok(None));
+        assert_eq!(msf_file.read_stream(2), Ok(Some(large_stream)));
+        assert_eq!(msf_file.read_stream(3), Ok(None));
+    }
+
+    #[test]
+    fn parse_rejects_files_without_the_msf_magic() {
+        assert!(MsfFile::parse(b"Microsoft C/C++ program database 2.00\r\n".to_vec()).is_err());
+    }
+}
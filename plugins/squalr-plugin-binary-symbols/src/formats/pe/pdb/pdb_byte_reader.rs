@@ -0,0 +1,154 @@
+const LF_NUMERIC: u16 = 0x8000;
+const LF_CHAR: u16 = 0x8000;
+const LF_SHORT: u16 = 0x8001;
+const LF_USHORT: u16 = 0x8002;
+const LF_LONG: u16 = 0x8003;
+const LF_ULONG: u16 = 0x8004;
+const LF_QUADWORD: u16 = 0x8009;
+const LF_UQUADWORD: u16 = 0x800A;
+
+/// Bounds-checked little-endian cursor over a PDB stream. Every read fails with a descriptive error instead of panicking on
+/// truncated input.
+#[derive(Clone, Debug)]
+pub(crate) struct PdbByteReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> PdbByteReader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    pub(crate) fn new_at(
+        bytes: &'a [u8],
+        position: usize,
+    ) -> Result<Self, String> {
+        if position > bytes.len() {
+            return Err(format!("PDB offset 0x{:X} is outside of a 0x{:X} byte stream.", position, bytes.len()));
+        }
+
+        Ok(Self { bytes, position })
+    }
+
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    pub(crate) fn remaining_len(&self) -> usize {
+        self.bytes.len().saturating_sub(self.position)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    pub(crate) fn peek_u8(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    pub(crate) fn read_bytes(
+        &mut self,
+        length: usize,
+    ) -> Result<&'a [u8], String> {
+        let end_position = self
+            .position
+            .checked_add(length)
+            .filter(|end_position| *end_position <= self.bytes.len())
+            .ok_or_else(|| format!("PDB data is truncated at offset 0x{:X}.", self.position))?;
+        let bytes = &self.bytes[self.position..end_position];
+
+        self.position = end_position;
+
+        Ok(bytes)
+    }
+
+    pub(crate) fn skip(
+        &mut self,
+        length: usize,
+    ) -> Result<(), String> {
+        self.read_bytes(length).map(|_| ())
+    }
+
+    pub(crate) fn read_u8(&mut self) -> Result<u8, String> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub(crate) fn read_u16(&mut self) -> Result<u16, String> {
+        let bytes = self.read_bytes(2)?;
+
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub(crate) fn read_u32(&mut self) -> Result<u32, String> {
+        let bytes = self.read_bytes(4)?;
+
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub(crate) fn read_u64(&mut self) -> Result<u64, String> {
+        let bytes = self.read_bytes(8)?;
+        let mut value_bytes = [0_u8; 8];
+
+        value_bytes.copy_from_slice(bytes);
+
+        Ok(u64::from_le_bytes(value_bytes))
+    }
+
+    /// Reads a null-terminated string. Names are not guaranteed to be UTF-8, so invalid bytes are replaced.
+    pub(crate) fn read_c_string(&mut self) -> Result<String, String> {
+        let remaining_bytes = &self.bytes[self.position.min(self.bytes.len())..];
+        let string_length = remaining_bytes
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or_else(|| format!("PDB string at offset 0x{:X} is not null-terminated.", self.position))?;
+        let string_bytes = self.read_bytes(string_length)?;
+
+        self.skip(1)?;
+
+        Ok(String::from_utf8_lossy(string_bytes).into_owned())
+    }
+
+    /// CodeView numeric leaves store small values inline and tag larger ones with a leaf kind that selects their width.
+    pub(crate) fn read_numeric(&mut self) -> Result<u64, String> {
+        let leaf_value = self.read_u16()?;
+
+        if leaf_value < LF_NUMERIC {
+            return Ok(leaf_value as u64);
+        }
+
+        match leaf_value {
+            LF_CHAR => Ok(self.read_u8()? as i8 as i64 as u64),
+            LF_SHORT => Ok(self.read_u16()? as i16 as i64 as u64),
+            LF_USHORT => Ok(self.read_u16()? as u64),
+            LF_LONG => Ok(self.read_u32()? as i32 as i64 as u64),
+            LF_ULONG => Ok(self.read_u32()? as u64),
+            LF_QUADWORD | LF_UQUADWORD => self.read_u64(),
+            _ => Err(format!("Unsupported CodeView numeric leaf 0x{leaf_value:X}.")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PdbByteReader;
+
+    #[test]
+    fn read_numeric_handles_inline_and_tagged_values() {
+        let bytes = [0x34, 0x12, 0x04, 0x80, 0x78, 0x56, 0x34, 0x12, 0x00, 0x80, 0xFF];
+        let mut pdb_byte_reader = PdbByteReader::new(&bytes);
+
+        assert_eq!(pdb_byte_reader.read_numeric(), Ok(0x1234));
+        assert_eq!(pdb_byte_reader.read_numeric(), Ok(0x1234_5678));
+        assert_eq!(pdb_byte_reader.read_numeric(), Ok(u64::MAX));
+        assert!(pdb_byte_reader.is_empty());
+    }
+
+    #[test]
+    fn reads_fail_instead_of_panicking_on_truncated_input() {
+        let mut pdb_byte_reader = PdbByteReader::new(b"name");
+
+        assert!(pdb_byte_reader.read_c_string().is_err());
+        assert!(pdb_byte_reader.read_u64().is_err());
+    }
+}
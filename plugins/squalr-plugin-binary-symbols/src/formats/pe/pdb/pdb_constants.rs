@@ -0,0 +1,45 @@
+pub(crate) const LF_MODIFIER: u16 = 0x1001;
+pub(crate) const LF_POINTER: u16 = 0x1002;
+pub(crate) const LF_FIELDLIST: u16 = 0x1203;
+pub(crate) const LF_BITFIELD: u16 = 0x1205;
+pub(crate) const LF_BCLASS: u16 = 0x1400;
+pub(crate) const LF_VBCLASS: u16 = 0x1401;
+pub(crate) const LF_IVBCLASS: u16 = 0x1402;
+pub(crate) const LF_INDEX: u16 = 0x1404;
+pub(crate) const LF_VFUNCTAB: u16 = 0x1409;
+pub(crate) const LF_ENUMERATE: u16 = 0x1502;
+pub(crate) const LF_ARRAY: u16 = 0x1503;
+pub(crate) const LF_CLASS: u16 = 0x1504;
+pub(crate) const LF_STRUCTURE: u16 = 0x1505;
+pub(crate) const LF_UNION: u16 = 0x1506;
+pub(crate) const LF_ENUM: u16 = 0x1507;
+pub(crate) const LF_MEMBER: u16 = 0x150D;
+pub(crate) const LF_STMEMBER: u16 = 0x150E;
+pub(crate) const LF_METHOD: u16 = 0x150F;
+pub(crate) const LF_NESTTYPE: u16 = 0x1510;
+pub(crate) const LF_ONEMETHOD: u16 = 0x1511;
+pub(crate) const LF_INTERFACE: u16 = 0x1519;
+pub(crate) const LF_PAD0: u8 = 0xF0;
+
+pub(crate) const PROPERTY_FORWARD_REFERENCE: u16 = 0x0080;
+
+pub(crate) const POINTER_MODE_MEMBER_DATA: u32 = 2;
+pub(crate) const POINTER_MODE_MEMBER_FUNCTION: u32 = 3;
+
+pub(crate) const SIMPLE_MODE_DIRECT: u32 = 0;
+pub(crate) const SIMPLE_MODE_NEAR_POINTER32: u32 = 4;
+pub(crate) const SIMPLE_MODE_NEAR_POINTER64: u32 = 6;
+
+pub(crate) const METHOD_PROPERTY_INTRODUCING_VIRTUAL: u16 = 4;
+pub(crate) const METHOD_PROPERTY_PURE_INTRODUCING_VIRTUAL: u16 = 6;
+
+pub(crate) const S_LDATA32: u16 = 0x110C;
+pub(crate) const S_GDATA32: u16 = 0x110D;
+pub(crate) const S_PUB32: u16 = 0x110E;
+pub(crate) const S_LPROC32: u16 = 0x110F;
+pub(crate) const S_GPROC32: u16 = 0x1110;
+pub(crate) const S_LPROC32_ID: u16 = 0x1146;
+pub(crate) const S_GPROC32_ID: u16 = 0x1147;
+
+pub(crate) const PUBLIC_SYMBOL_FLAG_CODE: u32 = 0x0002;
+pub(crate) const PUBLIC_SYMBOL_FLAG_FUNCTION: u32 = 0x0004;
@@ -0,0 +1,596 @@
+use crate::formats::pe::pdb::msf_file::MsfFile;
+use crate::formats::pe::pdb::pdb_byte_reader::PdbByteReader;
+
+const PDB_INFO_STREAM_INDEX: usize = 1;
+const TPI_STREAM_INDEX: usize = 2;
+const DBI_STREAM_INDEX: usize = 3;
+const DBI_HEADER_SIZE: usize = 64;
+const DBI_MODULE_INFO_FIXED_SIZE: usize = 64;
+const DBI_DEBUG_HEADER_SECTION_HEADERS_INDEX: usize = 5;
+const IMAGE_SECTION_HEADER_SIZE: usize = 40;
+const NIL_STREAM_INDEX: u16 = u16::MAX;
+const IMAGE_FILE_MACHINE_AMD64: u16 = 0x8664;
+const IMAGE_FILE_MACHINE_ARM64: u16 = 0xAA64;
+const IMAGE_FILE_MACHINE_IA64: u16 = 0x0200;
+
+/// A raw type record: its leaf kind followed by the record data that trails it.
+type PdbTypeRecord = (u16, Vec<u8>);
+
+/// A section of the image the PDB was linked against. Symbols address memory as a one-based section index plus an offset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct PdbSection {
+    pub(crate) virtual_address: u32,
+    pub(crate) virtual_size: u32,
+}
+
+/// The raw streams of a PDB that symbol import needs: identity, type records, symbol records, and section layout.
+#[derive(Clone, Debug)]
+pub(crate) struct PdbFile {
+    guid: [u8; 16],
+    age: u32,
+    dbi_age: u32,
+    is_64_bit: bool,
+    type_index_begin: u32,
+    type_records: Vec<PdbTypeRecord>,
+    symbol_streams: Vec<Vec<u8>>,
+    sections: Vec<PdbSection>,
+}
+
+impl PdbFile {
+    pub(crate) fn parse(file_bytes: Vec<u8>) -> Result<Self, String> {
+        let msf_file = MsfFile::parse(file_bytes)?;
+        let info_stream = require_stream(&msf_file, PDB_INFO_STREAM_INDEX, "PDB info")?;
+        let mut info_reader = PdbByteReader::new(&info_stream);
+        let _version = info_reader.read_u32()?;
+        let _signature = info_reader.read_u32()?;
+        let age = info_reader.read_u32()?;
+        let mut guid = [0_u8; 16];
+
+        guid.copy_from_slice(info_reader.read_bytes(16)?);
+
+        let (type_index_begin, type_records) = match msf_file.read_stream(TPI_STREAM_INDEX)? {
+            Some(tpi_stream) if !tpi_stream.is_empty() => read_type_records(&tpi_stream)?,
+            _ => (0, Vec::new()),
+        };
+        let dbi_stream = require_stream(&msf_file, DBI_STREAM_INDEX, "DBI")?;
+        let dbi_header = DbiHeader::parse(&dbi_stream)?;
+        let mut symbol_streams = Vec::new();
+
+        if let Some(global_symbol_stream) = read_optional_stream(&msf_file, dbi_header.symbol_record_stream_index)? {
+            symbol_streams.push(global_symbol_stream);
+        }
+
+        for (module_stream_index, symbol_byte_count) in dbi_header.read_module_symbol_streams(&dbi_stream)? {
+            // A damaged module stream only loses that module's procedures and statics.
+            let Ok(Some(module_stream)) = msf_file.read_stream(module_stream_index as usize) else {
+                continue;
+            };
+            let symbol_end = (symbol_byte_count as usize).min(module_stream.len());
+
+            // Module symbol streams begin with a four byte CodeView signature.
+            if symbol_end > 4 {
+                symbol_streams.push(module_stream[4..symbol_end].to_vec());
+            }
+        }
+
+        let sections = match read_optional_stream(&msf_file, dbi_header.read_section_header_stream_index(&dbi_stream)?)? {
+            Some(section_header_stream) => read_sections(&section_header_stream),
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            guid,
+            age,
+            dbi_age: dbi_header.age,
+            is_64_bit: matches!(
+                dbi_header.machine,
+                IMAGE_FILE_MACHINE_AMD64 | IMAGE_FILE_MACHINE_ARM64 | IMAGE_FILE_MACHINE_IA64
+            ),
+            type_index_begin,
+            type_records,
+            symbol_streams,
+            sections,
+        })
+    }
+
+    /// The linker may bump either age when it updates a PDB incrementally, so a match on either is accepted.
+    pub(crate) fn matches_identity(
+        &self,
+        guid: &[u8; 16],
+        age: u32,
+    ) -> bool {
+        &self.guid == guid && (self.age == age || self.dbi_age == age)
+    }
+
+    pub(crate) fn is_64_bit(&self) -> bool {
+        self.is_64_bit
+    }
+
+    pub(crate) fn get_type_index_begin(&self) -> u32 {
+        self.type_index_begin
+    }
+
+    pub(crate) fn get_type_index_end(&self) -> u32 {
+        self.type_index_begin
+            .saturating_add(self.type_records.len() as u32)
+    }
+
+    /// Returns the leaf kind and the bytes that follow it for a type index from the TPI stream.
+    pub(crate) fn get_type_record(
+        &self,
+        type_index: u32,
+    ) -> Option<(u16, &[u8])> {
+        let record_index = type_index.checked_sub(self.type_index_begin)? as usize;
+
+        self.type_records
+            .get(record_index)
+            .map(|(leaf_kind, record_bytes)| (*leaf_kind, record_bytes.as_slice()))
+    }
+
+    /// Returns the record kind and data of every symbol in the global symbol stream and in each module stream.
+    pub(crate) fn get_symbol_records(&self) -> Vec<(u16, &[u8])> {
+        let mut symbol_records = Vec::new();
+
+        for symbol_stream in &self.symbol_streams {
+            let mut symbol_reader = PdbByteReader::new(symbol_stream);
+
+            while symbol_reader.remaining_len() >= 4 {
+                let Ok(record_length) = symbol_reader.read_u16() else {
+                    break;
+                };
+                let Some(data_length) = (record_length as usize).checked_sub(2) else {
+                    break;
+                };
+                let Ok(record_kind) = symbol_reader.read_u16() else {
+                    break;
+                };
+                let Ok(record_bytes) = symbol_reader.read_bytes(data_length) else {
+                    break;
+                };
+
+                symbol_records.push((record_kind, record_bytes));
+            }
+        }
+
+        symbol_records
+    }
+
+    pub(crate) fn get_sections(&self) -> &[PdbSection] {
+        &self.sections
+    }
+
+    pub(crate) fn to_relative_virtual_address(
+        &self,
+        segment: u16,
+        offset: u32,
+    ) -> Option<u64> {
+        let section = self.sections.get((segment as usize).checked_sub(1)?)?;
+
+        Some(section.virtual_address as u64 + offset as u64)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DbiHeader {
+    age: u32,
+    symbol_record_stream_index: u16,
+    module_info_size: usize,
+    substreams_before_debug_header_size: usize,
+    debug_header_size: usize,
+    machine: u16,
+}
+
+impl DbiHeader {
+    fn parse(dbi_stream: &[u8]) -> Result<Self, String> {
+        let mut dbi_reader = PdbByteReader::new(dbi_stream);
+        let _version_signature = dbi_reader.read_u32()?;
+        let _version_header = dbi_reader.read_u32()?;
+        let age = dbi_reader.read_u32()?;
+        let _global_stream_index = dbi_reader.read_u16()?;
+        let _build_number = dbi_reader.read_u16()?;
+        let _public_stream_index = dbi_reader.read_u16()?;
+        let _pdb_dll_version = dbi_reader.read_u16()?;
+        let symbol_record_stream_index = dbi_reader.read_u16()?;
+        let _pdb_dll_rebuild = dbi_reader.read_u16()?;
+        let module_info_size = dbi_reader.read_u32()? as usize;
+        let section_contribution_size = dbi_reader.read_u32()? as usize;
+        let section_map_size = dbi_reader.read_u32()? as usize;
+        let source_info_size = dbi_reader.read_u32()? as usize;
+        let type_server_map_size = dbi_reader.read_u32()? as usize;
+        let _mfc_type_server_index = dbi_reader.read_u32()?;
+        let debug_header_size = dbi_reader.read_u32()? as usize;
+        let ec_substream_size = dbi_reader.read_u32()? as usize;
+        let _flags = dbi_reader.read_u16()?;
+        let machine = dbi_reader.read_u16()?;
+        let substreams_before_debug_header_size = [
+            module_info_size,
+            section_contribution_size,
+            section_map_size,
+            source_info_size,
+            type_server_map_size,
+            ec_substream_size,
+        ]
+        .into_iter()
+        .try_fold(0_usize, |total_size, substream_size| total_size.checked_add(substream_size))
+        .ok_or_else(|| String::from("DBI substream sizes overflow."))?;
+
+        Ok(Self {
+            age,
+            symbol_record_stream_index,
+            module_info_size,
+            substreams_before_debug_header_size,
+            debug_header_size,
+            machine,
+        })
+    }
+
+    /// Returns each module's symbol stream index and the byte count of its symbol records, including the signature.
+    fn read_module_symbol_streams(
+        &self,
+        dbi_stream: &[u8],
+    ) -> Result<Vec<(u16, u32)>, String> {
+        let module_info_end = DBI_HEADER_SIZE
+            .checked_add(self.module_info_size)
+            .filter(|module_info_end| *module_info_end <= dbi_stream.len())
+            .ok_or_else(|| String::from("DBI module info substream is truncated."))?;
+        let mut module_info_reader = PdbByteReader::new(&dbi_stream[DBI_HEADER_SIZE..module_info_end]);
+        let mut module_symbol_streams = Vec::new();
+
+        while module_info_reader.remaining_len() >= DBI_MODULE_INFO_FIXED_SIZE {
+            module_info_reader.skip(4 + 28 + 2)?;
+
+            let module_stream_index = module_info_reader.read_u16()?;
+            let symbol_byte_count = module_info_reader.read_u32()?;
+
+            module_info_reader.skip(DBI_MODULE_INFO_FIXED_SIZE - 40)?;
+            let _module_name = module_info_reader.read_c_string()?;
+            let _object_file_name = module_info_reader.read_c_string()?;
+            module_info_reader.skip(module_info_reader.position().next_multiple_of(4) - module_info_reader.position())?;
+
+            if module_stream_index != NIL_STREAM_INDEX {
+                module_symbol_streams.push((module_stream_index, symbol_byte_count));
+            }
+        }
+
+        Ok(module_symbol_streams)
+    }
+
+    fn read_section_header_stream_index(
+        &self,
+        dbi_stream: &[u8],
+    ) -> Result<u16, String> {
+        let entry_offset = DBI_DEBUG_HEADER_SECTION_HEADERS_INDEX * 2;
+
+        if self.debug_header_size < entry_offset + 2 {
+            return Ok(NIL_STREAM_INDEX);
+        }
+
+        let mut debug_header_reader = PdbByteReader::new_at(dbi_stream, DBI_HEADER_SIZE + self.substreams_before_debug_header_size + entry_offset)?;
+
+        debug_header_reader.read_u16()
+    }
+}
+
+fn require_stream(
+    msf_file: &MsfFile,
+    stream_index: usize,
+    stream_name: &str,
+) -> Result<Vec<u8>, String> {
+    msf_file
+        .read_stream(stream_index)?
+        .ok_or_else(|| format!("PDB is missing its {stream_name} stream."))
+}
+
+fn read_optional_stream(
+    msf_file: &MsfFile,
+    stream_index: u16,
+) -> Result<Option<Vec<u8>>, String> {
+    if stream_index == NIL_STREAM_INDEX {
+        return Ok(None);
+    }
+
+    msf_file.read_stream(stream_index as usize)
+}
+
+fn read_type_records(tpi_stream: &[u8]) -> Result<(u32, Vec<PdbTypeRecord>), String> {
+    let mut header_reader = PdbByteReader::new(tpi_stream);
+    let _version = header_reader.read_u32()?;
+    let header_size = header_reader.read_u32()? as usize;
+    let type_index_begin = header_reader.read_u32()?;
+    let _type_index_end = header_reader.read_u32()?;
+    let type_record_bytes = header_reader.read_u32()? as usize;
+    let mut record_reader = PdbByteReader::new_at(tpi_stream, header_size)?;
+    let records_end = header_size
+        .saturating_add(type_record_bytes)
+        .min(tpi_stream.len());
+    let mut type_records = Vec::new();
+
+    while record_reader.position() + 4 <= records_end {
+        let record_length = record_reader.read_u16()? as usize;
+        let data_length = record_length
+            .checked_sub(2)
+            .ok_or_else(|| format!("TPI record at offset 0x{:X} is too short.", record_reader.position()))?;
+        let leaf_kind = record_reader.read_u16()?;
+
+        type_records.push((leaf_kind, record_reader.read_bytes(data_length)?.to_vec()));
+    }
+
+    Ok((type_index_begin, type_records))
+}
+
+fn read_sections(section_header_stream: &[u8]) -> Vec<PdbSection> {
+    section_header_stream
+        .chunks_exact(IMAGE_SECTION_HEADER_SIZE)
+        .map(|section_header| PdbSection {
+            virtual_size: u32::from_le_bytes([
+                section_header[8],
+                section_header[9],
+                section_header[10],
+                section_header[11],
+            ]),
+            virtual_address: u32::from_le_bytes([
+                section_header[12],
+                section_header[13],
+                section_header[14],
+                section_header[15],
+            ]),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) const TEST_PDB_GUID: [u8; 16] = [
+    0x78, 0x56, 0x34, 0x12, 0xBC, 0x9A, 0xF0, 0xDE, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+];
+
+#[cfg(test)]
+pub(crate) const TEST_PDB_AGE: u32 = 2;
+
+/// Builds a 64-bit PDB with a `Game::Player` struct, a typed global, a procedure, and two public symbols, one of which is
+/// covered by the procedure. `.text` is mapped at RVA 0x1000 and `.data` at RVA 0x3000.
+#[cfg(test)]
+pub(crate) fn build_test_pdb_file() -> Vec<u8> {
+    use crate::formats::pe::pdb::msf_file::build_test_msf_file;
+
+    fn type_record(
+        leaf_kind: u16,
+        record_data: &[u8],
+    ) -> Vec<u8> {
+        let mut record_bytes = Vec::new();
+        let mut padded_data = record_data.to_vec();
+
+        while !(padded_data.len() + 2).is_multiple_of(4) {
+            padded_data.push(0xF0 + (4 - (padded_data.len() + 2) % 4) as u8);
+        }
+
+        record_bytes.extend_from_slice(&((padded_data.len() + 2) as u16).to_le_bytes());
+        record_bytes.extend_from_slice(&leaf_kind.to_le_bytes());
+        record_bytes.extend_from_slice(&padded_data);
+
+        record_bytes
+    }
+
+    fn symbol_record(
+        record_kind: u16,
+        record_data: &[u8],
+    ) -> Vec<u8> {
+        let mut record_bytes = Vec::new();
+
+        record_bytes.extend_from_slice(&((record_data.len() + 2) as u16).to_le_bytes());
+        record_bytes.extend_from_slice(&record_kind.to_le_bytes());
+        record_bytes.extend_from_slice(record_data);
+
+        record_bytes
+    }
+
+    fn member(
+        type_index: u32,
+        offset: u16,
+        name: &str,
+    ) -> Vec<u8> {
+        let mut member_bytes = Vec::new();
+
+        member_bytes.extend_from_slice(&0x150D_u16.to_le_bytes());
+        member_bytes.extend_from_slice(&3_u16.to_le_bytes());
+        member_bytes.extend_from_slice(&type_index.to_le_bytes());
+        member_bytes.extend_from_slice(&offset.to_le_bytes());
+        member_bytes.extend_from_slice(name.as_bytes());
+        member_bytes.push(0);
+
+        while member_bytes.len() % 4 != 0 {
+            member_bytes.push(0xF0 + (4 - member_bytes.len() % 4) as u8);
+        }
+
+        member_bytes
+    }
+
+    fn structure(
+        member_count: u16,
+        property: u16,
+        field_list: u32,
+        size: u16,
+        name: &str,
+    ) -> Vec<u8> {
+        let mut structure_bytes = Vec::new();
+
+        structure_bytes.extend_from_slice(&member_count.to_le_bytes());
+        structure_bytes.extend_from_slice(&property.to_le_bytes());
+        structure_bytes.extend_from_slice(&field_list.to_le_bytes());
+        structure_bytes.extend_from_slice(&0_u32.to_le_bytes());
+        structure_bytes.extend_from_slice(&0_u32.to_le_bytes());
+        structure_bytes.extend_from_slice(&size.to_le_bytes());
+        structure_bytes.extend_from_slice(name.as_bytes());
+        structure_bytes.push(0);
+
+        structure_bytes
+    }
+
+    fn data_symbol(
+        type_index: u32,
+        offset: u32,
+        segment: u16,
+        name: &str,
+    ) -> Vec<u8> {
+        let mut data_bytes = Vec::new();
+
+        data_bytes.extend_from_slice(&type_index.to_le_bytes());
+        data_bytes.extend_from_slice(&offset.to_le_bytes());
+        data_bytes.extend_from_slice(&segment.to_le_bytes());
+        data_bytes.extend_from_slice(name.as_bytes());
+        data_bytes.push(0);
+
+        data_bytes
+    }
+
+    fn section_header(
+        name: &[u8],
+        virtual_size: u32,
+        virtual_address: u32,
+    ) -> Vec<u8> {
+        let mut header_bytes = vec![0_u8; IMAGE_SECTION_HEADER_SIZE];
+
+        header_bytes[..name.len()].copy_from_slice(name);
+        header_bytes[8..12].copy_from_slice(&virtual_size.to_le_bytes());
+        header_bytes[12..16].copy_from_slice(&virtual_address.to_le_bytes());
+
+        header_bytes
+    }
+
+    let mut info_stream = Vec::new();
+
+    info_stream.extend_from_slice(&20000404_u32.to_le_bytes());
+    info_stream.extend_from_slice(&0x5F00_0000_u32.to_le_bytes());
+    info_stream.extend_from_slice(&TEST_PDB_AGE.to_le_bytes());
+    info_stream.extend_from_slice(&TEST_PDB_GUID);
+
+    // 0x1000 forward-declares Player, 0x1001 points at it, 0x1002 is float[3], 0x1003 lists the members, and 0x1004 is
+    // the definition that the forward reference resolves to.
+    let mut pointer_record = Vec::new();
+
+    pointer_record.extend_from_slice(&0x1000_u32.to_le_bytes());
+    pointer_record.extend_from_slice(&(0x0C_u32 | (8 << 13)).to_le_bytes());
+
+    let mut array_record = Vec::new();
+
+    array_record.extend_from_slice(&0x0040_u32.to_le_bytes());
+    array_record.extend_from_slice(&0x0023_u32.to_le_bytes());
+    array_record.extend_from_slice(&12_u16.to_le_bytes());
+    array_record.push(0);
+
+    let mut field_list_record = Vec::new();
+
+    field_list_record.extend(member(0x0074, 0, "health"));
+    field_list_record.extend(member(0x1002, 4, "position"));
+    field_list_record.extend(member(0x1001, 16, "next"));
+
+    let mut type_records = Vec::new();
+
+    type_records.extend(type_record(0x1505, &structure(0, 0x80, 0, 0, "Game::Player")));
+    type_records.extend(type_record(0x1002, &pointer_record));
+    type_records.extend(type_record(0x1503, &array_record));
+    type_records.extend(type_record(0x1203, &field_list_record));
+    type_records.extend(type_record(0x1505, &structure(3, 0, 0x1003, 24, "Game::Player")));
+
+    let mut tpi_stream = Vec::new();
+
+    tpi_stream.extend_from_slice(&20040203_u32.to_le_bytes());
+    tpi_stream.extend_from_slice(&56_u32.to_le_bytes());
+    tpi_stream.extend_from_slice(&0x1000_u32.to_le_bytes());
+    tpi_stream.extend_from_slice(&0x1005_u32.to_le_bytes());
+    tpi_stream.extend_from_slice(&(type_records.len() as u32).to_le_bytes());
+    tpi_stream.resize(56, 0);
+    tpi_stream.extend(type_records);
+
+    let mut global_symbol_stream = Vec::new();
+
+    // Public symbols share the data symbol layout, with flags in place of the type index. Flag 0x2 marks code.
+    global_symbol_stream.extend(symbol_record(0x110E, &data_symbol(0x2, 0x10, 1, "?tick@Game@@YAXXZ")));
+    global_symbol_stream.extend(symbol_record(0x110E, &data_symbol(0x2, 0x80, 1, "?helper@Game@@YAXXZ")));
+    global_symbol_stream.extend(symbol_record(0x110D, &data_symbol(0x1000, 0x100, 2, "Game::g_player")));
+
+    let mut procedure_record = Vec::new();
+
+    procedure_record.extend_from_slice(&[0_u8; 12]);
+    procedure_record.extend_from_slice(&0x40_u32.to_le_bytes());
+    procedure_record.extend_from_slice(&[0_u8; 12]);
+    procedure_record.extend_from_slice(&0x10_u32.to_le_bytes());
+    procedure_record.extend_from_slice(&1_u16.to_le_bytes());
+    procedure_record.push(0);
+    procedure_record.extend_from_slice(b"Game::tick\0");
+
+    let mut module_stream = 4_u32.to_le_bytes().to_vec();
+
+    module_stream.extend(symbol_record(0x1110, &procedure_record));
+    module_stream.extend(symbol_record(0x0006, &[]));
+
+    let mut module_info = Vec::new();
+
+    module_info.extend_from_slice(&[0_u8; 4 + 28 + 2]);
+    module_info.extend_from_slice(&7_u16.to_le_bytes());
+    module_info.extend_from_slice(&(module_stream.len() as u32).to_le_bytes());
+    module_info.extend_from_slice(&[0_u8; DBI_MODULE_INFO_FIXED_SIZE - 40]);
+    module_info.extend_from_slice(b"game.obj\0game.obj\0");
+    module_info.resize(module_info.len().next_multiple_of(4), 0);
+
+    let mut debug_header = vec![0xFF_u8; 11 * 2];
+
+    debug_header[DBI_DEBUG_HEADER_SECTION_HEADERS_INDEX * 2..DBI_DEBUG_HEADER_SECTION_HEADERS_INDEX * 2 + 2].copy_from_slice(&6_u16.to_le_bytes());
+
+    let mut dbi_stream = vec![0_u8; DBI_HEADER_SIZE];
+
+    dbi_stream[0..4].copy_from_slice(&u32::MAX.to_le_bytes());
+    dbi_stream[4..8].copy_from_slice(&19990903_u32.to_le_bytes());
+    dbi_stream[8..12].copy_from_slice(&TEST_PDB_AGE.to_le_bytes());
+    dbi_stream[20..22].copy_from_slice(&5_u16.to_le_bytes());
+    dbi_stream[24..28].copy_from_slice(&(module_info.len() as u32).to_le_bytes());
+    dbi_stream[48..52].copy_from_slice(&(debug_header.len() as u32).to_le_bytes());
+    dbi_stream[58..60].copy_from_slice(&IMAGE_FILE_MACHINE_AMD64.to_le_bytes());
+    dbi_stream.extend(module_info);
+    dbi_stream.extend(debug_header);
+
+    let mut section_header_stream = section_header(b".text", 0x200, 0x1000);
+
+    section_header_stream.extend(section_header(b".data", 0x200, 0x3000));
+
+    build_test_msf_file(&[
+        Some(Vec::new()),
+        Some(info_stream),
+        Some(tpi_stream),
+        Some(dbi_stream),
+        Some(Vec::new()),
+        Some(global_symbol_stream),
+        Some(section_header_stream),
+        Some(module_stream),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PdbFile, PdbSection, TEST_PDB_AGE, TEST_PDB_GUID, build_test_pdb_file};
+
+    #[test]
+    fn parse_reads_identity_types_symbols_and_sections() {
+        let pdb_file = PdbFile::parse(build_test_pdb_file()).expect("Expected PDB to parse.");
+        let symbol_record_kinds = pdb_file
+            .get_symbol_records()
+            .into_iter()
+            .map(|(record_kind, _)| record_kind)
+            .collect::<Vec<_>>();
+
+        assert!(pdb_file.matches_identity(&TEST_PDB_GUID, TEST_PDB_AGE));
+        assert!(!pdb_file.matches_identity(&TEST_PDB_GUID, TEST_PDB_AGE + 1));
+        assert!(pdb_file.is_64_bit());
+        assert_eq!(pdb_file.get_type_index_end(), 0x1005);
+        assert_eq!(pdb_file.get_type_record(0x1004).map(|(leaf_kind, _)| leaf_kind), Some(0x1505));
+        assert_eq!(symbol_record_kinds, vec![0x110E, 0x110E, 0x110D, 0x1110, 0x0006]);
+        assert_eq!(
+            pdb_file.get_sections()[1],
+            PdbSection {
+                virtual_address: 0x3000,
+                virtual_size: 0x200,
+            }
+        );
+        assert_eq!(pdb_file.to_relative_virtual_address(2, 0x100), Some(0x3100));
+        assert_eq!(pdb_file.to_relative_virtual_address(0, 0x100), None);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::formats::pe::pdb::code_view_pdb_reference::CodeViewPdbReference;
+use std::path::{Path, PathBuf};
+
+const SYMBOL_PATH_ENVIRONMENT_VARIABLE: &str = "_NT_SYMBOL_PATH";
+
+/// Produces candidate paths for a module's PDB: the symbol store layout and a flat layout under each local symbol
+/// directory, then next to the module, then the path recorded at link time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PdbFileLocator {
+    symbol_directories: Vec<PathBuf>,
+}
+
+impl PdbFileLocator {
+    pub(crate) fn new(symbol_directories: Vec<PathBuf>) -> Self {
+        Self { symbol_directories }
+    }
+
+    /// Reads the local directories out of a `_NT_SYMBOL_PATH` style value. `srv*` and `cache*` entries contribute their
+    /// downstream stores; symbol server URLs are skipped since only files already on disk are loaded.
+    pub(crate) fn from_symbol_path(symbol_path: &str) -> Self {
+        let mut symbol_directories = Vec::new();
+
+        for symbol_path_entry in symbol_path
+            .split(';')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let Some((entry_kind, entry_locations)) = symbol_path_entry.split_once('*') else {
+                symbol_directories.push(PathBuf::from(symbol_path_entry));
+                continue;
+            };
+
+            if !matches!(entry_kind.to_ascii_lowercase().as_str(), "srv" | "symsrv" | "cache") {
+                continue;
+            }
+
+            symbol_directories.extend(
+                entry_locations
+                    .split('*')
+                    .filter(|location| !location.is_empty() && !location.contains("://") && !location.to_ascii_lowercase().ends_with(".dll"))
+                    .map(PathBuf::from),
+            );
+        }
+
+        Self::new(symbol_directories)
+    }
+
+    pub(crate) fn pdb_candidates(
+        &self,
+        code_view_pdb_reference: &CodeViewPdbReference,
+        module_file_path: Option<&Path>,
+    ) -> Vec<PathBuf> {
+        let pdb_file_name = code_view_pdb_reference.pdb_file_name();
+        let symbol_store_key = code_view_pdb_reference.symbol_store_key();
+        let mut candidates = Vec::new();
+
+        for symbol_directory in &self.symbol_directories {
+            candidates.push(
+                symbol_directory
+                    .join(pdb_file_name)
+                    .join(&symbol_store_key)
+                    .join(pdb_file_name),
+            );
+            candidates.push(symbol_directory.join(pdb_file_name));
+        }
+
+        if let Some(module_directory) = module_file_path.and_then(Path::parent) {
+            candidates.push(module_directory.join(pdb_file_name));
+        }
+
+        candidates.push(PathBuf::from(&code_view_pdb_reference.pdb_path));
+        candidates.dedup();
+
+        candidates
+    }
+}
+
+impl Default for PdbFileLocator {
+    fn default() -> Self {
+        Self::from_symbol_path(&std::env::var(SYMBOL_PATH_ENVIRONMENT_VARIABLE).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PdbFileLocator;
+    use crate::formats::pe::pdb::code_view_pdb_reference::CodeViewPdbReference;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn from_symbol_path_keeps_only_local_directories() {
+        assert_eq!(
+            PdbFileLocator::from_symbol_path(
+                "srv*/symbols/cache*https://msdl.microsoft.com/download/symbols; /opt/pdbs ;cache*/tmp/cache;symsrv*symsrv.dll*/srv"
+            ),
+            PdbFileLocator::new(vec![
+                PathBuf::from("/symbols/cache"),
+                PathBuf::from("/opt/pdbs"),
+                PathBuf::from("/tmp/cache"),
+                PathBuf::from("/srv"),
+            ])
+        );
+    }
+
+    #[test]
+    fn pdb_candidates_try_symbol_store_then_module_directory_then_recorded_path() {
+        let code_view_pdb_reference = CodeViewPdbReference {
+            guid: [0; 16],
+            age: 1,
+            pdb_path: String::from("C:\\build\\game.pdb"),
+        };
+
+        assert_eq!(
+            PdbFileLocator::new(vec![PathBuf::from("/symbols")]).pdb_candidates(&code_view_pdb_reference, Some(Path::new("/games/game/game.exe"))),
+            vec![
+                PathBuf::from("/symbols/game.pdb/000000000000000000000000000000001/game.pdb"),
+                PathBuf::from("/symbols/game.pdb"),
+                PathBuf::from("/games/game/game.pdb"),
+                PathBuf::from("C:\\build\\game.pdb"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,940 @@
+use crate::formats::pe::pdb::pdb_byte_reader::PdbByteReader;
+use crate::formats::pe::pdb::pdb_constants::*;
+use crate::formats::pe::pdb::pdb_file::PdbFile;
+use squalr_engine_api::{
+    registries::symbols::struct_layout_descriptor::StructLayoutDescriptor,
+    structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::container_type::ContainerType,
+        pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize,
+        projects::symbol_layouts::symbol_layout_field_materializer::{SymbolLayoutFieldMaterializer, SymbolLayoutPositionedField},
+        structs::{
+            symbolic_field_definition::SymbolicFieldDefinition,
+            symbolic_struct_definition::{SymbolicLayoutKind, SymbolicStructDefinition},
+        },
+    },
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+const STRING_UTF8_NULL_TERMINATED_DATA_TYPE_ID: &str = "string_utf8{null_terminated}";
+const FIRST_TPI_TYPE_INDEX: u32 = 0x1000;
+const MAX_TYPE_RESOLUTION_DEPTH: u32 = 64;
+
+/// A global or static variable at a fixed offset from the image base.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PdbGlobalVariable {
+    pub(crate) display_name: String,
+    pub(crate) relative_virtual_address: u64,
+    pub(crate) symbol_type_id: String,
+    pub(crate) size_in_bytes: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PdbFunction {
+    pub(crate) display_name: String,
+    pub(crate) relative_virtual_address: u64,
+    pub(crate) size_in_bytes: u64,
+}
+
+/// Everything recovered from a module's PDB, expressed in symbol catalog terms but not yet placed in a module.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PdbSymbols {
+    pub(crate) struct_layout_descriptors: Vec<StructLayoutDescriptor>,
+    pub(crate) global_variables: Vec<PdbGlobalVariable>,
+    pub(crate) functions: Vec<PdbFunction>,
+}
+
+/// A field's data type, container, and total size once a CodeView type has been flattened into Squalr's field model.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct PdbFieldShape {
+    data_type_id: String,
+    container_type: ContainerType,
+    size_in_bytes: u64,
+    is_character: bool,
+}
+
+impl PdbFieldShape {
+    fn new(
+        data_type_id: &str,
+        container_type: ContainerType,
+        size_in_bytes: u64,
+    ) -> Self {
+        Self {
+            data_type_id: data_type_id.to_string(),
+            container_type,
+            size_in_bytes,
+            is_character: false,
+        }
+    }
+
+    fn opaque(size_in_bytes: u64) -> Option<Self> {
+        (size_in_bytes > 0).then(|| Self::new("u8", ContainerType::ArrayFixed(size_in_bytes), size_in_bytes))
+    }
+
+    fn to_symbol_type_id(&self) -> String {
+        format!("{}{}", self.data_type_id, self.container_type)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PdbRecordHeader {
+    leaf_kind: u16,
+    property: u16,
+    field_list_type_index: u32,
+    size_in_bytes: u64,
+    name: String,
+}
+
+impl PdbRecordHeader {
+    fn is_forward_reference(&self) -> bool {
+        self.property & PROPERTY_FORWARD_REFERENCE != 0
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PdbMember {
+    field_name: Option<String>,
+    type_index: u32,
+    offset: u64,
+}
+
+enum PdbFieldListEntry {
+    Member(PdbMember),
+    Continuation(u32),
+    Other,
+}
+
+#[derive(Clone, Debug)]
+struct PdbPublicSymbol {
+    display_name: String,
+    relative_virtual_address: u64,
+    is_code: bool,
+}
+
+/// Converts TPI type records and symbol records into struct layouts, global variables, and functions.
+pub(crate) struct PdbSymbolBuilder<'a> {
+    pdb_file: &'a PdbFile,
+    record_definitions: HashMap<String, u32>,
+}
+
+impl<'a> PdbSymbolBuilder<'a> {
+    pub(crate) fn build(pdb_file: &'a PdbFile) -> PdbSymbols {
+        let mut pdb_symbol_builder = Self {
+            pdb_file,
+            record_definitions: HashMap::new(),
+        };
+
+        pdb_symbol_builder.collect_record_definitions();
+
+        let struct_layout_descriptors = pdb_symbol_builder.build_struct_layouts();
+        let (global_variables, functions) = pdb_symbol_builder.build_global_symbols();
+
+        PdbSymbols {
+            struct_layout_descriptors,
+            global_variables,
+            functions,
+        }
+    }
+
+    /// Member and variable types usually name a forward reference, so definitions are indexed by name up front.
+    fn collect_record_definitions(&mut self) {
+        for type_index in self.pdb_file.get_type_index_begin()..self.pdb_file.get_type_index_end() {
+            let Some(record_header) = self.read_record_header(type_index) else {
+                continue;
+            };
+
+            if !record_header.is_forward_reference() {
+                self.record_definitions
+                    .entry(record_header.name)
+                    .or_insert(type_index);
+            }
+        }
+    }
+
+    fn build_struct_layouts(&self) -> Vec<StructLayoutDescriptor> {
+        let mut struct_layout_descriptors = Vec::new();
+        let mut built_layout_ids = HashSet::new();
+        let definition_type_indices = self
+            .record_definitions
+            .values()
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        for type_index in definition_type_indices {
+            let Some(record_header) = self.read_record_header(type_index) else {
+                continue;
+            };
+            let layout_id = get_layout_id(&record_header, type_index);
+
+            if record_header.size_in_bytes == 0 || !built_layout_ids.insert(layout_id.clone()) {
+                continue;
+            }
+
+            if let Some(struct_layout_descriptor) = self.build_struct_layout(&record_header, layout_id) {
+                struct_layout_descriptors.push(struct_layout_descriptor);
+            }
+        }
+
+        struct_layout_descriptors
+    }
+
+    fn build_struct_layout(
+        &self,
+        record_header: &PdbRecordHeader,
+        layout_id: String,
+    ) -> Option<StructLayoutDescriptor> {
+        let layout_kind = if record_header.leaf_kind == LF_UNION {
+            SymbolicLayoutKind::Union
+        } else {
+            SymbolicLayoutKind::Struct
+        };
+        let mut positioned_fields = Vec::new();
+        let mut next_free_offset = 0_u64;
+
+        for (member_index, member) in self
+            .read_field_list_members(record_header.field_list_type_index)
+            .into_iter()
+            .enumerate()
+        {
+            let Some(field_shape) = self.resolve_field_shape(member.type_index, 0) else {
+                continue;
+            };
+            let offset = if layout_kind == SymbolicLayoutKind::Union { 0 } else { member.offset };
+            let field_end = offset.saturating_add(field_shape.size_in_bytes);
+
+            // Bitfields and overlapping members cannot be expressed as struct fields, so the first one at an offset wins.
+            if layout_kind == SymbolicLayoutKind::Struct {
+                if offset < next_free_offset || field_end > record_header.size_in_bytes {
+                    continue;
+                }
+
+                next_free_offset = field_end;
+            }
+
+            let field_name = match member.field_name {
+                Some(field_name) if !field_name.is_empty() => field_name,
+                Some(_) => format!("anonymous_{member_index}"),
+                None => format!("base_{}", sanitize_layout_id_component(&field_shape.data_type_id)),
+            };
+
+            positioned_fields.push(SymbolLayoutPositionedField::new(
+                offset,
+                field_shape.size_in_bytes,
+                SymbolicFieldDefinition::new_named(field_name, DataTypeRef::new(&field_shape.data_type_id), field_shape.container_type),
+            ));
+        }
+
+        let materialized_fields =
+            SymbolLayoutFieldMaterializer::materialize_positioned_fields(layout_kind, Some(record_header.size_in_bytes), positioned_fields).ok()?;
+
+        Some(StructLayoutDescriptor::new(
+            layout_id.clone(),
+            SymbolicStructDefinition::new_with_layout_kind(layout_id, layout_kind, materialized_fields)
+                .with_declared_size_in_bytes(Some(record_header.size_in_bytes)),
+        ))
+    }
+
+    fn build_global_symbols(&self) -> (Vec<PdbGlobalVariable>, Vec<PdbFunction>) {
+        let mut global_variables = BTreeMap::new();
+        let mut functions = BTreeMap::new();
+        let mut public_symbols = Vec::new();
+
+        for (record_kind, record_bytes) in self.pdb_file.get_symbol_records() {
+            match record_kind {
+                S_GDATA32 | S_LDATA32 => {
+                    if let Some(global_variable) = self.read_data_symbol(record_bytes) {
+                        global_variables
+                            .entry(global_variable.relative_virtual_address)
+                            .or_insert(global_variable);
+                    }
+                }
+                S_GPROC32 | S_LPROC32 | S_GPROC32_ID | S_LPROC32_ID => {
+                    if let Some(function) = self.read_procedure_symbol(record_bytes) {
+                        functions
+                            .entry(function.relative_virtual_address)
+                            .or_insert(function);
+                    }
+                }
+                S_PUB32 => public_symbols.extend(self.read_public_symbol(record_bytes)),
+                _ => {}
+            }
+        }
+
+        self.add_public_symbols(public_symbols, &mut global_variables, &mut functions);
+
+        (global_variables.into_values().collect(), functions.into_values().collect())
+    }
+
+    /// Public symbols are all that stripped PDBs carry. They have no size, so each one spans up to the next known symbol
+    /// or the end of its section. Publics that land inside a typed symbol only duplicate it and are skipped.
+    fn add_public_symbols(
+        &self,
+        public_symbols: Vec<PdbPublicSymbol>,
+        global_variables: &mut BTreeMap<u64, PdbGlobalVariable>,
+        functions: &mut BTreeMap<u64, PdbFunction>,
+    ) {
+        let occupied_ranges = global_variables
+            .values()
+            .map(|global_variable| (global_variable.relative_virtual_address, global_variable.size_in_bytes))
+            .chain(
+                functions
+                    .values()
+                    .map(|function| (function.relative_virtual_address, function.size_in_bytes)),
+            )
+            .map(|(range_start, size_in_bytes)| (range_start, range_start.saturating_add(size_in_bytes.max(1))))
+            .collect::<Vec<_>>();
+        let symbol_starts = occupied_ranges
+            .iter()
+            .map(|(range_start, _)| *range_start)
+            .chain(
+                public_symbols
+                    .iter()
+                    .map(|public_symbol| public_symbol.relative_virtual_address),
+            )
+            .collect::<BTreeSet<_>>();
+
+        for public_symbol in public_symbols {
+            let relative_virtual_address = public_symbol.relative_virtual_address;
+
+            if occupied_ranges
+                .iter()
+                .any(|(range_start, range_end)| *range_start <= relative_virtual_address && relative_virtual_address < *range_end)
+            {
+                continue;
+            }
+
+            let section_end = self
+                .pdb_file
+                .get_sections()
+                .iter()
+                .map(|section| (section.virtual_address as u64, section.virtual_address as u64 + section.virtual_size as u64))
+                .find(|(section_start, section_end)| *section_start <= relative_virtual_address && relative_virtual_address < *section_end)
+                .map(|(_, section_end)| section_end);
+            let next_symbol_start = symbol_starts
+                .range(relative_virtual_address.saturating_add(1)..)
+                .next()
+                .copied();
+            let size_in_bytes = match (section_end, next_symbol_start) {
+                (Some(section_end), Some(next_symbol_start)) => section_end.min(next_symbol_start),
+                (Some(symbol_end), None) | (None, Some(symbol_end)) => symbol_end,
+                (None, None) => relative_virtual_address.saturating_add(1),
+            }
+            .saturating_sub(relative_virtual_address)
+            .max(1);
+
+            if public_symbol.is_code {
+                functions
+                    .entry(relative_virtual_address)
+                    .or_insert(PdbFunction {
+                        display_name: public_symbol.display_name,
+                        relative_virtual_address,
+                        size_in_bytes,
+                    });
+            } else {
+                global_variables
+                    .entry(relative_virtual_address)
+                    .or_insert(PdbGlobalVariable {
+                        display_name: public_symbol.display_name,
+                        relative_virtual_address,
+                        symbol_type_id: format!("u8[{size_in_bytes}]"),
+                        size_in_bytes,
+                    });
+            }
+        }
+    }
+
+    fn read_data_symbol(
+        &self,
+        record_bytes: &[u8],
+    ) -> Option<PdbGlobalVariable> {
+        let mut symbol_reader = PdbByteReader::new(record_bytes);
+        let type_index = symbol_reader.read_u32().ok()?;
+        let offset = symbol_reader.read_u32().ok()?;
+        let segment = symbol_reader.read_u16().ok()?;
+        let display_name = symbol_reader.read_c_string().ok()?;
+        let relative_virtual_address = self.pdb_file.to_relative_virtual_address(segment, offset)?;
+        let field_shape = self.resolve_field_shape(type_index, 0)?;
+
+        Some(PdbGlobalVariable {
+            display_name,
+            relative_virtual_address,
+            symbol_type_id: field_shape.to_symbol_type_id(),
+            size_in_bytes: field_shape.size_in_bytes,
+        })
+    }
+
+    fn read_procedure_symbol(
+        &self,
+        record_bytes: &[u8],
+    ) -> Option<PdbFunction> {
+        let mut symbol_reader = PdbByteReader::new(record_bytes);
+
+        // Skips the parent, end, and next scope pointers.
+        symbol_reader.skip(12).ok()?;
+
+        let size_in_bytes = symbol_reader.read_u32().ok()? as u64;
+
+        // Skips the debug start, debug end, and function type.
+        symbol_reader.skip(12).ok()?;
+
+        let offset = symbol_reader.read_u32().ok()?;
+        let segment = symbol_reader.read_u16().ok()?;
+        let _flags = symbol_reader.read_u8().ok()?;
+        let display_name = symbol_reader.read_c_string().ok()?;
+
+        if size_in_bytes == 0 {
+            return None;
+        }
+
+        Some(PdbFunction {
+            display_name,
+            relative_virtual_address: self.pdb_file.to_relative_virtual_address(segment, offset)?,
+            size_in_bytes,
+        })
+    }
+
+    fn read_public_symbol(
+        &self,
+        record_bytes: &[u8],
+    ) -> Option<PdbPublicSymbol> {
+        let mut symbol_reader = PdbByteReader::new(record_bytes);
+        let flags = symbol_reader.read_u32().ok()?;
+        let offset = symbol_reader.read_u32().ok()?;
+        let segment = symbol_reader.read_u16().ok()?;
+        let decorated_name = symbol_reader.read_c_string().ok()?;
+
+        Some(PdbPublicSymbol {
+            display_name: undecorate_public_name(&decorated_name),
+            relative_virtual_address: self.pdb_file.to_relative_virtual_address(segment, offset)?,
+            is_code: flags & (PUBLIC_SYMBOL_FLAG_CODE | PUBLIC_SYMBOL_FLAG_FUNCTION) != 0,
+        })
+    }
+
+    fn read_record_header(
+        &self,
+        type_index: u32,
+    ) -> Option<PdbRecordHeader> {
+        let (leaf_kind, record_bytes) = self.pdb_file.get_type_record(type_index)?;
+        let mut record_reader = PdbByteReader::new(record_bytes);
+        let _member_count = record_reader.read_u16().ok()?;
+        let property = record_reader.read_u16().ok()?;
+        let field_list_type_index = record_reader.read_u32().ok()?;
+
+        match leaf_kind {
+            LF_CLASS | LF_STRUCTURE | LF_INTERFACE => {
+                // Skips the derivation list and the virtual function table shape.
+                record_reader.skip(8).ok()?;
+            }
+            LF_UNION => {}
+            _ => return None,
+        }
+
+        let size_in_bytes = record_reader.read_numeric().ok()?;
+        let name = record_reader.read_c_string().ok()?;
+
+        Some(PdbRecordHeader {
+            leaf_kind,
+            property,
+            field_list_type_index,
+            size_in_bytes,
+            name,
+        })
+    }
+
+    fn resolve_record_definition(
+        &self,
+        type_index: u32,
+    ) -> Option<(u32, PdbRecordHeader)> {
+        let record_header = self.read_record_header(type_index)?;
+
+        if !record_header.is_forward_reference() {
+            return Some((type_index, record_header));
+        }
+
+        let definition_type_index = *self.record_definitions.get(&record_header.name)?;
+
+        Some((definition_type_index, self.read_record_header(definition_type_index)?))
+    }
+
+    /// Field lists longer than a single record chain into continuation records through `LF_INDEX`.
+    fn read_field_list_members(
+        &self,
+        field_list_type_index: u32,
+    ) -> Vec<PdbMember> {
+        let mut members = Vec::new();
+        let mut visited_type_indices = HashSet::new();
+        let mut next_type_index = Some(field_list_type_index);
+
+        while let Some(type_index) = next_type_index.take() {
+            if !visited_type_indices.insert(type_index) {
+                break;
+            }
+
+            let Some((LF_FIELDLIST, record_bytes)) = self.pdb_file.get_type_record(type_index) else {
+                break;
+            };
+            let mut field_list_reader = PdbByteReader::new(record_bytes);
+
+            while !field_list_reader.is_empty() {
+                match read_field_list_entry(&mut field_list_reader) {
+                    Ok(PdbFieldListEntry::Member(member)) => members.push(member),
+                    Ok(PdbFieldListEntry::Continuation(continuation_type_index)) => next_type_index = Some(continuation_type_index),
+                    Ok(PdbFieldListEntry::Other) => {}
+                    Err(_) => break,
+                }
+
+                while field_list_reader
+                    .peek_u8()
+                    .is_some_and(|padding_byte| padding_byte >= LF_PAD0)
+                {
+                    let _ = field_list_reader.skip(1);
+                }
+            }
+        }
+
+        members
+    }
+
+    fn resolve_field_shape(
+        &self,
+        type_index: u32,
+        depth: u32,
+    ) -> Option<PdbFieldShape> {
+        if depth > MAX_TYPE_RESOLUTION_DEPTH {
+            return None;
+        }
+
+        if type_index < FIRST_TPI_TYPE_INDEX {
+            return resolve_simple_type_shape(type_index);
+        }
+
+        let (leaf_kind, record_bytes) = self.pdb_file.get_type_record(type_index)?;
+        let mut record_reader = PdbByteReader::new(record_bytes);
+
+        match leaf_kind {
+            LF_MODIFIER | LF_BITFIELD => self.resolve_field_shape(record_reader.read_u32().ok()?, depth + 1),
+            LF_ENUM => {
+                record_reader.skip(4).ok()?;
+                self.resolve_field_shape(record_reader.read_u32().ok()?, depth + 1)
+            }
+            LF_POINTER => {
+                let pointee_type_index = record_reader.read_u32().ok()?;
+                let pointer_attributes = record_reader.read_u32().ok()?;
+                let pointer_mode = (pointer_attributes >> 5) & 0x7;
+                let pointer_size_in_bytes = ((pointer_attributes >> 13) & 0x3F) as u64;
+
+                if matches!(pointer_mode, POINTER_MODE_MEMBER_DATA | POINTER_MODE_MEMBER_FUNCTION) {
+                    return PdbFieldShape::opaque(pointer_size_in_bytes);
+                }
+
+                let pointer_size = map_pointer_size(pointer_size_in_bytes)?;
+
+                Some(PdbFieldShape::new(
+                    &self.resolve_pointee_data_type_id(pointee_type_index, depth + 1),
+                    ContainerType::Pointer(pointer_size),
+                    pointer_size.get_size_in_bytes(),
+                ))
+            }
+            LF_ARRAY => {
+                let element_type_index = record_reader.read_u32().ok()?;
+                let _index_type_index = record_reader.read_u32().ok()?;
+                let size_in_bytes = record_reader.read_numeric().ok()?;
+
+                self.resolve_array_shape(element_type_index, size_in_bytes, depth)
+            }
+            LF_CLASS | LF_STRUCTURE | LF_INTERFACE | LF_UNION => {
+                let (definition_type_index, record_header) = self.resolve_record_definition(type_index)?;
+
+                Some(PdbFieldShape::new(
+                    &get_layout_id(&record_header, definition_type_index),
+                    ContainerType::None,
+                    record_header.size_in_bytes,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn resolve_array_shape(
+        &self,
+        element_type_index: u32,
+        size_in_bytes: u64,
+        depth: u32,
+    ) -> Option<PdbFieldShape> {
+        let element_shape = self.resolve_field_shape(element_type_index, depth + 1)?;
+
+        if element_shape.size_in_bytes == 0 || size_in_bytes == 0 {
+            return None;
+        }
+
+        let element_count = size_in_bytes / element_shape.size_in_bytes;
+        let container_type = match element_shape.container_type {
+            ContainerType::None => ContainerType::ArrayFixed(element_count),
+            ContainerType::ArrayFixed(inner_element_count) => ContainerType::ArrayFixed(inner_element_count.saturating_mul(element_count)),
+            ContainerType::Pointer(pointer_size) => ContainerType::PointerArrayFixed(pointer_size, element_count),
+            ContainerType::PointerArrayFixed(pointer_size, inner_element_count) => {
+                ContainerType::PointerArrayFixed(pointer_size, inner_element_count.saturating_mul(element_count))
+            }
+            ContainerType::Array | ContainerType::PointerArray(_) => return PdbFieldShape::opaque(size_in_bytes),
+        };
+        let data_type_id = if element_shape.is_character && element_shape.container_type == ContainerType::None {
+            STRING_UTF8_NULL_TERMINATED_DATA_TYPE_ID
+        } else if element_shape.data_type_id == STRING_UTF8_NULL_TERMINATED_DATA_TYPE_ID {
+            // Multi-dimensional character arrays are not single strings, so they fall back to raw bytes.
+            "u8"
+        } else {
+            &element_shape.data_type_id
+        };
+
+        Some(PdbFieldShape::new(
+            data_type_id,
+            container_type,
+            element_count.saturating_mul(element_shape.size_in_bytes),
+        ))
+    }
+
+    /// Pointers to records that were only ever forward-declared fall back to byte pointers so no field references a
+    /// layout that does not exist.
+    fn resolve_pointee_data_type_id(
+        &self,
+        type_index: u32,
+        depth: u32,
+    ) -> String {
+        if depth > MAX_TYPE_RESOLUTION_DEPTH {
+            return String::from("u8");
+        }
+
+        if type_index < FIRST_TPI_TYPE_INDEX {
+            return match (type_index >> 8) & 0xF {
+                SIMPLE_MODE_DIRECT => map_simple_type(type_index & 0xFF).map_or_else(|| String::from("u8"), |field_shape| field_shape.data_type_id),
+                _ => self.pointer_sized_data_type_id(),
+            };
+        }
+
+        let Some((leaf_kind, record_bytes)) = self.pdb_file.get_type_record(type_index) else {
+            return String::from("u8");
+        };
+        let mut record_reader = PdbByteReader::new(record_bytes);
+
+        match leaf_kind {
+            LF_MODIFIER | LF_ARRAY => match record_reader.read_u32() {
+                Ok(underlying_type_index) => self.resolve_pointee_data_type_id(underlying_type_index, depth + 1),
+                Err(_) => String::from("u8"),
+            },
+            LF_ENUM => match record_reader.skip(4).and_then(|_| record_reader.read_u32()) {
+                Ok(underlying_type_index) => self.resolve_pointee_data_type_id(underlying_type_index, depth + 1),
+                Err(_) => String::from("u8"),
+            },
+            LF_POINTER => self.pointer_sized_data_type_id(),
+            LF_CLASS | LF_STRUCTURE | LF_INTERFACE | LF_UNION => self.resolve_record_definition(type_index).map_or_else(
+                || String::from("u8"),
+                |(definition_type_index, record_header)| get_layout_id(&record_header, definition_type_index),
+            ),
+            _ => String::from("u8"),
+        }
+    }
+
+    fn pointer_sized_data_type_id(&self) -> String {
+        String::from(if self.pdb_file.is_64_bit() { "u64" } else { "u32" })
+    }
+}
+
+fn read_field_list_entry(field_list_reader: &mut PdbByteReader) -> Result<PdbFieldListEntry, String> {
+    let leaf_kind = field_list_reader.read_u16()?;
+
+    match leaf_kind {
+        LF_MEMBER => {
+            let _attributes = field_list_reader.read_u16()?;
+            let type_index = field_list_reader.read_u32()?;
+            let offset = field_list_reader.read_numeric()?;
+            let field_name = field_list_reader.read_c_string()?;
+
+            Ok(PdbFieldListEntry::Member(PdbMember {
+                field_name: Some(field_name),
+                type_index,
+                offset,
+            }))
+        }
+        LF_BCLASS => {
+            let _attributes = field_list_reader.read_u16()?;
+            let type_index = field_list_reader.read_u32()?;
+            let offset = field_list_reader.read_numeric()?;
+
+            Ok(PdbFieldListEntry::Member(PdbMember {
+                field_name: None,
+                type_index,
+                offset,
+            }))
+        }
+        LF_VBCLASS | LF_IVBCLASS => {
+            field_list_reader.skip(2 + 4 + 4)?;
+            field_list_reader.read_numeric()?;
+            field_list_reader.read_numeric()?;
+
+            Ok(PdbFieldListEntry::Other)
+        }
+        LF_STMEMBER | LF_NESTTYPE => {
+            field_list_reader.skip(2 + 4)?;
+            field_list_reader.read_c_string()?;
+
+            Ok(PdbFieldListEntry::Other)
+        }
+        LF_METHOD => {
+            field_list_reader.skip(2 + 4)?;
+            field_list_reader.read_c_string()?;
+
+            Ok(PdbFieldListEntry::Other)
+        }
+        LF_ONEMETHOD => {
+            let attributes = field_list_reader.read_u16()?;
+            let _type_index = field_list_reader.read_u32()?;
+
+            if matches!(
+                (attributes >> 2) & 0x7,
+                METHOD_PROPERTY_INTRODUCING_VIRTUAL | METHOD_PROPERTY_PURE_INTRODUCING_VIRTUAL
+            ) {
+                field_list_reader.skip(4)?;
+            }
+
+            field_list_reader.read_c_string()?;
+
+            Ok(PdbFieldListEntry::Other)
+        }
+        LF_VFUNCTAB => {
+            field_list_reader.skip(2 + 4)?;
+
+            Ok(PdbFieldListEntry::Other)
+        }
+        LF_ENUMERATE => {
+            field_list_reader.skip(2)?;
+            field_list_reader.read_numeric()?;
+            field_list_reader.read_c_string()?;
+
+            Ok(PdbFieldListEntry::Other)
+        }
+        LF_INDEX => {
+            field_list_reader.skip(2)?;
+
+            Ok(PdbFieldListEntry::Continuation(field_list_reader.read_u32()?))
+        }
+        _ => Err(format!("Unsupported field list leaf 0x{leaf_kind:X}.")),
+    }
+}
+
+/// Type indices below the first TPI record encode a primitive kind in the low byte and a pointer mode above it.
+fn resolve_simple_type_shape(type_index: u32) -> Option<PdbFieldShape> {
+    let pointee_data_type_id = map_simple_type(type_index & 0xFF).map_or_else(|| String::from("u8"), |field_shape| field_shape.data_type_id);
+
+    match (type_index >> 8) & 0xF {
+        SIMPLE_MODE_DIRECT => map_simple_type(type_index & 0xFF),
+        SIMPLE_MODE_NEAR_POINTER32 => Some(PdbFieldShape::new(
+            &pointee_data_type_id,
+            ContainerType::Pointer(PointerScanPointerSize::Pointer32),
+            4,
+        )),
+        SIMPLE_MODE_NEAR_POINTER64 => Some(PdbFieldShape::new(
+            &pointee_data_type_id,
+            ContainerType::Pointer(PointerScanPointerSize::Pointer64),
+            8,
+        )),
+        _ => None,
+    }
+}
+
+fn map_simple_type(simple_kind: u32) -> Option<PdbFieldShape> {
+    let (data_type_id, size_in_bytes, is_character) = match simple_kind {
+        0x10 | 0x70 => ("i8", 1, true),
+        0x20 | 0x7C => ("u8", 1, true),
+        0x68 => ("i8", 1, false),
+        0x69 => ("u8", 1, false),
+        0x11 | 0x72 => ("i16", 2, false),
+        0x21 | 0x73 | 0x71 | 0x7A => ("u16", 2, false),
+        0x12 | 0x74 | 0x08 => ("i32", 4, false),
+        0x22 | 0x75 | 0x7B => ("u32", 4, false),
+        0x13 | 0x76 => ("i64", 8, false),
+        0x23 | 0x77 => ("u64", 8, false),
+        0x40 => ("f32", 4, false),
+        0x41 => ("f64", 8, false),
+        0x30 => ("bool8", 1, false),
+        0x31 => ("u16", 2, false),
+        0x32 => ("bool32", 4, false),
+        0x33 => ("u64", 8, false),
+        _ => return None,
+    };
+
+    Some(PdbFieldShape {
+        is_character,
+        ..PdbFieldShape::new(data_type_id, ContainerType::None, size_in_bytes)
+    })
+}
+
+fn map_pointer_size(pointer_size_in_bytes: u64) -> Option<PointerScanPointerSize> {
+    match pointer_size_in_bytes {
+        4 => Some(PointerScanPointerSize::Pointer32),
+        8 => Some(PointerScanPointerSize::Pointer64),
+        _ => None,
+    }
+}
+
+/// Nested and namespaced record names become dotted layout ids. Compilers name anonymous records with placeholders
+/// such as `<unnamed-tag>`, which are replaced by an id derived from the type index.
+fn get_layout_id(
+    record_header: &PdbRecordHeader,
+    type_index: u32,
+) -> String {
+    let record_name = &record_header.name;
+
+    if record_name.is_empty() || record_name.contains("<unnamed-") || record_name.contains("<anonymous-") || record_name.starts_with("__unnamed") {
+        return format!("anonymous_{type_index:X}");
+    }
+
+    split_scope_names(record_name)
+        .iter()
+        .map(|scope_name| sanitize_layout_id_component(scope_name))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Splits on `::` outside of template argument lists.
+fn split_scope_names(qualified_name: &str) -> Vec<&str> {
+    let mut scope_names = Vec::new();
+    let mut template_depth = 0_u32;
+    let mut scope_start = 0;
+    let name_bytes = qualified_name.as_bytes();
+    let mut byte_index = 0;
+
+    while byte_index < name_bytes.len() {
+        match name_bytes[byte_index] {
+            b'<' => template_depth += 1,
+            b'>' => template_depth = template_depth.saturating_sub(1),
+            b':' if template_depth == 0 && name_bytes.get(byte_index + 1) == Some(&b':') => {
+                scope_names.push(&qualified_name[scope_start..byte_index]);
+                scope_start = byte_index + 2;
+                byte_index += 1;
+            }
+            _ => {}
+        }
+
+        byte_index += 1;
+    }
+
+    scope_names.push(&qualified_name[scope_start..]);
+
+    scope_names
+}
+
+/// Recovers `Scope::name` from simple MSVC-decorated names such as `?name@Scope@@...`. Operators, special members, and
+/// templates use a richer encoding, so those keep their decorated name.
+fn undecorate_public_name(decorated_name: &str) -> String {
+    let Some(qualified_name) = decorated_name
+        .strip_prefix('?')
+        .filter(|qualified_name| !qualified_name.starts_with('?') && !qualified_name.starts_with('$'))
+        .and_then(|qualified_name| qualified_name.split_once("@@"))
+        .map(|(qualified_name, _)| qualified_name)
+    else {
+        return decorated_name.to_string();
+    };
+
+    if qualified_name.is_empty() || qualified_name.contains(['$', '?']) || qualified_name.split('@').any(str::is_empty) {
+        return decorated_name.to_string();
+    }
+
+    qualified_name.rsplit('@').collect::<Vec<_>>().join("::")
+}
+
+/// Template arguments and operators produce characters that the field and resolver syntax reserve, so layout id
+/// components keep only identifier characters.
+fn sanitize_layout_id_component(name: &str) -> String {
+    let mut sanitized_name = String::with_capacity(name.len());
+
+    for name_character in name.chars() {
+        if name_character.is_ascii_alphanumeric() || name_character == '_' {
+            sanitized_name.push(name_character);
+        } else if !sanitized_name.ends_with('_') {
+            sanitized_name.push('_');
+        }
+    }
+
+    let sanitized_name = sanitized_name.trim_matches('_');
+
+    if sanitized_name.is_empty() {
+        String::from("anonymous")
+    } else {
+        sanitized_name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PdbFunction, PdbGlobalVariable, PdbSymbolBuilder, split_scope_names, undecorate_public_name};
+    use crate::formats::pe::pdb::pdb_file::{PdbFile, build_test_pdb_file};
+
+    #[test]
+    fn build_resolves_forward_references_and_places_symbols_by_section() {
+        let pdb_file = PdbFile::parse(build_test_pdb_file()).expect("Expected PDB to parse.");
+        let pdb_symbols = PdbSymbolBuilder::build(&pdb_file);
+        let player_layout = pdb_symbols
+            .struct_layout_descriptors
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == "Game.Player")
+            .expect("Expected Game.Player layout.");
+        let player_fields = player_layout
+            .get_struct_layout_definition()
+            .get_fields()
+            .iter()
+            .map(|field_definition| field_definition.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(pdb_symbols.struct_layout_descriptors.len(), 1);
+        assert_eq!(
+            player_layout
+                .get_struct_layout_definition()
+                .get_declared_size_in_bytes(),
+            Some(24)
+        );
+        assert_eq!(player_fields.len(), 3);
+        assert!(player_fields[0].starts_with("health:i32"));
+        assert!(player_fields[1].starts_with("position:f32[3]"));
+        assert!(player_fields[2].starts_with("next:Game.Player*"));
+        assert_eq!(
+            pdb_symbols.global_variables,
+            vec![PdbGlobalVariable {
+                display_name: String::from("Game::g_player"),
+                relative_virtual_address: 0x3100,
+                symbol_type_id: String::from("Game.Player"),
+                size_in_bytes: 24,
+            }]
+        );
+        assert_eq!(
+            pdb_symbols.functions,
+            vec![
+                PdbFunction {
+                    display_name: String::from("Game::tick"),
+                    relative_virtual_address: 0x1010,
+                    size_in_bytes: 0x40,
+                },
+                PdbFunction {
+                    display_name: String::from("Game::helper"),
+                    relative_virtual_address: 0x1080,
+                    size_in_bytes: 0x180,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn undecorate_public_name_only_rewrites_simple_scoped_names() {
+        assert_eq!(undecorate_public_name("?tick@Player@Game@@QEAAXXZ"), "Game::Player::tick");
+        assert_eq!(undecorate_public_name("??0Player@Game@@QEAA@XZ"), "??0Player@Game@@QEAA@XZ");
+        assert_eq!(undecorate_public_name("?$vector@H@std@@"), "?$vector@H@std@@");
+        assert_eq!(undecorate_public_name("_main"), "_main");
+    }
+
+    #[test]
+    fn split_scope_names_ignores_separators_inside_template_arguments() {
+        assert_eq!(
+            split_scope_names("Game::Pool<Game::Player,8>::Slot"),
+            vec!["Game", "Pool<Game::Player,8>", "Slot"]
+        );
+    }
+}
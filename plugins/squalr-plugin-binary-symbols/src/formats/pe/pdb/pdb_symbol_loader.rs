@@ -0,0 +1,91 @@
+use crate::formats::pe::pdb::code_view_pdb_reference::CodeViewPdbReference;
+use crate::formats::pe::pdb::pdb_file::PdbFile;
+use crate::formats::pe::pdb::pdb_file_locator::PdbFileLocator;
+use crate::formats::pe::pdb::pdb_symbol_builder::{PdbSymbolBuilder, PdbSymbols};
+use std::path::Path;
+
+/// Loads the PDB matching a module's CodeView record from local symbol directories or from beside the module.
+#[derive(Clone, Debug)]
+pub(crate) struct PdbSymbolLoader {
+    pdb_file_locator: PdbFileLocator,
+}
+
+impl PdbSymbolLoader {
+    pub(crate) fn new(pdb_file_locator: PdbFileLocator) -> Self {
+        Self { pdb_file_locator }
+    }
+
+    /// Returns `None` when no candidate is a readable PDB for this exact build. A PDB from another build describes
+    /// different addresses and layouts, so GUID or age mismatches are skipped rather than loaded.
+    pub(crate) fn load(
+        &self,
+        code_view_pdb_reference: &CodeViewPdbReference,
+        module_file_path: Option<&Path>,
+    ) -> Option<PdbSymbols> {
+        self.pdb_file_locator
+            .pdb_candidates(code_view_pdb_reference, module_file_path)
+            .iter()
+            .filter(|pdb_candidate| pdb_candidate.is_file())
+            .filter_map(|pdb_candidate| std::fs::read(pdb_candidate).ok())
+            .filter_map(|pdb_file_bytes| PdbFile::parse(pdb_file_bytes).ok())
+            .find(|pdb_file| pdb_file.matches_identity(&code_view_pdb_reference.guid, code_view_pdb_reference.age))
+            .map(|pdb_file| PdbSymbolBuilder::build(&pdb_file))
+    }
+}
+
+impl Default for PdbSymbolLoader {
+    fn default() -> Self {
+        Self::new(PdbFileLocator::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PdbSymbolLoader;
+    use crate::formats::pe::pdb::code_view_pdb_reference::CodeViewPdbReference;
+    use crate::formats::pe::pdb::pdb_file::{TEST_PDB_AGE, TEST_PDB_GUID, build_test_pdb_file};
+    use crate::formats::pe::pdb::pdb_file_locator::PdbFileLocator;
+
+    #[test]
+    fn load_finds_pdb_in_symbol_store_layout() {
+        let temp_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let code_view_pdb_reference = CodeViewPdbReference {
+            guid: TEST_PDB_GUID,
+            age: TEST_PDB_AGE,
+            pdb_path: String::from("C:\\build\\game.pdb"),
+        };
+        let pdb_directory = temp_directory
+            .path()
+            .join("game.pdb")
+            .join(code_view_pdb_reference.symbol_store_key());
+
+        std::fs::create_dir_all(&pdb_directory).expect("Expected symbol store directory to be created.");
+        std::fs::write(pdb_directory.join("game.pdb"), build_test_pdb_file()).expect("Expected PDB to be written.");
+
+        let pdb_symbols = PdbSymbolLoader::new(PdbFileLocator::new(vec![temp_directory.path().to_path_buf()]))
+            .load(&code_view_pdb_reference, None)
+            .expect("Expected PDB to be found in the symbol store.");
+
+        assert_eq!(pdb_symbols.global_variables[0].display_name, "Game::g_player");
+        assert_eq!(pdb_symbols.functions[0].display_name, "Game::tick");
+    }
+
+    #[test]
+    fn load_skips_pdb_from_a_different_build() {
+        let temp_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let module_file_path = temp_directory.path().join("game.exe");
+        let code_view_pdb_reference = CodeViewPdbReference {
+            guid: TEST_PDB_GUID,
+            age: TEST_PDB_AGE + 1,
+            pdb_path: String::from("C:\\build\\game.pdb"),
+        };
+
+        std::fs::write(temp_directory.path().join("game.pdb"), build_test_pdb_file()).expect("Expected PDB to be written.");
+
+        assert!(
+            PdbSymbolLoader::new(PdbFileLocator::new(Vec::new()))
+                .load(&code_view_pdb_reference, Some(&module_file_path))
+                .is_none()
+        );
+    }
+}
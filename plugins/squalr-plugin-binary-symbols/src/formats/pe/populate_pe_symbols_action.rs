@@ -1,3 +1,4 @@
+use crate::formats::pe::pdb::{CodeViewPdbReference, PdbSymbolLoader, PdbSymbols};
 use squalr_engine_api::{
     plugins::{
         PluginPermission,
@@ -54,6 +55,14 @@ const DOS_STUB_OFFSET: u64 = DOS_HEADER_SIZE_IN_BYTES;
 const PE_SECTION_HEADERS_OFFSET_FROM_NT_HEADERS: u64 = IMAGE_NT_SIGNATURE_SIZE_IN_BYTES + IMAGE_FILE_HEADER_SIZE_IN_BYTES;
 const PE32_OPTIONAL_HEADER_MAGIC: u16 = 0x10B;
 const PE64_OPTIONAL_HEADER_MAGIC: u16 = 0x20B;
+const PE32_DATA_DIRECTORIES_OFFSET: u64 = 96;
+const PE64_DATA_DIRECTORIES_OFFSET: u64 = 112;
+const IMAGE_DATA_DIRECTORY_SIZE_IN_BYTES: u64 = 8;
+const IMAGE_DIRECTORY_ENTRY_DEBUG: u64 = 6;
+const IMAGE_DEBUG_DIRECTORY_SIZE_IN_BYTES: u64 = 28;
+const IMAGE_DEBUG_TYPE_CODEVIEW: u32 = 2;
+const MAX_DEBUG_DIRECTORY_ENTRY_COUNT: u64 = 32;
+const MAX_CODE_VIEW_RECORD_SIZE: u64 = 0x1000;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum PeOptionalHeaderKind {
@@ -126,12 +135,19 @@ impl SymbolTreeAction for PopulatePeSymbolsAction {
         let module_name = module_name.clone();
         let module_name_for_update = module_name.clone();
         let pe_header_layout = analyze_pe_header_layout(services.process_memory(), &module_name)?;
+        let pdb_symbols = load_pdb_symbols(services.process_memory(), &module_name, &pe_header_layout);
         let data_type_size_by_id = collect_data_type_size_by_id(services.data_type_registry());
 
         services.symbol_store().write_catalog(
             "populate PE symbols",
             Box::new(move |project_symbol_catalog| {
-                populate_pe_symbols(project_symbol_catalog, &module_name_for_update, &pe_header_layout, &data_type_size_by_id)
+                populate_pe_symbols(
+                    project_symbol_catalog,
+                    &module_name_for_update,
+                    &pe_header_layout,
+                    pdb_symbols.as_ref(),
+                    &data_type_size_by_id,
+                )
             }),
         )?;
         services.symbol_tree_window().request_refresh();
@@ -143,15 +159,110 @@ impl SymbolTreeAction for PopulatePeSymbolsAction {
     }
 }
 
+/// Debug info is optional. Modules without a CodeView record, or whose PDB is not available locally, still get their
+/// header symbols.
+fn load_pdb_symbols(
+    process_memory_store: &dyn ProcessMemoryStore,
+    module_name: &str,
+    pe_header_layout: &PeHeaderLayout,
+) -> Option<PdbSymbols> {
+    let code_view_pdb_reference = read_code_view_pdb_reference(process_memory_store, module_name, pe_header_layout).ok()??;
+    let module_file_path = process_memory_store
+        .resolve_module_file_path(module_name)
+        .ok()
+        .flatten();
+
+    PdbSymbolLoader::default().load(&code_view_pdb_reference, module_file_path.as_deref())
+}
+
+/// Walks the debug data directory of the mapped image for its CodeView entry. Loaded modules are addressed by RVA, so
+/// entries are read through `AddressOfRawData` rather than their file offset.
+fn read_code_view_pdb_reference(
+    process_memory_store: &dyn ProcessMemoryStore,
+    module_name: &str,
+    pe_header_layout: &PeHeaderLayout,
+) -> Result<Option<CodeViewPdbReference>, String> {
+    let data_directories_offset = match pe_header_layout.optional_header_kind {
+        PeOptionalHeaderKind::Pe32 => PE32_DATA_DIRECTORIES_OFFSET,
+        PeOptionalHeaderKind::Pe64 => PE64_DATA_DIRECTORIES_OFFSET,
+    };
+    let debug_directory_entry_offset = data_directories_offset + IMAGE_DIRECTORY_ENTRY_DEBUG * IMAGE_DATA_DIRECTORY_SIZE_IN_BYTES;
+
+    if debug_directory_entry_offset + IMAGE_DATA_DIRECTORY_SIZE_IN_BYTES > pe_header_layout.size_of_optional_header {
+        return Ok(None);
+    }
+
+    let debug_data_directory_bytes = process_memory_store.read_module_bytes(
+        module_name,
+        pe_header_layout.pe_header_offset + PE_SECTION_HEADERS_OFFSET_FROM_NT_HEADERS + debug_directory_entry_offset,
+        IMAGE_DATA_DIRECTORY_SIZE_IN_BYTES,
+    )?;
+    let (Some(debug_directory_address), Some(debug_directory_size)) =
+        (read_u32_at(&debug_data_directory_bytes, 0), read_u32_at(&debug_data_directory_bytes, 4))
+    else {
+        return Err(String::from("Debug data directory is not readable."));
+    };
+
+    if debug_directory_address == 0 || debug_directory_size == 0 {
+        return Ok(None);
+    }
+
+    let debug_entry_count = (debug_directory_size as u64 / IMAGE_DEBUG_DIRECTORY_SIZE_IN_BYTES).min(MAX_DEBUG_DIRECTORY_ENTRY_COUNT);
+    let debug_directory_bytes = process_memory_store.read_module_bytes(
+        module_name,
+        debug_directory_address as u64,
+        debug_entry_count * IMAGE_DEBUG_DIRECTORY_SIZE_IN_BYTES,
+    )?;
+
+    for debug_entry_bytes in debug_directory_bytes.chunks_exact(IMAGE_DEBUG_DIRECTORY_SIZE_IN_BYTES as usize) {
+        let (Some(debug_type), Some(size_of_data), Some(address_of_raw_data)) = (
+            read_u32_at(debug_entry_bytes, 12),
+            read_u32_at(debug_entry_bytes, 16),
+            read_u32_at(debug_entry_bytes, 20),
+        ) else {
+            continue;
+        };
+
+        if debug_type != IMAGE_DEBUG_TYPE_CODEVIEW || address_of_raw_data == 0 {
+            continue;
+        }
+
+        let code_view_bytes =
+            process_memory_store.read_module_bytes(module_name, address_of_raw_data as u64, (size_of_data as u64).min(MAX_CODE_VIEW_RECORD_SIZE))?;
+
+        // Older toolchains emit `NB10` records that point at PDB 2.0 files, which are not supported.
+        if let Ok(code_view_pdb_reference) = CodeViewPdbReference::parse(&code_view_bytes) {
+            return Ok(Some(code_view_pdb_reference));
+        }
+    }
+
+    Ok(None)
+}
+
+fn read_u32_at(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<u32> {
+    let value_bytes = bytes.get(offset..offset.checked_add(4)?)?;
+
+    Some(u32::from_le_bytes([value_bytes[0], value_bytes[1], value_bytes[2], value_bytes[3]]))
+}
+
 fn populate_pe_symbols(
     project_symbol_catalog: &mut ProjectSymbolCatalog,
     module_name: &str,
     pe_header_layout: &PeHeaderLayout,
+    pdb_symbols: Option<&PdbSymbols>,
     data_type_size_by_id: &BTreeMap<String, u64>,
 ) -> Result<(), String> {
     upsert_pe_symbolic_resolver_descriptors(project_symbol_catalog);
     upsert_pe_struct_layout_descriptors(project_symbol_catalog)?;
-    upsert_pe_module_fields(project_symbol_catalog, module_name, pe_header_layout, data_type_size_by_id)
+
+    if let Some(pdb_symbols) = pdb_symbols {
+        upsert_pdb_struct_layout_descriptors(project_symbol_catalog, pdb_symbols);
+    }
+
+    upsert_pe_module_fields(project_symbol_catalog, module_name, pe_header_layout, pdb_symbols, data_type_size_by_id)
 }
 
 fn upsert_pe_symbolic_resolver_descriptors(project_symbol_catalog: &mut ProjectSymbolCatalog) {
@@ -271,6 +382,19 @@ fn upsert_pe_struct_layout_descriptors(project_symbol_catalog: &mut ProjectSymbo
     Ok(())
 }
 
+fn upsert_pdb_struct_layout_descriptors(
+    project_symbol_catalog: &mut ProjectSymbolCatalog,
+    pdb_symbols: &PdbSymbols,
+) {
+    let mut struct_layout_descriptors = project_symbol_catalog.get_struct_layout_descriptors().to_vec();
+
+    for struct_layout_descriptor in &pdb_symbols.struct_layout_descriptors {
+        upsert_struct_layout_descriptor(&mut struct_layout_descriptors, struct_layout_descriptor.clone());
+    }
+
+    project_symbol_catalog.set_struct_layout_descriptors(struct_layout_descriptors);
+}
+
 fn upsert_struct_layout_descriptor(
     struct_layout_descriptors: &mut Vec<StructLayoutDescriptor>,
     new_struct_layout_descriptor: StructLayoutDescriptor,
@@ -290,9 +414,10 @@ fn upsert_pe_module_fields(
     project_symbol_catalog: &mut ProjectSymbolCatalog,
     module_name: &str,
     pe_header_layout: &PeHeaderLayout,
+    pdb_symbols: Option<&PdbSymbols>,
     data_type_size_by_id: &BTreeMap<String, u64>,
 ) -> Result<(), String> {
-    let desired_module_fields = build_desired_pe_module_fields(pe_header_layout)?;
+    let desired_module_fields = build_desired_pe_module_fields(pe_header_layout, pdb_symbols)?;
     let minimum_size = desired_module_fields
         .iter()
         .filter_map(|desired_module_field| {
@@ -318,7 +443,10 @@ fn upsert_pe_module_fields(
     upsert_pe_module_root_layout_fields(project_symbol_catalog, module_name, &desired_module_fields, module_size, data_type_size_by_id)
 }
 
-fn build_desired_pe_module_fields(pe_header_layout: &PeHeaderLayout) -> Result<Vec<DesiredModuleField>, String> {
+fn build_desired_pe_module_fields(
+    pe_header_layout: &PeHeaderLayout,
+    pdb_symbols: Option<&PdbSymbols>,
+) -> Result<Vec<DesiredModuleField>, String> {
     let nt_headers_size = IMAGE_NT_SIGNATURE_SIZE_IN_BYTES
         .checked_add(IMAGE_FILE_HEADER_SIZE_IN_BYTES)
         .and_then(|header_prefix_size| header_prefix_size.checked_add(pe_header_layout.size_of_optional_header))
@@ -335,12 +463,66 @@ fn build_desired_pe_module_fields(pe_header_layout: &PeHeaderLayout) -> Result<V
         .checked_add(section_headers_size)
         .ok_or_else(|| String::from("PE headers size is too large."))?;
 
-    Ok(vec![DesiredModuleField {
+    let mut desired_module_fields = vec![DesiredModuleField {
         display_name: String::from("PE Headers"),
         offset: 0,
         struct_layout_id: pe_header_layout.pe_headers_struct_layout_id().to_string(),
         size_in_bytes: pe_headers_size,
-    }])
+    }];
+
+    if let Some(pdb_symbols) = pdb_symbols {
+        desired_module_fields.extend(build_pdb_module_fields(pe_headers_size, pdb_symbols));
+    }
+
+    Ok(desired_module_fields)
+}
+
+/// Global variables keep their PDB type, and functions cover their code range as a byte array. Symbols that would
+/// overlap the PE headers or an earlier symbol are dropped, since module fields cannot overlap.
+fn build_pdb_module_fields(
+    pe_headers_size: u64,
+    pdb_symbols: &PdbSymbols,
+) -> Vec<DesiredModuleField> {
+    let global_variable_fields = pdb_symbols
+        .global_variables
+        .iter()
+        .map(|global_variable| DesiredModuleField {
+            display_name: global_variable.display_name.clone(),
+            offset: global_variable.relative_virtual_address,
+            struct_layout_id: global_variable.symbol_type_id.clone(),
+            size_in_bytes: global_variable.size_in_bytes,
+        });
+    let function_fields = pdb_symbols.functions.iter().map(|function| DesiredModuleField {
+        display_name: function.display_name.clone(),
+        offset: function.relative_virtual_address,
+        struct_layout_id: format!("u8[{}]", function.size_in_bytes),
+        size_in_bytes: function.size_in_bytes,
+    });
+    let mut candidate_module_fields = global_variable_fields
+        .chain(function_fields)
+        .collect::<Vec<_>>();
+    let mut next_free_offset = pe_headers_size;
+    let mut pdb_module_fields = Vec::new();
+
+    candidate_module_fields.sort_by_key(|candidate_module_field| candidate_module_field.offset);
+
+    for candidate_module_field in candidate_module_fields {
+        let Some(field_end_offset) = candidate_module_field
+            .offset
+            .checked_add(candidate_module_field.size_in_bytes)
+        else {
+            continue;
+        };
+
+        if candidate_module_field.offset < next_free_offset {
+            continue;
+        }
+
+        next_free_offset = field_end_offset;
+        pdb_module_fields.push(candidate_module_field);
+    }
+
+    pdb_module_fields
 }
 
 fn upsert_pe_module_fields_in_module(
@@ -1048,6 +1230,7 @@ mod tests {
         PeHeaderLayout, PeOptionalHeaderKind, PopulatePeSymbolsAction, STRING_UTF8_NULL_TERMINATED_DATA_TYPE_ID, SymbolicResolverRelativeSymbolPath,
         populate_pe_symbols,
     };
+    use crate::formats::pe::pdb::{PdbFunction, PdbGlobalVariable, PdbSymbols};
     use squalr_engine_api::{
         plugins::symbol_tree::symbol_tree_action::{
             DataTypeRegistryStore, ProcessMemoryStore, ProjectSymbolStore, SymbolTreeAction, SymbolTreeActionContext, SymbolTreeActionSelection,
@@ -1314,8 +1497,14 @@ mod tests {
             optional_header_kind: PeOptionalHeaderKind::Pe32,
         };
 
-        populate_pe_symbols(&mut project_symbol_catalog, "game.exe", &pe_header_layout, &default_data_type_size_by_id())
-            .expect("Expected PE symbol population to replace u8[] root field.");
+        populate_pe_symbols(
+            &mut project_symbol_catalog,
+            "game.exe",
+            &pe_header_layout,
+            None,
+            &default_data_type_size_by_id(),
+        )
+        .expect("Expected PE symbol population to replace u8[] root field.");
 
         let fields = project_symbol_catalog
             .find_symbol_module("game.exe")
@@ -1347,8 +1536,14 @@ mod tests {
             optional_header_kind: PeOptionalHeaderKind::Pe32,
         };
 
-        populate_pe_symbols(&mut project_symbol_catalog, "game.exe", &pe_header_layout, &default_data_type_size_by_id())
-            .expect("Expected PE symbol population to stomp conflicting fields.");
+        populate_pe_symbols(
+            &mut project_symbol_catalog,
+            "game.exe",
+            &pe_header_layout,
+            None,
+            &default_data_type_size_by_id(),
+        )
+        .expect("Expected PE symbol population to stomp conflicting fields.");
 
         let fields = project_symbol_catalog
             .find_symbol_module("game.exe")
@@ -1394,8 +1589,14 @@ mod tests {
             optional_header_kind: PeOptionalHeaderKind::Pe32,
         };
 
-        populate_pe_symbols(&mut project_symbol_catalog, "game.exe", &pe_header_layout, &default_data_type_size_by_id())
-            .expect("Expected PE symbol population to update the module root layout.");
+        populate_pe_symbols(
+            &mut project_symbol_catalog,
+            "game.exe",
+            &pe_header_layout,
+            None,
+            &default_data_type_size_by_id(),
+        )
+        .expect("Expected PE symbol population to update the module root layout.");
 
         let module_root_layout_descriptor = project_symbol_catalog
             .get_struct_layout_descriptors()
@@ -1414,6 +1615,77 @@ mod tests {
         assert_eq!(module_root_fields[3].get_unassigned_size_in_bytes(), Some(0x6000 - 0x579D));
     }
 
+    #[test]
+    fn populate_pe_symbols_adds_pdb_globals_and_functions() {
+        let mut project_symbol_catalog =
+            ProjectSymbolCatalog::new_with_modules_and_symbol_claims(vec![ProjectSymbolModule::new(String::from("game.exe"), 0x2000)], Vec::new(), Vec::new());
+        let pe_header_layout = PeHeaderLayout {
+            pe_header_offset: 0x80,
+            size_of_optional_header: 0xE0,
+            number_of_sections: 3,
+            optional_header_kind: PeOptionalHeaderKind::Pe32,
+        };
+        let pdb_symbols = PdbSymbols {
+            struct_layout_descriptors: vec![StructLayoutDescriptor::new(
+                String::from("Game.Player"),
+                SymbolicStructDefinition::new(String::from("Game.Player"), Vec::new()).with_declared_size_in_bytes(Some(0x18)),
+            )],
+            global_variables: vec![PdbGlobalVariable {
+                display_name: String::from("Game::g_player"),
+                relative_virtual_address: 0x3100,
+                symbol_type_id: String::from("Game.Player"),
+                size_in_bytes: 0x18,
+            }],
+            functions: vec![
+                PdbFunction {
+                    display_name: String::from("Game::tick"),
+                    relative_virtual_address: 0x1010,
+                    size_in_bytes: 0x40,
+                },
+                PdbFunction {
+                    display_name: String::from("Game::tick_thunk"),
+                    relative_virtual_address: 0x1020,
+                    size_in_bytes: 0x10,
+                },
+            ],
+        };
+
+        populate_pe_symbols(
+            &mut project_symbol_catalog,
+            "game.exe",
+            &pe_header_layout,
+            Some(&pdb_symbols),
+            &default_data_type_size_by_id(),
+        )
+        .expect("Expected PE symbol population to add PDB symbols.");
+
+        let symbol_module = project_symbol_catalog
+            .find_symbol_module("game.exe")
+            .expect("Expected module to exist.");
+        let module_fields = symbol_module
+            .get_fields()
+            .iter()
+            .map(|module_field| {
+                (
+                    module_field.get_display_name().to_string(),
+                    module_field.get_offset(),
+                    module_field.get_struct_layout_id().to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert!(project_symbol_catalog.contains_struct_layout_id("Game.Player"));
+        assert_eq!(
+            module_fields,
+            vec![
+                (String::from("PE Headers"), 0, String::from(PE_HEADERS32_ID)),
+                (String::from("Game::tick"), 0x1010, String::from("u8[64]")),
+                (String::from("Game::g_player"), 0x3100, String::from("Game.Player")),
+            ]
+        );
+        assert!(symbol_module.get_size() >= 0x3118);
+    }
+
     fn build_test_pe_header_bytes() -> Vec<u8> {
         let mut header_bytes = vec![0_u8; 0x1000];
         header_bytes[0..2].copy_from_slice(b"MZ");
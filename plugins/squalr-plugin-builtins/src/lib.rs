@@ -3,19 +3,31 @@ use std::sync::Arc;
 use squalr_engine_api::plugins::PluginPackage;
 use squalr_plugin_binary_symbols::BinarySymbolsPlugin;
 use squalr_plugin_data_types_24bit::TwentyFourBitDataTypesPlugin;
+use squalr_plugin_il2cpp_symbols::Il2CppSymbolsPlugin;
 use squalr_plugin_instructions_arm::ArmFamilyInstructionsPlugin;
+use squalr_plugin_instructions_mips::MipsFamilyInstructionsPlugin;
 use squalr_plugin_instructions_powerpc::PowerPcFamilyInstructionsPlugin;
 use squalr_plugin_instructions_x86::X86FamilyInstructionsPlugin;
 use squalr_plugin_memory_view_dolphin::DolphinMemoryViewPlugin;
+use squalr_plugin_memory_view_libretro::LibretroMemoryViewPlugin;
+use squalr_plugin_memory_view_playstation::PlayStationMemoryViewPlugin;
+use squalr_plugin_mono_symbols::MonoSymbolsPlugin;
+use squalr_plugin_unreal_symbols::UnrealSymbolsPlugin;
 
 pub fn get_builtin_plugin_packages() -> Vec<Arc<dyn PluginPackage>> {
     vec![
         Arc::new(DolphinMemoryViewPlugin::new()),
+        Arc::new(PlayStationMemoryViewPlugin::new()),
+        Arc::new(LibretroMemoryViewPlugin::new()),
         Arc::new(TwentyFourBitDataTypesPlugin::new()),
         Arc::new(ArmFamilyInstructionsPlugin::new()),
         Arc::new(PowerPcFamilyInstructionsPlugin::new()),
+        Arc::new(MipsFamilyInstructionsPlugin::new()),
         Arc::new(X86FamilyInstructionsPlugin::new()),
         Arc::new(BinarySymbolsPlugin::new()),
+        Arc::new(MonoSymbolsPlugin::new()),
+        Arc::new(Il2CppSymbolsPlugin::new()),
+        Arc::new(UnrealSymbolsPlugin::new()),
     ]
 }
 
@@ -39,6 +51,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn builtins_include_playstation_memory_view_plugin_package() {
+        let plugins = get_builtin_plugin_packages();
+        let plugin = plugins
+            .iter()
+            .find(|plugin| plugin.metadata().get_plugin_id() == "builtin.memory-view.playstation")
+            .expect("Expected the PlayStation memory-view package to be registered.");
+
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_capability(PluginCapability::MemoryView)
+        );
+    }
+
+    #[test]
+    fn builtins_include_libretro_memory_view_plugin_package() {
+        let plugins = get_builtin_plugin_packages();
+        let plugin = plugins
+            .iter()
+            .find(|plugin| plugin.metadata().get_plugin_id() == "builtin.memory-view.libretro")
+            .expect("Expected the libretro memory-view package to be registered.");
+
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_capability(PluginCapability::MemoryView)
+        );
+    }
+
     #[test]
     fn builtins_include_24_bit_data_type_plugin_package() {
         let plugins = get_builtin_plugin_packages();
@@ -114,6 +156,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn builtins_include_mips_family_instruction_plugin_package() {
+        let plugins = get_builtin_plugin_packages();
+        let plugin = plugins
+            .iter()
+            .find(|plugin| plugin.metadata().get_plugin_id() == "builtin.instruction-set.mips-family")
+            .expect("Expected the MIPS instruction package to be registered.");
+
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_capability(PluginCapability::InstructionSet)
+        );
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_capability(PluginCapability::DataType)
+        );
+    }
+
     #[test]
     fn builtins_include_binary_symbols_plugin_package() {
         let plugins = get_builtin_plugin_packages();
@@ -129,4 +191,52 @@ mod tests {
         );
         assert!(plugin.as_symbol_tree_plugin().is_some());
     }
+
+    #[test]
+    fn builtins_include_mono_symbols_plugin_package() {
+        let plugins = get_builtin_plugin_packages();
+        let plugin = plugins
+            .iter()
+            .find(|plugin| plugin.metadata().get_plugin_id() == "builtin.symbols.mono")
+            .expect("Expected the Mono symbols package to be registered.");
+
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_capability(PluginCapability::SymbolTree)
+        );
+        assert!(plugin.as_symbol_tree_plugin().is_some());
+    }
+
+    #[test]
+    fn builtins_include_il2cpp_symbols_plugin_package() {
+        let plugins = get_builtin_plugin_packages();
+        let plugin = plugins
+            .iter()
+            .find(|plugin| plugin.metadata().get_plugin_id() == "builtin.symbols.il2cpp")
+            .expect("Expected the IL2CPP symbols package to be registered.");
+
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_capability(PluginCapability::SymbolTree)
+        );
+        assert!(plugin.as_symbol_tree_plugin().is_some());
+    }
+
+    #[test]
+    fn builtins_include_unreal_symbols_plugin_package() {
+        let plugins = get_builtin_plugin_packages();
+        let plugin = plugins
+            .iter()
+            .find(|plugin| plugin.metadata().get_plugin_id() == "builtin.symbols.unreal")
+            .expect("Expected the Unreal symbols package to be registered.");
+
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_capability(PluginCapability::SymbolTree)
+        );
+        assert!(plugin.as_symbol_tree_plugin().is_some());
+    }
 }
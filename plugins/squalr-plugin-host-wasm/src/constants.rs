@@ -0,0 +1,29 @@
+/// File name of the manifest that describes a WebAssembly plugin within its plugin directory.
+pub const WASM_PLUGIN_MANIFEST_FILE_NAME: &str = "squalr-plugin.json";
+
+/// Import module that guests use to reach host calls.
+pub const WASM_PLUGIN_HOST_MODULE_NAME: &str = "squalr";
+
+/// Guest export used by the host to allocate buffers inside guest memory.
+pub const WASM_PLUGIN_ALLOC_EXPORT_NAME: &str = "squalr_alloc";
+/// Optional guest export that takes back host-allocated buffers once the host no longer needs them.
+pub const WASM_PLUGIN_FREE_EXPORT_NAME: &str = "squalr_free";
+pub const WASM_PLUGIN_MEMORY_EXPORT_NAME: &str = "memory";
+
+pub const WASM_MEMORY_VIEW_GET_PAGES_EXPORT_NAME: &str = "squalr_memory_view_get_pages";
+pub const WASM_MEMORY_VIEW_GET_MODULES_EXPORT_NAME: &str = "squalr_memory_view_get_modules";
+pub const WASM_MEMORY_VIEW_READ_EXPORT_NAME: &str = "squalr_memory_view_read";
+pub const WASM_MEMORY_VIEW_WRITE_EXPORT_NAME: &str = "squalr_memory_view_write";
+
+/// Instruction budget for a single guest call, so a runaway plugin traps instead of hanging the engine.
+pub const WASM_PLUGIN_CALL_FUEL: u64 = 1_000_000_000;
+
+/// Upper bound on the linear memory a single plugin instance may grow to.
+pub const WASM_PLUGIN_MAX_MEMORY_BYTES: usize = 256 * 1024 * 1024;
+
+/// Upper bound on the bytes a single host call reads from the target on behalf of a plugin, since the host buffers the
+/// read outside of the plugin's own memory limit.
+pub const WASM_PLUGIN_MAX_HOST_READ_BYTES: usize = 16 * 1024 * 1024;
+
+/// Plugin id prefix reserved for plugins compiled into Squalr.
+pub const BUILT_IN_PLUGIN_ID_PREFIX: &str = "builtin.";
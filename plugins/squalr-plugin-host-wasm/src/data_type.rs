@@ -0,0 +1,322 @@
+use crate::{manifest::WasmDataTypeManifest, runtime::WasmPluginSession};
+use squalr_engine_api::structures::{
+    data_types::{
+        comparisons::{scalar_comparable::ScalarComparable, vector_comparable::VectorComparable},
+        data_type::DataType,
+        data_type_error::DataTypeError,
+        data_type_ref::DataTypeRef,
+        data_type_scan_preference::DataTypeScanPreference,
+    },
+    data_values::{
+        anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat, container_type::ContainerType,
+        data_value::DataValue,
+    },
+    memory::endian::Endian,
+    scanning::{
+        comparisons::{
+            scan_function_scalar::{ScalarCompareFnDelta, ScalarCompareFnImmediate, ScalarCompareFnRelative},
+            scan_function_vector::{
+                VectorCompareFnDelta16, VectorCompareFnDelta32, VectorCompareFnDelta64, VectorCompareFnImmediate16, VectorCompareFnImmediate32,
+                VectorCompareFnImmediate64, VectorCompareFnRelative16, VectorCompareFnRelative32, VectorCompareFnRelative64,
+            },
+        },
+        constraints::scan_constraint::ScanConstraint,
+    },
+};
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+/// A plugin data type that shares the byte layout of a built-in storage type. Scans run the storage type's comparisons
+/// natively, and only the `String` display format round-trips through the guest.
+pub(crate) struct WasmDataType {
+    data_type_id: String,
+    icon_id: String,
+    storage_data_type: Arc<dyn DataType>,
+    format_export: Option<String>,
+    parse_export: Option<String>,
+    session: Option<Mutex<WasmPluginSession>>,
+}
+
+impl WasmDataType {
+    pub(crate) fn new(
+        data_type_manifest: &WasmDataTypeManifest,
+        storage_data_type: Arc<dyn DataType>,
+        session: Option<WasmPluginSession>,
+    ) -> Self {
+        Self {
+            data_type_id: data_type_manifest.data_type_id.clone(),
+            icon_id: data_type_manifest
+                .icon_id
+                .clone()
+                .unwrap_or_else(|| storage_data_type.get_icon_id().to_string()),
+            storage_data_type,
+            format_export: data_type_manifest.format_export.clone(),
+            parse_export: data_type_manifest.parse_export.clone(),
+            session: session.map(Mutex::new),
+        }
+    }
+
+    fn call_guest(
+        &self,
+        export_name: &str,
+        input: &[u8],
+    ) -> Result<Vec<u8>, DataTypeError> {
+        let Some(session) = self.session.as_ref() else {
+            return Err(DataTypeError::DecodingError {
+                error: format!("Data type `{}` has no plugin instance.", self.data_type_id),
+            });
+        };
+        let mut session = session.lock().map_err(|error| DataTypeError::DecodingError {
+            error: format!("Failed to lock plugin instance for `{}`: {}", self.data_type_id, error),
+        })?;
+
+        session
+            .call_with_buffer(export_name, input)
+            .map_err(|error| DataTypeError::DecodingError { error })
+    }
+
+    fn rebrand(
+        &self,
+        data_value: DataValue,
+    ) -> DataValue {
+        let mut data_value = data_value;
+        data_value.set_data_type_in_place(self.get_ref());
+
+        data_value
+    }
+}
+
+impl fmt::Debug for WasmDataType {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        formatter
+            .debug_struct("WasmDataType")
+            .field("data_type_id", &self.data_type_id)
+            .field("storage_data_type", &self.storage_data_type.get_data_type_id())
+            .finish()
+    }
+}
+
+impl DataType for WasmDataType {
+    fn get_data_type_id(&self) -> &str {
+        &self.data_type_id
+    }
+
+    fn get_icon_id(&self) -> &str {
+        &self.icon_id
+    }
+
+    fn get_unit_size_in_bytes(&self) -> u64 {
+        self.storage_data_type.get_unit_size_in_bytes()
+    }
+
+    fn validate_value_string(
+        &self,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> bool {
+        self.deanonymize_value_string(anonymous_value_string).is_ok()
+    }
+
+    fn deanonymize_value_string(
+        &self,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> Result<DataValue, DataTypeError> {
+        match (&self.parse_export, anonymous_value_string.get_anonymous_value_string_format()) {
+            (Some(parse_export), AnonymousValueStringFormat::String) => {
+                let value_bytes = self.call_guest(parse_export, anonymous_value_string.get_anonymous_value_string().as_bytes())?;
+                let expected_byte_count = self.get_unit_size_in_bytes();
+
+                if value_bytes.len() as u64 != expected_byte_count {
+                    return Err(DataTypeError::InvalidByteCount {
+                        expected: expected_byte_count,
+                        actual: value_bytes.len() as u64,
+                    });
+                }
+
+                Ok(DataValue::new(self.get_ref(), value_bytes))
+            }
+            _ => Ok(self.rebrand(
+                self.storage_data_type
+                    .deanonymize_value_string(anonymous_value_string)?,
+            )),
+        }
+    }
+
+    fn anonymize_value_bytes(
+        &self,
+        value_bytes: &[u8],
+        anonymous_value_string_format: AnonymousValueStringFormat,
+    ) -> Result<AnonymousValueString, DataTypeError> {
+        match (&self.format_export, anonymous_value_string_format) {
+            (Some(format_export), AnonymousValueStringFormat::String) => {
+                let formatted_bytes = self.call_guest(format_export, value_bytes)?;
+                let formatted_value = String::from_utf8(formatted_bytes).map_err(|error| DataTypeError::DecodingError { error: error.to_string() })?;
+
+                Ok(AnonymousValueString::new(
+                    formatted_value,
+                    AnonymousValueStringFormat::String,
+                    ContainerType::None,
+                ))
+            }
+            _ => self
+                .storage_data_type
+                .anonymize_value_bytes(value_bytes, anonymous_value_string_format),
+        }
+    }
+
+    fn get_supported_anonymous_value_string_formats(&self) -> Vec<AnonymousValueStringFormat> {
+        let mut supported_formats = self
+            .storage_data_type
+            .get_supported_anonymous_value_string_formats();
+
+        if self.format_export.is_some() && !supported_formats.contains(&AnonymousValueStringFormat::String) {
+            supported_formats.insert(0, AnonymousValueStringFormat::String);
+        }
+
+        supported_formats
+    }
+
+    fn get_default_anonymous_value_string_format(&self) -> AnonymousValueStringFormat {
+        if self.format_export.is_some() {
+            AnonymousValueStringFormat::String
+        } else {
+            self.storage_data_type
+                .get_default_anonymous_value_string_format()
+        }
+    }
+
+    fn get_endian(&self) -> Endian {
+        self.storage_data_type.get_endian()
+    }
+
+    fn is_floating_point(&self) -> bool {
+        self.storage_data_type.is_floating_point()
+    }
+
+    fn is_signed(&self) -> bool {
+        self.storage_data_type.is_signed()
+    }
+
+    fn supports_scalar_integer_values(&self) -> bool {
+        self.storage_data_type.supports_scalar_integer_values()
+    }
+
+    fn get_default_value(
+        &self,
+        data_type_ref: DataTypeRef,
+    ) -> DataValue {
+        self.storage_data_type.get_default_value(data_type_ref)
+    }
+
+    fn get_scan_preference(&self) -> DataTypeScanPreference {
+        self.storage_data_type.get_scan_preference()
+    }
+}
+
+/// Forwards comparison lookups to the storage type, which already knows how to compare the shared byte layout.
+macro_rules! delegate_comparisons_to_storage_data_type {
+    ($($compare_fn:ident -> $compare_fn_type:ty;)*) => {
+        $(
+            fn $compare_fn(
+                &self,
+                scan_constraint: &ScanConstraint,
+            ) -> Option<$compare_fn_type> {
+                self.storage_data_type.$compare_fn(scan_constraint)
+            }
+        )*
+    };
+}
+
+impl ScalarComparable for WasmDataType {
+    delegate_comparisons_to_storage_data_type! {
+        get_compare_equal -> ScalarCompareFnImmediate;
+        get_compare_not_equal -> ScalarCompareFnImmediate;
+        get_compare_greater_than -> ScalarCompareFnImmediate;
+        get_compare_greater_than_or_equal -> ScalarCompareFnImmediate;
+        get_compare_less_than -> ScalarCompareFnImmediate;
+        get_compare_less_than_or_equal -> ScalarCompareFnImmediate;
+        get_compare_changed -> ScalarCompareFnRelative;
+        get_compare_unchanged -> ScalarCompareFnRelative;
+        get_compare_increased -> ScalarCompareFnRelative;
+        get_compare_decreased -> ScalarCompareFnRelative;
+        get_compare_increased_by -> ScalarCompareFnDelta;
+        get_compare_decreased_by -> ScalarCompareFnDelta;
+        get_compare_multiplied_by -> ScalarCompareFnDelta;
+        get_compare_divided_by -> ScalarCompareFnDelta;
+        get_compare_modulo_by -> ScalarCompareFnDelta;
+        get_compare_shift_left_by -> ScalarCompareFnDelta;
+        get_compare_shift_right_by -> ScalarCompareFnDelta;
+        get_compare_logical_and_by -> ScalarCompareFnDelta;
+        get_compare_logical_or_by -> ScalarCompareFnDelta;
+        get_compare_logical_xor_by -> ScalarCompareFnDelta;
+    }
+}
+
+impl VectorComparable for WasmDataType {
+    delegate_comparisons_to_storage_data_type! {
+        get_vector_compare_equal_64 -> VectorCompareFnImmediate64;
+        get_vector_compare_equal_32 -> VectorCompareFnImmediate32;
+        get_vector_compare_equal_16 -> VectorCompareFnImmediate16;
+        get_vector_compare_not_equal_64 -> VectorCompareFnImmediate64;
+        get_vector_compare_not_equal_32 -> VectorCompareFnImmediate32;
+        get_vector_compare_not_equal_16 -> VectorCompareFnImmediate16;
+        get_vector_compare_greater_than_64 -> VectorCompareFnImmediate64;
+        get_vector_compare_greater_than_32 -> VectorCompareFnImmediate32;
+        get_vector_compare_greater_than_16 -> VectorCompareFnImmediate16;
+        get_vector_compare_greater_than_or_equal_64 -> VectorCompareFnImmediate64;
+        get_vector_compare_greater_than_or_equal_32 -> VectorCompareFnImmediate32;
+        get_vector_compare_greater_than_or_equal_16 -> VectorCompareFnImmediate16;
+        get_vector_compare_less_than_64 -> VectorCompareFnImmediate64;
+        get_vector_compare_less_than_32 -> VectorCompareFnImmediate32;
+        get_vector_compare_less_than_16 -> VectorCompareFnImmediate16;
+        get_vector_compare_less_than_or_equal_64 -> VectorCompareFnImmediate64;
+        get_vector_compare_less_than_or_equal_32 -> VectorCompareFnImmediate32;
+        get_vector_compare_less_than_or_equal_16 -> VectorCompareFnImmediate16;
+        get_vector_compare_changed_64 -> VectorCompareFnRelative64;
+        get_vector_compare_changed_32 -> VectorCompareFnRelative32;
+        get_vector_compare_changed_16 -> VectorCompareFnRelative16;
+        get_vector_compare_unchanged_64 -> VectorCompareFnRelative64;
+        get_vector_compare_unchanged_32 -> VectorCompareFnRelative32;
+        get_vector_compare_unchanged_16 -> VectorCompareFnRelative16;
+        get_vector_compare_increased_64 -> VectorCompareFnRelative64;
+        get_vector_compare_increased_32 -> VectorCompareFnRelative32;
+        get_vector_compare_increased_16 -> VectorCompareFnRelative16;
+        get_vector_compare_decreased_64 -> VectorCompareFnRelative64;
+        get_vector_compare_decreased_32 -> VectorCompareFnRelative32;
+        get_vector_compare_decreased_16 -> VectorCompareFnRelative16;
+        get_vector_compare_increased_by_64 -> VectorCompareFnDelta64;
+        get_vector_compare_increased_by_32 -> VectorCompareFnDelta32;
+        get_vector_compare_increased_by_16 -> VectorCompareFnDelta16;
+        get_vector_compare_decreased_by_64 -> VectorCompareFnDelta64;
+        get_vector_compare_decreased_by_32 -> VectorCompareFnDelta32;
+        get_vector_compare_decreased_by_16 -> VectorCompareFnDelta16;
+        get_vector_compare_multiplied_by_64 -> VectorCompareFnDelta64;
+        get_vector_compare_multiplied_by_32 -> VectorCompareFnDelta32;
+        get_vector_compare_multiplied_by_16 -> VectorCompareFnDelta16;
+        get_vector_compare_divided_by_64 -> VectorCompareFnDelta64;
+        get_vector_compare_divided_by_32 -> VectorCompareFnDelta32;
+        get_vector_compare_divided_by_16 -> VectorCompareFnDelta16;
+        get_vector_compare_modulo_by_64 -> VectorCompareFnDelta64;
+        get_vector_compare_modulo_by_32 -> VectorCompareFnDelta32;
+        get_vector_compare_modulo_by_16 -> VectorCompareFnDelta16;
+        get_vector_compare_shift_left_by_64 -> VectorCompareFnDelta64;
+        get_vector_compare_shift_left_by_32 -> VectorCompareFnDelta32;
+        get_vector_compare_shift_left_by_16 -> VectorCompareFnDelta16;
+        get_vector_compare_shift_right_by_64 -> VectorCompareFnDelta64;
+        get_vector_compare_shift_right_by_32 -> VectorCompareFnDelta32;
+        get_vector_compare_shift_right_by_16 -> VectorCompareFnDelta16;
+        get_vector_compare_logical_and_by_64 -> VectorCompareFnDelta64;
+        get_vector_compare_logical_and_by_32 -> VectorCompareFnDelta32;
+        get_vector_compare_logical_and_by_16 -> VectorCompareFnDelta16;
+        get_vector_compare_logical_or_by_64 -> VectorCompareFnDelta64;
+        get_vector_compare_logical_or_by_32 -> VectorCompareFnDelta32;
+        get_vector_compare_logical_or_by_16 -> VectorCompareFnDelta16;
+        get_vector_compare_logical_xor_by_64 -> VectorCompareFnDelta64;
+        get_vector_compare_logical_xor_by_32 -> VectorCompareFnDelta32;
+        get_vector_compare_logical_xor_by_16 -> VectorCompareFnDelta16;
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{constants::WASM_PLUGIN_MANIFEST_FILE_NAME, manifest::WasmPluginManifest, plugin::WasmPluginPackage, runtime::WasmPluginRuntime};
+use squalr_engine_api::plugins::{Plugin, PluginPackage};
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use wasmtime::Engine;
+
+/// Discovers third-party WebAssembly plugins. Each plugin lives in its own subdirectory of the plugins directory, holding a
+/// `squalr-plugin.json` manifest next to the `.wasm` module it references.
+pub struct WasmPluginHost {
+    engine: Engine,
+}
+
+impl WasmPluginHost {
+    pub fn new() -> Result<Self, String> {
+        Ok(Self {
+            engine: WasmPluginRuntime::create_engine()?,
+        })
+    }
+
+    /// Gets the per-user directory that third-party plugins are installed into.
+    pub fn get_default_plugins_directory() -> Option<PathBuf> {
+        dirs::data_local_dir().map(|mut plugins_directory| {
+            plugins_directory.push("Squalr");
+            plugins_directory.push("plugins");
+            plugins_directory
+        })
+    }
+
+    /// Loads every valid plugin under the plugins directory. Plugins that fail to load are logged and skipped, so one
+    /// broken plugin does not take the others down with it.
+    pub fn discover_plugin_packages(
+        &self,
+        plugins_directory: &Path,
+    ) -> Vec<Arc<dyn PluginPackage>> {
+        let Ok(directory_entries) = fs::read_dir(plugins_directory) else {
+            return Vec::new();
+        };
+        let mut plugin_directories = directory_entries
+            .filter_map(|directory_entry| {
+                directory_entry
+                    .ok()
+                    .map(|directory_entry| directory_entry.path())
+            })
+            .filter(|plugin_directory| plugin_directory.join(WASM_PLUGIN_MANIFEST_FILE_NAME).is_file())
+            .collect::<Vec<_>>();
+        plugin_directories.sort();
+
+        let mut plugin_ids = HashSet::new();
+        let mut plugin_packages: Vec<Arc<dyn PluginPackage>> = Vec::new();
+
+        for plugin_directory in plugin_directories {
+            match self.load_plugin_package(&plugin_directory) {
+                Ok(plugin_package) => {
+                    if !plugin_ids.insert(plugin_package.metadata().get_plugin_id().to_string()) {
+                        log::warn!(
+                            "Ignoring plugin in {} because plugin id `{}` is already loaded.",
+                            plugin_directory.display(),
+                            plugin_package.metadata().get_plugin_id()
+                        );
+                        continue;
+                    }
+
+                    log::info!(
+                        "Loaded WebAssembly plugin `{}` from {}.",
+                        plugin_package.metadata().get_plugin_id(),
+                        plugin_directory.display()
+                    );
+                    plugin_packages.push(Arc::new(plugin_package));
+                }
+                Err(error) => log::error!("Failed to load WebAssembly plugin from {}: {}", plugin_directory.display(), error),
+            }
+        }
+
+        plugin_packages
+    }
+
+    pub fn load_plugin_package(
+        &self,
+        plugin_directory: &Path,
+    ) -> Result<WasmPluginPackage, String> {
+        let manifest = WasmPluginManifest::load(&plugin_directory.join(WASM_PLUGIN_MANIFEST_FILE_NAME))?;
+        let module_path = plugin_directory.join(&manifest.module_path);
+        let module_bytes = fs::read(&module_path).map_err(|error| format!("Failed to read plugin module {}: {}", module_path.display(), error))?;
+
+        self.load_plugin_package_from_bytes(manifest, &module_bytes)
+    }
+
+    pub fn load_plugin_package_from_bytes(
+        &self,
+        manifest: WasmPluginManifest,
+        module_bytes: &[u8],
+    ) -> Result<WasmPluginPackage, String> {
+        WasmPluginPackage::load(&self.engine, manifest, module_bytes)
+    }
+}
@@ -0,0 +1,565 @@
+use crate::constants::{WASM_PLUGIN_ALLOC_EXPORT_NAME, WASM_PLUGIN_HOST_MODULE_NAME, WASM_PLUGIN_MAX_HOST_READ_BYTES, WASM_PLUGIN_MEMORY_EXPORT_NAME};
+use squalr_engine_api::{plugins::PluginPermission, structures::projects::project_symbol_catalog::ProjectSymbolCatalog};
+use std::ops::Range;
+use wasmtime::{Caller, Engine, Extern, Linker, Module, StoreLimits};
+
+const HOST_CALL_SUCCEEDED: i32 = 0;
+const HOST_CALL_FAILED: i32 = -1;
+
+/// Functions the host exposes to guests through the `squalr` import module.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum WasmHostCall {
+    Log,
+    ReportError,
+    ReadSymbolCatalog,
+    WriteSymbolCatalog,
+    ReadModuleBytes,
+    ReadProcessMemory,
+    WriteProcessMemory,
+    RequestSymbolTreeRefresh,
+    FocusSymbolTreeNode,
+}
+
+impl WasmHostCall {
+    pub(crate) const ALL: [WasmHostCall; 9] = [
+        Self::Log,
+        Self::ReportError,
+        Self::ReadSymbolCatalog,
+        Self::WriteSymbolCatalog,
+        Self::ReadModuleBytes,
+        Self::ReadProcessMemory,
+        Self::WriteProcessMemory,
+        Self::RequestSymbolTreeRefresh,
+        Self::FocusSymbolTreeNode,
+    ];
+
+    pub(crate) fn import_name(&self) -> &'static str {
+        match self {
+            Self::Log => "log",
+            Self::ReportError => "report_error",
+            Self::ReadSymbolCatalog => "read_symbol_catalog",
+            Self::WriteSymbolCatalog => "write_symbol_catalog",
+            Self::ReadModuleBytes => "read_module_bytes",
+            Self::ReadProcessMemory => "read_process_memory",
+            Self::WriteProcessMemory => "write_process_memory",
+            Self::RequestSymbolTreeRefresh => "request_symbol_tree_refresh",
+            Self::FocusSymbolTreeNode => "focus_symbol_tree_node",
+        }
+    }
+
+    /// Gets the permission a guest must hold to make this call, if any.
+    pub(crate) fn required_permission(&self) -> Option<PluginPermission> {
+        match self {
+            Self::Log | Self::ReportError => None,
+            Self::ReadSymbolCatalog => Some(PluginPermission::ReadSymbolStore),
+            Self::WriteSymbolCatalog => Some(PluginPermission::WriteSymbolStore),
+            Self::ReadModuleBytes | Self::ReadProcessMemory => Some(PluginPermission::ReadProcessMemory),
+            Self::WriteProcessMemory => Some(PluginPermission::WriteProcessMemory),
+            Self::RequestSymbolTreeRefresh | Self::FocusSymbolTreeNode => Some(PluginPermission::WriteSymbolTreeWindow),
+        }
+    }
+
+    fn from_import_name(import_name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|host_call| host_call.import_name() == import_name)
+    }
+}
+
+/// Host-side implementation of the calls a guest can make. Each entry point supplies its own backend, so a call that
+/// makes no sense for that entry point (such as writing process memory while formatting a value) is simply unavailable.
+pub(crate) trait WasmPluginHostBackend: Send {
+    fn read_symbol_catalog(&self) -> Result<ProjectSymbolCatalog, String> {
+        Err(unavailable_host_call(WasmHostCall::ReadSymbolCatalog))
+    }
+
+    fn write_symbol_catalog(
+        &self,
+        _project_symbol_catalog: ProjectSymbolCatalog,
+    ) -> Result<(), String> {
+        Err(unavailable_host_call(WasmHostCall::WriteSymbolCatalog))
+    }
+
+    fn read_module_bytes(
+        &self,
+        _module_name: &str,
+        _offset: u64,
+        _length: u64,
+    ) -> Result<Vec<u8>, String> {
+        Err(unavailable_host_call(WasmHostCall::ReadModuleBytes))
+    }
+
+    fn read_process_memory(
+        &self,
+        _address: u64,
+        _values: &mut [u8],
+    ) -> Result<(), String> {
+        Err(unavailable_host_call(WasmHostCall::ReadProcessMemory))
+    }
+
+    fn write_process_memory(
+        &self,
+        _address: u64,
+        _values: &[u8],
+    ) -> Result<(), String> {
+        Err(unavailable_host_call(WasmHostCall::WriteProcessMemory))
+    }
+
+    fn request_symbol_tree_refresh(&self) -> Result<(), String> {
+        Err(unavailable_host_call(WasmHostCall::RequestSymbolTreeRefresh))
+    }
+
+    fn focus_symbol_tree_node(
+        &self,
+        _tree_node_key: &str,
+    ) -> Result<(), String> {
+        Err(unavailable_host_call(WasmHostCall::FocusSymbolTreeNode))
+    }
+}
+
+/// Backend for entry points that may only log and report errors.
+pub(crate) struct WasmPluginNoHostBackend;
+
+impl WasmPluginHostBackend for WasmPluginNoHostBackend {}
+
+fn unavailable_host_call(host_call: WasmHostCall) -> String {
+    format!(
+        "`{}.{}` is not available from this entry point.",
+        WASM_PLUGIN_HOST_MODULE_NAME,
+        host_call.import_name()
+    )
+}
+
+/// Per-store state for a guest. `granted_permissions` is scoped to the current entry point, which can be narrower than
+/// what the plugin declares (for example, a symbol tree action only receives the permissions it requires).
+pub(crate) struct WasmPluginCallState {
+    plugin_id: String,
+    granted_permissions: Vec<PluginPermission>,
+    host_backend: Box<dyn WasmPluginHostBackend>,
+    store_limits: StoreLimits,
+    reported_error: Option<String>,
+}
+
+impl WasmPluginCallState {
+    pub(crate) fn new(
+        plugin_id: String,
+        granted_permissions: Vec<PluginPermission>,
+        host_backend: Box<dyn WasmPluginHostBackend>,
+        store_limits: StoreLimits,
+    ) -> Self {
+        Self {
+            plugin_id,
+            granted_permissions,
+            host_backend,
+            store_limits,
+            reported_error: None,
+        }
+    }
+
+    pub(crate) fn get_store_limits_mut(&mut self) -> &mut StoreLimits {
+        &mut self.store_limits
+    }
+
+    pub(crate) fn take_reported_error(&mut self) -> Option<String> {
+        self.reported_error.take()
+    }
+
+    fn record_host_error(
+        &mut self,
+        host_call: WasmHostCall,
+        error: String,
+    ) -> i32 {
+        log::warn!(
+            "Plugin `{}` host call `{}.{}` failed: {}",
+            self.plugin_id,
+            WASM_PLUGIN_HOST_MODULE_NAME,
+            host_call.import_name(),
+            error
+        );
+        self.reported_error = Some(error);
+
+        HOST_CALL_FAILED
+    }
+}
+
+/// Rejects modules that import unknown host functions, or host functions guarded by permissions the plugin did not declare.
+pub(crate) fn validate_module_imports(
+    plugin_id: &str,
+    module: &Module,
+    declared_permissions: &[PluginPermission],
+) -> Result<(), String> {
+    for module_import in module.imports() {
+        let import_path = format!("{}.{}", module_import.module(), module_import.name());
+
+        if module_import.module() != WASM_PLUGIN_HOST_MODULE_NAME {
+            return Err(format!("Plugin `{}` imports `{}`, which the host does not provide.", plugin_id, import_path));
+        }
+
+        let Some(host_call) = WasmHostCall::from_import_name(module_import.name()) else {
+            return Err(format!("Plugin `{}` imports `{}`, which the host does not provide.", plugin_id, import_path));
+        };
+
+        if let Some(required_permission) = host_call.required_permission()
+            && !declared_permissions.contains(&required_permission)
+        {
+            return Err(format!(
+                "Plugin `{}` imports `{}` without declaring the `{}` permission.",
+                plugin_id,
+                import_path,
+                required_permission.get_display_name()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds the linker that provides the `squalr` import module. Every guarded call checks the permissions granted to the
+/// current entry point and traps when they are missing, so undeclared access aborts the guest instead of failing quietly.
+pub(crate) fn build_host_linker(engine: &Engine) -> Result<Linker<WasmPluginCallState>, String> {
+    let mut linker = Linker::new(engine);
+
+    define_host_calls(&mut linker).map_err(|error| format!("Failed to define plugin host calls: {}", error))?;
+
+    Ok(linker)
+}
+
+fn define_host_calls(linker: &mut Linker<WasmPluginCallState>) -> wasmtime::Result<()> {
+    linker.func_wrap(
+        WASM_PLUGIN_HOST_MODULE_NAME,
+        WasmHostCall::Log.import_name(),
+        |mut caller: Caller<'_, WasmPluginCallState>, message_pointer: i32, message_length: i32| -> wasmtime::Result<()> {
+            let message = read_guest_string(&mut caller, message_pointer, message_length)?;
+            log::info!("[{}] {}", caller.data().plugin_id, message);
+
+            Ok(())
+        },
+    )?;
+
+    linker.func_wrap(
+        WASM_PLUGIN_HOST_MODULE_NAME,
+        WasmHostCall::ReportError.import_name(),
+        |mut caller: Caller<'_, WasmPluginCallState>, message_pointer: i32, message_length: i32| -> wasmtime::Result<()> {
+            let message = read_guest_string(&mut caller, message_pointer, message_length)?;
+            caller.data_mut().reported_error = Some(message);
+
+            Ok(())
+        },
+    )?;
+
+    linker.func_wrap(
+        WASM_PLUGIN_HOST_MODULE_NAME,
+        WasmHostCall::ReadSymbolCatalog.import_name(),
+        |mut caller: Caller<'_, WasmPluginCallState>| -> wasmtime::Result<i64> {
+            ensure_permission(&caller, WasmHostCall::ReadSymbolCatalog)?;
+
+            let catalog_json = match caller
+                .data()
+                .host_backend
+                .read_symbol_catalog()
+                .and_then(|project_symbol_catalog| {
+                    serde_json::to_vec(&project_symbol_catalog).map_err(|error| format!("Failed to serialize symbol catalog: {}", error))
+                }) {
+                Ok(catalog_json) => catalog_json,
+                Err(error) => {
+                    return Ok(caller
+                        .data_mut()
+                        .record_host_error(WasmHostCall::ReadSymbolCatalog, error) as i64);
+                }
+            };
+
+            allocate_guest_bytes(&mut caller, &catalog_json)
+        },
+    )?;
+
+    linker.func_wrap(
+        WASM_PLUGIN_HOST_MODULE_NAME,
+        WasmHostCall::WriteSymbolCatalog.import_name(),
+        |mut caller: Caller<'_, WasmPluginCallState>, catalog_pointer: i32, catalog_length: i32| -> wasmtime::Result<i32> {
+            ensure_permission(&caller, WasmHostCall::WriteSymbolCatalog)?;
+
+            let catalog_json = read_guest_bytes(&mut caller, catalog_pointer, catalog_length)?;
+            let write_result = serde_json::from_slice::<ProjectSymbolCatalog>(&catalog_json)
+                .map_err(|error| format!("Failed to parse symbol catalog written by the plugin: {}", error))
+                .and_then(|project_symbol_catalog| {
+                    caller
+                        .data()
+                        .host_backend
+                        .write_symbol_catalog(project_symbol_catalog)
+                });
+
+            Ok(complete_host_call(&mut caller, WasmHostCall::WriteSymbolCatalog, write_result))
+        },
+    )?;
+
+    linker.func_wrap(
+        WASM_PLUGIN_HOST_MODULE_NAME,
+        WasmHostCall::ReadModuleBytes.import_name(),
+        |mut caller: Caller<'_, WasmPluginCallState>,
+         module_name_pointer: i32,
+         module_name_length: i32,
+         offset: i64,
+         output_pointer: i32,
+         output_length: i32|
+         -> wasmtime::Result<i32> {
+            ensure_permission(&caller, WasmHostCall::ReadModuleBytes)?;
+
+            let module_name = read_guest_string(&mut caller, module_name_pointer, module_name_length)?;
+            let output_length = host_read_length(output_length)?;
+            guest_range(&mut caller, output_pointer, output_length)?;
+
+            let read_result = caller
+                .data()
+                .host_backend
+                .read_module_bytes(&module_name, offset as u64, output_length as u64);
+
+            match read_result {
+                Ok(module_bytes) => {
+                    let copy_length = module_bytes.len().min(output_length);
+                    write_guest_bytes(&mut caller, output_pointer, &module_bytes[..copy_length])?;
+
+                    Ok(copy_length as i32)
+                }
+                Err(error) => Ok(caller
+                    .data_mut()
+                    .record_host_error(WasmHostCall::ReadModuleBytes, error)),
+            }
+        },
+    )?;
+
+    linker.func_wrap(
+        WASM_PLUGIN_HOST_MODULE_NAME,
+        WasmHostCall::ReadProcessMemory.import_name(),
+        |mut caller: Caller<'_, WasmPluginCallState>, address: i64, output_pointer: i32, output_length: i32| -> wasmtime::Result<i32> {
+            ensure_permission(&caller, WasmHostCall::ReadProcessMemory)?;
+
+            let output_length = host_read_length(output_length)?;
+            guest_range(&mut caller, output_pointer, output_length)?;
+
+            let mut values = vec![0u8; output_length];
+            let read_result = caller
+                .data()
+                .host_backend
+                .read_process_memory(address as u64, &mut values);
+
+            if read_result.is_ok() {
+                write_guest_bytes(&mut caller, output_pointer, &values)?;
+            }
+
+            Ok(complete_host_call(&mut caller, WasmHostCall::ReadProcessMemory, read_result))
+        },
+    )?;
+
+    linker.func_wrap(
+        WASM_PLUGIN_HOST_MODULE_NAME,
+        WasmHostCall::WriteProcessMemory.import_name(),
+        |mut caller: Caller<'_, WasmPluginCallState>, address: i64, input_pointer: i32, input_length: i32| -> wasmtime::Result<i32> {
+            ensure_permission(&caller, WasmHostCall::WriteProcessMemory)?;
+
+            let values = read_guest_bytes(&mut caller, input_pointer, input_length)?;
+            let write_result = caller
+                .data()
+                .host_backend
+                .write_process_memory(address as u64, &values);
+
+            Ok(complete_host_call(&mut caller, WasmHostCall::WriteProcessMemory, write_result))
+        },
+    )?;
+
+    linker.func_wrap(
+        WASM_PLUGIN_HOST_MODULE_NAME,
+        WasmHostCall::RequestSymbolTreeRefresh.import_name(),
+        |mut caller: Caller<'_, WasmPluginCallState>| -> wasmtime::Result<i32> {
+            ensure_permission(&caller, WasmHostCall::RequestSymbolTreeRefresh)?;
+
+            let refresh_result = caller.data().host_backend.request_symbol_tree_refresh();
+
+            Ok(complete_host_call(&mut caller, WasmHostCall::RequestSymbolTreeRefresh, refresh_result))
+        },
+    )?;
+
+    linker.func_wrap(
+        WASM_PLUGIN_HOST_MODULE_NAME,
+        WasmHostCall::FocusSymbolTreeNode.import_name(),
+        |mut caller: Caller<'_, WasmPluginCallState>, tree_node_key_pointer: i32, tree_node_key_length: i32| -> wasmtime::Result<i32> {
+            ensure_permission(&caller, WasmHostCall::FocusSymbolTreeNode)?;
+
+            let tree_node_key = read_guest_string(&mut caller, tree_node_key_pointer, tree_node_key_length)?;
+            let focus_result = caller
+                .data()
+                .host_backend
+                .focus_symbol_tree_node(&tree_node_key);
+
+            Ok(complete_host_call(&mut caller, WasmHostCall::FocusSymbolTreeNode, focus_result))
+        },
+    )?;
+
+    Ok(())
+}
+
+fn ensure_permission(
+    caller: &Caller<'_, WasmPluginCallState>,
+    host_call: WasmHostCall,
+) -> wasmtime::Result<()> {
+    let Some(required_permission) = host_call.required_permission() else {
+        return Ok(());
+    };
+
+    if caller.data().granted_permissions.contains(&required_permission) {
+        return Ok(());
+    }
+
+    Err(wasmtime::Error::msg(format!(
+        "Plugin `{}` is not permitted to call `{}.{}` here: the `{}` permission was not granted.",
+        caller.data().plugin_id,
+        WASM_PLUGIN_HOST_MODULE_NAME,
+        host_call.import_name(),
+        required_permission.get_display_name()
+    )))
+}
+
+fn complete_host_call(
+    caller: &mut Caller<'_, WasmPluginCallState>,
+    host_call: WasmHostCall,
+    result: Result<(), String>,
+) -> i32 {
+    match result {
+        Ok(()) => HOST_CALL_SUCCEEDED,
+        Err(error) => caller.data_mut().record_host_error(host_call, error),
+    }
+}
+
+fn guest_length(length: i32) -> wasmtime::Result<usize> {
+    usize::try_from(length).map_err(|_| wasmtime::Error::msg(format!("Plugin passed a negative buffer length ({}).", length)))
+}
+
+/// Validates the length of a buffer the host fills by reading the target, which the host allocates on the guest's behalf.
+fn host_read_length(length: i32) -> wasmtime::Result<usize> {
+    let length = guest_length(length)?;
+
+    if length > WASM_PLUGIN_MAX_HOST_READ_BYTES {
+        return Err(wasmtime::Error::msg(format!(
+            "Plugin requested a {} byte read, above the {} byte limit for a single host call.",
+            length, WASM_PLUGIN_MAX_HOST_READ_BYTES
+        )));
+    }
+
+    Ok(length)
+}
+
+/// Resolves a guest buffer to its range of guest memory, rejecting buffers that extend past the end of that memory before
+/// the host allocates anything for them.
+fn guest_range(
+    caller: &mut Caller<'_, WasmPluginCallState>,
+    pointer: i32,
+    length: usize,
+) -> wasmtime::Result<(wasmtime::Memory, Range<usize>)> {
+    let memory = guest_memory(caller)?;
+    let start = pointer as u32 as usize;
+
+    match start.checked_add(length) {
+        Some(end) if end <= memory.data_size(&*caller) => Ok((memory, start..end)),
+        _ => Err(wasmtime::Error::msg(format!(
+            "Plugin passed a {} byte buffer at {:#X}, which extends past the end of its memory.",
+            length, start
+        ))),
+    }
+}
+
+fn guest_memory(caller: &mut Caller<'_, WasmPluginCallState>) -> wasmtime::Result<wasmtime::Memory> {
+    match caller.get_export(WASM_PLUGIN_MEMORY_EXPORT_NAME) {
+        Some(Extern::Memory(memory)) => Ok(memory),
+        _ => Err(wasmtime::Error::msg(format!(
+            "Plugin does not export its linear memory as `{}`.",
+            WASM_PLUGIN_MEMORY_EXPORT_NAME
+        ))),
+    }
+}
+
+fn read_guest_bytes(
+    caller: &mut Caller<'_, WasmPluginCallState>,
+    pointer: i32,
+    length: i32,
+) -> wasmtime::Result<Vec<u8>> {
+    let (memory, guest_range) = guest_range(caller, pointer, guest_length(length)?)?;
+
+    Ok(memory.data(&*caller)[guest_range].to_vec())
+}
+
+fn read_guest_string(
+    caller: &mut Caller<'_, WasmPluginCallState>,
+    pointer: i32,
+    length: i32,
+) -> wasmtime::Result<String> {
+    let values = read_guest_bytes(caller, pointer, length)?;
+
+    Ok(String::from_utf8_lossy(&values).into_owned())
+}
+
+fn write_guest_bytes(
+    caller: &mut Caller<'_, WasmPluginCallState>,
+    pointer: i32,
+    values: &[u8],
+) -> wasmtime::Result<()> {
+    let memory = guest_memory(caller)?;
+    memory.write(&mut *caller, pointer as u32 as usize, values)?;
+
+    Ok(())
+}
+
+/// Copies host bytes into a fresh guest allocation and returns the packed `(pointer << 32) | length` buffer.
+fn allocate_guest_bytes(
+    caller: &mut Caller<'_, WasmPluginCallState>,
+    values: &[u8],
+) -> wasmtime::Result<i64> {
+    let Some(Extern::Func(alloc_func)) = caller.get_export(WASM_PLUGIN_ALLOC_EXPORT_NAME) else {
+        return Err(wasmtime::Error::msg(format!("Plugin does not export `{}`.", WASM_PLUGIN_ALLOC_EXPORT_NAME)));
+    };
+    let length = i32::try_from(values.len()).map_err(|_| wasmtime::Error::msg("Host buffer is too large for guest memory."))?;
+    let pointer = alloc_func
+        .typed::<i32, i32>(&*caller)?
+        .call(&mut *caller, length)?;
+    write_guest_bytes(caller, pointer, values)?;
+
+    Ok(pack_guest_buffer(pointer, length))
+}
+
+pub(crate) fn pack_guest_buffer(
+    pointer: i32,
+    length: i32,
+) -> i64 {
+    (((pointer as u32 as u64) << 32) | length as u32 as u64) as i64
+}
+
+pub(crate) fn unpack_guest_buffer(packed_buffer: i64) -> (i32, i32) {
+    ((packed_buffer as u64 >> 32) as u32 as i32, packed_buffer as u64 as u32 as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WasmHostCall, pack_guest_buffer, unpack_guest_buffer};
+    use squalr_engine_api::plugins::PluginPermission;
+
+    #[test]
+    fn packed_guest_buffers_round_trip() {
+        let packed_buffer = pack_guest_buffer(0x0012_3400, 0x56);
+
+        assert_eq!(packed_buffer, 0x0012_3400_0000_0056);
+        assert_eq!(unpack_guest_buffer(packed_buffer), (0x0012_3400, 0x56));
+    }
+
+    #[test]
+    fn every_data_access_host_call_is_guarded_by_a_permission() {
+        for host_call in WasmHostCall::ALL {
+            let is_diagnostic_call = matches!(host_call, WasmHostCall::Log | WasmHostCall::ReportError);
+
+            assert_eq!(host_call.required_permission().is_none(), is_diagnostic_call, "{:?}", host_call);
+            assert_eq!(WasmHostCall::from_import_name(host_call.import_name()), Some(host_call));
+        }
+
+        assert_eq!(
+            WasmHostCall::WriteProcessMemory.required_permission(),
+            Some(PluginPermission::WriteProcessMemory)
+        );
+    }
+}
@@ -0,0 +1,17 @@
+mod constants;
+mod data_type;
+mod host;
+mod host_calls;
+mod manifest;
+mod memory_view;
+mod plugin;
+mod runtime;
+mod symbol_tree_action;
+
+pub use constants::WASM_PLUGIN_MANIFEST_FILE_NAME;
+pub use host::WasmPluginHost;
+pub use manifest::{WasmDataTypeManifest, WasmMemoryViewManifest, WasmPluginManifest, WasmSymbolTreeActionManifest, WasmSymbolTreeSelectionKind};
+pub use plugin::WasmPluginPackage;
+
+#[cfg(test)]
+mod tests;
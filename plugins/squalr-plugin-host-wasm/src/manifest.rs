@@ -0,0 +1,253 @@
+use crate::constants::BUILT_IN_PLUGIN_ID_PREFIX;
+use serde::{Deserialize, Serialize};
+use squalr_engine_api::plugins::{
+    PluginCapability, PluginPermission,
+    symbol_tree::symbol_tree_action::{SymbolTreeActionContext, SymbolTreeActionSelection},
+};
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+/// Describes a third-party WebAssembly plugin. The manifest is the only source of the plugin's permissions, so a guest can
+/// never call a host function that its manifest did not ask for.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WasmPluginManifest {
+    pub plugin_id: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Path to the `.wasm` module, relative to the plugin directory.
+    pub module_path: PathBuf,
+    #[serde(default)]
+    pub permissions: Vec<PluginPermission>,
+    #[serde(default)]
+    pub data_types: Vec<WasmDataTypeManifest>,
+    #[serde(default)]
+    pub symbol_tree_actions: Vec<WasmSymbolTreeActionManifest>,
+    #[serde(default)]
+    pub memory_view: Option<WasmMemoryViewManifest>,
+}
+
+/// A data type backed by a built-in storage type. Comparisons run on the storage type, while the optional guest exports
+/// provide the text shown for and parsed from the `String` display format.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WasmDataTypeManifest {
+    pub data_type_id: String,
+    pub storage_data_type_id: String,
+    #[serde(default)]
+    pub icon_id: Option<String>,
+    #[serde(default)]
+    pub format_export: Option<String>,
+    #[serde(default)]
+    pub parse_export: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WasmSymbolTreeActionManifest {
+    pub action_id: String,
+    pub label: String,
+    pub entry_point: String,
+    #[serde(default)]
+    pub required_permissions: Vec<PluginPermission>,
+    /// Selections the action is offered for. An empty list offers the action for every selection.
+    #[serde(default)]
+    pub selection_kinds: Vec<WasmSymbolTreeSelectionKind>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum WasmSymbolTreeSelectionKind {
+    ModuleRoot,
+    SymbolLocator,
+    ModuleRange,
+    DerivedNode,
+}
+
+impl WasmSymbolTreeSelectionKind {
+    pub fn matches(
+        &self,
+        context: &SymbolTreeActionContext,
+    ) -> bool {
+        matches!(
+            (self, context.get_selection()),
+            (Self::ModuleRoot, SymbolTreeActionSelection::ModuleRoot { .. })
+                | (Self::SymbolLocator, SymbolTreeActionSelection::SymbolLocator { .. })
+                | (Self::ModuleRange, SymbolTreeActionSelection::ModuleRange { .. })
+                | (Self::DerivedNode, SymbolTreeActionSelection::DerivedNode { .. })
+        )
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WasmMemoryViewManifest {
+    /// Process names (case-insensitive, with or without an extension) that this memory view can attach to.
+    pub process_names: Vec<String>,
+}
+
+impl WasmPluginManifest {
+    pub fn load(manifest_path: &Path) -> Result<Self, String> {
+        let manifest_json =
+            fs::read_to_string(manifest_path).map_err(|error| format!("Failed to read plugin manifest {}: {}", manifest_path.display(), error))?;
+        let manifest =
+            serde_json::from_str::<Self>(&manifest_json).map_err(|error| format!("Failed to parse plugin manifest {}: {}", manifest_path.display(), error))?;
+
+        manifest.validate()?;
+
+        Ok(manifest)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.plugin_id.trim().is_empty() {
+            return Err(String::from("Plugin manifests must declare a plugin id."));
+        }
+
+        if self.plugin_id.starts_with(BUILT_IN_PLUGIN_ID_PREFIX) {
+            return Err(format!(
+                "Plugin `{}` uses the reserved `{}` id prefix.",
+                self.plugin_id, BUILT_IN_PLUGIN_ID_PREFIX
+            ));
+        }
+
+        let module_path_stays_in_plugin_directory = self
+            .module_path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+
+        if self.module_path.as_os_str().is_empty() || !module_path_stays_in_plugin_directory {
+            return Err(format!(
+                "Plugin `{}` must reference its module with a relative path inside the plugin directory.",
+                self.plugin_id
+            ));
+        }
+
+        if self.get_plugin_capabilities().is_empty() {
+            return Err(format!(
+                "Plugin `{}` does not contribute any data types, symbol tree actions, or memory view.",
+                self.plugin_id
+            ));
+        }
+
+        let mut data_type_ids = HashSet::new();
+
+        for data_type in &self.data_types {
+            if data_type.data_type_id.trim().is_empty() || !data_type_ids.insert(data_type.data_type_id.as_str()) {
+                return Err(format!(
+                    "Plugin `{}` declares an empty or duplicate data type id `{}`.",
+                    self.plugin_id, data_type.data_type_id
+                ));
+            }
+        }
+
+        let mut action_ids = HashSet::new();
+
+        for symbol_tree_action in &self.symbol_tree_actions {
+            if symbol_tree_action.action_id.trim().is_empty() || !action_ids.insert(symbol_tree_action.action_id.as_str()) {
+                return Err(format!(
+                    "Plugin `{}` declares an empty or duplicate symbol tree action id `{}`.",
+                    self.plugin_id, symbol_tree_action.action_id
+                ));
+            }
+
+            if let Some(undeclared_permission) = symbol_tree_action
+                .required_permissions
+                .iter()
+                .find(|plugin_permission| !self.permissions.contains(plugin_permission))
+            {
+                return Err(format!(
+                    "Symbol tree action `{}` requires `{}`, which plugin `{}` does not declare.",
+                    symbol_tree_action.action_id,
+                    undeclared_permission.get_display_name(),
+                    self.plugin_id
+                ));
+            }
+        }
+
+        if let Some(memory_view) = &self.memory_view
+            && memory_view.process_names.is_empty()
+        {
+            return Err(format!("Memory view of plugin `{}` must list at least one process name.", self.plugin_id));
+        }
+
+        Ok(())
+    }
+
+    pub fn get_plugin_capabilities(&self) -> Vec<PluginCapability> {
+        let mut plugin_capabilities = Vec::new();
+
+        if !self.data_types.is_empty() {
+            plugin_capabilities.push(PluginCapability::DataType);
+        }
+
+        if !self.symbol_tree_actions.is_empty() {
+            plugin_capabilities.push(PluginCapability::SymbolTree);
+        }
+
+        if self.memory_view.is_some() {
+            plugin_capabilities.push(PluginCapability::MemoryView);
+        }
+
+        plugin_capabilities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WasmPluginManifest;
+    use squalr_engine_api::plugins::{PluginCapability, PluginPermission};
+
+    fn parse_manifest(manifest_json: &str) -> WasmPluginManifest {
+        serde_json::from_str(manifest_json).expect("Expected the test manifest to parse.")
+    }
+
+    #[test]
+    fn capabilities_follow_declared_sections() {
+        let manifest = parse_manifest(
+            r#"{
+                "plugin_id": "acme.tools",
+                "display_name": "Acme Tools",
+                "module_path": "acme.wasm",
+                "permissions": ["ReadSymbolStore"],
+                "symbol_tree_actions": [{ "action_id": "acme.tools.scan", "label": "Scan", "entry_point": "scan", "required_permissions": ["ReadSymbolStore"] }],
+                "memory_view": { "process_names": ["acme.exe"] }
+            }"#,
+        );
+
+        assert!(manifest.validate().is_ok());
+        assert_eq!(
+            manifest.get_plugin_capabilities(),
+            vec![PluginCapability::SymbolTree, PluginCapability::MemoryView]
+        );
+        assert_eq!(manifest.permissions, vec![PluginPermission::ReadSymbolStore]);
+    }
+
+    #[test]
+    fn validate_rejects_actions_that_exceed_declared_permissions() {
+        let manifest = parse_manifest(
+            r#"{
+                "plugin_id": "acme.tools",
+                "display_name": "Acme Tools",
+                "module_path": "acme.wasm",
+                "permissions": ["ReadSymbolStore"],
+                "symbol_tree_actions": [{ "action_id": "acme.tools.write", "label": "Write", "entry_point": "write", "required_permissions": ["WriteSymbolStore"] }]
+            }"#,
+        );
+
+        assert!(manifest.validate().is_err());
+    }
+
+    #[test]
+    fn validate_rejects_reserved_ids_and_escaping_module_paths() {
+        let reserved_manifest = parse_manifest(
+            r#"{ "plugin_id": "builtin.fake", "display_name": "Fake", "module_path": "fake.wasm", "memory_view": { "process_names": ["fake"] } }"#,
+        );
+        let escaping_manifest = parse_manifest(
+            r#"{ "plugin_id": "acme.escape", "display_name": "Escape", "module_path": "../escape.wasm", "memory_view": { "process_names": ["escape"] } }"#,
+        );
+        let empty_manifest = parse_manifest(r#"{ "plugin_id": "acme.empty", "display_name": "Empty", "module_path": "empty.wasm" }"#);
+
+        assert!(reserved_manifest.validate().is_err());
+        assert!(escaping_manifest.validate().is_err());
+        assert!(empty_manifest.validate().is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    constants::{
+        WASM_MEMORY_VIEW_GET_MODULES_EXPORT_NAME, WASM_MEMORY_VIEW_GET_PAGES_EXPORT_NAME, WASM_MEMORY_VIEW_READ_EXPORT_NAME, WASM_MEMORY_VIEW_WRITE_EXPORT_NAME,
+    },
+    host_calls::WasmPluginHostBackend,
+    runtime::WasmPluginSession,
+};
+use serde::Deserialize;
+use squalr_engine_api::{
+    plugins::memory_view::{MemoryViewInstance, MemoryViewPluginError, PageRetrievalMode},
+    structures::{
+        memory::{normalized_module::NormalizedModule, normalized_region::NormalizedRegion},
+        processes::opened_process_info::OpenedProcessInfo,
+    },
+};
+use squalr_engine_targets_native::{
+    memory_reader::{MemoryReader, memory_reader_trait::MemoryReaderTrait},
+    memory_writer::{MemoryWriter, memory_writer_trait::MemoryWriterTrait},
+};
+use std::sync::Mutex;
+
+const MEMORY_VIEW_CALL_SUCCEEDED: i32 = 0;
+
+/// Gives a memory-view guest access to the attached process. Permission gating happens in the host calls, so this
+/// backend only performs the reads and writes.
+pub(crate) struct ProcessMemoryHostBackend {
+    opened_process_info: OpenedProcessInfo,
+}
+
+impl ProcessMemoryHostBackend {
+    pub(crate) fn new(opened_process_info: OpenedProcessInfo) -> Self {
+        Self { opened_process_info }
+    }
+}
+
+impl WasmPluginHostBackend for ProcessMemoryHostBackend {
+    fn read_process_memory(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> Result<(), String> {
+        if MemoryReader::get_instance().read_bytes(&self.opened_process_info, address, values) {
+            Ok(())
+        } else {
+            Err(format!("Failed to read {} bytes at 0x{:X}.", values.len(), address))
+        }
+    }
+
+    fn write_process_memory(
+        &self,
+        address: u64,
+        values: &[u8],
+    ) -> Result<(), String> {
+        if MemoryWriter::get_instance().write_bytes(&self.opened_process_info, address, values) {
+            Ok(())
+        } else {
+            Err(format!("Failed to write {} bytes at 0x{:X}.", values.len(), address))
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct WasmMemoryViewPage {
+    base_address: u64,
+    size: u64,
+}
+
+#[derive(Deserialize)]
+struct WasmMemoryViewModule {
+    name: String,
+    base_address: u64,
+    size: u64,
+}
+
+/// A memory view backed by one long-lived guest instance, so the guest can keep its own address-translation state.
+pub(crate) struct WasmMemoryViewInstance {
+    plugin_id: String,
+    session: Mutex<WasmPluginSession>,
+}
+
+impl WasmMemoryViewInstance {
+    pub(crate) fn new(
+        plugin_id: &str,
+        session: WasmPluginSession,
+    ) -> Self {
+        Self {
+            plugin_id: plugin_id.to_string(),
+            session: Mutex::new(session),
+        }
+    }
+
+    fn with_session<Output>(
+        &self,
+        call_session: impl FnOnce(&mut WasmPluginSession) -> Result<Output, String>,
+    ) -> Result<Output, MemoryViewPluginError> {
+        let mut session = self
+            .session
+            .lock()
+            .map_err(|error| MemoryViewPluginError::message(&self.plugin_id, format!("failed to lock plugin instance: {}", error)))?;
+
+        call_session(&mut session).map_err(|error| MemoryViewPluginError::message(&self.plugin_id, error))
+    }
+
+    fn read_json_export<Value: for<'de> Deserialize<'de>>(
+        &self,
+        export_name: &str,
+    ) -> Result<Value, MemoryViewPluginError> {
+        self.with_session(|session| {
+            let packed_buffer = session.call::<(), i64>(export_name, ())?;
+            let json_bytes = session.read_packed_buffer(export_name, packed_buffer)?;
+
+            serde_json::from_slice(&json_bytes).map_err(|error| format!("`{}` returned malformed JSON: {}", export_name, error))
+        })
+    }
+}
+
+impl MemoryViewInstance for WasmMemoryViewInstance {
+    fn plugin_id(&self) -> &str {
+        &self.plugin_id
+    }
+
+    fn get_virtual_pages(
+        &self,
+        _page_retrieval_mode: PageRetrievalMode,
+    ) -> Result<Vec<NormalizedRegion>, MemoryViewPluginError> {
+        let pages = self.read_json_export::<Vec<WasmMemoryViewPage>>(WASM_MEMORY_VIEW_GET_PAGES_EXPORT_NAME)?;
+
+        Ok(pages
+            .into_iter()
+            .map(|page| NormalizedRegion::new(page.base_address, page.size))
+            .collect())
+    }
+
+    fn get_modules(&self) -> Result<Vec<NormalizedModule>, MemoryViewPluginError> {
+        let modules = self.read_json_export::<Vec<WasmMemoryViewModule>>(WASM_MEMORY_VIEW_GET_MODULES_EXPORT_NAME)?;
+
+        Ok(modules
+            .into_iter()
+            .map(|module| NormalizedModule::new(&module.name, module.base_address, module.size))
+            .collect())
+    }
+
+    fn read_bytes(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> Result<(), MemoryViewPluginError> {
+        let read_values = self.with_session(|session| {
+            let (output_pointer, output_length) = session.write_buffer(&vec![0u8; values.len()])?;
+            let read_result = session
+                .call::<(i64, i32, i32), i32>(WASM_MEMORY_VIEW_READ_EXPORT_NAME, (address as i64, output_pointer, output_length))
+                .and_then(|status| {
+                    if status == MEMORY_VIEW_CALL_SUCCEEDED {
+                        session.read_buffer(output_pointer, output_length)
+                    } else {
+                        Err(session.failure(WASM_MEMORY_VIEW_READ_EXPORT_NAME))
+                    }
+                });
+            session.release_host_buffers();
+
+            read_result
+        })?;
+
+        values.copy_from_slice(&read_values);
+
+        Ok(())
+    }
+
+    fn write_bytes(
+        &self,
+        address: u64,
+        values: &[u8],
+    ) -> Result<(), MemoryViewPluginError> {
+        self.with_session(|session| {
+            let (input_pointer, input_length) = session.write_buffer(values)?;
+            let write_result = session
+                .call::<(i64, i32, i32), i32>(WASM_MEMORY_VIEW_WRITE_EXPORT_NAME, (address as i64, input_pointer, input_length))
+                .and_then(|status| {
+                    if status == MEMORY_VIEW_CALL_SUCCEEDED {
+                        Ok(())
+                    } else {
+                        Err(session.failure(WASM_MEMORY_VIEW_WRITE_EXPORT_NAME))
+                    }
+                });
+            session.release_host_buffers();
+
+            write_result
+        })
+    }
+}
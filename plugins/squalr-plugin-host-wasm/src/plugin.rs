@@ -0,0 +1,222 @@
+use crate::{
+    constants::{
+        WASM_MEMORY_VIEW_GET_MODULES_EXPORT_NAME, WASM_MEMORY_VIEW_GET_PAGES_EXPORT_NAME, WASM_MEMORY_VIEW_READ_EXPORT_NAME, WASM_MEMORY_VIEW_WRITE_EXPORT_NAME,
+    },
+    data_type::WasmDataType,
+    host_calls::WasmPluginNoHostBackend,
+    manifest::{WasmMemoryViewManifest, WasmPluginManifest},
+    memory_view::{ProcessMemoryHostBackend, WasmMemoryViewInstance},
+    runtime::WasmPluginRuntime,
+    symbol_tree_action::WasmSymbolTreeAction,
+};
+use squalr_engine_api::{
+    plugins::{
+        Plugin, PluginMetadata, PluginPackage, PluginPermission,
+        data_type::DataTypePlugin,
+        memory_view::{MemoryViewInstance, MemoryViewPlugin, MemoryViewPluginError},
+        symbol_tree::{symbol_tree_action::SymbolTreeAction, symbol_tree_plugin::SymbolTreePlugin},
+    },
+    registries::symbols::symbol_registry::SymbolRegistry,
+    structures::{data_types::data_type::DataType, processes::opened_process_info::OpenedProcessInfo},
+};
+use std::{path::Path, sync::Arc};
+use wasmtime::Engine;
+
+/// A third-party plugin loaded from a WebAssembly module and its manifest. Third-party plugins start disabled, and their
+/// guests can only reach the host through calls gated by the permissions in the manifest.
+pub struct WasmPluginPackage {
+    metadata: PluginMetadata,
+    runtime: Arc<WasmPluginRuntime>,
+    memory_view: Option<WasmMemoryViewManifest>,
+    contributed_data_types: Vec<Arc<dyn DataType>>,
+    contributed_data_type_ids: &'static [&'static str],
+    symbol_tree_actions: Vec<Arc<dyn SymbolTreeAction>>,
+}
+
+impl WasmPluginPackage {
+    pub fn load(
+        engine: &Engine,
+        manifest: WasmPluginManifest,
+        module_bytes: &[u8],
+    ) -> Result<Self, String> {
+        manifest.validate()?;
+
+        let mut required_exports = manifest
+            .symbol_tree_actions
+            .iter()
+            .map(|symbol_tree_action| symbol_tree_action.entry_point.as_str())
+            .collect::<Vec<_>>();
+
+        for data_type in &manifest.data_types {
+            required_exports.extend(data_type.format_export.as_deref());
+            required_exports.extend(data_type.parse_export.as_deref());
+        }
+
+        if manifest.memory_view.is_some() {
+            required_exports.extend([
+                WASM_MEMORY_VIEW_GET_PAGES_EXPORT_NAME,
+                WASM_MEMORY_VIEW_GET_MODULES_EXPORT_NAME,
+                WASM_MEMORY_VIEW_READ_EXPORT_NAME,
+                WASM_MEMORY_VIEW_WRITE_EXPORT_NAME,
+            ]);
+        }
+
+        let runtime = Arc::new(WasmPluginRuntime::load(
+            engine,
+            &manifest.plugin_id,
+            module_bytes,
+            &manifest.permissions,
+            &required_exports,
+        )?);
+        let contributed_data_types = Self::load_data_types(&manifest, &runtime)?;
+        // The data type plugin trait hands out `'static` ids, so those are leaked once when the plugin loads.
+        let contributed_data_type_ids = Box::leak(
+            contributed_data_types
+                .iter()
+                .map(|data_type| &*Box::leak(data_type.get_data_type_id().to_string().into_boxed_str()))
+                .collect::<Vec<&'static str>>()
+                .into_boxed_slice(),
+        );
+        let symbol_tree_actions = manifest
+            .symbol_tree_actions
+            .iter()
+            .map(|symbol_tree_action| Arc::new(WasmSymbolTreeAction::new(symbol_tree_action, runtime.clone())) as Arc<dyn SymbolTreeAction>)
+            .collect();
+
+        Ok(Self {
+            metadata: PluginMetadata::new_with_permissions(
+                manifest.plugin_id.clone(),
+                manifest.display_name.clone(),
+                manifest.description.clone(),
+                manifest.get_plugin_capabilities(),
+                manifest.permissions.clone(),
+                false,
+                false,
+            ),
+            runtime,
+            memory_view: manifest.memory_view,
+            contributed_data_types,
+            contributed_data_type_ids,
+            symbol_tree_actions,
+        })
+    }
+
+    fn load_data_types(
+        manifest: &WasmPluginManifest,
+        runtime: &WasmPluginRuntime,
+    ) -> Result<Vec<Arc<dyn DataType>>, String> {
+        if manifest.data_types.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let built_in_symbol_registry = SymbolRegistry::new();
+        let mut contributed_data_types: Vec<Arc<dyn DataType>> = Vec::new();
+
+        for data_type_manifest in &manifest.data_types {
+            let Some(storage_data_type) = built_in_symbol_registry.get_data_type(&data_type_manifest.storage_data_type_id) else {
+                return Err(format!(
+                    "Data type `{}` of plugin `{}` uses unknown storage type `{}`.",
+                    data_type_manifest.data_type_id, manifest.plugin_id, data_type_manifest.storage_data_type_id
+                ));
+            };
+
+            // Value formatting is pure, so these instances get no host access at all.
+            let session = if data_type_manifest.format_export.is_some() || data_type_manifest.parse_export.is_some() {
+                Some(runtime.create_session(Vec::new(), Box::new(WasmPluginNoHostBackend))?)
+            } else {
+                None
+            };
+
+            contributed_data_types.push(Arc::new(WasmDataType::new(data_type_manifest, storage_data_type, session)));
+        }
+
+        Ok(contributed_data_types)
+    }
+
+    /// Memory views only ever receive the process memory permissions the plugin declared.
+    fn get_memory_view_permissions(&self) -> Vec<PluginPermission> {
+        [
+            PluginPermission::ReadProcessMemory,
+            PluginPermission::WriteProcessMemory,
+        ]
+        .into_iter()
+        .filter(|plugin_permission| self.has_permission(*plugin_permission))
+        .collect()
+    }
+}
+
+impl Plugin for WasmPluginPackage {
+    fn metadata(&self) -> &PluginMetadata {
+        &self.metadata
+    }
+}
+
+impl PluginPackage for WasmPluginPackage {
+    fn as_data_type_plugin(&self) -> Option<&dyn DataTypePlugin> {
+        (!self.contributed_data_types.is_empty()).then_some(self as &dyn DataTypePlugin)
+    }
+
+    fn as_memory_view_plugin(&self) -> Option<&dyn MemoryViewPlugin> {
+        self.memory_view
+            .is_some()
+            .then_some(self as &dyn MemoryViewPlugin)
+    }
+
+    fn as_symbol_tree_plugin(&self) -> Option<&dyn SymbolTreePlugin> {
+        (!self.symbol_tree_actions.is_empty()).then_some(self as &dyn SymbolTreePlugin)
+    }
+}
+
+impl DataTypePlugin for WasmPluginPackage {
+    fn contributed_data_types(&self) -> &[Arc<dyn DataType>] {
+        &self.contributed_data_types
+    }
+
+    fn contributed_data_type_ids(&self) -> &'static [&'static str] {
+        self.contributed_data_type_ids
+    }
+}
+
+impl SymbolTreePlugin for WasmPluginPackage {
+    fn symbol_tree_actions(&self) -> &[Arc<dyn SymbolTreeAction>] {
+        &self.symbol_tree_actions
+    }
+}
+
+impl MemoryViewPlugin for WasmPluginPackage {
+    fn can_attach(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> bool {
+        let Some(memory_view) = &self.memory_view else {
+            return false;
+        };
+        let process_name = process_info.get_name();
+        let process_stem = Path::new(process_name)
+            .file_stem()
+            .and_then(|process_stem| process_stem.to_str())
+            .unwrap_or(process_name);
+
+        memory_view
+            .process_names
+            .iter()
+            .any(|memory_view_process_name| {
+                memory_view_process_name.eq_ignore_ascii_case(process_name) || memory_view_process_name.eq_ignore_ascii_case(process_stem)
+            })
+    }
+
+    fn create_instance(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<Box<dyn MemoryViewInstance>, MemoryViewPluginError> {
+        let session = self
+            .runtime
+            .create_session(
+                self.get_memory_view_permissions(),
+                Box::new(ProcessMemoryHostBackend::new(process_info.clone())),
+            )
+            .map_err(|error| MemoryViewPluginError::message(self.metadata.get_plugin_id(), error))?;
+
+        Ok(Box::new(WasmMemoryViewInstance::new(self.metadata.get_plugin_id(), session)))
+    }
+}
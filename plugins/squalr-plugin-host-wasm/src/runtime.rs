@@ -0,0 +1,246 @@
+use crate::{
+    constants::{
+        WASM_PLUGIN_ALLOC_EXPORT_NAME, WASM_PLUGIN_CALL_FUEL, WASM_PLUGIN_FREE_EXPORT_NAME, WASM_PLUGIN_MAX_MEMORY_BYTES, WASM_PLUGIN_MEMORY_EXPORT_NAME,
+    },
+    host_calls::{WasmPluginCallState, WasmPluginHostBackend, build_host_linker, unpack_guest_buffer, validate_module_imports},
+};
+use squalr_engine_api::plugins::PluginPermission;
+use wasmtime::{Config, Engine, Instance, Linker, Memory, Module, Store, StoreLimitsBuilder, TypedFunc, WasmParams, WasmResults};
+
+/// A compiled plugin module, shared by every instance the plugin creates.
+pub(crate) struct WasmPluginRuntime {
+    plugin_id: String,
+    module: Module,
+    linker: Linker<WasmPluginCallState>,
+}
+
+impl WasmPluginRuntime {
+    pub(crate) fn create_engine() -> Result<Engine, String> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+
+        Engine::new(&config).map_err(|error| format!("Failed to create the WebAssembly engine: {}", error))
+    }
+
+    /// Compiles a plugin module, rejecting it if it imports anything its declared permissions do not cover or lacks the
+    /// exports the host needs to exchange buffers with it.
+    pub(crate) fn load(
+        engine: &Engine,
+        plugin_id: &str,
+        module_bytes: &[u8],
+        declared_permissions: &[PluginPermission],
+        required_exports: &[&str],
+    ) -> Result<Self, String> {
+        let module = Module::new(engine, module_bytes).map_err(|error| format!("Failed to compile plugin `{}`: {}", plugin_id, error))?;
+
+        validate_module_imports(plugin_id, &module, declared_permissions)?;
+
+        for export_name in [WASM_PLUGIN_MEMORY_EXPORT_NAME, WASM_PLUGIN_ALLOC_EXPORT_NAME]
+            .iter()
+            .chain(required_exports.iter())
+        {
+            if module.get_export(export_name).is_none() {
+                return Err(format!("Plugin `{}` does not export `{}`.", plugin_id, export_name));
+            }
+        }
+
+        Ok(Self {
+            plugin_id: plugin_id.to_string(),
+            module,
+            linker: build_host_linker(engine)?,
+        })
+    }
+
+    pub(crate) fn get_plugin_id(&self) -> &str {
+        &self.plugin_id
+    }
+
+    pub(crate) fn create_session(
+        &self,
+        granted_permissions: Vec<PluginPermission>,
+        host_backend: Box<dyn WasmPluginHostBackend>,
+    ) -> Result<WasmPluginSession, String> {
+        let store_limits = StoreLimitsBuilder::new()
+            .memory_size(WASM_PLUGIN_MAX_MEMORY_BYTES)
+            .build();
+        let call_state = WasmPluginCallState::new(self.plugin_id.clone(), granted_permissions, host_backend, store_limits);
+        let mut store = Store::new(self.module.engine(), call_state);
+        store.limiter(|call_state| call_state.get_store_limits_mut());
+        store
+            .set_fuel(WASM_PLUGIN_CALL_FUEL)
+            .map_err(|error| format!("Failed to fuel plugin `{}`: {}", self.plugin_id, error))?;
+
+        let instance = self
+            .linker
+            .instantiate(&mut store, &self.module)
+            .map_err(|error| format!("Failed to instantiate plugin `{}`: {:#}", self.plugin_id, error))?;
+        let memory = instance
+            .get_memory(&mut store, WASM_PLUGIN_MEMORY_EXPORT_NAME)
+            .ok_or_else(|| format!("Plugin `{}` does not export its linear memory.", self.plugin_id))?;
+        let alloc_func = instance
+            .get_typed_func::<i32, i32>(&mut store, WASM_PLUGIN_ALLOC_EXPORT_NAME)
+            .map_err(|error| {
+                format!(
+                    "Plugin `{}` has an invalid `{}` export: {}",
+                    self.plugin_id, WASM_PLUGIN_ALLOC_EXPORT_NAME, error
+                )
+            })?;
+        let free_func = match self.module.get_export(WASM_PLUGIN_FREE_EXPORT_NAME) {
+            Some(_) => Some(
+                instance
+                    .get_typed_func::<(i32, i32), ()>(&mut store, WASM_PLUGIN_FREE_EXPORT_NAME)
+                    .map_err(|error| {
+                        format!(
+                            "Plugin `{}` has an invalid `{}` export: {}",
+                            self.plugin_id, WASM_PLUGIN_FREE_EXPORT_NAME, error
+                        )
+                    })?,
+            ),
+            None => None,
+        };
+
+        Ok(WasmPluginSession {
+            plugin_id: self.plugin_id.clone(),
+            store,
+            instance,
+            memory,
+            alloc_func,
+            free_func,
+            host_buffers: Vec::new(),
+        })
+    }
+}
+
+/// A live guest instance along with the store that owns its memory and host-call state.
+pub(crate) struct WasmPluginSession {
+    plugin_id: String,
+    store: Store<WasmPluginCallState>,
+    instance: Instance,
+    memory: Memory,
+    alloc_func: TypedFunc<i32, i32>,
+    free_func: Option<TypedFunc<(i32, i32), ()>>,
+    /// Buffers the host allocated in guest memory that have not been handed back to the guest allocator yet.
+    host_buffers: Vec<(i32, i32)>,
+}
+
+impl WasmPluginSession {
+    /// Calls a guest export with a fresh fuel budget. Traps, including host-call permission violations, surface as errors.
+    pub(crate) fn call<Params: WasmParams, Results: WasmResults>(
+        &mut self,
+        export_name: &str,
+        params: Params,
+    ) -> Result<Results, String> {
+        self.refuel()?;
+        self.store.data_mut().take_reported_error();
+
+        let typed_func = self
+            .instance
+            .get_typed_func::<Params, Results>(&mut self.store, export_name)
+            .map_err(|error| format!("Plugin `{}` has an invalid `{}` export: {}", self.plugin_id, export_name, error))?;
+
+        typed_func
+            .call(&mut self.store, params)
+            .map_err(|error| format!("Plugin `{}` failed in `{}`: {:#}", self.plugin_id, export_name, error))
+    }
+
+    fn refuel(&mut self) -> Result<(), String> {
+        self.store
+            .set_fuel(WASM_PLUGIN_CALL_FUEL)
+            .map_err(|error| format!("Failed to fuel plugin `{}`: {}", self.plugin_id, error))
+    }
+
+    /// Builds the error for a guest call that returned a failure status, preferring the message the guest reported.
+    pub(crate) fn failure(
+        &mut self,
+        export_name: &str,
+    ) -> String {
+        match self.store.data_mut().take_reported_error() {
+            Some(reported_error) => format!("Plugin `{}` failed in `{}`: {}", self.plugin_id, export_name, reported_error),
+            None => format!("Plugin `{}` failed in `{}`.", self.plugin_id, export_name),
+        }
+    }
+
+    /// Copies bytes into a fresh guest allocation, returning the guest pointer and length.
+    pub(crate) fn write_buffer(
+        &mut self,
+        values: &[u8],
+    ) -> Result<(i32, i32), String> {
+        let length = i32::try_from(values.len()).map_err(|_| format!("Buffer is too large for plugin `{}`.", self.plugin_id))?;
+        self.refuel()?;
+        let pointer = self
+            .alloc_func
+            .call(&mut self.store, length)
+            .map_err(|error| format!("Plugin `{}` failed to allocate {} bytes: {:#}", self.plugin_id, length, error))?;
+
+        self.memory
+            .write(&mut self.store, pointer as u32 as usize, values)
+            .map_err(|error| format!("Plugin `{}` returned an invalid allocation: {}", self.plugin_id, error))?;
+        self.host_buffers.push((pointer, length));
+
+        Ok((pointer, length))
+    }
+
+    /// Returns buffers written by the host to the guest allocator, for guests that export `squalr_free`.
+    pub(crate) fn release_host_buffers(&mut self) {
+        let host_buffers = std::mem::take(&mut self.host_buffers);
+        let Some(free_func) = self.free_func.clone() else {
+            return;
+        };
+
+        for (pointer, length) in host_buffers {
+            if self.refuel().is_err() {
+                return;
+            }
+
+            if let Err(error) = free_func.call(&mut self.store, (pointer, length)) {
+                log::warn!("Plugin `{}` failed to free a host buffer: {:#}", self.plugin_id, error);
+                return;
+            }
+        }
+    }
+
+    pub(crate) fn read_buffer(
+        &self,
+        pointer: i32,
+        length: i32,
+    ) -> Result<Vec<u8>, String> {
+        let length = usize::try_from(length).map_err(|_| format!("Plugin `{}` returned a negative buffer length.", self.plugin_id))?;
+        let mut values = vec![0u8; length];
+
+        self.memory
+            .read(&self.store, pointer as u32 as usize, &mut values)
+            .map_err(|error| format!("Plugin `{}` returned an out-of-bounds buffer: {}", self.plugin_id, error))?;
+
+        Ok(values)
+    }
+
+    /// Reads a packed `(pointer << 32) | length` buffer returned by a guest export. Negative values signal failure.
+    pub(crate) fn read_packed_buffer(
+        &mut self,
+        export_name: &str,
+        packed_buffer: i64,
+    ) -> Result<Vec<u8>, String> {
+        if packed_buffer < 0 {
+            return Err(self.failure(export_name));
+        }
+
+        let (pointer, length) = unpack_guest_buffer(packed_buffer);
+
+        self.read_buffer(pointer, length)
+    }
+
+    /// Calls an export that takes one input buffer and returns a packed output buffer.
+    pub(crate) fn call_with_buffer(
+        &mut self,
+        export_name: &str,
+        input: &[u8],
+    ) -> Result<Vec<u8>, String> {
+        let (input_pointer, input_length) = self.write_buffer(input)?;
+        let output = self
+            .call::<(i32, i32), i64>(export_name, (input_pointer, input_length))
+            .and_then(|packed_buffer| self.read_packed_buffer(export_name, packed_buffer));
+        self.release_host_buffers();
+
+        output
+    }
+}
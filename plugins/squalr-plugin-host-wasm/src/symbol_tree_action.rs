@@ -0,0 +1,254 @@
+use crate::{host_calls::WasmPluginHostBackend, manifest::WasmSymbolTreeActionManifest, runtime::WasmPluginRuntime};
+use squalr_engine_api::{
+    plugins::{
+        PluginPermission,
+        symbol_tree::symbol_tree_action::{SymbolTreeAction, SymbolTreeActionContext, SymbolTreeActionServices},
+    },
+    structures::projects::project_symbol_catalog::ProjectSymbolCatalog,
+};
+use std::{
+    sync::{
+        Arc,
+        mpsc::{self, Receiver, Sender},
+    },
+    thread,
+};
+
+const ACTION_SUCCEEDED: i32 = 0;
+
+/// Requests a running action makes of the services that belong to the calling thread.
+enum SymbolTreeHostRequest {
+    ReadSymbolCatalog {
+        reply_sender: Sender<Result<ProjectSymbolCatalog, String>>,
+    },
+    WriteSymbolCatalog {
+        project_symbol_catalog: ProjectSymbolCatalog,
+        reply_sender: Sender<Result<(), String>>,
+    },
+    ReadModuleBytes {
+        module_name: String,
+        offset: u64,
+        length: u64,
+        reply_sender: Sender<Result<Vec<u8>, String>>,
+    },
+    RequestSymbolTreeRefresh,
+    FocusSymbolTreeNode {
+        tree_node_key: String,
+    },
+}
+
+/// Forwards guest host calls to the services of the action invocation. Guest stores must own their state, so the guest
+/// runs on a scoped worker thread and the borrowed services are reached over a channel instead of a stored reference.
+struct SymbolTreeActionHostBackend {
+    request_sender: Sender<SymbolTreeHostRequest>,
+}
+
+impl SymbolTreeActionHostBackend {
+    fn request<Reply>(
+        &self,
+        build_request: impl FnOnce(Sender<Result<Reply, String>>) -> SymbolTreeHostRequest,
+    ) -> Result<Reply, String> {
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        self.request_sender
+            .send(build_request(reply_sender))
+            .map_err(|_| String::from("The symbol tree action host is no longer available."))?;
+
+        reply_receiver
+            .recv()
+            .map_err(|_| String::from("The symbol tree action host did not reply."))?
+    }
+
+    fn notify(
+        &self,
+        request: SymbolTreeHostRequest,
+    ) -> Result<(), String> {
+        self.request_sender
+            .send(request)
+            .map_err(|_| String::from("The symbol tree action host is no longer available."))
+    }
+}
+
+impl WasmPluginHostBackend for SymbolTreeActionHostBackend {
+    fn read_symbol_catalog(&self) -> Result<ProjectSymbolCatalog, String> {
+        self.request(|reply_sender| SymbolTreeHostRequest::ReadSymbolCatalog { reply_sender })
+    }
+
+    fn write_symbol_catalog(
+        &self,
+        project_symbol_catalog: ProjectSymbolCatalog,
+    ) -> Result<(), String> {
+        self.request(|reply_sender| SymbolTreeHostRequest::WriteSymbolCatalog {
+            project_symbol_catalog,
+            reply_sender,
+        })
+    }
+
+    fn read_module_bytes(
+        &self,
+        module_name: &str,
+        offset: u64,
+        length: u64,
+    ) -> Result<Vec<u8>, String> {
+        self.request(|reply_sender| SymbolTreeHostRequest::ReadModuleBytes {
+            module_name: module_name.to_string(),
+            offset,
+            length,
+            reply_sender,
+        })
+    }
+
+    fn request_symbol_tree_refresh(&self) -> Result<(), String> {
+        self.notify(SymbolTreeHostRequest::RequestSymbolTreeRefresh)
+    }
+
+    fn focus_symbol_tree_node(
+        &self,
+        tree_node_key: &str,
+    ) -> Result<(), String> {
+        self.notify(SymbolTreeHostRequest::FocusSymbolTreeNode {
+            tree_node_key: tree_node_key.to_string(),
+        })
+    }
+}
+
+/// A symbol tree action whose body runs in a plugin guest. The guest export receives the action context as JSON and
+/// returns zero on success. Only the permissions the action requires are granted for the call.
+pub(crate) struct WasmSymbolTreeAction {
+    plugin_id: String,
+    action_id: &'static str,
+    label: String,
+    entry_point: String,
+    required_permissions: &'static [PluginPermission],
+    action_manifest: WasmSymbolTreeActionManifest,
+    runtime: Arc<WasmPluginRuntime>,
+}
+
+impl WasmSymbolTreeAction {
+    /// The action trait hands out `'static` ids and permissions, so those are leaked once when the plugin loads.
+    pub(crate) fn new(
+        action_manifest: &WasmSymbolTreeActionManifest,
+        runtime: Arc<WasmPluginRuntime>,
+    ) -> Self {
+        Self {
+            plugin_id: runtime.get_plugin_id().to_string(),
+            action_id: Box::leak(action_manifest.action_id.clone().into_boxed_str()),
+            label: action_manifest.label.clone(),
+            entry_point: action_manifest.entry_point.clone(),
+            required_permissions: Box::leak(action_manifest.required_permissions.clone().into_boxed_slice()),
+            action_manifest: action_manifest.clone(),
+            runtime,
+        }
+    }
+
+    fn run_guest(
+        &self,
+        context_json: Vec<u8>,
+        request_sender: Sender<SymbolTreeHostRequest>,
+    ) -> Result<(), String> {
+        let host_backend = Box::new(SymbolTreeActionHostBackend { request_sender });
+        let mut session = self
+            .runtime
+            .create_session(self.required_permissions.to_vec(), host_backend)?;
+        let (context_pointer, context_length) = session.write_buffer(&context_json)?;
+        let status = session.call::<(i32, i32), i32>(&self.entry_point, (context_pointer, context_length))?;
+
+        if status == ACTION_SUCCEEDED {
+            Ok(())
+        } else {
+            Err(session.failure(&self.entry_point))
+        }
+    }
+
+    fn serve_host_requests(
+        request_receiver: Receiver<SymbolTreeHostRequest>,
+        services: &dyn SymbolTreeActionServices,
+        reason: &str,
+    ) {
+        // The loop ends once the guest finishes and drops its sender.
+        for request in request_receiver {
+            match request {
+                SymbolTreeHostRequest::ReadSymbolCatalog { reply_sender } => {
+                    let _ = reply_sender.send(services.symbol_store().read_catalog());
+                }
+                SymbolTreeHostRequest::WriteSymbolCatalog {
+                    project_symbol_catalog,
+                    reply_sender,
+                } => {
+                    let write_result = services.symbol_store().write_catalog(
+                        reason,
+                        Box::new(move |current_catalog| {
+                            *current_catalog = project_symbol_catalog;
+
+                            Ok(())
+                        }),
+                    );
+                    let _ = reply_sender.send(write_result);
+                }
+                SymbolTreeHostRequest::ReadModuleBytes {
+                    module_name,
+                    offset,
+                    length,
+                    reply_sender,
+                } => {
+                    let _ = reply_sender.send(
+                        services
+                            .process_memory()
+                            .read_module_bytes(&module_name, offset, length),
+                    );
+                }
+                SymbolTreeHostRequest::RequestSymbolTreeRefresh => services.symbol_tree_window().request_refresh(),
+                SymbolTreeHostRequest::FocusSymbolTreeNode { tree_node_key } => services.symbol_tree_window().focus_tree_node(&tree_node_key),
+            }
+        }
+    }
+}
+
+impl SymbolTreeAction for WasmSymbolTreeAction {
+    fn action_id(&self) -> &'static str {
+        self.action_id
+    }
+
+    fn label(
+        &self,
+        _context: &SymbolTreeActionContext,
+    ) -> String {
+        self.label.clone()
+    }
+
+    fn is_visible(
+        &self,
+        context: &SymbolTreeActionContext,
+    ) -> bool {
+        self.action_manifest.selection_kinds.is_empty()
+            || self
+                .action_manifest
+                .selection_kinds
+                .iter()
+                .any(|selection_kind| selection_kind.matches(context))
+    }
+
+    fn required_permissions(&self) -> &'static [PluginPermission] {
+        self.required_permissions
+    }
+
+    fn execute(
+        &self,
+        context: &SymbolTreeActionContext,
+        services: &dyn SymbolTreeActionServices,
+    ) -> Result<(), String> {
+        let context_json = serde_json::to_vec(context).map_err(|error| format!("Failed to serialize symbol tree action context: {}", error))?;
+        let reason = format!("{} ({})", self.label, self.plugin_id);
+        let (request_sender, request_receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            let guest_thread = scope.spawn(move || self.run_guest(context_json, request_sender));
+
+            Self::serve_host_requests(request_receiver, services, &reason);
+
+            guest_thread
+                .join()
+                .unwrap_or_else(|_| Err(format!("Plugin `{}` action `{}` panicked.", self.plugin_id, self.action_id)))
+        })
+    }
+}
@@ -0,0 +1,496 @@
+use crate::{WASM_PLUGIN_MANIFEST_FILE_NAME, WasmPluginHost, WasmPluginManifest, WasmPluginPackage};
+use squalr_engine_api::{
+    plugins::{
+        PluginCapability, PluginPackage, PluginPermission,
+        memory_view::PageRetrievalMode,
+        symbol_tree::symbol_tree_action::{
+            DataTypeRegistryStore, ProcessMemoryStore, ProjectSymbolStore, SymbolTreeActionContext, SymbolTreeActionSelection, SymbolTreeActionServices,
+            SymbolTreeWindowStore,
+        },
+    },
+    structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::{anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat, container_type::ContainerType},
+        memory::bitness::Bitness,
+        processes::opened_process_info::OpenedProcessInfo,
+        projects::{project_symbol_catalog::ProjectSymbolCatalog, project_symbol_module::ProjectSymbolModule},
+    },
+};
+use std::{fs, path::Path, sync::Mutex};
+
+/// Bump allocator appended to every test guest, after its imports. Host buffers start past the static data segments.
+const GUEST_ALLOCATOR_WAT: &str = r#"
+    (memory (export "memory") 1)
+    (global $heap (mut i32) (i32.const 4096))
+    (func (export "squalr_alloc") (param $size i32) (result i32)
+        (local $pointer i32)
+        (local.set $pointer (global.get $heap))
+        (global.set $heap (i32.add (global.get $heap) (local.get $size)))
+        (local.get $pointer))
+"#;
+
+fn packed_buffer(
+    pointer: u32,
+    length: usize,
+) -> i64 {
+    ((pointer as i64) << 32) | length as i64
+}
+
+fn compile_guest(guest_wat: &str) -> Vec<u8> {
+    wat::parse_str(format!("(module {} {})", guest_wat, GUEST_ALLOCATOR_WAT)).expect("Expected the test guest to compile.")
+}
+
+fn load_plugin(
+    manifest_json: &str,
+    guest_wat: &str,
+) -> Result<WasmPluginPackage, String> {
+    let manifest = serde_json::from_str::<WasmPluginManifest>(manifest_json).expect("Expected the test manifest to parse.");
+    let plugin_host = WasmPluginHost::new().expect("Expected the plugin host to start.");
+
+    plugin_host.load_plugin_package_from_bytes(manifest, &compile_guest(guest_wat))
+}
+
+fn data_type_guest_wat() -> String {
+    let formatted_text = "42 gold";
+
+    format!(
+        r#"
+        (data (i32.const 16) "{formatted_text}")
+        (data (i32.const 32) "\2a\00\00\00")
+        (func (export "format_gold") (param i32 i32) (result i64) (i64.const {format_result}))
+        (func (export "parse_gold") (param i32 i32) (result i64) (i64.const {parse_result}))
+        "#,
+        format_result = packed_buffer(16, formatted_text.len()),
+        parse_result = packed_buffer(32, 4),
+    )
+}
+
+const DATA_TYPE_MANIFEST_JSON: &str = r#"{
+    "plugin_id": "acme.gold",
+    "display_name": "Gold",
+    "module_path": "gold.wasm",
+    "data_types": [{ "data_type_id": "gold", "storage_data_type_id": "u32", "format_export": "format_gold", "parse_export": "parse_gold" }]
+}"#;
+
+#[test]
+fn data_types_format_through_the_guest_and_delegate_other_formats() {
+    let plugin_package = load_plugin(DATA_TYPE_MANIFEST_JSON, &data_type_guest_wat()).expect("Expected the data type plugin to load.");
+    let data_type_plugin = plugin_package
+        .as_data_type_plugin()
+        .expect("Expected the plugin to contribute data types.");
+    let gold_data_type = &data_type_plugin.contributed_data_types()[0];
+
+    assert_eq!(data_type_plugin.contributed_data_type_ids(), &["gold"]);
+    assert_eq!(gold_data_type.get_unit_size_in_bytes(), 4);
+    assert_eq!(gold_data_type.get_default_anonymous_value_string_format(), AnonymousValueStringFormat::String);
+
+    let parsed_value = gold_data_type
+        .deanonymize_value_string(&AnonymousValueString::new(
+            String::from("42 gold"),
+            AnonymousValueStringFormat::String,
+            ContainerType::None,
+        ))
+        .expect("Expected the guest to parse the value.");
+    let decimal_value = gold_data_type
+        .deanonymize_value_string(&AnonymousValueString::new(
+            String::from("7"),
+            AnonymousValueStringFormat::Decimal,
+            ContainerType::None,
+        ))
+        .expect("Expected the storage type to parse decimal values.");
+
+    assert_eq!(parsed_value.get_data_type_ref(), &DataTypeRef::new("gold"));
+    assert_eq!(parsed_value.get_value_bytes(), &vec![42, 0, 0, 0]);
+    assert_eq!(decimal_value.get_data_type_ref(), &DataTypeRef::new("gold"));
+    assert_eq!(decimal_value.get_value_bytes(), &vec![7, 0, 0, 0]);
+    assert_eq!(
+        gold_data_type
+            .anonymize_value_bytes(&[42, 0, 0, 0], AnonymousValueStringFormat::String)
+            .expect("Expected the guest to format the value.")
+            .get_anonymous_value_string(),
+        "42 gold"
+    );
+    assert_eq!(
+        gold_data_type
+            .anonymize_value_bytes(&[42, 0, 0, 0], AnonymousValueStringFormat::Decimal)
+            .expect("Expected the storage type to format decimal values.")
+            .get_anonymous_value_string(),
+        "42"
+    );
+}
+
+#[test]
+fn discovery_loads_valid_plugins_and_skips_modules_with_undeclared_imports() {
+    let plugins_directory = tempfile::tempdir().expect("Expected a temporary plugins directory.");
+    let write_plugin = |directory_name: &str, manifest_json: &str, guest_wat: &str| {
+        let plugin_directory = plugins_directory.path().join(directory_name);
+        fs::create_dir_all(&plugin_directory).expect("Expected the plugin directory to be created.");
+        fs::write(plugin_directory.join(WASM_PLUGIN_MANIFEST_FILE_NAME), manifest_json).expect("Expected the manifest to be written.");
+        fs::write(plugin_directory.join("gold.wasm"), compile_guest(guest_wat)).expect("Expected the module to be written.");
+    };
+
+    write_plugin("gold", DATA_TYPE_MANIFEST_JSON, &data_type_guest_wat());
+    write_plugin(
+        "sneaky",
+        r#"{
+            "plugin_id": "acme.sneaky",
+            "display_name": "Sneaky",
+            "module_path": "gold.wasm",
+            "permissions": ["ReadProcessMemory"],
+            "memory_view": { "process_names": ["game.exe"] }
+        }"#,
+        r#"(import "squalr" "write_process_memory" (func (param i64 i32 i32) (result i32)))"#,
+    );
+
+    let plugin_packages = WasmPluginHost::new()
+        .expect("Expected the plugin host to start.")
+        .discover_plugin_packages(plugins_directory.path());
+
+    assert_eq!(plugin_packages.len(), 1);
+    assert_eq!(plugin_packages[0].metadata().get_plugin_id(), "acme.gold");
+    assert!(
+        plugin_packages[0]
+            .metadata()
+            .has_plugin_capability(PluginCapability::DataType)
+    );
+    assert!(!plugin_packages[0].metadata().get_is_built_in());
+    assert!(!plugin_packages[0].metadata().get_is_enabled_by_default());
+    assert!(
+        WasmPluginHost::new()
+            .expect("Expected the plugin host to start.")
+            .discover_plugin_packages(Path::new("/nonexistent/squalr/plugins"))
+            .is_empty()
+    );
+}
+
+#[test]
+fn loading_rejects_imports_not_covered_by_declared_permissions() {
+    let load_error = load_plugin(
+        r#"{
+            "plugin_id": "acme.sneaky",
+            "display_name": "Sneaky",
+            "module_path": "sneaky.wasm",
+            "permissions": ["ReadProcessMemory"],
+            "memory_view": { "process_names": ["game.exe"] }
+        }"#,
+        r#"(import "squalr" "write_process_memory" (func (param i64 i32 i32) (result i32)))"#,
+    )
+    .err()
+    .expect("Expected the plugin to be rejected.");
+    let unknown_import_error = load_plugin(
+        DATA_TYPE_MANIFEST_JSON,
+        &format!(
+            r#"(import "wasi_snapshot_preview1" "fd_write" (func (param i32 i32 i32 i32) (result i32))) {}"#,
+            data_type_guest_wat()
+        ),
+    )
+    .err()
+    .expect("Expected the plugin to be rejected.");
+
+    assert!(load_error.contains("Write process memory"), "{}", load_error);
+    assert!(unknown_import_error.contains("wasi_snapshot_preview1.fd_write"), "{}", unknown_import_error);
+}
+
+#[derive(Default)]
+struct TestSymbolTreeActionServices {
+    project_symbol_catalog: Mutex<ProjectSymbolCatalog>,
+    write_reasons: Mutex<Vec<String>>,
+    refresh_count: Mutex<usize>,
+}
+
+impl SymbolTreeActionServices for TestSymbolTreeActionServices {
+    fn symbol_store(&self) -> &dyn ProjectSymbolStore {
+        self
+    }
+
+    fn process_memory(&self) -> &dyn ProcessMemoryStore {
+        self
+    }
+
+    fn data_type_registry(&self) -> &dyn DataTypeRegistryStore {
+        self
+    }
+
+    fn symbol_tree_window(&self) -> &dyn SymbolTreeWindowStore {
+        self
+    }
+}
+
+impl ProjectSymbolStore for TestSymbolTreeActionServices {
+    fn read_catalog(&self) -> Result<ProjectSymbolCatalog, String> {
+        Ok(self.project_symbol_catalog.lock().unwrap().clone())
+    }
+
+    fn write_catalog(
+        &self,
+        reason: &str,
+        update_catalog: Box<dyn FnOnce(&mut ProjectSymbolCatalog) -> Result<(), String> + Send>,
+    ) -> Result<(), String> {
+        self.write_reasons.lock().unwrap().push(reason.to_string());
+
+        update_catalog(&mut self.project_symbol_catalog.lock().unwrap())
+    }
+}
+
+impl ProcessMemoryStore for TestSymbolTreeActionServices {
+    fn read_module_bytes(
+        &self,
+        _module_name: &str,
+        _offset: u64,
+        length: u64,
+    ) -> Result<Vec<u8>, String> {
+        Ok(vec![0; length as usize])
+    }
+}
+
+impl DataTypeRegistryStore for TestSymbolTreeActionServices {
+    fn get_registered_data_type_refs(&self) -> Vec<DataTypeRef> {
+        Vec::new()
+    }
+
+    fn get_unit_size_in_bytes(
+        &self,
+        _data_type_ref: &DataTypeRef,
+    ) -> u64 {
+        1
+    }
+}
+
+impl SymbolTreeWindowStore for TestSymbolTreeActionServices {
+    fn request_refresh(&self) {
+        *self.refresh_count.lock().unwrap() += 1;
+    }
+
+    fn focus_tree_node(
+        &self,
+        _tree_node_key: &str,
+    ) {
+    }
+}
+
+const SYMBOL_TREE_MANIFEST_JSON: &str = r#"{
+    "plugin_id": "acme.symbols",
+    "display_name": "Acme Symbols",
+    "module_path": "symbols.wasm",
+    "permissions": ["ReadSymbolStore", "WriteSymbolStore", "WriteSymbolTreeWindow"],
+    "symbol_tree_actions": [
+        {
+            "action_id": "acme.symbols.rewrite",
+            "label": "Rewrite Catalog",
+            "entry_point": "rewrite_catalog",
+            "required_permissions": ["ReadSymbolStore", "WriteSymbolStore", "WriteSymbolTreeWindow"],
+            "selection_kinds": ["ModuleRoot"]
+        },
+        {
+            "action_id": "acme.symbols.overreach",
+            "label": "Overreach",
+            "entry_point": "rewrite_catalog",
+            "required_permissions": ["ReadSymbolStore"]
+        },
+        {
+            "action_id": "acme.symbols.fail",
+            "label": "Fail",
+            "entry_point": "fail"
+        }
+    ]
+}"#;
+
+const SYMBOL_TREE_GUEST_WAT: &str = r#"
+    (import "squalr" "read_symbol_catalog" (func $read_symbol_catalog (result i64)))
+    (import "squalr" "write_symbol_catalog" (func $write_symbol_catalog (param i32 i32) (result i32)))
+    (import "squalr" "request_symbol_tree_refresh" (func $request_symbol_tree_refresh (result i32)))
+    (import "squalr" "report_error" (func $report_error (param i32 i32)))
+    (data (i32.const 16) "nothing to do")
+    (func (export "rewrite_catalog") (param i32 i32) (result i32)
+        (local $catalog i64)
+        (local.set $catalog (call $read_symbol_catalog))
+        (drop
+            (call $write_symbol_catalog
+                (i32.wrap_i64 (i64.shr_u (local.get $catalog) (i64.const 32)))
+                (i32.wrap_i64 (local.get $catalog))))
+        (call $request_symbol_tree_refresh))
+    (func (export "fail") (param i32 i32) (result i32)
+        (call $report_error (i32.const 16) (i32.const 13))
+        (i32.const 1))
+"#;
+
+#[test]
+fn symbol_tree_actions_reach_services_only_through_granted_permissions() {
+    let plugin_package = load_plugin(SYMBOL_TREE_MANIFEST_JSON, SYMBOL_TREE_GUEST_WAT).expect("Expected the symbol tree plugin to load.");
+    let symbol_tree_actions = plugin_package
+        .as_symbol_tree_plugin()
+        .expect("Expected the plugin to contribute symbol tree actions.")
+        .symbol_tree_actions();
+    let services = TestSymbolTreeActionServices::default();
+    services
+        .project_symbol_catalog
+        .lock()
+        .unwrap()
+        .get_symbol_modules_mut()
+        .push(ProjectSymbolModule::new(String::from("game.exe"), 0x1000));
+    let module_context = SymbolTreeActionContext::new(SymbolTreeActionSelection::ModuleRoot {
+        module_name: String::from("game.exe"),
+    });
+    let derived_context = SymbolTreeActionContext::default();
+
+    assert_eq!(symbol_tree_actions[0].action_id(), "acme.symbols.rewrite");
+    assert!(symbol_tree_actions[0].is_visible(&module_context));
+    assert!(!symbol_tree_actions[0].is_visible(&derived_context));
+    assert!(symbol_tree_actions[1].is_visible(&derived_context));
+    assert_eq!(symbol_tree_actions[1].required_permissions(), &[PluginPermission::ReadSymbolStore]);
+
+    symbol_tree_actions[0]
+        .execute(&module_context, &services)
+        .expect("Expected the action to rewrite the catalog.");
+
+    assert_eq!(services.write_reasons.lock().unwrap().len(), 1);
+    assert_eq!(*services.refresh_count.lock().unwrap(), 1);
+    assert_eq!(
+        services
+            .project_symbol_catalog
+            .lock()
+            .unwrap()
+            .get_symbol_modules()
+            .len(),
+        1
+    );
+
+    let overreach_error = symbol_tree_actions[1]
+        .execute(&module_context, &services)
+        .expect_err("Expected the write to trap without the write permission.");
+    let fail_error = symbol_tree_actions[2]
+        .execute(&module_context, &services)
+        .expect_err("Expected the guest to report a failure.");
+
+    assert!(overreach_error.contains("Write symbol store"), "{}", overreach_error);
+    assert_eq!(services.write_reasons.lock().unwrap().len(), 1);
+    assert!(fail_error.contains("nothing to do"), "{}", fail_error);
+}
+
+#[test]
+fn memory_views_expose_guest_pages_modules_and_reads() {
+    let pages_json = r#"[{"base_address":2147483648,"size":4096}]"#;
+    let modules_json = r#"[{"name":"ram","base_address":2147483648,"size":4096}]"#;
+    let guest_wat = format!(
+        r#"
+        (import "squalr" "report_error" (func $report_error (param i32 i32)))
+        (data (i32.const 16) "read-only")
+        (data (i32.const 64) "{pages_json}")
+        (data (i32.const 512) "{modules_json}")
+        (func (export "squalr_memory_view_get_pages") (result i64) (i64.const {pages_result}))
+        (func (export "squalr_memory_view_get_modules") (result i64) (i64.const {modules_result}))
+        (func (export "squalr_memory_view_read") (param i64 i32 i32) (result i32)
+            (memory.fill (local.get 1) (i32.const 171) (local.get 2))
+            (i32.const 0))
+        (func (export "squalr_memory_view_write") (param i64 i32 i32) (result i32)
+            (call $report_error (i32.const 16) (i32.const 9))
+            (i32.const 1))
+        "#,
+        pages_json = pages_json.replace('"', "\\\""),
+        modules_json = modules_json.replace('"', "\\\""),
+        pages_result = packed_buffer(64, pages_json.len()),
+        modules_result = packed_buffer(512, modules_json.len()),
+    );
+    let plugin_package = load_plugin(
+        r#"{
+            "plugin_id": "acme.console",
+            "display_name": "Acme Console",
+            "module_path": "console.wasm",
+            "permissions": ["ReadProcessMemory"],
+            "memory_view": { "process_names": ["AcmeConsole"] }
+        }"#,
+        &guest_wat,
+    )
+    .expect("Expected the memory view plugin to load.");
+    let memory_view_plugin = plugin_package
+        .as_memory_view_plugin()
+        .expect("Expected the plugin to contribute a memory view.");
+    let opened_process_info = OpenedProcessInfo::new(1, String::from("acmeconsole.exe"), 0, Bitness::Bit64, None);
+
+    assert!(memory_view_plugin.can_attach(&opened_process_info));
+    assert!(!memory_view_plugin.can_attach(&OpenedProcessInfo::new(1, String::from("other.exe"), 0, Bitness::Bit64, None)));
+
+    let memory_view_instance = memory_view_plugin
+        .create_instance(&opened_process_info)
+        .expect("Expected the memory view instance to be created.");
+    let virtual_pages = memory_view_instance
+        .get_virtual_pages(PageRetrievalMode::FromSettings)
+        .expect("Expected the guest to list its pages.");
+    let modules = memory_view_instance
+        .get_modules()
+        .expect("Expected the guest to list its modules.");
+    let mut values = [0u8; 8];
+
+    memory_view_instance
+        .read_bytes(0x8000_0000, &mut values)
+        .expect("Expected the guest to read bytes.");
+
+    assert_eq!(virtual_pages[0].get_base_address(), 0x8000_0000);
+    assert_eq!(virtual_pages[0].get_region_size(), 0x1000);
+    assert_eq!(modules[0].get_module_name(), "ram");
+    assert_eq!(values, [0xAB; 8]);
+    assert!(
+        memory_view_instance
+            .write_bytes(0x8000_0000, &[1, 2])
+            .expect_err("Expected the guest to refuse writes.")
+            .to_string()
+            .contains("read-only")
+    );
+}
+
+#[test]
+fn runaway_guests_trap_instead_of_hanging() {
+    let plugin_package = load_plugin(
+        r#"{
+            "plugin_id": "acme.spin",
+            "display_name": "Spin",
+            "module_path": "spin.wasm",
+            "data_types": [{ "data_type_id": "spin", "storage_data_type_id": "u8", "format_export": "spin" }]
+        }"#,
+        r#"(func (export "spin") (param i32 i32) (result i64) (loop $forever (br $forever)) (i64.const 0))"#,
+    )
+    .expect("Expected the plugin to load.");
+    let spin_data_type = &plugin_package
+        .as_data_type_plugin()
+        .expect("Expected the plugin to contribute data types.")
+        .contributed_data_types()[0];
+
+    assert!(
+        spin_data_type
+            .anonymize_value_bytes(&[0], AnonymousValueStringFormat::String)
+            .is_err()
+    );
+}
+
+#[test]
+fn guest_buffers_past_the_end_of_guest_memory_trap_before_the_host_reads_them() {
+    let plugin_package = load_plugin(
+        r#"{
+            "plugin_id": "acme.oversized",
+            "display_name": "Oversized",
+            "module_path": "oversized.wasm",
+            "memory_view": { "process_names": ["Oversized"] }
+        }"#,
+        r#"
+        (import "squalr" "log" (func $log (param i32 i32)))
+        (func (export "squalr_memory_view_get_pages") (result i64) (i64.const 0))
+        (func (export "squalr_memory_view_get_modules") (result i64) (i64.const 0))
+        (func (export "squalr_memory_view_read") (param i64 i32 i32) (result i32)
+            (call $log (i32.const 0) (i32.const 2147483647))
+            (i32.const 0))
+        (func (export "squalr_memory_view_write") (param i64 i32 i32) (result i32) (i32.const 1))
+        "#,
+    )
+    .expect("Expected the plugin to load.");
+    let memory_view_instance = plugin_package
+        .as_memory_view_plugin()
+        .expect("Expected the plugin to contribute a memory view.")
+        .create_instance(&OpenedProcessInfo::new(1, String::from("oversized.exe"), 0, Bitness::Bit64, None))
+        .expect("Expected the memory view instance to be created.");
+    let read_error = memory_view_instance
+        .read_bytes(0x1000, &mut [0u8; 4])
+        .expect_err("Expected the oversized log call to trap.")
+        .to_string();
+
+    assert!(read_error.contains("extends past the end of its memory"), "{}", read_error);
+}
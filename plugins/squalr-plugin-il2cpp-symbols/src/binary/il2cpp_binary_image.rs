@@ -0,0 +1,405 @@
+const PE_SIGNATURE: &[u8; 4] = b"PE\0\0";
+const PE_OPTIONAL_HEADER_MAGIC_PE32_PLUS: u16 = 0x20B;
+const PE_SECTION_HEADER_SIZE: usize = 40;
+const PE_SECTION_MEMORY_EXECUTE: u32 = 0x2000_0000;
+const ELF_MAGIC: &[u8; 4] = b"\x7FELF";
+const ELF_CLASS_64: u8 = 2;
+const ELF_MACHINE_X86_64: u16 = 62;
+const ELF_MACHINE_AARCH64: u16 = 183;
+const ELF_PROGRAM_HEADER_LOAD: u32 = 1;
+const ELF_PROGRAM_HEADER_DYNAMIC: u32 = 2;
+const ELF_PROGRAM_FLAG_EXECUTE: u32 = 1;
+const ELF_DYNAMIC_ENTRY_SIZE: usize = 16;
+const ELF_DYNAMIC_NULL: u64 = 0;
+const ELF_DYNAMIC_RELA: u64 = 7;
+const ELF_DYNAMIC_RELA_SIZE: u64 = 8;
+const ELF_RELA_ENTRY_SIZE: usize = 24;
+const ELF_RELOCATION_X86_64_RELATIVE: u32 = 8;
+const ELF_RELOCATION_AARCH64_RELATIVE: u32 = 1027;
+const POINTER_SIZE: u64 = 8;
+
+/// A file-backed range of the image, in the virtual addresses the image was linked at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Il2CppBinarySegment {
+    virtual_address: u64,
+    virtual_size: u64,
+    file_offset: u64,
+    file_size: u64,
+    is_executable: bool,
+}
+
+impl Il2CppBinarySegment {
+    fn contains_file_backed_range(
+        &self,
+        virtual_address: u64,
+        length: u64,
+    ) -> bool {
+        virtual_address >= self.virtual_address
+            && virtual_address
+                .checked_add(length)
+                .is_some_and(|range_end| range_end <= self.virtual_address + self.file_size.min(self.virtual_size))
+    }
+}
+
+/// A 64-bit `GameAssembly` (PE) or `libil2cpp`/`GameAssembly.so` (ELF) read from disk. Pointers stored in the image are
+/// link-time virtual addresses; ELF relative relocations are applied at load so data pointers read the same way for
+/// both formats.
+pub(crate) struct Il2CppBinaryImage {
+    image_bytes: Vec<u8>,
+    image_base: u64,
+    segments: Vec<Il2CppBinarySegment>,
+}
+
+impl Il2CppBinaryImage {
+    pub(crate) fn parse(image_bytes: Vec<u8>) -> Result<Self, String> {
+        if image_bytes.starts_with(b"MZ") {
+            Self::parse_pe(image_bytes)
+        } else if image_bytes.starts_with(ELF_MAGIC) {
+            Self::parse_elf(image_bytes)
+        } else {
+            Err(String::from("IL2CPP binary is neither a PE nor an ELF image."))
+        }
+    }
+
+    pub(crate) fn to_module_offset(
+        &self,
+        virtual_address: u64,
+    ) -> Option<u64> {
+        virtual_address.checked_sub(self.image_base)
+    }
+
+    pub(crate) fn is_mapped_address(
+        &self,
+        virtual_address: u64,
+    ) -> bool {
+        self.find_segment(virtual_address).is_some()
+    }
+
+    pub(crate) fn is_executable_address(
+        &self,
+        virtual_address: u64,
+    ) -> bool {
+        self.find_segment(virtual_address)
+            .is_some_and(|segment| segment.is_executable)
+    }
+
+    /// Returns the end of the segment that contains the address, in virtual addresses.
+    pub(crate) fn get_segment_end(
+        &self,
+        virtual_address: u64,
+    ) -> Option<u64> {
+        self.find_segment(virtual_address)
+            .map(|segment| segment.virtual_address + segment.virtual_size)
+    }
+
+    pub(crate) fn read_bytes(
+        &self,
+        virtual_address: u64,
+        length: u64,
+    ) -> Option<&[u8]> {
+        let segment = self
+            .segments
+            .iter()
+            .find(|segment| segment.contains_file_backed_range(virtual_address, length))?;
+        let file_offset = usize::try_from(segment.file_offset + (virtual_address - segment.virtual_address)).ok()?;
+
+        self.image_bytes
+            .get(file_offset..file_offset.checked_add(usize::try_from(length).ok()?)?)
+    }
+
+    pub(crate) fn read_u32(
+        &self,
+        virtual_address: u64,
+    ) -> Option<u32> {
+        Some(u32::from_le_bytes(self.read_bytes(virtual_address, 4)?.try_into().ok()?))
+    }
+
+    pub(crate) fn read_i32(
+        &self,
+        virtual_address: u64,
+    ) -> Option<i32> {
+        self.read_u32(virtual_address).map(|value| value as i32)
+    }
+
+    pub(crate) fn read_pointer(
+        &self,
+        virtual_address: u64,
+    ) -> Option<u64> {
+        Some(u64::from_le_bytes(
+            self.read_bytes(virtual_address, POINTER_SIZE)?
+                .try_into()
+                .ok()?,
+        ))
+    }
+
+    /// Reads a NUL-terminated string that fits within `max_length` bytes of one segment.
+    pub(crate) fn read_c_string(
+        &self,
+        virtual_address: u64,
+        max_length: u64,
+    ) -> Option<&[u8]> {
+        let segment = self
+            .segments
+            .iter()
+            .find(|segment| segment.contains_file_backed_range(virtual_address, 1))?;
+        let available_length = (segment.virtual_address + segment.file_size.min(segment.virtual_size) - virtual_address).min(max_length);
+        let string_bytes = self.read_bytes(virtual_address, available_length)?;
+        let string_length = string_bytes.iter().position(|string_byte| *string_byte == 0)?;
+
+        Some(&string_bytes[..string_length])
+    }
+
+    /// Yields every pointer-aligned word in non-executable segments, where the registration tables live.
+    pub(crate) fn data_words(&self) -> impl Iterator<Item = (u64, u64)> + '_ {
+        self.segments
+            .iter()
+            .filter(|segment| !segment.is_executable)
+            .flat_map(move |segment| {
+                let aligned_start = segment.virtual_address.next_multiple_of(POINTER_SIZE);
+                let file_backed_end = segment.virtual_address + segment.file_size.min(segment.virtual_size);
+
+                (aligned_start..file_backed_end.saturating_sub(POINTER_SIZE - 1))
+                    .step_by(POINTER_SIZE as usize)
+                    .filter_map(move |virtual_address| Some((virtual_address, self.read_pointer(virtual_address)?)))
+            })
+    }
+
+    fn find_segment(
+        &self,
+        virtual_address: u64,
+    ) -> Option<&Il2CppBinarySegment> {
+        self.segments
+            .iter()
+            .find(|segment| virtual_address >= segment.virtual_address && virtual_address - segment.virtual_address < segment.virtual_size)
+    }
+
+    fn parse_pe(image_bytes: Vec<u8>) -> Result<Self, String> {
+        let nt_headers_offset = read_u32(&image_bytes, 0x3C).ok_or_else(|| String::from("PE image is truncated."))? as usize;
+
+        if image_bytes.get(nt_headers_offset..nt_headers_offset + 4) != Some(PE_SIGNATURE.as_slice()) {
+            return Err(String::from("PE image has no NT headers."));
+        }
+
+        let file_header_offset = nt_headers_offset + 4;
+        let section_count = read_u16(&image_bytes, file_header_offset + 2).ok_or_else(|| String::from("PE image is truncated."))? as usize;
+        let optional_header_size = read_u16(&image_bytes, file_header_offset + 16).ok_or_else(|| String::from("PE image is truncated."))? as usize;
+        let optional_header_offset = file_header_offset + 20;
+
+        if read_u16(&image_bytes, optional_header_offset) != Some(PE_OPTIONAL_HEADER_MAGIC_PE32_PLUS) {
+            return Err(String::from("Only 64-bit IL2CPP binaries are supported."));
+        }
+
+        let image_base = read_u64(&image_bytes, optional_header_offset + 24).ok_or_else(|| String::from("PE image is truncated."))?;
+        let section_headers_offset = optional_header_offset + optional_header_size;
+        let segments = (0..section_count)
+            .map(|section_index| {
+                let section_header_offset = section_headers_offset + section_index * PE_SECTION_HEADER_SIZE;
+                let read_section_u32 = |field_offset: usize| {
+                    read_u32(&image_bytes, section_header_offset + field_offset).ok_or_else(|| String::from("PE section table is truncated."))
+                };
+                let virtual_size = read_section_u32(8)? as u64;
+                let raw_data_size = read_section_u32(16)? as u64;
+
+                Ok(Il2CppBinarySegment {
+                    virtual_address: image_base + read_section_u32(12)? as u64,
+                    virtual_size: if virtual_size == 0 { raw_data_size } else { virtual_size },
+                    file_offset: read_section_u32(20)? as u64,
+                    file_size: raw_data_size,
+                    is_executable: read_section_u32(36)? & PE_SECTION_MEMORY_EXECUTE != 0,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Self::new_checked(image_bytes, image_base, segments)
+    }
+
+    fn parse_elf(image_bytes: Vec<u8>) -> Result<Self, String> {
+        if image_bytes.get(4) != Some(&ELF_CLASS_64) {
+            return Err(String::from("Only 64-bit IL2CPP binaries are supported."));
+        }
+
+        let machine = read_u16(&image_bytes, 0x12).ok_or_else(|| String::from("ELF image is truncated."))?;
+        let relative_relocation_type = match machine {
+            ELF_MACHINE_X86_64 => ELF_RELOCATION_X86_64_RELATIVE,
+            ELF_MACHINE_AARCH64 => ELF_RELOCATION_AARCH64_RELATIVE,
+            _ => return Err(format!("ELF machine {machine} is not supported for IL2CPP binaries.")),
+        };
+        let program_headers_offset = read_u64(&image_bytes, 0x20).ok_or_else(|| String::from("ELF image is truncated."))? as usize;
+        let program_header_size = read_u16(&image_bytes, 0x36).ok_or_else(|| String::from("ELF image is truncated."))? as usize;
+        let program_header_count = read_u16(&image_bytes, 0x38).ok_or_else(|| String::from("ELF image is truncated."))? as usize;
+        let mut segments = Vec::new();
+        let mut dynamic_segment = None;
+
+        for program_header_index in 0..program_header_count {
+            let program_header_offset = program_headers_offset + program_header_index * program_header_size;
+            let read_program_header_u64 = |field_offset: usize| {
+                read_u64(&image_bytes, program_header_offset + field_offset).ok_or_else(|| String::from("ELF program headers are truncated."))
+            };
+            let program_header_type = read_u32(&image_bytes, program_header_offset).ok_or_else(|| String::from("ELF program headers are truncated."))?;
+            let program_header_flags = read_u32(&image_bytes, program_header_offset + 4).ok_or_else(|| String::from("ELF program headers are truncated."))?;
+
+            match program_header_type {
+                ELF_PROGRAM_HEADER_LOAD => segments.push(Il2CppBinarySegment {
+                    virtual_address: read_program_header_u64(0x10)?,
+                    virtual_size: read_program_header_u64(0x28)?,
+                    file_offset: read_program_header_u64(0x08)?,
+                    file_size: read_program_header_u64(0x20)?,
+                    is_executable: program_header_flags & ELF_PROGRAM_FLAG_EXECUTE != 0,
+                }),
+                ELF_PROGRAM_HEADER_DYNAMIC => dynamic_segment = Some((read_program_header_u64(0x08)?, read_program_header_u64(0x20)?)),
+                _ => {}
+            }
+        }
+
+        let image_base = segments
+            .iter()
+            .map(|segment| segment.virtual_address)
+            .min()
+            .ok_or_else(|| String::from("ELF image has no loadable segments."))?;
+        let mut binary_image = Self::new_checked(image_bytes, image_base, segments)?;
+
+        if let Some((dynamic_file_offset, dynamic_file_size)) = dynamic_segment {
+            binary_image.apply_elf_relative_relocations(dynamic_file_offset, dynamic_file_size, relative_relocation_type);
+        }
+
+        Ok(binary_image)
+    }
+
+    fn new_checked(
+        image_bytes: Vec<u8>,
+        image_base: u64,
+        segments: Vec<Il2CppBinarySegment>,
+    ) -> Result<Self, String> {
+        let image_length = image_bytes.len() as u64;
+
+        if segments.iter().any(|segment| {
+            segment
+                .file_offset
+                .checked_add(segment.file_size)
+                .is_none_or(|segment_end| segment_end > image_length)
+        }) {
+            return Err(String::from("IL2CPP binary has a segment that extends past the end of the file."));
+        }
+
+        Ok(Self {
+            image_bytes,
+            image_base,
+            segments,
+        })
+    }
+
+    /// Position-independent builds leave data pointers zeroed in the file and store their targets as `RELA` addends.
+    /// Packed `RELR` relocations keep their targets in place, so they need no patching for a link-time view.
+    fn apply_elf_relative_relocations(
+        &mut self,
+        dynamic_file_offset: u64,
+        dynamic_file_size: u64,
+        relative_relocation_type: u32,
+    ) {
+        let mut relocation_table_address = None;
+        let mut relocation_table_size = 0_u64;
+
+        for dynamic_entry_offset in (dynamic_file_offset..dynamic_file_offset + dynamic_file_size).step_by(ELF_DYNAMIC_ENTRY_SIZE) {
+            let (Some(dynamic_tag), Some(dynamic_value)) = (
+                read_u64(&self.image_bytes, dynamic_entry_offset as usize),
+                read_u64(&self.image_bytes, dynamic_entry_offset as usize + 8),
+            ) else {
+                break;
+            };
+
+            match dynamic_tag {
+                ELF_DYNAMIC_NULL => break,
+                ELF_DYNAMIC_RELA => relocation_table_address = Some(dynamic_value),
+                ELF_DYNAMIC_RELA_SIZE => relocation_table_size = dynamic_value,
+                _ => {}
+            }
+        }
+
+        let Some(relocation_table_bytes) = relocation_table_address
+            .and_then(|relocation_table_address| self.read_bytes(relocation_table_address, relocation_table_size))
+            .map(<[u8]>::to_vec)
+        else {
+            return;
+        };
+
+        for relocation_entry in relocation_table_bytes.chunks_exact(ELF_RELA_ENTRY_SIZE) {
+            let (Some(target_address), Some(relocation_info), Some(addend)) =
+                (read_u64(relocation_entry, 0), read_u64(relocation_entry, 8), read_u64(relocation_entry, 16))
+            else {
+                continue;
+            };
+
+            if relocation_info as u32 != relative_relocation_type {
+                continue;
+            }
+
+            let Some(segment) = self
+                .segments
+                .iter()
+                .find(|segment| segment.contains_file_backed_range(target_address, POINTER_SIZE))
+            else {
+                continue;
+            };
+            let file_offset = (segment.file_offset + (target_address - segment.virtual_address)) as usize;
+
+            self.image_bytes[file_offset..file_offset + POINTER_SIZE as usize].copy_from_slice(&addend.to_le_bytes());
+        }
+    }
+}
+
+fn read_u16(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<u16> {
+    Some(u16::from_le_bytes(bytes.get(offset..offset.checked_add(2)?)?.try_into().ok()?))
+}
+
+fn read_u32(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<u32> {
+    Some(u32::from_le_bytes(bytes.get(offset..offset.checked_add(4)?)?.try_into().ok()?))
+}
+
+fn read_u64(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<u64> {
+    Some(u64::from_le_bytes(bytes.get(offset..offset.checked_add(8)?)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Il2CppBinaryImage;
+    use crate::test_il2cpp_files::{TEST_CODE_GEN_MODULE_RVA, TEST_PE_IMAGE_BASE, build_test_elf64_game_assembly, build_test_pe64_game_assembly};
+
+    #[test]
+    fn parse_pe_maps_sections_at_the_preferred_base() {
+        let binary_image = Il2CppBinaryImage::parse(build_test_pe64_game_assembly()).expect("Expected the test PE to parse.");
+        let module_name_address = binary_image
+            .read_pointer(TEST_PE_IMAGE_BASE + TEST_CODE_GEN_MODULE_RVA)
+            .expect("Expected the code gen module to be readable.");
+
+        assert_eq!(
+            binary_image.to_module_offset(TEST_PE_IMAGE_BASE + TEST_CODE_GEN_MODULE_RVA),
+            Some(TEST_CODE_GEN_MODULE_RVA)
+        );
+        assert_eq!(binary_image.read_c_string(module_name_address, 64), Some(b"Assembly-CSharp.dll".as_slice()));
+    }
+
+    #[test]
+    fn parse_elf_applies_relative_relocations() {
+        let binary_image = Il2CppBinaryImage::parse(build_test_elf64_game_assembly()).expect("Expected the test ELF to parse.");
+        let module_name_address = binary_image
+            .read_pointer(TEST_CODE_GEN_MODULE_RVA)
+            .expect("Expected the code gen module to be readable.");
+
+        assert_eq!(binary_image.to_module_offset(TEST_CODE_GEN_MODULE_RVA), Some(TEST_CODE_GEN_MODULE_RVA));
+        assert_eq!(binary_image.read_c_string(module_name_address, 64), Some(b"Assembly-CSharp.dll".as_slice()));
+    }
+
+    #[test]
+    fn parse_rejects_unknown_formats() {
+        assert!(Il2CppBinaryImage::parse(b"not a binary".to_vec()).is_err());
+    }
+}
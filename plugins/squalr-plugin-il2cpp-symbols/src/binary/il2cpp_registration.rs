@@ -0,0 +1,272 @@
+use crate::binary::il2cpp_binary_image::Il2CppBinaryImage;
+use std::collections::{HashMap, HashSet};
+
+const POINTER_SIZE: u64 = 8;
+/// `Il2CppMetadataRegistration` is a run of `{ count, pointer }` pairs, each padded to 16 bytes on 64-bit targets.
+const METADATA_REGISTRATION_PAIR_SIZE: u64 = 16;
+const METADATA_REGISTRATION_TYPES_PAIR: u64 = 3;
+const METADATA_REGISTRATION_FIELD_OFFSETS_PAIR: u64 = 5;
+const METADATA_REGISTRATION_TYPE_DEFINITION_SIZES_PAIR: u64 = 6;
+const MAX_TYPE_COUNT: u64 = 0x100_0000;
+const CODE_GEN_MODULE_METHOD_POINTER_COUNT: u64 = 8;
+const CODE_GEN_MODULE_METHOD_POINTERS: u64 = 16;
+const MAX_MODULE_NAME_LENGTH: u64 = 256;
+const IL2CPP_TYPE_DATA: u64 = 0;
+const IL2CPP_TYPE_BITS: u64 = 8;
+const TYPE_DEFINITION_SIZES_INSTANCE_SIZE: u64 = 0;
+const TYPE_DEFINITION_SIZES_STATIC_FIELDS_SIZE: u64 = 8;
+const METHOD_TOKEN_ROW_MASK: u32 = 0x00FF_FFFF;
+
+/// The `Il2CppType` fields needed to shape a field: ECMA element type, field attributes and, for class and value types,
+/// the type definition index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppTypeInfo {
+    pub(crate) data: u64,
+    pub(crate) attributes: u16,
+    pub(crate) type_code: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppTypeDefinitionSizes {
+    pub(crate) instance_size: u64,
+    pub(crate) static_fields_size: u64,
+}
+
+/// Tables from `Il2CppMetadataRegistration`, which maps metadata indices to runtime types, field offsets and type sizes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppMetadataRegistration {
+    pub(crate) address: u64,
+    types_count: u64,
+    types_address: u64,
+    field_offsets_address: u64,
+    type_definition_sizes_address: u64,
+}
+
+impl Il2CppMetadataRegistration {
+    /// Field offsets and type sizes are both tables with one entry per type definition, so the registration is found by
+    /// looking for two adjacent pairs whose counts equal the metadata's type definition count.
+    pub(crate) fn locate(
+        binary_image: &Il2CppBinaryImage,
+        type_definition_count: u64,
+    ) -> Result<Self, String> {
+        if type_definition_count == 0 {
+            return Err(String::from("IL2CPP metadata declares no types."));
+        }
+
+        binary_image
+            .data_words()
+            .filter(|(_, word)| *word == type_definition_count)
+            .find_map(|(field_offsets_count_address, _)| {
+                let address = field_offsets_count_address.checked_sub(METADATA_REGISTRATION_FIELD_OFFSETS_PAIR * METADATA_REGISTRATION_PAIR_SIZE)?;
+
+                Self::read_candidate(binary_image, address, type_definition_count)
+            })
+            .ok_or_else(|| String::from("Could not find the IL2CPP metadata registration in the binary."))
+    }
+
+    fn read_candidate(
+        binary_image: &Il2CppBinaryImage,
+        address: u64,
+        type_definition_count: u64,
+    ) -> Option<Self> {
+        let read_pair = |pair_index: u64| {
+            let pair_address = address + pair_index * METADATA_REGISTRATION_PAIR_SIZE;
+
+            Some((
+                binary_image.read_pointer(pair_address)?,
+                binary_image.read_pointer(pair_address + POINTER_SIZE)?,
+            ))
+        };
+        let (types_count, types_address) = read_pair(METADATA_REGISTRATION_TYPES_PAIR)?;
+        let (field_offsets_count, field_offsets_address) = read_pair(METADATA_REGISTRATION_FIELD_OFFSETS_PAIR)?;
+        let (type_definition_sizes_count, type_definition_sizes_address) = read_pair(METADATA_REGISTRATION_TYPE_DEFINITION_SIZES_PAIR)?;
+        let is_valid = field_offsets_count == type_definition_count
+            && type_definition_sizes_count == type_definition_count
+            && types_count > 0
+            && types_count <= MAX_TYPE_COUNT
+            && [
+                types_address,
+                field_offsets_address,
+                type_definition_sizes_address,
+            ]
+            .iter()
+            .all(|table_address| binary_image.is_mapped_address(*table_address));
+
+        is_valid.then_some(Self {
+            address,
+            types_count,
+            types_address,
+            field_offsets_address,
+            type_definition_sizes_address,
+        })
+    }
+
+    pub(crate) fn read_type(
+        &self,
+        binary_image: &Il2CppBinaryImage,
+        type_index: i32,
+    ) -> Option<Il2CppTypeInfo> {
+        let type_index = u64::try_from(type_index)
+            .ok()
+            .filter(|type_index| *type_index < self.types_count)?;
+        let type_address = binary_image.read_pointer(self.types_address + type_index * POINTER_SIZE)?;
+        let type_bits = binary_image.read_u32(type_address + IL2CPP_TYPE_BITS)?;
+
+        Some(Il2CppTypeInfo {
+            data: binary_image.read_pointer(type_address + IL2CPP_TYPE_DATA)?,
+            attributes: type_bits as u16,
+            type_code: (type_bits >> 16) as u8,
+        })
+    }
+
+    /// Types without instance or static fields have no offset table.
+    pub(crate) fn read_field_offset(
+        &self,
+        binary_image: &Il2CppBinaryImage,
+        type_definition_index: usize,
+        field_index_in_type: usize,
+    ) -> Option<i32> {
+        let field_offsets_address = binary_image.read_pointer(self.field_offsets_address + type_definition_index as u64 * POINTER_SIZE)?;
+
+        if field_offsets_address == 0 {
+            return None;
+        }
+
+        binary_image.read_i32(field_offsets_address + field_index_in_type as u64 * 4)
+    }
+
+    pub(crate) fn read_type_definition_sizes(
+        &self,
+        binary_image: &Il2CppBinaryImage,
+        type_definition_index: usize,
+    ) -> Option<Il2CppTypeDefinitionSizes> {
+        let type_definition_sizes_address = binary_image.read_pointer(self.type_definition_sizes_address + type_definition_index as u64 * POINTER_SIZE)?;
+
+        Some(Il2CppTypeDefinitionSizes {
+            instance_size: binary_image.read_u32(type_definition_sizes_address + TYPE_DEFINITION_SIZES_INSTANCE_SIZE)? as u64,
+            static_fields_size: binary_image.read_u32(type_definition_sizes_address + TYPE_DEFINITION_SIZES_STATIC_FIELDS_SIZE)? as u64,
+        })
+    }
+}
+
+/// An `Il2CppCodeGenModule`, which holds the method pointers of one image indexed by method token row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppCodeGenModule {
+    method_pointer_count: u64,
+    method_pointers_address: u64,
+}
+
+impl Il2CppCodeGenModule {
+    /// Code gen modules start with a pointer to their image's name, so each is found by a data word that points at a
+    /// matching string. Metadata 24.0 and 24.1 predate code gen modules and yield none.
+    pub(crate) fn locate_all(
+        binary_image: &Il2CppBinaryImage,
+        image_names: &[String],
+    ) -> HashMap<String, Self> {
+        let image_names = image_names.iter().map(String::as_bytes).collect::<HashSet<_>>();
+        let mut code_gen_modules = HashMap::new();
+
+        for (module_address, module_name_address) in binary_image.data_words() {
+            if code_gen_modules.len() == image_names.len() {
+                break;
+            }
+
+            if !binary_image.is_mapped_address(module_name_address) {
+                continue;
+            }
+
+            let Some(module_name) = binary_image
+                .read_c_string(module_name_address, MAX_MODULE_NAME_LENGTH)
+                .filter(|module_name| image_names.contains(module_name))
+            else {
+                continue;
+            };
+            let module_name = String::from_utf8_lossy(module_name).into_owned();
+
+            if code_gen_modules.contains_key(&module_name) {
+                continue;
+            }
+
+            let (Some(method_pointer_count), Some(method_pointers_address)) = (
+                binary_image.read_u32(module_address + CODE_GEN_MODULE_METHOD_POINTER_COUNT),
+                binary_image.read_pointer(module_address + CODE_GEN_MODULE_METHOD_POINTERS),
+            ) else {
+                continue;
+            };
+
+            if method_pointer_count > 0 && !binary_image.is_mapped_address(method_pointers_address) {
+                continue;
+            }
+
+            code_gen_modules.insert(
+                module_name,
+                Self {
+                    method_pointer_count: method_pointer_count as u64,
+                    method_pointers_address,
+                },
+            );
+        }
+
+        code_gen_modules
+    }
+
+    /// Abstract methods and uninstantiated generic methods have no code, and read as a null pointer.
+    pub(crate) fn read_method_pointer(
+        &self,
+        binary_image: &Il2CppBinaryImage,
+        method_token: u32,
+    ) -> Option<u64> {
+        let method_row = (method_token & METHOD_TOKEN_ROW_MASK) as u64;
+
+        if method_row == 0 || method_row > self.method_pointer_count {
+            return None;
+        }
+
+        binary_image
+            .read_pointer(self.method_pointers_address + (method_row - 1) * POINTER_SIZE)
+            .filter(|method_pointer| *method_pointer != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Il2CppCodeGenModule, Il2CppMetadataRegistration};
+    use crate::{
+        binary::il2cpp_binary_image::Il2CppBinaryImage,
+        test_il2cpp_files::{
+            TEST_METADATA_REGISTRATION_RVA, TEST_PE_IMAGE_BASE, TEST_PLAYER_TYPE_INDEX, TEST_TYPE_DEFINITION_COUNT, TEST_UPDATE_METHOD_RVA,
+            build_test_pe64_game_assembly,
+        },
+    };
+
+    #[test]
+    fn locate_finds_metadata_registration_tables() {
+        let binary_image = Il2CppBinaryImage::parse(build_test_pe64_game_assembly()).expect("Expected the test PE to parse.");
+        let metadata_registration =
+            Il2CppMetadataRegistration::locate(&binary_image, TEST_TYPE_DEFINITION_COUNT as u64).expect("Expected the registration to be found.");
+        let player_sizes = metadata_registration
+            .read_type_definition_sizes(&binary_image, TEST_PLAYER_TYPE_INDEX)
+            .expect("Expected player sizes.");
+
+        assert_eq!(metadata_registration.address, TEST_PE_IMAGE_BASE + TEST_METADATA_REGISTRATION_RVA);
+        assert_eq!((player_sizes.instance_size, player_sizes.static_fields_size), (0x30, 4));
+        assert_eq!(metadata_registration.read_field_offset(&binary_image, TEST_PLAYER_TYPE_INDEX, 1), Some(0x20));
+        assert!(Il2CppMetadataRegistration::locate(&binary_image, TEST_TYPE_DEFINITION_COUNT as u64 + 1).is_err());
+    }
+
+    #[test]
+    fn locate_all_finds_code_gen_modules_by_image_name() {
+        let binary_image = Il2CppBinaryImage::parse(build_test_pe64_game_assembly()).expect("Expected the test PE to parse.");
+        let code_gen_modules = Il2CppCodeGenModule::locate_all(&binary_image, &[String::from("Assembly-CSharp.dll"), String::from("Missing.dll")]);
+        let code_gen_module = code_gen_modules
+            .get("Assembly-CSharp.dll")
+            .expect("Expected the code gen module to be found.");
+
+        assert_eq!(code_gen_modules.len(), 1);
+        assert_eq!(
+            code_gen_module.read_method_pointer(&binary_image, 0x0600_0001),
+            Some(TEST_PE_IMAGE_BASE + TEST_UPDATE_METHOD_RVA)
+        );
+        assert_eq!(code_gen_module.read_method_pointer(&binary_image, 0x0600_0003), None);
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod il2cpp_binary_image;
+pub(crate) mod il2cpp_registration;
@@ -0,0 +1,3 @@
+pub const IL2CPP_SYMBOLS_PLUGIN_ID: &str = "builtin.symbols.il2cpp";
+pub const IL2CPP_SYMBOLS_PLUGIN_DISPLAY_NAME: &str = "IL2CPP Symbols";
+pub const IL2CPP_SYMBOLS_PLUGIN_DESCRIPTION: &str = "Imports class layouts and method addresses for IL2CPP (Unity) games from global-metadata.dat.";
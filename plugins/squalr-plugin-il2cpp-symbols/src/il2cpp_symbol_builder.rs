@@ -0,0 +1,667 @@
+use crate::{
+    binary::{
+        il2cpp_binary_image::Il2CppBinaryImage,
+        il2cpp_registration::{Il2CppCodeGenModule, Il2CppMetadataRegistration},
+    },
+    metadata::il2cpp_metadata::Il2CppMetadata,
+};
+use squalr_engine_api::{
+    registries::symbols::struct_layout_descriptor::StructLayoutDescriptor,
+    structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::container_type::ContainerType,
+        pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize,
+        projects::symbol_layouts::symbol_layout_field_materializer::{SymbolLayoutFieldMaterializer, SymbolLayoutPositionedField},
+        structs::{
+            symbolic_field_definition::SymbolicFieldDefinition,
+            symbolic_struct_definition::{SymbolicLayoutKind, SymbolicStructDefinition},
+        },
+    },
+};
+use std::collections::{HashMap, HashSet};
+
+const LAYOUT_ID_PREFIX: &str = "il2cpp";
+const STATICS_LAYOUT_ID_SUFFIX: &str = "statics";
+const ENUM_VALUE_FIELD_NAME: &str = "value__";
+const METADATA_REGISTRATION_FIELD_NAME: &str = "Il2CppMetadataRegistration";
+const METADATA_REGISTRATION_SIZE_IN_BYTES: u64 = 0x80;
+const POINTER_SIZE: u64 = 8;
+/// `Il2CppObject` is a class pointer followed by a monitor pointer.
+const OBJECT_HEADER_SIZE: u64 = 0x10;
+const MAX_PARENT_DEPTH: usize = 64;
+const FIELD_ATTRIBUTE_STATIC: u16 = 0x0010;
+const FIELD_ATTRIBUTE_LITERAL: u16 = 0x0040;
+const IL2CPP_TYPE_BOOLEAN: u8 = 0x02;
+const IL2CPP_TYPE_CHAR: u8 = 0x03;
+const IL2CPP_TYPE_I1: u8 = 0x04;
+const IL2CPP_TYPE_U1: u8 = 0x05;
+const IL2CPP_TYPE_I2: u8 = 0x06;
+const IL2CPP_TYPE_U2: u8 = 0x07;
+const IL2CPP_TYPE_I4: u8 = 0x08;
+const IL2CPP_TYPE_U4: u8 = 0x09;
+const IL2CPP_TYPE_I8: u8 = 0x0A;
+const IL2CPP_TYPE_U8: u8 = 0x0B;
+const IL2CPP_TYPE_R4: u8 = 0x0C;
+const IL2CPP_TYPE_R8: u8 = 0x0D;
+const IL2CPP_TYPE_STRING: u8 = 0x0E;
+const IL2CPP_TYPE_PTR: u8 = 0x0F;
+const IL2CPP_TYPE_VALUETYPE: u8 = 0x11;
+const IL2CPP_TYPE_CLASS: u8 = 0x12;
+const IL2CPP_TYPE_ARRAY: u8 = 0x14;
+const IL2CPP_TYPE_I: u8 = 0x18;
+const IL2CPP_TYPE_U: u8 = 0x19;
+const IL2CPP_TYPE_FNPTR: u8 = 0x1B;
+const IL2CPP_TYPE_OBJECT: u8 = 0x1C;
+const IL2CPP_TYPE_SZARRAY: u8 = 0x1D;
+
+/// A named range of the IL2CPP binary, as a module offset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppModuleField {
+    pub(crate) display_name: String,
+    pub(crate) offset: u64,
+    pub(crate) struct_layout_id: String,
+    pub(crate) size_in_bytes: u64,
+}
+
+/// Layouts for every type that declares fields, plus module fields for the metadata registration and method code.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Il2CppSymbols {
+    pub(crate) struct_layout_descriptors: Vec<StructLayoutDescriptor>,
+    pub(crate) module_fields: Vec<Il2CppModuleField>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Il2CppFieldInfo {
+    name: String,
+    offset: u64,
+    type_code: u8,
+    is_static: bool,
+    /// The type definition named by `CLASS` and `VALUETYPE` field types.
+    referenced_type_definition_index: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Il2CppClassInfo {
+    qualified_name: String,
+    instance_size: u64,
+    static_fields_size: u64,
+    parent_type_definition_index: Option<usize>,
+    fields: Vec<Il2CppFieldInfo>,
+}
+
+#[derive(Clone, Debug)]
+struct FieldShape {
+    data_type_id: String,
+    container_type: ContainerType,
+    size_in_bytes: u64,
+}
+
+/// Joins metadata names with the binary's registration tables. Instance layouts describe objects as they sit on the
+/// managed heap, header and inherited fields included, because IL2CPP field offsets count the object header. Value
+/// types therefore describe their boxed form. Static layouts describe the block that `Il2CppClass::static_fields` points
+/// to once the class is initialized.
+pub(crate) struct Il2CppSymbolBuilder<'a> {
+    metadata: &'a Il2CppMetadata,
+    binary_image: &'a Il2CppBinaryImage,
+    metadata_registration: &'a Il2CppMetadataRegistration,
+    code_gen_modules: &'a HashMap<String, Il2CppCodeGenModule>,
+    classes_by_type_index: HashMap<usize, Il2CppClassInfo>,
+    layout_ids_by_type_index: HashMap<usize, String>,
+}
+
+impl<'a> Il2CppSymbolBuilder<'a> {
+    pub(crate) fn new(
+        metadata: &'a Il2CppMetadata,
+        binary_image: &'a Il2CppBinaryImage,
+        metadata_registration: &'a Il2CppMetadataRegistration,
+        code_gen_modules: &'a HashMap<String, Il2CppCodeGenModule>,
+    ) -> Self {
+        let classes_by_type_index = (0..metadata.type_definitions.len())
+            .map(|type_definition_index| {
+                (
+                    type_definition_index,
+                    read_class(metadata, binary_image, metadata_registration, type_definition_index),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        let mut classes_with_fields = classes_by_type_index
+            .iter()
+            .filter(|(_, class_info)| !class_info.fields.is_empty())
+            .collect::<Vec<_>>();
+        let mut used_layout_ids = HashSet::new();
+        let mut layout_ids_by_type_index = HashMap::new();
+
+        classes_with_fields.sort_by(|left_class, right_class| {
+            left_class
+                .1
+                .qualified_name
+                .cmp(&right_class.1.qualified_name)
+                .then_with(|| left_class.0.cmp(right_class.0))
+        });
+
+        // Types from different assemblies can share a name, so later duplicates are told apart by type index.
+        for (type_definition_index, class_info) in classes_with_fields {
+            let mut layout_id = build_layout_id(&class_info.qualified_name);
+
+            if !used_layout_ids.insert(layout_id.clone()) {
+                layout_id = format!("{layout_id}_{type_definition_index}");
+                used_layout_ids.insert(layout_id.clone());
+            }
+
+            layout_ids_by_type_index.insert(*type_definition_index, layout_id);
+        }
+
+        Self {
+            metadata,
+            binary_image,
+            metadata_registration,
+            code_gen_modules,
+            classes_by_type_index,
+            layout_ids_by_type_index,
+        }
+    }
+
+    pub(crate) fn build(&self) -> Il2CppSymbols {
+        let mut il2cpp_symbols = Il2CppSymbols::default();
+        let mut layout_entries = self
+            .layout_ids_by_type_index
+            .iter()
+            .filter_map(|(type_definition_index, layout_id)| Some((layout_id, self.classes_by_type_index.get(type_definition_index)?)))
+            .collect::<Vec<_>>();
+
+        layout_entries.sort_by(|left_entry, right_entry| left_entry.0.cmp(right_entry.0));
+
+        for (layout_id, class_info) in layout_entries {
+            if let Some(instance_layout_descriptor) = self.build_instance_layout(layout_id, class_info) {
+                il2cpp_symbols
+                    .struct_layout_descriptors
+                    .push(instance_layout_descriptor);
+            }
+
+            let statics_layout_id = format!("{layout_id}.{STATICS_LAYOUT_ID_SUFFIX}");
+
+            if let Some(statics_layout_descriptor) = self.build_statics_layout(&statics_layout_id, class_info) {
+                il2cpp_symbols
+                    .struct_layout_descriptors
+                    .push(statics_layout_descriptor);
+            }
+        }
+
+        il2cpp_symbols.module_fields = self.build_module_fields();
+
+        il2cpp_symbols
+    }
+
+    fn build_instance_layout(
+        &self,
+        layout_id: &str,
+        class_info: &Il2CppClassInfo,
+    ) -> Option<StructLayoutDescriptor> {
+        let mut instance_fields = self
+            .collect_class_hierarchy(class_info)
+            .into_iter()
+            .flat_map(|hierarchy_class| hierarchy_class.fields.iter())
+            .filter(|field| !field.is_static && field.offset >= OBJECT_HEADER_SIZE)
+            .collect::<Vec<_>>();
+        let header_fields = [
+            (String::from("klass"), 0),
+            (String::from("monitor"), POINTER_SIZE),
+        ]
+        .into_iter()
+        .map(|(field_name, header_offset)| {
+            SymbolLayoutPositionedField::new(
+                header_offset,
+                POINTER_SIZE,
+                SymbolicFieldDefinition::new_named(field_name, DataTypeRef::new("u8"), ContainerType::Pointer(PointerScanPointerSize::Pointer64)),
+            )
+        });
+
+        instance_fields.sort_by_key(|field| field.offset);
+
+        let positioned_fields = header_fields
+            .chain(self.position_fields(&instance_fields, class_info.instance_size))
+            .collect::<Vec<_>>();
+
+        materialize_layout(layout_id, class_info.instance_size, positioned_fields)
+    }
+
+    fn build_statics_layout(
+        &self,
+        layout_id: &str,
+        class_info: &Il2CppClassInfo,
+    ) -> Option<StructLayoutDescriptor> {
+        let mut static_fields = class_info
+            .fields
+            .iter()
+            .filter(|field| field.is_static)
+            .collect::<Vec<_>>();
+
+        static_fields.sort_by_key(|field| field.offset);
+
+        let positioned_fields = self
+            .position_fields(&static_fields, class_info.static_fields_size)
+            .collect::<Vec<_>>();
+
+        if positioned_fields.is_empty() {
+            return None;
+        }
+
+        materialize_layout(layout_id, class_info.static_fields_size, positioned_fields)
+    }
+
+    /// Expects fields sorted by offset. Fields that overlap an earlier one are dropped, and value types without a known
+    /// primitive shape fill the gap up to the next field as bytes.
+    fn position_fields<'field>(
+        &'field self,
+        sorted_fields: &'field [&'field Il2CppFieldInfo],
+        declared_size_in_bytes: u64,
+    ) -> impl Iterator<Item = SymbolLayoutPositionedField> + 'field {
+        let mut next_free_offset = 0_u64;
+
+        sorted_fields
+            .iter()
+            .enumerate()
+            .filter_map(move |(field_index, field)| {
+                let next_field_offset = sorted_fields
+                    .get(field_index + 1)
+                    .map(|next_field| next_field.offset)
+                    .unwrap_or(declared_size_in_bytes);
+                let field_shape = self
+                    .resolve_field_shape(field)
+                    .or_else(|| opaque_field_shape(next_field_offset.saturating_sub(field.offset)))?;
+
+                if field.offset < next_free_offset {
+                    return None;
+                }
+
+                next_free_offset = field.offset.saturating_add(field_shape.size_in_bytes);
+
+                Some(SymbolLayoutPositionedField::new(
+                    field.offset,
+                    field_shape.size_in_bytes,
+                    SymbolicFieldDefinition::new_named(field.name.clone(), DataTypeRef::new(&field_shape.data_type_id), field_shape.container_type),
+                ))
+            })
+    }
+
+    fn resolve_field_shape(
+        &self,
+        field: &Il2CppFieldInfo,
+    ) -> Option<FieldShape> {
+        if let Some(field_shape) = primitive_field_shape(field.type_code) {
+            return Some(field_shape);
+        }
+
+        match field.type_code {
+            IL2CPP_TYPE_CLASS => {
+                let pointee_layout_id = field
+                    .referenced_type_definition_index
+                    .and_then(|type_definition_index| self.layout_ids_by_type_index.get(&type_definition_index))
+                    .cloned()
+                    .unwrap_or_else(|| String::from("u8"));
+
+                Some(pointer_field_shape(&pointee_layout_id))
+            }
+            IL2CPP_TYPE_STRING | IL2CPP_TYPE_PTR | IL2CPP_TYPE_ARRAY | IL2CPP_TYPE_FNPTR | IL2CPP_TYPE_OBJECT | IL2CPP_TYPE_SZARRAY => {
+                Some(pointer_field_shape("u8"))
+            }
+            // Enums are value types whose only instance field holds the underlying integer.
+            IL2CPP_TYPE_VALUETYPE => {
+                let enum_class = self
+                    .classes_by_type_index
+                    .get(&field.referenced_type_definition_index?)?;
+                let mut enum_instance_fields = enum_class
+                    .fields
+                    .iter()
+                    .filter(|enum_field| !enum_field.is_static);
+                let value_field = enum_instance_fields.next()?;
+
+                if value_field.name != ENUM_VALUE_FIELD_NAME || enum_instance_fields.next().is_some() {
+                    return None;
+                }
+
+                primitive_field_shape(value_field.type_code)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the class and its ancestors, base class first.
+    fn collect_class_hierarchy<'class>(
+        &'class self,
+        class_info: &'class Il2CppClassInfo,
+    ) -> Vec<&'class Il2CppClassInfo> {
+        let mut class_hierarchy = vec![class_info];
+        let mut parent_type_definition_index = class_info.parent_type_definition_index;
+
+        while let Some(parent_class) = parent_type_definition_index.and_then(|type_definition_index| self.classes_by_type_index.get(&type_definition_index)) {
+            if class_hierarchy.len() >= MAX_PARENT_DEPTH {
+                break;
+            }
+
+            class_hierarchy.push(parent_class);
+            parent_type_definition_index = parent_class.parent_type_definition_index;
+        }
+
+        class_hierarchy.reverse();
+        class_hierarchy
+    }
+
+    /// Methods have no size in the binary, so each covers the code up to the next method. Methods folded onto the same
+    /// code keep the first name.
+    fn build_module_fields(&self) -> Vec<Il2CppModuleField> {
+        let mut method_entries = Vec::new();
+
+        for image_definition in &self.metadata.image_definitions {
+            let Some(code_gen_module) = self.code_gen_modules.get(&image_definition.name) else {
+                continue;
+            };
+            let type_start = image_definition.type_start.max(0) as usize;
+
+            for type_definition_index in type_start..type_start.saturating_add(image_definition.type_count as usize) {
+                let Some(type_definition) = self.metadata.type_definitions.get(type_definition_index) else {
+                    break;
+                };
+                let method_start = type_definition.method_start.max(0) as usize;
+                let qualified_type_name = &self.classes_by_type_index[&type_definition_index].qualified_name;
+
+                for method_definition in self
+                    .metadata
+                    .method_definitions
+                    .iter()
+                    .skip(method_start)
+                    .take(type_definition.method_count as usize)
+                {
+                    let Some(method_address) = code_gen_module
+                        .read_method_pointer(self.binary_image, method_definition.token)
+                        .filter(|method_address| self.binary_image.is_executable_address(*method_address))
+                    else {
+                        continue;
+                    };
+
+                    method_entries.push((method_address, format!("{qualified_type_name}$${}", method_definition.name)));
+                }
+            }
+        }
+
+        method_entries.sort_by_key(|method_entry| method_entry.0);
+        method_entries.dedup_by_key(|method_entry| method_entry.0);
+
+        let mut module_fields = method_entries
+            .iter()
+            .enumerate()
+            .filter_map(|(method_entry_index, (method_address, display_name))| {
+                let method_end = method_entries
+                    .get(method_entry_index + 1)
+                    .map(|next_method_entry| next_method_entry.0)
+                    .into_iter()
+                    .chain(self.binary_image.get_segment_end(*method_address))
+                    .min()?;
+                let size_in_bytes = method_end
+                    .checked_sub(*method_address)
+                    .filter(|size_in_bytes| *size_in_bytes > 0)?;
+
+                Some(Il2CppModuleField {
+                    display_name: display_name.clone(),
+                    offset: self.binary_image.to_module_offset(*method_address)?,
+                    struct_layout_id: format!("u8[{size_in_bytes}]"),
+                    size_in_bytes,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(metadata_registration_offset) = self
+            .binary_image
+            .to_module_offset(self.metadata_registration.address)
+        {
+            module_fields.push(Il2CppModuleField {
+                display_name: METADATA_REGISTRATION_FIELD_NAME.to_string(),
+                offset: metadata_registration_offset,
+                struct_layout_id: format!("u8[{METADATA_REGISTRATION_SIZE_IN_BYTES}]"),
+                size_in_bytes: METADATA_REGISTRATION_SIZE_IN_BYTES,
+            });
+        }
+
+        module_fields.sort_by_key(|module_field| module_field.offset);
+        module_fields
+    }
+}
+
+/// Literal fields have no storage, and thread statics report a negative offset, so both are left out.
+fn read_class(
+    metadata: &Il2CppMetadata,
+    binary_image: &Il2CppBinaryImage,
+    metadata_registration: &Il2CppMetadataRegistration,
+    type_definition_index: usize,
+) -> Il2CppClassInfo {
+    let type_definition = &metadata.type_definitions[type_definition_index];
+    let type_definition_count = metadata.type_definitions.len();
+    let resolve_type_definition_index = |type_index: i32, expected_type_codes: &[u8]| {
+        metadata_registration
+            .read_type(binary_image, type_index)
+            .filter(|type_info| expected_type_codes.contains(&type_info.type_code))
+            .and_then(|type_info| usize::try_from(type_info.data).ok())
+            .filter(|referenced_type_definition_index| *referenced_type_definition_index < type_definition_count)
+    };
+    let type_definition_sizes = metadata_registration.read_type_definition_sizes(binary_image, type_definition_index);
+    let field_start = type_definition.field_start.max(0) as usize;
+    let fields = (0..type_definition.field_count as usize)
+        .filter_map(|field_index_in_type| {
+            let field_definition = metadata
+                .field_definitions
+                .get(field_start + field_index_in_type)?;
+            let type_info = metadata_registration.read_type(binary_image, field_definition.type_index)?;
+            let field_offset = metadata_registration.read_field_offset(binary_image, type_definition_index, field_index_in_type)?;
+
+            if type_info.attributes & FIELD_ATTRIBUTE_LITERAL != 0 || field_offset < 0 {
+                return None;
+            }
+
+            Some(Il2CppFieldInfo {
+                name: field_definition.name.clone(),
+                offset: field_offset as u64,
+                type_code: type_info.type_code,
+                is_static: type_info.attributes & FIELD_ATTRIBUTE_STATIC != 0,
+                referenced_type_definition_index: resolve_type_definition_index(field_definition.type_index, &[IL2CPP_TYPE_CLASS, IL2CPP_TYPE_VALUETYPE]),
+            })
+        })
+        .collect();
+
+    Il2CppClassInfo {
+        qualified_name: metadata.get_qualified_type_name(type_definition_index),
+        instance_size: type_definition_sizes.map_or(0, |type_definition_sizes| type_definition_sizes.instance_size),
+        static_fields_size: type_definition_sizes.map_or(0, |type_definition_sizes| type_definition_sizes.static_fields_size),
+        parent_type_definition_index: resolve_type_definition_index(type_definition.parent_type_index, &[IL2CPP_TYPE_CLASS])
+            .filter(|parent_type_definition_index| *parent_type_definition_index != type_definition_index),
+        fields,
+    }
+}
+
+fn materialize_layout(
+    layout_id: &str,
+    declared_size_in_bytes: u64,
+    positioned_fields: Vec<SymbolLayoutPositionedField>,
+) -> Option<StructLayoutDescriptor> {
+    let field_end = positioned_fields
+        .iter()
+        .map(|positioned_field| {
+            positioned_field
+                .get_offset_in_bytes()
+                .saturating_add(positioned_field.get_size_in_bytes())
+        })
+        .max()
+        .unwrap_or(0);
+    let declared_size_in_bytes = declared_size_in_bytes.max(field_end);
+    let materialized_fields =
+        SymbolLayoutFieldMaterializer::materialize_positioned_fields(SymbolicLayoutKind::Struct, Some(declared_size_in_bytes), positioned_fields).ok()?;
+
+    Some(StructLayoutDescriptor::new(
+        layout_id.to_string(),
+        SymbolicStructDefinition::new_with_layout_kind(layout_id.to_string(), SymbolicLayoutKind::Struct, materialized_fields)
+            .with_declared_size_in_bytes(Some(declared_size_in_bytes)),
+    ))
+}
+
+fn primitive_field_shape(type_code: u8) -> Option<FieldShape> {
+    let (data_type_id, size_in_bytes) = match type_code {
+        IL2CPP_TYPE_BOOLEAN => ("bool8", 1),
+        IL2CPP_TYPE_CHAR | IL2CPP_TYPE_U2 => ("u16", 2),
+        IL2CPP_TYPE_I1 => ("i8", 1),
+        IL2CPP_TYPE_U1 => ("u8", 1),
+        IL2CPP_TYPE_I2 => ("i16", 2),
+        IL2CPP_TYPE_I4 => ("i32", 4),
+        IL2CPP_TYPE_U4 => ("u32", 4),
+        IL2CPP_TYPE_I8 | IL2CPP_TYPE_I => ("i64", 8),
+        IL2CPP_TYPE_U8 | IL2CPP_TYPE_U => ("u64", 8),
+        IL2CPP_TYPE_R4 => ("f32", 4),
+        IL2CPP_TYPE_R8 => ("f64", 8),
+        _ => return None,
+    };
+
+    Some(FieldShape {
+        data_type_id: data_type_id.to_string(),
+        container_type: ContainerType::None,
+        size_in_bytes,
+    })
+}
+
+fn pointer_field_shape(pointee_data_type_id: &str) -> FieldShape {
+    FieldShape {
+        data_type_id: pointee_data_type_id.to_string(),
+        container_type: ContainerType::Pointer(PointerScanPointerSize::Pointer64),
+        size_in_bytes: POINTER_SIZE,
+    }
+}
+
+fn opaque_field_shape(size_in_bytes: u64) -> Option<FieldShape> {
+    (size_in_bytes > 0).then(|| FieldShape {
+        data_type_id: String::from("u8"),
+        container_type: ContainerType::ArrayFixed(size_in_bytes),
+        size_in_bytes,
+    })
+}
+
+/// Namespace and declaring type components become dotted layout id components. Generic arity markers and
+/// compiler-generated names are reduced to identifier characters.
+fn build_layout_id(qualified_name: &str) -> String {
+    std::iter::once(LAYOUT_ID_PREFIX.to_string())
+        .chain(
+            qualified_name
+                .split('.')
+                .filter(|name_component| !name_component.is_empty())
+                .map(sanitize_layout_id_component),
+        )
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn sanitize_layout_id_component(name: &str) -> String {
+    let mut sanitized_name = String::with_capacity(name.len());
+
+    for name_character in name.chars() {
+        if name_character.is_ascii_alphanumeric() || name_character == '_' {
+            sanitized_name.push(name_character);
+        } else if !sanitized_name.ends_with('_') {
+            sanitized_name.push('_');
+        }
+    }
+
+    let sanitized_name = sanitized_name.trim_matches('_');
+
+    if sanitized_name.is_empty() {
+        String::from("anonymous")
+    } else {
+        sanitized_name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Il2CppSymbolBuilder, build_layout_id};
+    use crate::{
+        binary::{
+            il2cpp_binary_image::Il2CppBinaryImage,
+            il2cpp_registration::{Il2CppCodeGenModule, Il2CppMetadataRegistration},
+        },
+        metadata::{il2cpp_metadata::Il2CppMetadata, il2cpp_metadata_layout::Il2CppMetadataRevision},
+        test_il2cpp_files::{
+            TEST_GET_HEALTH_METHOD_RVA, TEST_METADATA_REGISTRATION_RVA, TEST_UPDATE_METHOD_RVA, build_test_metadata, build_test_pe64_game_assembly,
+        },
+    };
+
+    #[test]
+    fn build_layout_id_sanitizes_generic_and_nested_names() {
+        assert_eq!(build_layout_id("System.Collections.Generic.List`1"), "il2cpp.System.Collections.Generic.List_1");
+        assert_eq!(build_layout_id("<Module>"), "il2cpp.Module");
+    }
+
+    #[test]
+    fn build_joins_metadata_with_registration_tables() {
+        let metadata = Il2CppMetadata::parse(&build_test_metadata(Il2CppMetadataRevision::V27)).expect("Expected the test metadata to parse.");
+        let binary_image = Il2CppBinaryImage::parse(build_test_pe64_game_assembly()).expect("Expected the test PE to parse.");
+        let metadata_registration =
+            Il2CppMetadataRegistration::locate(&binary_image, metadata.type_definitions.len() as u64).expect("Expected the registration to be found.");
+        let image_names = vec![metadata.image_definitions[0].name.clone()];
+        let code_gen_modules = Il2CppCodeGenModule::locate_all(&binary_image, &image_names);
+        let il2cpp_symbols = Il2CppSymbolBuilder::new(&metadata, &binary_image, &metadata_registration, &code_gen_modules).build();
+        let find_layout_fields = |layout_id: &str| {
+            il2cpp_symbols
+                .struct_layout_descriptors
+                .iter()
+                .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == layout_id)
+                .unwrap_or_else(|| panic!("Expected layout `{layout_id}`."))
+                .get_struct_layout_definition()
+                .get_fields()
+                .iter()
+                .filter(|field_definition| !field_definition.is_unassigned())
+                .map(|field_definition| {
+                    (
+                        field_definition.get_field_name().to_string(),
+                        field_definition
+                            .get_data_type_ref()
+                            .get_data_type_id()
+                            .to_string(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            find_layout_fields("il2cpp.Game.Player"),
+            vec![
+                (String::from("klass"), String::from("u8")),
+                (String::from("monitor"), String::from("u8")),
+                (String::from("id"), String::from("i32")),
+                (String::from("health"), String::from("i32")),
+                (String::from("target"), String::from("il2cpp.Game.Entity")),
+                (String::from("state"), String::from("i32")),
+            ]
+        );
+        assert_eq!(
+            find_layout_fields("il2cpp.Game.Player.statics"),
+            vec![(String::from("instanceCount"), String::from("i32"))]
+        );
+        assert!(
+            il2cpp_symbols
+                .struct_layout_descriptors
+                .iter()
+                .any(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == "il2cpp.Game.Player.Inventory")
+        );
+        assert_eq!(
+            il2cpp_symbols
+                .module_fields
+                .iter()
+                .map(|module_field| (module_field.display_name.as_str(), module_field.offset, module_field.size_in_bytes))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "Game.Player$$Update",
+                    TEST_UPDATE_METHOD_RVA,
+                    TEST_GET_HEALTH_METHOD_RVA - TEST_UPDATE_METHOD_RVA
+                ),
+                ("Game.Player$$get_Health", TEST_GET_HEALTH_METHOD_RVA, 0x2000 - TEST_GET_HEALTH_METHOD_RVA),
+                ("Il2CppMetadataRegistration", TEST_METADATA_REGISTRATION_RVA, 0x80),
+            ]
+        );
+    }
+}
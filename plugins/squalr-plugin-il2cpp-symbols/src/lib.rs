@@ -0,0 +1,36 @@
+mod binary;
+mod constants;
+mod il2cpp_symbol_builder;
+mod metadata;
+mod plugin;
+mod populate_il2cpp_symbols_action;
+
+#[cfg(test)]
+mod test_il2cpp_files;
+
+pub use plugin::Il2CppSymbolsPlugin;
+
+#[cfg(test)]
+mod tests {
+    use super::Il2CppSymbolsPlugin;
+    use squalr_engine_api::plugins::{Plugin, PluginPermission, symbol_tree::symbol_tree_plugin::SymbolTreePlugin};
+
+    #[test]
+    fn plugin_exposes_symbol_store_and_process_memory_permissions() {
+        let plugin = Il2CppSymbolsPlugin::new();
+
+        assert_eq!(plugin.metadata().get_plugin_id(), "builtin.symbols.il2cpp");
+        assert!(plugin.metadata().get_is_enabled_by_default());
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_permission(PluginPermission::WriteSymbolStore)
+        );
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_permission(PluginPermission::ReadProcessMemory)
+        );
+        assert_eq!(plugin.symbol_tree_actions().len(), 1);
+    }
+}
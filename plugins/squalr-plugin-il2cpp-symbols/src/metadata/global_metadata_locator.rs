@@ -0,0 +1,116 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const GLOBAL_METADATA_FILE_NAME: &str = "global-metadata.dat";
+const PLAYER_DATA_DIRECTORY_SUFFIX: &str = "_Data";
+
+/// Produces candidate paths for `global-metadata.dat` given the on-disk IL2CPP binary. Desktop players keep it under
+/// `<Game>_Data/il2cpp_data/Metadata` beside the binary; unpacked Android builds keep it under `assets/bin/Data`
+/// two directories above `lib/<abi>/libil2cpp.so`.
+pub(crate) fn global_metadata_candidates(binary_file_path: &Path) -> Vec<PathBuf> {
+    let Some(binary_directory) = binary_file_path.parent() else {
+        return Vec::new();
+    };
+    let mut candidates = Vec::new();
+
+    if let Ok(directory_entries) = fs::read_dir(binary_directory) {
+        let mut player_data_directories = directory_entries
+            .filter_map(Result::ok)
+            .map(|directory_entry| directory_entry.path())
+            .filter(|entry_path| {
+                entry_path.is_dir()
+                    && entry_path
+                        .file_name()
+                        .and_then(|file_name| file_name.to_str())
+                        .is_some_and(|file_name| file_name.ends_with(PLAYER_DATA_DIRECTORY_SUFFIX))
+            })
+            .collect::<Vec<_>>();
+
+        player_data_directories.sort();
+        candidates.extend(
+            player_data_directories
+                .into_iter()
+                .map(|player_data_directory| {
+                    player_data_directory
+                        .join("il2cpp_data")
+                        .join("Metadata")
+                        .join(GLOBAL_METADATA_FILE_NAME)
+                }),
+        );
+    }
+
+    candidates.push(binary_directory.join(GLOBAL_METADATA_FILE_NAME));
+
+    if let Some(package_directory) = binary_directory.parent().and_then(Path::parent) {
+        candidates.push(
+            package_directory
+                .join("assets")
+                .join("bin")
+                .join("Data")
+                .join("Managed")
+                .join("Metadata")
+                .join(GLOBAL_METADATA_FILE_NAME),
+        );
+    }
+
+    candidates
+}
+
+pub(crate) fn locate_global_metadata(binary_file_path: &Path) -> Option<PathBuf> {
+    global_metadata_candidates(binary_file_path)
+        .into_iter()
+        .find(|candidate| candidate.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{global_metadata_candidates, locate_global_metadata};
+    use std::fs;
+
+    #[test]
+    fn candidates_cover_desktop_and_android_layouts() {
+        let temporary_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let game_directory = temporary_directory.path().join("Game");
+        let binary_file_path = game_directory.join("GameAssembly.dll");
+
+        fs::create_dir_all(game_directory.join("Game_Data")).expect("Expected the player data directory to be created.");
+
+        let candidates = global_metadata_candidates(&binary_file_path);
+
+        assert_eq!(
+            candidates[0],
+            game_directory
+                .join("Game_Data")
+                .join("il2cpp_data")
+                .join("Metadata")
+                .join("global-metadata.dat")
+        );
+        assert_eq!(
+            global_metadata_candidates(
+                &temporary_directory
+                    .path()
+                    .join("lib")
+                    .join("arm64-v8a")
+                    .join("libil2cpp.so")
+            )
+            .last(),
+            Some(
+                &temporary_directory
+                    .path()
+                    .join("assets")
+                    .join("bin")
+                    .join("Data")
+                    .join("Managed")
+                    .join("Metadata")
+                    .join("global-metadata.dat")
+            )
+        );
+        assert_eq!(locate_global_metadata(&binary_file_path), None);
+
+        fs::write(game_directory.join("global-metadata.dat"), b"metadata").expect("Expected the metadata file to be written.");
+
+        assert_eq!(locate_global_metadata(&binary_file_path), Some(game_directory.join("global-metadata.dat")));
+    }
+}
@@ -0,0 +1,351 @@
+use crate::metadata::il2cpp_metadata_layout::{Il2CppMetadataLayout, Il2CppMetadataRevision};
+
+const MAX_NAME_LENGTH: usize = 1024;
+const MAX_DECLARING_TYPE_DEPTH: usize = 32;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppTypeDefinition {
+    pub(crate) name: String,
+    pub(crate) namespace: String,
+    pub(crate) byval_type_index: i32,
+    pub(crate) declaring_type_index: i32,
+    pub(crate) parent_type_index: i32,
+    pub(crate) field_start: i32,
+    pub(crate) field_count: u16,
+    pub(crate) method_start: i32,
+    pub(crate) method_count: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppFieldDefinition {
+    pub(crate) name: String,
+    pub(crate) type_index: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppMethodDefinition {
+    pub(crate) name: String,
+    pub(crate) declaring_type_index: i32,
+    pub(crate) token: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppImageDefinition {
+    pub(crate) name: String,
+    pub(crate) type_start: i32,
+    pub(crate) type_count: u32,
+}
+
+/// The parts of `global-metadata.dat` needed to name types, fields and methods. Type, field and type-index tables in
+/// the binary's registrations are indexed in the same order as these tables.
+#[derive(Clone, Debug)]
+pub(crate) struct Il2CppMetadata {
+    pub(crate) type_definitions: Vec<Il2CppTypeDefinition>,
+    pub(crate) field_definitions: Vec<Il2CppFieldDefinition>,
+    pub(crate) method_definitions: Vec<Il2CppMethodDefinition>,
+    pub(crate) image_definitions: Vec<Il2CppImageDefinition>,
+}
+
+impl Il2CppMetadata {
+    pub(crate) fn parse(metadata_bytes: &[u8]) -> Result<Self, String> {
+        let sanity = read_u32(metadata_bytes, 0).ok_or_else(|| String::from("IL2CPP metadata is truncated."))?;
+
+        if sanity != Il2CppMetadataLayout::SANITY {
+            return Err(String::from("File is not IL2CPP global metadata."));
+        }
+
+        let version = read_u32(metadata_bytes, 4).ok_or_else(|| String::from("IL2CPP metadata is truncated."))?;
+        let layout = detect_layout(metadata_bytes, version)?;
+        let metadata_reader = MetadataReader { metadata_bytes, layout };
+
+        Ok(Self {
+            type_definitions: metadata_reader.read_type_definitions()?,
+            field_definitions: metadata_reader.read_field_definitions()?,
+            method_definitions: metadata_reader.read_method_definitions()?,
+            image_definitions: metadata_reader.read_image_definitions(layout.image_definition_size)?,
+        })
+    }
+
+    /// Nested types are named through their declaring types, which also supply the namespace.
+    pub(crate) fn get_qualified_type_name(
+        &self,
+        type_definition_index: usize,
+    ) -> String {
+        let mut name_components = Vec::new();
+        let mut namespace = "";
+        let mut next_type_definition_index = Some(type_definition_index);
+
+        while let Some(type_definition) = next_type_definition_index.and_then(|type_index| self.type_definitions.get(type_index)) {
+            if name_components.len() >= MAX_DECLARING_TYPE_DEPTH {
+                break;
+            }
+
+            name_components.push(type_definition.name.as_str());
+            namespace = type_definition.namespace.as_str();
+            next_type_definition_index = usize::try_from(type_definition.declaring_type_index).ok();
+        }
+
+        if !namespace.is_empty() {
+            name_components.push(namespace);
+        }
+
+        name_components.reverse();
+        name_components.join(".")
+    }
+}
+
+/// Version 24 spans several record layouts. Only 24.0 and 24.1 keep the runtime generic context section, which makes
+/// their header larger, and only their image records differ in size.
+fn detect_layout(
+    metadata_bytes: &[u8],
+    version: u32,
+) -> Result<Il2CppMetadataLayout, String> {
+    let revision = match version {
+        24 => {
+            let legacy_layout = Il2CppMetadataLayout::for_revision(Il2CppMetadataRevision::V24_1);
+            let first_section_offset = read_u32(metadata_bytes, 8).unwrap_or(0) as usize;
+
+            if first_section_offset < legacy_layout.header_size {
+                Il2CppMetadataRevision::V24_2
+            } else if image_table_matches_type_definitions(metadata_bytes, legacy_layout) {
+                Il2CppMetadataRevision::V24_1
+            } else {
+                Il2CppMetadataRevision::V24_0
+            }
+        }
+        27..=29 => Il2CppMetadataRevision::V27,
+        31 => Il2CppMetadataRevision::V31,
+        _ => {
+            return Err(format!("IL2CPP metadata version {version} is not supported (expected 24, 27 to 29, or 31)."));
+        }
+    };
+
+    Ok(Il2CppMetadataLayout::for_revision(revision))
+}
+
+/// Images list their types as consecutive ranges covering the whole type definition table.
+fn image_table_matches_type_definitions(
+    metadata_bytes: &[u8],
+    layout: Il2CppMetadataLayout,
+) -> bool {
+    let metadata_reader = MetadataReader { metadata_bytes, layout };
+    let Ok((_, type_definitions_size)) = metadata_reader.read_section(layout.type_definition_section_index) else {
+        return false;
+    };
+    let Ok(image_definitions) = metadata_reader.read_image_definitions(layout.image_definition_size) else {
+        return false;
+    };
+
+    if type_definitions_size % layout.type_definition.size != 0 || image_definitions.is_empty() {
+        return false;
+    }
+
+    let mut next_type_start = 0_u64;
+
+    for image_definition in &image_definitions {
+        if image_definition.type_start < 0 || image_definition.type_start as u64 != next_type_start {
+            return false;
+        }
+
+        next_type_start += image_definition.type_count as u64;
+    }
+
+    next_type_start == (type_definitions_size / layout.type_definition.size) as u64
+}
+
+struct MetadataReader<'a> {
+    metadata_bytes: &'a [u8],
+    layout: Il2CppMetadataLayout,
+}
+
+impl MetadataReader<'_> {
+    fn read_type_definitions(&self) -> Result<Vec<Il2CppTypeDefinition>, String> {
+        let type_definition_layout = self.layout.type_definition;
+
+        self.read_records(self.layout.type_definition_section_index, type_definition_layout.size, |record| {
+            Some(Il2CppTypeDefinition {
+                name: self.read_string(read_u32(record, type_definition_layout.name)?)?,
+                namespace: self.read_string(read_u32(record, type_definition_layout.namespace)?)?,
+                byval_type_index: read_i32(record, type_definition_layout.byval_type)?,
+                declaring_type_index: read_i32(record, type_definition_layout.declaring_type)?,
+                parent_type_index: read_i32(record, type_definition_layout.parent_type)?,
+                field_start: read_i32(record, type_definition_layout.field_start)?,
+                field_count: read_u16(record, type_definition_layout.field_count)?,
+                method_start: read_i32(record, type_definition_layout.method_start)?,
+                method_count: read_u16(record, type_definition_layout.method_count)?,
+            })
+        })
+    }
+
+    fn read_field_definitions(&self) -> Result<Vec<Il2CppFieldDefinition>, String> {
+        self.read_records(self.layout.field_section_index, self.layout.field_definition_size, |record| {
+            Some(Il2CppFieldDefinition {
+                name: self.read_string(read_u32(record, Il2CppMetadataLayout::FIELD_DEFINITION_NAME)?)?,
+                type_index: read_i32(record, Il2CppMetadataLayout::FIELD_DEFINITION_TYPE)?,
+            })
+        })
+    }
+
+    fn read_method_definitions(&self) -> Result<Vec<Il2CppMethodDefinition>, String> {
+        let method_definition_layout = self.layout.method_definition;
+
+        self.read_records(self.layout.method_section_index, method_definition_layout.size, |record| {
+            Some(Il2CppMethodDefinition {
+                name: self.read_string(read_u32(record, method_definition_layout.name)?)?,
+                declaring_type_index: read_i32(record, method_definition_layout.declaring_type)?,
+                token: read_u32(record, method_definition_layout.token)?,
+            })
+        })
+    }
+
+    fn read_image_definitions(
+        &self,
+        image_definition_size: usize,
+    ) -> Result<Vec<Il2CppImageDefinition>, String> {
+        self.read_records(self.layout.image_section_index, image_definition_size, |record| {
+            Some(Il2CppImageDefinition {
+                name: self.read_string(read_u32(record, Il2CppMetadataLayout::IMAGE_DEFINITION_NAME)?)?,
+                type_start: read_i32(record, Il2CppMetadataLayout::IMAGE_DEFINITION_TYPE_START)?,
+                type_count: read_u32(record, Il2CppMetadataLayout::IMAGE_DEFINITION_TYPE_COUNT)?,
+            })
+        })
+    }
+
+    fn read_records<T>(
+        &self,
+        section_index: usize,
+        record_size: usize,
+        read_record: impl Fn(&[u8]) -> Option<T>,
+    ) -> Result<Vec<T>, String> {
+        let (section_offset, section_size) = self.read_section(section_index)?;
+        let section_bytes = &self.metadata_bytes[section_offset..section_offset + section_size];
+
+        section_bytes
+            .chunks_exact(record_size)
+            .enumerate()
+            .map(|(record_index, record)| {
+                read_record(record).ok_or_else(|| format!("IL2CPP metadata record {record_index} in section {section_index} is malformed."))
+            })
+            .collect()
+    }
+
+    fn read_section(
+        &self,
+        section_index: usize,
+    ) -> Result<(usize, usize), String> {
+        let header_offset = 8 + section_index * 8;
+        let section_offset = read_u32(self.metadata_bytes, header_offset).ok_or_else(|| String::from("IL2CPP metadata header is truncated."))? as usize;
+        let section_size = read_u32(self.metadata_bytes, header_offset + 4).ok_or_else(|| String::from("IL2CPP metadata header is truncated."))? as usize;
+
+        if section_offset
+            .checked_add(section_size)
+            .is_none_or(|section_end| section_end > self.metadata_bytes.len())
+        {
+            return Err(format!("IL2CPP metadata section {section_index} lies outside the file."));
+        }
+
+        Ok((section_offset, section_size))
+    }
+
+    fn read_string(
+        &self,
+        string_index: u32,
+    ) -> Option<String> {
+        let (string_section_offset, string_section_size) = self.read_section(self.layout.string_section_index).ok()?;
+        let string_offset = string_section_offset.checked_add(string_index as usize)?;
+        let string_section_end = string_section_offset + string_section_size;
+
+        if string_offset >= string_section_end {
+            return None;
+        }
+
+        let string_bytes = &self.metadata_bytes[string_offset..string_section_end.min(string_offset + MAX_NAME_LENGTH)];
+        let string_length = string_bytes
+            .iter()
+            .position(|string_byte| *string_byte == 0)
+            .unwrap_or(string_bytes.len());
+
+        Some(String::from_utf8_lossy(&string_bytes[..string_length]).into_owned())
+    }
+}
+
+fn read_u16(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<u16> {
+    Some(u16::from_le_bytes(bytes.get(offset..offset.checked_add(2)?)?.try_into().ok()?))
+}
+
+fn read_u32(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<u32> {
+    Some(u32::from_le_bytes(bytes.get(offset..offset.checked_add(4)?)?.try_into().ok()?))
+}
+
+fn read_i32(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<i32> {
+    read_u32(bytes, offset).map(|value| value as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Il2CppMetadata, detect_layout};
+    use crate::{
+        metadata::il2cpp_metadata_layout::Il2CppMetadataRevision,
+        test_il2cpp_files::{TEST_PLAYER_TYPE_INDEX, build_test_metadata},
+    };
+
+    #[test]
+    fn parse_reads_every_supported_revision() {
+        for revision in [
+            Il2CppMetadataRevision::V24_0,
+            Il2CppMetadataRevision::V24_1,
+            Il2CppMetadataRevision::V24_2,
+            Il2CppMetadataRevision::V27,
+            Il2CppMetadataRevision::V31,
+        ] {
+            let metadata_bytes = build_test_metadata(revision);
+            let metadata = Il2CppMetadata::parse(&metadata_bytes).expect("Expected the test metadata to parse.");
+            let player_type_definition = &metadata.type_definitions[TEST_PLAYER_TYPE_INDEX];
+            let detected_layout = detect_layout(
+                &metadata_bytes,
+                u32::from_le_bytes([
+                    metadata_bytes[4],
+                    metadata_bytes[5],
+                    metadata_bytes[6],
+                    metadata_bytes[7],
+                ]),
+            )
+            .expect("Expected the test metadata version to be supported.");
+
+            assert_eq!(detected_layout.revision, revision);
+            assert_eq!(metadata.image_definitions[0].name, "Assembly-CSharp.dll");
+            assert_eq!(player_type_definition.name, "Player");
+            assert_eq!(player_type_definition.field_count, 5);
+            assert_eq!(metadata.field_definitions[player_type_definition.field_start as usize].name, "health");
+            assert_eq!(metadata.method_definitions[1].name, "get_Health");
+            assert_eq!(metadata.method_definitions[1].token, 0x0600_0002);
+        }
+    }
+
+    #[test]
+    fn parse_rejects_unknown_files_and_versions() {
+        let mut metadata_bytes = build_test_metadata(Il2CppMetadataRevision::V27);
+
+        metadata_bytes[4..8].copy_from_slice(&23_u32.to_le_bytes());
+
+        assert!(Il2CppMetadata::parse(&metadata_bytes).is_err());
+        assert!(Il2CppMetadata::parse(b"not metadata").is_err());
+    }
+
+    #[test]
+    fn qualified_type_names_include_declaring_types() {
+        let metadata = Il2CppMetadata::parse(&build_test_metadata(Il2CppMetadataRevision::V27)).expect("Expected the test metadata to parse.");
+
+        assert_eq!(metadata.get_qualified_type_name(TEST_PLAYER_TYPE_INDEX), "Game.Player");
+        assert_eq!(metadata.get_qualified_type_name(3), "Game.Player.Inventory");
+    }
+}
@@ -0,0 +1,201 @@
+/// Record layout revisions of `global-metadata.dat`. The header only stores the major version, so the 24.x revisions are
+/// told apart by the header size and by which image record size fits the type definition table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Il2CppMetadataRevision {
+    /// Unity 2018.1. Records still carry custom attribute indices.
+    V24_0,
+    /// Unity 2018.2. Custom attributes moved to images.
+    V24_1,
+    /// Unity 2018.3 to 2020.1. Runtime generic contexts moved out of the metadata, shrinking the header.
+    V24_2,
+    /// Unity 2020.2 to 2021.3 (versions 27 to 29). Type definitions lost their by-ref type index.
+    V27,
+    /// Unity 2022.2 and later. Method definitions gained a return parameter token.
+    V31,
+}
+
+/// Byte offsets of the fields read from an `Il2CppTypeDefinition` record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppTypeDefinitionLayout {
+    pub(crate) size: usize,
+    pub(crate) name: usize,
+    pub(crate) namespace: usize,
+    pub(crate) byval_type: usize,
+    pub(crate) declaring_type: usize,
+    pub(crate) parent_type: usize,
+    pub(crate) field_start: usize,
+    pub(crate) method_start: usize,
+    pub(crate) method_count: usize,
+    pub(crate) field_count: usize,
+}
+
+/// Byte offsets of the fields read from an `Il2CppMethodDefinition` record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppMethodDefinitionLayout {
+    pub(crate) size: usize,
+    pub(crate) name: usize,
+    pub(crate) declaring_type: usize,
+    pub(crate) token: usize,
+}
+
+/// Header slots and record layouts for one metadata revision. Header slots are indices of `{ offset, size }` pairs that
+/// follow the sanity and version words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Il2CppMetadataLayout {
+    pub(crate) revision: Il2CppMetadataRevision,
+    pub(crate) header_size: usize,
+    pub(crate) string_section_index: usize,
+    pub(crate) method_section_index: usize,
+    pub(crate) field_section_index: usize,
+    pub(crate) type_definition_section_index: usize,
+    pub(crate) image_section_index: usize,
+    pub(crate) type_definition: Il2CppTypeDefinitionLayout,
+    pub(crate) method_definition: Il2CppMethodDefinitionLayout,
+    pub(crate) field_definition_size: usize,
+    pub(crate) image_definition_size: usize,
+}
+
+impl Il2CppMetadataLayout {
+    pub(crate) const SANITY: u32 = 0xFAB1_1BAF;
+    pub(crate) const FIELD_DEFINITION_NAME: usize = 0;
+    pub(crate) const FIELD_DEFINITION_TYPE: usize = 4;
+    pub(crate) const IMAGE_DEFINITION_NAME: usize = 0;
+    pub(crate) const IMAGE_DEFINITION_TYPE_START: usize = 8;
+    pub(crate) const IMAGE_DEFINITION_TYPE_COUNT: usize = 12;
+
+    pub(crate) const fn for_revision(revision: Il2CppMetadataRevision) -> Self {
+        let has_custom_attribute_indices = matches!(revision, Il2CppMetadataRevision::V24_0);
+        let has_runtime_generic_contexts = matches!(revision, Il2CppMetadataRevision::V24_0 | Il2CppMetadataRevision::V24_1);
+        let has_byref_type = matches!(
+            revision,
+            Il2CppMetadataRevision::V24_0 | Il2CppMetadataRevision::V24_1 | Il2CppMetadataRevision::V24_2
+        );
+        let has_return_parameter_token = matches!(revision, Il2CppMetadataRevision::V31);
+        let header_pair_count = match revision {
+            Il2CppMetadataRevision::V24_0 | Il2CppMetadataRevision::V24_1 => 33,
+            Il2CppMetadataRevision::V24_2 => 32,
+            Il2CppMetadataRevision::V27 | Il2CppMetadataRevision::V31 => 31,
+        };
+
+        Self {
+            revision,
+            header_size: 8 + header_pair_count * 8,
+            string_section_index: 2,
+            method_section_index: 5,
+            field_section_index: 11,
+            type_definition_section_index: 19,
+            image_section_index: if has_runtime_generic_contexts { 21 } else { 20 },
+            type_definition: type_definition_layout(has_custom_attribute_indices, has_byref_type, has_runtime_generic_contexts),
+            method_definition: method_definition_layout(has_custom_attribute_indices, has_runtime_generic_contexts, has_return_parameter_token),
+            field_definition_size: if has_custom_attribute_indices { 16 } else { 12 },
+            image_definition_size: if has_custom_attribute_indices { 32 } else { 40 },
+        }
+    }
+}
+
+/// Type definitions are a run of 32-bit indices, eight 32-bit start indices, eight 16-bit counts, then a bitfield and token.
+const fn type_definition_layout(
+    has_custom_attribute_index: bool,
+    has_byref_type: bool,
+    has_runtime_generic_context: bool,
+) -> Il2CppTypeDefinitionLayout {
+    let byval_type_slot = 2 + has_custom_attribute_index as usize;
+    let declaring_type_slot = byval_type_slot + 1 + has_byref_type as usize;
+    let parent_type_slot = declaring_type_slot + 1;
+    // Element type, runtime generic context range, generic container and flags sit between the parent and the starts.
+    let field_start_slot = parent_type_slot + 4 + 2 * has_runtime_generic_context as usize;
+    let method_count = (field_start_slot + 8) * 4;
+
+    Il2CppTypeDefinitionLayout {
+        size: method_count + 8 * 2 + 8,
+        name: 0,
+        namespace: 4,
+        byval_type: byval_type_slot * 4,
+        declaring_type: declaring_type_slot * 4,
+        parent_type: parent_type_slot * 4,
+        field_start: field_start_slot * 4,
+        method_start: (field_start_slot + 1) * 4,
+        method_count,
+        field_count: method_count + 4,
+    }
+}
+
+/// Method definitions are a run of 32-bit indices ending in the token, followed by four 16-bit fields.
+const fn method_definition_layout(
+    has_custom_attribute_index: bool,
+    has_method_indices: bool,
+    has_return_parameter_token: bool,
+) -> Il2CppMethodDefinitionLayout {
+    // Name, declaring type, return type, parameter start and generic container are always present.
+    let token_slot = 5 + has_return_parameter_token as usize + has_custom_attribute_index as usize + 5 * has_method_indices as usize;
+
+    Il2CppMethodDefinitionLayout {
+        size: (token_slot + 1) * 4 + 4 * 2,
+        name: 0,
+        declaring_type: 4,
+        token: token_slot * 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Il2CppMetadataLayout, Il2CppMetadataRevision};
+
+    #[test]
+    fn record_sizes_match_each_metadata_revision() {
+        let record_sizes = [
+            Il2CppMetadataRevision::V24_0,
+            Il2CppMetadataRevision::V24_1,
+            Il2CppMetadataRevision::V24_2,
+            Il2CppMetadataRevision::V27,
+            Il2CppMetadataRevision::V31,
+        ]
+        .map(|revision| {
+            let layout = Il2CppMetadataLayout::for_revision(revision);
+
+            (
+                layout.header_size,
+                layout.type_definition.size,
+                layout.method_definition.size,
+                layout.field_definition_size,
+                layout.image_definition_size,
+            )
+        });
+
+        assert_eq!(
+            record_sizes,
+            [
+                (272, 104, 56, 16, 32),
+                (272, 100, 52, 12, 40),
+                (264, 92, 32, 12, 40),
+                (256, 88, 32, 12, 40),
+                (256, 88, 36, 12, 40),
+            ]
+        );
+    }
+
+    #[test]
+    fn type_definition_offsets_skip_removed_indices() {
+        let legacy_layout = Il2CppMetadataLayout::for_revision(Il2CppMetadataRevision::V24_0).type_definition;
+        let current_layout = Il2CppMetadataLayout::for_revision(Il2CppMetadataRevision::V27).type_definition;
+
+        assert_eq!(
+            (
+                legacy_layout.byval_type,
+                legacy_layout.parent_type,
+                legacy_layout.field_start,
+                legacy_layout.field_count
+            ),
+            (12, 24, 48, 84)
+        );
+        assert_eq!(
+            (
+                current_layout.byval_type,
+                current_layout.parent_type,
+                current_layout.field_start,
+                current_layout.field_count
+            ),
+            (8, 16, 32, 68)
+        );
+    }
+}
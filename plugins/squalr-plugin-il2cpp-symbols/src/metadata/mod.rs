@@ -0,0 +1,3 @@
+pub(crate) mod global_metadata_locator;
+pub(crate) mod il2cpp_metadata;
+pub(crate) mod il2cpp_metadata_layout;
@@ -0,0 +1,61 @@
+use crate::{
+    constants::{IL2CPP_SYMBOLS_PLUGIN_DESCRIPTION, IL2CPP_SYMBOLS_PLUGIN_DISPLAY_NAME, IL2CPP_SYMBOLS_PLUGIN_ID},
+    populate_il2cpp_symbols_action::PopulateIl2CppSymbolsAction,
+};
+use squalr_engine_api::plugins::{
+    Plugin, PluginCapability, PluginMetadata, PluginPackage, PluginPermission, symbol_tree::symbol_tree_action::SymbolTreeAction,
+    symbol_tree::symbol_tree_plugin::SymbolTreePlugin,
+};
+use std::sync::Arc;
+
+pub struct Il2CppSymbolsPlugin {
+    metadata: PluginMetadata,
+    symbol_tree_actions: Vec<Arc<dyn SymbolTreeAction>>,
+}
+
+impl Il2CppSymbolsPlugin {
+    pub fn new() -> Self {
+        Self {
+            metadata: PluginMetadata::new_with_permissions(
+                IL2CPP_SYMBOLS_PLUGIN_ID,
+                IL2CPP_SYMBOLS_PLUGIN_DISPLAY_NAME,
+                IL2CPP_SYMBOLS_PLUGIN_DESCRIPTION,
+                vec![PluginCapability::SymbolTree],
+                vec![
+                    PluginPermission::ReadSymbolStore,
+                    PluginPermission::WriteSymbolStore,
+                    PluginPermission::ReadSymbolTreeWindow,
+                    PluginPermission::WriteSymbolTreeWindow,
+                    PluginPermission::ReadProcessMemory,
+                ],
+                true,
+                true,
+            ),
+            symbol_tree_actions: vec![Arc::new(PopulateIl2CppSymbolsAction)],
+        }
+    }
+}
+
+impl Default for Il2CppSymbolsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for Il2CppSymbolsPlugin {
+    fn metadata(&self) -> &PluginMetadata {
+        &self.metadata
+    }
+}
+
+impl PluginPackage for Il2CppSymbolsPlugin {
+    fn as_symbol_tree_plugin(&self) -> Option<&dyn SymbolTreePlugin> {
+        Some(self)
+    }
+}
+
+impl SymbolTreePlugin for Il2CppSymbolsPlugin {
+    fn symbol_tree_actions(&self) -> &[Arc<dyn SymbolTreeAction>] {
+        &self.symbol_tree_actions
+    }
+}
@@ -0,0 +1,586 @@
+use crate::{
+    binary::{
+        il2cpp_binary_image::Il2CppBinaryImage,
+        il2cpp_registration::{Il2CppCodeGenModule, Il2CppMetadataRegistration},
+    },
+    il2cpp_symbol_builder::{Il2CppModuleField, Il2CppSymbolBuilder, Il2CppSymbols},
+    metadata::{global_metadata_locator::locate_global_metadata, il2cpp_metadata::Il2CppMetadata},
+};
+use squalr_engine_api::{
+    plugins::{
+        PluginPermission,
+        symbol_tree::symbol_tree_action::{
+            DataTypeRegistryStore, SymbolTreeAction, SymbolTreeActionContext, SymbolTreeActionSelection, SymbolTreeActionServices,
+        },
+    },
+    registries::symbols::struct_layout_descriptor::StructLayoutDescriptor,
+    structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::container_type::ContainerType,
+        projects::{
+            project_symbol_catalog::ProjectSymbolCatalog,
+            project_symbol_module_field::ProjectSymbolModuleField,
+            symbol_layouts::{
+                symbol_layout_field_materializer::{SymbolLayoutFieldMaterializer, SymbolLayoutPositionedField},
+                symbol_layout_size_resolver::SymbolLayoutSizeResolver,
+            },
+        },
+        structs::{
+            symbolic_field_definition::{SymbolicFieldDefinition, SymbolicFieldOffsetResolution},
+            symbolic_struct_definition::SymbolicStructDefinition,
+        },
+    },
+};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs,
+};
+
+/// Module names of the IL2CPP binary: `GameAssembly` on desktop players and `libil2cpp` on Android.
+const IL2CPP_MODULE_NAMES: [&str; 2] = ["gameassembly", "libil2cpp"];
+
+pub struct PopulateIl2CppSymbolsAction;
+
+impl SymbolTreeAction for PopulateIl2CppSymbolsAction {
+    fn action_id(&self) -> &'static str {
+        "builtin.symbols.il2cpp.populate-il2cpp-symbols"
+    }
+
+    fn label(
+        &self,
+        _context: &SymbolTreeActionContext,
+    ) -> String {
+        String::from("Populate IL2CPP Symbols")
+    }
+
+    fn is_visible(
+        &self,
+        context: &SymbolTreeActionContext,
+    ) -> bool {
+        matches!(context.get_selection(), SymbolTreeActionSelection::ModuleRoot { module_name } if is_il2cpp_module(module_name))
+    }
+
+    fn required_permissions(&self) -> &'static [PluginPermission] {
+        &[
+            PluginPermission::ReadSymbolStore,
+            PluginPermission::WriteSymbolStore,
+            PluginPermission::ReadSymbolTreeWindow,
+            PluginPermission::WriteSymbolTreeWindow,
+            PluginPermission::ReadProcessMemory,
+        ]
+    }
+
+    /// Both inputs are read from disk, so symbols can be imported before the game has initialized its runtime.
+    fn execute(
+        &self,
+        context: &SymbolTreeActionContext,
+        services: &dyn SymbolTreeActionServices,
+    ) -> Result<(), String> {
+        let SymbolTreeActionSelection::ModuleRoot { module_name } = context.get_selection() else {
+            return Err(String::from("IL2CPP symbol population requires a module root selection."));
+        };
+
+        if !is_il2cpp_module(module_name) {
+            return Err(format!("Module `{module_name}` is not an IL2CPP binary."));
+        }
+
+        let binary_file_path = services
+            .process_memory()
+            .resolve_module_file_path(module_name)?
+            .ok_or_else(|| format!("Could not resolve the file path of module `{module_name}`."))?;
+        let metadata_file_path =
+            locate_global_metadata(&binary_file_path).ok_or_else(|| format!("Could not find global-metadata.dat for `{}`.", binary_file_path.display()))?;
+        let metadata_bytes = fs::read(&metadata_file_path).map_err(|error| format!("Failed to read `{}`: {error}", metadata_file_path.display()))?;
+        let binary_bytes = fs::read(&binary_file_path).map_err(|error| format!("Failed to read `{}`: {error}", binary_file_path.display()))?;
+        let metadata = Il2CppMetadata::parse(&metadata_bytes)?;
+        let binary_image = Il2CppBinaryImage::parse(binary_bytes)?;
+        let metadata_registration = Il2CppMetadataRegistration::locate(&binary_image, metadata.type_definitions.len() as u64)?;
+        let image_names = metadata
+            .image_definitions
+            .iter()
+            .map(|image_definition| image_definition.name.clone())
+            .collect::<Vec<_>>();
+        let code_gen_modules = Il2CppCodeGenModule::locate_all(&binary_image, &image_names);
+        let il2cpp_symbols = Il2CppSymbolBuilder::new(&metadata, &binary_image, &metadata_registration, &code_gen_modules).build();
+        let data_type_size_by_id = collect_data_type_size_by_id(services.data_type_registry());
+        let module_name = module_name.clone();
+        let module_name_for_update = module_name.clone();
+
+        services.symbol_store().write_catalog(
+            "populate IL2CPP symbols",
+            Box::new(move |project_symbol_catalog| {
+                populate_il2cpp_symbols(project_symbol_catalog, &module_name_for_update, il2cpp_symbols, &data_type_size_by_id)
+            }),
+        )?;
+        services.symbol_tree_window().request_refresh();
+        services
+            .symbol_tree_window()
+            .focus_tree_node(&format!("module:{module_name}"));
+
+        Ok(())
+    }
+}
+
+fn is_il2cpp_module(module_name: &str) -> bool {
+    let module_name = module_name.to_ascii_lowercase();
+
+    IL2CPP_MODULE_NAMES
+        .iter()
+        .any(|il2cpp_module_name| module_name.starts_with(il2cpp_module_name))
+}
+
+fn populate_il2cpp_symbols(
+    project_symbol_catalog: &mut ProjectSymbolCatalog,
+    module_name: &str,
+    il2cpp_symbols: Il2CppSymbols,
+    data_type_size_by_id: &BTreeMap<String, u64>,
+) -> Result<(), String> {
+    upsert_struct_layout_descriptors(project_symbol_catalog, il2cpp_symbols.struct_layout_descriptors);
+    upsert_module_fields(project_symbol_catalog, module_name, &il2cpp_symbols.module_fields, data_type_size_by_id)
+}
+
+fn upsert_struct_layout_descriptors(
+    project_symbol_catalog: &mut ProjectSymbolCatalog,
+    new_struct_layout_descriptors: Vec<StructLayoutDescriptor>,
+) {
+    let mut struct_layout_descriptors = project_symbol_catalog.get_struct_layout_descriptors().to_vec();
+    let mut struct_layout_positions_by_id = struct_layout_descriptors
+        .iter()
+        .enumerate()
+        .map(|(struct_layout_position, struct_layout_descriptor)| (struct_layout_descriptor.get_struct_layout_id().to_string(), struct_layout_position))
+        .collect::<HashMap<_, _>>();
+
+    for new_struct_layout_descriptor in new_struct_layout_descriptors {
+        match struct_layout_positions_by_id.get(new_struct_layout_descriptor.get_struct_layout_id()) {
+            Some(struct_layout_position) => struct_layout_descriptors[*struct_layout_position] = new_struct_layout_descriptor,
+            None => {
+                struct_layout_positions_by_id.insert(new_struct_layout_descriptor.get_struct_layout_id().to_string(), struct_layout_descriptors.len());
+                struct_layout_descriptors.push(new_struct_layout_descriptor);
+            }
+        }
+    }
+
+    project_symbol_catalog.set_struct_layout_descriptors(struct_layout_descriptors);
+}
+
+/// Adds the method and registration fields to the IL2CPP module, both as module fields and in the module root layout.
+/// Existing fields that overlap a new one are replaced.
+fn upsert_module_fields(
+    project_symbol_catalog: &mut ProjectSymbolCatalog,
+    module_name: &str,
+    desired_module_fields: &[Il2CppModuleField],
+    data_type_size_by_id: &BTreeMap<String, u64>,
+) -> Result<(), String> {
+    let desired_field_ranges = desired_module_fields
+        .iter()
+        .map(|desired_module_field| {
+            desired_module_field
+                .offset
+                .checked_add(desired_module_field.size_in_bytes)
+                .map(|desired_field_end| (desired_module_field.offset, desired_field_end))
+                .ok_or_else(|| format!("IL2CPP field `{}` range is too large.", desired_module_field.display_name))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let desired_fields_end = desired_field_ranges
+        .iter()
+        .map(|(_, desired_field_end)| *desired_field_end)
+        .max()
+        .unwrap_or(0);
+
+    project_symbol_catalog.ensure_symbol_module(module_name, desired_fields_end);
+    let Some(symbol_module) = project_symbol_catalog.find_symbol_module_mut(module_name) else {
+        return Err(format!("Could not resolve module `{module_name}` after creating it."));
+    };
+    let module_fields = symbol_module.get_fields_mut();
+
+    module_fields.retain(|module_field| !overlaps_desired_range(&desired_field_ranges, module_field.get_offset(), module_field.get_offset() + 1));
+    module_fields.extend(desired_module_fields.iter().map(|desired_module_field| {
+        ProjectSymbolModuleField::new(
+            desired_module_field.display_name.clone(),
+            desired_module_field.offset,
+            desired_module_field.struct_layout_id.clone(),
+        )
+    }));
+    module_fields.sort_by_key(|module_field| module_field.get_offset());
+
+    let module_size = symbol_module.get_size();
+    let resolve_data_type_size_in_bytes = |data_type_ref: &DataTypeRef| {
+        data_type_size_by_id
+            .get(data_type_ref.get_data_type_id())
+            .copied()
+    };
+
+    project_symbol_catalog.ensure_module_root_struct_layout(module_name, module_size, resolve_data_type_size_in_bytes);
+
+    let mut struct_layout_descriptors = project_symbol_catalog.get_struct_layout_descriptors().to_vec();
+    let Some(module_root_layout_descriptor) = struct_layout_descriptors
+        .iter_mut()
+        .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == module_name)
+    else {
+        return Ok(());
+    };
+    let module_root_layout_definition = module_root_layout_descriptor
+        .get_struct_layout_definition()
+        .clone();
+    let resolve_struct_layout_definition = |struct_layout_id: &str| {
+        project_symbol_catalog
+            .get_struct_layout_descriptors()
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == struct_layout_id)
+            .map(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_definition().clone())
+    };
+    let mut positioned_fields = Vec::new();
+    let mut next_sequential_offset = 0_u64;
+
+    for field_definition in module_root_layout_definition.get_fields() {
+        let field_size_in_bytes = SymbolLayoutSizeResolver::resolve_symbolic_field_size_in_bytes(
+            field_definition,
+            resolve_data_type_size_in_bytes,
+            resolve_struct_layout_definition,
+            &mut HashSet::new(),
+        )
+        .unwrap_or(0)
+        .max(1);
+        let field_offset = match field_definition.get_offset_resolution() {
+            SymbolicFieldOffsetResolution::Static(offset_in_bytes) => *offset_in_bytes,
+            SymbolicFieldOffsetResolution::Sequential | SymbolicFieldOffsetResolution::Resolver(_) => next_sequential_offset,
+        };
+
+        next_sequential_offset = next_sequential_offset.max(field_offset.saturating_add(field_size_in_bytes));
+
+        if field_definition.is_unassigned() || overlaps_desired_range(&desired_field_ranges, field_offset, field_offset.saturating_add(field_size_in_bytes)) {
+            continue;
+        }
+
+        positioned_fields.push(SymbolLayoutPositionedField::new(field_offset, field_size_in_bytes, field_definition.clone()));
+    }
+
+    positioned_fields.extend(desired_module_fields.iter().map(|desired_module_field| {
+        SymbolLayoutPositionedField::new(
+            desired_module_field.offset,
+            desired_module_field.size_in_bytes,
+            SymbolicFieldDefinition::new_named(
+                desired_module_field.display_name.clone(),
+                DataTypeRef::new(&desired_module_field.struct_layout_id),
+                ContainerType::None,
+            ),
+        )
+    }));
+    positioned_fields.sort_by_key(|positioned_field| positioned_field.get_offset_in_bytes());
+
+    let declared_size_in_bytes = module_root_layout_definition
+        .get_declared_size_in_bytes()
+        .unwrap_or(0)
+        .max(module_size)
+        .max(desired_fields_end);
+    let rebuilt_fields = SymbolLayoutFieldMaterializer::materialize_positioned_fields(
+        module_root_layout_definition.get_layout_kind(),
+        Some(declared_size_in_bytes),
+        positioned_fields,
+    )?;
+
+    *module_root_layout_descriptor = StructLayoutDescriptor::new(
+        module_name.to_string(),
+        SymbolicStructDefinition::new_with_layout_kind(
+            module_root_layout_definition.get_symbol_namespace().to_string(),
+            module_root_layout_definition.get_layout_kind(),
+            rebuilt_fields,
+        )
+        .with_declared_size_in_bytes(Some(declared_size_in_bytes)),
+    );
+    project_symbol_catalog.set_struct_layout_descriptors(struct_layout_descriptors);
+
+    Ok(())
+}
+
+/// Expects ranges sorted by start and not overlapping each other, as the builder produces them.
+fn overlaps_desired_range(
+    desired_field_ranges: &[(u64, u64)],
+    range_start: u64,
+    range_end: u64,
+) -> bool {
+    let first_candidate_index = desired_field_ranges.partition_point(|(_, desired_field_end)| *desired_field_end <= range_start);
+
+    desired_field_ranges
+        .get(first_candidate_index)
+        .is_some_and(|(desired_field_start, _)| *desired_field_start < range_end)
+}
+
+fn collect_data_type_size_by_id(data_type_registry: &dyn DataTypeRegistryStore) -> BTreeMap<String, u64> {
+    data_type_registry
+        .get_registered_data_type_refs()
+        .into_iter()
+        .filter_map(|data_type_ref| {
+            let unit_size_in_bytes = data_type_registry.get_unit_size_in_bytes(&data_type_ref);
+
+            (unit_size_in_bytes > 0).then(|| (data_type_ref.get_data_type_id().to_string(), unit_size_in_bytes))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PopulateIl2CppSymbolsAction;
+    use crate::{
+        metadata::il2cpp_metadata_layout::Il2CppMetadataRevision,
+        test_il2cpp_files::{TEST_UPDATE_METHOD_RVA, build_test_elf64_game_assembly, build_test_metadata, build_test_pe64_game_assembly},
+    };
+    use squalr_engine_api::{
+        plugins::symbol_tree::symbol_tree_action::{
+            DataTypeRegistryStore, ProcessMemoryStore, ProjectSymbolStore, SymbolTreeAction, SymbolTreeActionContext, SymbolTreeActionSelection,
+            SymbolTreeActionServices, SymbolTreeWindowStore,
+        },
+        registries::symbols::symbol_registry::SymbolRegistry,
+        structures::{data_types::data_type_ref::DataTypeRef, projects::project_symbol_catalog::ProjectSymbolCatalog},
+    };
+    use std::{fs, path::PathBuf, sync::Mutex};
+
+    struct TestProjectSymbolStore {
+        project_symbol_catalog: Mutex<ProjectSymbolCatalog>,
+    }
+
+    impl ProjectSymbolStore for TestProjectSymbolStore {
+        fn read_catalog(&self) -> Result<ProjectSymbolCatalog, String> {
+            Ok(self
+                .project_symbol_catalog
+                .lock()
+                .map_err(|error| error.to_string())?
+                .clone())
+        }
+
+        fn write_catalog(
+            &self,
+            _reason: &str,
+            update_catalog: Box<dyn FnOnce(&mut ProjectSymbolCatalog) -> Result<(), String> + Send>,
+        ) -> Result<(), String> {
+            let mut project_symbol_catalog = self
+                .project_symbol_catalog
+                .lock()
+                .map_err(|error| error.to_string())?;
+
+            update_catalog(&mut project_symbol_catalog)
+        }
+    }
+
+    struct TestProcessMemoryStore {
+        module_file_path: Option<PathBuf>,
+    }
+
+    impl ProcessMemoryStore for TestProcessMemoryStore {
+        fn read_module_bytes(
+            &self,
+            module_name: &str,
+            _offset: u64,
+            _length: u64,
+        ) -> Result<Vec<u8>, String> {
+            Err(format!("Module `{module_name}` memory is not available in this test."))
+        }
+
+        fn resolve_module_file_path(
+            &self,
+            _module_name: &str,
+        ) -> Result<Option<PathBuf>, String> {
+            Ok(self.module_file_path.clone())
+        }
+    }
+
+    struct TestSymbolTreeWindowStore;
+
+    impl SymbolTreeWindowStore for TestSymbolTreeWindowStore {
+        fn request_refresh(&self) {}
+
+        fn focus_tree_node(
+            &self,
+            _tree_node_key: &str,
+        ) {
+        }
+    }
+
+    struct TestSymbolTreeActionServices {
+        project_symbol_store: TestProjectSymbolStore,
+        process_memory_store: TestProcessMemoryStore,
+        data_type_registry: SymbolRegistry,
+        symbol_tree_window_store: TestSymbolTreeWindowStore,
+    }
+
+    impl TestSymbolTreeActionServices {
+        fn new(module_file_path: Option<PathBuf>) -> Self {
+            Self {
+                project_symbol_store: TestProjectSymbolStore {
+                    project_symbol_catalog: Mutex::new(ProjectSymbolCatalog::default()),
+                },
+                process_memory_store: TestProcessMemoryStore { module_file_path },
+                data_type_registry: SymbolRegistry::new(),
+                symbol_tree_window_store: TestSymbolTreeWindowStore,
+            }
+        }
+    }
+
+    impl DataTypeRegistryStore for TestSymbolTreeActionServices {
+        fn get_registered_data_type_refs(&self) -> Vec<DataTypeRef> {
+            self.data_type_registry.get_registered_data_type_refs()
+        }
+
+        fn get_unit_size_in_bytes(
+            &self,
+            data_type_ref: &DataTypeRef,
+        ) -> u64 {
+            self.data_type_registry.get_unit_size_in_bytes(data_type_ref)
+        }
+    }
+
+    impl SymbolTreeActionServices for TestSymbolTreeActionServices {
+        fn symbol_store(&self) -> &dyn ProjectSymbolStore {
+            &self.project_symbol_store
+        }
+
+        fn process_memory(&self) -> &dyn ProcessMemoryStore {
+            &self.process_memory_store
+        }
+
+        fn data_type_registry(&self) -> &dyn DataTypeRegistryStore {
+            self
+        }
+
+        fn symbol_tree_window(&self) -> &dyn SymbolTreeWindowStore {
+            &self.symbol_tree_window_store
+        }
+    }
+
+    /// Lays out a player directory with the binary beside `<Game>_Data/il2cpp_data/Metadata/global-metadata.dat`.
+    fn write_test_player(
+        player_directory: &std::path::Path,
+        binary_file_name: &str,
+        binary_bytes: Vec<u8>,
+        metadata_revision: Il2CppMetadataRevision,
+    ) -> PathBuf {
+        let metadata_directory = player_directory
+            .join("Game_Data")
+            .join("il2cpp_data")
+            .join("Metadata");
+        let binary_file_path = player_directory.join(binary_file_name);
+
+        fs::create_dir_all(&metadata_directory).expect("Expected the metadata directory to be created.");
+        fs::write(metadata_directory.join("global-metadata.dat"), build_test_metadata(metadata_revision)).expect("Expected the metadata to be written.");
+        fs::write(&binary_file_path, binary_bytes).expect("Expected the binary to be written.");
+
+        binary_file_path
+    }
+
+    #[test]
+    fn action_is_visible_only_for_il2cpp_modules() {
+        let action = PopulateIl2CppSymbolsAction;
+        let visible_module_names = ["GameAssembly.dll", "GameAssembly.so", "libil2cpp.so"];
+
+        for module_name in visible_module_names {
+            assert!(action.is_visible(&SymbolTreeActionContext::new(SymbolTreeActionSelection::ModuleRoot {
+                module_name: module_name.to_string(),
+            })));
+        }
+
+        assert!(!action.is_visible(&SymbolTreeActionContext::new(SymbolTreeActionSelection::ModuleRoot {
+            module_name: String::from("game.exe"),
+        })));
+    }
+
+    #[test]
+    fn execute_populates_layouts_and_method_fields_from_pe_player() {
+        let temporary_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let binary_file_path = write_test_player(
+            temporary_directory.path(),
+            "GameAssembly.dll",
+            build_test_pe64_game_assembly(),
+            Il2CppMetadataRevision::V31,
+        );
+        let services = TestSymbolTreeActionServices::new(Some(binary_file_path));
+        let context = SymbolTreeActionContext::new(SymbolTreeActionSelection::ModuleRoot {
+            module_name: String::from("GameAssembly.dll"),
+        });
+
+        PopulateIl2CppSymbolsAction
+            .execute(&context, &services)
+            .expect("Expected IL2CPP symbol population to succeed.");
+        // Running twice must not duplicate module fields.
+        PopulateIl2CppSymbolsAction
+            .execute(&context, &services)
+            .expect("Expected repeated IL2CPP symbol population to succeed.");
+
+        let project_symbol_catalog = services
+            .project_symbol_store
+            .read_catalog()
+            .expect("Expected the test catalog to be readable.");
+        let symbol_module = project_symbol_catalog
+            .find_symbol_module("GameAssembly.dll")
+            .expect("Expected the IL2CPP module to exist.");
+        let module_field_names = symbol_module
+            .get_fields()
+            .iter()
+            .map(|module_field| module_field.get_display_name())
+            .collect::<Vec<_>>();
+
+        assert!(project_symbol_catalog.contains_struct_layout_id("il2cpp.Game.Player"));
+        assert!(project_symbol_catalog.contains_struct_layout_id("il2cpp.Game.Player.statics"));
+        assert!(project_symbol_catalog.contains_struct_layout_id("il2cpp.Game.Entity"));
+        assert_eq!(
+            module_field_names,
+            vec![
+                "Game.Player$$Update",
+                "Game.Player$$get_Health",
+                "Il2CppMetadataRegistration"
+            ]
+        );
+        assert_eq!(symbol_module.get_fields()[0].get_offset(), TEST_UPDATE_METHOD_RVA);
+    }
+
+    #[test]
+    fn execute_reads_position_independent_elf_player() {
+        let temporary_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let binary_file_path = write_test_player(
+            temporary_directory.path(),
+            "GameAssembly.so",
+            build_test_elf64_game_assembly(),
+            Il2CppMetadataRevision::V24_2,
+        );
+        let services = TestSymbolTreeActionServices::new(Some(binary_file_path));
+        let context = SymbolTreeActionContext::new(SymbolTreeActionSelection::ModuleRoot {
+            module_name: String::from("GameAssembly.so"),
+        });
+
+        PopulateIl2CppSymbolsAction
+            .execute(&context, &services)
+            .expect("Expected IL2CPP symbol population to succeed.");
+
+        let project_symbol_catalog = services
+            .project_symbol_store
+            .read_catalog()
+            .expect("Expected the test catalog to be readable.");
+
+        assert!(project_symbol_catalog.contains_struct_layout_id("il2cpp.Game.Player"));
+        assert_eq!(
+            project_symbol_catalog
+                .find_symbol_module("GameAssembly.so")
+                .expect("Expected the IL2CPP module to exist.")
+                .get_fields()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn execute_reports_missing_metadata() {
+        let temporary_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let binary_file_path = temporary_directory.path().join("GameAssembly.dll");
+
+        fs::write(&binary_file_path, build_test_pe64_game_assembly()).expect("Expected the binary to be written.");
+
+        let services = TestSymbolTreeActionServices::new(Some(binary_file_path));
+        let context = SymbolTreeActionContext::new(SymbolTreeActionSelection::ModuleRoot {
+            module_name: String::from("GameAssembly.dll"),
+        });
+        let error = PopulateIl2CppSymbolsAction
+            .execute(&context, &services)
+            .expect_err("Expected population without metadata to fail.");
+
+        assert!(error.contains("global-metadata.dat"));
+    }
+}
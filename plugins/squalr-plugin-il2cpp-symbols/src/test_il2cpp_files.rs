@@ -0,0 +1,359 @@
+use crate::metadata::il2cpp_metadata_layout::{Il2CppMetadataLayout, Il2CppMetadataRevision};
+
+pub(crate) const TEST_PE_IMAGE_BASE: u64 = 0x1_8000_0000;
+pub(crate) const TEST_TYPE_DEFINITION_COUNT: usize = 4;
+pub(crate) const TEST_PLAYER_TYPE_INDEX: usize = 1;
+pub(crate) const TEST_UPDATE_METHOD_RVA: u64 = 0x1100;
+pub(crate) const TEST_GET_HEALTH_METHOD_RVA: u64 = 0x1180;
+pub(crate) const TEST_CODE_GEN_MODULE_RVA: u64 = DATA_RVA + 0x40;
+pub(crate) const TEST_METADATA_REGISTRATION_RVA: u64 = DATA_RVA + 0x500;
+
+const TEST_IMAGE_NAME: &str = "Assembly-CSharp.dll";
+const TEXT_RVA: u64 = 0x1000;
+const DATA_RVA: u64 = 0x2000;
+const SECTION_SIZE: u64 = 0x1000;
+const IMAGE_SIZE: usize = 0x3000;
+const IL2CPP_TYPE_I4: u8 = 0x08;
+const IL2CPP_TYPE_VALUETYPE: u8 = 0x11;
+const IL2CPP_TYPE_CLASS: u8 = 0x12;
+const FIELD_ATTRIBUTE_PRIVATE: u16 = 0x0001;
+const FIELD_ATTRIBUTE_STATIC: u16 = 0x0010;
+const FIELD_ATTRIBUTE_LITERAL: u16 = 0x0040;
+const ELF_RELOCATION_X86_64_RELATIVE: u64 = 8;
+
+/// `(data, attributes, type code)` for each `Il2CppType` in the registration's type table.
+const TEST_TYPES: [(u64, u16, u8); 9] = [
+    (0, 0, IL2CPP_TYPE_CLASS),
+    (1, 0, IL2CPP_TYPE_CLASS),
+    (2, 0, IL2CPP_TYPE_VALUETYPE),
+    (0, FIELD_ATTRIBUTE_PRIVATE, IL2CPP_TYPE_I4),
+    (0, FIELD_ATTRIBUTE_PRIVATE | FIELD_ATTRIBUTE_STATIC, IL2CPP_TYPE_I4),
+    (0, FIELD_ATTRIBUTE_PRIVATE | FIELD_ATTRIBUTE_STATIC | FIELD_ATTRIBUTE_LITERAL, IL2CPP_TYPE_I4),
+    (0, FIELD_ATTRIBUTE_PRIVATE, IL2CPP_TYPE_CLASS),
+    (2, FIELD_ATTRIBUTE_PRIVATE, IL2CPP_TYPE_VALUETYPE),
+    (3, 0, IL2CPP_TYPE_CLASS),
+];
+
+/// `(name, namespace, byval type, declaring type, parent type, field start, field count, method start, method count)`.
+type TestTypeDefinition = (&'static str, &'static str, i32, i32, i32, i32, u16, i32, u16);
+
+const TEST_TYPE_DEFINITIONS: [TestTypeDefinition; TEST_TYPE_DEFINITION_COUNT] = [
+    ("Entity", "Game", 0, -1, -1, 0, 1, 0, 0),
+    ("Player", "Game", 1, -1, 0, 1, 5, 0, 2),
+    ("State", "Game", 2, -1, -1, 6, 2, 2, 0),
+    ("Inventory", "", 8, 1, -1, 8, 1, 2, 0),
+];
+
+/// `(name, type index)`, in type definition order.
+const TEST_FIELDS: [(&str, i32); 9] = [
+    ("id", 3),
+    ("health", 3),
+    ("target", 6),
+    ("state", 7),
+    ("instanceCount", 4),
+    ("MaxHealth", 5),
+    ("value__", 3),
+    ("Idle", 5),
+    ("count", 3),
+];
+
+/// Field offsets per type definition. Literal fields keep a placeholder slot like the real tables.
+const TEST_FIELD_OFFSETS: [&[i32]; TEST_TYPE_DEFINITION_COUNT] = [&[0x10], &[0x18, 0x20, 0x28, 0x0, 0x0], &[0x10, 0x0], &[0x10]];
+
+/// `(instance size, static fields size)` per type definition.
+const TEST_TYPE_DEFINITION_SIZES: [(u32, u32); TEST_TYPE_DEFINITION_COUNT] = [(0x18, 0), (0x30, 4), (0x14, 0), (0x18, 0)];
+
+/// `(name, declaring type, token)`.
+const TEST_METHODS: [(&str, i32, u32); 2] = [("Update", 1, 0x0600_0001), ("get_Health", 1, 0x0600_0002)];
+
+/// Builds a `global-metadata.dat` for one `Assembly-CSharp.dll` image holding `Game.Entity`, `Game.Player` (derived from
+/// `Entity`, with instance, static and literal fields), the `Game.State` enum and the nested `Game.Player.Inventory`.
+pub(crate) fn build_test_metadata(revision: Il2CppMetadataRevision) -> Vec<u8> {
+    let layout = Il2CppMetadataLayout::for_revision(revision);
+    let mut string_bytes = Vec::new();
+    let mut add_string = |value: &str| {
+        let string_index = string_bytes.len() as u32;
+
+        string_bytes.extend_from_slice(value.as_bytes());
+        string_bytes.push(0);
+        string_index
+    };
+    let mut type_definition_bytes = vec![0_u8; TEST_TYPE_DEFINITION_COUNT * layout.type_definition.size];
+    let mut field_bytes = vec![0_u8; TEST_FIELDS.len() * layout.field_definition_size];
+    let mut method_bytes = vec![0_u8; TEST_METHODS.len() * layout.method_definition.size];
+    let mut image_bytes = vec![0_u8; layout.image_definition_size];
+
+    for (type_definition_index, (name, namespace, byval_type, declaring_type, parent_type, field_start, field_count, method_start, method_count)) in
+        TEST_TYPE_DEFINITIONS.into_iter().enumerate()
+    {
+        let record = &mut type_definition_bytes[type_definition_index * layout.type_definition.size..];
+
+        write_u32(record, layout.type_definition.name, add_string(name));
+        write_u32(record, layout.type_definition.namespace, add_string(namespace));
+        write_u32(record, layout.type_definition.byval_type, byval_type as u32);
+        write_u32(record, layout.type_definition.declaring_type, declaring_type as u32);
+        write_u32(record, layout.type_definition.parent_type, parent_type as u32);
+        write_u32(record, layout.type_definition.field_start, field_start as u32);
+        write_u32(record, layout.type_definition.method_start, method_start as u32);
+        record[layout.type_definition.field_count..layout.type_definition.field_count + 2].copy_from_slice(&field_count.to_le_bytes());
+        record[layout.type_definition.method_count..layout.type_definition.method_count + 2].copy_from_slice(&method_count.to_le_bytes());
+    }
+
+    for (field_index, (name, type_index)) in TEST_FIELDS.into_iter().enumerate() {
+        let record = &mut field_bytes[field_index * layout.field_definition_size..];
+
+        write_u32(record, Il2CppMetadataLayout::FIELD_DEFINITION_NAME, add_string(name));
+        write_u32(record, Il2CppMetadataLayout::FIELD_DEFINITION_TYPE, type_index as u32);
+    }
+
+    for (method_index, (name, declaring_type, token)) in TEST_METHODS.into_iter().enumerate() {
+        let record = &mut method_bytes[method_index * layout.method_definition.size..];
+
+        write_u32(record, layout.method_definition.name, add_string(name));
+        write_u32(record, layout.method_definition.declaring_type, declaring_type as u32);
+        write_u32(record, layout.method_definition.token, token);
+    }
+
+    write_u32(&mut image_bytes, Il2CppMetadataLayout::IMAGE_DEFINITION_NAME, add_string(TEST_IMAGE_NAME));
+    write_u32(&mut image_bytes, Il2CppMetadataLayout::IMAGE_DEFINITION_TYPE_START, 0);
+    write_u32(
+        &mut image_bytes,
+        Il2CppMetadataLayout::IMAGE_DEFINITION_TYPE_COUNT,
+        TEST_TYPE_DEFINITION_COUNT as u32,
+    );
+
+    let version: u32 = match revision {
+        Il2CppMetadataRevision::V24_0 | Il2CppMetadataRevision::V24_1 | Il2CppMetadataRevision::V24_2 => 24,
+        Il2CppMetadataRevision::V27 => 29,
+        Il2CppMetadataRevision::V31 => 31,
+    };
+    let mut metadata_bytes = vec![0_u8; layout.header_size];
+
+    write_u32(&mut metadata_bytes, 0, Il2CppMetadataLayout::SANITY);
+    write_u32(&mut metadata_bytes, 4, version);
+    // The empty string literal section marks the end of the header, which tells the 24.x revisions apart.
+    write_u32(&mut metadata_bytes, 8, layout.header_size as u32);
+
+    for (section_index, section_bytes) in [
+        (layout.string_section_index, string_bytes),
+        (layout.method_section_index, method_bytes),
+        (layout.field_section_index, field_bytes),
+        (layout.type_definition_section_index, type_definition_bytes),
+        (layout.image_section_index, image_bytes),
+    ] {
+        let section_offset = metadata_bytes.len() as u32;
+
+        write_u32(&mut metadata_bytes, 8 + section_index * 8, section_offset);
+        write_u32(&mut metadata_bytes, 12 + section_index * 8, section_bytes.len() as u32);
+        metadata_bytes.extend_from_slice(&section_bytes);
+    }
+
+    metadata_bytes
+}
+
+/// Data section contents, with pointer slots recorded as RVAs so each container can encode them its own way.
+struct TestDataSection {
+    bytes: Vec<u8>,
+    pointer_slots: Vec<(u64, u64)>,
+}
+
+impl TestDataSection {
+    fn add_pointer(
+        &mut self,
+        offset: u64,
+        target_rva: u64,
+    ) {
+        self.pointer_slots.push((offset, target_rva));
+    }
+}
+
+fn build_test_data_section() -> TestDataSection {
+    let mut data_section = TestDataSection {
+        bytes: vec![0_u8; SECTION_SIZE as usize],
+        pointer_slots: Vec::new(),
+    };
+    let code_gen_module_offset = TEST_CODE_GEN_MODULE_RVA - DATA_RVA;
+    let method_pointers_offset = 0x60;
+    let types_offset = 0x100;
+    let type_table_offset = 0x200;
+    let field_offset_arrays_offset = 0x300;
+    let field_offsets_table_offset = 0x380;
+    let type_definition_sizes_offset = 0x400;
+    let type_definition_sizes_table_offset = 0x480;
+    let metadata_registration_offset = TEST_METADATA_REGISTRATION_RVA - DATA_RVA;
+
+    data_section.bytes[..TEST_IMAGE_NAME.len()].copy_from_slice(TEST_IMAGE_NAME.as_bytes());
+    data_section.add_pointer(code_gen_module_offset, DATA_RVA);
+    write_u32(&mut data_section.bytes, code_gen_module_offset as usize + 8, TEST_METHODS.len() as u32);
+    data_section.add_pointer(code_gen_module_offset + 16, DATA_RVA + method_pointers_offset);
+    data_section.add_pointer(method_pointers_offset, TEST_UPDATE_METHOD_RVA);
+    data_section.add_pointer(method_pointers_offset + 8, TEST_GET_HEALTH_METHOD_RVA);
+
+    for (type_index, (data, attributes, type_code)) in TEST_TYPES.into_iter().enumerate() {
+        let type_offset = types_offset + type_index as u64 * 16;
+
+        write_u64(&mut data_section.bytes, type_offset as usize, data);
+        write_u32(&mut data_section.bytes, type_offset as usize + 8, attributes as u32 | (type_code as u32) << 16);
+        data_section.add_pointer(type_table_offset + type_index as u64 * 8, DATA_RVA + type_offset);
+    }
+
+    let mut field_offset_array_offset = field_offset_arrays_offset;
+
+    for (type_definition_index, field_offsets) in TEST_FIELD_OFFSETS.into_iter().enumerate() {
+        for (field_index, field_offset) in field_offsets.iter().enumerate() {
+            write_u32(
+                &mut data_section.bytes,
+                field_offset_array_offset as usize + field_index * 4,
+                *field_offset as u32,
+            );
+        }
+
+        data_section.add_pointer(
+            field_offsets_table_offset + type_definition_index as u64 * 8,
+            DATA_RVA + field_offset_array_offset,
+        );
+        field_offset_array_offset += 0x10 * (field_offsets.len() as u64).div_ceil(4);
+    }
+
+    for (type_definition_index, (instance_size, static_fields_size)) in TEST_TYPE_DEFINITION_SIZES.into_iter().enumerate() {
+        let sizes_offset = type_definition_sizes_offset + type_definition_index as u64 * 16;
+
+        write_u32(&mut data_section.bytes, sizes_offset as usize, instance_size);
+        write_u32(&mut data_section.bytes, sizes_offset as usize + 4, instance_size);
+        write_u32(&mut data_section.bytes, sizes_offset as usize + 8, static_fields_size);
+        data_section.add_pointer(type_definition_sizes_table_offset + type_definition_index as u64 * 8, DATA_RVA + sizes_offset);
+    }
+
+    for (pair_index, count, table_offset) in [
+        (3, TEST_TYPES.len(), type_table_offset),
+        (5, TEST_TYPE_DEFINITION_COUNT, field_offsets_table_offset),
+        (6, TEST_TYPE_DEFINITION_COUNT, type_definition_sizes_table_offset),
+    ] {
+        let pair_offset = metadata_registration_offset + pair_index * 16;
+
+        write_u64(&mut data_section.bytes, pair_offset as usize, count as u64);
+        data_section.add_pointer(pair_offset + 8, DATA_RVA + table_offset);
+    }
+
+    data_section
+}
+
+/// Builds a PE32+ image with `.text` and `.data` sections, linked at `TEST_PE_IMAGE_BASE`.
+pub(crate) fn build_test_pe64_game_assembly() -> Vec<u8> {
+    let mut image_bytes = vec![0_u8; IMAGE_SIZE];
+    let nt_headers_offset = 0x80_usize;
+    let optional_header_offset = nt_headers_offset + 24;
+    let optional_header_size = 0xF0_usize;
+    let section_headers_offset = optional_header_offset + optional_header_size;
+    let mut data_section = build_test_data_section();
+
+    image_bytes[..2].copy_from_slice(b"MZ");
+    write_u32(&mut image_bytes, 0x3C, nt_headers_offset as u32);
+    image_bytes[nt_headers_offset..nt_headers_offset + 4].copy_from_slice(b"PE\0\0");
+    image_bytes[nt_headers_offset + 4..nt_headers_offset + 6].copy_from_slice(&0x8664_u16.to_le_bytes());
+    image_bytes[nt_headers_offset + 6..nt_headers_offset + 8].copy_from_slice(&2_u16.to_le_bytes());
+    image_bytes[nt_headers_offset + 20..nt_headers_offset + 22].copy_from_slice(&(optional_header_size as u16).to_le_bytes());
+    image_bytes[optional_header_offset..optional_header_offset + 2].copy_from_slice(&0x20B_u16.to_le_bytes());
+    write_u64(&mut image_bytes, optional_header_offset + 24, TEST_PE_IMAGE_BASE);
+
+    for (section_index, (section_name, section_rva, characteristics)) in [
+        (b".text\0\0\0", TEXT_RVA, 0x6000_0020_u32),
+        (b".data\0\0\0", DATA_RVA, 0xC000_0040),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let section_header_offset = section_headers_offset + section_index * 40;
+
+        image_bytes[section_header_offset..section_header_offset + 8].copy_from_slice(section_name);
+        write_u32(&mut image_bytes, section_header_offset + 8, SECTION_SIZE as u32);
+        write_u32(&mut image_bytes, section_header_offset + 12, section_rva as u32);
+        write_u32(&mut image_bytes, section_header_offset + 16, SECTION_SIZE as u32);
+        write_u32(&mut image_bytes, section_header_offset + 20, section_rva as u32);
+        write_u32(&mut image_bytes, section_header_offset + 36, characteristics);
+    }
+
+    for (slot_offset, target_rva) in std::mem::take(&mut data_section.pointer_slots) {
+        write_u64(&mut data_section.bytes, slot_offset as usize, TEST_PE_IMAGE_BASE + target_rva);
+    }
+
+    image_bytes[DATA_RVA as usize..].copy_from_slice(&data_section.bytes);
+    image_bytes
+}
+
+/// Builds a position-independent x86-64 ELF whose data pointers are left zeroed and supplied as `RELA` addends.
+pub(crate) fn build_test_elf64_game_assembly() -> Vec<u8> {
+    let mut image_bytes = vec![0_u8; IMAGE_SIZE];
+    let program_headers_offset = 0x40_usize;
+    let relocation_table_offset = 0x800_u64;
+    let dynamic_offset = 0xB00_u64;
+    let mut data_section = build_test_data_section();
+    let pointer_slots = std::mem::take(&mut data_section.pointer_slots);
+
+    image_bytes[..4].copy_from_slice(b"\x7FELF");
+    image_bytes[4] = 2;
+    image_bytes[5] = 1;
+    image_bytes[6] = 1;
+    image_bytes[0x10..0x12].copy_from_slice(&3_u16.to_le_bytes());
+    image_bytes[0x12..0x14].copy_from_slice(&62_u16.to_le_bytes());
+    write_u64(&mut image_bytes, 0x20, program_headers_offset as u64);
+    image_bytes[0x36..0x38].copy_from_slice(&56_u16.to_le_bytes());
+    image_bytes[0x38..0x3A].copy_from_slice(&3_u16.to_le_bytes());
+
+    for (program_header_index, (program_header_type, flags, offset, size)) in [
+        (1_u32, 5_u32, 0, DATA_RVA),
+        (1, 6, DATA_RVA, SECTION_SIZE),
+        (2, 6, DATA_RVA + dynamic_offset, 0x30),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let program_header_offset = program_headers_offset + program_header_index * 56;
+
+        write_u32(&mut image_bytes, program_header_offset, program_header_type);
+        write_u32(&mut image_bytes, program_header_offset + 4, flags);
+        write_u64(&mut image_bytes, program_header_offset + 0x08, offset);
+        write_u64(&mut image_bytes, program_header_offset + 0x10, offset);
+        write_u64(&mut image_bytes, program_header_offset + 0x20, size);
+        write_u64(&mut image_bytes, program_header_offset + 0x28, size);
+    }
+
+    for (relocation_index, (slot_offset, target_rva)) in pointer_slots.iter().enumerate() {
+        let relocation_offset = (relocation_table_offset as usize) + relocation_index * 24;
+
+        write_u64(&mut data_section.bytes, relocation_offset, DATA_RVA + slot_offset);
+        write_u64(&mut data_section.bytes, relocation_offset + 8, ELF_RELOCATION_X86_64_RELATIVE);
+        write_u64(&mut data_section.bytes, relocation_offset + 16, *target_rva);
+    }
+
+    for (dynamic_index, (dynamic_tag, dynamic_value)) in [
+        (7_u64, DATA_RVA + relocation_table_offset),
+        (8, pointer_slots.len() as u64 * 24),
+        (0, 0),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        write_u64(&mut data_section.bytes, dynamic_offset as usize + dynamic_index * 16, dynamic_tag);
+        write_u64(&mut data_section.bytes, dynamic_offset as usize + dynamic_index * 16 + 8, dynamic_value);
+    }
+
+    image_bytes[DATA_RVA as usize..].copy_from_slice(&data_section.bytes);
+    image_bytes
+}
+
+fn write_u32(
+    bytes: &mut [u8],
+    offset: usize,
+    value: u32,
+) {
+    bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+fn write_u64(
+    bytes: &mut [u8],
+    offset: usize,
+    value: u64,
+) {
+    bytes[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+}
@@ -0,0 +1,102 @@
+use crate::{
+    arm_instruction_mode::ArmInstructionMode,
+    instruction_set::{DecodedInstruction, format_signed_hex},
+};
+
+/// A single instruction decoded at its absolute address, for listing views such as the code viewer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArmDisassembledInstruction {
+    pub address: u64,
+    pub length: usize,
+    pub bytes: Vec<u8>,
+    pub text: String,
+    pub branch_target_address: Option<u64>,
+    /// The instruction set at the branch target, when the branch is direct and decoded from 32-bit ARM or Thumb code.
+    pub branch_target_mode: Option<ArmInstructionMode>,
+    pub is_control_flow: bool,
+    pub is_call: bool,
+    pub is_interworking_branch: bool,
+}
+
+impl ArmDisassembledInstruction {
+    pub(crate) fn from_decoded_instruction(
+        address: u64,
+        bytes: Vec<u8>,
+        decoded_instruction: &DecodedInstruction,
+        source_mode: Option<ArmInstructionMode>,
+    ) -> Self {
+        let mnemonic = decoded_instruction.mnemonic();
+        let is_interworking_branch = source_mode.is_some() && matches!(mnemonic, "bx" | "blx");
+        let is_call = matches!(mnemonic, "bl" | "blx");
+        let length = bytes.len();
+
+        match decoded_instruction {
+            DecodedInstruction::Plain(instruction_text) => Self {
+                address,
+                length,
+                bytes,
+                text: instruction_text.clone(),
+                branch_target_address: None,
+                branch_target_mode: None,
+                is_control_flow: is_indirect_control_flow(instruction_text),
+                is_call,
+                is_interworking_branch,
+            },
+            DecodedInstruction::Branch { target_address, .. } => Self {
+                address,
+                length,
+                bytes,
+                text: decoded_instruction.format_with_target_text(&format_signed_hex(*target_address)),
+                branch_target_address: u64::try_from(*target_address).ok(),
+                branch_target_mode: source_mode.map(|source_mode| if mnemonic == "blx" { source_mode.toggled() } else { source_mode }),
+                is_control_flow: true,
+                is_call,
+                is_interworking_branch,
+            },
+        }
+    }
+
+    pub(crate) fn from_undecodable_bytes(
+        address: u64,
+        bytes: Vec<u8>,
+        text: String,
+    ) -> Self {
+        Self {
+            address,
+            length: bytes.len(),
+            bytes,
+            text,
+            branch_target_address: None,
+            branch_target_mode: None,
+            is_control_flow: false,
+            is_call: false,
+            is_interworking_branch: false,
+        }
+    }
+
+    /// Describes the instruction set switch performed by an interworking branch. Register forms switch on bit 0 of
+    /// the register at runtime, so only the immediate forms can name the destination mode.
+    pub fn get_interworking_annotation(&self) -> Option<String> {
+        if !self.is_interworking_branch {
+            return None;
+        }
+
+        Some(match self.branch_target_mode {
+            Some(target_mode) => format!("→ {}", target_mode.get_display_name()),
+            None => String::from("→ ARM/Thumb (bit 0)"),
+        })
+    }
+}
+
+fn is_indirect_control_flow(instruction_text: &str) -> bool {
+    let (mnemonic, operands_text) = instruction_text
+        .split_once(' ')
+        .unwrap_or((instruction_text, ""));
+
+    match mnemonic {
+        "bx" | "blx" | "ret" | "svc" | "bkpt" | "udf" | "tbb" | "tbh" => true,
+        "pop" | "pop.w" | "ldmia" => operands_text.contains("pc"),
+        "mov" | "add" | "ldr" | "ldr.w" => operands_text.split(',').next().map(str::trim) == Some("pc"),
+        _ => false,
+    }
+}
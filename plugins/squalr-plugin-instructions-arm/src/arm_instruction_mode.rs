@@ -0,0 +1,131 @@
+use crate::arm_disassembled_instruction::ArmDisassembledInstruction;
+use std::{collections::BTreeMap, ops::Range};
+
+/// The 32-bit ARM instruction set state. Interworking branches (`bx`, `blx`) switch between the two, with bit 0
+/// of a branch target address selecting Thumb.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ArmInstructionMode {
+    Arm,
+    Thumb,
+}
+
+impl ArmInstructionMode {
+    pub fn from_interworking_address(address: u64) -> Self {
+        if address & 1 != 0 { Self::Thumb } else { Self::Arm }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            Self::Arm => Self::Thumb,
+            Self::Thumb => Self::Arm,
+        }
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            Self::Arm => "ARM",
+            Self::Thumb => "Thumb",
+        }
+    }
+}
+
+/// Tracks which instruction set is active across an address space. Each hint applies from its address up to the
+/// next hint, and addresses before the first hint are assumed to be ARM code.
+#[derive(Clone, Debug, Default)]
+pub struct ArmInstructionModeMap {
+    mode_hints: BTreeMap<u64, ArmInstructionMode>,
+}
+
+impl ArmInstructionModeMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_mode(
+        &mut self,
+        address: u64,
+        instruction_mode: ArmInstructionMode,
+    ) {
+        self.mode_hints.insert(address & !1, instruction_mode);
+    }
+
+    /// Records a code address that may carry the interworking bit, such as a Thumb function symbol, and returns the
+    /// instruction address with that bit cleared. Even addresses carry no mode information and record nothing.
+    pub fn record_interworking_address(
+        &mut self,
+        address: u64,
+    ) -> u64 {
+        if ArmInstructionMode::from_interworking_address(address) == ArmInstructionMode::Thumb {
+            self.record_mode(address, ArmInstructionMode::Thumb);
+        }
+
+        address & !1
+    }
+
+    /// Records the instruction set of direct call targets. Only calls are trusted, since they land on function
+    /// entries where the mode is fixed, while a mode hint at a local branch target could bleed into unrelated code.
+    pub fn record_call_targets(
+        &mut self,
+        instructions: &[ArmDisassembledInstruction],
+    ) {
+        for instruction in instructions {
+            if !instruction.is_call {
+                continue;
+            }
+
+            let (Some(target_address), Some(target_mode)) = (instruction.branch_target_address, instruction.branch_target_mode) else {
+                continue;
+            };
+
+            if self.resolve_mode(target_address) != target_mode {
+                self.mode_hints.entry(target_address).or_insert(target_mode);
+            }
+        }
+    }
+
+    pub fn resolve_mode(
+        &self,
+        address: u64,
+    ) -> ArmInstructionMode {
+        self.mode_hints
+            .range(..=address)
+            .next_back()
+            .map(|(_, instruction_mode)| *instruction_mode)
+            .unwrap_or(ArmInstructionMode::Arm)
+    }
+
+    /// Splits an address range into contiguous runs that share an instruction set.
+    pub fn resolve_mode_segments(
+        &self,
+        address_range: Range<u64>,
+    ) -> Vec<(Range<u64>, ArmInstructionMode)> {
+        if address_range.is_empty() {
+            return Vec::new();
+        }
+
+        let mut mode_segments = Vec::new();
+        let mut segment_start_address = address_range.start;
+        let mut segment_mode = self.resolve_mode(segment_start_address);
+
+        for (hint_address, hint_mode) in self
+            .mode_hints
+            .range(address_range.start.saturating_add(1)..address_range.end)
+        {
+            if *hint_mode == segment_mode {
+                continue;
+            }
+
+            mode_segments.push((segment_start_address..*hint_address, segment_mode));
+            segment_start_address = *hint_address;
+            segment_mode = *hint_mode;
+        }
+
+        mode_segments.push((segment_start_address..address_range.end, segment_mode));
+
+        mode_segments
+    }
+
+    pub fn clear(&mut self) {
+        self.mode_hints.clear();
+    }
+}
@@ -1,4 +1,6 @@
 use crate::{
+    arm_disassembled_instruction::ArmDisassembledInstruction,
+    arm_instruction_mode::ArmInstructionMode,
     arm_memory_operand::parse_arm_memory_expression,
     arm32_register::{format_arm32_register_name, parse_arm32_register_name},
     arm64_register::{
@@ -17,9 +19,37 @@ enum ArmMode {
 }
 
 #[derive(Clone, Debug)]
-enum DecodedInstruction {
+pub(crate) enum DecodedInstruction {
     Plain(String),
-    Branch { mnemonic: &'static str, target_address: i64 },
+    Branch {
+        mnemonic: &'static str,
+        leading_register: Option<u8>,
+        target_address: i64,
+    },
+}
+
+impl DecodedInstruction {
+    pub(crate) fn mnemonic(&self) -> &str {
+        match self {
+            DecodedInstruction::Plain(instruction_text) => instruction_text.split_whitespace().next().unwrap_or_default(),
+            DecodedInstruction::Branch { mnemonic, .. } => mnemonic,
+        }
+    }
+
+    pub(crate) fn format_with_target_text(
+        &self,
+        target_text: &str,
+    ) -> String {
+        match self {
+            DecodedInstruction::Plain(instruction_text) => instruction_text.clone(),
+            DecodedInstruction::Branch {
+                mnemonic,
+                leading_register: Some(leading_register),
+                ..
+            } => format!("{} {}, {}", mnemonic, format_arm32_register_name(*leading_register), target_text),
+            DecodedInstruction::Branch { mnemonic, .. } => format!("{} {}", mnemonic, target_text),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -32,12 +62,28 @@ impl Arm32InstructionSet {
     pub fn new() -> Self {
         Self
     }
+
+    pub fn disassemble_block(
+        &self,
+        instruction_bytes: &[u8],
+        base_address: u64,
+    ) -> Result<Vec<ArmDisassembledInstruction>, String> {
+        disassemble_instruction_block(ArmMode::Arm32, instruction_bytes, base_address)
+    }
 }
 
 impl Arm64InstructionSet {
     pub fn new() -> Self {
         Self
     }
+
+    pub fn disassemble_block(
+        &self,
+        instruction_bytes: &[u8],
+        base_address: u64,
+    ) -> Result<Vec<ArmDisassembledInstruction>, String> {
+        disassemble_instruction_block(ArmMode::Arm64, instruction_bytes, base_address)
+    }
 }
 
 impl InstructionSet for Arm32InstructionSet {
@@ -184,10 +230,51 @@ fn disassemble_instruction_sequence(
             ArmMode::Arm64 => decode_arm64_instruction(instruction_word, current_instruction_address)?,
         };
 
-        decoded_instructions.push(decoded_instruction);
+        decoded_instructions.push((current_instruction_address, decoded_instruction));
+    }
+
+    Ok(format_decoded_instruction_sequence(&decoded_instructions))
+}
+
+/// Decodes whole words at their absolute addresses. Unsupported words are listed as `.word` directives so a
+/// listing can continue past them.
+fn disassemble_instruction_block(
+    arm_mode: ArmMode,
+    instruction_bytes: &[u8],
+    base_address: u64,
+) -> Result<Vec<ArmDisassembledInstruction>, String> {
+    let source_mode = match arm_mode {
+        ArmMode::Arm32 => Some(ArmInstructionMode::Arm),
+        ArmMode::Arm64 => None,
+    };
+    let mut disassembled_instructions = Vec::with_capacity(instruction_bytes.len() / 4);
+
+    for (instruction_index, instruction_word_bytes) in instruction_bytes.chunks_exact(4).enumerate() {
+        let instruction_address = base_address.saturating_add((instruction_index as u64) * 4);
+        let instruction_word = u32::from_le_bytes([
+            instruction_word_bytes[0],
+            instruction_word_bytes[1],
+            instruction_word_bytes[2],
+            instruction_word_bytes[3],
+        ]);
+        let decode_result = match arm_mode {
+            ArmMode::Arm32 => decode_arm32_instruction(instruction_word, instruction_address as i64),
+            ArmMode::Arm64 => decode_arm64_instruction(instruction_word, instruction_address as i64),
+        };
+
+        disassembled_instructions.push(match decode_result {
+            Ok(decoded_instruction) => {
+                ArmDisassembledInstruction::from_decoded_instruction(instruction_address, instruction_word_bytes.to_vec(), &decoded_instruction, source_mode)
+            }
+            Err(_) => ArmDisassembledInstruction::from_undecodable_bytes(
+                instruction_address,
+                instruction_word_bytes.to_vec(),
+                format!(".word 0x{:08X}", instruction_word),
+            ),
+        });
     }
 
-    Ok(format_decoded_instruction_sequence(&decoded_instructions, instruction_bytes.len()))
+    Ok(disassembled_instructions)
 }
 
 fn encode_arm32_instruction(
@@ -196,8 +283,10 @@ fn encode_arm32_instruction(
     label_addresses: &HashMap<String, i64>,
 ) -> Result<u32, String> {
     match parsed_instruction.mnemonic() {
+        ".word" => encode_data_word_directive(parsed_instruction),
         "nop" if parsed_instruction.operands().is_empty() => Ok(0xE320_F000),
         "bx" => Ok(0xE12F_FF10 | (parse_arm32_register_operand(parsed_instruction.operands(), 0)? as u32)),
+        "blx" => encode_arm32_branch_with_exchange(parsed_instruction, current_instruction_address, label_addresses),
         "b" | "bl" => encode_arm32_branch(parsed_instruction, current_instruction_address, label_addresses),
         "mov" => encode_arm32_mov(parsed_instruction),
         "add" => encode_arm32_add(parsed_instruction),
@@ -212,6 +301,7 @@ fn encode_arm64_instruction(
     label_addresses: &HashMap<String, i64>,
 ) -> Result<u32, String> {
     match parsed_instruction.mnemonic() {
+        ".word" => encode_data_word_directive(parsed_instruction),
         "nop" if parsed_instruction.operands().is_empty() => Ok(0xD503_201F),
         "ret" => encode_arm64_ret(parsed_instruction),
         "b" | "bl" => encode_arm64_branch(parsed_instruction, current_instruction_address, label_addresses),
@@ -248,6 +338,39 @@ fn encode_arm32_branch(
     Ok(branch_opcode | ((branch_delta_words as i32 as u32) & 0x00FF_FFFF))
 }
 
+/// Encodes `blx`, which always switches instruction sets: the register form exchanges on bit 0 of the
+/// register, and the immediate form always enters Thumb code at a halfword-aligned target.
+fn encode_arm32_branch_with_exchange(
+    parsed_instruction: &ParsedInstruction,
+    current_instruction_address: i64,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<u32, String> {
+    if let Some(InstructionOperand::Identifier(register_name)) = parsed_instruction.operands().first()
+        && let Some(register_index) = parse_arm32_register_name(register_name)
+    {
+        return Ok(0xE12F_FF30 | (register_index as u32));
+    }
+
+    let target_address = resolve_branch_target_address(parsed_instruction.operands(), label_addresses)?;
+    let branch_delta_bytes = target_address - (current_instruction_address + 8);
+
+    if branch_delta_bytes % 2 != 0 {
+        return Err(format!("ARM blx target '{}' must be 2-byte aligned.", format_signed_hex(target_address)));
+    }
+
+    let branch_delta_words = branch_delta_bytes.div_euclid(4);
+    let half_word_bit = (branch_delta_bytes.rem_euclid(4) / 2) as u32;
+
+    if !(-0x80_0000..=0x7F_FFFF).contains(&branch_delta_words) {
+        return Err(format!(
+            "ARM blx target '{}' is out of range for a 24-bit branch immediate.",
+            format_signed_hex(target_address)
+        ));
+    }
+
+    Ok(0xFA00_0000 | (half_word_bit << 24) | ((branch_delta_words as i32 as u32) & 0x00FF_FFFF))
+}
+
 fn encode_arm64_branch(
     parsed_instruction: &ParsedInstruction,
     current_instruction_address: i64,
@@ -319,6 +442,16 @@ fn encode_arm32_load_store(parsed_instruction: &ParsedInstruction) -> Result<u32
     Ok(load_store_base | offset_direction_bit | ((base_register_index as u32) << 16) | ((data_register_index as u32) << 12) | (absolute_offset as u32))
 }
 
+fn encode_data_word_directive(parsed_instruction: &ParsedInstruction) -> Result<u32, String> {
+    if parsed_instruction.operands().len() != 1 {
+        return Err(String::from(".word requires exactly one immediate operand."));
+    }
+
+    let word_value = parse_non_negative_immediate_operand(parsed_instruction.operands(), 0, ".word")?;
+
+    u32::try_from(word_value).map_err(|_| format!(".word value '{}' does not fit in 32 bits.", word_value))
+}
+
 fn encode_arm64_ret(parsed_instruction: &ParsedInstruction) -> Result<u32, String> {
     let return_register_index = if parsed_instruction.operands().is_empty() {
         30
@@ -441,6 +574,18 @@ fn decode_arm32_instruction(
     instruction_word: u32,
     current_instruction_address: i64,
 ) -> Result<DecodedInstruction, String> {
+    if instruction_word & 0xFE00_0000 == 0xFA00_0000 {
+        let branch_delta_words = sign_extend(instruction_word & 0x00FF_FFFF, 24);
+        let half_word_offset = (((instruction_word >> 24) & 1) as i64) * 2;
+        let target_address = current_instruction_address + 8 + branch_delta_words * 4 + half_word_offset;
+
+        return Ok(DecodedInstruction::Branch {
+            mnemonic: "blx",
+            leading_register: None,
+            target_address,
+        });
+    }
+
     let condition_code = instruction_word >> 28;
 
     if condition_code != 0xE {
@@ -457,6 +602,12 @@ fn decode_arm32_instruction(
         return Ok(DecodedInstruction::Plain(format!("bx {}", format_arm32_register_name(register_index))));
     }
 
+    if instruction_word & 0x0FFF_FFF0 == 0x012F_FF30 {
+        let register_index = (instruction_word & 0xF) as u8;
+
+        return Ok(DecodedInstruction::Plain(format!("blx {}", format_arm32_register_name(register_index))));
+    }
+
     let op27_25 = (instruction_word >> 25) & 0b111;
 
     if op27_25 == 0b101 {
@@ -464,7 +615,11 @@ fn decode_arm32_instruction(
         let target_address = current_instruction_address + 8 + branch_delta_words * 4;
         let mnemonic = if (instruction_word & (1 << 24)) != 0 { "bl" } else { "b" };
 
-        return Ok(DecodedInstruction::Branch { mnemonic, target_address });
+        return Ok(DecodedInstruction::Branch {
+            mnemonic,
+            leading_register: None,
+            target_address,
+        });
     }
 
     if op27_25 == 0b001 {
@@ -533,7 +688,11 @@ fn decode_arm64_instruction(
         let target_address = current_instruction_address + branch_delta_words * 4;
         let mnemonic = if (instruction_word & 0x8000_0000) != 0 { "bl" } else { "b" };
 
-        return Ok(DecodedInstruction::Branch { mnemonic, target_address });
+        return Ok(DecodedInstruction::Branch {
+            mnemonic,
+            leading_register: None,
+            target_address,
+        });
     }
 
     if instruction_word & 0xFFFF_FC1F == 0xD65F_0000 {
@@ -627,7 +786,7 @@ fn decode_arm64_unsigned_immediate_load_store(
     ))
 }
 
-fn parse_arm32_register_operand(
+pub(crate) fn parse_arm32_register_operand(
     operands: &[InstructionOperand],
     operand_index: usize,
 ) -> Result<u8, String> {
@@ -651,10 +810,10 @@ fn parse_arm64_general_register_operand(
     Ok(register)
 }
 
-fn parse_identifier_operand<'a>(
-    operands: &'a [InstructionOperand],
+pub(crate) fn parse_identifier_operand(
+    operands: &[InstructionOperand],
     operand_index: usize,
-) -> Result<&'a str, String> {
+) -> Result<&str, String> {
     match operands.get(operand_index) {
         Some(InstructionOperand::Identifier(identifier)) => Ok(identifier.as_str()),
         Some(unexpected_operand) => Err(format!(
@@ -665,7 +824,7 @@ fn parse_identifier_operand<'a>(
     }
 }
 
-fn parse_non_negative_immediate_operand(
+pub(crate) fn parse_non_negative_immediate_operand(
     operands: &[InstructionOperand],
     operand_index: usize,
     instruction_name: &str,
@@ -684,7 +843,7 @@ fn parse_non_negative_immediate_operand(
     }
 }
 
-fn parse_memory_operand<'a>(
+pub(crate) fn parse_memory_operand<'a>(
     operands: &'a [InstructionOperand],
     operand_index: usize,
     mnemonic: &str,
@@ -699,7 +858,7 @@ fn parse_memory_operand<'a>(
     }
 }
 
-fn resolve_branch_target_address(
+pub(crate) fn resolve_branch_target_address(
     operands: &[InstructionOperand],
     label_addresses: &HashMap<String, i64>,
 ) -> Result<i64, String> {
@@ -732,7 +891,7 @@ fn encode_arm32_immediate(value: u32) -> Option<(u8, u8)> {
     None
 }
 
-fn sign_extend(
+pub(crate) fn sign_extend(
     value: u32,
     bit_width: u32,
 ) -> i64 {
@@ -741,17 +900,20 @@ fn sign_extend(
     ((value as i64) << shift_amount) >> shift_amount
 }
 
-fn format_decoded_instruction_sequence(
-    decoded_instructions: &[DecodedInstruction],
-    total_byte_len: usize,
-) -> String {
+/// Formats decoded instructions as a single-line sequence, replacing branch targets that land on a decoded
+/// instruction with generated labels so the text reassembles to the same bytes.
+pub(crate) fn format_decoded_instruction_sequence(decoded_instructions: &[(i64, DecodedInstruction)]) -> String {
+    let instruction_addresses = decoded_instructions
+        .iter()
+        .map(|(instruction_address, _)| *instruction_address)
+        .collect::<BTreeSet<_>>();
     let mut label_addresses = BTreeSet::new();
 
-    for decoded_instruction in decoded_instructions {
-        if let DecodedInstruction::Branch { target_address, .. } = decoded_instruction {
-            if *target_address >= 0 && (*target_address as usize) < total_byte_len && (*target_address as usize) % 4 == 0 {
-                label_addresses.insert(*target_address as usize);
-            }
+    for (_, decoded_instruction) in decoded_instructions {
+        if let DecodedInstruction::Branch { target_address, .. } = decoded_instruction
+            && instruction_addresses.contains(target_address)
+        {
+            label_addresses.insert(*target_address);
         }
     }
 
@@ -762,21 +924,20 @@ fn format_decoded_instruction_sequence(
         .collect::<BTreeMap<_, _>>();
     let mut instruction_texts = Vec::with_capacity(decoded_instructions.len());
 
-    for (instruction_index, decoded_instruction) in decoded_instructions.iter().enumerate() {
-        let instruction_address = instruction_index * 4;
+    for (instruction_address, decoded_instruction) in decoded_instructions {
         let label_prefix = label_names
-            .get(&instruction_address)
+            .get(instruction_address)
             .map(|label_name| format!("{}: ", label_name))
             .unwrap_or_default();
         let instruction_text = match decoded_instruction {
             DecodedInstruction::Plain(instruction_text) => instruction_text.clone(),
-            DecodedInstruction::Branch { mnemonic, target_address } => {
+            DecodedInstruction::Branch { target_address, .. } => {
                 let target_text = label_names
-                    .get(&(*target_address as usize))
+                    .get(target_address)
                     .cloned()
                     .unwrap_or_else(|| format_signed_hex(*target_address));
 
-                format!("{} {}", mnemonic, target_text)
+                decoded_instruction.format_with_target_text(&target_text)
             }
         };
 
@@ -786,11 +947,11 @@ fn format_decoded_instruction_sequence(
     instruction_texts.join("; ")
 }
 
-fn format_arm_memory_offset_suffix(offset: i64) -> String {
+pub(crate) fn format_arm_memory_offset_suffix(offset: i64) -> String {
     if offset == 0 { String::new() } else { format!(", #{}", offset) }
 }
 
-fn format_signed_hex(value: i64) -> String {
+pub(crate) fn format_signed_hex(value: i64) -> String {
     if value < 0 {
         format!("-0x{:X}", value.unsigned_abs())
     } else {
@@ -1,23 +1,32 @@
 mod arm32_register;
 mod arm64_register;
+mod arm_disassembled_instruction;
+mod arm_instruction_mode;
 mod arm_memory_operand;
 mod constants;
 mod data_types;
 mod instruction_set;
 mod plugin;
+mod thumb_instruction_set;
 
+pub use arm_disassembled_instruction::ArmDisassembledInstruction;
+pub use arm_instruction_mode::{ArmInstructionMode, ArmInstructionModeMap};
 pub use constants::{
     ARM_FAMILY_DATA_TYPE_IDS, ARM_FAMILY_INSTRUCTION_SET_IDS, ARM_FAMILY_PLUGIN_DESCRIPTION, ARM_FAMILY_PLUGIN_DISPLAY_NAME, ARM_FAMILY_PLUGIN_ID,
 };
-pub use data_types::{DataTypeInstructionArm, DataTypeInstructionArm64};
+pub use data_types::{DataTypeInstructionArm, DataTypeInstructionArm64, DataTypeInstructionThumb};
 pub use instruction_set::{Arm32InstructionSet, Arm64InstructionSet};
 pub use plugin::ArmFamilyInstructionsPlugin;
+pub use thumb_instruction_set::ThumbInstructionSet;
 
 #[cfg(test)]
 mod tests {
-    use crate::{ArmFamilyInstructionsPlugin, DataTypeInstructionArm, DataTypeInstructionArm64};
+    use crate::{
+        Arm32InstructionSet, ArmFamilyInstructionsPlugin, ArmInstructionMode, ArmInstructionModeMap, DataTypeInstructionArm, DataTypeInstructionArm64,
+        DataTypeInstructionThumb, ThumbInstructionSet,
+    };
     use squalr_engine_api::{
-        plugins::{Plugin, PluginCapability},
+        plugins::{Plugin, PluginCapability, instruction_set::InstructionSet},
         structures::{
             data_types::data_type::DataType,
             data_values::{
@@ -78,6 +87,133 @@ mod tests {
         assert_eq!(anonymous_value_string.get_anonymous_value_string(), "ldr x0, [x1, #16]; str x0, [x1, #16]");
     }
 
+    #[test]
+    fn i_thumb_data_type_assembles_push_and_pop_sequence() {
+        let data_type = DataTypeInstructionThumb::new();
+        let data_value = data_type
+            .deanonymize_value_string(&AnonymousValueString::new(
+                String::from("push {r4, lr}; movs r0, #5; pop {r4, pc}"),
+                AnonymousValueStringFormat::String,
+                ContainerType::None,
+            ))
+            .expect("Expected Thumb assembly text to assemble.");
+
+        assert_eq!(data_value.get_value_bytes(), &[0x10, 0xB5, 0x05, 0x20, 0x10, 0xBD]);
+    }
+
+    #[test]
+    fn thumb_instruction_set_encodes_mixed_width_label_branches() {
+        let instruction_set = ThumbInstructionSet::new();
+        let instruction_bytes = instruction_set
+            .assemble("bl target; cbz r0, target; movw r1, #0x1234; target: bx lr")
+            .expect("Expected mixed-width Thumb assembly to assemble.");
+
+        assert_eq!(
+            instruction_bytes,
+            vec![
+                0x00, 0xF0, 0x03, 0xF8, 0x08, 0xB1, 0x41, 0xF2, 0x34, 0x21, 0x70, 0x47
+            ]
+        );
+        assert_eq!(
+            instruction_set
+                .disassemble(&instruction_bytes)
+                .expect("Expected Thumb bytes to disassemble."),
+            "bl label_0; cbz r0, label_0; movw r1, #4660; label_0: bx lr"
+        );
+    }
+
+    #[test]
+    fn thumb_instruction_set_round_trips_common_forms() {
+        let instruction_set = ThumbInstructionSet::new();
+        let source_lines = [
+            "lsrs r0, r1, #32",
+            "adds r0, r1, r2",
+            "add r8, r0",
+            "ldr r0, [pc, #16]",
+            "ldrsh r0, [r1, r2]",
+            "ldrh r0, [r1, #62]",
+            "add r0, sp, #1020",
+            "sub sp, #16",
+            "cpsid i",
+            "itete gt",
+            "ldmia r0, {r0, r1}",
+            "stmia r1!, {r2, r3}",
+            "dmb ish",
+            "adds.w r0, r1, #16711935",
+            "mov.w r0, #4278190080",
+            "tst.w r0, r1, lsl #2",
+            "and.w r0, r1, r2, rrx",
+            "subw r0, sp, #12",
+            "ldrsh.w r0, [r1, #2]",
+            "push.w {r4, r5, r6, r7, r8, lr}",
+            "mls r0, r1, r2, r3",
+            "tbh [pc, r1, lsl #1]",
+            "strex r2, r0, [r1]",
+        ];
+
+        for source_line in source_lines {
+            let instruction_bytes = instruction_set
+                .assemble(source_line)
+                .unwrap_or_else(|error| panic!("Expected '{}' to assemble: {}", source_line, error));
+
+            assert_eq!(
+                instruction_set
+                    .disassemble(&instruction_bytes)
+                    .unwrap_or_else(|error| panic!("Expected '{}' to disassemble: {}", source_line, error)),
+                source_line
+            );
+        }
+    }
+
+    #[test]
+    fn thumb_instruction_set_rejects_high_registers_in_narrow_forms() {
+        let instruction_set = ThumbInstructionSet::new();
+
+        assert!(instruction_set.assemble("adds r8, r1, r2").is_err());
+        assert!(instruction_set.assemble("push {r8}").is_err());
+        assert!(instruction_set.assemble("mov.w r0, #0x12345678").is_err());
+    }
+
+    #[test]
+    fn thumb_disassemble_block_annotates_interworking_calls() {
+        let instruction_set = ThumbInstructionSet::new();
+        let disassembled_instructions = instruction_set
+            .disassemble_block(&[0x00, 0xF0, 0x02, 0xE8, 0x18, 0x47, 0xFF, 0xFF], 0x0800_0000)
+            .expect("Expected Thumb block to disassemble.");
+
+        assert_eq!(disassembled_instructions.len(), 3);
+        assert_eq!(disassembled_instructions[0].text, "blx 0x8000008");
+        assert_eq!(disassembled_instructions[0].branch_target_mode, Some(ArmInstructionMode::Arm));
+        assert_eq!(disassembled_instructions[0].get_interworking_annotation(), Some(String::from("→ ARM")));
+        assert_eq!(disassembled_instructions[1].text, "bx r3");
+        assert_eq!(
+            disassembled_instructions[1].get_interworking_annotation(),
+            Some(String::from("→ ARM/Thumb (bit 0)"))
+        );
+        assert_eq!(disassembled_instructions[2].text, ".hword 0xFFFF");
+    }
+
+    #[test]
+    fn arm_instruction_mode_map_follows_call_targets_into_thumb_code() {
+        let mut instruction_mode_map = ArmInstructionModeMap::new();
+        let arm_instructions = Arm32InstructionSet::new()
+            .disassemble_block(&[0x02, 0x00, 0x00, 0xFA], 0x1000)
+            .expect("Expected ARM block to disassemble.");
+
+        instruction_mode_map.record_call_targets(&arm_instructions);
+
+        assert_eq!(instruction_mode_map.resolve_mode(0x1000), ArmInstructionMode::Arm);
+        assert_eq!(instruction_mode_map.resolve_mode(0x1010), ArmInstructionMode::Thumb);
+        assert_eq!(instruction_mode_map.record_interworking_address(0x2001), 0x2000);
+        assert_eq!(
+            instruction_mode_map.resolve_mode_segments(0x0FF0..0x1020),
+            vec![
+                (0x0FF0..0x1010, ArmInstructionMode::Arm),
+                (0x1010..0x1020, ArmInstructionMode::Thumb)
+            ]
+        );
+    }
+
     #[test]
     fn plugin_exposes_data_type_and_instruction_set_capabilities() {
         let plugin = ArmFamilyInstructionsPlugin::new();
@@ -0,0 +1,1995 @@
+use crate::{
+    arm_disassembled_instruction::ArmDisassembledInstruction,
+    arm_instruction_mode::ArmInstructionMode,
+    arm_memory_operand::parse_signed_immediate,
+    arm32_register::{format_arm32_register_name, parse_arm32_register_name},
+    instruction_set::{
+        DecodedInstruction, format_arm_memory_offset_suffix, format_decoded_instruction_sequence, format_signed_hex, parse_arm32_register_operand,
+        parse_identifier_operand, parse_memory_operand, parse_non_negative_immediate_operand, resolve_branch_target_address, sign_extend,
+    },
+};
+use squalr_engine_api::plugins::instruction_set::{InstructionOperand, InstructionSet, ParsedInstruction, parse_instruction_sequence};
+use std::collections::HashMap;
+
+/// Condition code names indexed by their 4-bit encoding. `hs`/`lo` are accepted as aliases of `cs`/`cc`.
+const THUMB_CONDITION_NAMES: [&str; 15] = [
+    "eq", "ne", "cs", "cc", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt", "le", "al",
+];
+const THUMB_NARROW_CONDITIONAL_BRANCH_MNEMONICS: [&str; 14] = [
+    "beq", "bne", "bcs", "bcc", "bmi", "bpl", "bvs", "bvc", "bhi", "bls", "bge", "blt", "bgt", "ble",
+];
+const THUMB_WIDE_CONDITIONAL_BRANCH_MNEMONICS: [&str; 14] = [
+    "beq.w", "bne.w", "bcs.w", "bcc.w", "bmi.w", "bpl.w", "bvs.w", "bvc.w", "bhi.w", "bls.w", "bge.w", "blt.w", "bgt.w", "ble.w",
+];
+/// Two-register data-processing forms, indexed by the 4-bit opcode of `010000 op Rm Rdn`.
+const THUMB_ALU_MNEMONICS: [&str; 16] = [
+    "ands", "eors", "lsls", "lsrs", "asrs", "adcs", "sbcs", "rors", "tst", "negs", "cmp", "cmn", "orrs", "muls", "bics", "mvns",
+];
+/// Register-offset loads and stores, indexed by the 3-bit opcode of `0101 op Rm Rn Rt`.
+const THUMB_REGISTER_OFFSET_LOAD_STORE_MNEMONICS: [&str; 8] = ["str", "strh", "strb", "ldrsb", "ldr", "ldrh", "ldrb", "ldrsh"];
+const THUMB_EXTEND_MNEMONICS: [&str; 4] = ["sxth", "sxtb", "uxth", "uxtb"];
+const THUMB_HINT_MNEMONICS: [&str; 5] = ["nop", "yield", "wfe", "wfi", "sev"];
+/// Thumb-2 data-processing operations shared by the modified-immediate and shifted-register encodings.
+const THUMB2_DATA_PROCESSING_OPERATIONS: [Option<&str>; 16] = [
+    Some("and"),
+    Some("bic"),
+    Some("orr"),
+    Some("orn"),
+    Some("eor"),
+    None,
+    None,
+    None,
+    Some("add"),
+    None,
+    Some("adc"),
+    Some("sbc"),
+    None,
+    Some("sub"),
+    Some("rsb"),
+    None,
+];
+const THUMB2_BARRIER_OPTION_NAMES: [(&str, u16); 8] = [
+    ("sy", 15),
+    ("st", 14),
+    ("ish", 11),
+    ("ishst", 10),
+    ("nsh", 7),
+    ("nshst", 6),
+    ("osh", 3),
+    ("oshst", 2),
+];
+const THUMB_NO_OPERATION_FILL_HALFWORD: u16 = 0x46C0;
+
+#[derive(Clone, Debug, Default)]
+pub struct ThumbInstructionSet;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ThumbEncoding {
+    Narrow(u16),
+    Wide(u16, u16),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ThumbMemoryOffset {
+    Immediate(i64),
+    Register(u8),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ThumbShift {
+    None,
+    Immediate { shift_type: u16, amount: u16 },
+}
+
+impl ThumbInstructionSet {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Decodes a mixed stream of 16-bit and 32-bit Thumb instructions at their absolute addresses. Unsupported
+    /// halfwords are listed as `.hword` directives so a listing can resynchronize on the next halfword.
+    pub fn disassemble_block(
+        &self,
+        instruction_bytes: &[u8],
+        base_address: u64,
+    ) -> Result<Vec<ArmDisassembledInstruction>, String> {
+        let mut disassembled_instructions = Vec::with_capacity(instruction_bytes.len() / 2);
+        let mut byte_offset = 0usize;
+
+        while byte_offset + 2 <= instruction_bytes.len() {
+            let instruction_address = base_address.saturating_add(byte_offset as u64);
+            let first_halfword = read_halfword(instruction_bytes, byte_offset);
+            let second_halfword = (byte_offset + 4 <= instruction_bytes.len()).then(|| read_halfword(instruction_bytes, byte_offset + 2));
+
+            match decode_thumb_instruction(first_halfword, second_halfword, instruction_address as i64) {
+                Ok((decoded_instruction, instruction_length)) => {
+                    disassembled_instructions.push(ArmDisassembledInstruction::from_decoded_instruction(
+                        instruction_address,
+                        instruction_bytes[byte_offset..byte_offset + instruction_length].to_vec(),
+                        &decoded_instruction,
+                        Some(ArmInstructionMode::Thumb),
+                    ));
+                    byte_offset += instruction_length;
+                }
+                Err(_) => {
+                    disassembled_instructions.push(ArmDisassembledInstruction::from_undecodable_bytes(
+                        instruction_address,
+                        instruction_bytes[byte_offset..byte_offset + 2].to_vec(),
+                        format!(".hword 0x{:04X}", first_halfword),
+                    ));
+                    byte_offset += 2;
+                }
+            }
+        }
+
+        Ok(disassembled_instructions)
+    }
+}
+
+impl InstructionSet for ThumbInstructionSet {
+    fn get_instruction_set_id(&self) -> &str {
+        "thumb"
+    }
+
+    fn get_display_name(&self) -> &str {
+        "Thumb"
+    }
+
+    fn assemble(
+        &self,
+        assembly_source: &str,
+    ) -> Result<Vec<u8>, String> {
+        assemble_thumb_instruction_sequence(assembly_source)
+    }
+
+    fn disassemble(
+        &self,
+        instruction_bytes: &[u8],
+    ) -> Result<String, String> {
+        disassemble_thumb_instruction_sequence(instruction_bytes)
+    }
+
+    /// Fills with `mov r8, r8` rather than the Thumb-2 `nop` hint, since only the former is a no-op on ARMv4T cores
+    /// such as the GBA and DS.
+    fn build_no_operation_fill(
+        &self,
+        byte_count: usize,
+    ) -> Result<Vec<u8>, String> {
+        if !byte_count.is_multiple_of(2) {
+            return Err(String::from("Thumb no-operation fill requires a byte count aligned to 2 bytes."));
+        }
+
+        Ok(THUMB_NO_OPERATION_FILL_HALFWORD
+            .to_le_bytes()
+            .repeat(byte_count / 2))
+    }
+}
+
+impl ThumbEncoding {
+    fn len(&self) -> usize {
+        match self {
+            ThumbEncoding::Narrow(_) => 2,
+            ThumbEncoding::Wide(_, _) => 4,
+        }
+    }
+
+    fn append_bytes(
+        &self,
+        instruction_bytes: &mut Vec<u8>,
+    ) {
+        match self {
+            ThumbEncoding::Narrow(halfword) => instruction_bytes.extend_from_slice(&halfword.to_le_bytes()),
+            ThumbEncoding::Wide(first_halfword, second_halfword) => {
+                instruction_bytes.extend_from_slice(&first_halfword.to_le_bytes());
+                instruction_bytes.extend_from_slice(&second_halfword.to_le_bytes());
+            }
+        }
+    }
+}
+
+fn assemble_thumb_instruction_sequence(assembly_source: &str) -> Result<Vec<u8>, String> {
+    let assembly_source = rewrite_register_list_braces(assembly_source);
+    let parsed_instruction_sequence = parse_instruction_sequence(&assembly_source).map_err(|error| error.to_string())?;
+    let parsed_instructions = parsed_instruction_sequence.instructions();
+    let mut instruction_addresses = Vec::with_capacity(parsed_instructions.len() + 1);
+    let mut current_instruction_address = 0_i64;
+
+    // Instruction widths are fixed by syntax (`.w`, `bl`, `movw`, ...), so label addresses resolve in one pass.
+    for parsed_instruction in parsed_instructions {
+        instruction_addresses.push(current_instruction_address);
+        current_instruction_address += thumb_instruction_length(parsed_instruction) as i64;
+    }
+
+    instruction_addresses.push(current_instruction_address);
+
+    let label_addresses = parsed_instruction_sequence
+        .label_instruction_indices()
+        .iter()
+        .map(|(label_name, instruction_index)| (label_name.clone(), instruction_addresses[*instruction_index]))
+        .collect::<HashMap<_, _>>();
+    let mut instruction_bytes = Vec::with_capacity(current_instruction_address as usize);
+
+    for (parsed_instruction, instruction_address) in parsed_instructions.iter().zip(instruction_addresses.iter()) {
+        let thumb_encoding = encode_thumb_instruction(parsed_instruction, *instruction_address, &label_addresses)?;
+
+        debug_assert_eq!(thumb_encoding.len(), thumb_instruction_length(parsed_instruction));
+        thumb_encoding.append_bytes(&mut instruction_bytes);
+    }
+
+    Ok(instruction_bytes)
+}
+
+fn disassemble_thumb_instruction_sequence(instruction_bytes: &[u8]) -> Result<String, String> {
+    if instruction_bytes.is_empty() {
+        return Err(String::from("Instruction byte sequence must not be empty."));
+    }
+
+    if !instruction_bytes.len().is_multiple_of(2) {
+        return Err(format!(
+            "Instruction byte sequence length '{}' must be a multiple of 2.",
+            instruction_bytes.len()
+        ));
+    }
+
+    let mut decoded_instructions = Vec::new();
+    let mut byte_offset = 0usize;
+
+    while byte_offset < instruction_bytes.len() {
+        let first_halfword = read_halfword(instruction_bytes, byte_offset);
+        let second_halfword = (byte_offset + 4 <= instruction_bytes.len()).then(|| read_halfword(instruction_bytes, byte_offset + 2));
+        let (decoded_instruction, instruction_length) = decode_thumb_instruction(first_halfword, second_halfword, byte_offset as i64)?;
+
+        decoded_instructions.push((byte_offset as i64, decoded_instruction));
+        byte_offset += instruction_length;
+    }
+
+    Ok(format_decoded_instruction_sequence(&decoded_instructions))
+}
+
+fn read_halfword(
+    instruction_bytes: &[u8],
+    byte_offset: usize,
+) -> u16 {
+    u16::from_le_bytes([
+        instruction_bytes[byte_offset],
+        instruction_bytes[byte_offset + 1],
+    ])
+}
+
+/// The shared parser reserves braces for x86 decorators, so register lists are rewritten as bracketed operands.
+/// Register lists only appear in `push`/`pop`/`ldm`/`stm` positions where no memory operand is valid.
+fn rewrite_register_list_braces(assembly_source: &str) -> String {
+    assembly_source.replace('{', "[").replace('}', "]")
+}
+
+fn is_wide_prefix(first_halfword: u16) -> bool {
+    (first_halfword >> 11) >= 0b11101
+}
+
+fn thumb_instruction_length(parsed_instruction: &ParsedInstruction) -> usize {
+    let mnemonic = parsed_instruction.mnemonic();
+    let is_wide = mnemonic.ends_with(".w")
+        || match mnemonic {
+            "bl" | "movw" | "movt" | "addw" | "subw" | "mul" | "mla" | "mls" | "sdiv" | "udiv" | "tbb" | "tbh" | "ldrex" | "strex" | "dmb" | "dsb" | "isb" => {
+                true
+            }
+            "blx" => !matches!(
+                parsed_instruction.operands(),
+                [InstructionOperand::Identifier(register_name)] if parse_arm32_register_name(register_name).is_some()
+            ),
+            _ => false,
+        };
+
+    if is_wide { 4 } else { 2 }
+}
+
+fn encode_thumb_instruction(
+    parsed_instruction: &ParsedInstruction,
+    current_instruction_address: i64,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<ThumbEncoding, String> {
+    let mnemonic = parsed_instruction.mnemonic();
+    let operands = parsed_instruction.operands();
+
+    if let Some((condition_code, is_wide)) = parse_branch_mnemonic(mnemonic) {
+        return encode_thumb_branch(operands, condition_code, is_wide, current_instruction_address, label_addresses);
+    }
+
+    if let Some(if_then_pattern) = mnemonic.strip_prefix("it")
+        && if_then_pattern.len() <= 3
+        && if_then_pattern
+            .chars()
+            .all(|pattern_character| matches!(pattern_character, 't' | 'e'))
+    {
+        return encode_thumb_if_then(if_then_pattern, operands).map(ThumbEncoding::Narrow);
+    }
+
+    if let Some(wide_mnemonic) = mnemonic.strip_suffix(".w") {
+        return encode_thumb2_suffixed_instruction(wide_mnemonic, operands);
+    }
+
+    match mnemonic {
+        ".hword" => {
+            expect_operand_count(operands, 1, mnemonic)?;
+            let halfword = parse_bounded_immediate(operands, 0, mnemonic, 0xFFFF)?;
+
+            Ok(ThumbEncoding::Narrow(halfword as u16))
+        }
+        "nop" | "yield" | "wfe" | "wfi" | "sev" => {
+            expect_operand_count(operands, 0, mnemonic)?;
+            let hint_index = THUMB_HINT_MNEMONICS
+                .iter()
+                .position(|hint_mnemonic| *hint_mnemonic == mnemonic)
+                .unwrap_or_default();
+
+            Ok(ThumbEncoding::Narrow(0xBF00 | ((hint_index as u16) << 4)))
+        }
+        "bx" | "blx"
+            if operands.len() == 1
+                && matches!(&operands[0], InstructionOperand::Identifier(register_name) if parse_arm32_register_name(register_name).is_some()) =>
+        {
+            let register_index = parse_arm32_register_operand(operands, 0)? as u16;
+            let link_bit = if mnemonic == "blx" { 0x80 } else { 0 };
+
+            Ok(ThumbEncoding::Narrow(0x4700 | link_bit | (register_index << 3)))
+        }
+        "bl" | "blx" => encode_thumb2_branch_with_link(mnemonic, operands, current_instruction_address, label_addresses),
+        "cbz" | "cbnz" => encode_thumb_compare_and_branch(mnemonic, operands, current_instruction_address, label_addresses).map(ThumbEncoding::Narrow),
+        "movw" | "movt" | "addw" | "subw" => encode_thumb2_plain_immediate(mnemonic, operands),
+        "mul" | "mla" | "mls" | "sdiv" | "udiv" => encode_thumb2_multiply_divide(mnemonic, operands),
+        "tbb" | "tbh" => encode_thumb2_table_branch(mnemonic, operands),
+        "ldrex" | "strex" => encode_thumb2_exclusive(mnemonic, operands),
+        "dmb" | "dsb" | "isb" => encode_thumb2_barrier(mnemonic, operands),
+        _ => encode_thumb_narrow_instruction(mnemonic, operands).map(ThumbEncoding::Narrow),
+    }
+}
+
+fn encode_thumb_narrow_instruction(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<u16, String> {
+    match mnemonic {
+        "lsls" | "lsrs" | "asrs" if operands.len() == 3 => encode_thumb_shift_immediate(mnemonic, operands),
+        "movs" => {
+            expect_operand_count(operands, 2, mnemonic)?;
+            let destination_register_index = parse_low_register_operand(operands, 0)?;
+
+            if let InstructionOperand::Immediate(_) = &operands[1] {
+                let immediate_value = parse_bounded_immediate(operands, 1, mnemonic, 0xFF)?;
+
+                return Ok(0x2000 | (destination_register_index << 8) | immediate_value as u16);
+            }
+
+            Ok((parse_low_register_operand(operands, 1)? << 3) | destination_register_index)
+        }
+        "mov" => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            encode_thumb_high_register_operation(0x4600, operands)
+        }
+        "adds" | "subs" => encode_thumb_add_subtract(mnemonic, operands),
+        "add" => encode_thumb_add(operands),
+        "sub" => {
+            let immediate_operand_index = resolve_stack_pointer_adjust_operand_index(operands, mnemonic)?;
+            let scaled_immediate = parse_scaled_immediate(operands, immediate_operand_index, mnemonic, 4, 508)?;
+
+            Ok(0xB080 | scaled_immediate)
+        }
+        "cmp" => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            if let InstructionOperand::Immediate(_) = &operands[1] {
+                let register_index = parse_low_register_operand(operands, 0)?;
+                let immediate_value = parse_bounded_immediate(operands, 1, mnemonic, 0xFF)?;
+
+                return Ok(0x2800 | (register_index << 8) | immediate_value as u16);
+            }
+
+            let left_register_index = parse_arm32_register_operand(operands, 0)? as u16;
+            let right_register_index = parse_arm32_register_operand(operands, 1)? as u16;
+
+            if left_register_index < 8 && right_register_index < 8 {
+                Ok(0x4280 | (right_register_index << 3) | left_register_index)
+            } else {
+                encode_thumb_high_register_operation(0x4500, operands)
+            }
+        }
+        "muls" => {
+            if operands.len() == 3 && parse_low_register_operand(operands, 2)? != parse_low_register_operand(operands, 0)? {
+                return Err(String::from("Thumb muls requires the destination and final source register to match."));
+            }
+
+            if !matches!(operands.len(), 2 | 3) {
+                return Err(String::from("Thumb muls requires two or three register operands."));
+            }
+
+            encode_thumb_alu_operation(mnemonic, &operands[..2])
+        }
+        "rsbs" => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            if parse_bounded_immediate(operands, 2, mnemonic, 0)? != 0 {
+                return Err(String::from("Thumb rsbs only supports a zero immediate."));
+            }
+
+            encode_thumb_alu_operation("negs", &operands[..2])
+        }
+        _ if THUMB_ALU_MNEMONICS.contains(&mnemonic) => encode_thumb_alu_operation(mnemonic, operands),
+        "ldr" | "str" | "ldrb" | "strb" | "ldrh" | "strh" | "ldrsb" | "ldrsh" => encode_thumb_load_store(mnemonic, operands),
+        "sxth" | "sxtb" | "uxth" | "uxtb" | "rev" | "rev16" | "revsh" => {
+            expect_operand_count(operands, 2, mnemonic)?;
+            let opcode_base = match mnemonic {
+                "sxth" => 0xB200,
+                "sxtb" => 0xB240,
+                "uxth" => 0xB280,
+                "uxtb" => 0xB2C0,
+                "rev" => 0xBA00,
+                "rev16" => 0xBA40,
+                _ => 0xBAC0,
+            };
+
+            Ok(opcode_base | (parse_low_register_operand(operands, 1)? << 3) | parse_low_register_operand(operands, 0)?)
+        }
+        "push" | "pop" => {
+            expect_operand_count(operands, 1, mnemonic)?;
+            let register_list = parse_register_list_operand(operands, 0, mnemonic)?;
+            let (opcode_base, extra_register_index) = if mnemonic == "push" { (0xB400, 14) } else { (0xBC00, 15) };
+
+            if register_list & !(0x00FF | (1 << extra_register_index)) != 0 {
+                return Err(format!(
+                    "Thumb {} only supports r0-r7 and {}; use {}.w for other registers.",
+                    mnemonic,
+                    format_arm32_register_name(extra_register_index),
+                    mnemonic
+                ));
+            }
+
+            let extra_register_bit = if register_list & (1 << extra_register_index) != 0 { 0x100 } else { 0 };
+
+            Ok(opcode_base | extra_register_bit | (register_list & 0xFF))
+        }
+        "ldmia" | "ldm" | "ldmfd" | "stmia" | "stm" | "stmea" => encode_thumb_load_store_multiple(mnemonic, operands),
+        "cpsie" | "cpsid" => {
+            expect_operand_count(operands, 1, mnemonic)?;
+            let interrupt_flags_text = parse_identifier_operand(operands, 0)?;
+            let mut interrupt_flags = 0_u16;
+
+            for (flag_name, flag_bit) in [('a', 4_u16), ('i', 2), ('f', 1)] {
+                if interrupt_flags_text.contains(flag_name) {
+                    interrupt_flags |= flag_bit;
+                }
+            }
+
+            if interrupt_flags == 0
+                || interrupt_flags_text
+                    .chars()
+                    .any(|flag_name| !matches!(flag_name, 'a' | 'i' | 'f'))
+            {
+                return Err(format!("Unsupported {} interrupt flags '{}'.", mnemonic, interrupt_flags_text));
+            }
+
+            let disable_bit = if mnemonic == "cpsid" { 0x10 } else { 0 };
+
+            Ok(0xB660 | disable_bit | interrupt_flags)
+        }
+        "bkpt" | "svc" | "swi" | "udf" => {
+            expect_operand_count(operands, 1, mnemonic)?;
+            let immediate_value = parse_bounded_immediate(operands, 0, mnemonic, 0xFF)? as u16;
+            let opcode_base = match mnemonic {
+                "bkpt" => 0xBE00,
+                "udf" => 0xDE00,
+                _ => 0xDF00,
+            };
+
+            Ok(opcode_base | immediate_value)
+        }
+        unsupported_mnemonic => Err(format!("Unsupported Thumb mnemonic '{}'.", unsupported_mnemonic)),
+    }
+}
+
+fn encode_thumb_shift_immediate(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<u16, String> {
+    let destination_register_index = parse_low_register_operand(operands, 0)?;
+    let source_register_index = parse_low_register_operand(operands, 1)?;
+    let (opcode, shift_amount) = match mnemonic {
+        "lsls" => (0_u16, parse_bounded_immediate(operands, 2, mnemonic, 31)? as u16),
+        shift_mnemonic => {
+            let shift_amount = parse_bounded_immediate(operands, 2, shift_mnemonic, 32)?;
+
+            if shift_amount == 0 {
+                return Err(format!("Thumb {} shift amount must be between 1 and 32.", shift_mnemonic));
+            }
+
+            let opcode = if shift_mnemonic == "lsrs" { 1 } else { 2 };
+
+            (opcode, (shift_amount % 32) as u16)
+        }
+    };
+
+    Ok((opcode << 11) | (shift_amount << 6) | (source_register_index << 3) | destination_register_index)
+}
+
+fn encode_thumb_add_subtract(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<u16, String> {
+    let is_subtract = mnemonic == "subs";
+    let destination_register_index = parse_low_register_operand(operands, 0)?;
+
+    match operands.len() {
+        2 => {
+            let immediate_value = parse_bounded_immediate(operands, 1, mnemonic, 0xFF)? as u16;
+            let opcode_base = if is_subtract { 0x3800 } else { 0x3000 };
+
+            Ok(opcode_base | (destination_register_index << 8) | immediate_value)
+        }
+        3 => {
+            let source_register_index = parse_low_register_operand(operands, 1)?;
+            let (opcode_base, third_operand_value) = match &operands[2] {
+                InstructionOperand::Immediate(_) => (
+                    if is_subtract { 0x1E00 } else { 0x1C00 },
+                    parse_bounded_immediate(operands, 2, mnemonic, 7)? as u16,
+                ),
+                _ => (if is_subtract { 0x1A00 } else { 0x1800 }, parse_low_register_operand(operands, 2)?),
+            };
+
+            Ok(opcode_base | (third_operand_value << 6) | (source_register_index << 3) | destination_register_index)
+        }
+        _ => Err(format!("Thumb {} requires two or three operands.", mnemonic)),
+    }
+}
+
+/// Encodes the non-flag-setting `add` forms: high-register adds, and adds relative to SP or PC.
+fn encode_thumb_add(operands: &[InstructionOperand]) -> Result<u16, String> {
+    let destination_register_index = parse_arm32_register_operand(operands, 0)? as u16;
+
+    if destination_register_index == 13
+        && operands
+            .last()
+            .is_some_and(|operand| matches!(operand, InstructionOperand::Immediate(_)))
+    {
+        let immediate_operand_index = resolve_stack_pointer_adjust_operand_index(operands, "add")?;
+
+        return Ok(0xB000 | parse_scaled_immediate(operands, immediate_operand_index, "add", 4, 508)?);
+    }
+
+    match operands.len() {
+        2 => encode_thumb_high_register_operation(0x4400, operands),
+        3 => {
+            let base_register_index = parse_arm32_register_operand(operands, 1)?;
+            let opcode_base = match base_register_index {
+                15 => 0xA000,
+                13 => 0xA800,
+                _ => {
+                    return Err(String::from(
+                        "Thumb add with an immediate requires an SP or PC base register; use adds or add.w.",
+                    ));
+                }
+            };
+
+            if destination_register_index > 7 {
+                return Err(String::from("Thumb add relative to SP or PC requires a low destination register."));
+            }
+
+            Ok(opcode_base | (destination_register_index << 8) | parse_scaled_immediate(operands, 2, "add", 4, 1020)?)
+        }
+        _ => Err(String::from("Thumb add requires two or three operands.")),
+    }
+}
+
+/// Resolves the immediate operand of `add sp, #imm` / `sub sp, sp, #imm`.
+fn resolve_stack_pointer_adjust_operand_index(
+    operands: &[InstructionOperand],
+    mnemonic: &str,
+) -> Result<usize, String> {
+    let register_operand_count = operands.len().saturating_sub(1);
+
+    if !matches!(register_operand_count, 1 | 2) {
+        return Err(format!("Thumb {} sp requires an SP operand and an immediate.", mnemonic));
+    }
+
+    for register_operand_index in 0..register_operand_count {
+        if parse_arm32_register_operand(operands, register_operand_index)? != 13 {
+            return Err(format!(
+                "Thumb {} with an immediate only supports SP in this form; use {}s or {}.w.",
+                mnemonic, mnemonic, mnemonic
+            ));
+        }
+    }
+
+    Ok(register_operand_count)
+}
+
+fn encode_thumb_high_register_operation(
+    opcode_base: u16,
+    operands: &[InstructionOperand],
+) -> Result<u16, String> {
+    let destination_register_index = parse_arm32_register_operand(operands, 0)? as u16;
+    let source_register_index = parse_arm32_register_operand(operands, 1)? as u16;
+
+    Ok(opcode_base | ((destination_register_index & 0x8) << 4) | (source_register_index << 3) | (destination_register_index & 0x7))
+}
+
+fn encode_thumb_alu_operation(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<u16, String> {
+    expect_operand_count(operands, 2, mnemonic)?;
+
+    let opcode = THUMB_ALU_MNEMONICS
+        .iter()
+        .position(|alu_mnemonic| *alu_mnemonic == mnemonic)
+        .ok_or_else(|| format!("Unsupported Thumb mnemonic '{}'.", mnemonic))? as u16;
+
+    Ok(0x4000 | (opcode << 6) | (parse_low_register_operand(operands, 1)? << 3) | parse_low_register_operand(operands, 0)?)
+}
+
+fn encode_thumb_load_store(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<u16, String> {
+    expect_operand_count(operands, 2, mnemonic)?;
+
+    let data_register_index = parse_low_register_operand(operands, 0)?;
+    let memory_operand = parse_memory_operand(operands, 1, mnemonic)?;
+    let (base_register_index, memory_offset) = parse_thumb_memory_expression(memory_operand.expression_text())?;
+
+    if let ThumbMemoryOffset::Register(offset_register_index) = memory_offset {
+        let opcode = THUMB_REGISTER_OFFSET_LOAD_STORE_MNEMONICS
+            .iter()
+            .position(|load_store_mnemonic| *load_store_mnemonic == mnemonic)
+            .unwrap_or_default() as u16;
+
+        if base_register_index > 7 || offset_register_index > 7 {
+            return Err(format!("Thumb {} with a register offset requires low registers.", mnemonic));
+        }
+
+        return Ok(0x5000 | (opcode << 9) | ((offset_register_index as u16) << 6) | ((base_register_index as u16) << 3) | data_register_index);
+    }
+
+    let ThumbMemoryOffset::Immediate(offset) = memory_offset else {
+        unreachable!();
+    };
+
+    match (mnemonic, base_register_index) {
+        ("ldr", 15) => Ok(0x4800 | (data_register_index << 8) | scale_offset(offset, mnemonic, 4, 1020)?),
+        ("ldr", 13) | ("str", 13) => {
+            let opcode_base = if mnemonic == "ldr" { 0x9800 } else { 0x9000 };
+
+            Ok(opcode_base | (data_register_index << 8) | scale_offset(offset, mnemonic, 4, 1020)?)
+        }
+        (_, 0..=7) => {
+            let (opcode_base, scale) = match mnemonic {
+                "str" => (0x6000, 4),
+                "ldr" => (0x6800, 4),
+                "strb" => (0x7000, 1),
+                "ldrb" => (0x7800, 1),
+                "strh" => (0x8000, 2),
+                "ldrh" => (0x8800, 2),
+                _ => {
+                    return Err(format!(
+                        "Thumb {} only supports register offsets; use {}.w for immediate offsets.",
+                        mnemonic, mnemonic
+                    ));
+                }
+            };
+            let scaled_offset = scale_offset(offset, mnemonic, scale, 31 * scale)?;
+
+            Ok(opcode_base | (scaled_offset << 6) | ((base_register_index as u16) << 3) | data_register_index)
+        }
+        _ => Err(format!(
+            "Thumb {} does not support base register '{}'; use {}.w.",
+            mnemonic,
+            format_arm32_register_name(base_register_index),
+            mnemonic
+        )),
+    }
+}
+
+fn encode_thumb_load_store_multiple(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<u16, String> {
+    expect_operand_count(operands, 2, mnemonic)?;
+
+    let is_load = mnemonic.starts_with("ldm");
+    let (base_register_index, has_write_back) = parse_write_back_register_operand(operands, 0)?;
+    let register_list = parse_register_list_operand(operands, 1, mnemonic)?;
+
+    if base_register_index > 7 || register_list > 0xFF {
+        return Err(format!("Thumb {} only supports low registers.", mnemonic));
+    }
+
+    let requires_write_back = !is_load || register_list & (1 << base_register_index) == 0;
+
+    if has_write_back != requires_write_back {
+        return Err(format!(
+            "Thumb {} {} base register write-back ('!') for this register list.",
+            mnemonic,
+            if requires_write_back { "requires" } else { "does not support" }
+        ));
+    }
+
+    let opcode_base = if is_load { 0xC800 } else { 0xC000 };
+
+    Ok(opcode_base | ((base_register_index as u16) << 8) | register_list)
+}
+
+fn encode_thumb_if_then(
+    if_then_pattern: &str,
+    operands: &[InstructionOperand],
+) -> Result<u16, String> {
+    expect_operand_count(operands, 1, "it")?;
+
+    let condition_code = parse_condition_operand(operands, 0)?;
+
+    if condition_code == 14 && if_then_pattern.contains('e') {
+        return Err(String::from("IT blocks with the 'al' condition cannot contain else slots."));
+    }
+
+    let mut mask = 0_u16;
+
+    for (slot_index, slot_character) in if_then_pattern.chars().enumerate() {
+        let condition_bit = if slot_character == 't' { condition_code & 1 } else { !condition_code & 1 };
+
+        mask |= condition_bit << (3 - slot_index);
+    }
+
+    mask |= 1 << (3 - if_then_pattern.len());
+
+    Ok(0xBF00 | (condition_code << 4) | mask)
+}
+
+fn encode_thumb_compare_and_branch(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+    current_instruction_address: i64,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<u16, String> {
+    expect_operand_count(operands, 2, mnemonic)?;
+
+    let register_index = parse_low_register_operand(operands, 0)?;
+    let target_address = resolve_branch_target_address(&operands[1..], label_addresses)?;
+    let branch_offset = target_address - (current_instruction_address + 4);
+
+    if branch_offset % 2 != 0 || !(0..=126).contains(&branch_offset) {
+        return Err(format!(
+            "Thumb {} target '{}' must be a forward halfword-aligned offset of at most 126 bytes.",
+            mnemonic,
+            format_signed_hex(target_address)
+        ));
+    }
+
+    let opcode_base = if mnemonic == "cbnz" { 0xB900 } else { 0xB100 };
+    let branch_offset = branch_offset as u16;
+
+    Ok(opcode_base | ((branch_offset >> 6) << 9) | (((branch_offset >> 1) & 0x1F) << 3) | register_index)
+}
+
+fn encode_thumb_branch(
+    operands: &[InstructionOperand],
+    condition_code: Option<u16>,
+    is_wide: bool,
+    current_instruction_address: i64,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<ThumbEncoding, String> {
+    let target_address = resolve_branch_target_address(operands, label_addresses)?;
+    let branch_offset = target_address - (current_instruction_address + 4);
+
+    if branch_offset % 2 != 0 {
+        return Err(format!("Thumb branch target '{}' must be 2-byte aligned.", format_signed_hex(target_address)));
+    }
+
+    let (minimum_offset, maximum_offset, offset_bit_width) = match (condition_code.is_some(), is_wide) {
+        (true, false) => (-0x100, 0xFE, 9),
+        (false, false) => (-0x800, 0x7FE, 12),
+        (true, true) => (-0x10_0000, 0xF_FFFE, 21),
+        (false, true) => (-0x100_0000, 0xFF_FFFE, 25),
+    };
+
+    if !(minimum_offset..=maximum_offset).contains(&branch_offset) {
+        return Err(format!(
+            "Thumb branch target '{}' is out of range for a {}-bit branch offset.",
+            format_signed_hex(target_address),
+            offset_bit_width
+        ));
+    }
+
+    let branch_offset = branch_offset as i32 as u32;
+
+    Ok(match (condition_code, is_wide) {
+        (Some(condition_code), false) => ThumbEncoding::Narrow(0xD000 | (condition_code << 8) | ((branch_offset >> 1) & 0xFF) as u16),
+        (None, false) => ThumbEncoding::Narrow(0xE000 | ((branch_offset >> 1) & 0x7FF) as u16),
+        (Some(condition_code), true) => {
+            let sign_bit = ((branch_offset >> 20) & 1) as u16;
+            let jump_bit_2 = ((branch_offset >> 19) & 1) as u16;
+            let jump_bit_1 = ((branch_offset >> 18) & 1) as u16;
+
+            ThumbEncoding::Wide(
+                0xF000 | (sign_bit << 10) | (condition_code << 6) | ((branch_offset >> 12) & 0x3F) as u16,
+                0x8000 | (jump_bit_1 << 13) | (jump_bit_2 << 11) | ((branch_offset >> 1) & 0x7FF) as u16,
+            )
+        }
+        (None, true) => {
+            let (first_halfword, second_halfword) = encode_thumb2_branch_offset(branch_offset);
+
+            ThumbEncoding::Wide(first_halfword, 0x9000 | second_halfword)
+        }
+    })
+}
+
+/// Encodes `bl` and immediate `blx`. The 32-bit `bl` is backward compatible with the ARMv4T two-halfword pair
+/// for offsets within +/-4MB, so the same encoding serves GBA/DS code and Thumb-2 code.
+fn encode_thumb2_branch_with_link(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+    current_instruction_address: i64,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<ThumbEncoding, String> {
+    let target_address = resolve_branch_target_address(operands, label_addresses)?;
+    let is_exchange = mnemonic == "blx";
+    let branch_base_address = if is_exchange {
+        (current_instruction_address + 4) & !3
+    } else {
+        current_instruction_address + 4
+    };
+    let branch_offset = target_address - branch_base_address;
+    let required_alignment = if is_exchange { 4 } else { 2 };
+
+    if branch_offset % required_alignment != 0 {
+        return Err(format!(
+            "Thumb {} target '{}' must be {}-byte aligned.",
+            mnemonic,
+            format_signed_hex(target_address),
+            required_alignment
+        ));
+    }
+
+    if !(-0x100_0000..=0xFF_FFFE).contains(&branch_offset) {
+        return Err(format!(
+            "Thumb {} target '{}' is out of range for a 25-bit branch offset.",
+            mnemonic,
+            format_signed_hex(target_address)
+        ));
+    }
+
+    let (first_halfword, second_halfword) = encode_thumb2_branch_offset(branch_offset as i32 as u32);
+
+    Ok(if is_exchange {
+        ThumbEncoding::Wide(first_halfword, 0xC000 | second_halfword)
+    } else {
+        ThumbEncoding::Wide(first_halfword, 0xD000 | second_halfword)
+    })
+}
+
+/// Splits a 25-bit branch offset into the `S:imm10` and `J1:J2:imm11` fields shared by `b.w`, `bl`, and `blx`.
+fn encode_thumb2_branch_offset(branch_offset: u32) -> (u16, u16) {
+    let sign_bit = ((branch_offset >> 24) & 1) as u16;
+    let intermediate_bit_1 = ((branch_offset >> 23) & 1) as u16;
+    let intermediate_bit_2 = ((branch_offset >> 22) & 1) as u16;
+    let jump_bit_1 = !(intermediate_bit_1 ^ sign_bit) & 1;
+    let jump_bit_2 = !(intermediate_bit_2 ^ sign_bit) & 1;
+
+    (
+        0xF000 | (sign_bit << 10) | ((branch_offset >> 12) & 0x3FF) as u16,
+        (jump_bit_1 << 13) | (jump_bit_2 << 11) | ((branch_offset >> 1) & 0x7FF) as u16,
+    )
+}
+
+fn decode_thumb2_branch_offset(
+    first_halfword: u16,
+    second_halfword: u16,
+) -> i64 {
+    let sign_bit = ((first_halfword >> 10) & 1) as u32;
+    let jump_bit_1 = ((second_halfword >> 13) & 1) as u32;
+    let jump_bit_2 = ((second_halfword >> 11) & 1) as u32;
+    let intermediate_bit_1 = !(jump_bit_1 ^ sign_bit) & 1;
+    let intermediate_bit_2 = !(jump_bit_2 ^ sign_bit) & 1;
+    let branch_offset = (sign_bit << 24)
+        | (intermediate_bit_1 << 23)
+        | (intermediate_bit_2 << 22)
+        | (((first_halfword & 0x3FF) as u32) << 12)
+        | (((second_halfword & 0x7FF) as u32) << 1);
+
+    sign_extend(branch_offset, 25)
+}
+
+fn encode_thumb2_suffixed_instruction(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<ThumbEncoding, String> {
+    match mnemonic {
+        "ldr" | "str" | "ldrb" | "strb" | "ldrh" | "strh" | "ldrsb" | "ldrsh" => encode_thumb2_load_store(mnemonic, operands),
+        "push" | "pop" => {
+            expect_operand_count(operands, 1, mnemonic)?;
+            let register_list = parse_register_list_operand(operands, 0, mnemonic)?;
+            let disallowed_registers = if mnemonic == "push" { 0xA000 } else { 0x2000 };
+
+            if register_list & disallowed_registers != 0 || register_list & 0xC000 == 0xC000 || register_list.count_ones() < 2 {
+                return Err(format!(
+                    "Thumb {}.w requires at least two registers and cannot include {}.",
+                    mnemonic,
+                    if mnemonic == "push" { "sp or pc" } else { "sp, or both lr and pc" }
+                ));
+            }
+
+            let first_halfword = if mnemonic == "push" { 0xE92D } else { 0xE8BD };
+
+            Ok(ThumbEncoding::Wide(first_halfword, register_list))
+        }
+        _ => encode_thumb2_data_processing(mnemonic, operands),
+    }
+}
+
+fn encode_thumb2_load_store(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<ThumbEncoding, String> {
+    expect_operand_count(operands, 2, mnemonic)?;
+
+    let data_register_index = parse_arm32_register_operand(operands, 0)? as u16;
+    let memory_operand = parse_memory_operand(operands, 1, mnemonic)?;
+    let (base_register_index, memory_offset) = parse_thumb_memory_expression(memory_operand.expression_text())?;
+    let ThumbMemoryOffset::Immediate(offset) = memory_offset else {
+        return Err(format!("Thumb {}.w only supports immediate offsets in this plugin.", mnemonic));
+    };
+
+    if base_register_index == 15 || !(0..=0xFFF).contains(&offset) {
+        return Err(format!(
+            "Thumb {}.w requires a non-PC base register and an offset between 0 and 4095.",
+            mnemonic
+        ));
+    }
+
+    let first_halfword_base = match mnemonic {
+        "strb" => 0xF880,
+        "ldrb" => 0xF890,
+        "strh" => 0xF8A0,
+        "ldrh" => 0xF8B0,
+        "str" => 0xF8C0,
+        "ldr" => 0xF8D0,
+        "ldrsb" => 0xF990,
+        _ => 0xF9B0,
+    };
+
+    Ok(ThumbEncoding::Wide(
+        first_halfword_base | base_register_index as u16,
+        (data_register_index << 12) | offset as u16,
+    ))
+}
+
+/// Encodes Thumb-2 data-processing instructions in their modified-immediate or shifted-register forms, including
+/// the `mov`/`mvn` (no first operand) and `tst`/`teq`/`cmn`/`cmp` (no destination) aliases.
+fn encode_thumb2_data_processing(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<ThumbEncoding, String> {
+    let (operation_name, sets_flags, has_destination, has_first_operand) = match mnemonic {
+        "tst" => ("and", true, false, true),
+        "teq" => ("eor", true, false, true),
+        "cmn" => ("add", true, false, true),
+        "cmp" => ("sub", true, false, true),
+        "mov" | "movs" => ("orr", mnemonic == "movs", true, false),
+        "mvn" | "mvns" => ("orn", mnemonic == "mvns", true, false),
+        _ => match mnemonic.strip_suffix('s') {
+            Some(operation_name) if THUMB2_DATA_PROCESSING_OPERATIONS.contains(&Some(operation_name)) => (operation_name, true, true, true),
+            _ => (mnemonic, false, true, true),
+        },
+    };
+    let operation = THUMB2_DATA_PROCESSING_OPERATIONS
+        .iter()
+        .position(|known_operation| *known_operation == Some(operation_name))
+        .ok_or_else(|| format!("Unsupported Thumb mnemonic '{}.w'.", mnemonic))? as u16;
+    let register_operand_count = usize::from(has_destination) + usize::from(has_first_operand);
+    let second_operand_index = register_operand_count;
+
+    if operands.len() != register_operand_count + 1 && operands.len() != register_operand_count + 2 {
+        return Err(format!("Thumb {}.w has an unexpected number of operands.", mnemonic));
+    }
+
+    let destination_register_index = if has_destination {
+        parse_arm32_register_operand(operands, 0)? as u16
+    } else {
+        15
+    };
+    let first_register_index = if has_first_operand {
+        parse_arm32_register_operand(operands, usize::from(has_destination))? as u16
+    } else {
+        15
+    };
+    let flags_bit = if sets_flags { 0x10 } else { 0 };
+
+    if let InstructionOperand::Immediate(_) = &operands[second_operand_index] {
+        if operands.len() != second_operand_index + 1 {
+            return Err(format!("Thumb {}.w does not support a shift with an immediate operand.", mnemonic));
+        }
+
+        let immediate_value = parse_bounded_immediate(operands, second_operand_index, mnemonic, u32::MAX as i128)? as u32;
+        let encoded_immediate = encode_thumb2_modified_immediate(immediate_value).ok_or_else(|| {
+            format!(
+                "Thumb {}.w immediate '{}' cannot be encoded as a modified immediate.",
+                mnemonic, immediate_value
+            )
+        })?;
+
+        return Ok(ThumbEncoding::Wide(
+            0xF000 | ((encoded_immediate >> 11) << 10) | (operation << 5) | flags_bit | first_register_index,
+            (((encoded_immediate >> 8) & 0x7) << 12) | (destination_register_index << 8) | (encoded_immediate & 0xFF),
+        ));
+    }
+
+    let second_register_index = parse_arm32_register_operand(operands, second_operand_index)? as u16;
+    let shift = match operands.get(second_operand_index + 1) {
+        Some(_) => parse_shift_operand(operands, second_operand_index + 1)?,
+        None => ThumbShift::None,
+    };
+    let (shift_type, shift_amount) = match shift {
+        ThumbShift::None => (0, 0),
+        ThumbShift::Immediate { shift_type, amount } => (shift_type, amount),
+    };
+
+    Ok(ThumbEncoding::Wide(
+        0xEA00 | (operation << 5) | flags_bit | first_register_index,
+        ((shift_amount >> 2) << 12) | (destination_register_index << 8) | ((shift_amount & 0x3) << 6) | (shift_type << 4) | second_register_index,
+    ))
+}
+
+fn encode_thumb2_plain_immediate(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<ThumbEncoding, String> {
+    let destination_register_index = parse_arm32_register_operand(operands, 0)? as u16;
+    let (first_halfword_base, source_register_index, immediate_value) = match mnemonic {
+        "movw" | "movt" => {
+            expect_operand_count(operands, 2, mnemonic)?;
+            let immediate_value = parse_bounded_immediate(operands, 1, mnemonic, 0xFFFF)? as u16;
+            let first_halfword_base = if mnemonic == "movw" { 0xF240 } else { 0xF2C0 };
+
+            (first_halfword_base | (immediate_value >> 12), 0, immediate_value & 0xFFF)
+        }
+        _ => {
+            expect_operand_count(operands, 3, mnemonic)?;
+            let source_register_index = parse_arm32_register_operand(operands, 1)? as u16;
+            let immediate_value = parse_bounded_immediate(operands, 2, mnemonic, 0xFFF)? as u16;
+            let first_halfword_base = if mnemonic == "addw" { 0xF200 } else { 0xF2A0 };
+
+            (first_halfword_base, source_register_index, immediate_value)
+        }
+    };
+
+    Ok(ThumbEncoding::Wide(
+        first_halfword_base | ((immediate_value >> 11) << 10) | source_register_index,
+        (((immediate_value >> 8) & 0x7) << 12) | (destination_register_index << 8) | (immediate_value & 0xFF),
+    ))
+}
+
+fn encode_thumb2_multiply_divide(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<ThumbEncoding, String> {
+    let expected_operand_count = if matches!(mnemonic, "mla" | "mls") { 4 } else { 3 };
+
+    expect_operand_count(operands, expected_operand_count, mnemonic)?;
+
+    let destination_register_index = parse_arm32_register_operand(operands, 0)? as u16;
+    let first_register_index = parse_arm32_register_operand(operands, 1)? as u16;
+    let second_register_index = parse_arm32_register_operand(operands, 2)? as u16;
+    let (first_halfword_base, second_halfword_base) = match mnemonic {
+        "mul" => (0xFB00, 0xF000),
+        "sdiv" => (0xFB90, 0xF0F0),
+        "udiv" => (0xFBB0, 0xF0F0),
+        _ => {
+            let accumulate_register_index = parse_arm32_register_operand(operands, 3)? as u16;
+
+            if accumulate_register_index == 15 {
+                return Err(format!("Thumb {} does not support pc as the accumulator.", mnemonic));
+            }
+
+            let subtract_bit = if mnemonic == "mls" { 0x10 } else { 0 };
+
+            (0xFB00, (accumulate_register_index << 12) | subtract_bit)
+        }
+    };
+
+    Ok(ThumbEncoding::Wide(
+        first_halfword_base | first_register_index,
+        second_halfword_base | (destination_register_index << 8) | second_register_index,
+    ))
+}
+
+fn encode_thumb2_table_branch(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<ThumbEncoding, String> {
+    expect_operand_count(operands, 1, mnemonic)?;
+
+    let memory_operand = parse_memory_operand(operands, 0, mnemonic)?;
+    let expression_parts = memory_operand
+        .expression_text()
+        .split(',')
+        .map(str::trim)
+        .collect::<Vec<_>>();
+    let expected_parts: &[&str] = if mnemonic == "tbh" { &["lsl #1"] } else { &[] };
+
+    if expression_parts.len() != 2 + expected_parts.len() || expression_parts[2..] != *expected_parts {
+        return Err(format!(
+            "Thumb {} expects '[rn, rm{}]'.",
+            mnemonic,
+            if mnemonic == "tbh" { ", lsl #1" } else { "" }
+        ));
+    }
+
+    let base_register_index = parse_register_name(expression_parts[0])?;
+    let index_register_index = parse_register_name(expression_parts[1])?;
+    let halfword_bit = if mnemonic == "tbh" { 0x10 } else { 0 };
+
+    Ok(ThumbEncoding::Wide(
+        0xE8D0 | base_register_index as u16,
+        0xF000 | halfword_bit | index_register_index as u16,
+    ))
+}
+
+fn encode_thumb2_exclusive(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<ThumbEncoding, String> {
+    let is_load = mnemonic == "ldrex";
+    let memory_operand_index = if is_load { 1 } else { 2 };
+
+    expect_operand_count(operands, memory_operand_index + 1, mnemonic)?;
+
+    let memory_operand = parse_memory_operand(operands, memory_operand_index, mnemonic)?;
+    let (base_register_index, memory_offset) = parse_thumb_memory_expression(memory_operand.expression_text())?;
+    let ThumbMemoryOffset::Immediate(offset) = memory_offset else {
+        return Err(format!("Thumb {} only supports immediate offsets.", mnemonic));
+    };
+    let scaled_offset = scale_offset(offset, mnemonic, 4, 1020)?;
+    let data_register_index = parse_arm32_register_operand(operands, memory_operand_index - 1)? as u16;
+
+    Ok(if is_load {
+        ThumbEncoding::Wide(0xE850 | base_register_index as u16, (data_register_index << 12) | 0x0F00 | scaled_offset)
+    } else {
+        let status_register_index = parse_arm32_register_operand(operands, 0)? as u16;
+
+        ThumbEncoding::Wide(
+            0xE840 | base_register_index as u16,
+            (data_register_index << 12) | (status_register_index << 8) | scaled_offset,
+        )
+    })
+}
+
+fn encode_thumb2_barrier(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<ThumbEncoding, String> {
+    let barrier_option = match operands {
+        [] => 15,
+        [InstructionOperand::Immediate(_)] => parse_bounded_immediate(operands, 0, mnemonic, 15)? as u16,
+        [_] => {
+            let option_name = parse_identifier_operand(operands, 0)?;
+
+            THUMB2_BARRIER_OPTION_NAMES
+                .iter()
+                .find(|(known_option_name, _)| *known_option_name == option_name)
+                .map(|(_, barrier_option)| *barrier_option)
+                .ok_or_else(|| format!("Unsupported {} option '{}'.", mnemonic, option_name))?
+        }
+        _ => return Err(format!("Thumb {} accepts at most one option operand.", mnemonic)),
+    };
+    let barrier_opcode = match mnemonic {
+        "dsb" => 0x40,
+        "dmb" => 0x50,
+        _ => 0x60,
+    };
+
+    Ok(ThumbEncoding::Wide(0xF3BF, 0x8F00 | barrier_opcode | barrier_option))
+}
+
+/// Expands the Thumb-2 12-bit modified immediate, which is either a replicated byte pattern or a rotated byte
+/// with its top bit set.
+fn decode_thumb2_modified_immediate(encoded_immediate: u16) -> Option<u32> {
+    let immediate8 = (encoded_immediate & 0xFF) as u32;
+
+    if encoded_immediate >> 10 == 0 {
+        return match (encoded_immediate >> 8) & 0x3 {
+            0 => Some(immediate8),
+            _ if immediate8 == 0 => None,
+            1 => Some((immediate8 << 16) | immediate8),
+            2 => Some((immediate8 << 24) | (immediate8 << 8)),
+            _ => Some(immediate8 * 0x0101_0101),
+        };
+    }
+
+    let rotation = (encoded_immediate >> 7) as u32;
+
+    Some((0x80 | (encoded_immediate & 0x7F) as u32).rotate_right(rotation))
+}
+
+fn encode_thumb2_modified_immediate(value: u32) -> Option<u16> {
+    let low_byte = value & 0xFF;
+
+    if value <= 0xFF {
+        return Some(value as u16);
+    }
+
+    if low_byte != 0 && value == (low_byte << 16) | low_byte {
+        return Some(0x100 | low_byte as u16);
+    }
+
+    let second_byte = (value >> 8) & 0xFF;
+
+    if second_byte != 0 && value == (second_byte << 24) | (second_byte << 8) {
+        return Some(0x200 | second_byte as u16);
+    }
+
+    if low_byte != 0 && value == low_byte * 0x0101_0101 {
+        return Some(0x300 | low_byte as u16);
+    }
+
+    (8..=31_u32).find_map(|rotation| {
+        let unrotated_value = value.rotate_left(rotation);
+
+        (unrotated_value <= 0xFF && unrotated_value & 0x80 != 0).then_some(((rotation as u16) << 7) | (unrotated_value & 0x7F) as u16)
+    })
+}
+
+fn decode_thumb_instruction(
+    first_halfword: u16,
+    second_halfword: Option<u16>,
+    current_instruction_address: i64,
+) -> Result<(DecodedInstruction, usize), String> {
+    if !is_wide_prefix(first_halfword) {
+        return decode_thumb_narrow_instruction(first_halfword, current_instruction_address).map(|decoded_instruction| (decoded_instruction, 2));
+    }
+
+    let second_halfword = second_halfword.ok_or_else(|| format!("Thumb instruction 0x{:04X} is missing its second halfword.", first_halfword))?;
+
+    decode_thumb_wide_instruction(first_halfword, second_halfword, current_instruction_address).map(|decoded_instruction| (decoded_instruction, 4))
+}
+
+fn decode_thumb_narrow_instruction(
+    halfword: u16,
+    current_instruction_address: i64,
+) -> Result<DecodedInstruction, String> {
+    let low_register_0 = (halfword & 0x7) as u8;
+    let low_register_3 = ((halfword >> 3) & 0x7) as u8;
+    let low_register_6 = ((halfword >> 6) & 0x7) as u8;
+    let low_register_8 = ((halfword >> 8) & 0x7) as u8;
+    let immediate5 = (halfword >> 6) & 0x1F;
+    let immediate8 = halfword & 0xFF;
+    let plain = |instruction_text: String| Ok(DecodedInstruction::Plain(instruction_text));
+
+    match halfword >> 13 {
+        0b000 if (halfword >> 11) & 0x3 == 0x3 => {
+            let mnemonic = if halfword & 0x200 != 0 { "subs" } else { "adds" };
+            let third_operand_text = if halfword & 0x400 != 0 {
+                format!("#{}", low_register_6)
+            } else {
+                format_arm32_register_name(low_register_6)
+            };
+
+            plain(format!(
+                "{} {}, {}, {}",
+                mnemonic,
+                format_arm32_register_name(low_register_0),
+                format_arm32_register_name(low_register_3),
+                third_operand_text
+            ))
+        }
+        0b000 => {
+            let registers_text = format!("{}, {}", format_arm32_register_name(low_register_0), format_arm32_register_name(low_register_3));
+
+            match (halfword >> 11) & 0x3 {
+                0 if immediate5 == 0 => plain(format!("movs {}", registers_text)),
+                0 => plain(format!("lsls {}, #{}", registers_text, immediate5)),
+                shift_opcode => {
+                    let mnemonic = if shift_opcode == 1 { "lsrs" } else { "asrs" };
+                    let shift_amount = if immediate5 == 0 { 32 } else { immediate5 };
+
+                    plain(format!("{} {}, #{}", mnemonic, registers_text, shift_amount))
+                }
+            }
+        }
+        0b001 => {
+            let mnemonic = ["movs", "cmp", "adds", "subs"][((halfword >> 11) & 0x3) as usize];
+
+            plain(format!("{} {}, #{}", mnemonic, format_arm32_register_name(low_register_8), immediate8))
+        }
+        0b010 => match (halfword >> 10) & 0x7 {
+            0b000 => plain(format!(
+                "{} {}, {}",
+                THUMB_ALU_MNEMONICS[((halfword >> 6) & 0xF) as usize],
+                format_arm32_register_name(low_register_0),
+                format_arm32_register_name(low_register_3)
+            )),
+            0b001 => decode_thumb_high_register_operation(halfword),
+            0b010 | 0b011 => plain(format!(
+                "ldr {}, [pc{}]",
+                format_arm32_register_name(low_register_8),
+                format_arm_memory_offset_suffix((immediate8 as i64) * 4)
+            )),
+            _ => plain(format!(
+                "{} {}, [{}, {}]",
+                THUMB_REGISTER_OFFSET_LOAD_STORE_MNEMONICS[((halfword >> 9) & 0x7) as usize],
+                format_arm32_register_name(low_register_0),
+                format_arm32_register_name(low_register_3),
+                format_arm32_register_name(low_register_6)
+            )),
+        },
+        0b011 | 0b100 if halfword >> 12 != 0b1001 => {
+            let (mnemonic, scale) = match (halfword >> 11) & 0x1F {
+                0b01100 => ("str", 4),
+                0b01101 => ("ldr", 4),
+                0b01110 => ("strb", 1),
+                0b01111 => ("ldrb", 1),
+                0b10000 => ("strh", 2),
+                _ => ("ldrh", 2),
+            };
+
+            plain(format!(
+                "{} {}, [{}{}]",
+                mnemonic,
+                format_arm32_register_name(low_register_0),
+                format_arm32_register_name(low_register_3),
+                format_arm_memory_offset_suffix((immediate5 as i64) * scale)
+            ))
+        }
+        0b100 => {
+            let mnemonic = if halfword & 0x800 != 0 { "ldr" } else { "str" };
+
+            plain(format!(
+                "{} {}, [sp{}]",
+                mnemonic,
+                format_arm32_register_name(low_register_8),
+                format_arm_memory_offset_suffix((immediate8 as i64) * 4)
+            ))
+        }
+        0b101 if halfword & 0x1000 == 0 => {
+            let base_register_name = if halfword & 0x800 != 0 { "sp" } else { "pc" };
+
+            plain(format!(
+                "add {}, {}, #{}",
+                format_arm32_register_name(low_register_8),
+                base_register_name,
+                immediate8 * 4
+            ))
+        }
+        0b101 => decode_thumb_miscellaneous_instruction(halfword, current_instruction_address),
+        0b110 if halfword & 0x1000 == 0 => {
+            let register_list = immediate8;
+
+            if register_list == 0 {
+                return Err(format!("Thumb load/store multiple 0x{:04X} has an empty register list.", halfword));
+            }
+
+            let is_load = halfword & 0x800 != 0;
+            let write_back_suffix = if !is_load || register_list & (1 << low_register_8) == 0 { "!" } else { "" };
+
+            plain(format!(
+                "{} {}{}, {}",
+                if is_load { "ldmia" } else { "stmia" },
+                format_arm32_register_name(low_register_8),
+                write_back_suffix,
+                format_register_list(register_list)
+            ))
+        }
+        0b110 => match (halfword >> 8) & 0xF {
+            0xE => plain(format!("udf #{}", immediate8)),
+            0xF => plain(format!("svc #{}", immediate8)),
+            condition_code => Ok(DecodedInstruction::Branch {
+                mnemonic: THUMB_NARROW_CONDITIONAL_BRANCH_MNEMONICS[condition_code as usize],
+                leading_register: None,
+                target_address: current_instruction_address + 4 + sign_extend(immediate8 as u32, 8) * 2,
+            }),
+        },
+        _ => Ok(DecodedInstruction::Branch {
+            mnemonic: "b",
+            leading_register: None,
+            target_address: current_instruction_address + 4 + sign_extend((halfword & 0x7FF) as u32, 11) * 2,
+        }),
+    }
+}
+
+fn decode_thumb_high_register_operation(halfword: u16) -> Result<DecodedInstruction, String> {
+    let source_register_index = ((halfword >> 3) & 0xF) as u8;
+    let destination_register_index = ((halfword & 0x7) | ((halfword >> 4) & 0x8)) as u8;
+    let registers_text = format!(
+        "{}, {}",
+        format_arm32_register_name(destination_register_index),
+        format_arm32_register_name(source_register_index)
+    );
+
+    match (halfword >> 8) & 0x3 {
+        0 => Ok(DecodedInstruction::Plain(format!("add {}", registers_text))),
+        1 if destination_register_index < 8 && source_register_index < 8 => {
+            Err(format!("Thumb high-register cmp 0x{:04X} requires a high register.", halfword))
+        }
+        1 => Ok(DecodedInstruction::Plain(format!("cmp {}", registers_text))),
+        2 => Ok(DecodedInstruction::Plain(format!("mov {}", registers_text))),
+        _ if halfword & 0x7 != 0 => Err(format!("Unsupported Thumb branch-exchange encoding 0x{:04X}.", halfword)),
+        _ => {
+            let mnemonic = if halfword & 0x80 != 0 { "blx" } else { "bx" };
+
+            Ok(DecodedInstruction::Plain(format!(
+                "{} {}",
+                mnemonic,
+                format_arm32_register_name(source_register_index)
+            )))
+        }
+    }
+}
+
+fn decode_thumb_miscellaneous_instruction(
+    halfword: u16,
+    current_instruction_address: i64,
+) -> Result<DecodedInstruction, String> {
+    let low_register_0 = (halfword & 0x7) as u8;
+    let low_register_3 = ((halfword >> 3) & 0x7) as u8;
+    let immediate8 = halfword & 0xFF;
+    let two_registers_text = format!("{}, {}", format_arm32_register_name(low_register_0), format_arm32_register_name(low_register_3));
+    let plain = |instruction_text: String| Ok(DecodedInstruction::Plain(instruction_text));
+
+    match (halfword >> 8) & 0xF {
+        0x0 => {
+            let mnemonic = if halfword & 0x80 != 0 { "sub" } else { "add" };
+
+            plain(format!("{} sp, #{}", mnemonic, (halfword & 0x7F) * 4))
+        }
+        0x2 => plain(format!("{} {}", THUMB_EXTEND_MNEMONICS[((halfword >> 6) & 0x3) as usize], two_registers_text)),
+        0x1 | 0x3 | 0x9 | 0xB => {
+            let branch_offset = ((((halfword >> 9) & 0x1) << 6) | (((halfword >> 3) & 0x1F) << 1)) as i64;
+
+            Ok(DecodedInstruction::Branch {
+                mnemonic: if halfword & 0x800 != 0 { "cbnz" } else { "cbz" },
+                leading_register: Some(low_register_0),
+                target_address: current_instruction_address + 4 + branch_offset,
+            })
+        }
+        0x4 | 0x5 | 0xC | 0xD => {
+            let is_pop = halfword & 0x800 != 0;
+            let extra_register_bit = if halfword & 0x100 != 0 { if is_pop { 1 << 15 } else { 1 << 14 } } else { 0 };
+            let register_list = immediate8 | extra_register_bit;
+
+            if register_list == 0 {
+                return Err(format!("Thumb push/pop 0x{:04X} has an empty register list.", halfword));
+            }
+
+            plain(format!("{} {}", if is_pop { "pop" } else { "push" }, format_register_list(register_list)))
+        }
+        0x6 if halfword & 0xFFE8 == 0xB660 && halfword & 0x7 != 0 => {
+            let flags_text = [('a', 4), ('i', 2), ('f', 1)]
+                .iter()
+                .filter(|(_, flag_bit)| halfword & flag_bit != 0)
+                .map(|(flag_name, _)| *flag_name)
+                .collect::<String>();
+
+            plain(format!("{} {}", if halfword & 0x10 != 0 { "cpsid" } else { "cpsie" }, flags_text))
+        }
+        0xA if (halfword >> 6) & 0x3 != 0x2 => {
+            let mnemonic = ["rev", "rev16", "", "revsh"][((halfword >> 6) & 0x3) as usize];
+
+            plain(format!("{} {}", mnemonic, two_registers_text))
+        }
+        0xE => plain(format!("bkpt #{}", immediate8)),
+        0xF => decode_thumb_if_then_or_hint(halfword),
+        _ => Err(format!("Unsupported Thumb miscellaneous instruction 0x{:04X}.", halfword)),
+    }
+}
+
+fn decode_thumb_if_then_or_hint(halfword: u16) -> Result<DecodedInstruction, String> {
+    let condition_code = (halfword >> 4) & 0xF;
+    let mask = halfword & 0xF;
+
+    if mask == 0 {
+        return THUMB_HINT_MNEMONICS
+            .get(condition_code as usize)
+            .map(|hint_mnemonic| DecodedInstruction::Plain(hint_mnemonic.to_string()))
+            .ok_or_else(|| format!("Unsupported Thumb hint 0x{:04X}.", halfword));
+    }
+
+    let slot_count = 3 - mask.trailing_zeros() as usize;
+    let if_then_pattern = (0..slot_count)
+        .map(|slot_index| if (mask >> (3 - slot_index)) & 1 == condition_code & 1 { 't' } else { 'e' })
+        .collect::<String>();
+
+    if condition_code == 15 || (condition_code == 14 && if_then_pattern.contains('e')) {
+        return Err(format!("Unsupported Thumb IT condition in 0x{:04X}.", halfword));
+    }
+
+    Ok(DecodedInstruction::Plain(format!(
+        "it{} {}",
+        if_then_pattern, THUMB_CONDITION_NAMES[condition_code as usize]
+    )))
+}
+
+fn decode_thumb_wide_instruction(
+    first_halfword: u16,
+    second_halfword: u16,
+    current_instruction_address: i64,
+) -> Result<DecodedInstruction, String> {
+    let unsupported_instruction_error = || format!("Unsupported Thumb-2 instruction 0x{:04X} 0x{:04X}.", first_halfword, second_halfword);
+    let first_register_index = (first_halfword & 0xF) as u8;
+
+    if first_halfword & 0xF800 == 0xF000 && second_halfword & 0x8000 != 0 {
+        let branch_offset = decode_thumb2_branch_offset(first_halfword, second_halfword);
+
+        return match second_halfword & 0xD000 {
+            0xD000 => Ok(DecodedInstruction::Branch {
+                mnemonic: "bl",
+                leading_register: None,
+                target_address: current_instruction_address + 4 + branch_offset,
+            }),
+            0xC000 if second_halfword & 1 == 0 => Ok(DecodedInstruction::Branch {
+                mnemonic: "blx",
+                leading_register: None,
+                target_address: ((current_instruction_address + 4) & !3) + branch_offset,
+            }),
+            0x9000 => Ok(DecodedInstruction::Branch {
+                mnemonic: "b.w",
+                leading_register: None,
+                target_address: current_instruction_address + 4 + branch_offset,
+            }),
+            0x8000 => decode_thumb2_conditional_branch_or_barrier(first_halfword, second_halfword, current_instruction_address)
+                .ok_or_else(unsupported_instruction_error),
+            _ => Err(unsupported_instruction_error()),
+        };
+    }
+
+    if first_halfword & 0xFA00 == 0xF000 && second_halfword & 0x8000 == 0 {
+        let encoded_immediate = (((first_halfword >> 10) & 0x1) << 11) | (((second_halfword >> 12) & 0x7) << 8) | (second_halfword & 0xFF);
+        let immediate_value = decode_thumb2_modified_immediate(encoded_immediate).ok_or_else(unsupported_instruction_error)?;
+
+        return format_thumb2_data_processing(first_halfword, second_halfword, &format!("#{}", immediate_value)).ok_or_else(unsupported_instruction_error);
+    }
+
+    if first_halfword & 0xFA00 == 0xF200 && second_halfword & 0x8000 == 0 {
+        let immediate_value = (((first_halfword >> 10) & 0x1) << 11) | (((second_halfword >> 12) & 0x7) << 8) | (second_halfword & 0xFF);
+        let destination_register_name = format_arm32_register_name(((second_halfword >> 8) & 0xF) as u8);
+
+        return match (first_halfword >> 4) & 0x1F {
+            0b00000 | 0b01010 => Ok(DecodedInstruction::Plain(format!(
+                "{} {}, {}, #{}",
+                if first_halfword & 0x00A0 != 0 { "subw" } else { "addw" },
+                destination_register_name,
+                format_arm32_register_name(first_register_index),
+                immediate_value
+            ))),
+            0b00100 | 0b01100 => Ok(DecodedInstruction::Plain(format!(
+                "{} {}, #{}",
+                if first_halfword & 0x0080 != 0 { "movt" } else { "movw" },
+                destination_register_name,
+                ((first_halfword & 0xF) << 12) | immediate_value
+            ))),
+            _ => Err(unsupported_instruction_error()),
+        };
+    }
+
+    if first_halfword & 0xFE00 == 0xEA00 && second_halfword & 0x8000 == 0 {
+        let shift_type = (second_halfword >> 4) & 0x3;
+        let shift_amount = (((second_halfword >> 12) & 0x7) << 2) | ((second_halfword >> 6) & 0x3);
+        let second_operand_text = format!(
+            "{}{}",
+            format_arm32_register_name((second_halfword & 0xF) as u8),
+            format_thumb_shift_suffix(shift_type, shift_amount)
+        );
+
+        return format_thumb2_data_processing(first_halfword, second_halfword, &second_operand_text).ok_or_else(unsupported_instruction_error);
+    }
+
+    let is_unsigned_load_store = first_halfword & 0xFF80 == 0xF880 && (first_halfword >> 5) & 0x3 != 0x3;
+    let is_signed_load = first_halfword & 0xFF90 == 0xF990 && (first_halfword >> 5) & 0x3 != 0x3;
+
+    if (is_unsigned_load_store || is_signed_load) && first_register_index != 15 {
+        let data_register_index = (second_halfword >> 12) as u8;
+        let size_and_direction = ((first_halfword >> 4) & 0x7, is_signed_load);
+        let mnemonic = match size_and_direction {
+            (0b000, false) => "strb.w",
+            (0b001, false) => "ldrb.w",
+            (0b010, false) => "strh.w",
+            (0b011, false) => "ldrh.w",
+            (0b100, false) => "str.w",
+            (0b101, false) => "ldr.w",
+            (0b001, true) => "ldrsb.w",
+            _ => "ldrsh.w",
+        };
+
+        if data_register_index == 15 && !matches!(mnemonic, "ldr.w" | "str.w") {
+            return Err(unsupported_instruction_error());
+        }
+
+        return Ok(DecodedInstruction::Plain(format!(
+            "{} {}, [{}{}]",
+            mnemonic,
+            format_arm32_register_name(data_register_index),
+            format_arm32_register_name(first_register_index),
+            format_arm_memory_offset_suffix((second_halfword & 0xFFF) as i64)
+        )));
+    }
+
+    match first_halfword {
+        0xE92D if second_halfword & 0xA000 == 0 && second_halfword.count_ones() >= 2 => {
+            return Ok(DecodedInstruction::Plain(format!("push.w {}", format_register_list(second_halfword))));
+        }
+        0xE8BD if second_halfword & 0x2000 == 0 && second_halfword & 0xC000 != 0xC000 && second_halfword.count_ones() >= 2 => {
+            return Ok(DecodedInstruction::Plain(format!("pop.w {}", format_register_list(second_halfword))));
+        }
+        _ => {}
+    }
+
+    let second_register_name = format_arm32_register_name((second_halfword & 0xF) as u8);
+    let destination_register_name = format_arm32_register_name(((second_halfword >> 8) & 0xF) as u8);
+    let high_register_name = format_arm32_register_name((second_halfword >> 12) as u8);
+    let first_register_name = format_arm32_register_name(first_register_index);
+
+    let instruction_text = match (first_halfword & 0xFFF0, second_halfword) {
+        (0xE8D0, _) if second_halfword & 0xFFE0 == 0xF000 => {
+            if second_halfword & 0x10 != 0 {
+                format!("tbh [{}, {}, lsl #1]", first_register_name, second_register_name)
+            } else {
+                format!("tbb [{}, {}]", first_register_name, second_register_name)
+            }
+        }
+        (0xE850, _) if second_halfword & 0x0F00 == 0x0F00 => format!(
+            "ldrex {}, [{}{}]",
+            high_register_name,
+            first_register_name,
+            format_arm_memory_offset_suffix(((second_halfword & 0xFF) as i64) * 4)
+        ),
+        (0xE840, _) => format!(
+            "strex {}, {}, [{}{}]",
+            destination_register_name,
+            high_register_name,
+            first_register_name,
+            format_arm_memory_offset_suffix(((second_halfword & 0xFF) as i64) * 4)
+        ),
+        (0xFB00, _) if second_halfword & 0xF0F0 == 0xF000 => format!("mul {}, {}, {}", destination_register_name, first_register_name, second_register_name),
+        (0xFB00, _) if second_halfword & 0x00E0 == 0 => format!(
+            "{} {}, {}, {}, {}",
+            if second_halfword & 0x10 != 0 { "mls" } else { "mla" },
+            destination_register_name,
+            first_register_name,
+            second_register_name,
+            high_register_name
+        ),
+        (0xFB90, _) | (0xFBB0, _) if second_halfword & 0xF0F0 == 0xF0F0 => format!(
+            "{} {}, {}, {}",
+            if first_halfword & 0x0020 != 0 { "udiv" } else { "sdiv" },
+            destination_register_name,
+            first_register_name,
+            second_register_name
+        ),
+        _ => return Err(unsupported_instruction_error()),
+    };
+
+    Ok(DecodedInstruction::Plain(instruction_text))
+}
+
+fn decode_thumb2_conditional_branch_or_barrier(
+    first_halfword: u16,
+    second_halfword: u16,
+    current_instruction_address: i64,
+) -> Option<DecodedInstruction> {
+    let condition_code = (first_halfword >> 6) & 0xF;
+
+    if condition_code >= 0xE {
+        if first_halfword != 0xF3BF || !matches!(second_halfword & 0xFFF0, 0x8F40 | 0x8F50 | 0x8F60) {
+            return None;
+        }
+
+        let mnemonic = match second_halfword & 0xFFF0 {
+            0x8F40 => "dsb",
+            0x8F50 => "dmb",
+            _ => "isb",
+        };
+        let barrier_option = second_halfword & 0xF;
+        let option_text = THUMB2_BARRIER_OPTION_NAMES
+            .iter()
+            .find(|(_, known_barrier_option)| *known_barrier_option == barrier_option)
+            .map(|(option_name, _)| option_name.to_string())
+            .unwrap_or_else(|| format!("#{}", barrier_option));
+
+        return Some(DecodedInstruction::Plain(format!("{} {}", mnemonic, option_text)));
+    }
+
+    let branch_offset = (((first_halfword >> 10) & 0x1) as u32) << 20
+        | (((second_halfword >> 11) & 0x1) as u32) << 19
+        | (((second_halfword >> 13) & 0x1) as u32) << 18
+        | ((first_halfword & 0x3F) as u32) << 12
+        | ((second_halfword & 0x7FF) as u32) << 1;
+
+    Some(DecodedInstruction::Branch {
+        mnemonic: THUMB_WIDE_CONDITIONAL_BRANCH_MNEMONICS[condition_code as usize],
+        leading_register: None,
+        target_address: current_instruction_address + 4 + sign_extend(branch_offset, 21),
+    })
+}
+
+/// Formats a Thumb-2 data-processing instruction, applying the aliases that drop the first operand (`mov`, `mvn`)
+/// or the destination (`tst`, `teq`, `cmn`, `cmp`).
+fn format_thumb2_data_processing(
+    first_halfword: u16,
+    second_halfword: u16,
+    second_operand_text: &str,
+) -> Option<DecodedInstruction> {
+    let operation_name = THUMB2_DATA_PROCESSING_OPERATIONS[((first_halfword >> 5) & 0xF) as usize]?;
+    let sets_flags = first_halfword & 0x10 != 0;
+    let first_register_index = (first_halfword & 0xF) as u8;
+    let destination_register_index = ((second_halfword >> 8) & 0xF) as u8;
+    let flags_suffix = if sets_flags { "s" } else { "" };
+    let compare_alias = match operation_name {
+        "and" => Some("tst"),
+        "eor" => Some("teq"),
+        "add" => Some("cmn"),
+        "sub" => Some("cmp"),
+        _ => None,
+    };
+    let move_alias = match operation_name {
+        "orr" => Some("mov"),
+        "orn" => Some("mvn"),
+        _ => None,
+    };
+
+    let instruction_text = match (compare_alias, move_alias) {
+        (Some(compare_alias), _) if sets_flags && destination_register_index == 15 => format!(
+            "{}.w {}, {}",
+            compare_alias,
+            format_arm32_register_name(first_register_index),
+            second_operand_text
+        ),
+        (_, Some(move_alias)) if first_register_index == 15 => format!(
+            "{}{}.w {}, {}",
+            move_alias,
+            flags_suffix,
+            format_arm32_register_name(destination_register_index),
+            second_operand_text
+        ),
+        _ => format!(
+            "{}{}.w {}, {}, {}",
+            operation_name,
+            flags_suffix,
+            format_arm32_register_name(destination_register_index),
+            format_arm32_register_name(first_register_index),
+            second_operand_text
+        ),
+    };
+
+    Some(DecodedInstruction::Plain(instruction_text))
+}
+
+fn format_thumb_shift_suffix(
+    shift_type: u16,
+    shift_amount: u16,
+) -> String {
+    match (shift_type, shift_amount) {
+        (0, 0) => String::new(),
+        (3, 0) => String::from(", rrx"),
+        (1, 0) | (2, 0) => format!(", {} #32", ["lsl", "lsr", "asr", "ror"][shift_type as usize]),
+        _ => format!(", {} #{}", ["lsl", "lsr", "asr", "ror"][shift_type as usize], shift_amount),
+    }
+}
+
+fn parse_shift_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+) -> Result<ThumbShift, String> {
+    let shift_text = parse_identifier_operand(operands, operand_index)?;
+
+    if shift_text == "rrx" {
+        return Ok(ThumbShift::Immediate { shift_type: 3, amount: 0 });
+    }
+
+    let (shift_name, shift_amount_text) = shift_text
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| format!("Unsupported shift operand '{}'.", shift_text))?;
+    let shift_amount = parse_signed_immediate(shift_amount_text)?;
+    let (shift_type, minimum_amount, maximum_amount) = match shift_name {
+        "lsl" => (0, 0, 31),
+        "lsr" => (1, 1, 32),
+        "asr" => (2, 1, 32),
+        "ror" => (3, 1, 31),
+        _ => return Err(format!("Unsupported shift operand '{}'.", shift_text)),
+    };
+
+    if !(minimum_amount..=maximum_amount).contains(&shift_amount) {
+        return Err(format!("Shift amount in '{}' is out of range.", shift_text));
+    }
+
+    Ok(ThumbShift::Immediate {
+        shift_type,
+        amount: (shift_amount % 32) as u16,
+    })
+}
+
+/// Parses `b`, `b<cond>`, and their `.n`/`.w` width suffixes into an optional condition code and a width.
+fn parse_branch_mnemonic(mnemonic: &str) -> Option<(Option<u16>, bool)> {
+    let (base_mnemonic, is_wide) = match mnemonic.strip_suffix(".w") {
+        Some(base_mnemonic) => (base_mnemonic, true),
+        None => (mnemonic.strip_suffix(".n").unwrap_or(mnemonic), false),
+    };
+    let condition_name = base_mnemonic.strip_prefix('b')?;
+
+    if condition_name.is_empty() {
+        return Some((None, is_wide));
+    }
+
+    parse_condition_name(condition_name)
+        .filter(|condition_code| *condition_code < 14)
+        .map(|condition_code| (Some(condition_code), is_wide))
+}
+
+fn parse_condition_name(condition_name: &str) -> Option<u16> {
+    match condition_name {
+        "hs" => Some(2),
+        "lo" => Some(3),
+        _ => THUMB_CONDITION_NAMES
+            .iter()
+            .position(|known_condition_name| *known_condition_name == condition_name)
+            .map(|condition_code| condition_code as u16),
+    }
+}
+
+fn parse_condition_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+) -> Result<u16, String> {
+    let condition_name = parse_identifier_operand(operands, operand_index)?;
+
+    parse_condition_name(condition_name).ok_or_else(|| format!("Unsupported condition '{}'.", condition_name))
+}
+
+fn parse_thumb_memory_expression(expression_text: &str) -> Result<(u8, ThumbMemoryOffset), String> {
+    let mut expression_parts = expression_text.split(',').map(str::trim);
+    let base_register_index = parse_register_name(expression_parts.next().unwrap_or_default())?;
+    let memory_offset = match (expression_parts.next(), expression_parts.next()) {
+        (None, _) => ThumbMemoryOffset::Immediate(0),
+        (Some(offset_text), None) => match parse_arm32_register_name(offset_text) {
+            Some(offset_register_index) => ThumbMemoryOffset::Register(offset_register_index),
+            None => ThumbMemoryOffset::Immediate(parse_signed_immediate(offset_text)?),
+        },
+        (Some(_), Some(_)) => return Err(format!("Unsupported Thumb memory operand '[{}]'.", expression_text)),
+    };
+
+    Ok((base_register_index, memory_offset))
+}
+
+fn parse_register_name(register_name: &str) -> Result<u8, String> {
+    parse_arm32_register_name(register_name).ok_or_else(|| format!("Unsupported ARM register '{}'.", register_name))
+}
+
+fn parse_low_register_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+) -> Result<u16, String> {
+    let register_index = parse_arm32_register_operand(operands, operand_index)?;
+
+    if register_index > 7 {
+        return Err(format!(
+            "Register '{}' is not a low register (r0-r7) as required by this 16-bit Thumb form.",
+            format_arm32_register_name(register_index)
+        ));
+    }
+
+    Ok(register_index as u16)
+}
+
+fn parse_write_back_register_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+) -> Result<(u8, bool), String> {
+    let register_text = parse_identifier_operand(operands, operand_index)?;
+    let (register_name, has_write_back) = match register_text.strip_suffix('!') {
+        Some(register_name) => (register_name, true),
+        None => (register_text, false),
+    };
+
+    Ok((parse_register_name(register_name)?, has_write_back))
+}
+
+fn parse_register_list_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+    mnemonic: &str,
+) -> Result<u16, String> {
+    let register_list_text = parse_memory_operand(operands, operand_index, mnemonic)
+        .map_err(|_| format!("Thumb {} expects a register list such as '{{r4-r7, lr}}'.", mnemonic))?
+        .expression_text();
+    let mut register_list = 0_u16;
+
+    for register_list_item in register_list_text.split(',').map(str::trim) {
+        let (first_register_index, last_register_index) = match register_list_item.split_once('-') {
+            Some((first_register_name, last_register_name)) => (
+                parse_register_name(first_register_name.trim())?,
+                parse_register_name(last_register_name.trim())?,
+            ),
+            None => {
+                let register_index = parse_register_name(register_list_item)?;
+
+                (register_index, register_index)
+            }
+        };
+
+        if first_register_index > last_register_index {
+            return Err(format!("Register range '{}' is reversed.", register_list_item));
+        }
+
+        for register_index in first_register_index..=last_register_index {
+            register_list |= 1 << register_index;
+        }
+    }
+
+    Ok(register_list)
+}
+
+fn format_register_list(register_list: u16) -> String {
+    let register_names = (0..16_u8)
+        .filter(|register_index| register_list & (1 << register_index) != 0)
+        .map(format_arm32_register_name)
+        .collect::<Vec<_>>();
+
+    format!("{{{}}}", register_names.join(", "))
+}
+
+fn expect_operand_count(
+    operands: &[InstructionOperand],
+    expected_operand_count: usize,
+    mnemonic: &str,
+) -> Result<(), String> {
+    if operands.len() == expected_operand_count {
+        Ok(())
+    } else {
+        Err(format!(
+            "Thumb {} expects {} operand(s) but found {}.",
+            mnemonic,
+            expected_operand_count,
+            operands.len()
+        ))
+    }
+}
+
+fn parse_bounded_immediate(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+    mnemonic: &str,
+    maximum_value: i128,
+) -> Result<i128, String> {
+    let immediate_value = parse_non_negative_immediate_operand(operands, operand_index, mnemonic)?;
+
+    if immediate_value > maximum_value {
+        return Err(format!(
+            "Thumb {} immediate '{}' is out of range (maximum {}).",
+            mnemonic, immediate_value, maximum_value
+        ));
+    }
+
+    Ok(immediate_value)
+}
+
+fn parse_scaled_immediate(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+    mnemonic: &str,
+    scale: i64,
+    maximum_value: i64,
+) -> Result<u16, String> {
+    let immediate_value = parse_bounded_immediate(operands, operand_index, mnemonic, maximum_value as i128)? as i64;
+
+    scale_offset(immediate_value, mnemonic, scale, maximum_value)
+}
+
+fn scale_offset(
+    offset: i64,
+    mnemonic: &str,
+    scale: i64,
+    maximum_offset: i64,
+) -> Result<u16, String> {
+    if !(0..=maximum_offset).contains(&offset) || offset % scale != 0 {
+        return Err(format!(
+            "Thumb {} offset '{}' must be a multiple of {} between 0 and {}.",
+            mnemonic, offset, scale, maximum_offset
+        ));
+    }
+
+    Ok((offset / scale) as u16)
+}
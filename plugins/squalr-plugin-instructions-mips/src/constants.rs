@@ -0,0 +1,6 @@
+pub const MIPS_FAMILY_PLUGIN_ID: &str = "builtin.instruction-set.mips-family";
+pub const MIPS_FAMILY_PLUGIN_DISPLAY_NAME: &str = "MIPS Instructions";
+pub const MIPS_FAMILY_PLUGIN_DESCRIPTION: &str =
+    "Adds MIPS instruction data types with built-in assembly/disassembly support for the R5900 (PlayStation 2) and Allegrex (PSP) forms used by games.";
+pub const MIPS_FAMILY_INSTRUCTION_SET_IDS: [&str; 1] = ["mips32le"];
+pub const MIPS_FAMILY_DATA_TYPE_IDS: [&str; 1] = ["i_mips32le"];
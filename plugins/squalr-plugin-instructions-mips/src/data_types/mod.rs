@@ -0,0 +1,3 @@
+mod data_type_instruction_mips32_le;
+
+pub use data_type_instruction_mips32_le::DataTypeInstructionMips32Le;
@@ -0,0 +1,1040 @@
+use crate::{
+    mips_memory_operand::parse_mips_memory_operand,
+    mips_register::{
+        format_mips_floating_point_register_name, format_mips_general_purpose_register_name, parse_mips_floating_point_register_name,
+        parse_mips_general_purpose_register_name,
+    },
+};
+use squalr_engine_api::plugins::instruction_set::{InstructionOperand, InstructionSet, ParsedInstruction, parse_instruction_sequence};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const OPCODE_SPECIAL: u32 = 0x00;
+const OPCODE_REGIMM: u32 = 0x01;
+const OPCODE_BEQ: u32 = 0x04;
+const OPCODE_BNE: u32 = 0x05;
+const OPCODE_ADDIU: u32 = 0x09;
+const OPCODE_COP1: u32 = 0x11;
+const FUNCTION_ADDU: u32 = 0x21;
+const COP1_FORMAT_SINGLE: u32 = 0x10;
+const REGISTER_ZERO: u8 = 0;
+const REGISTER_RA: u8 = 31;
+
+#[derive(Clone, Debug)]
+enum DecodedInstruction {
+    Plain(String),
+    Branch {
+        mnemonic: &'static str,
+        register_operands: Vec<String>,
+        target_address: i64,
+    },
+}
+
+/// Operand layout of an instruction, along with the opcode fields that identify it.
+#[derive(Clone, Copy, Debug)]
+enum MipsInstructionForm {
+    /// `rd, rs, rt`
+    RegisterThree { function: u32 },
+    /// `rd, rt, sa`
+    Shift { function: u32 },
+    /// `rt, rs, imm`, where the immediate is sign-extended.
+    SignedImmediate { opcode: u32 },
+    /// `rt, rs, imm`, where the immediate is zero-extended.
+    UnsignedImmediate { opcode: u32 },
+    /// `rt, imm`
+    LoadUpperImmediate,
+    /// `rt, offset(base)`
+    LoadStore { opcode: u32 },
+    /// `ft, offset(base)`
+    FloatingPointLoadStore { opcode: u32 },
+    /// `rs`
+    JumpRegister,
+    /// `rs` or `rd, rs`
+    JumpAndLinkRegister,
+    /// `target`, where the target is an absolute address within the current 256MB segment.
+    Jump { opcode: u32 },
+    /// `rs, rt, target`
+    BranchCompare { opcode: u32 },
+    /// `rs, target`
+    BranchZero { opcode: u32, rt_field: u32 },
+    /// `rs, rt`
+    MultiplyDivide { function: u32 },
+    /// `rd`
+    MoveFromHiLo { function: u32 },
+    /// No operands.
+    Syscall,
+    /// `fd, fs, ft`
+    FloatingPointArithmetic { function: u32 },
+    /// `fd, fs`
+    FloatingPointMove,
+    /// `rt, fs`
+    FloatingPointTransfer { rs_field: u32 },
+}
+
+impl MipsInstructionForm {
+    /// Returns the mask and value of the bits that identify this form, with operand fields masked out.
+    fn get_fixed_bits(&self) -> (u32, u32) {
+        match *self {
+            Self::RegisterThree { function } => (0xFC00_07FF, function),
+            Self::Shift { function } => (0xFFE0_003F, function),
+            Self::SignedImmediate { opcode } | Self::UnsignedImmediate { opcode } => (0xFC00_0000, opcode << 26),
+            Self::LoadUpperImmediate => (0xFFE0_0000, 0x0F << 26),
+            Self::LoadStore { opcode } | Self::FloatingPointLoadStore { opcode } => (0xFC00_0000, opcode << 26),
+            Self::JumpRegister => (0xFC1F_FFFF, 0x08),
+            Self::JumpAndLinkRegister => (0xFC1F_07FF, 0x09),
+            Self::Jump { opcode } | Self::BranchCompare { opcode } => (0xFC00_0000, opcode << 26),
+            Self::BranchZero { opcode, rt_field } => (0xFC1F_0000, (opcode << 26) | (rt_field << 16)),
+            Self::MultiplyDivide { function } => (0xFC00_FFFF, function),
+            Self::MoveFromHiLo { function } => (0xFFFF_07FF, function),
+            Self::Syscall => (0xFC00_003F, 0x0C),
+            Self::FloatingPointArithmetic { function } => (0xFFE0_003F, (OPCODE_COP1 << 26) | (COP1_FORMAT_SINGLE << 21) | function),
+            Self::FloatingPointMove => (0xFFFF_003F, (OPCODE_COP1 << 26) | (COP1_FORMAT_SINGLE << 21) | 0x06),
+            Self::FloatingPointTransfer { rs_field } => (0xFFE0_07FF, (OPCODE_COP1 << 26) | (rs_field << 21)),
+        }
+    }
+}
+
+struct MipsInstructionDefinition {
+    mnemonic: &'static str,
+    form: MipsInstructionForm,
+}
+
+const MIPS_INSTRUCTION_DEFINITIONS: [MipsInstructionDefinition; 56] = [
+    MipsInstructionDefinition {
+        mnemonic: "addu",
+        form: MipsInstructionForm::RegisterThree { function: FUNCTION_ADDU },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "subu",
+        form: MipsInstructionForm::RegisterThree { function: 0x23 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "and",
+        form: MipsInstructionForm::RegisterThree { function: 0x24 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "or",
+        form: MipsInstructionForm::RegisterThree { function: 0x25 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "xor",
+        form: MipsInstructionForm::RegisterThree { function: 0x26 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "nor",
+        form: MipsInstructionForm::RegisterThree { function: 0x27 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "slt",
+        form: MipsInstructionForm::RegisterThree { function: 0x2A },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "sltu",
+        form: MipsInstructionForm::RegisterThree { function: 0x2B },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "sll",
+        form: MipsInstructionForm::Shift { function: 0x00 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "srl",
+        form: MipsInstructionForm::Shift { function: 0x02 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "sra",
+        form: MipsInstructionForm::Shift { function: 0x03 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "addiu",
+        form: MipsInstructionForm::SignedImmediate { opcode: OPCODE_ADDIU },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "slti",
+        form: MipsInstructionForm::SignedImmediate { opcode: 0x0A },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "sltiu",
+        form: MipsInstructionForm::SignedImmediate { opcode: 0x0B },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "andi",
+        form: MipsInstructionForm::UnsignedImmediate { opcode: 0x0C },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "ori",
+        form: MipsInstructionForm::UnsignedImmediate { opcode: 0x0D },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "xori",
+        form: MipsInstructionForm::UnsignedImmediate { opcode: 0x0E },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "lui",
+        form: MipsInstructionForm::LoadUpperImmediate,
+    },
+    MipsInstructionDefinition {
+        mnemonic: "lb",
+        form: MipsInstructionForm::LoadStore { opcode: 0x20 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "lh",
+        form: MipsInstructionForm::LoadStore { opcode: 0x21 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "lw",
+        form: MipsInstructionForm::LoadStore { opcode: 0x23 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "lbu",
+        form: MipsInstructionForm::LoadStore { opcode: 0x24 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "lhu",
+        form: MipsInstructionForm::LoadStore { opcode: 0x25 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "sb",
+        form: MipsInstructionForm::LoadStore { opcode: 0x28 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "sh",
+        form: MipsInstructionForm::LoadStore { opcode: 0x29 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "sw",
+        form: MipsInstructionForm::LoadStore { opcode: 0x2B },
+    },
+    // The 64-bit and 128-bit transfers are R5900 forms. Allegrex reuses these opcodes for VFPU instructions.
+    MipsInstructionDefinition {
+        mnemonic: "ld",
+        form: MipsInstructionForm::LoadStore { opcode: 0x37 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "sd",
+        form: MipsInstructionForm::LoadStore { opcode: 0x3F },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "lq",
+        form: MipsInstructionForm::LoadStore { opcode: 0x1E },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "sq",
+        form: MipsInstructionForm::LoadStore { opcode: 0x1F },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "lwc1",
+        form: MipsInstructionForm::FloatingPointLoadStore { opcode: 0x31 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "swc1",
+        form: MipsInstructionForm::FloatingPointLoadStore { opcode: 0x39 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "jr",
+        form: MipsInstructionForm::JumpRegister,
+    },
+    MipsInstructionDefinition {
+        mnemonic: "jalr",
+        form: MipsInstructionForm::JumpAndLinkRegister,
+    },
+    MipsInstructionDefinition {
+        mnemonic: "j",
+        form: MipsInstructionForm::Jump { opcode: 0x02 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "jal",
+        form: MipsInstructionForm::Jump { opcode: 0x03 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "beq",
+        form: MipsInstructionForm::BranchCompare { opcode: OPCODE_BEQ },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "bne",
+        form: MipsInstructionForm::BranchCompare { opcode: OPCODE_BNE },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "blez",
+        form: MipsInstructionForm::BranchZero { opcode: 0x06, rt_field: 0 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "bgtz",
+        form: MipsInstructionForm::BranchZero { opcode: 0x07, rt_field: 0 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "bltz",
+        form: MipsInstructionForm::BranchZero {
+            opcode: OPCODE_REGIMM,
+            rt_field: 0,
+        },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "bgez",
+        form: MipsInstructionForm::BranchZero {
+            opcode: OPCODE_REGIMM,
+            rt_field: 1,
+        },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "mult",
+        form: MipsInstructionForm::MultiplyDivide { function: 0x18 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "multu",
+        form: MipsInstructionForm::MultiplyDivide { function: 0x19 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "div",
+        form: MipsInstructionForm::MultiplyDivide { function: 0x1A },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "divu",
+        form: MipsInstructionForm::MultiplyDivide { function: 0x1B },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "mfhi",
+        form: MipsInstructionForm::MoveFromHiLo { function: 0x10 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "mflo",
+        form: MipsInstructionForm::MoveFromHiLo { function: 0x12 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "syscall",
+        form: MipsInstructionForm::Syscall,
+    },
+    MipsInstructionDefinition {
+        mnemonic: "add.s",
+        form: MipsInstructionForm::FloatingPointArithmetic { function: 0x00 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "sub.s",
+        form: MipsInstructionForm::FloatingPointArithmetic { function: 0x01 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "mul.s",
+        form: MipsInstructionForm::FloatingPointArithmetic { function: 0x02 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "div.s",
+        form: MipsInstructionForm::FloatingPointArithmetic { function: 0x03 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "mov.s",
+        form: MipsInstructionForm::FloatingPointMove,
+    },
+    MipsInstructionDefinition {
+        mnemonic: "mfc1",
+        form: MipsInstructionForm::FloatingPointTransfer { rs_field: 0x00 },
+    },
+    MipsInstructionDefinition {
+        mnemonic: "mtc1",
+        form: MipsInstructionForm::FloatingPointTransfer { rs_field: 0x04 },
+    },
+];
+
+/// Little-endian MIPS covering the integer, branch, and single-precision FPU forms shared by the PlayStation 2 R5900 and the
+/// PSP Allegrex, plus the R5900 64-bit and 128-bit transfers.
+#[derive(Clone, Debug, Default)]
+pub struct Mips32LeInstructionSet;
+
+impl Mips32LeInstructionSet {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl InstructionSet for Mips32LeInstructionSet {
+    fn get_instruction_set_id(&self) -> &str {
+        "mips32le"
+    }
+
+    fn get_display_name(&self) -> &str {
+        "MIPS32 LE"
+    }
+
+    fn assemble(
+        &self,
+        assembly_source: &str,
+    ) -> Result<Vec<u8>, String> {
+        let parsed_instruction_sequence = parse_instruction_sequence(assembly_source).map_err(|error| error.to_string())?;
+        let label_addresses = parsed_instruction_sequence
+            .label_instruction_indices()
+            .iter()
+            .map(|(label_name, instruction_index)| (label_name.clone(), (*instruction_index as i64) * 4))
+            .collect::<HashMap<_, _>>();
+        let mut instruction_bytes = Vec::with_capacity(parsed_instruction_sequence.instructions().len() * 4);
+
+        for (instruction_index, parsed_instruction) in parsed_instruction_sequence.instructions().iter().enumerate() {
+            let instruction_word = encode_instruction(parsed_instruction, (instruction_index as i64) * 4, &label_addresses)?;
+
+            instruction_bytes.extend_from_slice(&instruction_word.to_le_bytes());
+        }
+
+        Ok(instruction_bytes)
+    }
+
+    fn disassemble(
+        &self,
+        instruction_bytes: &[u8],
+    ) -> Result<String, String> {
+        if instruction_bytes.is_empty() {
+            return Err(String::from("Instruction byte sequence must not be empty."));
+        }
+
+        if !instruction_bytes.len().is_multiple_of(4) {
+            return Err(format!(
+                "Instruction byte sequence length '{}' must be a multiple of 4.",
+                instruction_bytes.len()
+            ));
+        }
+
+        let mut decoded_instructions = Vec::with_capacity(instruction_bytes.len() / 4);
+
+        for (instruction_index, instruction_word_bytes) in instruction_bytes.chunks_exact(4).enumerate() {
+            let instruction_word = u32::from_le_bytes([
+                instruction_word_bytes[0],
+                instruction_word_bytes[1],
+                instruction_word_bytes[2],
+                instruction_word_bytes[3],
+            ]);
+
+            decoded_instructions.push(decode_instruction(instruction_word, (instruction_index as i64) * 4)?);
+        }
+
+        Ok(format_decoded_instruction_sequence(&decoded_instructions, instruction_bytes.len()))
+    }
+
+    fn build_no_operation_fill(
+        &self,
+        byte_count: usize,
+    ) -> Result<Vec<u8>, String> {
+        if byte_count == 0 {
+            return Ok(Vec::new());
+        }
+
+        if !byte_count.is_multiple_of(4) {
+            return Err(String::from("MIPS32 LE no-operation fill requires a byte count aligned to 4 bytes."));
+        }
+
+        Ok(vec![0; byte_count])
+    }
+}
+
+fn find_instruction_definition(mnemonic: &str) -> Option<&'static MipsInstructionDefinition> {
+    MIPS_INSTRUCTION_DEFINITIONS
+        .iter()
+        .find(|instruction_definition| instruction_definition.mnemonic == mnemonic)
+}
+
+fn encode_instruction(
+    parsed_instruction: &ParsedInstruction,
+    current_instruction_address: i64,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<u32, String> {
+    let mnemonic = parsed_instruction.mnemonic();
+    let operands = parsed_instruction.operands();
+
+    match mnemonic {
+        "nop" => {
+            expect_operand_count(operands, 0, mnemonic)?;
+
+            return Ok(0);
+        }
+        "move" => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            return Ok(encode_register_fields(
+                OPCODE_SPECIAL,
+                parse_register_operand(operands, 1)?,
+                REGISTER_ZERO,
+                parse_register_operand(operands, 0)?,
+                0,
+                FUNCTION_ADDU,
+            ));
+        }
+        "li" => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            return encode_load_immediate(parse_register_operand(operands, 0)?, parse_immediate_operand(operands, 1, mnemonic)?);
+        }
+        "b" => {
+            expect_operand_count(operands, 1, mnemonic)?;
+
+            let branch_offset = encode_branch_offset(&operands[0], current_instruction_address, label_addresses)?;
+
+            return Ok(encode_immediate_fields(OPCODE_BEQ, REGISTER_ZERO, REGISTER_ZERO, branch_offset));
+        }
+        "beqz" | "bnez" => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let opcode = if mnemonic == "beqz" { OPCODE_BEQ } else { OPCODE_BNE };
+            let branch_offset = encode_branch_offset(&operands[1], current_instruction_address, label_addresses)?;
+
+            return Ok(encode_immediate_fields(
+                opcode,
+                parse_register_operand(operands, 0)?,
+                REGISTER_ZERO,
+                branch_offset,
+            ));
+        }
+        _ => {}
+    }
+
+    let instruction_definition = find_instruction_definition(mnemonic).ok_or_else(|| format!("Unsupported MIPS mnemonic '{}'.", mnemonic))?;
+
+    match instruction_definition.form {
+        MipsInstructionForm::RegisterThree { function } => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            Ok(encode_register_fields(
+                OPCODE_SPECIAL,
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 2)?,
+                parse_register_operand(operands, 0)?,
+                0,
+                function,
+            ))
+        }
+        MipsInstructionForm::Shift { function } => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            let shift_amount = parse_immediate_operand(operands, 2, mnemonic)?;
+
+            if !(0..=31).contains(&shift_amount) {
+                return Err(format!("MIPS {} shift amount '{}' must be between 0 and 31.", mnemonic, shift_amount));
+            }
+
+            Ok(encode_register_fields(
+                OPCODE_SPECIAL,
+                REGISTER_ZERO,
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 0)?,
+                shift_amount as u32,
+                function,
+            ))
+        }
+        MipsInstructionForm::SignedImmediate { opcode } => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            let immediate_value = parse_signed_immediate_operand(operands, 2, mnemonic)?;
+
+            Ok(encode_immediate_fields(
+                opcode,
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 0)?,
+                immediate_value as u16,
+            ))
+        }
+        MipsInstructionForm::UnsignedImmediate { opcode } => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            let immediate_value = parse_unsigned_immediate_operand(operands, 2, mnemonic)?;
+
+            Ok(encode_immediate_fields(
+                opcode,
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 0)?,
+                immediate_value,
+            ))
+        }
+        MipsInstructionForm::LoadUpperImmediate => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let immediate_value = parse_unsigned_immediate_operand(operands, 1, mnemonic)?;
+
+            Ok(encode_immediate_fields(
+                0x0F,
+                REGISTER_ZERO,
+                parse_register_operand(operands, 0)?,
+                immediate_value,
+            ))
+        }
+        MipsInstructionForm::LoadStore { opcode } => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let (displacement, base_register_index) = parse_memory_operand(operands, 1)?;
+
+            Ok(encode_immediate_fields(
+                opcode,
+                base_register_index,
+                parse_register_operand(operands, 0)?,
+                displacement as u16,
+            ))
+        }
+        MipsInstructionForm::FloatingPointLoadStore { opcode } => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let (displacement, base_register_index) = parse_memory_operand(operands, 1)?;
+
+            Ok(encode_immediate_fields(
+                opcode,
+                base_register_index,
+                parse_floating_point_register_operand(operands, 0)?,
+                displacement as u16,
+            ))
+        }
+        MipsInstructionForm::JumpRegister => {
+            expect_operand_count(operands, 1, mnemonic)?;
+
+            Ok(encode_register_fields(OPCODE_SPECIAL, parse_register_operand(operands, 0)?, 0, 0, 0, 0x08))
+        }
+        MipsInstructionForm::JumpAndLinkRegister => {
+            let (destination_register_index, source_register_index) = match operands.len() {
+                1 => (REGISTER_RA, parse_register_operand(operands, 0)?),
+                2 => (parse_register_operand(operands, 0)?, parse_register_operand(operands, 1)?),
+                _ => return Err(String::from("MIPS jalr requires one or two register operands.")),
+            };
+
+            Ok(encode_register_fields(
+                OPCODE_SPECIAL,
+                source_register_index,
+                0,
+                destination_register_index,
+                0,
+                0x09,
+            ))
+        }
+        MipsInstructionForm::Jump { opcode } => {
+            expect_operand_count(operands, 1, mnemonic)?;
+
+            let target_address = resolve_target_address(&operands[0], label_addresses)?;
+
+            if target_address < 0 || target_address % 4 != 0 || target_address >= 0x1000_0000 {
+                return Err(format!(
+                    "MIPS {} target '{}' must be a 4-byte aligned address within the current 256MB segment.",
+                    mnemonic,
+                    format_signed_hex(target_address)
+                ));
+            }
+
+            Ok((opcode << 26) | ((target_address as u32) >> 2))
+        }
+        MipsInstructionForm::BranchCompare { opcode } => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            let branch_offset = encode_branch_offset(&operands[2], current_instruction_address, label_addresses)?;
+
+            Ok(encode_immediate_fields(
+                opcode,
+                parse_register_operand(operands, 0)?,
+                parse_register_operand(operands, 1)?,
+                branch_offset,
+            ))
+        }
+        MipsInstructionForm::BranchZero { opcode, rt_field } => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let branch_offset = encode_branch_offset(&operands[1], current_instruction_address, label_addresses)?;
+
+            Ok(encode_immediate_fields(
+                opcode,
+                parse_register_operand(operands, 0)?,
+                rt_field as u8,
+                branch_offset,
+            ))
+        }
+        MipsInstructionForm::MultiplyDivide { function } => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            Ok(encode_register_fields(
+                OPCODE_SPECIAL,
+                parse_register_operand(operands, 0)?,
+                parse_register_operand(operands, 1)?,
+                0,
+                0,
+                function,
+            ))
+        }
+        MipsInstructionForm::MoveFromHiLo { function } => {
+            expect_operand_count(operands, 1, mnemonic)?;
+
+            Ok(encode_register_fields(OPCODE_SPECIAL, 0, 0, parse_register_operand(operands, 0)?, 0, function))
+        }
+        MipsInstructionForm::Syscall => {
+            expect_operand_count(operands, 0, mnemonic)?;
+
+            Ok(0x0C)
+        }
+        MipsInstructionForm::FloatingPointArithmetic { function } => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            Ok(encode_register_fields(
+                OPCODE_COP1,
+                COP1_FORMAT_SINGLE as u8,
+                parse_floating_point_register_operand(operands, 2)?,
+                parse_floating_point_register_operand(operands, 1)?,
+                parse_floating_point_register_operand(operands, 0)? as u32,
+                function,
+            ))
+        }
+        MipsInstructionForm::FloatingPointMove => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            Ok(encode_register_fields(
+                OPCODE_COP1,
+                COP1_FORMAT_SINGLE as u8,
+                0,
+                parse_floating_point_register_operand(operands, 1)?,
+                parse_floating_point_register_operand(operands, 0)? as u32,
+                0x06,
+            ))
+        }
+        MipsInstructionForm::FloatingPointTransfer { rs_field } => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            Ok(encode_register_fields(
+                OPCODE_COP1,
+                rs_field as u8,
+                parse_register_operand(operands, 0)?,
+                parse_floating_point_register_operand(operands, 1)?,
+                0,
+                0,
+            ))
+        }
+    }
+}
+
+fn decode_instruction(
+    instruction_word: u32,
+    current_instruction_address: i64,
+) -> Result<DecodedInstruction, String> {
+    let opcode = instruction_word >> 26;
+    let rs = ((instruction_word >> 21) & 0x1F) as u8;
+    let rt = ((instruction_word >> 16) & 0x1F) as u8;
+    let rd = ((instruction_word >> 11) & 0x1F) as u8;
+    let shift_amount = (instruction_word >> 6) & 0x1F;
+    let function = instruction_word & 0x3F;
+    let immediate_value = instruction_word as u16;
+    let branch_target_address = current_instruction_address + 4 + ((immediate_value as i16 as i64) << 2);
+
+    if instruction_word == 0 {
+        return Ok(DecodedInstruction::Plain(String::from("nop")));
+    }
+
+    if opcode == OPCODE_SPECIAL && function == FUNCTION_ADDU && shift_amount == 0 && rt == REGISTER_ZERO {
+        return Ok(DecodedInstruction::Plain(format!("move {}, {}", gpr(rd), gpr(rs))));
+    }
+
+    if opcode == OPCODE_ADDIU && rs == REGISTER_ZERO {
+        return Ok(DecodedInstruction::Plain(format!("li {}, {}", gpr(rt), immediate_value as i16)));
+    }
+
+    if opcode == OPCODE_BEQ && rs == REGISTER_ZERO && rt == REGISTER_ZERO {
+        return Ok(DecodedInstruction::Branch {
+            mnemonic: "b",
+            register_operands: Vec::new(),
+            target_address: branch_target_address,
+        });
+    }
+
+    if (opcode == OPCODE_BEQ || opcode == OPCODE_BNE) && rt == REGISTER_ZERO {
+        return Ok(DecodedInstruction::Branch {
+            mnemonic: if opcode == OPCODE_BEQ { "beqz" } else { "bnez" },
+            register_operands: vec![gpr(rs)],
+            target_address: branch_target_address,
+        });
+    }
+
+    let Some(instruction_definition) = MIPS_INSTRUCTION_DEFINITIONS
+        .iter()
+        .find(|instruction_definition| {
+            let (fixed_bits_mask, fixed_bits_value) = instruction_definition.form.get_fixed_bits();
+
+            instruction_word & fixed_bits_mask == fixed_bits_value
+        })
+    else {
+        return Err(format!("Unsupported MIPS instruction 0x{:08X}.", instruction_word));
+    };
+    let mnemonic = instruction_definition.mnemonic;
+    let fs = rd;
+    let fd = shift_amount as u8;
+
+    let instruction_text = match instruction_definition.form {
+        MipsInstructionForm::RegisterThree { .. } => format!("{} {}, {}, {}", mnemonic, gpr(rd), gpr(rs), gpr(rt)),
+        MipsInstructionForm::Shift { .. } => format!("{} {}, {}, {}", mnemonic, gpr(rd), gpr(rt), shift_amount),
+        MipsInstructionForm::SignedImmediate { .. } => format!("{} {}, {}, {}", mnemonic, gpr(rt), gpr(rs), immediate_value as i16),
+        MipsInstructionForm::UnsignedImmediate { .. } => format!("{} {}, {}, 0x{:X}", mnemonic, gpr(rt), gpr(rs), immediate_value),
+        MipsInstructionForm::LoadUpperImmediate => format!("{} {}, 0x{:X}", mnemonic, gpr(rt), immediate_value),
+        MipsInstructionForm::LoadStore { .. } => format!("{} {}, {}({})", mnemonic, gpr(rt), immediate_value as i16, gpr(rs)),
+        MipsInstructionForm::FloatingPointLoadStore { .. } => format!("{} {}, {}({})", mnemonic, fpr(rt), immediate_value as i16, gpr(rs)),
+        MipsInstructionForm::JumpRegister => format!("{} {}", mnemonic, gpr(rs)),
+        MipsInstructionForm::JumpAndLinkRegister if rd == REGISTER_RA => format!("{} {}", mnemonic, gpr(rs)),
+        MipsInstructionForm::JumpAndLinkRegister => format!("{} {}, {}", mnemonic, gpr(rd), gpr(rs)),
+        MipsInstructionForm::Jump { .. } => {
+            let segment_base_address = (current_instruction_address + 4) & !0x0FFF_FFFF;
+
+            return Ok(DecodedInstruction::Branch {
+                mnemonic,
+                register_operands: Vec::new(),
+                target_address: segment_base_address | (((instruction_word & 0x03FF_FFFF) as i64) << 2),
+            });
+        }
+        MipsInstructionForm::BranchCompare { .. } => {
+            return Ok(DecodedInstruction::Branch {
+                mnemonic,
+                register_operands: vec![gpr(rs), gpr(rt)],
+                target_address: branch_target_address,
+            });
+        }
+        MipsInstructionForm::BranchZero { .. } => {
+            return Ok(DecodedInstruction::Branch {
+                mnemonic,
+                register_operands: vec![gpr(rs)],
+                target_address: branch_target_address,
+            });
+        }
+        MipsInstructionForm::MultiplyDivide { .. } => format!("{} {}, {}", mnemonic, gpr(rs), gpr(rt)),
+        MipsInstructionForm::MoveFromHiLo { .. } => format!("{} {}", mnemonic, gpr(rd)),
+        MipsInstructionForm::Syscall => String::from(mnemonic),
+        MipsInstructionForm::FloatingPointArithmetic { .. } => format!("{} {}, {}, {}", mnemonic, fpr(fd), fpr(fs), fpr(rt)),
+        MipsInstructionForm::FloatingPointMove => format!("{} {}, {}", mnemonic, fpr(fd), fpr(fs)),
+        MipsInstructionForm::FloatingPointTransfer { .. } => format!("{} {}, {}", mnemonic, gpr(rt), fpr(fs)),
+    };
+
+    Ok(DecodedInstruction::Plain(instruction_text))
+}
+
+fn gpr(register_index: u8) -> String {
+    format_mips_general_purpose_register_name(register_index)
+}
+
+fn fpr(register_index: u8) -> String {
+    format_mips_floating_point_register_name(register_index)
+}
+
+fn encode_register_fields(
+    opcode: u32,
+    rs: u8,
+    rt: u8,
+    rd: u8,
+    shift_amount: u32,
+    function: u32,
+) -> u32 {
+    (opcode << 26) | ((rs as u32) << 21) | ((rt as u32) << 16) | ((rd as u32) << 11) | (shift_amount << 6) | function
+}
+
+fn encode_immediate_fields(
+    opcode: u32,
+    rs: u8,
+    rt: u8,
+    immediate_value: u16,
+) -> u32 {
+    (opcode << 26) | ((rs as u32) << 21) | ((rt as u32) << 16) | immediate_value as u32
+}
+
+/// Encodes `li` as a single instruction, using `addiu` for signed 16-bit values and `ori` for unsigned 16-bit values.
+fn encode_load_immediate(
+    register_index: u8,
+    immediate_value: i64,
+) -> Result<u32, String> {
+    if let Ok(signed_immediate_value) = i16::try_from(immediate_value) {
+        return Ok(encode_immediate_fields(
+            OPCODE_ADDIU,
+            REGISTER_ZERO,
+            register_index,
+            signed_immediate_value as u16,
+        ));
+    }
+
+    if let Ok(unsigned_immediate_value) = u16::try_from(immediate_value) {
+        return Ok(encode_immediate_fields(0x0D, REGISTER_ZERO, register_index, unsigned_immediate_value));
+    }
+
+    Err(format!(
+        "MIPS li immediate '{}' does not fit in 16 bits. Use lui and ori to build wider values.",
+        immediate_value
+    ))
+}
+
+fn encode_branch_offset(
+    target_operand: &InstructionOperand,
+    current_instruction_address: i64,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<u16, String> {
+    let target_address = resolve_target_address(target_operand, label_addresses)?;
+    let branch_delta = target_address - (current_instruction_address + 4);
+
+    if branch_delta % 4 != 0 {
+        return Err(format!("MIPS branch target '{}' must be 4-byte aligned.", format_signed_hex(target_address)));
+    }
+
+    i16::try_from(branch_delta >> 2)
+        .map(|branch_offset| branch_offset as u16)
+        .map_err(|_| format!("MIPS branch target '{}' is out of range.", format_signed_hex(target_address)))
+}
+
+fn resolve_target_address(
+    target_operand: &InstructionOperand,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<i64, String> {
+    match target_operand {
+        InstructionOperand::Identifier(label_name) => label_addresses
+            .get(label_name)
+            .copied()
+            .ok_or_else(|| format!("Unknown instruction label '{}'.", label_name)),
+        InstructionOperand::Immediate(immediate_value) => Ok(*immediate_value as i64),
+        unexpected_operand => Err(format!(
+            "Unsupported branch operand '{:?}'. Expected a label or absolute instruction offset.",
+            unexpected_operand
+        )),
+    }
+}
+
+fn expect_operand_count(
+    operands: &[InstructionOperand],
+    expected_operand_count: usize,
+    mnemonic: &str,
+) -> Result<(), String> {
+    if operands.len() != expected_operand_count {
+        return Err(format!(
+            "MIPS {} expects {} operand(s) but found {}.",
+            mnemonic,
+            expected_operand_count,
+            operands.len()
+        ));
+    }
+
+    Ok(())
+}
+
+fn parse_register_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+) -> Result<u8, String> {
+    let register_name = parse_identifier_operand(operands, operand_index)?;
+
+    parse_mips_general_purpose_register_name(register_name).ok_or_else(|| format!("Unsupported MIPS register '{}'.", register_name))
+}
+
+fn parse_floating_point_register_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+) -> Result<u8, String> {
+    let register_name = parse_identifier_operand(operands, operand_index)?;
+
+    parse_mips_floating_point_register_name(register_name).ok_or_else(|| format!("Unsupported MIPS floating-point register '{}'.", register_name))
+}
+
+fn parse_memory_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+) -> Result<(i16, u8), String> {
+    let (displacement, base_register_name) = parse_mips_memory_operand(parse_identifier_operand(operands, operand_index)?)?;
+    let base_register_index =
+        parse_mips_general_purpose_register_name(&base_register_name).ok_or_else(|| format!("Unsupported MIPS base register '{}'.", base_register_name))?;
+
+    Ok((displacement, base_register_index))
+}
+
+fn parse_identifier_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+) -> Result<&str, String> {
+    match operands.get(operand_index) {
+        Some(InstructionOperand::Identifier(identifier)) => Ok(identifier.as_str()),
+        Some(unexpected_operand) => Err(format!(
+            "Expected identifier operand at position '{}' but found '{:?}'.",
+            operand_index, unexpected_operand
+        )),
+        None => Err(format!("Instruction is missing operand '{}'.", operand_index)),
+    }
+}
+
+fn parse_immediate_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+    mnemonic: &str,
+) -> Result<i64, String> {
+    match operands.get(operand_index) {
+        Some(InstructionOperand::Immediate(immediate_value)) => {
+            i64::try_from(*immediate_value).map_err(|_| format!("MIPS {} immediate '{}' is out of range.", mnemonic, immediate_value))
+        }
+        Some(unexpected_operand) => Err(format!(
+            "MIPS {} expected an immediate operand at position '{}' but found '{:?}'.",
+            mnemonic, operand_index, unexpected_operand
+        )),
+        None => Err(format!("MIPS {} is missing operand '{}'.", mnemonic, operand_index)),
+    }
+}
+
+fn parse_signed_immediate_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+    mnemonic: &str,
+) -> Result<i16, String> {
+    let immediate_value = parse_immediate_operand(operands, operand_index, mnemonic)?;
+
+    i16::try_from(immediate_value).map_err(|_| format!("MIPS {} immediate '{}' is out of i16 range.", mnemonic, immediate_value))
+}
+
+fn parse_unsigned_immediate_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+    mnemonic: &str,
+) -> Result<u16, String> {
+    let immediate_value = parse_immediate_operand(operands, operand_index, mnemonic)?;
+
+    u16::try_from(immediate_value).map_err(|_| format!("MIPS {} immediate '{}' is out of u16 range.", mnemonic, immediate_value))
+}
+
+fn format_decoded_instruction_sequence(
+    decoded_instructions: &[DecodedInstruction],
+    total_byte_len: usize,
+) -> String {
+    let mut label_addresses = BTreeSet::new();
+
+    for decoded_instruction in decoded_instructions {
+        if let DecodedInstruction::Branch { target_address, .. } = decoded_instruction
+            && *target_address >= 0
+            && (*target_address as usize) < total_byte_len
+            && (*target_address as usize).is_multiple_of(4)
+        {
+            label_addresses.insert(*target_address as usize);
+        }
+    }
+
+    let label_names = label_addresses
+        .into_iter()
+        .enumerate()
+        .map(|(label_index, label_address)| (label_address, format!("label_{}", label_index)))
+        .collect::<BTreeMap<_, _>>();
+    let mut instruction_texts = Vec::with_capacity(decoded_instructions.len());
+
+    for (instruction_index, decoded_instruction) in decoded_instructions.iter().enumerate() {
+        let instruction_address = instruction_index * 4;
+        let label_prefix = label_names
+            .get(&instruction_address)
+            .map(|label_name| format!("{}: ", label_name))
+            .unwrap_or_default();
+        let instruction_text = match decoded_instruction {
+            DecodedInstruction::Plain(instruction_text) => instruction_text.clone(),
+            DecodedInstruction::Branch {
+                mnemonic,
+                register_operands,
+                target_address,
+            } => {
+                let target_text = label_names
+                    .get(&(*target_address as usize))
+                    .cloned()
+                    .unwrap_or_else(|| format_signed_hex(*target_address));
+                let mut operand_texts = register_operands.clone();
+
+                operand_texts.push(target_text);
+
+                format!("{} {}", mnemonic, operand_texts.join(", "))
+            }
+        };
+
+        instruction_texts.push(format!("{}{}", label_prefix, instruction_text));
+    }
+
+    instruction_texts.join("; ")
+}
+
+fn format_signed_hex(value: i64) -> String {
+    if value < 0 {
+        format!("-0x{:X}", value.unsigned_abs())
+    } else {
+        format!("0x{:X}", value as u64)
+    }
+}
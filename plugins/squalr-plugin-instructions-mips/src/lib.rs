@@ -0,0 +1,110 @@
+mod constants;
+mod data_types;
+mod instruction_set;
+mod mips_memory_operand;
+mod mips_register;
+mod plugin;
+
+pub use constants::{
+    MIPS_FAMILY_DATA_TYPE_IDS, MIPS_FAMILY_INSTRUCTION_SET_IDS, MIPS_FAMILY_PLUGIN_DESCRIPTION, MIPS_FAMILY_PLUGIN_DISPLAY_NAME, MIPS_FAMILY_PLUGIN_ID,
+};
+pub use data_types::DataTypeInstructionMips32Le;
+pub use instruction_set::Mips32LeInstructionSet;
+pub use plugin::MipsFamilyInstructionsPlugin;
+
+#[cfg(test)]
+mod tests {
+    use crate::{DataTypeInstructionMips32Le, Mips32LeInstructionSet, MipsFamilyInstructionsPlugin};
+    use squalr_engine_api::{
+        plugins::{Plugin, PluginCapability, instruction_set::InstructionSet},
+        structures::{
+            data_types::data_type::DataType,
+            data_values::{
+                anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat, container_type::ContainerType,
+            },
+        },
+    };
+
+    fn assemble(assembly_source: &str) -> Vec<u8> {
+        DataTypeInstructionMips32Le::new()
+            .deanonymize_value_string(&AnonymousValueString::new(
+                String::from(assembly_source),
+                AnonymousValueStringFormat::String,
+                ContainerType::None,
+            ))
+            .expect("Expected MIPS assembly text to assemble.")
+            .get_value_bytes()
+            .clone()
+    }
+
+    fn disassemble(instruction_bytes: &[u8]) -> String {
+        DataTypeInstructionMips32Le::new()
+            .anonymize_value_bytes(instruction_bytes, AnonymousValueStringFormat::String)
+            .expect("Expected MIPS bytes to disassemble.")
+            .get_anonymous_value_string()
+            .to_string()
+    }
+
+    #[test]
+    fn i_mips32le_data_type_assembles_li_and_return_sequence() {
+        assert_eq!(assemble("li v0, 5; jr ra"), vec![0x05, 0x00, 0x02, 0x24, 0x08, 0x00, 0xE0, 0x03]);
+    }
+
+    #[test]
+    fn i_mips32le_data_type_accepts_dollar_prefixed_registers() {
+        assert_eq!(assemble("addu $v0, $a0, $5"), assemble("addu v0, a0, a1"));
+        assert_eq!(assemble("sw $ra, 0x1C($sp)"), vec![0x1C, 0x00, 0xBF, 0xAF]);
+    }
+
+    #[test]
+    fn i_mips32le_data_type_supports_label_branches() {
+        assert_eq!(assemble("start: nop; bnez a0, start"), vec![0x00, 0x00, 0x00, 0x00, 0xFE, 0xFF, 0x80, 0x14]);
+    }
+
+    #[test]
+    fn i_mips32le_data_type_disassembles_common_forms() {
+        assert_eq!(
+            disassemble(&[
+                0xF0, 0xFF, 0xBD, 0x27, 0x10, 0x00, 0xA4, 0x8F, 0x40, 0x80, 0x04, 0x3C, 0x21, 0x10, 0x80, 0x00, 0x00, 0x00, 0x00, 0xC6,
+            ]),
+            "addiu sp, sp, -16; lw a0, 16(sp); lui a0, 0x8040; move v0, a0; lwc1 f0, 0(s0)"
+        );
+    }
+
+    #[test]
+    fn i_mips32le_data_type_round_trips_branches_and_floating_point_forms() {
+        let assembly_source = "label_0: add.s f0, f1, f2; mtc1 t0, f3; beq a0, a1, label_0; bgez s0, 0x40; sq ra, -32(sp); syscall";
+
+        assert_eq!(disassemble(&assemble(assembly_source)), assembly_source);
+    }
+
+    #[test]
+    fn i_mips32le_data_type_rejects_unsupported_forms() {
+        let instruction_set = Mips32LeInstructionSet::new();
+
+        assert!(instruction_set.assemble("li v0, 0x12345").is_err());
+        assert!(instruction_set.assemble("addiu v0, v0, 0x8000").is_err());
+        assert!(instruction_set.assemble("lw v0, 8(f0)").is_err());
+        assert!(instruction_set.disassemble(&[0x00, 0x00, 0x00]).is_err());
+        assert_eq!(instruction_set.build_no_operation_fill(8), Ok(vec![0; 8]));
+    }
+
+    #[test]
+    fn plugin_exposes_data_type_and_instruction_set_capabilities() {
+        let plugin = MipsFamilyInstructionsPlugin::new();
+        let expected_default_enablement = cfg!(any(target_arch = "mips", target_arch = "mips64"));
+
+        assert_eq!(plugin.metadata().get_plugin_id(), "builtin.instruction-set.mips-family");
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_capability(PluginCapability::DataType)
+        );
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_capability(PluginCapability::InstructionSet)
+        );
+        assert_eq!(plugin.metadata().get_is_enabled_by_default(), expected_default_enablement);
+    }
+}
@@ -0,0 +1,50 @@
+/// Parses a `displacement(base)` memory operand, returning the signed displacement and the base register text.
+pub fn parse_mips_memory_operand(operand_text: &str) -> Result<(i16, String), String> {
+    let trimmed_operand_text = operand_text.trim();
+    let Some(open_paren_index) = trimmed_operand_text.find('(') else {
+        return Err(format!("MIPS memory operand '{}' is missing '('.", operand_text));
+    };
+    let Some(close_paren_index) = trimmed_operand_text.rfind(')') else {
+        return Err(format!("MIPS memory operand '{}' is missing ')'.", operand_text));
+    };
+
+    if close_paren_index <= open_paren_index || !trimmed_operand_text[close_paren_index + 1..].trim().is_empty() {
+        return Err(format!("MIPS memory operand '{}' is malformed.", operand_text));
+    }
+
+    let displacement_text = trimmed_operand_text[..open_paren_index].trim();
+    let base_register_name = trimmed_operand_text[open_paren_index + 1..close_paren_index].trim();
+    let displacement = if displacement_text.is_empty() {
+        0
+    } else {
+        let displacement = parse_mips_displacement(displacement_text)?;
+
+        i16::try_from(displacement).map_err(|_| format!("MIPS displacement '{}' is out of i16 range.", displacement_text))?
+    };
+
+    if base_register_name.is_empty() {
+        return Err(format!("MIPS memory operand '{}' is missing a base register.", operand_text));
+    }
+
+    Ok((displacement, base_register_name.to_string()))
+}
+
+fn parse_mips_displacement(displacement_text: &str) -> Result<i64, String> {
+    let (sign_multiplier, unsigned_displacement_text) = if let Some(stripped_displacement_text) = displacement_text.strip_prefix('-') {
+        (-1_i64, stripped_displacement_text)
+    } else {
+        (1_i64, displacement_text.strip_prefix('+').unwrap_or(displacement_text))
+    };
+    let parsed_value = if let Some(hexadecimal_digits) = unsigned_displacement_text
+        .strip_prefix("0x")
+        .or_else(|| unsigned_displacement_text.strip_prefix("0X"))
+    {
+        i64::from_str_radix(hexadecimal_digits, 16).map_err(|error| format!("Invalid MIPS hexadecimal displacement '{}': {}.", displacement_text, error))?
+    } else {
+        unsigned_displacement_text
+            .parse::<i64>()
+            .map_err(|error| format!("Invalid MIPS displacement '{}': {}.", displacement_text, error))?
+    };
+
+    Ok(parsed_value * sign_multiplier)
+}
@@ -0,0 +1,56 @@
+const MIPS_GENERAL_PURPOSE_REGISTER_NAMES: [&str; 32] = [
+    "zero", "at", "v0", "v1", "a0", "a1", "a2", "a3", "t0", "t1", "t2", "t3", "t4", "t5", "t6", "t7", "s0", "s1", "s2", "s3", "s4", "s5", "s6", "s7", "t8",
+    "t9", "k0", "k1", "gp", "sp", "fp", "ra",
+];
+
+/// Parses an ABI register name (`t0`, `$sp`) or a numbered register (`$8`). Bare numbers are treated as immediates by the
+/// instruction parser, so numbered registers require the `$` prefix.
+pub fn parse_mips_general_purpose_register_name(register_name: &str) -> Option<u8> {
+    let normalized_register_name = register_name.trim().to_ascii_lowercase();
+    let (has_dollar_prefix, unprefixed_register_name) = match normalized_register_name.strip_prefix('$') {
+        Some(unprefixed_register_name) => (true, unprefixed_register_name),
+        None => (false, normalized_register_name.as_str()),
+    };
+
+    if unprefixed_register_name == "s8" {
+        return Some(30);
+    }
+
+    if let Some(register_index) = MIPS_GENERAL_PURPOSE_REGISTER_NAMES
+        .iter()
+        .position(|abi_register_name| *abi_register_name == unprefixed_register_name)
+    {
+        return Some(register_index as u8);
+    }
+
+    if !has_dollar_prefix {
+        return None;
+    }
+
+    unprefixed_register_name
+        .parse::<u8>()
+        .ok()
+        .filter(|register_index| *register_index <= 31)
+}
+
+pub fn format_mips_general_purpose_register_name(register_index: u8) -> String {
+    MIPS_GENERAL_PURPOSE_REGISTER_NAMES
+        .get(register_index as usize)
+        .map(|abi_register_name| abi_register_name.to_string())
+        .unwrap_or_else(|| format!("${}", register_index))
+}
+
+pub fn parse_mips_floating_point_register_name(register_name: &str) -> Option<u8> {
+    let normalized_register_name = register_name.trim().to_ascii_lowercase();
+
+    normalized_register_name
+        .strip_prefix('$')
+        .unwrap_or(&normalized_register_name)
+        .strip_prefix('f')
+        .and_then(|register_index_text| register_index_text.parse::<u8>().ok())
+        .filter(|register_index| *register_index <= 31)
+}
+
+pub fn format_mips_floating_point_register_name(register_index: u8) -> String {
+    format!("f{}", register_index)
+}
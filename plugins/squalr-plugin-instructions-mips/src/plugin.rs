@@ -0,0 +1,84 @@
+use crate::{
+    DataTypeInstructionMips32Le, Mips32LeInstructionSet,
+    constants::{
+        MIPS_FAMILY_DATA_TYPE_IDS, MIPS_FAMILY_INSTRUCTION_SET_IDS, MIPS_FAMILY_PLUGIN_DESCRIPTION, MIPS_FAMILY_PLUGIN_DISPLAY_NAME, MIPS_FAMILY_PLUGIN_ID,
+    },
+};
+use squalr_engine_api::{
+    plugins::{
+        Plugin, PluginCapability, PluginMetadata, PluginPackage,
+        data_type::DataTypePlugin,
+        instruction_set::{InstructionSet, InstructionSetPlugin},
+    },
+    structures::data_types::data_type::DataType,
+};
+use std::sync::Arc;
+
+pub struct MipsFamilyInstructionsPlugin {
+    metadata: PluginMetadata,
+    contributed_data_types: Vec<Arc<dyn DataType>>,
+    contributed_instruction_sets: Vec<Arc<dyn InstructionSet>>,
+}
+
+impl MipsFamilyInstructionsPlugin {
+    pub fn new() -> Self {
+        Self {
+            metadata: PluginMetadata::new(
+                MIPS_FAMILY_PLUGIN_ID,
+                MIPS_FAMILY_PLUGIN_DISPLAY_NAME,
+                MIPS_FAMILY_PLUGIN_DESCRIPTION,
+                vec![PluginCapability::DataType, PluginCapability::InstructionSet],
+                true,
+                is_enabled_by_default_for_current_target(),
+            ),
+            contributed_data_types: vec![Arc::new(DataTypeInstructionMips32Le::new())],
+            contributed_instruction_sets: vec![Arc::new(Mips32LeInstructionSet::new())],
+        }
+    }
+}
+
+fn is_enabled_by_default_for_current_target() -> bool {
+    cfg!(any(target_arch = "mips", target_arch = "mips64"))
+}
+
+impl Default for MipsFamilyInstructionsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for MipsFamilyInstructionsPlugin {
+    fn metadata(&self) -> &PluginMetadata {
+        &self.metadata
+    }
+}
+
+impl PluginPackage for MipsFamilyInstructionsPlugin {
+    fn as_data_type_plugin(&self) -> Option<&dyn DataTypePlugin> {
+        Some(self)
+    }
+
+    fn as_instruction_set_plugin(&self) -> Option<&dyn InstructionSetPlugin> {
+        Some(self)
+    }
+}
+
+impl DataTypePlugin for MipsFamilyInstructionsPlugin {
+    fn contributed_data_types(&self) -> &[Arc<dyn DataType>] {
+        &self.contributed_data_types
+    }
+
+    fn contributed_data_type_ids(&self) -> &'static [&'static str] {
+        &MIPS_FAMILY_DATA_TYPE_IDS
+    }
+}
+
+impl InstructionSetPlugin for MipsFamilyInstructionsPlugin {
+    fn contributed_instruction_sets(&self) -> &[Arc<dyn InstructionSet>] {
+        &self.contributed_instruction_sets
+    }
+
+    fn contributed_instruction_set_ids(&self) -> &'static [&'static str] {
+        &MIPS_FAMILY_INSTRUCTION_SET_IDS
+    }
+}
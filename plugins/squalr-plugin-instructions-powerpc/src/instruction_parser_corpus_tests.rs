@@ -0,0 +1,1433 @@
+use crate::{DataTypeInstructionPowerPc32Be, PowerPc32BeInstructionSet};
+use squalr_engine_api::{
+    plugins::instruction_set::InstructionSet,
+    structures::{
+        data_types::data_type::DataType,
+        data_values::{anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat, container_type::ContainerType},
+    },
+};
+
+#[derive(Clone, Copy, Debug)]
+struct InstructionParseCase {
+    category: &'static str,
+    source_text: &'static str,
+}
+
+const POWERPC_PARSE_CASES: &[InstructionParseCase] = &[
+    InstructionParseCase {
+        category: "integer-arithmetic",
+        source_text: "add r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic-overflow-record",
+        source_text: "addo. r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic",
+        source_text: "addc r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic",
+        source_text: "adde r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic",
+        source_text: "subf r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic",
+        source_text: "subfc. r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic",
+        source_text: "subfe r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic",
+        source_text: "mullw r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic-overflow",
+        source_text: "divwo r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic",
+        source_text: "divwu r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic-unary",
+        source_text: "neg r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic-unary",
+        source_text: "addze r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic-unary",
+        source_text: "addme r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic-unary",
+        source_text: "subfze r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-arithmetic-unary",
+        source_text: "subfme r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-multiply-high",
+        source_text: "mulhw r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-multiply-high",
+        source_text: "mulhwu. r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-immediate",
+        source_text: "addi r3, r4, -16",
+    },
+    InstructionParseCase {
+        category: "integer-immediate",
+        source_text: "addic r3, r4, 1",
+    },
+    InstructionParseCase {
+        category: "integer-immediate",
+        source_text: "addic. r3, r4, 1",
+    },
+    InstructionParseCase {
+        category: "integer-immediate",
+        source_text: "mulli r3, r4, 100",
+    },
+    InstructionParseCase {
+        category: "integer-immediate",
+        source_text: "subfic r3, r4, 0",
+    },
+    InstructionParseCase {
+        category: "integer-immediate-shifted",
+        source_text: "addis r3, r4, 0x8000",
+    },
+    InstructionParseCase {
+        category: "integer-logical-immediate",
+        source_text: "ori r3, r4, 0xFFFF",
+    },
+    InstructionParseCase {
+        category: "integer-logical-immediate",
+        source_text: "oris r3, r4, 0x8000",
+    },
+    InstructionParseCase {
+        category: "integer-logical-immediate",
+        source_text: "xori r3, r4, 1",
+    },
+    InstructionParseCase {
+        category: "integer-logical-immediate",
+        source_text: "xoris r3, r4, 1",
+    },
+    InstructionParseCase {
+        category: "integer-logical-immediate",
+        source_text: "andi. r3, r4, 0xFF",
+    },
+    InstructionParseCase {
+        category: "integer-logical-immediate",
+        source_text: "andis. r3, r4, 0xFF",
+    },
+    InstructionParseCase {
+        category: "integer-logical-register",
+        source_text: "and r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-logical-register",
+        source_text: "andc r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-logical-register",
+        source_text: "or r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-logical-register",
+        source_text: "orc r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-logical-register",
+        source_text: "xor. r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-logical-register",
+        source_text: "nand r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-logical-register",
+        source_text: "nor r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-logical-register",
+        source_text: "eqv r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-shift-register",
+        source_text: "slw r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-shift-register",
+        source_text: "srw r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-shift-register",
+        source_text: "sraw r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-shift-immediate",
+        source_text: "srawi r3, r4, 2",
+    },
+    InstructionParseCase {
+        category: "integer-logical-unary",
+        source_text: "cntlzw r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-logical-unary",
+        source_text: "extsb. r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-logical-unary",
+        source_text: "extsh r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-rotate",
+        source_text: "rlwinm r3, r4, 4, 8, 27",
+    },
+    InstructionParseCase {
+        category: "integer-rotate",
+        source_text: "rlwimi r3, r4, 16, 0, 15",
+    },
+    InstructionParseCase {
+        category: "integer-rotate",
+        source_text: "rlwnm r3, r4, r5, 0, 15",
+    },
+    InstructionParseCase {
+        category: "integer-compare",
+        source_text: "cmpw r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-compare",
+        source_text: "cmpw cr7, r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-compare",
+        source_text: "cmplw cr1, r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-compare",
+        source_text: "cmpwi r3, -1",
+    },
+    InstructionParseCase {
+        category: "integer-compare",
+        source_text: "cmplwi cr6, r3, 0xFFFF",
+    },
+    InstructionParseCase {
+        category: "integer-trap",
+        source_text: "tw 4, r3, r4",
+    },
+    InstructionParseCase {
+        category: "integer-trap",
+        source_text: "twi 31, r0, 0",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "lwz r3, 0x10(r4)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "lwzu r3, -4(r1)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "lbz r3, 0(r4)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "lbzu r3, 1(r4)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "stw r0, 4(sp)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "stwu sp, -32(sp)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "stb r3, 0(r4)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "stbu r3, 1(r4)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "lhz r3, 2(r4)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "lhzu r3, 2(r4)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "lha r3, 2(r4)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "lhau r3, 2(r4)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "sth r3, 2(r4)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store",
+        source_text: "sthu r3, 2(r4)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-multiple",
+        source_text: "lmw r27, 12(r1)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-multiple",
+        source_text: "stmw r27, 12(r1)",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "lwzx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "lwzux r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "lbzx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "lbzux r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "stwx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "stwux r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "stbx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "stbux r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "lhzx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "lhzux r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "lhax r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "lhaux r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "sthx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-indexed",
+        source_text: "sthux r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-byte-reversed",
+        source_text: "lwbrx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-byte-reversed",
+        source_text: "stwbrx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-byte-reversed",
+        source_text: "lhbrx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-byte-reversed",
+        source_text: "sthbrx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-reserved",
+        source_text: "lwarx r3, r0, r4",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-reserved",
+        source_text: "stwcx. r3, r0, r4",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-string",
+        source_text: "lswi r3, r4, 8",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-string",
+        source_text: "stswi r3, r4, 8",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-string",
+        source_text: "lswx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "integer-load-store-string",
+        source_text: "stswx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "external-control",
+        source_text: "eciwx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "external-control",
+        source_text: "ecowx r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "cache",
+        source_text: "dcbf r3, r4",
+    },
+    InstructionParseCase {
+        category: "cache",
+        source_text: "dcbst r3, r4",
+    },
+    InstructionParseCase {
+        category: "cache",
+        source_text: "dcbt r3, r4",
+    },
+    InstructionParseCase {
+        category: "cache",
+        source_text: "dcbtst r3, r4",
+    },
+    InstructionParseCase {
+        category: "cache",
+        source_text: "dcbz r0, r3",
+    },
+    InstructionParseCase {
+        category: "cache",
+        source_text: "icbi r3, r4",
+    },
+    InstructionParseCase {
+        category: "cache",
+        source_text: "dcbz_l r3, r4",
+    },
+    InstructionParseCase {
+        category: "synchronization",
+        source_text: "sync",
+    },
+    InstructionParseCase {
+        category: "synchronization",
+        source_text: "isync",
+    },
+    InstructionParseCase {
+        category: "synchronization",
+        source_text: "eieio",
+    },
+    InstructionParseCase {
+        category: "system",
+        source_text: "sc",
+    },
+    InstructionParseCase {
+        category: "fp-load-store",
+        source_text: "lfs f1, 8(r3)",
+    },
+    InstructionParseCase {
+        category: "fp-load-store",
+        source_text: "lfsu f1, 8(r3)",
+    },
+    InstructionParseCase {
+        category: "fp-load-store",
+        source_text: "lfd f1, -8(r3)",
+    },
+    InstructionParseCase {
+        category: "fp-load-store",
+        source_text: "lfdu f1, 8(r3)",
+    },
+    InstructionParseCase {
+        category: "fp-load-store",
+        source_text: "stfs f1, 8(r3)",
+    },
+    InstructionParseCase {
+        category: "fp-load-store",
+        source_text: "stfsu f1, 8(r3)",
+    },
+    InstructionParseCase {
+        category: "fp-load-store",
+        source_text: "stfd fr31, 0x18(r1)",
+    },
+    InstructionParseCase {
+        category: "fp-load-store",
+        source_text: "stfdu f1, 8(r3)",
+    },
+    InstructionParseCase {
+        category: "fp-load-store-indexed",
+        source_text: "lfsx f1, r3, r4",
+    },
+    InstructionParseCase {
+        category: "fp-load-store-indexed",
+        source_text: "lfsux f1, r3, r4",
+    },
+    InstructionParseCase {
+        category: "fp-load-store-indexed",
+        source_text: "lfdx f1, r3, r4",
+    },
+    InstructionParseCase {
+        category: "fp-load-store-indexed",
+        source_text: "lfdux f1, r3, r4",
+    },
+    InstructionParseCase {
+        category: "fp-load-store-indexed",
+        source_text: "stfsx f1, r3, r4",
+    },
+    InstructionParseCase {
+        category: "fp-load-store-indexed",
+        source_text: "stfsux f1, r3, r4",
+    },
+    InstructionParseCase {
+        category: "fp-load-store-indexed",
+        source_text: "stfdx f1, r3, r4",
+    },
+    InstructionParseCase {
+        category: "fp-load-store-indexed",
+        source_text: "stfdux f1, r3, r4",
+    },
+    InstructionParseCase {
+        category: "fp-load-store-indexed",
+        source_text: "stfiwx f1, r3, r4",
+    },
+    InstructionParseCase {
+        category: "fp-arithmetic",
+        source_text: "fadd f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "fp-arithmetic",
+        source_text: "fadds. f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "fp-arithmetic",
+        source_text: "fsub f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "fp-arithmetic",
+        source_text: "fsubs f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "fp-arithmetic",
+        source_text: "fdiv f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "fp-arithmetic",
+        source_text: "fdivs f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "fp-arithmetic",
+        source_text: "fmul f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "fp-arithmetic",
+        source_text: "fmuls f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "fp-multiply-add",
+        source_text: "fmadd f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "fp-multiply-add",
+        source_text: "fmadds f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "fp-multiply-add",
+        source_text: "fmsub f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "fp-multiply-add",
+        source_text: "fmsubs f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "fp-multiply-add",
+        source_text: "fnmadd f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "fp-multiply-add",
+        source_text: "fnmadds f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "fp-multiply-add",
+        source_text: "fnmsub f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "fp-multiply-add",
+        source_text: "fnmsubs f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "fp-select",
+        source_text: "fsel f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "fp-unary",
+        source_text: "fmr f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-unary",
+        source_text: "fneg f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-unary",
+        source_text: "fabs f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-unary",
+        source_text: "fnabs f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-unary",
+        source_text: "frsp f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-unary",
+        source_text: "fctiw f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-unary",
+        source_text: "fctiwz f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-unary",
+        source_text: "fres f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-unary",
+        source_text: "frsqrte f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-unary",
+        source_text: "fsqrt f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-unary",
+        source_text: "fsqrts f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-compare",
+        source_text: "fcmpu cr0, f1, f2",
+    },
+    InstructionParseCase {
+        category: "fp-compare",
+        source_text: "fcmpo cr1, f1, f2",
+    },
+    InstructionParseCase {
+        category: "fpscr",
+        source_text: "mffs f1",
+    },
+    InstructionParseCase {
+        category: "fpscr",
+        source_text: "mtfsf 0xFF, f1",
+    },
+    InstructionParseCase {
+        category: "fpscr",
+        source_text: "mtfsfi cr7, 3",
+    },
+    InstructionParseCase {
+        category: "fpscr",
+        source_text: "mtfsb0 30",
+    },
+    InstructionParseCase {
+        category: "fpscr",
+        source_text: "mtfsb1. 31",
+    },
+    InstructionParseCase {
+        category: "fpscr",
+        source_text: "mcrfs cr1, cr2",
+    },
+    InstructionParseCase {
+        category: "paired-single-load-store",
+        source_text: "psq_l f1, 0(r3), 0, 0",
+    },
+    InstructionParseCase {
+        category: "paired-single-load-store",
+        source_text: "psq_lu f1, -8(r3), 1, 2",
+    },
+    InstructionParseCase {
+        category: "paired-single-load-store",
+        source_text: "psq_st f1, 0x7FF(r3), 0, 7",
+    },
+    InstructionParseCase {
+        category: "paired-single-load-store",
+        source_text: "psq_stu f1, -0x800(r3), 1, 5",
+    },
+    InstructionParseCase {
+        category: "paired-single-load-store-indexed",
+        source_text: "psq_lx f1, r3, r4, 0, 0",
+    },
+    InstructionParseCase {
+        category: "paired-single-load-store-indexed",
+        source_text: "psq_lux f1, r3, r4, 1, 3",
+    },
+    InstructionParseCase {
+        category: "paired-single-load-store-indexed",
+        source_text: "psq_stx f1, r3, r4, 0, 6",
+    },
+    InstructionParseCase {
+        category: "paired-single-load-store-indexed",
+        source_text: "psq_stux f1, r3, r4, 1, 7",
+    },
+    InstructionParseCase {
+        category: "paired-single-arithmetic",
+        source_text: "ps_add f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "paired-single-arithmetic",
+        source_text: "ps_sub f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "paired-single-arithmetic",
+        source_text: "ps_div. f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "paired-single-arithmetic",
+        source_text: "ps_mul f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "paired-single-arithmetic",
+        source_text: "ps_muls0 f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "paired-single-arithmetic",
+        source_text: "ps_muls1 f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "paired-single-multiply-add",
+        source_text: "ps_madd f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "paired-single-multiply-add",
+        source_text: "ps_msub f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "paired-single-multiply-add",
+        source_text: "ps_nmadd f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "paired-single-multiply-add",
+        source_text: "ps_nmsub f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "paired-single-multiply-add",
+        source_text: "ps_madds0 f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "paired-single-multiply-add",
+        source_text: "ps_madds1 f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "paired-single-multiply-add",
+        source_text: "ps_sum0 f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "paired-single-multiply-add",
+        source_text: "ps_sum1 f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "paired-single-select",
+        source_text: "ps_sel f1, f2, f3, f4",
+    },
+    InstructionParseCase {
+        category: "paired-single-merge",
+        source_text: "ps_merge00 f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "paired-single-merge",
+        source_text: "ps_merge01 f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "paired-single-merge",
+        source_text: "ps_merge10 f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "paired-single-merge",
+        source_text: "ps_merge11 f1, f2, f3",
+    },
+    InstructionParseCase {
+        category: "paired-single-unary",
+        source_text: "ps_mr f1, f2",
+    },
+    InstructionParseCase {
+        category: "paired-single-unary",
+        source_text: "ps_neg f1, f2",
+    },
+    InstructionParseCase {
+        category: "paired-single-unary",
+        source_text: "ps_abs f1, f2",
+    },
+    InstructionParseCase {
+        category: "paired-single-unary",
+        source_text: "ps_nabs f1, f2",
+    },
+    InstructionParseCase {
+        category: "paired-single-unary",
+        source_text: "ps_res f1, f2",
+    },
+    InstructionParseCase {
+        category: "paired-single-unary",
+        source_text: "ps_rsqrte f1, f2",
+    },
+    InstructionParseCase {
+        category: "paired-single-compare",
+        source_text: "ps_cmpu0 cr0, f1, f2",
+    },
+    InstructionParseCase {
+        category: "paired-single-compare",
+        source_text: "ps_cmpo0 cr1, f1, f2",
+    },
+    InstructionParseCase {
+        category: "paired-single-compare",
+        source_text: "ps_cmpu1 cr2, f1, f2",
+    },
+    InstructionParseCase {
+        category: "paired-single-compare",
+        source_text: "ps_cmpo1 cr3, f1, f2",
+    },
+    InstructionParseCase {
+        category: "condition-register-logical",
+        source_text: "crand 0, 1, 2",
+    },
+    InstructionParseCase {
+        category: "condition-register-logical",
+        source_text: "crandc 0, 1, 2",
+    },
+    InstructionParseCase {
+        category: "condition-register-logical",
+        source_text: "creqv 0, 1, 2",
+    },
+    InstructionParseCase {
+        category: "condition-register-logical",
+        source_text: "crnand 0, 1, 2",
+    },
+    InstructionParseCase {
+        category: "condition-register-logical",
+        source_text: "crnor 0, 1, 2",
+    },
+    InstructionParseCase {
+        category: "condition-register-logical",
+        source_text: "cror 0, 1, 2",
+    },
+    InstructionParseCase {
+        category: "condition-register-logical",
+        source_text: "crorc 0, 1, 2",
+    },
+    InstructionParseCase {
+        category: "condition-register-logical",
+        source_text: "crxor 0, 1, 2",
+    },
+    InstructionParseCase {
+        category: "condition-register-move",
+        source_text: "mcrf cr1, cr0",
+    },
+    InstructionParseCase {
+        category: "condition-register-move",
+        source_text: "mcrxr cr2",
+    },
+    InstructionParseCase {
+        category: "condition-register-move",
+        source_text: "mfcr r3",
+    },
+    InstructionParseCase {
+        category: "condition-register-move",
+        source_text: "mtcrf 0x80, r3",
+    },
+    InstructionParseCase {
+        category: "special-purpose-register",
+        source_text: "mfspr r3, lr",
+    },
+    InstructionParseCase {
+        category: "special-purpose-register",
+        source_text: "mtspr ctr, r3",
+    },
+    InstructionParseCase {
+        category: "special-purpose-register",
+        source_text: "mfspr r3, gqr2",
+    },
+    InstructionParseCase {
+        category: "special-purpose-register",
+        source_text: "mtspr 920, r3",
+    },
+    InstructionParseCase {
+        category: "special-purpose-register",
+        source_text: "mfspr r3, 272",
+    },
+    InstructionParseCase {
+        category: "time-base",
+        source_text: "mftb r3",
+    },
+    InstructionParseCase {
+        category: "time-base",
+        source_text: "mftb r3, 269",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "nop",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "li r3, -1",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "lis r3, 0x8000",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "lis r3, -1",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "mr r3, r4",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "mr. r3, r4",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "not r3, r4",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "sub r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "subo. r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "subc r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "subi r3, r4, 8",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "subis r3, r4, 1",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "subic r3, r4, 1",
+    },
+    InstructionParseCase {
+        category: "simplified-integer",
+        source_text: "subic. r3, r4, 1",
+    },
+    InstructionParseCase {
+        category: "simplified-rotate",
+        source_text: "rotlwi r3, r4, 8",
+    },
+    InstructionParseCase {
+        category: "simplified-rotate",
+        source_text: "rotlw r3, r4, r5",
+    },
+    InstructionParseCase {
+        category: "simplified-rotate",
+        source_text: "slwi r3, r4, 2",
+    },
+    InstructionParseCase {
+        category: "simplified-rotate",
+        source_text: "srwi. r3, r4, 16",
+    },
+    InstructionParseCase {
+        category: "simplified-rotate",
+        source_text: "clrlwi r3, r4, 24",
+    },
+    InstructionParseCase {
+        category: "simplified-rotate",
+        source_text: "clrrwi r3, r4, 2",
+    },
+    InstructionParseCase {
+        category: "simplified-trap",
+        source_text: "trap",
+    },
+    InstructionParseCase {
+        category: "simplified-condition-register",
+        source_text: "crset 6",
+    },
+    InstructionParseCase {
+        category: "simplified-condition-register",
+        source_text: "crclr 6",
+    },
+    InstructionParseCase {
+        category: "simplified-condition-register",
+        source_text: "crmove 4, 8",
+    },
+    InstructionParseCase {
+        category: "simplified-condition-register",
+        source_text: "crnot 4, 8",
+    },
+    InstructionParseCase {
+        category: "simplified-condition-register",
+        source_text: "mtcr r3",
+    },
+    InstructionParseCase {
+        category: "simplified-special-purpose-register",
+        source_text: "mflr r0",
+    },
+    InstructionParseCase {
+        category: "simplified-special-purpose-register",
+        source_text: "mtlr r0",
+    },
+    InstructionParseCase {
+        category: "simplified-special-purpose-register",
+        source_text: "mfctr r12",
+    },
+    InstructionParseCase {
+        category: "simplified-special-purpose-register",
+        source_text: "mtctr r12",
+    },
+    InstructionParseCase {
+        category: "simplified-special-purpose-register",
+        source_text: "mfxer r3",
+    },
+    InstructionParseCase {
+        category: "simplified-special-purpose-register",
+        source_text: "mtxer r3",
+    },
+    InstructionParseCase {
+        category: "simplified-special-purpose-register",
+        source_text: "mftbu r3",
+    },
+    InstructionParseCase {
+        category: "branch",
+        source_text: "start: b start",
+    },
+    InstructionParseCase {
+        category: "branch",
+        source_text: "b 0x100",
+    },
+    InstructionParseCase {
+        category: "branch",
+        source_text: "bl 0x100",
+    },
+    InstructionParseCase {
+        category: "branch-absolute",
+        source_text: "ba 0x100",
+    },
+    InstructionParseCase {
+        category: "branch-absolute",
+        source_text: "bla 0x100",
+    },
+    InstructionParseCase {
+        category: "branch-conditional",
+        source_text: "start: beq start",
+    },
+    InstructionParseCase {
+        category: "branch-conditional",
+        source_text: "bne 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional",
+        source_text: "blt cr1, 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional",
+        source_text: "bge cr7, 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional",
+        source_text: "bgt 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional",
+        source_text: "ble 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional",
+        source_text: "bso 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional",
+        source_text: "bns 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional-alias",
+        source_text: "bnl 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional-alias",
+        source_text: "bng 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional-alias",
+        source_text: "bun 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional-alias",
+        source_text: "bnu 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional-link",
+        source_text: "beql 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional-absolute",
+        source_text: "bnea 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional-absolute",
+        source_text: "bltla 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional-hint",
+        source_text: "beq+ 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional-hint",
+        source_text: "beq- 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-conditional-hint",
+        source_text: "start: bne+ start",
+    },
+    InstructionParseCase {
+        category: "branch-conditional-hint",
+        source_text: "start: bne- start",
+    },
+    InstructionParseCase {
+        category: "branch-counter",
+        source_text: "start: bdnz start",
+    },
+    InstructionParseCase {
+        category: "branch-counter",
+        source_text: "bdz 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-counter",
+        source_text: "bdnz+ 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-to-register",
+        source_text: "blr",
+    },
+    InstructionParseCase {
+        category: "branch-to-register",
+        source_text: "blrl",
+    },
+    InstructionParseCase {
+        category: "branch-to-register",
+        source_text: "bctr",
+    },
+    InstructionParseCase {
+        category: "branch-to-register",
+        source_text: "bctrl",
+    },
+    InstructionParseCase {
+        category: "branch-to-register-conditional",
+        source_text: "beqlr",
+    },
+    InstructionParseCase {
+        category: "branch-to-register-conditional",
+        source_text: "bnelr cr1",
+    },
+    InstructionParseCase {
+        category: "branch-to-register-conditional",
+        source_text: "bltctr",
+    },
+    InstructionParseCase {
+        category: "branch-to-register-conditional",
+        source_text: "bgectrl cr7",
+    },
+    InstructionParseCase {
+        category: "branch-to-register-conditional",
+        source_text: "beqlr+",
+    },
+    InstructionParseCase {
+        category: "branch-to-register-counter",
+        source_text: "bdnzlr",
+    },
+    InstructionParseCase {
+        category: "branch-to-register-counter",
+        source_text: "bdzlrl",
+    },
+    InstructionParseCase {
+        category: "branch-raw",
+        source_text: "bc 12, 2, 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-raw",
+        source_text: "bcl 20, 31, 0x4",
+    },
+    InstructionParseCase {
+        category: "branch-raw",
+        source_text: "bca 0, 0, 0x20",
+    },
+    InstructionParseCase {
+        category: "branch-raw",
+        source_text: "bclr 20, 0",
+    },
+    InstructionParseCase {
+        category: "branch-raw",
+        source_text: "bcctr 4, 10",
+    },
+    InstructionParseCase {
+        category: "branch-raw",
+        source_text: "bclrl 8, 1",
+    },
+    InstructionParseCase {
+        category: "sequence",
+        source_text: "mflr r0; stw r0, 4(r1); stwu r1, -16(r1); bl 0x100; lwz r0, 20(r1); addi r1, r1, 16; mtlr r0; blr",
+    },
+    InstructionParseCase {
+        category: "sequence",
+        source_text: "loop: lbzu r5, 1(r3); cmpwi r5, 0; bne+ loop; blr",
+    },
+    InstructionParseCase {
+        category: "sequence",
+        source_text: "lis r3, 0x8034; ori r3, r3, 0x5678; psq_l f1, 0(r3), 0, 0; ps_add f1, f1, f1; psq_st f1, 0(r3), 0, 0",
+    },
+];
+
+/// Operand field patterns for bits 11 through 25, covering all-clear, mixed, and all-set register and immediate fields.
+const POWERPC_SWEEP_OPERAND_PATTERNS: [u32; 3] = [0x0000_0000, 0x0064_2800, 0x03FF_F800];
+
+#[test]
+fn i_ppc32be_data_type_parses_instruction_form_corpus() {
+    let data_type = DataTypeInstructionPowerPc32Be::new();
+
+    assert_instruction_parse_corpus(&data_type, POWERPC_PARSE_CASES);
+}
+
+#[test]
+fn i_ppc32be_data_type_round_trips_instruction_form_corpus() {
+    let data_type = DataTypeInstructionPowerPc32Be::new();
+
+    assert_instruction_round_trip_corpus(&data_type, POWERPC_PARSE_CASES);
+}
+
+#[test]
+fn i_ppc32be_instruction_set_round_trips_every_decodable_opcode() {
+    let instruction_set = PowerPc32BeInstructionSet::new();
+    let mut failure_messages = Vec::new();
+    let mut decoded_instruction_count = 0usize;
+
+    for opcode in 0..64u32 {
+        for extended_opcode_bits in 0..1024u32 {
+            for record_bit in 0..2u32 {
+                for operand_pattern in POWERPC_SWEEP_OPERAND_PATTERNS {
+                    let instruction_word = (opcode << 26) | operand_pattern | (extended_opcode_bits << 1) | record_bit;
+                    let Ok(disassembly_text) = instruction_set.disassemble(&instruction_word.to_be_bytes()) else {
+                        continue;
+                    };
+
+                    decoded_instruction_count += 1;
+
+                    match instruction_set.assemble(&disassembly_text) {
+                        Ok(reassembled_bytes) if reassembled_bytes == instruction_word.to_be_bytes() => {}
+                        Ok(reassembled_bytes) => failure_messages.push(format!(
+                            "0x{:08X} disassembled to '{}' but reassembled to {:02X?}.",
+                            instruction_word, disassembly_text, reassembled_bytes
+                        )),
+                        Err(assembly_error) => failure_messages.push(format!(
+                            "0x{:08X} disassembled to '{}' but failed to reassemble: {}",
+                            instruction_word, disassembly_text, assembly_error
+                        )),
+                    }
+                }
+            }
+        }
+    }
+
+    assert!(decoded_instruction_count > 0, "Expected the opcode sweep to decode at least one instruction.");
+    assert!(
+        failure_messages.is_empty(),
+        "Opcode sweep round-trip failures ({}):\n{}",
+        failure_messages.len(),
+        failure_messages
+            .iter()
+            .take(64)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+#[test]
+fn i_ppc32be_instruction_set_rejects_invalid_forms() {
+    let instruction_set = PowerPc32BeInstructionSet::new();
+
+    for source_text in [
+        "cmpw. r3, r4",
+        "lwz. r3, 0(r4)",
+        "addio r3, r4, 1",
+        "bdnzctr",
+        "b+ 0x20",
+        "blr+",
+        "addi r3, r4, 0x8000",
+        "psq_l f1, 0x800(r3), 0, 0",
+        "rlwinm r3, r4, 32, 0, 31",
+        "mfspr r3, unknown",
+        "frobnicate r3",
+    ] {
+        assert!(instruction_set.assemble(source_text).is_err(), "Expected '{}' to be rejected.", source_text);
+    }
+
+    assert!(instruction_set.disassemble(&[0x00, 0x00, 0x00, 0x00]).is_err());
+}
+
+#[test]
+fn i_ppc32be_instruction_set_encodes_gekko_paired_single_fields() {
+    let instruction_set = PowerPc32BeInstructionSet::new();
+
+    assert_eq!(
+        instruction_set
+            .assemble("psq_l f1, -8(r3), 1, 2; ps_madd f1, f2, f3, f4; ps_merge10 f5, f6, f7")
+            .expect("Expected paired-single instructions to assemble."),
+        vec![
+            0xE0, 0x23, 0xAF, 0xF8, 0x10, 0x22, 0x20, 0xFA, 0x10, 0xA6, 0x3C, 0xA0
+        ]
+    );
+    assert_eq!(
+        instruction_set
+            .disassemble(&[
+                0xE0, 0x23, 0xAF, 0xF8, 0x10, 0x22, 0x20, 0xFA, 0x10, 0xA6, 0x3C, 0xA0
+            ])
+            .expect("Expected paired-single instructions to disassemble."),
+        "psq_l f1, -8(r3), 1, 2; ps_madd f1, f2, f3, f4; ps_merge10 f5, f6, f7"
+    );
+}
+
+#[test]
+fn i_ppc32be_instruction_set_formats_branch_hints_and_condition_fields() {
+    let instruction_set = PowerPc32BeInstructionSet::new();
+    let source_text = "start: bne+ cr1, end; bdnz start; beqlr cr7; bctrl; end: blr";
+    let assembled_bytes = instruction_set
+        .assemble(source_text)
+        .expect("Expected branch sequence to assemble.");
+
+    assert_eq!(
+        instruction_set
+            .disassemble(&assembled_bytes)
+            .expect("Expected branch sequence to disassemble."),
+        "label_0: bne+ cr1, label_1; bdnz label_0; beqlr cr7; bctrl; label_1: blr"
+    );
+}
+
+fn assert_instruction_parse_corpus(
+    data_type: &dyn DataType,
+    parse_cases: &[InstructionParseCase],
+) {
+    let mut failure_messages = Vec::new();
+
+    for parse_case in parse_cases {
+        let assemble_result = data_type.deanonymize_value_string(&AnonymousValueString::new(
+            String::from(parse_case.source_text),
+            AnonymousValueStringFormat::String,
+            ContainerType::None,
+        ));
+
+        match assemble_result {
+            Ok(data_value) => {
+                if data_value.get_value_bytes().is_empty() {
+                    failure_messages.push(format!(
+                        "[{}] '{}' assembled to an empty byte sequence.",
+                        parse_case.category, parse_case.source_text
+                    ));
+                }
+            }
+            Err(data_type_error) => failure_messages.push(format!(
+                "[{}] '{}' failed to parse: {}",
+                parse_case.category, parse_case.source_text, data_type_error
+            )),
+        }
+    }
+
+    assert!(
+        failure_messages.is_empty(),
+        "Instruction parse corpus failures:\n{}",
+        failure_messages.join("\n")
+    );
+}
+
+fn assert_instruction_round_trip_corpus(
+    data_type: &dyn DataType,
+    round_trip_cases: &[InstructionParseCase],
+) {
+    let mut failure_messages = Vec::new();
+
+    for round_trip_case in round_trip_cases {
+        let assembled_value = match data_type.deanonymize_value_string(&AnonymousValueString::new(
+            String::from(round_trip_case.source_text),
+            AnonymousValueStringFormat::String,
+            ContainerType::None,
+        )) {
+            Ok(assembled_value) => assembled_value,
+            Err(data_type_error) => {
+                failure_messages.push(format!(
+                    "[{}] '{}' failed to assemble before round-trip: {}",
+                    round_trip_case.category, round_trip_case.source_text, data_type_error
+                ));
+
+                continue;
+            }
+        };
+
+        let disassembled_value = match data_type.anonymize_value_bytes(assembled_value.get_value_bytes(), AnonymousValueStringFormat::String) {
+            Ok(disassembled_value) => disassembled_value,
+            Err(data_type_error) => {
+                failure_messages.push(format!(
+                    "[{}] '{}' failed to disassemble after assembly: {}",
+                    round_trip_case.category, round_trip_case.source_text, data_type_error
+                ));
+
+                continue;
+            }
+        };
+
+        let reassembled_value = match data_type.deanonymize_value_string(&AnonymousValueString::new(
+            disassembled_value.get_anonymous_value_string().to_owned(),
+            AnonymousValueStringFormat::String,
+            ContainerType::None,
+        )) {
+            Ok(reassembled_value) => reassembled_value,
+            Err(data_type_error) => {
+                failure_messages.push(format!(
+                    "[{}] '{}' failed to reassemble canonical text '{}': {}",
+                    round_trip_case.category,
+                    round_trip_case.source_text,
+                    disassembled_value.get_anonymous_value_string(),
+                    data_type_error
+                ));
+
+                continue;
+            }
+        };
+
+        if assembled_value.get_value_bytes() != reassembled_value.get_value_bytes() {
+            failure_messages.push(format!(
+                "[{}] '{}' changed bytes after round-trip through '{}'.",
+                round_trip_case.category,
+                round_trip_case.source_text,
+                disassembled_value.get_anonymous_value_string()
+            ));
+        }
+    }
+
+    assert!(
+        failure_messages.is_empty(),
+        "Instruction round-trip corpus failures:\n{}",
+        failure_messages.join("\n")
+    );
+}
@@ -1,16 +1,514 @@
 use crate::{
     powerpc_memory_operand::parse_powerpc_memory_operand,
-    powerpc_register::{format_powerpc_register_name, parse_powerpc_register_name},
+    powerpc_register::{
+        format_powerpc_condition_register_field_name, format_powerpc_floating_point_register_name, format_powerpc_register_name,
+        format_powerpc_special_purpose_register, parse_powerpc_condition_register_field_name, parse_powerpc_floating_point_register_name,
+        parse_powerpc_register_name, parse_powerpc_special_purpose_register_name,
+    },
 };
 use squalr_engine_api::plugins::instruction_set::{InstructionOperand, InstructionSet, ParsedInstruction, parse_instruction_sequence};
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 
+const OPCODE_BRANCH_CONDITIONAL: u32 = 16;
+const OPCODE_BRANCH: u32 = 18;
+const OPCODE_CONDITION_REGISTER: u32 = 19;
+const EXTENDED_OPCODE_BRANCH_TO_LINK_REGISTER: u32 = 16;
+const EXTENDED_OPCODE_BRANCH_TO_COUNT_REGISTER: u32 = 528;
+const BRANCH_OPTIONS_IF_FALSE: u32 = 4;
+const BRANCH_OPTIONS_IF_TRUE: u32 = 12;
+const BRANCH_OPTIONS_DECREMENT_NOT_ZERO: u32 = 16;
+const BRANCH_OPTIONS_DECREMENT_ZERO: u32 = 18;
+const BRANCH_OPTIONS_ALWAYS: u32 = 20;
+const SPECIAL_PURPOSE_REGISTER_XER: u32 = 1;
+const SPECIAL_PURPOSE_REGISTER_LR: u32 = 8;
+const SPECIAL_PURPOSE_REGISTER_CTR: u32 = 9;
+const TIME_BASE_REGISTER_LOWER: u32 = 268;
+const TIME_BASE_REGISTER_UPPER: u32 = 269;
+const TRAP_OPTIONS_UNCONDITIONAL: u32 = 31;
+
+/// Condition names of branches taken when a condition register bit is set, indexed by the bit within its field.
+const BRANCH_IF_TRUE_CONDITION_NAMES: [&str; 4] = ["lt", "gt", "eq", "so"];
+
+/// Condition names of branches taken when a condition register bit is clear, indexed by the bit within its field.
+const BRANCH_IF_FALSE_CONDITION_NAMES: [&str; 4] = ["ge", "le", "ne", "ns"];
+
+/// Conditional branch mnemonic stems, with their branch options and the condition register bit they test. `nl`, `ng`, `un`,
+/// and `nu` are accepted when assembling, but are disassembled as `ge`, `le`, `so`, and `ns`.
+const BRANCH_CONDITIONS: [(&str, u32, Option<u32>); 14] = [
+    ("lt", BRANCH_OPTIONS_IF_TRUE, Some(0)),
+    ("le", BRANCH_OPTIONS_IF_FALSE, Some(1)),
+    ("eq", BRANCH_OPTIONS_IF_TRUE, Some(2)),
+    ("ge", BRANCH_OPTIONS_IF_FALSE, Some(0)),
+    ("gt", BRANCH_OPTIONS_IF_TRUE, Some(1)),
+    ("nl", BRANCH_OPTIONS_IF_FALSE, Some(0)),
+    ("ne", BRANCH_OPTIONS_IF_FALSE, Some(2)),
+    ("ng", BRANCH_OPTIONS_IF_FALSE, Some(1)),
+    ("so", BRANCH_OPTIONS_IF_TRUE, Some(3)),
+    ("ns", BRANCH_OPTIONS_IF_FALSE, Some(3)),
+    ("un", BRANCH_OPTIONS_IF_TRUE, Some(3)),
+    ("nu", BRANCH_OPTIONS_IF_FALSE, Some(3)),
+    ("dnz", BRANCH_OPTIONS_DECREMENT_NOT_ZERO, None),
+    ("dz", BRANCH_OPTIONS_DECREMENT_ZERO, None),
+];
+
+/// Mnemonics that stand in for a more general instruction, such as `mr` for `or` and `slwi` for `rlwinm`.
+const SIMPLIFIED_MNEMONICS: [&str; 29] = [
+    "nop", "li", "lis", "mr", "not", "rotlwi", "slwi", "srwi", "clrlwi", "clrrwi", "rotlw", "sub", "subc", "subi", "subis", "subic", "trap", "crset", "crclr",
+    "crmove", "crnot", "mtcr", "mflr", "mtlr", "mfctr", "mtctr", "mfxer", "mtxer", "mftbu",
+];
+
 #[derive(Clone, Debug)]
 enum DecodedInstruction {
     Plain(String),
-    Branch { mnemonic: &'static str, target_address: i64 },
+    Branch {
+        mnemonic: String,
+        leading_operands: Vec<String>,
+        target_address: i64,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum BranchTarget {
+    Displacement,
+    LinkRegister,
+    CountRegister,
+}
+
+/// A simplified conditional branch mnemonic, such as `beq`, `bdnz+`, `bnelrl`, or `bctr`, broken into its encoding fields.
+#[derive(Clone, Copy, Debug)]
+struct BranchConditionalMnemonic {
+    branch_options: u32,
+    condition_bit: Option<u32>,
+    branch_target: BranchTarget,
+    is_link: bool,
+    is_absolute: bool,
+    prediction_hint: Option<char>,
+}
+
+/// Operand layout of an instruction. Register fields are named after the `D`, `A`, `B`, and `C` fields they occupy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum PowerPcInstructionForm {
+    /// `rD, rA, rB`, with overflow and record variants.
+    IntegerArithmetic,
+    /// `rD, rA`, with overflow and record variants.
+    IntegerArithmeticUnary,
+    /// `rD, rA, rB`, with a record variant.
+    IntegerMultiplyHigh,
+    /// `rD, rA, SIMM`
+    SignedImmediate,
+    /// `rD, rA, SIMM`, where the immediate may also be written as an unsigned 16-bit value.
+    ShiftedImmediate,
+    /// `rA, rS, UIMM`
+    LogicalImmediate,
+    /// `rA, rS, rB`, with a record variant.
+    LogicalRegister,
+    /// `rA, rS`, with a record variant.
+    LogicalUnary,
+    /// `rA, rS, SH`, with a record variant.
+    ShiftImmediate,
+    /// `rA, rS, SH, MB, ME`, with a record variant.
+    RotateImmediate,
+    /// `rA, rS, rB, MB, ME`, with a record variant.
+    RotateRegister,
+    /// `crfD, rA, rB`, where `crfD` defaults to `cr0`.
+    Compare,
+    /// `crfD, rA, SIMM`, where `crfD` defaults to `cr0`.
+    CompareSignedImmediate,
+    /// `crfD, rA, UIMM`, where `crfD` defaults to `cr0`.
+    CompareUnsignedImmediate,
+    /// `TO, rA, rB`
+    Trap,
+    /// `TO, rA, SIMM`
+    TrapImmediate,
+    /// `rD, d(rA)`
+    LoadStore,
+    /// `rD, rA, rB`
+    LoadStoreIndexed,
+    /// `rS, rA, rB`, where the record bit is always set.
+    StoreConditional,
+    /// `rD, rA, NB`
+    LoadStoreString,
+    /// `rA, rB`
+    CacheBlock,
+    /// `frD, d(rA)`
+    FloatingPointLoadStore,
+    /// `frD, rA, rB`
+    FloatingPointLoadStoreIndexed,
+    /// `frD, d(rA), W, I`, where the displacement is 12 bits.
+    PairedSingleLoadStore,
+    /// `frD, rA, rB, W, I`
+    PairedSingleLoadStoreIndexed,
+    /// `frD, frA, frB`, with a record variant.
+    FloatingPointArithmetic,
+    /// `frD, frA, frC`, with a record variant.
+    FloatingPointMultiply,
+    /// `frD, frA, frC, frB`, with a record variant.
+    FloatingPointMultiplyAdd,
+    /// `frD, frB`, with a record variant.
+    FloatingPointUnary,
+    /// `crfD, frA, frB`
+    FloatingPointCompare,
+    /// `frD`, with a record variant.
+    MoveFromFpscr,
+    /// `FM, frB`, with a record variant.
+    MoveToFpscrFields,
+    /// `crfD, IMM`, with a record variant.
+    MoveToFpscrFieldImmediate,
+    /// `crbD`, with a record variant.
+    MoveToFpscrBit,
+    /// `crfD, crfS`
+    ConditionRegisterFieldMove,
+    /// `crbD, crbA, crbB`
+    ConditionRegisterLogical,
+    /// `crfD`
+    MoveToConditionRegisterFromXer,
+    /// `rD`
+    MoveFromConditionRegister,
+    /// `CRM, rS`
+    MoveToConditionRegisterFields,
+    /// `rD, SPR`
+    MoveFromSpecialPurposeRegister,
+    /// `SPR, rS`
+    MoveToSpecialPurposeRegister,
+    /// `rD, TBR`, where `TBR` defaults to the lower time base register.
+    MoveFromTimeBase,
+    /// No operands.
+    NoOperands,
+}
+
+impl PowerPcInstructionForm {
+    /// Returns the mask of the bits outside the primary opcode that identify this form, such as extended opcodes and reserved fields.
+    fn get_fixed_bits_mask(&self) -> u32 {
+        match self {
+            Self::IntegerArithmetic => 0x0000_03FE,
+            Self::IntegerArithmeticUnary => 0x0000_FBFE,
+            Self::IntegerMultiplyHigh | Self::LogicalRegister | Self::ShiftImmediate | Self::FloatingPointArithmetic => 0x0000_07FE,
+            Self::SignedImmediate
+            | Self::ShiftedImmediate
+            | Self::LogicalImmediate
+            | Self::RotateImmediate
+            | Self::RotateRegister
+            | Self::TrapImmediate
+            | Self::LoadStore
+            | Self::FloatingPointLoadStore
+            | Self::PairedSingleLoadStore => 0,
+            Self::LogicalUnary => 0x0000_FFFE,
+            Self::Compare | Self::FloatingPointCompare => 0x0060_07FF,
+            Self::CompareSignedImmediate | Self::CompareUnsignedImmediate => 0x0060_0000,
+            Self::Trap
+            | Self::LoadStoreIndexed
+            | Self::StoreConditional
+            | Self::LoadStoreString
+            | Self::FloatingPointLoadStoreIndexed
+            | Self::ConditionRegisterLogical
+            | Self::MoveFromSpecialPurposeRegister
+            | Self::MoveToSpecialPurposeRegister
+            | Self::MoveFromTimeBase => 0x0000_07FF,
+            Self::CacheBlock => 0x03E0_07FF,
+            Self::PairedSingleLoadStoreIndexed => 0x0000_007F,
+            Self::FloatingPointMultiply => 0x0000_F83E,
+            Self::FloatingPointMultiplyAdd => 0x0000_003E,
+            Self::FloatingPointUnary => 0x001F_07FE,
+            Self::MoveFromFpscr | Self::MoveToFpscrBit => 0x001F_FFFE,
+            Self::MoveToFpscrFields => 0x0201_07FE,
+            Self::MoveToFpscrFieldImmediate => 0x007F_0FFE,
+            Self::ConditionRegisterFieldMove => 0x0063_FFFF,
+            Self::MoveToConditionRegisterFromXer => 0x007F_FFFF,
+            Self::MoveFromConditionRegister => 0x001F_FFFF,
+            Self::MoveToConditionRegisterFields => 0x0010_0FFF,
+            Self::NoOperands => 0x03FF_FFFF,
+        }
+    }
+
+    /// Returns whether the form has a `.` variant that records its result in `cr0` (or `cr1` for floating-point instructions).
+    fn supports_record(&self) -> bool {
+        matches!(
+            self,
+            Self::IntegerArithmetic
+                | Self::IntegerArithmeticUnary
+                | Self::IntegerMultiplyHigh
+                | Self::LogicalRegister
+                | Self::LogicalUnary
+                | Self::ShiftImmediate
+                | Self::RotateImmediate
+                | Self::RotateRegister
+                | Self::FloatingPointArithmetic
+                | Self::FloatingPointMultiply
+                | Self::FloatingPointMultiplyAdd
+                | Self::FloatingPointUnary
+                | Self::MoveFromFpscr
+                | Self::MoveToFpscrFields
+                | Self::MoveToFpscrFieldImmediate
+                | Self::MoveToFpscrBit
+        )
+    }
+
+    /// Returns whether the form has an `o` variant that records overflow in `XER`.
+    fn supports_overflow(&self) -> bool {
+        matches!(self, Self::IntegerArithmetic | Self::IntegerArithmeticUnary)
+    }
+}
+
+struct PowerPcInstructionDefinition {
+    mnemonic: &'static str,
+    opcode: u32,
+    extended_opcode: u32,
+    form: PowerPcInstructionForm,
+}
+
+impl PowerPcInstructionDefinition {
+    const fn new(
+        mnemonic: &'static str,
+        opcode: u32,
+        extended_opcode: u32,
+        form: PowerPcInstructionForm,
+    ) -> Self {
+        Self {
+            mnemonic,
+            opcode,
+            extended_opcode,
+            form,
+        }
+    }
+
+    /// Returns the mask and value of the bits that identify this instruction, with operand fields masked out.
+    fn get_fixed_bits(&self) -> (u32, u32) {
+        let record_bit = (self.form == PowerPcInstructionForm::StoreConditional) as u32;
+
+        (
+            0xFC00_0000 | self.form.get_fixed_bits_mask(),
+            (self.opcode << 26) | (self.extended_opcode << 1) | record_bit,
+        )
+    }
 }
 
+/// 32-bit user-mode instructions, plus the Gekko and Broadway paired-single extensions. Extended opcodes are the values of
+/// bits 1 through 10 (bits 1 through 5 for A-form, 1 through 6 for paired-single indexed, and 1 through 9 for XO-form).
+const POWERPC_INSTRUCTION_DEFINITIONS: &[PowerPcInstructionDefinition] = &[
+    // Integer arithmetic.
+    PowerPcInstructionDefinition::new("add", 31, 266, PowerPcInstructionForm::IntegerArithmetic),
+    PowerPcInstructionDefinition::new("addc", 31, 10, PowerPcInstructionForm::IntegerArithmetic),
+    PowerPcInstructionDefinition::new("adde", 31, 138, PowerPcInstructionForm::IntegerArithmetic),
+    PowerPcInstructionDefinition::new("subf", 31, 40, PowerPcInstructionForm::IntegerArithmetic),
+    PowerPcInstructionDefinition::new("subfc", 31, 8, PowerPcInstructionForm::IntegerArithmetic),
+    PowerPcInstructionDefinition::new("subfe", 31, 136, PowerPcInstructionForm::IntegerArithmetic),
+    PowerPcInstructionDefinition::new("mullw", 31, 235, PowerPcInstructionForm::IntegerArithmetic),
+    PowerPcInstructionDefinition::new("divw", 31, 491, PowerPcInstructionForm::IntegerArithmetic),
+    PowerPcInstructionDefinition::new("divwu", 31, 459, PowerPcInstructionForm::IntegerArithmetic),
+    PowerPcInstructionDefinition::new("neg", 31, 104, PowerPcInstructionForm::IntegerArithmeticUnary),
+    PowerPcInstructionDefinition::new("addme", 31, 234, PowerPcInstructionForm::IntegerArithmeticUnary),
+    PowerPcInstructionDefinition::new("addze", 31, 202, PowerPcInstructionForm::IntegerArithmeticUnary),
+    PowerPcInstructionDefinition::new("subfme", 31, 232, PowerPcInstructionForm::IntegerArithmeticUnary),
+    PowerPcInstructionDefinition::new("subfze", 31, 200, PowerPcInstructionForm::IntegerArithmeticUnary),
+    PowerPcInstructionDefinition::new("mulhw", 31, 75, PowerPcInstructionForm::IntegerMultiplyHigh),
+    PowerPcInstructionDefinition::new("mulhwu", 31, 11, PowerPcInstructionForm::IntegerMultiplyHigh),
+    PowerPcInstructionDefinition::new("addi", 14, 0, PowerPcInstructionForm::SignedImmediate),
+    PowerPcInstructionDefinition::new("addic", 12, 0, PowerPcInstructionForm::SignedImmediate),
+    PowerPcInstructionDefinition::new("addic.", 13, 0, PowerPcInstructionForm::SignedImmediate),
+    PowerPcInstructionDefinition::new("subfic", 8, 0, PowerPcInstructionForm::SignedImmediate),
+    PowerPcInstructionDefinition::new("mulli", 7, 0, PowerPcInstructionForm::SignedImmediate),
+    PowerPcInstructionDefinition::new("addis", 15, 0, PowerPcInstructionForm::ShiftedImmediate),
+    // Integer logical, shift, and rotate.
+    PowerPcInstructionDefinition::new("ori", 24, 0, PowerPcInstructionForm::LogicalImmediate),
+    PowerPcInstructionDefinition::new("oris", 25, 0, PowerPcInstructionForm::LogicalImmediate),
+    PowerPcInstructionDefinition::new("xori", 26, 0, PowerPcInstructionForm::LogicalImmediate),
+    PowerPcInstructionDefinition::new("xoris", 27, 0, PowerPcInstructionForm::LogicalImmediate),
+    PowerPcInstructionDefinition::new("andi.", 28, 0, PowerPcInstructionForm::LogicalImmediate),
+    PowerPcInstructionDefinition::new("andis.", 29, 0, PowerPcInstructionForm::LogicalImmediate),
+    PowerPcInstructionDefinition::new("and", 31, 28, PowerPcInstructionForm::LogicalRegister),
+    PowerPcInstructionDefinition::new("andc", 31, 60, PowerPcInstructionForm::LogicalRegister),
+    PowerPcInstructionDefinition::new("or", 31, 444, PowerPcInstructionForm::LogicalRegister),
+    PowerPcInstructionDefinition::new("orc", 31, 412, PowerPcInstructionForm::LogicalRegister),
+    PowerPcInstructionDefinition::new("xor", 31, 316, PowerPcInstructionForm::LogicalRegister),
+    PowerPcInstructionDefinition::new("nand", 31, 476, PowerPcInstructionForm::LogicalRegister),
+    PowerPcInstructionDefinition::new("nor", 31, 124, PowerPcInstructionForm::LogicalRegister),
+    PowerPcInstructionDefinition::new("eqv", 31, 284, PowerPcInstructionForm::LogicalRegister),
+    PowerPcInstructionDefinition::new("slw", 31, 24, PowerPcInstructionForm::LogicalRegister),
+    PowerPcInstructionDefinition::new("srw", 31, 536, PowerPcInstructionForm::LogicalRegister),
+    PowerPcInstructionDefinition::new("sraw", 31, 792, PowerPcInstructionForm::LogicalRegister),
+    PowerPcInstructionDefinition::new("cntlzw", 31, 26, PowerPcInstructionForm::LogicalUnary),
+    PowerPcInstructionDefinition::new("extsb", 31, 954, PowerPcInstructionForm::LogicalUnary),
+    PowerPcInstructionDefinition::new("extsh", 31, 922, PowerPcInstructionForm::LogicalUnary),
+    PowerPcInstructionDefinition::new("srawi", 31, 824, PowerPcInstructionForm::ShiftImmediate),
+    PowerPcInstructionDefinition::new("rlwimi", 20, 0, PowerPcInstructionForm::RotateImmediate),
+    PowerPcInstructionDefinition::new("rlwinm", 21, 0, PowerPcInstructionForm::RotateImmediate),
+    PowerPcInstructionDefinition::new("rlwnm", 23, 0, PowerPcInstructionForm::RotateRegister),
+    // Integer compare and trap.
+    PowerPcInstructionDefinition::new("cmpw", 31, 0, PowerPcInstructionForm::Compare),
+    PowerPcInstructionDefinition::new("cmplw", 31, 32, PowerPcInstructionForm::Compare),
+    PowerPcInstructionDefinition::new("cmpwi", 11, 0, PowerPcInstructionForm::CompareSignedImmediate),
+    PowerPcInstructionDefinition::new("cmplwi", 10, 0, PowerPcInstructionForm::CompareUnsignedImmediate),
+    PowerPcInstructionDefinition::new("tw", 31, 4, PowerPcInstructionForm::Trap),
+    PowerPcInstructionDefinition::new("twi", 3, 0, PowerPcInstructionForm::TrapImmediate),
+    // Integer loads and stores.
+    PowerPcInstructionDefinition::new("lwz", 32, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("lwzu", 33, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("lbz", 34, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("lbzu", 35, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("stw", 36, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("stwu", 37, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("stb", 38, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("stbu", 39, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("lhz", 40, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("lhzu", 41, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("lha", 42, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("lhau", 43, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("sth", 44, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("sthu", 45, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("lmw", 46, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("stmw", 47, 0, PowerPcInstructionForm::LoadStore),
+    PowerPcInstructionDefinition::new("lwarx", 31, 20, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lwzx", 31, 23, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lwzux", 31, 55, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lbzx", 31, 87, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lbzux", 31, 119, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stwx", 31, 151, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stwux", 31, 183, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stbx", 31, 215, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stbux", 31, 247, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lhzx", 31, 279, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lhzux", 31, 311, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lhax", 31, 343, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lhaux", 31, 375, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("sthx", 31, 407, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("sthux", 31, 439, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lwbrx", 31, 534, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stwbrx", 31, 662, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lhbrx", 31, 790, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("sthbrx", 31, 918, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lswx", 31, 533, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stswx", 31, 661, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("eciwx", 31, 310, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("ecowx", 31, 438, PowerPcInstructionForm::LoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stwcx.", 31, 150, PowerPcInstructionForm::StoreConditional),
+    PowerPcInstructionDefinition::new("lswi", 31, 597, PowerPcInstructionForm::LoadStoreString),
+    PowerPcInstructionDefinition::new("stswi", 31, 725, PowerPcInstructionForm::LoadStoreString),
+    // Cache management and synchronization.
+    PowerPcInstructionDefinition::new("dcbf", 31, 86, PowerPcInstructionForm::CacheBlock),
+    PowerPcInstructionDefinition::new("dcbst", 31, 54, PowerPcInstructionForm::CacheBlock),
+    PowerPcInstructionDefinition::new("dcbt", 31, 278, PowerPcInstructionForm::CacheBlock),
+    PowerPcInstructionDefinition::new("dcbtst", 31, 246, PowerPcInstructionForm::CacheBlock),
+    PowerPcInstructionDefinition::new("dcbz", 31, 1014, PowerPcInstructionForm::CacheBlock),
+    PowerPcInstructionDefinition::new("icbi", 31, 982, PowerPcInstructionForm::CacheBlock),
+    PowerPcInstructionDefinition::new("dcbz_l", 4, 1014, PowerPcInstructionForm::CacheBlock),
+    PowerPcInstructionDefinition::new("sc", 17, 1, PowerPcInstructionForm::NoOperands),
+    PowerPcInstructionDefinition::new("isync", 19, 150, PowerPcInstructionForm::NoOperands),
+    PowerPcInstructionDefinition::new("sync", 31, 598, PowerPcInstructionForm::NoOperands),
+    PowerPcInstructionDefinition::new("eieio", 31, 854, PowerPcInstructionForm::NoOperands),
+    // Condition register and special-purpose register moves.
+    PowerPcInstructionDefinition::new("mcrf", 19, 0, PowerPcInstructionForm::ConditionRegisterFieldMove),
+    PowerPcInstructionDefinition::new("crand", 19, 257, PowerPcInstructionForm::ConditionRegisterLogical),
+    PowerPcInstructionDefinition::new("crandc", 19, 129, PowerPcInstructionForm::ConditionRegisterLogical),
+    PowerPcInstructionDefinition::new("creqv", 19, 289, PowerPcInstructionForm::ConditionRegisterLogical),
+    PowerPcInstructionDefinition::new("crnand", 19, 225, PowerPcInstructionForm::ConditionRegisterLogical),
+    PowerPcInstructionDefinition::new("crnor", 19, 33, PowerPcInstructionForm::ConditionRegisterLogical),
+    PowerPcInstructionDefinition::new("cror", 19, 449, PowerPcInstructionForm::ConditionRegisterLogical),
+    PowerPcInstructionDefinition::new("crorc", 19, 417, PowerPcInstructionForm::ConditionRegisterLogical),
+    PowerPcInstructionDefinition::new("crxor", 19, 193, PowerPcInstructionForm::ConditionRegisterLogical),
+    PowerPcInstructionDefinition::new("mcrxr", 31, 512, PowerPcInstructionForm::MoveToConditionRegisterFromXer),
+    PowerPcInstructionDefinition::new("mfcr", 31, 19, PowerPcInstructionForm::MoveFromConditionRegister),
+    PowerPcInstructionDefinition::new("mtcrf", 31, 144, PowerPcInstructionForm::MoveToConditionRegisterFields),
+    PowerPcInstructionDefinition::new("mfspr", 31, 339, PowerPcInstructionForm::MoveFromSpecialPurposeRegister),
+    PowerPcInstructionDefinition::new("mtspr", 31, 467, PowerPcInstructionForm::MoveToSpecialPurposeRegister),
+    PowerPcInstructionDefinition::new("mftb", 31, 371, PowerPcInstructionForm::MoveFromTimeBase),
+    // Floating-point loads and stores.
+    PowerPcInstructionDefinition::new("lfs", 48, 0, PowerPcInstructionForm::FloatingPointLoadStore),
+    PowerPcInstructionDefinition::new("lfsu", 49, 0, PowerPcInstructionForm::FloatingPointLoadStore),
+    PowerPcInstructionDefinition::new("lfd", 50, 0, PowerPcInstructionForm::FloatingPointLoadStore),
+    PowerPcInstructionDefinition::new("lfdu", 51, 0, PowerPcInstructionForm::FloatingPointLoadStore),
+    PowerPcInstructionDefinition::new("stfs", 52, 0, PowerPcInstructionForm::FloatingPointLoadStore),
+    PowerPcInstructionDefinition::new("stfsu", 53, 0, PowerPcInstructionForm::FloatingPointLoadStore),
+    PowerPcInstructionDefinition::new("stfd", 54, 0, PowerPcInstructionForm::FloatingPointLoadStore),
+    PowerPcInstructionDefinition::new("stfdu", 55, 0, PowerPcInstructionForm::FloatingPointLoadStore),
+    PowerPcInstructionDefinition::new("lfsx", 31, 535, PowerPcInstructionForm::FloatingPointLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lfsux", 31, 567, PowerPcInstructionForm::FloatingPointLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lfdx", 31, 599, PowerPcInstructionForm::FloatingPointLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("lfdux", 31, 631, PowerPcInstructionForm::FloatingPointLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stfsx", 31, 663, PowerPcInstructionForm::FloatingPointLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stfsux", 31, 695, PowerPcInstructionForm::FloatingPointLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stfdx", 31, 727, PowerPcInstructionForm::FloatingPointLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stfdux", 31, 759, PowerPcInstructionForm::FloatingPointLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("stfiwx", 31, 983, PowerPcInstructionForm::FloatingPointLoadStoreIndexed),
+    // Floating-point arithmetic, double precision.
+    PowerPcInstructionDefinition::new("fadd", 63, 21, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("fsub", 63, 20, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("fdiv", 63, 18, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("fmul", 63, 25, PowerPcInstructionForm::FloatingPointMultiply),
+    PowerPcInstructionDefinition::new("fmadd", 63, 29, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("fmsub", 63, 28, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("fnmadd", 63, 31, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("fnmsub", 63, 30, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("fsel", 63, 23, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("fmr", 63, 72, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("fneg", 63, 40, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("fabs", 63, 264, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("fnabs", 63, 136, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("frsp", 63, 12, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("fctiw", 63, 14, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("fctiwz", 63, 15, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("fsqrt", 63, 22, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("frsqrte", 63, 26, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("fcmpu", 63, 0, PowerPcInstructionForm::FloatingPointCompare),
+    PowerPcInstructionDefinition::new("fcmpo", 63, 32, PowerPcInstructionForm::FloatingPointCompare),
+    PowerPcInstructionDefinition::new("mffs", 63, 583, PowerPcInstructionForm::MoveFromFpscr),
+    PowerPcInstructionDefinition::new("mtfsf", 63, 711, PowerPcInstructionForm::MoveToFpscrFields),
+    PowerPcInstructionDefinition::new("mtfsfi", 63, 134, PowerPcInstructionForm::MoveToFpscrFieldImmediate),
+    PowerPcInstructionDefinition::new("mtfsb0", 63, 70, PowerPcInstructionForm::MoveToFpscrBit),
+    PowerPcInstructionDefinition::new("mtfsb1", 63, 38, PowerPcInstructionForm::MoveToFpscrBit),
+    PowerPcInstructionDefinition::new("mcrfs", 63, 64, PowerPcInstructionForm::ConditionRegisterFieldMove),
+    // Floating-point arithmetic, single precision.
+    PowerPcInstructionDefinition::new("fadds", 59, 21, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("fsubs", 59, 20, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("fdivs", 59, 18, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("fmuls", 59, 25, PowerPcInstructionForm::FloatingPointMultiply),
+    PowerPcInstructionDefinition::new("fmadds", 59, 29, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("fmsubs", 59, 28, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("fnmadds", 59, 31, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("fnmsubs", 59, 30, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("fres", 59, 24, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("fsqrts", 59, 22, PowerPcInstructionForm::FloatingPointUnary),
+    // Gekko paired singles.
+    PowerPcInstructionDefinition::new("psq_l", 56, 0, PowerPcInstructionForm::PairedSingleLoadStore),
+    PowerPcInstructionDefinition::new("psq_lu", 57, 0, PowerPcInstructionForm::PairedSingleLoadStore),
+    PowerPcInstructionDefinition::new("psq_st", 60, 0, PowerPcInstructionForm::PairedSingleLoadStore),
+    PowerPcInstructionDefinition::new("psq_stu", 61, 0, PowerPcInstructionForm::PairedSingleLoadStore),
+    PowerPcInstructionDefinition::new("psq_lx", 4, 6, PowerPcInstructionForm::PairedSingleLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("psq_stx", 4, 7, PowerPcInstructionForm::PairedSingleLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("psq_lux", 4, 38, PowerPcInstructionForm::PairedSingleLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("psq_stux", 4, 39, PowerPcInstructionForm::PairedSingleLoadStoreIndexed),
+    PowerPcInstructionDefinition::new("ps_add", 4, 21, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("ps_sub", 4, 20, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("ps_div", 4, 18, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("ps_merge00", 4, 528, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("ps_merge01", 4, 560, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("ps_merge10", 4, 592, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("ps_merge11", 4, 624, PowerPcInstructionForm::FloatingPointArithmetic),
+    PowerPcInstructionDefinition::new("ps_mul", 4, 25, PowerPcInstructionForm::FloatingPointMultiply),
+    PowerPcInstructionDefinition::new("ps_muls0", 4, 12, PowerPcInstructionForm::FloatingPointMultiply),
+    PowerPcInstructionDefinition::new("ps_muls1", 4, 13, PowerPcInstructionForm::FloatingPointMultiply),
+    PowerPcInstructionDefinition::new("ps_madd", 4, 29, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("ps_msub", 4, 28, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("ps_nmadd", 4, 31, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("ps_nmsub", 4, 30, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("ps_madds0", 4, 14, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("ps_madds1", 4, 15, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("ps_sum0", 4, 10, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("ps_sum1", 4, 11, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("ps_sel", 4, 23, PowerPcInstructionForm::FloatingPointMultiplyAdd),
+    PowerPcInstructionDefinition::new("ps_mr", 4, 72, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("ps_neg", 4, 40, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("ps_abs", 4, 264, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("ps_nabs", 4, 136, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("ps_res", 4, 24, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("ps_rsqrte", 4, 26, PowerPcInstructionForm::FloatingPointUnary),
+    PowerPcInstructionDefinition::new("ps_cmpu0", 4, 0, PowerPcInstructionForm::FloatingPointCompare),
+    PowerPcInstructionDefinition::new("ps_cmpo0", 4, 32, PowerPcInstructionForm::FloatingPointCompare),
+    PowerPcInstructionDefinition::new("ps_cmpu1", 4, 64, PowerPcInstructionForm::FloatingPointCompare),
+    PowerPcInstructionDefinition::new("ps_cmpo1", 4, 96, PowerPcInstructionForm::FloatingPointCompare),
+];
+
+/// Big-endian 32-bit PowerPC covering the user-mode integer, floating-point, condition register, and special-purpose
+/// register instructions, plus the Gekko and Broadway paired-single extensions used by GameCube and Wii titles.
 #[derive(Clone, Debug, Default)]
 pub struct PowerPc32BeInstructionSet;
 
@@ -108,168 +606,1452 @@ impl InstructionSet for PowerPc32BeInstructionSet {
     }
 }
 
+fn find_instruction_definition(mnemonic: &str) -> Option<&'static PowerPcInstructionDefinition> {
+    POWERPC_INSTRUCTION_DEFINITIONS
+        .iter()
+        .find(|instruction_definition| instruction_definition.mnemonic == mnemonic)
+}
+
+fn get_instruction_definition(mnemonic: &str) -> Result<&'static PowerPcInstructionDefinition, String> {
+    find_instruction_definition(mnemonic).ok_or_else(|| format!("Missing PowerPC instruction definition for '{}'.", mnemonic))
+}
+
+/// Splits the overflow (`o`) and record (`.`) suffixes from a mnemonic, such as `addo.` into `add`.
+fn split_mnemonic_suffixes(mnemonic: &str) -> (&str, bool, bool) {
+    let (mnemonic, is_record) = match mnemonic.strip_suffix('.') {
+        Some(base_mnemonic) => (base_mnemonic, true),
+        None => (mnemonic, false),
+    };
+
+    if let Some(base_mnemonic) = mnemonic.strip_suffix('o') {
+        let supports_overflow = matches!(base_mnemonic, "sub" | "subc")
+            || find_instruction_definition(base_mnemonic).is_some_and(|instruction_definition| instruction_definition.form.supports_overflow());
+
+        if supports_overflow {
+            return (base_mnemonic, true, is_record);
+        }
+    }
+
+    (mnemonic, false, is_record)
+}
+
 fn encode_instruction(
     parsed_instruction: &ParsedInstruction,
     current_instruction_address: i64,
     label_addresses: &HashMap<String, i64>,
 ) -> Result<u32, String> {
-    match parsed_instruction.mnemonic() {
-        "nop" if parsed_instruction.operands().is_empty() => Ok(0x6000_0000),
-        "blr" if parsed_instruction.operands().is_empty() => Ok(0x4E80_0020),
-        "b" | "bl" => encode_branch(parsed_instruction, current_instruction_address, label_addresses),
-        "li" => encode_li(parsed_instruction),
-        "addi" => encode_addi(parsed_instruction),
-        "lwz" | "stw" => encode_load_store(parsed_instruction),
-        "mr" => encode_mr(parsed_instruction),
-        unsupported_mnemonic => Err(format!("Unsupported PowerPC mnemonic '{}'.", unsupported_mnemonic)),
+    let mnemonic = parsed_instruction.mnemonic();
+    let operands = parsed_instruction.operands();
+
+    if let Some(branch_instruction_word) = encode_branch_instruction(mnemonic, operands, current_instruction_address, label_addresses) {
+        return branch_instruction_word;
     }
-}
 
-fn decode_instruction(
-    instruction_word: u32,
-    current_instruction_address: i64,
-) -> Result<DecodedInstruction, String> {
-    if instruction_word == 0x6000_0000 {
-        return Ok(DecodedInstruction::Plain(String::from("nop")));
+    // Mnemonics such as `andi.` and `stwcx.` are distinct instructions rather than record variants, so they are matched as written.
+    if let Some(instruction_definition) = find_instruction_definition(mnemonic) {
+        return encode_defined_instruction(instruction_definition, mnemonic, operands, false, false);
     }
 
-    if instruction_word == 0x4E80_0020 {
-        return Ok(DecodedInstruction::Plain(String::from("blr")));
+    let (base_mnemonic, is_overflow, is_record) = split_mnemonic_suffixes(mnemonic);
+
+    if SIMPLIFIED_MNEMONICS.contains(&base_mnemonic) {
+        return encode_simplified_instruction(base_mnemonic, mnemonic, operands, is_overflow, is_record);
     }
 
-    let primary_opcode = instruction_word >> 26;
+    let instruction_definition = find_instruction_definition(base_mnemonic)
+        .filter(|_| is_overflow || is_record)
+        .ok_or_else(|| format!("Unsupported PowerPC mnemonic '{}'.", mnemonic))?;
 
-    if primary_opcode == 18 {
-        let branch_delta = sign_extend_branch_delta(instruction_word & 0x03FF_FFFC);
-        let target_address = current_instruction_address + branch_delta;
-        let mnemonic = if (instruction_word & 1) != 0 { "bl" } else { "b" };
+    encode_defined_instruction(instruction_definition, mnemonic, operands, is_overflow, is_record)
+}
 
-        return Ok(DecodedInstruction::Branch { mnemonic, target_address });
+fn encode_defined_instruction(
+    instruction_definition: &PowerPcInstructionDefinition,
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+    is_overflow: bool,
+    is_record: bool,
+) -> Result<u32, String> {
+    if is_overflow && !instruction_definition.form.supports_overflow() {
+        return Err(format!("PowerPC {} does not have an overflow form.", instruction_definition.mnemonic));
     }
 
-    if primary_opcode == 14 {
-        let destination_register_index = ((instruction_word >> 21) & 0x1F) as u8;
-        let left_register_index = ((instruction_word >> 16) & 0x1F) as u8;
-        let immediate_value = instruction_word as i16;
-
-        return if left_register_index == 0 {
-            Ok(DecodedInstruction::Plain(format!(
-                "li {}, {}",
-                format_powerpc_register_name(destination_register_index),
-                immediate_value
-            )))
-        } else {
-            Ok(DecodedInstruction::Plain(format!(
-                "addi {}, {}, {}",
-                format_powerpc_register_name(destination_register_index),
-                format_powerpc_register_name(left_register_index),
-                immediate_value
-            )))
-        };
+    if is_record && !instruction_definition.form.supports_record() {
+        return Err(format!("PowerPC {} does not have a record form.", instruction_definition.mnemonic));
     }
 
-    if primary_opcode == 32 || primary_opcode == 36 {
-        let register_index = ((instruction_word >> 21) & 0x1F) as u8;
-        let base_register_index = ((instruction_word >> 16) & 0x1F) as u8;
-        let displacement = instruction_word as i16;
-        let mnemonic = if primary_opcode == 32 { "lwz" } else { "stw" };
+    let suffix_bits = ((is_overflow as u32) << 10) | is_record as u32;
 
-        return Ok(DecodedInstruction::Plain(format!(
-            "{} {}, {}({})",
-            mnemonic,
-            format_powerpc_register_name(register_index),
-            displacement,
-            format_powerpc_register_name(base_register_index)
-        )));
+    let (d_field, a_field, b_field, low_bits) = match instruction_definition.form {
+        PowerPcInstructionForm::IntegerArithmetic
+        | PowerPcInstructionForm::IntegerMultiplyHigh
+        | PowerPcInstructionForm::LoadStoreIndexed
+        | PowerPcInstructionForm::StoreConditional => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            (
+                parse_register_operand(operands, 0)?,
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 2)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::IntegerArithmeticUnary => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            (parse_register_operand(operands, 0)?, parse_register_operand(operands, 1)?, 0, 0)
+        }
+        PowerPcInstructionForm::SignedImmediate => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            let immediate_value = parse_signed_immediate_operand(operands, 2, mnemonic)?;
+
+            return Ok(encode_immediate_fields(
+                instruction_definition,
+                parse_register_operand(operands, 0)?,
+                parse_register_operand(operands, 1)?,
+                immediate_value,
+            ));
+        }
+        PowerPcInstructionForm::ShiftedImmediate => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            let immediate_value = parse_shifted_immediate_operand(operands, 2, mnemonic)?;
+
+            return Ok(encode_immediate_fields(
+                instruction_definition,
+                parse_register_operand(operands, 0)?,
+                parse_register_operand(operands, 1)?,
+                immediate_value,
+            ));
+        }
+        PowerPcInstructionForm::LogicalImmediate => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            let immediate_value = parse_unsigned_immediate_operand(operands, 2, mnemonic)?;
+
+            return Ok(encode_immediate_fields(
+                instruction_definition,
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 0)?,
+                immediate_value,
+            ));
+        }
+        PowerPcInstructionForm::LogicalRegister => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            (
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 0)?,
+                parse_register_operand(operands, 2)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::LogicalUnary => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            (parse_register_operand(operands, 1)?, parse_register_operand(operands, 0)?, 0, 0)
+        }
+        PowerPcInstructionForm::ShiftImmediate => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            (
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 0)?,
+                parse_field_operand(operands, 2, mnemonic, 31)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::RotateImmediate | PowerPcInstructionForm::RotateRegister => {
+            expect_operand_count(operands, 5, mnemonic)?;
+
+            let shift_field = if instruction_definition.form == PowerPcInstructionForm::RotateImmediate {
+                parse_field_operand(operands, 2, mnemonic, 31)?
+            } else {
+                parse_register_operand(operands, 2)?
+            };
+            let mask_begin = parse_field_operand(operands, 3, mnemonic, 31)?;
+            let mask_end = parse_field_operand(operands, 4, mnemonic, 31)?;
+
+            (
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 0)?,
+                shift_field,
+                (mask_begin << 6) | (mask_end << 1),
+            )
+        }
+        PowerPcInstructionForm::Compare => {
+            let (condition_register_field, operand_offset) = parse_optional_condition_register_field_operand(operands, 2, mnemonic)?;
+
+            (
+                condition_register_field << 2,
+                parse_register_operand(operands, operand_offset)?,
+                parse_register_operand(operands, operand_offset + 1)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::CompareSignedImmediate | PowerPcInstructionForm::CompareUnsignedImmediate => {
+            let (condition_register_field, operand_offset) = parse_optional_condition_register_field_operand(operands, 2, mnemonic)?;
+            let immediate_value = if instruction_definition.form == PowerPcInstructionForm::CompareSignedImmediate {
+                parse_signed_immediate_operand(operands, operand_offset + 1, mnemonic)?
+            } else {
+                parse_unsigned_immediate_operand(operands, operand_offset + 1, mnemonic)?
+            };
+
+            return Ok(encode_immediate_fields(
+                instruction_definition,
+                condition_register_field << 2,
+                parse_register_operand(operands, operand_offset)?,
+                immediate_value,
+            ));
+        }
+        PowerPcInstructionForm::Trap => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            (
+                parse_field_operand(operands, 0, mnemonic, 31)?,
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 2)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::TrapImmediate => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            let immediate_value = parse_signed_immediate_operand(operands, 2, mnemonic)?;
+
+            return Ok(encode_immediate_fields(
+                instruction_definition,
+                parse_field_operand(operands, 0, mnemonic, 31)?,
+                parse_register_operand(operands, 1)?,
+                immediate_value,
+            ));
+        }
+        PowerPcInstructionForm::LoadStore | PowerPcInstructionForm::FloatingPointLoadStore => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let register_index = if instruction_definition.form == PowerPcInstructionForm::LoadStore {
+                parse_register_operand(operands, 0)?
+            } else {
+                parse_floating_point_register_operand(operands, 0)?
+            };
+            let (displacement, base_register_index) = parse_memory_operand(operands, 1)?;
+
+            return Ok(encode_immediate_fields(
+                instruction_definition,
+                register_index,
+                base_register_index,
+                displacement as u16,
+            ));
+        }
+        PowerPcInstructionForm::LoadStoreString => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            (
+                parse_register_operand(operands, 0)?,
+                parse_register_operand(operands, 1)?,
+                parse_field_operand(operands, 2, mnemonic, 31)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::CacheBlock => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            (0, parse_register_operand(operands, 0)?, parse_register_operand(operands, 1)?, 0)
+        }
+        PowerPcInstructionForm::FloatingPointLoadStoreIndexed => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            (
+                parse_floating_point_register_operand(operands, 0)?,
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 2)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::PairedSingleLoadStore => {
+            expect_operand_count(operands, 4, mnemonic)?;
+
+            let (displacement, base_register_index) = parse_memory_operand(operands, 1)?;
+
+            if !(-0x800..=0x7FF).contains(&displacement) {
+                return Err(format!("PowerPC {} displacement '{}' must fit in 12 signed bits.", mnemonic, displacement));
+            }
+
+            let quantization_word_bits = (parse_field_operand(operands, 2, mnemonic, 1)? << 15) | (parse_field_operand(operands, 3, mnemonic, 7)? << 12);
+
+            return Ok(encode_immediate_fields(
+                instruction_definition,
+                parse_floating_point_register_operand(operands, 0)?,
+                base_register_index,
+                ((displacement as u16) & 0x0FFF) | quantization_word_bits as u16,
+            ));
+        }
+        PowerPcInstructionForm::PairedSingleLoadStoreIndexed => {
+            expect_operand_count(operands, 5, mnemonic)?;
+
+            (
+                parse_floating_point_register_operand(operands, 0)?,
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 2)?,
+                (parse_field_operand(operands, 3, mnemonic, 1)? << 10) | (parse_field_operand(operands, 4, mnemonic, 7)? << 7),
+            )
+        }
+        PowerPcInstructionForm::FloatingPointArithmetic => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            (
+                parse_floating_point_register_operand(operands, 0)?,
+                parse_floating_point_register_operand(operands, 1)?,
+                parse_floating_point_register_operand(operands, 2)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::FloatingPointMultiply => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            (
+                parse_floating_point_register_operand(operands, 0)?,
+                parse_floating_point_register_operand(operands, 1)?,
+                0,
+                parse_floating_point_register_operand(operands, 2)? << 6,
+            )
+        }
+        PowerPcInstructionForm::FloatingPointMultiplyAdd => {
+            expect_operand_count(operands, 4, mnemonic)?;
+
+            (
+                parse_floating_point_register_operand(operands, 0)?,
+                parse_floating_point_register_operand(operands, 1)?,
+                parse_floating_point_register_operand(operands, 3)?,
+                parse_floating_point_register_operand(operands, 2)? << 6,
+            )
+        }
+        PowerPcInstructionForm::FloatingPointUnary => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            (
+                parse_floating_point_register_operand(operands, 0)?,
+                0,
+                parse_floating_point_register_operand(operands, 1)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::FloatingPointCompare => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            (
+                parse_condition_register_field_operand(operands, 0)? << 2,
+                parse_floating_point_register_operand(operands, 1)?,
+                parse_floating_point_register_operand(operands, 2)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::MoveFromFpscr => {
+            expect_operand_count(operands, 1, mnemonic)?;
+
+            (parse_floating_point_register_operand(operands, 0)?, 0, 0, 0)
+        }
+        PowerPcInstructionForm::MoveToFpscrFields => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let field_mask = parse_field_operand(operands, 0, mnemonic, 0xFF)?;
+
+            (
+                field_mask >> 4,
+                (field_mask & 0x0F) << 1,
+                parse_floating_point_register_operand(operands, 1)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::MoveToFpscrFieldImmediate => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            (
+                parse_condition_register_field_operand(operands, 0)? << 2,
+                0,
+                parse_field_operand(operands, 1, mnemonic, 0x0F)? << 1,
+                0,
+            )
+        }
+        PowerPcInstructionForm::MoveToFpscrBit => {
+            expect_operand_count(operands, 1, mnemonic)?;
+
+            (parse_field_operand(operands, 0, mnemonic, 31)?, 0, 0, 0)
+        }
+        PowerPcInstructionForm::ConditionRegisterFieldMove => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            (
+                parse_condition_register_field_operand(operands, 0)? << 2,
+                parse_condition_register_field_operand(operands, 1)? << 2,
+                0,
+                0,
+            )
+        }
+        PowerPcInstructionForm::ConditionRegisterLogical => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            (
+                parse_field_operand(operands, 0, mnemonic, 31)?,
+                parse_field_operand(operands, 1, mnemonic, 31)?,
+                parse_field_operand(operands, 2, mnemonic, 31)?,
+                0,
+            )
+        }
+        PowerPcInstructionForm::MoveToConditionRegisterFromXer => {
+            expect_operand_count(operands, 1, mnemonic)?;
+
+            (parse_condition_register_field_operand(operands, 0)? << 2, 0, 0, 0)
+        }
+        PowerPcInstructionForm::MoveFromConditionRegister => {
+            expect_operand_count(operands, 1, mnemonic)?;
+
+            (parse_register_operand(operands, 0)?, 0, 0, 0)
+        }
+        PowerPcInstructionForm::MoveToConditionRegisterFields => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let field_mask = parse_field_operand(operands, 0, mnemonic, 0xFF)?;
+
+            (parse_register_operand(operands, 1)?, field_mask >> 4, (field_mask & 0x0F) << 1, 0)
+        }
+        PowerPcInstructionForm::MoveFromSpecialPurposeRegister => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let (a_field, b_field) = split_special_purpose_register_field(parse_special_purpose_register_operand(operands, 1, mnemonic)?);
+
+            (parse_register_operand(operands, 0)?, a_field, b_field, 0)
+        }
+        PowerPcInstructionForm::MoveToSpecialPurposeRegister => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let (a_field, b_field) = split_special_purpose_register_field(parse_special_purpose_register_operand(operands, 0, mnemonic)?);
+
+            (parse_register_operand(operands, 1)?, a_field, b_field, 0)
+        }
+        PowerPcInstructionForm::MoveFromTimeBase => {
+            let time_base_register = match operands.len() {
+                1 => TIME_BASE_REGISTER_LOWER,
+                2 => parse_field_operand(operands, 1, mnemonic, 0x3FF)?,
+                _ => return Err(format!("PowerPC {} requires one or two operands.", mnemonic)),
+            };
+            let (a_field, b_field) = split_special_purpose_register_field(time_base_register);
+
+            (parse_register_operand(operands, 0)?, a_field, b_field, 0)
+        }
+        PowerPcInstructionForm::NoOperands => {
+            expect_operand_count(operands, 0, mnemonic)?;
+
+            (0, 0, 0, 0)
+        }
+    };
+
+    Ok(encode_instruction_fields(
+        instruction_definition,
+        d_field,
+        a_field,
+        b_field,
+        low_bits | suffix_bits,
+    ))
+}
+
+fn encode_simplified_instruction(
+    base_mnemonic: &str,
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+    is_overflow: bool,
+    is_record: bool,
+) -> Result<u32, String> {
+    let supports_record = matches!(
+        base_mnemonic,
+        "mr" | "not" | "rotlwi" | "slwi" | "srwi" | "clrlwi" | "clrrwi" | "rotlw" | "sub" | "subc" | "subic"
+    );
+
+    if is_record && !supports_record {
+        return Err(format!("PowerPC {} does not have a record form.", base_mnemonic));
     }
 
-    if primary_opcode == 31 {
-        let source_register_index = ((instruction_word >> 21) & 0x1F) as u8;
-        let destination_register_index = ((instruction_word >> 16) & 0x1F) as u8;
-        let right_register_index = ((instruction_word >> 11) & 0x1F) as u8;
-        let extended_opcode = (instruction_word >> 1) & 0x3FF;
+    let record_bit = is_record as u32;
+
+    match base_mnemonic {
+        "nop" => {
+            expect_operand_count(operands, 0, mnemonic)?;
+
+            Ok(encode_immediate_fields(get_instruction_definition("ori")?, 0, 0, 0))
+        }
+        "li" | "lis" => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let (instruction_definition, immediate_value) = if base_mnemonic == "li" {
+                (get_instruction_definition("addi")?, parse_signed_immediate_operand(operands, 1, mnemonic)?)
+            } else {
+                (get_instruction_definition("addis")?, parse_shifted_immediate_operand(operands, 1, mnemonic)?)
+            };
+
+            Ok(encode_immediate_fields(
+                instruction_definition,
+                parse_register_operand(operands, 0)?,
+                0,
+                immediate_value,
+            ))
+        }
+        "mr" | "not" => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let instruction_definition = get_instruction_definition(if base_mnemonic == "mr" { "or" } else { "nor" })?;
+            let source_register_index = parse_register_operand(operands, 1)?;
+
+            Ok(encode_instruction_fields(
+                instruction_definition,
+                source_register_index,
+                parse_register_operand(operands, 0)?,
+                source_register_index,
+                record_bit,
+            ))
+        }
+        "rotlwi" | "slwi" | "srwi" | "clrlwi" | "clrrwi" => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            let bit_count = parse_field_operand(operands, 2, mnemonic, 31)?;
+            let (shift_amount, mask_begin, mask_end) = match base_mnemonic {
+                "rotlwi" => (bit_count, 0, 31),
+                "slwi" => (bit_count, 0, 31 - bit_count),
+                "srwi" => ((32 - bit_count) % 32, bit_count, 31),
+                "clrlwi" => (0, bit_count, 31),
+                _ => (0, 0, 31 - bit_count),
+            };
+
+            Ok(encode_instruction_fields(
+                get_instruction_definition("rlwinm")?,
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 0)?,
+                shift_amount,
+                (mask_begin << 6) | (mask_end << 1) | record_bit,
+            ))
+        }
+        "rotlw" => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            Ok(encode_instruction_fields(
+                get_instruction_definition("rlwnm")?,
+                parse_register_operand(operands, 1)?,
+                parse_register_operand(operands, 0)?,
+                parse_register_operand(operands, 2)?,
+                (31 << 1) | record_bit,
+            ))
+        }
+        "sub" | "subc" => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            let instruction_definition = get_instruction_definition(if base_mnemonic == "sub" { "subf" } else { "subfc" })?;
+
+            Ok(encode_instruction_fields(
+                instruction_definition,
+                parse_register_operand(operands, 0)?,
+                parse_register_operand(operands, 2)?,
+                parse_register_operand(operands, 1)?,
+                ((is_overflow as u32) << 10) | record_bit,
+            ))
+        }
+        "subi" | "subis" | "subic" => {
+            expect_operand_count(operands, 3, mnemonic)?;
+
+            let immediate_value = parse_immediate_operand(operands, 2, mnemonic)?;
+            let negated_immediate_value = immediate_value
+                .checked_neg()
+                .and_then(|negated_immediate_value| i16::try_from(negated_immediate_value).ok())
+                .ok_or_else(|| format!("PowerPC {} immediate '{}' is out of range.", mnemonic, immediate_value))?;
+            let instruction_definition = get_instruction_definition(match (base_mnemonic, is_record) {
+                ("subi", _) => "addi",
+                ("subis", _) => "addis",
+                (_, false) => "addic",
+                (_, true) => "addic.",
+            })?;
+
+            Ok(encode_immediate_fields(
+                instruction_definition,
+                parse_register_operand(operands, 0)?,
+                parse_register_operand(operands, 1)?,
+                negated_immediate_value as u16,
+            ))
+        }
+        "trap" => {
+            expect_operand_count(operands, 0, mnemonic)?;
+
+            Ok(encode_instruction_fields(
+                get_instruction_definition("tw")?,
+                TRAP_OPTIONS_UNCONDITIONAL,
+                0,
+                0,
+                0,
+            ))
+        }
+        "crset" | "crclr" => {
+            expect_operand_count(operands, 1, mnemonic)?;
+
+            let condition_register_bit = parse_field_operand(operands, 0, mnemonic, 31)?;
 
-        if extended_opcode == 444 && source_register_index == right_register_index {
-            return Ok(DecodedInstruction::Plain(format!(
-                "mr {}, {}",
-                format_powerpc_register_name(destination_register_index),
-                format_powerpc_register_name(source_register_index)
-            )));
+            Ok(encode_instruction_fields(
+                get_instruction_definition(if base_mnemonic == "crset" { "creqv" } else { "crxor" })?,
+                condition_register_bit,
+                condition_register_bit,
+                condition_register_bit,
+                0,
+            ))
         }
+        "crmove" | "crnot" => {
+            expect_operand_count(operands, 2, mnemonic)?;
+
+            let source_condition_register_bit = parse_field_operand(operands, 1, mnemonic, 31)?;
+
+            Ok(encode_instruction_fields(
+                get_instruction_definition(if base_mnemonic == "crmove" { "cror" } else { "crnor" })?,
+                parse_field_operand(operands, 0, mnemonic, 31)?,
+                source_condition_register_bit,
+                source_condition_register_bit,
+                0,
+            ))
+        }
+        "mtcr" => {
+            expect_operand_count(operands, 1, mnemonic)?;
+
+            Ok(encode_instruction_fields(
+                get_instruction_definition("mtcrf")?,
+                parse_register_operand(operands, 0)?,
+                0x0F,
+                0x1E,
+                0,
+            ))
+        }
+        "mflr" | "mfctr" | "mfxer" | "mtlr" | "mtctr" | "mtxer" | "mftbu" => {
+            expect_operand_count(operands, 1, mnemonic)?;
+
+            let (instruction_definition, special_purpose_register) = match base_mnemonic {
+                "mflr" => ("mfspr", SPECIAL_PURPOSE_REGISTER_LR),
+                "mfctr" => ("mfspr", SPECIAL_PURPOSE_REGISTER_CTR),
+                "mfxer" => ("mfspr", SPECIAL_PURPOSE_REGISTER_XER),
+                "mtlr" => ("mtspr", SPECIAL_PURPOSE_REGISTER_LR),
+                "mtctr" => ("mtspr", SPECIAL_PURPOSE_REGISTER_CTR),
+                "mtxer" => ("mtspr", SPECIAL_PURPOSE_REGISTER_XER),
+                _ => ("mftb", TIME_BASE_REGISTER_UPPER),
+            };
+            let (a_field, b_field) = split_special_purpose_register_field(special_purpose_register);
+
+            Ok(encode_instruction_fields(
+                get_instruction_definition(instruction_definition)?,
+                parse_register_operand(operands, 0)?,
+                a_field,
+                b_field,
+                0,
+            ))
+        }
+        _ => Err(format!("Unsupported PowerPC mnemonic '{}'.", mnemonic)),
     }
+}
 
-    Err(format!("Unsupported PowerPC instruction 0x{:08X}.", instruction_word))
+/// Encodes `b`, `bc`, and `bclr`/`bcctr` along with their simplified mnemonics, returning `None` for non-branch mnemonics.
+fn encode_branch_instruction(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+    current_instruction_address: i64,
+    label_addresses: &HashMap<String, i64>,
+) -> Option<Result<u32, String>> {
+    match mnemonic {
+        "b" | "bl" | "ba" | "bla" => Some(encode_branch(mnemonic, operands, current_instruction_address, label_addresses)),
+        "bc" | "bcl" | "bca" | "bcla" => Some(encode_raw_branch_conditional(mnemonic, operands, current_instruction_address, label_addresses)),
+        "bclr" | "bclrl" | "bcctr" | "bcctrl" => Some(encode_raw_branch_to_register(mnemonic, operands)),
+        _ => parse_branch_conditional_mnemonic(mnemonic).map(|branch_conditional_mnemonic| {
+            encode_branch_conditional(&branch_conditional_mnemonic, mnemonic, operands, current_instruction_address, label_addresses)
+        }),
+    }
 }
 
 fn encode_branch(
-    parsed_instruction: &ParsedInstruction,
+    mnemonic: &str,
+    operands: &[InstructionOperand],
     current_instruction_address: i64,
     label_addresses: &HashMap<String, i64>,
 ) -> Result<u32, String> {
-    let target_address = resolve_branch_target_address(parsed_instruction.operands(), label_addresses)?;
-    let branch_delta = target_address - current_instruction_address;
+    if operands.len() != 1 {
+        return Err(String::from("PowerPC branch instructions require exactly one target operand."));
+    }
+
+    let target_address = resolve_target_address(&operands[0], label_addresses)?;
+    let is_absolute = mnemonic.ends_with('a');
+    let branch_delta = if is_absolute {
+        target_address
+    } else {
+        target_address - current_instruction_address
+    };
 
     if branch_delta % 4 != 0 {
         return Err(format!("PowerPC branch target '{}' must be 4-byte aligned.", format_signed_hex(target_address)));
     }
 
-    if !(-0x20_00000..=0x1F_FFFFC).contains(&branch_delta) {
+    if !(-0x200_0000..=0x1FF_FFFC).contains(&branch_delta) {
         return Err(format!(
             "PowerPC branch target '{}' is out of range for the current relative branch encoding.",
             format_signed_hex(target_address)
         ));
     }
 
-    let branch_opcode = if parsed_instruction.mnemonic() == "bl" { 0x4800_0001 } else { 0x4800_0000 };
+    Ok((OPCODE_BRANCH << 26) | ((branch_delta as i32 as u32) & 0x03FF_FFFC) | ((is_absolute as u32) << 1) | (mnemonic.starts_with("bl") as u32))
+}
 
-    Ok(branch_opcode | ((branch_delta as i32 as u32) & 0x03FF_FFFC))
+/// Encodes `bc` with explicit branch options and condition register bit operands.
+fn encode_raw_branch_conditional(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+    current_instruction_address: i64,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<u32, String> {
+    expect_operand_count(operands, 3, mnemonic)?;
+
+    let is_absolute = mnemonic.ends_with('a');
+    let branch_displacement = resolve_branch_conditional_displacement(&operands[2], is_absolute, current_instruction_address, label_addresses)?;
+
+    Ok(encode_branch_conditional_fields(
+        parse_field_operand(operands, 0, mnemonic, 31)?,
+        parse_field_operand(operands, 1, mnemonic, 31)?,
+        branch_displacement,
+        is_absolute,
+        mnemonic.starts_with("bcl"),
+    ))
 }
 
-fn encode_li(parsed_instruction: &ParsedInstruction) -> Result<u32, String> {
-    let destination_register_index = parse_register_operand(parsed_instruction.operands(), 0)?;
-    let immediate_value = parse_i16_immediate_operand(parsed_instruction.operands(), 1, "li")?;
+/// Encodes `bclr` and `bcctr` with explicit branch options and condition register bit operands.
+fn encode_raw_branch_to_register(
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+) -> Result<u32, String> {
+    expect_operand_count(operands, 2, mnemonic)?;
+
+    let branch_target = if mnemonic.starts_with("bclr") {
+        BranchTarget::LinkRegister
+    } else {
+        BranchTarget::CountRegister
+    };
 
-    Ok(0x3800_0000 | ((destination_register_index as u32) << 21) | ((immediate_value as u16) as u32))
+    Ok(encode_branch_to_register_fields(
+        parse_field_operand(operands, 0, mnemonic, 31)?,
+        parse_field_operand(operands, 1, mnemonic, 31)?,
+        branch_target,
+        mnemonic.ends_with('l'),
+    ))
 }
 
-fn encode_addi(parsed_instruction: &ParsedInstruction) -> Result<u32, String> {
-    let destination_register_index = parse_register_operand(parsed_instruction.operands(), 0)?;
-    let left_register_index = parse_register_operand(parsed_instruction.operands(), 1)?;
-    let immediate_value = parse_i16_immediate_operand(parsed_instruction.operands(), 2, "addi")?;
+/// Breaks a simplified conditional branch mnemonic into its encoding fields, returning `None` if it is not one.
+fn parse_branch_conditional_mnemonic(mnemonic: &str) -> Option<BranchConditionalMnemonic> {
+    let (mnemonic_body, prediction_hint) = match mnemonic.strip_suffix('+') {
+        Some(mnemonic_body) => (mnemonic_body, Some('+')),
+        None => match mnemonic.strip_suffix('-') {
+            Some(mnemonic_body) => (mnemonic_body, Some('-')),
+            None => (mnemonic, None),
+        },
+    };
+    let condition_text = mnemonic_body.strip_prefix('b')?;
+    let (branch_options, condition_bit, suffix_text) = match condition_text {
+        "lr" | "lrl" | "ctr" | "ctrl" => (BRANCH_OPTIONS_ALWAYS, None, condition_text),
+        _ => BRANCH_CONDITIONS
+            .iter()
+            .find_map(|(condition_name, branch_options, condition_bit)| {
+                condition_text
+                    .strip_prefix(condition_name)
+                    .map(|suffix_text| (*branch_options, *condition_bit, suffix_text))
+            })?,
+    };
+    let (branch_target, is_link, is_absolute) = match suffix_text {
+        "" => (BranchTarget::Displacement, false, false),
+        "l" => (BranchTarget::Displacement, true, false),
+        "a" => (BranchTarget::Displacement, false, true),
+        "la" => (BranchTarget::Displacement, true, true),
+        "lr" => (BranchTarget::LinkRegister, false, false),
+        "lrl" => (BranchTarget::LinkRegister, true, false),
+        "ctr" => (BranchTarget::CountRegister, false, false),
+        "ctrl" => (BranchTarget::CountRegister, true, false),
+        _ => return None,
+    };
+
+    // Branching to the count register while decrementing it is an invalid form.
+    if branch_target == BranchTarget::CountRegister && condition_bit.is_none() && branch_options != BRANCH_OPTIONS_ALWAYS {
+        return None;
+    }
 
-    Ok(0x3800_0000 | ((destination_register_index as u32) << 21) | ((left_register_index as u32) << 16) | ((immediate_value as u16) as u32))
+    Some(BranchConditionalMnemonic {
+        branch_options,
+        condition_bit,
+        branch_target,
+        is_link,
+        is_absolute,
+        prediction_hint,
+    })
 }
 
-fn encode_load_store(parsed_instruction: &ParsedInstruction) -> Result<u32, String> {
-    let register_index = parse_register_operand(parsed_instruction.operands(), 0)?;
-    let (displacement, base_register_name) = parse_powerpc_memory_operand(parse_identifier_operand(parsed_instruction.operands(), 1)?)?;
-    let base_register_index =
-        parse_powerpc_register_name(&base_register_name).ok_or_else(|| format!("Unsupported PowerPC base register '{}'.", base_register_name))?;
-    let instruction_base = if parsed_instruction.mnemonic() == "lwz" { 0x8000_0000 } else { 0x9000_0000 };
+fn encode_branch_conditional(
+    branch_conditional_mnemonic: &BranchConditionalMnemonic,
+    mnemonic: &str,
+    operands: &[InstructionOperand],
+    current_instruction_address: i64,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<u32, String> {
+    let target_operand_count = if branch_conditional_mnemonic.branch_target == BranchTarget::Displacement {
+        1
+    } else {
+        0
+    };
+    let (condition_register_field, operand_offset) = match branch_conditional_mnemonic.condition_bit {
+        Some(_) => parse_optional_condition_register_field_operand(operands, target_operand_count, mnemonic)?,
+        None => {
+            expect_operand_count(operands, target_operand_count, mnemonic)?;
+
+            (0, 0)
+        }
+    };
+    let condition_register_bit = branch_conditional_mnemonic
+        .condition_bit
+        .map(|condition_bit| condition_register_field * 4 + condition_bit)
+        .unwrap_or(0);
+
+    if branch_conditional_mnemonic.branch_target != BranchTarget::Displacement {
+        let branch_options = apply_branch_prediction_hint(
+            branch_conditional_mnemonic.branch_options,
+            branch_conditional_mnemonic.prediction_hint,
+            false,
+            mnemonic,
+        )?;
+
+        return Ok(encode_branch_to_register_fields(
+            branch_options,
+            condition_register_bit,
+            branch_conditional_mnemonic.branch_target,
+            branch_conditional_mnemonic.is_link,
+        ));
+    }
+
+    let branch_displacement = resolve_branch_conditional_displacement(
+        &operands[operand_offset],
+        branch_conditional_mnemonic.is_absolute,
+        current_instruction_address,
+        label_addresses,
+    )?;
+    let branch_options = apply_branch_prediction_hint(
+        branch_conditional_mnemonic.branch_options,
+        branch_conditional_mnemonic.prediction_hint,
+        branch_displacement < 0,
+        mnemonic,
+    )?;
+
+    Ok(encode_branch_conditional_fields(
+        branch_options,
+        condition_register_bit,
+        branch_displacement,
+        branch_conditional_mnemonic.is_absolute,
+        branch_conditional_mnemonic.is_link,
+    ))
+}
+
+/// Applies a `+` (likely taken) or `-` (likely not taken) hint. Backward branches are predicted taken and all others are
+/// predicted not taken by default, so the hint bit is only set when the hint reverses that default.
+fn apply_branch_prediction_hint(
+    branch_options: u32,
+    prediction_hint: Option<char>,
+    is_backward_branch: bool,
+    mnemonic: &str,
+) -> Result<u32, String> {
+    let Some(prediction_hint) = prediction_hint else {
+        return Ok(branch_options);
+    };
+
+    if branch_options == BRANCH_OPTIONS_ALWAYS {
+        return Err(format!("PowerPC {} is unconditional and cannot take a prediction hint.", mnemonic));
+    }
+
+    let reverses_default_prediction = (prediction_hint == '+') != is_backward_branch;
+
+    Ok(branch_options | reverses_default_prediction as u32)
+}
+
+fn resolve_branch_conditional_displacement(
+    target_operand: &InstructionOperand,
+    is_absolute: bool,
+    current_instruction_address: i64,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<i64, String> {
+    let target_address = resolve_target_address(target_operand, label_addresses)?;
+    let branch_displacement = if is_absolute {
+        target_address
+    } else {
+        target_address - current_instruction_address
+    };
 
-    Ok(instruction_base | ((register_index as u32) << 21) | ((base_register_index as u32) << 16) | ((displacement as u16) as u32))
+    if branch_displacement % 4 != 0 {
+        return Err(format!("PowerPC branch target '{}' must be 4-byte aligned.", format_signed_hex(target_address)));
+    }
+
+    if !(-0x8000..=0x7FFC).contains(&branch_displacement) {
+        return Err(format!(
+            "PowerPC conditional branch target '{}' is out of range.",
+            format_signed_hex(target_address)
+        ));
+    }
+
+    Ok(branch_displacement)
+}
+
+fn encode_branch_conditional_fields(
+    branch_options: u32,
+    condition_register_bit: u32,
+    branch_displacement: i64,
+    is_absolute: bool,
+    is_link: bool,
+) -> u32 {
+    (OPCODE_BRANCH_CONDITIONAL << 26)
+        | (branch_options << 21)
+        | (condition_register_bit << 16)
+        | ((branch_displacement as i32 as u32) & 0xFFFC)
+        | ((is_absolute as u32) << 1)
+        | is_link as u32
+}
+
+fn encode_branch_to_register_fields(
+    branch_options: u32,
+    condition_register_bit: u32,
+    branch_target: BranchTarget,
+    is_link: bool,
+) -> u32 {
+    let extended_opcode = if branch_target == BranchTarget::LinkRegister {
+        EXTENDED_OPCODE_BRANCH_TO_LINK_REGISTER
+    } else {
+        EXTENDED_OPCODE_BRANCH_TO_COUNT_REGISTER
+    };
+
+    (OPCODE_CONDITION_REGISTER << 26) | (branch_options << 21) | (condition_register_bit << 16) | (extended_opcode << 1) | is_link as u32
 }
 
-fn encode_mr(parsed_instruction: &ParsedInstruction) -> Result<u32, String> {
-    let destination_register_index = parse_register_operand(parsed_instruction.operands(), 0)?;
-    let source_register_index = parse_register_operand(parsed_instruction.operands(), 1)?;
+fn encode_instruction_fields(
+    instruction_definition: &PowerPcInstructionDefinition,
+    d_field: u32,
+    a_field: u32,
+    b_field: u32,
+    low_bits: u32,
+) -> u32 {
+    let (_, fixed_bits_value) = instruction_definition.get_fixed_bits();
 
-    Ok(0x7C00_0378 | ((source_register_index as u32) << 21) | ((destination_register_index as u32) << 16) | ((source_register_index as u32) << 11))
+    fixed_bits_value | (d_field << 21) | (a_field << 16) | (b_field << 11) | low_bits
+}
+
+fn encode_immediate_fields(
+    instruction_definition: &PowerPcInstructionDefinition,
+    d_field: u32,
+    a_field: u32,
+    immediate_value: u16,
+) -> u32 {
+    let (_, fixed_bits_value) = instruction_definition.get_fixed_bits();
+
+    fixed_bits_value | (d_field << 21) | (a_field << 16) | immediate_value as u32
+}
+
+/// Special-purpose register numbers are encoded with their low and high 5-bit halves swapped.
+fn split_special_purpose_register_field(special_purpose_register: u32) -> (u32, u32) {
+    (special_purpose_register & 0x1F, (special_purpose_register >> 5) & 0x1F)
+}
+
+fn join_special_purpose_register_field(instruction_word: u32) -> u32 {
+    ((instruction_word >> 16) & 0x1F) | (((instruction_word >> 11) & 0x1F) << 5)
+}
+
+fn decode_instruction(
+    instruction_word: u32,
+    current_instruction_address: i64,
+) -> Result<DecodedInstruction, String> {
+    if let Some(decoded_branch_instruction) = decode_branch_instruction(instruction_word, current_instruction_address) {
+        return Ok(decoded_branch_instruction);
+    }
+
+    let Some(instruction_definition) = POWERPC_INSTRUCTION_DEFINITIONS
+        .iter()
+        .find(|instruction_definition| {
+            let (fixed_bits_mask, fixed_bits_value) = instruction_definition.get_fixed_bits();
+
+            instruction_word & fixed_bits_mask == fixed_bits_value
+        })
+    else {
+        return Err(format!("Unsupported PowerPC instruction 0x{:08X}.", instruction_word));
+    };
+
+    if let Some(instruction_text) = format_simplified_instruction(instruction_definition, instruction_word) {
+        return Ok(DecodedInstruction::Plain(instruction_text));
+    }
+
+    let d_field = ((instruction_word >> 21) & 0x1F) as u8;
+    let a_field = ((instruction_word >> 16) & 0x1F) as u8;
+    let b_field = ((instruction_word >> 11) & 0x1F) as u8;
+    let c_field = ((instruction_word >> 6) & 0x1F) as u8;
+    let signed_immediate_value = instruction_word as u16 as i16;
+    let unsigned_immediate_value = instruction_word as u16;
+    let overflow_suffix = if instruction_definition.form.supports_overflow() && (instruction_word & 0x400) != 0 {
+        "o"
+    } else {
+        ""
+    };
+    let record_suffix = if instruction_definition.form.supports_record() && (instruction_word & 1) != 0 {
+        "."
+    } else {
+        ""
+    };
+    let mnemonic = format!("{}{}{}", instruction_definition.mnemonic, overflow_suffix, record_suffix);
+
+    let instruction_operands = match instruction_definition.form {
+        PowerPcInstructionForm::IntegerArithmetic
+        | PowerPcInstructionForm::IntegerMultiplyHigh
+        | PowerPcInstructionForm::LoadStoreIndexed
+        | PowerPcInstructionForm::StoreConditional => vec![gpr(d_field), gpr(a_field), gpr(b_field)],
+        PowerPcInstructionForm::IntegerArithmeticUnary => vec![gpr(d_field), gpr(a_field)],
+        PowerPcInstructionForm::SignedImmediate => vec![gpr(d_field), gpr(a_field), signed_immediate_value.to_string()],
+        PowerPcInstructionForm::ShiftedImmediate => vec![
+            gpr(d_field),
+            gpr(a_field),
+            format!("0x{:X}", unsigned_immediate_value),
+        ],
+        PowerPcInstructionForm::LogicalImmediate => vec![
+            gpr(a_field),
+            gpr(d_field),
+            format!("0x{:X}", unsigned_immediate_value),
+        ],
+        PowerPcInstructionForm::LogicalRegister => vec![gpr(a_field), gpr(d_field), gpr(b_field)],
+        PowerPcInstructionForm::LogicalUnary => vec![gpr(a_field), gpr(d_field)],
+        PowerPcInstructionForm::ShiftImmediate => vec![gpr(a_field), gpr(d_field), b_field.to_string()],
+        PowerPcInstructionForm::RotateImmediate => vec![
+            gpr(a_field),
+            gpr(d_field),
+            b_field.to_string(),
+            c_field.to_string(),
+            ((instruction_word >> 1) & 0x1F).to_string(),
+        ],
+        PowerPcInstructionForm::RotateRegister => vec![
+            gpr(a_field),
+            gpr(d_field),
+            gpr(b_field),
+            c_field.to_string(),
+            ((instruction_word >> 1) & 0x1F).to_string(),
+        ],
+        PowerPcInstructionForm::Compare => with_condition_register_field(d_field >> 2, vec![gpr(a_field), gpr(b_field)]),
+        PowerPcInstructionForm::CompareSignedImmediate => with_condition_register_field(d_field >> 2, vec![gpr(a_field), signed_immediate_value.to_string()]),
+        PowerPcInstructionForm::CompareUnsignedImmediate => {
+            with_condition_register_field(d_field >> 2, vec![gpr(a_field), unsigned_immediate_value.to_string()])
+        }
+        PowerPcInstructionForm::Trap => vec![d_field.to_string(), gpr(a_field), gpr(b_field)],
+        PowerPcInstructionForm::TrapImmediate => vec![
+            d_field.to_string(),
+            gpr(a_field),
+            signed_immediate_value.to_string(),
+        ],
+        PowerPcInstructionForm::LoadStore => vec![
+            gpr(d_field),
+            format!("{}({})", signed_immediate_value, gpr(a_field)),
+        ],
+        PowerPcInstructionForm::LoadStoreString => vec![gpr(d_field), gpr(a_field), b_field.to_string()],
+        PowerPcInstructionForm::CacheBlock => vec![gpr(a_field), gpr(b_field)],
+        PowerPcInstructionForm::FloatingPointLoadStore => vec![
+            fpr(d_field),
+            format!("{}({})", signed_immediate_value, gpr(a_field)),
+        ],
+        PowerPcInstructionForm::FloatingPointLoadStoreIndexed => vec![fpr(d_field), gpr(a_field), gpr(b_field)],
+        PowerPcInstructionForm::PairedSingleLoadStore => {
+            let displacement = ((instruction_word << 20) as i32) >> 20;
+
+            vec![
+                fpr(d_field),
+                format!("{}({})", displacement, gpr(a_field)),
+                ((instruction_word >> 15) & 1).to_string(),
+                ((instruction_word >> 12) & 0x7).to_string(),
+            ]
+        }
+        PowerPcInstructionForm::PairedSingleLoadStoreIndexed => vec![
+            fpr(d_field),
+            gpr(a_field),
+            gpr(b_field),
+            ((instruction_word >> 10) & 1).to_string(),
+            ((instruction_word >> 7) & 0x7).to_string(),
+        ],
+        PowerPcInstructionForm::FloatingPointArithmetic => vec![fpr(d_field), fpr(a_field), fpr(b_field)],
+        PowerPcInstructionForm::FloatingPointMultiply => vec![fpr(d_field), fpr(a_field), fpr(c_field)],
+        PowerPcInstructionForm::FloatingPointMultiplyAdd => vec![fpr(d_field), fpr(a_field), fpr(c_field), fpr(b_field)],
+        PowerPcInstructionForm::FloatingPointUnary => vec![fpr(d_field), fpr(b_field)],
+        PowerPcInstructionForm::FloatingPointCompare => vec![crf(d_field >> 2), fpr(a_field), fpr(b_field)],
+        PowerPcInstructionForm::MoveFromFpscr => vec![fpr(d_field)],
+        PowerPcInstructionForm::MoveToFpscrFields => vec![format!("0x{:X}", (instruction_word >> 17) & 0xFF), fpr(b_field)],
+        PowerPcInstructionForm::MoveToFpscrFieldImmediate => vec![crf(d_field >> 2), ((instruction_word >> 12) & 0x0F).to_string()],
+        PowerPcInstructionForm::MoveToFpscrBit => vec![d_field.to_string()],
+        PowerPcInstructionForm::ConditionRegisterFieldMove => vec![crf(d_field >> 2), crf(a_field >> 2)],
+        PowerPcInstructionForm::ConditionRegisterLogical => vec![d_field.to_string(), a_field.to_string(), b_field.to_string()],
+        PowerPcInstructionForm::MoveToConditionRegisterFromXer => vec![crf(d_field >> 2)],
+        PowerPcInstructionForm::MoveFromConditionRegister => vec![gpr(d_field)],
+        PowerPcInstructionForm::MoveToConditionRegisterFields => vec![format!("0x{:X}", (instruction_word >> 12) & 0xFF), gpr(d_field)],
+        PowerPcInstructionForm::MoveFromSpecialPurposeRegister => vec![
+            gpr(d_field),
+            format_powerpc_special_purpose_register(join_special_purpose_register_field(instruction_word)),
+        ],
+        PowerPcInstructionForm::MoveToSpecialPurposeRegister => vec![
+            format_powerpc_special_purpose_register(join_special_purpose_register_field(instruction_word)),
+            gpr(d_field),
+        ],
+        PowerPcInstructionForm::MoveFromTimeBase => vec![
+            gpr(d_field),
+            join_special_purpose_register_field(instruction_word).to_string(),
+        ],
+        PowerPcInstructionForm::NoOperands => Vec::new(),
+    };
+
+    Ok(DecodedInstruction::Plain(format_instruction_text(&mnemonic, &instruction_operands)))
+}
+
+/// Formats instructions that have a simplified mnemonic, such as `or r3, r4, r4` as `mr r3, r4`.
+fn format_simplified_instruction(
+    instruction_definition: &PowerPcInstructionDefinition,
+    instruction_word: u32,
+) -> Option<String> {
+    let d_field = ((instruction_word >> 21) & 0x1F) as u8;
+    let a_field = ((instruction_word >> 16) & 0x1F) as u8;
+    let b_field = ((instruction_word >> 11) & 0x1F) as u8;
+    let c_field = ((instruction_word >> 6) & 0x1F) as u8;
+    let mask_end = ((instruction_word >> 1) & 0x1F) as u8;
+    let record_suffix = if instruction_definition.form.supports_record() && (instruction_word & 1) != 0 {
+        "."
+    } else {
+        ""
+    };
+
+    match instruction_definition.mnemonic {
+        "ori" if instruction_word & 0x03FF_FFFF == 0 => Some(String::from("nop")),
+        "addi" if a_field == 0 => Some(format!("li {}, {}", gpr(d_field), instruction_word as u16 as i16)),
+        "addis" if a_field == 0 => Some(format!("lis {}, 0x{:X}", gpr(d_field), instruction_word as u16)),
+        "or" if d_field == b_field => Some(format!("mr{} {}, {}", record_suffix, gpr(a_field), gpr(d_field))),
+        "nor" if d_field == b_field => Some(format!("not{} {}, {}", record_suffix, gpr(a_field), gpr(d_field))),
+        "rlwinm" => {
+            let (shift_amount, mask_begin) = (b_field, c_field);
+            let (simplified_mnemonic, bit_count) = if mask_begin == 0 && mask_end == 31 && shift_amount != 0 {
+                ("rotlwi", shift_amount)
+            } else if mask_begin == 0 && shift_amount != 0 && mask_end == 31 - shift_amount {
+                ("slwi", shift_amount)
+            } else if mask_end == 31 && mask_begin != 0 && shift_amount == 32 - mask_begin {
+                ("srwi", mask_begin)
+            } else if shift_amount == 0 && mask_end == 31 && mask_begin != 0 {
+                ("clrlwi", mask_begin)
+            } else if shift_amount == 0 && mask_begin == 0 && mask_end != 31 {
+                ("clrrwi", 31 - mask_end)
+            } else {
+                return None;
+            };
+
+            Some(format!(
+                "{}{} {}, {}, {}",
+                simplified_mnemonic,
+                record_suffix,
+                gpr(a_field),
+                gpr(d_field),
+                bit_count
+            ))
+        }
+        "rlwnm" if c_field == 0 && mask_end == 31 => Some(format!("rotlw{} {}, {}, {}", record_suffix, gpr(a_field), gpr(d_field), gpr(b_field))),
+        "tw" if d_field as u32 == TRAP_OPTIONS_UNCONDITIONAL && a_field == 0 && b_field == 0 => Some(String::from("trap")),
+        "creqv" if d_field == a_field && a_field == b_field => Some(format!("crset {}", d_field)),
+        "crxor" if d_field == a_field && a_field == b_field => Some(format!("crclr {}", d_field)),
+        "cror" if a_field == b_field => Some(format!("crmove {}, {}", d_field, a_field)),
+        "crnor" if a_field == b_field => Some(format!("crnot {}, {}", d_field, a_field)),
+        "mtcrf" if (instruction_word >> 12) & 0xFF == 0xFF => Some(format!("mtcr {}", gpr(d_field))),
+        "mfspr" | "mtspr" => {
+            let register_name = match join_special_purpose_register_field(instruction_word) {
+                SPECIAL_PURPOSE_REGISTER_LR => "lr",
+                SPECIAL_PURPOSE_REGISTER_CTR => "ctr",
+                SPECIAL_PURPOSE_REGISTER_XER => "xer",
+                _ => return None,
+            };
+
+            Some(format!("{}{} {}", &instruction_definition.mnemonic[..2], register_name, gpr(d_field)))
+        }
+        "mftb" => match join_special_purpose_register_field(instruction_word) {
+            TIME_BASE_REGISTER_LOWER => Some(format!("mftb {}", gpr(d_field))),
+            TIME_BASE_REGISTER_UPPER => Some(format!("mftbu {}", gpr(d_field))),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Decodes `b`, `bc`, `bclr`, and `bcctr`, preferring simplified mnemonics, and returns `None` for other instructions.
+fn decode_branch_instruction(
+    instruction_word: u32,
+    current_instruction_address: i64,
+) -> Option<DecodedInstruction> {
+    let opcode = instruction_word >> 26;
+    let branch_options = (instruction_word >> 21) & 0x1F;
+    let condition_register_bit = (instruction_word >> 16) & 0x1F;
+    let is_absolute = (instruction_word & 2) != 0;
+    let link_suffix = if (instruction_word & 1) != 0 { "l" } else { "" };
+    let absolute_suffix = if is_absolute { "a" } else { "" };
+
+    match opcode {
+        OPCODE_BRANCH => {
+            let branch_delta = sign_extend(instruction_word & 0x03FF_FFFC, 26);
+
+            Some(DecodedInstruction::Branch {
+                mnemonic: format!("b{}{}", link_suffix, absolute_suffix),
+                leading_operands: Vec::new(),
+                target_address: if is_absolute {
+                    branch_delta
+                } else {
+                    current_instruction_address + branch_delta
+                },
+            })
+        }
+        OPCODE_BRANCH_CONDITIONAL => {
+            let branch_displacement = sign_extend(instruction_word & 0xFFFC, 16);
+            let target_address = if is_absolute {
+                branch_displacement
+            } else {
+                current_instruction_address + branch_displacement
+            };
+
+            match describe_branch_condition(branch_options, condition_register_bit) {
+                Some((condition_name, leading_operands, has_prediction_hint)) if branch_options != BRANCH_OPTIONS_ALWAYS => {
+                    let prediction_hint_suffix = match (has_prediction_hint, branch_displacement < 0) {
+                        (false, _) => "",
+                        (true, false) => "+",
+                        (true, true) => "-",
+                    };
+
+                    Some(DecodedInstruction::Branch {
+                        mnemonic: format!("b{}{}{}{}", condition_name, link_suffix, absolute_suffix, prediction_hint_suffix),
+                        leading_operands,
+                        target_address,
+                    })
+                }
+                _ => Some(DecodedInstruction::Branch {
+                    mnemonic: format!("bc{}{}", link_suffix, absolute_suffix),
+                    leading_operands: vec![branch_options.to_string(), condition_register_bit.to_string()],
+                    target_address,
+                }),
+            }
+        }
+        OPCODE_CONDITION_REGISTER => {
+            let register_name = match (instruction_word >> 1) & 0x3FF {
+                EXTENDED_OPCODE_BRANCH_TO_LINK_REGISTER => "lr",
+                EXTENDED_OPCODE_BRANCH_TO_COUNT_REGISTER => "ctr",
+                _ => return None,
+            };
+
+            // The branch hint field is not part of the 32-bit architecture, so it must be clear.
+            if (instruction_word & 0xF800) != 0 {
+                return None;
+            }
+
+            let decrements_count_register = (branch_options & 0x04) == 0;
+
+            let instruction_text = match describe_branch_condition(branch_options, condition_register_bit) {
+                Some((condition_name, leading_operands, has_prediction_hint)) if !(register_name == "ctr" && decrements_count_register) => {
+                    let prediction_hint_suffix = if has_prediction_hint { "+" } else { "" };
+
+                    format_instruction_text(
+                        &format!("b{}{}{}{}", condition_name, register_name, link_suffix, prediction_hint_suffix),
+                        &leading_operands,
+                    )
+                }
+                _ => format!("bc{}{} {}, {}", register_name, link_suffix, branch_options, condition_register_bit),
+            };
+
+            Some(DecodedInstruction::Plain(instruction_text))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the simplified condition name, condition register operands, and whether the prediction hint bit is set for a
+/// branch, or `None` if its branch options have no simplified mnemonic.
+fn describe_branch_condition(
+    branch_options: u32,
+    condition_register_bit: u32,
+) -> Option<(&'static str, Vec<String>, bool)> {
+    if branch_options == BRANCH_OPTIONS_ALWAYS {
+        return (condition_register_bit == 0).then_some(("", Vec::new(), false));
+    }
+
+    let has_prediction_hint = (branch_options & 1) != 0;
+    let condition_index = (condition_register_bit % 4) as usize;
+    let condition_register_operands = with_condition_register_field((condition_register_bit / 4) as u8, Vec::new());
+
+    match branch_options & !1 {
+        BRANCH_OPTIONS_IF_TRUE => Some((
+            BRANCH_IF_TRUE_CONDITION_NAMES[condition_index],
+            condition_register_operands,
+            has_prediction_hint,
+        )),
+        BRANCH_OPTIONS_IF_FALSE => Some((
+            BRANCH_IF_FALSE_CONDITION_NAMES[condition_index],
+            condition_register_operands,
+            has_prediction_hint,
+        )),
+        BRANCH_OPTIONS_DECREMENT_NOT_ZERO if condition_register_bit == 0 => Some(("dnz", Vec::new(), has_prediction_hint)),
+        BRANCH_OPTIONS_DECREMENT_ZERO if condition_register_bit == 0 => Some(("dz", Vec::new(), has_prediction_hint)),
+        _ => None,
+    }
+}
+
+/// Prepends the condition register field operand, which is omitted when it is the default `cr0`.
+fn with_condition_register_field(
+    condition_register_field: u8,
+    mut instruction_operands: Vec<String>,
+) -> Vec<String> {
+    if condition_register_field != 0 {
+        instruction_operands.insert(0, crf(condition_register_field));
+    }
+
+    instruction_operands
+}
+
+fn format_instruction_text(
+    mnemonic: &str,
+    instruction_operands: &[String],
+) -> String {
+    if instruction_operands.is_empty() {
+        mnemonic.to_string()
+    } else {
+        format!("{} {}", mnemonic, instruction_operands.join(", "))
+    }
+}
+
+fn gpr(register_index: u8) -> String {
+    format_powerpc_register_name(register_index)
+}
+
+fn fpr(register_index: u8) -> String {
+    format_powerpc_floating_point_register_name(register_index)
+}
+
+fn crf(field_index: u8) -> String {
+    format_powerpc_condition_register_field_name(field_index)
+}
+
+fn sign_extend(
+    encoded_value: u32,
+    bit_count: u32,
+) -> i64 {
+    let shift_amount = 64 - bit_count;
+
+    ((encoded_value as i64) << shift_amount) >> shift_amount
+}
+
+fn resolve_target_address(
+    target_operand: &InstructionOperand,
+    label_addresses: &HashMap<String, i64>,
+) -> Result<i64, String> {
+    match target_operand {
+        InstructionOperand::Identifier(label_name) => label_addresses
+            .get(label_name)
+            .copied()
+            .ok_or_else(|| format!("Unknown instruction label '{}'.", label_name)),
+        InstructionOperand::Immediate(immediate_value) => Ok(*immediate_value as i64),
+        unexpected_operand => Err(format!(
+            "Unsupported branch operand '{:?}'. Expected a label or absolute instruction offset.",
+            unexpected_operand
+        )),
+    }
+}
+
+fn expect_operand_count(
+    operands: &[InstructionOperand],
+    expected_operand_count: usize,
+    mnemonic: &str,
+) -> Result<(), String> {
+    if operands.len() != expected_operand_count {
+        return Err(format!(
+            "PowerPC {} expects {} operand(s) but found {}.",
+            mnemonic,
+            expected_operand_count,
+            operands.len()
+        ));
+    }
+
+    Ok(())
 }
 
 fn parse_register_operand(
     operands: &[InstructionOperand],
     operand_index: usize,
-) -> Result<u8, String> {
+) -> Result<u32, String> {
     let register_name = parse_identifier_operand(operands, operand_index)?;
 
-    parse_powerpc_register_name(register_name).ok_or_else(|| format!("Unsupported PowerPC register '{}'.", register_name))
+    parse_powerpc_register_name(register_name)
+        .map(u32::from)
+        .ok_or_else(|| format!("Unsupported PowerPC register '{}'.", register_name))
+}
+
+fn parse_floating_point_register_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+) -> Result<u32, String> {
+    let register_name = parse_identifier_operand(operands, operand_index)?;
+
+    parse_powerpc_floating_point_register_name(register_name)
+        .map(u32::from)
+        .ok_or_else(|| format!("Unsupported PowerPC floating-point register '{}'.", register_name))
+}
+
+fn parse_condition_register_field_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+) -> Result<u32, String> {
+    let field_name = parse_identifier_operand(operands, operand_index)?;
+
+    parse_powerpc_condition_register_field_name(field_name)
+        .map(u32::from)
+        .ok_or_else(|| format!("Unsupported PowerPC condition register field '{}'.", field_name))
+}
+
+/// Parses a leading condition register field that defaults to `cr0` when omitted, returning the field and the index of the
+/// first operand after it.
+fn parse_optional_condition_register_field_operand(
+    operands: &[InstructionOperand],
+    remaining_operand_count: usize,
+    mnemonic: &str,
+) -> Result<(u32, usize), String> {
+    if operands.len() == remaining_operand_count + 1 {
+        return Ok((parse_condition_register_field_operand(operands, 0)?, 1));
+    }
+
+    expect_operand_count(operands, remaining_operand_count, mnemonic)?;
+
+    Ok((0, 0))
+}
+
+fn parse_special_purpose_register_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+    mnemonic: &str,
+) -> Result<u32, String> {
+    match operands.get(operand_index) {
+        Some(InstructionOperand::Identifier(register_name)) => parse_powerpc_special_purpose_register_name(register_name)
+            .ok_or_else(|| format!("Unsupported PowerPC special-purpose register '{}'.", register_name)),
+        _ => parse_field_operand(operands, operand_index, mnemonic, 0x3FF),
+    }
+}
+
+fn parse_memory_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+) -> Result<(i16, u32), String> {
+    let (displacement, base_register_name) = parse_powerpc_memory_operand(parse_identifier_operand(operands, operand_index)?)?;
+    let base_register_index =
+        parse_powerpc_register_name(&base_register_name).ok_or_else(|| format!("Unsupported PowerPC base register '{}'.", base_register_name))?;
+
+    Ok((displacement, base_register_index as u32))
 }
 
-fn parse_identifier_operand<'a>(
-    operands: &'a [InstructionOperand],
+fn parse_identifier_operand(
+    operands: &[InstructionOperand],
     operand_index: usize,
-) -> Result<&'a str, String> {
+) -> Result<&str, String> {
     match operands.get(operand_index) {
         Some(InstructionOperand::Identifier(identifier)) => Ok(identifier.as_str()),
         Some(unexpected_operand) => Err(format!(
@@ -280,14 +2062,14 @@ fn parse_identifier_operand<'a>(
     }
 }
 
-fn parse_i16_immediate_operand(
+fn parse_immediate_operand(
     operands: &[InstructionOperand],
     operand_index: usize,
     mnemonic: &str,
-) -> Result<i16, String> {
+) -> Result<i64, String> {
     match operands.get(operand_index) {
         Some(InstructionOperand::Immediate(immediate_value)) => {
-            i16::try_from(*immediate_value).map_err(|_| format!("PowerPC {} immediate '{}' is out of i16 range.", mnemonic, immediate_value))
+            i64::try_from(*immediate_value).map_err(|_| format!("PowerPC {} immediate '{}' is out of range.", mnemonic, immediate_value))
         }
         Some(unexpected_operand) => Err(format!(
             "PowerPC {} expected an immediate operand at position '{}' but found '{:?}'.",
@@ -297,31 +2079,56 @@ fn parse_i16_immediate_operand(
     }
 }
 
-fn resolve_branch_target_address(
+fn parse_field_operand(
     operands: &[InstructionOperand],
-    label_addresses: &HashMap<String, i64>,
-) -> Result<i64, String> {
-    if operands.len() != 1 {
-        return Err(String::from("PowerPC branch instructions require exactly one target operand."));
-    }
+    operand_index: usize,
+    mnemonic: &str,
+    maximum_value: u32,
+) -> Result<u32, String> {
+    let immediate_value = parse_immediate_operand(operands, operand_index, mnemonic)?;
 
-    match &operands[0] {
-        InstructionOperand::Identifier(label_name) => label_addresses
-            .get(label_name)
-            .copied()
-            .ok_or_else(|| format!("Unknown instruction label '{}'.", label_name)),
-        InstructionOperand::Immediate(immediate_value) => Ok(*immediate_value as i64),
-        unexpected_operand => Err(format!(
-            "Unsupported branch operand '{:?}'. Expected a label or absolute instruction offset.",
-            unexpected_operand
-        )),
-    }
+    u32::try_from(immediate_value)
+        .ok()
+        .filter(|field_value| *field_value <= maximum_value)
+        .ok_or_else(|| format!("PowerPC {} operand '{}' must be between 0 and {}.", mnemonic, immediate_value, maximum_value))
 }
 
-fn sign_extend_branch_delta(encoded_delta: u32) -> i64 {
-    let shift_amount = 64 - 26;
+/// Parses a sign-extended 16-bit immediate, returning its encoded bits.
+fn parse_signed_immediate_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+    mnemonic: &str,
+) -> Result<u16, String> {
+    let immediate_value = parse_immediate_operand(operands, operand_index, mnemonic)?;
 
-    ((encoded_delta as i64) << shift_amount) >> shift_amount
+    i16::try_from(immediate_value)
+        .map(|signed_immediate_value| signed_immediate_value as u16)
+        .map_err(|_| format!("PowerPC {} immediate '{}' is out of i16 range.", mnemonic, immediate_value))
+}
+
+fn parse_unsigned_immediate_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+    mnemonic: &str,
+) -> Result<u16, String> {
+    let immediate_value = parse_immediate_operand(operands, operand_index, mnemonic)?;
+
+    u16::try_from(immediate_value).map_err(|_| format!("PowerPC {} immediate '{}' is out of u16 range.", mnemonic, immediate_value))
+}
+
+/// Parses the upper-half immediate of `addis` and `lis`, which may be written either signed or unsigned.
+fn parse_shifted_immediate_operand(
+    operands: &[InstructionOperand],
+    operand_index: usize,
+    mnemonic: &str,
+) -> Result<u16, String> {
+    let immediate_value = parse_immediate_operand(operands, operand_index, mnemonic)?;
+
+    if (-0x8000..=0xFFFF).contains(&immediate_value) {
+        Ok(immediate_value as u16)
+    } else {
+        Err(format!("PowerPC {} immediate '{}' does not fit in 16 bits.", mnemonic, immediate_value))
+    }
 }
 
 fn format_decoded_instruction_sequence(
@@ -353,13 +2160,24 @@ fn format_decoded_instruction_sequence(
             .unwrap_or_default();
         let instruction_text = match decoded_instruction {
             DecodedInstruction::Plain(instruction_text) => instruction_text.clone(),
-            DecodedInstruction::Branch { mnemonic, target_address } => {
-                let target_text = label_names
-                    .get(&(*target_address as usize))
-                    .cloned()
-                    .unwrap_or_else(|| format_signed_hex(*target_address));
+            DecodedInstruction::Branch {
+                mnemonic,
+                leading_operands,
+                target_address,
+            } => {
+                let target_text = if *target_address >= 0 {
+                    label_names
+                        .get(&(*target_address as usize))
+                        .cloned()
+                        .unwrap_or_else(|| format_signed_hex(*target_address))
+                } else {
+                    format_signed_hex(*target_address)
+                };
+                let mut branch_operands = leading_operands.clone();
+
+                branch_operands.push(target_text);
 
-                format!("{} {}", mnemonic, target_text)
+                format_instruction_text(mnemonic, &branch_operands)
             }
         };
 
@@ -5,6 +5,9 @@ mod plugin;
 mod powerpc_memory_operand;
 mod powerpc_register;
 
+#[cfg(test)]
+mod instruction_parser_corpus_tests;
+
 pub use constants::{
     POWERPC_FAMILY_DATA_TYPE_IDS, POWERPC_FAMILY_INSTRUCTION_SET_IDS, POWERPC_FAMILY_PLUGIN_DESCRIPTION, POWERPC_FAMILY_PLUGIN_DISPLAY_NAME,
     POWERPC_FAMILY_PLUGIN_ID,
@@ -13,13 +13,21 @@ pub fn parse_powerpc_memory_operand(operand_text: &str) -> Result<(i16, String),
 
     let displacement_text = trimmed_operand_text[..open_paren_index].trim();
     let base_register_name = trimmed_operand_text[open_paren_index + 1..close_paren_index].trim();
+    let (is_negative, unsigned_displacement_text) = match displacement_text.strip_prefix('-') {
+        Some(unsigned_displacement_text) => (true, unsigned_displacement_text),
+        None => (false, displacement_text),
+    };
     let displacement = if displacement_text.is_empty() {
         0
-    } else if let Some(hexadecimal_digits) = displacement_text
+    } else if let Some(hexadecimal_digits) = unsigned_displacement_text
         .strip_prefix("0x")
-        .or_else(|| displacement_text.strip_prefix("0X"))
+        .or_else(|| unsigned_displacement_text.strip_prefix("0X"))
     {
-        i16::from_str_radix(hexadecimal_digits, 16).map_err(|error| format!("Invalid PowerPC hexadecimal displacement '{}': {}.", displacement_text, error))?
+        let magnitude = i32::from_str_radix(hexadecimal_digits, 16)
+            .map_err(|error| format!("Invalid PowerPC hexadecimal displacement '{}': {}.", displacement_text, error))?;
+        let signed_displacement = if is_negative { -magnitude } else { magnitude };
+
+        i16::try_from(signed_displacement).map_err(|_| format!("PowerPC displacement '{}' is out of i16 range.", displacement_text))?
     } else {
         displacement_text
             .parse::<i16>()
@@ -1,7 +1,32 @@
+/// Named special-purpose registers, including the Gekko graphics quantization and write-gather registers.
+const POWERPC_SPECIAL_PURPOSE_REGISTER_NAMES: [(&str, u32); 15] = [
+    ("xer", 1),
+    ("lr", 8),
+    ("ctr", 9),
+    ("gqr0", 912),
+    ("gqr1", 913),
+    ("gqr2", 914),
+    ("gqr3", 915),
+    ("gqr4", 916),
+    ("gqr5", 917),
+    ("gqr6", 918),
+    ("gqr7", 919),
+    ("hid2", 920),
+    ("wpar", 921),
+    ("dmau", 922),
+    ("dmal", 923),
+];
+
 pub fn parse_powerpc_register_name(register_name: &str) -> Option<u8> {
+    let register_name = register_name.trim().to_ascii_lowercase();
+
+    match register_name.as_str() {
+        "sp" => return Some(1),
+        "rtoc" => return Some(2),
+        _ => {}
+    }
+
     register_name
-        .trim()
-        .to_ascii_lowercase()
         .strip_prefix('r')
         .and_then(|register_index_text| register_index_text.parse::<u8>().ok())
         .filter(|register_index| *register_index <= 31)
@@ -10,3 +35,48 @@ pub fn parse_powerpc_register_name(register_name: &str) -> Option<u8> {
 pub fn format_powerpc_register_name(register_index: u8) -> String {
     format!("r{}", register_index)
 }
+
+pub fn parse_powerpc_floating_point_register_name(register_name: &str) -> Option<u8> {
+    let register_name = register_name.trim().to_ascii_lowercase();
+
+    register_name
+        .strip_prefix("fr")
+        .or_else(|| register_name.strip_prefix('f'))
+        .and_then(|register_index_text| register_index_text.parse::<u8>().ok())
+        .filter(|register_index| *register_index <= 31)
+}
+
+pub fn format_powerpc_floating_point_register_name(register_index: u8) -> String {
+    format!("f{}", register_index)
+}
+
+pub fn parse_powerpc_condition_register_field_name(register_name: &str) -> Option<u8> {
+    register_name
+        .trim()
+        .to_ascii_lowercase()
+        .strip_prefix("cr")
+        .and_then(|field_index_text| field_index_text.parse::<u8>().ok())
+        .filter(|field_index| *field_index <= 7)
+}
+
+pub fn format_powerpc_condition_register_field_name(field_index: u8) -> String {
+    format!("cr{}", field_index)
+}
+
+pub fn parse_powerpc_special_purpose_register_name(register_name: &str) -> Option<u32> {
+    let register_name = register_name.trim().to_ascii_lowercase();
+
+    POWERPC_SPECIAL_PURPOSE_REGISTER_NAMES
+        .iter()
+        .find(|(special_purpose_register_name, _)| *special_purpose_register_name == register_name)
+        .map(|(_, special_purpose_register_index)| *special_purpose_register_index)
+}
+
+/// Formats a special-purpose register by name when it has one, and by number otherwise.
+pub fn format_powerpc_special_purpose_register(special_purpose_register_index: u32) -> String {
+    POWERPC_SPECIAL_PURPOSE_REGISTER_NAMES
+        .iter()
+        .find(|(_, named_register_index)| *named_register_index == special_purpose_register_index)
+        .map(|(special_purpose_register_name, _)| special_purpose_register_name.to_string())
+        .unwrap_or_else(|| special_purpose_register_index.to_string())
+}
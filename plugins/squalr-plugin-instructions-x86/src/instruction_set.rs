@@ -1,5 +1,5 @@
 use crate::x86_operand_lowering::build_candidate_instructions;
-use iced_x86::{Decoder, DecoderOptions, Encoder, FlowControl, Formatter, NasmFormatter};
+use iced_x86::{Decoder, DecoderError, DecoderOptions, Encoder, FlowControl, Formatter, NasmFormatter};
 use squalr_engine_api::{
     plugins::instruction_set::{InstructionSet, ParsedInstruction, normalize_instruction_text, parse_instruction_sequence},
     structures::memory::bitness::Bitness,
@@ -14,6 +14,8 @@ pub struct DisassembledInstruction {
     pub text: String,
     pub branch_target_address: Option<u64>,
     pub is_control_flow: bool,
+    /// Extra listing text shown after the instruction, such as the instruction set an ARM interworking branch enters.
+    pub annotation: Option<String>,
 }
 
 #[derive(Debug)]
@@ -50,6 +52,7 @@ impl X86InstructionSetBase {
     fn assemble_instruction_sequence(
         &self,
         assembly_source: &str,
+        base_address: u64,
     ) -> Result<Vec<u8>, String> {
         let parsed_instruction_sequence = parse_instruction_sequence(assembly_source).map_err(|instruction_error| instruction_error.to_string())?;
         let parsed_instructions = parsed_instruction_sequence.instructions();
@@ -64,8 +67,8 @@ impl X86InstructionSetBase {
         let mut assembled_sequence_items = Vec::new();
 
         for _pass_index in 0..16 {
-            let label_addresses = build_label_addresses(label_instruction_indices, &item_lengths)?;
-            let mut current_ip = 0u64;
+            let label_addresses = build_label_addresses(label_instruction_indices, &item_lengths, base_address)?;
+            let mut current_ip = base_address;
             let mut next_assembled_sequence_items = Vec::with_capacity(parsed_instructions.len());
             let mut next_item_lengths = Vec::with_capacity(parsed_instructions.len());
 
@@ -111,7 +114,7 @@ impl X86InstructionSetBase {
         }
 
         let mut instruction_encoder = Encoder::new(bitness_as_u32(self.instruction_bitness));
-        let mut current_ip = 0u64;
+        let mut current_ip = base_address;
         let mut assembled_bytes = Vec::new();
 
         for assembled_sequence_item in &assembled_sequence_items {
@@ -163,6 +166,83 @@ impl X86InstructionSetBase {
         Ok(instruction_texts.join("; "))
     }
 
+    fn measure_instruction_lengths(
+        &self,
+        instruction_bytes: &[u8],
+    ) -> Result<Vec<usize>, String> {
+        let mut decoder = Decoder::new(bitness_as_u32(self.instruction_bitness), instruction_bytes, DecoderOptions::NONE);
+        let mut instruction_lengths = Vec::new();
+
+        while decoder.can_decode() {
+            let instruction = decoder.decode();
+
+            // A trailing partial instruction is not an error, since callers measure fixed size windows of memory.
+            if decoder.last_error() == DecoderError::NoMoreBytes {
+                break;
+            }
+
+            if instruction.is_invalid() {
+                return Err(format!(
+                    "Invalid {} instruction at byte offset {}.",
+                    self.display_name,
+                    instruction_lengths.iter().sum::<usize>()
+                ));
+            }
+
+            instruction_lengths.push(instruction.len());
+        }
+
+        Ok(instruction_lengths)
+    }
+
+    fn relocate_instruction_sequence(
+        &self,
+        instruction_bytes: &[u8],
+        source_address: u64,
+        destination_address: u64,
+    ) -> Result<Vec<u8>, String> {
+        let mut decoder = Decoder::with_ip(
+            bitness_as_u32(self.instruction_bitness),
+            instruction_bytes,
+            source_address,
+            DecoderOptions::NONE,
+        );
+        let mut instruction_encoder = Encoder::new(bitness_as_u32(self.instruction_bitness));
+        let mut destination_ip = destination_address;
+
+        while decoder.can_decode() {
+            let mut instruction = decoder.decode();
+
+            if instruction.is_invalid() {
+                return Err(format!(
+                    "Cannot relocate invalid {} instruction at 0x{:X}.",
+                    self.display_name,
+                    instruction.ip()
+                ));
+            }
+
+            // Short branches rarely reach their target from a new location, so they are widened to near branches. The encoder
+            // recomputes branch and instruction pointer relative displacements for the new address.
+            instruction.as_near_branch();
+
+            let instruction_length = instruction_encoder
+                .encode(&instruction, destination_ip)
+                .map_err(|instruction_error| {
+                    format!(
+                        "Failed to relocate {} instruction at 0x{:X} to 0x{:X}: {}.",
+                        self.display_name,
+                        instruction.ip(),
+                        destination_ip,
+                        instruction_error
+                    )
+                })?;
+
+            destination_ip = destination_ip.saturating_add(instruction_length as u64);
+        }
+
+        Ok(instruction_encoder.take_buffer())
+    }
+
     fn disassemble_instruction_block(
         &self,
         instruction_bytes: &[u8],
@@ -195,6 +275,7 @@ impl X86InstructionSetBase {
                     text: format!("db 0x{:02X}", instruction_bytes[byte_offset]),
                     branch_target_address: None,
                     is_control_flow: false,
+                    annotation: None,
                 });
                 byte_offset += 1;
                 continue;
@@ -215,6 +296,7 @@ impl X86InstructionSetBase {
                 text: normalize_instruction_text(&instruction_text),
                 branch_target_address,
                 is_control_flow,
+                annotation: None,
             });
             byte_offset += instruction_length;
         }
@@ -271,7 +353,16 @@ impl InstructionSet for X86InstructionSet {
         &self,
         assembly_source: &str,
     ) -> Result<Vec<u8>, String> {
-        self.inner.assemble_instruction_sequence(assembly_source)
+        self.inner.assemble_instruction_sequence(assembly_source, 0)
+    }
+
+    fn assemble_at(
+        &self,
+        assembly_source: &str,
+        instruction_address: u64,
+    ) -> Result<Vec<u8>, String> {
+        self.inner
+            .assemble_instruction_sequence(assembly_source, instruction_address)
     }
 
     fn disassemble(
@@ -281,6 +372,32 @@ impl InstructionSet for X86InstructionSet {
         self.inner.disassemble_instruction_sequence(instruction_bytes)
     }
 
+    fn measure_instructions(
+        &self,
+        instruction_bytes: &[u8],
+    ) -> Result<Vec<usize>, String> {
+        self.inner.measure_instruction_lengths(instruction_bytes)
+    }
+
+    fn relocate(
+        &self,
+        instruction_bytes: &[u8],
+        source_address: u64,
+        destination_address: u64,
+    ) -> Result<Vec<u8>, String> {
+        self.inner
+            .relocate_instruction_sequence(instruction_bytes, source_address, destination_address)
+    }
+
+    fn build_jump(
+        &self,
+        source_address: u64,
+        destination_address: u64,
+    ) -> Result<Vec<u8>, String> {
+        self.inner
+            .assemble_instruction_sequence(&format!("jmp 0x{:X}", destination_address), source_address)
+    }
+
     fn build_no_operation_fill(
         &self,
         byte_count: usize,
@@ -314,9 +431,10 @@ impl X64InstructionSet {
 fn build_label_addresses(
     label_instruction_indices: &HashMap<String, usize>,
     instruction_lengths: &[usize],
+    base_address: u64,
 ) -> Result<HashMap<String, u64>, String> {
     let mut instruction_addresses = Vec::with_capacity(instruction_lengths.len() + 1);
-    let mut current_ip = 0u64;
+    let mut current_ip = base_address;
     instruction_addresses.push(current_ip);
 
     for instruction_length in instruction_lengths {
@@ -505,7 +623,16 @@ impl InstructionSet for X64InstructionSet {
         &self,
         assembly_source: &str,
     ) -> Result<Vec<u8>, String> {
-        self.inner.assemble_instruction_sequence(assembly_source)
+        self.inner.assemble_instruction_sequence(assembly_source, 0)
+    }
+
+    fn assemble_at(
+        &self,
+        assembly_source: &str,
+        instruction_address: u64,
+    ) -> Result<Vec<u8>, String> {
+        self.inner
+            .assemble_instruction_sequence(assembly_source, instruction_address)
     }
 
     fn disassemble(
@@ -515,6 +642,32 @@ impl InstructionSet for X64InstructionSet {
         self.inner.disassemble_instruction_sequence(instruction_bytes)
     }
 
+    fn measure_instructions(
+        &self,
+        instruction_bytes: &[u8],
+    ) -> Result<Vec<usize>, String> {
+        self.inner.measure_instruction_lengths(instruction_bytes)
+    }
+
+    fn relocate(
+        &self,
+        instruction_bytes: &[u8],
+        source_address: u64,
+        destination_address: u64,
+    ) -> Result<Vec<u8>, String> {
+        self.inner
+            .relocate_instruction_sequence(instruction_bytes, source_address, destination_address)
+    }
+
+    fn build_jump(
+        &self,
+        source_address: u64,
+        destination_address: u64,
+    ) -> Result<Vec<u8>, String> {
+        self.inner
+            .assemble_instruction_sequence(&format!("jmp 0x{:X}", destination_address), source_address)
+    }
+
     fn build_no_operation_fill(
         &self,
         byte_count: usize,
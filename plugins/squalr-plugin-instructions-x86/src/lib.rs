@@ -20,9 +20,9 @@ pub use plugin::X86FamilyInstructionsPlugin;
 
 #[cfg(test)]
 mod tests {
-    use crate::{DataTypeInstructionX64, DataTypeInstructionX86, X86FamilyInstructionsPlugin, X86InstructionSet};
+    use crate::{DataTypeInstructionX64, DataTypeInstructionX86, X64InstructionSet, X86FamilyInstructionsPlugin, X86InstructionSet};
     use squalr_engine_api::{
-        plugins::{Plugin, PluginCapability},
+        plugins::{Plugin, PluginCapability, instruction_set::InstructionSet},
         structures::{
             data_types::data_type::DataType,
             data_values::{
@@ -306,6 +306,36 @@ mod tests {
         assert!(instruction_lines[0].is_control_flow);
     }
 
+    #[test]
+    fn x64_instruction_set_builds_jumps_relative_to_their_address() {
+        let instruction_set = X64InstructionSet::new();
+
+        assert_eq!(instruction_set.build_jump(0x401000, 0x402000), Ok(vec![0xE9, 0xFB, 0x0F, 0x00, 0x00]));
+        assert_eq!(instruction_set.assemble_at("jmp 0x401000", 0x401000), Ok(vec![0xEB, 0xFE]));
+    }
+
+    #[test]
+    fn x64_instruction_set_measures_whole_instructions() {
+        let instruction_set = X64InstructionSet::new();
+
+        assert_eq!(instruction_set.measure_instructions(&[0x55, 0x48, 0x89, 0xE5, 0xB8, 0x01]), Ok(vec![1, 3]));
+    }
+
+    #[test]
+    fn x64_instruction_set_relocates_rip_relative_operands_and_widens_short_branches() {
+        let instruction_set = X64InstructionSet::new();
+        let relocated_bytes = instruction_set
+            .relocate(&[0x48, 0x8B, 0x05, 0x10, 0x00, 0x00, 0x00, 0x74, 0x02], 0x1000, 0x2000)
+            .expect("Expected x64 instructions to relocate.");
+
+        assert_eq!(
+            relocated_bytes,
+            vec![
+                0x48, 0x8B, 0x05, 0x10, 0xF0, 0xFF, 0xFF, 0x0F, 0x84, 0xFE, 0xEF, 0xFF, 0xFF
+            ]
+        );
+    }
+
     #[test]
     fn i_x86_data_type_formats_hexadecimal_bytes() {
         let data_type = DataTypeInstructionX86::new();
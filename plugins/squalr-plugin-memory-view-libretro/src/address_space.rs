@@ -0,0 +1,297 @@
+use squalr_engine_api::structures::pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize;
+
+const WORK_RAM_MODULE_NAME: &str = "wram";
+const EXTERNAL_WORK_RAM_MODULE_NAME: &str = "ewram";
+const INTERNAL_WORK_RAM_MODULE_NAME: &str = "iwram";
+const SYSTEM_RAM_MODULE_NAME: &str = "ram";
+const SAVE_RAM_MODULE_NAME: &str = "sram";
+const VIDEO_RAM_MODULE_NAME: &str = "vram";
+
+const SNES_WORK_RAM_BASE_ADDRESS: u64 = 0x7E_0000;
+const SNES_WORK_RAM_SIZE: u64 = 0x2_0000;
+const SNES_SAVE_RAM_BASE_ADDRESS: u64 = 0x70_0000;
+const SNES_SAVE_RAM_SIZE: u64 = 0x8000;
+const SNES_VIDEO_RAM_SIZE: u64 = 0x1_0000;
+const GBA_EXTERNAL_WORK_RAM_BASE_ADDRESS: u64 = 0x0200_0000;
+const GBA_EXTERNAL_WORK_RAM_SIZE: u64 = 0x4_0000;
+const GBA_INTERNAL_WORK_RAM_BASE_ADDRESS: u64 = 0x0300_0000;
+const GBA_INTERNAL_WORK_RAM_SIZE: u64 = 0x8000;
+const GBA_VIDEO_RAM_BASE_ADDRESS: u64 = 0x0600_0000;
+const GBA_VIDEO_RAM_SIZE: u64 = 0x1_8000;
+const GBA_SAVE_RAM_BASE_ADDRESS: u64 = 0x0E00_0000;
+const GBA_SAVE_RAM_SIZE: u64 = 0x1_0000;
+const GENESIS_WORK_RAM_BASE_ADDRESS: u64 = 0xFF_0000;
+const GENESIS_WORK_RAM_SIZE: u64 = 0x1_0000;
+const GENESIS_SAVE_RAM_BASE_ADDRESS: u64 = 0x20_0000;
+const GENESIS_SAVE_RAM_SIZE: u64 = 0x1_0000;
+const GENESIS_VIDEO_RAM_SIZE: u64 = 0x1_0000;
+/// SNES and Genesis VRAM sit behind the video chip's ports rather than on the CPU bus, so they are placed just above the 24-bit bus.
+const OFF_BUS_VIDEO_RAM_BASE_ADDRESS: u64 = 0x100_0000;
+
+const SNES_REGION_KINDS: [LibretroMemoryRegionKind; 3] = [
+    LibretroMemoryRegionKind::SnesWorkRam,
+    LibretroMemoryRegionKind::SnesSaveRam,
+    LibretroMemoryRegionKind::SnesVideoRam,
+];
+const GBA_REGION_KINDS: [LibretroMemoryRegionKind; 4] = [
+    LibretroMemoryRegionKind::GbaExternalWorkRam,
+    LibretroMemoryRegionKind::GbaInternalWorkRam,
+    LibretroMemoryRegionKind::GbaVideoRam,
+    LibretroMemoryRegionKind::GbaSaveRam,
+];
+const GENESIS_REGION_KINDS: [LibretroMemoryRegionKind; 3] = [
+    LibretroMemoryRegionKind::GenesisWorkRam,
+    LibretroMemoryRegionKind::GenesisSaveRam,
+    LibretroMemoryRegionKind::GenesisVideoRam,
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LibretroConsoleKind {
+    Snes,
+    GameBoyAdvance,
+    Genesis,
+}
+
+impl LibretroConsoleKind {
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            Self::Snes => "SNES",
+            Self::GameBoyAdvance => "Game Boy Advance",
+            Self::Genesis => "Genesis",
+        }
+    }
+
+    /// Gets the memory blocks a core exposes for this console. System RAM always comes first.
+    pub fn get_region_kinds(&self) -> &'static [LibretroMemoryRegionKind] {
+        match self {
+            Self::Snes => &SNES_REGION_KINDS,
+            Self::GameBoyAdvance => &GBA_REGION_KINDS,
+            Self::Genesis => &GENESIS_REGION_KINDS,
+        }
+    }
+
+    /// The 65816 uses 24-bit little-endian long pointers, the ARM7 uses 32-bit little-endian pointers, and the 68000 stores
+    /// 32-bit big-endian pointers on a 24-bit bus.
+    pub fn get_native_pointer_size(&self) -> PointerScanPointerSize {
+        match self {
+            Self::Snes => PointerScanPointerSize::Pointer24,
+            Self::GameBoyAdvance => PointerScanPointerSize::Pointer32,
+            Self::Genesis => PointerScanPointerSize::Pointer32be,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LibretroMemoryRegionKind {
+    SnesWorkRam,
+    SnesSaveRam,
+    SnesVideoRam,
+    GbaExternalWorkRam,
+    GbaInternalWorkRam,
+    GbaVideoRam,
+    GbaSaveRam,
+    GenesisWorkRam,
+    GenesisSaveRam,
+    GenesisVideoRam,
+}
+
+impl LibretroMemoryRegionKind {
+    pub fn get_virtual_base_address(&self) -> u64 {
+        match self {
+            Self::SnesWorkRam => SNES_WORK_RAM_BASE_ADDRESS,
+            Self::SnesSaveRam => SNES_SAVE_RAM_BASE_ADDRESS,
+            Self::GbaExternalWorkRam => GBA_EXTERNAL_WORK_RAM_BASE_ADDRESS,
+            Self::GbaInternalWorkRam => GBA_INTERNAL_WORK_RAM_BASE_ADDRESS,
+            Self::GbaVideoRam => GBA_VIDEO_RAM_BASE_ADDRESS,
+            Self::GbaSaveRam => GBA_SAVE_RAM_BASE_ADDRESS,
+            Self::GenesisWorkRam => GENESIS_WORK_RAM_BASE_ADDRESS,
+            Self::GenesisSaveRam => GENESIS_SAVE_RAM_BASE_ADDRESS,
+            Self::SnesVideoRam | Self::GenesisVideoRam => OFF_BUS_VIDEO_RAM_BASE_ADDRESS,
+        }
+    }
+
+    pub fn get_region_size(&self) -> u64 {
+        match self {
+            Self::SnesWorkRam => SNES_WORK_RAM_SIZE,
+            Self::SnesSaveRam => SNES_SAVE_RAM_SIZE,
+            Self::SnesVideoRam => SNES_VIDEO_RAM_SIZE,
+            Self::GbaExternalWorkRam => GBA_EXTERNAL_WORK_RAM_SIZE,
+            Self::GbaInternalWorkRam => GBA_INTERNAL_WORK_RAM_SIZE,
+            Self::GbaVideoRam => GBA_VIDEO_RAM_SIZE,
+            Self::GbaSaveRam => GBA_SAVE_RAM_SIZE,
+            Self::GenesisWorkRam => GENESIS_WORK_RAM_SIZE,
+            Self::GenesisSaveRam => GENESIS_SAVE_RAM_SIZE,
+            Self::GenesisVideoRam => GENESIS_VIDEO_RAM_SIZE,
+        }
+    }
+
+    pub fn get_module_name(&self) -> &'static str {
+        match self {
+            Self::SnesWorkRam => WORK_RAM_MODULE_NAME,
+            Self::GbaExternalWorkRam => EXTERNAL_WORK_RAM_MODULE_NAME,
+            Self::GbaInternalWorkRam => INTERNAL_WORK_RAM_MODULE_NAME,
+            Self::GenesisWorkRam => SYSTEM_RAM_MODULE_NAME,
+            Self::SnesSaveRam | Self::GbaSaveRam | Self::GenesisSaveRam => SAVE_RAM_MODULE_NAME,
+            Self::SnesVideoRam | Self::GbaVideoRam | Self::GenesisVideoRam => VIDEO_RAM_MODULE_NAME,
+        }
+    }
+
+    /// Off-bus VRAM can never be the target of a guest pointer, so pointer scans skip it.
+    pub fn is_cpu_addressable(&self) -> bool {
+        !matches!(self, Self::SnesVideoRam | Self::GenesisVideoRam)
+    }
+
+    /// Genesis Plus GX stores 68000 RAM and VRAM as native 16-bit words, so each byte pair is swapped on little-endian hosts.
+    pub fn is_word_swapped(&self) -> bool {
+        matches!(self, Self::GenesisWorkRam | Self::GenesisVideoRam)
+    }
+
+    pub fn contains_virtual_address(
+        &self,
+        virtual_address: u64,
+    ) -> bool {
+        let virtual_base_address = self.get_virtual_base_address();
+        let virtual_end_address = virtual_base_address.saturating_add(self.get_region_size());
+
+        virtual_address >= virtual_base_address && virtual_address < virtual_end_address
+    }
+
+    pub fn virtual_offset(
+        &self,
+        virtual_address: u64,
+    ) -> Option<u64> {
+        self.contains_virtual_address(virtual_address)
+            .then(|| virtual_address.saturating_sub(self.get_virtual_base_address()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LibretroMemoryRegionDescriptor {
+    region_kind: LibretroMemoryRegionKind,
+    host_base_address: u64,
+}
+
+impl LibretroMemoryRegionDescriptor {
+    pub fn new(
+        region_kind: LibretroMemoryRegionKind,
+        host_base_address: u64,
+    ) -> Self {
+        Self {
+            region_kind,
+            host_base_address,
+        }
+    }
+
+    pub fn get_region_kind(&self) -> LibretroMemoryRegionKind {
+        self.region_kind
+    }
+
+    pub fn get_module_name(&self) -> &'static str {
+        self.region_kind.get_module_name()
+    }
+
+    pub fn get_virtual_base_address(&self) -> u64 {
+        self.region_kind.get_virtual_base_address()
+    }
+
+    pub fn get_region_size(&self) -> u64 {
+        self.region_kind.get_region_size()
+    }
+
+    pub fn get_host_base_address(&self) -> u64 {
+        self.host_base_address
+    }
+
+    pub fn is_cpu_addressable(&self) -> bool {
+        self.region_kind.is_cpu_addressable()
+    }
+
+    pub fn is_word_swapped(&self) -> bool {
+        self.region_kind.is_word_swapped()
+    }
+
+    pub fn contains_virtual_address(
+        &self,
+        virtual_address: u64,
+    ) -> bool {
+        self.region_kind.contains_virtual_address(virtual_address)
+    }
+
+    pub fn virtual_to_host_address(
+        &self,
+        virtual_address: u64,
+    ) -> Option<u64> {
+        self.region_kind
+            .virtual_offset(virtual_address)
+            .map(|virtual_offset| self.host_base_address.saturating_add(virtual_offset))
+    }
+
+    pub fn module_relative_address(
+        &self,
+        virtual_address: u64,
+    ) -> Option<(String, u64)> {
+        self.region_kind
+            .virtual_offset(virtual_address)
+            .map(|virtual_offset| (self.get_module_name().to_string(), virtual_offset))
+    }
+}
+
+pub fn find_libretro_region_by_virtual_address(
+    region_descriptors: &[LibretroMemoryRegionDescriptor],
+    virtual_address: u64,
+) -> Option<LibretroMemoryRegionDescriptor> {
+    region_descriptors
+        .iter()
+        .copied()
+        .find(|region_descriptor| region_descriptor.contains_virtual_address(virtual_address))
+}
+
+pub fn find_libretro_region_by_module_name(
+    region_descriptors: &[LibretroMemoryRegionDescriptor],
+    module_name: &str,
+) -> Option<LibretroMemoryRegionDescriptor> {
+    region_descriptors.iter().copied().find(|region_descriptor| {
+        region_descriptor
+            .get_module_name()
+            .eq_ignore_ascii_case(module_name)
+    })
+}
+
+/// Module names such as `sram` and `vram` are shared between consoles, so resolution depends on the console being emulated.
+pub fn resolve_virtual_address_from_module(
+    console_kind: LibretroConsoleKind,
+    module_name: &str,
+    module_offset: u64,
+) -> Option<u64> {
+    console_kind
+        .get_region_kinds()
+        .iter()
+        .find(|region_kind| region_kind.get_module_name().eq_ignore_ascii_case(module_name))
+        .filter(|region_kind| module_offset < region_kind.get_region_size())
+        .map(|region_kind| {
+            region_kind
+                .get_virtual_base_address()
+                .saturating_add(module_offset)
+        })
+}
+
+/// Widens a guest byte range to whole 16-bit words, which is the granularity word-swapped regions are stored in.
+pub fn align_to_word_range(
+    virtual_address: u64,
+    value_count: usize,
+) -> (u64, usize) {
+    let aligned_start_address = virtual_address & !1;
+    let aligned_end_address = virtual_address
+        .saturating_add(value_count as u64)
+        .saturating_add(1)
+        & !1;
+
+    (aligned_start_address, aligned_end_address.saturating_sub(aligned_start_address) as usize)
+}
+
+/// Converts between guest byte order and word-swapped host storage. The conversion is its own inverse.
+pub fn swap_word_bytes(word_bytes: &mut [u8]) {
+    for word in word_bytes.chunks_exact_mut(2) {
+        word.swap(0, 1);
+    }
+}
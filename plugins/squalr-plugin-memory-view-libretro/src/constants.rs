@@ -0,0 +1,4 @@
+pub const LIBRETRO_PLUGIN_ID: &str = "builtin.memory-view.libretro";
+pub const LIBRETRO_PLUGIN_DISPLAY_NAME: &str = "Libretro Memory View";
+pub const LIBRETRO_PLUGIN_DESCRIPTION: &str =
+    "Exposes SNES, Game Boy Advance and Genesis memory from RetroArch cores and standalone emulators as a canonical virtual address space.";
@@ -0,0 +1,122 @@
+use crate::{
+    address_space::{LibretroConsoleKind, LibretroMemoryRegionDescriptor},
+    constants::LIBRETRO_PLUGIN_ID,
+    process_detection::{LibretroFrontendKind, detect_libretro_core_console, detect_libretro_frontend},
+};
+use squalr_engine_api::{
+    plugins::memory_view::MemoryViewPluginError,
+    structures::{memory::normalized_region::NormalizedRegion, processes::opened_process_info::OpenedProcessInfo},
+};
+use squalr_engine_targets_native::memory_queryer::{memory_queryer::MemoryQueryer, memory_queryer_trait::MemoryQueryerTrait};
+
+/// Cores allocate their memory blocks together at startup, so companions of a system RAM candidate are only searched this close to it.
+const COMPANION_SEARCH_DISTANCE: u64 = 0x0100_0000;
+
+pub(crate) fn discover_libretro_memory_regions(
+    opened_process_info: &OpenedProcessInfo
+) -> Result<(LibretroConsoleKind, Vec<LibretroMemoryRegionDescriptor>), MemoryViewPluginError> {
+    let console_kind = match detect_libretro_frontend(opened_process_info.get_name()) {
+        Some(LibretroFrontendKind::Standalone(console_kind)) => console_kind,
+        Some(LibretroFrontendKind::RetroArch) => {
+            let module_names: Vec<String> = MemoryQueryer::get_instance()
+                .get_modules(opened_process_info)
+                .into_iter()
+                .map(|module| module.get_module_name().to_string())
+                .collect();
+
+            select_libretro_core_console(&module_names).ok_or_else(|| {
+                MemoryViewPluginError::unavailable(
+                    LIBRETRO_PLUGIN_ID,
+                    format!("no supported libretro core is loaded in process `{}`", opened_process_info.get_name()),
+                )
+            })?
+        }
+        None => {
+            return Err(MemoryViewPluginError::unavailable(
+                LIBRETRO_PLUGIN_ID,
+                format!("process `{}` is not a supported libretro frontend", opened_process_info.get_name()),
+            ));
+        }
+    };
+    let raw_memory_regions = MemoryQueryer::query_pages_by_address_range(
+        opened_process_info,
+        0,
+        MemoryQueryer::get_instance().get_max_usermode_address(opened_process_info),
+    );
+    let discovered_region_descriptors = select_libretro_memory_regions(console_kind, &raw_memory_regions);
+
+    if discovered_region_descriptors.is_empty() {
+        return Err(MemoryViewPluginError::unavailable(
+            LIBRETRO_PLUGIN_ID,
+            format!(
+                "no {} memory regions are currently exposed for process `{}`",
+                console_kind.get_display_name(),
+                opened_process_info.get_name()
+            ),
+        ));
+    }
+
+    Ok((console_kind, discovered_region_descriptors))
+}
+
+pub(crate) fn select_libretro_core_console(module_names: &[String]) -> Option<LibretroConsoleKind> {
+    module_names
+        .iter()
+        .find_map(|module_name| detect_libretro_core_console(module_name))
+}
+
+/// Cores allocate each memory block on its own, so blocks are matched by their exact allocation size. Every system RAM candidate
+/// collects the nearest unused companion blocks, and the candidate with the most companions wins (ties go to the lowest address).
+pub(crate) fn select_libretro_memory_regions(
+    console_kind: LibretroConsoleKind,
+    raw_memory_regions: &[NormalizedRegion],
+) -> Vec<LibretroMemoryRegionDescriptor> {
+    let Some((system_ram_kind, companion_kinds)) = console_kind.get_region_kinds().split_first() else {
+        return Vec::new();
+    };
+    let mut sorted_memory_regions = raw_memory_regions.to_vec();
+    let mut best_region_descriptors: Vec<LibretroMemoryRegionDescriptor> = Vec::new();
+
+    sorted_memory_regions.sort_by_key(|memory_region| memory_region.get_base_address());
+
+    for system_ram_region in sorted_memory_regions
+        .iter()
+        .filter(|memory_region| memory_region.get_region_size() == system_ram_kind.get_region_size())
+    {
+        let system_ram_host_address = system_ram_region.get_base_address();
+        let mut used_host_addresses = vec![system_ram_host_address];
+        let mut region_descriptors = vec![LibretroMemoryRegionDescriptor::new(
+            *system_ram_kind,
+            system_ram_host_address,
+        )];
+
+        for companion_kind in companion_kinds {
+            let companion_region = sorted_memory_regions
+                .iter()
+                .filter(|memory_region| memory_region.get_region_size() == companion_kind.get_region_size())
+                .filter(|memory_region| !used_host_addresses.contains(&memory_region.get_base_address()))
+                .filter(|memory_region| {
+                    memory_region
+                        .get_base_address()
+                        .abs_diff(system_ram_host_address)
+                        <= COMPANION_SEARCH_DISTANCE
+                })
+                .min_by_key(|memory_region| {
+                    memory_region
+                        .get_base_address()
+                        .abs_diff(system_ram_host_address)
+                });
+
+            if let Some(companion_region) = companion_region {
+                used_host_addresses.push(companion_region.get_base_address());
+                region_descriptors.push(LibretroMemoryRegionDescriptor::new(*companion_kind, companion_region.get_base_address()));
+            }
+        }
+
+        if region_descriptors.len() > best_region_descriptors.len() {
+            best_region_descriptors = region_descriptors;
+        }
+    }
+
+    best_region_descriptors
+}
@@ -0,0 +1,430 @@
+use crate::{
+    address_space::{
+        LibretroConsoleKind, LibretroMemoryRegionDescriptor, align_to_word_range, find_libretro_region_by_virtual_address, resolve_virtual_address_from_module,
+        swap_word_bytes,
+    },
+    constants::LIBRETRO_PLUGIN_ID,
+    discovery::discover_libretro_memory_regions,
+};
+use squalr_engine_api::{
+    plugins::memory_view::{MemoryViewInstance, MemoryViewPluginError, PageRetrievalMode},
+    structures::{
+        memory::{
+            normalized_module::{ModuleAddressDisplay, NormalizedModule},
+            normalized_region::NormalizedRegion,
+        },
+        pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize,
+        processes::opened_process_info::OpenedProcessInfo,
+    },
+};
+use squalr_engine_targets_native::{
+    memory_reader::{MemoryReader, memory_reader_trait::MemoryReaderTrait},
+    memory_writer::{MemoryWriter, memory_writer_trait::MemoryWriterTrait},
+};
+use std::{
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+const DISCOVERY_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Default)]
+struct LibretroDiscoveryCache {
+    discovered_console_kind: Option<LibretroConsoleKind>,
+    discovered_region_descriptors: Option<Vec<LibretroMemoryRegionDescriptor>>,
+    unavailable_reason: Option<String>,
+    last_refresh_at: Option<Instant>,
+}
+
+pub(crate) struct LibretroMemoryViewInstance {
+    opened_process_info: OpenedProcessInfo,
+    discovery_cache: RwLock<LibretroDiscoveryCache>,
+}
+
+impl LibretroMemoryViewInstance {
+    pub(crate) fn new(process_info: OpenedProcessInfo) -> Self {
+        Self {
+            opened_process_info: process_info,
+            discovery_cache: RwLock::new(LibretroDiscoveryCache::default()),
+        }
+    }
+
+    fn read_discovery_cache(&self) -> Option<Result<(LibretroConsoleKind, Vec<LibretroMemoryRegionDescriptor>), MemoryViewPluginError>> {
+        let discovery_cache = self.discovery_cache.read().ok()?;
+
+        if let (Some(discovered_console_kind), Some(discovered_region_descriptors)) =
+            (discovery_cache.discovered_console_kind, discovery_cache.discovered_region_descriptors.as_ref())
+        {
+            return Some(Ok((discovered_console_kind, discovered_region_descriptors.clone())));
+        }
+
+        discovery_cache
+            .unavailable_reason
+            .as_ref()
+            .map(|unavailable_reason| Err(MemoryViewPluginError::unavailable(self.plugin_id(), unavailable_reason.clone())))
+    }
+
+    fn get_or_discover_memory_layout(&self) -> Result<(LibretroConsoleKind, Vec<LibretroMemoryRegionDescriptor>), MemoryViewPluginError> {
+        if let Some(discovery_result) = self.read_discovery_cache() {
+            return discovery_result;
+        }
+
+        self.refresh_discovery_cache()?;
+
+        if let Some(discovery_result) = self.read_discovery_cache() {
+            return discovery_result;
+        }
+
+        Err(MemoryViewPluginError::unavailable(
+            self.plugin_id(),
+            "no libretro memory regions are currently exposed".to_string(),
+        ))
+    }
+
+    fn get_or_discover_region_descriptors(&self) -> Result<Vec<LibretroMemoryRegionDescriptor>, MemoryViewPluginError> {
+        self.get_or_discover_memory_layout()
+            .map(|(_, discovered_region_descriptors)| discovered_region_descriptors)
+    }
+
+    fn should_refresh_discovery_cache(&self) -> bool {
+        match self.discovery_cache.read() {
+            Ok(discovery_cache) => discovery_cache
+                .last_refresh_at
+                .map(|last_refresh_at| last_refresh_at.elapsed() >= DISCOVERY_REFRESH_INTERVAL)
+                .unwrap_or(true),
+            Err(_) => true,
+        }
+    }
+
+    fn refresh_discovery_cache(&self) -> Result<(), MemoryViewPluginError> {
+        if !self.should_refresh_discovery_cache() {
+            return match self.discovery_cache.read() {
+                Ok(discovery_cache) => {
+                    if discovery_cache.discovered_region_descriptors.is_some() {
+                        Ok(())
+                    } else if let Some(unavailable_reason) = discovery_cache.unavailable_reason.as_ref() {
+                        Err(MemoryViewPluginError::unavailable(self.plugin_id(), unavailable_reason.clone()))
+                    } else {
+                        Ok(())
+                    }
+                }
+                Err(error) => Err(MemoryViewPluginError::message(
+                    self.plugin_id(),
+                    format!("failed to access libretro discovery cache: {}", error),
+                )),
+            };
+        }
+
+        let discovery_result = discover_libretro_memory_regions(&self.opened_process_info);
+
+        match self.discovery_cache.write() {
+            Ok(mut discovery_cache) => {
+                discovery_cache.last_refresh_at = Some(Instant::now());
+
+                match discovery_result {
+                    Ok((discovered_console_kind, discovered_region_descriptors)) => {
+                        discovery_cache.discovered_console_kind = Some(discovered_console_kind);
+                        discovery_cache.discovered_region_descriptors = Some(discovered_region_descriptors);
+                        discovery_cache.unavailable_reason = None;
+                        Ok(())
+                    }
+                    Err(error) => {
+                        discovery_cache.discovered_console_kind = None;
+                        discovery_cache.discovered_region_descriptors = None;
+                        discovery_cache.unavailable_reason = if error.is_unavailable() { Some(error.to_string()) } else { None };
+                        Err(error)
+                    }
+                }
+            }
+            Err(error) => Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!("failed to update libretro memory-region cache: {}", error),
+            )),
+        }
+    }
+
+    fn build_virtual_pages(
+        &self,
+        page_retrieval_mode: PageRetrievalMode,
+        discovered_region_descriptors: &[LibretroMemoryRegionDescriptor],
+    ) -> Vec<NormalizedRegion> {
+        match page_retrieval_mode {
+            PageRetrievalMode::FromNonModules => Vec::new(),
+            PageRetrievalMode::FromSettings | PageRetrievalMode::FromUserMode | PageRetrievalMode::FromModules | PageRetrievalMode::FromVirtualModules => {
+                discovered_region_descriptors
+                    .iter()
+                    .map(|region_descriptor| NormalizedRegion::new(region_descriptor.get_virtual_base_address(), region_descriptor.get_region_size()))
+                    .collect()
+            }
+        }
+    }
+
+    /// Every region sits at its console-native address, so modules display absolute addresses.
+    fn build_modules(
+        &self,
+        discovered_region_descriptors: &[LibretroMemoryRegionDescriptor],
+    ) -> Vec<NormalizedModule> {
+        discovered_region_descriptors
+            .iter()
+            .map(|region_descriptor| {
+                NormalizedModule::new_with_display(
+                    region_descriptor.get_module_name(),
+                    region_descriptor.get_virtual_base_address(),
+                    region_descriptor.get_region_size(),
+                    ModuleAddressDisplay::AbsoluteAddress,
+                )
+            })
+            .collect()
+    }
+
+    fn build_pointer_scan_virtual_pages(
+        &self,
+        discovered_region_descriptors: &[LibretroMemoryRegionDescriptor],
+    ) -> Vec<NormalizedRegion> {
+        discovered_region_descriptors
+            .iter()
+            .filter(|region_descriptor| region_descriptor.is_cpu_addressable())
+            .map(|region_descriptor| NormalizedRegion::new(region_descriptor.get_virtual_base_address(), region_descriptor.get_region_size()))
+            .collect()
+    }
+
+    fn translate_virtual_range(
+        &self,
+        virtual_address: u64,
+        value_count: usize,
+    ) -> Result<(LibretroMemoryRegionDescriptor, u64), MemoryViewPluginError> {
+        let discovered_region_descriptors = self.get_or_discover_region_descriptors()?;
+        let Some(region_descriptor) = find_libretro_region_by_virtual_address(&discovered_region_descriptors, virtual_address) else {
+            return Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!("virtual address {:#X} is not within a discovered libretro memory region", virtual_address),
+            ));
+        };
+
+        let Some(host_address) = region_descriptor.virtual_to_host_address(virtual_address) else {
+            return Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!("virtual address {:#X} could not be translated to a host address", virtual_address),
+            ));
+        };
+        let virtual_end_address = virtual_address.saturating_add(value_count as u64);
+        let region_end_address = region_descriptor
+            .get_virtual_base_address()
+            .saturating_add(region_descriptor.get_region_size());
+
+        if virtual_end_address > region_end_address {
+            return Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!(
+                    "virtual range {:#X}-{:#X} crosses the end of libretro region `{}`",
+                    virtual_address,
+                    virtual_end_address,
+                    region_descriptor.get_module_name()
+                ),
+            ));
+        }
+
+        Ok((region_descriptor, host_address))
+    }
+
+    /// Reads whole host words around the requested range and restores guest byte order.
+    fn read_word_swapped_bytes(
+        &self,
+        host_address: u64,
+        values: &mut [u8],
+    ) -> bool {
+        let (aligned_host_address, aligned_byte_count) = align_to_word_range(host_address, values.len());
+        let leading_byte_count = host_address.saturating_sub(aligned_host_address) as usize;
+        let mut word_bytes = vec![0u8; aligned_byte_count];
+
+        if !MemoryReader::get_instance().read_bytes(&self.opened_process_info, aligned_host_address, &mut word_bytes) {
+            return false;
+        }
+
+        swap_word_bytes(&mut word_bytes);
+        values.copy_from_slice(&word_bytes[leading_byte_count..leading_byte_count + values.len()]);
+
+        true
+    }
+
+    /// Splices guest-order bytes into the surrounding host words, preserving the bytes outside the range.
+    fn write_word_swapped_bytes(
+        &self,
+        host_address: u64,
+        values: &[u8],
+    ) -> bool {
+        let (aligned_host_address, aligned_byte_count) = align_to_word_range(host_address, values.len());
+        let leading_byte_count = host_address.saturating_sub(aligned_host_address) as usize;
+        let mut word_bytes = vec![0u8; aligned_byte_count];
+
+        if !MemoryReader::get_instance().read_bytes(&self.opened_process_info, aligned_host_address, &mut word_bytes) {
+            return false;
+        }
+
+        swap_word_bytes(&mut word_bytes);
+        word_bytes[leading_byte_count..leading_byte_count + values.len()].copy_from_slice(values);
+        swap_word_bytes(&mut word_bytes);
+
+        MemoryWriter::get_instance().write_bytes(&self.opened_process_info, aligned_host_address, &word_bytes)
+    }
+}
+
+impl MemoryViewInstance for LibretroMemoryViewInstance {
+    fn plugin_id(&self) -> &str {
+        LIBRETRO_PLUGIN_ID
+    }
+
+    fn owns_address(
+        &self,
+        address: u64,
+    ) -> bool {
+        self.get_or_discover_region_descriptors()
+            .map(|discovered_region_descriptors| {
+                discovered_region_descriptors
+                    .iter()
+                    .any(|region_descriptor| region_descriptor.contains_virtual_address(address))
+            })
+            .unwrap_or(false)
+    }
+
+    fn refresh(&mut self) -> Result<(), MemoryViewPluginError> {
+        self.refresh_discovery_cache()
+    }
+
+    fn get_virtual_pages(
+        &self,
+        page_retrieval_mode: PageRetrievalMode,
+    ) -> Result<Vec<NormalizedRegion>, MemoryViewPluginError> {
+        let discovered_region_descriptors = self.get_or_discover_region_descriptors()?;
+
+        Ok(self.build_virtual_pages(page_retrieval_mode, &discovered_region_descriptors))
+    }
+
+    fn get_modules(&self) -> Result<Vec<NormalizedModule>, MemoryViewPluginError> {
+        let discovered_region_descriptors = self.get_or_discover_region_descriptors()?;
+
+        Ok(self.build_modules(&discovered_region_descriptors))
+    }
+
+    fn get_pointer_scan_memory_regions(
+        &self,
+        page_retrieval_mode: PageRetrievalMode,
+        _target_address: u64,
+    ) -> Result<Option<Vec<NormalizedRegion>>, MemoryViewPluginError> {
+        if page_retrieval_mode != PageRetrievalMode::FromVirtualModules {
+            return Ok(None);
+        }
+
+        let discovered_region_descriptors = self.get_or_discover_region_descriptors()?;
+
+        Ok(Some(self.build_pointer_scan_virtual_pages(&discovered_region_descriptors)))
+    }
+
+    fn get_native_pointer_size(&self) -> Option<PointerScanPointerSize> {
+        self.get_or_discover_memory_layout()
+            .ok()
+            .map(|(discovered_console_kind, _)| discovered_console_kind.get_native_pointer_size())
+    }
+
+    fn address_to_module(
+        &self,
+        address: u64,
+        modules: &[NormalizedModule],
+    ) -> Option<(String, u64)> {
+        if let Ok(discovered_region_descriptors) = self.get_or_discover_region_descriptors()
+            && let Some(region_descriptor) = find_libretro_region_by_virtual_address(&discovered_region_descriptors, address)
+        {
+            return region_descriptor.module_relative_address(address);
+        }
+
+        modules
+            .iter()
+            .find(|module| module.contains_address(address))
+            .map(|module| (module.get_module_name().to_string(), address.saturating_sub(module.get_base_address())))
+    }
+
+    fn resolve_module(
+        &self,
+        modules: &[NormalizedModule],
+        identifier: &str,
+    ) -> u64 {
+        if let Ok((discovered_console_kind, _)) = self.get_or_discover_memory_layout()
+            && let Some(virtual_address) = resolve_virtual_address_from_module(discovered_console_kind, identifier, 0)
+        {
+            return virtual_address;
+        }
+
+        modules
+            .iter()
+            .find(|module| module.get_module_name().eq_ignore_ascii_case(identifier))
+            .map(|module| module.get_base_address())
+            .unwrap_or(0)
+    }
+
+    fn resolve_module_address(
+        &self,
+        modules: &[NormalizedModule],
+        identifier: &str,
+        offset: u64,
+    ) -> Option<u64> {
+        if let Ok((discovered_console_kind, discovered_region_descriptors)) = self.get_or_discover_memory_layout()
+            && let Some(virtual_address) = resolve_virtual_address_from_module(discovered_console_kind, identifier, offset)
+        {
+            let region_descriptor = find_libretro_region_by_virtual_address(&discovered_region_descriptors, virtual_address)?;
+
+            return region_descriptor
+                .get_module_name()
+                .eq_ignore_ascii_case(identifier)
+                .then_some(virtual_address);
+        }
+
+        modules
+            .iter()
+            .find(|module| module.get_module_name().eq_ignore_ascii_case(identifier))
+            .and_then(|module| module.get_base_address().checked_add(offset))
+    }
+
+    fn read_bytes(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> Result<(), MemoryViewPluginError> {
+        let (region_descriptor, host_address) = self.translate_virtual_range(address, values.len())?;
+        let read_succeeded = if region_descriptor.is_word_swapped() {
+            self.read_word_swapped_bytes(host_address, values)
+        } else {
+            MemoryReader::get_instance().read_bytes(&self.opened_process_info, host_address, values)
+        };
+
+        if read_succeeded {
+            Ok(())
+        } else {
+            Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!("failed to read libretro host memory at translated address {:#X}", host_address),
+            ))
+        }
+    }
+
+    fn write_bytes(
+        &self,
+        address: u64,
+        values: &[u8],
+    ) -> Result<(), MemoryViewPluginError> {
+        let (region_descriptor, host_address) = self.translate_virtual_range(address, values.len())?;
+        let write_succeeded = if region_descriptor.is_word_swapped() {
+            self.write_word_swapped_bytes(host_address, values)
+        } else {
+            MemoryWriter::get_instance().write_bytes(&self.opened_process_info, host_address, values)
+        };
+
+        if write_succeeded {
+            Ok(())
+        } else {
+            Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!("failed to write libretro host memory at translated address {:#X}", host_address),
+            ))
+        }
+    }
+}
@@ -0,0 +1,12 @@
+pub mod address_space;
+
+mod constants;
+mod discovery;
+mod instance;
+mod plugin;
+mod process_detection;
+
+pub use plugin::LibretroMemoryViewPlugin;
+
+#[cfg(test)]
+mod tests;
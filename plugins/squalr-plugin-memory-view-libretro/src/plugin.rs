@@ -0,0 +1,67 @@
+use crate::{
+    constants::{LIBRETRO_PLUGIN_DESCRIPTION, LIBRETRO_PLUGIN_DISPLAY_NAME, LIBRETRO_PLUGIN_ID},
+    instance::LibretroMemoryViewInstance,
+    process_detection::detect_libretro_frontend,
+};
+use squalr_engine_api::{
+    plugins::memory_view::{MemoryViewInstance, MemoryViewPlugin, MemoryViewPluginError},
+    plugins::{Plugin, PluginCapability, PluginMetadata, PluginPackage, PluginPermission},
+    structures::processes::opened_process_info::OpenedProcessInfo,
+};
+
+pub struct LibretroMemoryViewPlugin {
+    metadata: PluginMetadata,
+}
+
+impl LibretroMemoryViewPlugin {
+    pub fn new() -> Self {
+        Self {
+            metadata: PluginMetadata::new_with_permissions(
+                LIBRETRO_PLUGIN_ID,
+                LIBRETRO_PLUGIN_DISPLAY_NAME,
+                LIBRETRO_PLUGIN_DESCRIPTION,
+                vec![PluginCapability::MemoryView],
+                vec![
+                    PluginPermission::ReadProcessMemory,
+                    PluginPermission::WriteProcessMemory,
+                ],
+                true,
+                true,
+            ),
+        }
+    }
+}
+
+impl Default for LibretroMemoryViewPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for LibretroMemoryViewPlugin {
+    fn metadata(&self) -> &PluginMetadata {
+        &self.metadata
+    }
+}
+
+impl PluginPackage for LibretroMemoryViewPlugin {
+    fn as_memory_view_plugin(&self) -> Option<&dyn MemoryViewPlugin> {
+        Some(self)
+    }
+}
+
+impl MemoryViewPlugin for LibretroMemoryViewPlugin {
+    fn can_attach(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> bool {
+        detect_libretro_frontend(process_info.get_name()).is_some()
+    }
+
+    fn create_instance(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<Box<dyn MemoryViewInstance>, MemoryViewPluginError> {
+        Ok(Box::new(LibretroMemoryViewInstance::new(process_info.clone())))
+    }
+}
@@ -0,0 +1,51 @@
+use crate::address_space::LibretroConsoleKind;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LibretroFrontendKind {
+    /// RetroArch hosts any core, so the console is only known once a core module is loaded.
+    RetroArch,
+    Standalone(LibretroConsoleKind),
+}
+
+pub fn detect_libretro_frontend(process_name: &str) -> Option<LibretroFrontendKind> {
+    let normalized_process_name = normalize_name(process_name);
+
+    if normalized_process_name.contains("retroarch") {
+        Some(LibretroFrontendKind::RetroArch)
+    } else if normalized_process_name.contains("snes9x") {
+        Some(LibretroFrontendKind::Standalone(LibretroConsoleKind::Snes))
+    } else if normalized_process_name.contains("mgba") {
+        Some(LibretroFrontendKind::Standalone(LibretroConsoleKind::GameBoyAdvance))
+    } else if normalized_process_name.contains("genesisplusgx") || normalized_process_name.contains("gpgx") {
+        Some(LibretroFrontendKind::Standalone(LibretroConsoleKind::Genesis))
+    } else {
+        None
+    }
+}
+
+/// Maps a loaded libretro core module (for example `snes9x_libretro.dll`) to the console it emulates.
+pub fn detect_libretro_core_console(module_name: &str) -> Option<LibretroConsoleKind> {
+    let normalized_module_name = normalize_name(module_name);
+
+    if !normalized_module_name.contains("libretro") {
+        return None;
+    }
+
+    if normalized_module_name.contains("snes9x") || normalized_module_name.contains("bsnes") {
+        Some(LibretroConsoleKind::Snes)
+    } else if normalized_module_name.contains("mgba") || normalized_module_name.contains("vbanext") || normalized_module_name.contains("gpsp") {
+        Some(LibretroConsoleKind::GameBoyAdvance)
+    } else if normalized_module_name.contains("genesisplusgx") || normalized_module_name.contains("picodrive") {
+        Some(LibretroConsoleKind::Genesis)
+    } else {
+        None
+    }
+}
+
+/// Lowercases the name and drops separators, so `Genesis Plus GX`, `genesis_plus_gx` and `genesis-plus-gx` compare equal.
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|character| character.is_ascii_alphanumeric())
+        .map(|character| character.to_ascii_lowercase())
+        .collect()
+}
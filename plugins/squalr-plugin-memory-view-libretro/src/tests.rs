@@ -0,0 +1,213 @@
+use crate::{
+    LibretroMemoryViewPlugin,
+    address_space::{
+        LibretroConsoleKind, LibretroMemoryRegionDescriptor, LibretroMemoryRegionKind, align_to_word_range, find_libretro_region_by_module_name,
+        find_libretro_region_by_virtual_address, resolve_virtual_address_from_module, swap_word_bytes,
+    },
+    discovery::{select_libretro_core_console, select_libretro_memory_regions},
+    process_detection::{LibretroFrontendKind, detect_libretro_core_console, detect_libretro_frontend},
+};
+use squalr_engine_api::{
+    plugins::memory_view::MemoryViewPlugin,
+    structures::{
+        memory::{bitness::Bitness, normalized_region::NormalizedRegion},
+        pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize,
+        processes::opened_process_info::OpenedProcessInfo,
+    },
+};
+
+fn get_region_kinds(region_descriptors: &[LibretroMemoryRegionDescriptor]) -> Vec<LibretroMemoryRegionKind> {
+    region_descriptors
+        .iter()
+        .map(|region_descriptor| region_descriptor.get_region_kind())
+        .collect()
+}
+
+#[test]
+fn libretro_plugin_matches_retroarch_and_standalone_emulator_process_names() {
+    let plugin = LibretroMemoryViewPlugin::new();
+
+    assert!(plugin.can_attach(&OpenedProcessInfo::new(1, "retroarch.exe".to_string(), 0, Bitness::Bit64, None)));
+    assert!(plugin.can_attach(&OpenedProcessInfo::new(1, "mGBA.exe".to_string(), 0, Bitness::Bit64, None)));
+    assert!(plugin.can_attach(&OpenedProcessInfo::new(1, "snes9x-x64.exe".to_string(), 0, Bitness::Bit64, None)));
+    assert!(plugin.can_attach(&OpenedProcessInfo::new(1, "Genesis Plus GX".to_string(), 0, Bitness::Bit64, None)));
+    assert!(!plugin.can_attach(&OpenedProcessInfo::new(1, "Dolphin.exe".to_string(), 0, Bitness::Bit64, None)));
+}
+
+#[test]
+fn process_detection_maps_standalone_emulators_to_consoles() {
+    assert_eq!(detect_libretro_frontend("RetroArch"), Some(LibretroFrontendKind::RetroArch));
+    assert_eq!(
+        detect_libretro_frontend("Snes9x.exe"),
+        Some(LibretroFrontendKind::Standalone(LibretroConsoleKind::Snes))
+    );
+    assert_eq!(
+        detect_libretro_frontend("mgba-qt"),
+        Some(LibretroFrontendKind::Standalone(LibretroConsoleKind::GameBoyAdvance))
+    );
+    assert_eq!(
+        detect_libretro_frontend("genesis_plus_gx"),
+        Some(LibretroFrontendKind::Standalone(LibretroConsoleKind::Genesis))
+    );
+    assert_eq!(detect_libretro_frontend("pcsx2-qt.exe"), None);
+}
+
+#[test]
+fn core_detection_identifies_loaded_libretro_cores() {
+    assert_eq!(detect_libretro_core_console("snes9x_libretro.dll"), Some(LibretroConsoleKind::Snes));
+    assert_eq!(detect_libretro_core_console("mgba_libretro.so"), Some(LibretroConsoleKind::GameBoyAdvance));
+    assert_eq!(
+        detect_libretro_core_console("genesis_plus_gx_libretro.dylib"),
+        Some(LibretroConsoleKind::Genesis)
+    );
+    assert_eq!(detect_libretro_core_console("snes9x.exe"), None);
+    assert_eq!(
+        select_libretro_core_console(&[
+            "retroarch.exe".to_string(),
+            "kernel32.dll".to_string(),
+            "bsnes_libretro.dll".to_string()
+        ]),
+        Some(LibretroConsoleKind::Snes)
+    );
+    assert_eq!(select_libretro_core_console(&["retroarch.exe".to_string()]), None);
+}
+
+#[test]
+fn consoles_choose_native_pointer_sizes() {
+    assert_eq!(LibretroConsoleKind::Snes.get_native_pointer_size(), PointerScanPointerSize::Pointer24);
+    assert_eq!(LibretroConsoleKind::GameBoyAdvance.get_native_pointer_size(), PointerScanPointerSize::Pointer32);
+    assert_eq!(LibretroConsoleKind::Genesis.get_native_pointer_size(), PointerScanPointerSize::Pointer32be);
+}
+
+#[test]
+fn system_ram_uses_console_native_addresses() {
+    let snes_work_ram = LibretroMemoryRegionDescriptor::new(LibretroMemoryRegionKind::SnesWorkRam, 0x7FF6_1000_0000);
+    let gba_external_work_ram = LibretroMemoryRegionDescriptor::new(LibretroMemoryRegionKind::GbaExternalWorkRam, 0x7FF6_2000_0000);
+    let genesis_work_ram = LibretroMemoryRegionDescriptor::new(LibretroMemoryRegionKind::GenesisWorkRam, 0x7FF6_3000_0000);
+
+    assert_eq!(snes_work_ram.virtual_to_host_address(0x7E_0010), Some(0x7FF6_1000_0010));
+    assert_eq!(snes_work_ram.virtual_to_host_address(0x7F_FFFF), Some(0x7FF6_1001_FFFF));
+    assert_eq!(snes_work_ram.virtual_to_host_address(0x80_0000), None);
+    assert_eq!(gba_external_work_ram.virtual_to_host_address(0x0200_1234), Some(0x7FF6_2000_1234));
+    assert_eq!(genesis_work_ram.virtual_to_host_address(0xFF_8000), Some(0x7FF6_3000_8000));
+    assert_eq!(genesis_work_ram.module_relative_address(0xFF_8000), Some(("ram".to_string(), 0x8000)));
+}
+
+#[test]
+fn module_offsets_resolve_per_console() {
+    assert_eq!(resolve_virtual_address_from_module(LibretroConsoleKind::Snes, "wram", 0x10), Some(0x7E_0010));
+    assert_eq!(resolve_virtual_address_from_module(LibretroConsoleKind::Snes, "SRAM", 0x20), Some(0x70_0020));
+    assert_eq!(
+        resolve_virtual_address_from_module(LibretroConsoleKind::GameBoyAdvance, "sram", 0x20),
+        Some(0x0E00_0020)
+    );
+    assert_eq!(
+        resolve_virtual_address_from_module(LibretroConsoleKind::GameBoyAdvance, "iwram", 0x30),
+        Some(0x0300_0030)
+    );
+    assert_eq!(
+        resolve_virtual_address_from_module(LibretroConsoleKind::Genesis, "ram", 0xFFFF),
+        Some(0xFF_FFFF)
+    );
+    assert_eq!(resolve_virtual_address_from_module(LibretroConsoleKind::Genesis, "ram", 0x1_0000), None);
+    assert_eq!(resolve_virtual_address_from_module(LibretroConsoleKind::Genesis, "ewram", 0), None);
+}
+
+#[test]
+fn region_lookup_finds_guest_address_and_module_name_matches() {
+    let region_descriptors = vec![
+        LibretroMemoryRegionDescriptor::new(LibretroMemoryRegionKind::GbaExternalWorkRam, 0x1000_0000),
+        LibretroMemoryRegionDescriptor::new(LibretroMemoryRegionKind::GbaInternalWorkRam, 0x1004_0000),
+        LibretroMemoryRegionDescriptor::new(LibretroMemoryRegionKind::GbaVideoRam, 0x1005_0000),
+    ];
+
+    assert_eq!(
+        find_libretro_region_by_virtual_address(&region_descriptors, 0x0300_0100).map(|region_descriptor| region_descriptor.get_region_kind()),
+        Some(LibretroMemoryRegionKind::GbaInternalWorkRam)
+    );
+    assert_eq!(
+        find_libretro_region_by_module_name(&region_descriptors, "VRAM").map(|region_descriptor| region_descriptor.get_host_base_address()),
+        Some(0x1005_0000)
+    );
+    assert!(find_libretro_region_by_virtual_address(&region_descriptors, 0x0E00_0000).is_none());
+}
+
+#[test]
+fn off_bus_video_ram_is_excluded_from_pointer_scans() {
+    assert!(LibretroMemoryRegionKind::SnesWorkRam.is_cpu_addressable());
+    assert!(LibretroMemoryRegionKind::GbaVideoRam.is_cpu_addressable());
+    assert!(!LibretroMemoryRegionKind::SnesVideoRam.is_cpu_addressable());
+    assert!(!LibretroMemoryRegionKind::GenesisVideoRam.is_cpu_addressable());
+}
+
+#[test]
+fn word_swapped_ranges_widen_to_whole_words() {
+    let mut word_bytes = [0x34, 0x12, 0x78, 0x56];
+
+    swap_word_bytes(&mut word_bytes);
+
+    assert_eq!(word_bytes, [0x12, 0x34, 0x56, 0x78]);
+    assert_eq!(align_to_word_range(0x1000, 2), (0x1000, 2));
+    assert_eq!(align_to_word_range(0x1001, 1), (0x1000, 2));
+    assert_eq!(align_to_word_range(0x1001, 2), (0x1000, 4));
+    assert_eq!(align_to_word_range(0x1000, 3), (0x1000, 4));
+    assert!(LibretroMemoryRegionKind::GenesisWorkRam.is_word_swapped());
+    assert!(!LibretroMemoryRegionKind::GenesisSaveRam.is_word_swapped());
+}
+
+#[test]
+fn discovery_selects_system_ram_with_the_most_nearby_companions() {
+    let raw_memory_regions = vec![
+        // A lone EWRAM-sized allocation far away from the core's other blocks.
+        NormalizedRegion::new(0x0100_0000, 0x4_0000),
+        NormalizedRegion::new(0x2000_0000, 0x4_0000),
+        NormalizedRegion::new(0x2004_0000, 0x8000),
+        NormalizedRegion::new(0x2010_0000, 0x1_8000),
+        NormalizedRegion::new(0x2020_0000, 0x1_0000),
+        NormalizedRegion::new(0x4000_0000, 0x8000),
+    ];
+
+    let region_descriptors = select_libretro_memory_regions(LibretroConsoleKind::GameBoyAdvance, &raw_memory_regions);
+
+    assert_eq!(
+        get_region_kinds(&region_descriptors),
+        vec![
+            LibretroMemoryRegionKind::GbaExternalWorkRam,
+            LibretroMemoryRegionKind::GbaInternalWorkRam,
+            LibretroMemoryRegionKind::GbaVideoRam,
+            LibretroMemoryRegionKind::GbaSaveRam,
+        ]
+    );
+    assert_eq!(region_descriptors[0].get_host_base_address(), 0x2000_0000);
+    assert_eq!(region_descriptors[1].get_host_base_address(), 0x2004_0000);
+}
+
+#[test]
+fn discovery_assigns_equally_sized_blocks_once() {
+    let raw_memory_regions = vec![
+        NormalizedRegion::new(0x3000_0000, 0x1_0000),
+        NormalizedRegion::new(0x3001_0000, 0x1_0000),
+        NormalizedRegion::new(0x3002_0000, 0x1_0000),
+    ];
+
+    let region_descriptors = select_libretro_memory_regions(LibretroConsoleKind::Genesis, &raw_memory_regions);
+
+    assert_eq!(
+        region_descriptors
+            .iter()
+            .map(|region_descriptor| region_descriptor.get_host_base_address())
+            .collect::<Vec<_>>(),
+        vec![0x3000_0000, 0x3001_0000, 0x3002_0000]
+    );
+    assert_eq!(region_descriptors[0].get_region_kind(), LibretroMemoryRegionKind::GenesisWorkRam);
+}
+
+#[test]
+fn discovery_requires_system_ram() {
+    let raw_memory_regions = vec![
+        NormalizedRegion::new(0x3000_0000, 0x8000),
+        NormalizedRegion::new(0x3001_0000, 0x1_0000),
+    ];
+
+    assert!(select_libretro_memory_regions(LibretroConsoleKind::Snes, &raw_memory_regions).is_empty());
+}
@@ -0,0 +1,255 @@
+const EE_RAM_MODULE_NAME: &str = "ee_ram";
+const EE_SCRATCHPAD_MODULE_NAME: &str = "ee_scratchpad";
+const IOP_RAM_MODULE_NAME: &str = "iop_ram";
+const PSP_RAM_MODULE_NAME: &str = "psp_ram";
+const PSP_SCRATCHPAD_MODULE_NAME: &str = "psp_scratchpad";
+const PSP_VRAM_MODULE_NAME: &str = "psp_vram";
+
+const EE_RAM_BASE_ADDRESS: u64 = 0x0000_0000;
+const EE_RAM_SIZE: u64 = 0x0200_0000;
+const EE_SCRATCHPAD_BASE_ADDRESS: u64 = 0x7000_0000;
+const EE_SCRATCHPAD_SIZE: u64 = 0x0000_4000;
+const IOP_RAM_BASE_ADDRESS: u64 = 0x1C00_0000;
+const IOP_RAM_SIZE: u64 = 0x0020_0000;
+const PSP_SCRATCHPAD_BASE_ADDRESS: u64 = 0x0001_0000;
+const PSP_SCRATCHPAD_SIZE: u64 = 0x0000_4000;
+const PSP_VRAM_BASE_ADDRESS: u64 = 0x0400_0000;
+const PSP_VRAM_SIZE: u64 = 0x0020_0000;
+const PSP_USER_MEMORY_BASE_ADDRESS: u64 = 0x0800_0000;
+const PSP_USER_MEMORY_SIZE: u64 = 0x0200_0000;
+const PSP_EXTENDED_MEMORY_BASE_ADDRESS: u64 = 0x0A00_0000;
+const PSP_EXTENDED_MEMORY_SIZE: u64 = 0x0200_0000;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlayStationMemoryRegionKind {
+    EmotionEngineRam,
+    EmotionEngineScratchpad,
+    InputOutputProcessorRam,
+    PspUserMemory,
+    PspExtendedMemory,
+    PspScratchpad,
+    PspVideoRam,
+}
+
+/// Guest pointers only ever target memory of the processor that owns them, so pointer scans stay within one domain.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlayStationPointerScanDomain {
+    EmotionEngine,
+    InputOutputProcessor,
+    Psp,
+}
+
+impl PlayStationMemoryRegionKind {
+    pub fn get_virtual_base_address(&self) -> u64 {
+        match self {
+            Self::EmotionEngineRam => EE_RAM_BASE_ADDRESS,
+            Self::EmotionEngineScratchpad => EE_SCRATCHPAD_BASE_ADDRESS,
+            Self::InputOutputProcessorRam => IOP_RAM_BASE_ADDRESS,
+            Self::PspUserMemory => PSP_USER_MEMORY_BASE_ADDRESS,
+            Self::PspExtendedMemory => PSP_EXTENDED_MEMORY_BASE_ADDRESS,
+            Self::PspScratchpad => PSP_SCRATCHPAD_BASE_ADDRESS,
+            Self::PspVideoRam => PSP_VRAM_BASE_ADDRESS,
+        }
+    }
+
+    pub fn get_region_size(&self) -> u64 {
+        match self {
+            Self::EmotionEngineRam => EE_RAM_SIZE,
+            Self::EmotionEngineScratchpad => EE_SCRATCHPAD_SIZE,
+            Self::InputOutputProcessorRam => IOP_RAM_SIZE,
+            Self::PspUserMemory => PSP_USER_MEMORY_SIZE,
+            Self::PspExtendedMemory => PSP_EXTENDED_MEMORY_SIZE,
+            Self::PspScratchpad => PSP_SCRATCHPAD_SIZE,
+            Self::PspVideoRam => PSP_VRAM_SIZE,
+        }
+    }
+
+    pub fn get_module_name(&self) -> &'static str {
+        match self {
+            Self::EmotionEngineRam => EE_RAM_MODULE_NAME,
+            Self::EmotionEngineScratchpad => EE_SCRATCHPAD_MODULE_NAME,
+            Self::InputOutputProcessorRam => IOP_RAM_MODULE_NAME,
+            Self::PspUserMemory | Self::PspExtendedMemory => PSP_RAM_MODULE_NAME,
+            Self::PspScratchpad => PSP_SCRATCHPAD_MODULE_NAME,
+            Self::PspVideoRam => PSP_VRAM_MODULE_NAME,
+        }
+    }
+
+    pub fn get_pointer_scan_domain(&self) -> PlayStationPointerScanDomain {
+        match self {
+            Self::EmotionEngineRam | Self::EmotionEngineScratchpad => PlayStationPointerScanDomain::EmotionEngine,
+            Self::InputOutputProcessorRam => PlayStationPointerScanDomain::InputOutputProcessor,
+            Self::PspUserMemory | Self::PspExtendedMemory | Self::PspScratchpad | Self::PspVideoRam => PlayStationPointerScanDomain::Psp,
+        }
+    }
+
+    pub fn contains_virtual_address(
+        &self,
+        virtual_address: u64,
+    ) -> bool {
+        let virtual_base_address = self.get_virtual_base_address();
+        let virtual_end_address = virtual_base_address.saturating_add(self.get_region_size());
+
+        virtual_address >= virtual_base_address && virtual_address < virtual_end_address
+    }
+
+    pub fn virtual_offset(
+        &self,
+        virtual_address: u64,
+    ) -> Option<u64> {
+        self.contains_virtual_address(virtual_address)
+            .then(|| virtual_address.saturating_sub(self.get_virtual_base_address()))
+    }
+
+    /// PSP extended memory directly follows user memory, so both are addressed through one contiguous `psp_ram` module.
+    pub fn module_offset(
+        &self,
+        virtual_address: u64,
+    ) -> Option<u64> {
+        let virtual_offset = self.virtual_offset(virtual_address)?;
+
+        match self {
+            Self::PspExtendedMemory => Some(PSP_USER_MEMORY_SIZE.saturating_add(virtual_offset)),
+            _ => Some(virtual_offset),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PlayStationMemoryRegionDescriptor {
+    region_kind: PlayStationMemoryRegionKind,
+    host_base_address: u64,
+}
+
+impl PlayStationMemoryRegionDescriptor {
+    pub fn new(
+        region_kind: PlayStationMemoryRegionKind,
+        host_base_address: u64,
+    ) -> Self {
+        Self {
+            region_kind,
+            host_base_address,
+        }
+    }
+
+    pub fn get_region_kind(&self) -> PlayStationMemoryRegionKind {
+        self.region_kind
+    }
+
+    pub fn get_module_name(&self) -> &'static str {
+        self.region_kind.get_module_name()
+    }
+
+    pub fn get_virtual_base_address(&self) -> u64 {
+        self.region_kind.get_virtual_base_address()
+    }
+
+    pub fn get_region_size(&self) -> u64 {
+        self.region_kind.get_region_size()
+    }
+
+    pub fn get_host_base_address(&self) -> u64 {
+        self.host_base_address
+    }
+
+    pub fn get_pointer_scan_domain(&self) -> PlayStationPointerScanDomain {
+        self.region_kind.get_pointer_scan_domain()
+    }
+
+    pub fn contains_virtual_address(
+        &self,
+        virtual_address: u64,
+    ) -> bool {
+        self.region_kind.contains_virtual_address(virtual_address)
+    }
+
+    pub fn virtual_to_host_address(
+        &self,
+        virtual_address: u64,
+    ) -> Option<u64> {
+        self.region_kind
+            .virtual_offset(virtual_address)
+            .map(|virtual_offset| self.host_base_address.saturating_add(virtual_offset))
+    }
+
+    pub fn host_to_virtual_address(
+        &self,
+        host_address: u64,
+    ) -> Option<u64> {
+        let host_end_address = self.host_base_address.saturating_add(self.get_region_size());
+
+        (host_address >= self.host_base_address && host_address < host_end_address).then(|| {
+            self.get_virtual_base_address()
+                .saturating_add(host_address.saturating_sub(self.host_base_address))
+        })
+    }
+
+    pub fn module_relative_address(
+        &self,
+        virtual_address: u64,
+    ) -> Option<(String, u64)> {
+        self.region_kind
+            .module_offset(virtual_address)
+            .map(|module_offset| (self.get_module_name().to_string(), module_offset))
+    }
+}
+
+pub fn find_playstation_region_by_virtual_address(
+    region_descriptors: &[PlayStationMemoryRegionDescriptor],
+    virtual_address: u64,
+) -> Option<PlayStationMemoryRegionDescriptor> {
+    region_descriptors
+        .iter()
+        .copied()
+        .find(|region_descriptor| region_descriptor.contains_virtual_address(virtual_address))
+}
+
+pub fn find_playstation_region_by_module_name(
+    region_descriptors: &[PlayStationMemoryRegionDescriptor],
+    module_name: &str,
+) -> Option<PlayStationMemoryRegionDescriptor> {
+    region_descriptors.iter().copied().find(|region_descriptor| {
+        region_descriptor
+            .get_module_name()
+            .eq_ignore_ascii_case(module_name)
+    })
+}
+
+pub fn resolve_virtual_address_from_module(
+    module_name: &str,
+    module_offset: u64,
+) -> Option<u64> {
+    let region_kinds: &[PlayStationMemoryRegionKind] = if module_name.eq_ignore_ascii_case(EE_RAM_MODULE_NAME) {
+        &[PlayStationMemoryRegionKind::EmotionEngineRam]
+    } else if module_name.eq_ignore_ascii_case(EE_SCRATCHPAD_MODULE_NAME) {
+        &[PlayStationMemoryRegionKind::EmotionEngineScratchpad]
+    } else if module_name.eq_ignore_ascii_case(IOP_RAM_MODULE_NAME) {
+        &[PlayStationMemoryRegionKind::InputOutputProcessorRam]
+    } else if module_name.eq_ignore_ascii_case(PSP_RAM_MODULE_NAME) {
+        &[
+            PlayStationMemoryRegionKind::PspUserMemory,
+            PlayStationMemoryRegionKind::PspExtendedMemory,
+        ]
+    } else if module_name.eq_ignore_ascii_case(PSP_SCRATCHPAD_MODULE_NAME) {
+        &[PlayStationMemoryRegionKind::PspScratchpad]
+    } else if module_name.eq_ignore_ascii_case(PSP_VRAM_MODULE_NAME) {
+        &[PlayStationMemoryRegionKind::PspVideoRam]
+    } else {
+        return None;
+    };
+    let mut remaining_module_offset = module_offset;
+
+    for region_kind in region_kinds {
+        if remaining_module_offset < region_kind.get_region_size() {
+            return Some(
+                region_kind
+                    .get_virtual_base_address()
+                    .saturating_add(remaining_module_offset),
+            );
+        }
+
+        remaining_module_offset -= region_kind.get_region_size();
+    }
+
+    None
+}
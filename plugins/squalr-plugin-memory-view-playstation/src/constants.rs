@@ -0,0 +1,3 @@
+pub const PLAYSTATION_PLUGIN_ID: &str = "builtin.memory-view.playstation";
+pub const PLAYSTATION_PLUGIN_DISPLAY_NAME: &str = "PlayStation Memory View";
+pub const PLAYSTATION_PLUGIN_DESCRIPTION: &str = "Exposes PCSX2 (PlayStation 2) and PPSSPP (PSP) emulator memory as a canonical virtual address space.";
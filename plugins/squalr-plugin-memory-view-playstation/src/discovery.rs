@@ -0,0 +1,282 @@
+use crate::{
+    address_space::{PlayStationMemoryRegionDescriptor, PlayStationMemoryRegionKind},
+    constants::PLAYSTATION_PLUGIN_ID,
+    process_detection::{PlayStationEmulatorKind, detect_playstation_emulator},
+};
+use squalr_engine_api::{
+    plugins::memory_view::MemoryViewPluginError,
+    structures::{memory::normalized_region::NormalizedRegion, processes::opened_process_info::OpenedProcessInfo},
+};
+use squalr_engine_targets_native::{
+    memory_queryer::{memory_queryer::MemoryQueryer, memory_queryer_trait::MemoryQueryerTrait},
+    memory_reader::{MemoryReader, memory_reader_trait::MemoryReaderTrait},
+};
+
+const PCSX2_EE_MEMORY_EXPORT_NAME: &str = "EEmem";
+const PCSX2_IOP_MEMORY_EXPORT_NAME: &str = "IOPmem";
+/// PCSX2 1.7 and later reserve 96MB of extra (TOOL) RAM between EE RAM and the scratchpad.
+const PCSX2_EXPORTED_SCRATCHPAD_OFFSET: u64 = 0x0800_0000;
+/// PCSX2 1.6 and earlier reserve the EE and IOP memory blocks at fixed host addresses and export nothing.
+const PCSX2_LEGACY_EE_MEMORY_ADDRESS: u64 = 0x2000_0000;
+const PCSX2_LEGACY_IOP_MEMORY_ADDRESS: u64 = 0x2400_0000;
+const PCSX2_LEGACY_SCRATCHPAD_OFFSET: u64 = 0x0200_0000;
+
+/// PPSSPP maps every PSP memory view into one arena at the view's guest address, so the arena base follows from any view.
+const PPSSPP_USER_MEMORY_ARENA_OFFSET: u64 = 0x0800_0000;
+const PPSSPP_VRAM_ARENA_OFFSET: u64 = 0x0400_0000;
+const PPSSPP_SCRATCHPAD_ARENA_OFFSET: u64 = 0x0001_0000;
+const PPSSPP_EXTENDED_USER_MEMORY_SIZE: u64 = 0x0400_0000;
+
+const PE_HEADER_OFFSET_OFFSET: u64 = 0x3C;
+const PE_SIGNATURE: [u8; 4] = *b"PE\0\0";
+const PE_OPTIONAL_HEADER_OFFSET: u64 = 0x18;
+const PE32_EXPORT_DIRECTORY_OFFSET: u64 = 0x60;
+const PE32_PLUS_EXPORT_DIRECTORY_OFFSET: u64 = 0x70;
+const PE32_PLUS_MAGIC: u16 = 0x20B;
+const MAX_EXPORT_NAME_COUNT: u32 = 0x1_0000;
+const MAX_EXPORT_NAME_LENGTH: usize = 0x100;
+
+pub(crate) fn discover_playstation_memory_regions(
+    opened_process_info: &OpenedProcessInfo
+) -> Result<Vec<PlayStationMemoryRegionDescriptor>, MemoryViewPluginError> {
+    let discovered_region_descriptors = match detect_playstation_emulator(opened_process_info.get_name()) {
+        Some(PlayStationEmulatorKind::Pcsx2) => discover_pcsx2_memory_regions(opened_process_info),
+        Some(PlayStationEmulatorKind::Ppsspp) => {
+            let raw_memory_regions = MemoryQueryer::query_pages_by_address_range(
+                opened_process_info,
+                0,
+                MemoryQueryer::get_instance().get_max_usermode_address(opened_process_info),
+            );
+
+            select_ppsspp_memory_regions(&raw_memory_regions)
+        }
+        None => Vec::new(),
+    };
+
+    if discovered_region_descriptors.is_empty() {
+        return Err(MemoryViewPluginError::unavailable(
+            PLAYSTATION_PLUGIN_ID,
+            format!(
+                "no PlayStation memory regions are currently exposed for process `{}`",
+                opened_process_info.get_name()
+            ),
+        ));
+    }
+
+    Ok(discovered_region_descriptors)
+}
+
+fn discover_pcsx2_memory_regions(opened_process_info: &OpenedProcessInfo) -> Vec<PlayStationMemoryRegionDescriptor> {
+    let read_host_bytes = |host_address: u64, values: &mut [u8]| MemoryReader::get_instance().read_bytes(opened_process_info, host_address, values);
+    let main_module_base_address = MemoryQueryer::get_instance()
+        .get_modules(opened_process_info)
+        .into_iter()
+        .find(|module| module.get_module_name().to_ascii_lowercase().contains("pcsx2"))
+        .map(|module| module.get_base_address());
+    let read_exported_pointer = |export_name: &str| {
+        let export_address = find_pe_export_address(&read_host_bytes, main_module_base_address?, export_name)?;
+
+        read_u64(&read_host_bytes, export_address).filter(|pointer_value| *pointer_value != 0)
+    };
+
+    select_pcsx2_memory_regions(
+        read_exported_pointer(PCSX2_EE_MEMORY_EXPORT_NAME),
+        read_exported_pointer(PCSX2_IOP_MEMORY_EXPORT_NAME),
+        |host_address| read_host_bytes(host_address, &mut [0u8; 1]),
+    )
+}
+
+/// Builds the PCSX2 region layout from the exported `EEmem`/`IOPmem` pointers, falling back to the fixed reservations
+/// used by older builds. Regions whose host memory cannot be read are omitted, and nothing is exposed without EE RAM.
+pub(crate) fn select_pcsx2_memory_regions(
+    exported_ee_memory_address: Option<u64>,
+    exported_iop_memory_address: Option<u64>,
+    mut is_host_address_readable: impl FnMut(u64) -> bool,
+) -> Vec<PlayStationMemoryRegionDescriptor> {
+    let (ee_memory_address, scratchpad_address, iop_memory_address) = match exported_ee_memory_address {
+        Some(ee_memory_address) => (
+            ee_memory_address,
+            ee_memory_address.saturating_add(PCSX2_EXPORTED_SCRATCHPAD_OFFSET),
+            exported_iop_memory_address,
+        ),
+        None => (
+            PCSX2_LEGACY_EE_MEMORY_ADDRESS,
+            PCSX2_LEGACY_EE_MEMORY_ADDRESS + PCSX2_LEGACY_SCRATCHPAD_OFFSET,
+            Some(PCSX2_LEGACY_IOP_MEMORY_ADDRESS),
+        ),
+    };
+
+    if !is_host_address_readable(ee_memory_address) {
+        return Vec::new();
+    }
+
+    let mut discovered_region_descriptors = vec![PlayStationMemoryRegionDescriptor::new(
+        PlayStationMemoryRegionKind::EmotionEngineRam,
+        ee_memory_address,
+    )];
+
+    if is_host_address_readable(scratchpad_address) {
+        discovered_region_descriptors.push(PlayStationMemoryRegionDescriptor::new(
+            PlayStationMemoryRegionKind::EmotionEngineScratchpad,
+            scratchpad_address,
+        ));
+    }
+
+    if let Some(iop_memory_address) = iop_memory_address.filter(|iop_memory_address| is_host_address_readable(*iop_memory_address)) {
+        discovered_region_descriptors.push(PlayStationMemoryRegionDescriptor::new(
+            PlayStationMemoryRegionKind::InputOutputProcessorRam,
+            iop_memory_address,
+        ));
+    }
+
+    discovered_region_descriptors
+}
+
+/// Finds the PPSSPP memory arena by looking for a user memory view whose VRAM and scratchpad views sit at the expected
+/// offsets from the same arena base. PPSSPP also maps kernel and uncached mirrors, so the lowest matching arena wins.
+pub(crate) fn select_ppsspp_memory_regions(raw_memory_regions: &[NormalizedRegion]) -> Vec<PlayStationMemoryRegionDescriptor> {
+    let has_region_at = |host_address: u64, minimum_region_size: u64| {
+        raw_memory_regions
+            .iter()
+            .any(|raw_memory_region| raw_memory_region.get_base_address() == host_address && raw_memory_region.get_region_size() >= minimum_region_size)
+    };
+    let Some(user_memory_region) = raw_memory_regions
+        .iter()
+        .filter(|raw_memory_region| {
+            let region_size = raw_memory_region.get_region_size();
+
+            region_size == PlayStationMemoryRegionKind::PspUserMemory.get_region_size() || region_size == PPSSPP_EXTENDED_USER_MEMORY_SIZE
+        })
+        .filter(|raw_memory_region| {
+            let Some(arena_base_address) = raw_memory_region
+                .get_base_address()
+                .checked_sub(PPSSPP_USER_MEMORY_ARENA_OFFSET)
+            else {
+                return false;
+            };
+
+            has_region_at(
+                arena_base_address + PPSSPP_VRAM_ARENA_OFFSET,
+                PlayStationMemoryRegionKind::PspVideoRam.get_region_size(),
+            ) && has_region_at(
+                arena_base_address + PPSSPP_SCRATCHPAD_ARENA_OFFSET,
+                PlayStationMemoryRegionKind::PspScratchpad.get_region_size(),
+            )
+        })
+        .min_by_key(|raw_memory_region| raw_memory_region.get_base_address())
+    else {
+        return Vec::new();
+    };
+    let user_memory_address = user_memory_region.get_base_address();
+    let arena_base_address = user_memory_address - PPSSPP_USER_MEMORY_ARENA_OFFSET;
+    let mut discovered_region_descriptors = vec![
+        PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::PspUserMemory, user_memory_address),
+        PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::PspScratchpad, arena_base_address + PPSSPP_SCRATCHPAD_ARENA_OFFSET),
+        PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::PspVideoRam, arena_base_address + PPSSPP_VRAM_ARENA_OFFSET),
+    ];
+
+    if user_memory_region.get_region_size() == PPSSPP_EXTENDED_USER_MEMORY_SIZE {
+        discovered_region_descriptors.push(PlayStationMemoryRegionDescriptor::new(
+            PlayStationMemoryRegionKind::PspExtendedMemory,
+            user_memory_address + PlayStationMemoryRegionKind::PspUserMemory.get_region_size(),
+        ));
+    }
+
+    discovered_region_descriptors
+}
+
+/// Resolves the address of a named export by walking the PE export directory of a mapped module.
+pub(crate) fn find_pe_export_address(
+    read_bytes: &impl Fn(u64, &mut [u8]) -> bool,
+    module_base_address: u64,
+    export_name: &str,
+) -> Option<u64> {
+    let pe_header_address = module_base_address.checked_add(read_u32(read_bytes, module_base_address + PE_HEADER_OFFSET_OFFSET)? as u64)?;
+    let mut pe_signature = [0u8; 4];
+
+    if !read_bytes(pe_header_address, &mut pe_signature) || pe_signature != PE_SIGNATURE {
+        return None;
+    }
+
+    let optional_header_address = pe_header_address + PE_OPTIONAL_HEADER_OFFSET;
+    let export_directory_entry_offset = if read_u16(read_bytes, optional_header_address)? == PE32_PLUS_MAGIC {
+        PE32_PLUS_EXPORT_DIRECTORY_OFFSET
+    } else {
+        PE32_EXPORT_DIRECTORY_OFFSET
+    };
+    let export_directory_rva = read_u32(read_bytes, optional_header_address + export_directory_entry_offset)?;
+
+    if export_directory_rva == 0 {
+        return None;
+    }
+
+    let export_directory_address = module_base_address + export_directory_rva as u64;
+    let name_count = read_u32(read_bytes, export_directory_address + 0x18)?.min(MAX_EXPORT_NAME_COUNT);
+    let functions_address = module_base_address + read_u32(read_bytes, export_directory_address + 0x1C)? as u64;
+    let names_address = module_base_address + read_u32(read_bytes, export_directory_address + 0x20)? as u64;
+    let name_ordinals_address = module_base_address + read_u32(read_bytes, export_directory_address + 0x24)? as u64;
+
+    for name_index in 0..name_count as u64 {
+        let name_address = module_base_address + read_u32(read_bytes, names_address + name_index * 4)? as u64;
+
+        if read_export_name(read_bytes, name_address, export_name.len())? != export_name.as_bytes() {
+            continue;
+        }
+
+        let function_ordinal = read_u16(read_bytes, name_ordinals_address + name_index * 2)? as u64;
+        let function_rva = read_u32(read_bytes, functions_address + function_ordinal * 4)?;
+
+        return Some(module_base_address + function_rva as u64);
+    }
+
+    None
+}
+
+/// Reads a null-terminated export name, stopping early once it is known not to match the requested length.
+fn read_export_name(
+    read_bytes: &impl Fn(u64, &mut [u8]) -> bool,
+    name_address: u64,
+    expected_name_length: usize,
+) -> Option<Vec<u8>> {
+    let mut name_bytes = vec![0u8; (expected_name_length + 1).min(MAX_EXPORT_NAME_LENGTH)];
+
+    if !read_bytes(name_address, &mut name_bytes) {
+        return None;
+    }
+
+    let name_length = name_bytes
+        .iter()
+        .position(|name_byte| *name_byte == 0)
+        .unwrap_or(name_bytes.len());
+
+    name_bytes.truncate(name_length);
+
+    Some(name_bytes)
+}
+
+fn read_u16(
+    read_bytes: &impl Fn(u64, &mut [u8]) -> bool,
+    address: u64,
+) -> Option<u16> {
+    let mut value_bytes = [0u8; 2];
+
+    read_bytes(address, &mut value_bytes).then(|| u16::from_le_bytes(value_bytes))
+}
+
+fn read_u32(
+    read_bytes: &impl Fn(u64, &mut [u8]) -> bool,
+    address: u64,
+) -> Option<u32> {
+    let mut value_bytes = [0u8; 4];
+
+    read_bytes(address, &mut value_bytes).then(|| u32::from_le_bytes(value_bytes))
+}
+
+fn read_u64(
+    read_bytes: &impl Fn(u64, &mut [u8]) -> bool,
+    address: u64,
+) -> Option<u64> {
+    let mut value_bytes = [0u8; 8];
+
+    read_bytes(address, &mut value_bytes).then(|| u64::from_le_bytes(value_bytes))
+}
@@ -0,0 +1,387 @@
+use crate::{
+    address_space::{PlayStationMemoryRegionDescriptor, find_playstation_region_by_virtual_address, resolve_virtual_address_from_module},
+    constants::PLAYSTATION_PLUGIN_ID,
+    discovery::discover_playstation_memory_regions,
+};
+use squalr_engine_api::{
+    plugins::memory_view::{MemoryViewInstance, MemoryViewPluginError, PageRetrievalMode},
+    structures::{
+        memory::{
+            normalized_module::{ModuleAddressDisplay, NormalizedModule},
+            normalized_region::NormalizedRegion,
+        },
+        processes::opened_process_info::OpenedProcessInfo,
+    },
+};
+use squalr_engine_targets_native::{
+    memory_reader::{MemoryReader, memory_reader_trait::MemoryReaderTrait},
+    memory_writer::{MemoryWriter, memory_writer_trait::MemoryWriterTrait},
+};
+use std::{
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+const DISCOVERY_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Default)]
+struct PlayStationDiscoveryCache {
+    discovered_region_descriptors: Option<Vec<PlayStationMemoryRegionDescriptor>>,
+    unavailable_reason: Option<String>,
+    last_refresh_at: Option<Instant>,
+}
+
+pub(crate) struct PlayStationMemoryViewInstance {
+    opened_process_info: OpenedProcessInfo,
+    discovery_cache: RwLock<PlayStationDiscoveryCache>,
+}
+
+impl PlayStationMemoryViewInstance {
+    pub(crate) fn new(process_info: OpenedProcessInfo) -> Self {
+        Self {
+            opened_process_info: process_info,
+            discovery_cache: RwLock::new(PlayStationDiscoveryCache::default()),
+        }
+    }
+
+    fn get_or_discover_region_descriptors(&self) -> Result<Vec<PlayStationMemoryRegionDescriptor>, MemoryViewPluginError> {
+        if let Ok(discovery_cache) = self.discovery_cache.read() {
+            if let Some(discovered_region_descriptors) = discovery_cache.discovered_region_descriptors.as_ref() {
+                return Ok(discovered_region_descriptors.clone());
+            }
+
+            if let Some(unavailable_reason) = discovery_cache.unavailable_reason.as_ref() {
+                return Err(MemoryViewPluginError::unavailable(self.plugin_id(), unavailable_reason.clone()));
+            }
+        }
+
+        self.refresh_discovery_cache()?;
+
+        if let Ok(discovery_cache) = self.discovery_cache.read() {
+            if let Some(discovered_region_descriptors) = discovery_cache.discovered_region_descriptors.as_ref() {
+                return Ok(discovered_region_descriptors.clone());
+            }
+
+            if let Some(unavailable_reason) = discovery_cache.unavailable_reason.as_ref() {
+                return Err(MemoryViewPluginError::unavailable(self.plugin_id(), unavailable_reason.clone()));
+            }
+        }
+
+        Err(MemoryViewPluginError::unavailable(
+            self.plugin_id(),
+            "no PlayStation memory regions are currently exposed".to_string(),
+        ))
+    }
+
+    fn should_refresh_discovery_cache(&self) -> bool {
+        match self.discovery_cache.read() {
+            Ok(discovery_cache) => discovery_cache
+                .last_refresh_at
+                .map(|last_refresh_at| last_refresh_at.elapsed() >= DISCOVERY_REFRESH_INTERVAL)
+                .unwrap_or(true),
+            Err(_) => true,
+        }
+    }
+
+    fn refresh_discovery_cache(&self) -> Result<(), MemoryViewPluginError> {
+        if !self.should_refresh_discovery_cache() {
+            return match self.discovery_cache.read() {
+                Ok(discovery_cache) => {
+                    if discovery_cache.discovered_region_descriptors.is_some() {
+                        Ok(())
+                    } else if let Some(unavailable_reason) = discovery_cache.unavailable_reason.as_ref() {
+                        Err(MemoryViewPluginError::unavailable(self.plugin_id(), unavailable_reason.clone()))
+                    } else {
+                        Ok(())
+                    }
+                }
+                Err(error) => Err(MemoryViewPluginError::message(
+                    self.plugin_id(),
+                    format!("failed to access PlayStation discovery cache: {}", error),
+                )),
+            };
+        }
+
+        let discovery_result = discover_playstation_memory_regions(&self.opened_process_info);
+
+        match self.discovery_cache.write() {
+            Ok(mut discovery_cache) => {
+                discovery_cache.last_refresh_at = Some(Instant::now());
+
+                match discovery_result {
+                    Ok(discovered_region_descriptors) => {
+                        discovery_cache.discovered_region_descriptors = Some(discovered_region_descriptors);
+                        discovery_cache.unavailable_reason = None;
+                        Ok(())
+                    }
+                    Err(error) => {
+                        discovery_cache.discovered_region_descriptors = None;
+                        discovery_cache.unavailable_reason = if error.is_unavailable() { Some(error.to_string()) } else { None };
+                        Err(error)
+                    }
+                }
+            }
+            Err(error) => Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!("failed to update PlayStation memory-region cache: {}", error),
+            )),
+        }
+    }
+
+    fn build_virtual_pages(
+        &self,
+        page_retrieval_mode: PageRetrievalMode,
+        discovered_region_descriptors: &[PlayStationMemoryRegionDescriptor],
+    ) -> Vec<NormalizedRegion> {
+        match page_retrieval_mode {
+            PageRetrievalMode::FromNonModules => Vec::new(),
+            PageRetrievalMode::FromSettings | PageRetrievalMode::FromUserMode | PageRetrievalMode::FromModules | PageRetrievalMode::FromVirtualModules => {
+                discovered_region_descriptors
+                    .iter()
+                    .map(|region_descriptor| NormalizedRegion::new(region_descriptor.get_virtual_base_address(), region_descriptor.get_region_size()))
+                    .collect()
+            }
+        }
+    }
+
+    /// Every region sits at its real guest address, so modules display absolute addresses. PSP user and extended memory
+    /// share the `psp_ram` module.
+    fn build_modules(
+        &self,
+        discovered_region_descriptors: &[PlayStationMemoryRegionDescriptor],
+    ) -> Vec<NormalizedModule> {
+        let mut modules: Vec<NormalizedModule> = Vec::new();
+
+        for region_descriptor in discovered_region_descriptors {
+            let region_end_address = region_descriptor
+                .get_virtual_base_address()
+                .saturating_add(region_descriptor.get_region_size());
+
+            if let Some(module) = modules
+                .iter_mut()
+                .find(|module| module.get_module_name() == region_descriptor.get_module_name())
+            {
+                let module_base_address = module
+                    .get_base_address()
+                    .min(region_descriptor.get_virtual_base_address());
+                let module_end_address = module
+                    .get_base_address()
+                    .saturating_add(module.get_region_size())
+                    .max(region_end_address);
+
+                module.set_base_address(module_base_address);
+                module.set_region_size(module_end_address.saturating_sub(module_base_address));
+                continue;
+            }
+
+            modules.push(NormalizedModule::new_with_display(
+                region_descriptor.get_module_name(),
+                region_descriptor.get_virtual_base_address(),
+                region_descriptor.get_region_size(),
+                ModuleAddressDisplay::AbsoluteAddress,
+            ));
+        }
+
+        modules
+    }
+
+    fn build_pointer_scan_virtual_pages(
+        &self,
+        discovered_region_descriptors: &[PlayStationMemoryRegionDescriptor],
+        target_address: u64,
+    ) -> Vec<NormalizedRegion> {
+        let Some(target_region_descriptor) = find_playstation_region_by_virtual_address(discovered_region_descriptors, target_address) else {
+            return self.build_virtual_pages(PageRetrievalMode::FromVirtualModules, discovered_region_descriptors);
+        };
+
+        let target_pointer_scan_domain = target_region_descriptor.get_pointer_scan_domain();
+
+        discovered_region_descriptors
+            .iter()
+            .filter(|region_descriptor| region_descriptor.get_pointer_scan_domain() == target_pointer_scan_domain)
+            .map(|region_descriptor| NormalizedRegion::new(region_descriptor.get_virtual_base_address(), region_descriptor.get_region_size()))
+            .collect()
+    }
+
+    fn translate_virtual_range(
+        &self,
+        virtual_address: u64,
+        value_count: usize,
+    ) -> Result<u64, MemoryViewPluginError> {
+        let discovered_region_descriptors = self.get_or_discover_region_descriptors()?;
+        let Some(region_descriptor) = find_playstation_region_by_virtual_address(&discovered_region_descriptors, virtual_address) else {
+            return Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!("virtual address {:#X} is not within a discovered PlayStation memory region", virtual_address),
+            ));
+        };
+
+        let Some(host_address) = region_descriptor.virtual_to_host_address(virtual_address) else {
+            return Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!("virtual address {:#X} could not be translated to a host address", virtual_address),
+            ));
+        };
+        let virtual_end_address = virtual_address.saturating_add(value_count as u64);
+        let region_end_address = region_descriptor
+            .get_virtual_base_address()
+            .saturating_add(region_descriptor.get_region_size());
+
+        if virtual_end_address > region_end_address {
+            return Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!(
+                    "virtual range {:#X}-{:#X} crosses the end of PlayStation region `{}`",
+                    virtual_address,
+                    virtual_end_address,
+                    region_descriptor.get_module_name()
+                ),
+            ));
+        }
+
+        Ok(host_address)
+    }
+}
+
+impl MemoryViewInstance for PlayStationMemoryViewInstance {
+    fn plugin_id(&self) -> &str {
+        PLAYSTATION_PLUGIN_ID
+    }
+
+    fn owns_address(
+        &self,
+        address: u64,
+    ) -> bool {
+        self.get_or_discover_region_descriptors()
+            .map(|discovered_region_descriptors| {
+                discovered_region_descriptors
+                    .iter()
+                    .any(|region_descriptor| region_descriptor.contains_virtual_address(address))
+            })
+            .unwrap_or(false)
+    }
+
+    fn refresh(&mut self) -> Result<(), MemoryViewPluginError> {
+        self.refresh_discovery_cache()
+    }
+
+    fn get_virtual_pages(
+        &self,
+        page_retrieval_mode: PageRetrievalMode,
+    ) -> Result<Vec<NormalizedRegion>, MemoryViewPluginError> {
+        let discovered_region_descriptors = self.get_or_discover_region_descriptors()?;
+
+        Ok(self.build_virtual_pages(page_retrieval_mode, &discovered_region_descriptors))
+    }
+
+    fn get_modules(&self) -> Result<Vec<NormalizedModule>, MemoryViewPluginError> {
+        let discovered_region_descriptors = self.get_or_discover_region_descriptors()?;
+
+        Ok(self.build_modules(&discovered_region_descriptors))
+    }
+
+    fn get_pointer_scan_memory_regions(
+        &self,
+        page_retrieval_mode: PageRetrievalMode,
+        target_address: u64,
+    ) -> Result<Option<Vec<NormalizedRegion>>, MemoryViewPluginError> {
+        if page_retrieval_mode != PageRetrievalMode::FromVirtualModules {
+            return Ok(None);
+        }
+
+        let discovered_region_descriptors = self.get_or_discover_region_descriptors()?;
+
+        Ok(Some(self.build_pointer_scan_virtual_pages(&discovered_region_descriptors, target_address)))
+    }
+
+    fn address_to_module(
+        &self,
+        address: u64,
+        modules: &[NormalizedModule],
+    ) -> Option<(String, u64)> {
+        if let Ok(discovered_region_descriptors) = self.get_or_discover_region_descriptors()
+            && let Some(region_descriptor) = find_playstation_region_by_virtual_address(&discovered_region_descriptors, address)
+        {
+            return region_descriptor.module_relative_address(address);
+        }
+
+        modules
+            .iter()
+            .find(|module| module.contains_address(address))
+            .map(|module| (module.get_module_name().to_string(), address.saturating_sub(module.get_base_address())))
+    }
+
+    fn resolve_module(
+        &self,
+        modules: &[NormalizedModule],
+        identifier: &str,
+    ) -> u64 {
+        if let Some(virtual_address) = resolve_virtual_address_from_module(identifier, 0) {
+            return virtual_address;
+        }
+
+        modules
+            .iter()
+            .find(|module| module.get_module_name().eq_ignore_ascii_case(identifier))
+            .map(|module| module.get_base_address())
+            .unwrap_or(0)
+    }
+
+    fn resolve_module_address(
+        &self,
+        modules: &[NormalizedModule],
+        identifier: &str,
+        offset: u64,
+    ) -> Option<u64> {
+        if let Some(virtual_address) = resolve_virtual_address_from_module(identifier, offset) {
+            let discovered_region_descriptors = self.get_or_discover_region_descriptors().ok()?;
+            let region_descriptor = find_playstation_region_by_virtual_address(&discovered_region_descriptors, virtual_address)?;
+
+            return region_descriptor
+                .get_module_name()
+                .eq_ignore_ascii_case(identifier)
+                .then_some(virtual_address);
+        }
+
+        modules
+            .iter()
+            .find(|module| module.get_module_name().eq_ignore_ascii_case(identifier))
+            .and_then(|module| module.get_base_address().checked_add(offset))
+    }
+
+    fn read_bytes(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> Result<(), MemoryViewPluginError> {
+        let host_address = self.translate_virtual_range(address, values.len())?;
+        let read_succeeded = MemoryReader::get_instance().read_bytes(&self.opened_process_info, host_address, values);
+
+        if read_succeeded {
+            Ok(())
+        } else {
+            Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!("failed to read PlayStation host memory at translated address {:#X}", host_address),
+            ))
+        }
+    }
+
+    fn write_bytes(
+        &self,
+        address: u64,
+        values: &[u8],
+    ) -> Result<(), MemoryViewPluginError> {
+        let host_address = self.translate_virtual_range(address, values.len())?;
+        let write_succeeded = MemoryWriter::get_instance().write_bytes(&self.opened_process_info, host_address, values);
+
+        if write_succeeded {
+            Ok(())
+        } else {
+            Err(MemoryViewPluginError::message(
+                self.plugin_id(),
+                format!("failed to write PlayStation host memory at translated address {:#X}", host_address),
+            ))
+        }
+    }
+}
@@ -0,0 +1,12 @@
+pub mod address_space;
+
+mod constants;
+mod discovery;
+mod instance;
+mod plugin;
+mod process_detection;
+
+pub use plugin::PlayStationMemoryViewPlugin;
+
+#[cfg(test)]
+mod tests;
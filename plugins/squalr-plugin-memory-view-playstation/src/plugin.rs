@@ -0,0 +1,67 @@
+use crate::{
+    constants::{PLAYSTATION_PLUGIN_DESCRIPTION, PLAYSTATION_PLUGIN_DISPLAY_NAME, PLAYSTATION_PLUGIN_ID},
+    instance::PlayStationMemoryViewInstance,
+    process_detection::detect_playstation_emulator,
+};
+use squalr_engine_api::{
+    plugins::memory_view::{MemoryViewInstance, MemoryViewPlugin, MemoryViewPluginError},
+    plugins::{Plugin, PluginCapability, PluginMetadata, PluginPackage, PluginPermission},
+    structures::processes::opened_process_info::OpenedProcessInfo,
+};
+
+pub struct PlayStationMemoryViewPlugin {
+    metadata: PluginMetadata,
+}
+
+impl PlayStationMemoryViewPlugin {
+    pub fn new() -> Self {
+        Self {
+            metadata: PluginMetadata::new_with_permissions(
+                PLAYSTATION_PLUGIN_ID,
+                PLAYSTATION_PLUGIN_DISPLAY_NAME,
+                PLAYSTATION_PLUGIN_DESCRIPTION,
+                vec![PluginCapability::MemoryView],
+                vec![
+                    PluginPermission::ReadProcessMemory,
+                    PluginPermission::WriteProcessMemory,
+                ],
+                true,
+                true,
+            ),
+        }
+    }
+}
+
+impl Default for PlayStationMemoryViewPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for PlayStationMemoryViewPlugin {
+    fn metadata(&self) -> &PluginMetadata {
+        &self.metadata
+    }
+}
+
+impl PluginPackage for PlayStationMemoryViewPlugin {
+    fn as_memory_view_plugin(&self) -> Option<&dyn MemoryViewPlugin> {
+        Some(self)
+    }
+}
+
+impl MemoryViewPlugin for PlayStationMemoryViewPlugin {
+    fn can_attach(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> bool {
+        detect_playstation_emulator(process_info.get_name()).is_some()
+    }
+
+    fn create_instance(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<Box<dyn MemoryViewInstance>, MemoryViewPluginError> {
+        Ok(Box::new(PlayStationMemoryViewInstance::new(process_info.clone())))
+    }
+}
@@ -0,0 +1,17 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlayStationEmulatorKind {
+    Pcsx2,
+    Ppsspp,
+}
+
+pub fn detect_playstation_emulator(process_name: &str) -> Option<PlayStationEmulatorKind> {
+    let normalized_process_name = process_name.to_ascii_lowercase();
+
+    if normalized_process_name.contains("pcsx2") {
+        Some(PlayStationEmulatorKind::Pcsx2)
+    } else if normalized_process_name.contains("ppsspp") {
+        Some(PlayStationEmulatorKind::Ppsspp)
+    } else {
+        None
+    }
+}
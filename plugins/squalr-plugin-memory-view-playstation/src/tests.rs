@@ -0,0 +1,239 @@
+use crate::{
+    PlayStationMemoryViewPlugin,
+    address_space::{
+        PlayStationMemoryRegionDescriptor, PlayStationMemoryRegionKind, PlayStationPointerScanDomain, find_playstation_region_by_module_name,
+        find_playstation_region_by_virtual_address, resolve_virtual_address_from_module,
+    },
+    discovery::{find_pe_export_address, select_pcsx2_memory_regions, select_ppsspp_memory_regions},
+    process_detection::{PlayStationEmulatorKind, detect_playstation_emulator},
+};
+use squalr_engine_api::{
+    plugins::memory_view::MemoryViewPlugin,
+    structures::{
+        memory::{bitness::Bitness, normalized_region::NormalizedRegion},
+        processes::opened_process_info::OpenedProcessInfo,
+    },
+};
+
+const PPSSPP_ARENA_BASE_ADDRESS: u64 = 0x0000_0200_0000_0000;
+
+fn build_ppsspp_arena_regions(
+    arena_base_address: u64,
+    user_memory_size: u64,
+) -> Vec<NormalizedRegion> {
+    vec![
+        NormalizedRegion::new(arena_base_address + 0x0001_0000, 0x4000),
+        NormalizedRegion::new(arena_base_address + 0x0400_0000, 0x0020_0000),
+        NormalizedRegion::new(arena_base_address + 0x0800_0000, user_memory_size),
+    ]
+}
+
+/// Builds a minimal PE32+ image at `module_base_address` that exports each name at the paired RVA.
+fn build_pe_image(exports: &[(&str, u32)]) -> Vec<u8> {
+    let mut image_bytes = vec![0u8; 0x1000];
+    let pe_header_offset = 0x80usize;
+    let export_directory_offset = 0x200usize;
+    let functions_offset = 0x300usize;
+    let names_offset = 0x340usize;
+    let name_ordinals_offset = 0x380usize;
+    let mut name_string_offset = 0x400usize;
+
+    image_bytes[0..2].copy_from_slice(b"MZ");
+    image_bytes[0x3C..0x40].copy_from_slice(&(pe_header_offset as u32).to_le_bytes());
+    image_bytes[pe_header_offset..pe_header_offset + 4].copy_from_slice(b"PE\0\0");
+    image_bytes[pe_header_offset + 0x18..pe_header_offset + 0x1A].copy_from_slice(&0x20B_u16.to_le_bytes());
+    image_bytes[pe_header_offset + 0x18 + 0x70..pe_header_offset + 0x18 + 0x74].copy_from_slice(&(export_directory_offset as u32).to_le_bytes());
+    image_bytes[export_directory_offset + 0x18..export_directory_offset + 0x1C].copy_from_slice(&(exports.len() as u32).to_le_bytes());
+    image_bytes[export_directory_offset + 0x1C..export_directory_offset + 0x20].copy_from_slice(&(functions_offset as u32).to_le_bytes());
+    image_bytes[export_directory_offset + 0x20..export_directory_offset + 0x24].copy_from_slice(&(names_offset as u32).to_le_bytes());
+    image_bytes[export_directory_offset + 0x24..export_directory_offset + 0x28].copy_from_slice(&(name_ordinals_offset as u32).to_le_bytes());
+
+    for (export_index, (export_name, export_rva)) in exports.iter().enumerate() {
+        // Ordinals are reversed so that name order and function order differ.
+        let function_ordinal = exports.len() - 1 - export_index;
+
+        image_bytes[functions_offset + function_ordinal * 4..functions_offset + function_ordinal * 4 + 4].copy_from_slice(&export_rva.to_le_bytes());
+        image_bytes[names_offset + export_index * 4..names_offset + export_index * 4 + 4].copy_from_slice(&(name_string_offset as u32).to_le_bytes());
+        image_bytes[name_ordinals_offset + export_index * 2..name_ordinals_offset + export_index * 2 + 2]
+            .copy_from_slice(&(function_ordinal as u16).to_le_bytes());
+        image_bytes[name_string_offset..name_string_offset + export_name.len()].copy_from_slice(export_name.as_bytes());
+        name_string_offset += export_name.len() + 1;
+    }
+
+    image_bytes
+}
+
+#[test]
+fn playstation_plugin_matches_pcsx2_and_ppsspp_process_names() {
+    let plugin = PlayStationMemoryViewPlugin::new();
+
+    assert!(plugin.can_attach(&OpenedProcessInfo::new(1, "pcsx2-qt.exe".to_string(), 0, Bitness::Bit64, None)));
+    assert!(plugin.can_attach(&OpenedProcessInfo::new(1, "PPSSPPWindows64.exe".to_string(), 0, Bitness::Bit64, None)));
+    assert!(!plugin.can_attach(&OpenedProcessInfo::new(1, "notepad.exe".to_string(), 0, Bitness::Bit64, None)));
+}
+
+#[test]
+fn process_detection_distinguishes_emulators() {
+    assert_eq!(detect_playstation_emulator("PCSX2.exe"), Some(PlayStationEmulatorKind::Pcsx2));
+    assert_eq!(detect_playstation_emulator("PPSSPPSDL"), Some(PlayStationEmulatorKind::Ppsspp));
+    assert_eq!(detect_playstation_emulator("Dolphin.exe"), None);
+}
+
+#[test]
+fn ee_ram_maps_guest_addresses_to_host_addresses() {
+    let region_descriptor = PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::EmotionEngineRam, 0x0000_7FF6_0000_0000);
+
+    assert_eq!(region_descriptor.virtual_to_host_address(0x0010_0010), Some(0x0000_7FF6_0010_0010));
+    assert_eq!(region_descriptor.host_to_virtual_address(0x0000_7FF6_0010_0010), Some(0x0010_0010));
+    assert_eq!(region_descriptor.virtual_to_host_address(0x0200_0000), None);
+    assert_eq!(
+        region_descriptor.module_relative_address(0x0010_0010),
+        Some(("ee_ram".to_string(), 0x0010_0010))
+    );
+}
+
+#[test]
+fn psp_extended_memory_continues_the_psp_ram_module() {
+    let region_descriptor = PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::PspExtendedMemory, 0x0000_0200_0A00_0000);
+
+    assert_eq!(region_descriptor.virtual_to_host_address(0x0A00_1234), Some(0x0000_0200_0A00_1234));
+    assert_eq!(
+        region_descriptor.module_relative_address(0x0A00_1234),
+        Some(("psp_ram".to_string(), 0x0200_1234))
+    );
+    assert_eq!(resolve_virtual_address_from_module("psp_ram", 0x0200_1234), Some(0x0A00_1234));
+    assert_eq!(resolve_virtual_address_from_module("PSP_RAM", 0x10), Some(0x0800_0010));
+    assert_eq!(resolve_virtual_address_from_module("psp_ram", 0x0400_0000), None);
+}
+
+#[test]
+fn module_offsets_resolve_to_canonical_virtual_addresses() {
+    assert_eq!(resolve_virtual_address_from_module("ee_ram", 0x1000), Some(0x1000));
+    assert_eq!(resolve_virtual_address_from_module("ee_scratchpad", 0x10), Some(0x7000_0010));
+    assert_eq!(resolve_virtual_address_from_module("iop_ram", 0x20), Some(0x1C00_0020));
+    assert_eq!(resolve_virtual_address_from_module("psp_scratchpad", 0x30), Some(0x0001_0030));
+    assert_eq!(resolve_virtual_address_from_module("psp_vram", 0x40), Some(0x0400_0040));
+    assert_eq!(resolve_virtual_address_from_module("ee_scratchpad", 0x4000), None);
+    assert_eq!(resolve_virtual_address_from_module("gc_wii", 0), None);
+}
+
+#[test]
+fn region_lookup_finds_guest_address_and_module_name_matches() {
+    let region_descriptors = vec![
+        PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::EmotionEngineRam, 0x1000_0000),
+        PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::EmotionEngineScratchpad, 0x1800_0000),
+        PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::InputOutputProcessorRam, 0x2000_0000),
+    ];
+
+    assert_eq!(
+        find_playstation_region_by_virtual_address(&region_descriptors, 0x7000_0100).map(|region_descriptor| region_descriptor.get_region_kind()),
+        Some(PlayStationMemoryRegionKind::EmotionEngineScratchpad)
+    );
+    assert_eq!(
+        find_playstation_region_by_module_name(&region_descriptors, "IOP_RAM").map(|region_descriptor| region_descriptor.get_pointer_scan_domain()),
+        Some(PlayStationPointerScanDomain::InputOutputProcessor)
+    );
+    assert!(find_playstation_region_by_virtual_address(&region_descriptors, 0x0800_0000).is_none());
+}
+
+#[test]
+fn pe_export_lookup_resolves_named_exports() {
+    let module_base_address = 0x1_4000_0000_u64;
+    let image_bytes = build_pe_image(&[("EEmemory", 0x500), ("EEmem", 0x600), ("IOPmem", 0x700)]);
+    let read_bytes = |address: u64, values: &mut [u8]| {
+        let Some(image_offset) = address
+            .checked_sub(module_base_address)
+            .map(|image_offset| image_offset as usize)
+        else {
+            return false;
+        };
+        let Some(source_bytes) = image_bytes.get(image_offset..image_offset + values.len()) else {
+            return false;
+        };
+
+        values.copy_from_slice(source_bytes);
+        true
+    };
+
+    assert_eq!(
+        find_pe_export_address(&read_bytes, module_base_address, "EEmem"),
+        Some(module_base_address + 0x600)
+    );
+    assert_eq!(
+        find_pe_export_address(&read_bytes, module_base_address, "IOPmem"),
+        Some(module_base_address + 0x700)
+    );
+    assert_eq!(find_pe_export_address(&read_bytes, module_base_address, "VUmem"), None);
+    assert_eq!(find_pe_export_address(&read_bytes, module_base_address + 0x10, "EEmem"), None);
+}
+
+#[test]
+fn pcsx2_discovery_uses_exported_memory_pointers() {
+    let region_descriptors = select_pcsx2_memory_regions(Some(0x7FF0_0000_0000), Some(0x7FF1_0000_0000), |_| true);
+
+    assert_eq!(
+        region_descriptors,
+        vec![
+            PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::EmotionEngineRam, 0x7FF0_0000_0000),
+            PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::EmotionEngineScratchpad, 0x7FF0_0800_0000),
+            PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::InputOutputProcessorRam, 0x7FF1_0000_0000),
+        ]
+    );
+}
+
+#[test]
+fn pcsx2_discovery_falls_back_to_legacy_reservations() {
+    let region_descriptors = select_pcsx2_memory_regions(None, None, |host_address| host_address != 0x2400_0000);
+
+    assert_eq!(
+        region_descriptors,
+        vec![
+            PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::EmotionEngineRam, 0x2000_0000),
+            PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::EmotionEngineScratchpad, 0x2200_0000),
+        ]
+    );
+}
+
+#[test]
+fn pcsx2_discovery_requires_readable_ee_ram() {
+    assert!(select_pcsx2_memory_regions(None, None, |_| false).is_empty());
+    assert!(select_pcsx2_memory_regions(Some(0x7FF0_0000_0000), None, |host_address| host_address != 0x7FF0_0000_0000).is_empty());
+}
+
+#[test]
+fn ppsspp_discovery_selects_the_lowest_complete_arena() {
+    let mut raw_memory_regions = build_ppsspp_arena_regions(PPSSPP_ARENA_BASE_ADDRESS + 0x4000_0000, 0x0200_0000);
+
+    raw_memory_regions.extend(build_ppsspp_arena_regions(PPSSPP_ARENA_BASE_ADDRESS, 0x0200_0000));
+    raw_memory_regions.push(NormalizedRegion::new(0x0000_0300_0800_0000, 0x0200_0000));
+
+    assert_eq!(
+        select_ppsspp_memory_regions(&raw_memory_regions),
+        vec![
+            PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::PspUserMemory, PPSSPP_ARENA_BASE_ADDRESS + 0x0800_0000),
+            PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::PspScratchpad, PPSSPP_ARENA_BASE_ADDRESS + 0x0001_0000),
+            PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::PspVideoRam, PPSSPP_ARENA_BASE_ADDRESS + 0x0400_0000),
+        ]
+    );
+}
+
+#[test]
+fn ppsspp_discovery_exposes_extended_memory_for_64mb_user_memory() {
+    let region_descriptors = select_ppsspp_memory_regions(&build_ppsspp_arena_regions(PPSSPP_ARENA_BASE_ADDRESS, 0x0400_0000));
+
+    assert_eq!(region_descriptors.len(), 4);
+    assert_eq!(
+        region_descriptors[3],
+        PlayStationMemoryRegionDescriptor::new(PlayStationMemoryRegionKind::PspExtendedMemory, PPSSPP_ARENA_BASE_ADDRESS + 0x0A00_0000)
+    );
+}
+
+#[test]
+fn ppsspp_discovery_rejects_user_memory_sized_regions_without_companion_views() {
+    let raw_memory_regions = vec![
+        NormalizedRegion::new(PPSSPP_ARENA_BASE_ADDRESS + 0x0800_0000, 0x0200_0000),
+        NormalizedRegion::new(PPSSPP_ARENA_BASE_ADDRESS + 0x0400_0000, 0x0020_0000),
+    ];
+
+    assert!(select_ppsspp_memory_regions(&raw_memory_regions).is_empty());
+}
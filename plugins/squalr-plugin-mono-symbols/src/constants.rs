@@ -0,0 +1,3 @@
+pub const MONO_SYMBOLS_PLUGIN_ID: &str = "builtin.symbols.mono";
+pub const MONO_SYMBOLS_PLUGIN_DISPLAY_NAME: &str = "Mono Symbols";
+pub const MONO_SYMBOLS_PLUGIN_DESCRIPTION: &str = "Populates managed class layouts and static fields from a running Mono (Unity) runtime.";
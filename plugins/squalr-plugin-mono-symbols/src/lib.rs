@@ -0,0 +1,35 @@
+mod constants;
+mod mono_symbol_builder;
+mod plugin;
+mod populate_mono_symbols_action;
+mod runtime;
+
+#[cfg(test)]
+mod test_process_memory;
+
+pub use plugin::MonoSymbolsPlugin;
+
+#[cfg(test)]
+mod tests {
+    use super::MonoSymbolsPlugin;
+    use squalr_engine_api::plugins::{Plugin, PluginPermission, symbol_tree::symbol_tree_plugin::SymbolTreePlugin};
+
+    #[test]
+    fn plugin_exposes_symbol_store_and_process_memory_permissions() {
+        let plugin = MonoSymbolsPlugin::new();
+
+        assert_eq!(plugin.metadata().get_plugin_id(), "builtin.symbols.mono");
+        assert!(plugin.metadata().get_is_enabled_by_default());
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_permission(PluginPermission::WriteSymbolStore)
+        );
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_permission(PluginPermission::ReadProcessMemory)
+        );
+        assert_eq!(plugin.symbol_tree_actions().len(), 1);
+    }
+}
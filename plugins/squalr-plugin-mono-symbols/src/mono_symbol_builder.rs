@@ -0,0 +1,531 @@
+use crate::runtime::{
+    mono_runtime_offsets::MonoRuntimeOffsets,
+    mono_runtime_walker::{MonoClassInfo, MonoFieldInfo},
+};
+use squalr_engine_api::{
+    registries::symbols::struct_layout_descriptor::StructLayoutDescriptor,
+    structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::container_type::ContainerType,
+        pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize,
+        projects::{
+            project_symbol_claim::ProjectSymbolClaim,
+            symbol_layouts::symbol_layout_field_materializer::{SymbolLayoutFieldMaterializer, SymbolLayoutPositionedField},
+        },
+        structs::{
+            symbolic_field_definition::SymbolicFieldDefinition,
+            symbolic_struct_definition::{SymbolicLayoutKind, SymbolicStructDefinition},
+        },
+    },
+};
+use std::collections::{HashMap, HashSet};
+
+const LAYOUT_ID_PREFIX: &str = "mono";
+const STATICS_LAYOUT_ID_SUFFIX: &str = "statics";
+const ENUM_VALUE_FIELD_NAME: &str = "value__";
+const MAX_PARENT_DEPTH: usize = 64;
+const MONO_TYPE_BOOLEAN: u8 = 0x02;
+const MONO_TYPE_CHAR: u8 = 0x03;
+const MONO_TYPE_I1: u8 = 0x04;
+const MONO_TYPE_U1: u8 = 0x05;
+const MONO_TYPE_I2: u8 = 0x06;
+const MONO_TYPE_U2: u8 = 0x07;
+const MONO_TYPE_I4: u8 = 0x08;
+const MONO_TYPE_U4: u8 = 0x09;
+const MONO_TYPE_I8: u8 = 0x0A;
+const MONO_TYPE_U8: u8 = 0x0B;
+const MONO_TYPE_R4: u8 = 0x0C;
+const MONO_TYPE_R8: u8 = 0x0D;
+const MONO_TYPE_STRING: u8 = 0x0E;
+const MONO_TYPE_PTR: u8 = 0x0F;
+const MONO_TYPE_VALUETYPE: u8 = 0x11;
+const MONO_TYPE_CLASS: u8 = 0x12;
+const MONO_TYPE_ARRAY: u8 = 0x14;
+const MONO_TYPE_I: u8 = 0x18;
+const MONO_TYPE_U: u8 = 0x19;
+const MONO_TYPE_FNPTR: u8 = 0x1B;
+const MONO_TYPE_OBJECT: u8 = 0x1C;
+const MONO_TYPE_SZARRAY: u8 = 0x1D;
+
+/// Layouts and static-field claims derived from the runtime's class metadata.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MonoSymbols {
+    pub(crate) struct_layout_descriptors: Vec<StructLayoutDescriptor>,
+    pub(crate) static_field_claims: Vec<ProjectSymbolClaim>,
+}
+
+#[derive(Clone, Debug)]
+struct FieldShape {
+    data_type_id: String,
+    container_type: ContainerType,
+    size_in_bytes: u64,
+}
+
+/// Builds one layout per class that declares fields. Instance layouts describe objects as they sit on the managed heap,
+/// header and inherited fields included, so a field's layout offset matches the offset Mono reports for it. Value types
+/// therefore describe their boxed form.
+pub(crate) struct MonoSymbolBuilder<'a> {
+    offsets: MonoRuntimeOffsets,
+    classes_by_address: HashMap<u64, &'a MonoClassInfo>,
+    layout_ids_by_address: HashMap<u64, String>,
+}
+
+impl<'a> MonoSymbolBuilder<'a> {
+    pub(crate) fn new(
+        classes: &'a [MonoClassInfo],
+        offsets: MonoRuntimeOffsets,
+    ) -> Self {
+        let classes_by_address = classes
+            .iter()
+            .map(|class_info| (class_info.address, class_info))
+            .collect::<HashMap<_, _>>();
+        let mut classes_with_fields = classes
+            .iter()
+            .filter(|class_info| !class_info.fields.is_empty())
+            .collect::<Vec<_>>();
+        let mut used_layout_ids = HashSet::new();
+        let mut layout_ids_by_address = HashMap::new();
+
+        classes_with_fields.sort_by(|left_class, right_class| {
+            left_class
+                .get_qualified_name()
+                .cmp(&right_class.get_qualified_name())
+                .then_with(|| left_class.address.cmp(&right_class.address))
+        });
+
+        // Classes from different assemblies can share a name, so later duplicates are told apart by address.
+        for class_info in classes_with_fields {
+            let mut layout_id = build_layout_id(class_info);
+
+            if !used_layout_ids.insert(layout_id.clone()) {
+                layout_id = format!("{layout_id}_{:X}", class_info.address);
+                used_layout_ids.insert(layout_id.clone());
+            }
+
+            layout_ids_by_address.insert(class_info.address, layout_id);
+        }
+
+        Self {
+            offsets,
+            classes_by_address,
+            layout_ids_by_address,
+        }
+    }
+
+    pub(crate) fn build(&self) -> MonoSymbols {
+        let mut mono_symbols = MonoSymbols::default();
+        let mut layout_entries = self
+            .layout_ids_by_address
+            .iter()
+            .filter_map(|(class_address, layout_id)| Some((layout_id, *self.classes_by_address.get(class_address)?)))
+            .collect::<Vec<_>>();
+
+        layout_entries.sort_by(|left_entry, right_entry| left_entry.0.cmp(right_entry.0));
+
+        for (layout_id, class_info) in layout_entries {
+            if let Some(instance_layout_descriptor) = self.build_instance_layout(layout_id, class_info) {
+                mono_symbols
+                    .struct_layout_descriptors
+                    .push(instance_layout_descriptor);
+            }
+
+            let Some(static_data_address) = class_info.static_data_address else {
+                continue;
+            };
+            let statics_layout_id = format!("{layout_id}.{STATICS_LAYOUT_ID_SUFFIX}");
+
+            if let Some(statics_layout_descriptor) = self.build_statics_layout(&statics_layout_id, class_info) {
+                mono_symbols
+                    .struct_layout_descriptors
+                    .push(statics_layout_descriptor);
+                mono_symbols
+                    .static_field_claims
+                    .push(ProjectSymbolClaim::new_absolute_address(
+                        format!("{} (static)", class_info.get_qualified_name()),
+                        static_data_address,
+                        statics_layout_id,
+                    ));
+            }
+        }
+
+        mono_symbols
+    }
+
+    fn build_instance_layout(
+        &self,
+        layout_id: &str,
+        class_info: &'a MonoClassInfo,
+    ) -> Option<StructLayoutDescriptor> {
+        let mut instance_fields = self
+            .collect_class_hierarchy(class_info)
+            .into_iter()
+            .flat_map(|hierarchy_class| hierarchy_class.fields.iter())
+            .filter(|field| !field.is_static && field.offset >= self.offsets.object_header_size)
+            .collect::<Vec<_>>();
+        let header_fields = [
+            (String::from("vtable"), 0),
+            (String::from("monitor"), self.offsets.pointer_size),
+        ]
+        .into_iter()
+        .filter(|(_, header_offset)| header_offset + self.offsets.pointer_size <= self.offsets.object_header_size)
+        .map(|(field_name, header_offset)| {
+            SymbolLayoutPositionedField::new(
+                header_offset,
+                self.offsets.pointer_size,
+                SymbolicFieldDefinition::new_named(field_name, DataTypeRef::new("u8"), ContainerType::Pointer(self.pointer_size())),
+            )
+        });
+
+        instance_fields.sort_by_key(|field| field.offset);
+
+        let positioned_fields = header_fields
+            .chain(self.position_fields(&instance_fields, class_info.instance_size))
+            .collect::<Vec<_>>();
+
+        materialize_layout(layout_id, class_info.instance_size, positioned_fields)
+    }
+
+    fn build_statics_layout(
+        &self,
+        layout_id: &str,
+        class_info: &MonoClassInfo,
+    ) -> Option<StructLayoutDescriptor> {
+        let mut static_fields = class_info
+            .fields
+            .iter()
+            .filter(|field| field.is_static)
+            .collect::<Vec<_>>();
+
+        static_fields.sort_by_key(|field| field.offset);
+
+        let positioned_fields = self.position_fields(&static_fields, 0).collect::<Vec<_>>();
+
+        if positioned_fields.is_empty() {
+            return None;
+        }
+
+        materialize_layout(layout_id, 0, positioned_fields)
+    }
+
+    /// Expects fields sorted by offset. Fields that overlap an earlier one are dropped, and value types without a known
+    /// primitive shape fill the gap up to the next field as bytes.
+    fn position_fields<'field>(
+        &'field self,
+        sorted_fields: &'field [&'field MonoFieldInfo],
+        declared_size_in_bytes: u64,
+    ) -> impl Iterator<Item = SymbolLayoutPositionedField> + 'field {
+        let mut next_free_offset = 0_u64;
+
+        sorted_fields
+            .iter()
+            .enumerate()
+            .filter_map(move |(field_index, field)| {
+                let next_field_offset = sorted_fields
+                    .get(field_index + 1)
+                    .map(|next_field| next_field.offset)
+                    .unwrap_or(declared_size_in_bytes);
+                let field_shape = self
+                    .resolve_field_shape(field)
+                    .or_else(|| opaque_field_shape(next_field_offset.saturating_sub(field.offset)))?;
+
+                if field.offset < next_free_offset {
+                    return None;
+                }
+
+                next_free_offset = field.offset.saturating_add(field_shape.size_in_bytes);
+
+                Some(SymbolLayoutPositionedField::new(
+                    field.offset,
+                    field_shape.size_in_bytes,
+                    SymbolicFieldDefinition::new_named(field.name.clone(), DataTypeRef::new(&field_shape.data_type_id), field_shape.container_type),
+                ))
+            })
+    }
+
+    fn resolve_field_shape(
+        &self,
+        field: &MonoFieldInfo,
+    ) -> Option<FieldShape> {
+        if let Some(field_shape) = primitive_field_shape(field.type_code) {
+            return Some(field_shape);
+        }
+
+        match field.type_code {
+            MONO_TYPE_CLASS => {
+                let pointee_layout_id = field
+                    .referenced_class_address
+                    .and_then(|class_address| self.layout_ids_by_address.get(&class_address))
+                    .cloned()
+                    .unwrap_or_else(|| String::from("u8"));
+
+                Some(self.pointer_field_shape(&pointee_layout_id))
+            }
+            MONO_TYPE_STRING | MONO_TYPE_PTR | MONO_TYPE_ARRAY | MONO_TYPE_FNPTR | MONO_TYPE_OBJECT | MONO_TYPE_SZARRAY => Some(self.pointer_field_shape("u8")),
+            // Enums are value types whose only instance field holds the underlying integer.
+            MONO_TYPE_VALUETYPE => {
+                let enum_class = self.classes_by_address.get(&field.referenced_class_address?)?;
+                let mut enum_instance_fields = enum_class
+                    .fields
+                    .iter()
+                    .filter(|enum_field| !enum_field.is_static);
+                let value_field = enum_instance_fields.next()?;
+
+                if value_field.name != ENUM_VALUE_FIELD_NAME || enum_instance_fields.next().is_some() {
+                    return None;
+                }
+
+                primitive_field_shape(value_field.type_code)
+            }
+            _ => None,
+        }
+    }
+
+    fn pointer_field_shape(
+        &self,
+        pointee_data_type_id: &str,
+    ) -> FieldShape {
+        FieldShape {
+            data_type_id: pointee_data_type_id.to_string(),
+            container_type: ContainerType::Pointer(self.pointer_size()),
+            size_in_bytes: self.offsets.pointer_size,
+        }
+    }
+
+    fn pointer_size(&self) -> PointerScanPointerSize {
+        if self.offsets.pointer_size == 4 {
+            PointerScanPointerSize::Pointer32
+        } else {
+            PointerScanPointerSize::Pointer64
+        }
+    }
+
+    /// Returns the class and its ancestors, base class first.
+    fn collect_class_hierarchy(
+        &self,
+        class_info: &'a MonoClassInfo,
+    ) -> Vec<&'a MonoClassInfo> {
+        let mut class_hierarchy = vec![class_info];
+        let mut parent_address = class_info.parent_address;
+
+        while let Some(parent_class) = parent_address.and_then(|address| self.classes_by_address.get(&address)) {
+            if class_hierarchy.len() >= MAX_PARENT_DEPTH {
+                break;
+            }
+
+            class_hierarchy.push(parent_class);
+            parent_address = parent_class.parent_address;
+        }
+
+        class_hierarchy.reverse();
+        class_hierarchy
+    }
+}
+
+fn materialize_layout(
+    layout_id: &str,
+    declared_size_in_bytes: u64,
+    positioned_fields: Vec<SymbolLayoutPositionedField>,
+) -> Option<StructLayoutDescriptor> {
+    let field_end = positioned_fields
+        .iter()
+        .map(|positioned_field| {
+            positioned_field
+                .get_offset_in_bytes()
+                .saturating_add(positioned_field.get_size_in_bytes())
+        })
+        .max()
+        .unwrap_or(0);
+    let declared_size_in_bytes = declared_size_in_bytes.max(field_end);
+    let materialized_fields =
+        SymbolLayoutFieldMaterializer::materialize_positioned_fields(SymbolicLayoutKind::Struct, Some(declared_size_in_bytes), positioned_fields).ok()?;
+
+    Some(StructLayoutDescriptor::new(
+        layout_id.to_string(),
+        SymbolicStructDefinition::new_with_layout_kind(layout_id.to_string(), SymbolicLayoutKind::Struct, materialized_fields)
+            .with_declared_size_in_bytes(Some(declared_size_in_bytes)),
+    ))
+}
+
+fn primitive_field_shape(type_code: u8) -> Option<FieldShape> {
+    let (data_type_id, size_in_bytes) = match type_code {
+        MONO_TYPE_BOOLEAN => ("bool8", 1),
+        MONO_TYPE_CHAR | MONO_TYPE_U2 => ("u16", 2),
+        MONO_TYPE_I1 => ("i8", 1),
+        MONO_TYPE_U1 => ("u8", 1),
+        MONO_TYPE_I2 => ("i16", 2),
+        MONO_TYPE_I4 => ("i32", 4),
+        MONO_TYPE_U4 => ("u32", 4),
+        MONO_TYPE_I8 | MONO_TYPE_I => ("i64", 8),
+        MONO_TYPE_U8 | MONO_TYPE_U => ("u64", 8),
+        MONO_TYPE_R4 => ("f32", 4),
+        MONO_TYPE_R8 => ("f64", 8),
+        _ => return None,
+    };
+
+    Some(FieldShape {
+        data_type_id: data_type_id.to_string(),
+        container_type: ContainerType::None,
+        size_in_bytes,
+    })
+}
+
+fn opaque_field_shape(size_in_bytes: u64) -> Option<FieldShape> {
+    (size_in_bytes > 0).then(|| FieldShape {
+        data_type_id: String::from("u8"),
+        container_type: ContainerType::ArrayFixed(size_in_bytes),
+        size_in_bytes,
+    })
+}
+
+/// Namespaces become dotted layout id components. Generic arity markers and compiler-generated names are reduced to
+/// identifier characters.
+fn build_layout_id(class_info: &MonoClassInfo) -> String {
+    std::iter::once(LAYOUT_ID_PREFIX.to_string())
+        .chain(
+            class_info
+                .namespace
+                .split('.')
+                .filter(|namespace_component| !namespace_component.is_empty())
+                .map(sanitize_layout_id_component),
+        )
+        .chain(std::iter::once(sanitize_layout_id_component(&class_info.name)))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn sanitize_layout_id_component(name: &str) -> String {
+    let mut sanitized_name = String::with_capacity(name.len());
+
+    for name_character in name.chars() {
+        if name_character.is_ascii_alphanumeric() || name_character == '_' {
+            sanitized_name.push(name_character);
+        } else if !sanitized_name.ends_with('_') {
+            sanitized_name.push('_');
+        }
+    }
+
+    let sanitized_name = sanitized_name.trim_matches('_');
+
+    if sanitized_name.is_empty() {
+        String::from("anonymous")
+    } else {
+        sanitized_name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MonoSymbolBuilder, build_layout_id};
+    use crate::runtime::{
+        mono_runtime_offsets::MonoRuntimeOffsets,
+        mono_runtime_walker::{MonoClassInfo, MonoFieldInfo},
+    };
+
+    fn field(
+        name: &str,
+        offset: u64,
+        type_code: u8,
+        is_static: bool,
+        referenced_class_address: Option<u64>,
+    ) -> MonoFieldInfo {
+        MonoFieldInfo {
+            name: name.to_string(),
+            offset,
+            type_code,
+            is_static,
+            referenced_class_address,
+        }
+    }
+
+    fn class(
+        address: u64,
+        namespace: &str,
+        name: &str,
+        instance_size: u64,
+        parent_address: Option<u64>,
+        fields: Vec<MonoFieldInfo>,
+    ) -> MonoClassInfo {
+        MonoClassInfo {
+            address,
+            assembly_name: String::from("Assembly-CSharp"),
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            instance_size,
+            parent_address,
+            fields,
+            static_data_address: None,
+        }
+    }
+
+    #[test]
+    fn build_layout_id_sanitizes_generic_and_nested_names() {
+        let generic_class = class(0x1000, "System.Collections.Generic", "List`1", 0, None, Vec::new());
+        let compiler_class = class(0x2000, "", "<Module>", 0, None, Vec::new());
+
+        assert_eq!(build_layout_id(&generic_class), "mono.System.Collections.Generic.List_1");
+        assert_eq!(build_layout_id(&compiler_class), "mono.Module");
+    }
+
+    #[test]
+    fn build_includes_header_inherited_fields_enums_and_statics() {
+        let mut player_class = class(
+            0x2000,
+            "Game",
+            "Player",
+            0x30,
+            Some(0x1000),
+            vec![
+                field("state", 0x14, 0x11, false, Some(0x3000)),
+                field("target", 0x18, 0x12, false, Some(0x1000)),
+                field("position", 0x20, 0x11, false, Some(0x4000)),
+                field("instanceCount", 0x0, 0x08, true, None),
+            ],
+        );
+        player_class.static_data_address = Some(0x9000);
+        let classes = vec![
+            class(0x1000, "Game", "Entity", 0x14, None, vec![field("id", 0x10, 0x08, false, None)]),
+            player_class,
+            class(0x3000, "Game", "State", 0x14, None, vec![field("value__", 0x10, 0x08, false, None)]),
+        ];
+        let mono_symbols = MonoSymbolBuilder::new(&classes, MonoRuntimeOffsets::UNITY_BDWGC_X64).build();
+        let player_layout = mono_symbols
+            .struct_layout_descriptors
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == "mono.Game.Player")
+            .expect("Expected a player layout.");
+        let player_field_types = player_layout
+            .get_struct_layout_definition()
+            .get_fields()
+            .iter()
+            .filter(|field_definition| !field_definition.is_unassigned())
+            .map(|field_definition| {
+                (
+                    field_definition.get_field_name().to_string(),
+                    field_definition
+                        .get_data_type_ref()
+                        .get_data_type_id()
+                        .to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            player_layout
+                .get_struct_layout_definition()
+                .get_declared_size_in_bytes(),
+            Some(0x30)
+        );
+        assert_eq!(
+            player_field_types
+                .iter()
+                .map(|(field_name, _)| field_name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["vtable", "monitor", "id", "state", "target", "position"]
+        );
+        assert_eq!(player_field_types[3].1, "i32");
+        assert_eq!(player_field_types[4].1, "mono.Game.Entity");
+        assert_eq!(mono_symbols.static_field_claims.len(), 1);
+        assert_eq!(mono_symbols.static_field_claims[0].get_display_name(), "Game.Player (static)");
+        assert_eq!(mono_symbols.static_field_claims[0].get_struct_layout_id(), "mono.Game.Player.statics");
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    constants::{MONO_SYMBOLS_PLUGIN_DESCRIPTION, MONO_SYMBOLS_PLUGIN_DISPLAY_NAME, MONO_SYMBOLS_PLUGIN_ID},
+    populate_mono_symbols_action::PopulateMonoSymbolsAction,
+};
+use squalr_engine_api::plugins::{
+    Plugin, PluginCapability, PluginMetadata, PluginPackage, PluginPermission, symbol_tree::symbol_tree_action::SymbolTreeAction,
+    symbol_tree::symbol_tree_plugin::SymbolTreePlugin,
+};
+use std::sync::Arc;
+
+pub struct MonoSymbolsPlugin {
+    metadata: PluginMetadata,
+    symbol_tree_actions: Vec<Arc<dyn SymbolTreeAction>>,
+}
+
+impl MonoSymbolsPlugin {
+    pub fn new() -> Self {
+        Self {
+            metadata: PluginMetadata::new_with_permissions(
+                MONO_SYMBOLS_PLUGIN_ID,
+                MONO_SYMBOLS_PLUGIN_DISPLAY_NAME,
+                MONO_SYMBOLS_PLUGIN_DESCRIPTION,
+                vec![PluginCapability::SymbolTree],
+                vec![
+                    PluginPermission::ReadSymbolStore,
+                    PluginPermission::WriteSymbolStore,
+                    PluginPermission::ReadSymbolTreeWindow,
+                    PluginPermission::WriteSymbolTreeWindow,
+                    PluginPermission::ReadProcessMemory,
+                ],
+                true,
+                true,
+            ),
+            symbol_tree_actions: vec![Arc::new(PopulateMonoSymbolsAction)],
+        }
+    }
+}
+
+impl Default for MonoSymbolsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for MonoSymbolsPlugin {
+    fn metadata(&self) -> &PluginMetadata {
+        &self.metadata
+    }
+}
+
+impl PluginPackage for MonoSymbolsPlugin {
+    fn as_symbol_tree_plugin(&self) -> Option<&dyn SymbolTreePlugin> {
+        Some(self)
+    }
+}
+
+impl SymbolTreePlugin for MonoSymbolsPlugin {
+    fn symbol_tree_actions(&self) -> &[Arc<dyn SymbolTreeAction>] {
+        &self.symbol_tree_actions
+    }
+}
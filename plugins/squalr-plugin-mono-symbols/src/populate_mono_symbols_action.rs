@@ -0,0 +1,464 @@
+use crate::{
+    mono_symbol_builder::{MonoSymbolBuilder, MonoSymbols},
+    runtime::{
+        mono_memory_reader::MonoMemoryReader, mono_root_domain_locator::locate_root_domain_global, mono_runtime_offsets::MonoRuntimeOffsets,
+        mono_runtime_walker::MonoRuntimeWalker,
+    },
+};
+use squalr_engine_api::{
+    plugins::{
+        PluginPermission,
+        symbol_tree::symbol_tree_action::{
+            DataTypeRegistryStore, SymbolTreeAction, SymbolTreeActionContext, SymbolTreeActionSelection, SymbolTreeActionServices,
+        },
+    },
+    registries::symbols::struct_layout_descriptor::StructLayoutDescriptor,
+    structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::container_type::ContainerType,
+        projects::{
+            project_symbol_catalog::ProjectSymbolCatalog,
+            project_symbol_module_field::ProjectSymbolModuleField,
+            symbol_layouts::{
+                symbol_layout_field_materializer::{SymbolLayoutFieldMaterializer, SymbolLayoutPositionedField},
+                symbol_layout_size_resolver::SymbolLayoutSizeResolver,
+            },
+        },
+        structs::{
+            symbolic_field_definition::{SymbolicFieldDefinition, SymbolicFieldOffsetResolution},
+            symbolic_struct_definition::SymbolicStructDefinition,
+        },
+    },
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Module names of the Boehm-GC Mono runtime that Unity ships on Windows, Linux and macOS.
+const MONO_RUNTIME_MODULE_NAMES: [&str; 2] = ["mono-2.0-bdwgc", "libmonobdwgc"];
+const ROOT_DOMAIN_FIELD_NAME: &str = "mono_root_domain";
+const ROOT_DOMAIN_FIELD_DATA_TYPE_ID: &str = "u64";
+
+pub struct PopulateMonoSymbolsAction;
+
+impl SymbolTreeAction for PopulateMonoSymbolsAction {
+    fn action_id(&self) -> &'static str {
+        "builtin.symbols.mono.populate-mono-symbols"
+    }
+
+    fn label(
+        &self,
+        _context: &SymbolTreeActionContext,
+    ) -> String {
+        String::from("Populate Mono Symbols")
+    }
+
+    fn is_visible(
+        &self,
+        context: &SymbolTreeActionContext,
+    ) -> bool {
+        matches!(context.get_selection(), SymbolTreeActionSelection::ModuleRoot { module_name } if is_mono_runtime_module(module_name))
+    }
+
+    fn required_permissions(&self) -> &'static [PluginPermission] {
+        &[
+            PluginPermission::ReadSymbolStore,
+            PluginPermission::WriteSymbolStore,
+            PluginPermission::ReadSymbolTreeWindow,
+            PluginPermission::WriteSymbolTreeWindow,
+            PluginPermission::ReadProcessMemory,
+        ]
+    }
+
+    fn execute(
+        &self,
+        context: &SymbolTreeActionContext,
+        services: &dyn SymbolTreeActionServices,
+    ) -> Result<(), String> {
+        let SymbolTreeActionSelection::ModuleRoot { module_name } = context.get_selection() else {
+            return Err(String::from("Mono symbol population requires a module root selection."));
+        };
+
+        if !is_mono_runtime_module(module_name) {
+            return Err(format!("Module `{module_name}` is not a Mono runtime."));
+        }
+
+        let offsets = MonoRuntimeOffsets::UNITY_BDWGC_X64;
+        let process_memory_store = services.process_memory();
+        let root_domain_global_offset = locate_root_domain_global(process_memory_store, module_name)?;
+        let memory_reader = MonoMemoryReader::new(process_memory_store);
+        let root_domain_bytes = process_memory_store.read_module_bytes(module_name, root_domain_global_offset, offsets.pointer_size)?;
+        let root_domain_address = root_domain_bytes
+            .try_into()
+            .map(u64::from_le_bytes)
+            .map_err(|_| String::from("Could not read the Mono root domain pointer."))?;
+
+        if root_domain_address == 0 {
+            return Err(String::from("The Mono root domain has not been created yet."));
+        }
+
+        let classes = MonoRuntimeWalker::new(&memory_reader, offsets).collect_classes(root_domain_address)?;
+        let mono_symbols = MonoSymbolBuilder::new(&classes, offsets).build();
+        let data_type_size_by_id = collect_data_type_size_by_id(services.data_type_registry());
+        let module_name = module_name.clone();
+        let module_name_for_update = module_name.clone();
+
+        services.symbol_store().write_catalog(
+            "populate Mono symbols",
+            Box::new(move |project_symbol_catalog| {
+                populate_mono_symbols(
+                    project_symbol_catalog,
+                    &module_name_for_update,
+                    root_domain_global_offset,
+                    mono_symbols,
+                    &data_type_size_by_id,
+                )
+            }),
+        )?;
+        services.symbol_tree_window().request_refresh();
+        services
+            .symbol_tree_window()
+            .focus_tree_node(&format!("module:{module_name}"));
+
+        Ok(())
+    }
+}
+
+fn is_mono_runtime_module(module_name: &str) -> bool {
+    let module_name = module_name.to_ascii_lowercase();
+
+    MONO_RUNTIME_MODULE_NAMES
+        .iter()
+        .any(|mono_runtime_module_name| module_name.starts_with(mono_runtime_module_name))
+}
+
+fn populate_mono_symbols(
+    project_symbol_catalog: &mut ProjectSymbolCatalog,
+    module_name: &str,
+    root_domain_global_offset: u64,
+    mono_symbols: MonoSymbols,
+    data_type_size_by_id: &BTreeMap<String, u64>,
+) -> Result<(), String> {
+    upsert_struct_layout_descriptors(project_symbol_catalog, mono_symbols.struct_layout_descriptors);
+
+    // Static storage is allocated per run, so claims from an earlier population are replaced rather than kept beside
+    // the new ones.
+    let static_claim_layout_ids = mono_symbols
+        .static_field_claims
+        .iter()
+        .map(|symbol_claim| symbol_claim.get_struct_layout_id().to_string())
+        .collect::<HashSet<_>>();
+    let symbol_claims = project_symbol_catalog.get_symbol_claims_mut();
+
+    symbol_claims.retain(|symbol_claim| !static_claim_layout_ids.contains(symbol_claim.get_struct_layout_id()));
+    symbol_claims.extend(mono_symbols.static_field_claims);
+
+    upsert_root_domain_module_field(project_symbol_catalog, module_name, root_domain_global_offset, data_type_size_by_id)
+}
+
+fn upsert_struct_layout_descriptors(
+    project_symbol_catalog: &mut ProjectSymbolCatalog,
+    new_struct_layout_descriptors: Vec<StructLayoutDescriptor>,
+) {
+    let mut struct_layout_descriptors = project_symbol_catalog.get_struct_layout_descriptors().to_vec();
+    let mut struct_layout_positions_by_id = struct_layout_descriptors
+        .iter()
+        .enumerate()
+        .map(|(struct_layout_position, struct_layout_descriptor)| (struct_layout_descriptor.get_struct_layout_id().to_string(), struct_layout_position))
+        .collect::<HashMap<_, _>>();
+
+    for new_struct_layout_descriptor in new_struct_layout_descriptors {
+        match struct_layout_positions_by_id.get(new_struct_layout_descriptor.get_struct_layout_id()) {
+            Some(struct_layout_position) => struct_layout_descriptors[*struct_layout_position] = new_struct_layout_descriptor,
+            None => {
+                struct_layout_positions_by_id.insert(new_struct_layout_descriptor.get_struct_layout_id().to_string(), struct_layout_descriptors.len());
+                struct_layout_descriptors.push(new_struct_layout_descriptor);
+            }
+        }
+    }
+
+    project_symbol_catalog.set_struct_layout_descriptors(struct_layout_descriptors);
+}
+
+/// Adds the root domain global to the runtime module, both as a module field and in the module root layout.
+fn upsert_root_domain_module_field(
+    project_symbol_catalog: &mut ProjectSymbolCatalog,
+    module_name: &str,
+    root_domain_global_offset: u64,
+    data_type_size_by_id: &BTreeMap<String, u64>,
+) -> Result<(), String> {
+    let field_size_in_bytes = data_type_size_by_id
+        .get(ROOT_DOMAIN_FIELD_DATA_TYPE_ID)
+        .copied()
+        .unwrap_or(8);
+    let field_end = root_domain_global_offset
+        .checked_add(field_size_in_bytes)
+        .ok_or_else(|| String::from("Mono root domain offset is too large."))?;
+
+    project_symbol_catalog.ensure_symbol_module(module_name, field_end);
+    let Some(symbol_module) = project_symbol_catalog.find_symbol_module_mut(module_name) else {
+        return Err(format!("Could not resolve module `{module_name}` after creating it."));
+    };
+    let module_fields = symbol_module.get_fields_mut();
+
+    module_fields.retain(|module_field| module_field.get_offset() < root_domain_global_offset || module_field.get_offset() >= field_end);
+    module_fields.push(ProjectSymbolModuleField::new(
+        ROOT_DOMAIN_FIELD_NAME.to_string(),
+        root_domain_global_offset,
+        ROOT_DOMAIN_FIELD_DATA_TYPE_ID.to_string(),
+    ));
+    module_fields.sort_by_key(|module_field| module_field.get_offset());
+
+    let module_size = symbol_module.get_size();
+    let resolve_data_type_size_in_bytes = |data_type_ref: &DataTypeRef| {
+        data_type_size_by_id
+            .get(data_type_ref.get_data_type_id())
+            .copied()
+    };
+
+    project_symbol_catalog.ensure_module_root_struct_layout(module_name, module_size, resolve_data_type_size_in_bytes);
+
+    let mut struct_layout_descriptors = project_symbol_catalog.get_struct_layout_descriptors().to_vec();
+    let Some(module_root_layout_descriptor) = struct_layout_descriptors
+        .iter_mut()
+        .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == module_name)
+    else {
+        return Ok(());
+    };
+    let module_root_layout_definition = module_root_layout_descriptor
+        .get_struct_layout_definition()
+        .clone();
+    let resolve_struct_layout_definition = |struct_layout_id: &str| {
+        project_symbol_catalog
+            .get_struct_layout_descriptors()
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == struct_layout_id)
+            .map(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_definition().clone())
+    };
+    let mut positioned_fields = Vec::new();
+    let mut next_sequential_offset = 0_u64;
+
+    for field_definition in module_root_layout_definition.get_fields() {
+        let field_size_in_bytes = SymbolLayoutSizeResolver::resolve_symbolic_field_size_in_bytes(
+            field_definition,
+            resolve_data_type_size_in_bytes,
+            resolve_struct_layout_definition,
+            &mut HashSet::new(),
+        )
+        .unwrap_or(0)
+        .max(1);
+        let field_offset = match field_definition.get_offset_resolution() {
+            SymbolicFieldOffsetResolution::Static(offset_in_bytes) => *offset_in_bytes,
+            SymbolicFieldOffsetResolution::Sequential | SymbolicFieldOffsetResolution::Resolver(_) => next_sequential_offset,
+        };
+
+        next_sequential_offset = next_sequential_offset.max(field_offset.saturating_add(field_size_in_bytes));
+
+        if field_definition.is_unassigned() || (field_offset < field_end && root_domain_global_offset < field_offset.saturating_add(field_size_in_bytes)) {
+            continue;
+        }
+
+        positioned_fields.push(SymbolLayoutPositionedField::new(field_offset, field_size_in_bytes, field_definition.clone()));
+    }
+
+    positioned_fields.push(SymbolLayoutPositionedField::new(
+        root_domain_global_offset,
+        field_size_in_bytes,
+        SymbolicFieldDefinition::new_named(
+            ROOT_DOMAIN_FIELD_NAME.to_string(),
+            DataTypeRef::new(ROOT_DOMAIN_FIELD_DATA_TYPE_ID),
+            ContainerType::None,
+        ),
+    ));
+    positioned_fields.sort_by_key(|positioned_field| positioned_field.get_offset_in_bytes());
+
+    let declared_size_in_bytes = module_root_layout_definition
+        .get_declared_size_in_bytes()
+        .unwrap_or(0)
+        .max(module_size)
+        .max(field_end);
+    let rebuilt_fields = SymbolLayoutFieldMaterializer::materialize_positioned_fields(
+        module_root_layout_definition.get_layout_kind(),
+        Some(declared_size_in_bytes),
+        positioned_fields,
+    )?;
+
+    *module_root_layout_descriptor = StructLayoutDescriptor::new(
+        module_name.to_string(),
+        SymbolicStructDefinition::new_with_layout_kind(
+            module_root_layout_definition.get_symbol_namespace().to_string(),
+            module_root_layout_definition.get_layout_kind(),
+            rebuilt_fields,
+        )
+        .with_declared_size_in_bytes(Some(declared_size_in_bytes)),
+    );
+    project_symbol_catalog.set_struct_layout_descriptors(struct_layout_descriptors);
+
+    Ok(())
+}
+
+fn collect_data_type_size_by_id(data_type_registry: &dyn DataTypeRegistryStore) -> BTreeMap<String, u64> {
+    data_type_registry
+        .get_registered_data_type_refs()
+        .into_iter()
+        .filter_map(|data_type_ref| {
+            let unit_size_in_bytes = data_type_registry.get_unit_size_in_bytes(&data_type_ref);
+
+            (unit_size_in_bytes > 0).then(|| (data_type_ref.get_data_type_id().to_string(), unit_size_in_bytes))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PopulateMonoSymbolsAction;
+    use crate::test_process_memory::{TEST_ROOT_DOMAIN_GLOBAL_OFFSET, TEST_STATIC_DATA_ADDRESS, TestProcessMemory};
+    use squalr_engine_api::{
+        plugins::symbol_tree::symbol_tree_action::{
+            DataTypeRegistryStore, ProcessMemoryStore, ProjectSymbolStore, SymbolTreeAction, SymbolTreeActionContext, SymbolTreeActionSelection,
+            SymbolTreeActionServices, SymbolTreeWindowStore,
+        },
+        registries::symbols::symbol_registry::SymbolRegistry,
+        structures::{
+            data_types::data_type_ref::DataTypeRef,
+            projects::{project_symbol_catalog::ProjectSymbolCatalog, project_symbol_locator::ProjectSymbolLocator},
+        },
+    };
+    use std::sync::Mutex;
+
+    struct TestProjectSymbolStore {
+        project_symbol_catalog: Mutex<ProjectSymbolCatalog>,
+    }
+
+    impl ProjectSymbolStore for TestProjectSymbolStore {
+        fn read_catalog(&self) -> Result<ProjectSymbolCatalog, String> {
+            Ok(self
+                .project_symbol_catalog
+                .lock()
+                .map_err(|error| error.to_string())?
+                .clone())
+        }
+
+        fn write_catalog(
+            &self,
+            _reason: &str,
+            update_catalog: Box<dyn FnOnce(&mut ProjectSymbolCatalog) -> Result<(), String> + Send>,
+        ) -> Result<(), String> {
+            let mut project_symbol_catalog = self
+                .project_symbol_catalog
+                .lock()
+                .map_err(|error| error.to_string())?;
+
+            update_catalog(&mut project_symbol_catalog)
+        }
+    }
+
+    struct TestSymbolTreeWindowStore;
+
+    impl SymbolTreeWindowStore for TestSymbolTreeWindowStore {
+        fn request_refresh(&self) {}
+
+        fn focus_tree_node(
+            &self,
+            _tree_node_key: &str,
+        ) {
+        }
+    }
+
+    struct TestSymbolTreeActionServices {
+        project_symbol_store: TestProjectSymbolStore,
+        process_memory_store: TestProcessMemory,
+        data_type_registry: SymbolRegistry,
+        symbol_tree_window_store: TestSymbolTreeWindowStore,
+    }
+
+    impl DataTypeRegistryStore for TestSymbolTreeActionServices {
+        fn get_registered_data_type_refs(&self) -> Vec<DataTypeRef> {
+            self.data_type_registry.get_registered_data_type_refs()
+        }
+
+        fn get_unit_size_in_bytes(
+            &self,
+            data_type_ref: &DataTypeRef,
+        ) -> u64 {
+            self.data_type_registry.get_unit_size_in_bytes(data_type_ref)
+        }
+    }
+
+    impl SymbolTreeActionServices for TestSymbolTreeActionServices {
+        fn symbol_store(&self) -> &dyn ProjectSymbolStore {
+            &self.project_symbol_store
+        }
+
+        fn process_memory(&self) -> &dyn ProcessMemoryStore {
+            &self.process_memory_store
+        }
+
+        fn data_type_registry(&self) -> &dyn DataTypeRegistryStore {
+            self
+        }
+
+        fn symbol_tree_window(&self) -> &dyn SymbolTreeWindowStore {
+            &self.symbol_tree_window_store
+        }
+    }
+
+    #[test]
+    fn action_is_visible_only_for_mono_runtime_modules() {
+        let action = PopulateMonoSymbolsAction;
+        let mono_context = SymbolTreeActionContext::new(SymbolTreeActionSelection::ModuleRoot {
+            module_name: String::from("mono-2.0-bdwgc.dll"),
+        });
+        let linux_mono_context = SymbolTreeActionContext::new(SymbolTreeActionSelection::ModuleRoot {
+            module_name: String::from("libmonobdwgc-2.0.so"),
+        });
+        let game_context = SymbolTreeActionContext::new(SymbolTreeActionSelection::ModuleRoot {
+            module_name: String::from("game.exe"),
+        });
+
+        assert!(action.is_visible(&mono_context));
+        assert!(action.is_visible(&linux_mono_context));
+        assert!(!action.is_visible(&game_context));
+    }
+
+    #[test]
+    fn execute_populates_class_layouts_static_claims_and_root_domain_field() {
+        let services = TestSymbolTreeActionServices {
+            project_symbol_store: TestProjectSymbolStore {
+                project_symbol_catalog: Mutex::new(ProjectSymbolCatalog::default()),
+            },
+            process_memory_store: TestProcessMemory::new().with_unity_runtime_module("mono-2.0-bdwgc.dll"),
+            data_type_registry: SymbolRegistry::new(),
+            symbol_tree_window_store: TestSymbolTreeWindowStore,
+        };
+        let context = SymbolTreeActionContext::new(SymbolTreeActionSelection::ModuleRoot {
+            module_name: String::from("mono-2.0-bdwgc.dll"),
+        });
+
+        PopulateMonoSymbolsAction
+            .execute(&context, &services)
+            .expect("Expected Mono symbol population to succeed.");
+        // Running twice must not duplicate static claims or module fields.
+        PopulateMonoSymbolsAction
+            .execute(&context, &services)
+            .expect("Expected repeated Mono symbol population to succeed.");
+
+        let project_symbol_catalog = services
+            .project_symbol_store
+            .read_catalog()
+            .expect("Expected the test catalog to be readable.");
+        let symbol_module = project_symbol_catalog
+            .find_symbol_module("mono-2.0-bdwgc.dll")
+            .expect("Expected the runtime module to exist.");
+
+        assert!(project_symbol_catalog.contains_struct_layout_id("mono.Game.Player"));
+        assert!(project_symbol_catalog.contains_struct_layout_id("mono.Game.Entity"));
+        assert!(project_symbol_catalog.contains_struct_layout_id("mono.Game.Player.statics"));
+        assert_eq!(symbol_module.get_fields().len(), 1);
+        assert_eq!(symbol_module.get_fields()[0].get_display_name(), "mono_root_domain");
+        assert_eq!(symbol_module.get_fields()[0].get_offset(), TEST_ROOT_DOMAIN_GLOBAL_OFFSET);
+        assert_eq!(project_symbol_catalog.get_symbol_claims().len(), 1);
+        assert_eq!(
+            project_symbol_catalog.get_symbol_claims()[0].get_locator(),
+            &ProjectSymbolLocator::new_absolute_address(TEST_STATIC_DATA_ADDRESS)
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod mono_memory_reader;
+pub(crate) mod mono_root_domain_locator;
+pub(crate) mod mono_runtime_offsets;
+pub(crate) mod mono_runtime_walker;
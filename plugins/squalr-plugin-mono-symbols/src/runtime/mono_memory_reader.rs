@@ -0,0 +1,109 @@
+use squalr_engine_api::plugins::symbol_tree::symbol_tree_action::ProcessMemoryStore;
+
+const ABSOLUTE_ADDRESS_MODULE_NAME: &str = "";
+const STRING_READ_CHUNK_SIZE: u64 = 64;
+const STRING_READ_PAGE_SIZE: u64 = 0x1000;
+
+/// Reads runtime structures that live on the native heap. All reads go through `ProcessMemoryStore`, where an empty module
+/// name addresses absolute memory.
+pub(crate) struct MonoMemoryReader<'a> {
+    process_memory_store: &'a dyn ProcessMemoryStore,
+}
+
+impl<'a> MonoMemoryReader<'a> {
+    pub(crate) fn new(process_memory_store: &'a dyn ProcessMemoryStore) -> Self {
+        Self { process_memory_store }
+    }
+
+    pub(crate) fn read_bytes(
+        &self,
+        address: u64,
+        length: u64,
+    ) -> Result<Vec<u8>, String> {
+        let bytes = self
+            .process_memory_store
+            .read_module_bytes(ABSOLUTE_ADDRESS_MODULE_NAME, address, length)?;
+
+        if bytes.len() as u64 != length {
+            return Err(format!("Short read of {length} bytes at 0x{address:X}."));
+        }
+
+        Ok(bytes)
+    }
+
+    pub(crate) fn read_u8(
+        &self,
+        address: u64,
+    ) -> Result<u8, String> {
+        Ok(self.read_bytes(address, 1)?[0])
+    }
+
+    pub(crate) fn read_u16(
+        &self,
+        address: u64,
+    ) -> Result<u16, String> {
+        let bytes = self.read_bytes(address, 2)?;
+
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub(crate) fn read_u32(
+        &self,
+        address: u64,
+    ) -> Result<u32, String> {
+        let bytes = self.read_bytes(address, 4)?;
+
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub(crate) fn read_i32(
+        &self,
+        address: u64,
+    ) -> Result<i32, String> {
+        Ok(self.read_u32(address)? as i32)
+    }
+
+    pub(crate) fn read_pointer(
+        &self,
+        address: u64,
+    ) -> Result<u64, String> {
+        let bytes = self.read_bytes(address, 8)?;
+        let mut pointer_bytes = [0_u8; 8];
+
+        pointer_bytes.copy_from_slice(&bytes);
+
+        Ok(u64::from_le_bytes(pointer_bytes))
+    }
+
+    /// Reads a null-terminated UTF-8 string. Reads never cross a page boundary, so a string that ends right before
+    /// unmapped memory still resolves.
+    pub(crate) fn read_c_string(
+        &self,
+        address: u64,
+        maximum_length: u64,
+    ) -> Result<String, String> {
+        let mut string_bytes = Vec::new();
+        let mut read_address = address;
+
+        while (string_bytes.len() as u64) < maximum_length {
+            let bytes_to_page_end = STRING_READ_PAGE_SIZE - (read_address % STRING_READ_PAGE_SIZE);
+            let chunk_size = STRING_READ_CHUNK_SIZE
+                .min(bytes_to_page_end)
+                .min(maximum_length - string_bytes.len() as u64);
+            let chunk_bytes = self.read_bytes(read_address, chunk_size)?;
+
+            if let Some(terminator_index) = chunk_bytes.iter().position(|byte| *byte == 0) {
+                string_bytes.extend_from_slice(&chunk_bytes[..terminator_index]);
+
+                return Ok(String::from_utf8_lossy(&string_bytes).into_owned());
+            }
+
+            string_bytes.extend_from_slice(&chunk_bytes);
+            read_address = read_address
+                .checked_add(chunk_size)
+                .ok_or_else(|| format!("String at 0x{address:X} runs past the end of the address space."))?;
+        }
+
+        Err(format!("String at 0x{address:X} is not terminated within {maximum_length} bytes."))
+    }
+}
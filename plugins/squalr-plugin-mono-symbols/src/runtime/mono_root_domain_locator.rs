@@ -0,0 +1,345 @@
+use squalr_engine_api::plugins::symbol_tree::symbol_tree_action::ProcessMemoryStore;
+
+const ROOT_DOMAIN_EXPORT_NAME: &str = "mono_get_root_domain";
+const PE_HEADER_OFFSET_OFFSET: u64 = 0x3C;
+const PE_OPTIONAL_HEADER_OFFSET: u64 = 24;
+const PE64_OPTIONAL_HEADER_MAGIC: u16 = 0x20B;
+const PE64_EXPORT_DIRECTORY_OFFSET: u64 = 112;
+const PE_EXPORT_DIRECTORY_SIZE: u64 = 40;
+const ELF_CLASS_64: u8 = 2;
+const ELF64_PROGRAM_HEADER_SIZE: u64 = 56;
+const ELF64_DYNAMIC_ENTRY_SIZE: u64 = 16;
+const ELF64_SYMBOL_SIZE: u64 = 24;
+const ELF_PT_LOAD: u32 = 1;
+const ELF_PT_DYNAMIC: u32 = 2;
+const ELF_DT_NULL: u64 = 0;
+const ELF_DT_STRTAB: u64 = 5;
+const ELF_DT_SYMTAB: u64 = 6;
+const ELF_DT_STRSZ: u64 = 10;
+const ELF_PAGE_MASK: u64 = !0xFFF;
+const MAX_EXPORT_COUNT: u64 = 0x10000;
+const MAX_ELF_SYMBOL_COUNT: u64 = 0x100000;
+const MAX_ELF_STRING_TABLE_SIZE: u64 = 0x1000000;
+const ROOT_DOMAIN_GETTER_READ_SIZE: u64 = 16;
+const ENDBR64: [u8; 4] = [0xF3, 0x0F, 0x1E, 0xFA];
+const MOV_RAX_RIP_RELATIVE: [u8; 3] = [0x48, 0x8B, 0x05];
+
+/// Finds the module offset of the global that holds the root `MonoDomain*`. The runtime exports `mono_get_root_domain`,
+/// which compiles to a single RIP-relative load of that global, so decoding its first instruction gives the global's
+/// location without relying on signatures.
+pub(crate) fn locate_root_domain_global(
+    process_memory_store: &dyn ProcessMemoryStore,
+    module_name: &str,
+) -> Result<u64, String> {
+    let header_bytes = process_memory_store.read_module_bytes(module_name, 0, 4)?;
+    let getter_offset = if header_bytes.starts_with(b"MZ") {
+        find_pe_export_offset(process_memory_store, module_name, ROOT_DOMAIN_EXPORT_NAME)?
+    } else if header_bytes.starts_with(b"\x7FELF") {
+        find_elf_dynamic_symbol_offset(process_memory_store, module_name, ROOT_DOMAIN_EXPORT_NAME)?
+    } else {
+        return Err(format!("Module `{module_name}` is not a PE or ELF image."));
+    };
+    let getter_bytes = process_memory_store.read_module_bytes(module_name, getter_offset, ROOT_DOMAIN_GETTER_READ_SIZE)?;
+
+    decode_rip_relative_load(&getter_bytes, getter_offset)
+        .ok_or_else(|| format!("Unrecognized `{ROOT_DOMAIN_EXPORT_NAME}` code in `{module_name}`. Only x86-64 runtimes are supported."))
+}
+
+fn decode_rip_relative_load(
+    getter_bytes: &[u8],
+    getter_offset: u64,
+) -> Option<u64> {
+    let instruction_start = if getter_bytes.starts_with(&ENDBR64) { ENDBR64.len() } else { 0 };
+    let instruction_bytes = getter_bytes.get(instruction_start..instruction_start + 7)?;
+
+    if !instruction_bytes.starts_with(&MOV_RAX_RIP_RELATIVE) {
+        return None;
+    }
+
+    let displacement = i32::from_le_bytes(instruction_bytes[3..7].try_into().ok()?) as i64;
+    let instruction_end = getter_offset.checked_add(instruction_start as u64 + 7)?;
+
+    instruction_end.checked_add_signed(displacement)
+}
+
+fn find_pe_export_offset(
+    process_memory_store: &dyn ProcessMemoryStore,
+    module_name: &str,
+    export_name: &str,
+) -> Result<u64, String> {
+    let pe_header_offset = read_module_u32(process_memory_store, module_name, PE_HEADER_OFFSET_OFFSET)? as u64;
+    let optional_header_offset = pe_header_offset + PE_OPTIONAL_HEADER_OFFSET;
+    let optional_header_magic = read_module_u16(process_memory_store, module_name, optional_header_offset)?;
+
+    if optional_header_magic != PE64_OPTIONAL_HEADER_MAGIC {
+        return Err(format!("Module `{module_name}` is not a 64-bit PE image."));
+    }
+
+    let export_directory_entry_offset = optional_header_offset + PE64_EXPORT_DIRECTORY_OFFSET;
+    let export_directory_rva = read_module_u32(process_memory_store, module_name, export_directory_entry_offset)? as u64;
+    let export_directory_size = read_module_u32(process_memory_store, module_name, export_directory_entry_offset + 4)? as u64;
+
+    if export_directory_rva == 0 || export_directory_size < PE_EXPORT_DIRECTORY_SIZE {
+        return Err(format!("Module `{module_name}` has no export directory."));
+    }
+
+    // Export names and tables normally live inside the export directory, so one read usually covers the whole lookup.
+    let export_bytes = process_memory_store.read_module_bytes(module_name, export_directory_rva, export_directory_size)?;
+    let read_export_range = |rva: u64, length: u64| -> Result<Vec<u8>, String> {
+        match rva
+            .checked_sub(export_directory_rva)
+            .and_then(|start| Some(start as usize..start.checked_add(length)? as usize))
+            .and_then(|range| export_bytes.get(range))
+        {
+            Some(bytes) => Ok(bytes.to_vec()),
+            None => process_memory_store.read_module_bytes(module_name, rva, length),
+        }
+    };
+    let name_count = read_le_u32(&export_bytes, 0x18)? as u64;
+    let functions_rva = read_le_u32(&export_bytes, 0x1C)? as u64;
+    let names_rva = read_le_u32(&export_bytes, 0x20)? as u64;
+    let name_ordinals_rva = read_le_u32(&export_bytes, 0x24)? as u64;
+
+    if name_count > MAX_EXPORT_COUNT {
+        return Err(format!("Module `{module_name}` declares an implausible export count of {name_count}."));
+    }
+
+    let name_rvas = read_export_range(names_rva, name_count * 4)?;
+    let name_ordinals = read_export_range(name_ordinals_rva, name_count * 2)?;
+
+    for name_index in 0..name_count as usize {
+        let name_rva = read_le_u32(&name_rvas, name_index * 4)? as u64;
+        let name_bytes = read_export_range(name_rva, export_name.len() as u64 + 1)?;
+
+        if name_bytes[..export_name.len()] != *export_name.as_bytes() || name_bytes[export_name.len()] != 0 {
+            continue;
+        }
+
+        let ordinal = read_le_u16(&name_ordinals, name_index * 2)? as u64;
+        let function_rva = read_le_u32(&read_export_range(functions_rva + ordinal * 4, 4)?, 0)? as u64;
+
+        return Ok(function_rva);
+    }
+
+    Err(format!("Module `{module_name}` does not export `{export_name}`."))
+}
+
+/// Locations read out of the in-memory dynamic section. Loaders either keep these as link-time addresses or relocate
+/// them in place, so values outside the image span are treated as absolute addresses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ElfTableLocation {
+    ModuleOffset(u64),
+    Absolute(u64),
+}
+
+impl ElfTableLocation {
+    fn read(
+        &self,
+        process_memory_store: &dyn ProcessMemoryStore,
+        module_name: &str,
+        length: u64,
+    ) -> Result<Vec<u8>, String> {
+        match self {
+            Self::ModuleOffset(offset) => process_memory_store.read_module_bytes(module_name, *offset, length),
+            Self::Absolute(address) => process_memory_store.read_module_bytes("", *address, length),
+        }
+    }
+
+    fn distance_to(
+        &self,
+        other: &Self,
+    ) -> Option<u64> {
+        match (self, other) {
+            (Self::ModuleOffset(start), Self::ModuleOffset(end)) | (Self::Absolute(start), Self::Absolute(end)) => end.checked_sub(*start),
+            _ => None,
+        }
+    }
+}
+
+fn find_elf_dynamic_symbol_offset(
+    process_memory_store: &dyn ProcessMemoryStore,
+    module_name: &str,
+    symbol_name: &str,
+) -> Result<u64, String> {
+    let elf_header = process_memory_store.read_module_bytes(module_name, 0, 64)?;
+
+    if elf_header.get(4) != Some(&ELF_CLASS_64) {
+        return Err(format!("Module `{module_name}` is not a 64-bit ELF image."));
+    }
+
+    let program_header_offset = read_le_u64(&elf_header, 0x20)?;
+    let program_header_count = read_le_u16(&elf_header, 0x38)? as u64;
+    let program_headers = process_memory_store.read_module_bytes(module_name, program_header_offset, program_header_count * ELF64_PROGRAM_HEADER_SIZE)?;
+    let mut minimum_load_address = u64::MAX;
+    let mut maximum_load_address = 0_u64;
+    let mut dynamic_segment = None;
+
+    for program_header_index in 0..program_header_count as usize {
+        let program_header_start = program_header_index * ELF64_PROGRAM_HEADER_SIZE as usize;
+        let segment_type = read_le_u32(&program_headers, program_header_start)?;
+        let virtual_address = read_le_u64(&program_headers, program_header_start + 0x10)?;
+        let memory_size = read_le_u64(&program_headers, program_header_start + 0x28)?;
+
+        match segment_type {
+            ELF_PT_LOAD => {
+                minimum_load_address = minimum_load_address.min(virtual_address & ELF_PAGE_MASK);
+                maximum_load_address = maximum_load_address.max(virtual_address.saturating_add(memory_size));
+            }
+            ELF_PT_DYNAMIC => dynamic_segment = Some((virtual_address, memory_size)),
+            _ => {}
+        }
+    }
+
+    let Some((dynamic_address, dynamic_size)) = dynamic_segment else {
+        return Err(format!("Module `{module_name}` has no dynamic segment."));
+    };
+
+    if minimum_load_address > dynamic_address {
+        return Err(format!("Module `{module_name}` has a dynamic segment outside of its loadable segments."));
+    }
+
+    let image_span = maximum_load_address.saturating_sub(minimum_load_address);
+    let to_table_location = |address: u64| match address.checked_sub(minimum_load_address) {
+        Some(offset) if offset < image_span => ElfTableLocation::ModuleOffset(offset),
+        _ => ElfTableLocation::Absolute(address),
+    };
+    let dynamic_bytes = process_memory_store.read_module_bytes(module_name, dynamic_address - minimum_load_address, dynamic_size)?;
+    let mut symbol_table = None;
+    let mut string_table = None;
+    let mut string_table_size = None;
+
+    for dynamic_entry_start in (0..dynamic_bytes.len()).step_by(ELF64_DYNAMIC_ENTRY_SIZE as usize) {
+        let tag = read_le_u64(&dynamic_bytes, dynamic_entry_start)?;
+        let value = read_le_u64(&dynamic_bytes, dynamic_entry_start + 8)?;
+
+        match tag {
+            ELF_DT_NULL => break,
+            ELF_DT_SYMTAB => symbol_table = Some(to_table_location(value)),
+            ELF_DT_STRTAB => string_table = Some(to_table_location(value)),
+            ELF_DT_STRSZ => string_table_size = Some(value),
+            _ => {}
+        }
+    }
+
+    let (Some(symbol_table), Some(string_table), Some(string_table_size)) = (symbol_table, string_table, string_table_size) else {
+        return Err(format!("Module `{module_name}` has no dynamic symbol table."));
+    };
+
+    if string_table_size > MAX_ELF_STRING_TABLE_SIZE {
+        return Err(format!(
+            "Module `{module_name}` declares an implausible string table size of {string_table_size}."
+        ));
+    }
+
+    // The dynamic section does not record the symbol count, but linkers place the string table right after the symbols.
+    let symbol_count = symbol_table
+        .distance_to(&string_table)
+        .map(|symbol_table_size| symbol_table_size / ELF64_SYMBOL_SIZE)
+        .filter(|symbol_count| *symbol_count > 0 && *symbol_count <= MAX_ELF_SYMBOL_COUNT)
+        .ok_or_else(|| format!("Could not size the dynamic symbol table of `{module_name}`."))?;
+    let symbol_bytes = symbol_table.read(process_memory_store, module_name, symbol_count * ELF64_SYMBOL_SIZE)?;
+    let string_bytes = string_table.read(process_memory_store, module_name, string_table_size)?;
+
+    for symbol_start in (0..symbol_bytes.len()).step_by(ELF64_SYMBOL_SIZE as usize) {
+        let name_offset = read_le_u32(&symbol_bytes, symbol_start)? as usize;
+        let section_index = read_le_u16(&symbol_bytes, symbol_start + 6)?;
+        let symbol_value = read_le_u64(&symbol_bytes, symbol_start + 8)?;
+
+        if section_index == 0 || symbol_value == 0 {
+            continue;
+        }
+
+        let Some(name_bytes) = string_bytes.get(name_offset..) else {
+            continue;
+        };
+        let name_end = name_bytes
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(name_bytes.len());
+
+        if &name_bytes[..name_end] == symbol_name.as_bytes() {
+            return symbol_value
+                .checked_sub(minimum_load_address)
+                .ok_or_else(|| format!("Symbol `{symbol_name}` lies before the image base of `{module_name}`."));
+        }
+    }
+
+    Err(format!("Module `{module_name}` does not export `{symbol_name}`."))
+}
+
+fn read_module_u16(
+    process_memory_store: &dyn ProcessMemoryStore,
+    module_name: &str,
+    offset: u64,
+) -> Result<u16, String> {
+    read_le_u16(&process_memory_store.read_module_bytes(module_name, offset, 2)?, 0)
+}
+
+fn read_module_u32(
+    process_memory_store: &dyn ProcessMemoryStore,
+    module_name: &str,
+    offset: u64,
+) -> Result<u32, String> {
+    read_le_u32(&process_memory_store.read_module_bytes(module_name, offset, 4)?, 0)
+}
+
+fn read_le_u16(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<u16, String> {
+    bytes
+        .get(offset..offset + 2)
+        .map(|value_bytes| u16::from_le_bytes([value_bytes[0], value_bytes[1]]))
+        .ok_or_else(|| format!("Unexpected end of image data at offset 0x{offset:X}."))
+}
+
+fn read_le_u32(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<u32, String> {
+    bytes
+        .get(offset..offset + 4)
+        .map(|value_bytes| u32::from_le_bytes([value_bytes[0], value_bytes[1], value_bytes[2], value_bytes[3]]))
+        .ok_or_else(|| format!("Unexpected end of image data at offset 0x{offset:X}."))
+}
+
+fn read_le_u64(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<u64, String> {
+    bytes
+        .get(offset..offset + 8)
+        .and_then(|value_bytes| value_bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or_else(|| format!("Unexpected end of image data at offset 0x{offset:X}."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_rip_relative_load, locate_root_domain_global};
+    use crate::test_process_memory::TestProcessMemory;
+
+    #[test]
+    fn decode_rip_relative_load_skips_endbr64() {
+        let getter_bytes = [
+            0xF3, 0x0F, 0x1E, 0xFA, 0x48, 0x8B, 0x05, 0x10, 0x00, 0x00, 0x00, 0xC3,
+        ];
+
+        assert_eq!(decode_rip_relative_load(&getter_bytes, 0x2000), Some(0x2000 + 11 + 0x10));
+        assert_eq!(decode_rip_relative_load(&[0xA1, 0, 0, 0, 0, 0xC3, 0, 0], 0x2000), None);
+    }
+
+    #[test]
+    fn locate_root_domain_global_reads_pe_exports() {
+        let test_process_memory = TestProcessMemory::new().with_module("mono-2.0-bdwgc.dll", TestProcessMemory::build_pe64_runtime_module(0x3000));
+
+        assert_eq!(locate_root_domain_global(&test_process_memory, "mono-2.0-bdwgc.dll"), Ok(0x3000));
+    }
+
+    #[test]
+    fn locate_root_domain_global_reads_elf_dynamic_symbols() {
+        let test_process_memory = TestProcessMemory::new().with_module("libmonobdwgc-2.0.so", TestProcessMemory::build_elf64_runtime_module(0x3000));
+
+        assert_eq!(locate_root_domain_global(&test_process_memory, "libmonobdwgc-2.0.so"), Ok(0x3000));
+    }
+}
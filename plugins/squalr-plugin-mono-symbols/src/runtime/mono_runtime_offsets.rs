@@ -0,0 +1,65 @@
+/// Field offsets into the Mono runtime's private structures. These are not exported by the runtime and vary between
+/// Mono releases, so each supported runtime build gets a preset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct MonoRuntimeOffsets {
+    pub(crate) pointer_size: u64,
+    pub(crate) domain_assemblies: u64,
+    pub(crate) assembly_name: u64,
+    pub(crate) assembly_image: u64,
+    pub(crate) image_class_cache: u64,
+    pub(crate) hash_table_size: u64,
+    pub(crate) hash_table_table: u64,
+    pub(crate) class_class_kind: u64,
+    pub(crate) class_instance_size: u64,
+    pub(crate) class_parent: u64,
+    pub(crate) class_name: u64,
+    pub(crate) class_namespace: u64,
+    pub(crate) class_vtable_size: u64,
+    pub(crate) class_fields: u64,
+    pub(crate) class_runtime_info: u64,
+    pub(crate) class_field_count: u64,
+    pub(crate) class_next_class_cache: u64,
+    pub(crate) class_field_size: u64,
+    pub(crate) class_field_type: u64,
+    pub(crate) class_field_name: u64,
+    pub(crate) class_field_offset: u64,
+    pub(crate) type_data: u64,
+    pub(crate) type_attributes: u64,
+    pub(crate) type_code: u64,
+    pub(crate) runtime_info_domain_vtables: u64,
+    pub(crate) vtable_methods: u64,
+    pub(crate) object_header_size: u64,
+}
+
+impl MonoRuntimeOffsets {
+    /// The `mono-2.0-bdwgc` / `libmonobdwgc-2.0` runtime shipped with Unity 2018 and later, for 64-bit players.
+    pub(crate) const UNITY_BDWGC_X64: Self = Self {
+        pointer_size: 8,
+        domain_assemblies: 0xA0,
+        assembly_name: 0x10,
+        assembly_image: 0x60,
+        image_class_cache: 0x4C0,
+        hash_table_size: 0x18,
+        hash_table_table: 0x20,
+        class_class_kind: 0x1B,
+        class_instance_size: 0x1C,
+        class_parent: 0x30,
+        class_name: 0x48,
+        class_namespace: 0x50,
+        class_vtable_size: 0x5C,
+        class_fields: 0x98,
+        class_runtime_info: 0xD0,
+        class_field_count: 0x100,
+        class_next_class_cache: 0x108,
+        class_field_size: 0x20,
+        class_field_type: 0x00,
+        class_field_name: 0x08,
+        class_field_offset: 0x18,
+        type_data: 0x00,
+        type_attributes: 0x08,
+        type_code: 0x0A,
+        runtime_info_domain_vtables: 0x08,
+        vtable_methods: 0x48,
+        object_header_size: 0x10,
+    };
+}
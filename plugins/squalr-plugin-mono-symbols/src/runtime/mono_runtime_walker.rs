@@ -0,0 +1,356 @@
+use crate::runtime::{mono_memory_reader::MonoMemoryReader, mono_runtime_offsets::MonoRuntimeOffsets};
+use std::collections::HashSet;
+
+const MAX_ASSEMBLY_COUNT: usize = 4096;
+const MAX_CLASS_CACHE_BUCKET_COUNT: u64 = 0x100000;
+const MAX_CLASSES_PER_IMAGE: usize = 0x40000;
+const MAX_FIELDS_PER_CLASS: u32 = 0x4000;
+const MAX_NAME_LENGTH: u64 = 1024;
+const MONO_CLASS_KIND_DEF: u8 = 1;
+const MONO_CLASS_KIND_GENERIC_TYPE_DEFINITION: u8 = 2;
+const FIELD_ATTRIBUTE_STATIC: u16 = 0x0010;
+const FIELD_ATTRIBUTE_LITERAL: u16 = 0x0040;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct MonoFieldInfo {
+    pub(crate) name: String,
+    pub(crate) offset: u64,
+    pub(crate) type_code: u8,
+    pub(crate) is_static: bool,
+    /// The class named by `CLASS` and `VALUETYPE` field types.
+    pub(crate) referenced_class_address: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct MonoClassInfo {
+    pub(crate) address: u64,
+    pub(crate) assembly_name: String,
+    pub(crate) namespace: String,
+    pub(crate) name: String,
+    pub(crate) instance_size: u64,
+    pub(crate) parent_address: Option<u64>,
+    pub(crate) fields: Vec<MonoFieldInfo>,
+    /// Only classes whose static constructor has run in the root domain have static storage.
+    pub(crate) static_data_address: Option<u64>,
+}
+
+impl MonoClassInfo {
+    pub(crate) fn get_qualified_name(&self) -> String {
+        if self.namespace.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", self.namespace, self.name)
+        }
+    }
+}
+
+/// Walks the root domain's loaded assemblies and every class in their images' class caches.
+pub(crate) struct MonoRuntimeWalker<'a> {
+    memory_reader: &'a MonoMemoryReader<'a>,
+    offsets: MonoRuntimeOffsets,
+}
+
+impl<'a> MonoRuntimeWalker<'a> {
+    pub(crate) fn new(
+        memory_reader: &'a MonoMemoryReader<'a>,
+        offsets: MonoRuntimeOffsets,
+    ) -> Self {
+        Self { memory_reader, offsets }
+    }
+
+    /// Assemblies and classes that cannot be read are skipped, since the runtime can be mid-update while it is walked.
+    pub(crate) fn collect_classes(
+        &self,
+        root_domain_address: u64,
+    ) -> Result<Vec<MonoClassInfo>, String> {
+        let mut classes = Vec::new();
+
+        for assembly_address in self.collect_domain_assemblies(root_domain_address)? {
+            let Ok(image_address) = self
+                .memory_reader
+                .read_pointer(assembly_address + self.offsets.assembly_image)
+            else {
+                continue;
+            };
+            let assembly_name = self
+                .memory_reader
+                .read_pointer(assembly_address + self.offsets.assembly_name)
+                .and_then(|name_address| self.memory_reader.read_c_string(name_address, MAX_NAME_LENGTH))
+                .unwrap_or_default();
+
+            if image_address == 0 {
+                continue;
+            }
+
+            for class_address in self.collect_image_classes(image_address) {
+                if let Ok(class_info) = self.read_class(class_address, &assembly_name) {
+                    classes.push(class_info);
+                }
+            }
+        }
+
+        Ok(classes)
+    }
+
+    fn collect_domain_assemblies(
+        &self,
+        root_domain_address: u64,
+    ) -> Result<Vec<u64>, String> {
+        let mut assembly_addresses = Vec::new();
+        let mut visited_list_nodes = HashSet::new();
+        let mut list_node_address = self
+            .memory_reader
+            .read_pointer(root_domain_address + self.offsets.domain_assemblies)?;
+
+        // Assemblies are held in a `GSList` of `{ data, next }` nodes.
+        while list_node_address != 0 && assembly_addresses.len() < MAX_ASSEMBLY_COUNT && visited_list_nodes.insert(list_node_address) {
+            let assembly_address = self.memory_reader.read_pointer(list_node_address)?;
+
+            if assembly_address != 0 {
+                assembly_addresses.push(assembly_address);
+            }
+
+            list_node_address = self
+                .memory_reader
+                .read_pointer(list_node_address + self.offsets.pointer_size)?;
+        }
+
+        Ok(assembly_addresses)
+    }
+
+    fn collect_image_classes(
+        &self,
+        image_address: u64,
+    ) -> Vec<u64> {
+        let class_cache_address = image_address + self.offsets.image_class_cache;
+        let Ok(bucket_count) = self
+            .memory_reader
+            .read_i32(class_cache_address + self.offsets.hash_table_size)
+        else {
+            return Vec::new();
+        };
+        let Ok(table_address) = self
+            .memory_reader
+            .read_pointer(class_cache_address + self.offsets.hash_table_table)
+        else {
+            return Vec::new();
+        };
+
+        if bucket_count <= 0 || bucket_count as u64 > MAX_CLASS_CACHE_BUCKET_COUNT || table_address == 0 {
+            return Vec::new();
+        }
+
+        let Ok(bucket_bytes) = self
+            .memory_reader
+            .read_bytes(table_address, bucket_count as u64 * self.offsets.pointer_size)
+        else {
+            return Vec::new();
+        };
+        let mut class_addresses = Vec::new();
+        let mut visited_class_addresses = HashSet::new();
+
+        for bucket_chunk in bucket_bytes.chunks_exact(self.offsets.pointer_size as usize) {
+            let mut class_address = u64::from_le_bytes(bucket_chunk.try_into().unwrap_or_default());
+
+            while class_address != 0 && class_addresses.len() < MAX_CLASSES_PER_IMAGE && visited_class_addresses.insert(class_address) {
+                class_addresses.push(class_address);
+                class_address = self
+                    .memory_reader
+                    .read_pointer(class_address + self.offsets.class_next_class_cache)
+                    .unwrap_or(0);
+            }
+        }
+
+        class_addresses
+    }
+
+    fn read_class(
+        &self,
+        class_address: u64,
+        assembly_name: &str,
+    ) -> Result<MonoClassInfo, String> {
+        let class_kind = self
+            .memory_reader
+            .read_u8(class_address + self.offsets.class_class_kind)?;
+
+        // Only definitions carry `field_count`; generic instances and arrays share their definition's fields.
+        if class_kind != MONO_CLASS_KIND_DEF && class_kind != MONO_CLASS_KIND_GENERIC_TYPE_DEFINITION {
+            return Err(format!("Class at 0x{class_address:X} is not a class definition."));
+        }
+
+        let name = self.read_string_pointer(class_address + self.offsets.class_name)?;
+        let namespace = self.read_string_pointer(class_address + self.offsets.class_namespace)?;
+        let instance_size = self
+            .memory_reader
+            .read_i32(class_address + self.offsets.class_instance_size)?
+            .max(0) as u64;
+        let parent_address = self
+            .memory_reader
+            .read_pointer(class_address + self.offsets.class_parent)?;
+        let fields = self.read_class_fields(class_address)?;
+        let static_data_address = if fields.iter().any(|field| field.is_static) {
+            self.read_static_data_address(class_address).ok().flatten()
+        } else {
+            None
+        };
+
+        Ok(MonoClassInfo {
+            address: class_address,
+            assembly_name: assembly_name.to_string(),
+            namespace,
+            name,
+            instance_size,
+            parent_address: (parent_address != 0).then_some(parent_address),
+            fields,
+            static_data_address,
+        })
+    }
+
+    fn read_class_fields(
+        &self,
+        class_address: u64,
+    ) -> Result<Vec<MonoFieldInfo>, String> {
+        let field_count = self
+            .memory_reader
+            .read_u32(class_address + self.offsets.class_field_count)?;
+        let fields_address = self
+            .memory_reader
+            .read_pointer(class_address + self.offsets.class_fields)?;
+
+        if field_count == 0 || fields_address == 0 {
+            return Ok(Vec::new());
+        }
+
+        if field_count > MAX_FIELDS_PER_CLASS {
+            return Err(format!("Class at 0x{class_address:X} declares an implausible field count of {field_count}."));
+        }
+
+        let mut fields = Vec::new();
+
+        for field_index in 0..field_count as u64 {
+            let field_address = fields_address + field_index * self.offsets.class_field_size;
+            let type_address = self
+                .memory_reader
+                .read_pointer(field_address + self.offsets.class_field_type)?;
+            let field_offset = self
+                .memory_reader
+                .read_i32(field_address + self.offsets.class_field_offset)?;
+
+            if type_address == 0 {
+                continue;
+            }
+
+            let type_attributes = self
+                .memory_reader
+                .read_u16(type_address + self.offsets.type_attributes)?;
+            let type_code = self
+                .memory_reader
+                .read_u8(type_address + self.offsets.type_code)?;
+
+            // Constants have no storage, and thread or context statics report a negative offset.
+            if type_attributes & FIELD_ATTRIBUTE_LITERAL != 0 || field_offset < 0 {
+                continue;
+            }
+
+            let type_data = self
+                .memory_reader
+                .read_pointer(type_address + self.offsets.type_data)?;
+
+            fields.push(MonoFieldInfo {
+                name: self.read_string_pointer(field_address + self.offsets.class_field_name)?,
+                offset: field_offset as u64,
+                type_code,
+                is_static: type_attributes & FIELD_ATTRIBUTE_STATIC != 0,
+                referenced_class_address: (type_data != 0).then_some(type_data),
+            });
+        }
+
+        Ok(fields)
+    }
+
+    /// Static storage hangs off the class's vtable for the root domain, in the slot after the last method.
+    fn read_static_data_address(
+        &self,
+        class_address: u64,
+    ) -> Result<Option<u64>, String> {
+        let runtime_info_address = self
+            .memory_reader
+            .read_pointer(class_address + self.offsets.class_runtime_info)?;
+
+        if runtime_info_address == 0 {
+            return Ok(None);
+        }
+
+        // The root domain always has id zero, so its vtable is the first entry in `domain_vtables`.
+        let vtable_address = self
+            .memory_reader
+            .read_pointer(runtime_info_address + self.offsets.runtime_info_domain_vtables)?;
+
+        if vtable_address == 0 {
+            return Ok(None);
+        }
+
+        let vtable_size = self
+            .memory_reader
+            .read_i32(class_address + self.offsets.class_vtable_size)?
+            .max(0) as u64;
+        let static_data_address = self
+            .memory_reader
+            .read_pointer(vtable_address + self.offsets.vtable_methods + vtable_size * self.offsets.pointer_size)?;
+
+        Ok((static_data_address != 0).then_some(static_data_address))
+    }
+
+    fn read_string_pointer(
+        &self,
+        pointer_address: u64,
+    ) -> Result<String, String> {
+        let string_address = self.memory_reader.read_pointer(pointer_address)?;
+
+        if string_address == 0 {
+            return Ok(String::new());
+        }
+
+        self.memory_reader
+            .read_c_string(string_address, MAX_NAME_LENGTH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MonoRuntimeWalker;
+    use crate::runtime::{mono_memory_reader::MonoMemoryReader, mono_runtime_offsets::MonoRuntimeOffsets};
+    use crate::test_process_memory::{TEST_PLAYER_CLASS_ADDRESS, TEST_ROOT_DOMAIN_ADDRESS, TEST_STATIC_DATA_ADDRESS, TestProcessMemory};
+
+    #[test]
+    fn collect_classes_walks_assemblies_classes_and_fields() {
+        let test_process_memory = TestProcessMemory::new().with_unity_heap();
+        let memory_reader = MonoMemoryReader::new(&test_process_memory);
+        let walker = MonoRuntimeWalker::new(&memory_reader, MonoRuntimeOffsets::UNITY_BDWGC_X64);
+        let classes = walker
+            .collect_classes(TEST_ROOT_DOMAIN_ADDRESS)
+            .expect("Expected the test heap to walk.");
+        let player_class = classes
+            .iter()
+            .find(|class_info| class_info.address == TEST_PLAYER_CLASS_ADDRESS)
+            .expect("Expected the player class to be found.");
+
+        assert_eq!(classes.len(), 2);
+        assert_eq!(player_class.assembly_name, "Assembly-CSharp");
+        assert_eq!(player_class.get_qualified_name(), "Game.Player");
+        assert_eq!(player_class.instance_size, 0x28);
+        assert_eq!(player_class.static_data_address, Some(TEST_STATIC_DATA_ADDRESS));
+        assert_eq!(
+            player_class
+                .fields
+                .iter()
+                .map(|field| (field.name.as_str(), field.offset, field.is_static))
+                .collect::<Vec<_>>(),
+            vec![
+                ("health", 0x18, false),
+                ("target", 0x20, false),
+                ("instanceCount", 0x0, true)
+            ]
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use squalr_engine_api::plugins::symbol_tree::symbol_tree_action::ProcessMemoryStore;
+use std::collections::{BTreeMap, HashMap};
+
+pub(crate) const TEST_ROOT_DOMAIN_GLOBAL_OFFSET: u64 = 0x3000;
+pub(crate) const TEST_ROOT_DOMAIN_ADDRESS: u64 = 0x10000;
+pub(crate) const TEST_PLAYER_CLASS_ADDRESS: u64 = 0x13000;
+pub(crate) const TEST_STATIC_DATA_ADDRESS: u64 = 0x19000;
+
+const TEST_PAGE_SIZE: u64 = 0x1000;
+const TEST_MODULE_SIZE: usize = 0x4000;
+const ROOT_DOMAIN_GETTER_OFFSET: u64 = 0x2000;
+const ASSEMBLY_ADDRESS: u64 = 0x10300;
+const ASSEMBLY_LIST_NODE_ADDRESS: u64 = 0x10200;
+const IMAGE_ADDRESS: u64 = 0x11000;
+const CLASS_CACHE_TABLE_ADDRESS: u64 = 0x12000;
+const ENTITY_CLASS_ADDRESS: u64 = 0x14000;
+const PLAYER_FIELDS_ADDRESS: u64 = 0x15000;
+const ENTITY_FIELDS_ADDRESS: u64 = 0x15100;
+const PLAYER_RUNTIME_INFO_ADDRESS: u64 = 0x16000;
+const PLAYER_VTABLE_ADDRESS: u64 = 0x16100;
+const INT32_TYPE_ADDRESS: u64 = 0x17000;
+const ENTITY_REFERENCE_TYPE_ADDRESS: u64 = 0x17020;
+const STATIC_INT32_TYPE_ADDRESS: u64 = 0x17040;
+const LITERAL_INT32_TYPE_ADDRESS: u64 = 0x17060;
+const STRINGS_ADDRESS: u64 = 0x18000;
+
+/// A sparse process image for exercising the runtime walker without a live Unity process. Module reads address module
+/// images, and reads with an empty module name address zero-filled heap pages.
+#[derive(Default)]
+pub(crate) struct TestProcessMemory {
+    modules: HashMap<String, Vec<u8>>,
+    heap_pages: BTreeMap<u64, Vec<u8>>,
+    next_string_address: u64,
+}
+
+impl TestProcessMemory {
+    pub(crate) fn new() -> Self {
+        Self {
+            next_string_address: STRINGS_ADDRESS,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn with_module(
+        mut self,
+        module_name: &str,
+        module_bytes: Vec<u8>,
+    ) -> Self {
+        self.modules.insert(module_name.to_string(), module_bytes);
+        self
+    }
+
+    /// A PE64 runtime whose root domain global points at the heap built by `with_unity_heap`.
+    pub(crate) fn with_unity_runtime_module(
+        self,
+        module_name: &str,
+    ) -> Self {
+        let mut module_bytes = Self::build_pe64_runtime_module(TEST_ROOT_DOMAIN_GLOBAL_OFFSET);
+
+        write_le(&mut module_bytes, TEST_ROOT_DOMAIN_GLOBAL_OFFSET, &TEST_ROOT_DOMAIN_ADDRESS.to_le_bytes());
+        self.with_module(module_name, module_bytes).with_unity_heap()
+    }
+
+    pub(crate) fn build_pe64_runtime_module(root_domain_global_offset: u64) -> Vec<u8> {
+        let mut module_bytes = vec![0_u8; TEST_MODULE_SIZE];
+
+        write_le(&mut module_bytes, 0, b"MZ");
+        write_le(&mut module_bytes, 0x3C, &0x80_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x80, b"PE\0\0");
+        write_le(&mut module_bytes, 0x98, &0x20B_u16.to_le_bytes());
+        write_le(&mut module_bytes, 0x108, &0x1000_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x10C, &0x100_u32.to_le_bytes());
+
+        // Export directory with `mono_domain_get` listed before the getter.
+        write_le(&mut module_bytes, 0x1018, &2_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x101C, &0x1040_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x1020, &0x1050_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x1024, &0x1060_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x1040, &0x2100_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x1044, &(ROOT_DOMAIN_GETTER_OFFSET as u32).to_le_bytes());
+        write_le(&mut module_bytes, 0x1050, &0x1070_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x1054, &0x1090_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x1060, &0_u16.to_le_bytes());
+        write_le(&mut module_bytes, 0x1062, &1_u16.to_le_bytes());
+        write_le(&mut module_bytes, 0x1070, b"mono_domain_get\0");
+        write_le(&mut module_bytes, 0x1090, b"mono_get_root_domain\0");
+        write_root_domain_getter(&mut module_bytes, false, root_domain_global_offset);
+
+        module_bytes
+    }
+
+    pub(crate) fn build_elf64_runtime_module(root_domain_global_offset: u64) -> Vec<u8> {
+        let mut module_bytes = vec![0_u8; TEST_MODULE_SIZE];
+
+        write_le(&mut module_bytes, 0, b"\x7FELF\x02\x01\x01");
+        write_le(&mut module_bytes, 0x20, &0x40_u64.to_le_bytes());
+        write_le(&mut module_bytes, 0x38, &2_u16.to_le_bytes());
+
+        // One loadable segment covering the image, and the dynamic segment.
+        write_le(&mut module_bytes, 0x40, &1_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x68, &(TEST_MODULE_SIZE as u64).to_le_bytes());
+        write_le(&mut module_bytes, 0x78, &2_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x88, &0x1000_u64.to_le_bytes());
+        write_le(&mut module_bytes, 0xA0, &0x40_u64.to_le_bytes());
+
+        for (entry_index, (tag, value)) in [(6_u64, 0x1100_u64), (5, 0x1130), (10, 0x20)]
+            .into_iter()
+            .enumerate()
+        {
+            let entry_offset = 0x1000 + entry_index as u64 * 16;
+
+            write_le(&mut module_bytes, entry_offset, &tag.to_le_bytes());
+            write_le(&mut module_bytes, entry_offset + 8, &value.to_le_bytes());
+        }
+
+        write_le(&mut module_bytes, 0x1118, &1_u32.to_le_bytes());
+        write_le(&mut module_bytes, 0x111E, &1_u16.to_le_bytes());
+        write_le(&mut module_bytes, 0x1120, &ROOT_DOMAIN_GETTER_OFFSET.to_le_bytes());
+        write_le(&mut module_bytes, 0x1130, b"\0mono_get_root_domain\0");
+        write_root_domain_getter(&mut module_bytes, true, root_domain_global_offset);
+
+        module_bytes
+    }
+
+    /// Builds a root domain with one assembly whose class cache holds `Game.Player` chained to its base `Game.Entity`.
+    pub(crate) fn with_unity_heap(mut self) -> Self {
+        self.write_u64(TEST_ROOT_DOMAIN_ADDRESS + 0xA0, ASSEMBLY_LIST_NODE_ADDRESS);
+        self.write_u64(ASSEMBLY_LIST_NODE_ADDRESS, ASSEMBLY_ADDRESS);
+        self.write_u64(ASSEMBLY_LIST_NODE_ADDRESS + 8, 0);
+        self.write_string_pointer(ASSEMBLY_ADDRESS + 0x10, "Assembly-CSharp");
+        self.write_u64(ASSEMBLY_ADDRESS + 0x60, IMAGE_ADDRESS);
+        self.write_u32(IMAGE_ADDRESS + 0x4C0 + 0x18, 4);
+        self.write_u64(IMAGE_ADDRESS + 0x4C0 + 0x20, CLASS_CACHE_TABLE_ADDRESS);
+        self.write_u64(CLASS_CACHE_TABLE_ADDRESS, TEST_PLAYER_CLASS_ADDRESS);
+
+        self.write_type(INT32_TYPE_ADDRESS, 0, 0x0001, 0x08);
+        self.write_type(ENTITY_REFERENCE_TYPE_ADDRESS, ENTITY_CLASS_ADDRESS, 0x0001, 0x12);
+        self.write_type(STATIC_INT32_TYPE_ADDRESS, 0, 0x0011, 0x08);
+        self.write_type(LITERAL_INT32_TYPE_ADDRESS, 0, 0x0051, 0x08);
+
+        self.write_class(TEST_PLAYER_CLASS_ADDRESS, "Player", 0x28, ENTITY_CLASS_ADDRESS, PLAYER_FIELDS_ADDRESS, 4);
+        self.write_u64(TEST_PLAYER_CLASS_ADDRESS + 0x108, ENTITY_CLASS_ADDRESS);
+        self.write_u32(TEST_PLAYER_CLASS_ADDRESS + 0x5C, 3);
+        self.write_u64(TEST_PLAYER_CLASS_ADDRESS + 0xD0, PLAYER_RUNTIME_INFO_ADDRESS);
+        self.write_field(PLAYER_FIELDS_ADDRESS, INT32_TYPE_ADDRESS, "health", 0x18);
+        self.write_field(PLAYER_FIELDS_ADDRESS + 0x20, ENTITY_REFERENCE_TYPE_ADDRESS, "target", 0x20);
+        self.write_field(PLAYER_FIELDS_ADDRESS + 0x40, STATIC_INT32_TYPE_ADDRESS, "instanceCount", 0);
+        self.write_field(PLAYER_FIELDS_ADDRESS + 0x60, LITERAL_INT32_TYPE_ADDRESS, "MaxHealth", 0);
+        self.write_u64(PLAYER_RUNTIME_INFO_ADDRESS + 8, PLAYER_VTABLE_ADDRESS);
+        self.write_u64(PLAYER_VTABLE_ADDRESS + 0x48 + 3 * 8, TEST_STATIC_DATA_ADDRESS);
+        self.write_u32(TEST_STATIC_DATA_ADDRESS, 7);
+
+        self.write_class(ENTITY_CLASS_ADDRESS, "Entity", 0x14, 0, ENTITY_FIELDS_ADDRESS, 1);
+        self.write_field(ENTITY_FIELDS_ADDRESS, INT32_TYPE_ADDRESS, "id", 0x10);
+
+        self
+    }
+
+    fn write_class(
+        &mut self,
+        class_address: u64,
+        class_name: &str,
+        instance_size: u32,
+        parent_address: u64,
+        fields_address: u64,
+        field_count: u32,
+    ) {
+        self.write_bytes(class_address + 0x1B, &[1]);
+        self.write_u32(class_address + 0x1C, instance_size);
+        self.write_u64(class_address + 0x30, parent_address);
+        self.write_string_pointer(class_address + 0x48, class_name);
+        self.write_string_pointer(class_address + 0x50, "Game");
+        self.write_u64(class_address + 0x98, fields_address);
+        self.write_u32(class_address + 0x100, field_count);
+    }
+
+    fn write_field(
+        &mut self,
+        field_address: u64,
+        type_address: u64,
+        field_name: &str,
+        field_offset: u32,
+    ) {
+        self.write_u64(field_address, type_address);
+        self.write_string_pointer(field_address + 8, field_name);
+        self.write_u64(field_address + 0x10, 0);
+        self.write_u32(field_address + 0x18, field_offset);
+    }
+
+    fn write_type(
+        &mut self,
+        type_address: u64,
+        type_data: u64,
+        type_attributes: u16,
+        type_code: u8,
+    ) {
+        self.write_u64(type_address, type_data);
+        self.write_bytes(type_address + 8, &type_attributes.to_le_bytes());
+        self.write_bytes(type_address + 0xA, &[type_code]);
+    }
+
+    fn write_string_pointer(
+        &mut self,
+        pointer_address: u64,
+        string: &str,
+    ) {
+        let string_address = self.next_string_address;
+
+        self.next_string_address += (string.len() as u64 + 1).next_multiple_of(0x10);
+        self.write_bytes(string_address, string.as_bytes());
+        self.write_bytes(string_address + string.len() as u64, &[0]);
+        self.write_u64(pointer_address, string_address);
+    }
+
+    fn write_u32(
+        &mut self,
+        address: u64,
+        value: u32,
+    ) {
+        self.write_bytes(address, &value.to_le_bytes());
+    }
+
+    fn write_u64(
+        &mut self,
+        address: u64,
+        value: u64,
+    ) {
+        self.write_bytes(address, &value.to_le_bytes());
+    }
+
+    fn write_bytes(
+        &mut self,
+        address: u64,
+        bytes: &[u8],
+    ) {
+        for (byte_index, byte) in bytes.iter().enumerate() {
+            let byte_address = address + byte_index as u64;
+            let page = self
+                .heap_pages
+                .entry(byte_address / TEST_PAGE_SIZE)
+                .or_insert_with(|| vec![0; TEST_PAGE_SIZE as usize]);
+
+            page[(byte_address % TEST_PAGE_SIZE) as usize] = *byte;
+        }
+    }
+}
+
+impl ProcessMemoryStore for TestProcessMemory {
+    fn read_module_bytes(
+        &self,
+        module_name: &str,
+        offset: u64,
+        length: u64,
+    ) -> Result<Vec<u8>, String> {
+        if !module_name.is_empty() {
+            let module_bytes = self
+                .modules
+                .get(module_name)
+                .ok_or_else(|| format!("Unknown module `{module_name}`."))?;
+
+            return module_bytes
+                .get(offset as usize..(offset + length) as usize)
+                .map(<[u8]>::to_vec)
+                .ok_or_else(|| format!("Read past the end of `{module_name}`."));
+        }
+
+        (offset..offset + length)
+            .map(|address| {
+                self.heap_pages
+                    .get(&(address / TEST_PAGE_SIZE))
+                    .map(|page| page[(address % TEST_PAGE_SIZE) as usize])
+                    .ok_or_else(|| format!("Address 0x{address:X} is not mapped."))
+            })
+            .collect()
+    }
+}
+
+fn write_root_domain_getter(
+    module_bytes: &mut [u8],
+    has_endbr64: bool,
+    root_domain_global_offset: u64,
+) {
+    let mut getter_offset = ROOT_DOMAIN_GETTER_OFFSET;
+
+    if has_endbr64 {
+        write_le(module_bytes, getter_offset, &[0xF3, 0x0F, 0x1E, 0xFA]);
+        getter_offset += 4;
+    }
+
+    let displacement = (root_domain_global_offset as i64 - (getter_offset as i64 + 7)) as i32;
+
+    write_le(module_bytes, getter_offset, &[0x48, 0x8B, 0x05]);
+    write_le(module_bytes, getter_offset + 3, &displacement.to_le_bytes());
+    write_le(module_bytes, getter_offset + 7, &[0xC3]);
+}
+
+fn write_le(
+    module_bytes: &mut [u8],
+    offset: u64,
+    bytes: &[u8],
+) {
+    module_bytes[offset as usize..offset as usize + bytes.len()].copy_from_slice(bytes);
+}
@@ -0,0 +1,3 @@
+pub const UNREAL_SYMBOLS_PLUGIN_ID: &str = "builtin.symbols.unreal";
+pub const UNREAL_SYMBOLS_PLUGIN_DISPLAY_NAME: &str = "Unreal Symbols";
+pub const UNREAL_SYMBOLS_PLUGIN_DESCRIPTION: &str = "Populates class and struct layouts and engine globals from a running Unreal Engine 4 or 5 game.";
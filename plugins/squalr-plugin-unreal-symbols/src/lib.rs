@@ -0,0 +1,35 @@
+mod constants;
+mod plugin;
+mod populate_unreal_symbols_action;
+mod runtime;
+mod unreal_symbol_builder;
+
+#[cfg(test)]
+mod test_process_memory;
+
+pub use plugin::UnrealSymbolsPlugin;
+
+#[cfg(test)]
+mod tests {
+    use super::UnrealSymbolsPlugin;
+    use squalr_engine_api::plugins::{Plugin, PluginPermission, symbol_tree::symbol_tree_plugin::SymbolTreePlugin};
+
+    #[test]
+    fn plugin_exposes_symbol_store_and_process_memory_permissions() {
+        let plugin = UnrealSymbolsPlugin::new();
+
+        assert_eq!(plugin.metadata().get_plugin_id(), "builtin.symbols.unreal");
+        assert!(plugin.metadata().get_is_enabled_by_default());
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_permission(PluginPermission::WriteSymbolStore)
+        );
+        assert!(
+            plugin
+                .metadata()
+                .has_plugin_permission(PluginPermission::ReadProcessMemory)
+        );
+        assert_eq!(plugin.symbol_tree_actions().len(), 1);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    constants::{UNREAL_SYMBOLS_PLUGIN_DESCRIPTION, UNREAL_SYMBOLS_PLUGIN_DISPLAY_NAME, UNREAL_SYMBOLS_PLUGIN_ID},
+    populate_unreal_symbols_action::PopulateUnrealSymbolsAction,
+};
+use squalr_engine_api::plugins::{
+    Plugin, PluginCapability, PluginMetadata, PluginPackage, PluginPermission, symbol_tree::symbol_tree_action::SymbolTreeAction,
+    symbol_tree::symbol_tree_plugin::SymbolTreePlugin,
+};
+use std::sync::Arc;
+
+pub struct UnrealSymbolsPlugin {
+    metadata: PluginMetadata,
+    symbol_tree_actions: Vec<Arc<dyn SymbolTreeAction>>,
+}
+
+impl UnrealSymbolsPlugin {
+    pub fn new() -> Self {
+        Self {
+            metadata: PluginMetadata::new_with_permissions(
+                UNREAL_SYMBOLS_PLUGIN_ID,
+                UNREAL_SYMBOLS_PLUGIN_DISPLAY_NAME,
+                UNREAL_SYMBOLS_PLUGIN_DESCRIPTION,
+                vec![PluginCapability::SymbolTree],
+                vec![
+                    PluginPermission::ReadSymbolStore,
+                    PluginPermission::WriteSymbolStore,
+                    PluginPermission::ReadSymbolTreeWindow,
+                    PluginPermission::WriteSymbolTreeWindow,
+                    PluginPermission::ReadProcessMemory,
+                ],
+                true,
+                true,
+            ),
+            symbol_tree_actions: vec![Arc::new(PopulateUnrealSymbolsAction)],
+        }
+    }
+}
+
+impl Default for UnrealSymbolsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for UnrealSymbolsPlugin {
+    fn metadata(&self) -> &PluginMetadata {
+        &self.metadata
+    }
+}
+
+impl PluginPackage for UnrealSymbolsPlugin {
+    fn as_symbol_tree_plugin(&self) -> Option<&dyn SymbolTreePlugin> {
+        Some(self)
+    }
+}
+
+impl SymbolTreePlugin for UnrealSymbolsPlugin {
+    fn symbol_tree_actions(&self) -> &[Arc<dyn SymbolTreeAction>] {
+        &self.symbol_tree_actions
+    }
+}
@@ -0,0 +1,643 @@
+use crate::{
+    runtime::{
+        unreal_engine_offsets::{UnrealEngineOffsets, UnrealNameTableKind},
+        unreal_global_locator::{UnrealGlobal, UnrealGlobalCandidates, locate_global_candidates},
+        unreal_memory_reader::UnrealMemoryReader,
+        unreal_name_resolver::UnrealNameResolver,
+        unreal_object_walker::UnrealObjectWalker,
+    },
+    unreal_symbol_builder::{UnrealEngineGlobals, UnrealModuleField, UnrealSymbolBuilder, UnrealSymbols},
+};
+use squalr_engine_api::{
+    plugins::{
+        PluginPermission,
+        symbol_tree::symbol_tree_action::{
+            DataTypeRegistryStore, SymbolTreeAction, SymbolTreeActionContext, SymbolTreeActionSelection, SymbolTreeActionServices,
+        },
+    },
+    registries::symbols::struct_layout_descriptor::StructLayoutDescriptor,
+    structures::{
+        data_types::data_type_ref::DataTypeRef,
+        projects::{
+            project_symbol_catalog::ProjectSymbolCatalog,
+            project_symbol_module_field::ProjectSymbolModuleField,
+            symbol_layouts::{
+                symbol_layout_field_materializer::{SymbolLayoutFieldMaterializer, SymbolLayoutPositionedField},
+                symbol_layout_size_resolver::SymbolLayoutSizeResolver,
+            },
+        },
+        structs::{
+            symbolic_field_definition::{SymbolicFieldDefinition, SymbolicFieldOffsetResolution},
+            symbolic_struct_definition::SymbolicStructDefinition,
+        },
+    },
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Packaged Unreal games name their binary `<Project>-<Platform>-<Configuration>.exe`.
+const UNREAL_MODULE_PLATFORM_MARKERS: [&str; 2] = ["-win64-", "-wingdk-"];
+const UNREAL_MODULE_EXTENSION: &str = ".exe";
+const WORLD_CLASS_NAME: &str = "World";
+
+pub struct PopulateUnrealSymbolsAction;
+
+impl SymbolTreeAction for PopulateUnrealSymbolsAction {
+    fn action_id(&self) -> &'static str {
+        "builtin.symbols.unreal.populate-unreal-symbols"
+    }
+
+    fn label(
+        &self,
+        _context: &SymbolTreeActionContext,
+    ) -> String {
+        String::from("Populate Unreal Symbols")
+    }
+
+    fn is_visible(
+        &self,
+        context: &SymbolTreeActionContext,
+    ) -> bool {
+        matches!(context.get_selection(), SymbolTreeActionSelection::ModuleRoot { module_name } if is_unreal_game_module(module_name))
+    }
+
+    fn required_permissions(&self) -> &'static [PluginPermission] {
+        &[
+            PluginPermission::ReadSymbolStore,
+            PluginPermission::WriteSymbolStore,
+            PluginPermission::ReadSymbolTreeWindow,
+            PluginPermission::WriteSymbolTreeWindow,
+            PluginPermission::ReadProcessMemory,
+        ]
+    }
+
+    fn execute(
+        &self,
+        context: &SymbolTreeActionContext,
+        services: &dyn SymbolTreeActionServices,
+    ) -> Result<(), String> {
+        let SymbolTreeActionSelection::ModuleRoot { module_name } = context.get_selection() else {
+            return Err(String::from("Unreal symbol population requires a module root selection."));
+        };
+
+        if !is_unreal_game_module(module_name) {
+            return Err(format!("Module `{module_name}` is not a packaged Unreal game."));
+        }
+
+        let memory_reader = UnrealMemoryReader::new(services.process_memory());
+        let global_candidates = locate_global_candidates(&memory_reader, module_name)?;
+        let (offsets, names_offset, object_array_offset) = select_engine_offsets(&memory_reader, module_name, &global_candidates)?;
+        let name_resolver = UnrealNameResolver::new(&memory_reader, offsets, module_name, names_offset);
+        let object_walker = UnrealObjectWalker::new(&memory_reader, &name_resolver, offsets, module_name, object_array_offset);
+        let structs = object_walker.collect_structs()?;
+        let (world_offset, world_class_address) = select_world_global(&memory_reader, &object_walker, offsets, module_name, &global_candidates).unzip();
+        let engine_globals = UnrealEngineGlobals {
+            names_offset,
+            object_array_offset,
+            world_offset,
+            world_class_address,
+        };
+        let unreal_symbols = UnrealSymbolBuilder::new(&structs, offsets).build(&engine_globals);
+        let data_type_size_by_id = collect_data_type_size_by_id(services.data_type_registry());
+        let module_name = module_name.clone();
+        let module_name_for_update = module_name.clone();
+
+        services.symbol_store().write_catalog(
+            "populate Unreal symbols",
+            Box::new(move |project_symbol_catalog| {
+                populate_unreal_symbols(project_symbol_catalog, &module_name_for_update, unreal_symbols, &data_type_size_by_id)
+            }),
+        )?;
+        services.symbol_tree_window().request_refresh();
+        services
+            .symbol_tree_window()
+            .focus_tree_node(&format!("module:{module_name}"));
+
+        Ok(())
+    }
+}
+
+fn is_unreal_game_module(module_name: &str) -> bool {
+    let module_name = module_name.to_ascii_lowercase();
+
+    module_name.ends_with(UNREAL_MODULE_EXTENSION)
+        && UNREAL_MODULE_PLATFORM_MARKERS
+            .iter()
+            .any(|platform_marker| module_name.contains(platform_marker))
+}
+
+/// Tries each preset against each pair of name table and object array candidates, returning the first combination that
+/// reads the engine's own core types back correctly.
+fn select_engine_offsets(
+    memory_reader: &UnrealMemoryReader,
+    module_name: &str,
+    global_candidates: &UnrealGlobalCandidates,
+) -> Result<(UnrealEngineOffsets, u64, u64), String> {
+    for offsets in UnrealEngineOffsets::PRESETS {
+        let names_global = match offsets.name_table_kind {
+            UnrealNameTableKind::NamePool => UnrealGlobal::NamePool,
+            UnrealNameTableKind::EntryArray => UnrealGlobal::NameEntryArray,
+        };
+
+        for names_offset in global_candidates.get(names_global) {
+            let name_resolver = UnrealNameResolver::new(memory_reader, offsets, module_name, *names_offset);
+
+            for object_array_offset in global_candidates.get(UnrealGlobal::ObjectArray) {
+                let object_walker = UnrealObjectWalker::new(memory_reader, &name_resolver, offsets, module_name, *object_array_offset);
+
+                if object_walker.validate().is_ok() {
+                    return Ok((offsets, *names_offset, *object_array_offset));
+                }
+            }
+        }
+    }
+
+    Err(format!(
+        "Could not find the name table and object array in `{module_name}` ({} name table and {} object array candidates). Engine versions 4.20 through 5.x are supported.",
+        global_candidates.get(UnrealGlobal::NamePool).len() + global_candidates.get(UnrealGlobal::NameEntryArray).len(),
+        global_candidates.get(UnrealGlobal::ObjectArray).len()
+    ))
+}
+
+/// Returns the first `GWorld` candidate that currently points at a `UWorld`, along with that world's class.
+fn select_world_global(
+    memory_reader: &UnrealMemoryReader,
+    object_walker: &UnrealObjectWalker,
+    offsets: UnrealEngineOffsets,
+    module_name: &str,
+    global_candidates: &UnrealGlobalCandidates,
+) -> Option<(u64, u64)> {
+    global_candidates
+        .get(UnrealGlobal::World)
+        .iter()
+        .find_map(|world_offset| {
+            let world_address = memory_reader
+                .read_module_pointer(module_name, *world_offset)
+                .ok()
+                .filter(|world_address| *world_address != 0)?;
+
+            if object_walker.read_object_class_name(world_address).ok()? != WORLD_CLASS_NAME {
+                return None;
+            }
+
+            let world_class_address = memory_reader
+                .read_pointer(world_address + offsets.object_class)
+                .ok()?;
+
+            Some((*world_offset, world_class_address))
+        })
+}
+
+fn populate_unreal_symbols(
+    project_symbol_catalog: &mut ProjectSymbolCatalog,
+    module_name: &str,
+    unreal_symbols: UnrealSymbols,
+    data_type_size_by_id: &BTreeMap<String, u64>,
+) -> Result<(), String> {
+    upsert_struct_layout_descriptors(project_symbol_catalog, unreal_symbols.struct_layout_descriptors);
+    upsert_module_fields(project_symbol_catalog, module_name, &unreal_symbols.module_fields, data_type_size_by_id)
+}
+
+fn upsert_struct_layout_descriptors(
+    project_symbol_catalog: &mut ProjectSymbolCatalog,
+    new_struct_layout_descriptors: Vec<StructLayoutDescriptor>,
+) {
+    let mut struct_layout_descriptors = project_symbol_catalog.get_struct_layout_descriptors().to_vec();
+    let mut struct_layout_positions_by_id = struct_layout_descriptors
+        .iter()
+        .enumerate()
+        .map(|(struct_layout_position, struct_layout_descriptor)| (struct_layout_descriptor.get_struct_layout_id().to_string(), struct_layout_position))
+        .collect::<HashMap<_, _>>();
+
+    for new_struct_layout_descriptor in new_struct_layout_descriptors {
+        match struct_layout_positions_by_id.get(new_struct_layout_descriptor.get_struct_layout_id()) {
+            Some(struct_layout_position) => struct_layout_descriptors[*struct_layout_position] = new_struct_layout_descriptor,
+            None => {
+                struct_layout_positions_by_id.insert(new_struct_layout_descriptor.get_struct_layout_id().to_string(), struct_layout_descriptors.len());
+                struct_layout_descriptors.push(new_struct_layout_descriptor);
+            }
+        }
+    }
+
+    project_symbol_catalog.set_struct_layout_descriptors(struct_layout_descriptors);
+}
+
+/// Adds the engine globals to the game module, both as module fields and in the module root layout. Existing fields that
+/// overlap a new one are replaced.
+fn upsert_module_fields(
+    project_symbol_catalog: &mut ProjectSymbolCatalog,
+    module_name: &str,
+    desired_module_fields: &[UnrealModuleField],
+    data_type_size_by_id: &BTreeMap<String, u64>,
+) -> Result<(), String> {
+    let desired_field_ranges = desired_module_fields
+        .iter()
+        .map(|desired_module_field| {
+            desired_module_field
+                .offset
+                .checked_add(desired_module_field.size_in_bytes)
+                .map(|desired_field_end| (desired_module_field.offset, desired_field_end))
+                .ok_or_else(|| format!("Engine global `{}` range is too large.", desired_module_field.display_name))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let desired_fields_end = desired_field_ranges
+        .iter()
+        .map(|(_, desired_field_end)| *desired_field_end)
+        .max()
+        .unwrap_or(0);
+
+    project_symbol_catalog.ensure_symbol_module(module_name, desired_fields_end);
+    let Some(symbol_module) = project_symbol_catalog.find_symbol_module_mut(module_name) else {
+        return Err(format!("Could not resolve module `{module_name}` after creating it."));
+    };
+    let module_fields = symbol_module.get_fields_mut();
+
+    module_fields.retain(|module_field| !overlaps_desired_range(&desired_field_ranges, module_field.get_offset(), module_field.get_offset() + 1));
+    module_fields.extend(desired_module_fields.iter().map(|desired_module_field| {
+        ProjectSymbolModuleField::new(
+            desired_module_field.display_name.clone(),
+            desired_module_field.offset,
+            desired_module_field.get_type_text(),
+        )
+    }));
+    module_fields.sort_by_key(|module_field| module_field.get_offset());
+
+    let module_size = symbol_module.get_size();
+    let resolve_data_type_size_in_bytes = |data_type_ref: &DataTypeRef| {
+        data_type_size_by_id
+            .get(data_type_ref.get_data_type_id())
+            .copied()
+    };
+
+    project_symbol_catalog.ensure_module_root_struct_layout(module_name, module_size, resolve_data_type_size_in_bytes);
+
+    let mut struct_layout_descriptors = project_symbol_catalog.get_struct_layout_descriptors().to_vec();
+    let Some(module_root_layout_descriptor) = struct_layout_descriptors
+        .iter_mut()
+        .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == module_name)
+    else {
+        return Ok(());
+    };
+    let module_root_layout_definition = module_root_layout_descriptor
+        .get_struct_layout_definition()
+        .clone();
+    let resolve_struct_layout_definition = |struct_layout_id: &str| {
+        project_symbol_catalog
+            .get_struct_layout_descriptors()
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == struct_layout_id)
+            .map(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_definition().clone())
+    };
+    let mut positioned_fields = Vec::new();
+    let mut next_sequential_offset = 0_u64;
+
+    for field_definition in module_root_layout_definition.get_fields() {
+        let field_size_in_bytes = SymbolLayoutSizeResolver::resolve_symbolic_field_size_in_bytes(
+            field_definition,
+            resolve_data_type_size_in_bytes,
+            resolve_struct_layout_definition,
+            &mut HashSet::new(),
+        )
+        .unwrap_or(0)
+        .max(1);
+        let field_offset = match field_definition.get_offset_resolution() {
+            SymbolicFieldOffsetResolution::Static(offset_in_bytes) => *offset_in_bytes,
+            SymbolicFieldOffsetResolution::Sequential | SymbolicFieldOffsetResolution::Resolver(_) => next_sequential_offset,
+        };
+
+        next_sequential_offset = next_sequential_offset.max(field_offset.saturating_add(field_size_in_bytes));
+
+        if field_definition.is_unassigned() || overlaps_desired_range(&desired_field_ranges, field_offset, field_offset.saturating_add(field_size_in_bytes)) {
+            continue;
+        }
+
+        positioned_fields.push(SymbolLayoutPositionedField::new(field_offset, field_size_in_bytes, field_definition.clone()));
+    }
+
+    positioned_fields.extend(desired_module_fields.iter().map(|desired_module_field| {
+        SymbolLayoutPositionedField::new(
+            desired_module_field.offset,
+            desired_module_field.size_in_bytes,
+            SymbolicFieldDefinition::new_named(
+                desired_module_field.display_name.clone(),
+                DataTypeRef::new(&desired_module_field.data_type_id),
+                desired_module_field.container_type,
+            ),
+        )
+    }));
+    positioned_fields.sort_by_key(|positioned_field| positioned_field.get_offset_in_bytes());
+
+    let declared_size_in_bytes = module_root_layout_definition
+        .get_declared_size_in_bytes()
+        .unwrap_or(0)
+        .max(module_size)
+        .max(desired_fields_end);
+    let rebuilt_fields = SymbolLayoutFieldMaterializer::materialize_positioned_fields(
+        module_root_layout_definition.get_layout_kind(),
+        Some(declared_size_in_bytes),
+        positioned_fields,
+    )?;
+
+    *module_root_layout_descriptor = StructLayoutDescriptor::new(
+        module_name.to_string(),
+        SymbolicStructDefinition::new_with_layout_kind(
+            module_root_layout_definition.get_symbol_namespace().to_string(),
+            module_root_layout_definition.get_layout_kind(),
+            rebuilt_fields,
+        )
+        .with_declared_size_in_bytes(Some(declared_size_in_bytes)),
+    );
+    project_symbol_catalog.set_struct_layout_descriptors(struct_layout_descriptors);
+
+    Ok(())
+}
+
+/// Expects ranges sorted by start and not overlapping each other, as the builder produces them.
+fn overlaps_desired_range(
+    desired_field_ranges: &[(u64, u64)],
+    range_start: u64,
+    range_end: u64,
+) -> bool {
+    let first_candidate_index = desired_field_ranges.partition_point(|(_, desired_field_end)| *desired_field_end <= range_start);
+
+    desired_field_ranges
+        .get(first_candidate_index)
+        .is_some_and(|(desired_field_start, _)| *desired_field_start < range_end)
+}
+
+fn collect_data_type_size_by_id(data_type_registry: &dyn DataTypeRegistryStore) -> BTreeMap<String, u64> {
+    data_type_registry
+        .get_registered_data_type_refs()
+        .into_iter()
+        .filter_map(|data_type_ref| {
+            let unit_size_in_bytes = data_type_registry.get_unit_size_in_bytes(&data_type_ref);
+
+            (unit_size_in_bytes > 0).then(|| (data_type_ref.get_data_type_id().to_string(), unit_size_in_bytes))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PopulateUnrealSymbolsAction;
+    use crate::test_process_memory::{
+        TEST_GAME_MODULE_NAME, TEST_NAME_POOL_OFFSET, TEST_OBJECT_ARRAY_OFFSET, TEST_WORLD_OFFSET, TestProcessMemory, build_pe64_game_module,
+    };
+    use squalr_engine_api::{
+        plugins::symbol_tree::symbol_tree_action::{
+            DataTypeRegistryStore, ProcessMemoryStore, ProjectSymbolStore, SymbolTreeAction, SymbolTreeActionContext, SymbolTreeActionSelection,
+            SymbolTreeActionServices, SymbolTreeWindowStore,
+        },
+        registries::symbols::symbol_registry::SymbolRegistry,
+        structures::{data_types::data_type_ref::DataTypeRef, projects::project_symbol_catalog::ProjectSymbolCatalog},
+    };
+    use std::sync::Mutex;
+
+    struct TestProjectSymbolStore {
+        project_symbol_catalog: Mutex<ProjectSymbolCatalog>,
+    }
+
+    impl ProjectSymbolStore for TestProjectSymbolStore {
+        fn read_catalog(&self) -> Result<ProjectSymbolCatalog, String> {
+            Ok(self
+                .project_symbol_catalog
+                .lock()
+                .map_err(|error| error.to_string())?
+                .clone())
+        }
+
+        fn write_catalog(
+            &self,
+            _reason: &str,
+            update_catalog: Box<dyn FnOnce(&mut ProjectSymbolCatalog) -> Result<(), String> + Send>,
+        ) -> Result<(), String> {
+            let mut project_symbol_catalog = self
+                .project_symbol_catalog
+                .lock()
+                .map_err(|error| error.to_string())?;
+
+            update_catalog(&mut project_symbol_catalog)
+        }
+    }
+
+    struct TestSymbolTreeWindowStore;
+
+    impl SymbolTreeWindowStore for TestSymbolTreeWindowStore {
+        fn request_refresh(&self) {}
+
+        fn focus_tree_node(
+            &self,
+            _tree_node_key: &str,
+        ) {
+        }
+    }
+
+    struct TestSymbolTreeActionServices {
+        project_symbol_store: TestProjectSymbolStore,
+        process_memory_store: TestProcessMemory,
+        data_type_registry: SymbolRegistry,
+        symbol_tree_window_store: TestSymbolTreeWindowStore,
+    }
+
+    impl TestSymbolTreeActionServices {
+        fn new(process_memory_store: TestProcessMemory) -> Self {
+            Self {
+                project_symbol_store: TestProjectSymbolStore {
+                    project_symbol_catalog: Mutex::new(ProjectSymbolCatalog::default()),
+                },
+                process_memory_store,
+                data_type_registry: SymbolRegistry::new(),
+                symbol_tree_window_store: TestSymbolTreeWindowStore,
+            }
+        }
+    }
+
+    impl DataTypeRegistryStore for TestSymbolTreeActionServices {
+        fn get_registered_data_type_refs(&self) -> Vec<DataTypeRef> {
+            self.data_type_registry.get_registered_data_type_refs()
+        }
+
+        fn get_unit_size_in_bytes(
+            &self,
+            data_type_ref: &DataTypeRef,
+        ) -> u64 {
+            self.data_type_registry.get_unit_size_in_bytes(data_type_ref)
+        }
+    }
+
+    impl SymbolTreeActionServices for TestSymbolTreeActionServices {
+        fn symbol_store(&self) -> &dyn ProjectSymbolStore {
+            &self.project_symbol_store
+        }
+
+        fn process_memory(&self) -> &dyn ProcessMemoryStore {
+            &self.process_memory_store
+        }
+
+        fn data_type_registry(&self) -> &dyn DataTypeRegistryStore {
+            self
+        }
+
+        fn symbol_tree_window(&self) -> &dyn SymbolTreeWindowStore {
+            &self.symbol_tree_window_store
+        }
+    }
+
+    fn module_root_context(module_name: &str) -> SymbolTreeActionContext {
+        SymbolTreeActionContext::new(SymbolTreeActionSelection::ModuleRoot {
+            module_name: module_name.to_string(),
+        })
+    }
+
+    fn find_layout_fields(
+        project_symbol_catalog: &ProjectSymbolCatalog,
+        layout_id: &str,
+    ) -> Vec<(String, String, String)> {
+        project_symbol_catalog
+            .get_struct_layout_descriptors()
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == layout_id)
+            .unwrap_or_else(|| panic!("Expected layout `{layout_id}`."))
+            .get_struct_layout_definition()
+            .get_fields()
+            .iter()
+            .filter(|field_definition| !field_definition.is_unassigned())
+            .map(|field_definition| {
+                (
+                    field_definition.get_field_name().to_string(),
+                    field_definition
+                        .get_data_type_ref()
+                        .get_data_type_id()
+                        .to_string(),
+                    field_definition.get_container_type().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn layout_field(
+        field_name: &str,
+        data_type_id: &str,
+        container_text: &str,
+    ) -> (String, String, String) {
+        (field_name.to_string(), data_type_id.to_string(), container_text.to_string())
+    }
+
+    #[test]
+    fn action_is_visible_only_for_packaged_unreal_games() {
+        let action = PopulateUnrealSymbolsAction;
+
+        for module_name in [
+            "Game-Win64-Shipping.exe",
+            "Game-WinGDK-Shipping.exe",
+            "Game-Win64-Test.EXE",
+        ] {
+            assert!(action.is_visible(&module_root_context(module_name)));
+        }
+
+        for module_name in ["game.exe", "Game-Win64-Shipping.dll", "UnityPlayer.dll"] {
+            assert!(!action.is_visible(&module_root_context(module_name)));
+        }
+    }
+
+    #[test]
+    fn execute_populates_reflected_layouts_and_engine_globals() {
+        let services = TestSymbolTreeActionServices::new(TestProcessMemory::new().with_unreal_game(false));
+        let context = module_root_context(TEST_GAME_MODULE_NAME);
+
+        PopulateUnrealSymbolsAction
+            .execute(&context, &services)
+            .expect("Expected Unreal symbol population to succeed.");
+        // Running twice must not duplicate module fields.
+        PopulateUnrealSymbolsAction
+            .execute(&context, &services)
+            .expect("Expected repeated Unreal symbol population to succeed.");
+
+        let project_symbol_catalog = services
+            .project_symbol_store
+            .read_catalog()
+            .expect("Expected the test catalog to be readable.");
+        let symbol_module = project_symbol_catalog
+            .find_symbol_module(TEST_GAME_MODULE_NAME)
+            .expect("Expected the game module to exist.");
+
+        assert_eq!(
+            find_layout_fields(&project_symbol_catalog, "unreal.CoreUObject.Vector"),
+            vec![
+                layout_field("X", "f32", ""),
+                layout_field("Y", "f32", ""),
+                layout_field("Z", "f32", ""),
+            ]
+        );
+        // The second bitfield shares its byte with the first, so only the first is kept.
+        assert_eq!(
+            find_layout_fields(&project_symbol_catalog, "unreal.Engine.Pawn")[6..],
+            [
+                layout_field("Location", "unreal.CoreUObject.Vector", ""),
+                layout_field("bHidden", "u8", ""),
+                layout_field("bActive", "bool8", ""),
+                layout_field("Health", "f32", ""),
+                layout_field("Owner", "unreal.Engine.Actor", "*(u64)"),
+                layout_field("Ammo", "i32", "[3]"),
+            ]
+        );
+        assert!(project_symbol_catalog.contains_struct_layout_id("unreal.Engine.World"));
+        assert!(project_symbol_catalog.contains_struct_layout_id("unreal.FNamePool"));
+        assert!(project_symbol_catalog.contains_struct_layout_id("unreal.FUObjectArray"));
+        assert_eq!(
+            symbol_module
+                .get_fields()
+                .iter()
+                .map(|module_field| (module_field.get_display_name(), module_field.get_offset(), module_field.get_struct_layout_id()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("GObjects", TEST_OBJECT_ARRAY_OFFSET, "unreal.FUObjectArray"),
+                ("GWorld", TEST_WORLD_OFFSET, "unreal.Engine.World*(u64)"),
+                ("GNames", TEST_NAME_POOL_OFFSET, "unreal.FNamePool"),
+            ]
+        );
+    }
+
+    #[test]
+    fn execute_reads_object_properties_from_older_engines() {
+        let services = TestSymbolTreeActionServices::new(TestProcessMemory::new().with_unreal_game(true));
+
+        PopulateUnrealSymbolsAction
+            .execute(&module_root_context(TEST_GAME_MODULE_NAME), &services)
+            .expect("Expected Unreal symbol population to succeed.");
+
+        let project_symbol_catalog = services
+            .project_symbol_store
+            .read_catalog()
+            .expect("Expected the test catalog to be readable.");
+        let actor_field_names = find_layout_fields(&project_symbol_catalog, "unreal.Engine.Actor")
+            .into_iter()
+            .map(|(field_name, _, _)| field_name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actor_field_names,
+            vec![
+                "VTable",
+                "ObjectFlags",
+                "InternalIndex",
+                "ClassPrivate",
+                "NamePrivate",
+                "OuterPrivate",
+                "Location",
+                "bHidden",
+                "bActive",
+            ]
+        );
+        assert!(!project_symbol_catalog.contains_struct_layout_id("unreal.Engine.ReceiveTick"));
+    }
+
+    #[test]
+    fn execute_reports_modules_without_engine_globals() {
+        let services = TestSymbolTreeActionServices::new(TestProcessMemory::new().with_module(TEST_GAME_MODULE_NAME, build_pe64_game_module()));
+        let error = PopulateUnrealSymbolsAction
+            .execute(&module_root_context(TEST_GAME_MODULE_NAME), &services)
+            .expect_err("Expected population without engine globals to fail.");
+
+        assert!(error.contains("4.20 through 5.x"));
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod unreal_engine_offsets;
+pub(crate) mod unreal_global_locator;
+pub(crate) mod unreal_memory_reader;
+pub(crate) mod unreal_name_resolver;
+pub(crate) mod unreal_object_walker;
@@ -0,0 +1,126 @@
+/// How the global name table is stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum UnrealNameTableKind {
+    /// `GNames` points at a `TNameEntryArray`: chunks of `FNameEntry*` indexed by name id (4.20 to 4.22).
+    EntryArray,
+    /// `NamePoolData` is an `FNamePool` whose blocks hold packed entries addressed by block and offset (4.23 and later).
+    NamePool,
+}
+
+/// How reflected properties are chained off a `UStruct`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum UnrealPropertyKind {
+    /// `UProperty` objects linked through `UField::Next`, starting at `UStruct::Children` (before 4.25).
+    Object,
+    /// `FProperty` fields linked through `FField::Next`, starting at `UStruct::ChildProperties` (4.25 and later).
+    Field,
+}
+
+/// Field offsets into engine structures. Shipping builds strip most of these types from their debug info and the
+/// layouts shift between engine releases, so each supported range of releases gets a preset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct UnrealEngineOffsets {
+    pub(crate) pointer_size: u64,
+    pub(crate) name_table_kind: UnrealNameTableKind,
+    pub(crate) name_pool_blocks: u64,
+    pub(crate) name_pool_max_blocks: u64,
+    pub(crate) name_pool_block_offset_bits: u32,
+    pub(crate) name_pool_entry_stride: u64,
+    pub(crate) name_pool_entry_header_size: u64,
+    pub(crate) name_pool_length_shift: u32,
+    pub(crate) name_array_chunk_size: u64,
+    pub(crate) name_array_entry_index: u64,
+    pub(crate) name_array_entry_string: u64,
+    pub(crate) name_array_max_length: u64,
+    pub(crate) object_array_objects: u64,
+    pub(crate) object_array_max_elements: u64,
+    pub(crate) object_array_num_elements: u64,
+    pub(crate) object_array_size: u64,
+    pub(crate) object_array_chunk_size: u64,
+    pub(crate) object_item_size: u64,
+    pub(crate) object_flags: u64,
+    pub(crate) object_internal_index: u64,
+    pub(crate) object_class: u64,
+    pub(crate) object_name: u64,
+    pub(crate) object_outer: u64,
+    pub(crate) object_header_size: u64,
+    pub(crate) struct_super: u64,
+    pub(crate) struct_first_property: u64,
+    pub(crate) struct_properties_size: u64,
+    pub(crate) property_kind: UnrealPropertyKind,
+    pub(crate) property_class: u64,
+    pub(crate) property_class_name: u64,
+    pub(crate) property_next: u64,
+    pub(crate) property_name: u64,
+    pub(crate) property_array_dim: u64,
+    pub(crate) property_element_size: u64,
+    pub(crate) property_offset: u64,
+    pub(crate) property_size: u64,
+}
+
+impl UnrealEngineOffsets {
+    /// Engine 4.25 through 5.x, for 64-bit shipping builds.
+    pub(crate) const UE4_25_X64: Self = Self {
+        pointer_size: 8,
+        name_table_kind: UnrealNameTableKind::NamePool,
+        name_pool_blocks: 0x10,
+        name_pool_max_blocks: 0x2000,
+        name_pool_block_offset_bits: 16,
+        name_pool_entry_stride: 2,
+        name_pool_entry_header_size: 2,
+        name_pool_length_shift: 6,
+        name_array_chunk_size: 0x4000,
+        name_array_entry_index: 0x00,
+        name_array_entry_string: 0x10,
+        name_array_max_length: 1024,
+        object_array_objects: 0x10,
+        object_array_max_elements: 0x20,
+        object_array_num_elements: 0x24,
+        object_array_size: 0x30,
+        object_array_chunk_size: 0x10000,
+        object_item_size: 0x18,
+        object_flags: 0x08,
+        object_internal_index: 0x0C,
+        object_class: 0x10,
+        object_name: 0x18,
+        object_outer: 0x20,
+        object_header_size: 0x28,
+        struct_super: 0x40,
+        struct_first_property: 0x50,
+        struct_properties_size: 0x58,
+        property_kind: UnrealPropertyKind::Field,
+        property_class: 0x08,
+        property_class_name: 0x00,
+        property_next: 0x20,
+        property_name: 0x28,
+        property_array_dim: 0x38,
+        property_element_size: 0x3C,
+        property_offset: 0x4C,
+        property_size: 0x78,
+    };
+
+    /// Engine 4.23 and 4.24, which introduced the name pool but still reflect properties as objects.
+    pub(crate) const UE4_23_X64: Self = Self {
+        struct_first_property: 0x48,
+        struct_properties_size: 0x50,
+        property_kind: UnrealPropertyKind::Object,
+        property_class: 0x10,
+        property_class_name: 0x18,
+        property_next: 0x28,
+        property_name: 0x18,
+        property_array_dim: 0x30,
+        property_element_size: 0x34,
+        property_offset: 0x44,
+        property_size: 0x70,
+        ..Self::UE4_25_X64
+    };
+
+    /// Engine 4.20 through 4.22, which keep names in a chunked entry array.
+    pub(crate) const UE4_20_X64: Self = Self {
+        name_table_kind: UnrealNameTableKind::EntryArray,
+        ..Self::UE4_23_X64
+    };
+
+    /// Presets tried in order until one reads the engine's own reflection data back correctly.
+    pub(crate) const PRESETS: [Self; 3] = [Self::UE4_25_X64, Self::UE4_23_X64, Self::UE4_20_X64];
+}
@@ -0,0 +1,273 @@
+use crate::runtime::unreal_memory_reader::UnrealMemoryReader;
+use squalr_engine_api::conversions::conversions_from_hex_pattern::ConversionsFromHexPattern;
+use std::collections::BTreeMap;
+
+const PE_HEADER_OFFSET_OFFSET: u64 = 0x3C;
+const PE_SECTION_COUNT_OFFSET: u64 = 6;
+const PE_OPTIONAL_HEADER_SIZE_OFFSET: u64 = 0x14;
+const PE_OPTIONAL_HEADER_OFFSET: u64 = 0x18;
+const PE_SECTION_HEADER_SIZE: u64 = 40;
+const PE_SECTION_EXECUTE: u32 = 0x2000_0000;
+const MAX_SECTION_COUNT: u64 = 96;
+const SCAN_CHUNK_SIZE: u64 = 0x10_0000;
+
+/// Engine globals that anchor the reflection walk, plus well-known roots exposed to the project.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum UnrealGlobal {
+    NamePool,
+    NameEntryArray,
+    ObjectArray,
+    World,
+}
+
+/// An x86-64 instruction sequence that references a global through a RIP-relative operand. The displacement is read at
+/// `displacement_offset` and applies from `instruction_end`. Some sequences reference a field inside the global rather
+/// than the global itself, which `target_field_offset` undoes.
+struct UnrealGlobalPattern {
+    global: UnrealGlobal,
+    pattern: &'static str,
+    displacement_offset: usize,
+    instruction_end: usize,
+    target_field_offset: u64,
+}
+
+const GLOBAL_PATTERNS: [UnrealGlobalPattern; 5] = [
+    // lea rcx, [NamePoolData]; call FNamePool::FNamePool; mov byte ptr [bNamePoolInitialized], 1
+    UnrealGlobalPattern {
+        global: UnrealGlobal::NamePool,
+        pattern: "48 8D 0D xx xx xx xx E8 xx xx xx xx C6 05 xx xx xx xx 01",
+        displacement_offset: 3,
+        instruction_end: 7,
+        target_field_offset: 0,
+    },
+    // mov rax, [GNames]; test rax, rax; jnz; mov ecx, sizeof(TNameEntryArray)
+    UnrealGlobalPattern {
+        global: UnrealGlobal::NameEntryArray,
+        pattern: "48 8B 05 xx xx xx xx 48 85 C0 75 xx B9 08 04 00 00",
+        displacement_offset: 3,
+        instruction_end: 7,
+        target_field_offset: 0,
+    },
+    // mov rax, [GUObjectArray.ObjObjects.Objects]; mov rcx, [rax + rcx * 8]; lea rax, [rcx + rdx * 8]
+    UnrealGlobalPattern {
+        global: UnrealGlobal::ObjectArray,
+        pattern: "48 8B 05 xx xx xx xx 48 8B 0C C8 xx 8D 04 D1",
+        displacement_offset: 3,
+        instruction_end: 7,
+        target_field_offset: 0x10,
+    },
+    // mov rcx, [GUObjectArray.ObjObjects.Objects]; cdqe; mov r8, [rcx + rdx * 8]; lea rcx, [rax + rax * 2]
+    UnrealGlobalPattern {
+        global: UnrealGlobal::ObjectArray,
+        pattern: "48 8B 0D xx xx xx xx 48 98 4C 8B 04 D1 48 8D 0C 40",
+        displacement_offset: 3,
+        instruction_end: 7,
+        target_field_offset: 0x10,
+    },
+    // mov rbx, [GWorld]; test rbx, rbx; jz; mov r8b, 1
+    UnrealGlobalPattern {
+        global: UnrealGlobal::World,
+        pattern: "48 8B 1D xx xx xx xx 48 85 DB 74 xx 41 B0 01",
+        displacement_offset: 3,
+        instruction_end: 7,
+        target_field_offset: 0,
+    },
+];
+
+/// Module offsets of every match for each global, in the order they were found. Patterns can hit unrelated code, so
+/// callers validate candidates against the engine data they point at.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct UnrealGlobalCandidates {
+    offsets_by_global: BTreeMap<UnrealGlobal, Vec<u64>>,
+}
+
+impl UnrealGlobalCandidates {
+    pub(crate) fn get(
+        &self,
+        global: UnrealGlobal,
+    ) -> &[u64] {
+        self.offsets_by_global
+            .get(&global)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn push(
+        &mut self,
+        global: UnrealGlobal,
+        global_offset: u64,
+    ) {
+        let global_offsets = self.offsets_by_global.entry(global).or_default();
+
+        if !global_offsets.contains(&global_offset) {
+            global_offsets.push(global_offset);
+        }
+    }
+}
+
+/// Scans the executable ranges of the game module for references to engine globals.
+pub(crate) fn locate_global_candidates(
+    memory_reader: &UnrealMemoryReader,
+    module_name: &str,
+) -> Result<UnrealGlobalCandidates, String> {
+    let executable_ranges = read_executable_ranges(memory_reader, module_name)?;
+    let parsed_patterns = GLOBAL_PATTERNS
+        .iter()
+        .map(|global_pattern| {
+            ConversionsFromHexPattern::parse(global_pattern.pattern).map(|(pattern_bytes, mask_bytes)| (global_pattern, pattern_bytes, mask_bytes))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let chunk_overlap = parsed_patterns
+        .iter()
+        .map(|(_, pattern_bytes, _)| pattern_bytes.len() as u64)
+        .max()
+        .unwrap_or(1)
+        - 1;
+    let mut global_candidates = UnrealGlobalCandidates::default();
+
+    for (range_start, range_end) in executable_ranges {
+        let mut chunk_start = range_start;
+
+        while chunk_start < range_end {
+            let chunk_end = chunk_start
+                .saturating_add(SCAN_CHUNK_SIZE + chunk_overlap)
+                .min(range_end);
+            let chunk_bytes = memory_reader.read_module_bytes(module_name, chunk_start, chunk_end - chunk_start)?;
+
+            for (global_pattern, pattern_bytes, mask_bytes) in &parsed_patterns {
+                for match_index in find_masked_matches(&chunk_bytes, pattern_bytes, mask_bytes) {
+                    if let Some(global_offset) = decode_global_offset(global_pattern, &chunk_bytes, match_index, chunk_start) {
+                        global_candidates.push(global_pattern.global, global_offset);
+                    }
+                }
+            }
+
+            chunk_start = chunk_start.saturating_add(SCAN_CHUNK_SIZE);
+        }
+    }
+
+    Ok(global_candidates)
+}
+
+fn find_masked_matches<'a>(
+    haystack: &'a [u8],
+    pattern_bytes: &'a [u8],
+    mask_bytes: &'a [u8],
+) -> impl Iterator<Item = usize> + 'a {
+    haystack
+        .windows(pattern_bytes.len())
+        .enumerate()
+        .filter(move |(_, window)| {
+            window
+                .iter()
+                .zip(pattern_bytes.iter().zip(mask_bytes))
+                .all(|(byte, (pattern_byte, mask_byte))| byte & mask_byte == pattern_byte & mask_byte)
+        })
+        .map(|(match_index, _)| match_index)
+}
+
+fn decode_global_offset(
+    global_pattern: &UnrealGlobalPattern,
+    chunk_bytes: &[u8],
+    match_index: usize,
+    chunk_start: u64,
+) -> Option<u64> {
+    let displacement_start = match_index + global_pattern.displacement_offset;
+    let displacement = i32::from_le_bytes(
+        chunk_bytes
+            .get(displacement_start..displacement_start + 4)?
+            .try_into()
+            .ok()?,
+    ) as i64;
+
+    chunk_start
+        .checked_add((match_index + global_pattern.instruction_end) as u64)?
+        .checked_add_signed(displacement)?
+        .checked_sub(global_pattern.target_field_offset)
+}
+
+/// Returns `[start, end)` module offset ranges of the executable sections.
+fn read_executable_ranges(
+    memory_reader: &UnrealMemoryReader,
+    module_name: &str,
+) -> Result<Vec<(u64, u64)>, String> {
+    let header_bytes = memory_reader.read_module_bytes(module_name, 0, 4)?;
+
+    if !header_bytes.starts_with(b"MZ") {
+        return Err(format!("Module `{module_name}` is not a PE image. Only Windows builds are supported."));
+    }
+
+    read_pe_executable_ranges(memory_reader, module_name)
+}
+
+fn read_pe_executable_ranges(
+    memory_reader: &UnrealMemoryReader,
+    module_name: &str,
+) -> Result<Vec<(u64, u64)>, String> {
+    let pe_header_offset = memory_reader.read_module_u32(module_name, PE_HEADER_OFFSET_OFFSET)? as u64;
+    let section_count = read_module_u16(memory_reader, module_name, pe_header_offset + PE_SECTION_COUNT_OFFSET)? as u64;
+    let optional_header_size = read_module_u16(memory_reader, module_name, pe_header_offset + PE_OPTIONAL_HEADER_SIZE_OFFSET)? as u64;
+
+    if section_count > MAX_SECTION_COUNT {
+        return Err(format!("Module `{module_name}` declares an implausible section count of {section_count}."));
+    }
+
+    let section_table_offset = pe_header_offset + PE_OPTIONAL_HEADER_OFFSET + optional_header_size;
+    let section_table = memory_reader.read_module_bytes(module_name, section_table_offset, section_count * PE_SECTION_HEADER_SIZE)?;
+
+    Ok(section_table
+        .chunks_exact(PE_SECTION_HEADER_SIZE as usize)
+        .filter_map(|section_header| {
+            let virtual_size = u32::from_le_bytes(section_header[8..12].try_into().ok()?) as u64;
+            let virtual_address = u32::from_le_bytes(section_header[12..16].try_into().ok()?) as u64;
+            let characteristics = u32::from_le_bytes(section_header[36..40].try_into().ok()?);
+
+            (characteristics & PE_SECTION_EXECUTE != 0 && virtual_size > 0).then_some((virtual_address, virtual_address + virtual_size))
+        })
+        .collect())
+}
+
+fn read_module_u16(
+    memory_reader: &UnrealMemoryReader,
+    module_name: &str,
+    offset: u64,
+) -> Result<u16, String> {
+    let bytes = memory_reader.read_module_bytes(module_name, offset, 2)?;
+
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UnrealGlobal, find_masked_matches, locate_global_candidates};
+    use crate::{
+        runtime::unreal_memory_reader::UnrealMemoryReader,
+        test_process_memory::{TEST_GAME_MODULE_NAME, TEST_NAME_POOL_OFFSET, TEST_OBJECT_ARRAY_OFFSET, TEST_WORLD_OFFSET, TestProcessMemory},
+    };
+
+    #[test]
+    fn find_masked_matches_honors_wildcards() {
+        let haystack = [0x48, 0x8B, 0x05, 0x11, 0x48, 0x8B, 0x06, 0x22];
+
+        assert_eq!(
+            find_masked_matches(&haystack, &[0x48, 0x8B, 0x05, 0x00], &[0xFF, 0xFF, 0xFF, 0x00]).collect::<Vec<_>>(),
+            vec![0]
+        );
+        assert_eq!(
+            find_masked_matches(&haystack, &[0x48, 0x8B, 0x00], &[0xFF, 0xFF, 0xF0]).collect::<Vec<_>>(),
+            vec![0, 4]
+        );
+    }
+
+    #[test]
+    fn locate_global_candidates_decodes_rip_relative_references() {
+        let test_process_memory = TestProcessMemory::new().with_unreal_game(false);
+        let memory_reader = UnrealMemoryReader::new(&test_process_memory);
+        let global_candidates = locate_global_candidates(&memory_reader, TEST_GAME_MODULE_NAME).expect("Expected the module to be scanned.");
+
+        assert_eq!(global_candidates.get(UnrealGlobal::NamePool), &[TEST_NAME_POOL_OFFSET]);
+        assert_eq!(global_candidates.get(UnrealGlobal::ObjectArray), &[TEST_OBJECT_ARRAY_OFFSET]);
+        assert_eq!(global_candidates.get(UnrealGlobal::World), &[TEST_WORLD_OFFSET]);
+        assert!(global_candidates.get(UnrealGlobal::NameEntryArray).is_empty());
+    }
+}
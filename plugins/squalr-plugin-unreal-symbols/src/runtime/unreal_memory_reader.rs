@@ -0,0 +1,185 @@
+use squalr_engine_api::plugins::symbol_tree::symbol_tree_action::ProcessMemoryStore;
+
+const ABSOLUTE_ADDRESS_MODULE_NAME: &str = "";
+const NAME_READ_CHUNK_SIZE: u64 = 64;
+const NAME_READ_PAGE_SIZE: u64 = 0x1000;
+
+/// Reads engine structures through `ProcessMemoryStore`. Engine globals are read relative to the game module, while
+/// everything they point at is read by absolute address.
+pub(crate) struct UnrealMemoryReader<'a> {
+    process_memory_store: &'a dyn ProcessMemoryStore,
+}
+
+impl<'a> UnrealMemoryReader<'a> {
+    pub(crate) fn new(process_memory_store: &'a dyn ProcessMemoryStore) -> Self {
+        Self { process_memory_store }
+    }
+
+    pub(crate) fn read_module_bytes(
+        &self,
+        module_name: &str,
+        offset: u64,
+        length: u64,
+    ) -> Result<Vec<u8>, String> {
+        let bytes = self
+            .process_memory_store
+            .read_module_bytes(module_name, offset, length)?;
+
+        if bytes.len() as u64 != length {
+            return Err(format!("Short read of {length} bytes at `{module_name}`+0x{offset:X}."));
+        }
+
+        Ok(bytes)
+    }
+
+    pub(crate) fn read_module_u32(
+        &self,
+        module_name: &str,
+        offset: u64,
+    ) -> Result<u32, String> {
+        read_le_u32(&self.read_module_bytes(module_name, offset, 4)?)
+    }
+
+    pub(crate) fn read_module_pointer(
+        &self,
+        module_name: &str,
+        offset: u64,
+    ) -> Result<u64, String> {
+        read_le_u64(&self.read_module_bytes(module_name, offset, 8)?)
+    }
+
+    pub(crate) fn read_bytes(
+        &self,
+        address: u64,
+        length: u64,
+    ) -> Result<Vec<u8>, String> {
+        self.read_module_bytes(ABSOLUTE_ADDRESS_MODULE_NAME, address, length)
+            .map_err(|_| format!("Could not read {length} bytes at 0x{address:X}."))
+    }
+
+    pub(crate) fn read_u8(
+        &self,
+        address: u64,
+    ) -> Result<u8, String> {
+        Ok(self.read_bytes(address, 1)?[0])
+    }
+
+    pub(crate) fn read_u16(
+        &self,
+        address: u64,
+    ) -> Result<u16, String> {
+        let bytes = self.read_bytes(address, 2)?;
+
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub(crate) fn read_u32(
+        &self,
+        address: u64,
+    ) -> Result<u32, String> {
+        read_le_u32(&self.read_bytes(address, 4)?)
+    }
+
+    pub(crate) fn read_i32(
+        &self,
+        address: u64,
+    ) -> Result<i32, String> {
+        Ok(self.read_u32(address)? as i32)
+    }
+
+    pub(crate) fn read_pointer(
+        &self,
+        address: u64,
+    ) -> Result<u64, String> {
+        read_le_u64(&self.read_bytes(address, 8)?)
+    }
+
+    /// Reads a name of known length, stored as Latin-1 or UTF-16 characters.
+    pub(crate) fn read_name_characters(
+        &self,
+        address: u64,
+        character_count: u64,
+        is_wide: bool,
+    ) -> Result<String, String> {
+        if !is_wide {
+            let name_bytes = self.read_bytes(address, character_count)?;
+
+            return Ok(name_bytes
+                .iter()
+                .map(|name_byte| char::from(*name_byte))
+                .collect());
+        }
+
+        let name_bytes = self.read_bytes(address, character_count * 2)?;
+        let name_units = name_bytes
+            .chunks_exact(2)
+            .map(|unit_bytes| u16::from_le_bytes([unit_bytes[0], unit_bytes[1]]))
+            .collect::<Vec<_>>();
+
+        Ok(String::from_utf16_lossy(&name_units))
+    }
+
+    /// Reads a null-terminated name of at most `maximum_length` Latin-1 or UTF-16 characters. Reads never cross a page
+    /// boundary, so a name that ends right before unmapped memory still resolves.
+    pub(crate) fn read_terminated_name(
+        &self,
+        address: u64,
+        maximum_length: u64,
+        is_wide: bool,
+    ) -> Result<String, String> {
+        let character_size = if is_wide { 2 } else { 1 };
+        let mut name_units = Vec::new();
+        let mut read_address = address;
+
+        while (name_units.len() as u64) < maximum_length {
+            let bytes_to_page_end = NAME_READ_PAGE_SIZE - (read_address % NAME_READ_PAGE_SIZE);
+            let chunk_length = (NAME_READ_CHUNK_SIZE.min(bytes_to_page_end) / character_size)
+                .max(1)
+                .min(maximum_length - name_units.len() as u64);
+            let chunk_bytes = self.read_bytes(read_address, chunk_length * character_size)?;
+            let chunk_units = chunk_bytes
+                .chunks_exact(character_size as usize)
+                .map(|unit_bytes| match unit_bytes {
+                    [low_byte, high_byte] => u16::from_le_bytes([*low_byte, *high_byte]),
+                    _ => unit_bytes[0] as u16,
+                });
+
+            for name_unit in chunk_units {
+                if name_unit == 0 {
+                    return Ok(if is_wide {
+                        String::from_utf16_lossy(&name_units)
+                    } else {
+                        name_units
+                            .iter()
+                            .map(|name_unit| char::from(*name_unit as u8))
+                            .collect()
+                    });
+                }
+
+                name_units.push(name_unit);
+            }
+
+            read_address = read_address
+                .checked_add(chunk_length * character_size)
+                .ok_or_else(|| format!("Name at 0x{address:X} runs past the end of the address space."))?;
+        }
+
+        Err(format!("Name at 0x{address:X} is not terminated within {maximum_length} characters."))
+    }
+}
+
+fn read_le_u32(bytes: &[u8]) -> Result<u32, String> {
+    bytes
+        .get(..4)
+        .and_then(|value_bytes| value_bytes.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or_else(|| String::from("Unexpected end of engine data."))
+}
+
+fn read_le_u64(bytes: &[u8]) -> Result<u64, String> {
+    bytes
+        .get(..8)
+        .and_then(|value_bytes| value_bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or_else(|| String::from("Unexpected end of engine data."))
+}
@@ -0,0 +1,223 @@
+use crate::runtime::{
+    unreal_engine_offsets::{UnrealEngineOffsets, UnrealNameTableKind},
+    unreal_memory_reader::UnrealMemoryReader,
+};
+use std::{cell::RefCell, collections::HashMap};
+
+const NAME_POOL_WIDE_FLAG: u16 = 0x1;
+const NAME_ARRAY_WIDE_FLAG: i32 = 0x1;
+
+/// Resolves `FName` values to strings through the global name table. Entries never move once allocated, so resolved
+/// names are cached for the lifetime of the resolver.
+pub(crate) struct UnrealNameResolver<'a> {
+    memory_reader: &'a UnrealMemoryReader<'a>,
+    offsets: UnrealEngineOffsets,
+    module_name: &'a str,
+    names_global_offset: u64,
+    table_addresses: RefCell<HashMap<u64, u64>>,
+    names_by_comparison_index: RefCell<HashMap<u32, String>>,
+}
+
+impl<'a> UnrealNameResolver<'a> {
+    /// `names_global_offset` is the module offset of `NamePoolData` for name pools, or of the `GNames` pointer for entry
+    /// arrays.
+    pub(crate) fn new(
+        memory_reader: &'a UnrealMemoryReader<'a>,
+        offsets: UnrealEngineOffsets,
+        module_name: &'a str,
+        names_global_offset: u64,
+    ) -> Self {
+        Self {
+            memory_reader,
+            offsets,
+            module_name,
+            names_global_offset,
+            table_addresses: RefCell::new(HashMap::new()),
+            names_by_comparison_index: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Reads the `FName` at `name_address`. Non-zero instance numbers are appended the way the engine prints them.
+    pub(crate) fn read_name(
+        &self,
+        name_address: u64,
+    ) -> Result<String, String> {
+        let comparison_index = self.memory_reader.read_u32(name_address)?;
+        let number = self.memory_reader.read_u32(name_address + 4)?;
+        let name = self.resolve_comparison_index(comparison_index)?;
+
+        if number == 0 { Ok(name) } else { Ok(format!("{name}_{}", number - 1)) }
+    }
+
+    pub(crate) fn resolve_comparison_index(
+        &self,
+        comparison_index: u32,
+    ) -> Result<String, String> {
+        if let Some(name) = self.names_by_comparison_index.borrow().get(&comparison_index) {
+            return Ok(name.clone());
+        }
+
+        let name = match self.offsets.name_table_kind {
+            UnrealNameTableKind::NamePool => self.read_name_pool_entry(comparison_index)?,
+            UnrealNameTableKind::EntryArray => self.read_name_array_entry(comparison_index)?,
+        };
+
+        self.names_by_comparison_index
+            .borrow_mut()
+            .insert(comparison_index, name.clone());
+
+        Ok(name)
+    }
+
+    fn read_name_pool_entry(
+        &self,
+        comparison_index: u32,
+    ) -> Result<String, String> {
+        let block_index = (comparison_index >> self.offsets.name_pool_block_offset_bits) as u64;
+        let block_offset = (comparison_index & ((1 << self.offsets.name_pool_block_offset_bits) - 1)) as u64;
+
+        if block_index >= self.offsets.name_pool_max_blocks {
+            return Err(format!("Name id {comparison_index} lies outside of the name pool."));
+        }
+
+        let block_address = self.read_table_address(block_index, || {
+            self.memory_reader.read_module_pointer(
+                self.module_name,
+                self.names_global_offset + self.offsets.name_pool_blocks + block_index * self.offsets.pointer_size,
+            )
+        })?;
+        let entry_address = block_address + block_offset * self.offsets.name_pool_entry_stride;
+        let entry_header = self.memory_reader.read_u16(entry_address)?;
+        let name_length = (entry_header >> self.offsets.name_pool_length_shift) as u64;
+
+        if name_length == 0 {
+            return Err(format!("Name id {comparison_index} has an empty name pool entry."));
+        }
+
+        self.memory_reader.read_name_characters(
+            entry_address + self.offsets.name_pool_entry_header_size,
+            name_length,
+            entry_header & NAME_POOL_WIDE_FLAG != 0,
+        )
+    }
+
+    fn read_name_array_entry(
+        &self,
+        comparison_index: u32,
+    ) -> Result<String, String> {
+        let chunk_index = comparison_index as u64 / self.offsets.name_array_chunk_size;
+        let chunk_slot = comparison_index as u64 % self.offsets.name_array_chunk_size;
+        let chunk_address = self.read_table_address(chunk_index, || {
+            let name_array_address = self
+                .memory_reader
+                .read_module_pointer(self.module_name, self.names_global_offset)?;
+
+            self.memory_reader
+                .read_pointer(name_array_address + chunk_index * self.offsets.pointer_size)
+        })?;
+        let entry_address = self
+            .memory_reader
+            .read_pointer(chunk_address + chunk_slot * self.offsets.pointer_size)?;
+
+        if entry_address == 0 {
+            return Err(format!("Name id {comparison_index} has no name entry."));
+        }
+
+        let entry_index = self
+            .memory_reader
+            .read_i32(entry_address + self.offsets.name_array_entry_index)?;
+
+        self.memory_reader.read_terminated_name(
+            entry_address + self.offsets.name_array_entry_string,
+            self.offsets.name_array_max_length,
+            entry_index & NAME_ARRAY_WIDE_FLAG != 0,
+        )
+    }
+
+    /// Name pool blocks and entry array chunks are allocated once and never freed, so their addresses are cached.
+    fn read_table_address(
+        &self,
+        table_index: u64,
+        read_address: impl FnOnce() -> Result<u64, String>,
+    ) -> Result<u64, String> {
+        if let Some(table_address) = self.table_addresses.borrow().get(&table_index) {
+            return Ok(*table_address);
+        }
+
+        let table_address = read_address()?;
+
+        if table_address == 0 {
+            return Err(format!("Name table chunk {table_index} has not been allocated."));
+        }
+
+        self.table_addresses
+            .borrow_mut()
+            .insert(table_index, table_address);
+
+        Ok(table_address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnrealNameResolver;
+    use crate::{
+        runtime::{unreal_engine_offsets::UnrealEngineOffsets, unreal_memory_reader::UnrealMemoryReader},
+        test_process_memory::{TEST_GAME_MODULE_NAME, TEST_NAME_BLOCK_ADDRESS, TestProcessMemory},
+    };
+
+    const NAME_ADDRESS: u64 = 0x30000;
+
+    fn encode_wide(name: &str) -> Vec<u8> {
+        name.encode_utf16()
+            .flat_map(|name_unit| name_unit.to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn read_name_resolves_name_pool_entries_and_instance_numbers() {
+        let mut module_bytes = vec![0_u8; 0x100];
+
+        module_bytes[0x10..0x18].copy_from_slice(&TEST_NAME_BLOCK_ADDRESS.to_le_bytes());
+
+        let mut test_process_memory = TestProcessMemory::new().with_module(TEST_GAME_MODULE_NAME, module_bytes);
+        let pawn_name_id = test_process_memory.write_name_pool_entry("Pawn");
+        let wide_name_id = 0x400_u32;
+
+        test_process_memory.write_bytes(TEST_NAME_BLOCK_ADDRESS + wide_name_id as u64 * 2, &((5_u16 << 6) | 1).to_le_bytes());
+        test_process_memory.write_bytes(TEST_NAME_BLOCK_ADDRESS + wide_name_id as u64 * 2 + 2, &encode_wide("Größe"));
+        test_process_memory.write_bytes(NAME_ADDRESS, &pawn_name_id.to_le_bytes());
+        test_process_memory.write_bytes(NAME_ADDRESS + 4, &3_u32.to_le_bytes());
+
+        let memory_reader = UnrealMemoryReader::new(&test_process_memory);
+        let name_resolver = UnrealNameResolver::new(&memory_reader, UnrealEngineOffsets::UE4_25_X64, TEST_GAME_MODULE_NAME, 0);
+
+        assert_eq!(name_resolver.read_name(NAME_ADDRESS), Ok(String::from("Pawn_2")));
+        assert_eq!(name_resolver.resolve_comparison_index(wide_name_id), Ok(String::from("Größe")));
+        assert!(name_resolver.resolve_comparison_index(1 << 16).is_err());
+    }
+
+    #[test]
+    fn resolve_comparison_index_reads_entry_array_chunks() {
+        let mut module_bytes = vec![0_u8; 0x100];
+
+        module_bytes[0x40..0x48].copy_from_slice(&0x50000_u64.to_le_bytes());
+
+        let mut test_process_memory = TestProcessMemory::new().with_module(TEST_GAME_MODULE_NAME, module_bytes);
+
+        test_process_memory.write_u64(0x50000, 0x51000);
+        test_process_memory.write_u64(0x51000 + 8, 0x52000);
+        test_process_memory.write_u64(0x51000 + 16, 0x52100);
+        test_process_memory.write_bytes(0x52000, &2_i32.to_le_bytes());
+        test_process_memory.write_bytes(0x52010, b"Actor\0");
+        test_process_memory.write_bytes(0x52100, &5_i32.to_le_bytes());
+        test_process_memory.write_bytes(0x52110, &encode_wide("Größe\0"));
+
+        let memory_reader = UnrealMemoryReader::new(&test_process_memory);
+        let name_resolver = UnrealNameResolver::new(&memory_reader, UnrealEngineOffsets::UE4_20_X64, TEST_GAME_MODULE_NAME, 0x40);
+
+        assert_eq!(name_resolver.resolve_comparison_index(1), Ok(String::from("Actor")));
+        assert_eq!(name_resolver.resolve_comparison_index(2), Ok(String::from("Größe")));
+        assert!(name_resolver.resolve_comparison_index(3).is_err());
+    }
+}
@@ -0,0 +1,464 @@
+use crate::runtime::{unreal_engine_offsets::UnrealEngineOffsets, unreal_memory_reader::UnrealMemoryReader, unreal_name_resolver::UnrealNameResolver};
+use std::{cell::RefCell, collections::HashMap};
+
+const CORE_PACKAGE_NAME: &str = "/Script/CoreUObject";
+const VECTOR_STRUCT_NAME: &str = "Vector";
+const VECTOR_COMPONENT_NAMES: [&str; 3] = ["X", "Y", "Z"];
+const PROPERTY_TYPE_NAME_SUFFIX: &str = "Property";
+const BOOL_PROPERTY_TYPE_NAME: &str = "BoolProperty";
+const NATIVE_BOOL_FIELD_MASK: u8 = 0xFF;
+const VALIDATION_OBJECT_COUNT: u64 = 0x1000;
+const MAX_OBJECT_COUNT: u64 = 0x1000_0000;
+const MAX_OUTER_DEPTH: usize = 64;
+const MAX_SUPER_DEPTH: usize = 64;
+const MAX_PROPERTY_COUNT: usize = 0x4000;
+
+/// Property types whose extra data starts with the `UClass*` or `UScriptStruct*` they refer to.
+const STRUCT_REFERENCE_PROPERTY_TYPE_NAMES: [&str; 5] = [
+    "ObjectProperty",
+    "ObjectPtrProperty",
+    "ClassProperty",
+    "ClassPtrProperty",
+    "StructProperty",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct UnrealPropertyInfo {
+    pub(crate) name: String,
+    pub(crate) type_name: String,
+    pub(crate) offset: u64,
+    pub(crate) element_size: u64,
+    pub(crate) array_dim: u64,
+    pub(crate) referenced_struct_address: Option<u64>,
+    /// Bitfield bools are narrowed to the byte that holds their bit.
+    pub(crate) is_bitfield: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct UnrealStructInfo {
+    pub(crate) address: u64,
+    pub(crate) package_name: String,
+    pub(crate) name: String,
+    pub(crate) is_class: bool,
+    pub(crate) super_address: Option<u64>,
+    pub(crate) properties_size: u64,
+    pub(crate) properties: Vec<UnrealPropertyInfo>,
+}
+
+impl UnrealStructInfo {
+    /// Script packages drop their `/Script/` root, so `/Script/Engine.Actor` reads as `Engine.Actor`.
+    pub(crate) fn get_qualified_name(&self) -> String {
+        let package_name = self
+            .package_name
+            .strip_prefix("/Script/")
+            .unwrap_or(&self.package_name);
+        let package_components = package_name
+            .split('/')
+            .filter(|package_component| !package_component.is_empty())
+            .collect::<Vec<_>>();
+
+        if package_components.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", package_components.join("."), self.name)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum UnrealObjectKind {
+    Class,
+    Struct,
+    Function,
+    Other,
+}
+
+/// Walks `GUObjectArray` and reads the reflection data of every class and script struct.
+pub(crate) struct UnrealObjectWalker<'a> {
+    memory_reader: &'a UnrealMemoryReader<'a>,
+    name_resolver: &'a UnrealNameResolver<'a>,
+    offsets: UnrealEngineOffsets,
+    module_name: &'a str,
+    object_array_offset: u64,
+    kinds_by_class_address: RefCell<HashMap<u64, UnrealObjectKind>>,
+}
+
+impl<'a> UnrealObjectWalker<'a> {
+    pub(crate) fn new(
+        memory_reader: &'a UnrealMemoryReader<'a>,
+        name_resolver: &'a UnrealNameResolver<'a>,
+        offsets: UnrealEngineOffsets,
+        module_name: &'a str,
+        object_array_offset: u64,
+    ) -> Self {
+        Self {
+            memory_reader,
+            name_resolver,
+            offsets,
+            module_name,
+            object_array_offset,
+            kinds_by_class_address: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Checks that the name table and object array were located correctly and that the offsets match the running engine.
+    /// The first object is always the core package, and `FVector` always reflects `X`, `Y` and `Z` in order, as floats
+    /// before 5.0 and doubles after.
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        let object_addresses = self.read_object_addresses(VALIDATION_OBJECT_COUNT)?;
+        let first_object_name = self.read_object_name(*object_addresses.first().unwrap_or(&0))?;
+
+        if first_object_name != CORE_PACKAGE_NAME {
+            return Err(format!("Expected the first object to be `{CORE_PACKAGE_NAME}`, found `{first_object_name}`."));
+        }
+
+        let vector_struct_address = object_addresses
+            .iter()
+            .copied()
+            .filter(|object_address| *object_address != 0)
+            .find(|object_address| {
+                self.read_object_name(*object_address).as_deref() == Ok(VECTOR_STRUCT_NAME)
+                    && self.read_outermost_name(*object_address).as_deref() == Ok(CORE_PACKAGE_NAME)
+            })
+            .ok_or_else(|| String::from("Could not find the `Vector` script struct."))?;
+        let vector_properties = self.read_properties(vector_struct_address)?;
+        let component_size = vector_properties
+            .first()
+            .map(|vector_property| vector_property.element_size)
+            .unwrap_or(0);
+        let components_match = (component_size == 4 || component_size == 8)
+            && vector_properties.len() >= VECTOR_COMPONENT_NAMES.len()
+            && VECTOR_COMPONENT_NAMES
+                .iter()
+                .zip(&vector_properties)
+                .enumerate()
+                .all(|(component_index, (component_name, vector_property))| {
+                    vector_property.name == *component_name && vector_property.offset == component_index as u64 * component_size
+                });
+
+        if !components_match {
+            return Err(String::from("The `Vector` script struct does not reflect X, Y and Z with these offsets."));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn collect_structs(&self) -> Result<Vec<UnrealStructInfo>, String> {
+        let object_addresses = self.read_object_addresses(MAX_OBJECT_COUNT)?;
+
+        // Objects can be mid-construction or mid-destruction while the array is read, so unreadable ones are skipped.
+        Ok(object_addresses
+            .into_iter()
+            .filter(|object_address| *object_address != 0)
+            .filter_map(|object_address| match self.classify_object(object_address) {
+                UnrealObjectKind::Class => self.read_struct(object_address, true).ok(),
+                UnrealObjectKind::Struct => self.read_struct(object_address, false).ok(),
+                UnrealObjectKind::Function | UnrealObjectKind::Other => None,
+            })
+            .collect())
+    }
+
+    pub(crate) fn read_object_name(
+        &self,
+        object_address: u64,
+    ) -> Result<String, String> {
+        self.name_resolver
+            .read_name(object_address + self.offsets.object_name)
+    }
+
+    pub(crate) fn read_object_class_name(
+        &self,
+        object_address: u64,
+    ) -> Result<String, String> {
+        let class_address = self
+            .memory_reader
+            .read_pointer(object_address + self.offsets.object_class)?;
+
+        self.read_object_name(class_address)
+    }
+
+    /// Reads up to `maximum_count` object pointers, one chunk of `FUObjectItem`s at a time. Free slots read as zero.
+    fn read_object_addresses(
+        &self,
+        maximum_count: u64,
+    ) -> Result<Vec<u64>, String> {
+        let chunk_table_address = self
+            .memory_reader
+            .read_module_pointer(self.module_name, self.object_array_offset + self.offsets.object_array_objects)?;
+        let max_elements = self
+            .memory_reader
+            .read_module_u32(self.module_name, self.object_array_offset + self.offsets.object_array_max_elements)? as u64;
+        let num_elements = self
+            .memory_reader
+            .read_module_u32(self.module_name, self.object_array_offset + self.offsets.object_array_num_elements)? as u64;
+
+        if chunk_table_address == 0 || num_elements == 0 || num_elements > max_elements || num_elements > MAX_OBJECT_COUNT {
+            return Err(format!("The object array reports an implausible {num_elements} of {max_elements} objects."));
+        }
+
+        let object_count = num_elements.min(maximum_count);
+        let mut object_addresses = Vec::with_capacity(object_count as usize);
+
+        for chunk_index in 0..object_count.div_ceil(self.offsets.object_array_chunk_size) {
+            let chunk_address = self
+                .memory_reader
+                .read_pointer(chunk_table_address + chunk_index * self.offsets.pointer_size)?;
+            let chunk_item_count = (object_count - chunk_index * self.offsets.object_array_chunk_size).min(self.offsets.object_array_chunk_size);
+            let chunk_bytes = self
+                .memory_reader
+                .read_bytes(chunk_address, chunk_item_count * self.offsets.object_item_size)?;
+
+            object_addresses.extend(
+                chunk_bytes
+                    .chunks_exact(self.offsets.object_item_size as usize)
+                    .map(|item_bytes| u64::from_le_bytes(item_bytes[..8].try_into().unwrap_or_default())),
+            );
+        }
+
+        Ok(object_addresses)
+    }
+
+    fn read_outermost_name(
+        &self,
+        object_address: u64,
+    ) -> Result<String, String> {
+        let mut outermost_address = object_address;
+
+        for _ in 0..MAX_OUTER_DEPTH {
+            let outer_address = self
+                .memory_reader
+                .read_pointer(outermost_address + self.offsets.object_outer)?;
+
+            if outer_address == 0 {
+                return self.read_object_name(outermost_address);
+            }
+
+            outermost_address = outer_address;
+        }
+
+        Err(format!("Object 0x{object_address:X} has an outer chain deeper than {MAX_OUTER_DEPTH}."))
+    }
+
+    /// Classifies an object by the first reflection type found walking up from its class.
+    fn classify_object(
+        &self,
+        object_address: u64,
+    ) -> UnrealObjectKind {
+        let Ok(class_address) = self
+            .memory_reader
+            .read_pointer(object_address + self.offsets.object_class)
+        else {
+            return UnrealObjectKind::Other;
+        };
+
+        if let Some(object_kind) = self.kinds_by_class_address.borrow().get(&class_address) {
+            return *object_kind;
+        }
+
+        let mut object_kind = UnrealObjectKind::Other;
+        let mut struct_address = class_address;
+
+        for _ in 0..MAX_SUPER_DEPTH {
+            if struct_address == 0 {
+                break;
+            }
+
+            match self.read_object_name(struct_address).as_deref() {
+                Ok("Class") => object_kind = UnrealObjectKind::Class,
+                Ok("Function") => object_kind = UnrealObjectKind::Function,
+                Ok("Struct") => object_kind = UnrealObjectKind::Struct,
+                Ok(_) => {}
+                Err(_) => break,
+            }
+
+            if object_kind != UnrealObjectKind::Other {
+                break;
+            }
+
+            let Ok(super_address) = self
+                .memory_reader
+                .read_pointer(struct_address + self.offsets.struct_super)
+            else {
+                break;
+            };
+
+            struct_address = super_address;
+        }
+
+        self.kinds_by_class_address
+            .borrow_mut()
+            .insert(class_address, object_kind);
+
+        object_kind
+    }
+
+    fn read_struct(
+        &self,
+        struct_address: u64,
+        is_class: bool,
+    ) -> Result<UnrealStructInfo, String> {
+        let super_address = self
+            .memory_reader
+            .read_pointer(struct_address + self.offsets.struct_super)?;
+        let properties_size = self
+            .memory_reader
+            .read_i32(struct_address + self.offsets.struct_properties_size)?;
+
+        Ok(UnrealStructInfo {
+            address: struct_address,
+            package_name: self.read_outermost_name(struct_address)?,
+            name: self.read_object_name(struct_address)?,
+            is_class,
+            super_address: (super_address != 0).then_some(super_address),
+            properties_size: properties_size.max(0) as u64,
+            properties: self.read_properties(struct_address)?,
+        })
+    }
+
+    /// Reads the properties declared by this struct, excluding inherited ones. Before 4.25 the chain also holds
+    /// functions and other fields, which are told apart by their class name.
+    fn read_properties(
+        &self,
+        struct_address: u64,
+    ) -> Result<Vec<UnrealPropertyInfo>, String> {
+        let mut properties = Vec::new();
+        let mut property_address = self
+            .memory_reader
+            .read_pointer(struct_address + self.offsets.struct_first_property)?;
+
+        while property_address != 0 {
+            if properties.len() >= MAX_PROPERTY_COUNT {
+                return Err(format!("Struct 0x{struct_address:X} declares more than {MAX_PROPERTY_COUNT} properties."));
+            }
+
+            let property_class_address = self
+                .memory_reader
+                .read_pointer(property_address + self.offsets.property_class)?;
+            let type_name = self
+                .name_resolver
+                .read_name(property_class_address + self.offsets.property_class_name)?;
+
+            if type_name.ends_with(PROPERTY_TYPE_NAME_SUFFIX) {
+                properties.push(self.read_property(property_address, type_name)?);
+            }
+
+            property_address = self
+                .memory_reader
+                .read_pointer(property_address + self.offsets.property_next)?;
+        }
+
+        Ok(properties)
+    }
+
+    fn read_property(
+        &self,
+        property_address: u64,
+        type_name: String,
+    ) -> Result<UnrealPropertyInfo, String> {
+        let extra_data_address = property_address + self.offsets.property_size;
+        let mut property_info = UnrealPropertyInfo {
+            name: self
+                .name_resolver
+                .read_name(property_address + self.offsets.property_name)?,
+            type_name,
+            offset: self
+                .memory_reader
+                .read_i32(property_address + self.offsets.property_offset)?
+                .max(0) as u64,
+            element_size: self
+                .memory_reader
+                .read_i32(property_address + self.offsets.property_element_size)?
+                .max(0) as u64,
+            array_dim: self
+                .memory_reader
+                .read_i32(property_address + self.offsets.property_array_dim)?
+                .max(1) as u64,
+            referenced_struct_address: None,
+            is_bitfield: false,
+        };
+
+        if STRUCT_REFERENCE_PROPERTY_TYPE_NAMES.contains(&property_info.type_name.as_str()) {
+            let referenced_struct_address = self.memory_reader.read_pointer(extra_data_address)?;
+
+            property_info.referenced_struct_address = (referenced_struct_address != 0).then_some(referenced_struct_address);
+        } else if property_info.type_name == BOOL_PROPERTY_TYPE_NAME {
+            // FieldSize, ByteOffset, ByteMask and FieldMask. Native bools use a full field mask.
+            let byte_offset = self.memory_reader.read_u8(extra_data_address + 1)?;
+            let field_mask = self.memory_reader.read_u8(extra_data_address + 3)?;
+
+            if field_mask != NATIVE_BOOL_FIELD_MASK {
+                property_info.offset += byte_offset as u64;
+                property_info.element_size = 1;
+                property_info.is_bitfield = true;
+            }
+        }
+
+        Ok(property_info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnrealObjectWalker;
+    use crate::{
+        runtime::{unreal_engine_offsets::UnrealEngineOffsets, unreal_memory_reader::UnrealMemoryReader, unreal_name_resolver::UnrealNameResolver},
+        test_process_memory::{TEST_GAME_MODULE_NAME, TEST_NAME_POOL_OFFSET, TEST_OBJECT_ARRAY_OFFSET, TestProcessMemory},
+    };
+
+    #[test]
+    fn collect_structs_reads_classes_and_script_structs_only() {
+        let test_process_memory = TestProcessMemory::new().with_unreal_game(false);
+        let memory_reader = UnrealMemoryReader::new(&test_process_memory);
+        let offsets = UnrealEngineOffsets::UE4_25_X64;
+        let name_resolver = UnrealNameResolver::new(&memory_reader, offsets, TEST_GAME_MODULE_NAME, TEST_NAME_POOL_OFFSET);
+        let object_walker = UnrealObjectWalker::new(&memory_reader, &name_resolver, offsets, TEST_GAME_MODULE_NAME, TEST_OBJECT_ARRAY_OFFSET);
+
+        object_walker
+            .validate()
+            .expect("Expected the core types to validate.");
+
+        let structs = object_walker
+            .collect_structs()
+            .expect("Expected the object array to be walked.");
+        let qualified_names = structs
+            .iter()
+            .map(|struct_info| (struct_info.get_qualified_name(), struct_info.is_class))
+            .collect::<Vec<_>>();
+        let actor_struct = structs
+            .iter()
+            .find(|struct_info| struct_info.name == "Actor")
+            .expect("Expected the actor class.");
+
+        assert!(qualified_names.contains(&(String::from("CoreUObject.Vector"), false)));
+        assert!(qualified_names.contains(&(String::from("Engine.Pawn"), true)));
+        assert!(
+            !qualified_names
+                .iter()
+                .any(|(qualified_name, _)| qualified_name.ends_with("ReceiveTick") || qualified_name.ends_with("Main"))
+        );
+        assert_eq!(
+            actor_struct
+                .properties
+                .iter()
+                .map(|property| (property.name.as_str(), property.offset, property.is_bitfield))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Location", 0x28, false),
+                ("bHidden", 0x34, true),
+                ("bCanBeDamaged", 0x34, true),
+                ("bActive", 0x35, false)
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_rejects_offsets_from_another_engine_range() {
+        let test_process_memory = TestProcessMemory::new().with_unreal_game(true);
+        let memory_reader = UnrealMemoryReader::new(&test_process_memory);
+        let offsets = UnrealEngineOffsets::UE4_25_X64;
+        let name_resolver = UnrealNameResolver::new(&memory_reader, offsets, TEST_GAME_MODULE_NAME, TEST_NAME_POOL_OFFSET);
+        let object_walker = UnrealObjectWalker::new(&memory_reader, &name_resolver, offsets, TEST_GAME_MODULE_NAME, TEST_OBJECT_ARRAY_OFFSET);
+
+        assert!(object_walker.validate().is_err());
+    }
+}
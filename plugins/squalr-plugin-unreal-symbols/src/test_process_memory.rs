@@ -0,0 +1,480 @@
+use crate::runtime::unreal_engine_offsets::UnrealEngineOffsets;
+use squalr_engine_api::plugins::symbol_tree::symbol_tree_action::ProcessMemoryStore;
+use std::collections::{BTreeMap, HashMap};
+
+pub(crate) const TEST_GAME_MODULE_NAME: &str = "Game-Win64-Shipping.exe";
+pub(crate) const TEST_OBJECT_ARRAY_OFFSET: u64 = 0x2000;
+pub(crate) const TEST_WORLD_OFFSET: u64 = 0x2800;
+pub(crate) const TEST_NAME_POOL_OFFSET: u64 = 0x3000;
+pub(crate) const TEST_NAME_BLOCK_ADDRESS: u64 = 0x10000;
+
+const TEST_PAGE_SIZE: u64 = 0x1000;
+const TEST_MODULE_SIZE: usize = 0x4000;
+const SECTION_TABLE_OFFSET: u64 = 0x188;
+const OBJECT_CHUNK_TABLE_ADDRESS: u64 = 0x18000;
+const OBJECT_CHUNK_ADDRESS: u64 = 0x19000;
+const PROPERTY_CLASSES_ADDRESS: u64 = 0x20000;
+const PROPERTY_CLASS_STRIDE: u64 = 0x40;
+const OBJECTS_ADDRESS: u64 = 0x30000;
+const OBJECT_STRIDE: u64 = 0x100;
+const PROPERTIES_ADDRESS: u64 = 0x40000;
+const PROPERTY_STRIDE: u64 = 0x100;
+
+const CORE_PACKAGE_INDEX: u64 = 0;
+const OBJECT_CLASS_INDEX: u64 = 1;
+const FIELD_CLASS_INDEX: u64 = 2;
+const STRUCT_CLASS_INDEX: u64 = 3;
+const CLASS_CLASS_INDEX: u64 = 4;
+const SCRIPT_STRUCT_CLASS_INDEX: u64 = 5;
+const PACKAGE_CLASS_INDEX: u64 = 6;
+const FUNCTION_CLASS_INDEX: u64 = 7;
+const VECTOR_STRUCT_INDEX: u64 = 8;
+const ENGINE_PACKAGE_INDEX: u64 = 9;
+const ACTOR_CLASS_INDEX: u64 = 10;
+const PAWN_CLASS_INDEX: u64 = 11;
+const WORLD_CLASS_INDEX: u64 = 12;
+const MAP_PACKAGE_INDEX: u64 = 13;
+const WORLD_INSTANCE_INDEX: u64 = 14;
+const RECEIVE_TICK_FUNCTION_INDEX: u64 = 15;
+
+/// Extra data after a `BoolProperty`: field size, byte offset, byte mask and field mask.
+const BITFIELD_BOOL_EXTRA_DATA: u64 = u32::from_le_bytes([1, 0, 0x01, 0x01]) as u64;
+const SECOND_BITFIELD_BOOL_EXTRA_DATA: u64 = u32::from_le_bytes([1, 0, 0x02, 0x02]) as u64;
+const NATIVE_BOOL_EXTRA_DATA: u64 = u32::from_le_bytes([1, 0, 0xFF, 0xFF]) as u64;
+
+struct TestProperty {
+    type_name: &'static str,
+    name: &'static str,
+    offset: u32,
+    element_size: u32,
+    array_dim: u32,
+    extra_data: u64,
+}
+
+impl TestProperty {
+    fn new(
+        type_name: &'static str,
+        name: &'static str,
+        offset: u32,
+        element_size: u32,
+    ) -> Self {
+        Self {
+            type_name,
+            name,
+            offset,
+            element_size,
+            array_dim: 1,
+            extra_data: 0,
+        }
+    }
+
+    fn with_array_dim(
+        mut self,
+        array_dim: u32,
+    ) -> Self {
+        self.array_dim = array_dim;
+        self
+    }
+
+    fn with_extra_data(
+        mut self,
+        extra_data: u64,
+    ) -> Self {
+        self.extra_data = extra_data;
+        self
+    }
+}
+
+/// A sparse process image for exercising the reflection walker without a live Unreal process. Module reads address
+/// module images, and reads with an empty module name address zero-filled heap pages.
+#[derive(Default)]
+pub(crate) struct TestProcessMemory {
+    modules: HashMap<String, Vec<u8>>,
+    heap_pages: BTreeMap<u64, Vec<u8>>,
+    name_ids: HashMap<String, u32>,
+    next_name_block_offset: u64,
+    property_class_addresses: HashMap<String, u64>,
+    next_property_address: u64,
+}
+
+impl TestProcessMemory {
+    pub(crate) fn new() -> Self {
+        Self {
+            next_property_address: PROPERTIES_ADDRESS,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn with_module(
+        mut self,
+        module_name: &str,
+        module_bytes: Vec<u8>,
+    ) -> Self {
+        self.modules.insert(module_name.to_string(), module_bytes);
+        self
+    }
+
+    /// A PE64 game module whose code references the name pool, object array and world globals, backed by a reflection
+    /// heap for `Engine.Actor`, `Engine.Pawn` and `Engine.World`. Legacy properties reflect as `UProperty` objects the
+    /// way 4.23 and 4.24 do, with a function mixed into the property chain.
+    pub(crate) fn with_unreal_game(
+        mut self,
+        legacy_properties: bool,
+    ) -> Self {
+        let offsets = if legacy_properties {
+            UnrealEngineOffsets::UE4_23_X64
+        } else {
+            UnrealEngineOffsets::UE4_25_X64
+        };
+        let mut module_bytes = build_pe64_game_module();
+
+        self.write_name_pool_entry("None");
+        write_le(
+            &mut module_bytes,
+            TEST_NAME_POOL_OFFSET + offsets.name_pool_blocks,
+            &TEST_NAME_BLOCK_ADDRESS.to_le_bytes(),
+        );
+
+        self.write_object(offsets, CORE_PACKAGE_INDEX, PACKAGE_CLASS_INDEX, "/Script/CoreUObject", None);
+        for (class_index, class_name, super_index, properties_size) in [
+            (OBJECT_CLASS_INDEX, "Object", None, 0x28),
+            (FIELD_CLASS_INDEX, "Field", Some(OBJECT_CLASS_INDEX), 0x30),
+            (STRUCT_CLASS_INDEX, "Struct", Some(FIELD_CLASS_INDEX), 0xB0),
+            (CLASS_CLASS_INDEX, "Class", Some(STRUCT_CLASS_INDEX), 0x230),
+            (SCRIPT_STRUCT_CLASS_INDEX, "ScriptStruct", Some(STRUCT_CLASS_INDEX), 0xC0),
+            (PACKAGE_CLASS_INDEX, "Package", Some(OBJECT_CLASS_INDEX), 0x80),
+            (FUNCTION_CLASS_INDEX, "Function", Some(STRUCT_CLASS_INDEX), 0xE0),
+        ] {
+            self.write_object(offsets, class_index, CLASS_CLASS_INDEX, class_name, Some(CORE_PACKAGE_INDEX));
+            self.write_struct(offsets, class_index, super_index, properties_size, &[]);
+        }
+
+        self.write_object(offsets, VECTOR_STRUCT_INDEX, SCRIPT_STRUCT_CLASS_INDEX, "Vector", Some(CORE_PACKAGE_INDEX));
+        self.write_struct(
+            offsets,
+            VECTOR_STRUCT_INDEX,
+            None,
+            12,
+            &[
+                TestProperty::new("FloatProperty", "X", 0, 4),
+                TestProperty::new("FloatProperty", "Y", 4, 4),
+                TestProperty::new("FloatProperty", "Z", 8, 4),
+            ],
+        );
+
+        self.write_object(offsets, ENGINE_PACKAGE_INDEX, PACKAGE_CLASS_INDEX, "/Script/Engine", None);
+        self.write_object(offsets, ACTOR_CLASS_INDEX, CLASS_CLASS_INDEX, "Actor", Some(ENGINE_PACKAGE_INDEX));
+        self.write_struct(
+            offsets,
+            ACTOR_CLASS_INDEX,
+            Some(OBJECT_CLASS_INDEX),
+            0x38,
+            &[
+                TestProperty::new("StructProperty", "Location", 0x28, 12).with_extra_data(object_address(VECTOR_STRUCT_INDEX)),
+                TestProperty::new("BoolProperty", "bHidden", 0x34, 1).with_extra_data(BITFIELD_BOOL_EXTRA_DATA),
+                TestProperty::new("BoolProperty", "bCanBeDamaged", 0x34, 1).with_extra_data(SECOND_BITFIELD_BOOL_EXTRA_DATA),
+                TestProperty::new("BoolProperty", "bActive", 0x35, 1).with_extra_data(NATIVE_BOOL_EXTRA_DATA),
+            ],
+        );
+        self.write_object(
+            offsets,
+            RECEIVE_TICK_FUNCTION_INDEX,
+            FUNCTION_CLASS_INDEX,
+            "ReceiveTick",
+            Some(ACTOR_CLASS_INDEX),
+        );
+
+        // Before 4.25 functions share the `Children` chain with properties.
+        if legacy_properties {
+            let first_property_address = self.read_heap_u64(object_address(ACTOR_CLASS_INDEX) + offsets.struct_first_property);
+
+            self.write_u64(
+                object_address(ACTOR_CLASS_INDEX) + offsets.struct_first_property,
+                object_address(RECEIVE_TICK_FUNCTION_INDEX),
+            );
+            self.write_u64(object_address(RECEIVE_TICK_FUNCTION_INDEX) + offsets.property_next, first_property_address);
+        }
+
+        self.write_object(offsets, PAWN_CLASS_INDEX, CLASS_CLASS_INDEX, "Pawn", Some(ENGINE_PACKAGE_INDEX));
+        self.write_struct(
+            offsets,
+            PAWN_CLASS_INDEX,
+            Some(ACTOR_CLASS_INDEX),
+            0x58,
+            &[
+                TestProperty::new("FloatProperty", "Health", 0x38, 4),
+                TestProperty::new("ObjectProperty", "Owner", 0x40, 8).with_extra_data(object_address(ACTOR_CLASS_INDEX)),
+                TestProperty::new("IntProperty", "Ammo", 0x48, 4).with_array_dim(3),
+            ],
+        );
+
+        self.write_object(offsets, WORLD_CLASS_INDEX, CLASS_CLASS_INDEX, "World", Some(ENGINE_PACKAGE_INDEX));
+        self.write_struct(offsets, WORLD_CLASS_INDEX, Some(OBJECT_CLASS_INDEX), 0x28, &[]);
+        self.write_object(offsets, MAP_PACKAGE_INDEX, PACKAGE_CLASS_INDEX, "/Game/Maps/Main", None);
+        self.write_object(offsets, WORLD_INSTANCE_INDEX, WORLD_CLASS_INDEX, "Main", Some(MAP_PACKAGE_INDEX));
+
+        let object_count = RECEIVE_TICK_FUNCTION_INDEX + 1;
+
+        self.write_u64(OBJECT_CHUNK_TABLE_ADDRESS, OBJECT_CHUNK_ADDRESS);
+        write_le(
+            &mut module_bytes,
+            TEST_OBJECT_ARRAY_OFFSET + offsets.object_array_objects,
+            &OBJECT_CHUNK_TABLE_ADDRESS.to_le_bytes(),
+        );
+        write_le(
+            &mut module_bytes,
+            TEST_OBJECT_ARRAY_OFFSET + offsets.object_array_max_elements,
+            &0x100_u32.to_le_bytes(),
+        );
+        write_le(
+            &mut module_bytes,
+            TEST_OBJECT_ARRAY_OFFSET + offsets.object_array_num_elements,
+            &(object_count as u32).to_le_bytes(),
+        );
+        write_le(&mut module_bytes, TEST_WORLD_OFFSET, &object_address(WORLD_INSTANCE_INDEX).to_le_bytes());
+
+        self.with_module(TEST_GAME_MODULE_NAME, module_bytes)
+    }
+
+    /// Appends a Latin-1 name pool entry to the first block and returns its comparison index.
+    pub(crate) fn write_name_pool_entry(
+        &mut self,
+        name: &str,
+    ) -> u32 {
+        if let Some(name_id) = self.name_ids.get(name) {
+            return *name_id;
+        }
+
+        let entry_address = TEST_NAME_BLOCK_ADDRESS + self.next_name_block_offset;
+        let name_id = (self.next_name_block_offset / 2) as u32;
+
+        self.write_bytes(entry_address, &((name.len() as u16) << 6).to_le_bytes());
+        self.write_bytes(entry_address + 2, name.as_bytes());
+        self.next_name_block_offset += (2 + name.len() as u64).next_multiple_of(2);
+        self.name_ids.insert(name.to_string(), name_id);
+
+        name_id
+    }
+
+    fn write_object(
+        &mut self,
+        offsets: UnrealEngineOffsets,
+        object_index: u64,
+        class_index: u64,
+        object_name: &str,
+        outer_index: Option<u64>,
+    ) {
+        let address = object_address(object_index);
+
+        self.write_u64(OBJECT_CHUNK_ADDRESS + object_index * offsets.object_item_size, address);
+        self.write_bytes(address + offsets.object_internal_index, &(object_index as u32).to_le_bytes());
+        self.write_u64(address + offsets.object_class, object_address(class_index));
+        self.write_name(address + offsets.object_name, object_name);
+        self.write_u64(address + offsets.object_outer, outer_index.map(object_address).unwrap_or(0));
+    }
+
+    fn write_struct(
+        &mut self,
+        offsets: UnrealEngineOffsets,
+        struct_index: u64,
+        super_index: Option<u64>,
+        properties_size: u32,
+        properties: &[TestProperty],
+    ) {
+        let struct_address = object_address(struct_index);
+        let mut previous_link_address = struct_address + offsets.struct_first_property;
+
+        self.write_u64(struct_address + offsets.struct_super, super_index.map(object_address).unwrap_or(0));
+        self.write_bytes(struct_address + offsets.struct_properties_size, &properties_size.to_le_bytes());
+
+        for property in properties {
+            let property_address = self.next_property_address;
+            let property_class_address = self.property_class_address(offsets, property.type_name);
+
+            self.next_property_address += PROPERTY_STRIDE;
+            self.write_u64(previous_link_address, property_address);
+            self.write_u64(property_address + offsets.property_class, property_class_address);
+            self.write_name(property_address + offsets.property_name, property.name);
+            self.write_bytes(property_address + offsets.property_array_dim, &property.array_dim.to_le_bytes());
+            self.write_bytes(property_address + offsets.property_element_size, &property.element_size.to_le_bytes());
+            self.write_bytes(property_address + offsets.property_offset, &property.offset.to_le_bytes());
+            self.write_u64(property_address + offsets.property_size, property.extra_data);
+            previous_link_address = property_address + offsets.property_next;
+        }
+    }
+
+    /// `FFieldClass` records before 4.25 are `UClass` objects, so either way the type name sits at a fixed offset.
+    fn property_class_address(
+        &mut self,
+        offsets: UnrealEngineOffsets,
+        type_name: &str,
+    ) -> u64 {
+        if let Some(property_class_address) = self.property_class_addresses.get(type_name) {
+            return *property_class_address;
+        }
+
+        let property_class_address = PROPERTY_CLASSES_ADDRESS + self.property_class_addresses.len() as u64 * PROPERTY_CLASS_STRIDE;
+
+        self.write_name(property_class_address + offsets.property_class_name, type_name);
+        self.property_class_addresses
+            .insert(type_name.to_string(), property_class_address);
+
+        property_class_address
+    }
+
+    fn write_name(
+        &mut self,
+        name_address: u64,
+        name: &str,
+    ) {
+        let name_id = self.write_name_pool_entry(name);
+
+        self.write_bytes(name_address, &name_id.to_le_bytes());
+        self.write_bytes(name_address + 4, &0_u32.to_le_bytes());
+    }
+
+    fn read_heap_u64(
+        &self,
+        address: u64,
+    ) -> u64 {
+        let bytes = self
+            .read_module_bytes("", address, 8)
+            .unwrap_or_else(|_| vec![0; 8]);
+
+        u64::from_le_bytes(bytes.try_into().unwrap_or_default())
+    }
+
+    pub(crate) fn write_u64(
+        &mut self,
+        address: u64,
+        value: u64,
+    ) {
+        self.write_bytes(address, &value.to_le_bytes());
+    }
+
+    pub(crate) fn write_bytes(
+        &mut self,
+        address: u64,
+        bytes: &[u8],
+    ) {
+        for (byte_index, byte) in bytes.iter().enumerate() {
+            let byte_address = address + byte_index as u64;
+            let page = self
+                .heap_pages
+                .entry(byte_address / TEST_PAGE_SIZE)
+                .or_insert_with(|| vec![0; TEST_PAGE_SIZE as usize]);
+
+            page[(byte_address % TEST_PAGE_SIZE) as usize] = *byte;
+        }
+    }
+}
+
+impl ProcessMemoryStore for TestProcessMemory {
+    fn read_module_bytes(
+        &self,
+        module_name: &str,
+        offset: u64,
+        length: u64,
+    ) -> Result<Vec<u8>, String> {
+        if !module_name.is_empty() {
+            let module_bytes = self
+                .modules
+                .get(module_name)
+                .ok_or_else(|| format!("Unknown module `{module_name}`."))?;
+
+            return module_bytes
+                .get(offset as usize..(offset + length) as usize)
+                .map(<[u8]>::to_vec)
+                .ok_or_else(|| format!("Read past the end of `{module_name}`."));
+        }
+
+        (offset..offset + length)
+            .map(|address| {
+                self.heap_pages
+                    .get(&(address / TEST_PAGE_SIZE))
+                    .map(|page| page[(address % TEST_PAGE_SIZE) as usize])
+                    .ok_or_else(|| format!("Address 0x{address:X} is not mapped."))
+            })
+            .collect()
+    }
+}
+
+fn object_address(object_index: u64) -> u64 {
+    OBJECTS_ADDRESS + object_index * OBJECT_STRIDE
+}
+
+/// A PE64 image with an executable `.text` section at 0x1000 and a writable `.data` section at 0x2000.
+pub(crate) fn build_pe64_game_module() -> Vec<u8> {
+    let mut module_bytes = vec![0_u8; TEST_MODULE_SIZE];
+
+    write_le(&mut module_bytes, 0, b"MZ");
+    write_le(&mut module_bytes, 0x3C, &0x80_u32.to_le_bytes());
+    write_le(&mut module_bytes, 0x80, b"PE\0\0");
+    write_le(&mut module_bytes, 0x86, &2_u16.to_le_bytes());
+    write_le(&mut module_bytes, 0x94, &0xF0_u16.to_le_bytes());
+    write_le(&mut module_bytes, 0x98, &0x20B_u16.to_le_bytes());
+
+    for (section_index, (section_name, virtual_address, virtual_size, characteristics)) in [
+        (b".text\0\0\0", 0x1000_u32, 0x1000_u32, 0x6000_0020_u32),
+        (b".data\0\0\0", 0x2000, 0x2000, 0xC000_0040),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let section_header_offset = SECTION_TABLE_OFFSET + section_index as u64 * 40;
+
+        write_le(&mut module_bytes, section_header_offset, section_name);
+        write_le(&mut module_bytes, section_header_offset + 8, &virtual_size.to_le_bytes());
+        write_le(&mut module_bytes, section_header_offset + 12, &virtual_address.to_le_bytes());
+        write_le(&mut module_bytes, section_header_offset + 36, &characteristics.to_le_bytes());
+    }
+
+    write_rip_relative_reference(
+        &mut module_bytes,
+        0x1000,
+        &[0x48, 0x8D, 0x0D],
+        TEST_NAME_POOL_OFFSET,
+        &[
+            0xE8, 0x00, 0x00, 0x00, 0x00, 0xC6, 0x05, 0x00, 0x00, 0x00, 0x00, 0x01,
+        ],
+    );
+    write_rip_relative_reference(
+        &mut module_bytes,
+        0x1020,
+        &[0x48, 0x8B, 0x05],
+        TEST_OBJECT_ARRAY_OFFSET + 0x10,
+        &[0x48, 0x8B, 0x0C, 0xC8, 0x48, 0x8D, 0x04, 0xD1],
+    );
+    write_rip_relative_reference(
+        &mut module_bytes,
+        0x1040,
+        &[0x48, 0x8B, 0x1D],
+        TEST_WORLD_OFFSET,
+        &[0x48, 0x85, 0xDB, 0x74, 0x00, 0x41, 0xB0, 0x01],
+    );
+
+    module_bytes
+}
+
+fn write_rip_relative_reference(
+    module_bytes: &mut [u8],
+    instruction_offset: u64,
+    opcode_bytes: &[u8],
+    target_offset: u64,
+    trailing_bytes: &[u8],
+) {
+    let instruction_end = instruction_offset + opcode_bytes.len() as u64 + 4;
+    let displacement = (target_offset as i64 - instruction_end as i64) as i32;
+
+    write_le(module_bytes, instruction_offset, opcode_bytes);
+    write_le(module_bytes, instruction_offset + opcode_bytes.len() as u64, &displacement.to_le_bytes());
+    write_le(module_bytes, instruction_end, trailing_bytes);
+}
+
+fn write_le(
+    module_bytes: &mut [u8],
+    offset: u64,
+    bytes: &[u8],
+) {
+    module_bytes[offset as usize..offset as usize + bytes.len()].copy_from_slice(bytes);
+}
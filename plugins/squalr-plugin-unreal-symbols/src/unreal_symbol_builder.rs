@@ -0,0 +1,643 @@
+use crate::runtime::{
+    unreal_engine_offsets::{UnrealEngineOffsets, UnrealNameTableKind},
+    unreal_object_walker::{UnrealPropertyInfo, UnrealStructInfo},
+};
+use squalr_engine_api::{
+    registries::symbols::struct_layout_descriptor::StructLayoutDescriptor,
+    structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::container_type::ContainerType,
+        pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize,
+        projects::symbol_layouts::symbol_layout_field_materializer::{SymbolLayoutFieldMaterializer, SymbolLayoutPositionedField},
+        structs::{
+            symbolic_field_definition::SymbolicFieldDefinition,
+            symbolic_struct_definition::{SymbolicLayoutKind, SymbolicStructDefinition},
+        },
+    },
+};
+use std::collections::{HashMap, HashSet};
+
+const LAYOUT_ID_PREFIX: &str = "unreal";
+const NAME_POOL_LAYOUT_ID: &str = "unreal.FNamePool";
+const OBJECT_ARRAY_LAYOUT_ID: &str = "unreal.FUObjectArray";
+const NAMES_FIELD_NAME: &str = "GNames";
+const OBJECTS_FIELD_NAME: &str = "GObjects";
+const WORLD_FIELD_NAME: &str = "GWorld";
+const CLASS_QUALIFIED_NAME: &str = "CoreUObject.Class";
+const OBJECT_QUALIFIED_NAME: &str = "CoreUObject.Object";
+const MAX_SUPER_DEPTH: usize = 64;
+
+/// Module offsets of the engine globals the walk was anchored on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct UnrealEngineGlobals {
+    pub(crate) names_offset: u64,
+    pub(crate) object_array_offset: u64,
+    pub(crate) world_offset: Option<u64>,
+    /// Class of the object `GWorld` pointed at when it was read, used to type the global.
+    pub(crate) world_class_address: Option<u64>,
+}
+
+/// A named engine global in the game module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct UnrealModuleField {
+    pub(crate) display_name: String,
+    pub(crate) offset: u64,
+    pub(crate) data_type_id: String,
+    pub(crate) container_type: ContainerType,
+    pub(crate) size_in_bytes: u64,
+}
+
+impl UnrealModuleField {
+    pub(crate) fn get_type_text(&self) -> String {
+        format!("{}{}", self.data_type_id, self.container_type)
+    }
+}
+
+/// Layouts derived from the engine's reflection data, and the engine globals they hang off.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct UnrealSymbols {
+    pub(crate) struct_layout_descriptors: Vec<StructLayoutDescriptor>,
+    pub(crate) module_fields: Vec<UnrealModuleField>,
+}
+
+#[derive(Clone, Debug)]
+struct FieldShape {
+    data_type_id: String,
+    container_type: ContainerType,
+    size_in_bytes: u64,
+}
+
+/// Builds one layout per reflected class and script struct. Class layouts describe the object as allocated, with the
+/// `UObject` header and inherited properties included, so a property's layout offset matches its reflected offset.
+pub(crate) struct UnrealSymbolBuilder<'a> {
+    offsets: UnrealEngineOffsets,
+    structs_by_address: HashMap<u64, &'a UnrealStructInfo>,
+    layout_ids_by_address: HashMap<u64, String>,
+    class_layout_id: String,
+    object_layout_id: String,
+}
+
+impl<'a> UnrealSymbolBuilder<'a> {
+    pub(crate) fn new(
+        structs: &'a [UnrealStructInfo],
+        offsets: UnrealEngineOffsets,
+    ) -> Self {
+        let structs_by_address = structs
+            .iter()
+            .map(|struct_info| (struct_info.address, struct_info))
+            .collect::<HashMap<_, _>>();
+        let mut sorted_structs = structs.iter().collect::<Vec<_>>();
+        let mut used_layout_ids = HashSet::new();
+        let mut layout_ids_by_address = HashMap::new();
+
+        sorted_structs.sort_by(|left_struct, right_struct| {
+            left_struct
+                .get_qualified_name()
+                .cmp(&right_struct.get_qualified_name())
+                .then_with(|| left_struct.address.cmp(&right_struct.address))
+        });
+
+        // Blueprint and hot-reloaded types can share a name, so later duplicates are told apart by address.
+        for struct_info in sorted_structs {
+            let mut layout_id = build_layout_id(struct_info);
+
+            if !used_layout_ids.insert(layout_id.clone()) {
+                layout_id = format!("{layout_id}_{:X}", struct_info.address);
+                used_layout_ids.insert(layout_id.clone());
+            }
+
+            layout_ids_by_address.insert(struct_info.address, layout_id);
+        }
+
+        let find_class_layout_id = |qualified_name: &str| {
+            structs
+                .iter()
+                .filter(|struct_info| struct_info.is_class && struct_info.get_qualified_name() == qualified_name)
+                .min_by_key(|struct_info| struct_info.address)
+                .and_then(|struct_info| layout_ids_by_address.get(&struct_info.address))
+                .cloned()
+                .unwrap_or_else(|| String::from("u8"))
+        };
+        let class_layout_id = find_class_layout_id(CLASS_QUALIFIED_NAME);
+        let object_layout_id = find_class_layout_id(OBJECT_QUALIFIED_NAME);
+
+        Self {
+            offsets,
+            structs_by_address,
+            layout_ids_by_address,
+            class_layout_id,
+            object_layout_id,
+        }
+    }
+
+    pub(crate) fn build(
+        &self,
+        engine_globals: &UnrealEngineGlobals,
+    ) -> UnrealSymbols {
+        let mut unreal_symbols = UnrealSymbols::default();
+        let mut layout_entries = self
+            .layout_ids_by_address
+            .iter()
+            .filter_map(|(struct_address, layout_id)| Some((layout_id, *self.structs_by_address.get(struct_address)?)))
+            .collect::<Vec<_>>();
+
+        layout_entries.sort_by(|left_entry, right_entry| left_entry.0.cmp(right_entry.0));
+
+        for (layout_id, struct_info) in layout_entries {
+            if let Some(struct_layout_descriptor) = self.build_struct_layout(layout_id, struct_info) {
+                unreal_symbols
+                    .struct_layout_descriptors
+                    .push(struct_layout_descriptor);
+            }
+        }
+
+        self.build_engine_globals(engine_globals, &mut unreal_symbols);
+
+        unreal_symbols
+    }
+
+    fn build_struct_layout(
+        &self,
+        layout_id: &str,
+        struct_info: &'a UnrealStructInfo,
+    ) -> Option<StructLayoutDescriptor> {
+        let mut properties = self
+            .collect_struct_hierarchy(struct_info)
+            .into_iter()
+            .flat_map(|hierarchy_struct| hierarchy_struct.properties.iter())
+            .collect::<Vec<_>>();
+        let (header_fields, first_free_offset) = if struct_info.is_class {
+            (self.build_object_header_fields(), self.offsets.object_header_size)
+        } else {
+            (Vec::new(), 0)
+        };
+
+        properties.sort_by_key(|property| property.offset);
+
+        let positioned_fields = header_fields
+            .into_iter()
+            .chain(self.position_properties(&properties, first_free_offset))
+            .collect::<Vec<_>>();
+
+        if positioned_fields.is_empty() {
+            return None;
+        }
+
+        materialize_layout(layout_id, struct_info.properties_size, positioned_fields)
+    }
+
+    /// `UObjectBase` is not reflected, so every class layout gets its fields spelled out.
+    fn build_object_header_fields(&self) -> Vec<SymbolLayoutPositionedField> {
+        [
+            ("VTable", 0, self.pointer_field_shape("u8")),
+            ("ObjectFlags", self.offsets.object_flags, primitive_field_shape("i32", 4)),
+            ("InternalIndex", self.offsets.object_internal_index, primitive_field_shape("i32", 4)),
+            ("ClassPrivate", self.offsets.object_class, self.pointer_field_shape(&self.class_layout_id)),
+            ("NamePrivate", self.offsets.object_name, primitive_field_shape("u64", 8)),
+            ("OuterPrivate", self.offsets.object_outer, self.pointer_field_shape(&self.object_layout_id)),
+        ]
+        .into_iter()
+        .map(|(field_name, field_offset, field_shape)| self.positioned_field(field_name, field_offset, field_shape))
+        .collect()
+    }
+
+    /// Expects properties sorted by offset. Properties that start before `first_free_offset` or overlap an earlier one are
+    /// dropped, which keeps the first of several bitfield bools sharing a byte.
+    fn position_properties<'property>(
+        &'property self,
+        sorted_properties: &'property [&'property UnrealPropertyInfo],
+        first_free_offset: u64,
+    ) -> impl Iterator<Item = SymbolLayoutPositionedField> + 'property {
+        let mut next_free_offset = first_free_offset;
+
+        sorted_properties.iter().filter_map(move |property| {
+            let field_shape = self
+                .resolve_field_shape(property)
+                .or_else(|| opaque_field_shape(property.element_size.saturating_mul(property.array_dim)))?;
+
+            if property.offset < next_free_offset {
+                return None;
+            }
+
+            next_free_offset = property.offset.saturating_add(field_shape.size_in_bytes);
+
+            Some(self.positioned_field(&property.name, property.offset, field_shape))
+        })
+    }
+
+    fn resolve_field_shape(
+        &self,
+        property: &UnrealPropertyInfo,
+    ) -> Option<FieldShape> {
+        let element_shape = match property.type_name.as_str() {
+            "BoolProperty" if property.is_bitfield => primitive_field_shape("u8", 1),
+            "BoolProperty" => primitive_field_shape("bool8", 1),
+            "Int8Property" => primitive_field_shape("i8", 1),
+            "ByteProperty" => primitive_field_shape("u8", 1),
+            "Int16Property" => primitive_field_shape("i16", 2),
+            "UInt16Property" => primitive_field_shape("u16", 2),
+            "IntProperty" => primitive_field_shape("i32", 4),
+            "UInt32Property" => primitive_field_shape("u32", 4),
+            "Int64Property" => primitive_field_shape("i64", 8),
+            "UInt64Property" => primitive_field_shape("u64", 8),
+            "FloatProperty" => primitive_field_shape("f32", 4),
+            "DoubleProperty" => primitive_field_shape("f64", 8),
+            // Enum properties take the size of their underlying integer.
+            "EnumProperty" => match property.element_size {
+                1 => primitive_field_shape("u8", 1),
+                2 => primitive_field_shape("u16", 2),
+                4 => primitive_field_shape("u32", 4),
+                8 => primitive_field_shape("u64", 8),
+                _ => return None,
+            },
+            "ObjectProperty" | "ObjectPtrProperty" | "ClassProperty" | "ClassPtrProperty" => {
+                let pointee_layout_id = property
+                    .referenced_struct_address
+                    .and_then(|struct_address| self.layout_ids_by_address.get(&struct_address))
+                    .map(String::as_str)
+                    .unwrap_or("u8");
+
+                self.pointer_field_shape(pointee_layout_id)
+            }
+            "StructProperty" => FieldShape {
+                data_type_id: self
+                    .layout_ids_by_address
+                    .get(&property.referenced_struct_address?)?
+                    .clone(),
+                container_type: ContainerType::None,
+                size_in_bytes: property.element_size,
+            },
+            _ => return None,
+        };
+
+        if element_shape.size_in_bytes != property.element_size || property.element_size == 0 {
+            return None;
+        }
+
+        if property.array_dim <= 1 {
+            return Some(element_shape);
+        }
+
+        Some(FieldShape {
+            data_type_id: element_shape.data_type_id,
+            container_type: match element_shape.container_type {
+                ContainerType::Pointer(pointer_size) => ContainerType::PointerArrayFixed(pointer_size, property.array_dim),
+                _ => ContainerType::ArrayFixed(property.array_dim),
+            },
+            size_in_bytes: element_shape.size_in_bytes.saturating_mul(property.array_dim),
+        })
+    }
+
+    /// Adds `GNames`, `GObjects` and `GWorld` as module fields, with layouts for the engine containers behind them.
+    fn build_engine_globals(
+        &self,
+        engine_globals: &UnrealEngineGlobals,
+        unreal_symbols: &mut UnrealSymbols,
+    ) {
+        let names_field = match self.offsets.name_table_kind {
+            UnrealNameTableKind::NamePool => {
+                let name_pool_size = self.offsets.name_pool_blocks + self.offsets.name_pool_max_blocks * self.offsets.pointer_size;
+
+                if let Some(name_pool_layout_descriptor) = materialize_layout(
+                    NAME_POOL_LAYOUT_ID,
+                    name_pool_size,
+                    vec![
+                        self.positioned_field("Lock", 0, primitive_field_shape("u64", 8)),
+                        self.positioned_field("CurrentBlock", 8, primitive_field_shape("u32", 4)),
+                        self.positioned_field("CurrentByteCursor", 12, primitive_field_shape("u32", 4)),
+                        self.positioned_field(
+                            "Blocks",
+                            self.offsets.name_pool_blocks,
+                            FieldShape {
+                                data_type_id: String::from("u8"),
+                                container_type: ContainerType::PointerArrayFixed(self.pointer_size(), self.offsets.name_pool_max_blocks),
+                                size_in_bytes: self.offsets.name_pool_max_blocks * self.offsets.pointer_size,
+                            },
+                        ),
+                    ],
+                ) {
+                    unreal_symbols
+                        .struct_layout_descriptors
+                        .push(name_pool_layout_descriptor);
+                }
+
+                self.module_field(
+                    NAMES_FIELD_NAME,
+                    engine_globals.names_offset,
+                    FieldShape {
+                        data_type_id: NAME_POOL_LAYOUT_ID.to_string(),
+                        container_type: ContainerType::None,
+                        size_in_bytes: name_pool_size,
+                    },
+                )
+            }
+            UnrealNameTableKind::EntryArray => self.module_field(NAMES_FIELD_NAME, engine_globals.names_offset, self.pointer_field_shape("u8")),
+        };
+
+        if let Some(object_array_layout_descriptor) = materialize_layout(
+            OBJECT_ARRAY_LAYOUT_ID,
+            self.offsets.object_array_size,
+            vec![
+                self.positioned_field("ObjFirstGCIndex", 0, primitive_field_shape("i32", 4)),
+                self.positioned_field("ObjLastNonGCIndex", 4, primitive_field_shape("i32", 4)),
+                self.positioned_field("MaxObjectsNotConsideredByGC", 8, primitive_field_shape("i32", 4)),
+                self.positioned_field("OpenForDisregardForGC", 12, primitive_field_shape("bool8", 1)),
+                self.positioned_field("Objects", self.offsets.object_array_objects, self.pointer_field_shape("u8")),
+                self.positioned_field("MaxElements", self.offsets.object_array_max_elements, primitive_field_shape("i32", 4)),
+                self.positioned_field("NumElements", self.offsets.object_array_num_elements, primitive_field_shape("i32", 4)),
+            ],
+        ) {
+            unreal_symbols
+                .struct_layout_descriptors
+                .push(object_array_layout_descriptor);
+        }
+
+        unreal_symbols.module_fields.push(names_field);
+        unreal_symbols.module_fields.push(self.module_field(
+            OBJECTS_FIELD_NAME,
+            engine_globals.object_array_offset,
+            FieldShape {
+                data_type_id: OBJECT_ARRAY_LAYOUT_ID.to_string(),
+                container_type: ContainerType::None,
+                size_in_bytes: self.offsets.object_array_size,
+            },
+        ));
+
+        if let Some(world_offset) = engine_globals.world_offset {
+            let world_layout_id = engine_globals
+                .world_class_address
+                .and_then(|world_class_address| self.layout_ids_by_address.get(&world_class_address))
+                .map(String::as_str)
+                .unwrap_or("u8");
+
+            unreal_symbols
+                .module_fields
+                .push(self.module_field(WORLD_FIELD_NAME, world_offset, self.pointer_field_shape(world_layout_id)));
+        }
+
+        unreal_symbols
+            .module_fields
+            .sort_by_key(|module_field| module_field.offset);
+    }
+
+    fn positioned_field(
+        &self,
+        field_name: &str,
+        field_offset: u64,
+        field_shape: FieldShape,
+    ) -> SymbolLayoutPositionedField {
+        SymbolLayoutPositionedField::new(
+            field_offset,
+            field_shape.size_in_bytes,
+            SymbolicFieldDefinition::new_named(field_name.to_string(), DataTypeRef::new(&field_shape.data_type_id), field_shape.container_type),
+        )
+    }
+
+    fn module_field(
+        &self,
+        display_name: &str,
+        offset: u64,
+        field_shape: FieldShape,
+    ) -> UnrealModuleField {
+        UnrealModuleField {
+            display_name: display_name.to_string(),
+            offset,
+            data_type_id: field_shape.data_type_id,
+            container_type: field_shape.container_type,
+            size_in_bytes: field_shape.size_in_bytes,
+        }
+    }
+
+    fn pointer_field_shape(
+        &self,
+        pointee_data_type_id: &str,
+    ) -> FieldShape {
+        FieldShape {
+            data_type_id: pointee_data_type_id.to_string(),
+            container_type: ContainerType::Pointer(self.pointer_size()),
+            size_in_bytes: self.offsets.pointer_size,
+        }
+    }
+
+    fn pointer_size(&self) -> PointerScanPointerSize {
+        if self.offsets.pointer_size == 4 {
+            PointerScanPointerSize::Pointer32
+        } else {
+            PointerScanPointerSize::Pointer64
+        }
+    }
+
+    /// Returns the struct and its ancestors, base first.
+    fn collect_struct_hierarchy(
+        &self,
+        struct_info: &'a UnrealStructInfo,
+    ) -> Vec<&'a UnrealStructInfo> {
+        let mut struct_hierarchy = vec![struct_info];
+        let mut super_address = struct_info.super_address;
+
+        while let Some(super_struct) = super_address.and_then(|address| self.structs_by_address.get(&address)) {
+            if struct_hierarchy.len() >= MAX_SUPER_DEPTH {
+                break;
+            }
+
+            struct_hierarchy.push(super_struct);
+            super_address = super_struct.super_address;
+        }
+
+        struct_hierarchy.reverse();
+        struct_hierarchy
+    }
+}
+
+fn materialize_layout(
+    layout_id: &str,
+    declared_size_in_bytes: u64,
+    positioned_fields: Vec<SymbolLayoutPositionedField>,
+) -> Option<StructLayoutDescriptor> {
+    let field_end = positioned_fields
+        .iter()
+        .map(|positioned_field| {
+            positioned_field
+                .get_offset_in_bytes()
+                .saturating_add(positioned_field.get_size_in_bytes())
+        })
+        .max()
+        .unwrap_or(0);
+    let declared_size_in_bytes = declared_size_in_bytes.max(field_end);
+    let materialized_fields =
+        SymbolLayoutFieldMaterializer::materialize_positioned_fields(SymbolicLayoutKind::Struct, Some(declared_size_in_bytes), positioned_fields).ok()?;
+
+    Some(StructLayoutDescriptor::new(
+        layout_id.to_string(),
+        SymbolicStructDefinition::new_with_layout_kind(layout_id.to_string(), SymbolicLayoutKind::Struct, materialized_fields)
+            .with_declared_size_in_bytes(Some(declared_size_in_bytes)),
+    ))
+}
+
+fn primitive_field_shape(
+    data_type_id: &str,
+    size_in_bytes: u64,
+) -> FieldShape {
+    FieldShape {
+        data_type_id: data_type_id.to_string(),
+        container_type: ContainerType::None,
+        size_in_bytes,
+    }
+}
+
+fn opaque_field_shape(size_in_bytes: u64) -> Option<FieldShape> {
+    (size_in_bytes > 0).then(|| FieldShape {
+        data_type_id: String::from("u8"),
+        container_type: ContainerType::ArrayFixed(size_in_bytes),
+        size_in_bytes,
+    })
+}
+
+/// Package paths become dotted layout id components, reduced to identifier characters.
+fn build_layout_id(struct_info: &UnrealStructInfo) -> String {
+    std::iter::once(LAYOUT_ID_PREFIX.to_string())
+        .chain(
+            struct_info
+                .get_qualified_name()
+                .split('.')
+                .map(sanitize_layout_id_component),
+        )
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn sanitize_layout_id_component(name: &str) -> String {
+    let mut sanitized_name = String::with_capacity(name.len());
+
+    for name_character in name.chars() {
+        if name_character.is_ascii_alphanumeric() || name_character == '_' {
+            sanitized_name.push(name_character);
+        } else if !sanitized_name.ends_with('_') {
+            sanitized_name.push('_');
+        }
+    }
+
+    let sanitized_name = sanitized_name.trim_matches('_');
+
+    if sanitized_name.is_empty() {
+        String::from("anonymous")
+    } else {
+        sanitized_name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UnrealEngineGlobals, UnrealSymbolBuilder, build_layout_id};
+    use crate::runtime::{
+        unreal_engine_offsets::UnrealEngineOffsets,
+        unreal_object_walker::{UnrealPropertyInfo, UnrealStructInfo},
+    };
+
+    fn script_struct(
+        address: u64,
+        package_name: &str,
+        name: &str,
+        properties: Vec<UnrealPropertyInfo>,
+    ) -> UnrealStructInfo {
+        UnrealStructInfo {
+            address,
+            package_name: package_name.to_string(),
+            name: name.to_string(),
+            is_class: false,
+            super_address: None,
+            properties_size: 8,
+            properties,
+        }
+    }
+
+    fn property(
+        name: &str,
+        type_name: &str,
+        offset: u64,
+        element_size: u64,
+    ) -> UnrealPropertyInfo {
+        UnrealPropertyInfo {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            offset,
+            element_size,
+            array_dim: 1,
+            referenced_struct_address: None,
+            is_bitfield: false,
+        }
+    }
+
+    #[test]
+    fn build_layout_id_strips_script_root_and_sanitizes_package_paths() {
+        assert_eq!(
+            build_layout_id(&script_struct(0x1000, "/Script/Engine", "HitResult", Vec::new())),
+            "unreal.Engine.HitResult"
+        );
+        assert_eq!(
+            build_layout_id(&script_struct(0x2000, "/Game/Blueprints/BP_Door", "BP_Door_C", Vec::new())),
+            "unreal.Game.Blueprints.BP_Door.BP_Door_C"
+        );
+        assert_eq!(
+            build_layout_id(&script_struct(0x3000, "/Game/Data", "Stats (Old)", Vec::new())),
+            "unreal.Game.Data.Stats_Old"
+        );
+    }
+
+    #[test]
+    fn build_suffixes_duplicate_names_and_opaques_unknown_properties() {
+        let structs = vec![
+            script_struct(0x2000, "/Game/Data", "Stats", vec![property("Curve", "MulticastDelegateProperty", 0, 8)]),
+            script_struct(0x1000, "/Game/Data", "Stats", vec![property("Speed", "FloatProperty", 4, 4)]),
+        ];
+        let unreal_symbols = UnrealSymbolBuilder::new(&structs, UnrealEngineOffsets::UE4_20_X64).build(&UnrealEngineGlobals {
+            names_offset: 0x100,
+            object_array_offset: 0x200,
+            world_offset: None,
+            world_class_address: None,
+        });
+        let find_first_field = |layout_id: &str| {
+            let field_definition = unreal_symbols
+                .struct_layout_descriptors
+                .iter()
+                .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == layout_id)
+                .unwrap_or_else(|| panic!("Expected layout `{layout_id}`."))
+                .get_struct_layout_definition()
+                .get_fields()
+                .iter()
+                .find(|field_definition| !field_definition.is_unassigned())
+                .expect("Expected an assigned field.")
+                .clone();
+
+            (
+                field_definition.get_field_name().to_string(),
+                field_definition
+                    .get_data_type_ref()
+                    .get_data_type_id()
+                    .to_string(),
+                field_definition.get_container_type().to_string(),
+            )
+        };
+
+        assert_eq!(
+            find_first_field("unreal.Game.Data.Stats"),
+            (String::from("Speed"), String::from("f32"), String::new())
+        );
+        assert_eq!(
+            find_first_field("unreal.Game.Data.Stats_2000"),
+            (String::from("Curve"), String::from("u8"), String::from("[8]"))
+        );
+        // Entry-array engines expose `GNames` as a plain pointer.
+        assert_eq!(
+            unreal_symbols
+                .module_fields
+                .iter()
+                .map(|module_field| (module_field.display_name.as_str(), module_field.get_type_text()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("GNames", String::from("u8*(u64)")),
+                ("GObjects", String::from("unreal.FUObjectArray")),
+            ]
+        );
+    }
+}
@@ -160,6 +160,9 @@ mod tests {
     use squalr_engine_api::commands::project_symbols::project_symbols_command::ProjectSymbolsCommand;
     use squalr_engine_api::commands::unprivileged_command::UnprivilegedCommand;
     use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::projects::symbol_interchange::c_header::c_header_import_options::CHeaderDataModel;
+    use squalr_engine_api::structures::projects::symbol_interchange::symbol_export_format::SymbolExportFormat;
 
     #[test]
     fn parse_input_returns_help_for_top_level_help_flag() {
@@ -253,6 +256,59 @@ mod tests {
         assert_eq!(project_symbols_upsert_layout_request.field_definitions.len(), 2);
     }
 
+    #[test]
+    fn parse_input_returns_unprivileged_command_for_project_symbols_import_header_command() {
+        let parsed_input = Cli::parse_input(
+            "project_symbols import-header game/player.h --bitness 32 --data-model msvc --prefix game",
+            CommandInputMode::Session,
+        )
+        .expect("Expected project_symbols import-header command to parse successfully");
+
+        let ParsedInput::UnprivilegedCommand(UnprivilegedCommand::ProjectSymbols(ProjectSymbolsCommand::ImportHeader {
+            project_symbols_import_header_request,
+        })) = parsed_input
+        else {
+            panic!("Expected project_symbols import-header command.");
+        };
+
+        assert_eq!(
+            project_symbols_import_header_request.header_file_path,
+            std::path::PathBuf::from("game/player.h")
+        );
+        assert_eq!(project_symbols_import_header_request.bitness, Bitness::Bit32);
+        assert_eq!(project_symbols_import_header_request.data_model, CHeaderDataModel::Llp64);
+        assert_eq!(project_symbols_import_header_request.layout_id_prefix, "game");
+    }
+
+    #[test]
+    fn parse_input_returns_unprivileged_command_for_project_symbols_export_command() {
+        let parsed_input = Cli::parse_input("project_symbols export out/game.rcnet --bitness 32", CommandInputMode::Session)
+            .expect("Expected project_symbols export command to parse successfully");
+
+        let ParsedInput::UnprivilegedCommand(UnprivilegedCommand::ProjectSymbols(ProjectSymbolsCommand::Export {
+            project_symbols_export_request,
+        })) = parsed_input
+        else {
+            panic!("Expected project_symbols export command.");
+        };
+
+        assert_eq!(project_symbols_export_request.output_file_path, std::path::PathBuf::from("out/game.rcnet"));
+        assert_eq!(project_symbols_export_request.export_format, SymbolExportFormat::ReClass);
+        assert_eq!(project_symbols_export_request.bitness, Bitness::Bit32);
+
+        let parsed_input = Cli::parse_input("project_symbols export out/types.txt --format ghidra", CommandInputMode::Session)
+            .expect("Expected project_symbols export command with format to parse successfully");
+
+        let ParsedInput::UnprivilegedCommand(UnprivilegedCommand::ProjectSymbols(ProjectSymbolsCommand::Export {
+            project_symbols_export_request,
+        })) = parsed_input
+        else {
+            panic!("Expected project_symbols export command.");
+        };
+
+        assert_eq!(project_symbols_export_request.export_format, SymbolExportFormat::GhidraScript);
+    }
+
     #[test]
     fn parse_input_returns_unprivileged_command_for_project_symbols_delete_layout_command() {
         let parsed_input = Cli::parse_input("project_symbols delete-layout --id player.stats", CommandInputMode::Session)
@@ -269,6 +325,38 @@ mod tests {
         assert_eq!(project_symbols_delete_layout_request.replacement_data_type_id, "u8");
     }
 
+    #[test]
+    fn parse_input_returns_unprivileged_command_for_project_symbols_references_command() {
+        let parsed_input = Cli::parse_input("project_symbols references game.exe!Health", CommandInputMode::Session)
+            .expect("Expected project_symbols references command to parse successfully");
+
+        let ParsedInput::UnprivilegedCommand(UnprivilegedCommand::ProjectSymbols(ProjectSymbolsCommand::References {
+            project_symbols_references_request,
+        })) = parsed_input
+        else {
+            panic!("Expected project_symbols references command.");
+        };
+
+        assert_eq!(project_symbols_references_request.symbol, "game.exe!Health");
+    }
+
+    #[test]
+    fn parse_input_returns_unprivileged_command_for_project_symbols_rename_layout_dry_run_command() {
+        let parsed_input = Cli::parse_input("project_symbols rename-layout --id player --new-id actor --dry-run", CommandInputMode::Session)
+            .expect("Expected project_symbols rename-layout command to parse successfully");
+
+        let ParsedInput::UnprivilegedCommand(UnprivilegedCommand::ProjectSymbols(ProjectSymbolsCommand::RenameLayout {
+            project_symbols_rename_layout_request,
+        })) = parsed_input
+        else {
+            panic!("Expected project_symbols rename-layout command.");
+        };
+
+        assert_eq!(project_symbols_rename_layout_request.struct_layout_id, "player");
+        assert_eq!(project_symbols_rename_layout_request.new_struct_layout_id, "actor");
+        assert!(project_symbols_rename_layout_request.dry_run);
+    }
+
     #[test]
     fn parse_input_returns_unprivileged_command_for_project_symbols_upsert_resolver_command() {
         let parsed_input = Cli::parse_input(
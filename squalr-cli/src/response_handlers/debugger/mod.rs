@@ -0,0 +1,187 @@
+use squalr_engine_api::commands::debugger::debugger_response::DebuggerResponse;
+use squalr_engine_api::commands::debugger::list_breakpoints::debugger_list_breakpoints_response::DebuggerListBreakpointsResponse;
+use squalr_engine_api::commands::debugger::set_breakpoint::debugger_set_breakpoint_response::DebuggerSetBreakpointResponse;
+use squalr_engine_api::commands::debugger::trace::debugger_trace_response::DebuggerTraceResponse;
+use squalr_engine_api::commands::debugger::watch::debugger_watch_response::DebuggerWatchResponse;
+use squalr_engine_api::structures::debugging::{register_snapshot::RegisterSnapshot, watchpoint_hit::WatchpointHit};
+
+pub fn handle_debugger_response(response: DebuggerResponse) {
+    match response {
+        DebuggerResponse::Watch { debugger_watch_response } => handle_debugger_watch_response(debugger_watch_response),
+        DebuggerResponse::Hits { debugger_hits_response } => {
+            log_watchpoint_hits(&debugger_hits_response.watchpoint_hits, debugger_hits_response.error.as_deref())
+        }
+        DebuggerResponse::Unwatch { debugger_unwatch_response } => {
+            if debugger_unwatch_response.error.is_none() {
+                log::info!("Watchpoint removed.");
+            }
+
+            log_watchpoint_hits(&debugger_unwatch_response.watchpoint_hits, debugger_unwatch_response.error.as_deref())
+        }
+        DebuggerResponse::SetBreakpoint {
+            debugger_set_breakpoint_response,
+        } => handle_debugger_set_breakpoint_response(debugger_set_breakpoint_response),
+        DebuggerResponse::RemoveBreakpoint {
+            debugger_remove_breakpoint_response,
+        } => match (debugger_remove_breakpoint_response.breakpoint, debugger_remove_breakpoint_response.error) {
+            (Some(breakpoint), _) => log::info!(
+                "Breakpoint {} removed after {} hits.",
+                breakpoint.get_breakpoint_id(),
+                breakpoint.get_hit_count()
+            ),
+            (None, Some(error)) => log::error!("Failed to remove breakpoint: {}", error),
+            (None, None) => log::error!("Failed to remove breakpoint."),
+        },
+        DebuggerResponse::ListBreakpoints {
+            debugger_list_breakpoints_response,
+        } => log_breakpoints(debugger_list_breakpoints_response),
+        DebuggerResponse::Trace { debugger_trace_response } => log_instruction_trace(debugger_trace_response),
+    }
+}
+
+fn log_instruction_trace(debugger_trace_response: DebuggerTraceResponse) {
+    if let Some(instruction_trace) = &debugger_trace_response.instruction_trace {
+        for (step_index, step) in instruction_trace.get_steps().iter().enumerate() {
+            let register_writes = step
+                .get_register_writes()
+                .iter()
+                .map(|register_value| format!("{}=0x{:X}", register_value.get_name(), register_value.get_value()))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            log::info!(
+                "#{} 0x{:X} {:<32} {}",
+                step_index,
+                step.get_instruction_pointer(),
+                step.get_disassembly(),
+                register_writes
+            );
+        }
+
+        log::info!(
+            "Traced {} instructions of thread {} from 0x{:X} to 0x{:X}, stopped because {}.",
+            instruction_trace.get_steps().len(),
+            instruction_trace.get_thread_id(),
+            instruction_trace.get_start_address(),
+            instruction_trace.get_stop_address(),
+            instruction_trace.get_stop_reason()
+        );
+    }
+
+    match debugger_trace_response.error {
+        Some(error) => log::error!("Instruction trace failed: {}", error),
+        None if debugger_trace_response.instruction_trace.is_none() => log::error!("Instruction trace failed."),
+        None => {}
+    }
+}
+
+fn handle_debugger_set_breakpoint_response(debugger_set_breakpoint_response: DebuggerSetBreakpointResponse) {
+    match (debugger_set_breakpoint_response.breakpoint, debugger_set_breakpoint_response.error) {
+        (Some(breakpoint), _) => log::info!(
+            "Breakpoint {} set on 0x{:X} ({}).",
+            breakpoint.get_breakpoint_id(),
+            breakpoint.get_address(),
+            breakpoint.get_breakpoint_mode()
+        ),
+        (None, Some(error)) => log::error!("Failed to set breakpoint: {}", error),
+        (None, None) => log::error!("Failed to set breakpoint."),
+    }
+}
+
+fn log_breakpoints(debugger_list_breakpoints_response: DebuggerListBreakpointsResponse) {
+    if debugger_list_breakpoints_response.breakpoints.is_empty() {
+        log::info!("No breakpoints are set.");
+        return;
+    }
+
+    for breakpoint in &debugger_list_breakpoints_response.breakpoints {
+        log::info!(
+            "Breakpoint {} at 0x{:X} ({}) hits={}{}",
+            breakpoint.get_breakpoint_id(),
+            breakpoint.get_address(),
+            breakpoint.get_breakpoint_mode(),
+            breakpoint.get_hit_count(),
+            if breakpoint.get_is_armed() { "" } else { " disarmed" }
+        );
+
+        for breakpoint_hit in debugger_list_breakpoints_response
+            .breakpoint_hits
+            .iter()
+            .filter(|breakpoint_hit| breakpoint_hit.get_breakpoint_id() == breakpoint.get_breakpoint_id())
+        {
+            let stack_frames = breakpoint_hit
+                .get_stack_frames()
+                .iter()
+                .map(|stack_frame| format!("0x{:X}", stack_frame))
+                .collect::<Vec<_>>()
+                .join(" <- ");
+
+            log::info!(
+                "  #{} thread={} {}",
+                breakpoint_hit.get_hit_index(),
+                breakpoint_hit.get_register_snapshot().get_thread_id(),
+                format_registers(breakpoint_hit.get_register_snapshot())
+            );
+            log::info!("    stack: {}", stack_frames);
+        }
+    }
+}
+
+fn format_registers(register_snapshot: &RegisterSnapshot) -> String {
+    register_snapshot
+        .get_registers()
+        .iter()
+        .map(|register_value| format!("{}=0x{:X}", register_value.get_name(), register_value.get_value()))
+        .chain(
+            register_snapshot
+                .get_vector_registers()
+                .iter()
+                .map(|vector_register_value| format!("{}={}", vector_register_value.get_name(), vector_register_value.format_hex())),
+        )
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn handle_debugger_watch_response(debugger_watch_response: DebuggerWatchResponse) {
+    match (debugger_watch_response.watchpoint, debugger_watch_response.error) {
+        (Some(watchpoint), _) => log::info!(
+            "Watchpoint {} set on 0x{:X} ({} bytes, {}).",
+            watchpoint.get_watchpoint_id(),
+            watchpoint.get_address(),
+            watchpoint.get_watchpoint_size(),
+            watchpoint.get_watchpoint_access()
+        ),
+        (None, Some(error)) => log::error!("Failed to set watchpoint: {}", error),
+        (None, None) => log::error!("Failed to set watchpoint."),
+    }
+}
+
+fn log_watchpoint_hits(
+    watchpoint_hits: &[WatchpointHit],
+    error: Option<&str>,
+) {
+    if let Some(error) = error {
+        log::error!("Watchpoint query failed: {}", error);
+        return;
+    }
+
+    if watchpoint_hits.is_empty() {
+        log::info!("No watchpoint hits yet.");
+        return;
+    }
+
+    for watchpoint_hit in watchpoint_hits {
+        let register_snapshot = watchpoint_hit.get_last_register_snapshot();
+        let registers = format_registers(register_snapshot);
+        let trap_position = if watchpoint_hit.get_is_trap_after_access() { "after" } else { "at" };
+
+        log::info!(
+            "0x{:X} ({} access) hits={} thread={} {}",
+            watchpoint_hit.get_trap_address(),
+            trap_position,
+            watchpoint_hit.get_hit_count(),
+            register_snapshot.get_thread_id(),
+            registers
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use squalr_engine_api::commands::memory::allocate::memory_allocate_response::MemoryAllocateResponse;
+
+pub fn handle_memory_allocate_response(memory_allocate_response: MemoryAllocateResponse) {
+    match (memory_allocate_response.region, memory_allocate_response.error) {
+        (Some(region), _) => log::info!("Allocated 0x{:X} bytes at 0x{:X}.", region.get_region_size(), region.get_base_address()),
+        (None, Some(error)) => log::error!("Failed to allocate memory: {}", error),
+        (None, None) => log::error!("Failed to allocate memory."),
+    }
+}
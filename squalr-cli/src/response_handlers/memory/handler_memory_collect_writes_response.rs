@@ -0,0 +1,64 @@
+use squalr_engine_api::commands::memory::collect_writes::memory_collect_writes_response::MemoryCollectWritesResponse;
+use squalr_engine_api::structures::memory::written_page::WrittenPage;
+
+pub fn handle_memory_collect_writes_response(memory_collect_writes_response: MemoryCollectWritesResponse) {
+    if let Some(error) = memory_collect_writes_response.error {
+        log::error!("Failed to collect writes: {}", error);
+        return;
+    }
+
+    let mut written_pages = memory_collect_writes_response.written_pages;
+
+    // List the most heavily written pages first.
+    written_pages.sort_by_key(|written_page| std::cmp::Reverse(written_page.get_changed_byte_count()));
+
+    for written_page in &written_pages {
+        log::info!("{}", format_written_page(written_page));
+    }
+
+    log::info!(
+        "{} page(s) were written out of 0x{:X} tracked bytes.",
+        written_pages.len(),
+        memory_collect_writes_response.tracked_byte_count
+    );
+
+    if memory_collect_writes_response.is_next_scan_narrowed {
+        log::info!("The next new scan only reads the written pages.");
+    }
+}
+
+fn format_written_page(written_page: &WrittenPage) -> String {
+    let page_region = written_page.get_page_region();
+    let page_location = if written_page.get_module_name().is_empty() {
+        format!("0x{:X}", page_region.get_base_address())
+    } else {
+        format!(
+            "0x{:X} ({}+0x{:X})",
+            page_region.get_base_address(),
+            written_page.get_module_name(),
+            written_page.get_module_offset()
+        )
+    };
+    let changed_offsets = match written_page.get_changed_regions() {
+        Some([]) => String::from("no bytes changed"),
+        Some(changed_regions) => changed_regions
+            .iter()
+            .map(|changed_region| {
+                format!(
+                    "+0x{:X}[{}]",
+                    changed_region.get_base_address() - page_region.get_base_address(),
+                    changed_region.get_region_size()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+        None => String::from("contents not captured"),
+    };
+
+    format!(
+        "{}: {} byte(s) changed: {}",
+        page_location,
+        written_page.get_changed_byte_count(),
+        changed_offsets
+    )
+}
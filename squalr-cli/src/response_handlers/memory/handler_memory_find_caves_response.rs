@@ -0,0 +1,27 @@
+use squalr_engine_api::commands::memory::find_caves::memory_find_caves_response::MemoryFindCavesResponse;
+
+pub fn handle_memory_find_caves_response(memory_find_caves_response: MemoryFindCavesResponse) {
+    if let Some(error) = memory_find_caves_response.error {
+        log::error!("Failed to find code caves: {}", error);
+        return;
+    }
+
+    for code_cave in &memory_find_caves_response.code_caves {
+        let distance = match memory_find_caves_response.target_address {
+            Some(target_address) => format!(", 0x{:X} bytes from the target", code_cave.get_distance(target_address)),
+            None => String::new(),
+        };
+
+        log::info!(
+            "0x{:X} ({}+0x{:X}): 0x{:X} bytes of {} padding{}",
+            code_cave.get_base_address(),
+            code_cave.get_module_name(),
+            code_cave.get_module_offset(),
+            code_cave.get_size(),
+            code_cave.get_padding(),
+            distance
+        );
+    }
+
+    log::info!("Found {} code cave(s).", memory_find_caves_response.code_caves.len());
+}
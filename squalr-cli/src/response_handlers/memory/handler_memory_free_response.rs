@@ -0,0 +1,9 @@
+use squalr_engine_api::commands::memory::free::memory_free_response::MemoryFreeResponse;
+
+pub fn handle_memory_free_response(memory_free_response: MemoryFreeResponse) {
+    match (memory_free_response.region, memory_free_response.error) {
+        (Some(region), _) => log::info!("Freed 0x{:X} bytes at 0x{:X}.", region.get_region_size(), region.get_base_address()),
+        (None, Some(error)) => log::error!("Failed to free memory: {}", error),
+        (None, None) => log::error!("Failed to free memory."),
+    }
+}
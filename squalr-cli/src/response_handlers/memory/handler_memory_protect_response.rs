@@ -0,0 +1,9 @@
+use squalr_engine_api::commands::memory::protect::memory_protect_response::MemoryProtectResponse;
+
+pub fn handle_memory_protect_response(memory_protect_response: MemoryProtectResponse) {
+    match (memory_protect_response.region, memory_protect_response.error) {
+        (Some(region), _) => log::info!("Reprotected 0x{:X} bytes at 0x{:X}.", region.get_region_size(), region.get_base_address()),
+        (None, Some(error)) => log::error!("Failed to change protection of memory: {}", error),
+        (None, None) => log::error!("Failed to change protection of memory."),
+    }
+}
@@ -0,0 +1,18 @@
+use squalr_engine_api::commands::memory::track_writes::memory_track_writes_response::MemoryTrackWritesResponse;
+
+pub fn handle_memory_track_writes_response(memory_track_writes_response: MemoryTrackWritesResponse) {
+    if let Some(error) = memory_track_writes_response.error {
+        log::error!("Failed to start tracking writes: {}", error);
+        return;
+    }
+
+    log::info!(
+        "Tracking writes to 0x{:X} bytes across {} region(s). Perform the in-game action, then run `memory collect-writes`.",
+        memory_track_writes_response.tracked_byte_count,
+        memory_track_writes_response.tracked_region_count
+    );
+
+    if !memory_track_writes_response.is_page_tracking_supported {
+        log::warn!("The OS does not report written pages, so only pages whose contents change will be found.");
+    }
+}
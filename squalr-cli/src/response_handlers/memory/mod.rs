@@ -1,11 +1,23 @@
+pub mod handler_memory_allocate_response;
+pub mod handler_memory_collect_writes_response;
+pub mod handler_memory_find_caves_response;
+pub mod handler_memory_free_response;
 pub mod handler_memory_freeze_response;
+pub mod handler_memory_protect_response;
 pub mod handler_memory_query_response;
 pub mod handler_memory_read_response;
+pub mod handler_memory_track_writes_response;
 pub mod handler_memory_write_response;
 
+use crate::response_handlers::memory::handler_memory_allocate_response::handle_memory_allocate_response;
+use crate::response_handlers::memory::handler_memory_collect_writes_response::handle_memory_collect_writes_response;
+use crate::response_handlers::memory::handler_memory_find_caves_response::handle_memory_find_caves_response;
+use crate::response_handlers::memory::handler_memory_free_response::handle_memory_free_response;
 use crate::response_handlers::memory::handler_memory_freeze_response::handle_memory_response_freeze;
+use crate::response_handlers::memory::handler_memory_protect_response::handle_memory_protect_response;
 use crate::response_handlers::memory::handler_memory_query_response::handle_memory_query_response;
 use crate::response_handlers::memory::handler_memory_read_response::handle_memory_read_response;
+use crate::response_handlers::memory::handler_memory_track_writes_response::handle_memory_track_writes_response;
 use crate::response_handlers::memory::handler_memory_write_response::handle_memory_response_write;
 use squalr_engine_api::commands::memory::memory_response::MemoryResponse;
 
@@ -15,5 +27,13 @@ pub fn handle_memory_response(cmd: MemoryResponse) {
         MemoryResponse::Query { memory_query_response } => handle_memory_query_response(memory_query_response),
         MemoryResponse::Read { memory_read_response } => handle_memory_read_response(memory_read_response),
         MemoryResponse::Write { memory_write_response } => handle_memory_response_write(memory_write_response),
+        MemoryResponse::Allocate { memory_allocate_response } => handle_memory_allocate_response(memory_allocate_response),
+        MemoryResponse::Free { memory_free_response } => handle_memory_free_response(memory_free_response),
+        MemoryResponse::Protect { memory_protect_response } => handle_memory_protect_response(memory_protect_response),
+        MemoryResponse::TrackWrites { memory_track_writes_response } => handle_memory_track_writes_response(memory_track_writes_response),
+        MemoryResponse::CollectWrites {
+            memory_collect_writes_response,
+        } => handle_memory_collect_writes_response(memory_collect_writes_response),
+        MemoryResponse::FindCaves { memory_find_caves_response } => handle_memory_find_caves_response(memory_find_caves_response),
     }
 }
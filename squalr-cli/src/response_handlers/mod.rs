@@ -1,4 +1,6 @@
+mod debugger;
 mod memory;
+mod patch;
 mod plugins;
 mod pointer_scan;
 mod process;
@@ -9,8 +11,11 @@ mod scan;
 mod scan_results;
 mod settings;
 mod struct_scan;
+mod thread;
 
+use crate::response_handlers::debugger::handle_debugger_response;
 use crate::response_handlers::memory::handle_memory_response;
+use crate::response_handlers::patch::handle_patch_response;
 use crate::response_handlers::plugins::handle_plugins_response;
 use crate::response_handlers::pointer_scan::handle_pointer_scan_response;
 use crate::response_handlers::process::handle_process_response;
@@ -21,13 +26,16 @@ use crate::response_handlers::scan::handle_scan_response;
 use crate::response_handlers::scan_results::handle_scan_results_response;
 use crate::response_handlers::settings::handle_settings_response;
 use crate::response_handlers::struct_scan::handle_struct_scan_response;
+use crate::response_handlers::thread::handle_thread_response;
 use squalr_engine_api::commands::privileged_command_response::PrivilegedCommandResponse;
 use squalr_engine_api::commands::unprivileged_command_response::UnprivilegedCommandResponse;
 
 pub fn handle_privileged_engine_response(response: PrivilegedCommandResponse) {
     match response {
         PrivilegedCommandResponse::Scan(response) => handle_scan_response(response),
+        PrivilegedCommandResponse::Debugger(response) => handle_debugger_response(response),
         PrivilegedCommandResponse::Memory(response) => handle_memory_response(response),
+        PrivilegedCommandResponse::Patch(response) => handle_patch_response(*response),
         PrivilegedCommandResponse::Plugins(response) => handle_plugins_response(response),
         PrivilegedCommandResponse::Process(response) => handle_process_response(response),
         PrivilegedCommandResponse::Registry(_response) => {}
@@ -36,6 +44,7 @@ pub fn handle_privileged_engine_response(response: PrivilegedCommandResponse) {
         PrivilegedCommandResponse::PointerScan(response) => handle_pointer_scan_response(response),
         PrivilegedCommandResponse::StructScan(response) => handle_struct_scan_response(response),
         PrivilegedCommandResponse::Settings(response) => handle_settings_response(response),
+        PrivilegedCommandResponse::Thread(response) => handle_thread_response(response),
         PrivilegedCommandResponse::ProjectItems(response) => {
             log::debug!("Unhandled project items response: {:?}", response);
         }
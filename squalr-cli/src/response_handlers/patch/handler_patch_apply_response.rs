@@ -0,0 +1,20 @@
+use squalr_engine_api::commands::patch::apply::patch_apply_response::PatchApplyResponse;
+
+pub fn handle_patch_apply_response(patch_apply_response: PatchApplyResponse) {
+    match (patch_apply_response.code_patch, patch_apply_response.error) {
+        (Some(code_patch), _) => {
+            log::info!(
+                "Applied {} patch of 0x{:X} bytes at 0x{:X}.",
+                code_patch.get_definition().kind,
+                code_patch.get_patch_size(),
+                code_patch.get_patch_address()
+            );
+
+            if let Some(code_cave) = code_patch.get_code_cave() {
+                log::info!("Detour code cave is at 0x{:X}.", code_cave.get_base_address());
+            }
+        }
+        (None, Some(error)) => log::error!("Failed to apply patch: {}", error),
+        (None, None) => log::error!("Failed to apply patch."),
+    }
+}
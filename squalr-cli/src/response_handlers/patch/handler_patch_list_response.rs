@@ -0,0 +1,25 @@
+use squalr_engine_api::commands::patch::list::patch_list_response::PatchListResponse;
+
+pub fn handle_patch_list_response(patch_list_response: PatchListResponse) {
+    if patch_list_response.code_patches.is_empty() {
+        log::info!("No patches are applied.");
+        return;
+    }
+
+    for code_patch in patch_list_response.code_patches {
+        let original_bytes = code_patch
+            .get_original_bytes()
+            .iter()
+            .map(|original_byte| format!("{:02X}", original_byte))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        log::info!(
+            "0x{:X} ({} patch, 0x{:X} bytes, original: {})",
+            code_patch.get_patch_address(),
+            code_patch.get_definition().kind,
+            code_patch.get_patch_size(),
+            original_bytes
+        );
+    }
+}
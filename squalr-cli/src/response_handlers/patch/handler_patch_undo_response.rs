@@ -0,0 +1,13 @@
+use squalr_engine_api::commands::patch::undo::patch_undo_response::PatchUndoResponse;
+
+pub fn handle_patch_undo_response(patch_undo_response: PatchUndoResponse) {
+    match (patch_undo_response.code_patch, patch_undo_response.error) {
+        (Some(code_patch), _) => log::info!(
+            "Restored 0x{:X} original bytes at 0x{:X}.",
+            code_patch.get_patch_size(),
+            code_patch.get_patch_address()
+        ),
+        (None, Some(error)) => log::error!("Failed to undo patch: {}", error),
+        (None, None) => log::error!("Failed to undo patch."),
+    }
+}
@@ -0,0 +1,16 @@
+pub mod handler_patch_apply_response;
+pub mod handler_patch_list_response;
+pub mod handler_patch_undo_response;
+
+use crate::response_handlers::patch::handler_patch_apply_response::handle_patch_apply_response;
+use crate::response_handlers::patch::handler_patch_list_response::handle_patch_list_response;
+use crate::response_handlers::patch::handler_patch_undo_response::handle_patch_undo_response;
+use squalr_engine_api::commands::patch::patch_response::PatchResponse;
+
+pub fn handle_patch_response(cmd: PatchResponse) {
+    match cmd {
+        PatchResponse::Apply { patch_apply_response } => handle_patch_apply_response(patch_apply_response),
+        PatchResponse::List { patch_list_response } => handle_patch_list_response(patch_list_response),
+        PatchResponse::Undo { patch_undo_response } => handle_patch_undo_response(patch_undo_response),
+    }
+}
@@ -0,0 +1,19 @@
+use squalr_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_inject_library_response(process_response: ProcessResponse) {
+    if let ProcessResponse::InjectLibrary {
+        process_inject_library_response,
+    } = process_response
+    {
+        if let Some(error) = process_inject_library_response.error {
+            log::error!("Failed to inject library: {}", error);
+        } else if let Some(injected_module) = process_inject_library_response.injected_module {
+            log::info!(
+                "Injected {} at 0x{:X} ({} modules loaded).",
+                injected_module.get_module_name(),
+                injected_module.get_base_address(),
+                process_inject_library_response.modules.len()
+            );
+        }
+    }
+}
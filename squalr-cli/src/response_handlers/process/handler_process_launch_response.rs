@@ -0,0 +1,15 @@
+use squalr_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_launch_response(process_response: ProcessResponse) {
+    if let ProcessResponse::Launch { process_launch_response } = process_response {
+        if let Some(error) = process_launch_response.error {
+            log::error!("Failed to launch process: {}", error);
+        } else if let Some(process_info) = process_launch_response.opened_process_info {
+            log::info!(
+                "Launched and opened process_id: {}, Name: {}",
+                process_info.get_process_id_raw(),
+                process_info.get_name()
+            );
+        }
+    }
+}
@@ -1,10 +1,14 @@
 pub mod handler_process_close_response;
 pub mod handler_process_icon_response;
+pub mod handler_process_inject_library_response;
+pub mod handler_process_launch_response;
 pub mod handler_process_list_response;
 pub mod handler_process_open_response;
 
 use crate::response_handlers::process::handler_process_close_response::handle_process_close_response;
 use crate::response_handlers::process::handler_process_icon_response::handle_process_icon_response;
+use crate::response_handlers::process::handler_process_inject_library_response::handle_process_inject_library_response;
+use crate::response_handlers::process::handler_process_launch_response::handle_process_launch_response;
 use crate::response_handlers::process::handler_process_list_response::handle_process_list_response;
 use crate::response_handlers::process::handler_process_open_response::handle_process_open_response;
 use squalr_engine_api::commands::process::process_response::ProcessResponse;
@@ -15,5 +19,7 @@ pub fn handle_process_response(response: ProcessResponse) {
         ProcessResponse::Icon { .. } => handle_process_icon_response(response),
         ProcessResponse::Close { .. } => handle_process_close_response(response),
         ProcessResponse::Open { .. } => handle_process_open_response(response),
+        ProcessResponse::Launch { .. } => handle_process_launch_response(response),
+        ProcessResponse::InjectLibrary { .. } => handle_process_inject_library_response(response),
     }
 }
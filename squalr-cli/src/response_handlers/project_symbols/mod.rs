@@ -67,14 +67,67 @@ pub fn handle_project_symbols_response(project_symbols_response: ProjectSymbolsR
                 project_symbols_create_module_response.module_name
             );
         }
+        ProjectSymbolsResponse::References {
+            project_symbols_references_response,
+        } => {
+            if project_symbols_references_response.success {
+                log::info!(
+                    "symbol references: target={}, count={}",
+                    project_symbols_references_response.target,
+                    project_symbols_references_response.references.len()
+                );
+
+                for reference in &project_symbols_references_response.references {
+                    log::info!("  {}", reference);
+                }
+            } else {
+                log::error!(
+                    "symbol references: success=false, error={}",
+                    project_symbols_references_response
+                        .error
+                        .as_deref()
+                        .unwrap_or("unknown error")
+                );
+            }
+        }
         ProjectSymbolsResponse::Rename {
             project_symbols_rename_response,
         } => {
             log::info!(
-                "renamed symbol claim: success={}, symbol_locator_key={}",
+                "renamed symbol claim: success={}, symbol_locator_key={}, dry_run={}, edits={}",
                 project_symbols_rename_response.success,
-                project_symbols_rename_response.symbol_locator_key
+                project_symbols_rename_response.symbol_locator_key,
+                project_symbols_rename_response.dry_run,
+                project_symbols_rename_response.edits.len()
             );
+
+            for edit in &project_symbols_rename_response.edits {
+                log::info!("{}", edit);
+            }
+        }
+        ProjectSymbolsResponse::RenameLayout {
+            project_symbols_rename_layout_response,
+        } => {
+            if project_symbols_rename_layout_response.success {
+                log::info!(
+                    "renamed symbol layout: success=true, id={}, dry_run={}, edits={}",
+                    project_symbols_rename_layout_response.struct_layout_id,
+                    project_symbols_rename_layout_response.dry_run,
+                    project_symbols_rename_layout_response.edits.len()
+                );
+
+                for edit in &project_symbols_rename_layout_response.edits {
+                    log::info!("{}", edit);
+                }
+            } else {
+                log::error!(
+                    "renamed symbol layout: success=false, error={}",
+                    project_symbols_rename_layout_response
+                        .error
+                        .as_deref()
+                        .unwrap_or("unknown error")
+                );
+            }
         }
         ProjectSymbolsResponse::RenameModule {
             project_symbols_rename_module_response,
@@ -94,6 +147,60 @@ pub fn handle_project_symbols_response(project_symbols_response: ProjectSymbolsR
                 project_symbols_update_response.symbol_locator_key
             );
         }
+        ProjectSymbolsResponse::Export {
+            project_symbols_export_response,
+        } => {
+            for warning in &project_symbols_export_response.warnings {
+                log::warn!("{}", warning);
+            }
+
+            if project_symbols_export_response.success {
+                log::info!(
+                    "exported symbol layouts: success=true, count={}, path={}",
+                    project_symbols_export_response.exported_struct_layout_ids.len(),
+                    project_symbols_export_response
+                        .output_file_path
+                        .as_ref()
+                        .map(|output_file_path| output_file_path.display().to_string())
+                        .unwrap_or_default()
+                );
+            } else {
+                log::error!(
+                    "exported symbol layouts: success=false, error={}",
+                    project_symbols_export_response
+                        .error
+                        .as_deref()
+                        .unwrap_or("unknown error")
+                );
+            }
+        }
+        ProjectSymbolsResponse::ImportHeader {
+            project_symbols_import_header_response,
+        } => {
+            for warning in &project_symbols_import_header_response.warnings {
+                log::warn!("{}", warning);
+            }
+
+            if project_symbols_import_header_response.success {
+                log::info!(
+                    "imported symbol layouts: success=true, count={}, ids={}",
+                    project_symbols_import_header_response
+                        .imported_struct_layout_ids
+                        .len(),
+                    project_symbols_import_header_response
+                        .imported_struct_layout_ids
+                        .join(", ")
+                );
+            } else {
+                log::error!(
+                    "imported symbol layouts: success=false, error={}",
+                    project_symbols_import_header_response
+                        .error
+                        .as_deref()
+                        .unwrap_or("unknown error")
+                );
+            }
+        }
         ProjectSymbolsResponse::UpsertLayout {
             project_symbols_upsert_layout_response,
         } => {
@@ -0,0 +1,69 @@
+use squalr_engine_api::commands::thread::context::thread_context_response::ThreadContextResponse;
+use squalr_engine_api::commands::thread::thread_response::ThreadResponse;
+use squalr_engine_api::structures::processes::thread_info::ThreadInfo;
+
+pub fn handle_thread_response(response: ThreadResponse) {
+    match response {
+        ThreadResponse::Context { thread_context_response } => handle_thread_context_response(thread_context_response),
+        ThreadResponse::List { thread_list_response } => log_threads(&thread_list_response.threads, thread_list_response.error.as_deref()),
+        ThreadResponse::Resume { thread_resume_response } => {
+            if thread_resume_response.error.is_none() {
+                log::info!("Threads resumed.");
+            }
+
+            log_threads(&thread_resume_response.threads, thread_resume_response.error.as_deref())
+        }
+        ThreadResponse::Suspend { thread_suspend_response } => {
+            if thread_suspend_response.error.is_none() {
+                log::info!("Threads suspended.");
+            }
+
+            log_threads(&thread_suspend_response.threads, thread_suspend_response.error.as_deref())
+        }
+    }
+}
+
+fn handle_thread_context_response(thread_context_response: ThreadContextResponse) {
+    match (thread_context_response.register_snapshot, thread_context_response.error) {
+        (Some(register_snapshot), _) => {
+            log::info!("Thread {} context:", register_snapshot.get_thread_id());
+
+            for register_value in register_snapshot.get_registers() {
+                log::info!("  {:<8} 0x{:016X}", register_value.get_name(), register_value.get_value());
+            }
+
+            for vector_register_value in register_snapshot.get_vector_registers() {
+                log::info!("  {:<8} {}", vector_register_value.get_name(), vector_register_value.format_hex());
+            }
+        }
+        (None, Some(error)) => log::error!("Failed to read thread context: {}", error),
+        (None, None) => log::error!("Failed to read thread context."),
+    }
+}
+
+fn log_threads(
+    threads: &[ThreadInfo],
+    error: Option<&str>,
+) {
+    if let Some(error) = error {
+        log::error!("{}", error);
+    }
+
+    for thread_info in threads {
+        let stack_description = match thread_info.get_stack_region() {
+            Some(stack_region) => format!("0x{:X}-0x{:X}", stack_region.get_base_address(), stack_region.get_end_address()),
+            None => String::from("unknown"),
+        };
+        let suspended_marker = if thread_info.get_is_suspended() { " [suspended]" } else { "" };
+
+        log::info!(
+            "{} {:?} ({}{}, cpu {} ms, stack {})",
+            thread_info.get_thread_id(),
+            thread_info.get_name(),
+            thread_info.get_thread_state(),
+            suspended_marker,
+            thread_info.get_cpu_time_ms(),
+            stack_description
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate as api;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) enum CommandLineDebuggerCommand {
+    Watch {
+        #[structopt(flatten)]
+        debugger_watch_request: CommandLineDebuggerWatchRequest,
+    },
+    Hits {
+        #[structopt(flatten)]
+        debugger_hits_request: CommandLineDebuggerHitsRequest,
+    },
+    Unwatch {
+        #[structopt(flatten)]
+        debugger_unwatch_request: CommandLineDebuggerUnwatchRequest,
+    },
+    #[structopt(alias = "bp")]
+    SetBreakpoint {
+        #[structopt(flatten)]
+        debugger_set_breakpoint_request: CommandLineDebuggerSetBreakpointRequest,
+    },
+    #[structopt(alias = "bc")]
+    RemoveBreakpoint {
+        #[structopt(flatten)]
+        debugger_remove_breakpoint_request: CommandLineDebuggerRemoveBreakpointRequest,
+    },
+    #[structopt(alias = "bl")]
+    ListBreakpoints,
+    Trace {
+        #[structopt(flatten)]
+        debugger_trace_request: CommandLineDebuggerTraceRequest,
+    },
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineDebuggerWatchRequest {
+    #[structopt(short = "a", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub address: u64,
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+    #[structopt(short = "s", long = "size", default_value = "4")]
+    pub watchpoint_size: api::structures::debugging::watchpoint_size::WatchpointSize,
+    #[structopt(long = "access", default_value = "write")]
+    pub watchpoint_access: api::structures::debugging::watchpoint_access::WatchpointAccess,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineDebuggerHitsRequest {
+    #[structopt(short = "i", long = "id")]
+    pub watchpoint_id: u64,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineDebuggerUnwatchRequest {
+    #[structopt(short = "i", long = "id")]
+    pub watchpoint_id: u64,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineDebuggerSetBreakpointRequest {
+    #[structopt(short = "a", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub address: u64,
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+    #[structopt(long = "mode", default_value = "trace")]
+    pub breakpoint_mode: api::structures::debugging::breakpoint_mode::BreakpointMode,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineDebuggerRemoveBreakpointRequest {
+    #[structopt(short = "i", long = "id")]
+    pub breakpoint_id: u64,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineDebuggerTraceRequest {
+    #[structopt(short = "a", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub start_address: u64,
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+    #[structopt(short = "e", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub end_address: Option<u64>,
+    #[structopt(short = "t", long)]
+    pub thread_id: Option<u32>,
+    #[structopt(short = "b", long = "budget", default_value = "10000")]
+    pub instruction_budget: u64,
+    #[structopt(long = "timeout", default_value = "10000")]
+    pub start_timeout_ms: u64,
+    #[structopt(long = "instruction-set", default_value = "")]
+    pub instruction_set_id: String,
+    #[structopt(short = "o", long = "output")]
+    pub trace_file_path: Option<PathBuf>,
+}
+
+impl From<CommandLineDebuggerCommand> for api::commands::debugger::debugger_command::DebuggerCommand {
+    fn from(command: CommandLineDebuggerCommand) -> Self {
+        match command {
+            CommandLineDebuggerCommand::Watch { debugger_watch_request } => Self::Watch {
+                debugger_watch_request: debugger_watch_request.into(),
+            },
+            CommandLineDebuggerCommand::Hits { debugger_hits_request } => Self::Hits {
+                debugger_hits_request: debugger_hits_request.into(),
+            },
+            CommandLineDebuggerCommand::Unwatch { debugger_unwatch_request } => Self::Unwatch {
+                debugger_unwatch_request: debugger_unwatch_request.into(),
+            },
+            CommandLineDebuggerCommand::SetBreakpoint {
+                debugger_set_breakpoint_request,
+            } => Self::SetBreakpoint {
+                debugger_set_breakpoint_request: debugger_set_breakpoint_request.into(),
+            },
+            CommandLineDebuggerCommand::RemoveBreakpoint {
+                debugger_remove_breakpoint_request,
+            } => Self::RemoveBreakpoint {
+                debugger_remove_breakpoint_request: debugger_remove_breakpoint_request.into(),
+            },
+            CommandLineDebuggerCommand::ListBreakpoints => Self::ListBreakpoints {
+                debugger_list_breakpoints_request:
+                    api::commands::debugger::list_breakpoints::debugger_list_breakpoints_request::DebuggerListBreakpointsRequest::default(),
+            },
+            CommandLineDebuggerCommand::Trace { debugger_trace_request } => Self::Trace {
+                debugger_trace_request: debugger_trace_request.into(),
+            },
+        }
+    }
+}
+
+impl From<CommandLineDebuggerWatchRequest> for api::commands::debugger::watch::debugger_watch_request::DebuggerWatchRequest {
+    fn from(request: CommandLineDebuggerWatchRequest) -> Self {
+        Self {
+            address: request.address,
+            module_name: request.module_name,
+            watchpoint_size: request.watchpoint_size,
+            watchpoint_access: request.watchpoint_access,
+        }
+    }
+}
+
+impl From<CommandLineDebuggerHitsRequest> for api::commands::debugger::hits::debugger_hits_request::DebuggerHitsRequest {
+    fn from(request: CommandLineDebuggerHitsRequest) -> Self {
+        Self {
+            watchpoint_id: request.watchpoint_id,
+        }
+    }
+}
+
+impl From<CommandLineDebuggerUnwatchRequest> for api::commands::debugger::unwatch::debugger_unwatch_request::DebuggerUnwatchRequest {
+    fn from(request: CommandLineDebuggerUnwatchRequest) -> Self {
+        Self {
+            watchpoint_id: request.watchpoint_id,
+        }
+    }
+}
+
+impl From<CommandLineDebuggerSetBreakpointRequest> for api::commands::debugger::set_breakpoint::debugger_set_breakpoint_request::DebuggerSetBreakpointRequest {
+    fn from(request: CommandLineDebuggerSetBreakpointRequest) -> Self {
+        Self {
+            address: request.address,
+            module_name: request.module_name,
+            breakpoint_mode: request.breakpoint_mode,
+        }
+    }
+}
+
+impl From<CommandLineDebuggerRemoveBreakpointRequest>
+    for api::commands::debugger::remove_breakpoint::debugger_remove_breakpoint_request::DebuggerRemoveBreakpointRequest
+{
+    fn from(request: CommandLineDebuggerRemoveBreakpointRequest) -> Self {
+        Self {
+            breakpoint_id: request.breakpoint_id,
+        }
+    }
+}
+
+impl From<CommandLineDebuggerTraceRequest> for api::commands::debugger::trace::debugger_trace_request::DebuggerTraceRequest {
+    fn from(request: CommandLineDebuggerTraceRequest) -> Self {
+        Self {
+            start_address: request.start_address,
+            module_name: request.module_name,
+            end_address: request.end_address,
+            thread_id: request.thread_id,
+            instruction_budget: request.instruction_budget,
+            start_timeout_ms: request.start_timeout_ms,
+            instruction_set_id: request.instruction_set_id,
+            trace_file_path: request.trace_file_path,
+        }
+    }
+}
@@ -3,10 +3,23 @@ use structopt::StructOpt;
 
 #[derive(Clone, StructOpt, Debug)]
 pub(crate) enum CommandLineMemoryCommand {
+    #[structopt(alias = "alloc")]
+    Allocate {
+        #[structopt(flatten)]
+        memory_allocate_request: CommandLineMemoryAllocateRequest,
+    },
+    Free {
+        #[structopt(flatten)]
+        memory_free_request: CommandLineMemoryFreeRequest,
+    },
     Freeze {
         #[structopt(flatten)]
         memory_freeze_request: CommandLineMemoryFreezeRequest,
     },
+    Protect {
+        #[structopt(flatten)]
+        memory_protect_request: CommandLineMemoryProtectRequest,
+    },
     Query {
         #[structopt(flatten)]
         memory_query_request: CommandLineMemoryQueryRequest,
@@ -19,6 +32,36 @@ pub(crate) enum CommandLineMemoryCommand {
         #[structopt(flatten)]
         memory_write_request: CommandLineMemoryWriteRequest,
     },
+    TrackWrites {
+        #[structopt(flatten)]
+        memory_track_writes_request: CommandLineMemoryTrackWritesRequest,
+    },
+    CollectWrites {
+        #[structopt(flatten)]
+        memory_collect_writes_request: CommandLineMemoryCollectWritesRequest,
+    },
+    FindCaves {
+        #[structopt(flatten)]
+        memory_find_caves_request: CommandLineMemoryFindCavesRequest,
+    },
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineMemoryAllocateRequest {
+    #[structopt(short = "s", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub size: u64,
+    #[structopt(short = "p", long, default_value = "rw-")]
+    pub protection: api::structures::memory::memory_protection::MemoryProtection,
+    #[structopt(short = "a", long = "near", default_value = "0", parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub preferred_address: u64,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineMemoryFreeRequest {
+    #[structopt(short = "a", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub address: u64,
+    #[structopt(short = "s", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub size: u64,
 }
 
 #[derive(Clone, StructOpt, Debug, Default)]
@@ -27,6 +70,18 @@ pub(crate) struct CommandLineMemoryFreezeRequest {
     pub is_frozen: bool,
 }
 
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineMemoryProtectRequest {
+    #[structopt(short = "a", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub address: u64,
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+    #[structopt(short = "s", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub size: u64,
+    #[structopt(short = "p", long)]
+    pub protection: api::structures::memory::memory_protection::MemoryProtection,
+}
+
 #[derive(Clone, StructOpt, Debug)]
 pub(crate) struct CommandLineMemoryQueryRequest {
     #[structopt(short = "p", long, default_value = "usermode")]
@@ -43,6 +98,8 @@ pub(crate) struct CommandLineMemoryReadRequest {
     pub symbolic_struct_definition: api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition,
     #[structopt(long)]
     pub suppress_logging: bool,
+    #[structopt(long)]
+    pub value_transform: Option<api::plugins::value_transform::ValueTransformRef>,
 }
 
 #[derive(Clone, StructOpt, Debug)]
@@ -53,14 +110,50 @@ pub(crate) struct CommandLineMemoryWriteRequest {
     pub module_name: String,
     #[structopt(short = "v")]
     pub value: Vec<u8>,
+    #[structopt(long)]
+    pub value_transform: Option<api::plugins::value_transform::ValueTransformRef>,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineMemoryTrackWritesRequest {
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+    #[structopt(short = "a", long, default_value = "0", parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub address: u64,
+    #[structopt(short = "s", long, default_value = "0", parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub size: u64,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineMemoryCollectWritesRequest {
+    #[structopt(long = "no-narrow")]
+    pub is_scan_narrowing_skipped: bool,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineMemoryFindCavesRequest {
+    pub module_name: String,
+    #[structopt(short = "s", long = "min-size", default_value = "32", parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub minimum_size: u64,
+    #[structopt(short = "a", long = "near", parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub target_address: Option<u64>,
 }
 
 impl From<CommandLineMemoryCommand> for api::commands::memory::memory_command::MemoryCommand {
     fn from(command: CommandLineMemoryCommand) -> Self {
         match command {
+            CommandLineMemoryCommand::Allocate { memory_allocate_request } => Self::Allocate {
+                memory_allocate_request: memory_allocate_request.into(),
+            },
+            CommandLineMemoryCommand::Free { memory_free_request } => Self::Free {
+                memory_free_request: memory_free_request.into(),
+            },
             CommandLineMemoryCommand::Freeze { memory_freeze_request } => Self::Freeze {
                 memory_freeze_request: memory_freeze_request.into(),
             },
+            CommandLineMemoryCommand::Protect { memory_protect_request } => Self::Protect {
+                memory_protect_request: memory_protect_request.into(),
+            },
             CommandLineMemoryCommand::Query { memory_query_request } => Self::Query {
                 memory_query_request: memory_query_request.into(),
             },
@@ -70,6 +163,34 @@ impl From<CommandLineMemoryCommand> for api::commands::memory::memory_command::M
             CommandLineMemoryCommand::Write { memory_write_request } => Self::Write {
                 memory_write_request: memory_write_request.into(),
             },
+            CommandLineMemoryCommand::TrackWrites { memory_track_writes_request } => Self::TrackWrites {
+                memory_track_writes_request: memory_track_writes_request.into(),
+            },
+            CommandLineMemoryCommand::CollectWrites { memory_collect_writes_request } => Self::CollectWrites {
+                memory_collect_writes_request: memory_collect_writes_request.into(),
+            },
+            CommandLineMemoryCommand::FindCaves { memory_find_caves_request } => Self::FindCaves {
+                memory_find_caves_request: memory_find_caves_request.into(),
+            },
+        }
+    }
+}
+
+impl From<CommandLineMemoryAllocateRequest> for api::commands::memory::allocate::memory_allocate_request::MemoryAllocateRequest {
+    fn from(request: CommandLineMemoryAllocateRequest) -> Self {
+        Self {
+            size: request.size,
+            protection: request.protection,
+            preferred_address: request.preferred_address,
+        }
+    }
+}
+
+impl From<CommandLineMemoryFreeRequest> for api::commands::memory::free::memory_free_request::MemoryFreeRequest {
+    fn from(request: CommandLineMemoryFreeRequest) -> Self {
+        Self {
+            address: request.address,
+            size: request.size,
         }
     }
 }
@@ -83,6 +204,17 @@ impl From<CommandLineMemoryFreezeRequest> for api::commands::memory::freeze::mem
     }
 }
 
+impl From<CommandLineMemoryProtectRequest> for api::commands::memory::protect::memory_protect_request::MemoryProtectRequest {
+    fn from(request: CommandLineMemoryProtectRequest) -> Self {
+        Self {
+            address: request.address,
+            module_name: request.module_name,
+            size: request.size,
+            protection: request.protection,
+        }
+    }
+}
+
 impl From<CommandLineMemoryQueryRequest> for api::commands::memory::query::memory_query_request::MemoryQueryRequest {
     fn from(request: CommandLineMemoryQueryRequest) -> Self {
         Self {
@@ -98,6 +230,7 @@ impl From<CommandLineMemoryReadRequest> for api::commands::memory::read::memory_
             module_name: request.module_name,
             symbolic_struct_definition: request.symbolic_struct_definition,
             suppress_logging: request.suppress_logging,
+            value_transform: request.value_transform,
         }
     }
 }
@@ -108,6 +241,35 @@ impl From<CommandLineMemoryWriteRequest> for api::commands::memory::write::memor
             address: request.address,
             module_name: request.module_name,
             value: request.value,
+            value_transform: request.value_transform,
+        }
+    }
+}
+
+impl From<CommandLineMemoryTrackWritesRequest> for api::commands::memory::track_writes::memory_track_writes_request::MemoryTrackWritesRequest {
+    fn from(request: CommandLineMemoryTrackWritesRequest) -> Self {
+        Self {
+            module_name: request.module_name,
+            address: request.address,
+            size: request.size,
+        }
+    }
+}
+
+impl From<CommandLineMemoryCollectWritesRequest> for api::commands::memory::collect_writes::memory_collect_writes_request::MemoryCollectWritesRequest {
+    fn from(request: CommandLineMemoryCollectWritesRequest) -> Self {
+        Self {
+            narrow_next_scan: !request.is_scan_narrowing_skipped,
+        }
+    }
+}
+
+impl From<CommandLineMemoryFindCavesRequest> for api::commands::memory::find_caves::memory_find_caves_request::MemoryFindCavesRequest {
+    fn from(request: CommandLineMemoryFindCavesRequest) -> Self {
+        Self {
+            module_name: request.module_name,
+            minimum_size: request.minimum_size,
+            target_address: request.target_address,
         }
     }
 }
@@ -1,9 +1,11 @@
 pub use structopt::clap;
 
 mod command;
+mod debugger;
 mod memory;
 mod parse_error;
 mod parser;
+mod patch;
 mod plugins;
 mod pointer_scan;
 mod process;
@@ -17,6 +19,7 @@ mod scan;
 mod scan_results;
 mod settings;
 mod struct_scan;
+mod thread;
 mod trackable_tasks;
 
 pub use command::CommandLineCommand;
@@ -0,0 +1,117 @@
+use crate as api;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) enum CommandLinePatchCommand {
+    Nop {
+        #[structopt(flatten)]
+        patch_nop_request: CommandLinePatchNopRequest,
+    },
+    Replace {
+        #[structopt(flatten)]
+        patch_replace_request: CommandLinePatchAssemblyRequest,
+    },
+    Detour {
+        #[structopt(flatten)]
+        patch_detour_request: CommandLinePatchAssemblyRequest,
+    },
+    Undo {
+        #[structopt(flatten)]
+        patch_undo_request: CommandLinePatchUndoRequest,
+    },
+    List,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLinePatchNopRequest {
+    #[structopt(short = "a", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub address: u64,
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+    #[structopt(short = "s", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub size: u64,
+    #[structopt(long = "isa", default_value = "")]
+    pub instruction_set_id: String,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLinePatchAssemblyRequest {
+    #[structopt(short = "a", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub address: u64,
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+    #[structopt(short = "i", long = "instructions")]
+    pub assembly_source: String,
+    #[structopt(long = "isa", default_value = "")]
+    pub instruction_set_id: String,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLinePatchUndoRequest {
+    #[structopt(short = "a", long, parse(try_from_str = api::conversions::conversions_from_primitives::Conversions::parse_hex_or_int))]
+    pub address: u64,
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+}
+
+impl From<CommandLinePatchCommand> for api::commands::patch::patch_command::PatchCommand {
+    fn from(command: CommandLinePatchCommand) -> Self {
+        use api::structures::patching::code_patch_kind::CodePatchKind;
+
+        match command {
+            CommandLinePatchCommand::Nop { patch_nop_request } => Self::Apply {
+                patch_apply_request: patch_nop_request.into(),
+            },
+            CommandLinePatchCommand::Replace { patch_replace_request } => Self::Apply {
+                patch_apply_request: patch_replace_request.into_apply_request(|assembly_source| CodePatchKind::Replace { assembly_source }),
+            },
+            CommandLinePatchCommand::Detour { patch_detour_request } => Self::Apply {
+                patch_apply_request: patch_detour_request.into_apply_request(|assembly_source| CodePatchKind::Detour { assembly_source }),
+            },
+            CommandLinePatchCommand::Undo { patch_undo_request } => Self::Undo {
+                patch_undo_request: patch_undo_request.into(),
+            },
+            CommandLinePatchCommand::List => Self::List {
+                patch_list_request: api::commands::patch::list::patch_list_request::PatchListRequest::default(),
+            },
+        }
+    }
+}
+
+impl From<CommandLinePatchNopRequest> for api::commands::patch::apply::patch_apply_request::PatchApplyRequest {
+    fn from(request: CommandLinePatchNopRequest) -> Self {
+        Self {
+            code_patch_definition: api::structures::patching::code_patch_definition::CodePatchDefinition {
+                address: request.address,
+                module_name: request.module_name,
+                instruction_set_id: request.instruction_set_id,
+                kind: api::structures::patching::code_patch_kind::CodePatchKind::Nop { byte_count: request.size },
+            },
+        }
+    }
+}
+
+impl CommandLinePatchAssemblyRequest {
+    fn into_apply_request(
+        self,
+        create_kind: impl FnOnce(String) -> api::structures::patching::code_patch_kind::CodePatchKind,
+    ) -> api::commands::patch::apply::patch_apply_request::PatchApplyRequest {
+        api::commands::patch::apply::patch_apply_request::PatchApplyRequest {
+            code_patch_definition: api::structures::patching::code_patch_definition::CodePatchDefinition {
+                address: self.address,
+                module_name: self.module_name,
+                instruction_set_id: self.instruction_set_id,
+                kind: create_kind(self.assembly_source),
+            },
+        }
+    }
+}
+
+impl From<CommandLinePatchUndoRequest> for api::commands::patch::undo::patch_undo_request::PatchUndoRequest {
+    fn from(request: CommandLinePatchUndoRequest) -> Self {
+        Self {
+            address: request.address,
+            module_name: request.module_name,
+        }
+    }
+}
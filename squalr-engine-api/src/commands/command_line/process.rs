@@ -7,6 +7,10 @@ pub(crate) enum CommandLineProcessCommand {
         #[structopt(flatten)]
         process_open_request: CommandLineProcessOpenRequest,
     },
+    Launch {
+        #[structopt(flatten)]
+        process_launch_request: CommandLineProcessLaunchRequest,
+    },
     List {
         #[structopt(flatten)]
         process_list_request: CommandLineProcessListRequest,
@@ -15,6 +19,10 @@ pub(crate) enum CommandLineProcessCommand {
         #[structopt(flatten)]
         process_close_request: CommandLineProcessCloseRequest,
     },
+    InjectLibrary {
+        #[structopt(flatten)]
+        process_inject_library_request: CommandLineProcessInjectLibraryRequest,
+    },
 }
 
 #[derive(Clone, StructOpt, Debug)]
@@ -27,6 +35,16 @@ pub(crate) struct CommandLineProcessOpenRequest {
     pub match_case: bool,
 }
 
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineProcessLaunchRequest {
+    pub executable_path: String,
+    pub arguments: Vec<String>,
+    #[structopt(short = "e", long = "env", parse(try_from_str = parse_environment_variable))]
+    pub environment: Vec<(String, String)>,
+    #[structopt(short = "s", long = "suspended")]
+    pub is_suspended: bool,
+}
+
 #[derive(Clone, StructOpt, Debug)]
 pub(crate) struct CommandLineProcessListRequest {
     #[structopt(short = "w", long)]
@@ -44,18 +62,31 @@ pub(crate) struct CommandLineProcessListRequest {
 #[derive(Clone, StructOpt, Debug)]
 pub(crate) struct CommandLineProcessCloseRequest {}
 
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineProcessInjectLibraryRequest {
+    pub library_path: String,
+}
+
 impl From<CommandLineProcessCommand> for api::commands::process::process_command::ProcessCommand {
     fn from(command: CommandLineProcessCommand) -> Self {
         match command {
             CommandLineProcessCommand::Open { process_open_request } => Self::Open {
                 process_open_request: process_open_request.into(),
             },
+            CommandLineProcessCommand::Launch { process_launch_request } => Self::Launch {
+                process_launch_request: process_launch_request.into(),
+            },
             CommandLineProcessCommand::List { process_list_request } => Self::List {
                 process_list_request: process_list_request.into(),
             },
             CommandLineProcessCommand::Close { process_close_request } => Self::Close {
                 process_close_request: process_close_request.into(),
             },
+            CommandLineProcessCommand::InjectLibrary {
+                process_inject_library_request,
+            } => Self::InjectLibrary {
+                process_inject_library_request: process_inject_library_request.into(),
+            },
         }
     }
 }
@@ -70,6 +101,17 @@ impl From<CommandLineProcessOpenRequest> for api::commands::process::open::proce
     }
 }
 
+impl From<CommandLineProcessLaunchRequest> for api::commands::process::launch::process_launch_request::ProcessLaunchRequest {
+    fn from(request: CommandLineProcessLaunchRequest) -> Self {
+        Self {
+            executable_path: request.executable_path,
+            arguments: request.arguments,
+            environment: request.environment,
+            is_suspended: request.is_suspended,
+        }
+    }
+}
+
 impl From<CommandLineProcessListRequest> for api::commands::process::list::process_list_request::ProcessListRequest {
     fn from(request: CommandLineProcessListRequest) -> Self {
         Self {
@@ -87,3 +129,19 @@ impl From<CommandLineProcessCloseRequest> for api::commands::process::close::pro
         Self {}
     }
 }
+
+impl From<CommandLineProcessInjectLibraryRequest> for api::commands::process::inject_library::process_inject_library_request::ProcessInjectLibraryRequest {
+    fn from(request: CommandLineProcessInjectLibraryRequest) -> Self {
+        Self {
+            library_path: request.library_path,
+        }
+    }
+}
+
+/// Parses a `KEY=VALUE` environment variable. The value may itself contain `=`.
+fn parse_environment_variable(environment_variable: &str) -> Result<(String, String), String> {
+    match environment_variable.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err(format!("Expected KEY=VALUE, got `{}`.", environment_variable)),
+    }
+}
@@ -82,6 +82,11 @@ pub(crate) struct CommandLineProjectItemsCreateRequest {
     pub project_item_name: String,
     #[structopt(long)]
     pub is_directory: bool,
+    #[structopt(long)]
+    pub project_item_type_id: Option<String>,
+    /// A JSON code patch definition, which creates a toggleable patch item.
+    #[structopt(long, parse(try_from_str = serde_json::from_str))]
+    pub code_patch: Option<api::structures::patching::code_patch_definition::CodePatchDefinition>,
 }
 
 #[derive(Clone, StructOpt, Debug)]
@@ -245,6 +250,8 @@ impl From<CommandLineProjectItemsCreateRequest> for api::commands::project_items
             module_name: None,
             data_type_id: None,
             pointer_offsets: None,
+            project_item_type_id: request.project_item_type_id,
+            code_patch: request.code_patch,
         }
     }
 }
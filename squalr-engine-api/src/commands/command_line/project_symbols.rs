@@ -1,5 +1,6 @@
 use crate as api;
 use std::collections::BTreeMap;
+use std::path::PathBuf;
 use structopt::StructOpt;
 
 #[derive(Clone, StructOpt, Debug)]
@@ -24,14 +25,30 @@ pub(crate) enum CommandLineProjectSymbolsCommand {
         #[structopt(flatten)]
         project_symbols_delete_resolver_request: CommandLineProjectSymbolsDeleteResolverRequest,
     },
+    Export {
+        #[structopt(flatten)]
+        project_symbols_export_request: CommandLineProjectSymbolsExportRequest,
+    },
+    ImportHeader {
+        #[structopt(flatten)]
+        project_symbols_import_header_request: CommandLineProjectSymbolsImportHeaderRequest,
+    },
     List {
         #[structopt(flatten)]
         project_symbols_list_request: CommandLineProjectSymbolsListRequest,
     },
+    References {
+        #[structopt(flatten)]
+        project_symbols_references_request: CommandLineProjectSymbolsReferencesRequest,
+    },
     Rename {
         #[structopt(flatten)]
         project_symbols_rename_request: CommandLineProjectSymbolsRenameRequest,
     },
+    RenameLayout {
+        #[structopt(flatten)]
+        project_symbols_rename_layout_request: CommandLineProjectSymbolsRenameLayoutRequest,
+    },
     RenameModule {
         #[structopt(flatten)]
         project_symbols_rename_module_request: CommandLineProjectSymbolsRenameModuleRequest,
@@ -98,15 +115,54 @@ pub(crate) struct CommandLineProjectSymbolsDeleteResolverRequest {
     pub resolver_id: String,
 }
 
+#[derive(Clone, Default, StructOpt, Debug)]
+pub(crate) struct CommandLineProjectSymbolsExportRequest {
+    #[structopt(parse(from_os_str))]
+    pub output_file_path: PathBuf,
+    #[structopt(short = "f", long = "format")]
+    pub export_format: Option<api::structures::projects::symbol_interchange::symbol_export_format::SymbolExportFormat>,
+    #[structopt(short = "b", long = "bitness", default_value = "64")]
+    pub bitness: api::structures::memory::bitness::Bitness,
+}
+
+#[derive(Clone, Default, StructOpt, Debug)]
+pub(crate) struct CommandLineProjectSymbolsImportHeaderRequest {
+    #[structopt(parse(from_os_str))]
+    pub header_file_path: PathBuf,
+    #[structopt(short = "b", long = "bitness", default_value = "64")]
+    pub bitness: api::structures::memory::bitness::Bitness,
+    #[structopt(long = "data-model")]
+    pub data_model: Option<api::structures::projects::symbol_interchange::c_header::c_header_import_options::CHeaderDataModel>,
+    #[structopt(short = "p", long = "prefix", default_value = "")]
+    pub layout_id_prefix: String,
+}
+
 #[derive(Clone, Default, StructOpt, Debug)]
 pub(crate) struct CommandLineProjectSymbolsListRequest {}
 
+#[derive(Clone, Default, StructOpt, Debug)]
+pub(crate) struct CommandLineProjectSymbolsReferencesRequest {
+    pub symbol: String,
+}
+
 #[derive(Clone, Default, StructOpt, Debug)]
 pub(crate) struct CommandLineProjectSymbolsRenameRequest {
     #[structopt(short = "k", long = "key")]
     pub symbol_locator_key: String,
     #[structopt(short = "n", long = "name")]
     pub display_name: String,
+    #[structopt(long = "dry-run")]
+    pub dry_run: bool,
+}
+
+#[derive(Clone, Default, StructOpt, Debug)]
+pub(crate) struct CommandLineProjectSymbolsRenameLayoutRequest {
+    #[structopt(short = "i", long = "id")]
+    pub struct_layout_id: String,
+    #[structopt(short = "n", long = "new-id")]
+    pub new_struct_layout_id: String,
+    #[structopt(long = "dry-run")]
+    pub dry_run: bool,
 }
 
 #[derive(Clone, Default, StructOpt, Debug)]
@@ -195,14 +251,34 @@ impl From<CommandLineProjectSymbolsCommand> for api::commands::project_symbols::
             } => Self::DeleteResolver {
                 project_symbols_delete_resolver_request: project_symbols_delete_resolver_request.into(),
             },
+            CommandLineProjectSymbolsCommand::Export {
+                project_symbols_export_request,
+            } => Self::Export {
+                project_symbols_export_request: project_symbols_export_request.into(),
+            },
+            CommandLineProjectSymbolsCommand::ImportHeader {
+                project_symbols_import_header_request,
+            } => Self::ImportHeader {
+                project_symbols_import_header_request: project_symbols_import_header_request.into(),
+            },
             CommandLineProjectSymbolsCommand::List { project_symbols_list_request } => Self::List {
                 project_symbols_list_request: project_symbols_list_request.into(),
             },
+            CommandLineProjectSymbolsCommand::References {
+                project_symbols_references_request,
+            } => Self::References {
+                project_symbols_references_request: project_symbols_references_request.into(),
+            },
             CommandLineProjectSymbolsCommand::Rename {
                 project_symbols_rename_request,
             } => Self::Rename {
                 project_symbols_rename_request: project_symbols_rename_request.into(),
             },
+            CommandLineProjectSymbolsCommand::RenameLayout {
+                project_symbols_rename_layout_request,
+            } => Self::RenameLayout {
+                project_symbols_rename_layout_request: project_symbols_rename_layout_request.into(),
+            },
             CommandLineProjectSymbolsCommand::RenameModule {
                 project_symbols_rename_module_request,
             } => Self::RenameModule {
@@ -287,17 +363,71 @@ impl From<CommandLineProjectSymbolsDeleteResolverRequest>
     }
 }
 
+impl From<CommandLineProjectSymbolsExportRequest> for api::commands::project_symbols::export::project_symbols_export_request::ProjectSymbolsExportRequest {
+    fn from(request: CommandLineProjectSymbolsExportRequest) -> Self {
+        // Without an explicit format, infer it from the output file extension.
+        let export_format = request.export_format.unwrap_or_else(|| {
+            request
+                .output_file_path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .and_then(|extension| extension.parse().ok())
+                .unwrap_or_default()
+        });
+
+        Self {
+            output_file_path: request.output_file_path,
+            export_format,
+            bitness: request.bitness,
+        }
+    }
+}
+
+impl From<CommandLineProjectSymbolsImportHeaderRequest>
+    for api::commands::project_symbols::import_header::project_symbols_import_header_request::ProjectSymbolsImportHeaderRequest
+{
+    fn from(request: CommandLineProjectSymbolsImportHeaderRequest) -> Self {
+        Self {
+            header_file_path: request.header_file_path,
+            bitness: request.bitness,
+            data_model: request.data_model.unwrap_or_default(),
+            layout_id_prefix: request.layout_id_prefix,
+        }
+    }
+}
+
 impl From<CommandLineProjectSymbolsListRequest> for api::commands::project_symbols::list::project_symbols_list_request::ProjectSymbolsListRequest {
     fn from(_: CommandLineProjectSymbolsListRequest) -> Self {
         Self {}
     }
 }
 
+impl From<CommandLineProjectSymbolsReferencesRequest>
+    for api::commands::project_symbols::references::project_symbols_references_request::ProjectSymbolsReferencesRequest
+{
+    fn from(request: CommandLineProjectSymbolsReferencesRequest) -> Self {
+        Self { symbol: request.symbol }
+    }
+}
+
 impl From<CommandLineProjectSymbolsRenameRequest> for api::commands::project_symbols::rename::project_symbols_rename_request::ProjectSymbolsRenameRequest {
     fn from(request: CommandLineProjectSymbolsRenameRequest) -> Self {
         Self {
             symbol_locator_key: request.symbol_locator_key,
             display_name: request.display_name,
+            dry_run: request.dry_run,
+        }
+    }
+}
+
+impl From<CommandLineProjectSymbolsRenameLayoutRequest>
+    for api::commands::project_symbols::rename_layout::project_symbols_rename_layout_request::ProjectSymbolsRenameLayoutRequest
+{
+    fn from(request: CommandLineProjectSymbolsRenameLayoutRequest) -> Self {
+        Self {
+            struct_layout_id: request.struct_layout_id,
+            new_struct_layout_id: request.new_struct_layout_id,
+            dry_run: request.dry_run,
         }
     }
 }
@@ -1,5 +1,7 @@
 use super::command::CommandLineCommand;
+use super::debugger::CommandLineDebuggerCommand;
 use super::memory::CommandLineMemoryCommand;
+use super::patch::CommandLinePatchCommand;
 use super::plugins::CommandLinePluginsCommand;
 use super::pointer_scan::CommandLinePointerScanCommand;
 use super::process::CommandLineProcessCommand;
@@ -11,14 +13,18 @@ use super::scan::CommandLineScanCommand;
 use super::scan_results::CommandLineScanResultsCommand;
 use super::settings::CommandLineSettingsCommand;
 use super::struct_scan::CommandLineStructScanCommand;
+use super::thread::CommandLineThreadCommand;
 use super::trackable_tasks::CommandLineTrackableTasksCommand;
 use crate::commands::privileged_command::PrivilegedCommand;
 use crate::commands::unprivileged_command::UnprivilegedCommand;
 use structopt::StructOpt;
 #[derive(Clone, StructOpt, Debug)]
 pub(crate) enum CommandLineRootCommand {
+    #[structopt(alias = "dbg", alias = "debug")]
+    Debugger(CommandLineDebuggerCommand),
     #[structopt(alias = "mem", alias = "m")]
     Memory(CommandLineMemoryCommand),
+    Patch(CommandLinePatchCommand),
     #[structopt(alias = "plug", alias = "plugins")]
     Plugins(CommandLinePluginsCommand),
     #[structopt(alias = "proc", alias = "pr")]
@@ -33,6 +39,8 @@ pub(crate) enum CommandLineRootCommand {
     PointerScan(CommandLinePointerScanCommand),
     #[structopt(alias = "sscan")]
     StructScan(CommandLineStructScanCommand),
+    #[structopt(alias = "threads", alias = "th")]
+    Thread(CommandLineThreadCommand),
     #[structopt(alias = "set", alias = "st")]
     Settings(CommandLineSettingsCommand),
     #[structopt(alias = "tasks", alias = "tt")]
@@ -48,7 +56,9 @@ pub(crate) enum CommandLineRootCommand {
 impl From<CommandLineRootCommand> for CommandLineCommand {
     fn from(command: CommandLineRootCommand) -> Self {
         match command {
+            CommandLineRootCommand::Debugger(command) => Self::Privileged(PrivilegedCommand::Debugger(command.into())),
             CommandLineRootCommand::Memory(command) => Self::Privileged(PrivilegedCommand::Memory(command.into())),
+            CommandLineRootCommand::Patch(command) => Self::Privileged(PrivilegedCommand::Patch(command.into())),
             CommandLineRootCommand::Plugins(command) => Self::Privileged(PrivilegedCommand::Plugins(command.into())),
             CommandLineRootCommand::Process(command) => Self::Privileged(PrivilegedCommand::Process(command.into())),
             CommandLineRootCommand::Registry(command) => Self::Privileged(PrivilegedCommand::Registry(command.into())),
@@ -56,6 +66,7 @@ impl From<CommandLineRootCommand> for CommandLineCommand {
             CommandLineRootCommand::Scan(command) => Self::Privileged(PrivilegedCommand::Scan(command.into())),
             CommandLineRootCommand::PointerScan(command) => Self::Privileged(PrivilegedCommand::PointerScan(command.into())),
             CommandLineRootCommand::StructScan(command) => Self::Privileged(PrivilegedCommand::StructScan(command.into())),
+            CommandLineRootCommand::Thread(command) => Self::Privileged(PrivilegedCommand::Thread(command.into())),
             CommandLineRootCommand::Settings(command) => Self::Privileged(PrivilegedCommand::Settings(command.into())),
             CommandLineRootCommand::TrackableTasks(command) => Self::Privileged(PrivilegedCommand::TrackableTasks(command.into())),
             CommandLineRootCommand::Project(command) => Self::Unprivileged(UnprivilegedCommand::Project(command.into())),
@@ -39,6 +39,8 @@ pub(crate) struct CommandLineElementScanRequest {
     pub scan_constraints: Vec<api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint>,
     #[structopt(short = "d", long)]
     pub data_type_refs: Vec<api::structures::data_types::data_type_ref::DataTypeRef>,
+    #[structopt(long)]
+    pub value_transform: Option<api::plugins::value_transform::ValueTransformRef>,
 }
 
 impl From<CommandLineScanCommand> for api::commands::scan::scan_command::ScanCommand {
@@ -85,6 +87,7 @@ impl From<CommandLineElementScanRequest> for api::commands::scan::element_scan::
         Self {
             scan_constraints: request.scan_constraints,
             data_type_refs: request.data_type_refs,
+            value_transform: request.value_transform,
         }
     }
 }
@@ -110,3 +110,142 @@ fn specific_unprivileged_parser_rejects_privileged_commands() {
 
     assert!(matches!(parse_error.kind, clap::ErrorKind::InvalidSubcommand));
 }
+
+#[test]
+fn parse_debugger_watch_command_accepts_hex_address_size_and_access() {
+    let parsed_command = parse_command_line("dbg watch -a 0x7ffe1000 --size 8 --access rw").expect("Expected debugger watch to parse.");
+
+    let CommandLineCommand::Privileged(api::commands::privileged_command::PrivilegedCommand::Debugger(
+        api::commands::debugger::debugger_command::DebuggerCommand::Watch { debugger_watch_request },
+    )) = parsed_command
+    else {
+        panic!("Expected a debugger watch command.");
+    };
+
+    assert_eq!(debugger_watch_request.address, 0x7ffe1000);
+    assert_eq!(
+        debugger_watch_request.watchpoint_size,
+        api::structures::debugging::watchpoint_size::WatchpointSize::Byte8
+    );
+    assert_eq!(
+        debugger_watch_request.watchpoint_access,
+        api::structures::debugging::watchpoint_access::WatchpointAccess::ReadWrite
+    );
+}
+
+#[test]
+fn parse_debugger_set_breakpoint_alias_accepts_count_mode() {
+    let parsed_command = parse_command_line("dbg bp -a 0x401000 --mode count").expect("Expected debugger breakpoint to parse.");
+
+    let CommandLineCommand::Privileged(api::commands::privileged_command::PrivilegedCommand::Debugger(
+        api::commands::debugger::debugger_command::DebuggerCommand::SetBreakpoint {
+            debugger_set_breakpoint_request,
+        },
+    )) = parsed_command
+    else {
+        panic!("Expected a debugger set breakpoint command.");
+    };
+
+    assert_eq!(debugger_set_breakpoint_request.address, 0x401000);
+    assert_eq!(
+        debugger_set_breakpoint_request.breakpoint_mode,
+        api::structures::debugging::breakpoint_mode::BreakpointMode::Count
+    );
+}
+
+#[test]
+fn parse_debugger_trace_command_accepts_an_end_address_budget_and_output_file() {
+    let parsed_command = parse_command_line("dbg trace -a 0x401000 -e 0x401080 -b 500 -t 12 -o trace.json").expect("Expected debugger trace to parse.");
+
+    let CommandLineCommand::Privileged(api::commands::privileged_command::PrivilegedCommand::Debugger(
+        api::commands::debugger::debugger_command::DebuggerCommand::Trace { debugger_trace_request },
+    )) = parsed_command
+    else {
+        panic!("Expected a debugger trace command.");
+    };
+
+    assert_eq!(debugger_trace_request.start_address, 0x401000);
+    assert_eq!(debugger_trace_request.end_address, Some(0x401080));
+    assert_eq!(debugger_trace_request.instruction_budget, 500);
+    assert_eq!(debugger_trace_request.thread_id, Some(12));
+    assert_eq!(debugger_trace_request.trace_file_path, Some(std::path::PathBuf::from("trace.json")));
+}
+
+#[test]
+fn parse_memory_allocate_alias_accepts_hex_size_protection_and_preferred_address() {
+    let parsed_command = parse_command_line("memory alloc -s 0x1000 -p rwx --near 0x7f0000000000").expect("Expected memory allocate to parse.");
+
+    let CommandLineCommand::Privileged(api::commands::privileged_command::PrivilegedCommand::Memory(
+        api::commands::memory::memory_command::MemoryCommand::Allocate { memory_allocate_request },
+    )) = parsed_command
+    else {
+        panic!("Expected a memory allocate command.");
+    };
+
+    assert_eq!(memory_allocate_request.size, 0x1000);
+    assert_eq!(
+        memory_allocate_request.protection,
+        api::structures::memory::memory_protection::MemoryProtection::READ_WRITE_EXECUTE
+    );
+    assert_eq!(memory_allocate_request.preferred_address, 0x7f0000000000);
+}
+
+#[test]
+fn parse_memory_collect_writes_narrows_the_next_scan_unless_disabled() {
+    let parse_narrow_next_scan = |command_line: &str| {
+        let parsed_command = parse_command_line(command_line).expect("Expected memory collect-writes to parse.");
+
+        let CommandLineCommand::Privileged(api::commands::privileged_command::PrivilegedCommand::Memory(
+            api::commands::memory::memory_command::MemoryCommand::CollectWrites { memory_collect_writes_request },
+        )) = parsed_command
+        else {
+            panic!("Expected a memory collect-writes command.");
+        };
+
+        memory_collect_writes_request.narrow_next_scan
+    };
+
+    assert!(parse_narrow_next_scan("memory collect-writes"));
+    assert!(!parse_narrow_next_scan("memory collect-writes --no-narrow"));
+}
+
+#[test]
+fn parse_memory_find_caves_takes_the_module_and_minimum_size() {
+    let parsed_command = parse_command_line("memory find-caves game.exe --min-size 0x40 --near 0x140001000").expect("Expected memory find-caves to parse.");
+
+    let CommandLineCommand::Privileged(api::commands::privileged_command::PrivilegedCommand::Memory(
+        api::commands::memory::memory_command::MemoryCommand::FindCaves { memory_find_caves_request },
+    )) = parsed_command
+    else {
+        panic!("Expected a memory find-caves command.");
+    };
+
+    assert_eq!(memory_find_caves_request.module_name, "game.exe");
+    assert_eq!(memory_find_caves_request.minimum_size, 0x40);
+    assert_eq!(memory_find_caves_request.target_address, Some(0x140001000));
+}
+
+#[test]
+fn parse_thread_suspend_without_thread_id_targets_the_whole_process() {
+    let parsed_command = parse_command_line("threads suspend").expect("Expected thread suspend to parse.");
+
+    let CommandLineCommand::Privileged(api::commands::privileged_command::PrivilegedCommand::Thread(
+        api::commands::thread::thread_command::ThreadCommand::Suspend { thread_suspend_request },
+    )) = parsed_command
+    else {
+        panic!("Expected a thread suspend command.");
+    };
+
+    assert_eq!(thread_suspend_request.thread_id, None);
+
+    let parsed_command = parse_command_line("thread ctx -t 4243").expect("Expected thread context to parse.");
+
+    let CommandLineCommand::Privileged(api::commands::privileged_command::PrivilegedCommand::Thread(
+        api::commands::thread::thread_command::ThreadCommand::Context { thread_context_request },
+    )) = parsed_command
+    else {
+        panic!("Expected a thread context command.");
+    };
+
+    assert_eq!(thread_context_request.thread_id, 4243);
+}
@@ -0,0 +1,78 @@
+use crate as api;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) enum CommandLineThreadCommand {
+    #[structopt(alias = "ls")]
+    List,
+    Suspend {
+        #[structopt(flatten)]
+        thread_suspend_request: CommandLineThreadSuspendRequest,
+    },
+    Resume {
+        #[structopt(flatten)]
+        thread_resume_request: CommandLineThreadResumeRequest,
+    },
+    #[structopt(alias = "ctx")]
+    Context {
+        #[structopt(flatten)]
+        thread_context_request: CommandLineThreadContextRequest,
+    },
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineThreadSuspendRequest {
+    /// The thread to suspend. Suspends the whole process when omitted.
+    #[structopt(short = "t", long = "thread")]
+    pub thread_id: Option<u32>,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineThreadResumeRequest {
+    /// The thread to resume. Resumes every suspended thread when omitted.
+    #[structopt(short = "t", long = "thread")]
+    pub thread_id: Option<u32>,
+}
+
+#[derive(Clone, StructOpt, Debug)]
+pub(crate) struct CommandLineThreadContextRequest {
+    #[structopt(short = "t", long = "thread")]
+    pub thread_id: u32,
+}
+
+impl From<CommandLineThreadCommand> for api::commands::thread::thread_command::ThreadCommand {
+    fn from(command: CommandLineThreadCommand) -> Self {
+        match command {
+            CommandLineThreadCommand::List => Self::List {
+                thread_list_request: api::commands::thread::list::thread_list_request::ThreadListRequest::default(),
+            },
+            CommandLineThreadCommand::Suspend { thread_suspend_request } => Self::Suspend {
+                thread_suspend_request: thread_suspend_request.into(),
+            },
+            CommandLineThreadCommand::Resume { thread_resume_request } => Self::Resume {
+                thread_resume_request: thread_resume_request.into(),
+            },
+            CommandLineThreadCommand::Context { thread_context_request } => Self::Context {
+                thread_context_request: thread_context_request.into(),
+            },
+        }
+    }
+}
+
+impl From<CommandLineThreadSuspendRequest> for api::commands::thread::suspend::thread_suspend_request::ThreadSuspendRequest {
+    fn from(request: CommandLineThreadSuspendRequest) -> Self {
+        Self { thread_id: request.thread_id }
+    }
+}
+
+impl From<CommandLineThreadResumeRequest> for api::commands::thread::resume::thread_resume_request::ThreadResumeRequest {
+    fn from(request: CommandLineThreadResumeRequest) -> Self {
+        Self { thread_id: request.thread_id }
+    }
+}
+
+impl From<CommandLineThreadContextRequest> for api::commands::thread::context::thread_context_request::ThreadContextRequest {
+    fn from(request: CommandLineThreadContextRequest) -> Self {
+        Self { thread_id: request.thread_id }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::commands::debugger::hits::debugger_hits_request::DebuggerHitsRequest;
+use crate::commands::debugger::list_breakpoints::debugger_list_breakpoints_request::DebuggerListBreakpointsRequest;
+use crate::commands::debugger::remove_breakpoint::debugger_remove_breakpoint_request::DebuggerRemoveBreakpointRequest;
+use crate::commands::debugger::set_breakpoint::debugger_set_breakpoint_request::DebuggerSetBreakpointRequest;
+use crate::commands::debugger::trace::debugger_trace_request::DebuggerTraceRequest;
+use crate::commands::debugger::unwatch::debugger_unwatch_request::DebuggerUnwatchRequest;
+use crate::commands::debugger::watch::debugger_watch_request::DebuggerWatchRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum DebuggerCommand {
+    Watch {
+        debugger_watch_request: DebuggerWatchRequest,
+    },
+    Hits {
+        debugger_hits_request: DebuggerHitsRequest,
+    },
+    Unwatch {
+        debugger_unwatch_request: DebuggerUnwatchRequest,
+    },
+    SetBreakpoint {
+        debugger_set_breakpoint_request: DebuggerSetBreakpointRequest,
+    },
+    RemoveBreakpoint {
+        debugger_remove_breakpoint_request: DebuggerRemoveBreakpointRequest,
+    },
+    ListBreakpoints {
+        debugger_list_breakpoints_request: DebuggerListBreakpointsRequest,
+    },
+    Trace {
+        debugger_trace_request: DebuggerTraceRequest,
+    },
+}
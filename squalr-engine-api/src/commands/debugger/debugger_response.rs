@@ -0,0 +1,33 @@
+use crate::commands::debugger::hits::debugger_hits_response::DebuggerHitsResponse;
+use crate::commands::debugger::list_breakpoints::debugger_list_breakpoints_response::DebuggerListBreakpointsResponse;
+use crate::commands::debugger::remove_breakpoint::debugger_remove_breakpoint_response::DebuggerRemoveBreakpointResponse;
+use crate::commands::debugger::set_breakpoint::debugger_set_breakpoint_response::DebuggerSetBreakpointResponse;
+use crate::commands::debugger::trace::debugger_trace_response::DebuggerTraceResponse;
+use crate::commands::debugger::unwatch::debugger_unwatch_response::DebuggerUnwatchResponse;
+use crate::commands::debugger::watch::debugger_watch_response::DebuggerWatchResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum DebuggerResponse {
+    Watch {
+        debugger_watch_response: DebuggerWatchResponse,
+    },
+    Hits {
+        debugger_hits_response: DebuggerHitsResponse,
+    },
+    Unwatch {
+        debugger_unwatch_response: DebuggerUnwatchResponse,
+    },
+    SetBreakpoint {
+        debugger_set_breakpoint_response: DebuggerSetBreakpointResponse,
+    },
+    RemoveBreakpoint {
+        debugger_remove_breakpoint_response: DebuggerRemoveBreakpointResponse,
+    },
+    ListBreakpoints {
+        debugger_list_breakpoints_response: DebuggerListBreakpointsResponse,
+    },
+    Trace {
+        debugger_trace_response: DebuggerTraceResponse,
+    },
+}
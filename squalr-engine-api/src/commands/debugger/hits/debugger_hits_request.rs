@@ -0,0 +1,27 @@
+use crate::commands::debugger::debugger_command::DebuggerCommand;
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::debugger::hits::debugger_hits_response::DebuggerHitsResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DebuggerHitsRequest {
+    pub watchpoint_id: u64,
+}
+
+impl PrivilegedCommandRequest for DebuggerHitsRequest {
+    type ResponseType = DebuggerHitsResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Debugger(DebuggerCommand::Hits {
+            debugger_hits_request: self.clone(),
+        })
+    }
+}
+
+impl From<DebuggerHitsResponse> for DebuggerResponse {
+    fn from(debugger_hits_response: DebuggerHitsResponse) -> Self {
+        DebuggerResponse::Hits { debugger_hits_response }
+    }
+}
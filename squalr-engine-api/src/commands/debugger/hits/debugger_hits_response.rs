@@ -0,0 +1,27 @@
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::debugging::watchpoint_hit::WatchpointHit;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DebuggerHitsResponse {
+    pub watchpoint_hits: Vec<WatchpointHit>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for DebuggerHitsResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Debugger(DebuggerResponse::Hits {
+            debugger_hits_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Debugger(DebuggerResponse::Hits { debugger_hits_response }) = response {
+            Ok(debugger_hits_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod debugger_hits_request;
+pub mod debugger_hits_response;
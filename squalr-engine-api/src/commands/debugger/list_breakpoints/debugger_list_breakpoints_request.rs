@@ -0,0 +1,27 @@
+use crate::commands::debugger::debugger_command::DebuggerCommand;
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::debugger::list_breakpoints::debugger_list_breakpoints_response::DebuggerListBreakpointsResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DebuggerListBreakpointsRequest {}
+
+impl PrivilegedCommandRequest for DebuggerListBreakpointsRequest {
+    type ResponseType = DebuggerListBreakpointsResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Debugger(DebuggerCommand::ListBreakpoints {
+            debugger_list_breakpoints_request: self.clone(),
+        })
+    }
+}
+
+impl From<DebuggerListBreakpointsResponse> for DebuggerResponse {
+    fn from(debugger_list_breakpoints_response: DebuggerListBreakpointsResponse) -> Self {
+        DebuggerResponse::ListBreakpoints {
+            debugger_list_breakpoints_response,
+        }
+    }
+}
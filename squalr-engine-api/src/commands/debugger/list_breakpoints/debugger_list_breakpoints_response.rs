@@ -0,0 +1,30 @@
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::debugging::{breakpoint::Breakpoint, breakpoint_hit::BreakpointHit};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DebuggerListBreakpointsResponse {
+    pub breakpoints: Vec<Breakpoint>,
+    pub breakpoint_hits: Vec<BreakpointHit>,
+}
+
+impl TypedPrivilegedCommandResponse for DebuggerListBreakpointsResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Debugger(DebuggerResponse::ListBreakpoints {
+            debugger_list_breakpoints_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Debugger(DebuggerResponse::ListBreakpoints {
+            debugger_list_breakpoints_response,
+        }) = response
+        {
+            Ok(debugger_list_breakpoints_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod debugger_list_breakpoints_request;
+pub mod debugger_list_breakpoints_response;
@@ -0,0 +1,9 @@
+pub mod debugger_command;
+pub mod debugger_response;
+pub mod hits;
+pub mod list_breakpoints;
+pub mod remove_breakpoint;
+pub mod set_breakpoint;
+pub mod trace;
+pub mod unwatch;
+pub mod watch;
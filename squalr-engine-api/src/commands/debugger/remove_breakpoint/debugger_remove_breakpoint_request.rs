@@ -0,0 +1,29 @@
+use crate::commands::debugger::debugger_command::DebuggerCommand;
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::debugger::remove_breakpoint::debugger_remove_breakpoint_response::DebuggerRemoveBreakpointResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DebuggerRemoveBreakpointRequest {
+    pub breakpoint_id: u64,
+}
+
+impl PrivilegedCommandRequest for DebuggerRemoveBreakpointRequest {
+    type ResponseType = DebuggerRemoveBreakpointResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Debugger(DebuggerCommand::RemoveBreakpoint {
+            debugger_remove_breakpoint_request: self.clone(),
+        })
+    }
+}
+
+impl From<DebuggerRemoveBreakpointResponse> for DebuggerResponse {
+    fn from(debugger_remove_breakpoint_response: DebuggerRemoveBreakpointResponse) -> Self {
+        DebuggerResponse::RemoveBreakpoint {
+            debugger_remove_breakpoint_response,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::debugging::breakpoint::Breakpoint;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DebuggerRemoveBreakpointResponse {
+    pub breakpoint: Option<Breakpoint>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for DebuggerRemoveBreakpointResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Debugger(DebuggerResponse::RemoveBreakpoint {
+            debugger_remove_breakpoint_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Debugger(DebuggerResponse::RemoveBreakpoint {
+            debugger_remove_breakpoint_response,
+        }) = response
+        {
+            Ok(debugger_remove_breakpoint_response)
+        } else {
+            Err(response)
+        }
+    }
+}
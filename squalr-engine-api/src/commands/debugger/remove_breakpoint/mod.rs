@@ -0,0 +1,2 @@
+pub mod debugger_remove_breakpoint_request;
+pub mod debugger_remove_breakpoint_response;
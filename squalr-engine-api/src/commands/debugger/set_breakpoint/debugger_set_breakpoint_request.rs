@@ -0,0 +1,32 @@
+use crate::commands::debugger::debugger_command::DebuggerCommand;
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::debugger::set_breakpoint::debugger_set_breakpoint_response::DebuggerSetBreakpointResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::debugging::breakpoint_mode::BreakpointMode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DebuggerSetBreakpointRequest {
+    pub address: u64,
+    pub module_name: String,
+    pub breakpoint_mode: BreakpointMode,
+}
+
+impl PrivilegedCommandRequest for DebuggerSetBreakpointRequest {
+    type ResponseType = DebuggerSetBreakpointResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Debugger(DebuggerCommand::SetBreakpoint {
+            debugger_set_breakpoint_request: self.clone(),
+        })
+    }
+}
+
+impl From<DebuggerSetBreakpointResponse> for DebuggerResponse {
+    fn from(debugger_set_breakpoint_response: DebuggerSetBreakpointResponse) -> Self {
+        DebuggerResponse::SetBreakpoint {
+            debugger_set_breakpoint_response,
+        }
+    }
+}
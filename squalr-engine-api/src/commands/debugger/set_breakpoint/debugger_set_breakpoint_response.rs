@@ -0,0 +1,32 @@
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::debugging::breakpoint::Breakpoint;
+use crate::structures::tasks::trackable_task_handle::TrackableTaskHandle;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DebuggerSetBreakpointResponse {
+    pub breakpoint: Option<Breakpoint>,
+    pub trackable_task_handle: Option<TrackableTaskHandle>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for DebuggerSetBreakpointResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Debugger(DebuggerResponse::SetBreakpoint {
+            debugger_set_breakpoint_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Debugger(DebuggerResponse::SetBreakpoint {
+            debugger_set_breakpoint_response,
+        }) = response
+        {
+            Ok(debugger_set_breakpoint_response)
+        } else {
+            Err(response)
+        }
+    }
+}
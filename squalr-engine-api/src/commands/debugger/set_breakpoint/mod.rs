@@ -0,0 +1,2 @@
+pub mod debugger_set_breakpoint_request;
+pub mod debugger_set_breakpoint_response;
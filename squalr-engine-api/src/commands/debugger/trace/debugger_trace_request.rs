@@ -0,0 +1,42 @@
+use crate::commands::debugger::debugger_command::DebuggerCommand;
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::debugger::trace::debugger_trace_response::DebuggerTraceResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DebuggerTraceRequest {
+    /// The first thread to execute this address is single stepped from it. Relative to the module when one is given.
+    pub start_address: u64,
+    pub module_name: String,
+    /// Stops the trace when the thread reaches this address. Relative to the module when one is given.
+    pub end_address: Option<u64>,
+    /// Only traces this thread when given, letting other threads run past the start address.
+    pub thread_id: Option<u32>,
+    /// Stops the trace after this many instructions ran.
+    pub instruction_budget: u64,
+    /// How long to wait for a thread to execute the start address.
+    pub start_timeout_ms: u64,
+    /// The instruction set used to disassemble the trace. Empty picks the one matching the bitness of the process.
+    pub instruction_set_id: String,
+    /// Saves the trace to this file when given, so that it can be loaded again later.
+    pub trace_file_path: Option<PathBuf>,
+}
+
+impl PrivilegedCommandRequest for DebuggerTraceRequest {
+    type ResponseType = DebuggerTraceResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Debugger(DebuggerCommand::Trace {
+            debugger_trace_request: self.clone(),
+        })
+    }
+}
+
+impl From<DebuggerTraceResponse> for DebuggerResponse {
+    fn from(debugger_trace_response: DebuggerTraceResponse) -> Self {
+        DebuggerResponse::Trace { debugger_trace_response }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::debugging::instruction_trace::InstructionTrace;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DebuggerTraceResponse {
+    pub instruction_trace: Option<InstructionTrace>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for DebuggerTraceResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Debugger(DebuggerResponse::Trace {
+            debugger_trace_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Debugger(DebuggerResponse::Trace { debugger_trace_response }) = response {
+            Ok(debugger_trace_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod debugger_trace_request;
+pub mod debugger_trace_response;
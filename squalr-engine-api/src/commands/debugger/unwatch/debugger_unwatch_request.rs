@@ -0,0 +1,27 @@
+use crate::commands::debugger::debugger_command::DebuggerCommand;
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::debugger::unwatch::debugger_unwatch_response::DebuggerUnwatchResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DebuggerUnwatchRequest {
+    pub watchpoint_id: u64,
+}
+
+impl PrivilegedCommandRequest for DebuggerUnwatchRequest {
+    type ResponseType = DebuggerUnwatchResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Debugger(DebuggerCommand::Unwatch {
+            debugger_unwatch_request: self.clone(),
+        })
+    }
+}
+
+impl From<DebuggerUnwatchResponse> for DebuggerResponse {
+    fn from(debugger_unwatch_response: DebuggerUnwatchResponse) -> Self {
+        DebuggerResponse::Unwatch { debugger_unwatch_response }
+    }
+}
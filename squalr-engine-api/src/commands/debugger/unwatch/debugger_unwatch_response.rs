@@ -0,0 +1,27 @@
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::debugging::watchpoint_hit::WatchpointHit;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DebuggerUnwatchResponse {
+    pub watchpoint_hits: Vec<WatchpointHit>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for DebuggerUnwatchResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Debugger(DebuggerResponse::Unwatch {
+            debugger_unwatch_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Debugger(DebuggerResponse::Unwatch { debugger_unwatch_response }) = response {
+            Ok(debugger_unwatch_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod debugger_unwatch_request;
+pub mod debugger_unwatch_response;
@@ -0,0 +1,31 @@
+use crate::commands::debugger::debugger_command::DebuggerCommand;
+use crate::commands::debugger::debugger_response::DebuggerResponse;
+use crate::commands::debugger::watch::debugger_watch_response::DebuggerWatchResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::debugging::{watchpoint_access::WatchpointAccess, watchpoint_size::WatchpointSize};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DebuggerWatchRequest {
+    pub address: u64,
+    pub module_name: String,
+    pub watchpoint_size: WatchpointSize,
+    pub watchpoint_access: WatchpointAccess,
+}
+
+impl PrivilegedCommandRequest for DebuggerWatchRequest {
+    type ResponseType = DebuggerWatchResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Debugger(DebuggerCommand::Watch {
+            debugger_watch_request: self.clone(),
+        })
+    }
+}
+
+impl From<DebuggerWatchResponse> for DebuggerResponse {
+    fn from(debugger_watch_response: DebuggerWatchResponse) -> Self {
+        DebuggerResponse::Watch { debugger_watch_response }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod debugger_watch_request;
+pub mod debugger_watch_response;
@@ -0,0 +1,32 @@
+use crate::commands::memory::allocate::memory_allocate_response::MemoryAllocateResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::memory::memory_protection::MemoryProtection;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryAllocateRequest {
+    /// The requested size in bytes, which is rounded up to whole pages.
+    pub size: u64,
+    pub protection: MemoryProtection,
+    /// A hint for where to place the allocation, such as near a module that needs a code cave. Zero lets the OS choose.
+    pub preferred_address: u64,
+}
+
+impl PrivilegedCommandRequest for MemoryAllocateRequest {
+    type ResponseType = MemoryAllocateResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Allocate {
+            memory_allocate_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryAllocateResponse> for MemoryResponse {
+    fn from(memory_allocate_response: MemoryAllocateResponse) -> Self {
+        MemoryResponse::Allocate { memory_allocate_response }
+    }
+}
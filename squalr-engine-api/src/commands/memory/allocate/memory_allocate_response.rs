@@ -0,0 +1,28 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryAllocateResponse {
+    /// The page-aligned region that was allocated.
+    pub region: Option<NormalizedRegion>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryAllocateResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Allocate {
+            memory_allocate_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Allocate { memory_allocate_response }) = response {
+            Ok(memory_allocate_response)
+        } else {
+            Err(response)
+        }
+    }
+}
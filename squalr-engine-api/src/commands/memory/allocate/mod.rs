@@ -0,0 +1,2 @@
+pub mod memory_allocate_request;
+pub mod memory_allocate_response;
@@ -0,0 +1,31 @@
+use crate::commands::memory::collect_writes::memory_collect_writes_response::MemoryCollectWritesResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+/// Ends write tracking, reporting the pages that the process wrote since tracking started.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryCollectWritesRequest {
+    /// Narrows the pages read by the next new scan to the written pages.
+    pub narrow_next_scan: bool,
+}
+
+impl PrivilegedCommandRequest for MemoryCollectWritesRequest {
+    type ResponseType = MemoryCollectWritesResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::CollectWrites {
+            memory_collect_writes_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryCollectWritesResponse> for MemoryResponse {
+    fn from(memory_collect_writes_response: MemoryCollectWritesResponse) -> Self {
+        MemoryResponse::CollectWrites {
+            memory_collect_writes_response,
+        }
+    }
+}
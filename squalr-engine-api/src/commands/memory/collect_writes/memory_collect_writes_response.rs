@@ -0,0 +1,34 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::memory::written_page::WrittenPage;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryCollectWritesResponse {
+    /// The written pages, ordered by address.
+    pub written_pages: Vec<WrittenPage>,
+    pub tracked_byte_count: u64,
+    /// Whether the next new scan only reads the written pages.
+    pub is_next_scan_narrowed: bool,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryCollectWritesResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::CollectWrites {
+            memory_collect_writes_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::CollectWrites {
+            memory_collect_writes_response,
+        }) = response
+        {
+            Ok(memory_collect_writes_response)
+        } else {
+            Err(response)
+        }
+    }
+}
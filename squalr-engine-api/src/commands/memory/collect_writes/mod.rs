@@ -0,0 +1,2 @@
+pub mod memory_collect_writes_request;
+pub mod memory_collect_writes_response;
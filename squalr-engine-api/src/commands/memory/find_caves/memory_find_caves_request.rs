@@ -0,0 +1,31 @@
+use crate::commands::memory::find_caves::memory_find_caves_response::MemoryFindCavesResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+/// Finds runs of padding in the executable sections of a module that can hold code without allocating memory.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryFindCavesRequest {
+    pub module_name: String,
+    pub minimum_size: u64,
+    /// Ranks caves of equal size by their distance from this address, such as the address of a planned detour.
+    pub target_address: Option<u64>,
+}
+
+impl PrivilegedCommandRequest for MemoryFindCavesRequest {
+    type ResponseType = MemoryFindCavesResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::FindCaves {
+            memory_find_caves_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryFindCavesResponse> for MemoryResponse {
+    fn from(memory_find_caves_response: MemoryFindCavesResponse) -> Self {
+        MemoryResponse::FindCaves { memory_find_caves_response }
+    }
+}
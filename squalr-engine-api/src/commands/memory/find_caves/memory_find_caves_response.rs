@@ -0,0 +1,29 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::patching::code_cave::CodeCave;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryFindCavesResponse {
+    /// The caves found, largest first.
+    pub code_caves: Vec<CodeCave>,
+    pub target_address: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryFindCavesResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::FindCaves {
+            memory_find_caves_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::FindCaves { memory_find_caves_response }) = response {
+            Ok(memory_find_caves_response)
+        } else {
+            Err(response)
+        }
+    }
+}
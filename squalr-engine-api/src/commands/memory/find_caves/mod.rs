@@ -0,0 +1,2 @@
+pub mod memory_find_caves_request;
+pub mod memory_find_caves_response;
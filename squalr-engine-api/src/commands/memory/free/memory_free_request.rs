@@ -0,0 +1,28 @@
+use crate::commands::memory::free::memory_free_response::MemoryFreeResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryFreeRequest {
+    pub address: u64,
+    pub size: u64,
+}
+
+impl PrivilegedCommandRequest for MemoryFreeRequest {
+    type ResponseType = MemoryFreeResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Free {
+            memory_free_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryFreeResponse> for MemoryResponse {
+    fn from(memory_free_response: MemoryFreeResponse) -> Self {
+        MemoryResponse::Free { memory_free_response }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_free_request;
+pub mod memory_free_response;
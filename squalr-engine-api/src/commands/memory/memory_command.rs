@@ -1,13 +1,45 @@
+use crate::commands::memory::allocate::memory_allocate_request::MemoryAllocateRequest;
+use crate::commands::memory::collect_writes::memory_collect_writes_request::MemoryCollectWritesRequest;
+use crate::commands::memory::find_caves::memory_find_caves_request::MemoryFindCavesRequest;
+use crate::commands::memory::free::memory_free_request::MemoryFreeRequest;
 use crate::commands::memory::freeze::memory_freeze_request::MemoryFreezeRequest;
+use crate::commands::memory::protect::memory_protect_request::MemoryProtectRequest;
 use crate::commands::memory::query::memory_query_request::MemoryQueryRequest;
 use crate::commands::memory::read::memory_read_request::MemoryReadRequest;
+use crate::commands::memory::track_writes::memory_track_writes_request::MemoryTrackWritesRequest;
 use crate::commands::memory::write::memory_write_request::MemoryWriteRequest;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum MemoryCommand {
-    Freeze { memory_freeze_request: MemoryFreezeRequest },
-    Query { memory_query_request: MemoryQueryRequest },
-    Read { memory_read_request: MemoryReadRequest },
-    Write { memory_write_request: MemoryWriteRequest },
+    Allocate {
+        memory_allocate_request: MemoryAllocateRequest,
+    },
+    Free {
+        memory_free_request: MemoryFreeRequest,
+    },
+    Freeze {
+        memory_freeze_request: MemoryFreezeRequest,
+    },
+    Protect {
+        memory_protect_request: MemoryProtectRequest,
+    },
+    Query {
+        memory_query_request: MemoryQueryRequest,
+    },
+    Read {
+        memory_read_request: MemoryReadRequest,
+    },
+    Write {
+        memory_write_request: MemoryWriteRequest,
+    },
+    TrackWrites {
+        memory_track_writes_request: MemoryTrackWritesRequest,
+    },
+    CollectWrites {
+        memory_collect_writes_request: MemoryCollectWritesRequest,
+    },
+    FindCaves {
+        memory_find_caves_request: MemoryFindCavesRequest,
+    },
 }
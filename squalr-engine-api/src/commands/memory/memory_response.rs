@@ -1,13 +1,45 @@
+use crate::commands::memory::allocate::memory_allocate_response::MemoryAllocateResponse;
+use crate::commands::memory::collect_writes::memory_collect_writes_response::MemoryCollectWritesResponse;
+use crate::commands::memory::find_caves::memory_find_caves_response::MemoryFindCavesResponse;
+use crate::commands::memory::free::memory_free_response::MemoryFreeResponse;
 use crate::commands::memory::freeze::memory_freeze_response::MemoryFreezeResponse;
+use crate::commands::memory::protect::memory_protect_response::MemoryProtectResponse;
 use crate::commands::memory::query::memory_query_response::MemoryQueryResponse;
 use crate::commands::memory::read::memory_read_response::MemoryReadResponse;
+use crate::commands::memory::track_writes::memory_track_writes_response::MemoryTrackWritesResponse;
 use crate::commands::memory::write::memory_write_response::MemoryWriteResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum MemoryResponse {
-    Freeze { memory_freeze_response: MemoryFreezeResponse },
-    Query { memory_query_response: MemoryQueryResponse },
-    Read { memory_read_response: MemoryReadResponse },
-    Write { memory_write_response: MemoryWriteResponse },
+    Allocate {
+        memory_allocate_response: MemoryAllocateResponse,
+    },
+    Free {
+        memory_free_response: MemoryFreeResponse,
+    },
+    Freeze {
+        memory_freeze_response: MemoryFreezeResponse,
+    },
+    Protect {
+        memory_protect_response: MemoryProtectResponse,
+    },
+    Query {
+        memory_query_response: MemoryQueryResponse,
+    },
+    Read {
+        memory_read_response: MemoryReadResponse,
+    },
+    Write {
+        memory_write_response: MemoryWriteResponse,
+    },
+    TrackWrites {
+        memory_track_writes_response: MemoryTrackWritesResponse,
+    },
+    CollectWrites {
+        memory_collect_writes_response: MemoryCollectWritesResponse,
+    },
+    FindCaves {
+        memory_find_caves_response: MemoryFindCavesResponse,
+    },
 }
@@ -1,6 +1,12 @@
+pub mod allocate;
+pub mod collect_writes;
+pub mod find_caves;
+pub mod free;
 pub mod freeze;
 pub mod memory_command;
 pub mod memory_response;
+pub mod protect;
 pub mod query;
 pub mod read;
+pub mod track_writes;
 pub mod write;
@@ -0,0 +1,31 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::protect::memory_protect_response::MemoryProtectResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::memory::memory_protection::MemoryProtection;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryProtectRequest {
+    pub address: u64,
+    pub module_name: String,
+    pub size: u64,
+    pub protection: MemoryProtection,
+}
+
+impl PrivilegedCommandRequest for MemoryProtectRequest {
+    type ResponseType = MemoryProtectResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Protect {
+            memory_protect_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryProtectResponse> for MemoryResponse {
+    fn from(memory_protect_response: MemoryProtectResponse) -> Self {
+        MemoryResponse::Protect { memory_protect_response }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryProtectResponse {
+    /// The page-aligned region that was re-protected.
+    pub region: Option<NormalizedRegion>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryProtectResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Protect {
+            memory_protect_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Protect { memory_protect_response }) = response {
+            Ok(memory_protect_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::commands::memory::memory_response::MemoryResponse;
 use crate::commands::memory::read::memory_read_response::MemoryReadResponse;
 use crate::commands::privileged_command::PrivilegedCommand;
 use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::plugins::value_transform::ValueTransformRef;
 use crate::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
 use serde::Deserialize;
 use serde::Serialize;
@@ -15,6 +16,10 @@ pub struct MemoryReadRequest {
     pub symbolic_struct_definition: SymbolicStructDefinition,
     #[serde(default)]
     pub suppress_logging: bool,
+
+    /// Decodes the value read from memory with this transform.
+    #[serde(default)]
+    pub value_transform: Option<ValueTransformRef>,
 }
 
 impl PrivilegedCommandRequest for MemoryReadRequest {
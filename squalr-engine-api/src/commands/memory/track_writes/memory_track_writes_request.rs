@@ -0,0 +1,32 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::track_writes::memory_track_writes_response::MemoryTrackWritesResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+/// Starts tracking which pages the opened process writes, among the pages that a new scan would read.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryTrackWritesRequest {
+    /// Limits tracking to this module when not empty. The address is then relative to the module base.
+    pub module_name: String,
+    pub address: u64,
+    /// Limits tracking to the range starting at the address. Zero tracks the whole module, or every page without a module.
+    pub size: u64,
+}
+
+impl PrivilegedCommandRequest for MemoryTrackWritesRequest {
+    type ResponseType = MemoryTrackWritesResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::TrackWrites {
+            memory_track_writes_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryTrackWritesResponse> for MemoryResponse {
+    fn from(memory_track_writes_response: MemoryTrackWritesResponse) -> Self {
+        MemoryResponse::TrackWrites { memory_track_writes_response }
+    }
+}
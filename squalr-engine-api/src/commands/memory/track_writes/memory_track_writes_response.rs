@@ -0,0 +1,29 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryTrackWritesResponse {
+    pub tracked_region_count: u64,
+    pub tracked_byte_count: u64,
+    /// Whether the OS reports written pages. Otherwise, only pages whose contents change are found.
+    pub is_page_tracking_supported: bool,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryTrackWritesResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::TrackWrites {
+            memory_track_writes_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::TrackWrites { memory_track_writes_response }) = response {
+            Ok(memory_track_writes_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_track_writes_request;
+pub mod memory_track_writes_response;
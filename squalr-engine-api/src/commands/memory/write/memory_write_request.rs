@@ -3,6 +3,7 @@ use crate::commands::memory::memory_response::MemoryResponse;
 use crate::commands::memory::write::memory_write_response::MemoryWriteResponse;
 use crate::commands::privileged_command::PrivilegedCommand;
 use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::plugins::value_transform::ValueTransformRef;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -11,6 +12,10 @@ pub struct MemoryWriteRequest {
     pub address: u64,
     pub module_name: String,
     pub value: Vec<u8>,
+
+    /// Encodes the value with this transform before it is written to memory.
+    #[serde(default)]
+    pub value_transform: Option<ValueTransformRef>,
 }
 
 impl PrivilegedCommandRequest for MemoryWriteRequest {
@@ -1,6 +1,8 @@
 pub mod command_invocation;
 pub mod command_line;
+pub mod debugger;
 pub mod memory;
+pub mod patch;
 pub mod plugins;
 pub mod pointer_scan;
 pub mod privileged_command;
@@ -16,6 +18,7 @@ pub mod scan;
 pub mod scan_results;
 pub mod settings;
 pub mod struct_scan;
+pub mod thread;
 pub mod trackable_tasks;
 pub mod unprivileged_command;
 pub mod unprivileged_command_request;
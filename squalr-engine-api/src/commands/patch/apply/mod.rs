@@ -0,0 +1,2 @@
+pub mod patch_apply_request;
+pub mod patch_apply_response;
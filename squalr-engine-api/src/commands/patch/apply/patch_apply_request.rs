@@ -0,0 +1,28 @@
+use crate::commands::patch::apply::patch_apply_response::PatchApplyResponse;
+use crate::commands::patch::patch_command::PatchCommand;
+use crate::commands::patch::patch_response::PatchResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::patching::code_patch_definition::CodePatchDefinition;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PatchApplyRequest {
+    pub code_patch_definition: CodePatchDefinition,
+}
+
+impl PrivilegedCommandRequest for PatchApplyRequest {
+    type ResponseType = PatchApplyResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Patch(PatchCommand::Apply {
+            patch_apply_request: self.clone(),
+        })
+    }
+}
+
+impl From<PatchApplyResponse> for PatchResponse {
+    fn from(patch_apply_response: PatchApplyResponse) -> Self {
+        PatchResponse::Apply { patch_apply_response }
+    }
+}
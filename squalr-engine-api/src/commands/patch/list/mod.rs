@@ -0,0 +1,2 @@
+pub mod patch_list_request;
+pub mod patch_list_response;
@@ -0,0 +1,25 @@
+use crate::commands::patch::list::patch_list_response::PatchListResponse;
+use crate::commands::patch::patch_command::PatchCommand;
+use crate::commands::patch::patch_response::PatchResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PatchListRequest {}
+
+impl PrivilegedCommandRequest for PatchListRequest {
+    type ResponseType = PatchListResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Patch(PatchCommand::List {
+            patch_list_request: self.clone(),
+        })
+    }
+}
+
+impl From<PatchListResponse> for PatchResponse {
+    fn from(patch_list_response: PatchListResponse) -> Self {
+        PatchResponse::List { patch_list_response }
+    }
+}
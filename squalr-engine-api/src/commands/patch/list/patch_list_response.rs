@@ -0,0 +1,28 @@
+use crate::commands::patch::patch_response::PatchResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::patching::code_patch::CodePatch;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PatchListResponse {
+    pub code_patches: Vec<CodePatch>,
+}
+
+impl TypedPrivilegedCommandResponse for PatchListResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Patch(Box::new(PatchResponse::List {
+            patch_list_response: self.clone(),
+        }))
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        match response {
+            PrivilegedCommandResponse::Patch(patch_response) => match *patch_response {
+                PatchResponse::List { patch_list_response } => Ok(patch_list_response),
+                patch_response => Err(PrivilegedCommandResponse::Patch(Box::new(patch_response))),
+            },
+            response => Err(response),
+        }
+    }
+}
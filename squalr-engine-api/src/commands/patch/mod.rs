@@ -0,0 +1,5 @@
+pub mod apply;
+pub mod list;
+pub mod patch_command;
+pub mod patch_response;
+pub mod undo;
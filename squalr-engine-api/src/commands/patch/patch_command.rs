@@ -0,0 +1,11 @@
+use crate::commands::patch::apply::patch_apply_request::PatchApplyRequest;
+use crate::commands::patch::list::patch_list_request::PatchListRequest;
+use crate::commands::patch::undo::patch_undo_request::PatchUndoRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum PatchCommand {
+    Apply { patch_apply_request: PatchApplyRequest },
+    List { patch_list_request: PatchListRequest },
+    Undo { patch_undo_request: PatchUndoRequest },
+}
@@ -0,0 +1,11 @@
+use crate::commands::patch::apply::patch_apply_response::PatchApplyResponse;
+use crate::commands::patch::list::patch_list_response::PatchListResponse;
+use crate::commands::patch::undo::patch_undo_response::PatchUndoResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum PatchResponse {
+    Apply { patch_apply_response: PatchApplyResponse },
+    List { patch_list_response: PatchListResponse },
+    Undo { patch_undo_response: PatchUndoResponse },
+}
@@ -0,0 +1,2 @@
+pub mod patch_undo_request;
+pub mod patch_undo_response;
@@ -0,0 +1,28 @@
+use crate::commands::patch::patch_command::PatchCommand;
+use crate::commands::patch::patch_response::PatchResponse;
+use crate::commands::patch::undo::patch_undo_response::PatchUndoResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PatchUndoRequest {
+    pub address: u64,
+    pub module_name: String,
+}
+
+impl PrivilegedCommandRequest for PatchUndoRequest {
+    type ResponseType = PatchUndoResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Patch(PatchCommand::Undo {
+            patch_undo_request: self.clone(),
+        })
+    }
+}
+
+impl From<PatchUndoResponse> for PatchResponse {
+    fn from(patch_undo_response: PatchUndoResponse) -> Self {
+        PatchResponse::Undo { patch_undo_response }
+    }
+}
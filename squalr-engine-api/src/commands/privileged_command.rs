@@ -1,4 +1,6 @@
+use crate::commands::debugger::debugger_command::DebuggerCommand;
 use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::patch::patch_command::PatchCommand;
 use crate::commands::plugins::plugins_command::PluginsCommand;
 use crate::commands::pointer_scan::pointer_scan_command::PointerScanCommand;
 use crate::commands::process::process_command::ProcessCommand;
@@ -7,12 +9,15 @@ use crate::commands::scan::scan_command::ScanCommand;
 use crate::commands::scan_results::scan_results_command::ScanResultsCommand;
 use crate::commands::settings::settings_command::SettingsCommand;
 use crate::commands::struct_scan::struct_scan_command::StructScanCommand;
+use crate::commands::thread::thread_command::ThreadCommand;
 use crate::commands::trackable_tasks::trackable_tasks_command::TrackableTasksCommand;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum PrivilegedCommand {
+    Debugger(DebuggerCommand),
     Memory(MemoryCommand),
+    Patch(PatchCommand),
     Plugins(PluginsCommand),
     Process(ProcessCommand),
     Registry(RegistryCommand),
@@ -20,6 +25,7 @@ pub enum PrivilegedCommand {
     Scan(ScanCommand),
     PointerScan(PointerScanCommand),
     StructScan(StructScanCommand),
+    Thread(ThreadCommand),
     Settings(SettingsCommand),
     TrackableTasks(TrackableTasksCommand),
 }
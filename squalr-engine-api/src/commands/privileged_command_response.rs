@@ -1,4 +1,6 @@
+use crate::commands::debugger::debugger_response::DebuggerResponse;
 use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::patch::patch_response::PatchResponse;
 use crate::commands::plugins::plugins_response::PluginsResponse;
 use crate::commands::pointer_scan::pointer_scan_response::PointerScanResponse;
 use crate::commands::process::process_response::ProcessResponse;
@@ -9,12 +11,16 @@ use crate::commands::scan::scan_response::ScanResponse;
 use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
 use crate::commands::settings::settings_response::SettingsResponse;
 use crate::commands::struct_scan::struct_scan_response::StructScanResponse;
+use crate::commands::thread::thread_response::ThreadResponse;
 use crate::commands::trackable_tasks::trackable_tasks_response::TrackableTasksResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum PrivilegedCommandResponse {
+    Debugger(DebuggerResponse),
     Memory(MemoryResponse),
+    /// Boxed, since applied patches carry their original and patched bytes along with the detour code cave.
+    Patch(Box<PatchResponse>),
     Plugins(PluginsResponse),
     Process(ProcessResponse),
     Registry(RegistryResponse),
@@ -24,6 +30,7 @@ pub enum PrivilegedCommandResponse {
     Scan(ScanResponse),
     PointerScan(PointerScanResponse),
     StructScan(StructScanResponse),
+    Thread(ThreadResponse),
     Settings(SettingsResponse),
     TrackableTasks(TrackableTasksResponse),
 }
@@ -0,0 +1,2 @@
+pub mod process_inject_library_request;
+pub mod process_inject_library_response;
@@ -0,0 +1,30 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::process::inject_library::process_inject_library_response::ProcessInjectLibraryResponse;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProcessInjectLibraryRequest {
+    /// The shared library to load into the opened process. Relative paths are resolved against the engine's working directory.
+    pub library_path: String,
+}
+
+impl PrivilegedCommandRequest for ProcessInjectLibraryRequest {
+    type ResponseType = ProcessInjectLibraryResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Process(ProcessCommand::InjectLibrary {
+            process_inject_library_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessInjectLibraryResponse> for ProcessResponse {
+    fn from(process_inject_library_response: ProcessInjectLibraryResponse) -> Self {
+        ProcessResponse::InjectLibrary {
+            process_inject_library_response,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::process::process_response::ProcessResponse;
+use crate::structures::memory::normalized_module::NormalizedModule;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProcessInjectLibraryResponse {
+    /// The module of the loaded library, whose base address is where it was mapped.
+    pub injected_module: Option<NormalizedModule>,
+    /// The modules of the process after the library was loaded.
+    pub modules: Vec<NormalizedModule>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for ProcessInjectLibraryResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Process(ProcessResponse::InjectLibrary {
+            process_inject_library_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Process(ProcessResponse::InjectLibrary {
+            process_inject_library_response,
+        }) = response
+        {
+            Ok(process_inject_library_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::process::launch::process_launch_response::ProcessLaunchResponse;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProcessLaunchRequest {
+    pub executable_path: String,
+    pub arguments: Vec<String>,
+    /// Variables set on top of the environment inherited from the engine.
+    pub environment: Vec<(String, String)>,
+    /// Leaves the launched process stopped at its entry point until its threads are resumed.
+    pub is_suspended: bool,
+}
+
+impl PrivilegedCommandRequest for ProcessLaunchRequest {
+    type ResponseType = ProcessLaunchResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Process(ProcessCommand::Launch {
+            process_launch_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessLaunchResponse> for ProcessResponse {
+    fn from(process_launch_response: ProcessLaunchResponse) -> Self {
+        ProcessResponse::Launch { process_launch_response }
+    }
+}
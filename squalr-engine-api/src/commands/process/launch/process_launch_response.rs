@@ -0,0 +1,27 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::process::process_response::ProcessResponse;
+use crate::structures::processes::opened_process_info::OpenedProcessInfo;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProcessLaunchResponse {
+    pub opened_process_info: Option<OpenedProcessInfo>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for ProcessLaunchResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Process(ProcessResponse::Launch {
+            process_launch_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Process(ProcessResponse::Launch { process_launch_response }) = response {
+            Ok(process_launch_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -1,13 +1,29 @@
 use crate::commands::process::close::process_close_request::ProcessCloseRequest;
 use crate::commands::process::icon::process_icon_request::ProcessIconRequest;
+use crate::commands::process::inject_library::process_inject_library_request::ProcessInjectLibraryRequest;
+use crate::commands::process::launch::process_launch_request::ProcessLaunchRequest;
 use crate::commands::process::list::process_list_request::ProcessListRequest;
 use crate::commands::process::open::process_open_request::ProcessOpenRequest;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ProcessCommand {
-    Open { process_open_request: ProcessOpenRequest },
-    List { process_list_request: ProcessListRequest },
-    Icon { process_icon_request: ProcessIconRequest },
-    Close { process_close_request: ProcessCloseRequest },
+    Open {
+        process_open_request: ProcessOpenRequest,
+    },
+    Launch {
+        process_launch_request: ProcessLaunchRequest,
+    },
+    List {
+        process_list_request: ProcessListRequest,
+    },
+    Icon {
+        process_icon_request: ProcessIconRequest,
+    },
+    Close {
+        process_close_request: ProcessCloseRequest,
+    },
+    InjectLibrary {
+        process_inject_library_request: ProcessInjectLibraryRequest,
+    },
 }
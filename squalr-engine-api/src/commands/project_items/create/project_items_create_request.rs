@@ -4,6 +4,7 @@ use crate::commands::project_items::project_items_response::ProjectItemsResponse
 use crate::commands::unprivileged_command::UnprivilegedCommand;
 use crate::commands::unprivileged_command_request::UnprivilegedCommandRequest;
 use crate::structures::memory::pointer_chain_segment::PointerChainSegment;
+use crate::structures::patching::code_patch_definition::CodePatchDefinition;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -26,6 +27,14 @@ pub struct ProjectItemsCreateRequest {
 
     #[serde(default)]
     pub pointer_offsets: Option<Vec<PointerChainSegment>>,
+
+    /// Creates an item of a plugin-contributed project item type instead of an address.
+    #[serde(default)]
+    pub project_item_type_id: Option<String>,
+
+    /// Creates a toggleable code patch item instead of an address.
+    #[serde(default)]
+    pub code_patch: Option<CodePatchDefinition>,
 }
 
 impl UnprivilegedCommandRequest for ProjectItemsCreateRequest {
@@ -0,0 +1,2 @@
+pub mod project_symbols_export_request;
+pub mod project_symbols_export_response;
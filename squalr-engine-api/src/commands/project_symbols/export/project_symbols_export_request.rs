@@ -0,0 +1,38 @@
+use crate::commands::project_symbols::export::project_symbols_export_response::ProjectSymbolsExportResponse;
+use crate::commands::project_symbols::project_symbols_command::ProjectSymbolsCommand;
+use crate::commands::project_symbols::project_symbols_response::ProjectSymbolsResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use crate::structures::memory::bitness::Bitness;
+use crate::structures::projects::symbol_interchange::symbol_export_format::SymbolExportFormat;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct ProjectSymbolsExportRequest {
+    pub output_file_path: PathBuf,
+
+    #[serde(default)]
+    pub export_format: SymbolExportFormat,
+
+    #[serde(default)]
+    pub bitness: Bitness,
+}
+
+impl UnprivilegedCommandRequest for ProjectSymbolsExportRequest {
+    type ResponseType = ProjectSymbolsExportResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectSymbols(ProjectSymbolsCommand::Export {
+            project_symbols_export_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectSymbolsExportResponse> for ProjectSymbolsResponse {
+    fn from(project_symbols_export_response: ProjectSymbolsExportResponse) -> Self {
+        ProjectSymbolsResponse::Export {
+            project_symbols_export_response,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::commands::project_symbols::project_symbols_response::ProjectSymbolsResponse;
+use crate::commands::unprivileged_command_response::{TypedUnprivilegedCommandResponse, UnprivilegedCommandResponse};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectSymbolsExportResponse {
+    pub success: bool,
+    pub output_file_path: Option<PathBuf>,
+    pub exported_struct_layout_ids: Vec<String>,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+impl TypedUnprivilegedCommandResponse for ProjectSymbolsExportResponse {
+    fn to_engine_response(&self) -> UnprivilegedCommandResponse {
+        UnprivilegedCommandResponse::ProjectSymbols(ProjectSymbolsResponse::Export {
+            project_symbols_export_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: UnprivilegedCommandResponse) -> Result<Self, UnprivilegedCommandResponse> {
+        if let UnprivilegedCommandResponse::ProjectSymbols(ProjectSymbolsResponse::Export {
+            project_symbols_export_response,
+        }) = response
+        {
+            Ok(project_symbols_export_response)
+        } else {
+            Err(response)
+        }
+    }
+}
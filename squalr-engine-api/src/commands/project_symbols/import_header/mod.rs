@@ -0,0 +1,2 @@
+pub mod project_symbols_import_header_request;
+pub mod project_symbols_import_header_response;
@@ -0,0 +1,47 @@
+use crate::commands::project_symbols::import_header::project_symbols_import_header_response::ProjectSymbolsImportHeaderResponse;
+use crate::commands::project_symbols::project_symbols_command::ProjectSymbolsCommand;
+use crate::commands::project_symbols::project_symbols_response::ProjectSymbolsResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use crate::structures::memory::bitness::Bitness;
+use crate::structures::projects::symbol_interchange::c_header::c_header_import_options::{CHeaderDataModel, CHeaderImportOptions};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct ProjectSymbolsImportHeaderRequest {
+    pub header_file_path: PathBuf,
+
+    #[serde(default)]
+    pub bitness: Bitness,
+
+    #[serde(default)]
+    pub data_model: CHeaderDataModel,
+
+    #[serde(default)]
+    pub layout_id_prefix: String,
+}
+
+impl ProjectSymbolsImportHeaderRequest {
+    pub fn to_import_options(&self) -> CHeaderImportOptions {
+        CHeaderImportOptions::new(self.bitness, self.data_model, self.layout_id_prefix.clone())
+    }
+}
+
+impl UnprivilegedCommandRequest for ProjectSymbolsImportHeaderRequest {
+    type ResponseType = ProjectSymbolsImportHeaderResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectSymbols(ProjectSymbolsCommand::ImportHeader {
+            project_symbols_import_header_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectSymbolsImportHeaderResponse> for ProjectSymbolsResponse {
+    fn from(project_symbols_import_header_response: ProjectSymbolsImportHeaderResponse) -> Self {
+        ProjectSymbolsResponse::ImportHeader {
+            project_symbols_import_header_response,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::commands::project_symbols::project_symbols_response::ProjectSymbolsResponse;
+use crate::commands::unprivileged_command_response::{TypedUnprivilegedCommandResponse, UnprivilegedCommandResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectSymbolsImportHeaderResponse {
+    pub success: bool,
+    pub imported_struct_layout_ids: Vec<String>,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+impl TypedUnprivilegedCommandResponse for ProjectSymbolsImportHeaderResponse {
+    fn to_engine_response(&self) -> UnprivilegedCommandResponse {
+        UnprivilegedCommandResponse::ProjectSymbols(ProjectSymbolsResponse::ImportHeader {
+            project_symbols_import_header_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: UnprivilegedCommandResponse) -> Result<Self, UnprivilegedCommandResponse> {
+        if let UnprivilegedCommandResponse::ProjectSymbols(ProjectSymbolsResponse::ImportHeader {
+            project_symbols_import_header_response,
+        }) = response
+        {
+            Ok(project_symbols_import_header_response)
+        } else {
+            Err(response)
+        }
+    }
+}
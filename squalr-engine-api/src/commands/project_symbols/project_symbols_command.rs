@@ -5,7 +5,10 @@ use crate::commands::project_symbols::{
     delete_layout::project_symbols_delete_layout_request::ProjectSymbolsDeleteLayoutRequest,
     delete_resolver::project_symbols_delete_resolver_request::ProjectSymbolsDeleteResolverRequest,
     execute_plugin_action::project_symbols_execute_plugin_action_request::ProjectSymbolsExecutePluginActionRequest,
-    list::project_symbols_list_request::ProjectSymbolsListRequest, rename::project_symbols_rename_request::ProjectSymbolsRenameRequest,
+    export::project_symbols_export_request::ProjectSymbolsExportRequest,
+    import_header::project_symbols_import_header_request::ProjectSymbolsImportHeaderRequest, list::project_symbols_list_request::ProjectSymbolsListRequest,
+    references::project_symbols_references_request::ProjectSymbolsReferencesRequest, rename::project_symbols_rename_request::ProjectSymbolsRenameRequest,
+    rename_layout::project_symbols_rename_layout_request::ProjectSymbolsRenameLayoutRequest,
     rename_module::project_symbols_rename_module_request::ProjectSymbolsRenameModuleRequest,
     update::project_symbols_update_request::ProjectSymbolsUpdateRequest,
     upsert_layout::project_symbols_upsert_layout_request::ProjectSymbolsUpsertLayoutRequest,
@@ -40,12 +43,28 @@ pub enum ProjectSymbolsCommand {
     ExecutePluginAction {
         project_symbols_execute_plugin_action_request: ProjectSymbolsExecutePluginActionRequest,
     },
+    /// Exports reusable symbol layouts and module roots to a C header, ReClass.NET, or Ghidra file.
+    Export {
+        project_symbols_export_request: ProjectSymbolsExportRequest,
+    },
+    /// Imports reusable symbol layouts from a C header file.
+    ImportHeader {
+        project_symbols_import_header_request: ProjectSymbolsImportHeaderRequest,
+    },
     /// Lists the current project symbol store.
     List { project_symbols_list_request: ProjectSymbolsListRequest },
-    /// Renames a project symbol claim display name.
+    /// Lists every layout, resolver, and project item that refers to a project symbol by name.
+    References {
+        project_symbols_references_request: ProjectSymbolsReferencesRequest,
+    },
+    /// Renames a project symbol claim display name and rewrites references to it.
     Rename {
         project_symbols_rename_request: ProjectSymbolsRenameRequest,
     },
+    /// Renames a reusable symbol layout and retargets every type that names it.
+    RenameLayout {
+        project_symbols_rename_layout_request: ProjectSymbolsRenameLayoutRequest,
+    },
     /// Renames a Symbol Tree module root and its module-relative claims.
     RenameModule {
         project_symbols_rename_module_request: ProjectSymbolsRenameModuleRequest,
@@ -5,7 +5,10 @@ use crate::commands::project_symbols::{
     delete_layout::project_symbols_delete_layout_response::ProjectSymbolsDeleteLayoutResponse,
     delete_resolver::project_symbols_delete_resolver_response::ProjectSymbolsDeleteResolverResponse,
     execute_plugin_action::project_symbols_execute_plugin_action_response::ProjectSymbolsExecutePluginActionResponse,
-    list::project_symbols_list_response::ProjectSymbolsListResponse, rename::project_symbols_rename_response::ProjectSymbolsRenameResponse,
+    export::project_symbols_export_response::ProjectSymbolsExportResponse,
+    import_header::project_symbols_import_header_response::ProjectSymbolsImportHeaderResponse, list::project_symbols_list_response::ProjectSymbolsListResponse,
+    references::project_symbols_references_response::ProjectSymbolsReferencesResponse, rename::project_symbols_rename_response::ProjectSymbolsRenameResponse,
+    rename_layout::project_symbols_rename_layout_response::ProjectSymbolsRenameLayoutResponse,
     rename_module::project_symbols_rename_module_response::ProjectSymbolsRenameModuleResponse,
     update::project_symbols_update_response::ProjectSymbolsUpdateResponse,
     upsert_layout::project_symbols_upsert_layout_response::ProjectSymbolsUpsertLayoutResponse,
@@ -34,12 +37,24 @@ pub enum ProjectSymbolsResponse {
     ExecutePluginAction {
         project_symbols_execute_plugin_action_response: ProjectSymbolsExecutePluginActionResponse,
     },
+    Export {
+        project_symbols_export_response: ProjectSymbolsExportResponse,
+    },
+    ImportHeader {
+        project_symbols_import_header_response: ProjectSymbolsImportHeaderResponse,
+    },
     List {
         project_symbols_list_response: ProjectSymbolsListResponse,
     },
+    References {
+        project_symbols_references_response: ProjectSymbolsReferencesResponse,
+    },
     Rename {
         project_symbols_rename_response: ProjectSymbolsRenameResponse,
     },
+    RenameLayout {
+        project_symbols_rename_layout_response: ProjectSymbolsRenameLayoutResponse,
+    },
     RenameModule {
         project_symbols_rename_module_response: ProjectSymbolsRenameModuleResponse,
     },
@@ -0,0 +1,2 @@
+pub mod project_symbols_references_request;
+pub mod project_symbols_references_response;
@@ -0,0 +1,30 @@
+use crate::commands::project_symbols::project_symbols_command::ProjectSymbolsCommand;
+use crate::commands::project_symbols::project_symbols_response::ProjectSymbolsResponse;
+use crate::commands::project_symbols::references::project_symbols_references_response::ProjectSymbolsReferencesResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct ProjectSymbolsReferencesRequest {
+    /// A symbol locator key, `module!name` path, or struct layout id.
+    pub symbol: String,
+}
+
+impl UnprivilegedCommandRequest for ProjectSymbolsReferencesRequest {
+    type ResponseType = ProjectSymbolsReferencesResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectSymbols(ProjectSymbolsCommand::References {
+            project_symbols_references_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectSymbolsReferencesResponse> for ProjectSymbolsResponse {
+    fn from(project_symbols_references_response: ProjectSymbolsReferencesResponse) -> Self {
+        ProjectSymbolsResponse::References {
+            project_symbols_references_response,
+        }
+    }
+}
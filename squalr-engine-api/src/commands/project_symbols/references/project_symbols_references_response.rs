@@ -0,0 +1,31 @@
+use crate::commands::project_symbols::project_symbols_response::ProjectSymbolsResponse;
+use crate::commands::unprivileged_command_response::{TypedUnprivilegedCommandResponse, UnprivilegedCommandResponse};
+use crate::structures::projects::symbol_references::project_symbol_reference::ProjectSymbolReference;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectSymbolsReferencesResponse {
+    pub success: bool,
+    pub target: String,
+    pub references: Vec<ProjectSymbolReference>,
+    pub error: Option<String>,
+}
+
+impl TypedUnprivilegedCommandResponse for ProjectSymbolsReferencesResponse {
+    fn to_engine_response(&self) -> UnprivilegedCommandResponse {
+        UnprivilegedCommandResponse::ProjectSymbols(ProjectSymbolsResponse::References {
+            project_symbols_references_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: UnprivilegedCommandResponse) -> Result<Self, UnprivilegedCommandResponse> {
+        if let UnprivilegedCommandResponse::ProjectSymbols(ProjectSymbolsResponse::References {
+            project_symbols_references_response,
+        }) = response
+        {
+            Ok(project_symbols_references_response)
+        } else {
+            Err(response)
+        }
+    }
+}
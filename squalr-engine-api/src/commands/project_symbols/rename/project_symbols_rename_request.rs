@@ -9,6 +9,9 @@ use serde::{Deserialize, Serialize};
 pub struct ProjectSymbolsRenameRequest {
     pub symbol_locator_key: String,
     pub display_name: String,
+    /// Returns the edits that the rename would make without applying them.
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 impl UnprivilegedCommandRequest for ProjectSymbolsRenameRequest {
@@ -1,11 +1,16 @@
 use crate::commands::project_symbols::project_symbols_response::ProjectSymbolsResponse;
 use crate::commands::unprivileged_command_response::{TypedUnprivilegedCommandResponse, UnprivilegedCommandResponse};
+use crate::structures::projects::symbol_references::project_symbol_rename_edit::ProjectSymbolRenameEdit;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ProjectSymbolsRenameResponse {
     pub success: bool,
     pub symbol_locator_key: String,
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default)]
+    pub edits: Vec<ProjectSymbolRenameEdit>,
 }
 
 impl TypedUnprivilegedCommandResponse for ProjectSymbolsRenameResponse {
@@ -0,0 +1,2 @@
+pub mod project_symbols_rename_layout_request;
+pub mod project_symbols_rename_layout_response;
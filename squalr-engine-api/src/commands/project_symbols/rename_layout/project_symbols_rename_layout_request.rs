@@ -0,0 +1,33 @@
+use crate::commands::project_symbols::project_symbols_command::ProjectSymbolsCommand;
+use crate::commands::project_symbols::project_symbols_response::ProjectSymbolsResponse;
+use crate::commands::project_symbols::rename_layout::project_symbols_rename_layout_response::ProjectSymbolsRenameLayoutResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct ProjectSymbolsRenameLayoutRequest {
+    pub struct_layout_id: String,
+    pub new_struct_layout_id: String,
+    /// Returns the edits that the rename would make without applying them.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+impl UnprivilegedCommandRequest for ProjectSymbolsRenameLayoutRequest {
+    type ResponseType = ProjectSymbolsRenameLayoutResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectSymbols(ProjectSymbolsCommand::RenameLayout {
+            project_symbols_rename_layout_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectSymbolsRenameLayoutResponse> for ProjectSymbolsResponse {
+    fn from(project_symbols_rename_layout_response: ProjectSymbolsRenameLayoutResponse) -> Self {
+        ProjectSymbolsResponse::RenameLayout {
+            project_symbols_rename_layout_response,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::commands::project_symbols::project_symbols_response::ProjectSymbolsResponse;
+use crate::commands::unprivileged_command_response::{TypedUnprivilegedCommandResponse, UnprivilegedCommandResponse};
+use crate::structures::projects::symbol_references::project_symbol_rename_edit::ProjectSymbolRenameEdit;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectSymbolsRenameLayoutResponse {
+    pub success: bool,
+    pub struct_layout_id: String,
+    pub dry_run: bool,
+    pub edits: Vec<ProjectSymbolRenameEdit>,
+    pub error: Option<String>,
+}
+
+impl TypedUnprivilegedCommandResponse for ProjectSymbolsRenameLayoutResponse {
+    fn to_engine_response(&self) -> UnprivilegedCommandResponse {
+        UnprivilegedCommandResponse::ProjectSymbols(ProjectSymbolsResponse::RenameLayout {
+            project_symbols_rename_layout_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: UnprivilegedCommandResponse) -> Result<Self, UnprivilegedCommandResponse> {
+        if let UnprivilegedCommandResponse::ProjectSymbols(ProjectSymbolsResponse::RenameLayout {
+            project_symbols_rename_layout_response,
+        }) = response
+        {
+            Ok(project_symbols_rename_layout_response)
+        } else {
+            Err(response)
+        }
+    }
+}
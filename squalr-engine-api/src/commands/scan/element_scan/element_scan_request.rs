@@ -2,6 +2,7 @@ use crate::commands::privileged_command_request::PrivilegedCommandRequest;
 use crate::commands::scan::element_scan::element_scan_response::ElementScanResponse;
 use crate::commands::scan::scan_command::ScanCommand;
 use crate::commands::scan::scan_response::ScanResponse;
+use crate::plugins::value_transform::ValueTransformRef;
 use crate::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
 use crate::{commands::privileged_command::PrivilegedCommand, structures::data_types::data_type_ref::DataTypeRef};
 use serde::{Deserialize, Serialize};
@@ -10,6 +11,10 @@ use serde::{Deserialize, Serialize};
 pub struct ElementScanRequest {
     pub scan_constraints: Vec<AnonymousScanConstraint>,
     pub data_type_refs: Vec<DataTypeRef>,
+
+    /// Compares values after decoding them with this transform, for values that the target stores obfuscated.
+    #[serde(default)]
+    pub value_transform: Option<ValueTransformRef>,
 }
 
 impl PrivilegedCommandRequest for ElementScanRequest {
@@ -0,0 +1,2 @@
+pub mod thread_context_request;
+pub mod thread_context_response;
@@ -0,0 +1,27 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::thread::context::thread_context_response::ThreadContextResponse;
+use crate::commands::thread::thread_command::ThreadCommand;
+use crate::commands::thread::thread_response::ThreadResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThreadContextRequest {
+    pub thread_id: u32,
+}
+
+impl PrivilegedCommandRequest for ThreadContextRequest {
+    type ResponseType = ThreadContextResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Thread(ThreadCommand::Context {
+            thread_context_request: self.clone(),
+        })
+    }
+}
+
+impl From<ThreadContextResponse> for ThreadResponse {
+    fn from(thread_context_response: ThreadContextResponse) -> Self {
+        ThreadResponse::Context { thread_context_response }
+    }
+}
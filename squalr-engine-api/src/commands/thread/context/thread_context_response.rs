@@ -0,0 +1,27 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::thread::thread_response::ThreadResponse;
+use crate::structures::debugging::register_snapshot::RegisterSnapshot;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ThreadContextResponse {
+    pub register_snapshot: Option<RegisterSnapshot>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for ThreadContextResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Thread(ThreadResponse::Context {
+            thread_context_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Thread(ThreadResponse::Context { thread_context_response }) = response {
+            Ok(thread_context_response)
+        } else {
+            Err(response)
+        }
+    }
+}
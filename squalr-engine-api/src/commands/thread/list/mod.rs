@@ -0,0 +1,2 @@
+pub mod thread_list_request;
+pub mod thread_list_response;
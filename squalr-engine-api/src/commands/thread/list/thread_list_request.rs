@@ -0,0 +1,25 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::thread::list::thread_list_response::ThreadListResponse;
+use crate::commands::thread::thread_command::ThreadCommand;
+use crate::commands::thread::thread_response::ThreadResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ThreadListRequest {}
+
+impl PrivilegedCommandRequest for ThreadListRequest {
+    type ResponseType = ThreadListResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Thread(ThreadCommand::List {
+            thread_list_request: self.clone(),
+        })
+    }
+}
+
+impl From<ThreadListResponse> for ThreadResponse {
+    fn from(thread_list_response: ThreadListResponse) -> Self {
+        ThreadResponse::List { thread_list_response }
+    }
+}
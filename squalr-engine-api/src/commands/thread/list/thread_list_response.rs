@@ -0,0 +1,27 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::thread::thread_response::ThreadResponse;
+use crate::structures::processes::thread_info::ThreadInfo;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ThreadListResponse {
+    pub threads: Vec<ThreadInfo>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for ThreadListResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Thread(ThreadResponse::List {
+            thread_list_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Thread(ThreadResponse::List { thread_list_response }) = response {
+            Ok(thread_list_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod context;
+pub mod list;
+pub mod resume;
+pub mod suspend;
+pub mod thread_command;
+pub mod thread_response;
@@ -0,0 +1,2 @@
+pub mod thread_resume_request;
+pub mod thread_resume_response;
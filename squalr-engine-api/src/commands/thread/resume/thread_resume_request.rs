@@ -0,0 +1,28 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::thread::resume::thread_resume_response::ThreadResumeResponse;
+use crate::commands::thread::thread_command::ThreadCommand;
+use crate::commands::thread::thread_response::ThreadResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ThreadResumeRequest {
+    /// The thread to resume. Resumes every suspended thread of the process when not set.
+    pub thread_id: Option<u32>,
+}
+
+impl PrivilegedCommandRequest for ThreadResumeRequest {
+    type ResponseType = ThreadResumeResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Thread(ThreadCommand::Resume {
+            thread_resume_request: self.clone(),
+        })
+    }
+}
+
+impl From<ThreadResumeResponse> for ThreadResponse {
+    fn from(thread_resume_response: ThreadResumeResponse) -> Self {
+        ThreadResponse::Resume { thread_resume_response }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::thread::thread_response::ThreadResponse;
+use crate::structures::processes::thread_info::ThreadInfo;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ThreadResumeResponse {
+    /// The threads of the process after the resume request was applied.
+    pub threads: Vec<ThreadInfo>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for ThreadResumeResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Thread(ThreadResponse::Resume {
+            thread_resume_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Thread(ThreadResponse::Resume { thread_resume_response }) = response {
+            Ok(thread_resume_response)
+        } else {
+            Err(response)
+        }
+    }
+}
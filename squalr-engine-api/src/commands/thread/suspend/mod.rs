@@ -0,0 +1,2 @@
+pub mod thread_suspend_request;
+pub mod thread_suspend_response;
@@ -0,0 +1,28 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::thread::suspend::thread_suspend_response::ThreadSuspendResponse;
+use crate::commands::thread::thread_command::ThreadCommand;
+use crate::commands::thread::thread_response::ThreadResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ThreadSuspendRequest {
+    /// The thread to suspend. Suspends every thread of the process when not set.
+    pub thread_id: Option<u32>,
+}
+
+impl PrivilegedCommandRequest for ThreadSuspendRequest {
+    type ResponseType = ThreadSuspendResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Thread(ThreadCommand::Suspend {
+            thread_suspend_request: self.clone(),
+        })
+    }
+}
+
+impl From<ThreadSuspendResponse> for ThreadResponse {
+    fn from(thread_suspend_response: ThreadSuspendResponse) -> Self {
+        ThreadResponse::Suspend { thread_suspend_response }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::thread::thread_response::ThreadResponse;
+use crate::structures::processes::thread_info::ThreadInfo;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ThreadSuspendResponse {
+    /// The threads of the process after the suspend request was applied.
+    pub threads: Vec<ThreadInfo>,
+    pub error: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for ThreadSuspendResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Thread(ThreadResponse::Suspend {
+            thread_suspend_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Thread(ThreadResponse::Suspend { thread_suspend_response }) = response {
+            Ok(thread_suspend_response)
+        } else {
+            Err(response)
+        }
+    }
+}
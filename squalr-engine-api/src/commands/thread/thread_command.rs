@@ -0,0 +1,13 @@
+use crate::commands::thread::context::thread_context_request::ThreadContextRequest;
+use crate::commands::thread::list::thread_list_request::ThreadListRequest;
+use crate::commands::thread::resume::thread_resume_request::ThreadResumeRequest;
+use crate::commands::thread::suspend::thread_suspend_request::ThreadSuspendRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ThreadCommand {
+    Context { thread_context_request: ThreadContextRequest },
+    List { thread_list_request: ThreadListRequest },
+    Resume { thread_resume_request: ThreadResumeRequest },
+    Suspend { thread_suspend_request: ThreadSuspendRequest },
+}
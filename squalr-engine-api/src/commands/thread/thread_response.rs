@@ -0,0 +1,13 @@
+use crate::commands::thread::context::thread_context_response::ThreadContextResponse;
+use crate::commands::thread::list::thread_list_response::ThreadListResponse;
+use crate::commands::thread::resume::thread_resume_response::ThreadResumeResponse;
+use crate::commands::thread::suspend::thread_suspend_response::ThreadSuspendResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ThreadResponse {
+    Context { thread_context_response: ThreadContextResponse },
+    List { thread_list_response: ThreadListResponse },
+    Resume { thread_resume_response: ThreadResumeResponse },
+    Suspend { thread_suspend_response: ThreadSuspendResponse },
+}
@@ -8,7 +8,7 @@ use crate::{
     structures::{
         data_types::data_type_ref::DataTypeRef,
         data_values::{anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat, data_value::DataValue},
-        projects::project_context::ProjectContext,
+        projects::{project_context::ProjectContext, project_items::project_item::ProjectItem},
         structs::symbolic_struct_definition::SymbolicStructDefinition,
     },
 };
@@ -111,4 +111,13 @@ pub trait EngineExecutionContext: Send + Sync {
         &self,
         symbolic_struct_ref_id: &str,
     ) -> Option<SymbolicStructDefinition>;
+
+    /// Creates a new project item of a type contributed by an enabled plugin.
+    fn create_plugin_project_item(
+        &self,
+        _project_item_type_id: &str,
+        _project_item_name: &str,
+    ) -> Option<ProjectItem> {
+        None
+    }
 }
@@ -0,0 +1,22 @@
+use crate::events::{
+    debugger::debugger_event::DebuggerEvent,
+    engine_event::{EngineEvent, EngineEventRequest},
+};
+use crate::structures::debugging::{breakpoint::Breakpoint, breakpoint_hit::BreakpointHit};
+use serde::{Deserialize, Serialize};
+
+/// Emitted when a breakpoint is hit, disarmed, or removed. Carries only the hits captured since the previous event.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BreakpointUpdatedEvent {
+    pub breakpoint: Breakpoint,
+    pub breakpoint_hits: Vec<BreakpointHit>,
+    pub is_removed: bool,
+}
+
+impl EngineEventRequest for BreakpointUpdatedEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::Debugger(Box::new(DebuggerEvent::BreakpointUpdated {
+            breakpoint_updated_event: self.clone(),
+        }))
+    }
+}
@@ -0,0 +1 @@
+pub mod breakpoint_updated_event;
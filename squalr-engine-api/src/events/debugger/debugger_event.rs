@@ -0,0 +1,7 @@
+use crate::events::debugger::breakpoint_updated::breakpoint_updated_event::BreakpointUpdatedEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum DebuggerEvent {
+    BreakpointUpdated { breakpoint_updated_event: BreakpointUpdatedEvent },
+}
@@ -0,0 +1,2 @@
+pub mod breakpoint_updated;
+pub mod debugger_event;
@@ -10,11 +10,57 @@ pub trait InstructionSet: Debug + Send + Sync {
         assembly_source: &str,
     ) -> Result<Vec<u8>, String>;
 
+    /// Assembles instructions as if they were placed at `instruction_address`, which matters for encodings relative to the
+    /// instruction pointer. Instruction sets without such encodings can rely on the default.
+    fn assemble_at(
+        &self,
+        assembly_source: &str,
+        _instruction_address: u64,
+    ) -> Result<Vec<u8>, String> {
+        self.assemble(assembly_source)
+    }
+
     fn disassemble(
         &self,
         instruction_bytes: &[u8],
     ) -> Result<String, String>;
 
+    /// Gets the length in bytes of each whole instruction at the start of `instruction_bytes`, in order.
+    fn measure_instructions(
+        &self,
+        instruction_bytes: &[u8],
+    ) -> Result<Vec<usize>, String> {
+        if instruction_bytes.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Err(format!("{} does not expose instruction boundaries.", self.get_display_name()))
+    }
+
+    /// Re-encodes instructions that were decoded at `source_address` so that they behave the same at `destination_address`,
+    /// adjusting relative branches and instruction pointer relative memory operands.
+    fn relocate(
+        &self,
+        instruction_bytes: &[u8],
+        source_address: u64,
+        destination_address: u64,
+    ) -> Result<Vec<u8>, String> {
+        if source_address == destination_address {
+            return Ok(instruction_bytes.to_vec());
+        }
+
+        Err(format!("{} does not support relocating instructions.", self.get_display_name()))
+    }
+
+    /// Builds an unconditional jump placed at `source_address` that transfers control to `destination_address`.
+    fn build_jump(
+        &self,
+        _source_address: u64,
+        _destination_address: u64,
+    ) -> Result<Vec<u8>, String> {
+        Err(format!("{} does not expose a jump encoding.", self.get_display_name()))
+    }
+
     fn build_no_operation_fill(
         &self,
         byte_count: usize,
@@ -1,6 +1,9 @@
 use crate::{
     plugins::memory_view::{MemoryViewPluginError, PageRetrievalMode},
-    structures::memory::{normalized_module::NormalizedModule, normalized_region::NormalizedRegion},
+    structures::{
+        memory::{normalized_module::NormalizedModule, normalized_region::NormalizedRegion},
+        pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize,
+    },
 };
 
 pub trait MemoryViewInstance: Send + Sync {
@@ -64,6 +67,12 @@ pub trait MemoryViewInstance: Send + Sync {
         Ok(None)
     }
 
+    /// Gets the pointer width and byte order of the emulated system, if known. Game-memory pointer scans use this size, and
+    /// element scans prefer big-endian data types when it is big-endian.
+    fn get_native_pointer_size(&self) -> Option<PointerScanPointerSize> {
+        None
+    }
+
     fn read_bytes(
         &self,
         address: u64,
@@ -9,7 +9,9 @@ mod plugin_package;
 mod plugin_permission;
 mod plugin_state;
 mod plugin_trait;
+pub mod project_item_type;
 pub mod symbol_tree;
+pub mod value_transform;
 
 pub use plugin_activation_state::PluginActivationState;
 pub use plugin_capability::PluginCapability;
@@ -5,7 +5,9 @@ pub enum PluginCapability {
     DataType,
     InstructionSet,
     MemoryView,
+    ProjectItemType,
     SymbolTree,
+    ValueTransform,
 }
 
 impl PluginCapability {
@@ -14,7 +16,9 @@ impl PluginCapability {
             Self::DataType => "data-type",
             Self::InstructionSet => "instruction-set",
             Self::MemoryView => "memory-view",
+            Self::ProjectItemType => "project-item-type",
             Self::SymbolTree => "symbol-tree",
+            Self::ValueTransform => "value-transform",
         }
     }
 
@@ -23,7 +27,9 @@ impl PluginCapability {
             Self::DataType => "Data type",
             Self::InstructionSet => "Instruction set",
             Self::MemoryView => "Memory view",
+            Self::ProjectItemType => "Project item type",
             Self::SymbolTree => "Symbol Tree",
+            Self::ValueTransform => "Value transform",
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::plugins::{
-    Plugin, data_type::DataTypePlugin, instruction_set::InstructionSetPlugin, memory_view::MemoryViewPlugin, symbol_tree::symbol_tree_plugin::SymbolTreePlugin,
+    Plugin, data_type::DataTypePlugin, instruction_set::InstructionSetPlugin, memory_view::MemoryViewPlugin, project_item_type::ProjectItemTypePlugin,
+    symbol_tree::symbol_tree_plugin::SymbolTreePlugin, value_transform::ValueTransformPlugin,
 };
 
 pub trait PluginPackage: Plugin {
@@ -15,7 +16,15 @@ pub trait PluginPackage: Plugin {
         None
     }
 
+    fn as_project_item_type_plugin(&self) -> Option<&dyn ProjectItemTypePlugin> {
+        None
+    }
+
     fn as_symbol_tree_plugin(&self) -> Option<&dyn SymbolTreePlugin> {
         None
     }
+
+    fn as_value_transform_plugin(&self) -> Option<&dyn ValueTransformPlugin> {
+        None
+    }
 }
@@ -0,0 +1,15 @@
+use crate::{plugins::Plugin, structures::projects::project_items::project_item_type::ProjectItemType};
+use std::sync::Arc;
+
+pub trait ProjectItemTypePlugin: Plugin {
+    fn contributed_project_item_types(&self) -> &[Arc<dyn ProjectItemType>];
+
+    fn contributes_project_item_type(
+        &self,
+        project_item_type_id: &str,
+    ) -> bool {
+        self.contributed_project_item_types()
+            .iter()
+            .any(|project_item_type| project_item_type.get_project_item_type_id() == project_item_type_id)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::plugins::PluginPermission;
 use crate::structures::data_types::data_type_ref::DataTypeRef;
 use crate::structures::projects::project_symbol_catalog::ProjectSymbolCatalog;
+use std::path::PathBuf;
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub enum SymbolTreeActionSelection {
@@ -48,6 +49,14 @@ pub trait ProcessMemoryStore: Send + Sync {
         offset: u64,
         length: u64,
     ) -> Result<Vec<u8>, String>;
+
+    /// Resolves the on-disk image backing a module, for actions that need data the loader does not map (such as debug info).
+    fn resolve_module_file_path(
+        &self,
+        _module_name: &str,
+    ) -> Result<Option<PathBuf>, String> {
+        Ok(None)
+    }
 }
 
 pub trait SymbolTreeWindowStore: Send + Sync {
@@ -0,0 +1,5 @@
+pub mod value_transform_add_constant;
+pub mod value_transform_byte_swap;
+pub mod value_transform_subtract_constant;
+pub mod value_transform_xor_constant;
+pub mod value_transform_xor_neighbor;
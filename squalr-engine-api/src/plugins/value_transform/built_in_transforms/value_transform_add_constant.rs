@@ -0,0 +1,67 @@
+use crate::plugins::value_transform::ValueTransform;
+
+/// Values stored with a constant salt added to them, as little-endian integers that wrap on overflow.
+pub struct ValueTransformAddConstant {}
+
+impl ValueTransformAddConstant {
+    pub const VALUE_TRANSFORM_ID: &str = "add_constant";
+
+    /// Adds a signed amount to a little-endian integer of 1, 2, 4, or 8 bytes, wrapping on overflow.
+    pub(crate) fn wrapping_add(
+        value_bytes: &mut [u8],
+        amount: i64,
+    ) -> Result<(), String> {
+        match value_bytes.len() {
+            1 => value_bytes[0] = value_bytes[0].wrapping_add(amount as u8),
+            2 => {
+                let value = u16::from_le_bytes([value_bytes[0], value_bytes[1]]);
+                value_bytes.copy_from_slice(&value.wrapping_add(amount as u16).to_le_bytes());
+            }
+            4 => {
+                let value = u32::from_le_bytes([value_bytes[0], value_bytes[1], value_bytes[2], value_bytes[3]]);
+                value_bytes.copy_from_slice(&value.wrapping_add(amount as u32).to_le_bytes());
+            }
+            8 => {
+                let mut value_array = [0u8; 8];
+                value_array.copy_from_slice(value_bytes);
+                value_bytes.copy_from_slice(
+                    &u64::from_le_bytes(value_array)
+                        .wrapping_add(amount as u64)
+                        .to_le_bytes(),
+                );
+            }
+            value_size => {
+                return Err(format!(
+                    "Constant offsets only apply to 1, 2, 4, or 8 byte integers, not {} byte(s).",
+                    value_size
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ValueTransform for ValueTransformAddConstant {
+    fn get_value_transform_id(&self) -> &str {
+        Self::VALUE_TRANSFORM_ID
+    }
+
+    fn decode(
+        &self,
+        value_bytes: &mut [u8],
+        _neighbor_bytes: Option<&[u8]>,
+        parameter: i64,
+    ) -> Result<(), String> {
+        Self::wrapping_add(value_bytes, parameter.wrapping_neg())
+    }
+
+    fn encode(
+        &self,
+        value_bytes: &mut [u8],
+        _neighbor_bytes: Option<&[u8]>,
+        parameter: i64,
+    ) -> Result<(), String> {
+        Self::wrapping_add(value_bytes, parameter)
+    }
+}
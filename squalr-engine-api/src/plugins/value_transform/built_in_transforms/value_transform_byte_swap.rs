@@ -0,0 +1,36 @@
+use crate::plugins::value_transform::ValueTransform;
+
+/// Values stored with their bytes in reverse order.
+pub struct ValueTransformByteSwap {}
+
+impl ValueTransformByteSwap {
+    pub const VALUE_TRANSFORM_ID: &str = "byte_swap";
+}
+
+impl ValueTransform for ValueTransformByteSwap {
+    fn get_value_transform_id(&self) -> &str {
+        Self::VALUE_TRANSFORM_ID
+    }
+
+    fn decode(
+        &self,
+        value_bytes: &mut [u8],
+        _neighbor_bytes: Option<&[u8]>,
+        _parameter: i64,
+    ) -> Result<(), String> {
+        value_bytes.reverse();
+
+        Ok(())
+    }
+
+    fn encode(
+        &self,
+        value_bytes: &mut [u8],
+        _neighbor_bytes: Option<&[u8]>,
+        _parameter: i64,
+    ) -> Result<(), String> {
+        value_bytes.reverse();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use crate::plugins::value_transform::{ValueTransform, built_in_transforms::value_transform_add_constant::ValueTransformAddConstant};
+
+/// Values stored with a constant salt subtracted from them, as little-endian integers that wrap on overflow.
+pub struct ValueTransformSubtractConstant {}
+
+impl ValueTransformSubtractConstant {
+    pub const VALUE_TRANSFORM_ID: &str = "subtract_constant";
+}
+
+impl ValueTransform for ValueTransformSubtractConstant {
+    fn get_value_transform_id(&self) -> &str {
+        Self::VALUE_TRANSFORM_ID
+    }
+
+    fn decode(
+        &self,
+        value_bytes: &mut [u8],
+        _neighbor_bytes: Option<&[u8]>,
+        parameter: i64,
+    ) -> Result<(), String> {
+        ValueTransformAddConstant::wrapping_add(value_bytes, parameter)
+    }
+
+    fn encode(
+        &self,
+        value_bytes: &mut [u8],
+        _neighbor_bytes: Option<&[u8]>,
+        parameter: i64,
+    ) -> Result<(), String> {
+        ValueTransformAddConstant::wrapping_add(value_bytes, parameter.wrapping_neg())
+    }
+}
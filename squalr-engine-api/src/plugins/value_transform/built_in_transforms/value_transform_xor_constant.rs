@@ -0,0 +1,47 @@
+use crate::plugins::value_transform::ValueTransform;
+
+/// Values stored XOR-ed with a constant key. The key is applied little-endian, repeating every 8 bytes for wider values.
+pub struct ValueTransformXorConstant {}
+
+impl ValueTransformXorConstant {
+    pub const VALUE_TRANSFORM_ID: &str = "xor_constant";
+
+    fn apply_key(
+        value_bytes: &mut [u8],
+        key: i64,
+    ) {
+        let key_bytes = key.to_le_bytes();
+
+        for (byte_index, value_byte) in value_bytes.iter_mut().enumerate() {
+            *value_byte ^= key_bytes[byte_index % key_bytes.len()];
+        }
+    }
+}
+
+impl ValueTransform for ValueTransformXorConstant {
+    fn get_value_transform_id(&self) -> &str {
+        Self::VALUE_TRANSFORM_ID
+    }
+
+    fn decode(
+        &self,
+        value_bytes: &mut [u8],
+        _neighbor_bytes: Option<&[u8]>,
+        parameter: i64,
+    ) -> Result<(), String> {
+        Self::apply_key(value_bytes, parameter);
+
+        Ok(())
+    }
+
+    fn encode(
+        &self,
+        value_bytes: &mut [u8],
+        _neighbor_bytes: Option<&[u8]>,
+        parameter: i64,
+    ) -> Result<(), String> {
+        Self::apply_key(value_bytes, parameter);
+
+        Ok(())
+    }
+}
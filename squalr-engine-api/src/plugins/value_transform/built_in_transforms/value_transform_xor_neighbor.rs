@@ -0,0 +1,63 @@
+use crate::plugins::value_transform::ValueTransform;
+
+/// Values stored XOR-ed with another field of the same size, such as a per-value key kept next to the value. The parameter
+/// is the byte offset from the value to that field.
+pub struct ValueTransformXorNeighbor {}
+
+impl ValueTransformXorNeighbor {
+    pub const VALUE_TRANSFORM_ID: &str = "xor_neighbor";
+
+    fn apply_neighbor(
+        value_bytes: &mut [u8],
+        neighbor_bytes: Option<&[u8]>,
+    ) -> Result<(), String> {
+        let Some(neighbor_bytes) = neighbor_bytes else {
+            return Err(String::from("XOR with a neighbor field requires the neighbor field's bytes."));
+        };
+
+        if neighbor_bytes.len() != value_bytes.len() {
+            return Err(format!(
+                "Neighbor field is {} byte(s), but the value is {} byte(s).",
+                neighbor_bytes.len(),
+                value_bytes.len()
+            ));
+        }
+
+        for (value_byte, neighbor_byte) in value_bytes.iter_mut().zip(neighbor_bytes) {
+            *value_byte ^= neighbor_byte;
+        }
+
+        Ok(())
+    }
+}
+
+impl ValueTransform for ValueTransformXorNeighbor {
+    fn get_value_transform_id(&self) -> &str {
+        Self::VALUE_TRANSFORM_ID
+    }
+
+    fn get_neighbor_offset(
+        &self,
+        parameter: i64,
+    ) -> Option<i64> {
+        Some(parameter)
+    }
+
+    fn decode(
+        &self,
+        value_bytes: &mut [u8],
+        neighbor_bytes: Option<&[u8]>,
+        _parameter: i64,
+    ) -> Result<(), String> {
+        Self::apply_neighbor(value_bytes, neighbor_bytes)
+    }
+
+    fn encode(
+        &self,
+        value_bytes: &mut [u8],
+        neighbor_bytes: Option<&[u8]>,
+        _parameter: i64,
+    ) -> Result<(), String> {
+        Self::apply_neighbor(value_bytes, neighbor_bytes)
+    }
+}
@@ -0,0 +1,10 @@
+pub mod built_in_transforms;
+mod resolved_value_transform;
+mod value_transform_kind;
+mod value_transform_plugin;
+mod value_transform_ref;
+
+pub use resolved_value_transform::ResolvedValueTransform;
+pub use value_transform_kind::ValueTransform;
+pub use value_transform_plugin::ValueTransformPlugin;
+pub use value_transform_ref::ValueTransformRef;
@@ -0,0 +1,153 @@
+use crate::plugins::value_transform::ValueTransform;
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use crate::structures::snapshots::snapshot_region::SnapshotRegion;
+use std::sync::Arc;
+
+/// A value transform paired with the parameter it was selected with.
+#[derive(Clone)]
+pub struct ResolvedValueTransform {
+    value_transform: Arc<dyn ValueTransform>,
+    parameter: i64,
+}
+
+impl ResolvedValueTransform {
+    pub fn new(
+        value_transform: Arc<dyn ValueTransform>,
+        parameter: i64,
+    ) -> Self {
+        Self { value_transform, parameter }
+    }
+
+    pub fn get_value_transform_id(&self) -> &str {
+        self.value_transform.get_value_transform_id()
+    }
+
+    pub fn get_parameter(&self) -> i64 {
+        self.parameter
+    }
+
+    pub fn get_neighbor_offset(&self) -> Option<i64> {
+        self.value_transform.get_neighbor_offset(self.parameter)
+    }
+
+    pub fn decode(
+        &self,
+        value_bytes: &mut [u8],
+        neighbor_bytes: Option<&[u8]>,
+    ) -> Result<(), String> {
+        self.value_transform
+            .decode(value_bytes, neighbor_bytes, self.parameter)
+    }
+
+    pub fn encode(
+        &self,
+        value_bytes: &mut [u8],
+        neighbor_bytes: Option<&[u8]>,
+    ) -> Result<(), String> {
+        self.value_transform
+            .encode(value_bytes, neighbor_bytes, self.parameter)
+    }
+
+    /// Decodes a buffer of raw values read from `base_address`, treating it as naturally aligned elements of the given size.
+    /// Bytes outside of a whole aligned element, and elements whose neighbor field falls outside of the buffer or that fail
+    /// to decode, are kept as they were read. These are returned as undecoded ranges, sorted by address, so that results
+    /// overlapping them can be discarded rather than compared as if they were decoded. Each element is decoded on its own, so
+    /// only values starting on an element boundary read decoded bytes that belong together.
+    pub fn decode_values(
+        &self,
+        base_address: u64,
+        raw_values: &[u8],
+        element_size: usize,
+    ) -> (Vec<u8>, Vec<NormalizedRegion>) {
+        let mut decoded_values = raw_values.to_vec();
+        let mut undecoded_regions: Vec<NormalizedRegion> = Vec::new();
+        let mut push_undecoded_range = |offset: usize, size: usize| {
+            let address = base_address + offset as u64;
+
+            match undecoded_regions.last_mut() {
+                _ if size == 0 => {}
+                Some(undecoded_region) if undecoded_region.get_end_address() == address => undecoded_region.set_end_address(address + size as u64),
+                _ => undecoded_regions.push(NormalizedRegion::new(address, size as u64)),
+            }
+        };
+
+        if element_size == 0 {
+            push_undecoded_range(0, raw_values.len());
+
+            return (decoded_values, undecoded_regions);
+        }
+
+        let neighbor_offset = self.get_neighbor_offset();
+        let first_element_offset = ((element_size - (base_address % element_size as u64) as usize) % element_size).min(raw_values.len());
+        let mut element_offset = first_element_offset;
+
+        push_undecoded_range(0, first_element_offset);
+
+        while element_offset + element_size <= raw_values.len() {
+            let neighbor_bytes = match neighbor_offset {
+                Some(neighbor_offset) => {
+                    let neighbor_start = element_offset as i64 + neighbor_offset;
+
+                    if neighbor_start < 0 || neighbor_start as usize + element_size > raw_values.len() {
+                        push_undecoded_range(element_offset, element_size);
+                        element_offset += element_size;
+                        continue;
+                    }
+
+                    Some(&raw_values[neighbor_start as usize..neighbor_start as usize + element_size])
+                }
+                None => None,
+            };
+            let element_bytes = &mut decoded_values[element_offset..element_offset + element_size];
+
+            if self.decode(element_bytes, neighbor_bytes).is_err() {
+                element_bytes.copy_from_slice(&raw_values[element_offset..element_offset + element_size]);
+                push_undecoded_range(element_offset, element_size);
+            }
+
+            element_offset += element_size;
+        }
+
+        push_undecoded_range(element_offset, raw_values.len() - element_offset);
+
+        (decoded_values, undecoded_regions)
+    }
+
+    /// Creates a standalone copy of a snapshot region with its current and previous values decoded, so that scanners compare
+    /// decoded values without the snapshot ever holding anything but raw memory. Also returns the ranges that could not be
+    /// decoded in either the current or previous values, sorted by address.
+    pub fn decode_snapshot_region(
+        &self,
+        snapshot_region: &SnapshotRegion,
+        element_size: usize,
+    ) -> (SnapshotRegion, Vec<NormalizedRegion>) {
+        let base_address = snapshot_region.get_base_address();
+        let (current_values, current_undecoded_regions) = self.decode_values(base_address, snapshot_region.get_current_values(), element_size);
+        let (previous_values, previous_undecoded_regions) = self.decode_values(base_address, snapshot_region.get_previous_values(), element_size);
+        let mut unmerged_undecoded_regions: Vec<NormalizedRegion> = current_undecoded_regions
+            .into_iter()
+            .chain(previous_undecoded_regions)
+            .collect();
+        let mut undecoded_regions: Vec<NormalizedRegion> = Vec::new();
+
+        unmerged_undecoded_regions.sort_by_key(|undecoded_region| undecoded_region.get_base_address());
+
+        for undecoded_region in unmerged_undecoded_regions {
+            match undecoded_regions.last_mut() {
+                Some(merged_region) if merged_region.get_end_address() >= undecoded_region.get_base_address() => {
+                    merged_region.set_end_address(
+                        merged_region
+                            .get_end_address()
+                            .max(undecoded_region.get_end_address()),
+                    );
+                }
+                _ => undecoded_regions.push(undecoded_region),
+            }
+        }
+
+        (
+            SnapshotRegion::from_current_and_previous_bytes(base_address, current_values, previous_values),
+            undecoded_regions,
+        )
+    }
+}
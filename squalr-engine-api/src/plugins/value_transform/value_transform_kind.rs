@@ -0,0 +1,33 @@
+/// Converts between the bytes a game stores in memory and the value they represent. Transforms sit between raw memory and
+/// data type comparisons, so scans compare decoded values and writes encode the new value before it reaches memory.
+///
+/// A transform operates on one element at a time. Transforms that mix in another field (such as a per-value key stored
+/// next to the value) declare where that field lives with `get_neighbor_offset`, and receive its raw bytes when called.
+pub trait ValueTransform: Send + Sync {
+    fn get_value_transform_id(&self) -> &str;
+
+    /// Gets the byte offset, relative to the value, of the neighbor field this transform reads. The neighbor field has the
+    /// same size as the value.
+    fn get_neighbor_offset(
+        &self,
+        _parameter: i64,
+    ) -> Option<i64> {
+        None
+    }
+
+    /// Converts stored bytes into the value they represent, in place.
+    fn decode(
+        &self,
+        value_bytes: &mut [u8],
+        neighbor_bytes: Option<&[u8]>,
+        parameter: i64,
+    ) -> Result<(), String>;
+
+    /// Converts a value into the bytes that should be stored in memory, in place.
+    fn encode(
+        &self,
+        value_bytes: &mut [u8],
+        neighbor_bytes: Option<&[u8]>,
+        parameter: i64,
+    ) -> Result<(), String>;
+}
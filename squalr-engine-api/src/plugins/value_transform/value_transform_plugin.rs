@@ -0,0 +1,15 @@
+use crate::plugins::{Plugin, value_transform::ValueTransform};
+use std::sync::Arc;
+
+pub trait ValueTransformPlugin: Plugin {
+    fn contributed_value_transforms(&self) -> &[Arc<dyn ValueTransform>];
+
+    fn contributes_value_transform(
+        &self,
+        value_transform_id: &str,
+    ) -> bool {
+        self.contributed_value_transforms()
+            .iter()
+            .any(|value_transform| value_transform.get_value_transform_id() == value_transform_id)
+    }
+}
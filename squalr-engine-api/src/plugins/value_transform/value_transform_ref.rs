@@ -0,0 +1,89 @@
+use crate::conversions::conversions_from_primitives::Conversions;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Selects a value transform by id, along with the parameter it is applied with (a key, salt, or neighbor offset).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ValueTransformRef {
+    value_transform_id: String,
+    #[serde(default)]
+    parameter: i64,
+}
+
+impl ValueTransformRef {
+    pub fn new(
+        value_transform_id: &str,
+        parameter: i64,
+    ) -> Self {
+        Self {
+            value_transform_id: value_transform_id.to_string(),
+            parameter,
+        }
+    }
+
+    pub fn get_value_transform_id(&self) -> &str {
+        &self.value_transform_id
+    }
+
+    pub fn get_parameter(&self) -> i64 {
+        self.parameter
+    }
+}
+
+impl FromStr for ValueTransformRef {
+    type Err = String;
+
+    /// Parses `id` or `id:parameter`, where the parameter is a decimal or `0x` prefixed hex integer and may be negative.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (value_transform_id, parameter_string) = match string.split_once(':') {
+            Some((value_transform_id, parameter_string)) => (value_transform_id.trim(), Some(parameter_string.trim())),
+            None => (string.trim(), None),
+        };
+
+        if value_transform_id.is_empty() {
+            return Err(String::from("Value transform id cannot be empty."));
+        }
+
+        let parameter = match parameter_string {
+            Some(parameter_string) => {
+                let (is_negative, magnitude_string) = match parameter_string.strip_prefix('-') {
+                    Some(magnitude_string) => (true, magnitude_string),
+                    None => (false, parameter_string),
+                };
+                let magnitude = Conversions::parse_hex_or_int(magnitude_string)
+                    .map_err(|error| format!("Invalid value transform parameter '{}': {}", parameter_string, error))?;
+
+                if is_negative { (magnitude as i64).wrapping_neg() } else { magnitude as i64 }
+            }
+            None => 0,
+        };
+
+        Ok(Self::new(value_transform_id, parameter))
+    }
+}
+
+impl fmt::Display for ValueTransformRef {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(formatter, "{}:{}", self.value_transform_id, self.parameter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueTransformRef;
+
+    #[test]
+    fn parses_ids_with_hex_decimal_and_negative_parameters() {
+        assert_eq!("byte_swap".parse::<ValueTransformRef>(), Ok(ValueTransformRef::new("byte_swap", 0)));
+        assert_eq!(
+            "xor_constant:0xBEEF".parse::<ValueTransformRef>(),
+            Ok(ValueTransformRef::new("xor_constant", 0xBEEF))
+        );
+        assert_eq!("xor_neighbor:-4".parse::<ValueTransformRef>(), Ok(ValueTransformRef::new("xor_neighbor", -4)));
+        assert!(":12".parse::<ValueTransformRef>().is_err());
+        assert!("add_constant:salt".parse::<ValueTransformRef>().is_err());
+    }
+}
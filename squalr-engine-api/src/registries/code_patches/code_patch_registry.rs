@@ -0,0 +1,164 @@
+use crate::structures::patching::code_patch::CodePatch;
+use std::collections::BTreeMap;
+
+/// Contains every code patch currently written to the opened process, keyed by patch address.
+/// Patches never overlap, so each one can be undone by restoring its original bytes without disturbing another.
+pub struct CodePatchRegistry {
+    applied_patches: BTreeMap<u64, CodePatch>,
+    /// The process that the applied patches were written to.
+    process_id: Option<u32>,
+}
+
+impl CodePatchRegistry {
+    pub fn new() -> Self {
+        Self {
+            applied_patches: BTreeMap::new(),
+            process_id: None,
+        }
+    }
+
+    /// Gets all applied patches, ordered by patch address.
+    pub fn get_applied_patches(&self) -> impl Iterator<Item = &CodePatch> {
+        self.applied_patches.values()
+    }
+
+    pub fn get_patch(
+        &self,
+        patch_address: u64,
+    ) -> Option<&CodePatch> {
+        self.applied_patches.get(&patch_address)
+    }
+
+    /// Finds an applied patch that rewrites any byte in the given range.
+    pub fn find_overlapping_patch(
+        &self,
+        address: u64,
+        size: u64,
+    ) -> Option<&CodePatch> {
+        self.applied_patches
+            .values()
+            .find(|code_patch| code_patch.overlaps(address, size))
+    }
+
+    /// Records a patch applied to the given process. Patches recorded for another process are dropped first, and the patch
+    /// is rejected if it overlaps a patch that is already recorded.
+    pub fn register_patch(
+        &mut self,
+        process_id: u32,
+        code_patch: CodePatch,
+    ) -> Result<(), String> {
+        self.set_process_id(Some(process_id));
+
+        if let Some(overlapping_patch) = self.find_overlapping_patch(code_patch.get_patch_address(), code_patch.get_patch_size()) {
+            return Err(format!(
+                "Patch at 0x{:X} overlaps the existing patch at 0x{:X}.",
+                code_patch.get_patch_address(),
+                overlapping_patch.get_patch_address()
+            ));
+        }
+
+        self.applied_patches
+            .insert(code_patch.get_patch_address(), code_patch);
+
+        Ok(())
+    }
+
+    pub fn remove_patch(
+        &mut self,
+        patch_address: u64,
+    ) -> Option<CodePatch> {
+        self.applied_patches.remove(&patch_address)
+    }
+
+    /// Drops the applied patches unless they belong to the given process. Process changes are observed asynchronously, so
+    /// patches may already have been applied to the newly opened process by the time its change is handled.
+    pub fn set_process_id(
+        &mut self,
+        process_id: Option<u32>,
+    ) {
+        if self.process_id != process_id {
+            self.applied_patches.clear();
+            self.process_id = process_id;
+        }
+    }
+}
+
+impl Default for CodePatchRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodePatchRegistry;
+    use crate::structures::patching::{code_patch::CodePatch, code_patch_definition::CodePatchDefinition, code_patch_kind::CodePatchKind};
+
+    fn create_nop_patch(
+        patch_address: u64,
+        byte_count: usize,
+    ) -> CodePatch {
+        let definition = CodePatchDefinition::new(patch_address, String::new(), CodePatchKind::Nop { byte_count: byte_count as u64 });
+
+        CodePatch::new(definition, patch_address, vec![0xCC; byte_count], vec![0x90; byte_count], None)
+    }
+
+    #[test]
+    fn register_patch_rejects_overlapping_patches() {
+        let mut code_patch_registry = CodePatchRegistry::new();
+
+        assert!(
+            code_patch_registry
+                .register_patch(42, create_nop_patch(0x1000, 5))
+                .is_ok()
+        );
+        assert!(
+            code_patch_registry
+                .register_patch(42, create_nop_patch(0x1004, 2))
+                .is_err()
+        );
+        assert!(
+            code_patch_registry
+                .register_patch(42, create_nop_patch(0x0FFC, 5))
+                .is_err()
+        );
+        assert!(
+            code_patch_registry
+                .register_patch(42, create_nop_patch(0x1005, 2))
+                .is_ok()
+        );
+        assert_eq!(code_patch_registry.get_applied_patches().count(), 2);
+    }
+
+    #[test]
+    fn remove_patch_returns_original_bytes_and_frees_the_range() {
+        let mut code_patch_registry = CodePatchRegistry::new();
+
+        code_patch_registry
+            .register_patch(42, create_nop_patch(0x2000, 3))
+            .expect("Expected patch to register.");
+
+        let removed_patch = code_patch_registry
+            .remove_patch(0x2000)
+            .expect("Expected patch to be removed.");
+
+        assert_eq!(removed_patch.get_original_bytes(), &[0xCC, 0xCC, 0xCC]);
+        assert!(code_patch_registry.find_overlapping_patch(0x2000, 3).is_none());
+    }
+
+    #[test]
+    fn set_process_id_only_drops_patches_of_other_processes() {
+        let mut code_patch_registry = CodePatchRegistry::new();
+
+        code_patch_registry
+            .register_patch(42, create_nop_patch(0x3000, 2))
+            .expect("Expected patch to register.");
+        code_patch_registry.set_process_id(Some(42));
+
+        assert!(code_patch_registry.get_patch(0x3000).is_some());
+
+        code_patch_registry.set_process_id(Some(43));
+
+        assert_eq!(code_patch_registry.get_applied_patches().count(), 0);
+    }
+}
@@ -1,5 +1,7 @@
+pub mod code_patches;
 pub mod freeze_list;
 pub mod project_item_types;
 pub mod registry_context;
 pub mod scan_rules;
 pub mod symbols;
+pub mod value_transforms;
@@ -1,7 +1,7 @@
 use crate::structures::projects::project_items::{
     built_in_types::{
         project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory,
-        project_item_type_script::ProjectItemTypeScript,
+        project_item_type_patch::ProjectItemTypePatch, project_item_type_script::ProjectItemTypeScript,
     },
     project_item_type::ProjectItemType,
 };
@@ -55,6 +55,7 @@ impl ProjectItemTypeRegistry {
             Arc::new(ProjectItemTypeDirectory {}),
             Arc::new(ProjectItemTypeAddress {}),
             Arc::new(ProjectItemTypeScript {}),
+            Arc::new(ProjectItemTypePatch {}),
         ];
 
         for built_in_project_item_type in built_in_project_item_types.into_iter() {
@@ -75,17 +76,18 @@ mod tests {
     use super::ProjectItemTypeRegistry;
     use crate::structures::projects::project_items::built_in_types::{
         project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory,
-        project_item_type_script::ProjectItemTypeScript,
+        project_item_type_patch::ProjectItemTypePatch, project_item_type_script::ProjectItemTypeScript,
     };
 
     #[test]
-    fn built_in_project_item_types_are_directory_address_and_script() {
+    fn built_in_project_item_types_are_directory_address_script_and_patch() {
         let project_item_type_registry = ProjectItemTypeRegistry::new();
         let registry = project_item_type_registry.get_registry();
 
         assert!(registry.contains_key(ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID));
         assert!(registry.contains_key(ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID));
         assert!(registry.contains_key(ProjectItemTypeScript::PROJECT_ITEM_TYPE_ID));
+        assert!(registry.contains_key(ProjectItemTypePatch::PROJECT_ITEM_TYPE_ID));
         assert!(!registry.contains_key("item"));
         assert!(!registry.contains_key("plugin"));
     }
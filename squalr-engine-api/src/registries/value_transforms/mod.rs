@@ -0,0 +1 @@
+pub mod value_transform_registry;
@@ -0,0 +1,170 @@
+use crate::plugins::value_transform::{
+    ResolvedValueTransform, ValueTransform, ValueTransformRef,
+    built_in_transforms::{
+        value_transform_add_constant::ValueTransformAddConstant, value_transform_byte_swap::ValueTransformByteSwap,
+        value_transform_subtract_constant::ValueTransformSubtractConstant, value_transform_xor_constant::ValueTransformXorConstant,
+        value_transform_xor_neighbor::ValueTransformXorNeighbor,
+    },
+};
+use std::{collections::HashMap, sync::Arc};
+
+pub struct ValueTransformRegistry {
+    registry: HashMap<String, Arc<dyn ValueTransform>>,
+}
+
+impl ValueTransformRegistry {
+    pub fn new() -> Self {
+        Self {
+            registry: Self::create_built_in_transforms(),
+        }
+    }
+
+    pub fn get(
+        &self,
+        value_transform_id: &str,
+    ) -> Option<Arc<dyn ValueTransform>> {
+        self.registry.get(value_transform_id).cloned()
+    }
+
+    pub fn get_registry(&self) -> &HashMap<String, Arc<dyn ValueTransform>> {
+        &self.registry
+    }
+
+    pub fn register(
+        &mut self,
+        value_transform: Arc<dyn ValueTransform>,
+    ) -> Result<(), String> {
+        let value_transform_id = value_transform.get_value_transform_id().trim().to_string();
+
+        if value_transform_id.is_empty() {
+            return Err("Value transform id cannot be empty.".to_string());
+        }
+
+        if self.registry.contains_key(&value_transform_id) {
+            return Err(format!("Value transform is already registered: {}", value_transform_id));
+        }
+
+        self.registry.insert(value_transform_id, value_transform);
+
+        Ok(())
+    }
+
+    /// Looks up the transform selected by a request and binds it to the requested parameter.
+    pub fn resolve(
+        &self,
+        value_transform_ref: &ValueTransformRef,
+    ) -> Result<ResolvedValueTransform, String> {
+        self.get(value_transform_ref.get_value_transform_id())
+            .map(|value_transform| ResolvedValueTransform::new(value_transform, value_transform_ref.get_parameter()))
+            .ok_or_else(|| format!("Unknown value transform: {}", value_transform_ref.get_value_transform_id()))
+    }
+
+    fn create_built_in_transforms() -> HashMap<String, Arc<dyn ValueTransform>> {
+        let mut registry: HashMap<String, Arc<dyn ValueTransform>> = HashMap::new();
+
+        let built_in_value_transforms: Vec<Arc<dyn ValueTransform>> = vec![
+            Arc::new(ValueTransformXorConstant {}),
+            Arc::new(ValueTransformXorNeighbor {}),
+            Arc::new(ValueTransformAddConstant {}),
+            Arc::new(ValueTransformSubtractConstant {}),
+            Arc::new(ValueTransformByteSwap {}),
+        ];
+
+        for built_in_value_transform in built_in_value_transforms.into_iter() {
+            registry.insert(built_in_value_transform.get_value_transform_id().to_string(), built_in_value_transform);
+        }
+
+        registry
+    }
+}
+
+impl Default for ValueTransformRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueTransformRegistry;
+    use crate::plugins::value_transform::ValueTransformRef;
+    use crate::structures::memory::normalized_region::NormalizedRegion;
+
+    fn round_trip(
+        value_transform_registry: &ValueTransformRegistry,
+        value_transform_ref: ValueTransformRef,
+        value: &[u8],
+        neighbor: Option<&[u8]>,
+    ) -> Vec<u8> {
+        let resolved_value_transform = value_transform_registry
+            .resolve(&value_transform_ref)
+            .expect("Expected the built-in transform to resolve.");
+        let mut value_bytes = value.to_vec();
+
+        resolved_value_transform
+            .encode(&mut value_bytes, neighbor)
+            .expect("Expected encoding to succeed.");
+        assert_ne!(value_bytes, value, "{} should change the stored bytes.", value_transform_ref);
+        resolved_value_transform
+            .decode(&mut value_bytes, neighbor)
+            .expect("Expected decoding to succeed.");
+
+        value_bytes
+    }
+
+    #[test]
+    fn built_in_transforms_round_trip() {
+        let value_transform_registry = ValueTransformRegistry::new();
+        let value = 1234u32.to_le_bytes();
+        let neighbor = 0x5A5A_1234u32.to_le_bytes();
+
+        for value_transform_ref in [
+            ValueTransformRef::new("xor_constant", 0x1F2E_3D4C),
+            ValueTransformRef::new("add_constant", 77),
+            ValueTransformRef::new("subtract_constant", 77),
+            ValueTransformRef::new("byte_swap", 0),
+        ] {
+            assert_eq!(round_trip(&value_transform_registry, value_transform_ref, &value, None), value);
+        }
+
+        assert_eq!(
+            round_trip(&value_transform_registry, ValueTransformRef::new("xor_neighbor", 4), &value, Some(&neighbor)),
+            value
+        );
+        assert!(
+            value_transform_registry
+                .resolve(&ValueTransformRef::new("rot13", 0))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn add_constant_decodes_salted_integers() {
+        let resolved_value_transform = ValueTransformRegistry::new()
+            .resolve(&ValueTransformRef::new("add_constant", 1000))
+            .expect("Expected the built-in transform to resolve.");
+        let mut stored_bytes = 1100u16.to_le_bytes();
+
+        resolved_value_transform
+            .decode(&mut stored_bytes, None)
+            .expect("Expected decoding to succeed.");
+
+        assert_eq!(u16::from_le_bytes(stored_bytes), 100);
+        assert!(resolved_value_transform.decode(&mut [0u8; 3], None).is_err());
+    }
+
+    #[test]
+    fn decode_values_uses_neighbor_fields_and_reports_undecodable_elements() {
+        let resolved_value_transform = ValueTransformRegistry::new()
+            .resolve(&ValueTransformRef::new("xor_neighbor", 2))
+            .expect("Expected the built-in transform to resolve.");
+        // Each u16 value is XOR-ed with the u16 that follows it. The last value has no neighbor and is kept as read, as is the
+        // trailing byte that is not a whole element.
+        let raw_values = [0x0F, 0xF0, 0x0A, 0xA0, 0x33, 0x44, 0x55];
+
+        let (decoded_values, undecoded_regions) = resolved_value_transform.decode_values(0x1000, &raw_values, 2);
+
+        assert_eq!(decoded_values, vec![0x05, 0x50, 0x39, 0xE4, 0x33, 0x44, 0x55]);
+        assert_eq!(undecoded_regions, vec![NormalizedRegion::new(0x1004, 3)]);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::structures::debugging::breakpoint_mode::BreakpointMode;
+use serde::{Deserialize, Serialize};
+
+/// A software breakpoint patched into the code of a process.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Breakpoint {
+    breakpoint_id: u64,
+    process_id: u32,
+    address: u64,
+    breakpoint_mode: BreakpointMode,
+    hit_count: u64,
+    is_armed: bool,
+}
+
+impl Breakpoint {
+    pub fn new(
+        breakpoint_id: u64,
+        process_id: u32,
+        address: u64,
+        breakpoint_mode: BreakpointMode,
+    ) -> Self {
+        Self {
+            breakpoint_id,
+            process_id,
+            address,
+            breakpoint_mode,
+            hit_count: 0,
+            is_armed: true,
+        }
+    }
+
+    pub fn get_breakpoint_id(&self) -> u64 {
+        self.breakpoint_id
+    }
+
+    pub fn get_process_id(&self) -> u32 {
+        self.process_id
+    }
+
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn get_breakpoint_mode(&self) -> BreakpointMode {
+        self.breakpoint_mode
+    }
+
+    pub fn get_hit_count(&self) -> u64 {
+        self.hit_count
+    }
+
+    /// Gets whether the breakpoint is still patched into the process. Trace breakpoints disarm once they reach their hit limit.
+    pub fn get_is_armed(&self) -> bool {
+        self.is_armed
+    }
+
+    /// Records a hit, returning the one-based index of the hit.
+    pub fn record_hit(&mut self) -> u64 {
+        self.hit_count = self.hit_count.saturating_add(1);
+
+        self.hit_count
+    }
+
+    pub fn has_reached_hit_limit(&self) -> bool {
+        self.breakpoint_mode
+            .get_hit_limit()
+            .is_some_and(|hit_limit| self.hit_count >= hit_limit)
+    }
+
+    pub fn disarm(&mut self) {
+        self.is_armed = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Breakpoint;
+    use crate::structures::debugging::breakpoint_mode::BreakpointMode;
+
+    #[test]
+    fn trace_breakpoint_reaches_hit_limit_but_count_breakpoint_does_not() {
+        let mut trace_breakpoint = Breakpoint::new(1, 42, 0x401000, BreakpointMode::Trace { hit_limit: 2 });
+        let mut count_breakpoint = Breakpoint::new(2, 42, 0x401000, BreakpointMode::Count);
+
+        for _ in 0..2 {
+            trace_breakpoint.record_hit();
+            count_breakpoint.record_hit();
+        }
+
+        assert!(trace_breakpoint.has_reached_hit_limit());
+        assert!(!count_breakpoint.has_reached_hit_limit());
+        assert_eq!(count_breakpoint.get_hit_count(), 2);
+    }
+}
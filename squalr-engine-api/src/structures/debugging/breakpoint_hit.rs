@@ -0,0 +1,46 @@
+use crate::structures::debugging::register_snapshot::RegisterSnapshot;
+use serde::{Deserialize, Serialize};
+
+/// The context captured when a thread executed a trace breakpoint.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BreakpointHit {
+    breakpoint_id: u64,
+    hit_index: u64,
+    register_snapshot: RegisterSnapshot,
+    stack_frames: Vec<u64>,
+}
+
+impl BreakpointHit {
+    pub fn new(
+        breakpoint_id: u64,
+        hit_index: u64,
+        register_snapshot: RegisterSnapshot,
+        stack_frames: Vec<u64>,
+    ) -> Self {
+        Self {
+            breakpoint_id,
+            hit_index,
+            register_snapshot,
+            stack_frames,
+        }
+    }
+
+    pub fn get_breakpoint_id(&self) -> u64 {
+        self.breakpoint_id
+    }
+
+    /// Gets the one-based index of this hit among all hits of the breakpoint.
+    pub fn get_hit_index(&self) -> u64 {
+        self.hit_index
+    }
+
+    pub fn get_register_snapshot(&self) -> &RegisterSnapshot {
+        &self.register_snapshot
+    }
+
+    /// Gets the return addresses of the top stack frames, starting with the breakpoint address itself.
+    /// Frames are recovered by walking frame pointers, so code built without them yields a shorter or empty chain.
+    pub fn get_stack_frames(&self) -> &[u64] {
+        &self.stack_frames
+    }
+}
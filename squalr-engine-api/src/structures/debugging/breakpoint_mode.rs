@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// How a software breakpoint reacts when a thread executes it.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum BreakpointMode {
+    /// Briefly stops the thread on each hit to capture its full register context and top stack frames, then disarms itself
+    /// after the given number of hits.
+    Trace { hit_limit: u64 },
+    /// Counts hits without capturing any context. The thread still traps, but is resumed immediately.
+    Count,
+}
+
+impl BreakpointMode {
+    pub const DEFAULT_TRACE_HIT_LIMIT: u64 = 100;
+
+    pub fn get_hit_limit(&self) -> Option<u64> {
+        match self {
+            Self::Trace { hit_limit } => Some(*hit_limit),
+            Self::Count => None,
+        }
+    }
+
+    pub fn captures_context(&self) -> bool {
+        matches!(self, Self::Trace { .. })
+    }
+}
+
+impl Default for BreakpointMode {
+    fn default() -> Self {
+        Self::Trace {
+            hit_limit: Self::DEFAULT_TRACE_HIT_LIMIT,
+        }
+    }
+}
+
+impl fmt::Display for BreakpointMode {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Trace { hit_limit } => write!(formatter, "trace:{}", hit_limit),
+            Self::Count => write!(formatter, "count"),
+        }
+    }
+}
+
+impl FromStr for BreakpointMode {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalized_input = input.trim().to_ascii_lowercase();
+        let (mode_name, hit_limit) = match normalized_input.split_once(':') {
+            Some((mode_name, hit_limit)) => (mode_name, Some(hit_limit)),
+            None => (normalized_input.as_str(), None),
+        };
+
+        match (mode_name, hit_limit) {
+            ("count", None) => Ok(Self::Count),
+            ("trace", None) => Ok(Self::default()),
+            ("trace", Some(hit_limit)) => match hit_limit.trim().parse::<u64>() {
+                Ok(hit_limit) if hit_limit > 0 => Ok(Self::Trace { hit_limit }),
+                _ => Err(format!("Unsupported trace hit limit: {}", hit_limit)),
+            },
+            _ => Err(format!("Unsupported breakpoint mode: {}", input)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BreakpointMode;
+    use std::str::FromStr;
+
+    #[test]
+    fn breakpoint_mode_round_trips_through_display() {
+        for breakpoint_mode in [BreakpointMode::Count, BreakpointMode::Trace { hit_limit: 7 }] {
+            assert_eq!(BreakpointMode::from_str(&breakpoint_mode.to_string()), Ok(breakpoint_mode));
+        }
+
+        assert_eq!(BreakpointMode::from_str("trace"), Ok(BreakpointMode::default()));
+        assert!(BreakpointMode::from_str("trace:0").is_err());
+        assert!(BreakpointMode::from_str("halt").is_err());
+    }
+}
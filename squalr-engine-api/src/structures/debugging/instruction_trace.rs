@@ -0,0 +1,377 @@
+use crate::plugins::instruction_set::InstructionSet;
+use crate::structures::debugging::{
+    instruction_trace_step::InstructionTraceStep, instruction_trace_stop_reason::InstructionTraceStopReason, register_value::RegisterValue,
+    traced_instruction::TracedInstruction,
+};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// Registers that hold the address of the current instruction. They change on every step, so they are not recorded as writes.
+const INSTRUCTION_POINTER_REGISTER_NAMES: [&str; 3] = ["rip", "eip", "pc"];
+
+/// Every instruction a thread executed between two points, recorded by single stepping it.
+///
+/// The registers are stored once in full at the start of the trace, and each step only stores the registers that its
+/// instruction changed. The value of any register at any step is recovered by replaying those writes.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InstructionTrace {
+    process_id: u32,
+    thread_id: u32,
+    instruction_set_id: String,
+    start_address: u64,
+    stop_address: u64,
+    stop_reason: InstructionTraceStopReason,
+    initial_registers: Vec<RegisterValue>,
+    steps: Vec<InstructionTraceStep>,
+}
+
+impl InstructionTrace {
+    /// Builds a trace from the stops of a stepped thread, decoding each instruction with the given instruction set. Every stop
+    /// is followed by the stop after its instruction ran, except for the last one, which is where the trace ended. If the
+    /// thread exited, the last stop is its final instruction, whose register writes are unknown.
+    pub fn record(
+        process_id: u32,
+        instruction_set: &dyn InstructionSet,
+        traced_instructions: &[TracedInstruction],
+        stop_reason: InstructionTraceStopReason,
+    ) -> Self {
+        let Some(first_traced_instruction) = traced_instructions.first() else {
+            return Self {
+                process_id,
+                instruction_set_id: instruction_set.get_instruction_set_id().to_string(),
+                stop_reason,
+                ..Self::default()
+            };
+        };
+        let last_traced_instruction = traced_instructions.last().unwrap_or(first_traced_instruction);
+        let executed_instruction_count = match stop_reason {
+            InstructionTraceStopReason::ThreadExited => traced_instructions.len(),
+            _ => traced_instructions.len() - 1,
+        };
+        let steps = (0..executed_instruction_count)
+            .map(|step_index| {
+                let traced_instruction = &traced_instructions[step_index];
+                let (instruction_bytes, disassembly) = Self::decode_instruction(instruction_set, traced_instruction.get_instruction_bytes());
+                let register_writes = traced_instructions
+                    .get(step_index + 1)
+                    .map(|next_traced_instruction| Self::collect_register_writes(traced_instruction, next_traced_instruction))
+                    .unwrap_or_default();
+
+                InstructionTraceStep::new(
+                    traced_instruction
+                        .get_register_snapshot()
+                        .get_instruction_pointer(),
+                    instruction_bytes,
+                    disassembly,
+                    register_writes,
+                )
+            })
+            .collect();
+        let initial_registers = first_traced_instruction
+            .get_register_snapshot()
+            .get_registers()
+            .iter()
+            .filter(|register_value| !Self::is_instruction_pointer_register(register_value.get_name()))
+            .cloned()
+            .collect();
+
+        Self {
+            process_id,
+            thread_id: first_traced_instruction.get_register_snapshot().get_thread_id(),
+            instruction_set_id: instruction_set.get_instruction_set_id().to_string(),
+            start_address: first_traced_instruction
+                .get_register_snapshot()
+                .get_instruction_pointer(),
+            stop_address: last_traced_instruction
+                .get_register_snapshot()
+                .get_instruction_pointer(),
+            stop_reason,
+            initial_registers,
+            steps,
+        }
+    }
+
+    pub fn load_from_file(trace_file_path: &Path) -> io::Result<Self> {
+        let trace_file = File::open(trace_file_path)?;
+
+        serde_json::from_reader(BufReader::new(trace_file)).map_err(io::Error::from)
+    }
+
+    pub fn save_to_file(
+        &self,
+        trace_file_path: &Path,
+    ) -> io::Result<()> {
+        let mut trace_writer = BufWriter::new(File::create(trace_file_path)?);
+
+        serde_json::to_writer(&mut trace_writer, self)?;
+        trace_writer.flush()
+    }
+
+    pub fn get_process_id(&self) -> u32 {
+        self.process_id
+    }
+
+    pub fn get_thread_id(&self) -> u32 {
+        self.thread_id
+    }
+
+    pub fn get_instruction_set_id(&self) -> &str {
+        &self.instruction_set_id
+    }
+
+    pub fn get_start_address(&self) -> u64 {
+        self.start_address
+    }
+
+    /// Gets the address the thread was stopped at when the trace ended, which is the end address if it was reached.
+    pub fn get_stop_address(&self) -> u64 {
+        self.stop_address
+    }
+
+    pub fn get_stop_reason(&self) -> InstructionTraceStopReason {
+        self.stop_reason
+    }
+
+    /// Gets the registers before the first instruction ran, without the instruction pointer.
+    pub fn get_initial_registers(&self) -> &[RegisterValue] {
+        &self.initial_registers
+    }
+
+    pub fn get_steps(&self) -> &[InstructionTraceStep] {
+        &self.steps
+    }
+
+    /// Gets the indices of the steps that executed the instruction at the given address, in execution order.
+    pub fn find_steps_at_address(
+        &self,
+        address: u64,
+    ) -> Vec<usize> {
+        self.steps
+            .iter()
+            .enumerate()
+            .filter(|(_, step)| step.get_instruction_pointer() == address)
+            .map(|(step_index, _)| step_index)
+            .collect()
+    }
+
+    /// Finds the step that last wrote a register before the given step ran. `None` means the register still holds the value
+    /// it had when the trace started.
+    pub fn find_last_write(
+        &self,
+        register_name: &str,
+        before_step_index: usize,
+    ) -> Option<usize> {
+        self.steps[..before_step_index.min(self.steps.len())]
+            .iter()
+            .rposition(|step| step.writes_register(register_name))
+    }
+
+    /// Gets the value a register held right before the given step ran. A step index past the last step gives the value at
+    /// the end of the trace.
+    pub fn get_register_value(
+        &self,
+        register_name: &str,
+        step_index: usize,
+    ) -> Option<u64> {
+        let find_register_value = |register_values: &[RegisterValue]| {
+            register_values
+                .iter()
+                .find(|register_value| register_value.get_name().eq_ignore_ascii_case(register_name))
+                .map(RegisterValue::get_value)
+        };
+
+        match self.find_last_write(register_name, step_index) {
+            Some(writing_step_index) => find_register_value(self.steps[writing_step_index].get_register_writes()),
+            None => find_register_value(&self.initial_registers),
+        }
+    }
+
+    /// Trims the captured code down to its first instruction, then disassembles it. Instructions that fail to decode keep
+    /// every captured byte, along with the decoding error in place of their disassembly.
+    fn decode_instruction(
+        instruction_set: &dyn InstructionSet,
+        captured_bytes: &[u8],
+    ) -> (Vec<u8>, String) {
+        let instruction_bytes = match instruction_set.measure_instructions(captured_bytes) {
+            Ok(instruction_lengths) => match instruction_lengths.first() {
+                Some(&instruction_length) if instruction_length > 0 => &captured_bytes[..instruction_length.min(captured_bytes.len())],
+                _ => captured_bytes,
+            },
+            Err(_) => captured_bytes,
+        };
+        let disassembly = instruction_set
+            .disassemble(instruction_bytes)
+            .unwrap_or_else(|error| format!("<{}>", error));
+
+        (instruction_bytes.to_vec(), disassembly)
+    }
+
+    fn collect_register_writes(
+        traced_instruction: &TracedInstruction,
+        next_traced_instruction: &TracedInstruction,
+    ) -> Vec<RegisterValue> {
+        let registers_before = traced_instruction.get_register_snapshot();
+
+        next_traced_instruction
+            .get_register_snapshot()
+            .get_registers()
+            .iter()
+            .filter(|register_value| !Self::is_instruction_pointer_register(register_value.get_name()))
+            .filter(|register_value| registers_before.get_register(register_value.get_name()) != Some(register_value.get_value()))
+            .cloned()
+            .collect()
+    }
+
+    fn is_instruction_pointer_register(register_name: &str) -> bool {
+        INSTRUCTION_POINTER_REGISTER_NAMES
+            .iter()
+            .any(|instruction_pointer_register_name| instruction_pointer_register_name.eq_ignore_ascii_case(register_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InstructionTrace;
+    use crate::plugins::instruction_set::InstructionSet;
+    use crate::structures::debugging::{
+        instruction_trace_stop_reason::InstructionTraceStopReason, register_snapshot::RegisterSnapshot, register_value::RegisterValue,
+        traced_instruction::TracedInstruction,
+    };
+
+    /// Decodes every byte as its own instruction, named after its value.
+    #[derive(Debug)]
+    struct SingleByteInstructionSet;
+
+    impl InstructionSet for SingleByteInstructionSet {
+        fn get_instruction_set_id(&self) -> &str {
+            "single_byte"
+        }
+
+        fn get_display_name(&self) -> &str {
+            "Single byte"
+        }
+
+        fn assemble(
+            &self,
+            _assembly_source: &str,
+        ) -> Result<Vec<u8>, String> {
+            Err(String::from("Assembling is not supported."))
+        }
+
+        fn disassemble(
+            &self,
+            instruction_bytes: &[u8],
+        ) -> Result<String, String> {
+            Ok(instruction_bytes
+                .iter()
+                .map(|instruction_byte| format!("op{:02X}", instruction_byte))
+                .collect::<Vec<_>>()
+                .join("; "))
+        }
+
+        fn measure_instructions(
+            &self,
+            instruction_bytes: &[u8],
+        ) -> Result<Vec<usize>, String> {
+            Ok(vec![1; instruction_bytes.len()])
+        }
+    }
+
+    fn create_traced_instruction(
+        instruction_pointer: u64,
+        rax: u64,
+        rbx: u64,
+    ) -> TracedInstruction {
+        let registers = vec![
+            RegisterValue::new(String::from("rax"), rax),
+            RegisterValue::new(String::from("rbx"), rbx),
+            RegisterValue::new(String::from("rip"), instruction_pointer),
+        ];
+
+        TracedInstruction::new(
+            RegisterSnapshot::new(7, instruction_pointer, 0x7FF0, registers),
+            vec![instruction_pointer as u8, 0xFF],
+        )
+    }
+
+    fn create_trace() -> InstructionTrace {
+        let traced_instructions = [
+            create_traced_instruction(0x1000, 1, 10),
+            create_traced_instruction(0x1001, 2, 10),
+            create_traced_instruction(0x1002, 2, 20),
+            create_traced_instruction(0x1001, 3, 20),
+            create_traced_instruction(0x1002, 3, 20),
+        ];
+
+        InstructionTrace::record(
+            42,
+            &SingleByteInstructionSet,
+            &traced_instructions,
+            InstructionTraceStopReason::ReachedEndAddress,
+        )
+    }
+
+    #[test]
+    fn record_keeps_one_instruction_per_step_and_only_changed_registers() {
+        let instruction_trace = create_trace();
+        let steps = instruction_trace.get_steps();
+
+        assert_eq!(instruction_trace.get_thread_id(), 7);
+        assert_eq!(instruction_trace.get_start_address(), 0x1000);
+        assert_eq!(instruction_trace.get_stop_address(), 0x1002);
+        assert_eq!(instruction_trace.get_initial_registers().len(), 2);
+        assert_eq!(steps.len(), 4);
+        assert_eq!(steps[0].get_instruction_bytes(), &[0x00]);
+        assert_eq!(steps[0].get_disassembly(), "op00");
+        assert_eq!(steps[0].get_register_writes(), &[RegisterValue::new(String::from("rax"), 2)]);
+        assert_eq!(steps[1].get_register_writes(), &[RegisterValue::new(String::from("rbx"), 20)]);
+        assert!(steps[3].get_register_writes().is_empty());
+        assert_eq!(instruction_trace.find_steps_at_address(0x1001), vec![1, 3]);
+    }
+
+    #[test]
+    fn backward_queries_find_the_last_write_of_a_register() {
+        let instruction_trace = create_trace();
+
+        assert_eq!(instruction_trace.find_last_write("RAX", 4), Some(2));
+        assert_eq!(instruction_trace.find_last_write("rax", 2), Some(0));
+        assert_eq!(instruction_trace.find_last_write("rax", 0), None);
+        assert_eq!(instruction_trace.find_last_write("rbx", usize::MAX), Some(1));
+        assert_eq!(instruction_trace.get_register_value("rax", 0), Some(1));
+        assert_eq!(instruction_trace.get_register_value("rax", 2), Some(2));
+        assert_eq!(instruction_trace.get_register_value("rax", 4), Some(3));
+        assert_eq!(instruction_trace.get_register_value("rcx", 4), None);
+    }
+
+    #[test]
+    fn thread_exit_keeps_the_final_instruction() {
+        let traced_instructions = [
+            create_traced_instruction(0x1000, 1, 10),
+            create_traced_instruction(0x1001, 2, 10),
+        ];
+        let instruction_trace = InstructionTrace::record(42, &SingleByteInstructionSet, &traced_instructions, InstructionTraceStopReason::ThreadExited);
+
+        assert_eq!(instruction_trace.get_steps().len(), 2);
+        assert!(
+            instruction_trace.get_steps()[1]
+                .get_register_writes()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn trace_round_trips_through_a_file() {
+        let instruction_trace = create_trace();
+        let trace_file_path = std::env::temp_dir().join(format!("squalr-instruction-trace-{}.json", std::process::id()));
+
+        instruction_trace
+            .save_to_file(&trace_file_path)
+            .expect("Expected the trace to be saved.");
+        let loaded_instruction_trace = InstructionTrace::load_from_file(&trace_file_path);
+        let _ = std::fs::remove_file(&trace_file_path);
+
+        assert_eq!(loaded_instruction_trace.expect("Expected the trace to load."), instruction_trace);
+    }
+}
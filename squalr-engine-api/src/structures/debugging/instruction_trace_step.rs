@@ -0,0 +1,59 @@
+use crate::structures::debugging::register_value::RegisterValue;
+use serde::{Deserialize, Serialize};
+
+/// One executed instruction of an instruction trace, along with the registers it changed.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InstructionTraceStep {
+    #[serde(rename = "ip")]
+    instruction_pointer: u64,
+    #[serde(rename = "bytes")]
+    instruction_bytes: Vec<u8>,
+    #[serde(rename = "asm")]
+    disassembly: String,
+    /// Only registers whose value changed are stored, which keeps trace files compact.
+    #[serde(rename = "writes", default, skip_serializing_if = "Vec::is_empty")]
+    register_writes: Vec<RegisterValue>,
+}
+
+impl InstructionTraceStep {
+    pub fn new(
+        instruction_pointer: u64,
+        instruction_bytes: Vec<u8>,
+        disassembly: String,
+        register_writes: Vec<RegisterValue>,
+    ) -> Self {
+        Self {
+            instruction_pointer,
+            instruction_bytes,
+            disassembly,
+            register_writes,
+        }
+    }
+
+    pub fn get_instruction_pointer(&self) -> u64 {
+        self.instruction_pointer
+    }
+
+    pub fn get_instruction_bytes(&self) -> &[u8] {
+        &self.instruction_bytes
+    }
+
+    pub fn get_disassembly(&self) -> &str {
+        &self.disassembly
+    }
+
+    /// Gets the new values of the registers this instruction changed. The instruction pointer is left out, as every
+    /// instruction changes it.
+    pub fn get_register_writes(&self) -> &[RegisterValue] {
+        &self.register_writes
+    }
+
+    pub fn writes_register(
+        &self,
+        register_name: &str,
+    ) -> bool {
+        self.register_writes
+            .iter()
+            .any(|register_value| register_value.get_name().eq_ignore_ascii_case(register_name))
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Why an instruction trace stopped recording.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum InstructionTraceStopReason {
+    /// The thread reached the end address. The instruction there was not executed.
+    #[default]
+    ReachedEndAddress,
+    /// The thread executed as many instructions as the trace allowed.
+    ReachedInstructionBudget,
+    /// The thread exited while it was being stepped.
+    ThreadExited,
+}
+
+impl fmt::Display for InstructionTraceStopReason {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::ReachedEndAddress => write!(formatter, "reached the end address"),
+            Self::ReachedInstructionBudget => write!(formatter, "reached the instruction budget"),
+            Self::ThreadExited => write!(formatter, "the thread exited"),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+pub mod breakpoint;
+pub mod breakpoint_hit;
+pub mod breakpoint_mode;
+pub mod instruction_trace;
+pub mod instruction_trace_step;
+pub mod instruction_trace_stop_reason;
+pub mod register_snapshot;
+pub mod register_value;
+pub mod traced_instruction;
+pub mod vector_register_value;
+pub mod watchpoint;
+pub mod watchpoint_access;
+pub mod watchpoint_hit;
+pub mod watchpoint_size;
@@ -0,0 +1,69 @@
+use crate::structures::debugging::{register_value::RegisterValue, vector_register_value::VectorRegisterValue};
+use serde::{Deserialize, Serialize};
+
+/// The user-mode registers of a thread, captured when it hit a watchpoint or breakpoint, or when its context was requested.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RegisterSnapshot {
+    thread_id: u32,
+    instruction_pointer: u64,
+    stack_pointer: u64,
+    registers: Vec<RegisterValue>,
+    #[serde(default)]
+    vector_registers: Vec<VectorRegisterValue>,
+}
+
+impl RegisterSnapshot {
+    pub fn new(
+        thread_id: u32,
+        instruction_pointer: u64,
+        stack_pointer: u64,
+        registers: Vec<RegisterValue>,
+    ) -> Self {
+        Self {
+            thread_id,
+            instruction_pointer,
+            stack_pointer,
+            registers,
+            vector_registers: Vec::new(),
+        }
+    }
+
+    pub fn with_vector_registers(
+        mut self,
+        vector_registers: Vec<VectorRegisterValue>,
+    ) -> Self {
+        self.vector_registers = vector_registers;
+
+        self
+    }
+
+    pub fn get_thread_id(&self) -> u32 {
+        self.thread_id
+    }
+
+    pub fn get_instruction_pointer(&self) -> u64 {
+        self.instruction_pointer
+    }
+
+    pub fn get_stack_pointer(&self) -> u64 {
+        self.stack_pointer
+    }
+
+    pub fn get_registers(&self) -> &[RegisterValue] {
+        &self.registers
+    }
+
+    pub fn get_register(
+        &self,
+        name: &str,
+    ) -> Option<u64> {
+        self.registers
+            .iter()
+            .find(|register_value| register_value.get_name().eq_ignore_ascii_case(name))
+            .map(RegisterValue::get_value)
+    }
+
+    pub fn get_vector_registers(&self) -> &[VectorRegisterValue] {
+        &self.vector_registers
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// A named general purpose register and its value at the time a thread was stopped.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RegisterValue {
+    name: String,
+    value: u64,
+}
+
+impl RegisterValue {
+    pub fn new(
+        name: String,
+        value: u64,
+    ) -> Self {
+        Self { name, value }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_value(&self) -> u64 {
+        self.value
+    }
+}
@@ -0,0 +1,32 @@
+use crate::structures::debugging::register_snapshot::RegisterSnapshot;
+use serde::{Deserialize, Serialize};
+
+/// A thread stopped on one instruction of a single-step trace, captured before the instruction ran.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TracedInstruction {
+    register_snapshot: RegisterSnapshot,
+    instruction_bytes: Vec<u8>,
+}
+
+impl TracedInstruction {
+    pub fn new(
+        register_snapshot: RegisterSnapshot,
+        instruction_bytes: Vec<u8>,
+    ) -> Self {
+        Self {
+            register_snapshot,
+            instruction_bytes,
+        }
+    }
+
+    /// Gets the general purpose registers of the thread. Vector registers are not captured while stepping.
+    pub fn get_register_snapshot(&self) -> &RegisterSnapshot {
+        &self.register_snapshot
+    }
+
+    /// Gets the code at the instruction pointer, as it was before any breakpoint was patched over it. This may hold more
+    /// than one instruction, since the length of the instruction is not known yet when it is read.
+    pub fn get_instruction_bytes(&self) -> &[u8] {
+        &self.instruction_bytes
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// A named vector register, such as `xmm0` or `v0`, stored as its little-endian bytes.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VectorRegisterValue {
+    name: String,
+    bytes: Vec<u8>,
+}
+
+impl VectorRegisterValue {
+    pub fn new(
+        name: String,
+        bytes: Vec<u8>,
+    ) -> Self {
+        Self { name, bytes }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Formats the register as a single hexadecimal value, most significant byte first.
+    pub fn format_hex(&self) -> String {
+        let hex_digits = self
+            .bytes
+            .iter()
+            .rev()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<String>();
+
+        format!("0x{}", hex_digits)
+    }
+}
@@ -0,0 +1,50 @@
+use crate::structures::debugging::{watchpoint_access::WatchpointAccess, watchpoint_size::WatchpointSize};
+use serde::{Deserialize, Serialize};
+
+/// A hardware watchpoint that is armed on every thread of a process.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Watchpoint {
+    watchpoint_id: u64,
+    process_id: u32,
+    address: u64,
+    watchpoint_size: WatchpointSize,
+    watchpoint_access: WatchpointAccess,
+}
+
+impl Watchpoint {
+    pub fn new(
+        watchpoint_id: u64,
+        process_id: u32,
+        address: u64,
+        watchpoint_size: WatchpointSize,
+        watchpoint_access: WatchpointAccess,
+    ) -> Self {
+        Self {
+            watchpoint_id,
+            process_id,
+            address,
+            watchpoint_size,
+            watchpoint_access,
+        }
+    }
+
+    pub fn get_watchpoint_id(&self) -> u64 {
+        self.watchpoint_id
+    }
+
+    pub fn get_process_id(&self) -> u32 {
+        self.process_id
+    }
+
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn get_watchpoint_size(&self) -> WatchpointSize {
+        self.watchpoint_size
+    }
+
+    pub fn get_watchpoint_access(&self) -> WatchpointAccess {
+        self.watchpoint_access
+    }
+}
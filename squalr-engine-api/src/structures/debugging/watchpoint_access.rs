@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The kind of memory access that triggers a hardware watchpoint.
+/// x86 debug registers cannot trap on reads alone, so read watchpoints always also trap on writes.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WatchpointAccess {
+    #[default]
+    Write,
+    ReadWrite,
+}
+
+impl WatchpointAccess {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Write => "Find what writes",
+            Self::ReadWrite => "Find what accesses",
+        }
+    }
+}
+
+impl fmt::Display for WatchpointAccess {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Write => write!(formatter, "write"),
+            Self::ReadWrite => write!(formatter, "access"),
+        }
+    }
+}
+
+impl FromStr for WatchpointAccess {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_ascii_lowercase().as_str() {
+            "w" | "write" | "writes" => Ok(Self::Write),
+            "r" | "rw" | "read" | "reads" | "readwrite" | "read-write" | "access" | "accesses" => Ok(Self::ReadWrite),
+            _ => Err(format!("Unsupported watchpoint access: {}", input)),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::structures::debugging::register_snapshot::RegisterSnapshot;
+use serde::{Deserialize, Serialize};
+
+/// An instruction that triggered a watchpoint, along with how often it did so.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WatchpointHit {
+    trap_address: u64,
+    is_trap_after_access: bool,
+    hit_count: u64,
+    last_register_snapshot: RegisterSnapshot,
+}
+
+impl WatchpointHit {
+    pub fn new(
+        trap_address: u64,
+        is_trap_after_access: bool,
+        hit_count: u64,
+        last_register_snapshot: RegisterSnapshot,
+    ) -> Self {
+        Self {
+            trap_address,
+            is_trap_after_access,
+            hit_count,
+            last_register_snapshot,
+        }
+    }
+
+    /// Gets the instruction pointer reported by the trap.
+    pub fn get_trap_address(&self) -> u64 {
+        self.trap_address
+    }
+
+    /// Gets whether the trap is reported after the accessing instruction retires, as on x86. When set, the accessing
+    /// instruction is the one that ends at the trap address rather than the one that starts there.
+    pub fn get_is_trap_after_access(&self) -> bool {
+        self.is_trap_after_access
+    }
+
+    pub fn get_hit_count(&self) -> u64 {
+        self.hit_count
+    }
+
+    pub fn get_last_register_snapshot(&self) -> &RegisterSnapshot {
+        &self.last_register_snapshot
+    }
+
+    pub fn record_hit(
+        &mut self,
+        register_snapshot: RegisterSnapshot,
+    ) {
+        self.hit_count = self.hit_count.saturating_add(1);
+        self.last_register_snapshot = register_snapshot;
+    }
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The number of bytes covered by a hardware watchpoint. The watched address must be aligned to this size.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WatchpointSize {
+    Byte1,
+    Byte2,
+    #[default]
+    Byte4,
+    Byte8,
+}
+
+impl WatchpointSize {
+    pub fn get_size_in_bytes(&self) -> u64 {
+        match self {
+            Self::Byte1 => 1,
+            Self::Byte2 => 2,
+            Self::Byte4 => 4,
+            Self::Byte8 => 8,
+        }
+    }
+
+    pub fn from_size_in_bytes(size_in_bytes: u64) -> Option<Self> {
+        match size_in_bytes {
+            1 => Some(Self::Byte1),
+            2 => Some(Self::Byte2),
+            4 => Some(Self::Byte4),
+            8 => Some(Self::Byte8),
+            _ => None,
+        }
+    }
+
+    /// Gets the largest watchpoint size that fits within the given byte count and keeps the address aligned.
+    pub fn largest_aligned_for(
+        address: u64,
+        byte_count: u64,
+    ) -> Self {
+        [Self::Byte8, Self::Byte4, Self::Byte2]
+            .into_iter()
+            .find(|watchpoint_size| byte_count >= watchpoint_size.get_size_in_bytes() && watchpoint_size.is_aligned(address))
+            .unwrap_or(Self::Byte1)
+    }
+
+    pub fn is_aligned(
+        &self,
+        address: u64,
+    ) -> bool {
+        address.is_multiple_of(self.get_size_in_bytes())
+    }
+}
+
+impl fmt::Display for WatchpointSize {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(formatter, "{}", self.get_size_in_bytes())
+    }
+}
+
+impl FromStr for WatchpointSize {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        input
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .and_then(Self::from_size_in_bytes)
+            .ok_or_else(|| format!("Unsupported watchpoint size: {}. Expected 1, 2, 4, or 8.", input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WatchpointSize;
+
+    #[test]
+    fn largest_aligned_size_respects_alignment_and_byte_count() {
+        assert_eq!(WatchpointSize::largest_aligned_for(0x1000, 16), WatchpointSize::Byte8);
+        assert_eq!(WatchpointSize::largest_aligned_for(0x1004, 16), WatchpointSize::Byte4);
+        assert_eq!(WatchpointSize::largest_aligned_for(0x1002, 4), WatchpointSize::Byte2);
+        assert_eq!(WatchpointSize::largest_aligned_for(0x1000, 3), WatchpointSize::Byte2);
+        assert_eq!(WatchpointSize::largest_aligned_for(0x1001, 8), WatchpointSize::Byte1);
+    }
+
+    #[test]
+    fn parses_byte_counts() {
+        assert_eq!("8".parse::<WatchpointSize>(), Ok(WatchpointSize::Byte8));
+        assert!("3".parse::<WatchpointSize>().is_err());
+    }
+}
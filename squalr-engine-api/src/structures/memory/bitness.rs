@@ -1,7 +1,26 @@
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Bitness {
     Bit32,
     Bit64,
 }
+
+impl Default for Bitness {
+    fn default() -> Self {
+        if cfg!(target_pointer_width = "32") { Self::Bit32 } else { Self::Bit64 }
+    }
+}
+
+impl FromStr for Bitness {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.trim().to_ascii_lowercase().as_str() {
+            "32" | "bit32" | "x86" | "i386" => Ok(Self::Bit32),
+            "64" | "bit64" | "x64" | "x86_64" | "amd64" => Ok(Self::Bit64),
+            _ => Err(format!("Unsupported bitness: {string}. Expected one of: 32, 64.")),
+        }
+    }
+}
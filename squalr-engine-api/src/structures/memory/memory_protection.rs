@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The page protection applied to memory that the engine allocates or re-protects in a target process.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MemoryProtection {
+    is_readable: bool,
+    is_writable: bool,
+    is_executable: bool,
+}
+
+impl MemoryProtection {
+    pub const NONE: Self = Self::new(false, false, false);
+    pub const READ_ONLY: Self = Self::new(true, false, false);
+    pub const READ_WRITE: Self = Self::new(true, true, false);
+    pub const READ_EXECUTE: Self = Self::new(true, false, true);
+    pub const READ_WRITE_EXECUTE: Self = Self::new(true, true, true);
+
+    pub const fn new(
+        is_readable: bool,
+        is_writable: bool,
+        is_executable: bool,
+    ) -> Self {
+        Self {
+            is_readable,
+            is_writable,
+            is_executable,
+        }
+    }
+
+    pub fn is_readable(&self) -> bool {
+        self.is_readable
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.is_writable
+    }
+
+    pub fn is_executable(&self) -> bool {
+        self.is_executable
+    }
+}
+
+impl Default for MemoryProtection {
+    fn default() -> Self {
+        Self::READ_WRITE
+    }
+}
+
+/// Formats the protection in the `rwx` style used by `/proc/<pid>/maps`, with `-` for each missing permission.
+impl fmt::Display for MemoryProtection {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(
+            formatter,
+            "{}{}{}",
+            if self.is_readable { 'r' } else { '-' },
+            if self.is_writable { 'w' } else { '-' },
+            if self.is_executable { 'x' } else { '-' }
+        )
+    }
+}
+
+/// Parses protections such as `rwx`, `r-x` or `rx`. `none` and `-` parse to no access.
+impl FromStr for MemoryProtection {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalized_input = input.trim().to_ascii_lowercase();
+
+        if normalized_input.is_empty() {
+            return Err(String::from("Memory protection cannot be empty."));
+        }
+
+        if normalized_input == "none" {
+            return Ok(Self::NONE);
+        }
+
+        let mut memory_protection = Self::NONE;
+
+        for permission in normalized_input.chars() {
+            let permission_flag = match permission {
+                'r' => &mut memory_protection.is_readable,
+                'w' => &mut memory_protection.is_writable,
+                'x' => &mut memory_protection.is_executable,
+                '-' => continue,
+                _ => return Err(format!("Unsupported memory protection: {}", input)),
+            };
+
+            if *permission_flag {
+                return Err(format!("Unsupported memory protection: {}", input));
+            }
+
+            *permission_flag = true;
+        }
+
+        Ok(memory_protection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryProtection;
+    use std::str::FromStr;
+
+    #[test]
+    fn memory_protection_round_trips_through_display() {
+        for memory_protection in [
+            MemoryProtection::NONE,
+            MemoryProtection::READ_ONLY,
+            MemoryProtection::READ_WRITE,
+            MemoryProtection::READ_EXECUTE,
+            MemoryProtection::READ_WRITE_EXECUTE,
+        ] {
+            assert_eq!(MemoryProtection::from_str(&memory_protection.to_string()), Ok(memory_protection));
+        }
+
+        assert_eq!(MemoryProtection::from_str("RX"), Ok(MemoryProtection::READ_EXECUTE));
+        assert_eq!(MemoryProtection::from_str("none"), Ok(MemoryProtection::NONE));
+        assert!(MemoryProtection::from_str("rr").is_err());
+        assert!(MemoryProtection::from_str("rwz").is_err());
+    }
+}
@@ -2,8 +2,10 @@ pub mod address_display;
 pub mod bitness;
 pub mod endian;
 pub mod memory_alignment;
+pub mod memory_protection;
 pub mod normalized_module;
 pub mod normalized_region;
 pub mod pointer;
 pub mod pointer_chain_segment;
 pub mod symbolic_pointer_chain;
+pub mod written_page;
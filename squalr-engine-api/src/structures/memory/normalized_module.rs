@@ -14,6 +14,9 @@ pub struct NormalizedModule {
     base_region: NormalizedRegion,
     module_name: String,
     module_address_display: ModuleAddressDisplay,
+    /// On-disk path of the image backing this module, when the target OS exposes it.
+    #[serde(default)]
+    module_path: Option<String>,
 }
 
 impl NormalizedModule {
@@ -35,6 +38,7 @@ impl NormalizedModule {
             base_region: NormalizedRegion::new(base_address, size),
             module_name: module_name.to_string(),
             module_address_display,
+            module_path: None,
         }
     }
 
@@ -54,9 +58,18 @@ impl NormalizedModule {
             base_region: normalized_region,
             module_name: module_name.to_string(),
             module_address_display,
+            module_path: None,
         }
     }
 
+    pub fn with_module_path(
+        mut self,
+        module_path: &str,
+    ) -> Self {
+        self.module_path = Some(module_path.to_string());
+        self
+    }
+
     pub fn into_base_region(self) -> NormalizedRegion {
         self.base_region
     }
@@ -101,6 +114,10 @@ impl NormalizedModule {
     pub fn get_module_address_display(&self) -> ModuleAddressDisplay {
         self.module_address_display
     }
+
+    pub fn get_module_path(&self) -> Option<&str> {
+        self.module_path.as_deref()
+    }
 }
 
 impl PartialEq for NormalizedModule {
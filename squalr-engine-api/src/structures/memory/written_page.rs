@@ -0,0 +1,105 @@
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use serde::{Deserialize, Serialize};
+
+/// A page that a process wrote to while write tracking was active, along with the bytes that the writes changed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WrittenPage {
+    page_region: NormalizedRegion,
+    /// The module containing this page, or an empty string if the page belongs to no module.
+    module_name: String,
+    /// The offset of this page from the base of its module, or its absolute address if it belongs to no module.
+    module_offset: u64,
+    /// The ranges of this page whose bytes differ from when tracking began, or `None` if the page contents were not captured.
+    /// A page can be written without changing, such as when a value is overwritten with itself.
+    changed_regions: Option<Vec<NormalizedRegion>>,
+}
+
+impl WrittenPage {
+    pub fn new(
+        page_region: NormalizedRegion,
+        module_name: String,
+        module_offset: u64,
+        changed_regions: Option<Vec<NormalizedRegion>>,
+    ) -> Self {
+        Self {
+            page_region,
+            module_name,
+            module_offset,
+            changed_regions,
+        }
+    }
+
+    pub fn get_page_region(&self) -> &NormalizedRegion {
+        &self.page_region
+    }
+
+    pub fn get_module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    pub fn get_module_offset(&self) -> u64 {
+        self.module_offset
+    }
+
+    pub fn get_changed_regions(&self) -> Option<&[NormalizedRegion]> {
+        self.changed_regions.as_deref()
+    }
+
+    /// Gets the number of bytes of this page that changed, which measures how heavily the page was written.
+    pub fn get_changed_byte_count(&self) -> u64 {
+        self.changed_regions
+            .iter()
+            .flatten()
+            .map(|changed_region| changed_region.get_region_size())
+            .sum()
+    }
+
+    /// Finds the runs of bytes that differ between two captures of the memory starting at the given address.
+    pub fn find_changed_regions(
+        base_address: u64,
+        previous_bytes: &[u8],
+        current_bytes: &[u8],
+    ) -> Vec<NormalizedRegion> {
+        let mut changed_regions: Vec<NormalizedRegion> = Vec::new();
+
+        for (byte_offset, (previous_byte, current_byte)) in previous_bytes.iter().zip(current_bytes).enumerate() {
+            if previous_byte == current_byte {
+                continue;
+            }
+
+            let byte_address = base_address + byte_offset as u64;
+
+            match changed_regions.last_mut() {
+                Some(changed_region) if changed_region.get_end_address() == byte_address => {
+                    changed_region.set_region_size(changed_region.get_region_size() + 1);
+                }
+                _ => changed_regions.push(NormalizedRegion::new(byte_address, 1)),
+            }
+        }
+
+        changed_regions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WrittenPage;
+    use crate::structures::memory::normalized_region::NormalizedRegion;
+
+    #[test]
+    fn find_changed_regions_groups_adjacent_changed_bytes() {
+        let changed_regions = WrittenPage::find_changed_regions(0x1000, &[0, 0, 0, 0, 0, 0], &[0, 1, 2, 0, 0, 3]);
+
+        assert_eq!(
+            changed_regions,
+            vec![
+                NormalizedRegion::new(0x1001, 2),
+                NormalizedRegion::new(0x1005, 1)
+            ]
+        );
+
+        let written_page = WrittenPage::new(NormalizedRegion::new(0x1000, 0x1000), String::new(), 0x1000, Some(changed_regions));
+
+        assert_eq!(written_page.get_changed_byte_count(), 3);
+    }
+}
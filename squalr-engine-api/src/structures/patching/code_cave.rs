@@ -0,0 +1,115 @@
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use crate::structures::patching::code_cave_padding::CodeCavePadding;
+use serde::{Deserialize, Serialize};
+
+/// A run of padding in an executable section of a module, which can hold detour code without allocating memory.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeCave {
+    region: NormalizedRegion,
+    module_name: String,
+    /// The offset of this cave from the base of its module.
+    module_offset: u64,
+    /// The fill that the cave was found in, such as int3 breakpoints.
+    padding: CodeCavePadding,
+}
+
+impl CodeCave {
+    pub fn new(
+        region: NormalizedRegion,
+        module_name: String,
+        module_offset: u64,
+        padding: CodeCavePadding,
+    ) -> Self {
+        Self {
+            region,
+            module_name,
+            module_offset,
+            padding,
+        }
+    }
+
+    pub fn get_region(&self) -> &NormalizedRegion {
+        &self.region
+    }
+
+    pub fn get_base_address(&self) -> u64 {
+        self.region.get_base_address()
+    }
+
+    pub fn get_size(&self) -> u64 {
+        self.region.get_region_size()
+    }
+
+    pub fn get_module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    pub fn get_module_offset(&self) -> u64 {
+        self.module_offset
+    }
+
+    pub fn get_padding(&self) -> CodeCavePadding {
+        self.padding
+    }
+
+    /// Gets the distance from the given address to the nearest byte of this cave.
+    pub fn get_distance(
+        &self,
+        address: u64,
+    ) -> u64 {
+        if address < self.region.get_base_address() {
+            self.region.get_base_address() - address
+        } else {
+            address.saturating_sub(self.region.get_end_address().saturating_sub(1))
+        }
+    }
+
+    /// Orders caves from largest to smallest, breaking ties by distance from the target address when one is given.
+    pub fn rank(
+        code_caves: &mut [CodeCave],
+        target_address: Option<u64>,
+    ) {
+        code_caves.sort_by_key(|code_cave| {
+            (
+                std::cmp::Reverse(code_cave.get_size()),
+                target_address.map_or(0, |target_address| code_cave.get_distance(target_address)),
+                code_cave.get_base_address(),
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodeCave;
+    use crate::structures::memory::normalized_region::NormalizedRegion;
+    use crate::structures::patching::code_cave_padding::CodeCavePadding;
+
+    #[test]
+    fn rank_orders_caves_by_size_then_by_distance_from_the_target() {
+        let code_cave = |base_address: u64, size: u64| {
+            CodeCave::new(
+                NormalizedRegion::new(base_address, size),
+                String::from("game"),
+                base_address,
+                CodeCavePadding::Breakpoint,
+            )
+        };
+        let mut code_caves = vec![
+            code_cave(0x1000, 0x20),
+            code_cave(0x9000, 0x40),
+            code_cave(0x5000, 0x40),
+        ];
+
+        CodeCave::rank(&mut code_caves, Some(0x9100));
+
+        let ranked_addresses: Vec<u64> = code_caves
+            .iter()
+            .map(|code_cave| code_cave.get_base_address())
+            .collect();
+
+        assert_eq!(ranked_addresses, vec![0x9000, 0x5000, 0x1000]);
+        assert_eq!(code_caves[0].get_distance(0x9100), 0x9100 - 0x903F);
+        assert_eq!(code_caves[0].get_distance(0x9010), 0);
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The fill that a code cave was found in. Compilers and linkers pad between functions and at the end of executable sections
+/// with zeroes, int3 breakpoints, or no-operation instructions.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum CodeCavePadding {
+    Zero,
+    Breakpoint,
+    /// A run of one byte or multi-byte no-operation instructions, which may mix encodings of different lengths.
+    NoOperation,
+}
+
+impl fmt::Display for CodeCavePadding {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Zero => write!(formatter, "zero"),
+            Self::Breakpoint => write!(formatter, "int3"),
+            Self::NoOperation => write!(formatter, "nop"),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use crate::structures::patching::code_patch_definition::CodePatchDefinition;
+use serde::{Deserialize, Serialize};
+
+/// A code patch that has been written to a process, along with everything needed to undo it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CodePatch {
+    definition: CodePatchDefinition,
+    patch_address: u64,
+    original_bytes: Vec<u8>,
+    patched_bytes: Vec<u8>,
+    code_cave: Option<NormalizedRegion>,
+    /// The padding that the detour code replaced, when the code cave was found in a module rather than allocated.
+    #[serde(default)]
+    code_cave_original_bytes: Option<Vec<u8>>,
+}
+
+impl CodePatch {
+    pub fn new(
+        definition: CodePatchDefinition,
+        patch_address: u64,
+        original_bytes: Vec<u8>,
+        patched_bytes: Vec<u8>,
+        code_cave: Option<NormalizedRegion>,
+    ) -> Self {
+        Self {
+            definition,
+            patch_address,
+            original_bytes,
+            patched_bytes,
+            code_cave,
+            code_cave_original_bytes: None,
+        }
+    }
+
+    /// Marks the code cave as padding found in a module, which undo restores instead of freeing.
+    pub fn with_code_cave_original_bytes(
+        mut self,
+        code_cave_original_bytes: Vec<u8>,
+    ) -> Self {
+        self.code_cave_original_bytes = Some(code_cave_original_bytes);
+        self
+    }
+
+    pub fn get_definition(&self) -> &CodePatchDefinition {
+        &self.definition
+    }
+
+    /// Gets the absolute address that the patch was written to.
+    pub fn get_patch_address(&self) -> u64 {
+        self.patch_address
+    }
+
+    /// Gets the bytes that were at the patch address before the patch was applied.
+    pub fn get_original_bytes(&self) -> &[u8] {
+        &self.original_bytes
+    }
+
+    pub fn get_patched_bytes(&self) -> &[u8] {
+        &self.patched_bytes
+    }
+
+    /// Gets the memory holding detour code, if this patch owns any. This is either a remote allocation or module padding.
+    pub fn get_code_cave(&self) -> Option<&NormalizedRegion> {
+        self.code_cave.as_ref()
+    }
+
+    /// Gets the padding that the detour code replaced, if the code cave was found in a module rather than allocated.
+    pub fn get_code_cave_original_bytes(&self) -> Option<&[u8]> {
+        self.code_cave_original_bytes.as_deref()
+    }
+
+    pub fn get_patch_size(&self) -> u64 {
+        self.original_bytes.len() as u64
+    }
+
+    /// Determines whether this patch rewrites any byte in the given range.
+    pub fn overlaps(
+        &self,
+        address: u64,
+        size: u64,
+    ) -> bool {
+        address < self.patch_address.saturating_add(self.get_patch_size()) && self.patch_address < address.saturating_add(size)
+    }
+}
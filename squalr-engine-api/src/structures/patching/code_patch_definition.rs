@@ -0,0 +1,29 @@
+use crate::structures::patching::code_patch_kind::CodePatchKind;
+use serde::{Deserialize, Serialize};
+
+/// A persistable description of a code patch, independent of where it was last applied.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodePatchDefinition {
+    pub address: u64,
+    pub module_name: String,
+
+    /// The instruction set used to assemble and measure instructions. Empty selects one from the process bitness.
+    #[serde(default)]
+    pub instruction_set_id: String,
+    pub kind: CodePatchKind,
+}
+
+impl CodePatchDefinition {
+    pub fn new(
+        address: u64,
+        module_name: String,
+        kind: CodePatchKind,
+    ) -> Self {
+        Self {
+            address,
+            module_name,
+            instruction_set_id: String::new(),
+            kind,
+        }
+    }
+}
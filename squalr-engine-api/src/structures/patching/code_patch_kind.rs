@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Describes how the instructions at a patch address are rewritten.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CodePatchKind {
+    /// Fills at least `byte_count` bytes with no-operation instructions, extended to the next instruction boundary.
+    Nop { byte_count: u64 },
+    /// Overwrites instructions with the assembled source, padding the last overwritten instruction with no-operations.
+    Replace { assembly_source: String },
+    /// Jumps to a code cave that runs the assembled source and the relocated overwritten instructions, then jumps back.
+    Detour { assembly_source: String },
+}
+
+impl CodePatchKind {
+    pub fn get_assembly_source(&self) -> Option<&str> {
+        match self {
+            Self::Nop { .. } => None,
+            Self::Replace { assembly_source } | Self::Detour { assembly_source } => Some(assembly_source),
+        }
+    }
+}
+
+impl fmt::Display for CodePatchKind {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Nop { .. } => write!(formatter, "nop"),
+            Self::Replace { .. } => write!(formatter, "replace"),
+            Self::Detour { .. } => write!(formatter, "detour"),
+        }
+    }
+}
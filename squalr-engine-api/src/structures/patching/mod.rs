@@ -0,0 +1,5 @@
+pub mod code_cave;
+pub mod code_cave_padding;
+pub mod code_patch;
+pub mod code_patch_definition;
+pub mod code_patch_kind;
@@ -1,3 +1,5 @@
 pub mod opened_process_info;
 pub mod process_icon;
 pub mod process_info;
+pub mod thread_info;
+pub mod thread_state;
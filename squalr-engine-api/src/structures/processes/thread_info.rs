@@ -0,0 +1,72 @@
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use crate::structures::processes::thread_state::ThreadState;
+use serde::{Deserialize, Serialize};
+
+/// A thread of an opened process.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThreadInfo {
+    thread_id: u32,
+    name: String,
+    thread_state: ThreadState,
+    user_time_ms: u64,
+    kernel_time_ms: u64,
+    /// The stack mapping of the thread, when it can be located.
+    stack_region: Option<NormalizedRegion>,
+    /// Whether the thread is held by a suspend request. Threads that only block in the kernel are not suspended.
+    is_suspended: bool,
+}
+
+impl ThreadInfo {
+    pub fn new(
+        thread_id: u32,
+        name: String,
+        thread_state: ThreadState,
+        user_time_ms: u64,
+        kernel_time_ms: u64,
+        stack_region: Option<NormalizedRegion>,
+        is_suspended: bool,
+    ) -> Self {
+        Self {
+            thread_id,
+            name,
+            thread_state,
+            user_time_ms,
+            kernel_time_ms,
+            stack_region,
+            is_suspended,
+        }
+    }
+
+    pub fn get_thread_id(&self) -> u32 {
+        self.thread_id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_thread_state(&self) -> ThreadState {
+        self.thread_state
+    }
+
+    pub fn get_user_time_ms(&self) -> u64 {
+        self.user_time_ms
+    }
+
+    pub fn get_kernel_time_ms(&self) -> u64 {
+        self.kernel_time_ms
+    }
+
+    /// Gets the CPU time the thread has spent in both user and kernel mode.
+    pub fn get_cpu_time_ms(&self) -> u64 {
+        self.user_time_ms.saturating_add(self.kernel_time_ms)
+    }
+
+    pub fn get_stack_region(&self) -> Option<&NormalizedRegion> {
+        self.stack_region.as_ref()
+    }
+
+    pub fn get_is_suspended(&self) -> bool {
+        self.is_suspended
+    }
+}
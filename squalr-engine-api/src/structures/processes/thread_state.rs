@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The scheduling state of a thread, as reported by the OS.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ThreadState {
+    Running,
+    Sleeping,
+    /// Waiting on I/O that cannot be interrupted.
+    DiskSleep,
+    /// Stopped by a signal, such as `SIGSTOP`.
+    Stopped,
+    /// Stopped by a debugger.
+    TracingStop,
+    Zombie,
+    Dead,
+    Idle,
+    #[default]
+    Unknown,
+}
+
+impl ThreadState {
+    /// Maps the state letter of a `/proc/<pid>/task/<tid>/stat` entry.
+    pub fn from_proc_state(state: char) -> Self {
+        match state {
+            'R' => Self::Running,
+            'S' => Self::Sleeping,
+            'D' => Self::DiskSleep,
+            'T' => Self::Stopped,
+            't' => Self::TracingStop,
+            'Z' => Self::Zombie,
+            'X' | 'x' => Self::Dead,
+            'I' => Self::Idle,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for ThreadState {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let state_name = match self {
+            Self::Running => "running",
+            Self::Sleeping => "sleeping",
+            Self::DiskSleep => "disk sleep",
+            Self::Stopped => "stopped",
+            Self::TracingStop => "tracing stop",
+            Self::Zombie => "zombie",
+            Self::Dead => "dead",
+            Self::Idle => "idle",
+            Self::Unknown => "unknown",
+        };
+
+        write!(formatter, "{}", state_name)
+    }
+}
@@ -1,5 +1,6 @@
 pub mod project_item_type_address;
 pub mod project_item_type_address_target;
 pub mod project_item_type_directory;
+pub mod project_item_type_patch;
 pub mod project_item_type_pointer;
 pub mod project_item_type_script;
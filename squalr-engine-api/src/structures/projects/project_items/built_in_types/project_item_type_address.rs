@@ -1,17 +1,16 @@
-use crate::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
-use crate::registries::registry_context::RegistryContext;
-use crate::structures::processes::opened_process_info::OpenedProcessInfo;
 use crate::structures::projects::project_items::built_in_types::project_item_type_address_target::ProjectItemAddressTarget;
-use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
 use crate::structures::structs::symbolic_struct_ref::SymbolicStructRef;
 use crate::structures::{
     data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8,
     data_values::{anonymous_value_string_format::AnonymousValueStringFormat, data_value::DataValue},
-    projects::project_items::{project_item::ProjectItem, project_item_type::ProjectItemType, project_item_type_ref::ProjectItemTypeRef},
+    projects::project_items::{
+        project_item::ProjectItem,
+        project_item_type::{ProjectItemType, ProjectItemTypeServices},
+        project_item_type_ref::ProjectItemTypeRef,
+    },
     structs::valued_struct_field::ValuedStructFieldData,
 };
 use serde::{Deserialize, Serialize};
-use std::sync::{Arc, RwLock};
 
 #[derive(Serialize, Deserialize)]
 pub struct ProjectItemTypeAddress {}
@@ -23,10 +22,9 @@ impl ProjectItemType for ProjectItemTypeAddress {
 
     fn on_activated_changed(
         &self,
-        _engine_bindings: &Arc<RwLock<dyn EngineApiPrivilegedBindings>>,
-        _registry_context: &dyn RegistryContext,
-        _project_item: &ProjectItemRef,
-    ) {
+        _project_item: &ProjectItem,
+        _services: &dyn ProjectItemTypeServices,
+    ) -> Result<(), String> {
         /*
         let address = ProjectItemTypeAddress::get_field_address(project_item);
         let module_name = ProjectItemTypeAddress::get_field_module(project_item);
@@ -59,15 +57,15 @@ impl ProjectItemType for ProjectItemTypeAddress {
                 }
             }
         }*/
+
+        Ok(())
     }
 
     fn tick(
         &self,
-        _engine_bindings: &dyn EngineApiPrivilegedBindings,
-        _opened_process: &Option<OpenedProcessInfo>,
-        _registry_context: &dyn RegistryContext,
-        _project_item_ref: &ProjectItemRef,
-    ) {
+        _project_item: &ProjectItem,
+        _services: &dyn ProjectItemTypeServices,
+    ) -> Result<(), String> {
         /*
         let memory_read_request = MemoryReadRequest {
             address,
@@ -81,6 +79,8 @@ impl ProjectItemType for ProjectItemTypeAddress {
                 freeze_list_registry.set_address_frozen(pointer, read_valued_struct_bytes);
             }
         });*/
+
+        Ok(())
     }
 }
 
@@ -0,0 +1,104 @@
+use crate::structures::data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8;
+use crate::structures::patching::code_patch_definition::CodePatchDefinition;
+use crate::structures::projects::project_items::project_item::ProjectItem;
+use crate::structures::projects::project_items::project_item_type::{ProjectItemType, ProjectItemTypeServices};
+use crate::structures::projects::project_items::project_item_type_ref::ProjectItemTypeRef;
+use crate::structures::structs::valued_struct_field::ValuedStructFieldData;
+use serde::{Deserialize, Serialize};
+
+/// A code patch that is applied while the item is activated, and undone when it is deactivated.
+#[derive(Serialize, Deserialize)]
+pub struct ProjectItemTypePatch {}
+
+impl ProjectItemType for ProjectItemTypePatch {
+    fn get_project_item_type_id(&self) -> &str {
+        Self::PROJECT_ITEM_TYPE_ID
+    }
+
+    fn on_activated_changed(
+        &self,
+        _project_item: &ProjectItem,
+        _services: &dyn ProjectItemTypeServices,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn tick(
+        &self,
+        _project_item: &ProjectItem,
+        _services: &dyn ProjectItemTypeServices,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl ProjectItemTypePatch {
+    pub const PROJECT_ITEM_TYPE_ID: &str = "patch";
+    pub const DEFAULT_PROJECT_ITEM_NAME: &str = "New Patch";
+    pub const PROPERTY_CODE_PATCH: &str = "code_patch";
+
+    pub fn new_project_item(
+        name: &str,
+        code_patch_definition: &CodePatchDefinition,
+    ) -> ProjectItem {
+        let project_item_type_ref = ProjectItemTypeRef::new(Self::PROJECT_ITEM_TYPE_ID.to_string());
+        let project_item_name = if name.trim().is_empty() { Self::DEFAULT_PROJECT_ITEM_NAME } else { name };
+        let mut project_item = ProjectItem::new(project_item_type_ref, project_item_name);
+
+        Self::set_field_code_patch_definition(&mut project_item, code_patch_definition);
+
+        project_item
+    }
+
+    pub fn get_field_code_patch_definition(project_item: &ProjectItem) -> Option<CodePatchDefinition> {
+        let data_value = project_item
+            .get_properties()
+            .get_field(Self::PROPERTY_CODE_PATCH)
+            .and_then(|field| field.get_data_value())?;
+
+        serde_json::from_slice::<CodePatchDefinition>(data_value.get_value_bytes()).ok()
+    }
+
+    pub fn set_field_code_patch_definition(
+        project_item: &mut ProjectItem,
+        code_patch_definition: &CodePatchDefinition,
+    ) {
+        let serialized_code_patch_definition = match serde_json::to_string(code_patch_definition) {
+            Ok(serialized_code_patch_definition) => serialized_code_patch_definition,
+            Err(error) => {
+                log::error!("Failed to serialize project code patch: {}", error);
+                return;
+            }
+        };
+        let code_patch_data_value = DataTypeStringUtf8::get_value_from_primitive_string(&serialized_code_patch_definition);
+        let field_data = ValuedStructFieldData::Value(code_patch_data_value);
+
+        project_item
+            .get_properties_mut()
+            .set_field_data(Self::PROPERTY_CODE_PATCH, field_data, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectItemTypePatch;
+    use crate::structures::patching::{code_patch_definition::CodePatchDefinition, code_patch_kind::CodePatchKind};
+
+    #[test]
+    fn new_project_item_round_trips_code_patch_definition() {
+        let code_patch_definition = CodePatchDefinition::new(
+            0x1F2A,
+            String::from("game.exe"),
+            CodePatchKind::Detour {
+                assembly_source: String::from("mov eax, 99"),
+            },
+        );
+        let project_item = ProjectItemTypePatch::new_project_item("", &code_patch_definition);
+
+        assert_eq!(project_item.get_field_name(), ProjectItemTypePatch::DEFAULT_PROJECT_ITEM_NAME);
+        assert_eq!(
+            ProjectItemTypePatch::get_field_code_patch_definition(&project_item),
+            Some(code_patch_definition)
+        );
+    }
+}
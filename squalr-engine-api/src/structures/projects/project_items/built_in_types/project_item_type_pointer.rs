@@ -1,12 +1,8 @@
-use crate::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
-use crate::registries::registry_context::RegistryContext;
 use crate::structures::memory::{
     pointer::Pointer,
     pointer_chain_segment::{IntoPointerChainSegments, PointerChainSegment},
 };
-use crate::structures::processes::opened_process_info::OpenedProcessInfo;
-use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
-use crate::structures::projects::project_items::project_item_type::ProjectItemType;
+use crate::structures::projects::project_items::project_item_type::{ProjectItemType, ProjectItemTypeServices};
 use crate::structures::{
     data_types::built_in_types::{string::utf8::data_type_string_utf8::DataTypeStringUtf8, u64::data_type_u64::DataTypeU64},
     data_values::anonymous_value_string_format::AnonymousValueStringFormat,
@@ -17,7 +13,6 @@ use crate::structures::{
 };
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
-use std::sync::{Arc, RwLock};
 
 #[derive(Serialize, Deserialize)]
 pub struct ProjectItemTypePointer {}
@@ -293,20 +288,20 @@ impl ProjectItemType for ProjectItemTypePointer {
 
     fn on_activated_changed(
         &self,
-        _engine_bindings: &Arc<RwLock<dyn EngineApiPrivilegedBindings>>,
-        _registry_context: &dyn RegistryContext,
-        _project_item: &ProjectItemRef,
-    ) {
+        _project_item: &ProjectItem,
+        _services: &dyn ProjectItemTypeServices,
+    ) -> Result<(), String> {
         // JIRA: Implement.
+
+        Ok(())
     }
 
     fn tick(
         &self,
-        _engine_bindings: &dyn EngineApiPrivilegedBindings,
-        _opened_process: &Option<OpenedProcessInfo>,
-        _registry_context: &dyn RegistryContext,
-        _project_item: &ProjectItemRef,
-    ) {
+        _project_item: &ProjectItem,
+        _services: &dyn ProjectItemTypeServices,
+    ) -> Result<(), String> {
+        Ok(())
     }
 }
 
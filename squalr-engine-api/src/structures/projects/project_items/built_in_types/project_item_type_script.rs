@@ -1,12 +1,7 @@
-use crate::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
-use crate::registries::registry_context::RegistryContext;
-use crate::structures::processes::opened_process_info::OpenedProcessInfo;
 use crate::structures::projects::project_items::project_item::ProjectItem;
-use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
-use crate::structures::projects::project_items::project_item_type::ProjectItemType;
+use crate::structures::projects::project_items::project_item_type::{ProjectItemType, ProjectItemTypeServices};
 use crate::structures::projects::project_items::project_item_type_ref::ProjectItemTypeRef;
 use serde::{Deserialize, Serialize};
-use std::sync::{Arc, RwLock};
 
 #[derive(Serialize, Deserialize)]
 pub struct ProjectItemTypeScript {}
@@ -30,19 +25,18 @@ impl ProjectItemType for ProjectItemTypeScript {
 
     fn on_activated_changed(
         &self,
-        _engine_bindings: &Arc<RwLock<dyn EngineApiPrivilegedBindings>>,
-        _registry_context: &dyn RegistryContext,
-        _project_item_ref: &ProjectItemRef,
-    ) {
+        _project_item: &ProjectItem,
+        _services: &dyn ProjectItemTypeServices,
+    ) -> Result<(), String> {
+        Ok(())
     }
 
     fn tick(
         &self,
-        _engine_bindings: &dyn EngineApiPrivilegedBindings,
-        _opened_process: &Option<OpenedProcessInfo>,
-        _registry_context: &dyn RegistryContext,
-        _project_item_ref: &ProjectItemRef,
-    ) {
+        _project_item: &ProjectItem,
+        _services: &dyn ProjectItemTypeServices,
+    ) -> Result<(), String> {
+        Ok(())
     }
 }
 
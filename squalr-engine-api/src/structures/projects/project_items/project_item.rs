@@ -1,13 +1,10 @@
-use crate::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
-use crate::registries::registry_context::RegistryContext;
-use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use crate::structures::projects::project_items::project_item_type::{ProjectItemType, ProjectItemTypeServices};
 use crate::structures::{
     data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8,
     projects::project_items::project_item_type_ref::ProjectItemTypeRef,
     structs::{valued_struct::ValuedStruct, valued_struct_field::ValuedStructFieldData},
 };
 use serde::{Deserialize, Serialize};
-use std::sync::{Arc, RwLock};
 
 /// Represents a unique reference to a project item in an opened project.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -85,18 +82,13 @@ impl ProjectItem {
 
     pub fn set_activated(
         &mut self,
-        project_item_ref: &ProjectItemRef,
-        engine_bindings: &Arc<RwLock<dyn EngineApiPrivilegedBindings>>,
-        registry_context: &dyn RegistryContext,
+        project_item_type: &dyn ProjectItemType,
+        services: &dyn ProjectItemTypeServices,
         is_activated: bool,
-    ) {
+    ) -> Result<(), String> {
         self.is_activated = is_activated;
 
-        if let Ok(project_item_type_registry) = registry_context.get_project_item_type_registry().read() {
-            if let Some(project_item_type) = project_item_type_registry.get(self.item_type.get_project_item_type_id()) {
-                project_item_type.on_activated_changed(engine_bindings, registry_context, project_item_ref);
-            }
-        }
+        project_item_type.on_activated_changed(self, services)
     }
 
     pub fn get_display_string(&self) -> &str {
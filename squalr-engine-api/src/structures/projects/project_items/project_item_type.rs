@@ -1,21 +1,111 @@
-use crate::registries::registry_context::RegistryContext;
-use crate::structures::processes::opened_process_info::OpenedProcessInfo;
-use crate::{engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings, structures::projects::project_items::project_item_ref::ProjectItemRef};
-use std::sync::{Arc, RwLock};
+use crate::structures::projects::project_items::{project_item::ProjectItem, project_item_type_ref::ProjectItemTypeRef};
+
+/// Process access available to project item types while they react to activation changes or tick.
+pub trait ProjectItemTypeServices: Send + Sync {
+    fn read_memory(
+        &self,
+        address: u64,
+        module_name: &str,
+        length: u64,
+    ) -> Result<Vec<u8>, String>;
+
+    fn write_memory(
+        &self,
+        address: u64,
+        module_name: &str,
+        values: &[u8],
+    ) -> Result<(), String>;
+}
 
 pub trait ProjectItemType: Send + Sync {
     fn get_project_item_type_id(&self) -> &str;
+
+    /// Creates a new project item of this type. Types with their own properties seed them here, and those properties are
+    /// persisted with the item in the project file.
+    fn create_project_item(
+        &self,
+        name: &str,
+    ) -> ProjectItem {
+        ProjectItem::new(ProjectItemTypeRef::new(self.get_project_item_type_id().to_string()), name)
+    }
+
     fn on_activated_changed(
         &self,
-        engine_bindings: &Arc<RwLock<dyn EngineApiPrivilegedBindings>>,
-        registry_context: &dyn RegistryContext,
-        project_item_ref: &ProjectItemRef,
-    );
+        project_item: &ProjectItem,
+        services: &dyn ProjectItemTypeServices,
+    ) -> Result<(), String>;
+
     fn tick(
         &self,
-        engine_bindings: &dyn EngineApiPrivilegedBindings,
-        opened_process: &Option<OpenedProcessInfo>,
-        registry_context: &dyn RegistryContext,
-        project_item_ref: &ProjectItemRef,
-    );
+        project_item: &ProjectItem,
+        services: &dyn ProjectItemTypeServices,
+    ) -> Result<(), String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProjectItemType, ProjectItemTypeServices};
+    use crate::structures::{
+        data_types::built_in_types::f32::data_type_f32::DataTypeF32,
+        projects::project_items::{project_item::ProjectItem, project_item_type_ref::ProjectItemTypeRef},
+        structs::valued_struct_field::ValuedStructFieldData,
+    };
+
+    struct TeleportBookmarkProjectItemType;
+
+    impl ProjectItemType for TeleportBookmarkProjectItemType {
+        fn get_project_item_type_id(&self) -> &str {
+            "acme.teleport-bookmark"
+        }
+
+        fn create_project_item(
+            &self,
+            name: &str,
+        ) -> ProjectItem {
+            let mut project_item = ProjectItem::new(ProjectItemTypeRef::new(self.get_project_item_type_id().to_string()), name);
+
+            project_item
+                .get_properties_mut()
+                .set_field_data("x", ValuedStructFieldData::Value(DataTypeF32::get_value_from_primitive(12.5)), false);
+
+            project_item
+        }
+
+        fn on_activated_changed(
+            &self,
+            _project_item: &ProjectItem,
+            _services: &dyn ProjectItemTypeServices,
+        ) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn tick(
+            &self,
+            _project_item: &ProjectItem,
+            _services: &dyn ProjectItemTypeServices,
+        ) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn plugin_project_item_properties_round_trip_through_serialization() {
+        let project_item = TeleportBookmarkProjectItemType.create_project_item("Spawn");
+        let serialized_project_item = serde_json::to_string(&project_item).expect("Expected project item to serialize.");
+        let deserialized_project_item: ProjectItem = serde_json::from_str(&serialized_project_item).expect("Expected project item to deserialize.");
+        let x_value = deserialized_project_item
+            .get_properties()
+            .get_field("x")
+            .and_then(|field| field.get_data_value())
+            .expect("Expected the plugin property to survive serialization.");
+
+        assert_eq!(deserialized_project_item.get_field_name(), "Spawn");
+        assert_eq!(
+            deserialized_project_item
+                .get_item_type()
+                .get_project_item_type_id(),
+            "acme.teleport-bookmark"
+        );
+        assert_eq!(x_value.get_value_bytes(), &12.5_f32.to_le_bytes().to_vec());
+    }
 }
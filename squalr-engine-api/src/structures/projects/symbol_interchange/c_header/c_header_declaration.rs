@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CHeaderIntegerRank {
+    Char,
+    Short,
+    Int,
+    Long,
+    LongLong,
+    WideChar,
+    PointerSized,
+    Fixed(u64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CHeaderPrimitiveType {
+    Bool,
+    /// Plain `char`, which is kept distinct so that `char[N]` members can import as strings.
+    Character,
+    Integer {
+        integer_rank: CHeaderIntegerRank,
+        is_signed: bool,
+    },
+    Float,
+    Double,
+    LongDouble,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CHeaderType {
+    Void,
+    Primitive(CHeaderPrimitiveType),
+    Record(String),
+    Enum(String),
+    Typedef(String),
+    Pointer(Box<CHeaderType>),
+    Array(Box<CHeaderType>, Option<u64>),
+    Function,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CHeaderRecordKind {
+    Struct,
+    Union,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CHeaderRecordMember {
+    pub member_name: Option<String>,
+    pub member_type: CHeaderType,
+    pub bit_width: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CHeaderRecordDefinition {
+    /// Unique key for this record. Tagged records use their tag, anonymous records use a generated key.
+    pub record_key: String,
+    pub record_kind: CHeaderRecordKind,
+    pub tag_name: Option<String>,
+    /// The first typedef that names this record, used as the layout id for anonymous records.
+    pub typedef_name: Option<String>,
+    /// The enclosing record and member index, for anonymous records declared inline as members.
+    pub enclosing_record: Option<(String, usize)>,
+    pub members: Option<Vec<CHeaderRecordMember>>,
+    pub pack_alignment: Option<u64>,
+    pub explicit_alignment: Option<u64>,
+}
+
+impl CHeaderRecordDefinition {
+    pub fn is_defined(&self) -> bool {
+        self.members.is_some()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CHeaderEnumDefinition {
+    pub enum_key: String,
+    pub underlying_type: CHeaderPrimitiveType,
+    pub constants: Vec<(String, i128)>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CHeaderTranslationUnit {
+    pub records: Vec<CHeaderRecordDefinition>,
+    pub enums: Vec<CHeaderEnumDefinition>,
+    pub typedefs: BTreeMap<String, CHeaderType>,
+    pub constants: BTreeMap<String, i128>,
+    pub warnings: Vec<String>,
+}
+
+impl CHeaderTranslationUnit {
+    pub fn find_record(
+        &self,
+        record_key: &str,
+    ) -> Option<&CHeaderRecordDefinition> {
+        self.records
+            .iter()
+            .find(|record_definition| record_definition.record_key == record_key)
+    }
+
+    pub fn find_enum(
+        &self,
+        enum_key: &str,
+    ) -> Option<&CHeaderEnumDefinition> {
+        self.enums
+            .iter()
+            .find(|enum_definition| enum_definition.enum_key == enum_key)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::structures::memory::bitness::Bitness;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// The C data model used to size `long`, `wchar_t`, and `long double`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CHeaderDataModel {
+    /// System V data model, where `long` matches pointer width and `wchar_t` is 4 bytes.
+    Lp64,
+    /// Windows data model, where `long` is always 4 bytes and `wchar_t` is 2 bytes.
+    Llp64,
+}
+
+impl Default for CHeaderDataModel {
+    fn default() -> Self {
+        if cfg!(windows) { Self::Llp64 } else { Self::Lp64 }
+    }
+}
+
+impl Display for CHeaderDataModel {
+    fn fmt(
+        &self,
+        formatter: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Lp64 => write!(formatter, "lp64"),
+            Self::Llp64 => write!(formatter, "llp64"),
+        }
+    }
+}
+
+impl FromStr for CHeaderDataModel {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.trim().to_ascii_lowercase().as_str() {
+            "lp64" | "sysv" | "unix" => Ok(Self::Lp64),
+            "llp64" | "msvc" | "windows" => Ok(Self::Llp64),
+            _ => Err(format!("Unsupported data model: {string}. Expected one of: lp64, llp64, sysv, msvc.")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CHeaderImportOptions {
+    bitness: Bitness,
+    data_model: CHeaderDataModel,
+    layout_id_prefix: String,
+}
+
+impl CHeaderImportOptions {
+    pub fn new(
+        bitness: Bitness,
+        data_model: CHeaderDataModel,
+        layout_id_prefix: String,
+    ) -> Self {
+        Self {
+            bitness,
+            data_model,
+            layout_id_prefix,
+        }
+    }
+
+    pub fn get_bitness(&self) -> Bitness {
+        self.bitness
+    }
+
+    pub fn get_data_model(&self) -> CHeaderDataModel {
+        self.data_model
+    }
+
+    pub fn get_layout_id_prefix(&self) -> &str {
+        &self.layout_id_prefix
+    }
+
+    pub fn get_pointer_size_in_bytes(&self) -> u64 {
+        match self.bitness {
+            Bitness::Bit32 => 4,
+            Bitness::Bit64 => 8,
+        }
+    }
+}
+
+impl Default for CHeaderImportOptions {
+    fn default() -> Self {
+        Self::new(Bitness::Bit64, CHeaderDataModel::default(), String::new())
+    }
+}
@@ -0,0 +1,522 @@
+use crate::structures::{
+    data_values::pointer_scan_pointer_size::PointerScanPointerSize,
+    memory::bitness::Bitness,
+    projects::symbol_interchange::symbol_catalog_export::{
+        SymbolCatalogExport, SymbolExportElementCount, SymbolExportField, SymbolExportFieldOffset, SymbolExportLayout, SymbolExportPrimitive,
+        SymbolExportResult,
+    },
+};
+use std::collections::{HashMap, HashSet};
+
+const C_RESERVED_IDENTIFIERS: [&str; 45] = [
+    "auto", "bool", "break", "case", "char", "const", "continue", "default", "do", "double", "else", "enum", "extern", "false", "float", "for", "goto", "if",
+    "inline", "int", "long", "register", "restrict", "return", "short", "signed", "sizeof", "static", "struct", "switch", "true", "typedef", "union",
+    "unsigned", "void", "volatile", "while", "int8_t", "int16_t", "int32_t", "int64_t", "uint8_t", "uint16_t", "uint32_t", "uint64_t",
+];
+
+/// The C spelling of a field's element type, plus any trailing array dimension the element needs on its own.
+struct CHeaderElementType {
+    type_name: String,
+    element_dimension: Option<u64>,
+    notes: Vec<String>,
+}
+
+/// Exports a symbol catalog as a packed C header. Every layout is emitted as a typedef inside `#pragma pack(push, 1)`
+/// with explicit padding, so the header reproduces Squalr offsets regardless of the compiler's natural alignment.
+pub struct CHeaderLayoutExporter;
+
+impl CHeaderLayoutExporter {
+    pub fn export(
+        symbol_catalog_export: &SymbolCatalogExport,
+        bitness: Bitness,
+    ) -> SymbolExportResult {
+        let type_names = Self::build_type_names(symbol_catalog_export);
+        let mut warnings = Vec::new();
+        let mut source =
+            String::from("/* Generated by Squalr from the project symbol catalog. */\n#pragma once\n\n#include <stdbool.h>\n#include <stdint.h>\n\n");
+
+        for layout in symbol_catalog_export.get_layouts() {
+            let type_name = &type_names[layout.get_layout_id()];
+
+            source.push_str(&format!("typedef {} {} {};\n", Self::get_record_keyword(layout), type_name, type_name));
+        }
+
+        source.push_str("\n#pragma pack(push, 1)\n");
+
+        for layout in symbol_catalog_export.get_layouts_in_dependency_order() {
+            source.push('\n');
+            source.push_str(&Self::export_layout(layout, &type_names, bitness, &mut warnings));
+        }
+
+        source.push_str("\n#pragma pack(pop)\n");
+
+        SymbolExportResult::new(source, warnings)
+    }
+
+    fn export_layout(
+        layout: &SymbolExportLayout,
+        type_names: &HashMap<String, String>,
+        bitness: Bitness,
+        warnings: &mut Vec<String>,
+    ) -> String {
+        let mut member_lines = Vec::new();
+        let mut runtime_lines = Vec::new();
+        let mut field_identifiers = HashSet::new();
+        let mut field_cursor_in_bytes = 0_u64;
+        let mut has_flexible_array_member = false;
+        let is_union = layout.get_layout_kind().is_union();
+        let last_static_field_index = layout
+            .get_fields()
+            .iter()
+            .rposition(|field| field.get_static_offset().is_some());
+
+        for (field_index, field) in layout.get_fields().iter().enumerate() {
+            let field_identifier = Self::make_unique_identifier(field.get_field_name(), &mut field_identifiers);
+
+            match (field.get_static_offset(), field.get_static_size_in_bytes()) {
+                (Some(offset_in_bytes), Some(size_in_bytes)) if is_union && offset_in_bytes == 0 => {
+                    member_lines.push(Self::format_member_line(field, &field_identifier, offset_in_bytes, type_names, bitness));
+                    field_cursor_in_bytes = field_cursor_in_bytes.max(size_in_bytes);
+                }
+                (Some(offset_in_bytes), Some(size_in_bytes)) if !is_union && offset_in_bytes >= field_cursor_in_bytes => {
+                    Self::push_padding_line(&mut member_lines, field_cursor_in_bytes, offset_in_bytes);
+                    member_lines.push(Self::format_member_line(field, &field_identifier, offset_in_bytes, type_names, bitness));
+                    field_cursor_in_bytes = offset_in_bytes.saturating_add(size_in_bytes);
+                }
+                (Some(offset_in_bytes), Some(_)) => {
+                    warnings.push(format!(
+                        "Field `{}` in `{}` overlaps another field and was exported as a comment.",
+                        field.get_field_name(),
+                        layout.get_layout_id()
+                    ));
+                    runtime_lines.push(format!(
+                        "{} offset: 0x{:04X}, overlaps another field",
+                        Self::format_declaration(field, &field_identifier, type_names, bitness),
+                        offset_in_bytes
+                    ));
+                }
+                (Some(offset_in_bytes), None)
+                    if !is_union && Some(field_index) == last_static_field_index && !member_lines.is_empty() && offset_in_bytes >= field_cursor_in_bytes =>
+                {
+                    // A trailing runtime-sized array maps directly onto a C flexible array member.
+                    Self::push_padding_line(&mut member_lines, field_cursor_in_bytes, offset_in_bytes);
+                    member_lines.push(Self::format_member_line(field, &field_identifier, offset_in_bytes, type_names, bitness));
+                    field_cursor_in_bytes = offset_in_bytes;
+                    has_flexible_array_member = true;
+                }
+                (Some(offset_in_bytes), None) => {
+                    runtime_lines.push(format!(
+                        "{} offset: 0x{:04X}",
+                        Self::format_declaration(field, &field_identifier, type_names, bitness),
+                        offset_in_bytes
+                    ));
+                }
+                (None, _) => {
+                    let offset_description = match field.get_offset() {
+                        SymbolExportFieldOffset::Resolved(offset_expression) => format!("offset: {}", offset_expression),
+                        SymbolExportFieldOffset::FollowsDynamicField(field_name) => format!("offset: follows `{}`", field_name),
+                        SymbolExportFieldOffset::Static(offset_in_bytes) => format!("offset: 0x{:04X}", offset_in_bytes),
+                    };
+
+                    runtime_lines.push(format!(
+                        "{} {}",
+                        Self::format_declaration(field, &field_identifier, type_names, bitness),
+                        offset_description
+                    ));
+                }
+            }
+        }
+
+        if let Some(size_in_bytes) = layout.get_size_in_bytes() {
+            if is_union && size_in_bytes > field_cursor_in_bytes {
+                member_lines.push(format!("    /* 0x0000 */ uint8_t _size[{}];", size_in_bytes));
+                field_cursor_in_bytes = size_in_bytes;
+            } else if !has_flexible_array_member {
+                Self::push_padding_line(&mut member_lines, field_cursor_in_bytes, size_in_bytes);
+                field_cursor_in_bytes = field_cursor_in_bytes.max(size_in_bytes);
+            }
+        }
+
+        if member_lines.is_empty() {
+            member_lines.push(String::from("    uint8_t _reserved[1];"));
+        }
+
+        let mut layout_source = format!("/* `{}`", Self::escape_comment(layout.get_layout_id()));
+
+        match layout.get_size_in_bytes() {
+            Some(size_in_bytes) => layout_source.push_str(&format!(", 0x{:X} bytes", size_in_bytes)),
+            None => layout_source.push_str(&format!(", at least 0x{:X} bytes", field_cursor_in_bytes)),
+        }
+
+        if let Some(module_name) = layout.get_module_name() {
+            layout_source.push_str(&format!(", module `{}`", Self::escape_comment(module_name)));
+        }
+
+        layout_source.push_str(&format!(
+            " */\n{} {}\n{{\n",
+            Self::get_record_keyword(layout),
+            type_names[layout.get_layout_id()]
+        ));
+
+        for member_line in member_lines {
+            layout_source.push_str(&member_line);
+            layout_source.push('\n');
+        }
+
+        if !runtime_lines.is_empty() {
+            layout_source.push_str("    /* Runtime-resolved fields:\n");
+
+            for runtime_line in runtime_lines {
+                layout_source.push_str(&format!("     *   {}\n", Self::escape_comment(&runtime_line)));
+            }
+
+            layout_source.push_str("     */\n");
+        }
+
+        layout_source.push_str("};\n");
+
+        layout_source
+    }
+
+    fn format_member_line(
+        field: &SymbolExportField,
+        field_identifier: &str,
+        offset_in_bytes: u64,
+        type_names: &HashMap<String, String>,
+        bitness: Bitness,
+    ) -> String {
+        let mut member_line = format!(
+            "    /* 0x{:04X} */ {};",
+            offset_in_bytes,
+            Self::format_declaration(field, field_identifier, type_names, bitness)
+        );
+        let notes = Self::collect_field_notes(field, type_names, bitness);
+
+        if !notes.is_empty() {
+            member_line.push_str(&format!(" /* {} */", Self::escape_comment(&notes.join("; "))));
+        }
+
+        member_line
+    }
+
+    fn format_declaration(
+        field: &SymbolExportField,
+        field_identifier: &str,
+        type_names: &HashMap<String, String>,
+        bitness: Bitness,
+    ) -> String {
+        let count_dimension = match field.get_element_count() {
+            SymbolExportElementCount::Single => String::new(),
+            SymbolExportElementCount::Fixed(element_count) => format!("[{}]", element_count),
+            SymbolExportElementCount::Inferred | SymbolExportElementCount::Resolved(_) => String::from("[]"),
+        };
+
+        match field.get_pointer_size() {
+            Some(pointer_size) if pointer_size == PointerScanPointerSize::from_process_bitness(bitness) => {
+                let pointee_type_name = match Self::resolve_element_type(field, type_names) {
+                    CHeaderElementType {
+                        type_name,
+                        element_dimension: None,
+                        ..
+                    } => type_name,
+                    _ => String::from("void"),
+                };
+
+                format!("{} *{}{}", pointee_type_name, field_identifier, count_dimension)
+            }
+            Some(pointer_size) => {
+                let pointer_element_type = Self::resolve_primitive_element_type(
+                    SymbolExportPrimitive::from_data_type_id(pointer_size.to_data_type_ref().get_data_type_id()),
+                    pointer_size.get_size_in_bytes(),
+                );
+
+                Self::format_element_declaration(&pointer_element_type, field_identifier, &count_dimension)
+            }
+            None => Self::format_element_declaration(&Self::resolve_element_type(field, type_names), field_identifier, &count_dimension),
+        }
+    }
+
+    fn format_element_declaration(
+        element_type: &CHeaderElementType,
+        field_identifier: &str,
+        count_dimension: &str,
+    ) -> String {
+        match element_type.element_dimension {
+            Some(element_dimension) => format!("{} {}{}[{}]", element_type.type_name, field_identifier, count_dimension, element_dimension),
+            None => format!("{} {}{}", element_type.type_name, field_identifier, count_dimension),
+        }
+    }
+
+    fn collect_field_notes(
+        field: &SymbolExportField,
+        type_names: &HashMap<String, String>,
+        bitness: Bitness,
+    ) -> Vec<String> {
+        let mut notes = match field.get_pointer_size() {
+            Some(pointer_size) if pointer_size == PointerScanPointerSize::from_process_bitness(bitness) => Vec::new(),
+            Some(pointer_size) => vec![format!(
+                "{} pointer to `{}`",
+                pointer_size,
+                field.get_data_type_id()
+            )],
+            None => Self::resolve_element_type(field, type_names).notes,
+        };
+
+        match field.get_element_count() {
+            SymbolExportElementCount::Resolved(count_expression) => notes.push(format!("count: {}", count_expression)),
+            SymbolExportElementCount::Inferred => notes.push(String::from("count: inferred at runtime")),
+            SymbolExportElementCount::Single | SymbolExportElementCount::Fixed(_) => {}
+        }
+
+        if let Some(active_when_expression) = field.get_active_when_expression() {
+            notes.push(format!("active when: {}", active_when_expression));
+        }
+
+        notes
+    }
+
+    fn resolve_element_type(
+        field: &SymbolExportField,
+        type_names: &HashMap<String, String>,
+    ) -> CHeaderElementType {
+        if field.is_layout_reference()
+            && let Some(type_name) = type_names.get(field.get_data_type_id())
+        {
+            return CHeaderElementType {
+                type_name: type_name.clone(),
+                element_dimension: None,
+                notes: Vec::new(),
+            };
+        }
+
+        let element_size_in_bytes = field.get_element_size_in_bytes().unwrap_or(1);
+        let mut element_type = Self::resolve_primitive_element_type(SymbolExportPrimitive::from_data_type_id(field.get_data_type_id()), element_size_in_bytes);
+
+        if SymbolExportPrimitive::from_data_type_id(field.get_data_type_id()).is_none() {
+            element_type
+                .notes
+                .push(format!("data type `{}`", field.get_data_type_id()));
+        }
+
+        element_type
+    }
+
+    fn resolve_primitive_element_type(
+        symbol_export_primitive: Option<SymbolExportPrimitive>,
+        element_size_in_bytes: u64,
+    ) -> CHeaderElementType {
+        let (type_name, element_dimension) = match symbol_export_primitive {
+            Some(SymbolExportPrimitive::Integer { size_in_bytes, is_signed, .. }) if matches!(size_in_bytes, 1 | 2 | 4 | 8) => {
+                (format!("{}int{}_t", if is_signed { "" } else { "u" }, size_in_bytes * 8), None)
+            }
+            Some(SymbolExportPrimitive::Integer { size_in_bytes, .. }) => (String::from("uint8_t"), Some(size_in_bytes)),
+            Some(SymbolExportPrimitive::Float { size_in_bytes: 4, .. }) => (String::from("float"), None),
+            Some(SymbolExportPrimitive::Float { .. }) => (String::from("double"), None),
+            Some(SymbolExportPrimitive::Bool { size_in_bytes: 1 }) => (String::from("bool"), None),
+            Some(SymbolExportPrimitive::Bool { size_in_bytes }) if matches!(size_in_bytes, 2 | 4 | 8) => (format!("uint{}_t", size_in_bytes * 8), None),
+            Some(SymbolExportPrimitive::Bool { size_in_bytes }) => (String::from("uint8_t"), Some(size_in_bytes)),
+            Some(SymbolExportPrimitive::Text) => (String::from("char"), Some(element_size_in_bytes).filter(|size_in_bytes| *size_in_bytes > 1)),
+            None => (String::from("uint8_t"), Some(element_size_in_bytes).filter(|size_in_bytes| *size_in_bytes > 1)),
+        };
+        let mut notes = Vec::new();
+
+        if symbol_export_primitive.is_some_and(|symbol_export_primitive| symbol_export_primitive.is_big_endian()) {
+            notes.push(String::from("big-endian"));
+        }
+
+        if matches!(symbol_export_primitive, Some(SymbolExportPrimitive::Bool { size_in_bytes }) if size_in_bytes > 1) {
+            notes.push(String::from("bool"));
+        }
+
+        CHeaderElementType {
+            type_name,
+            element_dimension,
+            notes,
+        }
+    }
+
+    fn push_padding_line(
+        member_lines: &mut Vec<String>,
+        from_offset_in_bytes: u64,
+        to_offset_in_bytes: u64,
+    ) {
+        if to_offset_in_bytes > from_offset_in_bytes {
+            member_lines.push(format!(
+                "    /* 0x{:04X} */ uint8_t _pad_{:04X}[{}];",
+                from_offset_in_bytes,
+                from_offset_in_bytes,
+                to_offset_in_bytes - from_offset_in_bytes
+            ));
+        }
+    }
+
+    fn get_record_keyword(layout: &SymbolExportLayout) -> &'static str {
+        if layout.get_layout_kind().is_union() { "union" } else { "struct" }
+    }
+
+    fn build_type_names(symbol_catalog_export: &SymbolCatalogExport) -> HashMap<String, String> {
+        let mut used_identifiers = HashSet::new();
+
+        symbol_catalog_export
+            .get_layouts()
+            .iter()
+            .map(|layout| {
+                (
+                    layout.get_layout_id().to_string(),
+                    Self::make_unique_identifier(layout.get_layout_id(), &mut used_identifiers),
+                )
+            })
+            .collect()
+    }
+
+    fn make_unique_identifier(
+        name: &str,
+        used_identifiers: &mut HashSet<String>,
+    ) -> String {
+        let mut identifier = name
+            .chars()
+            .map(|character| {
+                if character.is_ascii_alphanumeric() || character == '_' {
+                    character
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+
+        if identifier.is_empty() {
+            identifier = String::from("unnamed");
+        }
+
+        if identifier.starts_with(|character: char| character.is_ascii_digit()) || C_RESERVED_IDENTIFIERS.contains(&identifier.as_str()) {
+            identifier.insert(0, '_');
+        }
+
+        let mut unique_identifier = identifier.clone();
+        let mut suffix = 2;
+
+        while !used_identifiers.insert(unique_identifier.clone()) {
+            unique_identifier = format!("{}_{}", identifier, suffix);
+            suffix += 1;
+        }
+
+        unique_identifier
+    }
+
+    fn escape_comment(text: &str) -> String {
+        text.replace("*/", "* /")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CHeaderLayoutExporter;
+    use crate::registries::symbols::{struct_layout_descriptor::StructLayoutDescriptor, symbolic_resolver_descriptor::SymbolicResolverDescriptor};
+    use crate::structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::{container_type::ContainerType, pointer_scan_pointer_size::PointerScanPointerSize},
+        memory::bitness::Bitness,
+        projects::{
+            project_symbol_catalog::ProjectSymbolCatalog,
+            symbol_interchange::{
+                c_header::{c_header_import_options::CHeaderImportOptions, c_header_layout_importer::CHeaderLayoutImporter},
+                symbol_catalog_export::SymbolCatalogExport,
+            },
+        },
+        structs::{
+            symbolic_field_definition::{SymbolicFieldCountResolution, SymbolicFieldDefinition, SymbolicFieldOffsetResolution},
+            symbolic_resolver_definition::{SymbolicResolverDefinition, SymbolicResolverNode},
+            symbolic_struct_definition::SymbolicStructDefinition,
+        },
+    };
+
+    fn resolve_test_data_type_size_in_bytes(data_type_ref: &DataTypeRef) -> Option<u64> {
+        match data_type_ref.get_data_type_id() {
+            "u8" | "i8" | "bool8" => Some(1),
+            "u16" | "i16" => Some(2),
+            "u32" | "i32" | "f32" | "u32be" => Some(4),
+            "u64" | "i64" | "f64" => Some(8),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn exports_padded_layouts_with_flexible_array_for_resolved_count() {
+        let project_symbol_catalog = ProjectSymbolCatalog::new_with_modules_resolvers_and_symbol_claims(
+            Vec::new(),
+            vec![StructLayoutDescriptor::new(
+                String::from("game.Player"),
+                SymbolicStructDefinition::new(
+                    String::from("game.Player"),
+                    vec![
+                        SymbolicFieldDefinition::new_named(String::from("health"), DataTypeRef::new("u32be"), ContainerType::None),
+                        SymbolicFieldDefinition::new_named(
+                            String::from("next"),
+                            DataTypeRef::new("game.Player"),
+                            ContainerType::Pointer(PointerScanPointerSize::Pointer64),
+                        )
+                        .with_offset_resolution(SymbolicFieldOffsetResolution::new_static(8)),
+                        SymbolicFieldDefinition::new_named(String::from("item_count"), DataTypeRef::new("u16"), ContainerType::None),
+                        SymbolicFieldDefinition::new_named_with_resolutions(
+                            String::from("items"),
+                            DataTypeRef::new("u32"),
+                            ContainerType::Array,
+                            SymbolicFieldCountResolution::new_resolver(String::from("player.item_count")),
+                            SymbolicFieldOffsetResolution::Sequential,
+                        ),
+                    ],
+                ),
+            )],
+            vec![SymbolicResolverDescriptor::new(
+                String::from("player.item_count"),
+                SymbolicResolverDefinition::new(SymbolicResolverNode::new_local_field(String::from("item_count"))),
+            )],
+            Vec::new(),
+        );
+        let symbol_catalog_export = SymbolCatalogExport::from_project_symbol_catalog(&project_symbol_catalog, resolve_test_data_type_size_in_bytes);
+        let export_result = CHeaderLayoutExporter::export(&symbol_catalog_export, Bitness::Bit64);
+        let source = export_result.get_contents();
+
+        assert!(source.contains("typedef struct game_Player game_Player;"));
+        assert!(source.contains("/* 0x0000 */ uint32_t health; /* big-endian */"));
+        assert!(source.contains("/* 0x0004 */ uint8_t _pad_0004[4];"));
+        assert!(source.contains("/* 0x0008 */ game_Player *next;"));
+        assert!(source.contains("/* 0x0012 */ uint32_t items[]; /* count: item_count */"));
+        assert!(export_result.get_warnings().is_empty());
+    }
+
+    #[test]
+    fn exported_header_round_trips_through_importer() {
+        let source = "struct Vector3 { float x; float y; float z; };\n\
+                      union Value { int32_t integer; double real; };\n\
+                      struct Entity { struct Vector3 position; int16_t flags; union Value value; struct Entity *owner; char name[12]; };";
+        let import_result = CHeaderLayoutImporter::import_source(source, &CHeaderImportOptions::default()).expect("Expected header import to succeed.");
+        let project_symbol_catalog = ProjectSymbolCatalog::new(import_result.take_struct_layout_descriptors());
+        let symbol_catalog_export = SymbolCatalogExport::from_project_symbol_catalog(&project_symbol_catalog, resolve_test_data_type_size_in_bytes);
+        let exported_source = CHeaderLayoutExporter::export(&symbol_catalog_export, Bitness::Bit64).take_contents();
+        let reimport_result =
+            CHeaderLayoutImporter::import_source(&exported_source, &CHeaderImportOptions::default()).expect("Expected exported header to parse.");
+        let reimported_catalog = ProjectSymbolCatalog::new(reimport_result.take_struct_layout_descriptors());
+        let reimported_export = SymbolCatalogExport::from_project_symbol_catalog(&reimported_catalog, resolve_test_data_type_size_in_bytes);
+
+        for layout in symbol_catalog_export.get_layouts() {
+            let reimported_layout = reimported_export
+                .find_layout(layout.get_layout_id())
+                .expect("Expected exported layout to be re-imported.");
+
+            assert_eq!(reimported_layout.get_size_in_bytes(), layout.get_size_in_bytes(), "{}", layout.get_layout_id());
+        }
+
+        let entity_layout = reimported_export
+            .find_layout("Entity")
+            .expect("Expected entity layout.");
+        let owner_field = entity_layout
+            .get_fields()
+            .iter()
+            .find(|field| field.get_field_name() == "owner")
+            .expect("Expected owner field.");
+
+        assert_eq!(owner_field.get_static_offset(), Some(24));
+        assert_eq!(owner_field.get_pointer_size(), Some(PointerScanPointerSize::Pointer64));
+    }
+}
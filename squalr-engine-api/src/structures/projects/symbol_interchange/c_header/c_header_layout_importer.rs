@@ -0,0 +1,873 @@
+use crate::registries::symbols::struct_layout_descriptor::StructLayoutDescriptor;
+use crate::structures::{
+    data_types::data_type_ref::DataTypeRef,
+    data_values::{container_type::ContainerType, pointer_scan_pointer_size::PointerScanPointerSize},
+    memory::bitness::Bitness,
+    projects::{
+        symbol_interchange::c_header::{
+            c_header_declaration::{CHeaderIntegerRank, CHeaderPrimitiveType, CHeaderRecordDefinition, CHeaderRecordKind, CHeaderTranslationUnit, CHeaderType},
+            c_header_import_options::{CHeaderDataModel, CHeaderImportOptions},
+            c_header_parser::CHeaderParser,
+        },
+        symbol_layouts::symbol_layout_field_materializer::{SymbolLayoutFieldMaterializer, SymbolLayoutPositionedField},
+    },
+    structs::{
+        symbolic_field_definition::SymbolicFieldDefinition,
+        symbolic_struct_definition::{SymbolicLayoutKind, SymbolicStructDefinition},
+    },
+};
+use std::collections::{HashMap, HashSet};
+
+const NULL_TERMINATED_STRING_DATA_TYPE_ID: &str = "string_utf8{null_terminated}";
+
+#[derive(Clone, Debug, Default)]
+pub struct CHeaderImportResult {
+    struct_layout_descriptors: Vec<StructLayoutDescriptor>,
+    warnings: Vec<String>,
+}
+
+impl CHeaderImportResult {
+    pub fn get_struct_layout_descriptors(&self) -> &[StructLayoutDescriptor] {
+        &self.struct_layout_descriptors
+    }
+
+    pub fn take_struct_layout_descriptors(self) -> Vec<StructLayoutDescriptor> {
+        self.struct_layout_descriptors
+    }
+
+    pub fn get_warnings(&self) -> &[String] {
+        &self.warnings
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CHeaderPositionedMember {
+    offset_in_bytes: u64,
+    size_in_bytes: u64,
+    field_definition: SymbolicFieldDefinition,
+}
+
+#[derive(Clone, Debug)]
+struct CHeaderRecordLayout {
+    size_in_bytes: u64,
+    alignment_in_bytes: u64,
+    positioned_members: Vec<CHeaderPositionedMember>,
+    /// Union layouts for storage units shared by several named bitfields, which give each member a field of its own.
+    bitfield_layout_descriptors: Vec<StructLayoutDescriptor>,
+}
+
+/// A named bitfield within a storage unit, with its position counted from the least significant bit of the unit.
+struct CHeaderBitfieldMember {
+    member_name: String,
+    bit_offset: u64,
+    bit_width: u64,
+}
+
+/// An open bitfield storage unit. Consecutive bitfields that share a storage size are merged into one unit, matching the
+/// MSVC allocation rule, which also agrees with the System V ABI for the common same-type case.
+struct CHeaderBitfieldUnit {
+    offset_in_bytes: u64,
+    size_in_bytes: u64,
+    used_bits: u64,
+    members: Vec<CHeaderBitfieldMember>,
+}
+
+/// Converts parsed C declarations into struct layout descriptors, computing C ABI offsets for the configured target.
+pub struct CHeaderLayoutImporter<'a> {
+    translation_unit: &'a CHeaderTranslationUnit,
+    options: &'a CHeaderImportOptions,
+    record_layouts: HashMap<String, CHeaderRecordLayout>,
+    records_in_progress: HashSet<String>,
+    warnings: Vec<String>,
+}
+
+impl<'a> CHeaderLayoutImporter<'a> {
+    pub fn import_source(
+        source_text: &str,
+        options: &CHeaderImportOptions,
+    ) -> Result<CHeaderImportResult, String> {
+        let translation_unit = CHeaderParser::parse(source_text)?;
+
+        Ok(CHeaderLayoutImporter::import_translation_unit(&translation_unit, options))
+    }
+
+    pub fn import_translation_unit(
+        translation_unit: &CHeaderTranslationUnit,
+        options: &CHeaderImportOptions,
+    ) -> CHeaderImportResult {
+        let mut importer = CHeaderLayoutImporter {
+            translation_unit,
+            options,
+            record_layouts: HashMap::new(),
+            records_in_progress: HashSet::new(),
+            warnings: translation_unit.warnings.clone(),
+        };
+        let mut struct_layout_descriptors = Vec::new();
+
+        for record_definition in &translation_unit.records {
+            if !record_definition.is_defined() {
+                continue;
+            }
+
+            match importer.build_struct_layout_descriptors(record_definition) {
+                Ok(record_layout_descriptors) => struct_layout_descriptors.extend(record_layout_descriptors),
+                Err(error) => importer
+                    .warnings
+                    .push(format!("Skipped `{}`: {}", importer.get_layout_id(&record_definition.record_key), error)),
+            }
+        }
+
+        CHeaderImportResult {
+            struct_layout_descriptors,
+            warnings: importer.warnings,
+        }
+    }
+
+    /// Builds the layout of a record, followed by the union layouts of any bitfield storage units it shares between members.
+    fn build_struct_layout_descriptors(
+        &mut self,
+        record_definition: &CHeaderRecordDefinition,
+    ) -> Result<Vec<StructLayoutDescriptor>, String> {
+        let struct_layout_id = self.get_layout_id(&record_definition.record_key);
+        let record_layout = self.compute_record_layout(&record_definition.record_key)?;
+        let layout_kind = match record_definition.record_kind {
+            CHeaderRecordKind::Struct => SymbolicLayoutKind::Struct,
+            CHeaderRecordKind::Union => SymbolicLayoutKind::Union,
+        };
+        let positioned_fields = record_layout
+            .positioned_members
+            .into_iter()
+            .map(|positioned_member| {
+                SymbolLayoutPositionedField::new(
+                    positioned_member.offset_in_bytes,
+                    positioned_member.size_in_bytes,
+                    positioned_member.field_definition,
+                )
+            })
+            .collect();
+        let field_definitions =
+            SymbolLayoutFieldMaterializer::materialize_positioned_fields(layout_kind, Some(record_layout.size_in_bytes), positioned_fields)?;
+
+        let mut struct_layout_descriptors = vec![StructLayoutDescriptor::new(
+            struct_layout_id.clone(),
+            SymbolicStructDefinition::new_with_layout_kind(struct_layout_id, layout_kind, field_definitions)
+                .with_declared_size_in_bytes(Some(record_layout.size_in_bytes)),
+        )];
+
+        struct_layout_descriptors.extend(record_layout.bitfield_layout_descriptors);
+
+        Ok(struct_layout_descriptors)
+    }
+
+    /// Resolves the layout id for a record. Tagged records use their tag, typedef'd anonymous records use the typedef
+    /// name, and anonymous members are named after their enclosing layout.
+    fn get_layout_id(
+        &self,
+        record_key: &str,
+    ) -> String {
+        let Some(record_definition) = self.translation_unit.find_record(record_key) else {
+            return self.apply_layout_id_prefix(record_key);
+        };
+
+        if let Some(layout_name) = record_definition
+            .tag_name
+            .as_ref()
+            .or(record_definition.typedef_name.as_ref())
+        {
+            return self.apply_layout_id_prefix(layout_name);
+        }
+
+        if let Some((enclosing_record_key, member_index)) = &record_definition.enclosing_record {
+            let member_name = self
+                .translation_unit
+                .find_record(enclosing_record_key)
+                .and_then(|enclosing_record| enclosing_record.members.as_ref())
+                .and_then(|members| members.get(*member_index))
+                .and_then(|member| member.member_name.clone())
+                .unwrap_or_else(|| Self::get_anonymous_member_name(*member_index));
+
+            return format!("{}.{}", self.get_layout_id(enclosing_record_key), member_name);
+        }
+
+        self.apply_layout_id_prefix(record_key)
+    }
+
+    fn apply_layout_id_prefix(
+        &self,
+        layout_name: &str,
+    ) -> String {
+        let layout_id_prefix = self.options.get_layout_id_prefix().trim().trim_end_matches('.');
+
+        if layout_id_prefix.is_empty() {
+            layout_name.to_string()
+        } else {
+            format!("{}.{}", layout_id_prefix, layout_name)
+        }
+    }
+
+    fn get_anonymous_member_name(member_index: usize) -> String {
+        format!("anonymous_{}", member_index)
+    }
+
+    fn compute_record_layout(
+        &mut self,
+        record_key: &str,
+    ) -> Result<CHeaderRecordLayout, String> {
+        if let Some(record_layout) = self.record_layouts.get(record_key) {
+            return Ok(record_layout.clone());
+        }
+
+        let Some(record_definition) = self.translation_unit.find_record(record_key) else {
+            return Err(format!("Unknown record `{}`.", record_key));
+        };
+        let Some(members) = record_definition.members.as_ref() else {
+            return Err(format!("Record `{}` is incomplete.", self.get_layout_id(record_key)));
+        };
+
+        if !self.records_in_progress.insert(record_key.to_string()) {
+            return Err(format!("Record `{}` contains itself by value.", self.get_layout_id(record_key)));
+        }
+
+        let is_union = record_definition.record_kind == CHeaderRecordKind::Union;
+        let pack_alignment = record_definition.pack_alignment;
+        let mut positioned_members = Vec::new();
+        let mut bitfield_layout_descriptors = Vec::new();
+        let mut record_alignment = 1_u64;
+        let mut next_offset = 0_u64;
+        let mut record_size = 0_u64;
+        let mut open_bitfield_unit: Option<CHeaderBitfieldUnit> = None;
+
+        for (member_index, member) in members.iter().enumerate() {
+            let (member_size, natural_alignment) = self.compute_size_and_alignment(&member.member_type)?;
+            let member_alignment = Self::apply_pack_alignment(natural_alignment, pack_alignment);
+
+            if let Some(bit_width) = member.bit_width {
+                if bit_width > member_size.saturating_mul(8) {
+                    return Err(format!("Bitfield width {} exceeds its storage type.", bit_width));
+                }
+
+                let fits_open_unit = open_bitfield_unit.as_ref().is_some_and(|bitfield_unit| {
+                    !is_union && bit_width > 0 && bitfield_unit.size_in_bytes == member_size && bitfield_unit.used_bits + bit_width <= member_size * 8
+                });
+
+                if !fits_open_unit && let Some(bitfield_unit) = open_bitfield_unit.take() {
+                    self.push_bitfield_unit(
+                        &record_definition.record_key,
+                        bitfield_unit,
+                        &mut positioned_members,
+                        &mut bitfield_layout_descriptors,
+                    )?;
+                }
+
+                if bit_width == 0 {
+                    continue;
+                }
+
+                if !fits_open_unit {
+                    let unit_offset = if is_union { 0 } else { Self::align_up(next_offset, member_alignment) };
+
+                    open_bitfield_unit = Some(CHeaderBitfieldUnit {
+                        offset_in_bytes: unit_offset,
+                        size_in_bytes: member_size,
+                        used_bits: 0,
+                        members: Vec::new(),
+                    });
+                    record_alignment = record_alignment.max(member_alignment);
+                    next_offset = unit_offset + member_size;
+                    record_size = record_size.max(next_offset);
+                }
+
+                if let Some(bitfield_unit) = open_bitfield_unit.as_mut() {
+                    if let Some(member_name) = &member.member_name {
+                        bitfield_unit.members.push(CHeaderBitfieldMember {
+                            member_name: member_name.clone(),
+                            bit_offset: bitfield_unit.used_bits,
+                            bit_width,
+                        });
+                    }
+
+                    bitfield_unit.used_bits += bit_width;
+                }
+
+                continue;
+            }
+
+            if let Some(bitfield_unit) = open_bitfield_unit.take() {
+                self.push_bitfield_unit(
+                    &record_definition.record_key,
+                    bitfield_unit,
+                    &mut positioned_members,
+                    &mut bitfield_layout_descriptors,
+                )?;
+            }
+
+            let member_offset = if is_union { 0 } else { Self::align_up(next_offset, member_alignment) };
+            let field_name = member
+                .member_name
+                .clone()
+                .unwrap_or_else(|| Self::get_anonymous_member_name(member_index));
+            let (data_type_ref, container_type) = self.map_field_type(&member.member_type)?;
+
+            positioned_members.push(CHeaderPositionedMember {
+                offset_in_bytes: member_offset,
+                size_in_bytes: member_size,
+                field_definition: SymbolicFieldDefinition::new_named(field_name, data_type_ref, container_type),
+            });
+            record_alignment = record_alignment.max(member_alignment);
+            next_offset = member_offset + member_size;
+            record_size = record_size.max(next_offset);
+        }
+
+        if let Some(bitfield_unit) = open_bitfield_unit.take() {
+            self.push_bitfield_unit(
+                &record_definition.record_key,
+                bitfield_unit,
+                &mut positioned_members,
+                &mut bitfield_layout_descriptors,
+            )?;
+        }
+
+        if let Some(explicit_alignment) = record_definition.explicit_alignment {
+            record_alignment = record_alignment.max(explicit_alignment);
+        }
+
+        let record_layout = CHeaderRecordLayout {
+            size_in_bytes: Self::align_up(record_size, record_alignment),
+            alignment_in_bytes: record_alignment,
+            positioned_members,
+            bitfield_layout_descriptors,
+        };
+
+        self.records_in_progress.remove(record_key);
+        self.record_layouts
+            .insert(record_key.to_string(), record_layout.clone());
+
+        Ok(record_layout)
+    }
+
+    /// Adds the storage field of a bitfield unit. A unit holding a single named member is stored under that name, while a
+    /// shared unit is stored as a union of whole-unit fields, one per member, so that every member name survives import.
+    fn push_bitfield_unit(
+        &mut self,
+        record_key: &str,
+        bitfield_unit: CHeaderBitfieldUnit,
+        positioned_members: &mut Vec<CHeaderPositionedMember>,
+        bitfield_layout_descriptors: &mut Vec<StructLayoutDescriptor>,
+    ) -> Result<(), String> {
+        // Padding-only units (unnamed bitfields) become unassigned bytes during materialization.
+        if bitfield_unit.members.is_empty() {
+            return Ok(());
+        }
+
+        let storage_data_type_ref = DataTypeRef::new(&format!("u{}", bitfield_unit.size_in_bytes * 8));
+        let member_names: Vec<&str> = bitfield_unit
+            .members
+            .iter()
+            .map(|bitfield_member| bitfield_member.member_name.as_str())
+            .collect();
+        let field_name = member_names.join("_");
+        let field_data_type_ref = if bitfield_unit.members.len() > 1 {
+            let record_layout_id = self.get_layout_id(record_key);
+            let bitfield_layout_id = format!("{}.{}", record_layout_id, field_name);
+            let member_fields = bitfield_unit
+                .members
+                .iter()
+                .map(|bitfield_member| {
+                    SymbolLayoutPositionedField::new(
+                        0,
+                        bitfield_unit.size_in_bytes,
+                        SymbolicFieldDefinition::new_named(bitfield_member.member_name.clone(), storage_data_type_ref.clone(), ContainerType::None),
+                    )
+                })
+                .collect();
+            let field_definitions =
+                SymbolLayoutFieldMaterializer::materialize_positioned_fields(SymbolicLayoutKind::Union, Some(bitfield_unit.size_in_bytes), member_fields)?;
+
+            self.warnings.push(format!(
+                "Bitfields {} in `{}` share storage field `{}`, where each member reads the whole unit.",
+                bitfield_unit
+                    .members
+                    .iter()
+                    .map(|bitfield_member| format!(
+                        "`{}` (bits {}..{})",
+                        bitfield_member.member_name,
+                        bitfield_member.bit_offset,
+                        bitfield_member.bit_offset + bitfield_member.bit_width
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                record_layout_id,
+                field_name
+            ));
+            bitfield_layout_descriptors.push(StructLayoutDescriptor::new(
+                bitfield_layout_id.clone(),
+                SymbolicStructDefinition::new_with_layout_kind(bitfield_layout_id.clone(), SymbolicLayoutKind::Union, field_definitions)
+                    .with_declared_size_in_bytes(Some(bitfield_unit.size_in_bytes)),
+            ));
+
+            DataTypeRef::new(&bitfield_layout_id)
+        } else {
+            storage_data_type_ref
+        };
+
+        positioned_members.push(CHeaderPositionedMember {
+            offset_in_bytes: bitfield_unit.offset_in_bytes,
+            size_in_bytes: bitfield_unit.size_in_bytes,
+            field_definition: SymbolicFieldDefinition::new_named(field_name, field_data_type_ref, ContainerType::None),
+        });
+
+        Ok(())
+    }
+
+    fn compute_size_and_alignment(
+        &mut self,
+        header_type: &CHeaderType,
+    ) -> Result<(u64, u64), String> {
+        match self.resolve_typedefs(header_type)? {
+            CHeaderType::Void => Err(String::from("Members cannot have type `void`.")),
+            CHeaderType::Function => Err(String::from("Members cannot have function type.")),
+            CHeaderType::Primitive(primitive_type) => Ok(self.get_primitive_size_and_alignment(primitive_type)),
+            CHeaderType::Pointer(_) => {
+                let pointer_size_in_bytes = self.options.get_pointer_size_in_bytes();
+
+                Ok((pointer_size_in_bytes, pointer_size_in_bytes))
+            }
+            CHeaderType::Array(element_type, element_count) => {
+                let (element_size, element_alignment) = self.compute_size_and_alignment(&element_type)?;
+
+                Ok((element_size.saturating_mul(element_count.unwrap_or(0)), element_alignment))
+            }
+            CHeaderType::Record(record_key) => {
+                let record_layout = self.compute_record_layout(&record_key)?;
+
+                Ok((record_layout.size_in_bytes, record_layout.alignment_in_bytes))
+            }
+            CHeaderType::Enum(enum_key) => Ok(self.get_primitive_size_and_alignment(self.get_enum_underlying_type(&enum_key))),
+            CHeaderType::Typedef(typedef_name) => Err(format!("Unknown type `{}`.", typedef_name)),
+        }
+    }
+
+    /// Maps a member type onto the data type and container used by symbolic fields. Arrays are flattened and pointers
+    /// keep their pointee type so that the symbol tree can follow them.
+    fn map_field_type(
+        &self,
+        header_type: &CHeaderType,
+    ) -> Result<(DataTypeRef, ContainerType), String> {
+        let pointer_size = PointerScanPointerSize::from_process_bitness(self.options.get_bitness());
+
+        match self.resolve_typedefs(header_type)? {
+            CHeaderType::Array(element_type, element_count) => {
+                let mut element_type = self.resolve_typedefs(&element_type)?;
+                let mut total_element_count = element_count;
+                let mut is_multidimensional = false;
+
+                while let CHeaderType::Array(inner_element_type, inner_element_count) = element_type {
+                    total_element_count = match (total_element_count, inner_element_count) {
+                        (Some(outer_count), Some(inner_count)) => Some(outer_count.saturating_mul(inner_count)),
+                        _ => None,
+                    };
+                    element_type = self.resolve_typedefs(&inner_element_type)?;
+                    is_multidimensional = true;
+                }
+
+                match element_type {
+                    CHeaderType::Pointer(pointee_type) => {
+                        let pointee_data_type_ref = self.map_pointee_type(&pointee_type)?;
+
+                        Ok(match total_element_count {
+                            Some(element_count) => (pointee_data_type_ref, ContainerType::PointerArrayFixed(pointer_size, element_count)),
+                            None => (pointee_data_type_ref, ContainerType::PointerArray(pointer_size)),
+                        })
+                    }
+                    CHeaderType::Primitive(CHeaderPrimitiveType::Character) if !is_multidimensional => Ok((
+                        DataTypeRef::new(NULL_TERMINATED_STRING_DATA_TYPE_ID),
+                        total_element_count.map_or(ContainerType::Array, ContainerType::ArrayFixed),
+                    )),
+                    other_element_type => {
+                        let (element_data_type_ref, element_container_type) = self.map_field_type(&other_element_type)?;
+                        let element_multiplier = match element_container_type {
+                            ContainerType::None => 1,
+                            ContainerType::ArrayFixed(inner_count) => inner_count,
+                            _ => return Err(String::from("Unsupported nested array element type.")),
+                        };
+
+                        Ok((
+                            element_data_type_ref,
+                            total_element_count.map_or(ContainerType::Array, |element_count| {
+                                ContainerType::ArrayFixed(element_count.saturating_mul(element_multiplier))
+                            }),
+                        ))
+                    }
+                }
+            }
+            CHeaderType::Pointer(pointee_type) => Ok((self.map_pointee_type(&pointee_type)?, ContainerType::Pointer(pointer_size))),
+            CHeaderType::Record(record_key) => Ok((DataTypeRef::new(&self.get_layout_id(&record_key)), ContainerType::None)),
+            CHeaderType::Enum(enum_key) => self.map_primitive_type(self.get_enum_underlying_type(&enum_key)),
+            CHeaderType::Primitive(primitive_type) => self.map_primitive_type(primitive_type),
+            CHeaderType::Void | CHeaderType::Function => Err(String::from("Members cannot have `void` or function type.")),
+            CHeaderType::Typedef(typedef_name) => Err(format!("Unknown type `{}`.", typedef_name)),
+        }
+    }
+
+    fn map_pointee_type(
+        &self,
+        pointee_type: &CHeaderType,
+    ) -> Result<DataTypeRef, String> {
+        match self.resolve_typedefs(pointee_type) {
+            Ok(CHeaderType::Void) | Ok(CHeaderType::Function) | Err(_) => Ok(DataTypeRef::new("u8")),
+            Ok(CHeaderType::Pointer(_)) => Ok(self.get_pointer_sized_data_type_ref()),
+            Ok(CHeaderType::Record(record_key)) => {
+                if self
+                    .translation_unit
+                    .find_record(&record_key)
+                    .is_some_and(CHeaderRecordDefinition::is_defined)
+                {
+                    Ok(DataTypeRef::new(&self.get_layout_id(&record_key)))
+                } else {
+                    Ok(DataTypeRef::new("u8"))
+                }
+            }
+            Ok(resolved_pointee_type) => match self.map_field_type(&resolved_pointee_type) {
+                Ok((pointee_data_type_ref, ContainerType::None | ContainerType::Array | ContainerType::ArrayFixed(_))) => Ok(pointee_data_type_ref),
+                _ => Ok(self.get_pointer_sized_data_type_ref()),
+            },
+        }
+    }
+
+    fn map_primitive_type(
+        &self,
+        primitive_type: CHeaderPrimitiveType,
+    ) -> Result<(DataTypeRef, ContainerType), String> {
+        let (size_in_bytes, _) = self.get_primitive_size_and_alignment(primitive_type);
+
+        Ok(match primitive_type {
+            CHeaderPrimitiveType::Bool => (DataTypeRef::new("bool8"), ContainerType::None),
+            CHeaderPrimitiveType::Character => (DataTypeRef::new("i8"), ContainerType::None),
+            CHeaderPrimitiveType::Integer { is_signed, .. } => {
+                if !matches!(size_in_bytes, 1 | 2 | 4 | 8) {
+                    return Err(format!("Unsupported {}-byte integer type.", size_in_bytes));
+                }
+
+                (
+                    DataTypeRef::new(&format!("{}{}", if is_signed { "i" } else { "u" }, size_in_bytes * 8)),
+                    ContainerType::None,
+                )
+            }
+            CHeaderPrimitiveType::Float => (DataTypeRef::new("f32"), ContainerType::None),
+            CHeaderPrimitiveType::Double => (DataTypeRef::new("f64"), ContainerType::None),
+            // There is no extended precision float type, so wide `long double` values import as raw bytes.
+            CHeaderPrimitiveType::LongDouble if size_in_bytes == 8 => (DataTypeRef::new("f64"), ContainerType::None),
+            CHeaderPrimitiveType::LongDouble => (DataTypeRef::new("u8"), ContainerType::ArrayFixed(size_in_bytes)),
+        })
+    }
+
+    fn get_pointer_sized_data_type_ref(&self) -> DataTypeRef {
+        PointerScanPointerSize::from_process_bitness(self.options.get_bitness()).to_data_type_ref()
+    }
+
+    fn get_primitive_size_and_alignment(
+        &self,
+        primitive_type: CHeaderPrimitiveType,
+    ) -> (u64, u64) {
+        let pointer_size_in_bytes = self.options.get_pointer_size_in_bytes();
+        let data_model = self.options.get_data_model();
+        let size_in_bytes = match primitive_type {
+            CHeaderPrimitiveType::Bool | CHeaderPrimitiveType::Character => 1,
+            CHeaderPrimitiveType::Float => 4,
+            CHeaderPrimitiveType::Double => 8,
+            CHeaderPrimitiveType::LongDouble => match (data_model, self.options.get_bitness()) {
+                (CHeaderDataModel::Llp64, _) => 8,
+                (CHeaderDataModel::Lp64, Bitness::Bit32) => 12,
+                (CHeaderDataModel::Lp64, Bitness::Bit64) => 16,
+            },
+            CHeaderPrimitiveType::Integer { integer_rank, .. } => match integer_rank {
+                CHeaderIntegerRank::Char => 1,
+                CHeaderIntegerRank::Short => 2,
+                CHeaderIntegerRank::Int => 4,
+                CHeaderIntegerRank::Long => match data_model {
+                    CHeaderDataModel::Llp64 => 4,
+                    CHeaderDataModel::Lp64 => pointer_size_in_bytes,
+                },
+                CHeaderIntegerRank::LongLong => 8,
+                CHeaderIntegerRank::WideChar => match data_model {
+                    CHeaderDataModel::Llp64 => 2,
+                    CHeaderDataModel::Lp64 => 4,
+                },
+                CHeaderIntegerRank::PointerSized => pointer_size_in_bytes,
+                CHeaderIntegerRank::Fixed(size_in_bytes) => size_in_bytes,
+            },
+        };
+
+        // The i386 System V ABI aligns 8-byte and larger scalars to 4 bytes inside records.
+        let alignment_in_bytes = if data_model == CHeaderDataModel::Lp64 && self.options.get_bitness() == Bitness::Bit32 {
+            size_in_bytes.min(4)
+        } else {
+            size_in_bytes.min(16)
+        };
+
+        (size_in_bytes, alignment_in_bytes)
+    }
+
+    fn get_enum_underlying_type(
+        &self,
+        enum_key: &str,
+    ) -> CHeaderPrimitiveType {
+        self.translation_unit
+            .find_enum(enum_key)
+            .map(|enum_definition| enum_definition.underlying_type)
+            .unwrap_or(CHeaderPrimitiveType::Integer {
+                integer_rank: CHeaderIntegerRank::Int,
+                is_signed: true,
+            })
+    }
+
+    fn resolve_typedefs(
+        &self,
+        header_type: &CHeaderType,
+    ) -> Result<CHeaderType, String> {
+        let mut resolved_type = header_type.clone();
+        let mut visited_typedef_names = HashSet::new();
+
+        while let CHeaderType::Typedef(typedef_name) = &resolved_type {
+            if !visited_typedef_names.insert(typedef_name.clone()) {
+                return Err(format!("Typedef `{}` refers to itself.", typedef_name));
+            }
+
+            resolved_type = match self.translation_unit.typedefs.get(typedef_name) {
+                Some(aliased_type) => aliased_type.clone(),
+                // `struct Foo` declared without a typedef is still commonly referred to as `Foo` in C++ style headers.
+                None if self.translation_unit.find_record(typedef_name).is_some() => CHeaderType::Record(typedef_name.clone()),
+                None if self.translation_unit.find_enum(typedef_name).is_some() => CHeaderType::Enum(typedef_name.clone()),
+                None => return Err(format!("Unknown type `{}`.", typedef_name)),
+            };
+        }
+
+        Ok(resolved_type)
+    }
+
+    fn apply_pack_alignment(
+        natural_alignment: u64,
+        pack_alignment: Option<u64>,
+    ) -> u64 {
+        match pack_alignment {
+            Some(pack_alignment) if pack_alignment > 0 => natural_alignment.min(pack_alignment),
+            _ => natural_alignment,
+        }
+        .max(1)
+    }
+
+    fn align_up(
+        offset: u64,
+        alignment: u64,
+    ) -> u64 {
+        if alignment <= 1 {
+            return offset;
+        }
+
+        offset.div_ceil(alignment) * alignment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CHeaderLayoutImporter;
+    use crate::registries::symbols::struct_layout_descriptor::StructLayoutDescriptor;
+    use crate::structures::{
+        data_values::{container_type::ContainerType, pointer_scan_pointer_size::PointerScanPointerSize},
+        memory::bitness::Bitness,
+        projects::symbol_interchange::c_header::c_header_import_options::{CHeaderDataModel, CHeaderImportOptions},
+        structs::symbolic_struct_definition::SymbolicLayoutKind,
+    };
+
+    fn find_descriptor<'a>(
+        struct_layout_descriptors: &'a [StructLayoutDescriptor],
+        struct_layout_id: &str,
+    ) -> &'a StructLayoutDescriptor {
+        struct_layout_descriptors
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == struct_layout_id)
+            .unwrap_or_else(|| panic!("Expected `{}` layout.", struct_layout_id))
+    }
+
+    fn describe_fields(struct_layout_descriptor: &StructLayoutDescriptor) -> Vec<String> {
+        struct_layout_descriptor
+            .get_struct_layout_definition()
+            .get_fields()
+            .iter()
+            .map(|field_definition| match field_definition.get_unassigned_size_in_bytes() {
+                Some(unassigned_size_in_bytes) => format!("<{}>", unassigned_size_in_bytes),
+                None => format!(
+                    "{}:{}:{}",
+                    field_definition.get_field_name(),
+                    field_definition.get_data_type_ref().get_data_type_id(),
+                    field_definition.get_container_type()
+                ),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn imports_aligned_struct_with_pointers_and_arrays() {
+        let import_result = CHeaderLayoutImporter::import_source(
+            r#"
+            typedef struct Vector3 { float x, y, z; } Vector3;
+            struct Player {
+                char tag;
+                Vector3 position;
+                struct Player *next;
+                char name[16];
+                uint16_t ammo[2][3];
+                long score;
+            };
+            "#,
+            &CHeaderImportOptions::new(Bitness::Bit64, CHeaderDataModel::Lp64, String::from("game")),
+        )
+        .expect("Expected import to succeed.");
+        let struct_layout_descriptors = import_result.get_struct_layout_descriptors();
+        let player_descriptor = find_descriptor(struct_layout_descriptors, "game.Player");
+
+        assert_eq!(
+            describe_fields(player_descriptor),
+            vec![
+                String::from("tag:i8:"),
+                String::from("<3>"),
+                String::from("position:game.Vector3:"),
+                String::from("next:game.Player:*(u64)"),
+                String::from("name:string_utf8{null_terminated}:[16]"),
+                String::from("ammo:u16:[6]"),
+                String::from("<4>"),
+                String::from("score:i64:"),
+            ]
+        );
+        assert_eq!(
+            player_descriptor
+                .get_struct_layout_definition()
+                .get_declared_size_in_bytes(),
+            Some(64)
+        );
+        assert!(import_result.get_warnings().is_empty(), "{:?}", import_result.get_warnings());
+    }
+
+    #[test]
+    fn honors_bitness_data_model_and_pack() {
+        let source_text = r#"
+            #pragma pack(push, 1)
+            struct Packed { uint8_t kind; void *owner; long value; };
+            #pragma pack(pop)
+            struct Natural { uint8_t kind; void *owner; long value; };
+            "#;
+        let import_result =
+            CHeaderLayoutImporter::import_source(source_text, &CHeaderImportOptions::new(Bitness::Bit32, CHeaderDataModel::Llp64, String::new()))
+                .expect("Expected import to succeed.");
+        let packed_descriptor = find_descriptor(import_result.get_struct_layout_descriptors(), "Packed");
+        let natural_descriptor = find_descriptor(import_result.get_struct_layout_descriptors(), "Natural");
+
+        assert_eq!(
+            packed_descriptor
+                .get_struct_layout_definition()
+                .get_declared_size_in_bytes(),
+            Some(9)
+        );
+        assert_eq!(
+            natural_descriptor
+                .get_struct_layout_definition()
+                .get_declared_size_in_bytes(),
+            Some(12)
+        );
+        assert_eq!(
+            natural_descriptor.get_struct_layout_definition().get_fields()[2].get_container_type(),
+            ContainerType::Pointer(PointerScanPointerSize::Pointer32)
+        );
+    }
+
+    #[test]
+    fn imports_unions_enums_and_nested_anonymous_records() {
+        let import_result = CHeaderLayoutImporter::import_source(
+            r#"
+            enum Kind : uint16_t { KIND_INT, KIND_FLOAT };
+            typedef struct {
+                enum Kind kind;
+                union { int32_t as_int; double as_double; };
+                struct { int16_t x, y; } point;
+            } Value;
+            "#,
+            &CHeaderImportOptions::new(Bitness::Bit64, CHeaderDataModel::Llp64, String::new()),
+        )
+        .expect("Expected import to succeed.");
+        let struct_layout_descriptors = import_result.get_struct_layout_descriptors();
+        let union_descriptor = find_descriptor(struct_layout_descriptors, "Value.anonymous_1");
+
+        assert_eq!(
+            union_descriptor
+                .get_struct_layout_definition()
+                .get_layout_kind(),
+            SymbolicLayoutKind::Union
+        );
+        assert_eq!(
+            describe_fields(union_descriptor),
+            vec![String::from("as_double:f64:"), String::from("as_int:i32:")]
+        );
+        assert_eq!(
+            describe_fields(find_descriptor(struct_layout_descriptors, "Value")),
+            vec![
+                String::from("kind:u16:"),
+                String::from("<6>"),
+                String::from("anonymous_1:Value.anonymous_1:"),
+                String::from("point:Value.point:"),
+                String::from("<4>"),
+            ]
+        );
+    }
+
+    #[test]
+    fn merges_bitfields_into_storage_units() {
+        let import_result = CHeaderLayoutImporter::import_source(
+            "struct Flags { uint32_t alive : 1; uint32_t poisoned : 1; uint32_t : 0; uint32_t level : 7; uint8_t tail; };",
+            &CHeaderImportOptions::default(),
+        )
+        .expect("Expected import to succeed.");
+        let flags_descriptor = find_descriptor(import_result.get_struct_layout_descriptors(), "Flags");
+
+        assert_eq!(
+            describe_fields(flags_descriptor),
+            vec![
+                String::from("alive_poisoned:Flags.alive_poisoned:"),
+                String::from("level:u32:"),
+                String::from("tail:u8:"),
+                String::from("<3>"),
+            ]
+        );
+        assert_eq!(import_result.get_warnings().len(), 1);
+    }
+
+    #[test]
+    fn keeps_every_member_of_a_shared_bitfield_unit() {
+        let import_result = CHeaderLayoutImporter::import_source(
+            "struct Flags { uint16_t alive : 1; uint16_t poisoned : 1; uint16_t : 2; uint16_t level : 4; };",
+            &CHeaderImportOptions::default(),
+        )
+        .expect("Expected import to succeed.");
+        let bitfield_descriptor = find_descriptor(import_result.get_struct_layout_descriptors(), "Flags.alive_poisoned_level");
+
+        assert_eq!(
+            bitfield_descriptor
+                .get_struct_layout_definition()
+                .get_layout_kind(),
+            SymbolicLayoutKind::Union
+        );
+        assert_eq!(
+            describe_fields(bitfield_descriptor),
+            vec![
+                String::from("alive:u16:"),
+                String::from("level:u16:"),
+                String::from("poisoned:u16:")
+            ]
+        );
+        assert_eq!(
+            import_result.get_warnings(),
+            [String::from(
+                "Bitfields `alive` (bits 0..1), `poisoned` (bits 1..2), `level` (bits 4..8) in `Flags` share storage field `alive_poisoned_level`, where each member reads the whole unit."
+            )]
+        );
+    }
+}
@@ -0,0 +1,1384 @@
+use crate::structures::projects::symbol_interchange::c_header::{
+    c_header_declaration::{
+        CHeaderEnumDefinition, CHeaderIntegerRank, CHeaderPrimitiveType, CHeaderRecordDefinition, CHeaderRecordKind, CHeaderRecordMember,
+        CHeaderTranslationUnit, CHeaderType,
+    },
+    c_header_tokenizer::{CHeaderPackDirective, CHeaderToken, CHeaderTokenizer},
+};
+
+const IGNORED_QUALIFIERS: [&str; 24] = [
+    "const",
+    "volatile",
+    "restrict",
+    "__restrict",
+    "__restrict__",
+    "register",
+    "static",
+    "extern",
+    "inline",
+    "__inline",
+    "__inline__",
+    "__forceinline",
+    "mutable",
+    "__unaligned",
+    "__ptr32",
+    "__ptr64",
+    "_Atomic",
+    "__cdecl",
+    "__stdcall",
+    "__fastcall",
+    "__thiscall",
+    "__vectorcall",
+    "WINAPI",
+    "CALLBACK",
+];
+
+#[derive(Clone, Copy, Debug, Default)]
+struct CHeaderAttributes {
+    is_packed: bool,
+    alignment: Option<u64>,
+}
+
+impl CHeaderAttributes {
+    fn merge(
+        &mut self,
+        other_attributes: CHeaderAttributes,
+    ) {
+        self.is_packed |= other_attributes.is_packed;
+        self.alignment = self.alignment.max(other_attributes.alignment);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct CHeaderPrimitiveSpecifiers {
+    signedness: Option<bool>,
+    short_count: u32,
+    long_count: u32,
+    has_int: bool,
+    has_char: bool,
+    has_float: bool,
+    has_double: bool,
+    has_bool: bool,
+    has_void: bool,
+    has_wide_char: bool,
+    fixed_size_in_bytes: Option<u64>,
+}
+
+impl CHeaderPrimitiveSpecifiers {
+    fn is_empty(&self) -> bool {
+        self.signedness.is_none()
+            && self.short_count == 0
+            && self.long_count == 0
+            && !self.has_int
+            && !self.has_char
+            && !self.has_float
+            && !self.has_double
+            && !self.has_bool
+            && !self.has_void
+            && !self.has_wide_char
+            && self.fixed_size_in_bytes.is_none()
+    }
+
+    fn to_header_type(self) -> CHeaderType {
+        let is_signed = self.signedness.unwrap_or(true);
+
+        if self.has_void {
+            return CHeaderType::Void;
+        }
+
+        if self.has_bool {
+            return CHeaderType::Primitive(CHeaderPrimitiveType::Bool);
+        }
+
+        if self.has_float {
+            return CHeaderType::Primitive(CHeaderPrimitiveType::Float);
+        }
+
+        if self.has_double {
+            return CHeaderType::Primitive(if self.long_count > 0 {
+                CHeaderPrimitiveType::LongDouble
+            } else {
+                CHeaderPrimitiveType::Double
+            });
+        }
+
+        let integer_rank = if let Some(fixed_size_in_bytes) = self.fixed_size_in_bytes {
+            CHeaderIntegerRank::Fixed(fixed_size_in_bytes)
+        } else if self.has_char {
+            if self.signedness.is_none() {
+                return CHeaderType::Primitive(CHeaderPrimitiveType::Character);
+            }
+
+            CHeaderIntegerRank::Char
+        } else if self.has_wide_char {
+            CHeaderIntegerRank::WideChar
+        } else if self.short_count > 0 {
+            CHeaderIntegerRank::Short
+        } else if self.long_count >= 2 {
+            CHeaderIntegerRank::LongLong
+        } else if self.long_count == 1 {
+            CHeaderIntegerRank::Long
+        } else {
+            CHeaderIntegerRank::Int
+        };
+
+        CHeaderType::Primitive(CHeaderPrimitiveType::Integer {
+            integer_rank,
+            is_signed: if self.has_wide_char && self.signedness.is_none() { false } else { is_signed },
+        })
+    }
+}
+
+/// Parses the declaration subset of C needed to recover data layouts: records, enums, typedefs, `#define` constants, and
+/// `#pragma pack` state. Function prototypes, function bodies, and globals are skipped.
+pub struct CHeaderParser {
+    tokens: Vec<CHeaderToken>,
+    token_index: usize,
+    translation_unit: CHeaderTranslationUnit,
+    pack_stack: Vec<Option<u64>>,
+    current_pack_alignment: Option<u64>,
+    anonymous_record_count: usize,
+    anonymous_enum_count: usize,
+}
+
+impl CHeaderParser {
+    pub fn parse(source_text: &str) -> Result<CHeaderTranslationUnit, String> {
+        let mut parser = Self {
+            tokens: CHeaderTokenizer::tokenize(source_text)?,
+            token_index: 0,
+            translation_unit: CHeaderTranslationUnit::default(),
+            pack_stack: Vec::new(),
+            current_pack_alignment: None,
+            anonymous_record_count: 0,
+            anonymous_enum_count: 0,
+        };
+
+        parser.parse_translation_unit();
+
+        Ok(parser.translation_unit)
+    }
+
+    fn parse_translation_unit(&mut self) {
+        while let Some(token) = self.peek().cloned() {
+            match token {
+                CHeaderToken::Pack(pack_directive) => {
+                    self.apply_pack_directive(&pack_directive);
+                    self.advance();
+                }
+                CHeaderToken::Define { name, value_tokens } => {
+                    if let Ok(constant_value) = self.evaluate_constant_expression(&value_tokens) {
+                        self.translation_unit.constants.insert(name, constant_value);
+                    }
+                    self.advance();
+                }
+                CHeaderToken::Punctuator(";") | CHeaderToken::Punctuator("}") => self.advance(),
+                CHeaderToken::Identifier(identifier) if identifier == "extern" && matches!(self.peek_at(1), Some(CHeaderToken::StringLiteral(_))) => {
+                    self.advance();
+                    self.advance();
+                    if self.peek_is_punctuator("{") {
+                        self.advance();
+                    }
+                }
+                CHeaderToken::Identifier(identifier) if identifier == "namespace" => {
+                    while let Some(token) = self.peek() {
+                        let is_open_brace = token.is_punctuator("{");
+                        self.advance();
+                        if is_open_brace {
+                            break;
+                        }
+                    }
+                }
+                CHeaderToken::Identifier(identifier) if matches!(identifier.as_str(), "static_assert" | "_Static_assert") => {
+                    self.skip_declaration();
+                }
+                _ => {
+                    let declaration_start_index = self.token_index;
+
+                    if let Err(error) = self.parse_external_declaration() {
+                        self.translation_unit
+                            .warnings
+                            .push(format!("Skipped declaration at token {}: {}", declaration_start_index, error));
+                        self.token_index = declaration_start_index;
+                        self.skip_declaration();
+                    }
+                }
+            }
+        }
+    }
+
+    fn parse_external_declaration(&mut self) -> Result<(), String> {
+        let is_typedef = self.consume_identifier("typedef");
+        let base_type = self.parse_type_specifier()?;
+
+        if self.consume_punctuator(";") {
+            return Ok(());
+        }
+
+        loop {
+            let (declarator_name, declarator_type) = self.parse_declarator(base_type.clone())?;
+
+            if self.peek_is_punctuator("{") {
+                self.skip_balanced("{", "}")?;
+                return Ok(());
+            }
+
+            if self.consume_punctuator("=") {
+                self.skip_initializer();
+            }
+
+            if is_typedef {
+                let Some(declarator_name) = declarator_name else {
+                    return Err(String::from("Typedef is missing a name."));
+                };
+
+                self.register_typedef(declarator_name, declarator_type);
+            }
+
+            if self.consume_punctuator(",") {
+                continue;
+            }
+
+            self.expect_punctuator(";")?;
+            return Ok(());
+        }
+    }
+
+    fn register_typedef(
+        &mut self,
+        typedef_name: String,
+        aliased_type: CHeaderType,
+    ) {
+        if let CHeaderType::Record(record_key) = &aliased_type
+            && let Some(record_definition) = self
+                .translation_unit
+                .records
+                .iter_mut()
+                .find(|record_definition| record_definition.record_key == *record_key)
+            && record_definition.tag_name.is_none()
+            && record_definition.typedef_name.is_none()
+        {
+            record_definition.typedef_name = Some(typedef_name.clone());
+        }
+
+        // Self-referential typedefs such as `typedef struct Foo Foo;` are already resolved through the record tag.
+        if aliased_type == CHeaderType::Typedef(typedef_name.clone()) {
+            return;
+        }
+
+        self.translation_unit
+            .typedefs
+            .insert(typedef_name, aliased_type);
+    }
+
+    fn parse_type_specifier(&mut self) -> Result<CHeaderType, String> {
+        let mut primitive_specifiers = CHeaderPrimitiveSpecifiers::default();
+        let mut named_type = None;
+
+        while let Some(token) = self.peek().cloned() {
+            let CHeaderToken::Identifier(identifier) = token else {
+                break;
+            };
+
+            if IGNORED_QUALIFIERS.contains(&identifier.as_str()) {
+                self.advance();
+                continue;
+            }
+
+            if self.try_parse_attributes()?.is_some() {
+                continue;
+            }
+
+            match identifier.as_str() {
+                "signed" | "__signed" | "__signed__" => primitive_specifiers.signedness = Some(true),
+                "unsigned" => primitive_specifiers.signedness = Some(false),
+                "short" => primitive_specifiers.short_count += 1,
+                "long" => primitive_specifiers.long_count += 1,
+                "int" => primitive_specifiers.has_int = true,
+                "char" => primitive_specifiers.has_char = true,
+                "float" => primitive_specifiers.has_float = true,
+                "double" => primitive_specifiers.has_double = true,
+                "void" => primitive_specifiers.has_void = true,
+                "bool" | "_Bool" => primitive_specifiers.has_bool = true,
+                "wchar_t" => primitive_specifiers.has_wide_char = true,
+                "__int8" => primitive_specifiers.fixed_size_in_bytes = Some(1),
+                "__int16" => primitive_specifiers.fixed_size_in_bytes = Some(2),
+                "__int32" => primitive_specifiers.fixed_size_in_bytes = Some(4),
+                "__int64" => primitive_specifiers.fixed_size_in_bytes = Some(8),
+                "struct" | "class" if named_type.is_none() => {
+                    self.advance();
+                    named_type = Some(CHeaderType::Record(self.parse_record_specifier(CHeaderRecordKind::Struct)?));
+                    continue;
+                }
+                "union" if named_type.is_none() => {
+                    self.advance();
+                    named_type = Some(CHeaderType::Record(self.parse_record_specifier(CHeaderRecordKind::Union)?));
+                    continue;
+                }
+                "enum" if named_type.is_none() => {
+                    self.advance();
+                    named_type = Some(CHeaderType::Enum(self.parse_enum_specifier()?));
+                    continue;
+                }
+                _ if named_type.is_none() && primitive_specifiers.is_empty() => {
+                    named_type = Some(Self::resolve_well_known_type_name(&identifier).unwrap_or(CHeaderType::Typedef(identifier)));
+                }
+                _ => break,
+            }
+
+            self.advance();
+        }
+
+        if let Some(named_type) = named_type {
+            return Ok(named_type);
+        }
+
+        if primitive_specifiers.is_empty() {
+            return Err(format!("Expected a type, found {:?}.", self.peek()));
+        }
+
+        Ok(primitive_specifiers.to_header_type())
+    }
+
+    fn resolve_well_known_type_name(type_name: &str) -> Option<CHeaderType> {
+        let integer_type = |integer_rank, is_signed| Some(CHeaderType::Primitive(CHeaderPrimitiveType::Integer { integer_rank, is_signed }));
+
+        match type_name {
+            "int8_t" | "INT8" | "int8" | "s8" | "CHAR" => integer_type(CHeaderIntegerRank::Fixed(1), true),
+            "uint8_t" | "UINT8" | "uint8" | "u8" | "BYTE" | "UCHAR" | "BOOLEAN" => integer_type(CHeaderIntegerRank::Fixed(1), false),
+            "int16_t" | "INT16" | "int16" | "s16" | "SHORT" => integer_type(CHeaderIntegerRank::Fixed(2), true),
+            "uint16_t" | "UINT16" | "uint16" | "u16" | "WORD" | "USHORT" | "WCHAR" | "char16_t" => integer_type(CHeaderIntegerRank::Fixed(2), false),
+            "int32_t" | "INT32" | "int32" | "s32" | "INT" | "LONG" | "BOOL" => integer_type(CHeaderIntegerRank::Fixed(4), true),
+            "uint32_t" | "UINT32" | "uint32" | "u32" | "DWORD" | "UINT" | "ULONG" | "char32_t" => integer_type(CHeaderIntegerRank::Fixed(4), false),
+            "int64_t" | "INT64" | "int64" | "s64" | "LONGLONG" | "LONG64" => integer_type(CHeaderIntegerRank::Fixed(8), true),
+            "uint64_t" | "UINT64" | "uint64" | "u64" | "QWORD" | "DWORD64" | "ULONGLONG" | "ULONG64" => integer_type(CHeaderIntegerRank::Fixed(8), false),
+            "intptr_t" | "ssize_t" | "ptrdiff_t" | "INT_PTR" | "LONG_PTR" | "SSIZE_T" => integer_type(CHeaderIntegerRank::PointerSized, true),
+            "uintptr_t" | "size_t" | "UINT_PTR" | "ULONG_PTR" | "DWORD_PTR" | "SIZE_T" => integer_type(CHeaderIntegerRank::PointerSized, false),
+            "FLOAT" | "float32_t" => Some(CHeaderType::Primitive(CHeaderPrimitiveType::Float)),
+            "DOUBLE" | "float64_t" => Some(CHeaderType::Primitive(CHeaderPrimitiveType::Double)),
+            "PVOID" | "LPVOID" | "HANDLE" | "HMODULE" | "HWND" => Some(CHeaderType::Pointer(Box::new(CHeaderType::Void))),
+            _ => None,
+        }
+    }
+
+    fn parse_record_specifier(
+        &mut self,
+        record_kind: CHeaderRecordKind,
+    ) -> Result<String, String> {
+        let mut attributes = self.parse_attribute_sequence()?;
+        let tag_name = match self.peek() {
+            Some(CHeaderToken::Identifier(identifier)) => {
+                let tag_name = identifier.clone();
+                self.advance();
+                Some(tag_name)
+            }
+            _ => None,
+        };
+
+        attributes.merge(self.parse_attribute_sequence()?);
+
+        // C++ base clauses are not part of the C subset, but skipping them keeps simple POD headers importable.
+        if self.peek_is_punctuator(":") {
+            while let Some(token) = self.peek() {
+                if token.is_punctuator("{") || token.is_punctuator(";") {
+                    break;
+                }
+                self.advance();
+            }
+        }
+
+        if !self.peek_is_punctuator("{") {
+            let Some(tag_name) = tag_name else {
+                return Err(String::from("Expected a record tag or body."));
+            };
+
+            self.ensure_record_entry(&tag_name, record_kind, Some(tag_name.clone()));
+            return Ok(tag_name);
+        }
+
+        let record_key = match &tag_name {
+            Some(tag_name) => tag_name.clone(),
+            None => {
+                self.anonymous_record_count += 1;
+                format!("anonymous_record_{}", self.anonymous_record_count)
+            }
+        };
+
+        self.ensure_record_entry(&record_key, record_kind, tag_name.clone());
+        self.expect_punctuator("{")?;
+        let members = self.parse_member_list(&record_key)?;
+        self.expect_punctuator("}")?;
+        attributes.merge(self.parse_attribute_sequence()?);
+
+        let pack_alignment = if attributes.is_packed { Some(1) } else { self.current_pack_alignment };
+
+        if let Some(record_definition) = self
+            .translation_unit
+            .records
+            .iter_mut()
+            .find(|record_definition| record_definition.record_key == record_key)
+        {
+            record_definition.record_kind = record_kind;
+            record_definition.members = Some(members);
+            record_definition.pack_alignment = pack_alignment;
+            record_definition.explicit_alignment = attributes.alignment;
+        }
+
+        Ok(record_key)
+    }
+
+    fn ensure_record_entry(
+        &mut self,
+        record_key: &str,
+        record_kind: CHeaderRecordKind,
+        tag_name: Option<String>,
+    ) {
+        if self.translation_unit.find_record(record_key).is_some() {
+            return;
+        }
+
+        self.translation_unit.records.push(CHeaderRecordDefinition {
+            record_key: record_key.to_string(),
+            record_kind,
+            tag_name,
+            typedef_name: None,
+            enclosing_record: None,
+            members: None,
+            pack_alignment: None,
+            explicit_alignment: None,
+        });
+    }
+
+    fn parse_member_list(
+        &mut self,
+        record_key: &str,
+    ) -> Result<Vec<CHeaderRecordMember>, String> {
+        let mut members = Vec::new();
+
+        while let Some(token) = self.peek().cloned() {
+            match &token {
+                CHeaderToken::Punctuator("}") => break,
+                CHeaderToken::Punctuator(";") => {
+                    self.advance();
+                    continue;
+                }
+                CHeaderToken::Pack(pack_directive) => {
+                    self.apply_pack_directive(pack_directive);
+                    self.advance();
+                    continue;
+                }
+                CHeaderToken::Define { name, value_tokens } => {
+                    if let Ok(constant_value) = self.evaluate_constant_expression(value_tokens) {
+                        self.translation_unit
+                            .constants
+                            .insert(name.clone(), constant_value);
+                    }
+                    self.advance();
+                    continue;
+                }
+                CHeaderToken::Identifier(identifier) if matches!(identifier.as_str(), "static_assert" | "_Static_assert") => {
+                    self.skip_declaration();
+                    continue;
+                }
+                CHeaderToken::Identifier(identifier)
+                    if matches!(identifier.as_str(), "public" | "private" | "protected") && self.peek_at(1).is_some_and(|token| token.is_punctuator(":")) =>
+                {
+                    self.advance();
+                    self.advance();
+                    continue;
+                }
+                _ => {}
+            }
+
+            let member_type = self.parse_type_specifier()?;
+
+            if self.consume_punctuator(";") {
+                if let CHeaderType::Record(nested_record_key) = &member_type
+                    && self
+                        .translation_unit
+                        .find_record(nested_record_key)
+                        .is_some_and(|record_definition| record_definition.tag_name.is_none())
+                {
+                    self.set_enclosing_record(nested_record_key, record_key, members.len());
+                    members.push(CHeaderRecordMember {
+                        member_name: None,
+                        member_type,
+                        bit_width: None,
+                    });
+                }
+                continue;
+            }
+
+            loop {
+                let (member_name, declarator_type) = if self.peek_is_punctuator(":") {
+                    (None, member_type.clone())
+                } else {
+                    self.parse_declarator(member_type.clone())?
+                };
+                let bit_width = if self.consume_punctuator(":") {
+                    let bit_width_tokens = self.collect_expression_tokens(&[",", ";"]);
+                    let bit_width = self.evaluate_constant_expression(&bit_width_tokens)?;
+
+                    Some(u64::try_from(bit_width).map_err(|_| format!("Invalid bitfield width `{}`.", bit_width))?)
+                } else {
+                    None
+                };
+
+                self.parse_attribute_sequence()?;
+
+                if self.peek_is_punctuator("{") {
+                    // Inline member function bodies do not contribute to layout.
+                    self.skip_balanced("{", "}")?;
+                    break;
+                }
+
+                if self.consume_punctuator("=") {
+                    self.skip_initializer();
+                }
+
+                if !matches!(declarator_type, CHeaderType::Function) {
+                    if let CHeaderType::Record(nested_record_key) = &declarator_type
+                        && self
+                            .translation_unit
+                            .find_record(nested_record_key)
+                            .is_some_and(|record_definition| record_definition.tag_name.is_none())
+                    {
+                        self.set_enclosing_record(nested_record_key, record_key, members.len());
+                    }
+
+                    members.push(CHeaderRecordMember {
+                        member_name,
+                        member_type: declarator_type,
+                        bit_width,
+                    });
+                }
+
+                if self.consume_punctuator(",") {
+                    continue;
+                }
+
+                if !self.consume_punctuator(";") && !self.peek_is_punctuator("}") {
+                    return Err(format!("Expected `;` after member declaration, found {:?}.", self.peek()));
+                }
+                break;
+            }
+        }
+
+        Ok(members)
+    }
+
+    fn set_enclosing_record(
+        &mut self,
+        nested_record_key: &str,
+        enclosing_record_key: &str,
+        member_index: usize,
+    ) {
+        if let Some(record_definition) = self
+            .translation_unit
+            .records
+            .iter_mut()
+            .find(|record_definition| record_definition.record_key == nested_record_key)
+            && record_definition.enclosing_record.is_none()
+        {
+            record_definition.enclosing_record = Some((enclosing_record_key.to_string(), member_index));
+        }
+    }
+
+    fn parse_enum_specifier(&mut self) -> Result<String, String> {
+        // Scoped C++ enums (`enum class`) share the same layout rules as plain enums.
+        let _ = self.consume_identifier("class") || self.consume_identifier("struct");
+
+        self.parse_attribute_sequence()?;
+        let tag_name = match self.peek() {
+            Some(CHeaderToken::Identifier(identifier)) => {
+                let tag_name = identifier.clone();
+                self.advance();
+                Some(tag_name)
+            }
+            _ => None,
+        };
+        let underlying_type = if self.consume_punctuator(":") {
+            match self.parse_type_specifier()? {
+                CHeaderType::Primitive(primitive_type) => primitive_type,
+                CHeaderType::Typedef(typedef_name) => match self.translation_unit.typedefs.get(&typedef_name) {
+                    Some(CHeaderType::Primitive(primitive_type)) => *primitive_type,
+                    _ => return Err(format!("Unsupported enum underlying type `{}`.", typedef_name)),
+                },
+                other_type => return Err(format!("Unsupported enum underlying type {:?}.", other_type)),
+            }
+        } else {
+            CHeaderPrimitiveType::Integer {
+                integer_rank: CHeaderIntegerRank::Int,
+                is_signed: true,
+            }
+        };
+        let enum_key = match tag_name {
+            Some(tag_name) => tag_name,
+            None => {
+                self.anonymous_enum_count += 1;
+                format!("anonymous_enum_{}", self.anonymous_enum_count)
+            }
+        };
+
+        if !self.peek_is_punctuator("{") {
+            if self.translation_unit.find_enum(&enum_key).is_none() {
+                self.translation_unit.enums.push(CHeaderEnumDefinition {
+                    enum_key: enum_key.clone(),
+                    underlying_type,
+                    constants: Vec::new(),
+                });
+            }
+
+            return Ok(enum_key);
+        }
+
+        self.expect_punctuator("{")?;
+        let mut constants = Vec::new();
+        let mut next_value = 0_i128;
+
+        while !self.peek_is_punctuator("}") {
+            let Some(constant_name) = self
+                .peek()
+                .and_then(CHeaderToken::as_identifier)
+                .map(str::to_string)
+            else {
+                return Err(format!("Expected enum constant name, found {:?}.", self.peek()));
+            };
+
+            self.advance();
+            self.parse_attribute_sequence()?;
+
+            if self.consume_punctuator("=") {
+                let value_tokens = self.collect_expression_tokens(&[",", "}"]);
+                next_value = self.evaluate_constant_expression(&value_tokens)?;
+            }
+
+            self.translation_unit
+                .constants
+                .insert(constant_name.clone(), next_value);
+            constants.push((constant_name, next_value));
+            next_value = next_value.saturating_add(1);
+
+            if !self.consume_punctuator(",") {
+                break;
+            }
+        }
+
+        self.expect_punctuator("}")?;
+        self.parse_attribute_sequence()?;
+
+        if let Some(enum_definition) = self
+            .translation_unit
+            .enums
+            .iter_mut()
+            .find(|enum_definition| enum_definition.enum_key == enum_key)
+        {
+            enum_definition.underlying_type = underlying_type;
+            enum_definition.constants = constants;
+        } else {
+            self.translation_unit.enums.push(CHeaderEnumDefinition {
+                enum_key: enum_key.clone(),
+                underlying_type,
+                constants,
+            });
+        }
+
+        Ok(enum_key)
+    }
+
+    /// Parses a (possibly abstract) declarator and applies it to `base_type`. Pointer prefixes bind to the base type first,
+    /// then array and function suffixes, and finally any parenthesized inner declarator.
+    fn parse_declarator(
+        &mut self,
+        base_type: CHeaderType,
+    ) -> Result<(Option<String>, CHeaderType), String> {
+        let mut declarator_type = base_type;
+
+        loop {
+            if self.consume_punctuator("*") || self.consume_punctuator("&") || self.consume_punctuator("&&") {
+                declarator_type = CHeaderType::Pointer(Box::new(declarator_type));
+                continue;
+            }
+
+            if let Some(CHeaderToken::Identifier(identifier)) = self.peek()
+                && IGNORED_QUALIFIERS.contains(&identifier.as_str())
+            {
+                self.advance();
+                continue;
+            }
+
+            if self.try_parse_attributes()?.is_some() {
+                continue;
+            }
+
+            break;
+        }
+
+        let mut nested_declarator_range = None;
+        let mut declarator_name = None;
+
+        if self.peek_is_punctuator("(") && self.is_nested_declarator_start() {
+            let nested_declarator_start_index = self.token_index + 1;
+            self.skip_balanced("(", ")")?;
+            nested_declarator_range = Some((nested_declarator_start_index, self.token_index - 1));
+        } else if let Some(CHeaderToken::Identifier(identifier)) = self.peek() {
+            declarator_name = Some(identifier.clone());
+            self.advance();
+        }
+
+        let mut array_lengths = Vec::new();
+        let mut is_function = false;
+
+        loop {
+            if self.consume_punctuator("[") {
+                let length_tokens = self.collect_expression_tokens(&["]"]);
+                self.expect_punctuator("]")?;
+
+                if length_tokens.is_empty() {
+                    array_lengths.push(None);
+                } else {
+                    let array_length = self.evaluate_constant_expression(&length_tokens)?;
+                    array_lengths.push(Some(
+                        u64::try_from(array_length).map_err(|_| format!("Invalid array length `{}`.", array_length))?,
+                    ));
+                }
+                continue;
+            }
+
+            if self.peek_is_punctuator("(") {
+                self.skip_balanced("(", ")")?;
+                is_function = true;
+                continue;
+            }
+
+            if let Some(CHeaderToken::Identifier(identifier)) = self.peek()
+                && matches!(identifier.as_str(), "const" | "volatile" | "noexcept" | "override")
+            {
+                self.advance();
+                continue;
+            }
+
+            if self.try_parse_attributes()?.is_some() {
+                continue;
+            }
+
+            break;
+        }
+
+        if is_function {
+            declarator_type = CHeaderType::Function;
+        }
+
+        for array_length in array_lengths.into_iter().rev() {
+            declarator_type = CHeaderType::Array(Box::new(declarator_type), array_length);
+        }
+
+        if let Some((nested_declarator_start_index, nested_declarator_end_index)) = nested_declarator_range {
+            let resume_token_index = self.token_index;
+
+            self.token_index = nested_declarator_start_index;
+            let (nested_declarator_name, nested_declarator_type) = self.parse_declarator(declarator_type)?;
+
+            if self.token_index != nested_declarator_end_index {
+                return Err(format!("Unexpected token {:?} in parenthesized declarator.", self.peek()));
+            }
+
+            self.token_index = resume_token_index;
+            return Ok((nested_declarator_name, nested_declarator_type));
+        }
+
+        Ok((declarator_name, declarator_type))
+    }
+
+    fn is_nested_declarator_start(&self) -> bool {
+        match self.peek_at(1) {
+            Some(CHeaderToken::Punctuator("*")) | Some(CHeaderToken::Punctuator("&")) | Some(CHeaderToken::Punctuator("(")) => true,
+            Some(CHeaderToken::Identifier(identifier)) => IGNORED_QUALIFIERS.contains(&identifier.as_str()) || identifier.starts_with("__"),
+            _ => false,
+        }
+    }
+
+    fn parse_attribute_sequence(&mut self) -> Result<CHeaderAttributes, String> {
+        let mut attributes = CHeaderAttributes::default();
+
+        while let Some(parsed_attributes) = self.try_parse_attributes()? {
+            attributes.merge(parsed_attributes);
+        }
+
+        Ok(attributes)
+    }
+
+    fn try_parse_attributes(&mut self) -> Result<Option<CHeaderAttributes>, String> {
+        let Some(CHeaderToken::Identifier(identifier)) = self.peek().cloned() else {
+            if self.peek_is_punctuator("[") && self.peek_at(1).is_some_and(|token| token.is_punctuator("[")) {
+                self.skip_balanced("[", "]")?;
+                return Ok(Some(CHeaderAttributes::default()));
+            }
+
+            return Ok(None);
+        };
+
+        match identifier.as_str() {
+            "__attribute__" | "__attribute" | "__declspec" => {
+                self.advance();
+                let attribute_start_index = self.token_index;
+                self.skip_balanced("(", ")")?;
+                let attribute_tokens = self.tokens[attribute_start_index..self.token_index].to_vec();
+
+                Ok(Some(self.interpret_attribute_tokens(&attribute_tokens)))
+            }
+            "alignas" | "_Alignas" => {
+                self.advance();
+                let attribute_start_index = self.token_index;
+                self.skip_balanced("(", ")")?;
+                let alignment_tokens = self.tokens[attribute_start_index + 1..self.token_index - 1].to_vec();
+
+                Ok(Some(CHeaderAttributes {
+                    is_packed: false,
+                    alignment: self
+                        .evaluate_constant_expression(&alignment_tokens)
+                        .ok()
+                        .and_then(|alignment| u64::try_from(alignment).ok()),
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn interpret_attribute_tokens(
+        &self,
+        attribute_tokens: &[CHeaderToken],
+    ) -> CHeaderAttributes {
+        let mut attributes = CHeaderAttributes::default();
+
+        for (token_offset, token) in attribute_tokens.iter().enumerate() {
+            match token.as_identifier() {
+                Some("packed") | Some("__packed__") => attributes.is_packed = true,
+                Some("aligned") | Some("__aligned__") | Some("align") => {
+                    let alignment = match (attribute_tokens.get(token_offset + 1), attribute_tokens.get(token_offset + 2)) {
+                        (Some(open_token), Some(CHeaderToken::Integer(alignment))) if open_token.is_punctuator("(") => u64::try_from(*alignment).ok(),
+                        (Some(open_token), Some(CHeaderToken::Identifier(constant_name))) if open_token.is_punctuator("(") => self
+                            .translation_unit
+                            .constants
+                            .get(constant_name)
+                            .and_then(|alignment| u64::try_from(*alignment).ok()),
+                        _ => None,
+                    };
+
+                    attributes.alignment = attributes.alignment.max(alignment);
+                }
+                _ => {}
+            }
+        }
+
+        attributes
+    }
+
+    fn apply_pack_directive(
+        &mut self,
+        pack_directive: &CHeaderPackDirective,
+    ) {
+        match pack_directive {
+            CHeaderPackDirective::Set(pack_alignment) => self.current_pack_alignment = *pack_alignment,
+            CHeaderPackDirective::Push(pack_alignment) => {
+                self.pack_stack.push(self.current_pack_alignment);
+
+                if pack_alignment.is_some() {
+                    self.current_pack_alignment = *pack_alignment;
+                }
+            }
+            CHeaderPackDirective::Pop => self.current_pack_alignment = self.pack_stack.pop().unwrap_or(None),
+        }
+    }
+
+    /// Collects tokens up to (but not including) the first terminator punctuator at nesting depth zero.
+    fn collect_expression_tokens(
+        &mut self,
+        terminators: &[&str],
+    ) -> Vec<CHeaderToken> {
+        let mut expression_tokens = Vec::new();
+        let mut nesting_depth = 0_i32;
+
+        while let Some(token) = self.peek() {
+            if nesting_depth == 0
+                && terminators
+                    .iter()
+                    .any(|terminator| token.is_punctuator(terminator))
+            {
+                break;
+            }
+
+            if token.is_punctuator("(") || token.is_punctuator("[") || token.is_punctuator("{") {
+                nesting_depth += 1;
+            } else if token.is_punctuator(")") || token.is_punctuator("]") || token.is_punctuator("}") {
+                if nesting_depth == 0 {
+                    break;
+                }
+                nesting_depth -= 1;
+            }
+
+            expression_tokens.push(token.clone());
+            self.advance();
+        }
+
+        expression_tokens
+    }
+
+    fn skip_initializer(&mut self) {
+        self.collect_expression_tokens(&[",", ";"]);
+    }
+
+    /// Skips the remainder of a declaration, including any balanced brace body, through its terminating `;`.
+    fn skip_declaration(&mut self) {
+        let mut brace_depth = 0_i32;
+
+        while let Some(token) = self.peek().cloned() {
+            self.advance();
+
+            if token.is_punctuator("{") {
+                brace_depth += 1;
+            } else if token.is_punctuator("}") {
+                brace_depth -= 1;
+
+                if brace_depth <= 0 && !self.peek_is_punctuator(";") && !matches!(self.peek(), Some(CHeaderToken::Identifier(_))) {
+                    return;
+                }
+            } else if token.is_punctuator(";") && brace_depth <= 0 {
+                return;
+            }
+        }
+    }
+
+    fn skip_balanced(
+        &mut self,
+        open_punctuator: &str,
+        close_punctuator: &str,
+    ) -> Result<(), String> {
+        self.expect_punctuator(open_punctuator)?;
+        let mut nesting_depth = 1_u32;
+
+        while let Some(token) = self.peek() {
+            if token.is_punctuator(open_punctuator) {
+                nesting_depth += 1;
+            } else if token.is_punctuator(close_punctuator) {
+                nesting_depth -= 1;
+
+                if nesting_depth == 0 {
+                    self.advance();
+                    return Ok(());
+                }
+            }
+
+            self.advance();
+        }
+
+        Err(format!("Missing closing `{}`.", close_punctuator))
+    }
+
+    fn evaluate_constant_expression(
+        &self,
+        expression_tokens: &[CHeaderToken],
+    ) -> Result<i128, String> {
+        let mut expression_evaluator = CHeaderConstantExpressionEvaluator {
+            tokens: expression_tokens,
+            token_index: 0,
+            translation_unit: &self.translation_unit,
+        };
+        let value = expression_evaluator.evaluate_conditional()?;
+
+        if expression_evaluator.token_index != expression_tokens.len() {
+            return Err(format!(
+                "Unsupported constant expression near {:?}.",
+                expression_tokens.get(expression_evaluator.token_index)
+            ));
+        }
+
+        Ok(value)
+    }
+
+    fn peek(&self) -> Option<&CHeaderToken> {
+        self.tokens.get(self.token_index)
+    }
+
+    fn peek_at(
+        &self,
+        token_offset: usize,
+    ) -> Option<&CHeaderToken> {
+        self.tokens.get(self.token_index + token_offset)
+    }
+
+    fn peek_is_punctuator(
+        &self,
+        punctuator: &str,
+    ) -> bool {
+        self.peek().is_some_and(|token| token.is_punctuator(punctuator))
+    }
+
+    fn advance(&mut self) {
+        self.token_index += 1;
+    }
+
+    fn consume_punctuator(
+        &mut self,
+        punctuator: &str,
+    ) -> bool {
+        if self.peek_is_punctuator(punctuator) {
+            self.advance();
+            return true;
+        }
+
+        false
+    }
+
+    fn consume_identifier(
+        &mut self,
+        identifier: &str,
+    ) -> bool {
+        if self.peek().is_some_and(|token| token.is_identifier(identifier)) {
+            self.advance();
+            return true;
+        }
+
+        false
+    }
+
+    fn expect_punctuator(
+        &mut self,
+        punctuator: &str,
+    ) -> Result<(), String> {
+        if self.consume_punctuator(punctuator) {
+            return Ok(());
+        }
+
+        Err(format!("Expected `{}`, found {:?}.", punctuator, self.peek()))
+    }
+}
+
+struct CHeaderConstantExpressionEvaluator<'a> {
+    tokens: &'a [CHeaderToken],
+    token_index: usize,
+    translation_unit: &'a CHeaderTranslationUnit,
+}
+
+impl CHeaderConstantExpressionEvaluator<'_> {
+    const BINARY_OPERATOR_PRECEDENCE: [(&'static str, u8); 18] = [
+        ("||", 1),
+        ("&&", 2),
+        ("|", 3),
+        ("^", 4),
+        ("&", 5),
+        ("==", 6),
+        ("!=", 6),
+        ("<", 7),
+        (">", 7),
+        ("<=", 7),
+        (">=", 7),
+        ("<<", 8),
+        (">>", 8),
+        ("+", 9),
+        ("-", 9),
+        ("*", 10),
+        ("/", 10),
+        ("%", 10),
+    ];
+
+    fn evaluate_conditional(&mut self) -> Result<i128, String> {
+        let condition_value = self.evaluate_binary(0)?;
+
+        if !self.consume_punctuator("?") {
+            return Ok(condition_value);
+        }
+
+        let true_value = self.evaluate_conditional()?;
+
+        if !self.consume_punctuator(":") {
+            return Err(String::from("Expected `:` in conditional constant expression."));
+        }
+
+        let false_value = self.evaluate_conditional()?;
+
+        Ok(if condition_value != 0 { true_value } else { false_value })
+    }
+
+    fn evaluate_binary(
+        &mut self,
+        minimum_precedence: u8,
+    ) -> Result<i128, String> {
+        let mut left_value = self.evaluate_unary()?;
+
+        while let Some(CHeaderToken::Punctuator(operator)) = self.tokens.get(self.token_index) {
+            let Some((_, operator_precedence)) = Self::BINARY_OPERATOR_PRECEDENCE
+                .iter()
+                .find(|(binary_operator, _)| binary_operator == operator)
+            else {
+                break;
+            };
+
+            if *operator_precedence <= minimum_precedence {
+                break;
+            }
+
+            let operator = *operator;
+            self.token_index += 1;
+            let right_value = self.evaluate_binary(*operator_precedence)?;
+
+            left_value = match operator {
+                "||" => ((left_value != 0) || (right_value != 0)) as i128,
+                "&&" => ((left_value != 0) && (right_value != 0)) as i128,
+                "|" => left_value | right_value,
+                "^" => left_value ^ right_value,
+                "&" => left_value & right_value,
+                "==" => (left_value == right_value) as i128,
+                "!=" => (left_value != right_value) as i128,
+                "<" => (left_value < right_value) as i128,
+                ">" => (left_value > right_value) as i128,
+                "<=" => (left_value <= right_value) as i128,
+                ">=" => (left_value >= right_value) as i128,
+                "<<" => left_value
+                    .checked_shl(u32::try_from(right_value).unwrap_or(u32::MAX))
+                    .unwrap_or(0),
+                ">>" => left_value
+                    .checked_shr(u32::try_from(right_value).unwrap_or(u32::MAX))
+                    .unwrap_or(0),
+                "+" => left_value.saturating_add(right_value),
+                "-" => left_value.saturating_sub(right_value),
+                "*" => left_value.saturating_mul(right_value),
+                "/" => left_value
+                    .checked_div(right_value)
+                    .ok_or_else(|| String::from("Division by zero in constant expression."))?,
+                "%" => left_value
+                    .checked_rem(right_value)
+                    .ok_or_else(|| String::from("Division by zero in constant expression."))?,
+                _ => unreachable!("Operator precedence table and evaluation must stay in sync."),
+            };
+        }
+
+        Ok(left_value)
+    }
+
+    fn evaluate_unary(&mut self) -> Result<i128, String> {
+        let Some(token) = self.tokens.get(self.token_index) else {
+            return Err(String::from("Unexpected end of constant expression."));
+        };
+
+        match token {
+            CHeaderToken::Integer(value) => {
+                self.token_index += 1;
+                Ok(*value)
+            }
+            CHeaderToken::Identifier(identifier) => {
+                self.token_index += 1;
+                self.translation_unit
+                    .constants
+                    .get(identifier)
+                    .copied()
+                    .ok_or_else(|| format!("Unknown constant `{}`.", identifier))
+            }
+            CHeaderToken::Punctuator("(") => {
+                self.token_index += 1;
+
+                // Casts such as `(int)4` or `(uint32_t)FLAG` do not change constant values for layout purposes.
+                if self.is_cast_prefix() {
+                    while !self.consume_punctuator(")") {
+                        self.token_index += 1;
+                    }
+
+                    return self.evaluate_unary();
+                }
+
+                let value = self.evaluate_conditional()?;
+
+                if !self.consume_punctuator(")") {
+                    return Err(String::from("Expected `)` in constant expression."));
+                }
+
+                Ok(value)
+            }
+            CHeaderToken::Punctuator("-") => {
+                self.token_index += 1;
+                Ok(self.evaluate_unary()?.saturating_neg())
+            }
+            CHeaderToken::Punctuator("+") => {
+                self.token_index += 1;
+                self.evaluate_unary()
+            }
+            CHeaderToken::Punctuator("~") => {
+                self.token_index += 1;
+                Ok(!self.evaluate_unary()?)
+            }
+            CHeaderToken::Punctuator("!") => {
+                self.token_index += 1;
+                Ok((self.evaluate_unary()? == 0) as i128)
+            }
+            other_token => Err(format!("Unsupported token {:?} in constant expression.", other_token)),
+        }
+    }
+
+    fn is_cast_prefix(&self) -> bool {
+        let mut lookahead_index = self.token_index;
+        let mut has_type_name = false;
+
+        while let Some(token) = self.tokens.get(lookahead_index) {
+            match token {
+                CHeaderToken::Identifier(identifier) if !self.translation_unit.constants.contains_key(identifier) => has_type_name = true,
+                CHeaderToken::Punctuator("*") if has_type_name => {}
+                CHeaderToken::Punctuator(")") => return has_type_name && self.tokens.get(lookahead_index + 1).is_some(),
+                _ => return false,
+            }
+
+            lookahead_index += 1;
+        }
+
+        false
+    }
+
+    fn consume_punctuator(
+        &mut self,
+        punctuator: &str,
+    ) -> bool {
+        if self
+            .tokens
+            .get(self.token_index)
+            .is_some_and(|token| token.is_punctuator(punctuator))
+        {
+            self.token_index += 1;
+            return true;
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CHeaderParser;
+    use crate::structures::projects::symbol_interchange::c_header::c_header_declaration::{
+        CHeaderIntegerRank, CHeaderPrimitiveType, CHeaderRecordKind, CHeaderType,
+    };
+
+    #[test]
+    fn parses_records_typedefs_and_declarators() {
+        let translation_unit = CHeaderParser::parse(
+            r#"
+            #define NAME_LENGTH (8 * 2)
+            typedef struct {
+                unsigned int id;
+                char name[NAME_LENGTH];
+                void (*on_hit)(int damage);
+                struct Weapon *weapons[4];
+                int (*grid)[3];
+            } Player;
+            "#,
+        )
+        .expect("Expected header to parse.");
+        let player_record = &translation_unit.records[0];
+        let members = player_record
+            .members
+            .as_ref()
+            .expect("Expected player members.");
+
+        assert_eq!(player_record.typedef_name.as_deref(), Some("Player"));
+        assert_eq!(player_record.record_kind, CHeaderRecordKind::Struct);
+        assert_eq!(
+            members[0].member_type,
+            CHeaderType::Primitive(CHeaderPrimitiveType::Integer {
+                integer_rank: CHeaderIntegerRank::Int,
+                is_signed: false,
+            })
+        );
+        assert_eq!(
+            members[1].member_type,
+            CHeaderType::Array(Box::new(CHeaderType::Primitive(CHeaderPrimitiveType::Character)), Some(16))
+        );
+        assert_eq!(members[2].member_type, CHeaderType::Pointer(Box::new(CHeaderType::Function)));
+        assert_eq!(
+            members[3].member_type,
+            CHeaderType::Array(Box::new(CHeaderType::Pointer(Box::new(CHeaderType::Record(String::from("Weapon"))))), Some(4))
+        );
+        assert_eq!(
+            members[4].member_type,
+            CHeaderType::Pointer(Box::new(CHeaderType::Array(
+                Box::new(CHeaderType::Primitive(CHeaderPrimitiveType::Integer {
+                    integer_rank: CHeaderIntegerRank::Int,
+                    is_signed: true,
+                })),
+                Some(3)
+            )))
+        );
+    }
+
+    #[test]
+    fn parses_enums_bitfields_and_pack_state() {
+        let translation_unit = CHeaderParser::parse(
+            r#"
+            enum Team : uint8_t { TEAM_RED = 1, TEAM_BLUE, TEAM_COUNT };
+            #pragma pack(push, 1)
+            struct Flags { unsigned alive : 1, poisoned : 1; unsigned : 6; enum Team team; int values[TEAM_COUNT]; };
+            #pragma pack(pop)
+            struct Unpacked { int value; };
+            "#,
+        )
+        .expect("Expected header to parse.");
+        let flags_record = translation_unit
+            .find_record("Flags")
+            .expect("Expected Flags record.");
+        let flags_members = flags_record.members.as_ref().expect("Expected Flags members.");
+
+        assert_eq!(translation_unit.constants.get("TEAM_COUNT"), Some(&3));
+        assert_eq!(flags_record.pack_alignment, Some(1));
+        assert_eq!(flags_members[0].bit_width, Some(1));
+        assert_eq!(flags_members[1].member_name.as_deref(), Some("poisoned"));
+        assert_eq!(flags_members[2].member_name, None);
+        assert_eq!(flags_members[2].bit_width, Some(6));
+        assert_eq!(flags_members[3].member_type, CHeaderType::Enum(String::from("Team")));
+        assert!(matches!(flags_members[4].member_type, CHeaderType::Array(_, Some(3))));
+        assert_eq!(
+            translation_unit
+                .find_record("Unpacked")
+                .and_then(|record| record.pack_alignment),
+            None
+        );
+    }
+
+    #[test]
+    fn skips_function_declarations_and_definitions() {
+        let translation_unit = CHeaderParser::parse(
+            r#"
+            extern "C" {
+            int get_health(struct Player *player);
+            static inline int add(int a, int b) { return a + b; }
+            struct Player { int health; };
+            }
+            "#,
+        )
+        .expect("Expected header to parse.");
+
+        assert!(translation_unit.warnings.is_empty(), "{:?}", translation_unit.warnings);
+        assert!(
+            translation_unit
+                .find_record("Player")
+                .is_some_and(|record| record.is_defined())
+        );
+    }
+
+    #[test]
+    fn records_nested_anonymous_members() {
+        let translation_unit = CHeaderParser::parse("struct Value { int kind; union { int as_int; float as_float; }; struct { short x, y; } point; };")
+            .expect("Expected header to parse.");
+        let value_record = translation_unit
+            .find_record("Value")
+            .expect("Expected Value record.");
+        let value_members = value_record.members.as_ref().expect("Expected Value members.");
+        let anonymous_union_key = match &value_members[1].member_type {
+            CHeaderType::Record(record_key) => record_key.clone(),
+            other_type => panic!("Expected anonymous union member, found {:?}.", other_type),
+        };
+        let anonymous_union = translation_unit
+            .find_record(&anonymous_union_key)
+            .expect("Expected anonymous union record.");
+
+        assert_eq!(value_members.len(), 3);
+        assert_eq!(anonymous_union.record_kind, CHeaderRecordKind::Union);
+        assert_eq!(anonymous_union.enclosing_record, Some((String::from("Value"), 1)));
+        assert_eq!(value_members[2].member_name.as_deref(), Some("point"));
+    }
+}
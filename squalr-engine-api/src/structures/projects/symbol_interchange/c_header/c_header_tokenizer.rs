@@ -0,0 +1,442 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CHeaderPackDirective {
+    Set(Option<u64>),
+    Push(Option<u64>),
+    Pop,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CHeaderToken {
+    Identifier(String),
+    Integer(i128),
+    StringLiteral(String),
+    Punctuator(&'static str),
+    Pack(CHeaderPackDirective),
+    Define { name: String, value_tokens: Vec<CHeaderToken> },
+    Other,
+}
+
+impl CHeaderToken {
+    pub fn is_punctuator(
+        &self,
+        punctuator: &str,
+    ) -> bool {
+        matches!(self, Self::Punctuator(token_punctuator) if *token_punctuator == punctuator)
+    }
+
+    pub fn is_identifier(
+        &self,
+        identifier: &str,
+    ) -> bool {
+        matches!(self, Self::Identifier(token_identifier) if token_identifier == identifier)
+    }
+
+    pub fn as_identifier(&self) -> Option<&str> {
+        match self {
+            Self::Identifier(identifier) => Some(identifier),
+            _ => None,
+        }
+    }
+}
+
+const MULTI_CHARACTER_PUNCTUATORS: [&str; 12] = [
+    "...", "<<", ">>", "::", "->", "&&", "||", "==", "!=", "<=", ">=", "##",
+];
+const SINGLE_CHARACTER_PUNCTUATORS: [&str; 24] = [
+    "{", "}", "(", ")", "[", "]", ";", ",", ":", "*", "=", "+", "-", "/", "%", "&", "|", "^", "~", "!", "<", ">", "?", ".",
+];
+
+/// Splits C header source text into tokens. Preprocessor lines are folded into `Pack` and `Define` tokens, and every
+/// other directive (includes, conditionals, macros with parameters) is skipped.
+pub struct CHeaderTokenizer;
+
+impl CHeaderTokenizer {
+    pub fn tokenize(source_text: &str) -> Result<Vec<CHeaderToken>, String> {
+        let source_characters = source_text.chars().collect::<Vec<_>>();
+        let mut tokens = Vec::new();
+        let mut character_index = 0_usize;
+        let mut is_at_line_start = true;
+
+        while character_index < source_characters.len() {
+            let current_character = source_characters[character_index];
+
+            if current_character == '\n' {
+                is_at_line_start = true;
+                character_index += 1;
+                continue;
+            }
+
+            if current_character.is_whitespace() {
+                character_index += 1;
+                continue;
+            }
+
+            if current_character == '/' && source_characters.get(character_index + 1) == Some(&'/') {
+                while character_index < source_characters.len() && source_characters[character_index] != '\n' {
+                    character_index += 1;
+                }
+                continue;
+            }
+
+            if current_character == '/' && source_characters.get(character_index + 1) == Some(&'*') {
+                character_index = Self::skip_block_comment(&source_characters, character_index)?;
+                continue;
+            }
+
+            if current_character == '#' && is_at_line_start {
+                let (directive_text, next_character_index) = Self::read_directive_line(&source_characters, character_index + 1)?;
+
+                if let Some(directive_token) = Self::tokenize_directive(&directive_text)? {
+                    tokens.push(directive_token);
+                }
+
+                character_index = next_character_index;
+                continue;
+            }
+
+            is_at_line_start = false;
+
+            if current_character == '_' || current_character.is_ascii_alphabetic() {
+                let identifier_start_index = character_index;
+
+                while character_index < source_characters.len()
+                    && (source_characters[character_index] == '_' || source_characters[character_index].is_ascii_alphanumeric())
+                {
+                    character_index += 1;
+                }
+
+                let identifier = source_characters[identifier_start_index..character_index]
+                    .iter()
+                    .collect::<String>();
+
+                // Wide and UTF string literal prefixes are folded into the literal itself.
+                if matches!(identifier.as_str(), "L" | "u" | "U" | "u8") && matches!(source_characters.get(character_index), Some('"') | Some('\'')) {
+                    continue;
+                }
+
+                tokens.push(CHeaderToken::Identifier(identifier));
+                continue;
+            }
+
+            if current_character.is_ascii_digit()
+                || (current_character == '.'
+                    && source_characters
+                        .get(character_index + 1)
+                        .is_some_and(|next_character| next_character.is_ascii_digit()))
+            {
+                let (number_token, next_character_index) = Self::read_number(&source_characters, character_index)?;
+
+                tokens.push(number_token);
+                character_index = next_character_index;
+                continue;
+            }
+
+            if current_character == '"' {
+                let (string_literal, next_character_index) = Self::read_quoted(&source_characters, character_index, '"')?;
+
+                tokens.push(CHeaderToken::StringLiteral(string_literal));
+                character_index = next_character_index;
+                continue;
+            }
+
+            if current_character == '\'' {
+                let (character_literal, next_character_index) = Self::read_quoted(&source_characters, character_index, '\'')?;
+                let character_value = character_literal
+                    .chars()
+                    .next()
+                    .map(|literal_character| literal_character as i128)
+                    .unwrap_or(0);
+
+                tokens.push(CHeaderToken::Integer(character_value));
+                character_index = next_character_index;
+                continue;
+            }
+
+            if let Some(punctuator) = MULTI_CHARACTER_PUNCTUATORS
+                .iter()
+                .find(|punctuator| Self::matches_text_at(&source_characters, character_index, punctuator))
+            {
+                tokens.push(CHeaderToken::Punctuator(punctuator));
+                character_index += punctuator.len();
+                continue;
+            }
+
+            if let Some(punctuator) = SINGLE_CHARACTER_PUNCTUATORS
+                .iter()
+                .find(|punctuator| Self::matches_text_at(&source_characters, character_index, punctuator))
+            {
+                tokens.push(CHeaderToken::Punctuator(punctuator));
+                character_index += 1;
+                continue;
+            }
+
+            tokens.push(CHeaderToken::Other);
+            character_index += 1;
+        }
+
+        Ok(tokens)
+    }
+
+    fn matches_text_at(
+        source_characters: &[char],
+        character_index: usize,
+        text: &str,
+    ) -> bool {
+        text.chars()
+            .enumerate()
+            .all(|(text_offset, text_character)| source_characters.get(character_index + text_offset) == Some(&text_character))
+    }
+
+    fn skip_block_comment(
+        source_characters: &[char],
+        comment_start_index: usize,
+    ) -> Result<usize, String> {
+        let mut character_index = comment_start_index + 2;
+
+        while character_index + 1 < source_characters.len() {
+            if source_characters[character_index] == '*' && source_characters[character_index + 1] == '/' {
+                return Ok(character_index + 2);
+            }
+
+            character_index += 1;
+        }
+
+        Err(String::from("Unterminated block comment in C header."))
+    }
+
+    fn read_directive_line(
+        source_characters: &[char],
+        directive_start_index: usize,
+    ) -> Result<(String, usize), String> {
+        let mut directive_text = String::new();
+        let mut character_index = directive_start_index;
+
+        while character_index < source_characters.len() {
+            let current_character = source_characters[character_index];
+
+            if current_character == '\\' && source_characters.get(character_index + 1) == Some(&'\n') {
+                directive_text.push(' ');
+                character_index += 2;
+                continue;
+            }
+
+            if current_character == '\\'
+                && source_characters.get(character_index + 1) == Some(&'\r')
+                && source_characters.get(character_index + 2) == Some(&'\n')
+            {
+                directive_text.push(' ');
+                character_index += 3;
+                continue;
+            }
+
+            if current_character == '\n' {
+                break;
+            }
+
+            if current_character == '/' && source_characters.get(character_index + 1) == Some(&'*') {
+                character_index = Self::skip_block_comment(source_characters, character_index)?;
+                directive_text.push(' ');
+                continue;
+            }
+
+            if current_character == '/' && source_characters.get(character_index + 1) == Some(&'/') {
+                while character_index < source_characters.len() && source_characters[character_index] != '\n' {
+                    character_index += 1;
+                }
+                break;
+            }
+
+            directive_text.push(current_character);
+            character_index += 1;
+        }
+
+        Ok((directive_text, character_index))
+    }
+
+    fn tokenize_directive(directive_text: &str) -> Result<Option<CHeaderToken>, String> {
+        let directive_tokens = Self::tokenize(directive_text)?;
+        let Some(directive_name) = directive_tokens.first().and_then(CHeaderToken::as_identifier) else {
+            return Ok(None);
+        };
+
+        match directive_name {
+            "pragma"
+                if directive_tokens
+                    .get(1)
+                    .is_some_and(|token| token.is_identifier("pack")) =>
+            {
+                Ok(Some(CHeaderToken::Pack(Self::parse_pack_directive(&directive_tokens[2..])?)))
+            }
+            "define" => {
+                let Some(define_name) = directive_tokens.get(1).and_then(CHeaderToken::as_identifier) else {
+                    return Ok(None);
+                };
+
+                // Function-like macros are skipped because their expansion is not needed for layout importing.
+                if directive_tokens
+                    .get(2)
+                    .is_some_and(|token| token.is_punctuator("("))
+                    && !directive_text
+                        .trim_start()
+                        .trim_start_matches("define")
+                        .trim_start()
+                        .trim_start_matches(define_name)
+                        .starts_with(char::is_whitespace)
+                {
+                    return Ok(None);
+                }
+
+                Ok(Some(CHeaderToken::Define {
+                    name: define_name.to_string(),
+                    value_tokens: directive_tokens[2..].to_vec(),
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn parse_pack_directive(pack_tokens: &[CHeaderToken]) -> Result<CHeaderPackDirective, String> {
+        let argument_tokens = pack_tokens
+            .iter()
+            .filter(|token| !token.is_punctuator("(") && !token.is_punctuator(")") && !token.is_punctuator(","))
+            .collect::<Vec<_>>();
+        let alignment = argument_tokens.iter().find_map(|token| match token {
+            CHeaderToken::Integer(alignment) => u64::try_from(*alignment).ok(),
+            _ => None,
+        });
+
+        if let Some(alignment) = alignment
+            && !alignment.is_power_of_two()
+        {
+            return Err(format!("Invalid #pragma pack alignment `{}`.", alignment));
+        }
+
+        if argument_tokens.iter().any(|token| token.is_identifier("push")) {
+            return Ok(CHeaderPackDirective::Push(alignment));
+        }
+
+        if argument_tokens.iter().any(|token| token.is_identifier("pop")) {
+            return Ok(CHeaderPackDirective::Pop);
+        }
+
+        Ok(CHeaderPackDirective::Set(alignment))
+    }
+
+    fn read_number(
+        source_characters: &[char],
+        number_start_index: usize,
+    ) -> Result<(CHeaderToken, usize), String> {
+        let mut character_index = number_start_index;
+
+        while character_index < source_characters.len()
+            && (source_characters[character_index].is_ascii_alphanumeric()
+                || source_characters[character_index] == '.'
+                || source_characters[character_index] == '\''
+                || ((source_characters[character_index] == '+' || source_characters[character_index] == '-')
+                    && matches!(source_characters[character_index - 1], 'e' | 'E' | 'p' | 'P')))
+        {
+            character_index += 1;
+        }
+
+        let number_text = source_characters[number_start_index..character_index]
+            .iter()
+            .filter(|number_character| **number_character != '\'')
+            .collect::<String>();
+        let lowercase_number_text = number_text.to_ascii_lowercase();
+        let is_hexadecimal = lowercase_number_text.starts_with("0x");
+
+        if !is_hexadecimal && (lowercase_number_text.contains('.') || lowercase_number_text.contains('e')) {
+            return Ok((CHeaderToken::Other, character_index));
+        }
+
+        let digits_text = lowercase_number_text.trim_end_matches(['u', 'l', 'z']);
+        let parsed_value = if let Some(hexadecimal_digits) = digits_text.strip_prefix("0x") {
+            i128::from_str_radix(hexadecimal_digits, 16)
+        } else if let Some(binary_digits) = digits_text.strip_prefix("0b") {
+            i128::from_str_radix(binary_digits, 2)
+        } else if digits_text.len() > 1 && digits_text.starts_with('0') {
+            i128::from_str_radix(&digits_text[1..], 8)
+        } else {
+            digits_text.parse::<i128>()
+        };
+
+        parsed_value
+            .map(|value| (CHeaderToken::Integer(value), character_index))
+            .map_err(|_| format!("Invalid integer literal `{}` in C header.", number_text))
+    }
+
+    fn read_quoted(
+        source_characters: &[char],
+        quote_start_index: usize,
+        quote_character: char,
+    ) -> Result<(String, usize), String> {
+        let mut literal_text = String::new();
+        let mut character_index = quote_start_index + 1;
+
+        while character_index < source_characters.len() {
+            let current_character = source_characters[character_index];
+
+            if current_character == '\\' {
+                if let Some(escaped_character) = source_characters.get(character_index + 1) {
+                    literal_text.push(match escaped_character {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        '0' => '\0',
+                        other_character => *other_character,
+                    });
+                }
+
+                character_index += 2;
+                continue;
+            }
+
+            if current_character == quote_character {
+                return Ok((literal_text, character_index + 1));
+            }
+
+            if current_character == '\n' {
+                break;
+            }
+
+            literal_text.push(current_character);
+            character_index += 1;
+        }
+
+        Err(String::from("Unterminated literal in C header."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CHeaderPackDirective, CHeaderToken, CHeaderTokenizer};
+
+    #[test]
+    fn tokenizes_identifiers_numbers_and_skips_comments() {
+        let tokens = CHeaderTokenizer::tokenize("int /* hp */ health[0x10]; // trailing\nchar c = 'A';").expect("Expected source to tokenize.");
+
+        assert_eq!(tokens[0], CHeaderToken::Identifier(String::from("int")));
+        assert_eq!(tokens[1], CHeaderToken::Identifier(String::from("health")));
+        assert_eq!(tokens[3], CHeaderToken::Integer(16));
+        assert!(tokens.contains(&CHeaderToken::Integer(65)));
+    }
+
+    #[test]
+    fn folds_pack_and_define_directives() {
+        let tokens = CHeaderTokenizer::tokenize("#pragma pack(push, 1)\n#define MAX_ITEMS (4 * 8)\n#include <stdint.h>\n#pragma pack(pop)\n")
+            .expect("Expected directives to tokenize.");
+
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[0], CHeaderToken::Pack(CHeaderPackDirective::Push(Some(1))));
+        assert!(matches!(&tokens[1], CHeaderToken::Define { name, value_tokens } if name == "MAX_ITEMS" && value_tokens.len() == 5));
+        assert_eq!(tokens[2], CHeaderToken::Pack(CHeaderPackDirective::Pop));
+    }
+
+    #[test]
+    fn skips_function_like_macros() {
+        let tokens = CHeaderTokenizer::tokenize("#define OFFSET(x) ((x) + 4)\n#define GROUPED (1)\n").expect("Expected macros to tokenize.");
+
+        assert_eq!(tokens.len(), 1);
+        assert!(matches!(&tokens[0], CHeaderToken::Define { name, .. } if name == "GROUPED"));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod c_header_declaration;
+pub mod c_header_import_options;
+pub mod c_header_layout_exporter;
+pub mod c_header_layout_importer;
+pub mod c_header_parser;
+pub mod c_header_tokenizer;
@@ -0,0 +1,427 @@
+use crate::structures::{
+    memory::{bitness::Bitness, endian::Endian},
+    projects::symbol_interchange::symbol_catalog_export::{
+        SymbolCatalogExport, SymbolExportElementCount, SymbolExportField, SymbolExportFieldOffset, SymbolExportLayout, SymbolExportPrimitive,
+        SymbolExportResult,
+    },
+};
+
+const GHIDRA_SCRIPT_PRELUDE: &str = r#"# Creates the Squalr project symbol layouts in the current program's data type manager.
+# Generated by Squalr from the project symbol catalog.
+# @category Squalr
+from ghidra.program.model.data import ArrayDataType, BooleanDataType, ByteDataType, CategoryPath, CharDataType, DataTypeConflictHandler
+from ghidra.program.model.data import DoubleDataType, DWordDataType, FloatDataType, IntegerDataType, LongLongDataType, PointerDataType
+from ghidra.program.model.data import QWordDataType, ShortDataType, SignedByteDataType, StructureDataType, UnionDataType, WordDataType
+
+CATEGORY = CategoryPath("/Squalr")
+dtm = currentProgram.getDataTypeManager()
+layouts = {}
+union_layouts = set()
+
+
+def declare_layout(name, is_union, size, description):
+    if is_union:
+        data_type = UnionDataType(CATEGORY, name, dtm)
+        union_layouts.add(name)
+    else:
+        data_type = StructureDataType(CATEGORY, name, size, dtm)
+    data_type.setDescription(description)
+    layouts[name] = dtm.addDataType(data_type, DataTypeConflictHandler.REPLACE_HANDLER)
+
+
+def place(layout_name, offset, data_type, name, comment):
+    layout = layouts[layout_name]
+    if layout_name in union_layouts:
+        layout.add(data_type, data_type.getLength(), name, comment)
+    else:
+        layout.replaceAtOffset(offset, data_type, data_type.getLength(), name, comment)
+
+
+def array_of(data_type, count):
+    return ArrayDataType(data_type, count, data_type.getLength(), dtm)
+
+
+def bytes_of(size):
+    if size > 1:
+        return array_of(ByteDataType.dataType, size)
+    return ByteDataType.dataType
+
+
+def pointer_to(data_type, size):
+    return PointerDataType(data_type, size, dtm)
+
+
+def label_module(module_name, offset, name, data_type):
+    if currentProgram.getName().lower() != module_name.lower():
+        return
+    address = currentProgram.getImageBase().add(offset)
+    createLabel(address, name, True)
+    try:
+        clearListing(address, address.add(data_type.getLength() - 1))
+        createData(address, data_type)
+    except Exception as error:
+        println("Unable to apply %s at %s: %s" % (data_type.getName(), address, error))
+
+"#;
+
+/// Exports a symbol catalog as a Ghidra Python script. Running the script from the Script Manager creates every layout
+/// under `/Squalr` in the current program and labels module root fields when the program matches the module name.
+pub struct GhidraScriptExporter;
+
+impl GhidraScriptExporter {
+    pub fn export(
+        symbol_catalog_export: &SymbolCatalogExport,
+        bitness: Bitness,
+    ) -> SymbolExportResult {
+        let mut warnings = Vec::new();
+        let mut script = String::from(GHIDRA_SCRIPT_PRELUDE);
+        let ordered_layouts = symbol_catalog_export.get_layouts_in_dependency_order();
+
+        // Declare every layout up front so pointers can refer to layouts declared later in the catalog.
+        for layout in &ordered_layouts {
+            script.push_str(&format!(
+                "declare_layout({}, {}, {}, {})\n",
+                Self::quote(layout.get_layout_id()),
+                if layout.get_layout_kind().is_union() { "True" } else { "False" },
+                Self::resolve_minimum_layout_size_in_bytes(layout),
+                Self::quote(&Self::build_layout_description(layout))
+            ));
+        }
+
+        for layout in &ordered_layouts {
+            script.push('\n');
+            script.push_str(&Self::export_layout_fields(layout, bitness, &mut warnings));
+        }
+
+        for layout in &ordered_layouts {
+            let Some(module_name) = layout.get_module_name() else {
+                continue;
+            };
+
+            script.push('\n');
+
+            for field in layout.get_fields() {
+                if let Some(offset_in_bytes) = field.get_static_offset() {
+                    script.push_str(&format!(
+                        "label_module({}, 0x{:X}, {}, {})\n",
+                        Self::quote(module_name),
+                        offset_in_bytes,
+                        Self::quote(field.get_field_name()),
+                        Self::build_field_data_type(field, bitness)
+                    ));
+                }
+            }
+        }
+
+        script.push_str("\nprintln(\"Imported %d Squalr layouts.\" % len(layouts))\n");
+
+        SymbolExportResult::new(script, warnings)
+    }
+
+    fn export_layout_fields(
+        layout: &SymbolExportLayout,
+        bitness: Bitness,
+        warnings: &mut Vec<String>,
+    ) -> String {
+        let is_union = layout.get_layout_kind().is_union();
+        let mut layout_script = String::new();
+        let mut static_fields = layout
+            .get_fields()
+            .iter()
+            .filter(|field| field.get_static_offset().is_some())
+            .collect::<Vec<_>>();
+        let mut field_cursor_in_bytes = 0_u64;
+
+        if !is_union {
+            static_fields.sort_by_key(|field| field.get_static_offset());
+        }
+
+        for field in static_fields {
+            let offset_in_bytes = field.get_static_offset().unwrap_or(0);
+
+            if (is_union && offset_in_bytes != 0) || (!is_union && offset_in_bytes < field_cursor_in_bytes) {
+                warnings.push(format!(
+                    "Field `{}` in `{}` overlaps another field and was left out of the Ghidra script.",
+                    field.get_field_name(),
+                    layout.get_layout_id()
+                ));
+                continue;
+            }
+
+            layout_script.push_str(&format!(
+                "place({}, 0x{:X}, {}, {}, {})\n",
+                Self::quote(layout.get_layout_id()),
+                offset_in_bytes,
+                Self::build_field_data_type(field, bitness),
+                Self::quote(field.get_field_name()),
+                Self::build_field_comment(field)
+            ));
+
+            if !is_union {
+                // Runtime-sized arrays are placed with a single element, matching how Squalr lays out the fields that follow.
+                let size_in_bytes = field
+                    .get_static_size_in_bytes()
+                    .or_else(|| field.get_slot_size_in_bytes())
+                    .unwrap_or(1);
+
+                field_cursor_in_bytes = offset_in_bytes.saturating_add(size_in_bytes);
+            }
+        }
+
+        if is_union {
+            let largest_member_size_in_bytes = layout
+                .get_fields()
+                .iter()
+                .filter_map(|field| field.get_static_size_in_bytes())
+                .max()
+                .unwrap_or(0);
+
+            if let Some(size_in_bytes) = layout
+                .get_size_in_bytes()
+                .filter(|size_in_bytes| *size_in_bytes > largest_member_size_in_bytes)
+            {
+                layout_script.push_str(&format!(
+                    "place({}, 0, bytes_of({}), \"_size\", None)\n",
+                    Self::quote(layout.get_layout_id()),
+                    size_in_bytes
+                ));
+            }
+        }
+
+        layout_script
+    }
+
+    fn build_field_data_type(
+        field: &SymbolExportField,
+        bitness: Bitness,
+    ) -> String {
+        let element_data_type = match field.get_pointer_size() {
+            Some(pointer_size) => {
+                let pointee_data_type = if field.is_layout_reference() {
+                    format!("layouts[{}]", Self::quote(field.get_data_type_id()))
+                } else if SymbolExportPrimitive::from_data_type_id(field.get_data_type_id()).is_some() {
+                    Self::build_primitive_data_type(field.get_data_type_id(), field.get_element_size_in_bytes().unwrap_or(1))
+                } else {
+                    String::from("None")
+                };
+
+                if pointer_size.get_size_in_bytes() == Self::get_pointer_size_in_bytes(bitness) {
+                    format!("pointer_to({}, -1)", pointee_data_type)
+                } else {
+                    format!("pointer_to({}, {})", pointee_data_type, pointer_size.get_size_in_bytes())
+                }
+            }
+            None if field.is_layout_reference() => format!("layouts[{}]", Self::quote(field.get_data_type_id())),
+            None => Self::build_primitive_data_type(field.get_data_type_id(), field.get_element_size_in_bytes().unwrap_or(1)),
+        };
+
+        match field.get_element_count() {
+            SymbolExportElementCount::Fixed(element_count) => format!("array_of({}, {})", element_data_type, element_count),
+            SymbolExportElementCount::Single | SymbolExportElementCount::Inferred | SymbolExportElementCount::Resolved(_) => element_data_type,
+        }
+    }
+
+    fn build_primitive_data_type(
+        data_type_id: &str,
+        element_size_in_bytes: u64,
+    ) -> String {
+        match SymbolExportPrimitive::from_data_type_id(data_type_id) {
+            Some(SymbolExportPrimitive::Integer { size_in_bytes, is_signed, .. }) => match (size_in_bytes, is_signed) {
+                (1, true) => String::from("SignedByteDataType.dataType"),
+                (1, false) => String::from("ByteDataType.dataType"),
+                (2, true) => String::from("ShortDataType.dataType"),
+                (2, false) => String::from("WordDataType.dataType"),
+                (4, true) => String::from("IntegerDataType.dataType"),
+                (4, false) => String::from("DWordDataType.dataType"),
+                (8, true) => String::from("LongLongDataType.dataType"),
+                (8, false) => String::from("QWordDataType.dataType"),
+                _ => format!("bytes_of({})", size_in_bytes),
+            },
+            Some(SymbolExportPrimitive::Float { size_in_bytes: 4, .. }) => String::from("FloatDataType.dataType"),
+            Some(SymbolExportPrimitive::Float { .. }) => String::from("DoubleDataType.dataType"),
+            Some(SymbolExportPrimitive::Bool { size_in_bytes: 1 }) => String::from("BooleanDataType.dataType"),
+            Some(SymbolExportPrimitive::Bool { size_in_bytes }) => Self::build_primitive_data_type(&format!("u{}", size_in_bytes * 8), size_in_bytes),
+            Some(SymbolExportPrimitive::Text) if element_size_in_bytes > 1 => format!("array_of(CharDataType.dataType, {})", element_size_in_bytes),
+            Some(SymbolExportPrimitive::Text) => String::from("CharDataType.dataType"),
+            None => format!("bytes_of({})", element_size_in_bytes),
+        }
+    }
+
+    fn build_field_comment(field: &SymbolExportField) -> String {
+        let mut comments = Vec::new();
+
+        if SymbolExportPrimitive::from_data_type_id(field.get_data_type_id()).is_some_and(|symbol_export_primitive| symbol_export_primitive.is_big_endian()) {
+            comments.push(String::from("big-endian"));
+        }
+
+        if let Some(pointer_size) = field.get_pointer_size()
+            && pointer_size.get_endian() == Endian::Big
+        {
+            comments.push(format!("{} pointer", pointer_size));
+        }
+
+        match field.get_element_count() {
+            SymbolExportElementCount::Resolved(count_expression) => comments.push(format!("count: {}", count_expression)),
+            SymbolExportElementCount::Inferred => comments.push(String::from("count: inferred at runtime")),
+            SymbolExportElementCount::Single | SymbolExportElementCount::Fixed(_) => {}
+        }
+
+        if let Some(active_when_expression) = field.get_active_when_expression() {
+            comments.push(format!("active when: {}", active_when_expression));
+        }
+
+        if comments.is_empty() {
+            String::from("None")
+        } else {
+            Self::quote(&comments.join("; "))
+        }
+    }
+
+    fn build_layout_description(layout: &SymbolExportLayout) -> String {
+        let runtime_notes = layout
+            .get_fields()
+            .iter()
+            .filter_map(|field| match field.get_offset() {
+                SymbolExportFieldOffset::Resolved(offset_expression) => Some(format!("{} at {}", field.get_field_name(), offset_expression)),
+                SymbolExportFieldOffset::FollowsDynamicField(field_name) => Some(format!("{} follows {}", field.get_field_name(), field_name)),
+                SymbolExportFieldOffset::Static(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        if runtime_notes.is_empty() {
+            format!("Squalr layout {}", layout.get_layout_id())
+        } else {
+            format!("Squalr layout {}. Runtime-resolved: {}", layout.get_layout_id(), runtime_notes.join("; "))
+        }
+    }
+
+    /// Gets the declared size, or the extent of the statically placed fields when the size depends on a resolver.
+    fn resolve_minimum_layout_size_in_bytes(layout: &SymbolExportLayout) -> u64 {
+        layout.get_size_in_bytes().unwrap_or_else(|| {
+            layout
+                .get_fields()
+                .iter()
+                .filter_map(|field| {
+                    let size_in_bytes = field
+                        .get_static_size_in_bytes()
+                        .or_else(|| field.get_slot_size_in_bytes())?;
+
+                    field
+                        .get_static_offset()
+                        .map(|offset_in_bytes| offset_in_bytes.saturating_add(size_in_bytes))
+                })
+                .max()
+                .unwrap_or(0)
+        })
+    }
+
+    fn get_pointer_size_in_bytes(bitness: Bitness) -> u64 {
+        match bitness {
+            Bitness::Bit32 => 4,
+            Bitness::Bit64 => 8,
+        }
+    }
+
+    fn quote(text: &str) -> String {
+        let mut quoted_text = String::from("\"");
+
+        for character in text.chars() {
+            match character {
+                '\\' => quoted_text.push_str("\\\\"),
+                '"' => quoted_text.push_str("\\\""),
+                '\n' => quoted_text.push_str("\\n"),
+                '\r' => quoted_text.push_str("\\r"),
+                _ => quoted_text.push(character),
+            }
+        }
+
+        quoted_text.push('"');
+
+        quoted_text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GhidraScriptExporter;
+    use crate::registries::symbols::{struct_layout_descriptor::StructLayoutDescriptor, symbolic_resolver_descriptor::SymbolicResolverDescriptor};
+    use crate::structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::{container_type::ContainerType, pointer_scan_pointer_size::PointerScanPointerSize},
+        memory::bitness::Bitness,
+        projects::{
+            project_symbol_catalog::ProjectSymbolCatalog, project_symbol_module::ProjectSymbolModule, project_symbol_module_field::ProjectSymbolModuleField,
+            symbol_interchange::symbol_catalog_export::SymbolCatalogExport,
+        },
+        structs::{
+            symbolic_field_definition::{SymbolicFieldCountResolution, SymbolicFieldDefinition, SymbolicFieldOffsetResolution},
+            symbolic_resolver_definition::{SymbolicResolverDefinition, SymbolicResolverNode},
+            symbolic_struct_definition::SymbolicStructDefinition,
+        },
+    };
+
+    fn resolve_test_data_type_size_in_bytes(data_type_ref: &DataTypeRef) -> Option<u64> {
+        match data_type_ref.get_data_type_id() {
+            "u8" => Some(1),
+            "u16be" => Some(2),
+            "i32" => Some(4),
+            "f64" => Some(8),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn exports_layouts_pointers_and_module_labels() {
+        let mut symbol_module = ProjectSymbolModule::new(String::from("game.exe"), 0x100);
+        symbol_module
+            .get_fields_mut()
+            .push(ProjectSymbolModuleField::new(String::from("World"), 0x80, String::from("World")));
+        let project_symbol_catalog = ProjectSymbolCatalog::new_with_modules_resolvers_and_symbol_claims(
+            vec![symbol_module],
+            vec![StructLayoutDescriptor::new(
+                String::from("World"),
+                SymbolicStructDefinition::new(
+                    String::from("World"),
+                    vec![
+                        SymbolicFieldDefinition::new_named(String::from("entity_count"), DataTypeRef::new("i32"), ContainerType::None),
+                        SymbolicFieldDefinition::new_named(
+                            String::from("self"),
+                            DataTypeRef::new("World"),
+                            ContainerType::Pointer(PointerScanPointerSize::Pointer32),
+                        )
+                        .with_offset_resolution(SymbolicFieldOffsetResolution::new_static(8)),
+                        SymbolicFieldDefinition::new_named(String::from("gravity"), DataTypeRef::new("f64"), ContainerType::None),
+                        SymbolicFieldDefinition::new_named_with_resolutions(
+                            String::from("flags"),
+                            DataTypeRef::new("u16be"),
+                            ContainerType::Array,
+                            SymbolicFieldCountResolution::new_resolver(String::from("world.flag_count")),
+                            SymbolicFieldOffsetResolution::Sequential,
+                        ),
+                    ],
+                ),
+            )],
+            vec![SymbolicResolverDescriptor::new(
+                String::from("world.flag_count"),
+                SymbolicResolverDefinition::new(SymbolicResolverNode::new_local_field(String::from("entity_count"))),
+            )],
+            Vec::new(),
+        );
+        let symbol_catalog_export = SymbolCatalogExport::from_project_symbol_catalog(&project_symbol_catalog, resolve_test_data_type_size_in_bytes);
+        let export_result = GhidraScriptExporter::export(&symbol_catalog_export, Bitness::Bit64);
+        let script = export_result.get_contents();
+
+        assert!(script.contains("# @category Squalr"));
+        assert!(script.contains("declare_layout(\"World\", False, 22, \"Squalr layout World\")"));
+        assert!(script.contains("place(\"World\", 0x0, IntegerDataType.dataType, \"entity_count\", None)"));
+        assert!(script.contains("place(\"World\", 0x8, pointer_to(layouts[\"World\"], 4), \"self\", None)"));
+        assert!(script.contains("place(\"World\", 0x14, WordDataType.dataType, \"flags\", \"big-endian; count: entity_count\")"));
+        assert!(script.contains("label_module(\"game.exe\", 0x80, \"World\", layouts[\"World\"])"));
+        assert!(export_result.get_warnings().is_empty());
+    }
+
+    #[test]
+    fn quotes_python_strings() {
+        assert_eq!(GhidraScriptExporter::quote("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+    }
+}
@@ -0,0 +1 @@
+pub mod ghidra_script_exporter;
@@ -0,0 +1,5 @@
+pub mod c_header;
+pub mod ghidra;
+pub mod reclass;
+pub mod symbol_catalog_export;
+pub mod symbol_export_format;
@@ -0,0 +1 @@
+pub mod reclass_layout_exporter;
@@ -0,0 +1,480 @@
+use crate::structures::{
+    data_values::pointer_scan_pointer_size::PointerScanPointerSize,
+    memory::bitness::Bitness,
+    projects::symbol_interchange::symbol_catalog_export::{
+        SymbolCatalogExport, SymbolExportElementCount, SymbolExportField, SymbolExportFieldOffset, SymbolExportLayout, SymbolExportPrimitive,
+        SymbolExportResult,
+    },
+};
+use std::collections::HashMap;
+
+const RECLASS_FILE_VERSION: u32 = 0x0001_0001;
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Exports a symbol catalog as the `Data.xml` document stored inside a ReClass.NET `.rcnet` archive. ReClass.NET lays
+/// nodes out sequentially, so gaps become hex nodes and fields with runtime-resolved offsets are listed in the class comment.
+pub struct ReClassLayoutExporter;
+
+impl ReClassLayoutExporter {
+    /// The name of the XML document inside a `.rcnet` archive.
+    pub const DATA_FILE_NAME: &'static str = "Data.xml";
+
+    pub fn export(
+        symbol_catalog_export: &SymbolCatalogExport,
+        bitness: Bitness,
+    ) -> SymbolExportResult {
+        let class_uuids = symbol_catalog_export
+            .get_layouts()
+            .iter()
+            .map(|layout| (layout.get_layout_id().to_string(), Self::build_class_uuid(layout.get_layout_id())))
+            .collect::<HashMap<_, _>>();
+        let platform = match bitness {
+            Bitness::Bit32 => "x86",
+            Bitness::Bit64 => "x64",
+        };
+        let mut warnings = Vec::new();
+        let mut document = format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<reclass version=\"{}\" type=\"ReClass.NET\" platform=\"{}\">\n  <custom_data />\n  <type_mapping />\n  <enums />\n  <classes>\n",
+            RECLASS_FILE_VERSION, platform
+        );
+
+        for layout in symbol_catalog_export.get_layouts() {
+            document.push_str(&Self::export_class(layout, &class_uuids, bitness, &mut warnings));
+        }
+
+        document.push_str("  </classes>\n</reclass>\n");
+
+        SymbolExportResult::new(document, warnings)
+    }
+
+    fn export_class(
+        layout: &SymbolExportLayout,
+        class_uuids: &HashMap<String, String>,
+        bitness: Bitness,
+        warnings: &mut Vec<String>,
+    ) -> String {
+        let mut node_lines = Vec::new();
+        let mut runtime_notes = Vec::new();
+        let mut field_cursor_in_bytes = 0_u64;
+        let mut static_fields = layout
+            .get_fields()
+            .iter()
+            .filter(|field| field.get_static_offset().is_some())
+            .collect::<Vec<_>>();
+
+        if layout.get_layout_kind().is_union() {
+            // ReClass.NET classes are sequential, so keep the widest union member and describe the others.
+            static_fields.sort_by_key(|field| std::cmp::Reverse(field.get_static_size_in_bytes().unwrap_or(0)));
+
+            if static_fields.len() > 1 {
+                warnings.push(format!("Union `{}` was exported with only its largest member.", layout.get_layout_id()));
+            }
+
+            for field in static_fields.iter().skip(1) {
+                runtime_notes.push(format!("{} overlaps at 0x{:X}", field.get_field_name(), field.get_static_offset().unwrap_or(0)));
+            }
+
+            static_fields.truncate(1);
+        } else {
+            static_fields.sort_by_key(|field| field.get_static_offset());
+        }
+
+        for field in static_fields {
+            let offset_in_bytes = field.get_static_offset().unwrap_or(0);
+
+            if offset_in_bytes < field_cursor_in_bytes {
+                warnings.push(format!(
+                    "Field `{}` in `{}` overlaps another field and was exported as a comment.",
+                    field.get_field_name(),
+                    layout.get_layout_id()
+                ));
+                runtime_notes.push(format!("{} overlaps at 0x{:X}", field.get_field_name(), offset_in_bytes));
+                continue;
+            }
+
+            Self::push_padding_nodes(&mut node_lines, offset_in_bytes - field_cursor_in_bytes);
+            node_lines.push(Self::build_field_node(field, class_uuids, bitness, "    "));
+
+            // Runtime-sized arrays are exported with a single element, matching how Squalr lays out the fields that follow.
+            let size_in_bytes = field
+                .get_static_size_in_bytes()
+                .or_else(|| field.get_slot_size_in_bytes())
+                .unwrap_or(1);
+
+            field_cursor_in_bytes = offset_in_bytes.saturating_add(size_in_bytes);
+        }
+
+        for field in layout.get_fields() {
+            match field.get_offset() {
+                SymbolExportFieldOffset::Resolved(offset_expression) => runtime_notes.push(format!("{} at {}", field.get_field_name(), offset_expression)),
+                SymbolExportFieldOffset::FollowsDynamicField(field_name) => runtime_notes.push(format!("{} follows {}", field.get_field_name(), field_name)),
+                SymbolExportFieldOffset::Static(_) => {}
+            }
+        }
+
+        if let Some(size_in_bytes) = layout.get_size_in_bytes() {
+            Self::push_padding_nodes(&mut node_lines, size_in_bytes.saturating_sub(field_cursor_in_bytes));
+        }
+
+        let class_comment = if runtime_notes.is_empty() {
+            String::new()
+        } else {
+            format!("Runtime-resolved: {}", runtime_notes.join("; "))
+        };
+        let class_address = match layout.get_module_name() {
+            Some(module_name) => format!("<{}>", module_name),
+            None => String::from("0"),
+        };
+        let mut class_element = format!(
+            "    <class uuid=\"{}\" name=\"{}\" comment=\"{}\" address=\"{}\">\n",
+            class_uuids[layout.get_layout_id()],
+            Self::escape_xml(layout.get_layout_id()),
+            Self::escape_xml(&class_comment),
+            Self::escape_xml(&class_address)
+        );
+
+        for node_line in node_lines {
+            class_element.push_str(&node_line);
+        }
+
+        class_element.push_str("    </class>\n");
+
+        class_element
+    }
+
+    fn build_field_node(
+        field: &SymbolExportField,
+        class_uuids: &HashMap<String, String>,
+        bitness: Bitness,
+        indentation: &str,
+    ) -> String {
+        let mut comments = Vec::new();
+
+        match field.get_element_count() {
+            SymbolExportElementCount::Resolved(count_expression) => comments.push(format!("count: {}", count_expression)),
+            SymbolExportElementCount::Inferred => comments.push(String::from("count: inferred at runtime")),
+            SymbolExportElementCount::Single | SymbolExportElementCount::Fixed(_) => {}
+        }
+
+        if let Some(active_when_expression) = field.get_active_when_expression() {
+            comments.push(format!("active when: {}", active_when_expression));
+        }
+
+        let element_count = match field.get_element_count() {
+            SymbolExportElementCount::Single => None,
+            SymbolExportElementCount::Fixed(element_count) => Some(*element_count),
+            SymbolExportElementCount::Inferred | SymbolExportElementCount::Resolved(_) => Some(1),
+        };
+        let inner_indentation = format!("{}  ", indentation);
+        let element_node = |node_name: &str, node_comments: &[String], node_indentation: &str| match field.get_pointer_size() {
+            Some(pointer_size) if pointer_size == PointerScanPointerSize::from_process_bitness(bitness) => {
+                let pointee_node = Self::build_element_node(field, class_uuids, "", &[], &format!("{}  ", node_indentation));
+
+                Self::format_node("PointerNode", node_name, &node_comments.join("; "), &[], node_indentation, Some(pointee_node))
+            }
+            Some(pointer_size) => {
+                let mut pointer_comments = node_comments.to_vec();
+
+                pointer_comments.push(format!("{} pointer to {}", pointer_size, field.get_data_type_id()));
+
+                Self::build_primitive_node(
+                    SymbolExportPrimitive::from_data_type_id(pointer_size.to_data_type_ref().get_data_type_id()),
+                    pointer_size.get_size_in_bytes(),
+                    node_name,
+                    &pointer_comments,
+                    node_indentation,
+                )
+            }
+            None => Self::build_element_node(field, class_uuids, node_name, node_comments, node_indentation),
+        };
+
+        match element_count {
+            Some(element_count) => {
+                let inner_node = element_node("", &[], &inner_indentation);
+
+                Self::format_node(
+                    "ArrayNode",
+                    field.get_field_name(),
+                    &comments.join("; "),
+                    &[("count", element_count.to_string())],
+                    indentation,
+                    Some(inner_node),
+                )
+            }
+            None => element_node(field.get_field_name(), &comments, indentation),
+        }
+    }
+
+    fn build_element_node(
+        field: &SymbolExportField,
+        class_uuids: &HashMap<String, String>,
+        node_name: &str,
+        comments: &[String],
+        indentation: &str,
+    ) -> String {
+        if field.is_layout_reference()
+            && let Some(class_uuid) = class_uuids.get(field.get_data_type_id())
+        {
+            return Self::format_node(
+                "ClassInstanceNode",
+                node_name,
+                &comments.join("; "),
+                &[("reference", class_uuid.clone())],
+                indentation,
+                None,
+            );
+        }
+
+        let symbol_export_primitive = SymbolExportPrimitive::from_data_type_id(field.get_data_type_id());
+        let mut comments = comments.to_vec();
+
+        if symbol_export_primitive.is_none() {
+            comments.push(format!("data type {}", field.get_data_type_id()));
+        }
+
+        Self::build_primitive_node(
+            symbol_export_primitive,
+            field.get_element_size_in_bytes().unwrap_or(1),
+            node_name,
+            &comments,
+            indentation,
+        )
+    }
+
+    fn build_primitive_node(
+        symbol_export_primitive: Option<SymbolExportPrimitive>,
+        element_size_in_bytes: u64,
+        node_name: &str,
+        comments: &[String],
+        indentation: &str,
+    ) -> String {
+        let mut comments = comments.to_vec();
+
+        if symbol_export_primitive.is_some_and(|symbol_export_primitive| symbol_export_primitive.is_big_endian()) {
+            comments.push(String::from("big-endian"));
+        }
+
+        let node_type = match symbol_export_primitive {
+            Some(SymbolExportPrimitive::Integer {
+                size_in_bytes,
+                is_signed: true,
+                ..
+            }) if matches!(size_in_bytes, 1 | 2 | 4 | 8) => format!("Int{}Node", size_in_bytes * 8),
+            Some(SymbolExportPrimitive::Integer { size_in_bytes, .. }) if matches!(size_in_bytes, 1 | 2 | 4 | 8) => format!("UInt{}Node", size_in_bytes * 8),
+            Some(SymbolExportPrimitive::Float { size_in_bytes: 4, .. }) => String::from("FloatNode"),
+            Some(SymbolExportPrimitive::Float { .. }) => String::from("DoubleNode"),
+            Some(SymbolExportPrimitive::Bool { size_in_bytes: 1 }) => String::from("BoolNode"),
+            Some(SymbolExportPrimitive::Bool { size_in_bytes }) if matches!(size_in_bytes, 2 | 4 | 8) => format!("UInt{}Node", size_in_bytes * 8),
+            Some(SymbolExportPrimitive::Text) => {
+                return Self::format_node(
+                    "Utf8TextNode",
+                    node_name,
+                    &comments.join("; "),
+                    &[("length", element_size_in_bytes.max(1).to_string())],
+                    indentation,
+                    None,
+                );
+            }
+            Some(SymbolExportPrimitive::Integer { size_in_bytes, .. }) | Some(SymbolExportPrimitive::Bool { size_in_bytes }) => {
+                return Self::build_byte_array_node(size_in_bytes, node_name, &comments, indentation);
+            }
+            None => return Self::build_byte_array_node(element_size_in_bytes, node_name, &comments, indentation),
+        };
+
+        Self::format_node(&node_type, node_name, &comments.join("; "), &[], indentation, None)
+    }
+
+    fn build_byte_array_node(
+        size_in_bytes: u64,
+        node_name: &str,
+        comments: &[String],
+        indentation: &str,
+    ) -> String {
+        if size_in_bytes <= 1 {
+            return Self::format_node("UInt8Node", node_name, &comments.join("; "), &[], indentation, None);
+        }
+
+        let inner_node = Self::format_node("UInt8Node", "", "", &[], &format!("{}  ", indentation), None);
+
+        Self::format_node(
+            "ArrayNode",
+            node_name,
+            &comments.join("; "),
+            &[("count", size_in_bytes.to_string())],
+            indentation,
+            Some(inner_node),
+        )
+    }
+
+    fn push_padding_nodes(
+        node_lines: &mut Vec<String>,
+        mut padding_in_bytes: u64,
+    ) {
+        for (hex_node_size_in_bytes, hex_node_type) in [
+            (8, "Hex64Node"),
+            (4, "Hex32Node"),
+            (2, "Hex16Node"),
+            (1, "Hex8Node"),
+        ] {
+            while padding_in_bytes >= hex_node_size_in_bytes {
+                node_lines.push(Self::format_node(hex_node_type, "", "", &[], "    ", None));
+                padding_in_bytes -= hex_node_size_in_bytes;
+            }
+        }
+    }
+
+    fn format_node(
+        node_type: &str,
+        node_name: &str,
+        comment: &str,
+        extra_attributes: &[(&str, String)],
+        indentation: &str,
+        inner_node: Option<String>,
+    ) -> String {
+        let mut node = format!(
+            "  {}<node type=\"{}\" name=\"{}\" comment=\"{}\" hidden=\"false\"",
+            indentation,
+            node_type,
+            Self::escape_xml(node_name),
+            Self::escape_xml(comment)
+        );
+
+        for (attribute_name, attribute_value) in extra_attributes {
+            node.push_str(&format!(" {}=\"{}\"", attribute_name, Self::escape_xml(attribute_value)));
+        }
+
+        match inner_node {
+            Some(inner_node) => node.push_str(&format!(">\n{}  {}</node>\n", inner_node, indentation)),
+            None => node.push_str(" />\n"),
+        }
+
+        node
+    }
+
+    /// Builds a stable ReClass.NET class uuid (a base64 encoded GUID) from the layout id, so re-exports keep references intact.
+    fn build_class_uuid(layout_id: &str) -> String {
+        let mut uuid_bytes = [0_u8; 16];
+        let low_hash = Self::hash_fnv1a(layout_id.as_bytes(), 0xCBF2_9CE4_8422_2325);
+        let high_hash = Self::hash_fnv1a(layout_id.as_bytes(), low_hash ^ 0x9E37_79B9_7F4A_7C15);
+
+        uuid_bytes[..8].copy_from_slice(&low_hash.to_le_bytes());
+        uuid_bytes[8..].copy_from_slice(&high_hash.to_le_bytes());
+
+        Self::encode_base64(&uuid_bytes)
+    }
+
+    fn hash_fnv1a(
+        bytes: &[u8],
+        seed: u64,
+    ) -> u64 {
+        bytes
+            .iter()
+            .fold(seed, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01B3))
+    }
+
+    fn encode_base64(bytes: &[u8]) -> String {
+        let mut encoded = String::new();
+
+        for chunk in bytes.chunks(3) {
+            let chunk_value = (u32::from(chunk[0]) << 16) | (u32::from(*chunk.get(1).unwrap_or(&0)) << 8) | u32::from(*chunk.get(2).unwrap_or(&0));
+
+            for character_index in 0..4 {
+                if character_index <= chunk.len() {
+                    encoded.push(BASE64_ALPHABET[((chunk_value >> (18 - character_index * 6)) & 0x3F) as usize] as char);
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+
+        encoded
+    }
+
+    fn escape_xml(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&apos;")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReClassLayoutExporter;
+    use crate::registries::symbols::struct_layout_descriptor::StructLayoutDescriptor;
+    use crate::structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::{container_type::ContainerType, pointer_scan_pointer_size::PointerScanPointerSize},
+        memory::bitness::Bitness,
+        projects::{
+            project_symbol_catalog::ProjectSymbolCatalog, project_symbol_module::ProjectSymbolModule, project_symbol_module_field::ProjectSymbolModuleField,
+            symbol_interchange::symbol_catalog_export::SymbolCatalogExport,
+        },
+        structs::{
+            symbolic_field_definition::{SymbolicFieldDefinition, SymbolicFieldOffsetResolution},
+            symbolic_struct_definition::SymbolicStructDefinition,
+        },
+    };
+
+    fn resolve_test_data_type_size_in_bytes(data_type_ref: &DataTypeRef) -> Option<u64> {
+        match data_type_ref.get_data_type_id() {
+            "u8" | "bool8" => Some(1),
+            "i32" | "f32" => Some(4),
+            "u64" => Some(8),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn exports_classes_with_padding_pointers_and_module_addresses() {
+        let mut symbol_module = ProjectSymbolModule::new(String::from("game.exe"), 0x20);
+        symbol_module
+            .get_fields_mut()
+            .push(ProjectSymbolModuleField::new(String::from("LocalPlayer"), 0x10, String::from("Player")));
+        let project_symbol_catalog = ProjectSymbolCatalog::new_with_modules_and_symbol_claims(
+            vec![symbol_module],
+            vec![StructLayoutDescriptor::new(
+                String::from("Player"),
+                SymbolicStructDefinition::new(
+                    String::from("Player"),
+                    vec![
+                        SymbolicFieldDefinition::new_named(String::from("health"), DataTypeRef::new("i32"), ContainerType::None),
+                        SymbolicFieldDefinition::new_named(
+                            String::from("target"),
+                            DataTypeRef::new("Player"),
+                            ContainerType::Pointer(PointerScanPointerSize::Pointer64),
+                        )
+                        .with_offset_resolution(SymbolicFieldOffsetResolution::new_static(8)),
+                        SymbolicFieldDefinition::new_named(String::from("speeds"), DataTypeRef::new("f32"), ContainerType::ArrayFixed(2)),
+                    ],
+                ),
+            )],
+            Vec::new(),
+        );
+        let symbol_catalog_export = SymbolCatalogExport::from_project_symbol_catalog(&project_symbol_catalog, resolve_test_data_type_size_in_bytes);
+        let export_result = ReClassLayoutExporter::export(&symbol_catalog_export, Bitness::Bit64);
+        let document = export_result.get_contents();
+        let player_uuid = ReClassLayoutExporter::build_class_uuid("Player");
+
+        assert_eq!(player_uuid.len(), 24);
+        assert!(document.contains("platform=\"x64\""));
+        assert!(document.contains(&format!("<class uuid=\"{}\" name=\"Player\" comment=\"\" address=\"0\">", player_uuid)));
+        assert!(document.contains("<node type=\"Int32Node\" name=\"health\""));
+        assert!(document.contains("<node type=\"Hex32Node\""));
+        assert!(document.contains(&format!(
+            "<node type=\"ClassInstanceNode\" name=\"\" comment=\"\" hidden=\"false\" reference=\"{}\" />",
+            player_uuid
+        )));
+        assert!(document.contains("<node type=\"ArrayNode\" name=\"speeds\" comment=\"\" hidden=\"false\" count=\"2\">"));
+        assert!(document.contains("name=\"game.exe\" comment=\"\" address=\"&lt;game.exe&gt;\""));
+        assert!(document.contains(&format!("name=\"LocalPlayer\" comment=\"\" hidden=\"false\" reference=\"{}\"", player_uuid)));
+    }
+
+    #[test]
+    fn encodes_base64_with_padding() {
+        assert_eq!(ReClassLayoutExporter::encode_base64(b"Man"), "TWFu");
+        assert_eq!(ReClassLayoutExporter::encode_base64(b"Ma"), "TWE=");
+        assert_eq!(ReClassLayoutExporter::encode_base64(b"M"), "TQ==");
+    }
+}
@@ -0,0 +1,680 @@
+use crate::structures::{
+    data_types::data_type_ref::DataTypeRef,
+    data_values::{container_type::ContainerType, pointer_scan_pointer_size::PointerScanPointerSize},
+    projects::{project_symbol_catalog::ProjectSymbolCatalog, symbol_layouts::symbol_layout_size_resolver::SymbolLayoutSizeResolver},
+    structs::{
+        symbolic_field_definition::{SymbolicFieldCountResolution, SymbolicFieldDefinition, SymbolicFieldOffsetResolution},
+        symbolic_resolver_definition::{SymbolicResolverBinaryOperator, SymbolicResolverNode},
+        symbolic_struct_definition::{SymbolicLayoutKind, SymbolicStructDefinition},
+    },
+};
+use std::collections::{BTreeSet, HashSet};
+
+/// The primitive shape of a built-in data type, used to map fields onto the native types of an export target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SymbolExportPrimitive {
+    Integer { size_in_bytes: u64, is_signed: bool, is_big_endian: bool },
+    Float { size_in_bytes: u64, is_big_endian: bool },
+    Bool { size_in_bytes: u64 },
+    Text,
+}
+
+impl SymbolExportPrimitive {
+    pub fn from_data_type_id(data_type_id: &str) -> Option<Self> {
+        let base_data_type_id = data_type_id
+            .split_once('{')
+            .map_or(data_type_id, |(base_data_type_id, _)| base_data_type_id);
+
+        if base_data_type_id.starts_with("string") {
+            return Some(Self::Text);
+        }
+
+        if let Some(bit_count) = base_data_type_id.strip_prefix("bool") {
+            return Self::parse_bit_count(bit_count).map(|size_in_bytes| Self::Bool { size_in_bytes });
+        }
+
+        let (base_data_type_id, is_big_endian) = match base_data_type_id.strip_suffix("be") {
+            Some(base_data_type_id) => (base_data_type_id, true),
+            None => (base_data_type_id, false),
+        };
+        let mut characters = base_data_type_id.chars();
+        let kind = characters.next()?;
+        let size_in_bytes = Self::parse_bit_count(characters.as_str())?;
+
+        match kind {
+            'u' | 'i' => Some(Self::Integer {
+                size_in_bytes,
+                is_signed: kind == 'i',
+                is_big_endian,
+            }),
+            'f' if size_in_bytes == 4 || size_in_bytes == 8 => Some(Self::Float { size_in_bytes, is_big_endian }),
+            _ => None,
+        }
+    }
+
+    pub fn is_big_endian(&self) -> bool {
+        match self {
+            Self::Integer { is_big_endian, .. } | Self::Float { is_big_endian, .. } => *is_big_endian,
+            Self::Bool { .. } | Self::Text => false,
+        }
+    }
+
+    fn parse_bit_count(bit_count: &str) -> Option<u64> {
+        match bit_count.parse::<u64>() {
+            Ok(bit_count) if bit_count > 0 && bit_count % 8 == 0 => Some(bit_count / 8),
+            _ => None,
+        }
+    }
+}
+
+/// The text produced by a symbol exporter, along with anything that could not be represented faithfully.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SymbolExportResult {
+    contents: String,
+    warnings: Vec<String>,
+}
+
+impl SymbolExportResult {
+    pub fn new(
+        contents: String,
+        warnings: Vec<String>,
+    ) -> Self {
+        Self { contents, warnings }
+    }
+
+    pub fn get_contents(&self) -> &str {
+        &self.contents
+    }
+
+    pub fn take_contents(self) -> String {
+        self.contents
+    }
+
+    pub fn get_warnings(&self) -> &[String] {
+        &self.warnings
+    }
+}
+
+/// How many elements an exported field holds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SymbolExportElementCount {
+    Single,
+    Fixed(u64),
+    /// An unbounded array whose length is inferred at runtime.
+    Inferred,
+    /// An array whose length is computed by a resolver. Holds the rendered resolver expression.
+    Resolved(String),
+}
+
+/// Where an exported field lives within its layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SymbolExportFieldOffset {
+    Static(u64),
+    /// The offset is computed by a resolver. Holds the rendered resolver expression.
+    Resolved(String),
+    /// The field is sequential, but follows a field whose size is only known at runtime.
+    FollowsDynamicField(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SymbolExportField {
+    field_name: String,
+    data_type_id: String,
+    is_layout_reference: bool,
+    pointer_size: Option<PointerScanPointerSize>,
+    element_count: SymbolExportElementCount,
+    element_size_in_bytes: Option<u64>,
+    offset: SymbolExportFieldOffset,
+    active_when_expression: Option<String>,
+}
+
+impl SymbolExportField {
+    pub fn get_field_name(&self) -> &str {
+        &self.field_name
+    }
+
+    pub fn get_data_type_id(&self) -> &str {
+        &self.data_type_id
+    }
+
+    /// Gets whether the data type id refers to another layout in the catalog rather than a primitive data type.
+    pub fn is_layout_reference(&self) -> bool {
+        self.is_layout_reference
+    }
+
+    pub fn get_pointer_size(&self) -> Option<PointerScanPointerSize> {
+        self.pointer_size
+    }
+
+    pub fn get_element_count(&self) -> &SymbolExportElementCount {
+        &self.element_count
+    }
+
+    /// Gets the size of a single element. For pointer fields this is the pointee size.
+    pub fn get_element_size_in_bytes(&self) -> Option<u64> {
+        self.element_size_in_bytes
+    }
+
+    pub fn get_offset(&self) -> &SymbolExportFieldOffset {
+        &self.offset
+    }
+
+    pub fn get_static_offset(&self) -> Option<u64> {
+        match self.offset {
+            SymbolExportFieldOffset::Static(offset_in_bytes) => Some(offset_in_bytes),
+            _ => None,
+        }
+    }
+
+    pub fn get_active_when_expression(&self) -> Option<&str> {
+        self.active_when_expression.as_deref()
+    }
+
+    /// Gets the size of a single slot, which is the pointer size for pointer fields and the element size otherwise.
+    pub fn get_slot_size_in_bytes(&self) -> Option<u64> {
+        match self.pointer_size {
+            Some(pointer_size) => Some(pointer_size.get_size_in_bytes()),
+            None => self.element_size_in_bytes,
+        }
+    }
+
+    /// Gets the total size of the field when it can be known without reading process memory.
+    pub fn get_static_size_in_bytes(&self) -> Option<u64> {
+        let slot_size_in_bytes = self.get_slot_size_in_bytes()?;
+
+        match self.element_count {
+            SymbolExportElementCount::Single => Some(slot_size_in_bytes),
+            SymbolExportElementCount::Fixed(element_count) => slot_size_in_bytes.checked_mul(element_count),
+            SymbolExportElementCount::Inferred | SymbolExportElementCount::Resolved(_) => None,
+        }
+    }
+
+    /// Gets whether the field has a runtime-dependent size or position.
+    pub fn is_dynamic(&self) -> bool {
+        self.get_static_offset().is_none() || self.get_static_size_in_bytes().is_none()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SymbolExportLayout {
+    layout_id: String,
+    layout_kind: SymbolicLayoutKind,
+    size_in_bytes: Option<u64>,
+    module_name: Option<String>,
+    fields: Vec<SymbolExportField>,
+}
+
+impl SymbolExportLayout {
+    pub fn get_layout_id(&self) -> &str {
+        &self.layout_id
+    }
+
+    pub fn get_layout_kind(&self) -> SymbolicLayoutKind {
+        self.layout_kind
+    }
+
+    pub fn get_size_in_bytes(&self) -> Option<u64> {
+        self.size_in_bytes
+    }
+
+    /// Gets the module name when this layout is the root layout of a Symbol Tree module.
+    pub fn get_module_name(&self) -> Option<&str> {
+        self.module_name.as_deref()
+    }
+
+    pub fn get_fields(&self) -> &[SymbolExportField] {
+        &self.fields
+    }
+
+    /// Gets the layouts this layout embeds by value, which must be declared first in most target formats.
+    pub fn get_by_value_layout_ids(&self) -> BTreeSet<&str> {
+        self.fields
+            .iter()
+            .filter(|field| field.is_layout_reference && field.pointer_size.is_none())
+            .map(|field| field.data_type_id.as_str())
+            .collect()
+    }
+}
+
+/// A target-neutral view of a project symbol catalog with static offsets and sizes resolved, shared by the symbol
+/// exporters. Anything that depends on a resolver is kept as a rendered expression so exporters can emit it as a comment.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SymbolCatalogExport {
+    layouts: Vec<SymbolExportLayout>,
+}
+
+impl SymbolCatalogExport {
+    pub fn from_project_symbol_catalog(
+        project_symbol_catalog: &ProjectSymbolCatalog,
+        resolve_data_type_size_in_bytes: impl Fn(&DataTypeRef) -> Option<u64> + Copy,
+    ) -> Self {
+        let mut layouts = Vec::new();
+
+        for struct_layout_descriptor in project_symbol_catalog.get_struct_layout_descriptors() {
+            let struct_layout_id = struct_layout_descriptor.get_struct_layout_id();
+            let module_name = project_symbol_catalog
+                .find_symbol_module(struct_layout_id)
+                .map(|symbol_module| symbol_module.get_module_name().to_string());
+
+            layouts.push(Self::build_layout(
+                project_symbol_catalog,
+                struct_layout_id,
+                struct_layout_descriptor.get_struct_layout_definition(),
+                module_name,
+                resolve_data_type_size_in_bytes,
+            ));
+        }
+
+        // Modules without a root layout still carry their fields, so synthesize one from the module fields.
+        for symbol_module in project_symbol_catalog.get_symbol_modules() {
+            if project_symbol_catalog.contains_struct_layout_id(symbol_module.get_module_name()) {
+                continue;
+            }
+
+            let mut module_fields = symbol_module.get_fields().to_vec();
+            module_fields.sort_by_key(|module_field| module_field.get_offset());
+
+            let module_struct_definition = SymbolicStructDefinition::new(
+                symbol_module.get_module_name().to_string(),
+                module_fields
+                    .iter()
+                    .map(|module_field| {
+                        SymbolicFieldDefinition::new_named(
+                            module_field.get_display_name().to_string(),
+                            DataTypeRef::new(module_field.get_struct_layout_id()),
+                            ContainerType::None,
+                        )
+                        .with_offset_resolution(SymbolicFieldOffsetResolution::new_static(module_field.get_offset()))
+                    })
+                    .collect(),
+            )
+            .with_declared_size_in_bytes(Some(symbol_module.get_size()));
+
+            layouts.push(Self::build_layout(
+                project_symbol_catalog,
+                symbol_module.get_module_name(),
+                &module_struct_definition,
+                Some(symbol_module.get_module_name().to_string()),
+                resolve_data_type_size_in_bytes,
+            ));
+        }
+
+        Self { layouts }
+    }
+
+    pub fn get_layouts(&self) -> &[SymbolExportLayout] {
+        &self.layouts
+    }
+
+    pub fn find_layout(
+        &self,
+        layout_id: &str,
+    ) -> Option<&SymbolExportLayout> {
+        self.layouts.iter().find(|layout| layout.layout_id == layout_id)
+    }
+
+    /// Orders layouts so that every layout appears after the layouts it embeds by value. Cycles, which cannot be
+    /// represented by value anyway, are broken in catalog order.
+    pub fn get_layouts_in_dependency_order(&self) -> Vec<&SymbolExportLayout> {
+        let mut ordered_layouts = Vec::new();
+        let mut visited_layout_ids = HashSet::new();
+
+        for layout in &self.layouts {
+            self.visit_layout_dependencies(layout, &mut visited_layout_ids, &mut ordered_layouts);
+        }
+
+        ordered_layouts
+    }
+
+    fn visit_layout_dependencies<'a>(
+        &'a self,
+        layout: &'a SymbolExportLayout,
+        visited_layout_ids: &mut HashSet<&'a str>,
+        ordered_layouts: &mut Vec<&'a SymbolExportLayout>,
+    ) {
+        if !visited_layout_ids.insert(layout.layout_id.as_str()) {
+            return;
+        }
+
+        for dependency_layout_id in layout.get_by_value_layout_ids() {
+            if let Some(dependency_layout) = self.find_layout(dependency_layout_id) {
+                self.visit_layout_dependencies(dependency_layout, visited_layout_ids, ordered_layouts);
+            }
+        }
+
+        ordered_layouts.push(layout);
+    }
+
+    fn build_layout(
+        project_symbol_catalog: &ProjectSymbolCatalog,
+        layout_id: &str,
+        symbolic_struct_definition: &SymbolicStructDefinition,
+        module_name: Option<String>,
+        resolve_data_type_size_in_bytes: impl Fn(&DataTypeRef) -> Option<u64> + Copy,
+    ) -> SymbolExportLayout {
+        let is_union = symbolic_struct_definition.get_layout_kind().is_union();
+        let mut fields = Vec::new();
+        let mut next_sequential_offset = Some(0_u64);
+        let mut last_dynamic_field_name = String::new();
+
+        for symbolic_field_definition in symbolic_struct_definition.get_fields() {
+            if symbolic_field_definition.is_unassigned() {
+                next_sequential_offset = next_sequential_offset.and_then(|offset| {
+                    offset.checked_add(
+                        symbolic_field_definition
+                            .get_unassigned_size_in_bytes()
+                            .unwrap_or(0),
+                    )
+                });
+                continue;
+            }
+
+            let data_type_id = symbolic_field_definition
+                .get_data_type_ref()
+                .get_data_type_id()
+                .to_string();
+            let is_layout_reference = project_symbol_catalog.contains_struct_layout_id(&data_type_id);
+            let element_size_in_bytes = if is_layout_reference {
+                Self::resolve_layout_size_in_bytes(project_symbol_catalog, &data_type_id, resolve_data_type_size_in_bytes)
+            } else {
+                resolve_data_type_size_in_bytes(symbolic_field_definition.get_data_type_ref()).filter(|size_in_bytes| *size_in_bytes > 0)
+            };
+            let container_type = symbolic_field_definition.get_container_type();
+            let pointer_size = match container_type {
+                ContainerType::Pointer(pointer_size) | ContainerType::PointerArray(pointer_size) | ContainerType::PointerArrayFixed(pointer_size, _) => {
+                    Some(pointer_size)
+                }
+                ContainerType::None | ContainerType::Array | ContainerType::ArrayFixed(_) => None,
+            };
+            let element_count = match (symbolic_field_definition.get_count_resolution(), container_type) {
+                (SymbolicFieldCountResolution::Resolver(resolver_id), ContainerType::Array | ContainerType::ArrayFixed(_))
+                | (SymbolicFieldCountResolution::Resolver(resolver_id), ContainerType::PointerArrayFixed(_, _)) => {
+                    SymbolExportElementCount::Resolved(Self::describe_resolver(project_symbol_catalog, resolver_id))
+                }
+                (_, ContainerType::ArrayFixed(element_count)) | (_, ContainerType::PointerArrayFixed(_, element_count)) => {
+                    SymbolExportElementCount::Fixed(element_count)
+                }
+                (_, ContainerType::Array) => SymbolExportElementCount::Inferred,
+                _ => SymbolExportElementCount::Single,
+            };
+            let offset = match symbolic_field_definition.get_offset_resolution() {
+                SymbolicFieldOffsetResolution::Static(offset_in_bytes) => SymbolExportFieldOffset::Static(*offset_in_bytes),
+                SymbolicFieldOffsetResolution::Resolver(resolver_id) => {
+                    SymbolExportFieldOffset::Resolved(Self::describe_resolver(project_symbol_catalog, resolver_id))
+                }
+                SymbolicFieldOffsetResolution::Sequential if is_union => SymbolExportFieldOffset::Static(0),
+                SymbolicFieldOffsetResolution::Sequential => match next_sequential_offset {
+                    Some(offset_in_bytes) => SymbolExportFieldOffset::Static(offset_in_bytes),
+                    None => SymbolExportFieldOffset::FollowsDynamicField(last_dynamic_field_name.clone()),
+                },
+            };
+            let export_field = SymbolExportField {
+                field_name: symbolic_field_definition.get_field_name().to_string(),
+                data_type_id,
+                is_layout_reference,
+                pointer_size,
+                element_count,
+                element_size_in_bytes,
+                offset,
+                active_when_expression: symbolic_field_definition
+                    .get_active_when_resolver()
+                    .map(|resolver_ref| Self::describe_resolver(project_symbol_catalog, resolver_ref.get_resolver_id())),
+            };
+
+            if !is_union {
+                // Squalr sizes inferred arrays as a single element when laying out sequential fields.
+                let layout_size_in_bytes = match export_field.element_count {
+                    SymbolExportElementCount::Inferred => export_field.get_slot_size_in_bytes(),
+                    _ => export_field.get_static_size_in_bytes(),
+                };
+
+                next_sequential_offset = match (export_field.get_static_offset(), layout_size_in_bytes) {
+                    (Some(offset_in_bytes), Some(size_in_bytes)) => offset_in_bytes
+                        .checked_add(size_in_bytes)
+                        .map(|field_end_offset| field_end_offset.max(next_sequential_offset.unwrap_or(0))),
+                    _ => {
+                        last_dynamic_field_name = export_field.field_name.clone();
+                        None
+                    }
+                };
+            }
+
+            fields.push(export_field);
+        }
+
+        SymbolExportLayout {
+            layout_id: layout_id.to_string(),
+            layout_kind: symbolic_struct_definition.get_layout_kind(),
+            size_in_bytes: SymbolLayoutSizeResolver::resolve_symbolic_struct_size_in_bytes(
+                symbolic_struct_definition,
+                resolve_data_type_size_in_bytes,
+                |struct_layout_id| Self::find_struct_layout_definition(project_symbol_catalog, struct_layout_id),
+                &mut HashSet::from([layout_id.to_string()]),
+            ),
+            module_name,
+            fields,
+        }
+    }
+
+    fn resolve_layout_size_in_bytes(
+        project_symbol_catalog: &ProjectSymbolCatalog,
+        struct_layout_id: &str,
+        resolve_data_type_size_in_bytes: impl Fn(&DataTypeRef) -> Option<u64> + Copy,
+    ) -> Option<u64> {
+        let symbolic_struct_definition = Self::find_struct_layout_definition(project_symbol_catalog, struct_layout_id)?;
+
+        SymbolLayoutSizeResolver::resolve_symbolic_struct_size_in_bytes(
+            &symbolic_struct_definition,
+            resolve_data_type_size_in_bytes,
+            |struct_layout_id| Self::find_struct_layout_definition(project_symbol_catalog, struct_layout_id),
+            &mut HashSet::from([struct_layout_id.to_string()]),
+        )
+    }
+
+    fn find_struct_layout_definition(
+        project_symbol_catalog: &ProjectSymbolCatalog,
+        struct_layout_id: &str,
+    ) -> Option<SymbolicStructDefinition> {
+        project_symbol_catalog
+            .get_struct_layout_descriptors()
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == struct_layout_id)
+            .map(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_definition().clone())
+    }
+
+    /// Renders a resolver as a C-like expression for comments in exported files.
+    pub fn describe_resolver(
+        project_symbol_catalog: &ProjectSymbolCatalog,
+        resolver_id: &str,
+    ) -> String {
+        match project_symbol_catalog.find_symbolic_resolver_descriptor(resolver_id) {
+            Some(symbolic_resolver_descriptor) => Self::describe_resolver_node(
+                symbolic_resolver_descriptor
+                    .get_resolver_definition()
+                    .get_root_node(),
+            ),
+            None => format!("resolver `{}`", resolver_id),
+        }
+    }
+
+    fn describe_resolver_node(resolver_node: &SymbolicResolverNode) -> String {
+        match resolver_node {
+            SymbolicResolverNode::Literal(value) => value.to_string(),
+            SymbolicResolverNode::LocalField { field_name } => field_name.clone(),
+            SymbolicResolverNode::RelativeSymbolField { symbol_path } => symbol_path.to_string(),
+            SymbolicResolverNode::GlobalSymbolField { module_name, symbol_path } => format!("{}!{}", module_name, symbol_path),
+            SymbolicResolverNode::RelativePointerChain { pointer_chain } => format!("[{}]", pointer_chain),
+            SymbolicResolverNode::GlobalPointerChain { pointer_chain } => format!("[{}]", pointer_chain),
+            SymbolicResolverNode::TypeSize { data_type_ref } => format!("sizeof({})", data_type_ref.get_data_type_id()),
+            SymbolicResolverNode::Binary {
+                operator,
+                left_node,
+                right_node,
+            } => match operator {
+                SymbolicResolverBinaryOperator::Minimum | SymbolicResolverBinaryOperator::Maximum => format!(
+                    "{}({}, {})",
+                    operator.label(),
+                    Self::describe_resolver_node(left_node),
+                    Self::describe_resolver_node(right_node)
+                ),
+                _ => format!(
+                    "({} {} {})",
+                    Self::describe_resolver_node(left_node),
+                    operator.label(),
+                    Self::describe_resolver_node(right_node)
+                ),
+            },
+            SymbolicResolverNode::Conditional {
+                condition_node,
+                true_node,
+                false_node,
+            } => format!(
+                "({} ? {} : {})",
+                Self::describe_resolver_node(condition_node),
+                Self::describe_resolver_node(true_node),
+                Self::describe_resolver_node(false_node)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SymbolCatalogExport, SymbolExportElementCount, SymbolExportFieldOffset};
+    use crate::registries::symbols::{struct_layout_descriptor::StructLayoutDescriptor, symbolic_resolver_descriptor::SymbolicResolverDescriptor};
+    use crate::structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::{container_type::ContainerType, pointer_scan_pointer_size::PointerScanPointerSize},
+        projects::{
+            project_symbol_catalog::ProjectSymbolCatalog, project_symbol_module::ProjectSymbolModule, project_symbol_module_field::ProjectSymbolModuleField,
+        },
+        structs::{
+            symbolic_field_definition::{SymbolicFieldCountResolution, SymbolicFieldDefinition, SymbolicFieldOffsetResolution},
+            symbolic_resolver_definition::{SymbolicResolverBinaryOperator, SymbolicResolverDefinition, SymbolicResolverNode},
+            symbolic_struct_definition::SymbolicStructDefinition,
+        },
+    };
+
+    fn resolve_test_data_type_size_in_bytes(data_type_ref: &DataTypeRef) -> Option<u64> {
+        match data_type_ref.get_data_type_id() {
+            "u8" | "i8" => Some(1),
+            "u16" => Some(2),
+            "u32" | "i32" | "f32" => Some(4),
+            "u64" => Some(8),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn resolves_static_offsets_and_renders_dynamic_fields() {
+        let mut symbol_module = ProjectSymbolModule::new(String::from("game.exe"), 0x1000);
+        symbol_module
+            .get_fields_mut()
+            .push(ProjectSymbolModuleField::new(String::from("LocalPlayer"), 0x40, String::from("player")));
+        let project_symbol_catalog = ProjectSymbolCatalog::new_with_modules_resolvers_and_symbol_claims(
+            vec![symbol_module],
+            vec![StructLayoutDescriptor::new(
+                String::from("player"),
+                SymbolicStructDefinition::new(
+                    String::from("player"),
+                    vec![
+                        SymbolicFieldDefinition::new_named(String::from("item_count"), DataTypeRef::new("u32"), ContainerType::None),
+                        SymbolicFieldDefinition::new_unassigned(4),
+                        SymbolicFieldDefinition::new_named(
+                            String::from("next"),
+                            DataTypeRef::new("player"),
+                            ContainerType::Pointer(PointerScanPointerSize::Pointer64),
+                        ),
+                        SymbolicFieldDefinition::new_named_with_resolutions(
+                            String::from("items"),
+                            DataTypeRef::new("u16"),
+                            ContainerType::Array,
+                            SymbolicFieldCountResolution::new_resolver(String::from("player.item_count")),
+                            SymbolicFieldOffsetResolution::Sequential,
+                        ),
+                        SymbolicFieldDefinition::new_named(String::from("tail"), DataTypeRef::new("u8"), ContainerType::None),
+                    ],
+                ),
+            )],
+            vec![SymbolicResolverDescriptor::new(
+                String::from("player.item_count"),
+                SymbolicResolverDefinition::new(SymbolicResolverNode::new_binary(
+                    SymbolicResolverBinaryOperator::Multiply,
+                    SymbolicResolverNode::new_local_field(String::from("item_count")),
+                    SymbolicResolverNode::new_literal(2),
+                )),
+            )],
+            Vec::new(),
+        );
+        let symbol_catalog_export = SymbolCatalogExport::from_project_symbol_catalog(&project_symbol_catalog, resolve_test_data_type_size_in_bytes);
+        let player_layout = symbol_catalog_export
+            .find_layout("player")
+            .expect("Expected player layout.");
+        let player_fields = player_layout.get_fields();
+
+        assert_eq!(player_fields.len(), 4);
+        assert_eq!(player_fields[1].get_static_offset(), Some(8));
+        assert_eq!(player_fields[1].get_static_size_in_bytes(), Some(8));
+        assert!(player_fields[1].is_layout_reference());
+        assert_eq!(
+            player_fields[2].get_element_count(),
+            &SymbolExportElementCount::Resolved(String::from("(item_count * 2)"))
+        );
+        assert_eq!(player_fields[2].get_static_offset(), Some(16));
+        assert_eq!(
+            player_fields[3].get_offset(),
+            &SymbolExportFieldOffset::FollowsDynamicField(String::from("items"))
+        );
+
+        let module_layout = symbol_catalog_export
+            .find_layout("game.exe")
+            .expect("Expected synthesized module layout.");
+
+        assert_eq!(module_layout.get_module_name(), Some("game.exe"));
+        assert_eq!(module_layout.get_size_in_bytes(), Some(0x1000));
+        assert_eq!(module_layout.get_fields()[0].get_static_offset(), Some(0x40));
+        assert_eq!(module_layout.get_fields()[0].get_field_name(), "LocalPlayer");
+    }
+
+    #[test]
+    fn orders_layouts_after_their_by_value_dependencies() {
+        let project_symbol_catalog = ProjectSymbolCatalog::new(vec![
+            StructLayoutDescriptor::new(
+                String::from("outer"),
+                SymbolicStructDefinition::new(
+                    String::from("outer"),
+                    vec![SymbolicFieldDefinition::new_named(
+                        String::from("inner"),
+                        DataTypeRef::new("inner"),
+                        ContainerType::None,
+                    )],
+                ),
+            ),
+            StructLayoutDescriptor::new(
+                String::from("inner"),
+                SymbolicStructDefinition::new(
+                    String::from("inner"),
+                    vec![SymbolicFieldDefinition::new_named(
+                        String::from("value"),
+                        DataTypeRef::new("u32"),
+                        ContainerType::None,
+                    )],
+                ),
+            ),
+        ]);
+        let symbol_catalog_export = SymbolCatalogExport::from_project_symbol_catalog(&project_symbol_catalog, resolve_test_data_type_size_in_bytes);
+        let ordered_layout_ids = symbol_catalog_export
+            .get_layouts_in_dependency_order()
+            .iter()
+            .map(|layout| layout.get_layout_id())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ordered_layout_ids, vec!["inner", "outer"]);
+        assert_eq!(
+            symbol_catalog_export
+                .find_layout("outer")
+                .and_then(|layout| layout.get_size_in_bytes()),
+            Some(4)
+        );
+    }
+}
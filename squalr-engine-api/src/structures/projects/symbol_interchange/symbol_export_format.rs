@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// The file formats a project symbol catalog can be exported to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SymbolExportFormat {
+    /// A packed C header with one typedef per layout.
+    #[default]
+    CHeader,
+    /// A ReClass.NET project archive (`.rcnet`).
+    ReClass,
+    /// A Ghidra Python script that creates the layouts in the current program's data type manager.
+    GhidraScript,
+}
+
+impl SymbolExportFormat {
+    pub fn get_default_file_extension(&self) -> &'static str {
+        match self {
+            Self::CHeader => "h",
+            Self::ReClass => "rcnet",
+            Self::GhidraScript => "py",
+        }
+    }
+}
+
+impl Display for SymbolExportFormat {
+    fn fmt(
+        &self,
+        formatter: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::CHeader => write!(formatter, "c-header"),
+            Self::ReClass => write!(formatter, "reclass"),
+            Self::GhidraScript => write!(formatter, "ghidra"),
+        }
+    }
+}
+
+impl FromStr for SymbolExportFormat {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.trim().to_ascii_lowercase().as_str() {
+            "c-header" | "c" | "h" | "header" => Ok(Self::CHeader),
+            "reclass" | "rcnet" | "reclass.net" => Ok(Self::ReClass),
+            "ghidra" | "ghidra-script" | "py" => Ok(Self::GhidraScript),
+            _ => Err(format!("Unsupported export format: {string}. Expected one of: c-header, reclass, ghidra.")),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod project_symbol_reference;
+pub mod project_symbol_reference_index;
+pub mod project_symbol_reference_rewriter;
+pub mod project_symbol_reference_target;
+pub mod project_symbol_rename_edit;
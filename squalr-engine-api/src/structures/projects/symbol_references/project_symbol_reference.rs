@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+
+/// Where a reference to a project symbol lives.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ProjectSymbolReferenceSource {
+    StructLayout { struct_layout_id: String },
+    StructLayoutField { struct_layout_id: String, field_name: String },
+    ModuleField { symbol_locator_key: String },
+    SymbolClaim { symbol_locator_key: String },
+    Resolver { resolver_id: String },
+    ProjectItem { project_item_path: PathBuf, property_name: String },
+}
+
+impl fmt::Display for ProjectSymbolReferenceSource {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::StructLayout { struct_layout_id } => write!(formatter, "layout {}", struct_layout_id),
+            Self::StructLayoutField { struct_layout_id, field_name } => write!(formatter, "layout {}.{}", struct_layout_id, field_name),
+            Self::ModuleField { symbol_locator_key } => write!(formatter, "module field {}", symbol_locator_key),
+            Self::SymbolClaim { symbol_locator_key } => write!(formatter, "symbol claim {}", symbol_locator_key),
+            Self::Resolver { resolver_id } => write!(formatter, "resolver {}", resolver_id),
+            Self::ProjectItem {
+                project_item_path,
+                property_name,
+            } => write!(formatter, "project item {} ({})", project_item_path.display(), property_name),
+        }
+    }
+}
+
+/// A single use of a project symbol, along with the text that names it at that location.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ProjectSymbolReference {
+    source: ProjectSymbolReferenceSource,
+    reference_text: String,
+}
+
+impl ProjectSymbolReference {
+    pub fn new(
+        source: ProjectSymbolReferenceSource,
+        reference_text: String,
+    ) -> Self {
+        Self { source, reference_text }
+    }
+
+    pub fn get_source(&self) -> &ProjectSymbolReferenceSource {
+        &self.source
+    }
+
+    pub fn get_reference_text(&self) -> &str {
+        &self.reference_text
+    }
+}
+
+impl fmt::Display for ProjectSymbolReference {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(formatter, "{}: {}", self.source, self.reference_text)
+    }
+}
@@ -0,0 +1,683 @@
+use crate::structures::memory::symbolic_pointer_chain::SymbolicPointerChainLink;
+use crate::structures::projects::{
+    project_items::{
+        built_in_types::{project_item_type_address::ProjectItemTypeAddress, project_item_type_pointer::ProjectItemTypePointer},
+        project_item::ProjectItem,
+        project_item_ref::ProjectItemRef,
+    },
+    project_symbol_catalog::ProjectSymbolCatalog,
+    symbol_references::{
+        project_symbol_reference::{ProjectSymbolReference, ProjectSymbolReferenceSource},
+        project_symbol_reference_target::ProjectSymbolReferenceTarget,
+    },
+};
+use crate::structures::structs::{
+    symbolic_field_definition::SymbolicFieldDefinition,
+    symbolic_resolver_definition::{SymbolicResolverNode, SymbolicResolverRelativeSymbolPath},
+};
+use std::collections::{HashMap, HashSet};
+
+/// Every by-name use of struct layouts and module symbols across a project's symbol catalog and project items.
+///
+/// Layouts are referenced from type strings (layout fields, module fields, claims, `sizeof` resolver nodes and item
+/// types). Module symbols are referenced by display name from global resolver paths, pointer chain links, and from
+/// relative resolver paths evaluated against the module root layout.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectSymbolReferenceIndex {
+    references_by_target: HashMap<ProjectSymbolReferenceTarget, Vec<ProjectSymbolReference>>,
+}
+
+impl ProjectSymbolReferenceIndex {
+    pub fn build(
+        project_symbol_catalog: &ProjectSymbolCatalog,
+        project_items: &HashMap<ProjectItemRef, ProjectItem>,
+    ) -> Self {
+        let struct_layout_ids = project_symbol_catalog
+            .get_struct_layout_descriptors()
+            .iter()
+            .map(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id().to_string())
+            .collect::<HashSet<_>>();
+        let mut project_symbol_reference_index = Self::default();
+
+        project_symbol_reference_index.index_struct_layouts(project_symbol_catalog, &struct_layout_ids);
+        project_symbol_reference_index.index_module_fields_and_claims(project_symbol_catalog, &struct_layout_ids);
+        project_symbol_reference_index.index_resolvers(project_symbol_catalog, &struct_layout_ids);
+        project_symbol_reference_index.index_project_items(project_items, &struct_layout_ids);
+
+        for references in project_symbol_reference_index.references_by_target.values_mut() {
+            references.sort();
+            references.dedup();
+        }
+
+        project_symbol_reference_index
+    }
+
+    pub fn get_references(
+        &self,
+        target: &ProjectSymbolReferenceTarget,
+    ) -> &[ProjectSymbolReference] {
+        self.references_by_target
+            .get(target)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns each location that references the target once, even when it names the target several times.
+    pub fn get_reference_sources(
+        &self,
+        target: &ProjectSymbolReferenceTarget,
+    ) -> Vec<&ProjectSymbolReferenceSource> {
+        let mut reference_sources = self
+            .get_references(target)
+            .iter()
+            .map(ProjectSymbolReference::get_source)
+            .collect::<Vec<_>>();
+
+        reference_sources.dedup();
+
+        reference_sources
+    }
+
+    fn index_struct_layouts(
+        &mut self,
+        project_symbol_catalog: &ProjectSymbolCatalog,
+        struct_layout_ids: &HashSet<String>,
+    ) {
+        for struct_layout_descriptor in project_symbol_catalog.get_struct_layout_descriptors() {
+            let struct_layout_id = struct_layout_descriptor.get_struct_layout_id();
+            let is_module_root_layout = project_symbol_catalog
+                .find_symbol_module(struct_layout_id)
+                .is_some();
+
+            for field_definition in struct_layout_descriptor
+                .get_struct_layout_definition()
+                .get_fields()
+            {
+                if field_definition.is_unassigned() {
+                    continue;
+                }
+
+                let field_name = field_definition.get_field_name();
+                let source = ProjectSymbolReferenceSource::StructLayoutField {
+                    struct_layout_id: struct_layout_id.to_string(),
+                    field_name: field_name.to_string(),
+                };
+                let field_data_type_id = field_definition.get_data_type_ref().get_data_type_id();
+
+                if struct_layout_ids.contains(field_data_type_id) {
+                    self.add_reference(
+                        ProjectSymbolReferenceTarget::new_struct_layout(field_data_type_id.to_string()),
+                        source.clone(),
+                        format_field_type_text(field_definition),
+                    );
+                }
+
+                // Module root layouts mirror the module's fields, so their field names are the module symbol names.
+                if is_module_root_layout && !field_name.is_empty() {
+                    self.add_reference(
+                        ProjectSymbolReferenceTarget::new_module_symbol(struct_layout_id.to_string(), field_name.to_string()),
+                        source,
+                        field_name.to_string(),
+                    );
+                }
+            }
+        }
+    }
+
+    fn index_module_fields_and_claims(
+        &mut self,
+        project_symbol_catalog: &ProjectSymbolCatalog,
+        struct_layout_ids: &HashSet<String>,
+    ) {
+        for symbol_module in project_symbol_catalog.get_symbol_modules() {
+            for module_field in symbol_module.get_fields() {
+                let struct_layout_id = get_type_text_struct_layout_id(module_field.get_struct_layout_id());
+
+                if struct_layout_ids.contains(struct_layout_id) {
+                    self.add_reference(
+                        ProjectSymbolReferenceTarget::new_struct_layout(struct_layout_id.to_string()),
+                        ProjectSymbolReferenceSource::ModuleField {
+                            symbol_locator_key: module_field.get_symbol_locator_key(symbol_module.get_module_name()),
+                        },
+                        module_field.get_struct_layout_id().to_string(),
+                    );
+                }
+            }
+        }
+
+        for symbol_claim in project_symbol_catalog.get_symbol_claims() {
+            let struct_layout_id = get_type_text_struct_layout_id(symbol_claim.get_struct_layout_id());
+
+            if struct_layout_ids.contains(struct_layout_id) {
+                self.add_reference(
+                    ProjectSymbolReferenceTarget::new_struct_layout(struct_layout_id.to_string()),
+                    ProjectSymbolReferenceSource::SymbolClaim {
+                        symbol_locator_key: symbol_claim.get_symbol_locator_key(),
+                    },
+                    symbol_claim.get_struct_layout_id().to_string(),
+                );
+            }
+        }
+    }
+
+    fn index_resolvers(
+        &mut self,
+        project_symbol_catalog: &ProjectSymbolCatalog,
+        struct_layout_ids: &HashSet<String>,
+    ) {
+        let module_names_by_resolver_id = collect_module_root_resolver_module_names(project_symbol_catalog);
+
+        for symbolic_resolver_descriptor in project_symbol_catalog.get_symbolic_resolver_descriptors() {
+            let resolver_id = symbolic_resolver_descriptor.get_resolver_id();
+            let relative_module_names = module_names_by_resolver_id
+                .get(resolver_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let mut resolver_node_references = Vec::new();
+
+            collect_resolver_node_references(
+                symbolic_resolver_descriptor
+                    .get_resolver_definition()
+                    .get_root_node(),
+                relative_module_names,
+                &mut resolver_node_references,
+            );
+
+            for (target, reference_text) in resolver_node_references {
+                if let ProjectSymbolReferenceTarget::StructLayout { struct_layout_id } = &target
+                    && !struct_layout_ids.contains(struct_layout_id)
+                {
+                    continue;
+                }
+
+                self.add_reference(
+                    target,
+                    ProjectSymbolReferenceSource::Resolver {
+                        resolver_id: resolver_id.to_string(),
+                    },
+                    reference_text,
+                );
+            }
+        }
+    }
+
+    fn index_project_items(
+        &mut self,
+        project_items: &HashMap<ProjectItemRef, ProjectItem>,
+        struct_layout_ids: &HashSet<String>,
+    ) {
+        for (project_item_ref, project_item) in project_items {
+            for (property_name, target, reference_text) in collect_project_item_references(project_item) {
+                if let ProjectSymbolReferenceTarget::StructLayout { struct_layout_id } = &target
+                    && !struct_layout_ids.contains(struct_layout_id)
+                {
+                    continue;
+                }
+
+                self.add_reference(
+                    target,
+                    ProjectSymbolReferenceSource::ProjectItem {
+                        project_item_path: project_item_ref.get_project_item_path().clone(),
+                        property_name: property_name.to_string(),
+                    },
+                    reference_text,
+                );
+            }
+        }
+    }
+
+    fn add_reference(
+        &mut self,
+        target: ProjectSymbolReferenceTarget,
+        source: ProjectSymbolReferenceSource,
+        reference_text: String,
+    ) {
+        self.references_by_target
+            .entry(target)
+            .or_default()
+            .push(ProjectSymbolReference::new(source, reference_text));
+    }
+}
+
+/// Type strings carry container suffixes such as `[4]` or `*(u64)` after the data type id.
+pub(crate) fn get_type_text_struct_layout_id(type_text: &str) -> &str {
+    let type_text = type_text.trim();
+    let container_position = type_text.find(['[', '*']).unwrap_or(type_text.len());
+
+    type_text[..container_position].trim_end()
+}
+
+pub(crate) fn retarget_type_text(
+    type_text: &str,
+    source_struct_layout_id: &str,
+    replacement_struct_layout_id: &str,
+) -> Option<String> {
+    let struct_layout_id = get_type_text_struct_layout_id(type_text);
+
+    if struct_layout_id != source_struct_layout_id {
+        return None;
+    }
+
+    let container_text = &type_text.trim()[struct_layout_id.len()..];
+
+    Some(format!("{}{}", replacement_struct_layout_id, container_text))
+}
+
+pub(crate) fn format_field_type_text(field_definition: &SymbolicFieldDefinition) -> String {
+    format!(
+        "{}{}",
+        field_definition.get_data_type_ref().get_data_type_id(),
+        field_definition.get_container_type()
+    )
+}
+
+pub(crate) fn format_module_pointer_chain_links(
+    module_name: &str,
+    pointer_chain_links: &[SymbolicPointerChainLink],
+) -> String {
+    if module_name.is_empty() {
+        SymbolicPointerChainLink::display_text_list(pointer_chain_links)
+    } else {
+        format!("{}: {}", module_name, SymbolicPointerChainLink::display_text_list(pointer_chain_links))
+    }
+}
+
+/// Maps each resolver used by a module root layout to the modules whose fields its relative symbol paths name.
+pub(crate) fn collect_module_root_resolver_module_names(project_symbol_catalog: &ProjectSymbolCatalog) -> HashMap<String, Vec<String>> {
+    let mut module_names_by_resolver_id = HashMap::<String, Vec<String>>::new();
+
+    for symbol_module in project_symbol_catalog.get_symbol_modules() {
+        let module_name = symbol_module.get_module_name();
+        let Some(module_root_layout_descriptor) = project_symbol_catalog
+            .get_struct_layout_descriptors()
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == module_name)
+        else {
+            continue;
+        };
+
+        for field_definition in module_root_layout_descriptor
+            .get_struct_layout_definition()
+            .get_fields()
+        {
+            let resolver_ids = [
+                field_definition.get_count_resolution().as_resolver_id(),
+                field_definition.get_display_count_resolution().as_resolver_id(),
+                field_definition.get_offset_resolution().as_resolver_id(),
+                field_definition
+                    .get_active_when_resolver()
+                    .map(|active_when_resolver| active_when_resolver.get_resolver_id()),
+            ];
+
+            for resolver_id in resolver_ids.into_iter().flatten() {
+                let module_names = module_names_by_resolver_id
+                    .entry(resolver_id.to_string())
+                    .or_default();
+
+                if !module_names
+                    .iter()
+                    .any(|existing_module_name| existing_module_name == module_name)
+                {
+                    module_names.push(module_name.to_string());
+                }
+            }
+        }
+    }
+
+    module_names_by_resolver_id
+}
+
+pub(crate) fn format_global_symbol_path(
+    module_name: &str,
+    symbol_path: &SymbolicResolverRelativeSymbolPath,
+) -> String {
+    format!("{}!{}", module_name, symbol_path)
+}
+
+pub(crate) fn get_symbol_path_root_name(symbol_path: &SymbolicResolverRelativeSymbolPath) -> Option<&str> {
+    symbol_path
+        .get_links()
+        .first()
+        .and_then(SymbolicPointerChainLink::symbol_name)
+}
+
+fn collect_resolver_node_references(
+    resolver_node: &SymbolicResolverNode,
+    relative_module_names: &[String],
+    references: &mut Vec<(ProjectSymbolReferenceTarget, String)>,
+) {
+    match resolver_node {
+        SymbolicResolverNode::RelativeSymbolField { symbol_path } => {
+            if let Some(root_symbol_name) = get_symbol_path_root_name(symbol_path) {
+                for module_name in relative_module_names {
+                    references.push((
+                        ProjectSymbolReferenceTarget::new_module_symbol(module_name.to_string(), root_symbol_name.to_string()),
+                        symbol_path.to_string(),
+                    ));
+                }
+            }
+        }
+        SymbolicResolverNode::GlobalSymbolField { module_name, symbol_path } => {
+            if let Some(root_symbol_name) = get_symbol_path_root_name(symbol_path) {
+                references.push((
+                    ProjectSymbolReferenceTarget::new_module_symbol(module_name.to_string(), root_symbol_name.to_string()),
+                    format_global_symbol_path(module_name, symbol_path),
+                ));
+            }
+        }
+        SymbolicResolverNode::GlobalPointerChain { pointer_chain } => {
+            for symbol_name in pointer_chain
+                .get_links()
+                .iter()
+                .filter_map(SymbolicPointerChainLink::symbol_name)
+            {
+                references.push((
+                    ProjectSymbolReferenceTarget::new_module_symbol(pointer_chain.get_module_name().to_string(), symbol_name.to_string()),
+                    pointer_chain.to_string(),
+                ));
+            }
+        }
+        SymbolicResolverNode::TypeSize { data_type_ref } => {
+            references.push((
+                ProjectSymbolReferenceTarget::new_struct_layout(data_type_ref.get_data_type_id().to_string()),
+                format!("sizeof({})", data_type_ref.get_data_type_id()),
+            ));
+        }
+        SymbolicResolverNode::Binary { left_node, right_node, .. } => {
+            collect_resolver_node_references(left_node, relative_module_names, references);
+            collect_resolver_node_references(right_node, relative_module_names, references);
+        }
+        SymbolicResolverNode::Conditional {
+            condition_node,
+            true_node,
+            false_node,
+        } => {
+            collect_resolver_node_references(condition_node, relative_module_names, references);
+            collect_resolver_node_references(true_node, relative_module_names, references);
+            collect_resolver_node_references(false_node, relative_module_names, references);
+        }
+        SymbolicResolverNode::Literal(_) | SymbolicResolverNode::LocalField { .. } | SymbolicResolverNode::RelativePointerChain { .. } => {}
+    }
+}
+
+fn collect_project_item_references(project_item: &ProjectItem) -> Vec<(&'static str, ProjectSymbolReferenceTarget, String)> {
+    let mut references = Vec::new();
+
+    match project_item.get_item_type().get_project_item_type_id() {
+        ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID => {
+            let mut project_item = project_item.clone();
+            let address_target = ProjectItemTypeAddress::get_address_target(&mut project_item);
+
+            for symbol_name in address_target
+                .get_pointer_offsets()
+                .iter()
+                .filter_map(SymbolicPointerChainLink::symbol_name)
+            {
+                references.push((
+                    ProjectItemTypeAddress::PROPERTY_TARGET,
+                    ProjectSymbolReferenceTarget::new_module_symbol(address_target.get_module_name().to_string(), symbol_name.to_string()),
+                    format_module_pointer_chain_links(address_target.get_module_name(), address_target.get_pointer_offsets()),
+                ));
+            }
+
+            if let Some(symbolic_struct_ref) = ProjectItemTypeAddress::get_field_symbolic_struct_definition_reference(&mut project_item) {
+                let type_text = symbolic_struct_ref.get_symbolic_struct_namespace();
+
+                references.push((
+                    ProjectItemTypeAddress::PROPERTY_SYMBOLIC_STRUCT_DEFINITION_REFERENCE,
+                    ProjectSymbolReferenceTarget::new_struct_layout(get_type_text_struct_layout_id(type_text).to_string()),
+                    type_text.to_string(),
+                ));
+            }
+        }
+        ProjectItemTypePointer::PROJECT_ITEM_TYPE_ID => {
+            let module_name = ProjectItemTypePointer::get_field_module(project_item);
+            let pointer_chain_segments = ProjectItemTypePointer::get_field_pointer_chain_segments(project_item);
+
+            for symbol_name in pointer_chain_segments
+                .iter()
+                .filter_map(SymbolicPointerChainLink::symbol_name)
+            {
+                references.push((
+                    ProjectItemTypePointer::PROPERTY_POINTER_OFFSETS,
+                    ProjectSymbolReferenceTarget::new_module_symbol(module_name.clone(), symbol_name.to_string()),
+                    format_module_pointer_chain_links(&module_name, &pointer_chain_segments),
+                ));
+            }
+
+            if let Some(symbolic_struct_ref) = ProjectItemTypePointer::get_field_symbolic_struct_definition_reference(project_item) {
+                let type_text = symbolic_struct_ref.get_symbolic_struct_namespace();
+
+                references.push((
+                    ProjectItemTypePointer::PROPERTY_SYMBOLIC_STRUCT_DEFINITION_REFERENCE,
+                    ProjectSymbolReferenceTarget::new_struct_layout(get_type_text_struct_layout_id(type_text).to_string()),
+                    type_text.to_string(),
+                ));
+            }
+        }
+        _ => {}
+    }
+
+    references
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{ProjectSymbolReferenceIndex, get_type_text_struct_layout_id, retarget_type_text};
+    use crate::registries::symbols::{struct_layout_descriptor::StructLayoutDescriptor, symbolic_resolver_descriptor::SymbolicResolverDescriptor};
+    use crate::structures::data_types::{built_in_types::u8::data_type_u8::DataTypeU8, data_type_ref::DataTypeRef};
+    use crate::structures::data_values::{container_type::ContainerType, pointer_scan_pointer_size::PointerScanPointerSize};
+    use crate::structures::memory::{
+        pointer::Pointer,
+        symbolic_pointer_chain::{SymbolicPointerChain, SymbolicPointerChainLink},
+    };
+    use crate::structures::projects::{
+        project_items::{
+            built_in_types::{
+                project_item_type_address::ProjectItemTypeAddress, project_item_type_address_target::ProjectItemAddressTarget,
+                project_item_type_pointer::ProjectItemTypePointer,
+            },
+            project_item::ProjectItem,
+            project_item_ref::ProjectItemRef,
+        },
+        project_symbol_catalog::ProjectSymbolCatalog,
+        project_symbol_claim::ProjectSymbolClaim,
+        project_symbol_module::ProjectSymbolModule,
+        project_symbol_module_field::ProjectSymbolModuleField,
+        symbol_references::{project_symbol_reference::ProjectSymbolReferenceSource, project_symbol_reference_target::ProjectSymbolReferenceTarget},
+    };
+    use crate::structures::structs::{
+        symbolic_field_definition::SymbolicFieldDefinition,
+        symbolic_resolver_definition::{SymbolicResolverDefinition, SymbolicResolverNode, SymbolicResolverRelativeSymbolPath},
+        symbolic_struct_definition::SymbolicStructDefinition,
+    };
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    #[test]
+    fn type_text_helpers_keep_container_suffixes() {
+        assert_eq!(get_type_text_struct_layout_id("player[4]"), "player");
+        assert_eq!(get_type_text_struct_layout_id("player*(u64)"), "player");
+        assert_eq!(get_type_text_struct_layout_id(" player "), "player");
+        assert_eq!(retarget_type_text("player*(u64)[2]", "player", "actor"), Some(String::from("actor*(u64)[2]")));
+        assert_eq!(retarget_type_text("player_state", "player", "actor"), None);
+    }
+
+    #[test]
+    fn build_indexes_layout_and_module_symbol_references_across_catalog_and_items() {
+        let project_symbol_catalog = build_test_project_symbol_catalog();
+        let project_items = build_test_project_items();
+        let project_symbol_reference_index = ProjectSymbolReferenceIndex::build(&project_symbol_catalog, &project_items);
+        let player_sources = project_symbol_reference_index
+            .get_reference_sources(&ProjectSymbolReferenceTarget::new_struct_layout(String::from("player")))
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        let health_references = project_symbol_reference_index
+            .get_references(&ProjectSymbolReferenceTarget::new_module_symbol(
+                String::from("game.exe"),
+                String::from("Health"),
+            ))
+            .iter()
+            .map(|reference| reference.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            player_sources,
+            vec![
+                ProjectSymbolReferenceSource::StructLayoutField {
+                    struct_layout_id: String::from("world"),
+                    field_name: String::from("players"),
+                },
+                ProjectSymbolReferenceSource::ModuleField {
+                    symbol_locator_key: String::from("module:game.exe:100"),
+                },
+                ProjectSymbolReferenceSource::SymbolClaim {
+                    symbol_locator_key: String::from("absolute:1234"),
+                },
+                ProjectSymbolReferenceSource::Resolver {
+                    resolver_id: String::from("player_size"),
+                },
+                ProjectSymbolReferenceSource::ProjectItem {
+                    project_item_path: PathBuf::from("project/player.json"),
+                    property_name: String::from(ProjectItemTypeAddress::PROPERTY_SYMBOLIC_STRUCT_DEFINITION_REFERENCE),
+                },
+            ]
+        );
+        assert_eq!(
+            health_references,
+            vec![
+                String::from("layout game.exe.Health: Health"),
+                String::from("resolver health_max: game.exe!Health.max"),
+                String::from("resolver health_pointer: game.exe: Health, 0x10"),
+                String::from("resolver module_count: Health"),
+                String::from("project item project/health.json (target_data): game.exe: Health, 0x8"),
+                String::from("project item project/health_pointer.json (pointer_offsets): game.exe: Health, 0x4"),
+            ]
+        );
+        assert!(
+            project_symbol_reference_index
+                .get_references(&ProjectSymbolReferenceTarget::new_struct_layout(String::from("u32")))
+                .is_empty()
+        );
+    }
+
+    pub(crate) fn build_test_project_symbol_catalog() -> ProjectSymbolCatalog {
+        let mut symbol_module = ProjectSymbolModule::new(String::from("game.exe"), 0x1000);
+        symbol_module
+            .get_fields_mut()
+            .push(ProjectSymbolModuleField::new(String::from("Health"), 0x40, String::from("u32")));
+        symbol_module
+            .get_fields_mut()
+            .push(ProjectSymbolModuleField::new(String::from("LocalPlayer"), 0x100, String::from("player*(u64)")));
+
+        ProjectSymbolCatalog::new_with_modules_resolvers_and_symbol_claims(
+            vec![symbol_module],
+            vec![
+                StructLayoutDescriptor::new(
+                    String::from("game.exe"),
+                    SymbolicStructDefinition::new(
+                        String::from("game.exe"),
+                        vec![
+                            SymbolicFieldDefinition::new_unassigned(0x40),
+                            SymbolicFieldDefinition::from_str("Health:u32").expect("Expected module root field to parse."),
+                            SymbolicFieldDefinition::from_str("Scores:u32[resolver(module_count)]").expect("Expected module root field to parse."),
+                        ],
+                    ),
+                ),
+                StructLayoutDescriptor::new(
+                    String::from("player"),
+                    SymbolicStructDefinition::new(
+                        String::from("player"),
+                        vec![SymbolicFieldDefinition::from_str("health:u32").expect("Expected field to parse.")],
+                    ),
+                ),
+                StructLayoutDescriptor::new(
+                    String::from("world"),
+                    SymbolicStructDefinition::new(
+                        String::from("world"),
+                        vec![SymbolicFieldDefinition::new_named(
+                            String::from("players"),
+                            DataTypeRef::new("player"),
+                            ContainerType::ArrayFixed(4),
+                        )],
+                    ),
+                ),
+            ],
+            vec![
+                SymbolicResolverDescriptor::new(
+                    String::from("health_max"),
+                    SymbolicResolverDefinition::new(SymbolicResolverNode::new_global_symbol_field(
+                        String::from("game.exe"),
+                        SymbolicResolverRelativeSymbolPath::from_dot_path("Health.max"),
+                    )),
+                ),
+                SymbolicResolverDescriptor::new(
+                    String::from("health_pointer"),
+                    SymbolicResolverDefinition::new(SymbolicResolverNode::new_global_pointer_chain(SymbolicPointerChain::new(
+                        String::from("game.exe"),
+                        vec![
+                            SymbolicPointerChainLink::Symbol(String::from("Health")),
+                            SymbolicPointerChainLink::Offset(0x10),
+                        ],
+                        PointerScanPointerSize::Pointer64,
+                    ))),
+                ),
+                SymbolicResolverDescriptor::new(
+                    String::from("module_count"),
+                    SymbolicResolverDefinition::new(SymbolicResolverNode::new_relative_symbol_field(
+                        SymbolicResolverRelativeSymbolPath::from_dot_path("Health"),
+                    )),
+                ),
+                SymbolicResolverDescriptor::new(
+                    String::from("player_size"),
+                    SymbolicResolverDefinition::new(SymbolicResolverNode::new_type_size(DataTypeRef::new("player"))),
+                ),
+            ],
+            vec![ProjectSymbolClaim::new_absolute_address(
+                String::from("Global Player"),
+                0x1234,
+                String::from("player"),
+            )],
+        )
+    }
+
+    pub(crate) fn build_test_project_items() -> HashMap<ProjectItemRef, ProjectItem> {
+        let mut health_project_item = ProjectItemTypeAddress::new_project_item("Health", 0, "game.exe", "", DataTypeU8::get_value_from_primitive(0));
+        let mut player_project_item = ProjectItemTypeAddress::new_project_item("Player", 0x2000, "game.exe", "", DataTypeU8::get_value_from_primitive(0));
+        let health_pointer_project_item = ProjectItemTypePointer::new_project_item(
+            "Health Pointer",
+            &Pointer::new_with_size_and_segments(
+                0x20,
+                vec![
+                    SymbolicPointerChainLink::Symbol(String::from("Health")),
+                    SymbolicPointerChainLink::Offset(0x4),
+                ],
+                String::from("game.exe"),
+                PointerScanPointerSize::Pointer64,
+            ),
+            "",
+            "u32",
+        );
+
+        ProjectItemTypeAddress::set_address_target(
+            &mut health_project_item,
+            ProjectItemAddressTarget::new(
+                String::from("game.exe"),
+                vec![
+                    SymbolicPointerChainLink::Symbol(String::from("Health")),
+                    SymbolicPointerChainLink::Offset(0x8),
+                ],
+                PointerScanPointerSize::Pointer64,
+            ),
+        );
+        ProjectItemTypeAddress::set_field_symbolic_struct_definition_reference(&mut player_project_item, "player");
+
+        HashMap::from([
+            (ProjectItemRef::new(PathBuf::from("project/health.json")), health_project_item),
+            (ProjectItemRef::new(PathBuf::from("project/player.json")), player_project_item),
+            (ProjectItemRef::new(PathBuf::from("project/health_pointer.json")), health_pointer_project_item),
+        ])
+    }
+}
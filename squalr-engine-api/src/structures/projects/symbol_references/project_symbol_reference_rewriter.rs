@@ -0,0 +1,739 @@
+use crate::registries::symbols::{struct_layout_descriptor::StructLayoutDescriptor, symbolic_resolver_descriptor::SymbolicResolverDescriptor};
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::memory::symbolic_pointer_chain::SymbolicPointerChainLink;
+use crate::structures::projects::{
+    project_items::{
+        built_in_types::{project_item_type_address::ProjectItemTypeAddress, project_item_type_pointer::ProjectItemTypePointer},
+        project_item::ProjectItem,
+        project_item_ref::ProjectItemRef,
+    },
+    project_symbol_catalog::ProjectSymbolCatalog,
+    project_symbol_locator::ProjectSymbolLocator,
+    symbol_references::{
+        project_symbol_reference::ProjectSymbolReferenceSource,
+        project_symbol_reference_index::{
+            ProjectSymbolReferenceIndex, collect_module_root_resolver_module_names, format_field_type_text, format_global_symbol_path,
+            format_module_pointer_chain_links, get_symbol_path_root_name, retarget_type_text,
+        },
+        project_symbol_reference_target::ProjectSymbolReferenceTarget,
+        project_symbol_rename_edit::ProjectSymbolRenameEdit,
+    },
+};
+use crate::structures::structs::{
+    symbolic_field_definition::SymbolicFieldDefinition,
+    symbolic_resolver_definition::{SymbolicResolverDefinition, SymbolicResolverNode, SymbolicResolverRelativeSymbolPath},
+    symbolic_struct_definition::SymbolicStructDefinition,
+};
+use std::collections::HashMap;
+
+/// Renames struct layouts and module symbols together with every by-name reference to them.
+///
+/// Callers that need the rename to be atomic should run it against copies of the catalog and project items, then keep
+/// the copies only when it succeeds. Running it against copies and discarding them is a dry run.
+pub struct ProjectSymbolReferenceRewriter;
+
+impl ProjectSymbolReferenceRewriter {
+    pub fn rename_struct_layout(
+        project_symbol_catalog: &mut ProjectSymbolCatalog,
+        project_items: &mut HashMap<ProjectItemRef, ProjectItem>,
+        struct_layout_id: &str,
+        new_struct_layout_id: &str,
+    ) -> Result<Vec<ProjectSymbolRenameEdit>, String> {
+        let struct_layout_id = struct_layout_id.trim();
+        let new_struct_layout_id = new_struct_layout_id.trim();
+
+        if new_struct_layout_id.is_empty() {
+            return Err(String::from("A non-empty layout id is required."));
+        }
+
+        if project_symbol_catalog
+            .find_symbol_module(struct_layout_id)
+            .is_some()
+        {
+            return Err(format!("Layout `{}` is a module root layout. Rename the module instead.", struct_layout_id));
+        }
+
+        if !project_symbol_catalog.contains_struct_layout_id(struct_layout_id) {
+            return Err(format!("No layout has id `{}`.", struct_layout_id));
+        }
+
+        if struct_layout_id == new_struct_layout_id {
+            return Ok(Vec::new());
+        }
+
+        if project_symbol_catalog.contains_struct_layout_id(new_struct_layout_id) {
+            return Err(format!("Layout `{}` already exists.", new_struct_layout_id));
+        }
+
+        let target = ProjectSymbolReferenceTarget::new_struct_layout(struct_layout_id.to_string());
+        let mut rename_edits = Self::rewrite_references(project_symbol_catalog, project_items, &target, new_struct_layout_id);
+
+        // References are rewritten first, so fields that refer to the layout itself already carry the new id.
+        let Some(struct_layout_definition) = project_symbol_catalog
+            .get_struct_layout_descriptors()
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == struct_layout_id)
+            .map(StructLayoutDescriptor::get_struct_layout_definition)
+        else {
+            return Err(format!("No layout has id `{}`.", struct_layout_id));
+        };
+        let renamed_struct_layout_definition = SymbolicStructDefinition::new_with_layout_kind(
+            new_struct_layout_id.to_string(),
+            struct_layout_definition.get_layout_kind(),
+            struct_layout_definition.get_fields().to_vec(),
+        )
+        .with_declared_size_in_bytes(struct_layout_definition.get_declared_size_in_bytes());
+        let mut struct_layout_descriptors = project_symbol_catalog
+            .get_struct_layout_descriptors()
+            .iter()
+            .filter(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() != struct_layout_id)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        struct_layout_descriptors.push(StructLayoutDescriptor::new(new_struct_layout_id.to_string(), renamed_struct_layout_definition));
+        struct_layout_descriptors.sort_by(|left_descriptor, right_descriptor| {
+            left_descriptor
+                .get_struct_layout_id()
+                .cmp(right_descriptor.get_struct_layout_id())
+        });
+        project_symbol_catalog.set_struct_layout_descriptors(struct_layout_descriptors);
+        rename_edits.insert(
+            0,
+            ProjectSymbolRenameEdit::new(
+                ProjectSymbolReferenceSource::StructLayout {
+                    struct_layout_id: struct_layout_id.to_string(),
+                },
+                struct_layout_id.to_string(),
+                new_struct_layout_id.to_string(),
+            ),
+        );
+
+        Ok(rename_edits)
+    }
+
+    /// Renames a symbol claim or module field. Module-relative symbols also have their by-name references rewritten.
+    /// The display name is used as given, so callers should deduplicate it within the symbol's scope first.
+    pub fn rename_module_symbol(
+        project_symbol_catalog: &mut ProjectSymbolCatalog,
+        project_items: &mut HashMap<ProjectItemRef, ProjectItem>,
+        symbol_locator_key: &str,
+        new_display_name: &str,
+    ) -> Result<Vec<ProjectSymbolRenameEdit>, String> {
+        let new_display_name = new_display_name.trim();
+
+        if new_display_name.is_empty() {
+            return Err(String::from("A non-empty display name is required."));
+        }
+
+        let (definition_source, symbol_locator, display_name) = if let Some(symbol_claim) = project_symbol_catalog.find_symbol_claim(symbol_locator_key) {
+            (
+                ProjectSymbolReferenceSource::SymbolClaim {
+                    symbol_locator_key: symbol_locator_key.to_string(),
+                },
+                symbol_claim.get_locator().clone(),
+                symbol_claim.get_display_name().to_string(),
+            )
+        } else if let Some((symbol_module, module_field)) = project_symbol_catalog.find_module_field(symbol_locator_key) {
+            (
+                ProjectSymbolReferenceSource::ModuleField {
+                    symbol_locator_key: symbol_locator_key.to_string(),
+                },
+                ProjectSymbolLocator::new_module_offset(symbol_module.get_module_name().to_string(), module_field.get_offset()),
+                module_field.get_display_name().to_string(),
+            )
+        } else {
+            return Err(format!("No symbol has locator key `{}`.", symbol_locator_key));
+        };
+
+        if display_name == new_display_name {
+            return Ok(Vec::new());
+        }
+
+        let mut rename_edits = match ProjectSymbolReferenceTarget::from_symbol_locator(&symbol_locator, &display_name) {
+            Some(target) => Self::rewrite_references(project_symbol_catalog, project_items, &target, new_display_name),
+            None => Vec::new(),
+        };
+
+        if let Some(symbol_claim) = project_symbol_catalog.find_symbol_claim_mut(symbol_locator_key) {
+            symbol_claim.set_display_name(new_display_name.to_string());
+        } else if let Some(module_field) = project_symbol_catalog.find_module_field_mut(symbol_locator_key) {
+            module_field.set_display_name(new_display_name.to_string());
+        }
+
+        rename_edits.insert(0, ProjectSymbolRenameEdit::new(definition_source, display_name, new_display_name.to_string()));
+
+        Ok(rename_edits)
+    }
+
+    /// Rewrites every reference to the target so that it names `new_name` instead, without touching the target itself.
+    pub fn rewrite_references(
+        project_symbol_catalog: &mut ProjectSymbolCatalog,
+        project_items: &mut HashMap<ProjectItemRef, ProjectItem>,
+        target: &ProjectSymbolReferenceTarget,
+        new_name: &str,
+    ) -> Vec<ProjectSymbolRenameEdit> {
+        let project_symbol_reference_index = ProjectSymbolReferenceIndex::build(project_symbol_catalog, project_items);
+        let reference_sources = project_symbol_reference_index
+            .get_reference_sources(target)
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut rename_edits = Vec::new();
+
+        for reference_source in reference_sources {
+            match &reference_source {
+                // Layout definitions are renamed by `rename_struct_layout`; the index never reports them as references.
+                ProjectSymbolReferenceSource::StructLayout { .. } => {}
+                ProjectSymbolReferenceSource::StructLayoutField { struct_layout_id, field_name } => {
+                    Self::rewrite_struct_layout_field(project_symbol_catalog, struct_layout_id, field_name, target, new_name, &mut rename_edits);
+                }
+                ProjectSymbolReferenceSource::ModuleField { symbol_locator_key } => {
+                    let ProjectSymbolReferenceTarget::StructLayout { struct_layout_id } = target else {
+                        continue;
+                    };
+                    let Some(module_field) = project_symbol_catalog.find_module_field_mut(symbol_locator_key) else {
+                        continue;
+                    };
+                    let old_type_text = module_field.get_struct_layout_id().to_string();
+
+                    if let Some(new_type_text) = retarget_type_text(&old_type_text, struct_layout_id, new_name) {
+                        module_field.set_struct_layout_id(new_type_text.clone());
+                        rename_edits.push(ProjectSymbolRenameEdit::new(reference_source.clone(), old_type_text, new_type_text));
+                    }
+                }
+                ProjectSymbolReferenceSource::SymbolClaim { symbol_locator_key } => {
+                    let ProjectSymbolReferenceTarget::StructLayout { struct_layout_id } = target else {
+                        continue;
+                    };
+                    let Some(symbol_claim) = project_symbol_catalog.find_symbol_claim_mut(symbol_locator_key) else {
+                        continue;
+                    };
+                    let old_type_text = symbol_claim.get_struct_layout_id().to_string();
+
+                    if let Some(new_type_text) = retarget_type_text(&old_type_text, struct_layout_id, new_name) {
+                        symbol_claim.set_struct_layout_id(new_type_text.clone());
+                        rename_edits.push(ProjectSymbolRenameEdit::new(reference_source.clone(), old_type_text, new_type_text));
+                    }
+                }
+                ProjectSymbolReferenceSource::Resolver { resolver_id } => {
+                    Self::rewrite_resolver(project_symbol_catalog, resolver_id, target, new_name, &mut rename_edits);
+                }
+                ProjectSymbolReferenceSource::ProjectItem {
+                    project_item_path,
+                    property_name,
+                } => {
+                    let Some(project_item) = project_items.get_mut(&ProjectItemRef::new(project_item_path.clone())) else {
+                        continue;
+                    };
+
+                    if let Some((old_text, new_text)) = rewrite_project_item_property(project_item, property_name, target, new_name) {
+                        project_item.set_has_unsaved_changes(true);
+                        rename_edits.push(ProjectSymbolRenameEdit::new(reference_source.clone(), old_text, new_text));
+                    }
+                }
+            }
+        }
+
+        rename_edits
+    }
+
+    fn rewrite_struct_layout_field(
+        project_symbol_catalog: &mut ProjectSymbolCatalog,
+        struct_layout_id: &str,
+        field_name: &str,
+        target: &ProjectSymbolReferenceTarget,
+        new_name: &str,
+        rename_edits: &mut Vec<ProjectSymbolRenameEdit>,
+    ) {
+        let mut struct_layout_descriptors = project_symbol_catalog.get_struct_layout_descriptors().to_vec();
+        let Some(struct_layout_position) = struct_layout_descriptors
+            .iter()
+            .position(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == struct_layout_id)
+        else {
+            return;
+        };
+        let struct_layout_definition = struct_layout_descriptors[struct_layout_position].get_struct_layout_definition();
+        let mut did_rewrite = false;
+        let fields = struct_layout_definition
+            .get_fields()
+            .iter()
+            .map(|field_definition| {
+                if field_definition.is_unassigned() || field_definition.get_field_name() != field_name {
+                    return field_definition.clone();
+                }
+
+                let Some((rewritten_field_definition, old_text, new_text)) = rewrite_field_definition(field_definition, target, new_name) else {
+                    return field_definition.clone();
+                };
+
+                did_rewrite = true;
+                rename_edits.push(ProjectSymbolRenameEdit::new(
+                    ProjectSymbolReferenceSource::StructLayoutField {
+                        struct_layout_id: struct_layout_id.to_string(),
+                        field_name: field_name.to_string(),
+                    },
+                    old_text,
+                    new_text,
+                ));
+
+                rewritten_field_definition
+            })
+            .collect::<Vec<_>>();
+
+        if !did_rewrite {
+            return;
+        }
+
+        let rewritten_struct_layout_definition = SymbolicStructDefinition::new_with_layout_kind(
+            struct_layout_definition.get_symbol_namespace().to_string(),
+            struct_layout_definition.get_layout_kind(),
+            fields,
+        )
+        .with_declared_size_in_bytes(struct_layout_definition.get_declared_size_in_bytes());
+
+        struct_layout_descriptors[struct_layout_position] = StructLayoutDescriptor::new(struct_layout_id.to_string(), rewritten_struct_layout_definition);
+        project_symbol_catalog.set_struct_layout_descriptors(struct_layout_descriptors);
+    }
+
+    fn rewrite_resolver(
+        project_symbol_catalog: &mut ProjectSymbolCatalog,
+        resolver_id: &str,
+        target: &ProjectSymbolReferenceTarget,
+        new_name: &str,
+        rename_edits: &mut Vec<ProjectSymbolRenameEdit>,
+    ) {
+        let module_names_by_resolver_id = collect_module_root_resolver_module_names(project_symbol_catalog);
+        let relative_module_names = module_names_by_resolver_id
+            .get(resolver_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let mut symbolic_resolver_descriptors = project_symbol_catalog
+            .get_symbolic_resolver_descriptors()
+            .to_vec();
+        let Some(symbolic_resolver_descriptor) = symbolic_resolver_descriptors
+            .iter_mut()
+            .find(|symbolic_resolver_descriptor| symbolic_resolver_descriptor.get_resolver_id() == resolver_id)
+        else {
+            return;
+        };
+        let mut resolver_definition = symbolic_resolver_descriptor.get_resolver_definition().clone();
+        let mut rewritten_texts = Vec::new();
+
+        rewrite_resolver_node(
+            resolver_definition.get_root_node_mut(),
+            relative_module_names,
+            target,
+            new_name,
+            &mut rewritten_texts,
+        );
+
+        if rewritten_texts.is_empty() {
+            return;
+        }
+
+        *symbolic_resolver_descriptor = SymbolicResolverDescriptor::new(
+            resolver_id.to_string(),
+            SymbolicResolverDefinition::new(resolver_definition.get_root_node().clone()),
+        );
+        project_symbol_catalog.set_symbolic_resolver_descriptors(symbolic_resolver_descriptors);
+
+        for (old_text, new_text) in rewritten_texts {
+            rename_edits.push(ProjectSymbolRenameEdit::new(
+                ProjectSymbolReferenceSource::Resolver {
+                    resolver_id: resolver_id.to_string(),
+                },
+                old_text,
+                new_text,
+            ));
+        }
+    }
+}
+
+fn rewrite_field_definition(
+    field_definition: &SymbolicFieldDefinition,
+    target: &ProjectSymbolReferenceTarget,
+    new_name: &str,
+) -> Option<(SymbolicFieldDefinition, String, String)> {
+    match target {
+        ProjectSymbolReferenceTarget::StructLayout { struct_layout_id } => {
+            if field_definition.get_data_type_ref().get_data_type_id() != struct_layout_id {
+                return None;
+            }
+
+            let rewritten_field_definition = field_definition
+                .clone()
+                .with_data_type_ref(DataTypeRef::new(new_name));
+
+            Some((
+                rewritten_field_definition.clone(),
+                format_field_type_text(field_definition),
+                format_field_type_text(&rewritten_field_definition),
+            ))
+        }
+        ProjectSymbolReferenceTarget::ModuleSymbol { display_name, .. } => {
+            if field_definition.get_field_name() != display_name {
+                return None;
+            }
+
+            Some((
+                field_definition.clone().with_field_name(new_name.to_string()),
+                display_name.to_string(),
+                new_name.to_string(),
+            ))
+        }
+    }
+}
+
+/// Renames matching symbol links, returning `None` when no link names the symbol.
+fn rename_symbol_links(
+    pointer_chain_links: &[SymbolicPointerChainLink],
+    display_name: &str,
+    new_name: &str,
+) -> Option<Vec<SymbolicPointerChainLink>> {
+    let mut did_rename = false;
+    let renamed_links = pointer_chain_links
+        .iter()
+        .map(|pointer_chain_link| match pointer_chain_link {
+            SymbolicPointerChainLink::Symbol(symbol_name) if symbol_name == display_name => {
+                did_rename = true;
+                SymbolicPointerChainLink::Symbol(new_name.to_string())
+            }
+            _ => pointer_chain_link.clone(),
+        })
+        .collect();
+
+    did_rename.then_some(renamed_links)
+}
+
+/// Renames the root link of a symbol path. Later links are struct field names rather than module symbols.
+fn rename_symbol_path_root(
+    symbol_path: &SymbolicResolverRelativeSymbolPath,
+    display_name: &str,
+    new_name: &str,
+) -> Option<SymbolicResolverRelativeSymbolPath> {
+    if get_symbol_path_root_name(symbol_path) != Some(display_name) {
+        return None;
+    }
+
+    let mut links = symbol_path.get_links().to_vec();
+    links[0] = SymbolicPointerChainLink::Symbol(new_name.to_string());
+
+    Some(SymbolicResolverRelativeSymbolPath::from_links(links))
+}
+
+fn rewrite_resolver_node(
+    resolver_node: &mut SymbolicResolverNode,
+    relative_module_names: &[String],
+    target: &ProjectSymbolReferenceTarget,
+    new_name: &str,
+    rewritten_texts: &mut Vec<(String, String)>,
+) {
+    match (resolver_node, target) {
+        (SymbolicResolverNode::RelativeSymbolField { symbol_path }, ProjectSymbolReferenceTarget::ModuleSymbol { module_name, display_name }) => {
+            if !relative_module_names.contains(module_name) {
+                return;
+            }
+
+            if let Some(renamed_symbol_path) = rename_symbol_path_root(symbol_path, display_name, new_name) {
+                rewritten_texts.push((symbol_path.to_string(), renamed_symbol_path.to_string()));
+                *symbol_path = renamed_symbol_path;
+            }
+        }
+        (
+            SymbolicResolverNode::GlobalSymbolField {
+                module_name: path_module_name,
+                symbol_path,
+            },
+            ProjectSymbolReferenceTarget::ModuleSymbol { module_name, display_name },
+        ) => {
+            if path_module_name != module_name {
+                return;
+            }
+
+            if let Some(renamed_symbol_path) = rename_symbol_path_root(symbol_path, display_name, new_name) {
+                rewritten_texts.push((
+                    format_global_symbol_path(path_module_name, symbol_path),
+                    format_global_symbol_path(path_module_name, &renamed_symbol_path),
+                ));
+                *symbol_path = renamed_symbol_path;
+            }
+        }
+        (SymbolicResolverNode::GlobalPointerChain { pointer_chain }, ProjectSymbolReferenceTarget::ModuleSymbol { module_name, display_name }) => {
+            if pointer_chain.get_module_name() != module_name {
+                return;
+            }
+
+            if let Some(renamed_links) = rename_symbol_links(pointer_chain.get_links(), display_name, new_name) {
+                let old_text = pointer_chain.to_string();
+
+                pointer_chain.set_links(renamed_links);
+                rewritten_texts.push((old_text, pointer_chain.to_string()));
+            }
+        }
+        (SymbolicResolverNode::TypeSize { data_type_ref }, ProjectSymbolReferenceTarget::StructLayout { struct_layout_id }) => {
+            if data_type_ref.get_data_type_id() != struct_layout_id {
+                return;
+            }
+
+            rewritten_texts.push((format!("sizeof({})", struct_layout_id), format!("sizeof({})", new_name)));
+            *data_type_ref = DataTypeRef::new(new_name);
+        }
+        (SymbolicResolverNode::Binary { left_node, right_node, .. }, _) => {
+            rewrite_resolver_node(left_node, relative_module_names, target, new_name, rewritten_texts);
+            rewrite_resolver_node(right_node, relative_module_names, target, new_name, rewritten_texts);
+        }
+        (
+            SymbolicResolverNode::Conditional {
+                condition_node,
+                true_node,
+                false_node,
+            },
+            _,
+        ) => {
+            rewrite_resolver_node(condition_node, relative_module_names, target, new_name, rewritten_texts);
+            rewrite_resolver_node(true_node, relative_module_names, target, new_name, rewritten_texts);
+            rewrite_resolver_node(false_node, relative_module_names, target, new_name, rewritten_texts);
+        }
+        _ => {}
+    }
+}
+
+fn rewrite_project_item_property(
+    project_item: &mut ProjectItem,
+    property_name: &str,
+    target: &ProjectSymbolReferenceTarget,
+    new_name: &str,
+) -> Option<(String, String)> {
+    let project_item_type_id = project_item
+        .get_item_type()
+        .get_project_item_type_id()
+        .to_string();
+
+    match (project_item_type_id.as_str(), property_name, target) {
+        (
+            ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID,
+            ProjectItemTypeAddress::PROPERTY_TARGET,
+            ProjectSymbolReferenceTarget::ModuleSymbol { module_name, display_name },
+        ) => {
+            let mut address_target = ProjectItemTypeAddress::get_address_target(project_item);
+
+            if address_target.get_module_name() != module_name {
+                return None;
+            }
+
+            let renamed_links = rename_symbol_links(address_target.get_pointer_offsets(), display_name, new_name)?;
+            let old_text = format_module_pointer_chain_links(module_name, address_target.get_pointer_offsets());
+            let new_text = format_module_pointer_chain_links(module_name, &renamed_links);
+
+            address_target.set_pointer_offsets(renamed_links);
+            ProjectItemTypeAddress::set_address_target(project_item, address_target);
+
+            Some((old_text, new_text))
+        }
+        (
+            ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID,
+            ProjectItemTypeAddress::PROPERTY_SYMBOLIC_STRUCT_DEFINITION_REFERENCE,
+            ProjectSymbolReferenceTarget::StructLayout { struct_layout_id },
+        ) => {
+            let old_text = ProjectItemTypeAddress::get_field_symbolic_struct_definition_reference(project_item)?
+                .get_symbolic_struct_namespace()
+                .to_string();
+            let new_text = retarget_type_text(&old_text, struct_layout_id, new_name)?;
+
+            ProjectItemTypeAddress::set_field_symbolic_struct_definition_reference(project_item, &new_text);
+
+            Some((old_text, new_text))
+        }
+        (
+            ProjectItemTypePointer::PROJECT_ITEM_TYPE_ID,
+            ProjectItemTypePointer::PROPERTY_POINTER_OFFSETS,
+            ProjectSymbolReferenceTarget::ModuleSymbol { module_name, display_name },
+        ) => {
+            if ProjectItemTypePointer::get_field_module(project_item) != *module_name {
+                return None;
+            }
+
+            let pointer_chain_segments = ProjectItemTypePointer::get_field_pointer_chain_segments(project_item);
+            let renamed_links = rename_symbol_links(&pointer_chain_segments, display_name, new_name)?;
+            let old_text = format_module_pointer_chain_links(module_name, &pointer_chain_segments);
+            let new_text = format_module_pointer_chain_links(module_name, &renamed_links);
+
+            ProjectItemTypePointer::set_field_pointer_chain_segments(project_item, renamed_links);
+
+            Some((old_text, new_text))
+        }
+        (
+            ProjectItemTypePointer::PROJECT_ITEM_TYPE_ID,
+            ProjectItemTypePointer::PROPERTY_SYMBOLIC_STRUCT_DEFINITION_REFERENCE,
+            ProjectSymbolReferenceTarget::StructLayout { struct_layout_id },
+        ) => {
+            let old_text = ProjectItemTypePointer::get_field_symbolic_struct_definition_reference(project_item)?
+                .get_symbolic_struct_namespace()
+                .to_string();
+            let new_text = retarget_type_text(&old_text, struct_layout_id, new_name)?;
+
+            ProjectItemTypePointer::set_field_symbolic_struct_definition_reference(project_item, &new_text);
+
+            Some((old_text, new_text))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectSymbolReferenceRewriter;
+    use crate::structures::data_types::data_type_ref::DataTypeRef;
+    use crate::structures::memory::symbolic_pointer_chain::SymbolicPointerChainLink;
+    use crate::structures::projects::{
+        project_items::{
+            built_in_types::{project_item_type_address::ProjectItemTypeAddress, project_item_type_pointer::ProjectItemTypePointer},
+            project_item_ref::ProjectItemRef,
+        },
+        symbol_references::{
+            project_symbol_reference_index::{
+                ProjectSymbolReferenceIndex,
+                tests::{build_test_project_items, build_test_project_symbol_catalog},
+            },
+            project_symbol_reference_target::ProjectSymbolReferenceTarget,
+        },
+    };
+    use crate::structures::structs::symbolic_resolver_definition::{SymbolicResolverNode, SymbolicResolverRelativeSymbolPath};
+    use std::path::PathBuf;
+
+    #[test]
+    fn rename_module_symbol_rewrites_resolver_paths_layout_fields_and_items() {
+        let mut project_symbol_catalog = build_test_project_symbol_catalog();
+        let mut project_items = build_test_project_items();
+        let rename_edits =
+            ProjectSymbolReferenceRewriter::rename_module_symbol(&mut project_symbol_catalog, &mut project_items, "module:game.exe:40", "Vitality")
+                .expect("Expected module field rename to succeed.");
+        let rename_edit_texts = rename_edits
+            .iter()
+            .map(|rename_edit| format!("{}: {} -> {}", rename_edit.get_source(), rename_edit.get_old_text(), rename_edit.get_new_text()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rename_edit_texts,
+            vec![
+                String::from("module field module:game.exe:40: Health -> Vitality"),
+                String::from("layout game.exe.Health: Health -> Vitality"),
+                String::from("resolver health_max: game.exe!Health.max -> game.exe!Vitality.max"),
+                String::from("resolver health_pointer: game.exe: Health, 0x10 -> game.exe: Vitality, 0x10"),
+                String::from("resolver module_count: Health -> Vitality"),
+                String::from("project item project/health.json (target_data): game.exe: Health, 0x8 -> game.exe: Vitality, 0x8"),
+                String::from("project item project/health_pointer.json (pointer_offsets): game.exe: Health, 0x4 -> game.exe: Vitality, 0x4"),
+            ]
+        );
+        assert_eq!(
+            project_symbol_catalog
+                .find_symbol_module("game.exe")
+                .and_then(|symbol_module| symbol_module.find_field(0x40))
+                .map(|module_field| module_field.get_display_name()),
+            Some("Vitality")
+        );
+        assert_eq!(
+            project_symbol_catalog
+                .find_symbolic_resolver_descriptor("module_count")
+                .map(|symbolic_resolver_descriptor| symbolic_resolver_descriptor
+                    .get_resolver_definition()
+                    .get_root_node()
+                    .clone()),
+            Some(SymbolicResolverNode::new_relative_symbol_field(
+                SymbolicResolverRelativeSymbolPath::from_dot_path("Vitality")
+            ))
+        );
+
+        let health_pointer_project_item = project_items
+            .get(&ProjectItemRef::new(PathBuf::from("project/health_pointer.json")))
+            .expect("Expected pointer item to remain.");
+
+        assert!(health_pointer_project_item.get_has_unsaved_changes());
+        assert_eq!(
+            ProjectItemTypePointer::get_field_pointer_chain_segments(health_pointer_project_item),
+            vec![
+                SymbolicPointerChainLink::Symbol(String::from("Vitality")),
+                SymbolicPointerChainLink::Offset(0x4),
+            ]
+        );
+
+        let project_symbol_reference_index = ProjectSymbolReferenceIndex::build(&project_symbol_catalog, &project_items);
+
+        assert!(
+            project_symbol_reference_index
+                .get_references(&ProjectSymbolReferenceTarget::new_module_symbol(
+                    String::from("game.exe"),
+                    String::from("Health"),
+                ))
+                .is_empty()
+        );
+        assert_eq!(
+            project_symbol_reference_index
+                .get_references(&ProjectSymbolReferenceTarget::new_module_symbol(
+                    String::from("game.exe"),
+                    String::from("Vitality"),
+                ))
+                .len(),
+            6
+        );
+    }
+
+    #[test]
+    fn rename_struct_layout_retargets_type_strings_and_rejects_conflicts() {
+        let mut project_symbol_catalog = build_test_project_symbol_catalog();
+        let mut project_items = build_test_project_items();
+
+        assert!(ProjectSymbolReferenceRewriter::rename_struct_layout(&mut project_symbol_catalog, &mut project_items, "player", "world").is_err());
+        assert!(ProjectSymbolReferenceRewriter::rename_struct_layout(&mut project_symbol_catalog, &mut project_items, "game.exe", "game").is_err());
+
+        let rename_edits = ProjectSymbolReferenceRewriter::rename_struct_layout(&mut project_symbol_catalog, &mut project_items, "player", "actor")
+            .expect("Expected layout rename to succeed.");
+        let rename_edit_texts = rename_edits
+            .iter()
+            .map(|rename_edit| format!("{}: {} -> {}", rename_edit.get_source(), rename_edit.get_old_text(), rename_edit.get_new_text()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rename_edit_texts,
+            vec![
+                String::from("layout player: player -> actor"),
+                String::from("layout world.players: player[4] -> actor[4]"),
+                String::from("module field module:game.exe:100: player*(u64) -> actor*(u64)"),
+                String::from("symbol claim absolute:1234: player -> actor"),
+                String::from("resolver player_size: sizeof(player) -> sizeof(actor)"),
+                String::from("project item project/player.json (symbolic_struct_definition_reference): player -> actor"),
+            ]
+        );
+        assert!(!project_symbol_catalog.contains_struct_layout_id("player"));
+        assert_eq!(
+            project_symbol_catalog
+                .get_struct_layout_descriptors()
+                .iter()
+                .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == "actor")
+                .map(|struct_layout_descriptor| struct_layout_descriptor
+                    .get_struct_layout_definition()
+                    .get_symbol_namespace()),
+            Some("actor")
+        );
+        assert_eq!(
+            project_symbol_catalog
+                .find_symbolic_resolver_descriptor("player_size")
+                .map(|symbolic_resolver_descriptor| symbolic_resolver_descriptor
+                    .get_resolver_definition()
+                    .get_root_node()
+                    .clone()),
+            Some(SymbolicResolverNode::new_type_size(DataTypeRef::new("actor")))
+        );
+
+        let mut player_project_item = project_items
+            .get(&ProjectItemRef::new(PathBuf::from("project/player.json")))
+            .cloned()
+            .expect("Expected player item to remain.");
+
+        assert_eq!(
+            ProjectItemTypeAddress::get_field_symbolic_struct_definition_reference(&mut player_project_item)
+                .map(|symbolic_struct_ref| symbolic_struct_ref.get_symbolic_struct_namespace().to_string()),
+            Some(String::from("actor"))
+        );
+    }
+}
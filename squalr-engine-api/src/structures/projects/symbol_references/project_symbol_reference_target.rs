@@ -0,0 +1,137 @@
+use crate::structures::projects::{project_symbol_catalog::ProjectSymbolCatalog, project_symbol_locator::ProjectSymbolLocator};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A project symbol that other parts of the project can refer to by name.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ProjectSymbolReferenceTarget {
+    /// A reusable struct layout, referenced by its layout id from type strings.
+    StructLayout { struct_layout_id: String },
+    /// A module field or module-relative claim, referenced by display name within its module.
+    ModuleSymbol { module_name: String, display_name: String },
+}
+
+impl ProjectSymbolReferenceTarget {
+    pub fn new_struct_layout(struct_layout_id: String) -> Self {
+        Self::StructLayout { struct_layout_id }
+    }
+
+    pub fn new_module_symbol(
+        module_name: String,
+        display_name: String,
+    ) -> Self {
+        Self::ModuleSymbol { module_name, display_name }
+    }
+
+    /// Resolves user input into a reference target. Accepts a symbol locator key (`module:game.exe:1234`), a
+    /// `module!name` path, or a struct layout id, in that order.
+    pub fn resolve(
+        project_symbol_catalog: &ProjectSymbolCatalog,
+        symbol_text: &str,
+    ) -> Result<Self, String> {
+        let symbol_text = symbol_text.trim();
+
+        if symbol_text.is_empty() {
+            return Err(String::from("A symbol locator key, module!name path, or layout id is required."));
+        }
+
+        if let Some(symbol_claim) = project_symbol_catalog.resolve_symbol_claim(symbol_text) {
+            return match symbol_claim.get_locator() {
+                ProjectSymbolLocator::ModuleOffset { module_name, .. } => {
+                    Ok(Self::new_module_symbol(module_name.to_string(), symbol_claim.get_display_name().to_string()))
+                }
+                ProjectSymbolLocator::AbsoluteAddress { .. } => {
+                    Err(format!("Symbol `{}` is not module-relative, so nothing can refer to it by name.", symbol_text))
+                }
+            };
+        }
+
+        if let Some((module_name, display_name)) = symbol_text.split_once('!') {
+            if project_symbol_catalog
+                .find_module_symbol_offset_by_display_name(module_name, display_name)
+                .is_some()
+            {
+                return Ok(Self::new_module_symbol(module_name.to_string(), display_name.to_string()));
+            }
+
+            return Err(format!("Module `{}` has no symbol named `{}`.", module_name, display_name));
+        }
+
+        if project_symbol_catalog.contains_struct_layout_id(symbol_text) {
+            return Ok(Self::new_struct_layout(symbol_text.to_string()));
+        }
+
+        Err(format!("No symbol or layout matches `{}`.", symbol_text))
+    }
+
+    /// Builds the target for a symbol locator key without requiring the symbol to still exist under its current name.
+    pub fn from_symbol_locator(
+        symbol_locator: &ProjectSymbolLocator,
+        display_name: &str,
+    ) -> Option<Self> {
+        match symbol_locator {
+            ProjectSymbolLocator::ModuleOffset { module_name, .. } => Some(Self::new_module_symbol(module_name.to_string(), display_name.to_string())),
+            ProjectSymbolLocator::AbsoluteAddress { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for ProjectSymbolReferenceTarget {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::StructLayout { struct_layout_id } => write!(formatter, "{}", struct_layout_id),
+            Self::ModuleSymbol { module_name, display_name } => write!(formatter, "{}!{}", module_name, display_name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectSymbolReferenceTarget;
+    use crate::registries::symbols::struct_layout_descriptor::StructLayoutDescriptor;
+    use crate::structures::projects::{
+        project_symbol_catalog::ProjectSymbolCatalog, project_symbol_claim::ProjectSymbolClaim, project_symbol_module::ProjectSymbolModule,
+        project_symbol_module_field::ProjectSymbolModuleField,
+    };
+    use crate::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+
+    #[test]
+    fn resolve_accepts_locator_keys_module_paths_and_layout_ids() {
+        let mut symbol_module = ProjectSymbolModule::new(String::from("game.exe"), 0x1000);
+        symbol_module
+            .get_fields_mut()
+            .push(ProjectSymbolModuleField::new(String::from("Health"), 0x40, String::from("u32")));
+        let project_symbol_catalog = ProjectSymbolCatalog::new_with_modules_and_symbol_claims(
+            vec![symbol_module],
+            vec![StructLayoutDescriptor::new(
+                String::from("player"),
+                SymbolicStructDefinition::new(String::from("player"), Vec::new()),
+            )],
+            vec![ProjectSymbolClaim::new_absolute_address(
+                String::from("Global"),
+                0x1234,
+                String::from("u32"),
+            )],
+        );
+        let health_target = ProjectSymbolReferenceTarget::new_module_symbol(String::from("game.exe"), String::from("Health"));
+
+        assert_eq!(
+            ProjectSymbolReferenceTarget::resolve(&project_symbol_catalog, "module:game.exe:40"),
+            Ok(health_target.clone())
+        );
+        assert_eq!(
+            ProjectSymbolReferenceTarget::resolve(&project_symbol_catalog, "game.exe!Health"),
+            Ok(health_target)
+        );
+        assert_eq!(
+            ProjectSymbolReferenceTarget::resolve(&project_symbol_catalog, "player"),
+            Ok(ProjectSymbolReferenceTarget::new_struct_layout(String::from("player")))
+        );
+        assert!(ProjectSymbolReferenceTarget::resolve(&project_symbol_catalog, "absolute:1234").is_err());
+        assert!(ProjectSymbolReferenceTarget::resolve(&project_symbol_catalog, "game.exe!Mana").is_err());
+        assert!(ProjectSymbolReferenceTarget::resolve(&project_symbol_catalog, "enemy").is_err());
+    }
+}
@@ -0,0 +1,42 @@
+use crate::structures::projects::symbol_references::project_symbol_reference::ProjectSymbolReferenceSource;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// One text change made by a symbol rename. A dry run returns these without applying them.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ProjectSymbolRenameEdit {
+    source: ProjectSymbolReferenceSource,
+    old_text: String,
+    new_text: String,
+}
+
+impl ProjectSymbolRenameEdit {
+    pub fn new(
+        source: ProjectSymbolReferenceSource,
+        old_text: String,
+        new_text: String,
+    ) -> Self {
+        Self { source, old_text, new_text }
+    }
+
+    pub fn get_source(&self) -> &ProjectSymbolReferenceSource {
+        &self.source
+    }
+
+    pub fn get_old_text(&self) -> &str {
+        &self.old_text
+    }
+
+    pub fn get_new_text(&self) -> &str {
+        &self.new_text
+    }
+}
+
+impl fmt::Display for ProjectSymbolRenameEdit {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(formatter, "{}\n- {}\n+ {}", self.source, self.old_text, self.new_text)
+    }
+}
@@ -1,16 +1,13 @@
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum MemoryReadMode {
     Skip,
+    #[default]
     ReadBeforeScan,
-}
-
-impl Default for MemoryReadMode {
-    fn default() -> Self {
-        MemoryReadMode::ReadBeforeScan
-    }
+    /// Reads values before the scan while every thread of the process is suspended, giving a consistent snapshot.
+    ReadWhileSuspended,
 }
 
 impl FromStr for MemoryReadMode {
@@ -20,6 +17,7 @@ impl FromStr for MemoryReadMode {
         match string {
             "s" => Ok(MemoryReadMode::Skip),
             "b" => Ok(MemoryReadMode::ReadBeforeScan),
+            "suspended" => Ok(MemoryReadMode::ReadWhileSuspended),
             _ => Err("Unknown memory reading mode.".to_string()),
         }
     }
@@ -1,3 +1,4 @@
+use crate::plugins::value_transform::ResolvedValueTransform;
 use crate::structures::data_types::data_type_ref::DataTypeRef;
 use crate::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
 use crate::structures::memory::memory_alignment::MemoryAlignment;
@@ -16,6 +17,9 @@ pub struct ElementScanPlan {
     /// If this debug flag is provided, the scan will be performed twice. Once with a specialized scan, and once with the default scan.
     /// An assertion will be made that the default scan produced the exact same result as the specialized scan.
     debug_perform_validation_scan: bool,
+
+    /// An optional transform that decodes region values before they are compared.
+    value_transform: Option<ResolvedValueTransform>,
 }
 
 impl ElementScanPlan {
@@ -34,9 +38,19 @@ impl ElementScanPlan {
             memory_read_mode,
             is_single_thread_scan,
             debug_perform_validation_scan,
+            value_transform: None,
         }
     }
 
+    /// Compares values after decoding them with the given transform.
+    pub fn with_value_transform(
+        mut self,
+        value_transform: Option<ResolvedValueTransform>,
+    ) -> Self {
+        self.value_transform = value_transform;
+        self
+    }
+
     pub fn get_scan_constraints_by_data_type(&self) -> &HashMap<DataTypeRef, Vec<ScanConstraintFinalized>> {
         &self.scan_constraints_by_data_type
     }
@@ -64,4 +78,8 @@ impl ElementScanPlan {
     pub fn get_debug_perform_validation_scan(&self) -> bool {
         self.debug_perform_validation_scan
     }
+
+    pub fn get_value_transform(&self) -> Option<&ResolvedValueTransform> {
+        self.value_transform.as_ref()
+    }
 }
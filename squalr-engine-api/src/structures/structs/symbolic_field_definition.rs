@@ -193,6 +193,28 @@ impl SymbolicFieldDefinition {
         self
     }
 
+    pub fn with_field_name(
+        mut self,
+        field_name: String,
+    ) -> Self {
+        if let SymbolicFieldDefinition::Field(field_definition) = &mut self {
+            field_definition.field_name = field_name;
+        }
+
+        self
+    }
+
+    pub fn with_data_type_ref(
+        mut self,
+        data_type_ref: DataTypeRef,
+    ) -> Self {
+        if let SymbolicFieldDefinition::Field(field_definition) = &mut self {
+            field_definition.data_type_ref = data_type_ref;
+        }
+
+        self
+    }
+
     pub fn with_display_format(
         mut self,
         display_format: Option<AnonymousValueStringFormat>,
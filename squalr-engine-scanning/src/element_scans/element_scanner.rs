@@ -4,10 +4,13 @@ use crate::scanners::scan_control::ScanControl;
 use rayon::iter::{IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator};
 use squalr_engine_api::conversions::storage_size_conversions::StorageSizeConversions;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+use squalr_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
 use squalr_engine_api::structures::scanning::plans::element_scan::element_scan_plan::ElementScanPlan;
 use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
 use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
@@ -59,8 +62,34 @@ impl ElementScanner {
             );
             refresh_region(snapshot_region);
 
-            let element_scan_dispatcher = |snapshot_region_filter_collection| {
-                ElementScanDispatcher::dispatch_scan(symbol_registry, snapshot_region, snapshot_region_filter_collection, element_scan_plan)
+            // Transforms decode a copy of the region once per element size, since each data type decodes at its own element size.
+            let decoded_snapshot_regions: HashMap<usize, (SnapshotRegion, Vec<NormalizedRegion>)> = match element_scan_plan.get_value_transform() {
+                Some(value_transform) => snapshot_region
+                    .get_scan_results()
+                    .get_filter_collections()
+                    .iter()
+                    .map(|snapshot_region_filter_collection| {
+                        symbol_registry.get_unit_size_in_bytes(snapshot_region_filter_collection.get_data_type_ref()) as usize
+                    })
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .map(|element_size| (element_size, value_transform.decode_snapshot_region(snapshot_region, element_size)))
+                    .collect(),
+                None => HashMap::new(),
+            };
+
+            let element_scan_dispatcher = |snapshot_region_filter_collection: &SnapshotRegionFilterCollection| {
+                let element_size = symbol_registry.get_unit_size_in_bytes(snapshot_region_filter_collection.get_data_type_ref()) as usize;
+
+                match decoded_snapshot_regions.get(&element_size) {
+                    Some((decoded_snapshot_region, undecoded_regions)) => Self::remove_undecoded_results(
+                        symbol_registry,
+                        ElementScanDispatcher::dispatch_scan(symbol_registry, decoded_snapshot_region, snapshot_region_filter_collection, element_scan_plan),
+                        element_size as u64,
+                        undecoded_regions,
+                    ),
+                    None => ElementScanDispatcher::dispatch_scan(symbol_registry, snapshot_region, snapshot_region_filter_collection, element_scan_plan),
+                }
             };
 
             let scan_results_collection = snapshot_region.get_scan_results().get_filter_collections();
@@ -116,13 +145,72 @@ impl ElementScanner {
             scan_duration,
         )
     }
+
+    /// Removes the results that a value transform did not decode as a whole. This covers results overlapping bytes that could
+    /// not be decoded, which still hold raw memory, and results starting partway into a decoded element when scanning below
+    /// the element alignment, which would combine bytes of elements that were decoded separately.
+    fn remove_undecoded_results(
+        symbol_registry: &SymbolRegistry,
+        snapshot_region_filter_collection: SnapshotRegionFilterCollection,
+        element_size: u64,
+        undecoded_regions: &[NormalizedRegion],
+    ) -> SnapshotRegionFilterCollection {
+        let memory_alignment = snapshot_region_filter_collection.get_memory_alignment();
+        let alignment = (memory_alignment as u64).max(1);
+        let element_size = element_size.max(1);
+        let is_misaligned_scan = alignment < element_size;
+
+        if undecoded_regions.is_empty() && !is_misaligned_scan {
+            return snapshot_region_filter_collection;
+        }
+
+        let result_size = snapshot_region_filter_collection
+            .get_result_value_size_in_bytes()
+            .max(1);
+        let mut collection_result_index_base = 0u64;
+        let mut undecoded_result_indices = Vec::new();
+
+        for snapshot_region_filter in snapshot_region_filter_collection.iter() {
+            let filter_result_count = snapshot_region_filter.get_element_count(result_size, memory_alignment);
+            let filter_base_address = snapshot_region_filter.get_base_address();
+
+            if is_misaligned_scan {
+                undecoded_result_indices.extend(
+                    (0..filter_result_count)
+                        .filter(|result_index| !(filter_base_address + result_index * alignment).is_multiple_of(element_size))
+                        .map(|result_index| collection_result_index_base + result_index),
+                );
+            }
+
+            for undecoded_region in undecoded_regions {
+                // A result overlaps an undecoded range when it starts before the range ends and ends after the range starts.
+                let first_result_index = (undecoded_region.get_base_address() + 1)
+                    .saturating_sub(result_size)
+                    .saturating_sub(filter_base_address)
+                    .div_ceil(alignment);
+                let end_result_index = undecoded_region
+                    .get_end_address()
+                    .saturating_sub(filter_base_address)
+                    .div_ceil(alignment)
+                    .min(filter_result_count);
+
+                undecoded_result_indices.extend((first_result_index..end_result_index).map(|result_index| collection_result_index_base + result_index));
+            }
+
+            collection_result_index_base += filter_result_count;
+        }
+
+        snapshot_region_filter_collection.remove_results_at_indices(symbol_registry, &undecoded_result_indices)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::ElementScanner;
     use crate::scanners::scan_control::ScanControl;
+    use squalr_engine_api::plugins::value_transform::ValueTransformRef;
     use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+    use squalr_engine_api::registries::value_transforms::value_transform_registry::ValueTransformRegistry;
     use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
     use squalr_engine_api::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
     use squalr_engine_api::structures::data_values::data_value::DataValue;
@@ -184,6 +272,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scan_snapshot_compares_values_decoded_by_the_plan_value_transform() {
+        let symbol_registry = SymbolRegistry::new();
+        let data_type_ref = DataTypeRef::new("u8");
+        let value_transform = ValueTransformRegistry::new()
+            .resolve(&ValueTransformRef::new("xor_constant", 0x55))
+            .expect("Expected the built-in transform to resolve.");
+        let element_scan_plan = build_equal_u8_plan(&symbol_registry, data_type_ref.clone(), 0x7F).with_value_transform(Some(value_transform));
+        let raw_values = vec![0x7F ^ 0x55, 0x7F, 0x7F ^ 0x55];
+        let mut snapshot = Snapshot::from_regions(vec![SnapshotRegion::from_bytes(0x3000, raw_values.clone())]);
+
+        ElementScanner::scan_snapshot(&mut snapshot, &symbol_registry, &element_scan_plan, &ScanControl::default());
+
+        assert_eq!(
+            snapshot.collect_scan_result_addresses_for_data_type(&symbol_registry, &data_type_ref),
+            vec![0x3000, 0x3002]
+        );
+        assert_eq!(snapshot.get_snapshot_regions()[0].get_current_values(), &raw_values);
+    }
+
+    #[test]
+    fn scan_snapshot_discards_results_whose_neighbor_field_is_outside_the_region() {
+        let symbol_registry = SymbolRegistry::new();
+        let data_type_ref = DataTypeRef::new("u8");
+        let value_transform = ValueTransformRegistry::new()
+            .resolve(&ValueTransformRef::new("xor_neighbor", 1))
+            .expect("Expected the built-in transform to resolve.");
+        let element_scan_plan = build_equal_u8_plan(&symbol_registry, data_type_ref.clone(), 0x7F).with_value_transform(Some(value_transform));
+        // The last byte reads as the expected value, but has no neighbor to decode it with.
+        let mut snapshot = Snapshot::from_regions(vec![SnapshotRegion::from_bytes(
+            0x4000,
+            vec![0x7F ^ 0x10, 0x10, 0x7F],
+        )]);
+
+        ElementScanner::scan_snapshot(&mut snapshot, &symbol_registry, &element_scan_plan, &ScanControl::default());
+
+        assert_eq!(
+            snapshot.collect_scan_result_addresses_for_data_type(&symbol_registry, &data_type_ref),
+            vec![0x4000]
+        );
+    }
+
+    #[test]
+    fn scan_snapshot_discards_results_that_straddle_decoded_elements() {
+        let symbol_registry = SymbolRegistry::new();
+        let data_type_ref = DataTypeRef::new("u32");
+        let value_transform = ValueTransformRegistry::new()
+            .resolve(&ValueTransformRef::new("byte_swap", 0))
+            .expect("Expected the built-in transform to resolve.");
+        let scan_constraint = ScanConstraint::new(
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+            DataValue::new(data_type_ref.clone(), 0x0403_0201u32.to_le_bytes().to_vec()),
+            FloatingPointTolerance::default(),
+        );
+        let element_scan_plan = ElementScanPlan::new(
+            HashMap::from([(data_type_ref.clone(), vec![ScanConstraintFinalized::new(&symbol_registry, scan_constraint)])]),
+            MemoryAlignment::Alignment1,
+            FloatingPointTolerance::default(),
+            MemoryReadMode::Skip,
+            true,
+            false,
+        )
+        .with_value_transform(Some(value_transform));
+        // The first two elements decode to `00 00 01 02 | 03 04 00 00`, which only reads as the value across their boundary.
+        let mut snapshot = Snapshot::from_regions(vec![SnapshotRegion::from_bytes(
+            0x5000,
+            vec![
+                0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x04, 0x03, 0x04, 0x03, 0x02, 0x01,
+            ],
+        )]);
+
+        ElementScanner::scan_snapshot(&mut snapshot, &symbol_registry, &element_scan_plan, &ScanControl::default());
+
+        assert_eq!(
+            snapshot.collect_scan_result_addresses_for_data_type(&symbol_registry, &data_type_ref),
+            vec![0x5008]
+        );
+    }
+
     fn build_equal_u8_plan(
         symbol_registry: &SymbolRegistry,
         data_type_ref: DataTypeRef,
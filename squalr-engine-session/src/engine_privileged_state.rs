@@ -1,3 +1,4 @@
+use crate::memory_write_tracking::memory_write_tracking_session::MemoryWriteTrackingSession;
 use crate::os::ProcessManager;
 use crate::os::engine_os_provider::EngineOsProviders;
 use crate::plugins::plugin_registry::PluginRegistry;
@@ -12,7 +13,9 @@ use squalr_engine_api::events::engine_event::{EngineEvent, EngineEventRequest};
 use squalr_engine_api::events::plugins::changed::plugins_changed_event::PluginsChangedEvent;
 use squalr_engine_api::events::process::process_event::ProcessEvent;
 use squalr_engine_api::events::registry::changed::registry_changed_event::RegistryChangedEvent;
+use squalr_engine_api::plugins::value_transform::{ResolvedValueTransform, ValueTransformRef};
 use squalr_engine_api::plugins::{PluginPackage, PluginState};
+use squalr_engine_api::registries::code_patches::code_patch_registry::CodePatchRegistry;
 use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
 use squalr_engine_api::registries::project_item_types::project_item_type_registry::ProjectItemTypeRegistry;
 use squalr_engine_api::registries::registry_context::RegistryContext;
@@ -20,6 +23,8 @@ use squalr_engine_api::registries::scan_rules::element_scan_rule_registry::Eleme
 use squalr_engine_api::registries::symbols::privileged_registry_catalog::PrivilegedRegistryCatalog;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
 use squalr_engine_api::registries::symbols::{data_type_descriptor::DataTypeDescriptor, struct_layout_descriptor::StructLayoutDescriptor};
+use squalr_engine_api::registries::value_transforms::value_transform_registry::ValueTransformRegistry;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::pointer_scans::pointer_scan_results::PointerScanResults;
 use squalr_engine_api::structures::projects::project_symbol_catalog::ProjectSymbolCatalog;
 use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
@@ -44,6 +49,12 @@ pub struct EnginePrivilegedState {
     /// Monotonically increasing identifier for new pointer scan results.
     next_pointer_scan_results_id: AtomicU64,
 
+    /// The write tracking window opened on the current process, if any.
+    memory_write_tracking_session: Arc<RwLock<Option<MemoryWriteTrackingSession>>>,
+
+    /// Pages that the next new scan is narrowed to, consumed once that scan collects its memory pages.
+    pending_scan_pages: Arc<RwLock<Option<Vec<NormalizedRegion>>>>,
+
     /// Monotonically increasing generation for privileged registry catalog exports.
     symbol_registry_generation: AtomicU64,
 
@@ -85,6 +96,68 @@ impl EnginePrivilegedState {
         }
     }
 
+    fn register_plugin_project_item_types(
+        project_item_type_registry: &RwLock<ProjectItemTypeRegistry>,
+        plugin_packages: &[Arc<dyn PluginPackage>],
+    ) {
+        let mut project_item_type_registry = match project_item_type_registry.write() {
+            Ok(project_item_type_registry) => project_item_type_registry,
+            Err(error) => {
+                log::error!("Failed to acquire project item type registry lock for plugin registration: {}", error);
+
+                return;
+            }
+        };
+
+        for plugin_package in plugin_packages {
+            let Some(project_item_type_plugin) = plugin_package.as_project_item_type_plugin() else {
+                continue;
+            };
+
+            for project_item_type in project_item_type_plugin.contributed_project_item_types() {
+                if let Err(error) = project_item_type_registry.register(project_item_type.clone()) {
+                    log::warn!(
+                        "Failed to register plugin-authored project item type '{}' from plugin '{}': {}",
+                        project_item_type.get_project_item_type_id(),
+                        project_item_type_plugin.metadata().get_plugin_id(),
+                        error
+                    );
+                }
+            }
+        }
+    }
+
+    fn register_plugin_value_transforms(
+        value_transform_registry: &RwLock<ValueTransformRegistry>,
+        plugin_packages: &[Arc<dyn PluginPackage>],
+    ) {
+        let mut value_transform_registry = match value_transform_registry.write() {
+            Ok(value_transform_registry) => value_transform_registry,
+            Err(error) => {
+                log::error!("Failed to acquire value transform registry lock for plugin registration: {}", error);
+
+                return;
+            }
+        };
+
+        for plugin_package in plugin_packages {
+            let Some(value_transform_plugin) = plugin_package.as_value_transform_plugin() else {
+                continue;
+            };
+
+            for value_transform in value_transform_plugin.contributed_value_transforms() {
+                if let Err(error) = value_transform_registry.register(value_transform.clone()) {
+                    log::warn!(
+                        "Failed to register plugin-authored value transform '{}' from plugin '{}': {}",
+                        value_transform.get_value_transform_id(),
+                        value_transform_plugin.metadata().get_plugin_id(),
+                        error
+                    );
+                }
+            }
+        }
+    }
+
     pub fn new(
         engine_bindings: Arc<RwLock<dyn EngineApiPrivilegedBindings>>,
         os_providers: EngineOsProviders,
@@ -95,8 +168,10 @@ impl EnginePrivilegedState {
         let snapshot = Arc::new(RwLock::new(Snapshot::new()));
         let pointer_scan_results = Arc::new(RwLock::new(None));
         let registries = Arc::new(Registries::new());
-        let plugin_registry = Arc::new(PluginRegistry::new());
+        let plugin_registry = Arc::new(PluginRegistry::new_with_installed_plugins());
         Self::register_plugin_data_types(registries.get_symbol_registry().as_ref(), plugin_registry.get_plugin_packages());
+        Self::register_plugin_project_item_types(registries.get_project_item_type_registry().as_ref(), plugin_registry.get_plugin_packages());
+        Self::register_plugin_value_transforms(registries.get_value_transform_registry().as_ref(), plugin_registry.get_plugin_packages());
         let os_providers = os_providers.with_memory_view_routing(plugin_registry.clone());
 
         SnapshotScanResultFreezeTask::start_task(
@@ -111,6 +186,8 @@ impl EnginePrivilegedState {
             snapshot,
             pointer_scan_results,
             next_pointer_scan_results_id: AtomicU64::new(0),
+            memory_write_tracking_session: Arc::new(RwLock::new(None)),
+            pending_scan_pages: Arc::new(RwLock::new(None)),
             symbol_registry_generation: AtomicU64::new(1),
             symbol_registry_mutation_guard: Mutex::new(()),
             engine_bindings,
@@ -148,6 +225,16 @@ impl EnginePrivilegedState {
         self.pointer_scan_results.clone()
     }
 
+    /// Gets the write tracking window opened on the current process, if any.
+    pub fn get_memory_write_tracking_session(&self) -> Arc<RwLock<Option<MemoryWriteTrackingSession>>> {
+        self.memory_write_tracking_session.clone()
+    }
+
+    /// Gets the pages that the next new scan is narrowed to, if any.
+    pub fn get_pending_scan_pages(&self) -> Arc<RwLock<Option<Vec<NormalizedRegion>>>> {
+        self.pending_scan_pages.clone()
+    }
+
     /// Allocates a stable identifier for new pointer scan results.
     pub fn allocate_pointer_scan_results_id(&self) -> u64 {
         self.next_pointer_scan_results_id.fetch_add(1, Ordering::SeqCst) + 1
@@ -266,6 +353,11 @@ impl EnginePrivilegedState {
         self.registries.get_freeze_list_registry()
     }
 
+    /// Gets the registry for code patches applied to the opened process.
+    pub fn get_code_patch_registry(&self) -> Arc<RwLock<CodePatchRegistry>> {
+        self.registries.get_code_patch_registry()
+    }
+
     /// Provides controlled read access to the symbol registry without exposing its handle publicly.
     pub fn read_symbol_registry<T>(
         &self,
@@ -284,6 +376,29 @@ impl EnginePrivilegedState {
         self.registries.get_element_scan_rule_registry()
     }
 
+    /// Gets the registry for value transforms.
+    pub fn get_value_transform_registry(&self) -> Arc<RwLock<ValueTransformRegistry>> {
+        self.registries.get_value_transform_registry()
+    }
+
+    /// Resolves the value transform selected by a request, if any.
+    pub fn resolve_value_transform(
+        &self,
+        value_transform_ref: Option<&ValueTransformRef>,
+    ) -> Result<Option<ResolvedValueTransform>, String> {
+        let Some(value_transform_ref) = value_transform_ref else {
+            return Ok(None);
+        };
+        let resolved_value_transform = self
+            .registries
+            .get_value_transform_registry()
+            .read()
+            .map_err(|error| format!("Failed to acquire value transform registry lock: {}", error))?
+            .resolve(value_transform_ref)?;
+
+        Ok(Some(resolved_value_transform))
+    }
+
     /// Dispatches an event from the engine.
     pub fn subscribe_to_engine_events(&self) -> Result<Receiver<EngineEventEnvelope>, EngineBindingError> {
         match self.engine_bindings.read() {
@@ -318,6 +433,11 @@ impl EnginePrivilegedState {
         }
     }
 
+    /// Creates an emitter that dispatches engine events from background tasks.
+    pub fn create_engine_event_emitter(&self) -> Arc<dyn Fn(EngineEvent) + Send + Sync> {
+        Self::create_event_emitter(self.engine_bindings.clone())
+    }
+
     fn create_event_emitter(engine_bindings: Arc<RwLock<dyn EngineApiPrivilegedBindings>>) -> Arc<dyn Fn(EngineEvent) + Send + Sync> {
         let engine_bindings = engine_bindings.clone();
         Arc::new(move |event: EngineEvent| {
@@ -342,8 +462,27 @@ impl EnginePrivilegedState {
         std::thread::spawn(move || {
             while let Ok(engine_event_envelope) = event_receiver.recv() {
                 match engine_event_envelope.into_engine_event() {
-                    EngineEvent::Process(ProcessEvent::ProcessChanged { .. }) => {
+                    EngineEvent::Process(ProcessEvent::ProcessChanged { process_changed_event }) => {
                         engine_privileged_state.invalidate_memory_view_runtime_state();
+
+                        // Patches applied to the previous process no longer have valid undo records.
+                        if let Ok(mut code_patch_registry) = engine_privileged_state.get_code_patch_registry().write() {
+                            let process_id = process_changed_event
+                                .process_info
+                                .as_ref()
+                                .map(|process_info| process_info.get_process_id_raw());
+
+                            code_patch_registry.set_process_id(process_id);
+                        }
+
+                        // Written pages of the previous process say nothing about the memory of the new one.
+                        if let Ok(mut memory_write_tracking_session) = engine_privileged_state.memory_write_tracking_session.write() {
+                            *memory_write_tracking_session = None;
+                        }
+
+                        if let Ok(mut pending_scan_pages) = engine_privileged_state.pending_scan_pages.write() {
+                            *pending_scan_pages = None;
+                        }
                     }
                     EngineEvent::Logging(_) => {}
                     _ => {}
@@ -405,7 +544,7 @@ mod tests {
         projects::project_symbol_catalog::ProjectSymbolCatalog,
         structs::{symbolic_field_definition::SymbolicFieldDefinition, symbolic_struct_definition::SymbolicStructDefinition},
     };
-    use squalr_engine_targets::{ProcessQueryError, ProcessQueryOptions};
+    use squalr_engine_targets::{ProcessLaunchOptions, ProcessQueryError, ProcessQueryOptions};
     use std::sync::{Arc, Mutex, RwLock};
 
     struct NoOpProcessQueryProvider;
@@ -429,6 +568,13 @@ mod tests {
             Err(ProcessQueryError::internal("open_process", "not implemented in no-op provider"))
         }
 
+        fn launch_process(
+            &self,
+            _process_launch_options: &ProcessLaunchOptions,
+        ) -> Result<OpenedProcessInfo, ProcessQueryError> {
+            Err(ProcessQueryError::internal("launch_process", "not implemented in no-op provider"))
+        }
+
         fn close_process(
             &self,
             _handle: u64,
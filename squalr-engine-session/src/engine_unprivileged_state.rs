@@ -2,6 +2,7 @@ use crate::logging::log_dispatcher::{LogDispatcher, LogDispatcherOptions};
 use crate::plugins::plugin_registry::PluginRegistry;
 use crate::projects::project_manager::ProjectManager;
 use crate::registries::privileged_registry_cache::PrivilegedRegistryCache;
+use crate::tasks::project_item_type_tick_task::ProjectItemTypeTickTask;
 use crate::virtual_snapshots::{
     virtual_snapshot::VirtualSnapshot, virtual_snapshot_query::VirtualSnapshotQuery, virtual_snapshot_resolver::materialize_virtual_snapshot_queries,
 };
@@ -18,6 +19,7 @@ use squalr_engine_api::commands::{privileged_command::PrivilegedCommand, privile
 use squalr_engine_api::engine::engine_api_unprivileged_bindings::EngineApiUnprivilegedBindings;
 use squalr_engine_api::engine::engine_event_envelope::EngineEventEnvelope;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::events::debugger::debugger_event::DebuggerEvent;
 use squalr_engine_api::events::engine_event::{EngineEvent, EngineEventRequest};
 use squalr_engine_api::events::logging::logging_event::LoggingEvent;
 use squalr_engine_api::events::plugins::plugins_event::PluginsEvent;
@@ -34,6 +36,7 @@ use squalr_engine_api::structures::data_values::{
     anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat, data_value::DataValue,
 };
 use squalr_engine_api::structures::projects::project_context::ProjectContext;
+use squalr_engine_api::structures::projects::project_items::project_item::ProjectItem;
 use squalr_engine_api::structures::projects::project_symbol_catalog::ProjectSymbolCatalog;
 use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
 use std::{
@@ -181,6 +184,16 @@ impl EngineExecutionContext for EngineUnprivilegedState {
                 .unwrap_or_default()
             })
     }
+
+    fn create_plugin_project_item(
+        &self,
+        project_item_type_id: &str,
+        project_item_name: &str,
+    ) -> Option<ProjectItem> {
+        self.plugin_registry
+            .get_enabled_project_item_type(project_item_type_id)
+            .map(|(_plugin_id, project_item_type)| project_item_type.create_project_item(project_item_name))
+    }
 }
 
 impl EngineUnprivilegedState {
@@ -193,7 +206,7 @@ impl EngineUnprivilegedState {
         options: EngineUnprivilegedStateOptions,
     ) -> Arc<Self> {
         let project_manager = Arc::new(ProjectManager::new());
-        let plugin_registry = Arc::new(PluginRegistry::new());
+        let plugin_registry = Arc::new(PluginRegistry::new_with_installed_plugins());
 
         let engine_unprivileged_state = Arc::new(EngineUnprivilegedState {
             engine_api_unprivileged_bindings,
@@ -219,6 +232,7 @@ impl EngineUnprivilegedState {
     pub fn initialize(self: &Arc<Self>) {
         self.start_event_dispatcher();
         self.refresh_privileged_registry_catalog();
+        ProjectItemTypeTickTask::start_task(Arc::downgrade(self));
     }
 
     /// Gets the file system logger that routes log events to the log file.
@@ -914,6 +928,11 @@ impl EngineUnprivilegedState {
         engine_event: EngineEvent,
     ) {
         match engine_event {
+            EngineEvent::Debugger(debugger_event) => match *debugger_event {
+                DebuggerEvent::BreakpointUpdated { breakpoint_updated_event } => {
+                    Self::dispatch_engine_event(event_listeners, breakpoint_updated_event);
+                }
+            },
             EngineEvent::Logging(logging_event) => match logging_event {
                 LoggingEvent::LogRecorded { log_recorded_event } => {
                     log::log!(
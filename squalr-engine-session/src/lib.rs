@@ -1,6 +1,7 @@
 pub mod engine_privileged_state;
 pub mod engine_unprivileged_state;
 mod logging;
+pub mod memory_write_tracking;
 pub mod os;
 pub mod plugins;
 pub mod projects;
@@ -0,0 +1,96 @@
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+
+/// A write tracking window opened on a process, along with the page contents captured when it began, so that the bytes
+/// written during the window can be located within each written page.
+pub struct MemoryWriteTrackingSession {
+    process_id: u32,
+    tracked_regions: Vec<NormalizedRegion>,
+    /// Contents of the tracked regions when tracking began, sorted by address. Regions that could not be read are missing.
+    captured_regions: Vec<(NormalizedRegion, Vec<u8>)>,
+    /// Whether the OS reports written pages. Otherwise, written pages are found by comparing the captured contents.
+    is_page_tracking_supported: bool,
+}
+
+impl MemoryWriteTrackingSession {
+    pub fn new(
+        process_id: u32,
+        tracked_regions: Vec<NormalizedRegion>,
+        mut captured_regions: Vec<(NormalizedRegion, Vec<u8>)>,
+        is_page_tracking_supported: bool,
+    ) -> Self {
+        captured_regions.sort_by_key(|(captured_region, _)| captured_region.get_base_address());
+
+        Self {
+            process_id,
+            tracked_regions,
+            captured_regions,
+            is_page_tracking_supported,
+        }
+    }
+
+    pub fn get_process_id(&self) -> u32 {
+        self.process_id
+    }
+
+    pub fn get_tracked_regions(&self) -> &[NormalizedRegion] {
+        &self.tracked_regions
+    }
+
+    pub fn get_captured_regions(&self) -> &[(NormalizedRegion, Vec<u8>)] {
+        &self.captured_regions
+    }
+
+    pub fn is_page_tracking_supported(&self) -> bool {
+        self.is_page_tracking_supported
+    }
+
+    /// Gets the contents captured for a range when tracking began, if the range lies entirely within one captured region.
+    pub fn find_captured_bytes(
+        &self,
+        region: &NormalizedRegion,
+    ) -> Option<&[u8]> {
+        let captured_region_index = self
+            .captured_regions
+            .partition_point(|(captured_region, _)| captured_region.get_base_address() <= region.get_base_address())
+            .checked_sub(1)?;
+        let (captured_region, captured_bytes) = &self.captured_regions[captured_region_index];
+
+        if region.get_end_address() > captured_region.get_end_address() {
+            return None;
+        }
+
+        let start_offset = (region.get_base_address() - captured_region.get_base_address()) as usize;
+
+        captured_bytes.get(start_offset..start_offset + region.get_region_size() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryWriteTrackingSession;
+    use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+
+    #[test]
+    fn find_captured_bytes_only_returns_ranges_within_one_captured_region() {
+        let memory_write_tracking_session = MemoryWriteTrackingSession::new(
+            7,
+            vec![NormalizedRegion::new(0x1000, 0x3000)],
+            vec![
+                (NormalizedRegion::new(0x3000, 0x4), vec![5, 6, 7, 8]),
+                (NormalizedRegion::new(0x1000, 0x4), vec![1, 2, 3, 4]),
+            ],
+            false,
+        );
+
+        assert_eq!(
+            memory_write_tracking_session.find_captured_bytes(&NormalizedRegion::new(0x1001, 0x2)),
+            Some(&[2u8, 3u8][..])
+        );
+        assert_eq!(
+            memory_write_tracking_session.find_captured_bytes(&NormalizedRegion::new(0x3000, 0x4)),
+            Some(&[5u8, 6u8, 7u8, 8u8][..])
+        );
+        assert_eq!(memory_write_tracking_session.find_captured_bytes(&NormalizedRegion::new(0x1002, 0x4)), None);
+        assert_eq!(memory_write_tracking_session.find_captured_bytes(&NormalizedRegion::new(0x0800, 0x4)), None);
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_write_tracking_session;
@@ -2,22 +2,43 @@ use crate::os::memory_view_router::MemoryViewRouter;
 use crate::plugins::plugin_registry::PluginRegistry;
 use squalr_engine_api::plugins::memory_view::MemoryViewPluginError;
 use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::debugging::{
+    breakpoint::Breakpoint, breakpoint_hit::BreakpointHit, breakpoint_mode::BreakpointMode, instruction_trace_stop_reason::InstructionTraceStopReason,
+    register_snapshot::RegisterSnapshot, traced_instruction::TracedInstruction, watchpoint::Watchpoint, watchpoint_access::WatchpointAccess,
+    watchpoint_hit::WatchpointHit, watchpoint_size::WatchpointSize,
+};
+use squalr_engine_api::structures::memory::memory_protection::MemoryProtection;
 use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
 use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::processes::process_info::ProcessInfo;
+use squalr_engine_api::structures::processes::thread_info::ThreadInfo;
 use squalr_engine_api::structures::structs::valued_struct::ValuedStruct;
 use squalr_engine_targets::PageRetrievalMode;
-pub use squalr_engine_targets::{MemoryQueryProvider, MemoryReadProvider, MemoryWriteProvider, ProcessQueryError, ProcessQueryOptions, ProcessQueryProvider};
+pub use squalr_engine_targets::{
+    DebuggerError, DebuggerProvider, MemoryAllocationError, MemoryAllocationProvider, MemoryQueryProvider, MemoryReadProvider, MemoryWriteProvider,
+    MemoryWriteTrackingError, MemoryWriteTrackingProvider, ProcessLaunchOptions, ProcessQueryError, ProcessQueryOptions, ProcessQueryProvider,
+    ThreadControlError, ThreadControlProvider,
+};
+use squalr_engine_targets_native::debugger::Debugger;
+use squalr_engine_targets_native::debugger::debugger_trait::DebuggerTrait;
+use squalr_engine_targets_native::memory_allocator::MemoryAllocator;
+use squalr_engine_targets_native::memory_allocator::memory_allocator_trait::MemoryAllocatorTrait;
 use squalr_engine_targets_native::memory_queryer::memory_queryer::MemoryQueryer;
 use squalr_engine_targets_native::memory_queryer::memory_queryer_trait::MemoryQueryerTrait;
 use squalr_engine_targets_native::memory_reader::MemoryReader;
 use squalr_engine_targets_native::memory_reader::memory_reader_trait::MemoryReaderTrait;
+use squalr_engine_targets_native::memory_write_tracker::MemoryWriteTracker;
+use squalr_engine_targets_native::memory_write_tracker::memory_write_tracker_trait::MemoryWriteTrackerTrait;
 use squalr_engine_targets_native::memory_writer::MemoryWriter;
 use squalr_engine_targets_native::memory_writer::memory_writer_trait::MemoryWriterTrait;
 use squalr_engine_targets_native::process_query::process_queryer::ProcessQuery;
+use squalr_engine_targets_native::thread_controller::ThreadController;
+use squalr_engine_targets_native::thread_controller::thread_controller_trait::ThreadControllerTrait;
 use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Clone)]
 pub struct EngineOsProviders {
@@ -26,6 +47,10 @@ pub struct EngineOsProviders {
     pub memory_query: Arc<dyn MemoryQueryProvider>,
     pub memory_read: Arc<dyn MemoryReadProvider>,
     pub memory_write: Arc<dyn MemoryWriteProvider>,
+    pub debugger: Arc<dyn DebuggerProvider>,
+    pub memory_allocation: Arc<dyn MemoryAllocationProvider>,
+    pub thread_control: Arc<dyn ThreadControlProvider>,
+    pub memory_write_tracking: Arc<dyn MemoryWriteTrackingProvider>,
     memory_view_router: Option<Arc<MemoryViewRouter>>,
 }
 
@@ -42,10 +67,50 @@ impl EngineOsProviders {
             memory_query,
             memory_read,
             memory_write,
+            debugger: Arc::new(DefaultDebuggerProvider {}),
+            memory_allocation: Arc::new(DefaultMemoryAllocationProvider {}),
+            thread_control: Arc::new(DefaultThreadControlProvider {}),
+            memory_write_tracking: Arc::new(DefaultMemoryWriteTrackingProvider {}),
             memory_view_router: None,
         }
     }
 
+    pub fn with_debugger(
+        mut self,
+        debugger: Arc<dyn DebuggerProvider>,
+    ) -> Self {
+        self.debugger = debugger;
+
+        self
+    }
+
+    pub fn with_memory_allocation(
+        mut self,
+        memory_allocation: Arc<dyn MemoryAllocationProvider>,
+    ) -> Self {
+        self.memory_allocation = memory_allocation;
+
+        self
+    }
+
+    pub fn with_thread_control(
+        mut self,
+        thread_control: Arc<dyn ThreadControlProvider>,
+    ) -> Self {
+        self.thread_control = thread_control;
+
+        self
+    }
+
+    pub fn with_memory_write_tracking(
+        mut self,
+        memory_write_tracking: Arc<dyn MemoryWriteTrackingProvider>,
+    ) -> Self {
+        self.memory_write_tracking = memory_write_tracking;
+
+        self
+    }
+
     pub fn with_memory_view_routing(
         self,
         plugin_registry: Arc<PluginRegistry>,
@@ -57,6 +122,10 @@ impl EngineOsProviders {
             memory_query,
             memory_read,
             memory_write,
+            debugger,
+            memory_allocation,
+            thread_control,
+            memory_write_tracking,
             memory_view_router: _,
         } = self;
         let base_memory_query = memory_query.clone();
@@ -71,6 +140,11 @@ impl EngineOsProviders {
                 memory_view_router.clone(),
             )),
             memory_write: Arc::new(RoutedMemoryWriteProvider::new(memory_write, base_memory_query, memory_view_router.clone())),
+            debugger,
+            // Allocation, thread control and write tracking always target the real process, since memory views only remap reads and writes.
+            memory_allocation,
+            thread_control,
+            memory_write_tracking,
             memory_view_router: Some(memory_view_router.clone()),
         }
     }
@@ -108,6 +182,10 @@ impl Default for EngineOsProviders {
             memory_query: Arc::new(DefaultMemoryQueryProvider {}),
             memory_read: Arc::new(DefaultMemoryReadProvider {}),
             memory_write: Arc::new(DefaultMemoryWriteProvider {}),
+            debugger: Arc::new(DefaultDebuggerProvider {}),
+            memory_allocation: Arc::new(DefaultMemoryAllocationProvider {}),
+            thread_control: Arc::new(DefaultThreadControlProvider {}),
+            memory_write_tracking: Arc::new(DefaultMemoryWriteTrackingProvider {}),
             memory_view_router: None,
         }
     }
@@ -134,6 +212,13 @@ impl ProcessQueryProvider for DefaultProcessQueryProvider {
         ProcessQuery::open_process(process_info)
     }
 
+    fn launch_process(
+        &self,
+        process_launch_options: &ProcessLaunchOptions,
+    ) -> Result<OpenedProcessInfo, ProcessQueryError> {
+        ProcessQuery::launch_process(process_launch_options)
+    }
+
     fn close_process(
         &self,
         handle: u64,
@@ -186,6 +271,24 @@ impl MemoryQueryProvider for DefaultMemoryQueryProvider {
     ) -> Vec<NormalizedRegion> {
         MemoryQueryer::get_memory_page_bounds(process_info, page_retrieval_mode)
     }
+
+    fn get_executable_page_bounds(
+        &self,
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: u64,
+    ) -> Vec<NormalizedRegion> {
+        MemoryQueryer::query_executable_pages_by_address_range(process_info, start_address, end_address)
+    }
+
+    fn resolve_module_symbol(
+        &self,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+        symbol_name: &str,
+    ) -> Option<u64> {
+        MemoryQueryer::get_instance().resolve_module_symbol(process_info, module, symbol_name)
+    }
 }
 
 struct DefaultMemoryReadProvider;
@@ -230,6 +333,197 @@ impl MemoryWriteProvider for DefaultMemoryWriteProvider {
     ) -> bool {
         MemoryWriter::get_instance().write_bytes(process_info, address, values)
     }
+
+    fn write_bytes_ignoring_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        MemoryWriter::get_instance().write_bytes_ignoring_protection(process_info, address, values)
+    }
+}
+
+struct DefaultMemoryAllocationProvider;
+
+impl MemoryAllocationProvider for DefaultMemoryAllocationProvider {
+    fn allocate(
+        &self,
+        process_info: &OpenedProcessInfo,
+        preferred_address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        MemoryAllocator::get_instance().allocate(process_info, preferred_address, size, protection)
+    }
+
+    fn free(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        MemoryAllocator::get_instance().free(process_info, address, size)
+    }
+
+    fn protect(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        MemoryAllocator::get_instance().protect(process_info, address, size, protection)
+    }
+}
+
+struct DefaultMemoryWriteTrackingProvider;
+
+impl MemoryWriteTrackingProvider for DefaultMemoryWriteTrackingProvider {
+    fn begin_write_tracking(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), MemoryWriteTrackingError> {
+        MemoryWriteTracker::get_instance().begin_write_tracking(process_info)
+    }
+
+    fn get_written_pages(
+        &self,
+        process_info: &OpenedProcessInfo,
+        memory_regions: &[NormalizedRegion],
+    ) -> Result<Vec<NormalizedRegion>, MemoryWriteTrackingError> {
+        MemoryWriteTracker::get_instance().get_written_pages(process_info, memory_regions)
+    }
+}
+
+struct DefaultThreadControlProvider;
+
+impl ThreadControlProvider for DefaultThreadControlProvider {
+    fn get_threads(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<Vec<ThreadInfo>, ThreadControlError> {
+        ThreadController::get_instance().get_threads(process_info)
+    }
+
+    fn suspend_thread(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<(), ThreadControlError> {
+        ThreadController::get_instance().suspend_thread(process_info, thread_id)
+    }
+
+    fn resume_thread(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<(), ThreadControlError> {
+        ThreadController::get_instance().resume_thread(process_info, thread_id)
+    }
+
+    fn suspend_process(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError> {
+        ThreadController::get_instance().suspend_process(process_info)
+    }
+
+    fn resume_process(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError> {
+        ThreadController::get_instance().resume_process(process_info)
+    }
+
+    fn get_thread_context(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<RegisterSnapshot, ThreadControlError> {
+        ThreadController::get_instance().get_thread_context(process_info, thread_id)
+    }
+}
+
+struct DefaultDebuggerProvider;
+
+impl DebuggerProvider for DefaultDebuggerProvider {
+    fn set_watchpoint(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        watchpoint_size: WatchpointSize,
+        watchpoint_access: WatchpointAccess,
+    ) -> Result<Watchpoint, DebuggerError> {
+        Debugger::get_instance().set_watchpoint(process_info, address, watchpoint_size, watchpoint_access)
+    }
+
+    fn remove_watchpoint(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError> {
+        Debugger::get_instance().remove_watchpoint(watchpoint_id)
+    }
+
+    fn get_watchpoint_hits(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError> {
+        Debugger::get_instance().get_watchpoint_hits(watchpoint_id)
+    }
+
+    fn get_watchpoints(&self) -> Vec<Watchpoint> {
+        Debugger::get_instance().get_watchpoints()
+    }
+
+    fn set_breakpoint(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        breakpoint_mode: BreakpointMode,
+    ) -> Result<Breakpoint, DebuggerError> {
+        Debugger::get_instance().set_breakpoint(process_info, address, breakpoint_mode)
+    }
+
+    fn remove_breakpoint(
+        &self,
+        breakpoint_id: u64,
+    ) -> Result<Breakpoint, DebuggerError> {
+        Debugger::get_instance().remove_breakpoint(breakpoint_id)
+    }
+
+    fn get_breakpoint_hits(
+        &self,
+        breakpoint_id: u64,
+        after_hit_index: u64,
+    ) -> Result<(Breakpoint, Vec<BreakpointHit>), DebuggerError> {
+        Debugger::get_instance().get_breakpoint_hits(breakpoint_id, after_hit_index)
+    }
+
+    fn get_breakpoints(&self) -> Vec<Breakpoint> {
+        Debugger::get_instance().get_breakpoints()
+    }
+
+    fn call_function(
+        &self,
+        process_info: &OpenedProcessInfo,
+        function_address: u64,
+        arguments: &[u64],
+    ) -> Result<u64, DebuggerError> {
+        Debugger::get_instance().call_function(process_info, function_address, arguments)
+    }
+
+    fn trace_instructions(
+        &self,
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: Option<u64>,
+        thread_id: Option<u32>,
+        instruction_budget: u64,
+        start_timeout: Duration,
+    ) -> Result<(Vec<TracedInstruction>, InstructionTraceStopReason), DebuggerError> {
+        Debugger::get_instance().trace_instructions(process_info, start_address, end_address, thread_id, instruction_budget, start_timeout)
+    }
 }
 
 struct RoutedProcessQueryProvider {
@@ -268,6 +562,13 @@ impl ProcessQueryProvider for RoutedProcessQueryProvider {
         self.base_provider.open_process(process_info)
     }
 
+    fn launch_process(
+        &self,
+        process_launch_options: &ProcessLaunchOptions,
+    ) -> Result<OpenedProcessInfo, ProcessQueryError> {
+        self.base_provider.launch_process(process_launch_options)
+    }
+
     fn close_process(
         &self,
         handle: u64,
@@ -496,6 +797,48 @@ impl MemoryQueryProvider for RoutedMemoryQueryProvider {
 
         self.get_memory_page_bounds(process_info, page_retrieval_mode)
     }
+
+    fn get_native_pointer_size(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Option<PointerScanPointerSize> {
+        if let Some(memory_view_instance) = self.memory_view_router.get_or_create_instance(process_info) {
+            match memory_view_instance.lock() {
+                Ok(mut memory_view_instance) => {
+                    if let Err(error) = memory_view_instance.refresh() {
+                        RoutedMemoryQueryProvider::log_fallback_if_unexpected(memory_view_instance.plugin_id(), "refresh", "native pointer size", &error);
+                    } else {
+                        return memory_view_instance.get_native_pointer_size();
+                    }
+                }
+                Err(error) => {
+                    log::warn!("Failed to lock memory-view instance for native pointer size query: {}", error);
+                }
+            }
+        }
+
+        self.base_provider.get_native_pointer_size(process_info)
+    }
+
+    fn get_executable_page_bounds(
+        &self,
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: u64,
+    ) -> Vec<NormalizedRegion> {
+        self.base_provider
+            .get_executable_page_bounds(process_info, start_address, end_address)
+    }
+
+    fn resolve_module_symbol(
+        &self,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+        symbol_name: &str,
+    ) -> Option<u64> {
+        self.base_provider
+            .resolve_module_symbol(process_info, module, symbol_name)
+    }
 }
 
 struct RoutedMemoryReadProvider {
@@ -673,14 +1016,14 @@ impl RoutedMemoryWriteProvider {
                 address >= region_start_address && range_end_address <= region_end_address
             })
     }
-}
 
-impl MemoryWriteProvider for RoutedMemoryWriteProvider {
-    fn write_bytes(
+    /// Writes through the memory view that owns the address, or through `write_base_bytes` for memory the view does not own.
+    fn write_routed_bytes(
         &self,
         process_info: &OpenedProcessInfo,
         address: u64,
         values: &[u8],
+        write_base_bytes: impl Fn(&OpenedProcessInfo, u64, &[u8]) -> bool,
     ) -> bool {
         if let Some(memory_view_instance) = self.memory_view_router.get_or_create_instance(process_info) {
             match memory_view_instance.lock() {
@@ -688,14 +1031,14 @@ impl MemoryWriteProvider for RoutedMemoryWriteProvider {
                     let owns_address = memory_view_instance.owns_address(address);
 
                     if !owns_address {
-                        return self.base_provider.write_bytes(process_info, address, values);
+                        return write_base_bytes(process_info, address, values);
                     }
 
                     match memory_view_instance.write_bytes(address, values) {
                         Ok(()) => return true,
                         Err(_error) => {
                             if self.is_range_mapped_by_base_provider(process_info, address, values.len()) {
-                                return self.base_provider.write_bytes(process_info, address, values);
+                                return write_base_bytes(process_info, address, values);
                             }
 
                             return false;
@@ -708,7 +1051,32 @@ impl MemoryWriteProvider for RoutedMemoryWriteProvider {
             }
         }
 
-        self.base_provider.write_bytes(process_info, address, values)
+        write_base_bytes(process_info, address, values)
+    }
+}
+
+impl MemoryWriteProvider for RoutedMemoryWriteProvider {
+    fn write_bytes(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        self.write_routed_bytes(process_info, address, values, |process_info, address, values| {
+            self.base_provider.write_bytes(process_info, address, values)
+        })
+    }
+
+    fn write_bytes_ignoring_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        self.write_routed_bytes(process_info, address, values, |process_info, address, values| {
+            self.base_provider
+                .write_bytes_ignoring_protection(process_info, address, values)
+        })
     }
 }
 
@@ -723,12 +1091,13 @@ mod tests {
     use squalr_engine_api::structures::{
         data_values::data_value::DataValue,
         memory::{bitness::Bitness, normalized_module::NormalizedModule, normalized_region::NormalizedRegion},
+        pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize,
         processes::{opened_process_info::OpenedProcessInfo, process_info::ProcessInfo},
         structs::valued_struct::ValuedStruct,
     };
     use squalr_engine_targets_native::{
         memory_queryer::page_retrieval_mode::PageRetrievalMode,
-        process_query::{process_query_error::ProcessQueryError, process_query_options::ProcessQueryOptions},
+        process_query::{process_launch_options::ProcessLaunchOptions, process_query_error::ProcessQueryError, process_query_options::ProcessQueryOptions},
     };
     use std::sync::{Arc, Mutex};
 
@@ -753,6 +1122,13 @@ mod tests {
             Err(ProcessQueryError::internal("open_process", "not used in routing tests"))
         }
 
+        fn launch_process(
+            &self,
+            _process_launch_options: &ProcessLaunchOptions,
+        ) -> Result<OpenedProcessInfo, ProcessQueryError> {
+            Err(ProcessQueryError::internal("launch_process", "not used in routing tests"))
+        }
+
         fn close_process(
             &self,
             _handle: u64,
@@ -958,6 +1334,10 @@ mod tests {
             Ok(Vec::new())
         }
 
+        fn get_native_pointer_size(&self) -> Option<PointerScanPointerSize> {
+            Some(PointerScanPointerSize::Pointer32be)
+        }
+
         fn read_bytes(
             &self,
             _address: u64,
@@ -1021,6 +1401,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn native_pointer_size_comes_from_active_memory_view() {
+        let os_providers = EngineOsProviders::new(
+            Arc::new(TestProcessQueryProvider),
+            Arc::new(TestMemoryQueryProvider {
+                module_query_count: Arc::new(Mutex::new(0)),
+                page_query_count: Arc::new(Mutex::new(0)),
+                pages: vec![NormalizedRegion::new(0x1000, 0x80)],
+            }),
+            Arc::new(TestMemoryReadProvider {
+                read_bytes_count: Arc::new(Mutex::new(0)),
+            }),
+            Arc::new(TestMemoryWriteProvider {
+                write_count: Arc::new(Mutex::new(0)),
+            }),
+        )
+        .with_memory_view_routing(create_test_memory_view_plugin_registry());
+        let dolphin_process_info = OpenedProcessInfo::new(7, String::from("Dolphin.exe"), 42, Bitness::Bit64, None);
+        let unsupported_process_info = OpenedProcessInfo::new(8, String::from("notepad.exe"), 43, Bitness::Bit64, None);
+
+        assert_eq!(
+            os_providers
+                .memory_query
+                .get_native_pointer_size(&dolphin_process_info),
+            Some(PointerScanPointerSize::Pointer32be)
+        );
+        assert_eq!(
+            os_providers
+                .memory_query
+                .get_native_pointer_size(&unsupported_process_info),
+            None
+        );
+    }
+
     #[test]
     fn dolphin_plugin_stub_does_not_fall_back_for_unmapped_guest_addresses() {
         let page_query_count = Arc::new(Mutex::new(0));
@@ -1,7 +1,7 @@
 pub mod engine_os_provider;
 mod memory_view_router;
 
-pub use squalr_engine_targets::{PageRetrievalMode, ProcessQueryError, ProcessQueryOptions};
+pub use squalr_engine_targets::{PageRetrievalMode, ProcessLaunchOptions, ProcessQueryError, ProcessQueryOptions};
 pub use squalr_engine_targets_native::config::memory_settings_config::MemorySettingsConfig;
 pub use squalr_engine_targets_native::process::process_manager::ProcessManager;
 pub use sysinfo::Pid;
@@ -0,0 +1,112 @@
+use squalr_engine_api::{
+    commands::{
+        memory::{
+            read::{memory_read_request::MemoryReadRequest, memory_read_response::MemoryReadResponse},
+            write::{memory_write_request::MemoryWriteRequest, memory_write_response::MemoryWriteResponse},
+        },
+        privileged_command_request::PrivilegedCommandRequest,
+        privileged_command_response::TypedPrivilegedCommandResponse,
+    },
+    engine::engine_execution_context::EngineExecutionContext,
+    structures::{
+        data_types::data_type_ref::DataTypeRef,
+        data_values::container_type::ContainerType,
+        projects::project_items::project_item_type::ProjectItemTypeServices,
+        structs::{symbolic_field_definition::SymbolicFieldDefinition, symbolic_struct_definition::SymbolicStructDefinition},
+    },
+};
+use std::sync::{Arc, mpsc};
+use std::time::Duration;
+
+const MEMORY_REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Reaches the attached process for project item types by dispatching privileged memory commands.
+pub struct EngineProjectItemTypeServices {
+    engine_execution_context: Arc<dyn EngineExecutionContext>,
+}
+
+impl EngineProjectItemTypeServices {
+    pub fn new(engine_execution_context: Arc<dyn EngineExecutionContext>) -> Self {
+        Self { engine_execution_context }
+    }
+}
+
+impl ProjectItemTypeServices for EngineProjectItemTypeServices {
+    fn read_memory(
+        &self,
+        address: u64,
+        module_name: &str,
+        length: u64,
+    ) -> Result<Vec<u8>, String> {
+        if length == 0 {
+            return Ok(Vec::new());
+        }
+
+        let memory_read_request = MemoryReadRequest {
+            address,
+            module_name: module_name.to_string(),
+            symbolic_struct_definition: SymbolicStructDefinition::new_anonymous(vec![SymbolicFieldDefinition::new(
+                DataTypeRef::new("u8"),
+                ContainerType::ArrayFixed(length),
+            )]),
+            suppress_logging: true,
+            value_transform: None,
+        };
+        let (memory_read_response_sender, memory_read_response_receiver) = mpsc::channel();
+
+        if !self.engine_execution_context.dispatch_privileged_command(
+            memory_read_request.to_engine_command(),
+            Box::new(move |memory_read_response| {
+                let _ = memory_read_response_sender.send(MemoryReadResponse::from_engine_response(memory_read_response));
+            }),
+        ) {
+            return Err(String::from("Failed to dispatch process memory read for project item type."));
+        }
+
+        let memory_read_response = memory_read_response_receiver
+            .recv_timeout(MEMORY_REQUEST_TIMEOUT)
+            .map_err(|error| format!("Timed out reading process memory for project item type: {}", error))?
+            .map_err(|_| String::from("Process memory read returned an unexpected response type."))?;
+
+        if !memory_read_response.success {
+            return Err(format!("Failed to read {} byte(s) from {}+0x{:X}.", length, module_name, address));
+        }
+
+        Ok(memory_read_response.valued_struct.get_bytes())
+    }
+
+    fn write_memory(
+        &self,
+        address: u64,
+        module_name: &str,
+        values: &[u8],
+    ) -> Result<(), String> {
+        let memory_write_request = MemoryWriteRequest {
+            address,
+            module_name: module_name.to_string(),
+            value: values.to_vec(),
+            value_transform: None,
+        };
+        let (memory_write_response_sender, memory_write_response_receiver) = mpsc::channel();
+
+        if !self.engine_execution_context.dispatch_privileged_command(
+            memory_write_request.to_engine_command(),
+            Box::new(move |memory_write_response| {
+                let _ = memory_write_response_sender.send(MemoryWriteResponse::from_engine_response(memory_write_response));
+            }),
+        ) {
+            return Err(String::from("Failed to dispatch process memory write for project item type."));
+        }
+
+        let memory_write_response = memory_write_response_receiver
+            .recv_timeout(MEMORY_REQUEST_TIMEOUT)
+            .map_err(|error| format!("Timed out writing process memory for project item type: {}", error))?
+            .map_err(|_| String::from("Process memory write returned an unexpected response type."))?;
+
+        if !memory_write_response.success {
+            return Err(format!("Failed to write {} byte(s) to {}+0x{:X}.", values.len(), module_name, address));
+        }
+
+        Ok(())
+    }
+}
@@ -1 +1,2 @@
+pub mod engine_project_item_type_services;
 pub mod plugin_registry;
@@ -1,13 +1,18 @@
 use std::{
     collections::{HashMap, HashSet},
+    path::Path,
     sync::{Arc, RwLock},
 };
 
 use squalr_engine_api::{
-    plugins::{PluginActivationState, PluginCapability, PluginPackage, PluginPermission, PluginState, symbol_tree::symbol_tree_action::SymbolTreeAction},
-    structures::processes::opened_process_info::OpenedProcessInfo,
+    plugins::{
+        PluginActivationState, PluginCapability, PluginPackage, PluginPermission, PluginState, instruction_set::InstructionSet,
+        symbol_tree::symbol_tree_action::SymbolTreeAction,
+    },
+    structures::{processes::opened_process_info::OpenedProcessInfo, projects::project_items::project_item_type::ProjectItemType},
 };
 use squalr_plugin_builtins::get_builtin_plugin_packages;
+use squalr_plugin_host_wasm::WasmPluginHost;
 
 pub struct PluginRegistry {
     plugin_packages: Vec<Arc<dyn PluginPackage>>,
@@ -21,6 +26,25 @@ impl PluginRegistry {
         Self::from_plugin_packages(get_builtin_plugin_packages())
     }
 
+    /// Creates a registry with the built-in plugins plus the third-party WebAssembly plugins installed for this user.
+    pub fn new_with_installed_plugins() -> Self {
+        match WasmPluginHost::get_default_plugins_directory() {
+            Some(plugins_directory) => Self::new_with_plugins_directory(&plugins_directory),
+            None => Self::new(),
+        }
+    }
+
+    pub fn new_with_plugins_directory(plugins_directory: &Path) -> Self {
+        let mut plugin_packages = get_builtin_plugin_packages();
+
+        match WasmPluginHost::new() {
+            Ok(wasm_plugin_host) => plugin_packages.extend(wasm_plugin_host.discover_plugin_packages(plugins_directory)),
+            Err(error) => log::error!("Failed to start the WebAssembly plugin host: {}", error),
+        }
+
+        Self::from_plugin_packages(plugin_packages)
+    }
+
     pub(crate) fn from_plugin_packages(plugin_packages: Vec<Arc<dyn PluginPackage>>) -> Self {
         let enabled_plugin_ids = plugin_packages
             .iter()
@@ -377,6 +401,54 @@ impl PluginRegistry {
             .collect()
     }
 
+    /// Gets the project item types contributed by enabled plugins, in plugin priority order.
+    pub fn get_enabled_project_item_types(&self) -> Vec<(String, Arc<dyn ProjectItemType>)> {
+        self.get_ordered_plugin_packages()
+            .iter()
+            .filter(|plugin_package| self.is_plugin_enabled(plugin_package.metadata().get_plugin_id()))
+            .filter_map(|plugin_package| {
+                plugin_package
+                    .as_project_item_type_plugin()
+                    .map(|project_item_type_plugin| {
+                        (
+                            plugin_package.metadata().get_plugin_id().to_string(),
+                            project_item_type_plugin.contributed_project_item_types(),
+                        )
+                    })
+            })
+            .flat_map(|(plugin_id, project_item_types)| {
+                project_item_types
+                    .iter()
+                    .cloned()
+                    .map(move |project_item_type| (plugin_id.clone(), project_item_type))
+            })
+            .collect()
+    }
+
+    /// Resolves a project item type id to the enabled plugin that contributes it. Higher priority plugins win.
+    pub fn get_enabled_project_item_type(
+        &self,
+        project_item_type_id: &str,
+    ) -> Option<(String, Arc<dyn ProjectItemType>)> {
+        self.get_enabled_project_item_types()
+            .into_iter()
+            .find(|(_plugin_id, project_item_type)| project_item_type.get_project_item_type_id() == project_item_type_id)
+    }
+
+    /// Resolves an instruction set id to an instruction set contributed by an enabled plugin. Higher priority plugins win.
+    pub fn get_enabled_instruction_set(
+        &self,
+        instruction_set_id: &str,
+    ) -> Option<Arc<dyn InstructionSet>> {
+        self.get_ordered_plugin_packages()
+            .iter()
+            .filter(|plugin_package| self.is_plugin_enabled(plugin_package.metadata().get_plugin_id()))
+            .filter_map(|plugin_package| plugin_package.as_instruction_set_plugin())
+            .flat_map(|instruction_set_plugin| instruction_set_plugin.contributed_instruction_sets().iter())
+            .find(|instruction_set| instruction_set.get_instruction_set_id() == instruction_set_id)
+            .cloned()
+    }
+
     pub fn plugin_action_has_required_permissions(
         &self,
         plugin_id: &str,
@@ -554,7 +626,7 @@ mod tests {
         let plugin_package = plugin_registry.find_memory_view_plugin_package(&opened_process_info);
 
         assert!(plugin_package.is_some());
-        assert_eq!(plugin_registry.get_plugin_packages().len(), 6);
+        assert_eq!(plugin_registry.get_plugin_packages().len(), 12);
         assert_eq!(
             plugin_package
                 .expect("Expected the Dolphin plugin to match the Dolphin process.")
@@ -575,7 +647,7 @@ mod tests {
             .find(|plugin_state| plugin_state.get_metadata().get_plugin_id() == "builtin.memory-view.dolphin")
             .expect("Expected the Dolphin plugin state to be present.");
 
-        assert_eq!(plugin_states.len(), 6);
+        assert_eq!(plugin_states.len(), 12);
         assert_eq!(dolphin_plugin_state.get_activation_state(), PluginActivationState::Activating);
     }
 
@@ -590,7 +662,7 @@ mod tests {
             .find(|plugin_state| plugin_state.get_metadata().get_plugin_id() == "builtin.memory-view.dolphin")
             .expect("Expected the Dolphin plugin state to be present.");
 
-        assert_eq!(plugin_states.len(), 6);
+        assert_eq!(plugin_states.len(), 12);
         assert_eq!(dolphin_plugin_state.get_activation_state(), PluginActivationState::Activated);
     }
 
@@ -613,12 +685,45 @@ mod tests {
             .find(|plugin_state| plugin_state.get_metadata().get_plugin_id() == "builtin.memory-view.dolphin")
             .expect("Expected the Dolphin plugin state to be present.");
 
-        assert_eq!(plugin_states.len(), 6);
+        assert_eq!(plugin_states.len(), 12);
         assert!(!dolphin_plugin_state.get_is_enabled());
         assert!(dolphin_plugin_state.get_can_activate_for_current_process());
         assert!(!dolphin_plugin_state.get_is_active_for_current_process());
     }
 
+    #[test]
+    fn registry_loads_installed_wasm_plugins_disabled_by_default() {
+        let plugins_directory = tempfile::tempdir().expect("Expected a temporary plugins directory.");
+        let plugin_directory = plugins_directory.path().join("gold");
+        let module_bytes = wat::parse_str(
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "squalr_alloc") (param i32) (result i32) (i32.const 1024)))"#,
+        )
+        .expect("Expected the test guest to compile.");
+        std::fs::create_dir_all(&plugin_directory).expect("Expected the plugin directory to be created.");
+        std::fs::write(plugin_directory.join("gold.wasm"), module_bytes).expect("Expected the module to be written.");
+        std::fs::write(
+            plugin_directory.join(squalr_plugin_host_wasm::WASM_PLUGIN_MANIFEST_FILE_NAME),
+            r#"{
+                "plugin_id": "acme.gold",
+                "display_name": "Gold",
+                "module_path": "gold.wasm",
+                "data_types": [{ "data_type_id": "gold", "storage_data_type_id": "u32" }]
+            }"#,
+        )
+        .expect("Expected the manifest to be written.");
+
+        let plugin_registry = PluginRegistry::new_with_plugins_directory(plugins_directory.path());
+
+        assert_eq!(plugin_registry.get_plugin_packages().len(), 13);
+        assert!(plugin_registry.has_plugin_capability("acme.gold", PluginCapability::DataType));
+        assert!(!plugin_registry.is_plugin_enabled("acme.gold"));
+        assert!(!plugin_registry.is_data_type_enabled("gold"));
+        assert!(plugin_registry.set_plugin_enabled("acme.gold", true));
+        assert!(plugin_registry.is_data_type_enabled("gold"));
+    }
+
     #[test]
     fn registry_exposes_builtin_x86_instruction_plugin_capabilities() {
         let plugin_registry = PluginRegistry::new();
@@ -627,6 +732,20 @@ mod tests {
         assert!(plugin_registry.has_plugin_capability("builtin.instruction-set.x86-family", PluginCapability::DataType));
     }
 
+    #[test]
+    fn registry_resolves_enabled_instruction_sets_by_id() {
+        let plugin_registry = PluginRegistry::new();
+
+        assert_eq!(
+            plugin_registry
+                .get_enabled_instruction_set("x64")
+                .map(|instruction_set| instruction_set.get_display_name().to_string()),
+            Some(String::from("x64"))
+        );
+        assert!(plugin_registry.set_plugin_enabled("builtin.instruction-set.x86-family", false));
+        assert!(plugin_registry.get_enabled_instruction_set("x64").is_none());
+    }
+
     #[test]
     fn registry_exposes_builtin_arm_instruction_plugin_capabilities() {
         let plugin_registry = PluginRegistry::new();
@@ -1,20 +1,28 @@
+use squalr_engine_api::registries::code_patches::code_patch_registry::CodePatchRegistry;
 use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
 use squalr_engine_api::registries::project_item_types::project_item_type_registry::ProjectItemTypeRegistry;
 use squalr_engine_api::registries::registry_context::RegistryContext;
 use squalr_engine_api::registries::scan_rules::element_scan_rule_registry::ElementScanRuleRegistry;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::registries::value_transforms::value_transform_registry::ValueTransformRegistry;
 use std::sync::{Arc, RwLock};
 
 pub struct Registries {
     // The list of frozen scan results.
     freeze_list_registry: Arc<RwLock<FreezeListRegistry>>,
 
+    /// The code patches currently written to the opened process.
+    code_patch_registry: Arc<RwLock<CodePatchRegistry>>,
+
     /// The registry for project item types.
     project_item_type_registry: Arc<RwLock<ProjectItemTypeRegistry>>,
 
     /// The registry for element scan rules.
     element_scan_rule_registry: Arc<RwLock<ElementScanRuleRegistry>>,
 
+    /// The registry for transforms that decode obfuscated values.
+    value_transform_registry: Arc<RwLock<ValueTransformRegistry>>,
+
     /// The registry for symbolic struct definitions.
     symbol_registry: Arc<SymbolRegistry>,
 }
@@ -22,14 +30,18 @@ pub struct Registries {
 impl Registries {
     pub fn new() -> Self {
         let freeze_list_registry = Arc::new(RwLock::new(FreezeListRegistry::new()));
+        let code_patch_registry = Arc::new(RwLock::new(CodePatchRegistry::new()));
         let project_item_type_registry = Arc::new(RwLock::new(ProjectItemTypeRegistry::new()));
         let element_scan_rule_registry = Arc::new(RwLock::new(ElementScanRuleRegistry::new()));
+        let value_transform_registry = Arc::new(RwLock::new(ValueTransformRegistry::new()));
         let symbol_registry = Arc::new(SymbolRegistry::new());
 
         Self {
             freeze_list_registry,
+            code_patch_registry,
             project_item_type_registry,
             element_scan_rule_registry,
+            value_transform_registry,
             symbol_registry,
         }
     }
@@ -45,6 +57,11 @@ impl RegistryContext for Registries {
         self.freeze_list_registry.clone()
     }
 
+    /// Gets the registry for code patches applied to the opened process.
+    fn get_code_patch_registry(&self) -> Arc<RwLock<CodePatchRegistry>> {
+        self.code_patch_registry.clone()
+    }
+
     /// Gets the registry for project item types.
     fn get_project_item_type_registry(&self) -> Arc<RwLock<ProjectItemTypeRegistry>> {
         self.project_item_type_registry.clone()
@@ -54,4 +71,9 @@ impl RegistryContext for Registries {
     fn get_element_scan_rule_registry(&self) -> Arc<RwLock<ElementScanRuleRegistry>> {
         self.element_scan_rule_registry.clone()
     }
+
+    /// Gets the registry for value transforms.
+    fn get_value_transform_registry(&self) -> Arc<RwLock<ValueTransformRegistry>> {
+        self.value_transform_registry.clone()
+    }
 }
@@ -0,0 +1,91 @@
+use squalr_engine_api::events::debugger::breakpoint_updated::breakpoint_updated_event::BreakpointUpdatedEvent;
+use squalr_engine_api::events::engine_event::{EngineEvent, EngineEventRequest};
+use squalr_engine_api::structures::debugging::breakpoint::Breakpoint;
+use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+use squalr_engine_targets::DebuggerProvider;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Duration;
+
+const TASK_NAME: &str = "Breakpoint Session";
+
+/// How often the debugger is polled for new breakpoint hits.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+pub struct DebuggerBreakpointSessionTask;
+
+/// Implementation of a task that streams the hits of a software breakpoint to listeners as engine events.
+/// Cancelling the task removes the breakpoint.
+impl DebuggerBreakpointSessionTask {
+    pub fn start_task(
+        breakpoint: Breakpoint,
+        debugger: Arc<dyn DebuggerProvider>,
+        event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(format!("{} 0x{:X}", TASK_NAME, breakpoint.get_address()), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            let breakpoint_id = breakpoint.get_breakpoint_id();
+            let mut last_breakpoint = breakpoint;
+            let mut last_hit_index = 0;
+
+            loop {
+                if task_clone.get_cancellation_token().load(Ordering::Acquire) {
+                    match debugger.remove_breakpoint(breakpoint_id) {
+                        Ok(removed_breakpoint) => event_emitter(
+                            BreakpointUpdatedEvent {
+                                breakpoint: removed_breakpoint,
+                                breakpoint_hits: Vec::new(),
+                                is_removed: true,
+                            }
+                            .to_engine_event(),
+                        ),
+                        Err(error) => log::warn!("Failed to remove breakpoint {} on cancellation: {}", breakpoint_id, error),
+                    }
+
+                    break;
+                }
+
+                // The breakpoint disappears when it is removed by another request, which ends the session.
+                let Ok((breakpoint, breakpoint_hits)) = debugger.get_breakpoint_hits(breakpoint_id, last_hit_index) else {
+                    break;
+                };
+
+                if let Some(hit_limit) = breakpoint.get_breakpoint_mode().get_hit_limit() {
+                    task_clone.set_progress(breakpoint.get_hit_count() as f32 / hit_limit.max(1) as f32);
+                }
+
+                if breakpoint != last_breakpoint || !breakpoint_hits.is_empty() {
+                    last_hit_index = breakpoint_hits
+                        .iter()
+                        .map(|breakpoint_hit| breakpoint_hit.get_hit_index())
+                        .max()
+                        .unwrap_or(last_hit_index);
+                    last_breakpoint = breakpoint.clone();
+
+                    event_emitter(
+                        BreakpointUpdatedEvent {
+                            breakpoint: breakpoint.clone(),
+                            breakpoint_hits,
+                            is_removed: false,
+                        }
+                        .to_engine_event(),
+                    );
+                }
+
+                // Disarmed breakpoints stay listed with their hits until removed, but no longer change.
+                if !breakpoint.get_is_armed() {
+                    break;
+                }
+
+                thread::sleep(POLL_INTERVAL);
+            }
+
+            task_clone.complete();
+        });
+
+        task
+    }
+}
@@ -1,2 +1,4 @@
+pub mod debugger_breakpoint_session_task;
+pub mod project_item_type_tick_task;
 pub mod snapshot_scan_result_freeze_task;
 pub mod trackable_task_manager;
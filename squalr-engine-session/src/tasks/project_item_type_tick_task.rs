@@ -0,0 +1,458 @@
+use crate::engine_unprivileged_state::EngineUnprivilegedState;
+use crate::plugins::{engine_project_item_type_services::EngineProjectItemTypeServices, plugin_registry::PluginRegistry};
+use crate::settings::scan_settings_store::ScanSettingsStore;
+use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::plugins::PluginPermission;
+use squalr_engine_api::structures::projects::project_items::{
+    project_item::ProjectItem,
+    project_item_ref::ProjectItemRef,
+    project_item_type::{ProjectItemType, ProjectItemTypeServices},
+};
+use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Weak};
+use std::thread;
+use std::time::Duration;
+
+const TASK_NAME: &str = "Project Item Type Ticker";
+
+pub struct ProjectItemTypeTickTask;
+
+/// Implementation of a task that drives activation changes and ticks for project item types contributed by plugins.
+impl ProjectItemTypeTickTask {
+    pub fn start_task(engine_unprivileged_state: Weak<EngineUnprivilegedState>) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            let mut project_item_type_ticker = ProjectItemTypeTicker::default();
+
+            loop {
+                if task_clone.get_cancellation_token().load(Ordering::Acquire) {
+                    break;
+                }
+
+                // The task holds a weak reference so that it ends with the session instead of keeping it alive.
+                let Some(engine_unprivileged_state) = engine_unprivileged_state.upgrade() else {
+                    break;
+                };
+
+                Self::tick_project_items(&engine_unprivileged_state, &mut project_item_type_ticker);
+                drop(engine_unprivileged_state);
+                thread::sleep(Duration::from_millis(ScanSettingsStore::get_results_read_interval_ms()));
+            }
+
+            task_clone.complete();
+        });
+
+        task
+    }
+
+    fn tick_project_items(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        project_item_type_ticker: &mut ProjectItemTypeTicker,
+    ) {
+        let plugin_registry = engine_unprivileged_state.get_plugin_registry();
+        let opened_project = engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let activated_project_items = match opened_project.read() {
+            Ok(opened_project_guard) => match opened_project_guard.as_ref() {
+                Some(opened_project) => ProjectItemTypeTicker::collect_activated_plugin_project_items(opened_project.get_project_items(), &plugin_registry),
+                None => HashMap::new(),
+            },
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock for project item type ticks: {}", error);
+
+                return;
+            }
+        };
+
+        if activated_project_items.is_empty() && !project_item_type_ticker.has_active_project_items() {
+            return;
+        }
+
+        let engine_execution_context: Arc<dyn EngineExecutionContext> = engine_unprivileged_state.clone();
+        let project_item_type_services = EngineProjectItemTypeServices::new(engine_execution_context);
+
+        project_item_type_ticker.tick(activated_project_items, &plugin_registry, &project_item_type_services);
+    }
+}
+
+/// An activated project item whose type is contributed by a plugin.
+#[derive(Clone)]
+pub(crate) struct ActivatedPluginProjectItem {
+    plugin_id: String,
+    project_item_type: Arc<dyn ProjectItemType>,
+    project_item: ProjectItem,
+}
+
+/// Tracks the activated plugin project items between ticks, so that activation changes can be reported to their types.
+#[derive(Default)]
+pub(crate) struct ProjectItemTypeTicker {
+    active_project_items: HashMap<ProjectItemRef, ActivatedPluginProjectItem>,
+}
+
+impl ProjectItemTypeTicker {
+    pub(crate) fn collect_activated_plugin_project_items(
+        project_items: &HashMap<ProjectItemRef, ProjectItem>,
+        plugin_registry: &PluginRegistry,
+    ) -> HashMap<ProjectItemRef, ActivatedPluginProjectItem> {
+        let enabled_project_item_types = plugin_registry.get_enabled_project_item_types();
+
+        if enabled_project_item_types.is_empty() {
+            return HashMap::new();
+        }
+
+        project_items
+            .iter()
+            .filter(|(_project_item_ref, project_item)| project_item.get_is_activated())
+            .filter_map(|(project_item_ref, project_item)| {
+                enabled_project_item_types
+                    .iter()
+                    .find(|(_plugin_id, project_item_type)| {
+                        project_item_type.get_project_item_type_id() == project_item.get_item_type().get_project_item_type_id()
+                    })
+                    .map(|(plugin_id, project_item_type)| {
+                        (
+                            project_item_ref.clone(),
+                            ActivatedPluginProjectItem {
+                                plugin_id: plugin_id.clone(),
+                                project_item_type: project_item_type.clone(),
+                                project_item: project_item.clone(),
+                            },
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    pub(crate) fn has_active_project_items(&self) -> bool {
+        !self.active_project_items.is_empty()
+    }
+
+    pub(crate) fn tick(
+        &mut self,
+        activated_project_items: HashMap<ProjectItemRef, ActivatedPluginProjectItem>,
+        plugin_registry: &PluginRegistry,
+        project_item_type_services: &dyn ProjectItemTypeServices,
+    ) {
+        // Items that were deleted, deactivated, or whose plugin was disabled are reported as deactivated.
+        let deactivated_project_item_refs = self
+            .active_project_items
+            .keys()
+            .filter(|project_item_ref| !activated_project_items.contains_key(*project_item_ref))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for project_item_ref in deactivated_project_item_refs {
+            let Some(mut deactivated_project_item) = self.active_project_items.remove(&project_item_ref) else {
+                continue;
+            };
+
+            if deactivated_project_item.project_item.get_is_activated() {
+                deactivated_project_item.project_item.toggle_activated();
+            }
+
+            Self::notify_activated_changed(&deactivated_project_item, plugin_registry, project_item_type_services);
+        }
+
+        for (project_item_ref, activated_project_item) in activated_project_items {
+            if !self.active_project_items.contains_key(&project_item_ref) {
+                Self::notify_activated_changed(&activated_project_item, plugin_registry, project_item_type_services);
+            }
+
+            let plugin_services = PluginProjectItemTypeServices::new(&activated_project_item.plugin_id, plugin_registry, project_item_type_services);
+
+            if let Err(error) = activated_project_item
+                .project_item_type
+                .tick(&activated_project_item.project_item, &plugin_services)
+            {
+                log::warn!(
+                    "Project item type `{}` from plugin `{}` failed to tick: {}",
+                    activated_project_item
+                        .project_item_type
+                        .get_project_item_type_id(),
+                    activated_project_item.plugin_id,
+                    error
+                );
+            }
+
+            self.active_project_items
+                .insert(project_item_ref, activated_project_item);
+        }
+    }
+
+    fn notify_activated_changed(
+        activated_project_item: &ActivatedPluginProjectItem,
+        plugin_registry: &PluginRegistry,
+        project_item_type_services: &dyn ProjectItemTypeServices,
+    ) {
+        let plugin_services = PluginProjectItemTypeServices::new(&activated_project_item.plugin_id, plugin_registry, project_item_type_services);
+
+        if let Err(error) = activated_project_item
+            .project_item_type
+            .on_activated_changed(&activated_project_item.project_item, &plugin_services)
+        {
+            log::warn!(
+                "Project item type `{}` from plugin `{}` failed to handle an activation change: {}",
+                activated_project_item
+                    .project_item_type
+                    .get_project_item_type_id(),
+                activated_project_item.plugin_id,
+                error
+            );
+        }
+    }
+}
+
+/// Limits a plugin's project item types to the process memory permissions the plugin declared.
+struct PluginProjectItemTypeServices<'services> {
+    plugin_id: &'services str,
+    can_read_process_memory: bool,
+    can_write_process_memory: bool,
+    project_item_type_services: &'services dyn ProjectItemTypeServices,
+}
+
+impl<'services> PluginProjectItemTypeServices<'services> {
+    fn new(
+        plugin_id: &'services str,
+        plugin_registry: &PluginRegistry,
+        project_item_type_services: &'services dyn ProjectItemTypeServices,
+    ) -> Self {
+        Self {
+            plugin_id,
+            can_read_process_memory: plugin_registry.has_plugin_permission(plugin_id, PluginPermission::ReadProcessMemory),
+            can_write_process_memory: plugin_registry.has_plugin_permission(plugin_id, PluginPermission::WriteProcessMemory),
+            project_item_type_services,
+        }
+    }
+}
+
+impl ProjectItemTypeServices for PluginProjectItemTypeServices<'_> {
+    fn read_memory(
+        &self,
+        address: u64,
+        module_name: &str,
+        length: u64,
+    ) -> Result<Vec<u8>, String> {
+        if !self.can_read_process_memory {
+            return Err(format!(
+                "Plugin `{}` does not declare the `{}` permission.",
+                self.plugin_id,
+                PluginPermission::ReadProcessMemory.get_display_name()
+            ));
+        }
+
+        self.project_item_type_services
+            .read_memory(address, module_name, length)
+    }
+
+    fn write_memory(
+        &self,
+        address: u64,
+        module_name: &str,
+        values: &[u8],
+    ) -> Result<(), String> {
+        if !self.can_write_process_memory {
+            return Err(format!(
+                "Plugin `{}` does not declare the `{}` permission.",
+                self.plugin_id,
+                PluginPermission::WriteProcessMemory.get_display_name()
+            ));
+        }
+
+        self.project_item_type_services
+            .write_memory(address, module_name, values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectItemTypeTicker;
+    use crate::plugins::plugin_registry::PluginRegistry;
+    use squalr_engine_api::{
+        plugins::{Plugin, PluginCapability, PluginMetadata, PluginPackage, PluginPermission, project_item_type::ProjectItemTypePlugin},
+        structures::projects::project_items::{
+            project_item::ProjectItem,
+            project_item_ref::ProjectItemRef,
+            project_item_type::{ProjectItemType, ProjectItemTypeServices},
+        },
+    };
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+
+    const TOGGLE_PATCH_PROJECT_ITEM_TYPE_ID: &str = "acme.toggle-patch";
+
+    /// Writes `0x90` while activated and restores `0x75` once deactivated.
+    struct TogglePatchProjectItemType {
+        tick_count: Mutex<u32>,
+    }
+
+    impl ProjectItemType for TogglePatchProjectItemType {
+        fn get_project_item_type_id(&self) -> &str {
+            TOGGLE_PATCH_PROJECT_ITEM_TYPE_ID
+        }
+
+        fn on_activated_changed(
+            &self,
+            project_item: &ProjectItem,
+            services: &dyn ProjectItemTypeServices,
+        ) -> Result<(), String> {
+            let patch_byte = if project_item.get_is_activated() { 0x90 } else { 0x75 };
+
+            services.write_memory(0x1000, "game.exe", &[patch_byte])
+        }
+
+        fn tick(
+            &self,
+            _project_item: &ProjectItem,
+            _services: &dyn ProjectItemTypeServices,
+        ) -> Result<(), String> {
+            *self.tick_count.lock().expect("Expected tick count lock.") += 1;
+
+            Ok(())
+        }
+    }
+
+    struct TestProjectItemTypePlugin {
+        metadata: PluginMetadata,
+        project_item_types: Vec<Arc<dyn ProjectItemType>>,
+    }
+
+    impl Plugin for TestProjectItemTypePlugin {
+        fn metadata(&self) -> &PluginMetadata {
+            &self.metadata
+        }
+    }
+
+    impl PluginPackage for TestProjectItemTypePlugin {
+        fn as_project_item_type_plugin(&self) -> Option<&dyn ProjectItemTypePlugin> {
+            Some(self)
+        }
+    }
+
+    impl ProjectItemTypePlugin for TestProjectItemTypePlugin {
+        fn contributed_project_item_types(&self) -> &[Arc<dyn ProjectItemType>] {
+            &self.project_item_types
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingProjectItemTypeServices {
+        writes: Mutex<Vec<(u64, String, Vec<u8>)>>,
+    }
+
+    impl ProjectItemTypeServices for RecordingProjectItemTypeServices {
+        fn read_memory(
+            &self,
+            _address: u64,
+            _module_name: &str,
+            length: u64,
+        ) -> Result<Vec<u8>, String> {
+            Ok(vec![0; length as usize])
+        }
+
+        fn write_memory(
+            &self,
+            address: u64,
+            module_name: &str,
+            values: &[u8],
+        ) -> Result<(), String> {
+            self.writes
+                .lock()
+                .expect("Expected writes lock.")
+                .push((address, module_name.to_string(), values.to_vec()));
+
+            Ok(())
+        }
+    }
+
+    fn create_plugin_registry(
+        plugin_permissions: Vec<PluginPermission>,
+        toggle_patch_project_item_type: Arc<TogglePatchProjectItemType>,
+    ) -> PluginRegistry {
+        let plugin_package: Arc<dyn PluginPackage> = Arc::new(TestProjectItemTypePlugin {
+            metadata: PluginMetadata::new_with_permissions(
+                "acme.patches",
+                "Patches",
+                "Test plugin",
+                vec![PluginCapability::ProjectItemType],
+                plugin_permissions,
+                false,
+                true,
+            ),
+            project_item_types: vec![toggle_patch_project_item_type],
+        });
+
+        PluginRegistry::from_plugin_packages(vec![plugin_package])
+    }
+
+    fn create_project_items(is_activated: bool) -> HashMap<ProjectItemRef, ProjectItem> {
+        let mut project_item = TogglePatchProjectItemType { tick_count: Mutex::new(0) }.create_project_item("Infinite Ammo");
+
+        if is_activated {
+            project_item.toggle_activated();
+        }
+
+        HashMap::from([(ProjectItemRef::new(PathBuf::from("project/infinite_ammo.json")), project_item)])
+    }
+
+    #[test]
+    fn ticker_reports_activation_changes_and_ticks_active_plugin_items() {
+        let toggle_patch_project_item_type = Arc::new(TogglePatchProjectItemType { tick_count: Mutex::new(0) });
+        let plugin_registry = create_plugin_registry(vec![PluginPermission::WriteProcessMemory], toggle_patch_project_item_type.clone());
+        let project_item_type_services = RecordingProjectItemTypeServices::default();
+        let mut project_item_type_ticker = ProjectItemTypeTicker::default();
+
+        for _ in 0..2 {
+            let activated_project_items = ProjectItemTypeTicker::collect_activated_plugin_project_items(&create_project_items(true), &plugin_registry);
+
+            project_item_type_ticker.tick(activated_project_items, &plugin_registry, &project_item_type_services);
+        }
+
+        let activated_project_items = ProjectItemTypeTicker::collect_activated_plugin_project_items(&create_project_items(false), &plugin_registry);
+        project_item_type_ticker.tick(activated_project_items, &plugin_registry, &project_item_type_services);
+
+        assert!(!project_item_type_ticker.has_active_project_items());
+        assert_eq!(*toggle_patch_project_item_type.tick_count.lock().unwrap(), 2);
+        assert_eq!(
+            *project_item_type_services.writes.lock().unwrap(),
+            vec![
+                (0x1000, String::from("game.exe"), vec![0x90]),
+                (0x1000, String::from("game.exe"), vec![0x75]),
+            ]
+        );
+    }
+
+    #[test]
+    fn ticker_blocks_memory_writes_without_declared_permission() {
+        let toggle_patch_project_item_type = Arc::new(TogglePatchProjectItemType { tick_count: Mutex::new(0) });
+        let plugin_registry = create_plugin_registry(vec![PluginPermission::ReadProcessMemory], toggle_patch_project_item_type.clone());
+        let project_item_type_services = RecordingProjectItemTypeServices::default();
+        let mut project_item_type_ticker = ProjectItemTypeTicker::default();
+        let activated_project_items = ProjectItemTypeTicker::collect_activated_plugin_project_items(&create_project_items(true), &plugin_registry);
+
+        project_item_type_ticker.tick(activated_project_items, &plugin_registry, &project_item_type_services);
+
+        assert!(project_item_type_ticker.has_active_project_items());
+        assert!(project_item_type_services.writes.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn ticker_ignores_items_of_disabled_plugins() {
+        let toggle_patch_project_item_type = Arc::new(TogglePatchProjectItemType { tick_count: Mutex::new(0) });
+        let plugin_registry = create_plugin_registry(vec![PluginPermission::WriteProcessMemory], toggle_patch_project_item_type.clone());
+
+        plugin_registry.set_plugin_enabled("acme.patches", false);
+
+        assert!(ProjectItemTypeTicker::collect_activated_plugin_project_items(&create_project_items(true), &plugin_registry).is_empty());
+        assert!(
+            plugin_registry
+                .get_enabled_project_item_type(TOGGLE_PATCH_PROJECT_ITEM_TYPE_ID)
+                .is_none()
+        );
+    }
+}
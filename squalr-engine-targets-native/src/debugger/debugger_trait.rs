@@ -0,0 +1,67 @@
+use squalr_engine_api::structures::debugging::{
+    breakpoint::Breakpoint, breakpoint_hit::BreakpointHit, breakpoint_mode::BreakpointMode, instruction_trace_stop_reason::InstructionTraceStopReason,
+    traced_instruction::TracedInstruction, watchpoint::Watchpoint, watchpoint_access::WatchpointAccess, watchpoint_hit::WatchpointHit,
+    watchpoint_size::WatchpointSize,
+};
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_targets::debugger::debugger_error::DebuggerError;
+use std::time::Duration;
+
+pub trait DebuggerTrait {
+    fn set_watchpoint(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        watchpoint_size: WatchpointSize,
+        watchpoint_access: WatchpointAccess,
+    ) -> Result<Watchpoint, DebuggerError>;
+
+    fn remove_watchpoint(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError>;
+
+    fn get_watchpoint_hits(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError>;
+
+    fn get_watchpoints(&self) -> Vec<Watchpoint>;
+
+    fn set_breakpoint(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        breakpoint_mode: BreakpointMode,
+    ) -> Result<Breakpoint, DebuggerError>;
+
+    fn remove_breakpoint(
+        &self,
+        breakpoint_id: u64,
+    ) -> Result<Breakpoint, DebuggerError>;
+
+    fn get_breakpoint_hits(
+        &self,
+        breakpoint_id: u64,
+        after_hit_index: u64,
+    ) -> Result<(Breakpoint, Vec<BreakpointHit>), DebuggerError>;
+
+    fn get_breakpoints(&self) -> Vec<Breakpoint>;
+
+    fn call_function(
+        &self,
+        process_info: &OpenedProcessInfo,
+        function_address: u64,
+        arguments: &[u64],
+    ) -> Result<u64, DebuggerError>;
+
+    fn trace_instructions(
+        &self,
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: Option<u64>,
+        thread_id: Option<u32>,
+        instruction_budget: u64,
+        start_timeout: Duration,
+    ) -> Result<(Vec<TracedInstruction>, InstructionTraceStopReason), DebuggerError>;
+}
@@ -0,0 +1,899 @@
+use crate::debugger::debugger_trait::DebuggerTrait;
+use crate::debugger::linux::linux_perf_event::{HW_BREAKPOINT_RW, HW_BREAKPOINT_W, PerfBreakpointEvent};
+use crate::debugger::linux::linux_ptrace_session::{LinuxBreakpointState, LinuxPtraceSession};
+use crate::debugger::linux::linux_register_layout::{IS_TRAP_AFTER_ACCESS, SAMPLE_REGS_USER_MASK, build_register_snapshot};
+use crate::debugger::linux::linux_remote_syscall::LinuxRemoteSyscall;
+use squalr_engine_api::structures::debugging::{
+    breakpoint::Breakpoint, breakpoint_hit::BreakpointHit, breakpoint_mode::BreakpointMode, instruction_trace_stop_reason::InstructionTraceStopReason,
+    register_snapshot::RegisterSnapshot, traced_instruction::TracedInstruction, watchpoint::Watchpoint, watchpoint_access::WatchpointAccess,
+    watchpoint_hit::WatchpointHit, watchpoint_size::WatchpointSize,
+};
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_targets::debugger::debugger_error::DebuggerError;
+use std::collections::{BTreeMap, HashMap, hash_map::Entry};
+use std::fs;
+use std::io;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Hardware watchpoints built on `perf_event_open` breakpoint events, and software breakpoints built on ptrace.
+///
+/// Each thread of the target gets its own breakpoint event, which samples the instruction pointer and user registers on
+/// every hit into a ring buffer. Events are inherited by threads created afterwards, so they only need to be opened once.
+/// The target is never stopped, so watching a hot address does not stall the game. Opening
+/// the events is subject to the same ptrace access check as attaching a debugger.
+///
+/// Software breakpoints share one ptrace session per process, which stays attached while any breakpoint exists or any thread
+/// is suspended. Remote system calls and register captures go through that session too, since a process can only have one
+/// tracer, or through a brief attachment otherwise.
+pub struct LinuxDebugger {
+    next_watchpoint_id: AtomicU64,
+    watchpoint_sessions: Mutex<HashMap<u64, LinuxWatchpointSession>>,
+    next_breakpoint_id: AtomicU64,
+    breakpoint_sessions: Mutex<HashMap<u32, LinuxPtraceSession>>,
+}
+
+struct LinuxWatchpointSession {
+    watchpoint: Watchpoint,
+    thread_events: HashMap<u32, PerfBreakpointEvent>,
+    hits_by_trap_address: BTreeMap<u64, WatchpointHit>,
+}
+
+impl LinuxDebugger {
+    pub fn new() -> Self {
+        LinuxDebugger {
+            next_watchpoint_id: AtomicU64::new(1),
+            watchpoint_sessions: Mutex::new(HashMap::new()),
+            next_breakpoint_id: AtomicU64::new(1),
+            breakpoint_sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn list_thread_ids(process_id: u32) -> io::Result<Vec<u32>> {
+        let mut thread_ids = fs::read_dir(format!("/proc/{}/task", process_id))?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|name| name.parse::<u32>().ok())
+            })
+            .collect::<Vec<_>>();
+        thread_ids.sort_unstable();
+
+        Ok(thread_ids)
+    }
+
+    /// Runs a system call inside the process, as used to allocate and re-protect its memory.
+    pub(crate) fn execute_remote_syscall(
+        &self,
+        process_id: u32,
+        remote_syscall: LinuxRemoteSyscall,
+    ) -> Result<u64, DebuggerError> {
+        let breakpoint_sessions = self
+            .breakpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("execute_remote_syscall", error.to_string()))?;
+        let result = match breakpoint_sessions.get(&process_id) {
+            Some(session) if session.is_attached() => session.execute_syscall(remote_syscall),
+            _ => {
+                // The lock is held while attached, so that a breakpoint cannot start a second session for the same process.
+                let session =
+                    LinuxPtraceSession::attach(process_id).map_err(|error| DebuggerError::attach_failed(process_id, Self::describe_attach_error(&error)))?;
+
+                session.execute_syscall(remote_syscall)
+            }
+        };
+
+        result.map_err(|error| DebuggerError::internal(remote_syscall.get_name(), error.to_string()))
+    }
+
+    /// Suspends one thread, or every thread of the process when no thread id is given.
+    pub(crate) fn suspend_threads(
+        &self,
+        process_id: u32,
+        thread_id: Option<u32>,
+    ) -> Result<(), DebuggerError> {
+        let mut breakpoint_sessions = self
+            .breakpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("suspend_threads", error.to_string()))?;
+        let session = Self::get_or_attach_session(&mut breakpoint_sessions, process_id)?;
+        let result = session.suspend(thread_id);
+
+        Self::release_idle_session(&mut breakpoint_sessions, process_id);
+
+        result.map_err(|error| DebuggerError::internal("suspend_threads", error.to_string()))
+    }
+
+    /// Resumes one suspended thread, or every suspended thread of the process when no thread id is given.
+    pub(crate) fn resume_threads(
+        &self,
+        process_id: u32,
+        thread_id: Option<u32>,
+    ) -> Result<(), DebuggerError> {
+        let mut breakpoint_sessions = self
+            .breakpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("resume_threads", error.to_string()))?;
+        let result = match breakpoint_sessions.get(&process_id) {
+            Some(session) if session.is_attached() => session.resume(thread_id),
+            _ => Ok(()),
+        };
+
+        Self::release_idle_session(&mut breakpoint_sessions, process_id);
+
+        result.map_err(|error| DebuggerError::internal("resume_threads", error.to_string()))
+    }
+
+    pub(crate) fn get_suspended_thread_ids(
+        &self,
+        process_id: u32,
+    ) -> Vec<u32> {
+        match self.breakpoint_sessions.lock() {
+            Ok(breakpoint_sessions) => match breakpoint_sessions.get(&process_id) {
+                Some(session) if session.is_attached() => session.get_suspended_thread_ids(),
+                _ => Vec::new(),
+            },
+            Err(error) => {
+                log::error!("Failed to acquire breakpoint session lock: {}", error);
+
+                Vec::new()
+            }
+        }
+    }
+
+    /// Captures the registers of a thread, interrupting it briefly unless it is suspended.
+    pub(crate) fn capture_thread_context(
+        &self,
+        process_id: u32,
+        thread_id: u32,
+    ) -> Result<RegisterSnapshot, DebuggerError> {
+        let breakpoint_sessions = self
+            .breakpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("capture_thread_context", error.to_string()))?;
+        let result = match breakpoint_sessions.get(&process_id) {
+            Some(session) if session.is_attached() => session.capture_context(thread_id),
+            _ => {
+                let session =
+                    LinuxPtraceSession::attach(process_id).map_err(|error| DebuggerError::attach_failed(process_id, Self::describe_attach_error(&error)))?;
+
+                session.capture_context(thread_id)
+            }
+        };
+
+        result.map_err(|error| DebuggerError::internal("capture_thread_context", error.to_string()))
+    }
+
+    /// Gets the ptrace session of a process, attaching a new one if there is none. Sessions outlive their process until the
+    /// next request, since the tracer stops on its own when the process exits.
+    fn get_or_attach_session(
+        breakpoint_sessions: &mut HashMap<u32, LinuxPtraceSession>,
+        process_id: u32,
+    ) -> Result<&mut LinuxPtraceSession, DebuggerError> {
+        if breakpoint_sessions
+            .get(&process_id)
+            .is_some_and(|session| !session.is_attached())
+        {
+            breakpoint_sessions.remove(&process_id);
+        }
+
+        match breakpoint_sessions.entry(process_id) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                let session =
+                    LinuxPtraceSession::attach(process_id).map_err(|error| DebuggerError::attach_failed(process_id, Self::describe_attach_error(&error)))?;
+
+                Ok(entry.insert(session))
+            }
+        }
+    }
+
+    /// Detaches from a process once it has no breakpoints and no suspended threads, leaving it free for other debuggers.
+    fn release_idle_session(
+        breakpoint_sessions: &mut HashMap<u32, LinuxPtraceSession>,
+        process_id: u32,
+    ) {
+        let is_idle = breakpoint_sessions.get(&process_id).is_some_and(|session| {
+            !session.has_suspended_threads()
+                && session
+                    .get_breakpoint_table()
+                    .lock()
+                    .is_ok_and(|breakpoint_table| breakpoint_table.is_empty())
+        });
+
+        if is_idle {
+            breakpoint_sessions.remove(&process_id);
+        }
+    }
+
+    fn open_thread_event(
+        watchpoint: &Watchpoint,
+        thread_id: u32,
+    ) -> io::Result<PerfBreakpointEvent> {
+        let breakpoint_type = match watchpoint.get_watchpoint_access() {
+            WatchpointAccess::Write => HW_BREAKPOINT_W,
+            WatchpointAccess::ReadWrite => HW_BREAKPOINT_RW,
+        };
+
+        PerfBreakpointEvent::open(
+            thread_id,
+            watchpoint.get_address(),
+            watchpoint.get_watchpoint_size().get_size_in_bytes(),
+            breakpoint_type,
+            SAMPLE_REGS_USER_MASK,
+        )
+    }
+
+    fn collect_samples(session: &mut LinuxWatchpointSession) {
+        for thread_event in session.thread_events.values_mut() {
+            let (samples, lost_sample_count) = thread_event.drain_samples();
+
+            if lost_sample_count > 0 {
+                log::warn!(
+                    "Watchpoint {} dropped {} hits because they arrived faster than they were collected.",
+                    session.watchpoint.get_watchpoint_id(),
+                    lost_sample_count
+                );
+            }
+
+            for sample in samples {
+                let register_snapshot = build_register_snapshot(sample.thread_id, sample.instruction_pointer, sample.register_abi, &sample.register_values);
+
+                session
+                    .hits_by_trap_address
+                    .entry(sample.instruction_pointer)
+                    .and_modify(|watchpoint_hit| watchpoint_hit.record_hit(register_snapshot.clone()))
+                    .or_insert_with(|| WatchpointHit::new(sample.instruction_pointer, IS_TRAP_AFTER_ACCESS, 1, register_snapshot));
+            }
+        }
+    }
+
+    fn read_sysctl(path: &str) -> String {
+        fs::read_to_string(path)
+            .map(|value| value.trim().to_string())
+            .unwrap_or_else(|_| String::from("unknown"))
+    }
+
+    /// Explains the most common reasons the kernel refuses breakpoint events for another process.
+    fn describe_open_error(error: &io::Error) -> String {
+        match error.raw_os_error() {
+            Some(libc::EACCES) | Some(libc::EPERM) => format!(
+                "{} (kernel.perf_event_paranoid = {}, kernel.yama.ptrace_scope = {}; watchpoints need perf events and ptrace access to the target)",
+                error,
+                Self::read_sysctl("/proc/sys/kernel/perf_event_paranoid"),
+                Self::read_sysctl("/proc/sys/kernel/yama/ptrace_scope"),
+            ),
+            Some(libc::ENOSPC) => format!("{} (no free hardware debug registers)", error),
+            Some(libc::ENOENT) | Some(libc::EOPNOTSUPP) => format!("{} (hardware breakpoints are not supported by this kernel or CPU)", error),
+            _ => error.to_string(),
+        }
+    }
+
+    fn describe_attach_error(error: &io::Error) -> String {
+        match error.raw_os_error() {
+            Some(libc::EACCES) | Some(libc::EPERM) => format!(
+                "{} (kernel.yama.ptrace_scope = {}; software breakpoints need ptrace access to the target, and no other debugger may be attached)",
+                error,
+                Self::read_sysctl("/proc/sys/kernel/yama/ptrace_scope"),
+            ),
+            _ => error.to_string(),
+        }
+    }
+
+    fn get_sorted_hits(session: &LinuxWatchpointSession) -> Vec<WatchpointHit> {
+        session.hits_by_trap_address.values().cloned().collect()
+    }
+
+    /// Finds the process and address of a breakpoint.
+    fn find_breakpoint(
+        breakpoint_sessions: &HashMap<u32, LinuxPtraceSession>,
+        breakpoint_id: u64,
+    ) -> Option<(u32, u64)> {
+        breakpoint_sessions.iter().find_map(|(process_id, session)| {
+            let breakpoint_table = session.get_breakpoint_table().lock().ok()?;
+
+            breakpoint_table
+                .values()
+                .find(|breakpoint_state| breakpoint_state.breakpoint.get_breakpoint_id() == breakpoint_id)
+                .map(|breakpoint_state| (*process_id, breakpoint_state.breakpoint.get_address()))
+        })
+    }
+}
+
+impl DebuggerTrait for LinuxDebugger {
+    fn set_watchpoint(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        watchpoint_size: WatchpointSize,
+        watchpoint_access: WatchpointAccess,
+    ) -> Result<Watchpoint, DebuggerError> {
+        if !watchpoint_size.is_aligned(address) {
+            return Err(DebuggerError::unaligned_watchpoint(address, watchpoint_size.get_size_in_bytes()));
+        }
+
+        let process_id = process_info.get_process_id_raw();
+        let thread_ids = Self::list_thread_ids(process_id).map_err(|error| DebuggerError::attach_failed(process_id, error.to_string()))?;
+        let watchpoint_id = self.next_watchpoint_id.fetch_add(1, Ordering::Relaxed);
+        let watchpoint = Watchpoint::new(watchpoint_id, process_id, address, watchpoint_size, watchpoint_access);
+        let mut thread_events = HashMap::new();
+        let mut first_error = None;
+
+        for thread_id in thread_ids {
+            match Self::open_thread_event(&watchpoint, thread_id) {
+                Ok(thread_event) => {
+                    thread_events.insert(thread_id, thread_event);
+                }
+                Err(error) if error.raw_os_error() == Some(libc::ESRCH) => {}
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        if thread_events.is_empty() {
+            let details = first_error
+                .map(|error| Self::describe_open_error(&error))
+                .unwrap_or_else(|| String::from("the process has no threads"));
+
+            return Err(DebuggerError::attach_failed(process_id, details));
+        }
+
+        let mut watchpoint_sessions = self
+            .watchpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("set_watchpoint", error.to_string()))?;
+
+        watchpoint_sessions.insert(
+            watchpoint_id,
+            LinuxWatchpointSession {
+                watchpoint: watchpoint.clone(),
+                thread_events,
+                hits_by_trap_address: BTreeMap::new(),
+            },
+        );
+
+        Ok(watchpoint)
+    }
+
+    fn remove_watchpoint(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError> {
+        let mut watchpoint_sessions = self
+            .watchpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("remove_watchpoint", error.to_string()))?;
+        let mut session = watchpoint_sessions
+            .remove(&watchpoint_id)
+            .ok_or_else(|| DebuggerError::unknown_watchpoint(watchpoint_id))?;
+
+        Self::collect_samples(&mut session);
+
+        Ok(Self::get_sorted_hits(&session))
+    }
+
+    fn get_watchpoint_hits(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError> {
+        let mut watchpoint_sessions = self
+            .watchpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("get_watchpoint_hits", error.to_string()))?;
+        let session = watchpoint_sessions
+            .get_mut(&watchpoint_id)
+            .ok_or_else(|| DebuggerError::unknown_watchpoint(watchpoint_id))?;
+
+        Self::collect_samples(session);
+
+        Ok(Self::get_sorted_hits(session))
+    }
+
+    fn get_watchpoints(&self) -> Vec<Watchpoint> {
+        match self.watchpoint_sessions.lock() {
+            Ok(watchpoint_sessions) => {
+                let mut watchpoints = watchpoint_sessions
+                    .values()
+                    .map(|session| session.watchpoint.clone())
+                    .collect::<Vec<_>>();
+                watchpoints.sort_by_key(Watchpoint::get_watchpoint_id);
+
+                watchpoints
+            }
+            Err(error) => {
+                log::error!("Failed to acquire watchpoint session lock: {}", error);
+
+                Vec::new()
+            }
+        }
+    }
+
+    fn set_breakpoint(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        breakpoint_mode: BreakpointMode,
+    ) -> Result<Breakpoint, DebuggerError> {
+        let process_id = process_info.get_process_id_raw();
+        let mut breakpoint_sessions = self
+            .breakpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("set_breakpoint", error.to_string()))?;
+
+        let session = Self::get_or_attach_session(&mut breakpoint_sessions, process_id)?;
+        let breakpoint_id = self.next_breakpoint_id.fetch_add(1, Ordering::Relaxed);
+        let breakpoint = Breakpoint::new(breakpoint_id, process_id, address, breakpoint_mode);
+
+        {
+            let mut breakpoint_table = session
+                .get_breakpoint_table()
+                .lock()
+                .map_err(|error| DebuggerError::internal("set_breakpoint", error.to_string()))?;
+
+            // Trace breakpoints that reached their hit limit stay listed until removed, but can be replaced.
+            if breakpoint_table
+                .get(&address)
+                .is_some_and(|breakpoint_state| breakpoint_state.breakpoint.get_is_armed())
+            {
+                return Err(DebuggerError::breakpoint_exists(address));
+            }
+
+            breakpoint_table.insert(address, LinuxBreakpointState::new(breakpoint.clone()));
+        }
+
+        if let Err(error) = session.arm(address) {
+            if let Ok(mut breakpoint_table) = session.get_breakpoint_table().lock() {
+                breakpoint_table.remove(&address);
+            }
+
+            Self::release_idle_session(&mut breakpoint_sessions, process_id);
+
+            return Err(DebuggerError::internal(
+                "set_breakpoint",
+                format!("failed to patch `0x{:X}`: {}", address, error),
+            ));
+        }
+
+        Ok(breakpoint)
+    }
+
+    fn remove_breakpoint(
+        &self,
+        breakpoint_id: u64,
+    ) -> Result<Breakpoint, DebuggerError> {
+        let mut breakpoint_sessions = self
+            .breakpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("remove_breakpoint", error.to_string()))?;
+        let (process_id, address) =
+            Self::find_breakpoint(&breakpoint_sessions, breakpoint_id).ok_or_else(|| DebuggerError::unknown_breakpoint(breakpoint_id))?;
+        let session = breakpoint_sessions
+            .get(&process_id)
+            .ok_or_else(|| DebuggerError::unknown_breakpoint(breakpoint_id))?;
+
+        if session.is_attached()
+            && let Err(error) = session.disarm(address)
+        {
+            log::warn!("Failed to restore the instruction at 0x{:X}: {}", address, error);
+        }
+
+        let breakpoint_state = session
+            .get_breakpoint_table()
+            .lock()
+            .map_err(|error| DebuggerError::internal("remove_breakpoint", error.to_string()))?
+            .remove(&address)
+            .ok_or_else(|| DebuggerError::unknown_breakpoint(breakpoint_id))?;
+
+        Self::release_idle_session(&mut breakpoint_sessions, process_id);
+
+        let mut breakpoint = breakpoint_state.breakpoint;
+        breakpoint.disarm();
+
+        Ok(breakpoint)
+    }
+
+    fn get_breakpoint_hits(
+        &self,
+        breakpoint_id: u64,
+        after_hit_index: u64,
+    ) -> Result<(Breakpoint, Vec<BreakpointHit>), DebuggerError> {
+        let breakpoint_sessions = self
+            .breakpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("get_breakpoint_hits", error.to_string()))?;
+        let (process_id, address) =
+            Self::find_breakpoint(&breakpoint_sessions, breakpoint_id).ok_or_else(|| DebuggerError::unknown_breakpoint(breakpoint_id))?;
+        let breakpoint_table = breakpoint_sessions
+            .get(&process_id)
+            .ok_or_else(|| DebuggerError::unknown_breakpoint(breakpoint_id))?
+            .get_breakpoint_table()
+            .lock()
+            .map_err(|error| DebuggerError::internal("get_breakpoint_hits", error.to_string()))?;
+        let breakpoint_state = breakpoint_table
+            .get(&address)
+            .ok_or_else(|| DebuggerError::unknown_breakpoint(breakpoint_id))?;
+        let breakpoint_hits = breakpoint_state
+            .breakpoint_hits
+            .iter()
+            .filter(|breakpoint_hit| breakpoint_hit.get_hit_index() > after_hit_index)
+            .cloned()
+            .collect();
+
+        Ok((breakpoint_state.breakpoint.clone(), breakpoint_hits))
+    }
+
+    fn get_breakpoints(&self) -> Vec<Breakpoint> {
+        match self.breakpoint_sessions.lock() {
+            Ok(breakpoint_sessions) => {
+                let mut breakpoints = breakpoint_sessions
+                    .values()
+                    .filter_map(|session| session.get_breakpoint_table().lock().ok())
+                    .flat_map(|breakpoint_table| {
+                        breakpoint_table
+                            .values()
+                            .map(|breakpoint_state| breakpoint_state.breakpoint.clone())
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+                breakpoints.sort_by_key(Breakpoint::get_breakpoint_id);
+
+                breakpoints
+            }
+            Err(error) => {
+                log::error!("Failed to acquire breakpoint session lock: {}", error);
+
+                Vec::new()
+            }
+        }
+    }
+
+    /// Calls a function through the ptrace session of the process. A brief attachment is not enough here, since the function
+    /// may run for a while and must not be cut off by a detach, so the process must already be traced.
+    fn call_function(
+        &self,
+        process_info: &OpenedProcessInfo,
+        function_address: u64,
+        arguments: &[u64],
+    ) -> Result<u64, DebuggerError> {
+        let process_id = process_info.get_process_id_raw();
+        let breakpoint_sessions = self
+            .breakpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("call_function", error.to_string()))?;
+        let session = match breakpoint_sessions.get(&process_id) {
+            Some(session) if session.is_attached() => session,
+            _ => return Err(DebuggerError::not_traced(process_id)),
+        };
+
+        session
+            .call_function(function_address, arguments)
+            .map_err(|error| DebuggerError::internal("call_function", error.to_string()))
+    }
+
+    fn trace_instructions(
+        &self,
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: Option<u64>,
+        thread_id: Option<u32>,
+        instruction_budget: u64,
+        start_timeout: Duration,
+    ) -> Result<(Vec<TracedInstruction>, InstructionTraceStopReason), DebuggerError> {
+        let process_id = process_info.get_process_id_raw();
+        let mut breakpoint_sessions = self
+            .breakpoint_sessions
+            .lock()
+            .map_err(|error| DebuggerError::internal("trace_instructions", error.to_string()))?;
+        let session = Self::get_or_attach_session(&mut breakpoint_sessions, process_id)?;
+        let result = session.trace_instructions(start_address, end_address, thread_id, instruction_budget, start_timeout);
+
+        Self::release_idle_session(&mut breakpoint_sessions, process_id);
+
+        result.map_err(|error| DebuggerError::internal("trace_instructions", error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinuxDebugger;
+    use crate::debugger::debugger_trait::DebuggerTrait;
+    use squalr_engine_api::structures::debugging::{
+        breakpoint_mode::BreakpointMode, instruction_trace_stop_reason::InstructionTraceStopReason, watchpoint_access::WatchpointAccess,
+        watchpoint_size::WatchpointSize,
+    };
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+    use squalr_engine_targets::debugger::debugger_error::DebuggerError;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::{Duration, Instant};
+
+    static WATCHED_VALUE: AtomicU64 = AtomicU64::new(0);
+
+    fn create_self_process_info() -> OpenedProcessInfo {
+        OpenedProcessInfo::new(std::process::id(), String::from("self"), 0, Bitness::Bit64, None)
+    }
+
+    #[test]
+    fn unaligned_watchpoints_are_rejected() {
+        let linux_debugger = LinuxDebugger::new();
+        let result = linux_debugger.set_watchpoint(&create_self_process_info(), 0x1002, WatchpointSize::Byte4, WatchpointAccess::Write);
+
+        assert!(matches!(result, Err(DebuggerError::UnalignedWatchpoint { .. })));
+    }
+
+    #[test]
+    #[ignore = "requires perf_event_open hardware breakpoints"]
+    fn write_watchpoint_collects_hits_from_new_threads() {
+        let linux_debugger = LinuxDebugger::new();
+        let watched_address = &WATCHED_VALUE as *const AtomicU64 as u64;
+        let watchpoint = match linux_debugger.set_watchpoint(&create_self_process_info(), watched_address, WatchpointSize::Byte8, WatchpointAccess::Write) {
+            Ok(watchpoint) => watchpoint,
+            Err(error) => panic!("Unexpected watchpoint error: {}", error),
+        };
+
+        std::thread::spawn(|| {
+            for value in 0..3 {
+                WATCHED_VALUE.store(value, Ordering::SeqCst);
+            }
+        })
+        .join()
+        .expect("Writer thread panicked.");
+        WATCHED_VALUE.store(42, Ordering::SeqCst);
+
+        let watchpoint_hits = linux_debugger
+            .remove_watchpoint(watchpoint.get_watchpoint_id())
+            .expect("Expected the watchpoint to exist.");
+        let total_hit_count = watchpoint_hits
+            .iter()
+            .map(|watchpoint_hit| watchpoint_hit.get_hit_count())
+            .sum::<u64>();
+
+        assert!(total_hit_count >= 4);
+        assert!(linux_debugger.get_watchpoints().is_empty());
+    }
+
+    /// Kills a forked child when dropped, so a failed assertion does not leave it running.
+    struct ChildProcessGuard(libc::pid_t);
+
+    impl Drop for ChildProcessGuard {
+        fn drop(&mut self) {
+            unsafe {
+                libc::kill(self.0, libc::SIGKILL);
+                libc::waitpid(self.0, std::ptr::null_mut(), 0);
+            }
+        }
+    }
+
+    #[inline(never)]
+    extern "C" fn breakpoint_target(value: u64) -> u64 {
+        std::hint::black_box(value.wrapping_mul(3).wrapping_add(1))
+    }
+
+    #[test]
+    #[ignore = "requires ptrace"]
+    fn trace_breakpoint_captures_hits_and_disarms_at_its_hit_limit() {
+        let child_process_id = unsafe { libc::fork() };
+        assert!(child_process_id >= 0, "fork failed");
+
+        if child_process_id == 0 {
+            let mut value = 0;
+
+            loop {
+                value = breakpoint_target(std::hint::black_box(value));
+                unsafe { libc::usleep(1_000) };
+            }
+        }
+
+        let _child_process_guard = ChildProcessGuard(child_process_id);
+        let linux_debugger = LinuxDebugger::new();
+        let process_info = OpenedProcessInfo::new(child_process_id as u32, String::from("child"), 0, Bitness::Bit64, None);
+        let breakpoint_address = breakpoint_target as *const () as usize as u64;
+        let breakpoint = match linux_debugger.set_breakpoint(&process_info, breakpoint_address, BreakpointMode::Trace { hit_limit: 3 }) {
+            Ok(breakpoint) => breakpoint,
+            Err(error) => panic!("Unexpected breakpoint error: {}", error),
+        };
+
+        assert!(matches!(
+            linux_debugger.set_breakpoint(&process_info, breakpoint_address, BreakpointMode::Count),
+            Err(DebuggerError::BreakpointExists { .. })
+        ));
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let (traced_breakpoint, breakpoint_hits) = loop {
+            let (traced_breakpoint, breakpoint_hits) = linux_debugger
+                .get_breakpoint_hits(breakpoint.get_breakpoint_id(), 0)
+                .expect("Expected the breakpoint to exist.");
+
+            if !traced_breakpoint.get_is_armed() || Instant::now() >= deadline {
+                break (traced_breakpoint, breakpoint_hits);
+            }
+
+            std::thread::sleep(Duration::from_millis(10));
+        };
+
+        assert!(!traced_breakpoint.get_is_armed());
+        assert_eq!(traced_breakpoint.get_hit_count(), 3);
+        assert_eq!(breakpoint_hits.len(), 3);
+        assert!(breakpoint_hits.iter().all(|breakpoint_hit| {
+            breakpoint_hit.get_register_snapshot().get_instruction_pointer() == breakpoint_address
+                && breakpoint_hit.get_stack_frames().first() == Some(&breakpoint_address)
+        }));
+
+        linux_debugger
+            .remove_breakpoint(breakpoint.get_breakpoint_id())
+            .expect("Expected the breakpoint to be removed.");
+
+        assert!(linux_debugger.get_breakpoints().is_empty());
+        assert_eq!(unsafe { libc::kill(child_process_id, 0) }, 0, "The child should survive detaching.");
+    }
+
+    #[test]
+    #[ignore = "requires ptrace"]
+    fn suspended_threads_stay_stopped_with_readable_registers_until_resumed() {
+        let child_process_id = unsafe { libc::fork() };
+        assert!(child_process_id >= 0, "fork failed");
+
+        if child_process_id == 0 {
+            let mut value = 0;
+
+            loop {
+                value = breakpoint_target(std::hint::black_box(value));
+            }
+        }
+
+        let _child_process_guard = ChildProcessGuard(child_process_id);
+        let linux_debugger = LinuxDebugger::new();
+        let child_process_id = child_process_id as u32;
+
+        match linux_debugger.suspend_threads(child_process_id, None) {
+            Ok(()) => {}
+            Err(error) => panic!("Unexpected suspend error: {}", error),
+        }
+
+        assert_eq!(linux_debugger.get_suspended_thread_ids(child_process_id), vec![child_process_id]);
+
+        let first_register_snapshot = linux_debugger
+            .capture_thread_context(child_process_id, child_process_id)
+            .expect("Expected the registers of the suspended thread to be readable.");
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        let second_register_snapshot = linux_debugger
+            .capture_thread_context(child_process_id, child_process_id)
+            .expect("Expected the registers of the suspended thread to be readable.");
+
+        assert_ne!(first_register_snapshot.get_instruction_pointer(), 0);
+        assert_eq!(
+            first_register_snapshot, second_register_snapshot,
+            "A suspended thread should not make progress."
+        );
+
+        linux_debugger
+            .resume_threads(child_process_id, None)
+            .expect("Expected the process to resume.");
+
+        assert!(
+            linux_debugger
+                .get_suspended_thread_ids(child_process_id)
+                .is_empty()
+        );
+        assert!(linux_debugger.get_breakpoints().is_empty());
+        assert_eq!(
+            unsafe { libc::kill(child_process_id as libc::pid_t, 0) },
+            0,
+            "The child should survive resuming."
+        );
+    }
+
+    #[test]
+    #[ignore = "requires ptrace"]
+    fn call_function_runs_in_a_traced_process_and_restores_its_registers() {
+        let child_process_id = unsafe { libc::fork() };
+        assert!(child_process_id >= 0, "fork failed");
+
+        if child_process_id == 0 {
+            let mut value = 0;
+
+            loop {
+                value = breakpoint_target(std::hint::black_box(value));
+                unsafe { libc::usleep(1_000) };
+            }
+        }
+
+        let _child_process_guard = ChildProcessGuard(child_process_id);
+        let linux_debugger = LinuxDebugger::new();
+        let process_info = OpenedProcessInfo::new(child_process_id as u32, String::from("child"), 0, Bitness::Bit64, None);
+        let child_process_id = child_process_id as u32;
+        let function_address = breakpoint_target as *const () as usize as u64;
+
+        assert!(matches!(
+            linux_debugger.call_function(&process_info, function_address, &[5]),
+            Err(DebuggerError::NotTraced { .. })
+        ));
+
+        match linux_debugger.suspend_threads(child_process_id, None) {
+            Ok(()) => {}
+            Err(error) => panic!("Unexpected suspend error: {}", error),
+        }
+
+        let register_snapshot_before_call = linux_debugger
+            .capture_thread_context(child_process_id, child_process_id)
+            .expect("Expected the registers of the suspended thread to be readable.");
+        let return_value = linux_debugger
+            .call_function(&process_info, function_address, &[5])
+            .expect("Expected the remote call to return.");
+        let register_snapshot_after_call = linux_debugger
+            .capture_thread_context(child_process_id, child_process_id)
+            .expect("Expected the registers of the suspended thread to be readable.");
+
+        assert_eq!(return_value, 16);
+        assert_eq!(register_snapshot_before_call, register_snapshot_after_call);
+
+        linux_debugger
+            .resume_threads(child_process_id, None)
+            .expect("Expected the process to resume.");
+
+        assert_eq!(
+            unsafe { libc::kill(child_process_id as libc::pid_t, 0) },
+            0,
+            "The child should survive the call."
+        );
+    }
+
+    #[test]
+    #[ignore = "requires ptrace"]
+    fn trace_instructions_steps_from_the_start_address_within_the_budget() {
+        let child_process_id = unsafe { libc::fork() };
+        assert!(child_process_id >= 0, "fork failed");
+
+        if child_process_id == 0 {
+            let mut value = 0;
+
+            loop {
+                value = breakpoint_target(std::hint::black_box(value));
+                unsafe { libc::usleep(1_000) };
+            }
+        }
+
+        let _child_process_guard = ChildProcessGuard(child_process_id);
+        let linux_debugger = LinuxDebugger::new();
+        let process_info = OpenedProcessInfo::new(child_process_id as u32, String::from("child"), 0, Bitness::Bit64, None);
+        let start_address = breakpoint_target as *const () as usize as u64;
+        let (traced_instructions, stop_reason) = match linux_debugger.trace_instructions(&process_info, start_address, None, None, 8, Duration::from_secs(5)) {
+            Ok(trace_result) => trace_result,
+            Err(error) => panic!("Unexpected trace error: {}", error),
+        };
+
+        assert_eq!(stop_reason, InstructionTraceStopReason::ReachedInstructionBudget);
+        assert_eq!(traced_instructions.len(), 9);
+        assert_eq!(
+            traced_instructions[0]
+                .get_register_snapshot()
+                .get_instruction_pointer(),
+            start_address
+        );
+        assert!(
+            traced_instructions
+                .iter()
+                .all(|traced_instruction| !traced_instruction.get_instruction_bytes().is_empty())
+        );
+        // The temporary breakpoint must not leak into the traced code.
+        assert_ne!(traced_instructions[0].get_instruction_bytes()[0], 0xCC);
+
+        let second_address = traced_instructions[1]
+            .get_register_snapshot()
+            .get_instruction_pointer();
+        let (traced_instructions, stop_reason) = linux_debugger
+            .trace_instructions(&process_info, start_address, Some(second_address), None, 8, Duration::from_secs(5))
+            .expect("Expected the second trace to reach its end address.");
+
+        assert_eq!(stop_reason, InstructionTraceStopReason::ReachedEndAddress);
+        assert_eq!(traced_instructions.len(), 2);
+        assert!(linux_debugger.get_breakpoints().is_empty());
+        assert_eq!(unsafe { libc::kill(child_process_id, 0) }, 0, "The child should survive tracing.");
+    }
+}
@@ -0,0 +1,248 @@
+use libc::{MAP_FAILED, MAP_SHARED, PROT_READ, PROT_WRITE, c_void, pid_t};
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const PERF_TYPE_BREAKPOINT: u32 = 5;
+const PERF_SAMPLE_IP: u64 = 1 << 0;
+const PERF_SAMPLE_TID: u64 = 1 << 1;
+const PERF_SAMPLE_REGS_USER: u64 = 1 << 12;
+const PERF_FLAG_FD_CLOEXEC: libc::c_ulong = 1 << 3;
+const PERF_ATTR_FLAG_INHERIT: u64 = 1 << 1;
+const PERF_ATTR_FLAG_EXCLUDE_KERNEL: u64 = 1 << 5;
+const PERF_ATTR_FLAG_EXCLUDE_HV: u64 = 1 << 6;
+const PERF_RECORD_LOST: u32 = 2;
+const PERF_RECORD_SAMPLE: u32 = 9;
+
+/// Offsets of `data_head` and `data_tail` in `struct perf_event_mmap_page`.
+const MMAP_PAGE_DATA_HEAD_OFFSET: usize = 1024;
+const MMAP_PAGE_DATA_TAIL_OFFSET: usize = 1032;
+
+/// Number of ring buffer data pages mapped per thread. Must be a power of two.
+const RING_BUFFER_DATA_PAGE_COUNT: usize = 16;
+
+pub(crate) const HW_BREAKPOINT_W: u32 = 2;
+pub(crate) const HW_BREAKPOINT_RW: u32 = 3;
+
+/// Mirrors `struct perf_event_attr` up to `PERF_ATTR_SIZE_VER5`, which every kernel with user register sampling accepts.
+#[repr(C)]
+#[derive(Default)]
+struct PerfEventAttr {
+    event_type: u32,
+    size: u32,
+    config: u64,
+    sample_period: u64,
+    sample_type: u64,
+    read_format: u64,
+    flags: u64,
+    wakeup_events: u32,
+    bp_type: u32,
+    bp_addr: u64,
+    bp_len: u64,
+    branch_sample_type: u64,
+    sample_regs_user: u64,
+    sample_stack_user: u32,
+    clockid: i32,
+    sample_regs_intr: u64,
+    aux_watermark: u32,
+    sample_max_stack: u16,
+    reserved_2: u16,
+}
+
+const _: () = assert!(size_of::<PerfEventAttr>() == 112);
+
+/// A sample taken when a thread hit the breakpoint.
+pub(crate) struct PerfBreakpointSample {
+    pub instruction_pointer: u64,
+    pub thread_id: u32,
+    pub register_abi: u64,
+    pub register_values: Vec<u64>,
+}
+
+/// A hardware breakpoint perf event bound to a single thread, along with its sample ring buffer.
+pub(crate) struct PerfBreakpointEvent {
+    file_descriptor: i32,
+    mmap_base: *mut u8,
+    mmap_length: usize,
+    data_size: usize,
+    sample_register_count: usize,
+}
+
+// The mapping is owned exclusively by this event and is only accessed through `&mut self`.
+unsafe impl Send for PerfBreakpointEvent {}
+
+impl PerfBreakpointEvent {
+    /// Opens a breakpoint event that samples every hit on the given thread. The event is inherited by threads that this thread
+    /// creates later, and their samples are written to this event's ring buffer.
+    pub fn open(
+        thread_id: u32,
+        address: u64,
+        length: u64,
+        breakpoint_type: u32,
+        sample_regs_user: u64,
+    ) -> io::Result<Self> {
+        let mut attr = PerfEventAttr {
+            event_type: PERF_TYPE_BREAKPOINT,
+            size: size_of::<PerfEventAttr>() as u32,
+            sample_period: 1,
+            sample_type: PERF_SAMPLE_IP | PERF_SAMPLE_TID,
+            flags: PERF_ATTR_FLAG_INHERIT | PERF_ATTR_FLAG_EXCLUDE_KERNEL | PERF_ATTR_FLAG_EXCLUDE_HV,
+            wakeup_events: 1,
+            bp_type: breakpoint_type,
+            bp_addr: address,
+            bp_len: length,
+            ..Default::default()
+        };
+
+        if sample_regs_user != 0 {
+            attr.sample_type |= PERF_SAMPLE_REGS_USER;
+            attr.sample_regs_user = sample_regs_user;
+        }
+
+        let file_descriptor = unsafe {
+            libc::syscall(
+                libc::SYS_perf_event_open,
+                &attr as *const PerfEventAttr,
+                thread_id as pid_t,
+                -1 as libc::c_int,
+                -1 as libc::c_int,
+                PERF_FLAG_FD_CLOEXEC,
+            )
+        };
+
+        if file_descriptor < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let file_descriptor = file_descriptor as i32;
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) }.max(4096) as usize;
+        let data_size = page_size * RING_BUFFER_DATA_PAGE_COUNT;
+        let mmap_length = page_size + data_size;
+        let mmap_base = unsafe { libc::mmap(std::ptr::null_mut(), mmap_length, PROT_READ | PROT_WRITE, MAP_SHARED, file_descriptor, 0) };
+
+        if mmap_base == MAP_FAILED {
+            let error = io::Error::last_os_error();
+            unsafe { libc::close(file_descriptor) };
+
+            return Err(error);
+        }
+
+        Ok(Self {
+            file_descriptor,
+            mmap_base: mmap_base as *mut u8,
+            mmap_length,
+            data_size,
+            sample_register_count: sample_regs_user.count_ones() as usize,
+        })
+    }
+
+    /// Drains every pending record from the ring buffer, returning the parsed samples and the number of samples the kernel dropped.
+    pub fn drain_samples(&mut self) -> (Vec<PerfBreakpointSample>, u64) {
+        let mut samples = Vec::new();
+        let mut lost_sample_count = 0u64;
+        let data_head = self.data_head_atomic().load(Ordering::Acquire);
+        let mut data_tail = self.data_tail_atomic().load(Ordering::Relaxed);
+
+        while data_tail < data_head {
+            let header = self.copy_ring_bytes(data_tail, 8);
+            let record_type = u32::from_ne_bytes([header[0], header[1], header[2], header[3]]);
+            let record_size = u16::from_ne_bytes([header[6], header[7]]) as u64;
+
+            if record_size < 8 || data_tail + record_size > data_head {
+                break;
+            }
+
+            let record_body = self.copy_ring_bytes(data_tail + 8, record_size as usize - 8);
+
+            match record_type {
+                PERF_RECORD_SAMPLE => {
+                    if let Some(sample) = self.parse_sample(&record_body) {
+                        samples.push(sample);
+                    }
+                }
+                PERF_RECORD_LOST => {
+                    // Body is `u64 id; u64 lost;`.
+                    if let Some(lost_bytes) = record_body.get(8..16) {
+                        lost_sample_count = lost_sample_count.saturating_add(u64::from_ne_bytes(lost_bytes.try_into().unwrap_or([0; 8])));
+                    }
+                }
+                _ => {}
+            }
+
+            data_tail += record_size;
+        }
+
+        self.data_tail_atomic().store(data_tail, Ordering::Release);
+
+        (samples, lost_sample_count)
+    }
+
+    fn parse_sample(
+        &self,
+        record_body: &[u8],
+    ) -> Option<PerfBreakpointSample> {
+        let read_u64 = |offset: usize| -> Option<u64> {
+            record_body
+                .get(offset..offset + 8)
+                .map(|bytes| u64::from_ne_bytes(bytes.try_into().unwrap_or([0; 8])))
+        };
+        let instruction_pointer = read_u64(0)?;
+        // `PERF_SAMPLE_TID` is `u32 pid; u32 tid;`.
+        let thread_id = u32::from_ne_bytes(record_body.get(12..16)?.try_into().ok()?);
+        let mut register_abi = 0;
+        let mut register_values = Vec::new();
+
+        if self.sample_register_count > 0 {
+            register_abi = read_u64(16)?;
+
+            if register_abi != 0 {
+                register_values = (0..self.sample_register_count)
+                    .map(|register_index| read_u64(24 + register_index * 8))
+                    .collect::<Option<Vec<_>>>()?;
+            }
+        }
+
+        Some(PerfBreakpointSample {
+            instruction_pointer,
+            thread_id,
+            register_abi,
+            register_values,
+        })
+    }
+
+    fn copy_ring_bytes(
+        &self,
+        position: u64,
+        length: usize,
+    ) -> Vec<u8> {
+        let data = unsafe { std::slice::from_raw_parts(self.mmap_base.add(self.mmap_length - self.data_size), self.data_size) };
+        let start = (position % self.data_size as u64) as usize;
+
+        if start + length <= self.data_size {
+            data[start..start + length].to_vec()
+        } else {
+            let first_length = self.data_size - start;
+            let mut bytes = Vec::with_capacity(length);
+            bytes.extend_from_slice(&data[start..]);
+            bytes.extend_from_slice(&data[..length - first_length]);
+
+            bytes
+        }
+    }
+
+    fn data_head_atomic(&self) -> &AtomicU64 {
+        unsafe { &*(self.mmap_base.add(MMAP_PAGE_DATA_HEAD_OFFSET) as *const AtomicU64) }
+    }
+
+    fn data_tail_atomic(&self) -> &AtomicU64 {
+        unsafe { &*(self.mmap_base.add(MMAP_PAGE_DATA_TAIL_OFFSET) as *const AtomicU64) }
+    }
+}
+
+impl Drop for PerfBreakpointEvent {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.mmap_base as *mut c_void, self.mmap_length);
+            libc::close(self.file_descriptor);
+        }
+    }
+}
@@ -0,0 +1,394 @@
+use crate::debugger::linux::linux_ptrace_context::{BREAKPOINT_INSTRUCTION, LinuxPtraceContext};
+use crate::process_query::process_launch_options::ProcessLaunchOptions;
+use libc::{c_char, c_int, c_void, pid_t};
+use std::ffi::CString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read};
+use std::os::fd::FromRawFd;
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+use std::os::unix::fs::{FileExt, PermissionsExt};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a launched process may take to reach a stop before the launch is abandoned.
+const STOP_TIMEOUT: Duration = Duration::from_secs(5);
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// The exit code of a launched process whose `execve` failed.
+const EXEC_FAILED_EXIT_CODE: c_int = 127;
+
+/// The auxiliary vector key of the program entry point, which libc only exposes with the width of `c_ulong`.
+const AT_ENTRY: u64 = 9;
+
+/// Starts executables under ptrace, so that they can be inspected before any of their own code runs.
+pub(crate) struct LinuxProcessLauncher;
+
+impl LinuxProcessLauncher {
+    /// Launches an executable and runs it to its entry point, where the dynamic loader has mapped every library but none of the
+    /// program's own code has run. The process is left stopped with `SIGSTOP` when the launch asks for it, and resumed otherwise.
+    pub fn launch(process_launch_options: &ProcessLaunchOptions) -> io::Result<u32> {
+        let executable_path = &process_launch_options.executable_path;
+        let metadata = fs::metadata(executable_path)?;
+
+        if !metadata.is_file() || metadata.permissions().mode() & 0o111 == 0 {
+            return Err(io::Error::new(io::ErrorKind::PermissionDenied, "the file is not an executable"));
+        }
+
+        // Everything the launched process needs is allocated up front, as the forked child may not allocate.
+        let executable_path = CString::new(executable_path.as_os_str().as_bytes())?;
+        let argument_strings = std::iter::once(Ok(executable_path.clone()))
+            .chain(
+                process_launch_options
+                    .arguments
+                    .iter()
+                    .map(|argument| CString::new(argument.as_str())),
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        let environment_strings = Self::build_environment(&process_launch_options.environment)?;
+        let argument_pointers = Self::build_pointer_array(&argument_strings);
+        let environment_pointers = Self::build_pointer_array(&environment_strings);
+        let process_id = Self::spawn_stopped(&executable_path, &argument_pointers, &environment_pointers)?;
+
+        if let Err(error) = Self::run_to_entry_point(process_id).and_then(|_| Self::wait_until_stopped(process_id)) {
+            unsafe { libc::kill(process_id as pid_t, libc::SIGKILL) };
+
+            return Err(error);
+        }
+
+        if !process_launch_options.is_suspended && unsafe { libc::kill(process_id as pid_t, libc::SIGCONT) } == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(process_id)
+    }
+
+    /// Builds the environment of the launched process, which inherits the engine environment with the requested overrides.
+    fn build_environment(environment_overrides: &[(String, String)]) -> io::Result<Vec<CString>> {
+        let mut environment_strings = std::env::vars_os()
+            .filter(|(name, _value)| {
+                !environment_overrides
+                    .iter()
+                    .any(|(override_name, _override_value)| name.as_bytes() == override_name.as_bytes())
+            })
+            .map(|(name, value)| {
+                let mut environment_entry = name.into_vec();
+                environment_entry.push(b'=');
+                environment_entry.extend(value.into_vec());
+
+                CString::new(environment_entry)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (name, value) in environment_overrides {
+            environment_strings.push(CString::new(format!("{}={}", name, value))?);
+        }
+
+        Ok(environment_strings)
+    }
+
+    fn build_pointer_array(strings: &[CString]) -> Vec<*const c_char> {
+        strings
+            .iter()
+            .map(|string| string.as_ptr())
+            .chain(std::iter::once(std::ptr::null()))
+            .collect()
+    }
+
+    /// Starts the executable as a grandchild that stops itself right before `execve`. The intermediate child exits at once, so
+    /// the launched process is adopted and reaped by init rather than lingering as a zombie of the engine.
+    fn spawn_stopped(
+        executable_path: &CString,
+        argument_pointers: &[*const c_char],
+        environment_pointers: &[*const c_char],
+    ) -> io::Result<u32> {
+        let mut pipe_fds: [c_int; 2] = [0; 2];
+
+        if unsafe { libc::pipe2(pipe_fds.as_mut_ptr(), libc::O_CLOEXEC) } == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let [read_fd, write_fd] = pipe_fds;
+        let tracer_process_id = unsafe { libc::getpid() };
+        let intermediate_process_id = unsafe { libc::fork() };
+
+        if intermediate_process_id == -1 {
+            let error = io::Error::last_os_error();
+
+            unsafe {
+                libc::close(read_fd);
+                libc::close(write_fd);
+            }
+
+            return Err(error);
+        }
+
+        // Only async-signal-safe calls are allowed until `execve`, as other engine threads may have held locks during the fork.
+        if intermediate_process_id == 0 {
+            unsafe {
+                let process_id = libc::fork();
+
+                if process_id == 0 {
+                    // A session of its own keeps the process alive when the engine exits, which would otherwise orphan a process
+                    // group with a stopped member and have the kernel hang it up. PR_SET_PTRACER lets Yama allow the attach.
+                    libc::setsid();
+                    libc::prctl(libc::PR_SET_PTRACER, tracer_process_id as libc::c_ulong, 0, 0, 0);
+                    libc::kill(libc::getpid(), libc::SIGSTOP);
+                    libc::execve(executable_path.as_ptr(), argument_pointers.as_ptr(), environment_pointers.as_ptr());
+                    libc::_exit(EXEC_FAILED_EXIT_CODE);
+                }
+
+                libc::write(write_fd, &process_id as *const pid_t as *const c_void, size_of::<pid_t>());
+                libc::_exit(0);
+            }
+        }
+
+        unsafe { libc::close(write_fd) };
+
+        let mut pipe = unsafe { File::from_raw_fd(read_fd) };
+        let mut process_id_bytes = [0u8; size_of::<pid_t>()];
+        let read_result = pipe.read_exact(&mut process_id_bytes);
+        let mut status = 0;
+
+        while unsafe { libc::waitpid(intermediate_process_id, &mut status, 0) } == -1 && io::Error::last_os_error().raw_os_error() == Some(libc::EINTR) {}
+
+        read_result?;
+
+        let process_id = pid_t::from_ne_bytes(process_id_bytes);
+
+        if process_id <= 0 {
+            return Err(io::Error::other("failed to fork the launched process"));
+        }
+
+        Ok(process_id as u32)
+    }
+
+    /// Attaches to the self-stopped process, lets it `execve`, and then runs it to the entry point of the executable. Detaching
+    /// with `SIGSTOP` leaves the process in a group-stop, where it stays until it receives `SIGCONT`.
+    fn run_to_entry_point(process_id: u32) -> io::Result<()> {
+        Self::wait_until_stopped(process_id)?;
+        Self::ptrace(libc::PTRACE_SEIZE, process_id, 0, (libc::PTRACE_O_TRACEEXEC | libc::PTRACE_O_EXITKILL) as usize)?;
+
+        if unsafe { libc::kill(process_id as pid_t, libc::SIGCONT) } == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Self::continue_until(process_id, |signal, ptrace_event| {
+            Ok(signal == libc::SIGTRAP && ptrace_event == libc::PTRACE_EVENT_EXEC)
+        })?;
+
+        // Without a breakpoint instruction, the process is held at the first instruction of the dynamic loader instead.
+        if !BREAKPOINT_INSTRUCTION.is_empty() {
+            let pointer_size = LinuxPtraceContext::read(process_id)?.get_pointer_size() as usize;
+            let auxiliary_vector = fs::read(format!("/proc/{}/auxv", process_id))?;
+            let entry_point = Self::parse_auxiliary_vector_entry(&auxiliary_vector, pointer_size, AT_ENTRY)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "the auxiliary vector has no entry point"))?;
+
+            Self::run_to_address(process_id, entry_point)?;
+        }
+
+        Self::ptrace(libc::PTRACE_DETACH, process_id, 0, libc::SIGSTOP as usize)
+    }
+
+    fn run_to_address(
+        process_id: u32,
+        address: u64,
+    ) -> io::Result<()> {
+        let process_memory = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(format!("/proc/{}/mem", process_id))?;
+        let mut original_bytes = vec![0u8; BREAKPOINT_INSTRUCTION.len()];
+
+        process_memory.read_exact_at(&mut original_bytes, address)?;
+        process_memory.write_all_at(BREAKPOINT_INSTRUCTION, address)?;
+
+        let run_result = Self::ptrace(libc::PTRACE_CONT, process_id, 0, 0).and_then(|_| {
+            Self::continue_until(process_id, |signal, ptrace_event| {
+                Ok(signal == libc::SIGTRAP && ptrace_event == 0 && LinuxPtraceContext::read(process_id)?.get_breakpoint_address() == address)
+            })
+        });
+        let restore_result = process_memory.write_all_at(&original_bytes, address);
+
+        run_result?;
+        restore_result?;
+
+        LinuxPtraceContext::read(process_id)?.set_instruction_pointer(address)
+    }
+
+    /// Waits for ptrace stops until one matches, continuing past the others. The `SIGCONT` and `SIGSTOP` used to start the
+    /// process are swallowed, while any other signal is delivered as it would be without a tracer.
+    fn continue_until(
+        process_id: u32,
+        mut is_expected_stop: impl FnMut(c_int, c_int) -> io::Result<bool>,
+    ) -> io::Result<()> {
+        loop {
+            let mut status = 0;
+
+            if unsafe { libc::waitpid(process_id as pid_t, &mut status, libc::__WALL) } == -1 {
+                let error = io::Error::last_os_error();
+
+                if error.raw_os_error() == Some(libc::EINTR) {
+                    continue;
+                }
+
+                return Err(error);
+            }
+
+            if libc::WIFEXITED(status) {
+                return Err(match libc::WEXITSTATUS(status) {
+                    EXEC_FAILED_EXIT_CODE => io::Error::other("the executable could not be started"),
+                    exit_code => io::Error::other(format!("the process exited with code {} before reaching its entry point", exit_code)),
+                });
+            }
+
+            if libc::WIFSIGNALED(status) {
+                return Err(io::Error::other(format!(
+                    "the process was killed by signal {} before reaching its entry point",
+                    libc::WTERMSIG(status)
+                )));
+            }
+
+            if !libc::WIFSTOPPED(status) {
+                continue;
+            }
+
+            let signal = libc::WSTOPSIG(status);
+            let ptrace_event = (status >> 16) & 0xFFFF;
+
+            if is_expected_stop(signal, ptrace_event)? {
+                return Ok(());
+            }
+
+            let is_suppressed_signal = matches!(signal, libc::SIGCONT | libc::SIGSTOP | libc::SIGTRAP);
+            let injected_signal = if ptrace_event != 0 || is_suppressed_signal { 0 } else { signal };
+
+            Self::ptrace(libc::PTRACE_CONT, process_id, 0, injected_signal as usize)?;
+        }
+    }
+
+    /// Polls until the process is in a stop, which is not reported through `waitpid` to a process that is not its parent.
+    fn wait_until_stopped(process_id: u32) -> io::Result<()> {
+        let start_time = Instant::now();
+
+        loop {
+            let stat = fs::read_to_string(format!("/proc/{}/stat", process_id))?;
+
+            match Self::parse_stat_state(&stat) {
+                Some('T') => return Ok(()),
+                Some('Z') | Some('X') => return Err(io::Error::other("the process exited before it stopped")),
+                _ => {}
+            }
+
+            if start_time.elapsed() >= STOP_TIMEOUT {
+                return Err(io::Error::new(io::ErrorKind::TimedOut, "the process did not stop"));
+            }
+
+            thread::sleep(STOP_POLL_INTERVAL);
+        }
+    }
+
+    /// Reads the state letter of a stat entry, which follows the parenthesized process name.
+    fn parse_stat_state(stat: &str) -> Option<char> {
+        stat.get(stat.rfind(')')? + 1..)?.trim_start().chars().next()
+    }
+
+    /// Finds a value in an auxiliary vector, which is a list of key and value pairs of the process pointer size.
+    fn parse_auxiliary_vector_entry(
+        auxiliary_vector: &[u8],
+        pointer_size: usize,
+        key: u64,
+    ) -> Option<u64> {
+        let read_word = |bytes: &[u8]| match pointer_size {
+            4 => bytes
+                .try_into()
+                .ok()
+                .map(|bytes| u32::from_ne_bytes(bytes) as u64),
+            8 => bytes.try_into().ok().map(u64::from_ne_bytes),
+            _ => None,
+        };
+
+        auxiliary_vector
+            .chunks_exact(pointer_size * 2)
+            .map(|entry| (read_word(&entry[..pointer_size]), read_word(&entry[pointer_size..])))
+            .find(|(entry_key, _entry_value)| *entry_key == Some(key))
+            .and_then(|(_entry_key, entry_value)| entry_value)
+    }
+
+    fn ptrace(
+        request: libc::c_uint,
+        process_id: u32,
+        address: usize,
+        data: usize,
+    ) -> io::Result<()> {
+        let result = unsafe { libc::ptrace(request, process_id as pid_t, address as *mut c_void, data as *mut c_void) };
+
+        if result == -1 { Err(io::Error::last_os_error()) } else { Ok(()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AT_ENTRY, LinuxProcessLauncher};
+    use crate::process_query::process_launch_options::ProcessLaunchOptions;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn encode_auxiliary_vector(entries: &[(u64, u64)]) -> Vec<u8> {
+        entries
+            .iter()
+            .flat_map(|(key, value)| key.to_ne_bytes().into_iter().chain(value.to_ne_bytes()))
+            .collect()
+    }
+
+    #[test]
+    fn parse_auxiliary_vector_entry_finds_the_requested_key() {
+        let auxiliary_vector = encode_auxiliary_vector(&[(6, 0x1000), (AT_ENTRY, 0x5555_0000_1040), (0, 0)]);
+
+        assert_eq!(
+            LinuxProcessLauncher::parse_auxiliary_vector_entry(&auxiliary_vector, 8, AT_ENTRY),
+            Some(0x5555_0000_1040)
+        );
+        assert_eq!(LinuxProcessLauncher::parse_auxiliary_vector_entry(&auxiliary_vector, 8, 31), None);
+    }
+
+    #[test]
+    fn parse_stat_state_skips_names_containing_parentheses() {
+        assert_eq!(LinuxProcessLauncher::parse_stat_state("4242 (game (1)) T 1 4242 4242 0"), Some('T'));
+    }
+
+    #[test]
+    #[ignore = "requires ptrace"]
+    fn launched_process_waits_at_its_entry_point_until_continued() {
+        let process_launch_options = ProcessLaunchOptions {
+            executable_path: PathBuf::from("/bin/sleep"),
+            arguments: vec![String::from("30")],
+            environment: vec![(String::from("SQUALR_LAUNCH_TEST"), String::from("1"))],
+            is_suspended: true,
+        };
+        let process_id = match LinuxProcessLauncher::launch(&process_launch_options) {
+            Ok(process_id) => process_id,
+            Err(error) => panic!("failed to launch process: {}", error),
+        };
+        let stat = fs::read_to_string(format!("/proc/{}/stat", process_id)).expect("stat should be readable");
+        let environment = fs::read(format!("/proc/{}/environ", process_id)).unwrap_or_default();
+
+        unsafe { libc::kill(process_id as libc::pid_t, libc::SIGKILL) };
+
+        assert_eq!(LinuxProcessLauncher::parse_stat_state(&stat), Some('T'));
+        assert!(
+            environment
+                .split(|byte| *byte == 0)
+                .any(|entry| entry == b"SQUALR_LAUNCH_TEST=1")
+        );
+    }
+
+    #[test]
+    fn launch_reports_missing_executables() {
+        let process_launch_options = ProcessLaunchOptions {
+            executable_path: PathBuf::from("/nonexistent/squalr-launch-test"),
+            ..ProcessLaunchOptions::default()
+        };
+
+        assert!(LinuxProcessLauncher::launch(&process_launch_options).is_err());
+    }
+}
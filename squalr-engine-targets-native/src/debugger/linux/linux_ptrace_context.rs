@@ -0,0 +1,777 @@
+use libc::{c_void, pid_t};
+use squalr_engine_api::structures::debugging::{
+    register_snapshot::RegisterSnapshot, register_value::RegisterValue, vector_register_value::VectorRegisterValue,
+};
+use std::io;
+
+/// The instruction patched over breakpoint addresses.
+#[cfg(target_arch = "x86_64")]
+pub(crate) const BREAKPOINT_INSTRUCTION: &[u8] = &[0xCC];
+
+/// `brk #0`, which is also what debuggers use for A64 code.
+#[cfg(target_arch = "aarch64")]
+pub(crate) const BREAKPOINT_INSTRUCTION: &[u8] = &[0x00, 0x00, 0x20, 0xD4];
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+pub(crate) const BREAKPOINT_INSTRUCTION: &[u8] = &[];
+
+/// The alignment that a system call instruction must have to be executed.
+pub(crate) const SYSCALL_INSTRUCTION_ALIGNMENT: u64 = arch::SYSCALL_INSTRUCTION_ALIGNMENT;
+
+/// How far below the stack pointer of a thread a remote call builds its frame. This skips the x86-64 red zone, which may hold
+/// live data of the interrupted function.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+const CALL_STACK_RESERVE: u64 = 256;
+
+/// The general purpose registers of a thread that is stopped in a ptrace stop.
+pub(crate) struct LinuxPtraceContext {
+    thread_id: u32,
+    registers: arch::RawRegisters,
+}
+
+impl LinuxPtraceContext {
+    pub fn read(thread_id: u32) -> io::Result<Self> {
+        Ok(Self {
+            thread_id,
+            registers: arch::read_registers(thread_id)?,
+        })
+    }
+
+    pub fn get_instruction_pointer(&self) -> u64 {
+        arch::get_instruction_pointer(&self.registers)
+    }
+
+    /// Resolves the address of the breakpoint instruction that caused the current trap.
+    pub fn get_breakpoint_address(&self) -> u64 {
+        arch::resolve_breakpoint_address(self.get_instruction_pointer())
+    }
+
+    pub fn get_frame_pointer(&self) -> u64 {
+        arch::get_frame_pointer(&self.registers)
+    }
+
+    pub fn get_pointer_size(&self) -> u64 {
+        if arch::is_compat_mode(&self.registers) { 4 } else { 8 }
+    }
+
+    /// Moves the thread back to the given address, which is needed on x86 where the trap is reported after the `int3`.
+    pub fn set_instruction_pointer(
+        &mut self,
+        instruction_pointer: u64,
+    ) -> io::Result<()> {
+        if self.get_instruction_pointer() == instruction_pointer {
+            return Ok(());
+        }
+
+        arch::set_instruction_pointer(&mut self.registers, instruction_pointer);
+        arch::write_registers(self.thread_id, &self.registers)
+    }
+
+    pub fn is_compat_mode(&self) -> bool {
+        arch::is_compat_mode(&self.registers)
+    }
+
+    /// Gets the instruction that enters the kernel in the mode the thread is running in, which is empty if unsupported.
+    pub fn get_syscall_instruction(&self) -> &'static [u8] {
+        if self.is_compat_mode() {
+            arch::COMPAT_SYSCALL_INSTRUCTION
+        } else {
+            arch::SYSCALL_INSTRUCTION
+        }
+    }
+
+    /// Runs one system call on the thread by pointing it at an existing system call instruction, then restores every register
+    /// so that the thread continues from where it was stopped. The `step` callback must execute exactly that one instruction.
+    pub fn execute_syscall(
+        &self,
+        instruction_address: u64,
+        number: u64,
+        arguments: [u64; 6],
+        step: impl FnOnce() -> io::Result<()>,
+    ) -> io::Result<i64> {
+        let is_compat_mode = self.is_compat_mode();
+        let saved_syscall_state = arch::begin_syscall(self.thread_id, &self.registers, is_compat_mode, instruction_address, number, arguments)?;
+        let return_value = step().and_then(|_| arch::read_syscall_result(self.thread_id, is_compat_mode));
+        let restore_result = arch::end_syscall(self.thread_id, &self.registers, saved_syscall_state);
+        let return_value = return_value?;
+
+        restore_result.map(|_| return_value)
+    }
+
+    /// Calls a function on the thread with integer arguments, then restores every register so that the thread continues from
+    /// where it was stopped. The function returns to address zero, so `run` must continue the thread until it faults there.
+    /// Arguments that are passed on the stack are written through `write_stack`.
+    pub fn execute_call(
+        &self,
+        function_address: u64,
+        arguments: &[u64],
+        write_stack: impl FnOnce(u64, &[u8]) -> io::Result<()>,
+        run: impl FnOnce() -> io::Result<()>,
+    ) -> io::Result<u64> {
+        let is_compat_mode = self.is_compat_mode();
+        let (call_registers, stack_bytes) = arch::build_call_frame(&self.registers, is_compat_mode, function_address, arguments)?;
+
+        if !stack_bytes.is_empty() {
+            write_stack(arch::get_stack_pointer(&call_registers), &stack_bytes)?;
+        }
+
+        let saved_syscall_state = arch::begin_call(self.thread_id, &call_registers)?;
+        let return_value = run().and_then(|_| arch::read_syscall_result(self.thread_id, is_compat_mode));
+        let restore_result = arch::end_syscall(self.thread_id, &self.registers, saved_syscall_state);
+        let return_value = return_value?;
+
+        // Pointers returned by 32-bit functions must not be sign extended like system call errors are.
+        restore_result.map(|_| if is_compat_mode { return_value as u32 as u64 } else { return_value as u64 })
+    }
+
+    /// Captures every general purpose register, which is cheap enough to do on every single step.
+    pub fn capture_general_register_snapshot(&self) -> RegisterSnapshot {
+        let registers = arch::build_registers(&self.registers, arch::is_compat_mode(&self.registers));
+
+        RegisterSnapshot::new(
+            self.thread_id,
+            self.get_instruction_pointer(),
+            arch::get_stack_pointer(&self.registers),
+            registers,
+        )
+    }
+
+    /// Captures every general purpose register, along with the vector registers if the kernel exposes them.
+    pub fn capture_register_snapshot(&self) -> RegisterSnapshot {
+        let is_compat_mode = arch::is_compat_mode(&self.registers);
+        let registers = arch::build_registers(&self.registers, is_compat_mode);
+        let stack_pointer = arch::get_stack_pointer(&self.registers);
+        let vector_registers = arch::read_vector_registers(self.thread_id, is_compat_mode).unwrap_or_else(|error| {
+            log::debug!("Failed to read vector registers of thread {}: {}", self.thread_id, error);
+            Vec::new()
+        });
+
+        RegisterSnapshot::new(self.thread_id, self.get_instruction_pointer(), stack_pointer, registers).with_vector_registers(vector_registers)
+    }
+}
+
+fn check_ptrace_result(result: libc::c_long) -> io::Result<()> {
+    if result == -1 { Err(io::Error::last_os_error()) } else { Ok(()) }
+}
+
+fn build_register_values(register_pairs: &[(&str, u64)]) -> Vec<RegisterValue> {
+    register_pairs
+        .iter()
+        .map(|(register_name, register_value)| RegisterValue::new(register_name.to_string(), *register_value))
+        .collect()
+}
+
+#[cfg(target_arch = "x86_64")]
+mod arch {
+    use super::{CALL_STACK_RESERVE, VectorRegisterValue, build_register_values, c_void, check_ptrace_result, io, pid_t};
+    use squalr_engine_api::structures::debugging::register_value::RegisterValue;
+
+    pub(super) type RawRegisters = libc::user_regs_struct;
+
+    /// `syscall`, which can be executed at any byte offset.
+    pub(super) const SYSCALL_INSTRUCTION: &[u8] = &[0x0F, 0x05];
+
+    /// `int 0x80`, the entry point that 32-bit processes use.
+    pub(super) const COMPAT_SYSCALL_INSTRUCTION: &[u8] = &[0xCD, 0x80];
+    pub(super) const SYSCALL_INSTRUCTION_ALIGNMENT: u64 = 1;
+
+    /// The user code segment selector of 32-bit processes running on a 64-bit kernel.
+    const COMPAT_USER_CODE_SEGMENT: u64 = 0x23;
+
+    /// The general purpose registers already hold everything that a system call clobbers.
+    pub(super) struct SavedSyscallState;
+    const XMM_REGISTER_COUNT_64: usize = 16;
+    const XMM_REGISTER_COUNT_32: usize = 8;
+
+    pub(super) fn read_registers(thread_id: u32) -> io::Result<RawRegisters> {
+        let mut registers: RawRegisters = unsafe { std::mem::zeroed() };
+        let result = unsafe {
+            libc::ptrace(
+                libc::PTRACE_GETREGS,
+                thread_id as pid_t,
+                std::ptr::null_mut::<c_void>(),
+                &mut registers as *mut RawRegisters as *mut c_void,
+            )
+        };
+
+        check_ptrace_result(result).map(|_| registers)
+    }
+
+    pub(super) fn write_registers(
+        thread_id: u32,
+        registers: &RawRegisters,
+    ) -> io::Result<()> {
+        let result = unsafe {
+            libc::ptrace(
+                libc::PTRACE_SETREGS,
+                thread_id as pid_t,
+                std::ptr::null_mut::<c_void>(),
+                registers as *const RawRegisters as *mut c_void,
+            )
+        };
+
+        check_ptrace_result(result)
+    }
+
+    pub(super) fn get_instruction_pointer(registers: &RawRegisters) -> u64 {
+        registers.rip
+    }
+
+    pub(super) fn begin_syscall(
+        thread_id: u32,
+        registers: &RawRegisters,
+        is_compat_mode: bool,
+        instruction_address: u64,
+        number: u64,
+        arguments: [u64; 6],
+    ) -> io::Result<SavedSyscallState> {
+        let mut syscall_registers = *registers;
+
+        // Clearing orig_rax stops the kernel from restarting a system call that the thread was interrupted in.
+        syscall_registers.orig_rax = u64::MAX;
+        syscall_registers.rip = instruction_address;
+        syscall_registers.rax = number;
+
+        let argument_registers = if is_compat_mode {
+            [
+                &mut syscall_registers.rbx,
+                &mut syscall_registers.rcx,
+                &mut syscall_registers.rdx,
+                &mut syscall_registers.rsi,
+                &mut syscall_registers.rdi,
+                &mut syscall_registers.rbp,
+            ]
+        } else {
+            [
+                &mut syscall_registers.rdi,
+                &mut syscall_registers.rsi,
+                &mut syscall_registers.rdx,
+                &mut syscall_registers.r10,
+                &mut syscall_registers.r8,
+                &mut syscall_registers.r9,
+            ]
+        };
+
+        for (argument_register, argument) in argument_registers.into_iter().zip(arguments) {
+            *argument_register = argument;
+        }
+
+        write_registers(thread_id, &syscall_registers).map(|_| SavedSyscallState)
+    }
+
+    /// Sets up a call with the System V calling convention, returning the registers to run it with and the bytes to write at
+    /// their stack pointer. The return address is zero, so the thread faults once the function returns.
+    pub(super) fn build_call_frame(
+        registers: &RawRegisters,
+        is_compat_mode: bool,
+        function_address: u64,
+        arguments: &[u64],
+    ) -> io::Result<(RawRegisters, Vec<u8>)> {
+        let mut call_registers = *registers;
+        let stack_base = registers.rsp.wrapping_sub(CALL_STACK_RESERVE);
+        let stack_bytes = if is_compat_mode {
+            // 32-bit code takes every argument from the stack, which must be 16-byte aligned where the arguments start.
+            let argument_bytes = arguments
+                .iter()
+                .flat_map(|argument| (*argument as u32).to_le_bytes())
+                .collect::<Vec<_>>();
+            let argument_address = stack_base.wrapping_sub(argument_bytes.len() as u64) & !0xF;
+            call_registers.rsp = argument_address.wrapping_sub(4);
+
+            [0u32.to_le_bytes().as_slice(), &argument_bytes].concat()
+        } else {
+            if arguments.len() > 6 {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, "remote calls take at most 6 arguments"));
+            }
+
+            let argument_registers = [
+                &mut call_registers.rdi,
+                &mut call_registers.rsi,
+                &mut call_registers.rdx,
+                &mut call_registers.rcx,
+                &mut call_registers.r8,
+                &mut call_registers.r9,
+            ];
+
+            for (argument_register, argument) in argument_registers.into_iter().zip(arguments) {
+                *argument_register = *argument;
+            }
+
+            // The stack is 16-byte aligned before a call pushes its return address. Variadic functions read the number of
+            // vector registers that hold arguments from al.
+            call_registers.rsp = (stack_base & !0xF).wrapping_sub(8);
+            call_registers.rax = 0;
+
+            0u64.to_le_bytes().to_vec()
+        };
+
+        call_registers.rip = function_address;
+        call_registers.orig_rax = u64::MAX;
+
+        Ok((call_registers, stack_bytes))
+    }
+
+    pub(super) fn begin_call(
+        thread_id: u32,
+        call_registers: &RawRegisters,
+    ) -> io::Result<SavedSyscallState> {
+        write_registers(thread_id, call_registers).map(|_| SavedSyscallState)
+    }
+
+    pub(super) fn read_syscall_result(
+        thread_id: u32,
+        is_compat_mode: bool,
+    ) -> io::Result<i64> {
+        let registers = read_registers(thread_id)?;
+
+        // 32-bit system calls return in eax, so errors need to be sign extended from 32 bits.
+        Ok(if is_compat_mode {
+            registers.rax as u32 as i32 as i64
+        } else {
+            registers.rax as i64
+        })
+    }
+
+    pub(super) fn end_syscall(
+        thread_id: u32,
+        registers: &RawRegisters,
+        _saved_syscall_state: SavedSyscallState,
+    ) -> io::Result<()> {
+        write_registers(thread_id, registers)
+    }
+
+    pub(super) fn set_instruction_pointer(
+        registers: &mut RawRegisters,
+        instruction_pointer: u64,
+    ) {
+        registers.rip = instruction_pointer;
+    }
+
+    pub(super) fn get_stack_pointer(registers: &RawRegisters) -> u64 {
+        registers.rsp
+    }
+
+    pub(super) fn get_frame_pointer(registers: &RawRegisters) -> u64 {
+        registers.rbp
+    }
+
+    /// `int3` is a trap, so the reported instruction pointer is one byte past the breakpoint.
+    pub(super) fn resolve_breakpoint_address(instruction_pointer: u64) -> u64 {
+        instruction_pointer.wrapping_sub(1)
+    }
+
+    pub(super) fn is_compat_mode(registers: &RawRegisters) -> bool {
+        registers.cs == COMPAT_USER_CODE_SEGMENT
+    }
+
+    pub(super) fn build_registers(
+        registers: &RawRegisters,
+        is_compat_mode: bool,
+    ) -> Vec<RegisterValue> {
+        if is_compat_mode {
+            return build_register_values(&[
+                ("eax", registers.rax),
+                ("ebx", registers.rbx),
+                ("ecx", registers.rcx),
+                ("edx", registers.rdx),
+                ("esi", registers.rsi),
+                ("edi", registers.rdi),
+                ("ebp", registers.rbp),
+                ("esp", registers.rsp),
+                ("eip", registers.rip),
+                ("eflags", registers.eflags),
+                ("cs", registers.cs),
+                ("ss", registers.ss),
+                ("ds", registers.ds),
+                ("es", registers.es),
+                ("fs", registers.fs),
+                ("gs", registers.gs),
+            ]);
+        }
+
+        build_register_values(&[
+            ("rax", registers.rax),
+            ("rbx", registers.rbx),
+            ("rcx", registers.rcx),
+            ("rdx", registers.rdx),
+            ("rsi", registers.rsi),
+            ("rdi", registers.rdi),
+            ("rbp", registers.rbp),
+            ("rsp", registers.rsp),
+            ("r8", registers.r8),
+            ("r9", registers.r9),
+            ("r10", registers.r10),
+            ("r11", registers.r11),
+            ("r12", registers.r12),
+            ("r13", registers.r13),
+            ("r14", registers.r14),
+            ("r15", registers.r15),
+            ("rip", registers.rip),
+            ("rflags", registers.eflags),
+            ("cs", registers.cs),
+            ("ss", registers.ss),
+            ("fs_base", registers.fs_base),
+            ("gs_base", registers.gs_base),
+        ])
+    }
+
+    pub(super) fn read_vector_registers(
+        thread_id: u32,
+        is_compat_mode: bool,
+    ) -> io::Result<Vec<VectorRegisterValue>> {
+        let mut floating_point_registers: libc::user_fpregs_struct = unsafe { std::mem::zeroed() };
+        let result = unsafe {
+            libc::ptrace(
+                libc::PTRACE_GETFPREGS,
+                thread_id as pid_t,
+                std::ptr::null_mut::<c_void>(),
+                &mut floating_point_registers as *mut libc::user_fpregs_struct as *mut c_void,
+            )
+        };
+        check_ptrace_result(result)?;
+
+        let register_count = if is_compat_mode { XMM_REGISTER_COUNT_32 } else { XMM_REGISTER_COUNT_64 };
+
+        Ok(floating_point_registers
+            .xmm_space
+            .chunks_exact(4)
+            .take(register_count)
+            .enumerate()
+            .map(|(register_index, register_words)| {
+                let register_bytes = register_words
+                    .iter()
+                    .flat_map(|register_word| register_word.to_le_bytes())
+                    .collect();
+
+                VectorRegisterValue::new(format!("xmm{}", register_index), register_bytes)
+            })
+            .collect())
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod arch {
+    use super::{CALL_STACK_RESERVE, VectorRegisterValue, build_register_values, c_void, check_ptrace_result, io, pid_t};
+    use squalr_engine_api::structures::debugging::register_value::RegisterValue;
+
+    const NT_PRSTATUS: libc::c_int = 1;
+    const NT_PRFPREG: libc::c_int = 2;
+    const NT_ARM_SYSTEM_CALL: libc::c_int = 0x404;
+
+    /// `svc #0`.
+    pub(super) const SYSCALL_INSTRUCTION: &[u8] = &[0x01, 0x00, 0x00, 0xD4];
+    pub(super) const COMPAT_SYSCALL_INSTRUCTION: &[u8] = &[];
+    pub(super) const SYSCALL_INSTRUCTION_ALIGNMENT: u64 = 4;
+
+    /// The system call number the thread was stopped in, which the kernel keeps outside of the general purpose registers.
+    pub(super) type SavedSyscallState = libc::c_int;
+
+    /// Mirrors `struct user_pt_regs`.
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub(super) struct RawRegisters {
+        regs: [u64; 31],
+        sp: u64,
+        pc: u64,
+        pstate: u64,
+    }
+
+    /// Mirrors `struct user_fpsimd_state`.
+    #[repr(C)]
+    struct RawVectorRegisters {
+        vregs: [u128; 32],
+        fpsr: u32,
+        fpcr: u32,
+        reserved: [u32; 2],
+    }
+
+    fn transfer_register_set<T>(
+        request: libc::c_uint,
+        thread_id: u32,
+        register_set: libc::c_int,
+        registers: *mut T,
+    ) -> io::Result<()> {
+        let mut io_vector = libc::iovec {
+            iov_base: registers as *mut c_void,
+            iov_len: size_of::<T>(),
+        };
+        let result = unsafe {
+            libc::ptrace(
+                request as _,
+                thread_id as pid_t,
+                register_set as usize as *mut c_void,
+                &mut io_vector as *mut libc::iovec as *mut c_void,
+            )
+        };
+
+        check_ptrace_result(result)
+    }
+
+    pub(super) fn read_registers(thread_id: u32) -> io::Result<RawRegisters> {
+        let mut registers: RawRegisters = unsafe { std::mem::zeroed() };
+        transfer_register_set(libc::PTRACE_GETREGSET as _, thread_id, NT_PRSTATUS, &mut registers)?;
+
+        Ok(registers)
+    }
+
+    pub(super) fn write_registers(
+        thread_id: u32,
+        registers: &RawRegisters,
+    ) -> io::Result<()> {
+        let mut registers = *registers;
+
+        transfer_register_set(libc::PTRACE_SETREGSET as _, thread_id, NT_PRSTATUS, &mut registers)
+    }
+
+    pub(super) fn get_instruction_pointer(registers: &RawRegisters) -> u64 {
+        registers.pc
+    }
+
+    /// Saves the system call number the thread was stopped in, then clears it. A system call number of -1 stops the kernel
+    /// from restarting a system call that the thread was interrupted in.
+    fn clear_syscall_number(thread_id: u32) -> io::Result<SavedSyscallState> {
+        let mut saved_syscall_number: libc::c_int = 0;
+        transfer_register_set(libc::PTRACE_GETREGSET as _, thread_id, NT_ARM_SYSTEM_CALL, &mut saved_syscall_number)?;
+
+        let mut cleared_syscall_number: libc::c_int = -1;
+        transfer_register_set(libc::PTRACE_SETREGSET as _, thread_id, NT_ARM_SYSTEM_CALL, &mut cleared_syscall_number)?;
+
+        Ok(saved_syscall_number)
+    }
+
+    pub(super) fn begin_syscall(
+        thread_id: u32,
+        registers: &RawRegisters,
+        _is_compat_mode: bool,
+        instruction_address: u64,
+        number: u64,
+        arguments: [u64; 6],
+    ) -> io::Result<SavedSyscallState> {
+        let saved_syscall_number = clear_syscall_number(thread_id)?;
+
+        let mut syscall_registers = *registers;
+        syscall_registers.regs[..arguments.len()].copy_from_slice(&arguments);
+        syscall_registers.regs[8] = number;
+        syscall_registers.pc = instruction_address;
+        write_registers(thread_id, &syscall_registers)?;
+
+        Ok(saved_syscall_number)
+    }
+
+    /// Sets up a call with the AAPCS64 calling convention. The link register is zero, so the thread faults once the
+    /// function returns, and no arguments need to be written to the stack.
+    pub(super) fn build_call_frame(
+        registers: &RawRegisters,
+        _is_compat_mode: bool,
+        function_address: u64,
+        arguments: &[u64],
+    ) -> io::Result<(RawRegisters, Vec<u8>)> {
+        if arguments.len() > 8 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "remote calls take at most 8 arguments"));
+        }
+
+        let mut call_registers = *registers;
+        call_registers.regs[..arguments.len()].copy_from_slice(arguments);
+        call_registers.regs[30] = 0;
+        call_registers.sp = registers.sp.wrapping_sub(CALL_STACK_RESERVE) & !0xF;
+        call_registers.pc = function_address;
+
+        Ok((call_registers, Vec::new()))
+    }
+
+    pub(super) fn begin_call(
+        thread_id: u32,
+        call_registers: &RawRegisters,
+    ) -> io::Result<SavedSyscallState> {
+        let saved_syscall_number = clear_syscall_number(thread_id)?;
+        write_registers(thread_id, call_registers)?;
+
+        Ok(saved_syscall_number)
+    }
+
+    pub(super) fn read_syscall_result(
+        thread_id: u32,
+        _is_compat_mode: bool,
+    ) -> io::Result<i64> {
+        Ok(read_registers(thread_id)?.regs[0] as i64)
+    }
+
+    pub(super) fn end_syscall(
+        thread_id: u32,
+        registers: &RawRegisters,
+        saved_syscall_state: SavedSyscallState,
+    ) -> io::Result<()> {
+        let mut saved_syscall_number = saved_syscall_state;
+
+        write_registers(thread_id, registers)?;
+        transfer_register_set(libc::PTRACE_SETREGSET as _, thread_id, NT_ARM_SYSTEM_CALL, &mut saved_syscall_number)
+    }
+
+    pub(super) fn set_instruction_pointer(
+        registers: &mut RawRegisters,
+        instruction_pointer: u64,
+    ) {
+        registers.pc = instruction_pointer;
+    }
+
+    pub(super) fn get_stack_pointer(registers: &RawRegisters) -> u64 {
+        registers.sp
+    }
+
+    pub(super) fn get_frame_pointer(registers: &RawRegisters) -> u64 {
+        registers.regs[29]
+    }
+
+    /// `brk` is reported at the breakpoint instruction itself.
+    pub(super) fn resolve_breakpoint_address(instruction_pointer: u64) -> u64 {
+        instruction_pointer
+    }
+
+    pub(super) fn is_compat_mode(_registers: &RawRegisters) -> bool {
+        false
+    }
+
+    pub(super) fn build_registers(
+        registers: &RawRegisters,
+        _is_compat_mode: bool,
+    ) -> Vec<RegisterValue> {
+        let mut register_values = registers
+            .regs
+            .iter()
+            .enumerate()
+            .map(|(register_index, register_value)| RegisterValue::new(format!("x{}", register_index), *register_value))
+            .collect::<Vec<_>>();
+        register_values.extend(build_register_values(&[
+            ("sp", registers.sp),
+            ("pc", registers.pc),
+            ("pstate", registers.pstate),
+        ]));
+
+        register_values
+    }
+
+    pub(super) fn read_vector_registers(
+        thread_id: u32,
+        _is_compat_mode: bool,
+    ) -> io::Result<Vec<VectorRegisterValue>> {
+        let mut vector_registers: RawVectorRegisters = unsafe { std::mem::zeroed() };
+        transfer_register_set(libc::PTRACE_GETREGSET as _, thread_id, NT_PRFPREG, &mut vector_registers)?;
+
+        Ok(vector_registers
+            .vregs
+            .iter()
+            .enumerate()
+            .map(|(register_index, register_value)| VectorRegisterValue::new(format!("v{}", register_index), register_value.to_le_bytes().to_vec()))
+            .collect())
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod arch {
+    use super::{VectorRegisterValue, io};
+    use squalr_engine_api::structures::debugging::register_value::RegisterValue;
+
+    pub(super) type RawRegisters = ();
+    pub(super) type SavedSyscallState = ();
+
+    pub(super) const SYSCALL_INSTRUCTION: &[u8] = &[];
+    pub(super) const COMPAT_SYSCALL_INSTRUCTION: &[u8] = &[];
+    pub(super) const SYSCALL_INSTRUCTION_ALIGNMENT: u64 = 1;
+
+    fn unsupported() -> io::Error {
+        io::Error::new(io::ErrorKind::Unsupported, "ptrace register access is not supported on this architecture")
+    }
+
+    pub(super) fn read_registers(_thread_id: u32) -> io::Result<RawRegisters> {
+        Err(unsupported())
+    }
+
+    pub(super) fn write_registers(
+        _thread_id: u32,
+        _registers: &RawRegisters,
+    ) -> io::Result<()> {
+        Err(unsupported())
+    }
+
+    pub(super) fn get_instruction_pointer(_registers: &RawRegisters) -> u64 {
+        0
+    }
+
+    pub(super) fn begin_syscall(
+        _thread_id: u32,
+        _registers: &RawRegisters,
+        _is_compat_mode: bool,
+        _instruction_address: u64,
+        _number: u64,
+        _arguments: [u64; 6],
+    ) -> io::Result<SavedSyscallState> {
+        Err(unsupported())
+    }
+
+    pub(super) fn build_call_frame(
+        _registers: &RawRegisters,
+        _is_compat_mode: bool,
+        _function_address: u64,
+        _arguments: &[u64],
+    ) -> io::Result<(RawRegisters, Vec<u8>)> {
+        Err(unsupported())
+    }
+
+    pub(super) fn begin_call(
+        _thread_id: u32,
+        _call_registers: &RawRegisters,
+    ) -> io::Result<SavedSyscallState> {
+        Err(unsupported())
+    }
+
+    pub(super) fn read_syscall_result(
+        _thread_id: u32,
+        _is_compat_mode: bool,
+    ) -> io::Result<i64> {
+        Err(unsupported())
+    }
+
+    pub(super) fn end_syscall(
+        _thread_id: u32,
+        _registers: &RawRegisters,
+        _saved_syscall_state: SavedSyscallState,
+    ) -> io::Result<()> {
+        Err(unsupported())
+    }
+
+    pub(super) fn set_instruction_pointer(
+        _registers: &mut RawRegisters,
+        _instruction_pointer: u64,
+    ) {
+    }
+
+    pub(super) fn get_stack_pointer(_registers: &RawRegisters) -> u64 {
+        0
+    }
+
+    pub(super) fn get_frame_pointer(_registers: &RawRegisters) -> u64 {
+        0
+    }
+
+    pub(super) fn resolve_breakpoint_address(instruction_pointer: u64) -> u64 {
+        instruction_pointer
+    }
+
+    pub(super) fn is_compat_mode(_registers: &RawRegisters) -> bool {
+        false
+    }
+
+    pub(super) fn build_registers(
+        _registers: &RawRegisters,
+        _is_compat_mode: bool,
+    ) -> Vec<RegisterValue> {
+        Vec::new()
+    }
+
+    pub(super) fn read_vector_registers(
+        _thread_id: u32,
+        _is_compat_mode: bool,
+    ) -> io::Result<Vec<VectorRegisterValue>> {
+        Err(unsupported())
+    }
+}
@@ -0,0 +1,1566 @@
+use crate::debugger::linux::linux_debugger::LinuxDebugger;
+use crate::debugger::linux::linux_ptrace_context::{BREAKPOINT_INSTRUCTION, LinuxPtraceContext, SYSCALL_INSTRUCTION_ALIGNMENT};
+use crate::debugger::linux::linux_remote_syscall::LinuxRemoteSyscall;
+use libc::{c_int, c_void, pid_t};
+use squalr_engine_api::structures::debugging::{
+    breakpoint::Breakpoint, breakpoint_hit::BreakpointHit, instruction_trace_stop_reason::InstructionTraceStopReason, register_snapshot::RegisterSnapshot,
+    traced_instruction::TracedInstruction,
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::os::unix::fs::FileExt;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// The most recent hits retained per trace breakpoint. Older hits are dropped once the limit is reached.
+const MAX_RETAINED_HITS: usize = 256;
+
+/// The number of frame pointer links followed when capturing the stack of a hit.
+const MAX_STACK_FRAMES: usize = 16;
+
+/// How long the tracer waits for commands before polling the tracees again.
+const COMMAND_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// How long callers wait for the tracer to attach or apply a patch.
+const COMMAND_REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long a function called inside the process may run before it is abandoned.
+const REMOTE_CALL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long an instruction trace may spend stepping once a thread reached its start address.
+const INSTRUCTION_TRACE_STEPPING_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// How many bytes are captured at each traced instruction, which covers the longest x86 instruction.
+const MAX_TRACED_INSTRUCTION_LENGTH: usize = 16;
+
+/// Attaching to a process is retried until no new threads appear between listings.
+const MAX_ATTACH_PASSES: usize = 8;
+
+/// How much of each executable mapping is searched for a system call instruction to borrow.
+const MAX_SYSCALL_INSTRUCTION_SEARCH_BYTES: u64 = 0x10_0000;
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+type PtraceRequest = libc::c_uint;
+
+#[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+type PtraceRequest = libc::c_int;
+
+// Requests from <linux/ptrace.h> that not every libc binding exposes.
+const PTRACE_SEIZE: PtraceRequest = 0x4206;
+const PTRACE_INTERRUPT: PtraceRequest = 0x4207;
+const PTRACE_LISTEN: PtraceRequest = 0x4208;
+
+/// The state of one breakpoint, shared between the tracer thread and the debugger.
+pub(crate) struct LinuxBreakpointState {
+    pub breakpoint: Breakpoint,
+    pub breakpoint_hits: VecDeque<BreakpointHit>,
+}
+
+impl LinuxBreakpointState {
+    pub fn new(breakpoint: Breakpoint) -> Self {
+        Self {
+            breakpoint,
+            breakpoint_hits: VecDeque::new(),
+        }
+    }
+}
+
+/// Breakpoint states of a single process, keyed by breakpoint address.
+pub(crate) type LinuxBreakpointTable = Arc<Mutex<HashMap<u64, LinuxBreakpointState>>>;
+
+/// The threads held in a ptrace stop by a suspend request, mapped to the signal of the stop that is restored on resume.
+pub(crate) type LinuxSuspendedThreads = Arc<Mutex<HashMap<u32, c_int>>>;
+
+enum TracerCommand {
+    Arm {
+        address: u64,
+        reply_sender: Sender<io::Result<()>>,
+    },
+    Disarm {
+        address: u64,
+        reply_sender: Sender<io::Result<()>>,
+    },
+    Syscall {
+        remote_syscall: LinuxRemoteSyscall,
+        reply_sender: Sender<io::Result<u64>>,
+    },
+    Call {
+        function_address: u64,
+        arguments: Vec<u64>,
+        reply_sender: Sender<io::Result<u64>>,
+    },
+    Trace {
+        start_address: u64,
+        end_address: Option<u64>,
+        thread_id: Option<u32>,
+        instruction_budget: u64,
+        start_timeout: Duration,
+        reply_sender: Sender<io::Result<(Vec<TracedInstruction>, InstructionTraceStopReason)>>,
+    },
+    Suspend {
+        thread_id: Option<u32>,
+        reply_sender: Sender<io::Result<()>>,
+    },
+    Resume {
+        thread_id: Option<u32>,
+        reply_sender: Sender<io::Result<()>>,
+    },
+    CaptureContext {
+        thread_id: u32,
+        reply_sender: Sender<io::Result<RegisterSnapshot>>,
+    },
+    Detach,
+}
+
+/// A ptrace attachment to every thread of a process, used to service software breakpoints, to hold suspended threads, and to
+/// run system calls inside it.
+///
+/// The kernel binds a tracee to the thread that attached to it, so all ptrace calls are made from a dedicated tracer thread
+/// that receives commands over a channel. Threads are seized rather than attached, so they keep running until they hit a
+/// breakpoint, and threads created afterwards are traced automatically.
+pub(crate) struct LinuxPtraceSession {
+    breakpoint_table: LinuxBreakpointTable,
+    suspended_threads: LinuxSuspendedThreads,
+    command_sender: Sender<TracerCommand>,
+    tracer_thread: Option<JoinHandle<()>>,
+}
+
+impl LinuxPtraceSession {
+    pub fn attach(process_id: u32) -> io::Result<Self> {
+        let breakpoint_table: LinuxBreakpointTable = Arc::new(Mutex::new(HashMap::new()));
+        let (command_sender, command_receiver) = mpsc::channel();
+        let (attach_sender, attach_receiver) = mpsc::channel();
+        let suspended_threads: LinuxSuspendedThreads = Arc::new(Mutex::new(HashMap::new()));
+        let tracer_breakpoint_table = breakpoint_table.clone();
+        let tracer_suspended_threads = suspended_threads.clone();
+        let tracer_thread = thread::Builder::new()
+            .name(format!("ptrace-tracer-{}", process_id))
+            .spawn(move || {
+                let mut tracer = match LinuxPtraceTracer::attach(process_id, tracer_breakpoint_table, tracer_suspended_threads) {
+                    Ok(tracer) => {
+                        let _ = attach_sender.send(Ok(()));
+                        tracer
+                    }
+                    Err(error) => {
+                        let _ = attach_sender.send(Err(error));
+                        return;
+                    }
+                };
+
+                tracer.run(command_receiver);
+            })?;
+
+        Self::receive_reply(&attach_receiver)?;
+
+        Ok(Self {
+            breakpoint_table,
+            suspended_threads,
+            command_sender,
+            tracer_thread: Some(tracer_thread),
+        })
+    }
+
+    pub fn get_breakpoint_table(&self) -> &LinuxBreakpointTable {
+        &self.breakpoint_table
+    }
+
+    /// Gets the ids of the threads that are currently suspended, in ascending order.
+    pub fn get_suspended_thread_ids(&self) -> Vec<u32> {
+        let mut suspended_thread_ids = match self.suspended_threads.lock() {
+            Ok(suspended_threads) => suspended_threads.keys().copied().collect::<Vec<_>>(),
+            Err(error) => {
+                log::error!("Failed to acquire suspended thread lock: {}", error);
+
+                Vec::new()
+            }
+        };
+        suspended_thread_ids.sort_unstable();
+
+        suspended_thread_ids
+    }
+
+    pub fn has_suspended_threads(&self) -> bool {
+        self.suspended_threads
+            .lock()
+            .is_ok_and(|suspended_threads| !suspended_threads.is_empty())
+    }
+
+    /// Gets whether the tracer is still attached. The tracer stops on its own once every thread of the process has exited.
+    pub fn is_attached(&self) -> bool {
+        self.tracer_thread
+            .as_ref()
+            .is_some_and(|tracer_thread| !tracer_thread.is_finished())
+    }
+
+    /// Patches the breakpoint instruction over the given address.
+    pub fn arm(
+        &self,
+        address: u64,
+    ) -> io::Result<()> {
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        self.send_command(TracerCommand::Arm { address, reply_sender })?;
+        Self::receive_reply(&reply_receiver)
+    }
+
+    /// Restores the original instruction at the given address.
+    pub fn disarm(
+        &self,
+        address: u64,
+    ) -> io::Result<()> {
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        self.send_command(TracerCommand::Disarm { address, reply_sender })?;
+        Self::receive_reply(&reply_receiver)
+    }
+
+    /// Runs a system call on a thread of the process, as if the process had made the call itself.
+    pub fn execute_syscall(
+        &self,
+        remote_syscall: LinuxRemoteSyscall,
+    ) -> io::Result<u64> {
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        self.send_command(TracerCommand::Syscall { remote_syscall, reply_sender })?;
+        Self::receive_reply(&reply_receiver)
+    }
+
+    /// Calls a function on a thread of the process, as if the thread had called it itself, returning its integer result.
+    pub fn call_function(
+        &self,
+        function_address: u64,
+        arguments: &[u64],
+    ) -> io::Result<u64> {
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        self.send_command(TracerCommand::Call {
+            function_address,
+            arguments: arguments.to_vec(),
+            reply_sender,
+        })?;
+        Self::receive_reply_within(&reply_receiver, REMOTE_CALL_TIMEOUT + COMMAND_REPLY_TIMEOUT)
+    }
+
+    /// Waits for a thread to execute the start address, then single steps it, returning each stop of the thread.
+    pub fn trace_instructions(
+        &self,
+        start_address: u64,
+        end_address: Option<u64>,
+        thread_id: Option<u32>,
+        instruction_budget: u64,
+        start_timeout: Duration,
+    ) -> io::Result<(Vec<TracedInstruction>, InstructionTraceStopReason)> {
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        self.send_command(TracerCommand::Trace {
+            start_address,
+            end_address,
+            thread_id,
+            instruction_budget,
+            start_timeout,
+            reply_sender,
+        })?;
+        Self::receive_reply_within(&reply_receiver, start_timeout + INSTRUCTION_TRACE_STEPPING_TIMEOUT)
+    }
+
+    /// Holds one thread, or every thread when no id is given, in a ptrace stop until it is resumed.
+    pub fn suspend(
+        &self,
+        thread_id: Option<u32>,
+    ) -> io::Result<()> {
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        self.send_command(TracerCommand::Suspend { thread_id, reply_sender })?;
+        Self::receive_reply(&reply_receiver)
+    }
+
+    /// Releases one suspended thread, or every suspended thread when no id is given.
+    pub fn resume(
+        &self,
+        thread_id: Option<u32>,
+    ) -> io::Result<()> {
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        self.send_command(TracerCommand::Resume { thread_id, reply_sender })?;
+        Self::receive_reply(&reply_receiver)
+    }
+
+    /// Captures the registers of a thread, briefly interrupting it if it is not suspended.
+    pub fn capture_context(
+        &self,
+        thread_id: u32,
+    ) -> io::Result<RegisterSnapshot> {
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        self.send_command(TracerCommand::CaptureContext { thread_id, reply_sender })?;
+        Self::receive_reply(&reply_receiver)
+    }
+
+    fn send_command(
+        &self,
+        tracer_command: TracerCommand,
+    ) -> io::Result<()> {
+        self.command_sender
+            .send(tracer_command)
+            .map_err(|_| io::Error::new(io::ErrorKind::NotConnected, "the process is no longer traced"))
+    }
+
+    fn receive_reply<T>(reply_receiver: &Receiver<io::Result<T>>) -> io::Result<T> {
+        Self::receive_reply_within(reply_receiver, COMMAND_REPLY_TIMEOUT)
+    }
+
+    fn receive_reply_within<T>(
+        reply_receiver: &Receiver<io::Result<T>>,
+        timeout: Duration,
+    ) -> io::Result<T> {
+        match reply_receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => Err(io::Error::new(io::ErrorKind::TimedOut, "timed out waiting for the tracer")),
+            Err(RecvTimeoutError::Disconnected) => Err(io::Error::new(io::ErrorKind::NotConnected, "the process is no longer traced")),
+        }
+    }
+}
+
+impl Drop for LinuxPtraceSession {
+    fn drop(&mut self) {
+        let _ = self.command_sender.send(TracerCommand::Detach);
+
+        if let Some(tracer_thread) = self.tracer_thread.take() {
+            let _ = tracer_thread.join();
+        }
+    }
+}
+
+/// A decoded `waitpid` status.
+enum WaitStatus {
+    Exited,
+    Stopped { signal: c_int, ptrace_event: c_int },
+}
+
+impl WaitStatus {
+    fn decode(status: c_int) -> Option<Self> {
+        if libc::WIFEXITED(status) || libc::WIFSIGNALED(status) {
+            Some(Self::Exited)
+        } else if libc::WIFSTOPPED(status) {
+            Some(Self::Stopped {
+                signal: libc::WSTOPSIG(status),
+                ptrace_event: (status >> 16) & 0xFFFF,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Owns the ptrace attachment. Only ever used from the tracer thread.
+struct LinuxPtraceTracer {
+    process_id: u32,
+    process_memory: File,
+    thread_ids: HashSet<u32>,
+    breakpoint_table: LinuxBreakpointTable,
+    suspended_threads: LinuxSuspendedThreads,
+    /// The original bytes of every address that currently holds a breakpoint instruction.
+    original_bytes_by_address: HashMap<u64, Vec<u8>>,
+    /// Addresses that held a breakpoint at some point. A thread may have trapped on one just before it was removed.
+    retired_addresses: HashSet<u64>,
+    /// A system call instruction found in the process, which threads are pointed at to run remote system calls.
+    syscall_instruction_address: Option<u64>,
+}
+
+impl LinuxPtraceTracer {
+    fn attach(
+        process_id: u32,
+        breakpoint_table: LinuxBreakpointTable,
+        suspended_threads: LinuxSuspendedThreads,
+    ) -> io::Result<Self> {
+        if BREAKPOINT_INSTRUCTION.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "software breakpoints are not supported on this architecture",
+            ));
+        }
+
+        // Writes through /proc/pid/mem bypass page protections, so code can be patched without changing its protection.
+        let process_memory = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(format!("/proc/{}/mem", process_id))?;
+        let mut tracer = Self {
+            process_id,
+            process_memory,
+            thread_ids: HashSet::new(),
+            breakpoint_table,
+            suspended_threads,
+            original_bytes_by_address: HashMap::new(),
+            retired_addresses: HashSet::new(),
+            syscall_instruction_address: None,
+        };
+
+        for _ in 0..MAX_ATTACH_PASSES {
+            let mut has_new_threads = false;
+
+            for thread_id in LinuxDebugger::list_thread_ids(process_id)? {
+                if tracer.thread_ids.contains(&thread_id) {
+                    continue;
+                }
+
+                match Self::ptrace(PTRACE_SEIZE, thread_id, 0, libc::PTRACE_O_TRACECLONE as usize) {
+                    Ok(()) => {
+                        tracer.thread_ids.insert(thread_id);
+                        has_new_threads = true;
+                    }
+                    Err(error) if error.raw_os_error() == Some(libc::ESRCH) => {}
+                    Err(error) => {
+                        tracer.detach();
+
+                        return Err(error);
+                    }
+                }
+            }
+
+            if !has_new_threads {
+                break;
+            }
+        }
+
+        if tracer.thread_ids.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "the process has no threads"));
+        }
+
+        Ok(tracer)
+    }
+
+    fn run(
+        &mut self,
+        command_receiver: Receiver<TracerCommand>,
+    ) {
+        loop {
+            if !self.drain_wait_statuses() {
+                log::info!("Process {} exited, stopping its breakpoint tracer.", self.process_id);
+                self.disarm_all_breakpoints();
+
+                return;
+            }
+
+            match command_receiver.recv_timeout(COMMAND_POLL_INTERVAL) {
+                Ok(TracerCommand::Arm { address, reply_sender }) => {
+                    let _ = reply_sender.send(self.arm(address));
+                }
+                Ok(TracerCommand::Disarm { address, reply_sender }) => {
+                    let _ = reply_sender.send(self.disarm(address));
+                }
+                Ok(TracerCommand::Syscall { remote_syscall, reply_sender }) => {
+                    let _ = reply_sender.send(self.execute_syscall(remote_syscall));
+                }
+                Ok(TracerCommand::Call {
+                    function_address,
+                    arguments,
+                    reply_sender,
+                }) => {
+                    let _ = reply_sender.send(self.call_function(function_address, &arguments));
+                }
+                Ok(TracerCommand::Trace {
+                    start_address,
+                    end_address,
+                    thread_id,
+                    instruction_budget,
+                    start_timeout,
+                    reply_sender,
+                }) => {
+                    let _ = reply_sender.send(self.trace_instructions(start_address, end_address, thread_id, instruction_budget, start_timeout));
+                }
+                Ok(TracerCommand::Suspend { thread_id, reply_sender }) => {
+                    let _ = reply_sender.send(match thread_id {
+                        Some(thread_id) => self.suspend_thread(thread_id),
+                        None => self.suspend_all_threads(),
+                    });
+                }
+                Ok(TracerCommand::Resume { thread_id, reply_sender }) => {
+                    let _ = reply_sender.send(self.resume_suspended_threads(thread_id));
+                }
+                Ok(TracerCommand::CaptureContext { thread_id, reply_sender }) => {
+                    let _ = reply_sender.send(self.capture_context(thread_id));
+                }
+                Ok(TracerCommand::Detach) | Err(RecvTimeoutError::Disconnected) => {
+                    self.detach();
+
+                    return;
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+            }
+        }
+    }
+
+    /// Handles every pending stop, returning false once no traced threads remain.
+    fn drain_wait_statuses(&mut self) -> bool {
+        loop {
+            let mut status = 0;
+            let thread_id = unsafe { libc::waitpid(-1, &mut status, libc::__WALL | libc::__WNOTHREAD | libc::WNOHANG) };
+
+            match thread_id {
+                0 => return !self.thread_ids.is_empty(),
+                -1 => {
+                    let error = io::Error::last_os_error();
+
+                    if error.raw_os_error() != Some(libc::EINTR) {
+                        return error.raw_os_error() != Some(libc::ECHILD) && !self.thread_ids.is_empty();
+                    }
+                }
+                thread_id => self.handle_wait_status(thread_id as u32, status),
+            }
+        }
+    }
+
+    fn handle_wait_status(
+        &mut self,
+        thread_id: u32,
+        status: c_int,
+    ) {
+        match WaitStatus::decode(status) {
+            Some(WaitStatus::Exited) => {
+                self.thread_ids.remove(&thread_id);
+                self.take_suspended_stop_signal(thread_id);
+            }
+            Some(WaitStatus::Stopped { signal, ptrace_event }) => match ptrace_event {
+                libc::PTRACE_EVENT_CLONE => {
+                    if let Ok(new_thread_id) = Self::get_event_message(thread_id) {
+                        self.thread_ids.insert(new_thread_id as u32);
+                    }
+
+                    self.resume(thread_id, 0);
+                }
+                libc::PTRACE_EVENT_STOP => {
+                    self.thread_ids.insert(thread_id);
+
+                    // Group stops must be listened through, so that the thread stays stopped until the process is continued.
+                    if signal == libc::SIGTRAP {
+                        self.resume(thread_id, 0);
+                    } else if let Err(error) = Self::ptrace(PTRACE_LISTEN, thread_id, 0, 0) {
+                        log::debug!("Failed to listen on thread {}: {}", thread_id, error);
+                    }
+                }
+                0 if signal == libc::SIGTRAP => self.handle_trap(thread_id),
+                0 => self.resume(thread_id, signal),
+                _ => self.resume(thread_id, 0),
+            },
+            None => {}
+        }
+    }
+
+    fn handle_trap(
+        &mut self,
+        thread_id: u32,
+    ) {
+        let mut context = match LinuxPtraceContext::read(thread_id) {
+            Ok(context) => context,
+            Err(error) => {
+                log::debug!("Failed to read registers of thread {}: {}", thread_id, error);
+                self.resume(thread_id, libc::SIGTRAP);
+
+                return;
+            }
+        };
+        let breakpoint_address = context.get_breakpoint_address();
+
+        let Some(original_bytes) = self.original_bytes_by_address.get(&breakpoint_address).cloned() else {
+            // The breakpoint was removed after this thread trapped on it, so it only needs to run the restored instruction.
+            if self.retired_addresses.contains(&breakpoint_address) {
+                if let Err(error) = context.set_instruction_pointer(breakpoint_address) {
+                    log::debug!("Failed to rewind thread {}: {}", thread_id, error);
+                }
+
+                self.resume(thread_id, 0);
+            } else {
+                self.resume(thread_id, libc::SIGTRAP);
+            }
+
+            return;
+        };
+
+        if let Err(error) = context.set_instruction_pointer(breakpoint_address) {
+            log::error!("Failed to rewind thread {} to breakpoint 0x{:X}: {}", thread_id, breakpoint_address, error);
+            self.resume(thread_id, 0);
+
+            return;
+        }
+
+        let is_still_armed = self.record_hit(breakpoint_address, &context);
+
+        self.step_over_breakpoint(thread_id, breakpoint_address, &original_bytes, is_still_armed);
+    }
+
+    /// Runs the original instruction under a breakpoint on a thread that is stopped on it, then puts the breakpoint back
+    /// unless it should be disarmed, and lets the thread continue.
+    fn step_over_breakpoint(
+        &mut self,
+        thread_id: u32,
+        breakpoint_address: u64,
+        original_bytes: &[u8],
+        is_still_armed: bool,
+    ) {
+        if let Err(error) = self.write_memory(breakpoint_address, original_bytes) {
+            log::error!("Failed to restore the instruction at 0x{:X}: {}", breakpoint_address, error);
+        }
+
+        if self.single_step(thread_id) {
+            if is_still_armed {
+                if let Err(error) = self.write_memory(breakpoint_address, BREAKPOINT_INSTRUCTION) {
+                    log::error!("Failed to re-arm the breakpoint at 0x{:X}: {}", breakpoint_address, error);
+                }
+            } else {
+                self.original_bytes_by_address.remove(&breakpoint_address);
+            }
+
+            self.resume(thread_id, 0);
+        } else if is_still_armed {
+            // The thread exited while stepping, so the breakpoint can be re-armed without it.
+            let _ = self.write_memory(breakpoint_address, BREAKPOINT_INSTRUCTION);
+        } else {
+            self.original_bytes_by_address.remove(&breakpoint_address);
+        }
+    }
+
+    /// Records a hit of the breakpoint at the given address, returning whether it should stay armed.
+    fn record_hit(
+        &self,
+        breakpoint_address: u64,
+        context: &LinuxPtraceContext,
+    ) -> bool {
+        let mut breakpoint_table = match self.breakpoint_table.lock() {
+            Ok(breakpoint_table) => breakpoint_table,
+            Err(error) => {
+                log::error!("Failed to acquire breakpoint table lock: {}", error);
+
+                return true;
+            }
+        };
+        let Some(breakpoint_state) = breakpoint_table.get_mut(&breakpoint_address) else {
+            return false;
+        };
+
+        if !breakpoint_state.breakpoint.get_is_armed() {
+            return false;
+        }
+
+        let hit_index = breakpoint_state.breakpoint.record_hit();
+
+        if breakpoint_state
+            .breakpoint
+            .get_breakpoint_mode()
+            .captures_context()
+        {
+            let breakpoint_hit = BreakpointHit::new(
+                breakpoint_state.breakpoint.get_breakpoint_id(),
+                hit_index,
+                context.capture_register_snapshot(),
+                self.walk_stack_frames(context),
+            );
+
+            if breakpoint_state.breakpoint_hits.len() >= MAX_RETAINED_HITS {
+                breakpoint_state.breakpoint_hits.pop_front();
+            }
+
+            breakpoint_state.breakpoint_hits.push_back(breakpoint_hit);
+        }
+
+        if breakpoint_state.breakpoint.has_reached_hit_limit() {
+            breakpoint_state.breakpoint.disarm();
+        }
+
+        breakpoint_state.breakpoint.get_is_armed()
+    }
+
+    /// Collects return addresses by following the frame pointer chain. This is best effort, as code built without frame
+    /// pointers stops the walk early, and a breakpoint on the first instruction of a function does not see its own caller.
+    fn walk_stack_frames(
+        &self,
+        context: &LinuxPtraceContext,
+    ) -> Vec<u64> {
+        let pointer_size = context.get_pointer_size();
+        let mut stack_frames = vec![context.get_instruction_pointer()];
+        let mut frame_pointer = context.get_frame_pointer();
+
+        while stack_frames.len() < MAX_STACK_FRAMES && frame_pointer != 0 && frame_pointer.is_multiple_of(pointer_size) {
+            let (Some(next_frame_pointer), Some(return_address)) = (
+                self.read_pointer(frame_pointer, pointer_size),
+                self.read_pointer(frame_pointer.wrapping_add(pointer_size), pointer_size),
+            ) else {
+                break;
+            };
+
+            if return_address == 0 {
+                break;
+            }
+
+            stack_frames.push(return_address);
+
+            // Frames grow toward lower addresses, so a caller frame must be above the current one.
+            if next_frame_pointer <= frame_pointer {
+                break;
+            }
+
+            frame_pointer = next_frame_pointer;
+        }
+
+        stack_frames
+    }
+
+    fn read_pointer(
+        &self,
+        address: u64,
+        pointer_size: u64,
+    ) -> Option<u64> {
+        let mut pointer_bytes = [0u8; 8];
+        let pointer_bytes = &mut pointer_bytes[..pointer_size as usize];
+
+        self.process_memory.read_exact_at(pointer_bytes, address).ok()?;
+
+        Some(
+            pointer_bytes
+                .iter()
+                .rev()
+                .fold(0u64, |pointer, byte| (pointer << 8) | *byte as u64),
+        )
+    }
+
+    fn write_memory(
+        &self,
+        address: u64,
+        bytes: &[u8],
+    ) -> io::Result<()> {
+        self.process_memory.write_all_at(bytes, address)
+    }
+
+    fn arm(
+        &mut self,
+        address: u64,
+    ) -> io::Result<()> {
+        if self.original_bytes_by_address.contains_key(&address) {
+            return Ok(());
+        }
+
+        let mut original_bytes = vec![0u8; BREAKPOINT_INSTRUCTION.len()];
+        self.process_memory
+            .read_exact_at(&mut original_bytes, address)?;
+        self.write_memory(address, BREAKPOINT_INSTRUCTION)?;
+        self.original_bytes_by_address.insert(address, original_bytes);
+        self.retired_addresses.insert(address);
+
+        Ok(())
+    }
+
+    fn disarm(
+        &mut self,
+        address: u64,
+    ) -> io::Result<()> {
+        match self.original_bytes_by_address.remove(&address) {
+            Some(original_bytes) => self.write_memory(address, &original_bytes),
+            None => Ok(()),
+        }
+    }
+
+    /// Interrupts one thread, runs the system call on it, then lets the thread continue from where it was stopped.
+    fn execute_syscall(
+        &mut self,
+        remote_syscall: LinuxRemoteSyscall,
+    ) -> io::Result<u64> {
+        let thread_id = self.get_remote_thread_id()?;
+        // A suspended thread is already stopped, and stays stopped once the call is done.
+        let stop_signal = if self.is_suspended(thread_id) {
+            None
+        } else {
+            Some(self.interrupt_thread(thread_id)?)
+        };
+        let mut deferred_signals = Vec::new();
+        let return_value = self.execute_syscall_on_stopped_thread(thread_id, remote_syscall, &mut deferred_signals);
+
+        if let Some(stop_signal) = stop_signal {
+            self.release_thread(thread_id, stop_signal);
+        }
+
+        // Signals that arrived while the system call was being stepped were held back, so they are raised again now.
+        for deferred_signal in deferred_signals {
+            unsafe {
+                libc::syscall(libc::SYS_tgkill, self.process_id as pid_t, thread_id as pid_t, deferred_signal);
+            }
+        }
+
+        LinuxRemoteSyscall::decode_result(return_value?)
+    }
+
+    /// Interrupts the main thread, calls the function on it, then lets the thread continue from where it was stopped.
+    fn call_function(
+        &mut self,
+        function_address: u64,
+        arguments: &[u64],
+    ) -> io::Result<u64> {
+        let thread_id = self.get_remote_thread_id()?;
+        let stop_signal = if self.is_suspended(thread_id) {
+            None
+        } else {
+            Some(self.interrupt_thread(thread_id)?)
+        };
+        let mut deferred_signals = Vec::new();
+        // The frame is written through a duplicate of the memory handle, as the tracer itself is needed to run the call.
+        let return_value = self.process_memory.try_clone().and_then(|process_memory| {
+            LinuxPtraceContext::read(thread_id)?.execute_call(
+                function_address,
+                arguments,
+                |stack_address, stack_bytes| process_memory.write_all_at(stack_bytes, stack_address),
+                || self.run_call(thread_id, &mut deferred_signals),
+            )
+        });
+
+        if let Some(stop_signal) = stop_signal {
+            self.release_thread(thread_id, stop_signal);
+        }
+
+        for deferred_signal in deferred_signals {
+            unsafe {
+                libc::syscall(libc::SYS_tgkill, self.process_id as pid_t, thread_id as pid_t, deferred_signal);
+            }
+        }
+
+        return_value
+    }
+
+    /// Continues a thread that was set up to call a function until the function returns to address zero. Breakpoints and
+    /// events of other threads are serviced in the meantime, since the function may wait on them.
+    fn run_call(
+        &mut self,
+        thread_id: u32,
+        deferred_signals: &mut Vec<c_int>,
+    ) -> io::Result<()> {
+        let deadline = Instant::now() + REMOTE_CALL_TIMEOUT;
+
+        Self::ptrace(libc::PTRACE_CONT, thread_id, 0, 0)?;
+
+        loop {
+            let mut status = 0;
+            let waited_thread_id = unsafe { libc::waitpid(-1, &mut status, libc::__WALL | libc::__WNOTHREAD | libc::WNOHANG) };
+
+            match waited_thread_id {
+                0 => {
+                    if Instant::now() >= deadline {
+                        // The thread is stopped again so that its registers can be restored.
+                        self.interrupt_thread(thread_id)?;
+
+                        return Err(io::Error::new(io::ErrorKind::TimedOut, "the called function did not return in time"));
+                    }
+
+                    thread::sleep(COMMAND_POLL_INTERVAL);
+                }
+                -1 => {
+                    let error = io::Error::last_os_error();
+
+                    if error.raw_os_error() != Some(libc::EINTR) {
+                        return Err(error);
+                    }
+                }
+                waited_thread_id if waited_thread_id as u32 != thread_id => self.handle_wait_status(waited_thread_id as u32, status),
+                _ => match WaitStatus::decode(status) {
+                    Some(WaitStatus::Exited) | None => {
+                        self.handle_wait_status(thread_id, status);
+
+                        return Err(io::Error::new(io::ErrorKind::NotFound, "the thread exited during the call"));
+                    }
+                    Some(WaitStatus::Stopped { signal, ptrace_event: 0 }) if signal == libc::SIGTRAP => self.handle_trap(thread_id),
+                    Some(WaitStatus::Stopped { signal, ptrace_event: 0 }) => {
+                        let instruction_pointer = LinuxPtraceContext::read(thread_id)?.get_instruction_pointer();
+
+                        if instruction_pointer == 0 {
+                            return Ok(());
+                        }
+
+                        if matches!(signal, libc::SIGSEGV | libc::SIGBUS | libc::SIGILL | libc::SIGFPE) {
+                            return Err(io::Error::other(format!(
+                                "the called function faulted with signal {} at 0x{:X}",
+                                signal, instruction_pointer
+                            )));
+                        }
+
+                        // Other signals are held back, as their handlers would run on the stack frame of the call.
+                        deferred_signals.push(signal);
+                        Self::ptrace(libc::PTRACE_CONT, thread_id, 0, 0)?;
+                    }
+                    Some(WaitStatus::Stopped { ptrace_event, .. }) => {
+                        if ptrace_event == libc::PTRACE_EVENT_CLONE
+                            && let Ok(new_thread_id) = Self::get_event_message(thread_id)
+                        {
+                            self.thread_ids.insert(new_thread_id as u32);
+                        }
+
+                        Self::ptrace(libc::PTRACE_CONT, thread_id, 0, 0)?;
+                    }
+                },
+            }
+        }
+    }
+
+    /// Waits for a thread to execute the start address, then single steps it until it reaches the end address or has
+    /// executed the instruction budget, and lets it continue. An existing breakpoint at the start address is borrowed without
+    /// counting the hit of the traced thread, otherwise a temporary one is placed there for the wait.
+    fn trace_instructions(
+        &mut self,
+        start_address: u64,
+        end_address: Option<u64>,
+        thread_id: Option<u32>,
+        instruction_budget: u64,
+        start_timeout: Duration,
+    ) -> io::Result<(Vec<TracedInstruction>, InstructionTraceStopReason)> {
+        if let Some(thread_id) = thread_id {
+            if !self.thread_ids.contains(&thread_id) {
+                return Err(io::Error::new(io::ErrorKind::NotFound, format!("thread {} is not traced", thread_id)));
+            }
+
+            if self.is_suspended(thread_id) {
+                return Err(io::Error::other(format!(
+                    "thread {} is suspended and cannot reach the start address",
+                    thread_id
+                )));
+            }
+        }
+
+        let is_temporary_breakpoint = !self.original_bytes_by_address.contains_key(&start_address);
+
+        if is_temporary_breakpoint {
+            self.arm(start_address)?;
+        }
+
+        let traced_thread_id = self.wait_for_trace_start(start_address, thread_id, is_temporary_breakpoint, start_timeout);
+
+        if is_temporary_breakpoint && let Err(error) = self.disarm(start_address) {
+            log::error!("Failed to restore the instruction at 0x{:X}: {}", start_address, error);
+        }
+
+        let traced_thread_id = traced_thread_id?;
+        let trace_result = self.step_trace(traced_thread_id, end_address, instruction_budget);
+
+        if self.thread_ids.contains(&traced_thread_id) {
+            self.resume(traced_thread_id, 0);
+        }
+
+        trace_result
+    }
+
+    /// Services the tracees until a matching thread traps on the start address, returning that thread while it is stopped
+    /// there. Other threads that trap on a temporary start breakpoint are stepped over it.
+    fn wait_for_trace_start(
+        &mut self,
+        start_address: u64,
+        thread_id: Option<u32>,
+        is_temporary_breakpoint: bool,
+        start_timeout: Duration,
+    ) -> io::Result<u32> {
+        let deadline = Instant::now() + start_timeout;
+
+        loop {
+            let mut status = 0;
+            let waited_thread_id = unsafe { libc::waitpid(-1, &mut status, libc::__WALL | libc::__WNOTHREAD | libc::WNOHANG) };
+
+            match waited_thread_id {
+                0 => {
+                    if Instant::now() >= deadline {
+                        return Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            format!("no thread executed 0x{:X} in time", start_address),
+                        ));
+                    }
+
+                    thread::sleep(COMMAND_POLL_INTERVAL);
+                }
+                -1 => {
+                    let error = io::Error::last_os_error();
+
+                    if error.raw_os_error() != Some(libc::EINTR) {
+                        return Err(error);
+                    }
+                }
+                waited_thread_id => {
+                    let waited_thread_id = waited_thread_id as u32;
+
+                    if let Some(WaitStatus::Stopped { signal, ptrace_event: 0 }) = WaitStatus::decode(status)
+                        && signal == libc::SIGTRAP
+                        && let Ok(mut context) = LinuxPtraceContext::read(waited_thread_id)
+                        && context.get_breakpoint_address() == start_address
+                    {
+                        if thread_id.is_none_or(|thread_id| thread_id == waited_thread_id) {
+                            context.set_instruction_pointer(start_address)?;
+
+                            return Ok(waited_thread_id);
+                        }
+
+                        if is_temporary_breakpoint {
+                            context.set_instruction_pointer(start_address)?;
+
+                            if let Some(original_bytes) = self.original_bytes_by_address.get(&start_address).cloned() {
+                                self.step_over_breakpoint(waited_thread_id, start_address, &original_bytes, true);
+                            }
+
+                            continue;
+                        }
+                    }
+
+                    self.handle_wait_status(waited_thread_id, status);
+
+                    if thread_id.is_some_and(|thread_id| !self.thread_ids.contains(&thread_id)) {
+                        return Err(io::Error::new(io::ErrorKind::NotFound, "the thread exited before it reached the start address"));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Single steps a thread that is stopped at the start of a trace, capturing its registers and code before each step.
+    /// Breakpoints under the instruction pointer are lifted for the step, so that the original instruction runs.
+    fn step_trace(
+        &mut self,
+        thread_id: u32,
+        end_address: Option<u64>,
+        instruction_budget: u64,
+    ) -> io::Result<(Vec<TracedInstruction>, InstructionTraceStopReason)> {
+        let mut traced_instructions = Vec::new();
+
+        loop {
+            let context = LinuxPtraceContext::read(thread_id)?;
+            let instruction_pointer = context.get_instruction_pointer();
+
+            traced_instructions.push(TracedInstruction::new(
+                context.capture_general_register_snapshot(),
+                self.read_instruction_bytes(instruction_pointer),
+            ));
+
+            if end_address == Some(instruction_pointer) {
+                return Ok((traced_instructions, InstructionTraceStopReason::ReachedEndAddress));
+            }
+
+            if traced_instructions.len() as u64 > instruction_budget {
+                return Ok((traced_instructions, InstructionTraceStopReason::ReachedInstructionBudget));
+            }
+
+            let original_bytes = self
+                .original_bytes_by_address
+                .get(&instruction_pointer)
+                .cloned();
+
+            if let Some(original_bytes) = &original_bytes {
+                self.write_memory(instruction_pointer, original_bytes)?;
+            }
+
+            let did_step = self.single_step(thread_id);
+
+            if original_bytes.is_some()
+                && let Err(error) = self.write_memory(instruction_pointer, BREAKPOINT_INSTRUCTION)
+            {
+                log::error!("Failed to re-arm the breakpoint at 0x{:X}: {}", instruction_pointer, error);
+            }
+
+            if !did_step {
+                return Ok((traced_instructions, InstructionTraceStopReason::ThreadExited));
+            }
+        }
+    }
+
+    /// Reads the code at an address as it was before breakpoints were patched into it. Fewer bytes are returned when the
+    /// code ends at an unreadable page.
+    fn read_instruction_bytes(
+        &self,
+        address: u64,
+    ) -> Vec<u8> {
+        let mut instruction_bytes = vec![0u8; MAX_TRACED_INSTRUCTION_LENGTH];
+        let read_length = self
+            .process_memory
+            .read_at(&mut instruction_bytes, address)
+            .unwrap_or(0);
+        instruction_bytes.truncate(read_length);
+
+        for (breakpoint_address, original_bytes) in &self.original_bytes_by_address {
+            for (byte_offset, original_byte) in original_bytes.iter().enumerate() {
+                let byte_index = (breakpoint_address + byte_offset as u64).wrapping_sub(address);
+
+                if let Some(instruction_byte) = instruction_bytes.get_mut(byte_index as usize) {
+                    *instruction_byte = *original_byte;
+                }
+            }
+        }
+
+        instruction_bytes
+    }
+
+    /// Gets the thread that remote system calls and function calls run on, preferring the main thread.
+    fn get_remote_thread_id(&self) -> io::Result<u32> {
+        if self.thread_ids.contains(&self.process_id) {
+            Ok(self.process_id)
+        } else {
+            self.thread_ids
+                .iter()
+                .min()
+                .copied()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "the process has no threads"))
+        }
+    }
+
+    fn execute_syscall_on_stopped_thread(
+        &mut self,
+        thread_id: u32,
+        remote_syscall: LinuxRemoteSyscall,
+        deferred_signals: &mut Vec<c_int>,
+    ) -> io::Result<i64> {
+        let context = LinuxPtraceContext::read(thread_id)?;
+        let syscall_instruction = context.get_syscall_instruction();
+
+        if syscall_instruction.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "remote system calls are not supported for this process architecture",
+            ));
+        }
+
+        let syscall_instruction_address = self.find_syscall_instruction(syscall_instruction)?;
+        let (number, arguments) = remote_syscall.encode(context.is_compat_mode())?;
+
+        context.execute_syscall(syscall_instruction_address, number, arguments, || {
+            self.step_syscall(thread_id, deferred_signals)
+        })
+    }
+
+    fn is_suspended(
+        &self,
+        thread_id: u32,
+    ) -> bool {
+        self.suspended_threads
+            .lock()
+            .is_ok_and(|suspended_threads| suspended_threads.contains_key(&thread_id))
+    }
+
+    fn take_suspended_stop_signal(
+        &self,
+        thread_id: u32,
+    ) -> Option<c_int> {
+        match self.suspended_threads.lock() {
+            Ok(mut suspended_threads) => suspended_threads.remove(&thread_id),
+            Err(error) => {
+                log::error!("Failed to acquire suspended thread lock: {}", error);
+
+                None
+            }
+        }
+    }
+
+    fn suspend_thread(
+        &mut self,
+        thread_id: u32,
+    ) -> io::Result<()> {
+        if !self.thread_ids.contains(&thread_id) {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("thread {} is not traced", thread_id)));
+        }
+
+        if self.is_suspended(thread_id) {
+            return Ok(());
+        }
+
+        let stop_signal = self.interrupt_thread(thread_id)?;
+
+        self.suspended_threads
+            .lock()
+            .map_err(|error| io::Error::other(error.to_string()))?
+            .insert(thread_id, stop_signal);
+
+        Ok(())
+    }
+
+    /// Suspends every thread. Threads that are created while the others are being stopped are picked up on the next pass.
+    fn suspend_all_threads(&mut self) -> io::Result<()> {
+        for _ in 0..MAX_ATTACH_PASSES {
+            self.drain_wait_statuses();
+
+            let mut running_thread_ids = self
+                .thread_ids
+                .iter()
+                .copied()
+                .filter(|thread_id| !self.is_suspended(*thread_id))
+                .collect::<Vec<_>>();
+            running_thread_ids.sort_unstable();
+
+            if running_thread_ids.is_empty() {
+                return Ok(());
+            }
+
+            for thread_id in running_thread_ids {
+                match self.suspend_thread(thread_id) {
+                    Ok(()) => {}
+                    // Threads that exit while the process is being stopped have nothing left to suspend.
+                    Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+
+        Err(io::Error::other("threads kept being created while the process was being suspended"))
+    }
+
+    fn resume_suspended_threads(
+        &mut self,
+        thread_id: Option<u32>,
+    ) -> io::Result<()> {
+        let thread_ids = match thread_id {
+            Some(thread_id) => vec![thread_id],
+            None => {
+                let mut thread_ids = self
+                    .suspended_threads
+                    .lock()
+                    .map_err(|error| io::Error::other(error.to_string()))?
+                    .keys()
+                    .copied()
+                    .collect::<Vec<_>>();
+                thread_ids.sort_unstable();
+
+                thread_ids
+            }
+        };
+
+        for thread_id in thread_ids {
+            if let Some(stop_signal) = self.take_suspended_stop_signal(thread_id) {
+                self.release_thread(thread_id, stop_signal);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn capture_context(
+        &mut self,
+        thread_id: u32,
+    ) -> io::Result<RegisterSnapshot> {
+        if !self.thread_ids.contains(&thread_id) {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("thread {} is not traced", thread_id)));
+        }
+
+        if self.is_suspended(thread_id) {
+            return LinuxPtraceContext::read(thread_id).map(|context| context.capture_register_snapshot());
+        }
+
+        let stop_signal = self.interrupt_thread(thread_id)?;
+        let register_snapshot = LinuxPtraceContext::read(thread_id).map(|context| context.capture_register_snapshot());
+
+        self.release_thread(thread_id, stop_signal);
+
+        register_snapshot
+    }
+
+    /// Lets a thread that was stopped by an interrupt continue. Threads that were in a group stop are listened through, so
+    /// that they stay stopped until the process is continued.
+    fn release_thread(
+        &mut self,
+        thread_id: u32,
+        stop_signal: c_int,
+    ) {
+        if stop_signal == libc::SIGTRAP {
+            self.resume(thread_id, 0);
+        } else if let Err(error) = Self::ptrace(PTRACE_LISTEN, thread_id, 0, 0) {
+            log::debug!("Failed to listen on thread {}: {}", thread_id, error);
+        }
+    }
+
+    /// Stops a running thread with an interrupt, returning the signal of the stop. Events that other threads or this thread
+    /// report in the meantime are handled as usual.
+    fn interrupt_thread(
+        &mut self,
+        thread_id: u32,
+    ) -> io::Result<c_int> {
+        loop {
+            // Interrupts do not queue, so one is requested again in case a breakpoint step swallowed the previous stop.
+            Self::ptrace(PTRACE_INTERRUPT, thread_id, 0, 0)?;
+
+            let Some(status) = Self::wait_for_thread(thread_id) else {
+                self.thread_ids.remove(&thread_id);
+
+                return Err(io::Error::new(io::ErrorKind::NotFound, "the thread exited before it could be interrupted"));
+            };
+
+            match WaitStatus::decode(status) {
+                Some(WaitStatus::Stopped {
+                    signal,
+                    ptrace_event: libc::PTRACE_EVENT_STOP,
+                }) => return Ok(signal),
+                Some(WaitStatus::Exited) => {
+                    self.thread_ids.remove(&thread_id);
+
+                    return Err(io::Error::new(io::ErrorKind::NotFound, "the thread exited before it could be interrupted"));
+                }
+                _ => self.handle_wait_status(thread_id, status),
+            }
+        }
+    }
+
+    /// Steps a thread over the borrowed system call instruction. Signals that arrive first are held back rather than
+    /// delivered, as their handlers would run with the registers that were set up for the system call.
+    fn step_syscall(
+        &mut self,
+        thread_id: u32,
+        deferred_signals: &mut Vec<c_int>,
+    ) -> io::Result<()> {
+        loop {
+            Self::ptrace(libc::PTRACE_SINGLESTEP, thread_id, 0, 0)?;
+
+            let Some(status) = Self::wait_for_thread(thread_id) else {
+                self.thread_ids.remove(&thread_id);
+
+                return Err(io::Error::new(io::ErrorKind::NotFound, "the thread exited during the system call"));
+            };
+
+            match WaitStatus::decode(status) {
+                Some(WaitStatus::Stopped { signal, ptrace_event: 0 }) if signal == libc::SIGTRAP => return Ok(()),
+                Some(WaitStatus::Stopped { signal, ptrace_event: 0 }) => deferred_signals.push(signal),
+                Some(WaitStatus::Stopped { .. }) | None => {}
+                Some(WaitStatus::Exited) => {
+                    self.thread_ids.remove(&thread_id);
+
+                    return Err(io::Error::new(io::ErrorKind::NotFound, "the thread exited during the system call"));
+                }
+            }
+        }
+    }
+
+    /// Finds a system call instruction in an executable mapping, preferring the vDSO, which every process maps and never
+    /// unmaps. Borrowing an existing instruction avoids patching code that other threads may be running.
+    fn find_syscall_instruction(
+        &mut self,
+        syscall_instruction: &[u8],
+    ) -> io::Result<u64> {
+        if let Some(syscall_instruction_address) = self.syscall_instruction_address {
+            let mut instruction_bytes = vec![0u8; syscall_instruction.len()];
+
+            if self
+                .process_memory
+                .read_exact_at(&mut instruction_bytes, syscall_instruction_address)
+                .is_ok()
+                && instruction_bytes == syscall_instruction
+            {
+                return Ok(syscall_instruction_address);
+            }
+        }
+
+        let memory_maps = fs::read_to_string(format!("/proc/{}/maps", self.process_id))?;
+        let mut executable_regions = memory_maps
+            .lines()
+            .filter_map(|memory_map_line| {
+                let mut fields = memory_map_line.split_whitespace();
+                let (start_address, end_address) = fields.next()?.split_once('-')?;
+                let permissions = fields.next()?;
+                let path = fields.nth(3).unwrap_or_default();
+
+                if !permissions.starts_with("r-x") && !permissions.starts_with("rwx") {
+                    return None;
+                }
+
+                Some((
+                    path != "[vdso]",
+                    u64::from_str_radix(start_address, 16).ok()?,
+                    u64::from_str_radix(end_address, 16).ok()?,
+                ))
+            })
+            .collect::<Vec<_>>();
+        executable_regions.sort();
+
+        for (_, start_address, end_address) in executable_regions {
+            let search_length = end_address
+                .saturating_sub(start_address)
+                .min(MAX_SYSCALL_INSTRUCTION_SEARCH_BYTES);
+            let mut region_bytes = vec![0u8; search_length as usize];
+
+            if self
+                .process_memory
+                .read_exact_at(&mut region_bytes, start_address)
+                .is_err()
+            {
+                continue;
+            }
+
+            let instruction_offset = region_bytes
+                .windows(syscall_instruction.len())
+                .enumerate()
+                .step_by(SYSCALL_INSTRUCTION_ALIGNMENT as usize)
+                .find(|(_, instruction_bytes)| *instruction_bytes == syscall_instruction)
+                .map(|(instruction_offset, _)| instruction_offset as u64);
+
+            if let Some(instruction_offset) = instruction_offset {
+                let syscall_instruction_address = start_address + instruction_offset;
+                self.syscall_instruction_address = Some(syscall_instruction_address);
+
+                return Ok(syscall_instruction_address);
+            }
+        }
+
+        Err(io::Error::new(io::ErrorKind::NotFound, "no system call instruction was found in the process"))
+    }
+
+    fn disarm_all_breakpoints(&mut self) {
+        self.original_bytes_by_address.clear();
+
+        if let Ok(mut breakpoint_table) = self.breakpoint_table.lock() {
+            for breakpoint_state in breakpoint_table.values_mut() {
+                breakpoint_state.breakpoint.disarm();
+            }
+        }
+    }
+
+    /// Restores every patched instruction, then stops and detaches from each thread.
+    fn detach(&mut self) {
+        let armed_addresses = self
+            .original_bytes_by_address
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+
+        for address in armed_addresses {
+            if let Err(error) = self.disarm(address) {
+                log::error!("Failed to restore the instruction at 0x{:X}: {}", address, error);
+            }
+        }
+
+        let mut pending_thread_ids = self.thread_ids.drain().collect::<VecDeque<_>>();
+
+        while let Some(thread_id) = pending_thread_ids.pop_front() {
+            // Suspended threads are already in a ptrace stop, so they can be detached directly.
+            if self.take_suspended_stop_signal(thread_id).is_some() {
+                let _ = Self::ptrace(libc::PTRACE_DETACH, thread_id, 0, 0);
+
+                continue;
+            }
+
+            if Self::ptrace(PTRACE_INTERRUPT, thread_id, 0, 0).is_err() {
+                continue;
+            }
+
+            while let Some(status) = Self::wait_for_thread(thread_id) {
+                match WaitStatus::decode(status) {
+                    Some(WaitStatus::Stopped { signal, ptrace_event }) => {
+                        let pending_signal = match ptrace_event {
+                            libc::PTRACE_EVENT_CLONE => {
+                                if let Ok(new_thread_id) = Self::get_event_message(thread_id) {
+                                    pending_thread_ids.push_back(new_thread_id as u32);
+                                }
+
+                                0
+                            }
+                            libc::PTRACE_EVENT_STOP => 0,
+                            _ if signal == libc::SIGTRAP => {
+                                self.rewind_retired_trap(thread_id);
+
+                                0
+                            }
+                            _ => signal,
+                        };
+
+                        let _ = Self::ptrace(libc::PTRACE_DETACH, thread_id, 0, pending_signal as usize);
+
+                        break;
+                    }
+                    Some(WaitStatus::Exited) => break,
+                    None => {}
+                }
+            }
+        }
+    }
+
+    /// Rewinds a thread that trapped on a breakpoint that has since been restored.
+    fn rewind_retired_trap(
+        &self,
+        thread_id: u32,
+    ) {
+        if let Ok(mut context) = LinuxPtraceContext::read(thread_id) {
+            let breakpoint_address = context.get_breakpoint_address();
+
+            if self.retired_addresses.contains(&breakpoint_address) {
+                let _ = context.set_instruction_pointer(breakpoint_address);
+            }
+        }
+    }
+
+    /// Single steps a thread, returning false if it exited before completing the step.
+    fn single_step(
+        &mut self,
+        thread_id: u32,
+    ) -> bool {
+        let mut pending_signal = 0;
+
+        loop {
+            if let Err(error) = Self::ptrace(libc::PTRACE_SINGLESTEP, thread_id, 0, pending_signal as usize) {
+                log::debug!("Failed to single step thread {}: {}", thread_id, error);
+
+                return false;
+            }
+
+            let Some(status) = Self::wait_for_thread(thread_id) else {
+                return false;
+            };
+
+            match WaitStatus::decode(status) {
+                Some(WaitStatus::Exited) | None => {
+                    self.thread_ids.remove(&thread_id);
+
+                    return false;
+                }
+                Some(WaitStatus::Stopped { signal, ptrace_event }) => {
+                    if ptrace_event == 0 && signal == libc::SIGTRAP {
+                        return true;
+                    }
+
+                    // A signal arrived before the step completed. It is delivered on the next step, which may enter its handler
+                    // and hit the breakpoint again once the handler returns.
+                    pending_signal = if ptrace_event == 0 { signal } else { 0 };
+                }
+            }
+        }
+    }
+
+    fn resume(
+        &mut self,
+        thread_id: u32,
+        signal: c_int,
+    ) {
+        if let Err(error) = Self::ptrace(libc::PTRACE_CONT, thread_id, 0, signal as usize) {
+            if error.raw_os_error() == Some(libc::ESRCH) {
+                self.thread_ids.remove(&thread_id);
+            } else {
+                log::debug!("Failed to resume thread {}: {}", thread_id, error);
+            }
+        }
+    }
+
+    fn wait_for_thread(thread_id: u32) -> Option<c_int> {
+        loop {
+            let mut status = 0;
+            let result = unsafe { libc::waitpid(thread_id as pid_t, &mut status, libc::__WALL) };
+
+            if result == thread_id as pid_t {
+                return Some(status);
+            }
+
+            if result == -1 && io::Error::last_os_error().raw_os_error() != Some(libc::EINTR) {
+                return None;
+            }
+        }
+    }
+
+    fn get_event_message(thread_id: u32) -> io::Result<libc::c_ulong> {
+        let mut event_message: libc::c_ulong = 0;
+
+        Self::ptrace(libc::PTRACE_GETEVENTMSG, thread_id, 0, &mut event_message as *mut libc::c_ulong as usize).map(|_| event_message)
+    }
+
+    fn ptrace(
+        request: PtraceRequest,
+        thread_id: u32,
+        address: usize,
+        data: usize,
+    ) -> io::Result<()> {
+        let result = unsafe { libc::ptrace(request, thread_id as pid_t, address as *mut c_void, data as *mut c_void) };
+
+        if result == -1 { Err(io::Error::last_os_error()) } else { Ok(()) }
+    }
+}
@@ -0,0 +1,102 @@
+use squalr_engine_api::structures::debugging::{register_snapshot::RegisterSnapshot, register_value::RegisterValue};
+
+/// `PERF_SAMPLE_REGS_ABI_32`, reported for 32-bit processes running on a 64-bit kernel.
+const PERF_SAMPLE_REGS_ABI_32: u64 = 1;
+
+/// Whether hardware watchpoints trap after the accessing instruction has retired.
+/// x86 data breakpoints are traps, while ARM reports the accessing instruction itself.
+pub(crate) const IS_TRAP_AFTER_ACCESS: bool = cfg!(any(target_arch = "x86", target_arch = "x86_64"));
+
+// Bit positions follow `enum perf_event_x86_regs`. The segment registers DS, ES, FS, and GS (bits 12 to 15) cannot be
+// sampled from 64-bit kernels, so they are left out of the mask.
+#[cfg(target_arch = "x86_64")]
+const REGISTER_NAMES_64: &[&str] = &[
+    "rax", "rbx", "rcx", "rdx", "rsi", "rdi", "rbp", "rsp", "rip", "rflags", "cs", "ss", "r8", "r9", "r10", "r11", "r12", "r13", "r14", "r15",
+];
+
+#[cfg(target_arch = "x86_64")]
+const REGISTER_NAMES_32: &[&str] = &[
+    "eax", "ebx", "ecx", "edx", "esi", "edi", "ebp", "esp", "eip", "eflags", "cs", "ss", "", "", "", "", "", "", "", "",
+];
+
+#[cfg(target_arch = "x86_64")]
+pub(crate) const SAMPLE_REGS_USER_MASK: u64 = 0x0000_0000_00FF_0FFF;
+
+// Bit positions follow `enum perf_event_arm64_regs`: x0 to x30, then sp and pc. 32-bit processes see r0 to r14 in the low
+// registers and pc in its usual slot.
+#[cfg(target_arch = "aarch64")]
+const REGISTER_NAMES_64: &[&str] = &[
+    "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13", "x14", "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22",
+    "x23", "x24", "x25", "x26", "x27", "x28", "x29", "x30", "sp", "pc",
+];
+
+#[cfg(target_arch = "aarch64")]
+const REGISTER_NAMES_32: &[&str] = &[
+    "r0", "r1", "r2", "r3", "r4", "r5", "r6", "r7", "r8", "r9", "r10", "r11", "r12", "sp", "lr", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "",
+    "", "", "pc",
+];
+
+#[cfg(target_arch = "aarch64")]
+pub(crate) const SAMPLE_REGS_USER_MASK: u64 = (1 << 33) - 1;
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+const REGISTER_NAMES_64: &[&str] = &[];
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+const REGISTER_NAMES_32: &[&str] = &[];
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+pub(crate) const SAMPLE_REGS_USER_MASK: u64 = 0;
+
+/// Builds a register snapshot from the user registers of a perf sample, which are ordered by register bit.
+pub(crate) fn build_register_snapshot(
+    thread_id: u32,
+    instruction_pointer: u64,
+    register_abi: u64,
+    register_values: &[u64],
+) -> RegisterSnapshot {
+    let register_names = if register_abi == PERF_SAMPLE_REGS_ABI_32 {
+        REGISTER_NAMES_32
+    } else {
+        REGISTER_NAMES_64
+    };
+    let registers = register_names
+        .iter()
+        .zip(register_values.iter())
+        .filter(|(register_name, _)| !register_name.is_empty())
+        .map(|(register_name, register_value)| RegisterValue::new(register_name.to_string(), *register_value))
+        .collect::<Vec<_>>();
+    let stack_pointer = ["rsp", "esp", "sp"]
+        .iter()
+        .find_map(|stack_pointer_name| {
+            registers
+                .iter()
+                .find(|register_value| register_value.get_name() == *stack_pointer_name)
+        })
+        .map(RegisterValue::get_value)
+        .unwrap_or(0);
+
+    RegisterSnapshot::new(thread_id, instruction_pointer, stack_pointer, registers)
+}
+
+#[cfg(all(test, target_arch = "x86_64"))]
+mod tests {
+    use super::{SAMPLE_REGS_USER_MASK, build_register_snapshot};
+
+    #[test]
+    fn register_names_match_sampled_register_count() {
+        assert_eq!(SAMPLE_REGS_USER_MASK.count_ones() as usize, super::REGISTER_NAMES_64.len());
+        assert_eq!(super::REGISTER_NAMES_32.len(), super::REGISTER_NAMES_64.len());
+    }
+
+    #[test]
+    fn compat_snapshots_use_32_bit_names_and_skip_extended_registers() {
+        let register_values = (0..20u64).collect::<Vec<_>>();
+        let register_snapshot = build_register_snapshot(7, 0x401000, 1, &register_values);
+
+        assert_eq!(register_snapshot.get_registers().len(), 12);
+        assert_eq!(register_snapshot.get_register("esp"), Some(7));
+        assert_eq!(register_snapshot.get_stack_pointer(), 7);
+        assert_eq!(register_snapshot.get_register("r8"), None);
+    }
+}
@@ -0,0 +1,116 @@
+use std::io;
+
+/// A system call that a thread of a traced process executes on behalf of the engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LinuxRemoteSyscall {
+    /// Maps private anonymous pages, with the address as a placement hint.
+    Mmap {
+        address: u64,
+        size: u64,
+        protection: i32,
+    },
+    Mprotect {
+        address: u64,
+        size: u64,
+        protection: i32,
+    },
+    Munmap {
+        address: u64,
+        size: u64,
+    },
+}
+
+/// System call numbers of 32-bit x86 processes, which enter the kernel through `int 0x80` with their own numbering.
+#[cfg(target_arch = "x86_64")]
+mod compat_syscall_numbers {
+    pub const MMAP2: u64 = 192;
+    pub const MPROTECT: u64 = 125;
+    pub const MUNMAP: u64 = 91;
+}
+
+impl LinuxRemoteSyscall {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Mmap { .. } => "mmap",
+            Self::Mprotect { .. } => "mprotect",
+            Self::Munmap { .. } => "munmap",
+        }
+    }
+
+    /// Resolves the system call number and its six argument registers for the calling convention of the traced thread.
+    pub fn encode(
+        &self,
+        is_compat_mode: bool,
+    ) -> io::Result<(u64, [u64; 6])> {
+        let number = self.resolve_number(is_compat_mode)?;
+        let arguments = match *self {
+            // The fd of an anonymous mapping is -1, and 32-bit kernels read it from a 32-bit register. mmap2 takes its offset
+            // in pages, which is zero either way.
+            Self::Mmap { address, size, protection } => [
+                address,
+                size,
+                protection as u64,
+                (libc::MAP_PRIVATE | libc::MAP_ANONYMOUS) as u64,
+                if is_compat_mode { u32::MAX as u64 } else { u64::MAX },
+                0,
+            ],
+            Self::Mprotect { address, size, protection } => [address, size, protection as u64, 0, 0, 0],
+            Self::Munmap { address, size } => [address, size, 0, 0, 0, 0],
+        };
+
+        Ok((number, arguments))
+    }
+
+    /// Converts a raw return value into a result, as the kernel reports errors as a negated errno in the return register.
+    pub fn decode_result(return_value: i64) -> io::Result<u64> {
+        if (-4095..0).contains(&return_value) {
+            Err(io::Error::from_raw_os_error(-return_value as i32))
+        } else {
+            Ok(return_value as u64)
+        }
+    }
+
+    fn resolve_number(
+        &self,
+        is_compat_mode: bool,
+    ) -> io::Result<u64> {
+        #[cfg(target_arch = "x86_64")]
+        if is_compat_mode {
+            return Ok(match self {
+                Self::Mmap { .. } => compat_syscall_numbers::MMAP2,
+                Self::Mprotect { .. } => compat_syscall_numbers::MPROTECT,
+                Self::Munmap { .. } => compat_syscall_numbers::MUNMAP,
+            });
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        if is_compat_mode {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "32-bit processes are not supported on this architecture",
+            ));
+        }
+
+        Ok(match self {
+            Self::Mmap { .. } => libc::SYS_mmap as u64,
+            Self::Mprotect { .. } => libc::SYS_mprotect as u64,
+            Self::Munmap { .. } => libc::SYS_munmap as u64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinuxRemoteSyscall;
+
+    #[test]
+    fn decode_result_maps_negated_errno_to_errors() {
+        assert_eq!(LinuxRemoteSyscall::decode_result(0x7F00_0000_0000).ok(), Some(0x7F00_0000_0000));
+        assert_eq!(
+            LinuxRemoteSyscall::decode_result(-(libc::ENOMEM as i64))
+                .err()
+                .and_then(|error| error.raw_os_error()),
+            Some(libc::ENOMEM)
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod linux_debugger;
+mod linux_perf_event;
+pub(crate) mod linux_process_launcher;
+mod linux_ptrace_context;
+mod linux_ptrace_session;
+mod linux_register_layout;
+pub(crate) mod linux_remote_syscall;
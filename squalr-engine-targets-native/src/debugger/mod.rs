@@ -0,0 +1,38 @@
+pub mod debugger_trait;
+
+use std::sync::OnceLock;
+
+// Android shares the Linux perf and ptrace interfaces, so it uses the Linux debugger directly.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod linux;
+
+#[cfg(any(target_os = "macos"))]
+mod macos;
+
+#[cfg(target_os = "windows")]
+mod windows;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub use crate::debugger::linux::linux_debugger::LinuxDebugger as DebuggerImpl;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) use crate::debugger::linux::linux_process_launcher::LinuxProcessLauncher;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) use crate::debugger::linux::linux_remote_syscall::LinuxRemoteSyscall;
+
+#[cfg(target_os = "macos")]
+pub use crate::debugger::macos::macos_debugger::MacOsDebugger as DebuggerImpl;
+
+#[cfg(target_os = "windows")]
+pub use crate::debugger::windows::windows_debugger::WindowsDebugger as DebuggerImpl;
+
+pub struct Debugger;
+
+impl Debugger {
+    pub fn get_instance() -> &'static DebuggerImpl {
+        static INSTANCE: OnceLock<DebuggerImpl> = OnceLock::new();
+
+        INSTANCE.get_or_init(DebuggerImpl::new)
+    }
+}
@@ -0,0 +1,96 @@
+use crate::debugger::debugger_trait::DebuggerTrait;
+use squalr_engine_api::structures::debugging::{
+    breakpoint::Breakpoint, breakpoint_hit::BreakpointHit, breakpoint_mode::BreakpointMode, instruction_trace_stop_reason::InstructionTraceStopReason,
+    traced_instruction::TracedInstruction, watchpoint::Watchpoint, watchpoint_access::WatchpointAccess, watchpoint_hit::WatchpointHit,
+    watchpoint_size::WatchpointSize,
+};
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_targets::debugger::debugger_error::DebuggerError;
+use std::time::Duration;
+
+pub struct WindowsDebugger;
+
+impl WindowsDebugger {
+    pub fn new() -> Self {
+        WindowsDebugger
+    }
+}
+
+impl DebuggerTrait for WindowsDebugger {
+    fn set_watchpoint(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        _address: u64,
+        _watchpoint_size: WatchpointSize,
+        _watchpoint_access: WatchpointAccess,
+    ) -> Result<Watchpoint, DebuggerError> {
+        Err(DebuggerError::not_implemented("set_watchpoint", "windows"))
+    }
+
+    fn remove_watchpoint(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError> {
+        Err(DebuggerError::unknown_watchpoint(watchpoint_id))
+    }
+
+    fn get_watchpoint_hits(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError> {
+        Err(DebuggerError::unknown_watchpoint(watchpoint_id))
+    }
+
+    fn get_watchpoints(&self) -> Vec<Watchpoint> {
+        Vec::new()
+    }
+
+    fn set_breakpoint(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        _address: u64,
+        _breakpoint_mode: BreakpointMode,
+    ) -> Result<Breakpoint, DebuggerError> {
+        Err(DebuggerError::not_implemented("set_breakpoint", "windows"))
+    }
+
+    fn remove_breakpoint(
+        &self,
+        breakpoint_id: u64,
+    ) -> Result<Breakpoint, DebuggerError> {
+        Err(DebuggerError::unknown_breakpoint(breakpoint_id))
+    }
+
+    fn get_breakpoint_hits(
+        &self,
+        breakpoint_id: u64,
+        _after_hit_index: u64,
+    ) -> Result<(Breakpoint, Vec<BreakpointHit>), DebuggerError> {
+        Err(DebuggerError::unknown_breakpoint(breakpoint_id))
+    }
+
+    fn get_breakpoints(&self) -> Vec<Breakpoint> {
+        Vec::new()
+    }
+
+    fn call_function(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        _function_address: u64,
+        _arguments: &[u64],
+    ) -> Result<u64, DebuggerError> {
+        Err(DebuggerError::not_implemented("call_function", "windows"))
+    }
+
+    fn trace_instructions(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        _start_address: u64,
+        _end_address: Option<u64>,
+        _thread_id: Option<u32>,
+        _instruction_budget: u64,
+        _start_timeout: Duration,
+    ) -> Result<(Vec<TracedInstruction>, InstructionTraceStopReason), DebuggerError> {
+        Err(DebuggerError::not_implemented("trace_instructions", "windows"))
+    }
+}
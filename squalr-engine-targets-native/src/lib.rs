@@ -1,6 +1,10 @@
 pub mod config;
+pub mod debugger;
+pub mod memory_allocator;
 pub mod memory_queryer;
 pub mod memory_reader;
+pub mod memory_write_tracker;
 pub mod memory_writer;
 pub mod process;
 pub mod process_query;
+pub mod thread_controller;
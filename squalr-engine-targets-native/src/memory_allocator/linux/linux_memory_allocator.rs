@@ -0,0 +1,219 @@
+use crate::debugger::{Debugger, LinuxRemoteSyscall};
+use crate::memory_allocator::memory_allocator_trait::MemoryAllocatorTrait;
+use crate::memory_allocator::page_alignment::align_range_to_pages;
+use squalr_engine_api::structures::memory::{memory_protection::MemoryProtection, normalized_region::NormalizedRegion};
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_targets::debugger::debugger_error::DebuggerError;
+use squalr_engine_targets::memory_allocation::memory_allocation_error::MemoryAllocationError;
+
+/// Allocates and protects memory by having a stopped thread of the target run `mmap`, `mprotect` and `munmap` through ptrace.
+pub struct LinuxMemoryAllocator {
+    page_size: u64,
+}
+
+impl LinuxMemoryAllocator {
+    pub fn new() -> Self {
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+
+        LinuxMemoryAllocator {
+            page_size: if page_size > 0 { page_size as u64 } else { 0x1000 },
+        }
+    }
+
+    fn to_prot_flags(protection: MemoryProtection) -> i32 {
+        let mut prot_flags = libc::PROT_NONE;
+
+        if protection.is_readable() {
+            prot_flags |= libc::PROT_READ;
+        }
+
+        if protection.is_writable() {
+            prot_flags |= libc::PROT_WRITE;
+        }
+
+        if protection.is_executable() {
+            prot_flags |= libc::PROT_EXEC;
+        }
+
+        prot_flags
+    }
+
+    fn execute_remote_syscall(
+        process_info: &OpenedProcessInfo,
+        remote_syscall: LinuxRemoteSyscall,
+    ) -> Result<u64, MemoryAllocationError> {
+        Debugger::get_instance()
+            .execute_remote_syscall(process_info.get_process_id_raw(), remote_syscall)
+            .map_err(|error| match error {
+                DebuggerError::AttachFailed { process_id, details } => MemoryAllocationError::attach_failed(process_id, details),
+                DebuggerError::Internal { details, .. } => MemoryAllocationError::operation_failed(remote_syscall.get_name(), details),
+                error => MemoryAllocationError::operation_failed(remote_syscall.get_name(), error.to_string()),
+            })
+    }
+}
+
+impl MemoryAllocatorTrait for LinuxMemoryAllocator {
+    fn allocate(
+        &self,
+        process_info: &OpenedProcessInfo,
+        preferred_address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        // The preferred address is only a hint, so the kernel is free to place the mapping elsewhere.
+        let hint_region = align_range_to_pages(preferred_address, size, self.page_size)?;
+        let base_address = Self::execute_remote_syscall(
+            process_info,
+            LinuxRemoteSyscall::Mmap {
+                address: hint_region.get_base_address(),
+                size: hint_region.get_region_size(),
+                protection: Self::to_prot_flags(protection),
+            },
+        )?;
+
+        Ok(NormalizedRegion::new(base_address, hint_region.get_region_size()))
+    }
+
+    fn free(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        let region = align_range_to_pages(address, size, self.page_size)?;
+
+        Self::execute_remote_syscall(
+            process_info,
+            LinuxRemoteSyscall::Munmap {
+                address: region.get_base_address(),
+                size: region.get_region_size(),
+            },
+        )?;
+
+        Ok(region)
+    }
+
+    fn protect(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        let region = align_range_to_pages(address, size, self.page_size)?;
+
+        Self::execute_remote_syscall(
+            process_info,
+            LinuxRemoteSyscall::Mprotect {
+                address: region.get_base_address(),
+                size: region.get_region_size(),
+                protection: Self::to_prot_flags(protection),
+            },
+        )?;
+
+        Ok(region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinuxMemoryAllocator;
+    use crate::memory_allocator::memory_allocator_trait::MemoryAllocatorTrait;
+    use squalr_engine_api::structures::memory::{bitness::Bitness, memory_protection::MemoryProtection};
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+    use std::fs;
+    use std::os::unix::fs::FileExt;
+
+    /// Kills a forked child when dropped, so a failed assertion does not leave it running.
+    struct ChildProcessGuard(libc::pid_t);
+
+    impl Drop for ChildProcessGuard {
+        fn drop(&mut self) {
+            unsafe {
+                libc::kill(self.0, libc::SIGKILL);
+                libc::waitpid(self.0, std::ptr::null_mut(), 0);
+            }
+        }
+    }
+
+    fn find_mapping_permissions(
+        process_id: libc::pid_t,
+        address: u64,
+    ) -> Option<String> {
+        let maps = fs::read_to_string(format!("/proc/{}/maps", process_id)).ok()?;
+
+        maps.lines().find_map(|line| {
+            let mut fields = line.split_whitespace();
+            let (start_address, end_address) = fields.next()?.split_once('-')?;
+            let start_address = u64::from_str_radix(start_address, 16).ok()?;
+            let end_address = u64::from_str_radix(end_address, 16).ok()?;
+
+            (start_address..end_address)
+                .contains(&address)
+                .then(|| fields.next().map(|permissions| permissions[..3].to_string()))
+                .flatten()
+        })
+    }
+
+    #[test]
+    #[ignore = "requires ptrace"]
+    fn allocated_memory_can_be_written_reprotected_and_freed() {
+        let child_process_id = unsafe { libc::fork() };
+        assert!(child_process_id >= 0, "fork failed");
+
+        if child_process_id == 0 {
+            loop {
+                unsafe { libc::usleep(1_000) };
+            }
+        }
+
+        let _child_process_guard = ChildProcessGuard(child_process_id);
+        let linux_memory_allocator = LinuxMemoryAllocator::new();
+        let process_info = OpenedProcessInfo::new(child_process_id as u32, String::from("child"), 0, Bitness::Bit64, None);
+        let region = match linux_memory_allocator.allocate(&process_info, 0, 0x10, MemoryProtection::READ_WRITE_EXECUTE) {
+            Ok(region) => region,
+            Err(error) => panic!("Unexpected allocation error: {}", error),
+        };
+
+        assert_eq!(region.get_region_size(), linux_memory_allocator.page_size);
+        assert_eq!(find_mapping_permissions(child_process_id, region.get_base_address()).as_deref(), Some("rwx"));
+
+        let process_memory = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(format!("/proc/{}/mem", child_process_id))
+            .expect("Expected the child memory to open.");
+        let mut read_bytes = [0u8; 4];
+
+        process_memory
+            .write_all_at(&[0xDE, 0xAD, 0xBE, 0xEF], region.get_base_address())
+            .expect("Expected the allocation to be writable.");
+        process_memory
+            .read_exact_at(&mut read_bytes, region.get_base_address())
+            .expect("Expected the allocation to be readable.");
+
+        assert_eq!(read_bytes, [0xDE, 0xAD, 0xBE, 0xEF]);
+
+        linux_memory_allocator
+            .protect(
+                &process_info,
+                region.get_base_address(),
+                region.get_region_size(),
+                MemoryProtection::READ_EXECUTE,
+            )
+            .expect("Expected the allocation to be reprotected.");
+
+        assert_eq!(find_mapping_permissions(child_process_id, region.get_base_address()).as_deref(), Some("r-x"));
+
+        linux_memory_allocator
+            .free(&process_info, region.get_base_address(), region.get_region_size())
+            .expect("Expected the allocation to be freed.");
+
+        assert_eq!(find_mapping_permissions(child_process_id, region.get_base_address()), None);
+        assert_eq!(
+            unsafe { libc::kill(child_process_id, 0) },
+            0,
+            "The child should survive the remote system calls."
+        );
+    }
+}
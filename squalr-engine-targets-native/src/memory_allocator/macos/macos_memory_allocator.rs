@@ -0,0 +1,135 @@
+use crate::memory_allocator::memory_allocator_trait::MemoryAllocatorTrait;
+use crate::memory_allocator::page_alignment::align_range_to_pages;
+use mach2::kern_return::{KERN_SUCCESS, kern_return_t};
+use mach2::vm::{mach_vm_allocate, mach_vm_deallocate, mach_vm_protect};
+use mach2::vm_prot::{VM_PROT_EXECUTE, VM_PROT_NONE, VM_PROT_READ, VM_PROT_WRITE, vm_prot_t};
+use mach2::vm_statistics::{VM_FLAGS_ANYWHERE, VM_FLAGS_FIXED};
+use mach2::vm_types::{mach_vm_address_t, mach_vm_size_t};
+use squalr_engine_api::structures::memory::{memory_protection::MemoryProtection, normalized_region::NormalizedRegion};
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_targets::memory_allocation::memory_allocation_error::MemoryAllocationError;
+
+pub struct MacOsMemoryAllocator {
+    page_size: u64,
+}
+
+impl MacOsMemoryAllocator {
+    pub fn new() -> Self {
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+
+        MacOsMemoryAllocator {
+            page_size: if page_size > 0 { page_size as u64 } else { 0x4000 },
+        }
+    }
+
+    fn to_vm_protection(protection: MemoryProtection) -> vm_prot_t {
+        let mut vm_protection = VM_PROT_NONE;
+
+        if protection.is_readable() {
+            vm_protection |= VM_PROT_READ;
+        }
+
+        if protection.is_writable() {
+            vm_protection |= VM_PROT_WRITE;
+        }
+
+        if protection.is_executable() {
+            vm_protection |= VM_PROT_EXECUTE;
+        }
+
+        vm_protection
+    }
+
+    fn check_kern_return(
+        operation: &'static str,
+        kern_return: kern_return_t,
+    ) -> Result<(), MemoryAllocationError> {
+        if kern_return == KERN_SUCCESS {
+            Ok(())
+        } else {
+            Err(MemoryAllocationError::operation_failed(operation, format!("kern_return_t {}", kern_return)))
+        }
+    }
+
+    fn protect_region(
+        process_handle: u64,
+        region: &NormalizedRegion,
+        protection: MemoryProtection,
+    ) -> Result<(), MemoryAllocationError> {
+        let kern_return = unsafe {
+            mach_vm_protect(
+                process_handle as _,
+                region.get_base_address() as mach_vm_address_t,
+                region.get_region_size() as mach_vm_size_t,
+                0,
+                Self::to_vm_protection(protection),
+            )
+        };
+
+        Self::check_kern_return("mach_vm_protect", kern_return)
+    }
+}
+
+impl MemoryAllocatorTrait for MacOsMemoryAllocator {
+    fn allocate(
+        &self,
+        process_info: &OpenedProcessInfo,
+        preferred_address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        let hint_region = align_range_to_pages(preferred_address, size, self.page_size)?;
+        let process_handle = process_info.get_handle();
+        let mut base_address = hint_region.get_base_address() as mach_vm_address_t;
+        let mut kern_return = !KERN_SUCCESS;
+
+        // The preferred address is only a hint, so retry anywhere when that range is already taken.
+        if preferred_address != 0 {
+            kern_return = unsafe { mach_vm_allocate(process_handle as _, &mut base_address, hint_region.get_region_size(), VM_FLAGS_FIXED) };
+        }
+
+        if kern_return != KERN_SUCCESS {
+            base_address = 0;
+            kern_return = unsafe { mach_vm_allocate(process_handle as _, &mut base_address, hint_region.get_region_size(), VM_FLAGS_ANYWHERE) };
+        }
+
+        Self::check_kern_return("mach_vm_allocate", kern_return)?;
+
+        // New pages start out read-write, so apply the requested protection afterwards.
+        let region = NormalizedRegion::new(base_address, hint_region.get_region_size());
+
+        if protection != MemoryProtection::READ_WRITE {
+            Self::protect_region(process_handle, &region, protection)?;
+        }
+
+        Ok(region)
+    }
+
+    fn free(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        let region = align_range_to_pages(address, size, self.page_size)?;
+        let kern_return = unsafe { mach_vm_deallocate(process_info.get_handle() as _, region.get_base_address(), region.get_region_size()) };
+
+        Self::check_kern_return("mach_vm_deallocate", kern_return)?;
+
+        Ok(region)
+    }
+
+    fn protect(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        let region = align_range_to_pages(address, size, self.page_size)?;
+
+        Self::protect_region(process_info.get_handle(), &region, protection)?;
+
+        Ok(region)
+    }
+}
@@ -0,0 +1 @@
+pub mod macos_memory_allocator;
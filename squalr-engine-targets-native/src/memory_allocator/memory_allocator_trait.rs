@@ -0,0 +1,28 @@
+use squalr_engine_api::structures::memory::{memory_protection::MemoryProtection, normalized_region::NormalizedRegion};
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_targets::memory_allocation::memory_allocation_error::MemoryAllocationError;
+
+pub trait MemoryAllocatorTrait {
+    fn allocate(
+        &self,
+        process_info: &OpenedProcessInfo,
+        preferred_address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError>;
+
+    fn free(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+    ) -> Result<NormalizedRegion, MemoryAllocationError>;
+
+    fn protect(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError>;
+}
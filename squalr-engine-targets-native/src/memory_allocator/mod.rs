@@ -0,0 +1,33 @@
+pub mod memory_allocator_trait;
+pub mod page_alignment;
+
+use std::sync::OnceLock;
+
+// Android shares the Linux ptrace interface, so it uses the Linux allocator directly.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod linux;
+
+#[cfg(any(target_os = "macos"))]
+mod macos;
+
+#[cfg(target_os = "windows")]
+mod windows;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub use crate::memory_allocator::linux::linux_memory_allocator::LinuxMemoryAllocator as MemoryAllocatorImpl;
+
+#[cfg(target_os = "macos")]
+pub use crate::memory_allocator::macos::macos_memory_allocator::MacOsMemoryAllocator as MemoryAllocatorImpl;
+
+#[cfg(target_os = "windows")]
+pub use crate::memory_allocator::windows::windows_memory_allocator::WindowsMemoryAllocator as MemoryAllocatorImpl;
+
+pub struct MemoryAllocator;
+
+impl MemoryAllocator {
+    pub fn get_instance() -> &'static MemoryAllocatorImpl {
+        static INSTANCE: OnceLock<MemoryAllocatorImpl> = OnceLock::new();
+
+        INSTANCE.get_or_init(MemoryAllocatorImpl::new)
+    }
+}
@@ -0,0 +1,38 @@
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_targets::memory_allocation::memory_allocation_error::MemoryAllocationError;
+
+/// Expands a range to the whole pages that cover it, since the OS allocates and protects memory in pages.
+pub fn align_range_to_pages(
+    address: u64,
+    size: u64,
+    page_size: u64,
+) -> Result<NormalizedRegion, MemoryAllocationError> {
+    if size == 0 || !page_size.is_power_of_two() {
+        return Err(MemoryAllocationError::invalid_range(address, size));
+    }
+
+    let page_mask = page_size - 1;
+    let base_address = address & !page_mask;
+    let end_address = address
+        .checked_add(size)
+        .and_then(|end_address| end_address.checked_add(page_mask))
+        .map(|end_address| end_address & !page_mask)
+        .ok_or_else(|| MemoryAllocationError::invalid_range(address, size))?;
+
+    Ok(NormalizedRegion::new(base_address, end_address - base_address))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::align_range_to_pages;
+
+    #[test]
+    fn align_range_to_pages_covers_every_touched_page() {
+        let region = align_range_to_pages(0x1FF0, 0x20, 0x1000).expect("Expected range to align.");
+
+        assert_eq!(region.get_base_address(), 0x1000);
+        assert_eq!(region.get_region_size(), 0x2000);
+        assert!(align_range_to_pages(0x1000, 0, 0x1000).is_err());
+        assert!(align_range_to_pages(u64::MAX - 0x10, 0x20, 0x1000).is_err());
+    }
+}
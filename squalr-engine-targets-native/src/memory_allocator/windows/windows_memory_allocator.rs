@@ -0,0 +1,140 @@
+use crate::memory_allocator::memory_allocator_trait::MemoryAllocatorTrait;
+use crate::memory_allocator::page_alignment::align_range_to_pages;
+use squalr_engine_api::structures::memory::{memory_protection::MemoryProtection, normalized_region::NormalizedRegion};
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_targets::memory_allocation::memory_allocation_error::MemoryAllocationError;
+use std::io;
+use std::os::raw::c_void;
+use std::ptr::null;
+use windows_sys::Win32::System::Memory::{
+    MEM_COMMIT, MEM_RELEASE, MEM_RESERVE, PAGE_EXECUTE, PAGE_EXECUTE_READ, PAGE_EXECUTE_READWRITE, PAGE_NOACCESS, PAGE_READONLY, PAGE_READWRITE,
+    VirtualAllocEx, VirtualFreeEx, VirtualProtectEx,
+};
+use windows_sys::Win32::System::SystemInformation::{GetSystemInfo, SYSTEM_INFO};
+
+pub struct WindowsMemoryAllocator {
+    page_size: u64,
+}
+
+impl WindowsMemoryAllocator {
+    pub fn new() -> Self {
+        let mut system_info: SYSTEM_INFO = unsafe { std::mem::zeroed() };
+
+        unsafe { GetSystemInfo(&mut system_info) };
+
+        WindowsMemoryAllocator {
+            page_size: if system_info.dwPageSize > 0 { system_info.dwPageSize as u64 } else { 0x1000 },
+        }
+    }
+
+    /// Windows has no write-only pages, so writable protections always include read access.
+    fn to_page_protection(protection: MemoryProtection) -> u32 {
+        match (
+            protection.is_readable() || protection.is_writable(),
+            protection.is_writable(),
+            protection.is_executable(),
+        ) {
+            (_, true, true) => PAGE_EXECUTE_READWRITE,
+            (true, false, true) => PAGE_EXECUTE_READ,
+            (false, false, true) => PAGE_EXECUTE,
+            (_, true, false) => PAGE_READWRITE,
+            (true, false, false) => PAGE_READONLY,
+            (false, false, false) => PAGE_NOACCESS,
+        }
+    }
+}
+
+impl MemoryAllocatorTrait for WindowsMemoryAllocator {
+    fn allocate(
+        &self,
+        process_info: &OpenedProcessInfo,
+        preferred_address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        let hint_region = align_range_to_pages(preferred_address, size, self.page_size)?;
+        let process_handle = process_info.get_handle() as *mut c_void;
+        let region_size = hint_region.get_region_size() as usize;
+        let page_protection = Self::to_page_protection(protection);
+        let mut base_address = null::<c_void>() as *mut c_void;
+
+        // The preferred address is only a hint, so retry anywhere when that range is already taken.
+        if preferred_address != 0 {
+            base_address = unsafe {
+                VirtualAllocEx(
+                    process_handle,
+                    hint_region.get_base_address() as *const c_void,
+                    region_size,
+                    MEM_COMMIT | MEM_RESERVE,
+                    page_protection,
+                )
+            };
+        }
+
+        if base_address.is_null() {
+            base_address = unsafe { VirtualAllocEx(process_handle, null(), region_size, MEM_COMMIT | MEM_RESERVE, page_protection) };
+        }
+
+        if base_address.is_null() {
+            return Err(MemoryAllocationError::operation_failed(
+                "VirtualAllocEx",
+                io::Error::last_os_error().to_string(),
+            ));
+        }
+
+        Ok(NormalizedRegion::new(base_address as u64, hint_region.get_region_size()))
+    }
+
+    fn free(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        let region = align_range_to_pages(address, size, self.page_size)?;
+
+        // MEM_RELEASE frees the whole reservation and requires a size of zero.
+        if unsafe {
+            VirtualFreeEx(
+                process_info.get_handle() as *mut c_void,
+                region.get_base_address() as *mut c_void,
+                0,
+                MEM_RELEASE,
+            )
+        } == 0
+        {
+            return Err(MemoryAllocationError::operation_failed("VirtualFreeEx", io::Error::last_os_error().to_string()));
+        }
+
+        Ok(region)
+    }
+
+    fn protect(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        let region = align_range_to_pages(address, size, self.page_size)?;
+        let mut old_protection = 0;
+        let success = unsafe {
+            VirtualProtectEx(
+                process_info.get_handle() as *mut c_void,
+                region.get_base_address() as *const c_void,
+                region.get_region_size() as usize,
+                Self::to_page_protection(protection),
+                &mut old_protection,
+            )
+        };
+
+        if success == 0 {
+            return Err(MemoryAllocationError::operation_failed(
+                "VirtualProtectEx",
+                io::Error::last_os_error().to_string(),
+            ));
+        }
+
+        Ok(region)
+    }
+}
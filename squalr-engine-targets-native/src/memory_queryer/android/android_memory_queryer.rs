@@ -318,7 +318,7 @@ impl MemoryQueryerTrait for AndroidMemoryQueryer {
             // If perms has 'x' (execute) and pathname is not empty
             if reg.perms.len() >= 3 && &reg.perms[2..3] == "x" && !reg.pathname.is_empty() {
                 let size = reg.end.saturating_sub(reg.start);
-                modules.push(NormalizedModule::new(&reg.pathname, reg.start, size));
+                modules.push(NormalizedModule::new(&reg.pathname, reg.start, size).with_module_path(&reg.pathname));
             }
         }
         modules
@@ -0,0 +1,353 @@
+/// Reads the dynamic symbol table of an ELF image, as used to find exported functions of libraries mapped into a process.
+///
+/// Only little-endian images are supported, which covers every architecture the engine runs on.
+pub(crate) struct LinuxElfSymbols;
+
+const ELF_MAGIC: &[u8; 4] = b"\x7FELF";
+const ELF_CLASS32: u8 = 1;
+const ELF_CLASS64: u8 = 2;
+const ELF_DATA_LITTLE_ENDIAN: u8 = 1;
+const PT_LOAD: u32 = 1;
+const SHT_DYNSYM: u32 = 11;
+const SHT_GNU_VERSYM: u32 = 0x6FFF_FFFF;
+const STT_FUNC: u8 = 2;
+const STT_GNU_IFUNC: u8 = 10;
+const SHN_UNDEF: u16 = 0;
+
+/// Marks a symbol version that is only kept for binaries linked against an older release of the library.
+const VERSYM_HIDDEN: u16 = 0x8000;
+
+/// Mappings are aligned to pages, so the lowest loaded address of an image is rounded down to one.
+const PAGE_SIZE: u64 = 0x1000;
+
+/// The field layout of the ELF class of an image.
+struct ElfLayout {
+    is_64_bit: bool,
+}
+
+struct ElfSection {
+    section_type: u32,
+    offset: u64,
+    size: u64,
+    link: u32,
+    entry_size: u64,
+}
+
+impl ElfLayout {
+    fn read_word(
+        &self,
+        bytes: &[u8],
+        offset: u64,
+    ) -> Option<u64> {
+        if self.is_64_bit {
+            read_u64(bytes, offset)
+        } else {
+            read_u32(bytes, offset).map(u64::from)
+        }
+    }
+}
+
+impl LinuxElfSymbols {
+    /// Finds an exported function, returning its offset from the lowest mapped address of the image. When a library exports
+    /// several versions of a function, the default version is preferred.
+    pub fn find_function_offset(
+        image_bytes: &[u8],
+        symbol_name: &str,
+    ) -> Option<u64> {
+        let layout = Self::read_layout(image_bytes)?;
+        let sections = Self::read_sections(image_bytes, &layout)?;
+        let lowest_load_address = Self::read_lowest_load_address(image_bytes, &layout)?;
+        let (dynamic_symbol_index, dynamic_symbols) = sections
+            .iter()
+            .enumerate()
+            .find(|(_, section)| section.section_type == SHT_DYNSYM)?;
+        let string_table = sections.get(dynamic_symbols.link as usize)?;
+        let version_table = sections
+            .iter()
+            .find(|section| section.section_type == SHT_GNU_VERSYM && section.link as usize == dynamic_symbol_index);
+        let symbol_size = if layout.is_64_bit { 24 } else { 16 };
+
+        if dynamic_symbols.entry_size != 0 && dynamic_symbols.entry_size != symbol_size {
+            return None;
+        }
+
+        let mut hidden_version_value = None;
+
+        for symbol_index in 0..dynamic_symbols.size / symbol_size {
+            let symbol_offset = dynamic_symbols.offset + symbol_index * symbol_size;
+            let name_offset = read_u32(image_bytes, symbol_offset)? as u64;
+            let (info, section_index, value) = if layout.is_64_bit {
+                (
+                    *image_bytes.get((symbol_offset + 4) as usize)?,
+                    read_u16(image_bytes, symbol_offset + 6)?,
+                    read_u64(image_bytes, symbol_offset + 8)?,
+                )
+            } else {
+                (
+                    *image_bytes.get((symbol_offset + 12) as usize)?,
+                    read_u16(image_bytes, symbol_offset + 14)?,
+                    read_u32(image_bytes, symbol_offset + 4)? as u64,
+                )
+            };
+            let symbol_type = info & 0xF;
+
+            if section_index == SHN_UNDEF || !matches!(symbol_type, STT_FUNC | STT_GNU_IFUNC) {
+                continue;
+            }
+
+            if name_offset >= string_table.size || read_c_string(image_bytes, string_table.offset + name_offset)? != symbol_name.as_bytes() {
+                continue;
+            }
+
+            let is_hidden_version = version_table
+                .and_then(|version_table| read_u16(image_bytes, version_table.offset + symbol_index * 2))
+                .is_some_and(|version| version & VERSYM_HIDDEN != 0);
+
+            if !is_hidden_version {
+                return value.checked_sub(lowest_load_address);
+            }
+
+            hidden_version_value.get_or_insert(value);
+        }
+
+        hidden_version_value.and_then(|value| value.checked_sub(lowest_load_address))
+    }
+
+    fn read_layout(image_bytes: &[u8]) -> Option<ElfLayout> {
+        if image_bytes.get(..4)? != ELF_MAGIC || *image_bytes.get(5)? != ELF_DATA_LITTLE_ENDIAN {
+            return None;
+        }
+
+        match *image_bytes.get(4)? {
+            ELF_CLASS32 => Some(ElfLayout { is_64_bit: false }),
+            ELF_CLASS64 => Some(ElfLayout { is_64_bit: true }),
+            _ => None,
+        }
+    }
+
+    fn read_sections(
+        image_bytes: &[u8],
+        layout: &ElfLayout,
+    ) -> Option<Vec<ElfSection>> {
+        let (section_table_offset, entry_size_offset) = if layout.is_64_bit { (0x28, 0x3A) } else { (0x20, 0x2E) };
+        let section_table_offset = layout.read_word(image_bytes, section_table_offset)?;
+        let section_entry_size = read_u16(image_bytes, entry_size_offset)? as u64;
+        let section_count = read_u16(image_bytes, entry_size_offset + 2)? as u64;
+
+        (0..section_count)
+            .map(|section_index| {
+                let section_offset = section_table_offset + section_index * section_entry_size;
+
+                if layout.is_64_bit {
+                    Some(ElfSection {
+                        section_type: read_u32(image_bytes, section_offset + 4)?,
+                        offset: read_u64(image_bytes, section_offset + 0x18)?,
+                        size: read_u64(image_bytes, section_offset + 0x20)?,
+                        link: read_u32(image_bytes, section_offset + 0x28)?,
+                        entry_size: read_u64(image_bytes, section_offset + 0x38)?,
+                    })
+                } else {
+                    Some(ElfSection {
+                        section_type: read_u32(image_bytes, section_offset + 4)?,
+                        offset: read_u32(image_bytes, section_offset + 0x10)? as u64,
+                        size: read_u32(image_bytes, section_offset + 0x14)? as u64,
+                        link: read_u32(image_bytes, section_offset + 0x18)?,
+                        entry_size: read_u32(image_bytes, section_offset + 0x24)? as u64,
+                    })
+                }
+            })
+            .collect()
+    }
+
+    /// Reads the lowest virtual address of a loadable segment, which is where the first mapping of the image starts.
+    fn read_lowest_load_address(
+        image_bytes: &[u8],
+        layout: &ElfLayout,
+    ) -> Option<u64> {
+        let (program_table_offset, entry_size_offset) = if layout.is_64_bit { (0x20, 0x36) } else { (0x1C, 0x2A) };
+        let program_table_offset = layout.read_word(image_bytes, program_table_offset)?;
+        let program_entry_size = read_u16(image_bytes, entry_size_offset)? as u64;
+        let program_count = read_u16(image_bytes, entry_size_offset + 2)? as u64;
+        let virtual_address_offset = if layout.is_64_bit { 0x10 } else { 0x08 };
+
+        (0..program_count)
+            .filter_map(|program_index| {
+                let program_offset = program_table_offset + program_index * program_entry_size;
+
+                if read_u32(image_bytes, program_offset)? != PT_LOAD {
+                    return None;
+                }
+
+                layout.read_word(image_bytes, program_offset + virtual_address_offset)
+            })
+            .min()
+            .map(|lowest_load_address| lowest_load_address & !(PAGE_SIZE - 1))
+    }
+}
+
+fn read_bytes<const SIZE: usize>(
+    bytes: &[u8],
+    offset: u64,
+) -> Option<[u8; SIZE]> {
+    let start = usize::try_from(offset).ok()?;
+
+    bytes.get(start..start.checked_add(SIZE)?)?.try_into().ok()
+}
+
+fn read_u16(
+    bytes: &[u8],
+    offset: u64,
+) -> Option<u16> {
+    read_bytes(bytes, offset).map(u16::from_le_bytes)
+}
+
+fn read_u32(
+    bytes: &[u8],
+    offset: u64,
+) -> Option<u32> {
+    read_bytes(bytes, offset).map(u32::from_le_bytes)
+}
+
+fn read_u64(
+    bytes: &[u8],
+    offset: u64,
+) -> Option<u64> {
+    read_bytes(bytes, offset).map(u64::from_le_bytes)
+}
+
+fn read_c_string(
+    bytes: &[u8],
+    offset: u64,
+) -> Option<&[u8]> {
+    let string_bytes = bytes.get(usize::try_from(offset).ok()?..)?;
+    let string_length = string_bytes.iter().position(|byte| *byte == 0)?;
+
+    Some(&string_bytes[..string_length])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinuxElfSymbols;
+    use std::fs;
+
+    /// Builds a 64-bit image with one loadable segment at the given address and a dynamic symbol table.
+    fn build_elf64_image(
+        load_address: u64,
+        symbols: &[(&str, u64, u8, u16)],
+        versions: Option<&[u16]>,
+    ) -> Vec<u8> {
+        let mut string_table = vec![0u8];
+        let mut symbol_table = vec![0u8; 24];
+
+        for (symbol_name, symbol_value, symbol_info, section_index) in symbols {
+            let name_offset = string_table.len() as u32;
+            string_table.extend_from_slice(symbol_name.as_bytes());
+            string_table.push(0);
+            symbol_table.extend_from_slice(&name_offset.to_le_bytes());
+            symbol_table.push(*symbol_info);
+            symbol_table.push(0);
+            symbol_table.extend_from_slice(&section_index.to_le_bytes());
+            symbol_table.extend_from_slice(&symbol_value.to_le_bytes());
+            symbol_table.extend_from_slice(&0u64.to_le_bytes());
+        }
+
+        let version_table = versions
+            .map(|versions| {
+                std::iter::once(0u16)
+                    .chain(versions.iter().copied())
+                    .flat_map(u16::to_le_bytes)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let program_table_offset = 0x40u64;
+        let symbol_table_offset = program_table_offset + 56;
+        let string_table_offset = symbol_table_offset + symbol_table.len() as u64;
+        let version_table_offset = string_table_offset + string_table.len() as u64;
+        let section_table_offset = (version_table_offset + version_table.len() as u64 + 7) & !7;
+        let mut sections = vec![
+            (0u32, 0u64, 0u64, 0u32, 0u64),
+            (11, symbol_table_offset, symbol_table.len() as u64, 2, 24),
+            (3, string_table_offset, string_table.len() as u64, 0, 0),
+        ];
+
+        if !version_table.is_empty() {
+            sections.push((0x6FFF_FFFF, version_table_offset, version_table.len() as u64, 1, 2));
+        }
+
+        let mut image = vec![0u8; 0x40];
+        image[..4].copy_from_slice(b"\x7FELF");
+        image[4] = 2;
+        image[5] = 1;
+        image[0x20..0x28].copy_from_slice(&program_table_offset.to_le_bytes());
+        image[0x28..0x30].copy_from_slice(&section_table_offset.to_le_bytes());
+        image[0x36..0x38].copy_from_slice(&56u16.to_le_bytes());
+        image[0x38..0x3A].copy_from_slice(&1u16.to_le_bytes());
+        image[0x3A..0x3C].copy_from_slice(&64u16.to_le_bytes());
+        image[0x3C..0x3E].copy_from_slice(&(sections.len() as u16).to_le_bytes());
+
+        let mut program_header = vec![0u8; 56];
+        program_header[..4].copy_from_slice(&1u32.to_le_bytes());
+        program_header[0x10..0x18].copy_from_slice(&load_address.to_le_bytes());
+        image.extend(program_header);
+        image.extend(symbol_table);
+        image.extend(string_table);
+        image.extend(version_table);
+        image.resize(section_table_offset as usize, 0);
+
+        for (section_type, offset, size, link, entry_size) in sections {
+            let mut section_header = vec![0u8; 64];
+            section_header[4..8].copy_from_slice(&section_type.to_le_bytes());
+            section_header[0x18..0x20].copy_from_slice(&offset.to_le_bytes());
+            section_header[0x20..0x28].copy_from_slice(&size.to_le_bytes());
+            section_header[0x28..0x2C].copy_from_slice(&link.to_le_bytes());
+            section_header[0x38..0x40].copy_from_slice(&entry_size.to_le_bytes());
+            image.extend(section_header);
+        }
+
+        image
+    }
+
+    #[test]
+    fn find_function_offset_is_relative_to_the_first_loaded_page() {
+        let image = build_elf64_image(0x40_0000, &[("puts", 0x40_1234, 0x12, 12), ("dlopen", 0x40_2000, 0x12, 12)], None);
+
+        assert_eq!(LinuxElfSymbols::find_function_offset(&image, "dlopen"), Some(0x2000));
+        assert_eq!(LinuxElfSymbols::find_function_offset(&image, "puts"), Some(0x1234));
+    }
+
+    #[test]
+    fn find_function_offset_skips_imports_and_data() {
+        let image = build_elf64_image(0, &[("dlopen", 0, 0x12, 0), ("environ", 0x5000, 0x11, 20)], None);
+
+        assert_eq!(LinuxElfSymbols::find_function_offset(&image, "dlopen"), None);
+        assert_eq!(LinuxElfSymbols::find_function_offset(&image, "environ"), None);
+    }
+
+    #[test]
+    fn find_function_offset_prefers_the_default_symbol_version() {
+        let image = build_elf64_image(0, &[("dlopen", 0x1000, 0x12, 12), ("dlopen", 0x2000, 0x12, 12)], Some(&[0x8002, 3]));
+
+        assert_eq!(LinuxElfSymbols::find_function_offset(&image, "dlopen"), Some(0x2000));
+    }
+
+    #[test]
+    fn find_function_offset_rejects_other_files() {
+        assert_eq!(LinuxElfSymbols::find_function_offset(b"#!/bin/sh\n", "dlopen"), None);
+    }
+
+    #[test]
+    fn find_function_offset_resolves_exports_of_the_system_libc() {
+        let Some(libc_bytes) = [
+            "/lib/x86_64-linux-gnu/libc.so.6",
+            "/lib/aarch64-linux-gnu/libc.so.6",
+            "/lib64/libc.so.6",
+            "/usr/lib/libc.so.6",
+        ]
+        .iter()
+        .find_map(|libc_path| fs::read(libc_path).ok()) else {
+            return;
+        };
+
+        assert!(LinuxElfSymbols::find_function_offset(&libc_bytes, "malloc").is_some_and(|offset| offset > 0));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::memory_queryer::linux::linux_elf_symbols::LinuxElfSymbols;
 use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
 use crate::memory_queryer::memory_queryer_trait::MemoryQueryerTrait;
 use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
@@ -7,7 +8,7 @@ use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
 use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
@@ -361,7 +362,7 @@ impl LinuxMemoryQueryer {
 
                 let module_name = Self::module_name_from_path(module_path);
 
-                Some(NormalizedModule::new(&module_name, *module_start_address, module_region_size))
+                Some(NormalizedModule::new(&module_name, *module_start_address, module_region_size).with_module_path(module_path))
             })
             .collect();
 
@@ -535,6 +536,21 @@ impl MemoryQueryerTrait for LinuxMemoryQueryer {
 
         0
     }
+
+    fn resolve_module_symbol(
+        &self,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+        symbol_name: &str,
+    ) -> Option<u64> {
+        let module_path = module.get_module_path()?;
+        // Reading through the root of the process finds the same file even when it runs in another mount namespace.
+        let module_bytes = fs::read(format!("/proc/{}/root{}", process_info.get_process_id_raw(), module_path))
+            .or_else(|_| fs::read(module_path))
+            .ok()?;
+
+        LinuxElfSymbols::find_function_offset(&module_bytes, symbol_name)?.checked_add(module.get_base_address())
+    }
 }
 
 #[cfg(test)]
@@ -544,7 +560,9 @@ mod tests {
     use crate::memory_queryer::memory_queryer_trait::MemoryQueryerTrait;
     use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
     use crate::memory_queryer::region_bounds_handling::RegionBoundsHandling;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
     use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 
     #[test]
     fn parse_maps_line_parses_well_formed_rows() {
@@ -698,6 +716,7 @@ mod tests {
         assert_eq!(modules[0].get_module_name(), "squalr");
         assert_eq!(modules[0].get_base_address(), 0x1000);
         assert_eq!(modules[0].get_region_size(), 0x5000);
+        assert_eq!(modules[0].get_module_path(), Some("/tmp/squalr"));
 
         let queryer = LinuxMemoryQueryer::new();
         let (_module_name, module_offset) = queryer
@@ -706,4 +725,22 @@ mod tests {
 
         assert_eq!(module_offset, 0x4800);
     }
+
+    #[test]
+    fn resolve_module_symbol_matches_the_dynamic_linker() {
+        let process_info = OpenedProcessInfo::new(std::process::id(), String::from("self"), 0, Bitness::Bit64, None);
+        let queryer = LinuxMemoryQueryer::new();
+        let modules = queryer.get_modules(&process_info);
+        let Some(libc_module) = modules
+            .iter()
+            .find(|module| module.get_module_name().starts_with("libc.so") || module.get_module_name().starts_with("libc-"))
+        else {
+            // Statically linked test binaries have no libc module to resolve against.
+            return;
+        };
+        let expected_address = unsafe { libc::dlsym(libc::RTLD_DEFAULT, c"getpid".as_ptr()) } as u64;
+
+        assert_eq!(queryer.resolve_module_symbol(&process_info, libc_module, "getpid"), Some(expected_address));
+        assert_eq!(queryer.resolve_module_symbol(&process_info, libc_module, "squalr_missing_symbol"), None);
+    }
 }
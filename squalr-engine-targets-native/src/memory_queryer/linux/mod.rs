@@ -1 +1,2 @@
+pub(crate) mod linux_elf_symbols;
 pub mod linux_memory_queryer;
@@ -396,11 +396,7 @@ impl MemoryQueryerTrait for MacOsMemoryQueryer {
                     return None;
                 }
 
-                Some(NormalizedModule::new(
-                    &Self::module_name_from_path(module_path),
-                    *module_start_address,
-                    module_region_size,
-                ))
+                Some(NormalizedModule::new(&Self::module_name_from_path(module_path), *module_start_address, module_region_size).with_module_path(module_path))
             })
             .collect();
 
@@ -53,6 +53,27 @@ impl MemoryQueryer {
         normalized_regions
     }
 
+    /// Queries the executable pages within an address range, clipped to the range.
+    pub fn query_executable_pages_by_address_range(
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: u64,
+    ) -> Vec<NormalizedRegion> {
+        let required_page_flags = MemoryProtectionEnum::EXECUTE;
+        let excluded_page_flags = MemoryProtectionEnum::empty();
+        let allowed_type_flags = MemoryTypeEnum::NONE | MemoryTypeEnum::PRIVATE | MemoryTypeEnum::IMAGE | MemoryTypeEnum::MAPPED;
+
+        MemoryQueryer::get_instance().get_virtual_pages(
+            process_info,
+            required_page_flags,
+            excluded_page_flags,
+            allowed_type_flags,
+            start_address,
+            end_address,
+            RegionBoundsHandling::Resize,
+        )
+    }
+
     fn query_pages_from_usermode_memory(process_info: &OpenedProcessInfo) -> Vec<NormalizedRegion> {
         let required_page_flags = MemoryProtectionEnum::empty();
         let excluded_page_flags = MemoryProtectionEnum::empty();
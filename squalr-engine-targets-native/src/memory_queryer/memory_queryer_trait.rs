@@ -59,4 +59,14 @@ pub trait MemoryQueryerTrait {
         modules: &Vec<NormalizedModule>,
         identifier: &str,
     ) -> u64;
+
+    /// Resolves an exported function of a loaded module, on platforms that can read the symbol table of the module file.
+    fn resolve_module_symbol(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        _module: &NormalizedModule,
+        _symbol_name: &str,
+    ) -> Option<u64> {
+        None
+    }
 }
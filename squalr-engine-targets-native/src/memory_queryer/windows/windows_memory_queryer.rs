@@ -281,11 +281,9 @@ impl MemoryQueryerTrait for WindowsMemoryQueryer {
                 continue;
             }
 
-            modules.push(NormalizedModule::new(
-                &module_name,
-                module_info.lpBaseOfDll as u64,
-                module_info.SizeOfImage as u64,
-            ));
+            modules.push(
+                NormalizedModule::new(&module_name, module_info.lpBaseOfDll as u64, module_info.SizeOfImage as u64).with_module_path(&module_path),
+            );
         }
 
         modules
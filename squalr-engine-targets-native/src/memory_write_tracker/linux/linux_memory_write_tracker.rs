@@ -0,0 +1,158 @@
+use crate::memory_write_tracker::memory_write_tracker_trait::MemoryWriteTrackerTrait;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_targets::memory_write_tracking::memory_write_tracking_error::MemoryWriteTrackingError;
+use std::fs::{self, File};
+use std::os::unix::fs::FileExt;
+use std::sync::OnceLock;
+
+/// Detects written pages through the kernel soft-dirty bits, which are cleared through `/proc/<pid>/clear_refs` and
+/// reported for each page in `/proc/<pid>/pagemap`.
+pub struct LinuxMemoryWriteTracker {
+    page_size: u64,
+}
+
+impl LinuxMemoryWriteTracker {
+    /// The `clear_refs` command that clears the soft-dirty bit of every page in the process.
+    const CLEAR_SOFT_DIRTY_COMMAND: &'static [u8] = b"4";
+    const PAGEMAP_SOFT_DIRTY_BIT: u64 = 1 << 55;
+    const PAGEMAP_ENTRY_SIZE: u64 = 8;
+    const PAGEMAP_ENTRIES_PER_READ: u64 = 0x1000;
+
+    pub fn new() -> Self {
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+
+        LinuxMemoryWriteTracker {
+            page_size: if page_size > 0 { page_size as u64 } else { 0x1000 },
+        }
+    }
+
+    /// Kernels built without `CONFIG_MEM_SOFT_DIRTY` accept `clear_refs` but never set the bit, which would silently report
+    /// no writes. Freshly faulted pages always start soft-dirty, so touching a new page of our own reveals whether the bit works.
+    fn is_soft_dirty_supported(&self) -> bool {
+        static IS_SOFT_DIRTY_SUPPORTED: OnceLock<bool> = OnceLock::new();
+
+        *IS_SOFT_DIRTY_SUPPORTED.get_or_init(|| {
+            let probe_page = unsafe {
+                libc::mmap(
+                    std::ptr::null_mut(),
+                    self.page_size as usize,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                    -1,
+                    0,
+                )
+            };
+
+            if probe_page == libc::MAP_FAILED {
+                return false;
+            }
+
+            unsafe { std::ptr::write_volatile(probe_page as *mut u8, 1) };
+
+            let mut pagemap_entry = [0u8; Self::PAGEMAP_ENTRY_SIZE as usize];
+            let is_soft_dirty = File::open("/proc/self/pagemap")
+                .and_then(|pagemap| pagemap.read_exact_at(&mut pagemap_entry, probe_page as u64 / self.page_size * Self::PAGEMAP_ENTRY_SIZE))
+                .map(|_| u64::from_ne_bytes(pagemap_entry) & Self::PAGEMAP_SOFT_DIRTY_BIT != 0)
+                .unwrap_or(false);
+
+            unsafe { libc::munmap(probe_page, self.page_size as usize) };
+
+            is_soft_dirty
+        })
+    }
+}
+
+impl MemoryWriteTrackerTrait for LinuxMemoryWriteTracker {
+    fn begin_write_tracking(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), MemoryWriteTrackingError> {
+        if !self.is_soft_dirty_supported() {
+            return Err(MemoryWriteTrackingError::not_supported("the kernel does not track soft-dirty pages"));
+        }
+
+        fs::write(
+            format!("/proc/{}/clear_refs", process_info.get_process_id_raw()),
+            Self::CLEAR_SOFT_DIRTY_COMMAND,
+        )
+        .map_err(|error| MemoryWriteTrackingError::operation_failed("clear_refs", error.to_string()))
+    }
+
+    fn get_written_pages(
+        &self,
+        process_info: &OpenedProcessInfo,
+        memory_regions: &[NormalizedRegion],
+    ) -> Result<Vec<NormalizedRegion>, MemoryWriteTrackingError> {
+        let pagemap = File::open(format!("/proc/{}/pagemap", process_info.get_process_id_raw()))
+            .map_err(|error| MemoryWriteTrackingError::operation_failed("pagemap", error.to_string()))?;
+        let mut pagemap_entries = vec![0u8; (Self::PAGEMAP_ENTRIES_PER_READ * Self::PAGEMAP_ENTRY_SIZE) as usize];
+        let mut written_pages = Vec::new();
+
+        for memory_region in memory_regions {
+            let first_page_index = memory_region.get_base_address() / self.page_size;
+            let end_page_index = memory_region.get_end_address().div_ceil(self.page_size);
+            let mut page_index = first_page_index;
+
+            while page_index < end_page_index {
+                let entry_count = (end_page_index - page_index).min(Self::PAGEMAP_ENTRIES_PER_READ);
+                let entry_bytes = &mut pagemap_entries[..(entry_count * Self::PAGEMAP_ENTRY_SIZE) as usize];
+
+                pagemap
+                    .read_exact_at(entry_bytes, page_index * Self::PAGEMAP_ENTRY_SIZE)
+                    .map_err(|error| MemoryWriteTrackingError::operation_failed("pagemap", error.to_string()))?;
+
+                for (entry_index, pagemap_entry) in entry_bytes
+                    .chunks_exact(Self::PAGEMAP_ENTRY_SIZE as usize)
+                    .enumerate()
+                {
+                    let pagemap_entry = u64::from_ne_bytes(pagemap_entry.try_into().unwrap_or_default());
+
+                    if pagemap_entry & Self::PAGEMAP_SOFT_DIRTY_BIT != 0 {
+                        written_pages.push(NormalizedRegion::new((page_index + entry_index as u64) * self.page_size, self.page_size));
+                    }
+                }
+
+                page_index += entry_count;
+            }
+        }
+
+        Ok(written_pages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinuxMemoryWriteTracker;
+    use crate::memory_write_tracker::memory_write_tracker_trait::MemoryWriteTrackerTrait;
+    use squalr_engine_api::structures::memory::{bitness::Bitness, normalized_region::NormalizedRegion};
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+    #[test]
+    #[ignore = "requires soft-dirty page tracking"]
+    fn written_pages_only_include_pages_written_after_tracking_began() {
+        let linux_memory_write_tracker = LinuxMemoryWriteTracker::new();
+        let page_size = linux_memory_write_tracker.page_size as usize;
+        let mut tracked_pages = vec![0u8; page_size * 4];
+        let tracked_pages_address = tracked_pages.as_ptr() as u64;
+        let first_page_address = tracked_pages_address.next_multiple_of(page_size as u64);
+        let process_info = OpenedProcessInfo::new(std::process::id(), String::from("self"), 0, Bitness::Bit64, None);
+
+        tracked_pages.fill(1);
+
+        if let Err(error) = linux_memory_write_tracker.begin_write_tracking(&process_info) {
+            panic!("Unexpected write tracking error: {}", error);
+        }
+
+        let written_offset = (first_page_address - tracked_pages_address) as usize + page_size + 8;
+
+        unsafe { std::ptr::write_volatile(&mut tracked_pages[written_offset], 2) };
+
+        let written_pages = linux_memory_write_tracker
+            .get_written_pages(&process_info, &[NormalizedRegion::new(first_page_address, page_size as u64 * 3)])
+            .expect("Expected the written pages to be read.");
+
+        assert!(written_pages.contains(&NormalizedRegion::new(first_page_address + page_size as u64, page_size as u64)));
+        assert_eq!(tracked_pages[written_offset], 2);
+    }
+}
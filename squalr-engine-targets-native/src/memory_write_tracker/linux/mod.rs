@@ -0,0 +1 @@
+pub mod linux_memory_write_tracker;
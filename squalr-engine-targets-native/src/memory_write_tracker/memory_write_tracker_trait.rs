@@ -0,0 +1,16 @@
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_targets::memory_write_tracking::memory_write_tracking_error::MemoryWriteTrackingError;
+
+pub trait MemoryWriteTrackerTrait {
+    fn begin_write_tracking(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), MemoryWriteTrackingError>;
+
+    fn get_written_pages(
+        &self,
+        process_info: &OpenedProcessInfo,
+        memory_regions: &[NormalizedRegion],
+    ) -> Result<Vec<NormalizedRegion>, MemoryWriteTrackingError>;
+}
@@ -0,0 +1,32 @@
+pub mod memory_write_tracker_trait;
+
+use std::sync::OnceLock;
+
+// Android shares the Linux procfs interface, so it uses the Linux write tracker directly.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod linux;
+
+#[cfg(any(target_os = "macos"))]
+mod macos;
+
+#[cfg(target_os = "windows")]
+mod windows;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub use crate::memory_write_tracker::linux::linux_memory_write_tracker::LinuxMemoryWriteTracker as MemoryWriteTrackerImpl;
+
+#[cfg(target_os = "macos")]
+pub use crate::memory_write_tracker::macos::macos_memory_write_tracker::MacOsMemoryWriteTracker as MemoryWriteTrackerImpl;
+
+#[cfg(target_os = "windows")]
+pub use crate::memory_write_tracker::windows::windows_memory_write_tracker::WindowsMemoryWriteTracker as MemoryWriteTrackerImpl;
+
+pub struct MemoryWriteTracker;
+
+impl MemoryWriteTracker {
+    pub fn get_instance() -> &'static MemoryWriteTrackerImpl {
+        static INSTANCE: OnceLock<MemoryWriteTrackerImpl> = OnceLock::new();
+
+        INSTANCE.get_or_init(MemoryWriteTrackerImpl::new)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::memory_write_tracker::memory_write_tracker_trait::MemoryWriteTrackerTrait;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_targets::memory_write_tracking::memory_write_tracking_error::MemoryWriteTrackingError;
+
+pub struct WindowsMemoryWriteTracker;
+
+impl WindowsMemoryWriteTracker {
+    pub fn new() -> Self {
+        WindowsMemoryWriteTracker
+    }
+}
+
+impl MemoryWriteTrackerTrait for WindowsMemoryWriteTracker {
+    fn begin_write_tracking(
+        &self,
+        _process_info: &OpenedProcessInfo,
+    ) -> Result<(), MemoryWriteTrackingError> {
+        Err(MemoryWriteTrackingError::not_implemented("begin_write_tracking", "windows"))
+    }
+
+    fn get_written_pages(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        _memory_regions: &[NormalizedRegion],
+    ) -> Result<Vec<NormalizedRegion>, MemoryWriteTrackingError> {
+        Err(MemoryWriteTrackingError::not_implemented("get_written_pages", "windows"))
+    }
+}
@@ -1,6 +1,8 @@
 use crate::memory_writer::memory_writer_trait::MemoryWriterTrait;
 use libc::{c_void, iovec, pid_t, process_vm_writev};
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::fs::OpenOptions;
+use std::os::unix::fs::FileExt;
 
 pub struct LinuxMemoryWriter;
 
@@ -32,6 +34,23 @@ impl LinuxMemoryWriter {
 
         bytes_written == source_bytes.len() as isize
     }
+
+    /// Writes through the proc mem file, which the kernel performs on our behalf regardless of page protections.
+    fn write_proc_mem(
+        process_id: u32,
+        destination_address: u64,
+        source_bytes: &[u8],
+    ) -> bool {
+        match OpenOptions::new()
+            .write(true)
+            .open(format!("/proc/{}/mem", process_id))
+        {
+            Ok(proc_mem_file) => proc_mem_file
+                .write_all_at(source_bytes, destination_address)
+                .is_ok(),
+            Err(_) => false,
+        }
+    }
 }
 
 impl MemoryWriterTrait for LinuxMemoryWriter {
@@ -43,4 +62,15 @@ impl MemoryWriterTrait for LinuxMemoryWriter {
     ) -> bool {
         Self::write_process_memory(process_info.get_process_id_raw(), address, values)
     }
+
+    fn write_bytes_ignoring_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        // Code pages are usually read-only, which process_vm_writev honors, so fall back to the proc mem file.
+        Self::write_process_memory(process_info.get_process_id_raw(), address, values)
+            || Self::write_proc_mem(process_info.get_process_id_raw(), address, values)
+    }
 }
@@ -7,4 +7,15 @@ pub trait MemoryWriterTrait {
         address: u64,
         values: &[u8],
     ) -> bool;
+
+    /// Writes bytes even where the page protection forbids it, such as into read-only code. Platforms whose regular writes
+    /// already do this fall back to `write_bytes`.
+    fn write_bytes_ignoring_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        self.write_bytes(process_info, address, values)
+    }
 }
@@ -1,4 +1,5 @@
 use crate::process_query::android::android_process_info::AndroidProcessInfo;
+use crate::process_query::process_launch_options::ProcessLaunchOptions;
 use crate::process_query::process_query_error::ProcessQueryError;
 use crate::process_query::process_query_options::ProcessQueryOptions;
 use crate::process_query::process_queryer::ProcessQueryer;
@@ -622,6 +623,11 @@ impl ProcessQueryer for AndroidProcessQuery {
         ))
     }
 
+    // Android apps are started through the activity manager rather than executed directly.
+    fn launch_process(_process_launch_options: &ProcessLaunchOptions) -> Result<OpenedProcessInfo, ProcessQueryError> {
+        Err(ProcessQueryError::not_implemented("launch_process", "android"))
+    }
+
     // Android has no concept of closing a process.
     fn close_process(_handle: u64) -> Result<(), ProcessQueryError> {
         Ok(())
@@ -1,6 +1,9 @@
+use crate::debugger::LinuxProcessLauncher;
+use crate::process_query::process_launch_options::ProcessLaunchOptions;
 use crate::process_query::process_query_error::ProcessQueryError;
 use crate::process_query::process_query_options::ProcessQueryOptions;
 use crate::process_query::process_queryer::ProcessQueryer;
+use crate::thread_controller::ThreadController;
 use image::ImageReader;
 use resvg::{tiny_skia, usvg};
 use squalr_engine_api::structures::memory::bitness::Bitness;
@@ -11,7 +14,7 @@ use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io::Cursor;
 use std::path::{Path, PathBuf};
-use sysinfo::{ProcessRefreshKind, RefreshKind, System};
+use sysinfo::{Pid, ProcessRefreshKind, RefreshKind, System};
 
 pub struct LinuxProcessQuery;
 
@@ -776,6 +779,33 @@ impl ProcessQueryer for LinuxProcessQuery {
         ))
     }
 
+    fn launch_process(process_launch_options: &ProcessLaunchOptions) -> Result<OpenedProcessInfo, ProcessQueryError> {
+        let executable_path = process_launch_options.executable_path.display().to_string();
+        let process_id = LinuxProcessLauncher::launch(process_launch_options)
+            .map_err(|error| ProcessQueryError::launch_process_failed(executable_path.clone(), error.to_string()))?;
+        let process_info = Self::get_processes(ProcessQueryOptions {
+            required_process_id: Some(Pid::from_u32(process_id)),
+            search_name: None,
+            require_windowed: false,
+            match_case: false,
+            fetch_icons: true,
+            limit: Some(1),
+        })
+        .into_iter()
+        .next()
+        .ok_or_else(|| ProcessQueryError::launch_process_failed(executable_path.clone(), format!("process {} exited after launch", process_id)))?;
+        let opened_process_info = Self::open_process(&process_info).inspect_err(|_error| unsafe {
+            libc::kill(process_id as libc::pid_t, libc::SIGKILL);
+        })?;
+
+        // The launcher leaves a suspended process in a SIGSTOP group-stop, which resuming the process has to continue.
+        if process_launch_options.is_suspended {
+            ThreadController::get_instance().track_signal_stopped_process(process_id);
+        }
+
+        Ok(opened_process_info)
+    }
+
     fn close_process(_handle: u64) -> Result<(), ProcessQueryError> {
         Ok(())
     }
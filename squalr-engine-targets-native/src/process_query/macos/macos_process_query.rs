@@ -1,3 +1,4 @@
+use crate::process_query::process_launch_options::ProcessLaunchOptions;
 use crate::process_query::process_query_error::ProcessQueryError;
 use crate::process_query::process_query_options::ProcessQueryOptions;
 use crate::process_query::process_queryer::ProcessQueryer;
@@ -467,6 +468,10 @@ impl ProcessQueryer for MacOsProcessQuery {
         ))
     }
 
+    fn launch_process(_process_launch_options: &ProcessLaunchOptions) -> Result<OpenedProcessInfo, ProcessQueryError> {
+        Err(ProcessQueryError::not_implemented("launch_process", "macos"))
+    }
+
     fn close_process(handle: u64) -> Result<(), ProcessQueryError> {
         if handle == 0 {
             return Ok(());
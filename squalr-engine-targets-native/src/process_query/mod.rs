@@ -1,3 +1,4 @@
+pub mod process_launch_options;
 pub mod process_query_error;
 pub mod process_query_options;
 pub mod process_queryer;
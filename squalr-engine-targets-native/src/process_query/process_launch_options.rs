@@ -0,0 +1 @@
+pub use squalr_engine_targets::process_query::process_launch_options::ProcessLaunchOptions;
@@ -1,3 +1,4 @@
+use crate::process_query::process_launch_options::ProcessLaunchOptions;
 use crate::process_query::process_query_error::ProcessQueryError;
 use crate::process_query::process_query_options::ProcessQueryOptions;
 use squalr_engine_api::structures::processes::{opened_process_info::OpenedProcessInfo, process_info::ProcessInfo};
@@ -6,6 +7,7 @@ pub(crate) trait ProcessQueryer {
     fn start_monitoring() -> Result<(), ProcessQueryError>;
     fn stop_monitoring() -> Result<(), ProcessQueryError>;
     fn open_process(process_info: &ProcessInfo) -> Result<OpenedProcessInfo, ProcessQueryError>;
+    fn launch_process(process_launch_options: &ProcessLaunchOptions) -> Result<OpenedProcessInfo, ProcessQueryError>;
     fn close_process(handle: u64) -> Result<(), ProcessQueryError>;
     fn get_processes(options: ProcessQueryOptions) -> Vec<ProcessInfo>;
 }
@@ -37,6 +39,10 @@ impl ProcessQuery {
         ProcessQueryImpl::open_process(process_info)
     }
 
+    pub fn launch_process(process_launch_options: &ProcessLaunchOptions) -> Result<OpenedProcessInfo, ProcessQueryError> {
+        ProcessQueryImpl::launch_process(process_launch_options)
+    }
+
     pub fn close_process(handle: u64) -> Result<(), ProcessQueryError> {
         ProcessQueryImpl::close_process(handle)
     }
@@ -0,0 +1,441 @@
+use crate::debugger::{Debugger, DebuggerImpl};
+use crate::thread_controller::thread_controller_trait::ThreadControllerTrait;
+use squalr_engine_api::structures::debugging::register_snapshot::RegisterSnapshot;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::{opened_process_info::OpenedProcessInfo, thread_info::ThreadInfo, thread_state::ThreadState};
+use squalr_engine_targets::debugger::debugger_error::DebuggerError;
+use squalr_engine_targets::thread_control::thread_control_error::ThreadControlError;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// The fields of a `/proc/<pid>/task/<tid>/stat` entry that describe a thread.
+#[derive(Debug, PartialEq, Eq)]
+struct ProcThreadStat {
+    name: String,
+    state: char,
+    user_ticks: u64,
+    kernel_ticks: u64,
+}
+
+/// A mapping from `/proc/<pid>/maps`, reduced to what is needed to locate thread stacks.
+struct StackCandidateMapping {
+    start_address: u64,
+    end_address: u64,
+    is_main_stack: bool,
+}
+
+/// Enumerates threads through procfs, and suspends them through the ptrace session of the debugger.
+///
+/// Suspended threads are held in a ptrace stop, which also makes their registers readable. When another debugger is already
+/// attached, the whole process can still be stopped with `SIGSTOP`, but individual threads cannot be suspended.
+pub struct LinuxThreadController {
+    clock_ticks_per_second: u64,
+    /// Processes stopped with `SIGSTOP` rather than ptrace, which are continued with `SIGCONT` when resumed.
+    signal_stopped_process_ids: Mutex<HashSet<u32>>,
+}
+
+impl LinuxThreadController {
+    pub fn new() -> Self {
+        let clock_ticks_per_second = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+
+        LinuxThreadController {
+            clock_ticks_per_second: if clock_ticks_per_second > 0 { clock_ticks_per_second as u64 } else { 100 },
+            signal_stopped_process_ids: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Records a process that was stopped with `SIGSTOP` outside of the thread controller, such as one launched suspended.
+    pub(crate) fn track_signal_stopped_process(
+        &self,
+        process_id: u32,
+    ) {
+        if let Ok(mut signal_stopped_process_ids) = self.signal_stopped_process_ids.lock() {
+            signal_stopped_process_ids.insert(process_id);
+        }
+    }
+
+    /// Parses a stat entry. The thread name is wrapped in parentheses and may itself contain spaces and parentheses, so the
+    /// remaining fields are read after the last closing parenthesis.
+    fn parse_thread_stat(stat: &str) -> Option<ProcThreadStat> {
+        let name_start = stat.find('(')?;
+        let name_end = stat.rfind(')')?;
+        let name = stat.get(name_start + 1..name_end)?.to_string();
+        let fields = stat.get(name_end + 1..)?.split_whitespace().collect::<Vec<_>>();
+
+        // Fields are numbered from 1 in proc(5), starting with the pid and name, so the state is field 3.
+        Some(ProcThreadStat {
+            name,
+            state: fields.first()?.chars().next()?,
+            user_ticks: fields.get(11)?.parse().ok()?,
+            kernel_ticks: fields.get(12)?.parse().ok()?,
+        })
+    }
+
+    /// Parses the stack pointer from a `/proc/<pid>/task/<tid>/syscall` entry, which the kernel only reports for threads that
+    /// are blocked or stopped. The stack pointer is always the second to last field.
+    fn parse_syscall_stack_pointer(syscall: &str) -> Option<u64> {
+        let fields = syscall.split_whitespace().collect::<Vec<_>>();
+
+        if fields.len() < 3 {
+            return None;
+        }
+
+        let stack_pointer = fields[fields.len() - 2];
+
+        u64::from_str_radix(stack_pointer.trim_start_matches("0x"), 16).ok()
+    }
+
+    fn read_stack_candidate_mappings(process_id: u32) -> Vec<StackCandidateMapping> {
+        let Ok(memory_maps) = fs::read_to_string(format!("/proc/{}/maps", process_id)) else {
+            return Vec::new();
+        };
+
+        memory_maps
+            .lines()
+            .filter_map(|memory_map_line| {
+                let mut fields = memory_map_line.split_whitespace();
+                let (start_address, end_address) = fields.next()?.split_once('-')?;
+                let path = fields.nth(4).unwrap_or_default();
+
+                Some(StackCandidateMapping {
+                    start_address: u64::from_str_radix(start_address, 16).ok()?,
+                    end_address: u64::from_str_radix(end_address, 16).ok()?,
+                    is_main_stack: path == "[stack]",
+                })
+            })
+            .collect()
+    }
+
+    /// Reads the stack pointer of a thread from procfs. The kernel only reports it for threads that are blocked or stopped,
+    /// so running threads have none. Their registers are not read instead, since that would stop them just to list them.
+    fn read_stack_pointer(
+        process_id: u32,
+        thread_id: u32,
+    ) -> Option<u64> {
+        fs::read_to_string(format!("/proc/{}/task/{}/syscall", process_id, thread_id))
+            .ok()
+            .and_then(|syscall| Self::parse_syscall_stack_pointer(&syscall))
+    }
+
+    /// Locates the stack of a thread from its stack pointer, falling back to the `[stack]` mapping for the main thread. Other
+    /// threads without a known stack pointer, which are those running when listed, have no stack region.
+    fn find_stack_region(
+        stack_pointer: Option<u64>,
+        is_main_thread: bool,
+        stack_candidate_mappings: &[StackCandidateMapping],
+    ) -> Option<NormalizedRegion> {
+        let stack_mapping = match stack_pointer {
+            Some(stack_pointer) => stack_candidate_mappings
+                .iter()
+                .find(|mapping| mapping.start_address <= stack_pointer && stack_pointer < mapping.end_address),
+            None => None,
+        }
+        .or_else(|| {
+            stack_candidate_mappings
+                .iter()
+                .find(|mapping| mapping.is_main_stack && is_main_thread)
+        })?;
+
+        Some(NormalizedRegion::new(
+            stack_mapping.start_address,
+            stack_mapping.end_address - stack_mapping.start_address,
+        ))
+    }
+
+    fn ticks_to_milliseconds(
+        &self,
+        ticks: u64,
+    ) -> u64 {
+        ticks.saturating_mul(1000) / self.clock_ticks_per_second
+    }
+
+    fn is_signal_stopped(
+        &self,
+        process_id: u32,
+    ) -> bool {
+        self.signal_stopped_process_ids
+            .lock()
+            .is_ok_and(|signal_stopped_process_ids| signal_stopped_process_ids.contains(&process_id))
+    }
+
+    fn check_thread_exists(
+        process_id: u32,
+        thread_id: u32,
+    ) -> Result<(), ThreadControlError> {
+        if Path::new(&format!("/proc/{}/task/{}", process_id, thread_id)).exists() {
+            Ok(())
+        } else {
+            Err(ThreadControlError::unknown_thread(process_id, thread_id))
+        }
+    }
+
+    fn map_debugger_error(
+        operation: &'static str,
+        error: DebuggerError,
+    ) -> ThreadControlError {
+        match error {
+            DebuggerError::AttachFailed { process_id, details } => ThreadControlError::attach_failed(process_id, details),
+            DebuggerError::Internal { details, .. } => ThreadControlError::operation_failed(operation, details),
+            error => ThreadControlError::operation_failed(operation, error.to_string()),
+        }
+    }
+
+    fn send_signal(
+        operation: &'static str,
+        process_id: u32,
+        signal: libc::c_int,
+    ) -> Result<(), ThreadControlError> {
+        if unsafe { libc::kill(process_id as libc::pid_t, signal) } == 0 {
+            Ok(())
+        } else {
+            Err(ThreadControlError::operation_failed(operation, std::io::Error::last_os_error().to_string()))
+        }
+    }
+}
+
+impl ThreadControllerTrait for LinuxThreadController {
+    fn get_threads(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<Vec<ThreadInfo>, ThreadControlError> {
+        let process_id = process_info.get_process_id_raw();
+        let thread_ids = DebuggerImpl::list_thread_ids(process_id).map_err(|error| ThreadControlError::operation_failed("get_threads", error.to_string()))?;
+        let stack_candidate_mappings = Self::read_stack_candidate_mappings(process_id);
+        let suspended_thread_ids = Debugger::get_instance()
+            .get_suspended_thread_ids(process_id)
+            .into_iter()
+            .collect::<HashSet<_>>();
+        let is_signal_stopped = self.is_signal_stopped(process_id);
+
+        Ok(thread_ids
+            .into_iter()
+            .filter_map(|thread_id| {
+                // Threads that exit between listing and reading their stat entry are skipped.
+                let stat = fs::read_to_string(format!("/proc/{}/task/{}/stat", process_id, thread_id)).ok()?;
+                let proc_thread_stat = Self::parse_thread_stat(&stat)?;
+
+                Some(ThreadInfo::new(
+                    thread_id,
+                    proc_thread_stat.name,
+                    ThreadState::from_proc_state(proc_thread_stat.state),
+                    self.ticks_to_milliseconds(proc_thread_stat.user_ticks),
+                    self.ticks_to_milliseconds(proc_thread_stat.kernel_ticks),
+                    Self::find_stack_region(
+                        Self::read_stack_pointer(process_id, thread_id),
+                        thread_id == process_id,
+                        &stack_candidate_mappings,
+                    ),
+                    is_signal_stopped || suspended_thread_ids.contains(&thread_id),
+                ))
+            })
+            .collect())
+    }
+
+    fn suspend_thread(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<(), ThreadControlError> {
+        let process_id = process_info.get_process_id_raw();
+
+        Self::check_thread_exists(process_id, thread_id)?;
+        Debugger::get_instance()
+            .suspend_threads(process_id, Some(thread_id))
+            .map_err(|error| Self::map_debugger_error("suspend_thread", error))
+    }
+
+    fn resume_thread(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<(), ThreadControlError> {
+        let process_id = process_info.get_process_id_raw();
+
+        if self.is_signal_stopped(process_id) {
+            return Err(ThreadControlError::operation_failed(
+                "resume_thread",
+                "the process was stopped with SIGSTOP, so it can only be resumed as a whole",
+            ));
+        }
+
+        Self::check_thread_exists(process_id, thread_id)?;
+        Debugger::get_instance()
+            .resume_threads(process_id, Some(thread_id))
+            .map_err(|error| Self::map_debugger_error("resume_thread", error))
+    }
+
+    fn suspend_process(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError> {
+        let process_id = process_info.get_process_id_raw();
+
+        if self.is_signal_stopped(process_id) {
+            return Ok(());
+        }
+
+        match Debugger::get_instance().suspend_threads(process_id, None) {
+            Ok(()) => Ok(()),
+            Err(DebuggerError::AttachFailed { details, .. }) => {
+                // Another debugger may own the process, which still leaves job control available.
+                log::warn!("Failed to attach to process {} ({}), stopping it with SIGSTOP instead.", process_id, details);
+                Self::send_signal("suspend_process", process_id, libc::SIGSTOP)?;
+
+                if let Ok(mut signal_stopped_process_ids) = self.signal_stopped_process_ids.lock() {
+                    signal_stopped_process_ids.insert(process_id);
+                }
+
+                Ok(())
+            }
+            Err(error) => Err(Self::map_debugger_error("suspend_process", error)),
+        }
+    }
+
+    fn resume_process(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError> {
+        let process_id = process_info.get_process_id_raw();
+        let was_signal_stopped = self
+            .signal_stopped_process_ids
+            .lock()
+            .is_ok_and(|mut signal_stopped_process_ids| signal_stopped_process_ids.remove(&process_id));
+
+        if was_signal_stopped {
+            Self::send_signal("resume_process", process_id, libc::SIGCONT)?;
+        }
+
+        Debugger::get_instance()
+            .resume_threads(process_id, None)
+            .map_err(|error| Self::map_debugger_error("resume_process", error))
+    }
+
+    fn get_thread_context(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<RegisterSnapshot, ThreadControlError> {
+        let process_id = process_info.get_process_id_raw();
+
+        Self::check_thread_exists(process_id, thread_id)?;
+        Debugger::get_instance()
+            .capture_thread_context(process_id, thread_id)
+            .map_err(|error| Self::map_debugger_error("get_thread_context", error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinuxThreadController, ProcThreadStat, StackCandidateMapping};
+    use crate::thread_controller::thread_controller_trait::ThreadControllerTrait;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+    use std::sync::mpsc;
+
+    #[test]
+    fn thread_stat_names_may_contain_spaces_and_parentheses() {
+        let stat = "4243 (Render (main) 2) S 4242 4242 4242 0 -1 4194368 1200 0 0 0 157 42 0 0 20 0 12 0 3500 0 0";
+
+        assert_eq!(
+            LinuxThreadController::parse_thread_stat(stat),
+            Some(ProcThreadStat {
+                name: String::from("Render (main) 2"),
+                state: 'S',
+                user_ticks: 157,
+                kernel_ticks: 42,
+            })
+        );
+    }
+
+    #[test]
+    fn syscall_entries_report_the_stack_pointer_of_blocked_threads() {
+        assert_eq!(
+            LinuxThreadController::parse_syscall_stack_pointer("202 0x7f00 0x80 0x0 0x0 0x0 0x0 0x7ffd1000 0x7f1234"),
+            Some(0x7ffd1000)
+        );
+        assert_eq!(LinuxThreadController::parse_syscall_stack_pointer("-1 0x7ffd2000 0x401000"), Some(0x7ffd2000));
+        assert_eq!(LinuxThreadController::parse_syscall_stack_pointer("running"), None);
+    }
+
+    #[test]
+    fn only_the_main_thread_has_a_stack_region_without_a_stack_pointer() {
+        let stack_candidate_mappings = [
+            StackCandidateMapping {
+                start_address: 0x7F00_0000,
+                end_address: 0x7F80_0000,
+                is_main_stack: false,
+            },
+            StackCandidateMapping {
+                start_address: 0x7FFD_0000,
+                end_address: 0x7FFF_0000,
+                is_main_stack: true,
+            },
+        ];
+
+        assert_eq!(
+            LinuxThreadController::find_stack_region(Some(0x7F40_0000), false, &stack_candidate_mappings),
+            Some(NormalizedRegion::new(0x7F00_0000, 0x80_0000))
+        );
+        assert_eq!(
+            LinuxThreadController::find_stack_region(None, true, &stack_candidate_mappings),
+            Some(NormalizedRegion::new(0x7FFD_0000, 0x2_0000))
+        );
+        assert_eq!(LinuxThreadController::find_stack_region(None, false, &stack_candidate_mappings), None);
+    }
+
+    #[test]
+    fn get_threads_lists_named_threads_with_their_stacks() {
+        let (thread_id_sender, thread_id_receiver) = mpsc::channel();
+        let (release_sender, release_receiver) = mpsc::channel::<()>();
+        let blocked_thread = std::thread::Builder::new()
+            .name(String::from("squalr-threads"))
+            .spawn(move || {
+                let _ = thread_id_sender.send(unsafe { libc::gettid() } as u32);
+                let _ = release_receiver.recv();
+            })
+            .expect("Expected the thread to spawn.");
+        let blocked_thread_id = thread_id_receiver
+            .recv()
+            .expect("Expected the thread id to be sent.");
+        let process_info = OpenedProcessInfo::new(std::process::id(), String::from("self"), 0, Bitness::Bit64, None);
+        let thread_controller = LinuxThreadController::new();
+
+        // The thread is blocked on the channel once the kernel reports it as sleeping with a stack pointer.
+        let mut thread_infos = Vec::new();
+
+        for _ in 0..100 {
+            thread_infos = thread_controller
+                .get_threads(&process_info)
+                .expect("Expected the threads of this process to be listed.");
+
+            if thread_infos
+                .iter()
+                .any(|thread_info| thread_info.get_thread_id() == blocked_thread_id && thread_info.get_stack_region().is_some())
+            {
+                break;
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        let _ = release_sender.send(());
+        blocked_thread.join().expect("Expected the thread to exit.");
+
+        let main_thread_info = thread_infos
+            .iter()
+            .find(|thread_info| thread_info.get_thread_id() == std::process::id())
+            .expect("Expected the main thread to be listed.");
+        let blocked_thread_info = thread_infos
+            .iter()
+            .find(|thread_info| thread_info.get_thread_id() == blocked_thread_id)
+            .expect("Expected the spawned thread to be listed.");
+
+        assert!(main_thread_info.get_stack_region().is_some());
+        assert_eq!(blocked_thread_info.get_name(), "squalr-threads");
+        assert!(blocked_thread_info.get_stack_region().is_some());
+        assert!(!blocked_thread_info.get_is_suspended());
+    }
+}
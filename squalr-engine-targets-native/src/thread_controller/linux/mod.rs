@@ -0,0 +1 @@
+pub mod linux_thread_controller;
@@ -0,0 +1 @@
+pub mod macos_thread_controller;
@@ -0,0 +1,32 @@
+pub mod thread_controller_trait;
+
+use std::sync::OnceLock;
+
+// Android shares the Linux procfs and ptrace interfaces, so it uses the Linux thread controller directly.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod linux;
+
+#[cfg(any(target_os = "macos"))]
+mod macos;
+
+#[cfg(target_os = "windows")]
+mod windows;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub use crate::thread_controller::linux::linux_thread_controller::LinuxThreadController as ThreadControllerImpl;
+
+#[cfg(target_os = "macos")]
+pub use crate::thread_controller::macos::macos_thread_controller::MacOsThreadController as ThreadControllerImpl;
+
+#[cfg(target_os = "windows")]
+pub use crate::thread_controller::windows::windows_thread_controller::WindowsThreadController as ThreadControllerImpl;
+
+pub struct ThreadController;
+
+impl ThreadController {
+    pub fn get_instance() -> &'static ThreadControllerImpl {
+        static INSTANCE: OnceLock<ThreadControllerImpl> = OnceLock::new();
+
+        INSTANCE.get_or_init(ThreadControllerImpl::new)
+    }
+}
@@ -0,0 +1,38 @@
+use squalr_engine_api::structures::debugging::register_snapshot::RegisterSnapshot;
+use squalr_engine_api::structures::processes::{opened_process_info::OpenedProcessInfo, thread_info::ThreadInfo};
+use squalr_engine_targets::thread_control::thread_control_error::ThreadControlError;
+
+pub trait ThreadControllerTrait {
+    fn get_threads(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<Vec<ThreadInfo>, ThreadControlError>;
+
+    fn suspend_thread(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<(), ThreadControlError>;
+
+    fn resume_thread(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<(), ThreadControlError>;
+
+    fn suspend_process(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError>;
+
+    fn resume_process(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError>;
+
+    fn get_thread_context(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<RegisterSnapshot, ThreadControlError>;
+}
@@ -0,0 +1,59 @@
+use crate::thread_controller::thread_controller_trait::ThreadControllerTrait;
+use squalr_engine_api::structures::debugging::register_snapshot::RegisterSnapshot;
+use squalr_engine_api::structures::processes::{opened_process_info::OpenedProcessInfo, thread_info::ThreadInfo};
+use squalr_engine_targets::thread_control::thread_control_error::ThreadControlError;
+
+pub struct WindowsThreadController;
+
+impl WindowsThreadController {
+    pub fn new() -> Self {
+        WindowsThreadController
+    }
+}
+
+impl ThreadControllerTrait for WindowsThreadController {
+    fn get_threads(
+        &self,
+        _process_info: &OpenedProcessInfo,
+    ) -> Result<Vec<ThreadInfo>, ThreadControlError> {
+        Err(ThreadControlError::not_implemented("get_threads", "windows"))
+    }
+
+    fn suspend_thread(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        _thread_id: u32,
+    ) -> Result<(), ThreadControlError> {
+        Err(ThreadControlError::not_implemented("suspend_thread", "windows"))
+    }
+
+    fn resume_thread(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        _thread_id: u32,
+    ) -> Result<(), ThreadControlError> {
+        Err(ThreadControlError::not_implemented("resume_thread", "windows"))
+    }
+
+    fn suspend_process(
+        &self,
+        _process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError> {
+        Err(ThreadControlError::not_implemented("suspend_process", "windows"))
+    }
+
+    fn resume_process(
+        &self,
+        _process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError> {
+        Err(ThreadControlError::not_implemented("resume_process", "windows"))
+    }
+
+    fn get_thread_context(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        _thread_id: u32,
+    ) -> Result<RegisterSnapshot, ThreadControlError> {
+        Err(ThreadControlError::not_implemented("get_thread_context", "windows"))
+    }
+}
@@ -0,0 +1,102 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DebuggerError {
+    #[error("Watchpoint address `0x{address:X}` is not aligned to its {size}-byte size.")]
+    UnalignedWatchpoint { address: u64, size: u64 },
+    #[error("Unknown watchpoint id `{watchpoint_id}`.")]
+    UnknownWatchpoint { watchpoint_id: u64 },
+    #[error("Unknown breakpoint id `{breakpoint_id}`.")]
+    UnknownBreakpoint { breakpoint_id: u64 },
+    #[error("A breakpoint is already set at `0x{address:X}`.")]
+    BreakpointExists { address: u64 },
+    #[error("Failed to attach to process `{process_id}`: {details}.")]
+    AttachFailed { process_id: u32, details: String },
+    #[error("Process `{process_id}` is not traced by Squalr. Suspend its threads or set a breakpoint first.")]
+    NotTraced { process_id: u32 },
+    #[error("Operation `{operation}` is not implemented on `{platform}`.")]
+    NotImplemented { operation: &'static str, platform: &'static str },
+    #[error("Debugger operation `{operation}` failed: {details}.")]
+    Internal { operation: &'static str, details: String },
+}
+
+impl DebuggerError {
+    pub fn unaligned_watchpoint(
+        address: u64,
+        size: u64,
+    ) -> Self {
+        Self::UnalignedWatchpoint { address, size }
+    }
+
+    pub fn unknown_watchpoint(watchpoint_id: u64) -> Self {
+        Self::UnknownWatchpoint { watchpoint_id }
+    }
+
+    pub fn unknown_breakpoint(breakpoint_id: u64) -> Self {
+        Self::UnknownBreakpoint { breakpoint_id }
+    }
+
+    pub fn breakpoint_exists(address: u64) -> Self {
+        Self::BreakpointExists { address }
+    }
+
+    pub fn attach_failed(
+        process_id: u32,
+        details: impl Into<String>,
+    ) -> Self {
+        Self::AttachFailed {
+            process_id,
+            details: details.into(),
+        }
+    }
+
+    pub fn not_traced(process_id: u32) -> Self {
+        Self::NotTraced { process_id }
+    }
+
+    pub fn not_implemented(
+        operation: &'static str,
+        platform: &'static str,
+    ) -> Self {
+        Self::NotImplemented { operation, platform }
+    }
+
+    pub fn internal(
+        operation: &'static str,
+        details: impl Into<String>,
+    ) -> Self {
+        Self::Internal {
+            operation,
+            details: details.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebuggerError;
+
+    #[test]
+    fn unaligned_watchpoint_error_contains_address_and_size() {
+        let error = DebuggerError::unaligned_watchpoint(0x1002, 4);
+
+        assert_eq!(error.to_string(), "Watchpoint address `0x1002` is not aligned to its 4-byte size.");
+    }
+
+    #[test]
+    fn attach_failed_error_contains_process_and_details() {
+        let error = DebuggerError::attach_failed(42, "perf_event_open returned EACCES");
+
+        assert_eq!(error.to_string(), "Failed to attach to process `42`: perf_event_open returned EACCES.");
+    }
+
+    #[test]
+    fn not_traced_error_explains_how_to_attach() {
+        let error = DebuggerError::not_traced(42);
+
+        assert_eq!(
+            error.to_string(),
+            "Process `42` is not traced by Squalr. Suspend its threads or set a breakpoint first."
+        );
+    }
+}
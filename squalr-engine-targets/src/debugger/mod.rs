@@ -0,0 +1 @@
+pub mod debugger_error;
@@ -1,7 +1,19 @@
+pub mod debugger;
+pub mod memory_allocation;
+pub mod memory_write_tracking;
 pub mod process_query;
 pub mod target_providers;
+pub mod thread_control;
 
+pub use debugger::debugger_error::DebuggerError;
+pub use memory_allocation::memory_allocation_error::MemoryAllocationError;
+pub use memory_write_tracking::memory_write_tracking_error::MemoryWriteTrackingError;
+pub use process_query::process_launch_options::ProcessLaunchOptions;
 pub use process_query::process_query_error::ProcessQueryError;
 pub use process_query::process_query_options::ProcessQueryOptions;
 pub use squalr_engine_api::plugins::memory_view::PageRetrievalMode;
-pub use target_providers::{MemoryQueryProvider, MemoryReadProvider, MemoryWriteProvider, ProcessQueryProvider};
+pub use target_providers::{
+    DebuggerProvider, MemoryAllocationProvider, MemoryQueryProvider, MemoryReadProvider, MemoryWriteProvider, MemoryWriteTrackingProvider,
+    ProcessQueryProvider, ThreadControlProvider,
+};
+pub use thread_control::thread_control_error::ThreadControlError;
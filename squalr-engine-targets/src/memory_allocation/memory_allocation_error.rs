@@ -0,0 +1,68 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum MemoryAllocationError {
+    #[error("Memory range at `0x{address:X}` with size `{size}` is invalid.")]
+    InvalidRange { address: u64, size: u64 },
+    #[error("Failed to attach to process `{process_id}`: {details}.")]
+    AttachFailed { process_id: u32, details: String },
+    #[error("Operation `{operation}` is not implemented on `{platform}`.")]
+    NotImplemented { operation: &'static str, platform: &'static str },
+    #[error("Memory operation `{operation}` failed: {details}.")]
+    OperationFailed { operation: &'static str, details: String },
+}
+
+impl MemoryAllocationError {
+    pub fn invalid_range(
+        address: u64,
+        size: u64,
+    ) -> Self {
+        Self::InvalidRange { address, size }
+    }
+
+    pub fn attach_failed(
+        process_id: u32,
+        details: impl Into<String>,
+    ) -> Self {
+        Self::AttachFailed {
+            process_id,
+            details: details.into(),
+        }
+    }
+
+    pub fn not_implemented(
+        operation: &'static str,
+        platform: &'static str,
+    ) -> Self {
+        Self::NotImplemented { operation, platform }
+    }
+
+    pub fn operation_failed(
+        operation: &'static str,
+        details: impl Into<String>,
+    ) -> Self {
+        Self::OperationFailed {
+            operation,
+            details: details.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryAllocationError;
+
+    #[test]
+    fn invalid_range_error_contains_address_and_size() {
+        let error = MemoryAllocationError::invalid_range(0x1000, 0);
+
+        assert_eq!(error.to_string(), "Memory range at `0x1000` with size `0` is invalid.");
+    }
+
+    #[test]
+    fn operation_failed_error_contains_operation_and_details() {
+        let error = MemoryAllocationError::operation_failed("mmap", "Cannot allocate memory (os error 12)");
+
+        assert_eq!(error.to_string(), "Memory operation `mmap` failed: Cannot allocate memory (os error 12).");
+    }
+}
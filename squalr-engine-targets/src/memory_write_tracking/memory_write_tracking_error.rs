@@ -0,0 +1,49 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum MemoryWriteTrackingError {
+    #[error("Operation `{operation}` is not implemented on `{platform}`.")]
+    NotImplemented { operation: &'static str, platform: &'static str },
+    #[error("Write tracking is not supported: {details}.")]
+    NotSupported { details: String },
+    #[error("Write tracking operation `{operation}` failed: {details}.")]
+    OperationFailed { operation: &'static str, details: String },
+}
+
+impl MemoryWriteTrackingError {
+    pub fn not_implemented(
+        operation: &'static str,
+        platform: &'static str,
+    ) -> Self {
+        Self::NotImplemented { operation, platform }
+    }
+
+    pub fn not_supported(details: impl Into<String>) -> Self {
+        Self::NotSupported { details: details.into() }
+    }
+
+    pub fn operation_failed(
+        operation: &'static str,
+        details: impl Into<String>,
+    ) -> Self {
+        Self::OperationFailed {
+            operation,
+            details: details.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryWriteTrackingError;
+
+    #[test]
+    fn operation_failed_error_contains_operation_and_details() {
+        let error = MemoryWriteTrackingError::operation_failed("clear_refs", "Permission denied (os error 13)");
+
+        assert_eq!(
+            error.to_string(),
+            "Write tracking operation `clear_refs` failed: Permission denied (os error 13)."
+        );
+    }
+}
@@ -1,2 +1,3 @@
+pub mod process_launch_options;
 pub mod process_query_error;
 pub mod process_query_options;
@@ -0,0 +1,11 @@
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Default)]
+pub struct ProcessLaunchOptions {
+    pub executable_path: PathBuf,
+    pub arguments: Vec<String>,
+    /// Variables set on top of the environment inherited from the engine.
+    pub environment: Vec<(String, String)>,
+    /// Leaves the process stopped at its entry point rather than letting it run.
+    pub is_suspended: bool,
+}
@@ -6,6 +6,8 @@ pub enum ProcessQueryError {
     ProcessMonitorLockPoisoned { operation: &'static str, details: String },
     #[error("Failed to open process with id `{process_id}`{details_suffix}.")]
     OpenProcessFailed { process_id: u32, details_suffix: String },
+    #[error("Failed to launch `{executable_path}`: {details}.")]
+    LaunchProcessFailed { executable_path: String, details: String },
     #[error("Failed to close process handle `{handle}`.")]
     CloseProcessFailed { handle: u64 },
     #[error("Operation `{operation}` is not implemented on `{platform}`.")]
@@ -55,6 +57,16 @@ impl ProcessQueryError {
 
         Self::OpenProcessFailed { process_id, details_suffix }
     }
+
+    pub fn launch_process_failed(
+        executable_path: impl Into<String>,
+        details: impl Into<String>,
+    ) -> Self {
+        Self::LaunchProcessFailed {
+            executable_path: executable_path.into(),
+            details: details.into(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -84,4 +96,14 @@ mod tests {
 
         assert_eq!(error.to_string(), "Failed to open process with id `42`: task_for_pid returned KERN_FAILURE.");
     }
+
+    #[test]
+    fn launch_process_failed_error_contains_executable_path_and_details() {
+        let error = ProcessQueryError::launch_process_failed("/opt/game/game.x86_64", "No such file or directory (os error 2)");
+
+        assert_eq!(
+            error.to_string(),
+            "Failed to launch `/opt/game/game.x86_64`: No such file or directory (os error 2)."
+        );
+    }
 }
@@ -1,10 +1,21 @@
 use squalr_engine_api::plugins::memory_view::PageRetrievalMode;
 use squalr_engine_api::structures::data_values::data_value::DataValue;
-use squalr_engine_api::structures::memory::{normalized_module::NormalizedModule, normalized_region::NormalizedRegion};
-use squalr_engine_api::structures::processes::{opened_process_info::OpenedProcessInfo, process_info::ProcessInfo};
+use squalr_engine_api::structures::debugging::{
+    breakpoint::Breakpoint, breakpoint_hit::BreakpointHit, breakpoint_mode::BreakpointMode, instruction_trace_stop_reason::InstructionTraceStopReason,
+    register_snapshot::RegisterSnapshot, traced_instruction::TracedInstruction, watchpoint::Watchpoint, watchpoint_access::WatchpointAccess,
+    watchpoint_hit::WatchpointHit, watchpoint_size::WatchpointSize,
+};
+use squalr_engine_api::structures::memory::{memory_protection::MemoryProtection, normalized_module::NormalizedModule, normalized_region::NormalizedRegion};
+use squalr_engine_api::structures::pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize;
+use squalr_engine_api::structures::processes::{opened_process_info::OpenedProcessInfo, process_info::ProcessInfo, thread_info::ThreadInfo};
 use squalr_engine_api::structures::structs::valued_struct::ValuedStruct;
+use std::time::Duration;
 
-use crate::process_query::{process_query_error::ProcessQueryError, process_query_options::ProcessQueryOptions};
+use crate::debugger::debugger_error::DebuggerError;
+use crate::memory_allocation::memory_allocation_error::MemoryAllocationError;
+use crate::memory_write_tracking::memory_write_tracking_error::MemoryWriteTrackingError;
+use crate::process_query::{process_launch_options::ProcessLaunchOptions, process_query_error::ProcessQueryError, process_query_options::ProcessQueryOptions};
+use crate::thread_control::thread_control_error::ThreadControlError;
 
 pub trait ProcessQueryProvider: Send + Sync {
     fn start_monitoring(&self) -> Result<(), ProcessQueryError>;
@@ -19,6 +30,12 @@ pub trait ProcessQueryProvider: Send + Sync {
         process_info: &ProcessInfo,
     ) -> Result<OpenedProcessInfo, ProcessQueryError>;
 
+    /// Starts an executable and opens it before any of its own code has run.
+    fn launch_process(
+        &self,
+        process_launch_options: &ProcessLaunchOptions,
+    ) -> Result<OpenedProcessInfo, ProcessQueryError>;
+
     fn close_process(
         &self,
         handle: u64,
@@ -68,6 +85,39 @@ pub trait MemoryQueryProvider: Send + Sync {
 
         self.get_memory_page_bounds(process_info, page_retrieval_mode)
     }
+
+    /// Gets the executable pages within an address range, clipped to the range. Targets without page protections report none.
+    fn get_executable_page_bounds(
+        &self,
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: u64,
+    ) -> Vec<NormalizedRegion> {
+        let _ = (process_info, start_address, end_address);
+
+        Vec::new()
+    }
+
+    fn get_native_pointer_size(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Option<PointerScanPointerSize> {
+        let _ = process_info;
+
+        None
+    }
+
+    /// Resolves an exported function of a module to its address in the process, using the symbol table of the module file.
+    fn resolve_module_symbol(
+        &self,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+        symbol_name: &str,
+    ) -> Option<u64> {
+        let _ = (process_info, module, symbol_name);
+
+        None
+    }
 }
 
 pub trait MemoryReadProvider: Send + Sync {
@@ -100,4 +150,169 @@ pub trait MemoryWriteProvider: Send + Sync {
         address: u64,
         values: &[u8],
     ) -> bool;
+
+    /// Writes bytes even where the page protection forbids it, such as when patching read-only code. Targets without page
+    /// protections write as usual.
+    fn write_bytes_ignoring_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        self.write_bytes(process_info, address, values)
+    }
+}
+
+pub trait MemoryAllocationProvider: Send + Sync {
+    /// Maps new pages into the process. Sizes are rounded up to whole pages, and the preferred address is only a hint.
+    fn allocate(
+        &self,
+        process_info: &OpenedProcessInfo,
+        preferred_address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError>;
+
+    /// Unmaps the pages covering the given range, returning the page-aligned region that was released.
+    fn free(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+    ) -> Result<NormalizedRegion, MemoryAllocationError>;
+
+    /// Changes the protection of the pages covering the given range, returning the page-aligned region that was changed.
+    fn protect(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError>;
+}
+
+pub trait MemoryWriteTrackingProvider: Send + Sync {
+    /// Forgets which pages the process has written so far, so that only writes made after this call are reported.
+    fn begin_write_tracking(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), MemoryWriteTrackingError>;
+
+    /// Returns the pages within the given regions that the process wrote since tracking began, one region per page.
+    fn get_written_pages(
+        &self,
+        process_info: &OpenedProcessInfo,
+        memory_regions: &[NormalizedRegion],
+    ) -> Result<Vec<NormalizedRegion>, MemoryWriteTrackingError>;
+}
+
+pub trait DebuggerProvider: Send + Sync {
+    /// Arms a hardware watchpoint on every thread of the process, including threads created after it is set.
+    fn set_watchpoint(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        watchpoint_size: WatchpointSize,
+        watchpoint_access: WatchpointAccess,
+    ) -> Result<Watchpoint, DebuggerError>;
+
+    /// Disarms a watchpoint, returning the hits collected since it was set.
+    fn remove_watchpoint(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError>;
+
+    /// Gets the hits collected since the watchpoint was set, ordered by trap address.
+    fn get_watchpoint_hits(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError>;
+
+    fn get_watchpoints(&self) -> Vec<Watchpoint>;
+
+    /// Patches a software breakpoint into the code of the process. Hits are collected by a tracer that stays attached
+    /// until the last breakpoint of the process is removed.
+    fn set_breakpoint(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        breakpoint_mode: BreakpointMode,
+    ) -> Result<Breakpoint, DebuggerError>;
+
+    /// Restores the original code under a breakpoint, returning its final state.
+    fn remove_breakpoint(
+        &self,
+        breakpoint_id: u64,
+    ) -> Result<Breakpoint, DebuggerError>;
+
+    /// Gets the current state of a breakpoint along with the retained hits whose index is greater than `after_hit_index`.
+    fn get_breakpoint_hits(
+        &self,
+        breakpoint_id: u64,
+        after_hit_index: u64,
+    ) -> Result<(Breakpoint, Vec<BreakpointHit>), DebuggerError>;
+
+    fn get_breakpoints(&self) -> Vec<Breakpoint>;
+
+    /// Calls a function inside the process with the given integer arguments, returning its integer result. The process must
+    /// already be traced, either because threads are suspended or because a breakpoint is set.
+    fn call_function(
+        &self,
+        process_info: &OpenedProcessInfo,
+        function_address: u64,
+        arguments: &[u64],
+    ) -> Result<u64, DebuggerError>;
+
+    /// Waits up to `start_timeout` for a thread to execute the start address, then single steps that thread until it reaches
+    /// the end address or has executed `instruction_budget` instructions. When a thread id is given, other threads pass the
+    /// start address untraced. Returns every stop of the thread, ending with the stop where the trace ended.
+    fn trace_instructions(
+        &self,
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: Option<u64>,
+        thread_id: Option<u32>,
+        instruction_budget: u64,
+        start_timeout: Duration,
+    ) -> Result<(Vec<TracedInstruction>, InstructionTraceStopReason), DebuggerError>;
+}
+
+pub trait ThreadControlProvider: Send + Sync {
+    /// Lists the threads of the process, ordered by thread id.
+    fn get_threads(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<Vec<ThreadInfo>, ThreadControlError>;
+
+    /// Stops one thread until it is resumed. Other threads keep running.
+    fn suspend_thread(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<(), ThreadControlError>;
+
+    fn resume_thread(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<(), ThreadControlError>;
+
+    /// Stops every thread of the process until it is resumed.
+    fn suspend_process(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError>;
+
+    /// Resumes every thread that was suspended, whether individually or along with the whole process.
+    fn resume_process(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError>;
+
+    /// Captures the registers of a thread. Running threads are briefly stopped to read them.
+    fn get_thread_context(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<RegisterSnapshot, ThreadControlError>;
 }
@@ -0,0 +1,61 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ThreadControlError {
+    #[error("Thread `{thread_id}` does not belong to process `{process_id}`.")]
+    UnknownThread { process_id: u32, thread_id: u32 },
+    #[error("Failed to attach to process `{process_id}`: {details}.")]
+    AttachFailed { process_id: u32, details: String },
+    #[error("Operation `{operation}` is not implemented on `{platform}`.")]
+    NotImplemented { operation: &'static str, platform: &'static str },
+    #[error("Thread operation `{operation}` failed: {details}.")]
+    OperationFailed { operation: &'static str, details: String },
+}
+
+impl ThreadControlError {
+    pub fn unknown_thread(
+        process_id: u32,
+        thread_id: u32,
+    ) -> Self {
+        Self::UnknownThread { process_id, thread_id }
+    }
+
+    pub fn attach_failed(
+        process_id: u32,
+        details: impl Into<String>,
+    ) -> Self {
+        Self::AttachFailed {
+            process_id,
+            details: details.into(),
+        }
+    }
+
+    pub fn not_implemented(
+        operation: &'static str,
+        platform: &'static str,
+    ) -> Self {
+        Self::NotImplemented { operation, platform }
+    }
+
+    pub fn operation_failed(
+        operation: &'static str,
+        details: impl Into<String>,
+    ) -> Self {
+        Self::OperationFailed {
+            operation,
+            details: details.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThreadControlError;
+
+    #[test]
+    fn unknown_thread_error_contains_process_and_thread_ids() {
+        let error = ThreadControlError::unknown_thread(4242, 4243);
+
+        assert_eq!(error.to_string(), "Thread `4243` does not belong to process `4242`.");
+    }
+}
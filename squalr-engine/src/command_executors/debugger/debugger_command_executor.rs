@@ -0,0 +1,45 @@
+use crate::command_executors::privileged_command_executor::PrivilegedCommandExecutor;
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::debugger::debugger_command::DebuggerCommand;
+use squalr_engine_api::commands::privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse};
+use std::sync::Arc;
+
+impl PrivilegedCommandExecutor for DebuggerCommand {
+    type ResponseType = PrivilegedCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
+        match self {
+            DebuggerCommand::Watch { debugger_watch_request } => debugger_watch_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            DebuggerCommand::Hits { debugger_hits_request } => debugger_hits_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            DebuggerCommand::Unwatch { debugger_unwatch_request } => debugger_unwatch_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            DebuggerCommand::SetBreakpoint {
+                debugger_set_breakpoint_request,
+            } => debugger_set_breakpoint_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            DebuggerCommand::RemoveBreakpoint {
+                debugger_remove_breakpoint_request,
+            } => debugger_remove_breakpoint_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            DebuggerCommand::ListBreakpoints {
+                debugger_list_breakpoints_request,
+            } => debugger_list_breakpoints_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            DebuggerCommand::Trace { debugger_trace_request } => debugger_trace_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::debugger::hits::debugger_hits_request::DebuggerHitsRequest;
+use squalr_engine_api::commands::debugger::hits::debugger_hits_response::DebuggerHitsResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for DebuggerHitsRequest {
+    type ResponseType = DebuggerHitsResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state
+            .get_os_providers()
+            .debugger
+            .get_watchpoint_hits(self.watchpoint_id)
+        {
+            Ok(watchpoint_hits) => DebuggerHitsResponse { watchpoint_hits, error: None },
+            Err(error) => DebuggerHitsResponse {
+                watchpoint_hits: Vec::new(),
+                error: Some(error.to_string()),
+            },
+        }
+    }
+}
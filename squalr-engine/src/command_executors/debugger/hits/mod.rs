@@ -0,0 +1 @@
+pub mod debugger_hits_request_executor;
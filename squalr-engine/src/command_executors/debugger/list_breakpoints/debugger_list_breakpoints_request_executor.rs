@@ -0,0 +1,28 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::debugger::list_breakpoints::debugger_list_breakpoints_request::DebuggerListBreakpointsRequest;
+use squalr_engine_api::commands::debugger::list_breakpoints::debugger_list_breakpoints_response::DebuggerListBreakpointsResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for DebuggerListBreakpointsRequest {
+    type ResponseType = DebuggerListBreakpointsResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let debugger = &engine_privileged_state.get_os_providers().debugger;
+        let breakpoints = debugger.get_breakpoints();
+        let breakpoint_hits = breakpoints
+            .iter()
+            .filter_map(|breakpoint| {
+                debugger
+                    .get_breakpoint_hits(breakpoint.get_breakpoint_id(), 0)
+                    .ok()
+            })
+            .flat_map(|(_, breakpoint_hits)| breakpoint_hits)
+            .collect();
+
+        DebuggerListBreakpointsResponse { breakpoints, breakpoint_hits }
+    }
+}
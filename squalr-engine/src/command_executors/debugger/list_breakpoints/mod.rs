@@ -0,0 +1 @@
+pub mod debugger_list_breakpoints_request_executor;
@@ -0,0 +1,38 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::debugger::remove_breakpoint::debugger_remove_breakpoint_request::DebuggerRemoveBreakpointRequest;
+use squalr_engine_api::commands::debugger::remove_breakpoint::debugger_remove_breakpoint_response::DebuggerRemoveBreakpointResponse;
+use squalr_engine_api::events::debugger::breakpoint_updated::breakpoint_updated_event::BreakpointUpdatedEvent;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for DebuggerRemoveBreakpointRequest {
+    type ResponseType = DebuggerRemoveBreakpointResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state
+            .get_os_providers()
+            .debugger
+            .remove_breakpoint(self.breakpoint_id)
+        {
+            Ok(breakpoint) => {
+                engine_privileged_state.emit_event(BreakpointUpdatedEvent {
+                    breakpoint: breakpoint.clone(),
+                    breakpoint_hits: Vec::new(),
+                    is_removed: true,
+                });
+
+                DebuggerRemoveBreakpointResponse {
+                    breakpoint: Some(breakpoint),
+                    error: None,
+                }
+            }
+            Err(error) => DebuggerRemoveBreakpointResponse {
+                breakpoint: None,
+                error: Some(error.to_string()),
+            },
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod debugger_remove_breakpoint_request_executor;
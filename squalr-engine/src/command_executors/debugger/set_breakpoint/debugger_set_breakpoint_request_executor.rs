@@ -0,0 +1,76 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::debugger::set_breakpoint::debugger_set_breakpoint_request::DebuggerSetBreakpointRequest;
+use squalr_engine_api::commands::debugger::set_breakpoint::debugger_set_breakpoint_response::DebuggerSetBreakpointResponse;
+use squalr_engine_session::tasks::debugger_breakpoint_session_task::DebuggerBreakpointSessionTask;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for DebuggerSetBreakpointRequest {
+    type ResponseType = DebuggerSetBreakpointResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return DebuggerSetBreakpointResponse {
+                error: Some(String::from("No process is opened to set a breakpoint in.")),
+                ..DebuggerSetBreakpointResponse::default()
+            };
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+
+        // Breakpoints are patched into host code, so modules are resolved without memory view routing.
+        let breakpoint_address = if self.module_name.is_empty() {
+            Some(self.address)
+        } else {
+            let modules = os_providers.memory_query_raw.get_modules(&process_info);
+
+            os_providers
+                .memory_query_raw
+                .resolve_module_address(&modules, &self.module_name, self.address)
+        };
+
+        let Some(breakpoint_address) = breakpoint_address else {
+            return DebuggerSetBreakpointResponse {
+                error: Some(format!("Failed to resolve module `{}`.", self.module_name)),
+                ..DebuggerSetBreakpointResponse::default()
+            };
+        };
+
+        match os_providers
+            .debugger
+            .set_breakpoint(&process_info, breakpoint_address, self.breakpoint_mode)
+        {
+            Ok(breakpoint) => {
+                let task = DebuggerBreakpointSessionTask::start_task(
+                    breakpoint.clone(),
+                    os_providers.debugger.clone(),
+                    engine_privileged_state.create_engine_event_emitter(),
+                );
+                let trackable_task_handle = task.get_task_handle();
+
+                engine_privileged_state
+                    .get_trackable_task_manager()
+                    .register_task(task);
+
+                DebuggerSetBreakpointResponse {
+                    breakpoint: Some(breakpoint),
+                    trackable_task_handle: Some(trackable_task_handle),
+                    error: None,
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to set breakpoint: {}", error);
+
+                DebuggerSetBreakpointResponse {
+                    error: Some(error.to_string()),
+                    ..DebuggerSetBreakpointResponse::default()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod debugger_set_breakpoint_request_executor;
@@ -0,0 +1,108 @@
+use crate::command_executors::patch::apply::patch_apply_request_executor::resolve_instruction_set;
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::debugger::trace::debugger_trace_request::DebuggerTraceRequest;
+use squalr_engine_api::commands::debugger::trace::debugger_trace_response::DebuggerTraceResponse;
+use squalr_engine_api::structures::debugging::instruction_trace::InstructionTrace;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Every step keeps its disassembly and register writes, so traces are capped to keep them in memory and in a file.
+const MAX_INSTRUCTION_BUDGET: u64 = 100_000;
+
+impl PrivilegedCommandRequestExecutor for DebuggerTraceRequest {
+    type ResponseType = DebuggerTraceResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return DebuggerTraceResponse {
+                error: Some(String::from("No process is opened to trace.")),
+                ..DebuggerTraceResponse::default()
+            };
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+
+        // Tracing steps through host code, so modules are resolved without memory view routing.
+        let (start_address, end_address) = if self.module_name.is_empty() {
+            (Some(self.start_address), self.end_address)
+        } else {
+            let modules = os_providers.memory_query_raw.get_modules(&process_info);
+            let resolve_address = |address| {
+                os_providers
+                    .memory_query_raw
+                    .resolve_module_address(&modules, &self.module_name, address)
+            };
+
+            (resolve_address(self.start_address), self.end_address.and_then(resolve_address))
+        };
+
+        let Some(start_address) = start_address else {
+            return DebuggerTraceResponse {
+                error: Some(format!("Failed to resolve module `{}`.", self.module_name)),
+                ..DebuggerTraceResponse::default()
+            };
+        };
+        let instruction_set = match resolve_instruction_set(engine_privileged_state, &process_info, &self.instruction_set_id) {
+            Ok(instruction_set) => instruction_set,
+            Err(error) => {
+                return DebuggerTraceResponse {
+                    error: Some(error),
+                    ..DebuggerTraceResponse::default()
+                };
+            }
+        };
+        let instruction_budget = self.instruction_budget.clamp(1, MAX_INSTRUCTION_BUDGET);
+
+        let (traced_instructions, stop_reason) = match os_providers.debugger.trace_instructions(
+            &process_info,
+            start_address,
+            end_address,
+            self.thread_id,
+            instruction_budget,
+            Duration::from_millis(self.start_timeout_ms),
+        ) {
+            Ok(trace_result) => trace_result,
+            Err(error) => {
+                log::error!("Failed to trace instructions: {}", error);
+
+                return DebuggerTraceResponse {
+                    error: Some(error.to_string()),
+                    ..DebuggerTraceResponse::default()
+                };
+            }
+        };
+        let instruction_trace = InstructionTrace::record(process_info.get_process_id_raw(), instruction_set.as_ref(), &traced_instructions, stop_reason);
+
+        log::info!(
+            "Traced {} instructions of thread {} from 0x{:X}, stopping at 0x{:X} because {}.",
+            instruction_trace.get_steps().len(),
+            instruction_trace.get_thread_id(),
+            instruction_trace.get_start_address(),
+            instruction_trace.get_stop_address(),
+            instruction_trace.get_stop_reason()
+        );
+
+        // A trace that failed to save is still returned, since it cannot be recorded again once the code has run.
+        let error = self.trace_file_path.as_ref().and_then(|trace_file_path| {
+            instruction_trace
+                .save_to_file(trace_file_path)
+                .err()
+                .map(|error| format!("Failed to save the trace to `{}`: {}", trace_file_path.display(), error))
+        });
+
+        if let Some(error) = &error {
+            log::error!("{}", error);
+        }
+
+        DebuggerTraceResponse {
+            instruction_trace: Some(instruction_trace),
+            error,
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod debugger_trace_request_executor;
@@ -0,0 +1,26 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::debugger::unwatch::debugger_unwatch_request::DebuggerUnwatchRequest;
+use squalr_engine_api::commands::debugger::unwatch::debugger_unwatch_response::DebuggerUnwatchResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for DebuggerUnwatchRequest {
+    type ResponseType = DebuggerUnwatchResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state
+            .get_os_providers()
+            .debugger
+            .remove_watchpoint(self.watchpoint_id)
+        {
+            Ok(watchpoint_hits) => DebuggerUnwatchResponse { watchpoint_hits, error: None },
+            Err(error) => DebuggerUnwatchResponse {
+                watchpoint_hits: Vec::new(),
+                error: Some(error.to_string()),
+            },
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod debugger_unwatch_request_executor;
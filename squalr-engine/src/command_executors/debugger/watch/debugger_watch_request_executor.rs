@@ -0,0 +1,61 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::debugger::watch::debugger_watch_request::DebuggerWatchRequest;
+use squalr_engine_api::commands::debugger::watch::debugger_watch_response::DebuggerWatchResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for DebuggerWatchRequest {
+    type ResponseType = DebuggerWatchResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return DebuggerWatchResponse {
+                watchpoint: None,
+                error: Some(String::from("No process is opened to watch.")),
+            };
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+
+        // Hardware watchpoints are armed on host addresses, so modules are resolved without memory view routing.
+        let watch_address = if self.module_name.is_empty() {
+            Some(self.address)
+        } else {
+            let modules = os_providers.memory_query_raw.get_modules(&process_info);
+
+            os_providers
+                .memory_query_raw
+                .resolve_module_address(&modules, &self.module_name, self.address)
+        };
+
+        let Some(watch_address) = watch_address else {
+            return DebuggerWatchResponse {
+                watchpoint: None,
+                error: Some(format!("Failed to resolve module `{}`.", self.module_name)),
+            };
+        };
+
+        match os_providers
+            .debugger
+            .set_watchpoint(&process_info, watch_address, self.watchpoint_size, self.watchpoint_access)
+        {
+            Ok(watchpoint) => DebuggerWatchResponse {
+                watchpoint: Some(watchpoint),
+                error: None,
+            },
+            Err(error) => {
+                log::error!("Failed to set watchpoint: {}", error);
+
+                DebuggerWatchResponse {
+                    watchpoint: None,
+                    error: Some(error.to_string()),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod debugger_watch_request_executor;
@@ -0,0 +1,43 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::allocate::memory_allocate_request::MemoryAllocateRequest;
+use squalr_engine_api::commands::memory::allocate::memory_allocate_response::MemoryAllocateResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryAllocateRequest {
+    type ResponseType = MemoryAllocateResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return MemoryAllocateResponse {
+                error: Some(String::from("No process is opened to allocate memory in.")),
+                ..MemoryAllocateResponse::default()
+            };
+        };
+
+        match engine_privileged_state
+            .get_os_providers()
+            .memory_allocation
+            .allocate(&process_info, self.preferred_address, self.size, self.protection)
+        {
+            Ok(region) => MemoryAllocateResponse {
+                region: Some(region),
+                error: None,
+            },
+            Err(error) => {
+                log::error!("Failed to allocate memory: {}", error);
+
+                MemoryAllocateResponse {
+                    error: Some(error.to_string()),
+                    ..MemoryAllocateResponse::default()
+                }
+            }
+        }
+    }
+}
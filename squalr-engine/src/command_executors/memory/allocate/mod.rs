@@ -0,0 +1 @@
+pub mod memory_allocate_request_executor;
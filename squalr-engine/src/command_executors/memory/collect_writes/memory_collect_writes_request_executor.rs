@@ -0,0 +1,163 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::collect_writes::memory_collect_writes_request::MemoryCollectWritesRequest;
+use squalr_engine_api::commands::memory::collect_writes::memory_collect_writes_response::MemoryCollectWritesResponse;
+use squalr_engine_api::structures::memory::{normalized_region::NormalizedRegion, written_page::WrittenPage};
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_session::memory_write_tracking::memory_write_tracking_session::MemoryWriteTrackingSession;
+use std::sync::Arc;
+
+/// Page size used to group changed bytes into pages when the OS does not report written pages.
+const COMPARISON_PAGE_SIZE: u64 = 0x1000;
+
+impl PrivilegedCommandRequestExecutor for MemoryCollectWritesRequest {
+    type ResponseType = MemoryCollectWritesResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return MemoryCollectWritesResponse {
+                error: Some(String::from("No process is opened to collect writes from.")),
+                ..MemoryCollectWritesResponse::default()
+            };
+        };
+        let memory_write_tracking_session = match engine_privileged_state
+            .get_memory_write_tracking_session()
+            .write()
+        {
+            Ok(mut memory_write_tracking_session) => memory_write_tracking_session.take(),
+            Err(error) => {
+                return MemoryCollectWritesResponse {
+                    error: Some(format!("Failed to acquire write lock on the write tracking session: {}", error)),
+                    ..MemoryCollectWritesResponse::default()
+                };
+            }
+        };
+        let Some(memory_write_tracking_session) = memory_write_tracking_session else {
+            return MemoryCollectWritesResponse {
+                error: Some(String::from("Write tracking has not been started.")),
+                ..MemoryCollectWritesResponse::default()
+            };
+        };
+
+        if memory_write_tracking_session.get_process_id() != process_info.get_process_id_raw() {
+            return MemoryCollectWritesResponse {
+                error: Some(String::from("Write tracking was started on a different process.")),
+                ..MemoryCollectWritesResponse::default()
+            };
+        }
+
+        let os_providers = engine_privileged_state.get_os_providers();
+        let written_page_regions = if memory_write_tracking_session.is_page_tracking_supported() {
+            match os_providers
+                .memory_write_tracking
+                .get_written_pages(&process_info, memory_write_tracking_session.get_tracked_regions())
+            {
+                Ok(written_page_regions) => written_page_regions,
+                Err(error) => {
+                    log::warn!("Falling back to comparing page contents to find writes: {}", error);
+
+                    find_changed_pages(engine_privileged_state, &process_info, &memory_write_tracking_session)
+                }
+            }
+        } else {
+            find_changed_pages(engine_privileged_state, &process_info, &memory_write_tracking_session)
+        };
+        let modules = os_providers.memory_query.get_modules(&process_info);
+        let written_pages: Vec<WrittenPage> = written_page_regions
+            .into_iter()
+            .map(|page_region| {
+                let changed_regions = memory_write_tracking_session
+                    .find_captured_bytes(&page_region)
+                    .and_then(|captured_bytes| {
+                        let mut current_bytes = vec![0u8; page_region.get_region_size() as usize];
+
+                        os_providers
+                            .memory_read
+                            .read_bytes(&process_info, page_region.get_base_address(), &mut current_bytes)
+                            .then(|| WrittenPage::find_changed_regions(page_region.get_base_address(), captured_bytes, &current_bytes))
+                    });
+                let (module_name, module_offset) = os_providers
+                    .memory_query
+                    .address_to_module(page_region.get_base_address(), &modules)
+                    .unwrap_or((String::new(), page_region.get_base_address()));
+
+                WrittenPage::new(page_region, module_name, module_offset, changed_regions)
+            })
+            .collect();
+        let tracked_byte_count = memory_write_tracking_session
+            .get_tracked_regions()
+            .iter()
+            .map(|tracked_region| tracked_region.get_region_size())
+            .sum();
+        let is_next_scan_narrowed = self.narrow_next_scan && !written_pages.is_empty();
+
+        if is_next_scan_narrowed {
+            match engine_privileged_state.get_pending_scan_pages().write() {
+                Ok(mut pending_scan_pages) => {
+                    *pending_scan_pages = Some(
+                        written_pages
+                            .iter()
+                            .map(|written_page| written_page.get_page_region().clone())
+                            .collect(),
+                    );
+                }
+                Err(error) => {
+                    return MemoryCollectWritesResponse {
+                        error: Some(format!("Failed to acquire write lock on pending scan pages: {}", error)),
+                        ..MemoryCollectWritesResponse::default()
+                    };
+                }
+            }
+        }
+
+        log::info!("Collected {} written page(s).", written_pages.len());
+
+        MemoryCollectWritesResponse {
+            written_pages,
+            tracked_byte_count,
+            is_next_scan_narrowed,
+            error: None,
+        }
+    }
+}
+
+/// Finds written pages by comparing the captured contents against the current contents, which misses writes that
+/// left a page unchanged.
+fn find_changed_pages(
+    engine_privileged_state: &EnginePrivilegedState,
+    process_info: &OpenedProcessInfo,
+    memory_write_tracking_session: &MemoryWriteTrackingSession,
+) -> Vec<NormalizedRegion> {
+    let memory_read = &engine_privileged_state.get_os_providers().memory_read;
+    let mut changed_pages: Vec<NormalizedRegion> = Vec::new();
+
+    for (captured_region, captured_bytes) in memory_write_tracking_session.get_captured_regions() {
+        let mut current_bytes = vec![0u8; captured_bytes.len()];
+
+        if !memory_read.read_bytes(process_info, captured_region.get_base_address(), &mut current_bytes) {
+            continue;
+        }
+
+        for changed_region in WrittenPage::find_changed_regions(captured_region.get_base_address(), captured_bytes, &current_bytes) {
+            let first_page_address = changed_region.get_base_address() / COMPARISON_PAGE_SIZE * COMPARISON_PAGE_SIZE;
+
+            for page_address in (first_page_address..changed_region.get_end_address()).step_by(COMPARISON_PAGE_SIZE as usize) {
+                if changed_pages
+                    .last()
+                    .map(|changed_page| changed_page.get_base_address())
+                    != Some(page_address)
+                {
+                    changed_pages.push(NormalizedRegion::new(page_address, COMPARISON_PAGE_SIZE));
+                }
+            }
+        }
+    }
+
+    changed_pages
+}
@@ -0,0 +1 @@
+pub mod memory_collect_writes_request_executor;
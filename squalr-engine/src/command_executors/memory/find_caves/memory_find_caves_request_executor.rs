@@ -0,0 +1,109 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use crate::services::patching::code_cave_finder::{find_padding_runs, remove_borrowed_ranges};
+use squalr_engine_api::commands::memory::find_caves::memory_find_caves_request::MemoryFindCavesRequest;
+use squalr_engine_api::commands::memory::find_caves::memory_find_caves_response::MemoryFindCavesResponse;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::patching::code_cave::CodeCave;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryFindCavesRequest {
+    type ResponseType = MemoryFindCavesResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return MemoryFindCavesResponse {
+                error: Some(String::from("No process is opened to find code caves in.")),
+                ..MemoryFindCavesResponse::default()
+            };
+        };
+        let memory_query = &engine_privileged_state.get_os_providers().memory_query;
+        let modules = memory_query.get_modules(&process_info);
+        let module_base_address = memory_query.resolve_module(&modules, &self.module_name);
+        let Some(module) = modules
+            .iter()
+            .find(|module| module_base_address != 0 && module.get_base_address() == module_base_address)
+        else {
+            return MemoryFindCavesResponse {
+                error: Some(format!("Failed to resolve module `{}`.", self.module_name)),
+                ..MemoryFindCavesResponse::default()
+            };
+        };
+        let mut code_caves = find_module_code_caves(engine_privileged_state, &process_info, module, self.minimum_size);
+
+        CodeCave::rank(&mut code_caves, self.target_address);
+
+        log::info!(
+            "Found {} code cave(s) of at least 0x{:X} bytes in `{}`.",
+            code_caves.len(),
+            self.minimum_size,
+            module.get_module_name()
+        );
+
+        MemoryFindCavesResponse {
+            code_caves,
+            target_address: self.target_address,
+            error: None,
+        }
+    }
+}
+
+/// Finds runs of padding of at least `minimum_size` bytes in the executable pages of a module. Padding that an applied patch
+/// already borrowed as its code cave is cut out of the runs, keeping the pieces around it that are still large enough.
+pub(crate) fn find_module_code_caves(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    process_info: &OpenedProcessInfo,
+    module: &NormalizedModule,
+    minimum_size: u64,
+) -> Vec<CodeCave> {
+    let os_providers = engine_privileged_state.get_os_providers();
+    let executable_pages = os_providers.memory_query.get_executable_page_bounds(
+        process_info,
+        module.get_base_address(),
+        module
+            .get_base_address()
+            .saturating_add(module.get_region_size()),
+    );
+    let mut code_regions = Vec::new();
+
+    for executable_page in executable_pages {
+        let mut code_bytes = vec![0u8; executable_page.get_region_size() as usize];
+
+        if os_providers
+            .memory_read
+            .read_bytes(process_info, executable_page.get_base_address(), &mut code_bytes)
+        {
+            code_regions.push((executable_page, code_bytes));
+        }
+    }
+
+    let padding_runs = engine_privileged_state.read_symbol_registry(|symbol_registry| find_padding_runs(symbol_registry, &code_regions, minimum_size));
+    let borrowed_code_caves: Vec<NormalizedRegion> = match engine_privileged_state.get_code_patch_registry().read() {
+        Ok(code_patch_registry) => code_patch_registry
+            .get_applied_patches()
+            .filter_map(|code_patch| code_patch.get_code_cave().cloned())
+            .collect(),
+        Err(error) => {
+            log::warn!("Failed to acquire code patch registry lock, so borrowed code caves may be reported: {}", error);
+
+            Vec::new()
+        }
+    };
+
+    remove_borrowed_ranges(padding_runs, &borrowed_code_caves, minimum_size)
+        .into_iter()
+        .map(|(cave_region, code_cave_padding)| {
+            let module_offset = cave_region.get_base_address() - module.get_base_address();
+
+            CodeCave::new(cave_region, module.get_module_name().to_string(), module_offset, code_cave_padding)
+        })
+        .collect()
+}
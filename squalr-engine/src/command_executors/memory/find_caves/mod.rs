@@ -0,0 +1 @@
+pub mod memory_find_caves_request_executor;
@@ -0,0 +1,43 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::free::memory_free_request::MemoryFreeRequest;
+use squalr_engine_api::commands::memory::free::memory_free_response::MemoryFreeResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryFreeRequest {
+    type ResponseType = MemoryFreeResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return MemoryFreeResponse {
+                error: Some(String::from("No process is opened to free memory in.")),
+                ..MemoryFreeResponse::default()
+            };
+        };
+
+        match engine_privileged_state
+            .get_os_providers()
+            .memory_allocation
+            .free(&process_info, self.address, self.size)
+        {
+            Ok(region) => MemoryFreeResponse {
+                region: Some(region),
+                error: None,
+            },
+            Err(error) => {
+                log::error!("Failed to free memory: {}", error);
+
+                MemoryFreeResponse {
+                    error: Some(error.to_string()),
+                    ..MemoryFreeResponse::default()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_free_request_executor;
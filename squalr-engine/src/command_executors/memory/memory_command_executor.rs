@@ -25,6 +25,24 @@ impl PrivilegedCommandExecutor for MemoryCommand {
             MemoryCommand::Read { memory_read_request } => memory_read_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            MemoryCommand::Allocate { memory_allocate_request } => memory_allocate_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Free { memory_free_request } => memory_free_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Protect { memory_protect_request } => memory_protect_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::TrackWrites { memory_track_writes_request } => memory_track_writes_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::CollectWrites { memory_collect_writes_request } => memory_collect_writes_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::FindCaves { memory_find_caves_request } => memory_find_caves_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
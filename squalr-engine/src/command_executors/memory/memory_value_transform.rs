@@ -0,0 +1,57 @@
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::plugins::value_transform::ResolvedValueTransform;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// Decodes bytes read from `address` in place, reading the transform's neighbor field from memory when it needs one.
+pub fn decode_memory_value(
+    engine_privileged_state: &EnginePrivilegedState,
+    process_info: &OpenedProcessInfo,
+    address: u64,
+    value_transform: &ResolvedValueTransform,
+    value_bytes: &mut [u8],
+) -> Result<(), String> {
+    let neighbor_bytes = read_neighbor_bytes(engine_privileged_state, process_info, address, value_transform, value_bytes.len())?;
+
+    value_transform.decode(value_bytes, neighbor_bytes.as_deref())
+}
+
+/// Encodes bytes about to be written to `address` in place, reading the transform's neighbor field from memory when it needs one.
+pub fn encode_memory_value(
+    engine_privileged_state: &EnginePrivilegedState,
+    process_info: &OpenedProcessInfo,
+    address: u64,
+    value_transform: &ResolvedValueTransform,
+    value_bytes: &mut [u8],
+) -> Result<(), String> {
+    let neighbor_bytes = read_neighbor_bytes(engine_privileged_state, process_info, address, value_transform, value_bytes.len())?;
+
+    value_transform.encode(value_bytes, neighbor_bytes.as_deref())
+}
+
+fn read_neighbor_bytes(
+    engine_privileged_state: &EnginePrivilegedState,
+    process_info: &OpenedProcessInfo,
+    address: u64,
+    value_transform: &ResolvedValueTransform,
+    value_size: usize,
+) -> Result<Option<Vec<u8>>, String> {
+    let Some(neighbor_offset) = value_transform.get_neighbor_offset() else {
+        return Ok(None);
+    };
+    let neighbor_address = address.wrapping_add_signed(neighbor_offset);
+    let mut neighbor_bytes = vec![0u8; value_size];
+
+    if !engine_privileged_state
+        .get_os_providers()
+        .memory_read
+        .read_bytes(process_info, neighbor_address, &mut neighbor_bytes)
+    {
+        return Err(format!(
+            "Failed to read the neighbor field of value transform '{}' at 0x{:X}.",
+            value_transform.get_value_transform_id(),
+            neighbor_address
+        ));
+    }
+
+    Ok(Some(neighbor_bytes))
+}
@@ -1,5 +1,12 @@
+pub mod allocate;
+pub mod collect_writes;
+pub mod find_caves;
+pub mod free;
 pub mod freeze;
 pub mod memory_command_executor;
+pub mod memory_value_transform;
+pub mod protect;
 pub mod query;
 pub mod read;
+pub mod track_writes;
 pub mod write;
@@ -0,0 +1,61 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::protect::memory_protect_request::MemoryProtectRequest;
+use squalr_engine_api::commands::memory::protect::memory_protect_response::MemoryProtectResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryProtectRequest {
+    type ResponseType = MemoryProtectResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return MemoryProtectResponse {
+                error: Some(String::from("No process is opened to change memory protection in.")),
+                ..MemoryProtectResponse::default()
+            };
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+
+        // Protection changes apply to host pages, so modules are resolved without memory view routing.
+        let protect_address = if self.module_name.is_empty() {
+            Some(self.address)
+        } else {
+            let modules = os_providers.memory_query_raw.get_modules(&process_info);
+
+            os_providers
+                .memory_query_raw
+                .resolve_module_address(&modules, &self.module_name, self.address)
+        };
+
+        let Some(protect_address) = protect_address else {
+            return MemoryProtectResponse {
+                error: Some(format!("Failed to resolve module `{}`.", self.module_name)),
+                ..MemoryProtectResponse::default()
+            };
+        };
+
+        match os_providers
+            .memory_allocation
+            .protect(&process_info, protect_address, self.size, self.protection)
+        {
+            Ok(region) => MemoryProtectResponse {
+                region: Some(region),
+                error: None,
+            },
+            Err(error) => {
+                log::error!("Failed to change memory protection: {}", error);
+
+                MemoryProtectResponse {
+                    error: Some(error.to_string()),
+                    ..MemoryProtectResponse::default()
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::command_executors::memory::memory_value_transform::decode_memory_value;
 use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
@@ -28,7 +29,22 @@ impl PrivilegedCommandRequestExecutor for MemoryReadRequest {
                     .get_default_valued_struct(symbol_registry)
             });
 
-            if !self.module_name.is_empty() {
+            let value_transform = match engine_privileged_state.resolve_value_transform(self.value_transform.as_ref()) {
+                Ok(value_transform) => value_transform,
+                Err(error) => {
+                    if !self.suppress_logging {
+                        log::error!("Unable to apply value transform to memory read: {}", error);
+                    }
+
+                    return MemoryReadResponse {
+                        valued_struct: out_valued_struct,
+                        address: self.address,
+                        success: false,
+                    };
+                }
+            };
+
+            let (read_address, is_address_resolved) = if !self.module_name.is_empty() {
                 let modules = if let Some(opened_process_info) = engine_privileged_state
                     .get_process_manager()
                     .get_opened_process()
@@ -40,25 +56,34 @@ impl PrivilegedCommandRequestExecutor for MemoryReadRequest {
                 let module_address = os_providers
                     .memory_query
                     .resolve_module_address(&modules, &self.module_name, self.address);
-                let success = os_providers
-                    .memory_read
-                    .read_struct(&process_info, module_address.unwrap_or(0), &mut out_valued_struct);
 
-                MemoryReadResponse {
-                    valued_struct: out_valued_struct,
-                    address: self.address,
-                    success: module_address.is_some() && success,
-                }
+                (module_address.unwrap_or(0), module_address.is_some())
             } else {
-                let success = os_providers
-                    .memory_read
-                    .read_struct(&process_info, self.address, &mut out_valued_struct);
+                (self.address, true)
+            };
+            let mut success = os_providers
+                .memory_read
+                .read_struct(&process_info, read_address, &mut out_valued_struct);
 
-                MemoryReadResponse {
-                    valued_struct: out_valued_struct,
-                    address: self.address,
-                    success,
-                }
+            if success && let Some(value_transform) = &value_transform {
+                let mut value_bytes = out_valued_struct.get_bytes();
+
+                success = match decode_memory_value(engine_privileged_state, &process_info, read_address, value_transform, &mut value_bytes) {
+                    Ok(()) => out_valued_struct.copy_from_bytes(&value_bytes),
+                    Err(error) => {
+                        if !self.suppress_logging {
+                            log::error!("Failed to decode value read from address {}: {}", self.address, error);
+                        }
+
+                        false
+                    }
+                };
+            }
+
+            MemoryReadResponse {
+                valued_struct: out_valued_struct,
+                address: self.address,
+                success: is_address_resolved && success,
             }
         } else {
             if !self.suppress_logging {
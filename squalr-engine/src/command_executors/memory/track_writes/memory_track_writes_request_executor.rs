@@ -0,0 +1,170 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::snapshot_region_builder::intersect_memory_regions;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::track_writes::memory_track_writes_request::MemoryTrackWritesRequest;
+use squalr_engine_api::commands::memory::track_writes::memory_track_writes_response::MemoryTrackWritesResponse;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_session::memory_write_tracking::memory_write_tracking_session::MemoryWriteTrackingSession;
+use squalr_engine_session::settings::scan_settings_store::ScanSettingsStore;
+use std::sync::Arc;
+
+/// Upper bound on the page contents captured when tracking starts. Written pages beyond it are still reported, but without
+/// the ranges that changed. Without OS write tracking, changes are the only way to find written pages, so the whole
+/// selection must fit.
+const MAX_CAPTURED_BYTE_COUNT: u64 = 512 * 1024 * 1024;
+
+impl PrivilegedCommandRequestExecutor for MemoryTrackWritesRequest {
+    type ResponseType = MemoryTrackWritesResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return MemoryTrackWritesResponse {
+                error: Some(String::from("No process is opened to track writes in.")),
+                ..MemoryTrackWritesResponse::default()
+            };
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+
+        // Track the same pages that a new scan reads, so that the written pages can narrow it.
+        let mut tracked_regions = os_providers
+            .memory_query
+            .get_memory_page_bounds(&process_info, ScanSettingsStore::get_page_retrieval_mode());
+
+        if !self.module_name.is_empty() || self.size > 0 {
+            let selected_region = match resolve_selected_region(self, engine_privileged_state, &process_info) {
+                Ok(selected_region) => selected_region,
+                Err(error) => {
+                    return MemoryTrackWritesResponse {
+                        error: Some(error),
+                        ..MemoryTrackWritesResponse::default()
+                    };
+                }
+            };
+
+            tracked_regions = intersect_memory_regions(tracked_regions, &[selected_region]);
+        }
+
+        if tracked_regions.is_empty() {
+            return MemoryTrackWritesResponse {
+                error: Some(String::from("No scannable pages lie within the selection.")),
+                ..MemoryTrackWritesResponse::default()
+            };
+        }
+
+        let tracked_byte_count: u64 = tracked_regions
+            .iter()
+            .map(|tracked_region| tracked_region.get_region_size())
+            .sum();
+
+        // Clear the written state before capturing contents, so that a write racing the capture still marks its page.
+        let is_page_tracking_supported = match os_providers
+            .memory_write_tracking
+            .begin_write_tracking(&process_info)
+        {
+            Ok(()) => true,
+            Err(error) => {
+                log::warn!("Falling back to comparing page contents to find writes: {}", error);
+
+                false
+            }
+        };
+
+        if !is_page_tracking_supported && tracked_byte_count > MAX_CAPTURED_BYTE_COUNT {
+            return MemoryTrackWritesResponse {
+                error: Some(format!(
+                    "Tracking 0x{:X} bytes without OS write tracking needs more than the 0x{:X} bytes that can be captured. Select a module or range.",
+                    tracked_byte_count, MAX_CAPTURED_BYTE_COUNT
+                )),
+                ..MemoryTrackWritesResponse::default()
+            };
+        }
+
+        let mut captured_regions = Vec::new();
+        let mut captured_byte_count = 0u64;
+
+        for tracked_region in &tracked_regions {
+            if captured_byte_count + tracked_region.get_region_size() > MAX_CAPTURED_BYTE_COUNT {
+                log::warn!("Stopped capturing page contents at 0x{:X} bytes.", captured_byte_count);
+                break;
+            }
+
+            let mut region_bytes = vec![0u8; tracked_region.get_region_size() as usize];
+
+            // Pages can be unmapped at any time, so unreadable regions are tracked without their contents.
+            if os_providers
+                .memory_read
+                .read_bytes(&process_info, tracked_region.get_base_address(), &mut region_bytes)
+            {
+                captured_byte_count += tracked_region.get_region_size();
+                captured_regions.push((tracked_region.clone(), region_bytes));
+            }
+        }
+
+        let tracked_region_count = tracked_regions.len() as u64;
+        let memory_write_tracking_session =
+            MemoryWriteTrackingSession::new(process_info.get_process_id_raw(), tracked_regions, captured_regions, is_page_tracking_supported);
+
+        match engine_privileged_state
+            .get_memory_write_tracking_session()
+            .write()
+        {
+            Ok(mut memory_write_tracking_session_guard) => *memory_write_tracking_session_guard = Some(memory_write_tracking_session),
+            Err(error) => {
+                return MemoryTrackWritesResponse {
+                    error: Some(format!("Failed to acquire write lock on the write tracking session: {}", error)),
+                    ..MemoryTrackWritesResponse::default()
+                };
+            }
+        }
+
+        log::info!(
+            "Started tracking writes to 0x{:X} bytes across {} region(s).",
+            tracked_byte_count,
+            tracked_region_count
+        );
+
+        MemoryTrackWritesResponse {
+            tracked_region_count,
+            tracked_byte_count,
+            is_page_tracking_supported,
+            error: None,
+        }
+    }
+}
+
+/// Resolves the module or range that limits tracking.
+fn resolve_selected_region(
+    memory_track_writes_request: &MemoryTrackWritesRequest,
+    engine_privileged_state: &EnginePrivilegedState,
+    process_info: &OpenedProcessInfo,
+) -> Result<NormalizedRegion, String> {
+    if memory_track_writes_request.module_name.is_empty() {
+        return Ok(NormalizedRegion::new(memory_track_writes_request.address, memory_track_writes_request.size));
+    }
+
+    let memory_query = &engine_privileged_state.get_os_providers().memory_query;
+    let modules = memory_query.get_modules(process_info);
+    let module_base_address = memory_query.resolve_module(&modules, &memory_track_writes_request.module_name);
+    let Some(module) = modules
+        .iter()
+        .find(|module| module_base_address != 0 && module.get_base_address() == module_base_address)
+    else {
+        return Err(format!("Failed to resolve module `{}`.", memory_track_writes_request.module_name));
+    };
+
+    if memory_track_writes_request.size > 0 {
+        Ok(NormalizedRegion::new(
+            module.get_base_address() + memory_track_writes_request.address,
+            memory_track_writes_request.size,
+        ))
+    } else {
+        Ok(module.get_base_region().clone())
+    }
+}
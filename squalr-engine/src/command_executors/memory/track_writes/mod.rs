@@ -0,0 +1 @@
+pub mod memory_track_writes_request_executor;
@@ -1,3 +1,4 @@
+use crate::command_executors::memory::memory_value_transform::encode_memory_value;
 use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
@@ -16,8 +17,15 @@ impl PrivilegedCommandRequestExecutor for MemoryWriteRequest {
             .get_opened_process()
         {
             let os_providers = engine_privileged_state.get_os_providers();
+            let value_transform = match engine_privileged_state.resolve_value_transform(self.value_transform.as_ref()) {
+                Ok(value_transform) => value_transform,
+                Err(error) => {
+                    log::error!("Unable to apply value transform to memory write: {}", error);
+                    return MemoryWriteResponse { success: false };
+                }
+            };
 
-            if !self.module_name.is_empty() {
+            let (write_address, is_address_resolved) = if !self.module_name.is_empty() {
                 let modules = if let Some(opened_process_info) = engine_privileged_state
                     .get_process_manager()
                     .get_opened_process()
@@ -29,19 +37,27 @@ impl PrivilegedCommandRequestExecutor for MemoryWriteRequest {
                 let module_address = os_providers
                     .memory_query
                     .resolve_module_address(&modules, &self.module_name, self.address);
-                let success = os_providers
-                    .memory_write
-                    .write_bytes(&process_info, module_address.unwrap_or(0), &self.value);
 
-                MemoryWriteResponse {
-                    success: module_address.is_some() && success,
-                }
+                (module_address.unwrap_or(0), module_address.is_some())
             } else {
-                let success = os_providers
-                    .memory_write
-                    .write_bytes(&process_info, self.address, &self.value);
+                (self.address, true)
+            };
+
+            let mut value_bytes = self.value.clone();
+
+            if let Some(value_transform) = &value_transform
+                && let Err(error) = encode_memory_value(engine_privileged_state, &process_info, write_address, value_transform, &mut value_bytes)
+            {
+                log::error!("Failed to encode value for address {}: {}", self.address, error);
+                return MemoryWriteResponse { success: false };
+            }
+
+            let success = os_providers
+                .memory_write
+                .write_bytes(&process_info, write_address, &value_bytes);
 
-                MemoryWriteResponse { success }
+            MemoryWriteResponse {
+                success: is_address_resolved && success,
             }
         } else {
             // log::error!("No process is opened to write to.");
@@ -1,4 +1,6 @@
+pub mod debugger;
 pub mod memory;
+pub mod patch;
 pub mod plugins;
 pub mod pointer_scan;
 pub mod privileged_command_executor;
@@ -13,6 +15,7 @@ pub mod scan_results;
 pub mod settings;
 pub mod snapshot_region_builder;
 pub mod struct_scan;
+pub mod thread;
 pub mod trackable_tasks;
 pub mod unprivileged_command_executor;
 pub mod unprivileged_request_executor;
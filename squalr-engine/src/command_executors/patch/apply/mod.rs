@@ -0,0 +1 @@
+pub mod patch_apply_request_executor;
@@ -0,0 +1,354 @@
+use crate::command_executors::memory::find_caves::memory_find_caves_request_executor::find_module_code_caves;
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use crate::services::patching::code_patch_planner::{CodePatchPlan, plan_detour_patch, plan_nop_patch, plan_replace_patch};
+use squalr_engine_api::commands::patch::apply::patch_apply_request::PatchApplyRequest;
+use squalr_engine_api::commands::patch::apply::patch_apply_response::PatchApplyResponse;
+use squalr_engine_api::plugins::instruction_set::InstructionSet;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::memory_protection::MemoryProtection;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::patching::code_patch::CodePatch;
+use squalr_engine_api::structures::patching::code_patch_kind::CodePatchKind;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::sync::Arc;
+
+/// The number of bytes read past the patched range, enough to decode the longest instruction that a patch can cut into.
+const CODE_READ_PADDING: usize = 32;
+const CODE_CAVE_SIZE: u64 = 0x1000;
+
+/// Detours prefer caves within this distance of the hook, so that a short relative jump can reach them.
+const NEAR_CODE_CAVE_RANGE: u64 = 0x7FFF_0000;
+const NEAR_CODE_CAVE_STRIDE: u64 = 0x0100_0000;
+const NEAR_CODE_CAVE_ATTEMPTS: u64 = 4;
+
+/// The smallest run of module padding considered for detour code.
+const BORROWED_CODE_CAVE_MINIMUM_SIZE: u64 = 0x20;
+
+impl PrivilegedCommandRequestExecutor for PatchApplyRequest {
+    type ResponseType = PatchApplyResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return PatchApplyResponse {
+                error: Some(String::from("No process is opened to patch.")),
+                ..PatchApplyResponse::default()
+            };
+        };
+
+        match apply_code_patch(engine_privileged_state, &process_info, self) {
+            Ok(code_patch) => PatchApplyResponse {
+                code_patch: Some(code_patch),
+                error: None,
+            },
+            Err(error) => {
+                log::error!("Failed to apply code patch: {}", error);
+
+                PatchApplyResponse {
+                    error: Some(error),
+                    ..PatchApplyResponse::default()
+                }
+            }
+        }
+    }
+}
+
+fn apply_code_patch(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    process_info: &OpenedProcessInfo,
+    patch_apply_request: &PatchApplyRequest,
+) -> Result<CodePatch, String> {
+    let code_patch_definition = &patch_apply_request.code_patch_definition;
+    let os_providers = engine_privileged_state.get_os_providers();
+    let patch_address = if code_patch_definition.module_name.is_empty() {
+        Some(code_patch_definition.address)
+    } else {
+        let modules = os_providers.memory_query.get_modules(process_info);
+
+        os_providers
+            .memory_query
+            .resolve_module_address(&modules, &code_patch_definition.module_name, code_patch_definition.address)
+    }
+    .ok_or_else(|| format!("Failed to resolve module `{}`.", code_patch_definition.module_name))?;
+    let instruction_set = resolve_instruction_set(engine_privileged_state, process_info, &code_patch_definition.instruction_set_id)?;
+    let minimum_patch_length = match &code_patch_definition.kind {
+        CodePatchKind::Nop { byte_count } => *byte_count as usize,
+        CodePatchKind::Replace { assembly_source } => instruction_set
+            .assemble_at(assembly_source, patch_address)?
+            .len(),
+        CodePatchKind::Detour { .. } => 0,
+    };
+    let mut code_bytes = vec![0u8; minimum_patch_length.saturating_add(CODE_READ_PADDING)];
+
+    if !os_providers
+        .memory_read
+        .read_bytes(process_info, patch_address, &mut code_bytes)
+    {
+        return Err(format!("Failed to read code at 0x{:X}.", patch_address));
+    }
+
+    // Detours first borrow padding near the hook, and only allocate a code cave when no padding can hold them.
+    let borrowed_code_cave = match &code_patch_definition.kind {
+        CodePatchKind::Detour { .. } => borrow_code_cave(
+            engine_privileged_state,
+            process_info,
+            instruction_set.as_ref(),
+            &code_bytes,
+            patch_address,
+            &code_patch_definition.kind,
+        ),
+        CodePatchKind::Nop { .. } | CodePatchKind::Replace { .. } => None,
+    };
+    let (code_cave, code_cave_original_bytes, code_patch_plan) = match borrowed_code_cave {
+        Some((code_cave, code_cave_original_bytes, code_patch_plan)) => (Some(code_cave), Some(code_cave_original_bytes), code_patch_plan),
+        None => {
+            let code_cave = match &code_patch_definition.kind {
+                CodePatchKind::Detour { .. } => Some(allocate_code_cave(engine_privileged_state, process_info, patch_address)?),
+                CodePatchKind::Nop { .. } | CodePatchKind::Replace { .. } => None,
+            };
+            let code_patch_plan = match plan_code_patch(
+                instruction_set.as_ref(),
+                &code_bytes,
+                patch_address,
+                &code_patch_definition.kind,
+                code_cave.as_ref(),
+            ) {
+                Ok(code_patch_plan) => code_patch_plan,
+                Err(error) => {
+                    free_code_cave(engine_privileged_state, process_info, code_cave.as_ref());
+                    return Err(error);
+                }
+            };
+
+            if let Some(code_cave) = &code_cave
+                && code_patch_plan.code_cave_bytes.len() as u64 > code_cave.get_region_size()
+            {
+                free_code_cave(engine_privileged_state, process_info, Some(code_cave));
+                return Err(String::from("The detour does not fit in its code cave."));
+            }
+
+            (code_cave, None, code_patch_plan)
+        }
+    };
+    let release_code_cave = || match &code_cave_original_bytes {
+        Some(code_cave_original_bytes) => restore_code_cave(engine_privileged_state, process_info, code_cave.as_ref(), code_cave_original_bytes),
+        None => free_code_cave(engine_privileged_state, process_info, code_cave.as_ref()),
+    };
+
+    let code_patch = CodePatch::new(
+        code_patch_definition.clone(),
+        patch_address,
+        code_patch_plan.original_bytes,
+        code_patch_plan.patched_bytes,
+        code_cave.clone(),
+    );
+    let code_patch = match code_cave_original_bytes.clone() {
+        Some(code_cave_original_bytes) => code_patch.with_code_cave_original_bytes(code_cave_original_bytes),
+        None => code_patch,
+    };
+    let code_patch_registry = engine_privileged_state.get_code_patch_registry();
+    let mut code_patch_registry_guard = match code_patch_registry.write() {
+        Ok(code_patch_registry_guard) => code_patch_registry_guard,
+        Err(error) => {
+            release_code_cave();
+            return Err(format!("Failed to acquire code patch registry lock: {}", error));
+        }
+    };
+
+    // Registering drops patches recorded for a previously opened process before checking for overlaps. The patch is recorded
+    // before anything is written, so every failure below only has to roll back the registration and the cave.
+    if let Err(error) = code_patch_registry_guard.register_patch(process_info.get_process_id_raw(), code_patch.clone()) {
+        release_code_cave();
+        return Err(error);
+    }
+
+    // The cave must be complete before the hook can route execution into it.
+    if let Some(code_cave) = &code_cave
+        && !os_providers
+            .memory_write
+            .write_bytes_ignoring_protection(process_info, code_cave.get_base_address(), &code_patch_plan.code_cave_bytes)
+    {
+        code_patch_registry_guard.remove_patch(patch_address);
+        release_code_cave();
+        return Err(format!("Failed to write the code cave at 0x{:X}.", code_cave.get_base_address()));
+    }
+
+    // The patched bytes go out in a single write, so the process never observes a partially rewritten instruction range.
+    if !os_providers
+        .memory_write
+        .write_bytes_ignoring_protection(process_info, patch_address, code_patch.get_patched_bytes())
+    {
+        code_patch_registry_guard.remove_patch(patch_address);
+        release_code_cave();
+        return Err(format!("Failed to write patched code at 0x{:X}.", patch_address));
+    }
+
+    Ok(code_patch)
+}
+
+fn plan_code_patch(
+    instruction_set: &dyn InstructionSet,
+    code_bytes: &[u8],
+    patch_address: u64,
+    code_patch_kind: &CodePatchKind,
+    code_cave: Option<&NormalizedRegion>,
+) -> Result<CodePatchPlan, String> {
+    match (code_patch_kind, code_cave) {
+        (CodePatchKind::Nop { byte_count }, _) => plan_nop_patch(instruction_set, code_bytes, *byte_count as usize),
+        (CodePatchKind::Replace { assembly_source }, _) => plan_replace_patch(instruction_set, code_bytes, patch_address, assembly_source),
+        (CodePatchKind::Detour { assembly_source }, Some(code_cave)) => {
+            plan_detour_patch(instruction_set, code_bytes, patch_address, assembly_source, code_cave.get_base_address())
+        }
+        (CodePatchKind::Detour { .. }, None) => Err(String::from("A detour requires a code cave.")),
+    }
+}
+
+/// Resolves the requested instruction set, or picks the x86 family member matching the process bitness.
+pub(crate) fn resolve_instruction_set(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    process_info: &OpenedProcessInfo,
+    instruction_set_id: &str,
+) -> Result<Arc<dyn InstructionSet>, String> {
+    let instruction_set_id = if instruction_set_id.trim().is_empty() {
+        match process_info.get_bitness() {
+            Bitness::Bit32 => "x86",
+            Bitness::Bit64 => "x64",
+        }
+    } else {
+        instruction_set_id.trim()
+    };
+
+    engine_privileged_state
+        .get_plugin_registry()
+        .get_enabled_instruction_set(instruction_set_id)
+        .ok_or_else(|| format!("No enabled plugin contributes the `{}` instruction set.", instruction_set_id))
+}
+
+/// Finds padding in the module being patched that is close enough to the hook for a relative jump and can hold the detour
+/// code. Returns the part of the padding that the detour uses, the padding it replaces, and the planned patch.
+fn borrow_code_cave(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    process_info: &OpenedProcessInfo,
+    instruction_set: &dyn InstructionSet,
+    code_bytes: &[u8],
+    patch_address: u64,
+    code_patch_kind: &CodePatchKind,
+) -> Option<(NormalizedRegion, Vec<u8>, CodePatchPlan)> {
+    let modules = engine_privileged_state
+        .get_os_providers()
+        .memory_query
+        .get_modules(process_info);
+    let module = modules
+        .iter()
+        .find(|module| module.contains_address(patch_address))?;
+    let mut code_caves = find_module_code_caves(engine_privileged_state, process_info, module, BORROWED_CODE_CAVE_MINIMUM_SIZE);
+
+    // Padding that the patch itself overwrites cannot also hold the detour.
+    code_caves.retain(|code_cave| {
+        code_cave.get_base_address().abs_diff(patch_address) < NEAR_CODE_CAVE_RANGE && code_cave.get_distance(patch_address) >= code_bytes.len() as u64
+    });
+    code_caves.sort_by_key(|code_cave| code_cave.get_distance(patch_address));
+
+    code_caves.into_iter().find_map(|code_cave| {
+        let code_patch_plan = plan_code_patch(instruction_set, code_bytes, patch_address, code_patch_kind, Some(code_cave.get_region())).ok()?;
+        let code_cave_size = code_patch_plan.code_cave_bytes.len() as u64;
+
+        if code_cave_size > code_cave.get_size() {
+            return None;
+        }
+
+        // No-operation padding mixes encodings, so the padding that undoing the patch restores is read back rather than rebuilt.
+        let mut code_cave_original_bytes = vec![0u8; code_cave_size as usize];
+
+        if !engine_privileged_state
+            .get_os_providers()
+            .memory_read
+            .read_bytes(process_info, code_cave.get_base_address(), &mut code_cave_original_bytes)
+        {
+            return None;
+        }
+
+        Some((
+            NormalizedRegion::new(code_cave.get_base_address(), code_cave_size),
+            code_cave_original_bytes,
+            code_patch_plan,
+        ))
+    })
+}
+
+/// Allocates executable memory for a detour, preferring addresses close enough to the hook for a relative jump.
+fn allocate_code_cave(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    process_info: &OpenedProcessInfo,
+    patch_address: u64,
+) -> Result<NormalizedRegion, String> {
+    let memory_allocation = &engine_privileged_state.get_os_providers().memory_allocation;
+
+    for attempt in 1..=NEAR_CODE_CAVE_ATTEMPTS {
+        let search_distance = attempt * NEAR_CODE_CAVE_STRIDE;
+
+        for preferred_address in [
+            patch_address.checked_sub(search_distance),
+            patch_address.checked_add(search_distance),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if let Ok(code_cave) = memory_allocation.allocate(process_info, preferred_address, CODE_CAVE_SIZE, MemoryProtection::READ_WRITE_EXECUTE) {
+                if code_cave.get_base_address().abs_diff(patch_address) < NEAR_CODE_CAVE_RANGE {
+                    return Ok(code_cave);
+                }
+
+                free_code_cave(engine_privileged_state, process_info, Some(&code_cave));
+            }
+        }
+    }
+
+    // Instruction sets with long jump encodings can still reach a distant cave.
+    memory_allocation
+        .allocate(process_info, 0, CODE_CAVE_SIZE, MemoryProtection::READ_WRITE_EXECUTE)
+        .map_err(|error| format!("Failed to allocate a code cave: {}", error))
+}
+
+fn free_code_cave(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    process_info: &OpenedProcessInfo,
+    code_cave: Option<&NormalizedRegion>,
+) {
+    let Some(code_cave) = code_cave else {
+        return;
+    };
+
+    if let Err(error) = engine_privileged_state
+        .get_os_providers()
+        .memory_allocation
+        .free(process_info, code_cave.get_base_address(), code_cave.get_region_size())
+    {
+        log::warn!("Failed to free code cave at 0x{:X}: {}", code_cave.get_base_address(), error);
+    }
+}
+
+fn restore_code_cave(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    process_info: &OpenedProcessInfo,
+    code_cave: Option<&NormalizedRegion>,
+    code_cave_original_bytes: &[u8],
+) {
+    let Some(code_cave) = code_cave else {
+        return;
+    };
+
+    if !engine_privileged_state
+        .get_os_providers()
+        .memory_write
+        .write_bytes_ignoring_protection(process_info, code_cave.get_base_address(), code_cave_original_bytes)
+    {
+        log::warn!("Failed to restore code cave padding at 0x{:X}.", code_cave.get_base_address());
+    }
+}
@@ -0,0 +1 @@
+pub mod patch_list_request_executor;
@@ -0,0 +1,24 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::patch::list::patch_list_request::PatchListRequest;
+use squalr_engine_api::commands::patch::list::patch_list_response::PatchListResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for PatchListRequest {
+    type ResponseType = PatchListResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let code_patches = match engine_privileged_state.get_code_patch_registry().read() {
+            Ok(code_patch_registry) => code_patch_registry.get_applied_patches().cloned().collect(),
+            Err(error) => {
+                log::error!("Failed to acquire code patch registry lock: {}", error);
+                Vec::new()
+            }
+        };
+
+        PatchListResponse { code_patches }
+    }
+}
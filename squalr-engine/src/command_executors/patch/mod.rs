@@ -0,0 +1,4 @@
+pub mod apply;
+pub mod list;
+pub mod patch_command_executor;
+pub mod undo;
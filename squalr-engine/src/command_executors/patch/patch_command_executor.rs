@@ -0,0 +1,27 @@
+use crate::command_executors::privileged_command_executor::PrivilegedCommandExecutor;
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::patch::patch_command::PatchCommand;
+use squalr_engine_api::commands::privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse};
+use std::sync::Arc;
+
+impl PrivilegedCommandExecutor for PatchCommand {
+    type ResponseType = PrivilegedCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
+        match self {
+            PatchCommand::Apply { patch_apply_request } => patch_apply_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            PatchCommand::List { patch_list_request } => patch_list_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            PatchCommand::Undo { patch_undo_request } => patch_undo_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod patch_undo_request_executor;
@@ -0,0 +1,102 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::patch::undo::patch_undo_request::PatchUndoRequest;
+use squalr_engine_api::commands::patch::undo::patch_undo_response::PatchUndoResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for PatchUndoRequest {
+    type ResponseType = PatchUndoResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return PatchUndoResponse {
+                error: Some(String::from("No process is opened to undo patches in.")),
+                ..PatchUndoResponse::default()
+            };
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+        let patch_address = if self.module_name.is_empty() {
+            Some(self.address)
+        } else {
+            let modules = os_providers.memory_query.get_modules(&process_info);
+
+            os_providers
+                .memory_query
+                .resolve_module_address(&modules, &self.module_name, self.address)
+        };
+
+        let Some(patch_address) = patch_address else {
+            return PatchUndoResponse {
+                error: Some(format!("Failed to resolve module `{}`.", self.module_name)),
+                ..PatchUndoResponse::default()
+            };
+        };
+        let code_patch_registry = engine_privileged_state.get_code_patch_registry();
+        let mut code_patch_registry_guard = match code_patch_registry.write() {
+            Ok(code_patch_registry_guard) => code_patch_registry_guard,
+            Err(error) => {
+                return PatchUndoResponse {
+                    error: Some(format!("Failed to acquire code patch registry lock: {}", error)),
+                    ..PatchUndoResponse::default()
+                };
+            }
+        };
+
+        // Any address inside a patch identifies it, which lets callers undo by the address they originally targeted.
+        let Some(code_patch) = code_patch_registry_guard
+            .find_overlapping_patch(patch_address, 1)
+            .cloned()
+        else {
+            return PatchUndoResponse {
+                error: Some(format!("No patch is applied at 0x{:X}.", patch_address)),
+                ..PatchUndoResponse::default()
+            };
+        };
+
+        // Restoring every original byte in one write keeps the undo atomic from the process' point of view.
+        if !os_providers
+            .memory_write
+            .write_bytes_ignoring_protection(&process_info, code_patch.get_patch_address(), code_patch.get_original_bytes())
+        {
+            return PatchUndoResponse {
+                error: Some(format!("Failed to restore original code at 0x{:X}.", code_patch.get_patch_address())),
+                ..PatchUndoResponse::default()
+            };
+        }
+
+        code_patch_registry_guard.remove_patch(code_patch.get_patch_address());
+
+        // The cave is only released once nothing jumps into it anymore. Caves found in module padding get their padding back instead.
+        if let Some(code_cave) = code_patch.get_code_cave() {
+            match code_patch.get_code_cave_original_bytes() {
+                Some(code_cave_original_bytes) => {
+                    if !os_providers
+                        .memory_write
+                        .write_bytes_ignoring_protection(&process_info, code_cave.get_base_address(), code_cave_original_bytes)
+                    {
+                        log::warn!("Failed to restore code cave padding at 0x{:X}.", code_cave.get_base_address());
+                    }
+                }
+                None => {
+                    if let Err(error) = os_providers
+                        .memory_allocation
+                        .free(&process_info, code_cave.get_base_address(), code_cave.get_region_size())
+                    {
+                        log::warn!("Failed to free code cave at 0x{:X}: {}", code_cave.get_base_address(), error);
+                    }
+                }
+            }
+        }
+
+        PatchUndoResponse {
+            code_patch: Some(code_patch),
+            error: None,
+        }
+    }
+}
@@ -32,7 +32,14 @@ impl PrivilegedCommandRequestExecutor for PointerScanStartRequest {
             return PointerScanStartResponse::default();
         };
         let effective_address_space = resolve_pointer_scan_address_space(self.address_space, &self.target);
-        let effective_pointer_size = resolve_pointer_size_for_process_bitness(self.pointer_size, effective_address_space, &process_info);
+        let native_pointer_size = match effective_address_space {
+            PointerScanAddressSpace::GameMemory => engine_privileged_state
+                .get_os_providers()
+                .memory_query
+                .get_native_pointer_size(&process_info),
+            PointerScanAddressSpace::Auto | PointerScanAddressSpace::EmulatorMemory => None,
+        };
+        let effective_pointer_size = resolve_pointer_size_for_process_bitness(self.pointer_size, effective_address_space, &process_info, native_pointer_size);
         let modules = match effective_address_space {
             PointerScanAddressSpace::Auto => engine_privileged_state
                 .get_os_providers()
@@ -166,7 +173,7 @@ mod tests {
     use squalr_engine_session::os::engine_os_provider::{
         EngineOsProviders, MemoryQueryProvider, MemoryReadProvider, MemoryWriteProvider, ProcessQueryProvider,
     };
-    use squalr_engine_session::os::{PageRetrievalMode, ProcessQueryError, ProcessQueryOptions};
+    use squalr_engine_session::os::{PageRetrievalMode, ProcessLaunchOptions, ProcessQueryError, ProcessQueryOptions};
     use std::collections::HashMap;
     use std::sync::{Arc, RwLock};
 
@@ -223,6 +230,13 @@ mod tests {
             ))
         }
 
+        fn launch_process(
+            &self,
+            _process_launch_options: &ProcessLaunchOptions,
+        ) -> Result<OpenedProcessInfo, ProcessQueryError> {
+            Err(ProcessQueryError::internal("launch_process", "not used in pointer scan tests"))
+        }
+
         fn close_process(
             &self,
             _handle: u64,
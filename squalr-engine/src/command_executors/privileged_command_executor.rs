@@ -18,7 +18,9 @@ impl ExecutableCommandPrivileged for PrivilegedCommand {
         engine_privileged_state: &Arc<EnginePrivilegedState>,
     ) -> PrivilegedCommandResponse {
         match self {
+            PrivilegedCommand::Debugger(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::Memory(command) => command.execute(engine_privileged_state),
+            PrivilegedCommand::Patch(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::Plugins(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::Process(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::Registry(command) => command.execute(engine_privileged_state),
@@ -27,6 +29,7 @@ impl ExecutableCommandPrivileged for PrivilegedCommand {
             PrivilegedCommand::PointerScan(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::StructScan(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::Settings(command) => command.execute(engine_privileged_state),
+            PrivilegedCommand::Thread(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::TrackableTasks(command) => command.execute(engine_privileged_state),
         }
     }
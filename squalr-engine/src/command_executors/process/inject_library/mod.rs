@@ -0,0 +1 @@
+pub mod process_inject_library_request_executor;
@@ -0,0 +1,207 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::process::inject_library::process_inject_library_request::ProcessInjectLibraryRequest;
+use squalr_engine_api::commands::process::inject_library::process_inject_library_response::ProcessInjectLibraryResponse;
+use squalr_engine_api::structures::memory::memory_protection::MemoryProtection;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::processes::thread_info::ThreadInfo;
+use squalr_engine_session::os::engine_os_provider::DebuggerError;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Resolves every symbol of the library while it is loaded, so that a library with missing dependencies fails to load
+/// instead of faulting in the game later.
+const RTLD_NOW: u64 = 2;
+
+/// The modules that export `dlopen`. glibc exports it from libc since 2.34, and from libdl before that.
+const DYNAMIC_LOADER_MODULE_PREFIXES: [&str; 4] = ["libc.so", "libc-", "libdl", "ld-musl"];
+
+/// The longest `dlerror` message that is read back from the process.
+const MAX_DYNAMIC_LOADER_ERROR_LENGTH: usize = 512;
+
+impl PrivilegedCommandRequestExecutor for ProcessInjectLibraryRequest {
+    type ResponseType = ProcessInjectLibraryResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return ProcessInjectLibraryResponse {
+                error: Some(String::from("No process is opened to inject a library into.")),
+                ..ProcessInjectLibraryResponse::default()
+            };
+        };
+
+        match inject_library(engine_privileged_state, &process_info, &self.library_path) {
+            Ok((injected_module, modules)) => {
+                log::info!("Loaded {} at 0x{:X}.", injected_module.get_module_name(), injected_module.get_base_address());
+
+                ProcessInjectLibraryResponse {
+                    injected_module: Some(injected_module),
+                    modules,
+                    error: None,
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to inject library: {}", error);
+
+                ProcessInjectLibraryResponse {
+                    error: Some(error),
+                    ..ProcessInjectLibraryResponse::default()
+                }
+            }
+        }
+    }
+}
+
+/// Loads a library by calling `dlopen` on a thread of the process, returning its module along with the refreshed module list.
+fn inject_library(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    process_info: &OpenedProcessInfo,
+    library_path: &str,
+) -> Result<(NormalizedModule, Vec<NormalizedModule>), String> {
+    let os_providers = engine_privileged_state.get_os_providers();
+
+    if !is_process_traced(engine_privileged_state, process_info) {
+        return Err(DebuggerError::not_traced(process_info.get_process_id_raw()).to_string());
+    }
+
+    if library_path.trim().is_empty() {
+        return Err(String::from("No library path provided."));
+    }
+
+    // The process resolves the path against its own working directory, so it is made absolute first.
+    let library_path = fs::canonicalize(library_path.trim()).map_err(|error| format!("Failed to resolve `{}`: {}.", library_path.trim(), error))?;
+    let library_path = library_path.to_string_lossy().into_owned();
+
+    // Libraries are loaded into the host process, so modules are resolved without memory view routing.
+    let modules = os_providers.memory_query_raw.get_modules(process_info);
+    let (dlopen_address, dlerror_address) = modules
+        .iter()
+        .filter(|module| is_dynamic_loader_module(module))
+        .find_map(|module| {
+            let dlopen_address = os_providers
+                .memory_query_raw
+                .resolve_module_symbol(process_info, module, "dlopen")?;
+            let dlerror_address = os_providers
+                .memory_query_raw
+                .resolve_module_symbol(process_info, module, "dlerror");
+
+            Some((dlopen_address, dlerror_address))
+        })
+        .ok_or_else(|| String::from("Failed to find `dlopen` in the modules of the process."))?;
+
+    let mut library_path_bytes = library_path.as_bytes().to_vec();
+    library_path_bytes.push(0);
+
+    let path_region = os_providers
+        .memory_allocation
+        .allocate(process_info, 0, library_path_bytes.len() as u64, MemoryProtection::READ_WRITE)
+        .map_err(|error| error.to_string())?;
+    let library_handle = if os_providers
+        .memory_write
+        .write_bytes(process_info, path_region.get_base_address(), &library_path_bytes)
+    {
+        os_providers
+            .debugger
+            .call_function(process_info, dlopen_address, &[path_region.get_base_address(), RTLD_NOW])
+            .map_err(|error| error.to_string())
+    } else {
+        Err(format!("Failed to write the library path at 0x{:X}.", path_region.get_base_address()))
+    };
+
+    if let Err(error) = os_providers
+        .memory_allocation
+        .free(process_info, path_region.get_base_address(), path_region.get_region_size())
+    {
+        log::warn!("Failed to free the library path at 0x{:X}: {}", path_region.get_base_address(), error);
+    }
+
+    if library_handle? == 0 {
+        let dynamic_loader_error =
+            dlerror_address.and_then(|dlerror_address| read_dynamic_loader_error(engine_privileged_state, process_info, dlerror_address));
+
+        return Err(match dynamic_loader_error {
+            Some(dynamic_loader_error) => format!("`dlopen` failed: {}.", dynamic_loader_error),
+            None => String::from("`dlopen` failed to load the library."),
+        });
+    }
+
+    let modules = os_providers.memory_query_raw.get_modules(process_info);
+    let injected_module = modules
+        .iter()
+        .find(|module| module.get_module_path() == Some(library_path.as_str()))
+        .or_else(|| {
+            let library_name = Path::new(&library_path).file_name()?.to_str()?;
+
+            modules
+                .iter()
+                .find(|module| module.get_module_name() == library_name)
+        })
+        .cloned()
+        .ok_or_else(|| format!("`dlopen` succeeded, but `{}` is not mapped into the process.", library_path))?;
+
+    Ok((injected_module, modules))
+}
+
+/// Remote calls need a tracer that is already attached, which is the case while threads are suspended or breakpoints are set.
+fn is_process_traced(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    process_info: &OpenedProcessInfo,
+) -> bool {
+    let os_providers = engine_privileged_state.get_os_providers();
+    let has_breakpoints = os_providers
+        .debugger
+        .get_breakpoints()
+        .iter()
+        .any(|breakpoint| breakpoint.get_process_id() == process_info.get_process_id_raw());
+
+    has_breakpoints
+        || os_providers
+            .thread_control
+            .get_threads(process_info)
+            .is_ok_and(|threads| threads.iter().any(ThreadInfo::get_is_suspended))
+}
+
+fn is_dynamic_loader_module(module: &NormalizedModule) -> bool {
+    DYNAMIC_LOADER_MODULE_PREFIXES
+        .iter()
+        .any(|module_prefix| module.get_module_name().starts_with(module_prefix))
+}
+
+/// Reads the message that `dlerror` reports for the failed load, which names the missing file or symbol.
+fn read_dynamic_loader_error(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    process_info: &OpenedProcessInfo,
+    dlerror_address: u64,
+) -> Option<String> {
+    let os_providers = engine_privileged_state.get_os_providers();
+    let message_address = os_providers
+        .debugger
+        .call_function(process_info, dlerror_address, &[])
+        .ok()
+        .filter(|message_address| *message_address != 0)?;
+    let mut message_bytes = vec![0u8; MAX_DYNAMIC_LOADER_ERROR_LENGTH];
+
+    // The message may end close to the end of its page, so it is read in shrinking chunks until a read succeeds.
+    while !message_bytes.is_empty()
+        && !os_providers
+            .memory_read
+            .read_bytes(process_info, message_address, &mut message_bytes)
+    {
+        message_bytes.truncate(message_bytes.len() / 2);
+    }
+
+    let message_length = message_bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(message_bytes.len());
+
+    (message_length > 0).then(|| String::from_utf8_lossy(&message_bytes[..message_length]).into_owned())
+}
@@ -0,0 +1 @@
+pub mod process_launch_request_executor;
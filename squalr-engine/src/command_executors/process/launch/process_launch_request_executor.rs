@@ -0,0 +1,60 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::process::launch::process_launch_request::ProcessLaunchRequest;
+use squalr_engine_api::commands::process::launch::process_launch_response::ProcessLaunchResponse;
+use squalr_engine_session::os::ProcessLaunchOptions;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ProcessLaunchRequest {
+    type ResponseType = ProcessLaunchResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        if self.executable_path.trim().is_empty() {
+            return ProcessLaunchResponse {
+                error: Some("No executable path provided. Cannot launch process.".to_string()),
+                ..Default::default()
+            };
+        }
+
+        log::info!("Launching {}...", self.executable_path);
+
+        let process_launch_options = ProcessLaunchOptions {
+            executable_path: PathBuf::from(&self.executable_path),
+            arguments: self.arguments.clone(),
+            environment: self.environment.clone(),
+            is_suspended: self.is_suspended,
+        };
+
+        match engine_privileged_state
+            .get_os_providers()
+            .process_query
+            .launch_process(&process_launch_options)
+        {
+            Ok(opened_process_info) => {
+                if self.is_suspended {
+                    log::info!(
+                        "Process {} is suspended at its entry point. Resume it with `threads resume`.",
+                        opened_process_info.get_process_id_raw()
+                    );
+                }
+
+                engine_privileged_state
+                    .get_process_manager()
+                    .set_opened_process(opened_process_info.clone());
+
+                ProcessLaunchResponse {
+                    opened_process_info: Some(opened_process_info),
+                    error: None,
+                }
+            }
+            Err(error) => ProcessLaunchResponse {
+                error: Some(error.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+}
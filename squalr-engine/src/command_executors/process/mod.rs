@@ -1,5 +1,7 @@
 pub mod close;
 pub mod icon;
+pub mod inject_library;
+pub mod launch;
 pub mod list;
 pub mod open;
 pub mod process_command_executor;
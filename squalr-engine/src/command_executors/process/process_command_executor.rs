@@ -16,6 +16,9 @@ impl PrivilegedCommandExecutor for ProcessCommand {
             ProcessCommand::Open { process_open_request } => process_open_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ProcessCommand::Launch { process_launch_request } => process_launch_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
             ProcessCommand::List { process_list_request } => process_list_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
@@ -25,6 +28,11 @@ impl PrivilegedCommandExecutor for ProcessCommand {
             ProcessCommand::Close { process_close_request } => process_close_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ProcessCommand::InjectLibrary {
+                process_inject_library_request,
+            } => process_inject_library_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
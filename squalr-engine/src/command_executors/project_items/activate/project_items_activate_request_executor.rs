@@ -1,5 +1,5 @@
 use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
-use crate::services::projects::project_item_activation::{apply_project_item_activation, dispatch_memory_freeze_request};
+use crate::services::projects::project_item_activation::{apply_project_item_activation, dispatch_code_patch_requests, dispatch_memory_freeze_request};
 use squalr_engine_api::commands::project_items::activate::project_items_activate_request::ProjectItemsActivateRequest;
 use squalr_engine_api::commands::project_items::activate::project_items_activate_response::ProjectItemsActivateResponse;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
@@ -36,6 +36,7 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsActivateRequest {
 
         if activation_change_set.has_activation_changes {
             dispatch_memory_freeze_request(engine_unprivileged_state, &activation_change_set.freeze_targets, self.is_activated);
+            dispatch_code_patch_requests(engine_unprivileged_state, &activation_change_set.code_patch_definitions, self.is_activated);
             project_manager.notify_project_items_changed();
         }
 
@@ -8,11 +8,13 @@ use squalr_engine_api::commands::project_items::create::project_items_create_req
 use squalr_engine_api::commands::project_items::create::project_items_create_response::ProjectItemsCreateResponse;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
 use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use squalr_engine_api::structures::patching::code_patch_definition::CodePatchDefinition;
 use squalr_engine_api::structures::projects::project::Project;
 use squalr_engine_api::structures::projects::project_items::built_in_types::{
     project_item_type_address::ProjectItemTypeAddress, project_item_type_address_target::ProjectItemAddressTarget,
+    project_item_type_patch::ProjectItemTypePatch,
 };
-use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use squalr_engine_api::structures::projects::project_items::{project_item::ProjectItem, project_item_ref::ProjectItemRef};
 use squalr_engine_projects::project::serialization::serializable_project_file::SerializableProjectFile;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -29,6 +31,18 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsCreateRequest {
             return create_directory_item(self, engine_unprivileged_state);
         }
 
+        if let Some(code_patch_definition) = &self.code_patch {
+            return create_patch_item(self, code_patch_definition, engine_unprivileged_state);
+        }
+
+        if let Some(project_item_type_id) = self
+            .project_item_type_id
+            .as_deref()
+            .filter(|project_item_type_id| !project_item_type_id.trim().is_empty())
+        {
+            return create_plugin_item(self, project_item_type_id, engine_unprivileged_state);
+        }
+
         create_address_item(self, engine_unprivileged_state)
     }
 }
@@ -217,6 +231,117 @@ fn create_address_item(
     }
 }
 
+fn create_plugin_item(
+    project_items_create_request: &ProjectItemsCreateRequest,
+    project_item_type_id: &str,
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+) -> ProjectItemsCreateResponse {
+    let Some(project_item) = engine_unprivileged_state.create_plugin_project_item(project_item_type_id, &project_items_create_request.project_item_name) else {
+        log::warn!(
+            "Cannot create project item of type `{}` because no enabled plugin contributes it.",
+            project_item_type_id
+        );
+
+        return ProjectItemsCreateResponse {
+            success: false,
+            created_project_item_path: PathBuf::new(),
+        };
+    };
+
+    insert_project_item(project_items_create_request, project_item, "plugin item", engine_unprivileged_state)
+}
+
+fn create_patch_item(
+    project_items_create_request: &ProjectItemsCreateRequest,
+    code_patch_definition: &CodePatchDefinition,
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+) -> ProjectItemsCreateResponse {
+    let project_item = ProjectItemTypePatch::new_project_item(&project_items_create_request.project_item_name, code_patch_definition);
+
+    insert_project_item(project_items_create_request, project_item, "patch item", engine_unprivileged_state)
+}
+
+fn insert_project_item(
+    project_items_create_request: &ProjectItemsCreateRequest,
+    project_item: ProjectItem,
+    project_item_description: &str,
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+) -> ProjectItemsCreateResponse {
+    let project_manager = engine_unprivileged_state.get_project_manager();
+    let opened_project = project_manager.get_opened_project();
+    let mut opened_project_guard = match opened_project.write() {
+        Ok(opened_project_guard) => opened_project_guard,
+        Err(error) => {
+            log::error!(
+                "Failed to acquire opened project lock for {} create command: {}",
+                project_item_description,
+                error
+            );
+
+            return ProjectItemsCreateResponse {
+                success: false,
+                created_project_item_path: PathBuf::new(),
+            };
+        }
+    };
+    let opened_project = match opened_project_guard.as_mut() {
+        Some(opened_project) => opened_project,
+        None => {
+            log::warn!("Cannot create {} without an opened project.", project_item_description);
+
+            return ProjectItemsCreateResponse {
+                success: false,
+                created_project_item_path: PathBuf::new(),
+            };
+        }
+    };
+    let project_directory_path = match opened_project.get_project_info().get_project_directory() {
+        Some(project_directory_path) => project_directory_path,
+        None => {
+            log::error!("Failed to resolve opened project directory for {} create operation.", project_item_description);
+
+            return ProjectItemsCreateResponse {
+                success: false,
+                created_project_item_path: PathBuf::new(),
+            };
+        }
+    };
+    let parent_directory_path = resolve_project_file_parent_directory_path(&project_directory_path, &project_items_create_request.parent_directory_path);
+    let project_item_file_stem = sanitize_file_name_component(&project_items_create_request.project_item_name, "project_item");
+    let created_project_item_path = generate_unique_project_item_file_path(&parent_directory_path, opened_project.get_project_items(), &project_item_file_stem);
+
+    opened_project
+        .get_project_items_mut()
+        .insert(ProjectItemRef::new(created_project_item_path.clone()), project_item);
+
+    if let Err(error) = create_placeholder_file(&created_project_item_path) {
+        log::error!("Failed creating {} placeholder file: {}", project_item_description, error);
+
+        return ProjectItemsCreateResponse {
+            success: false,
+            created_project_item_path: PathBuf::new(),
+        };
+    }
+
+    append_project_items_to_sort_order(opened_project, &project_directory_path, std::slice::from_ref(&created_project_item_path));
+
+    if let Err(error) = opened_project.save_to_path(&project_directory_path, false) {
+        log::error!("Failed to save project after {} create operation: {}", project_item_description, error);
+
+        return ProjectItemsCreateResponse {
+            success: false,
+            created_project_item_path: PathBuf::new(),
+        };
+    }
+
+    project_manager.notify_project_items_changed();
+
+    ProjectItemsCreateResponse {
+        success: true,
+        created_project_item_path,
+    }
+}
+
 fn reload_opened_project(
     opened_project_guard: &mut Option<Project>,
     project_directory_path: &Path,
@@ -240,9 +365,13 @@ mod tests {
     use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
     use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
     use squalr_engine_api::structures::memory::pointer_chain_segment::PointerChainSegment;
+    use squalr_engine_api::structures::patching::{code_patch_definition::CodePatchDefinition, code_patch_kind::CodePatchKind};
     use squalr_engine_api::structures::projects::{
-        project::Project, project_info::ProjectInfo, project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress,
-        project_items::project_item_ref::ProjectItemRef, project_manifest::ProjectManifest,
+        project::Project,
+        project_info::ProjectInfo,
+        project_items::built_in_types::{project_item_type_address::ProjectItemTypeAddress, project_item_type_patch::ProjectItemTypePatch},
+        project_items::project_item_ref::ProjectItemRef,
+        project_manifest::ProjectManifest,
     };
     use squalr_engine_projects::project::serialization::serializable_project_file::SerializableProjectFile;
     use std::path::PathBuf;
@@ -273,6 +402,8 @@ mod tests {
             module_name: Some(String::from("winmine.exe")),
             data_type_id: Some(String::from("u32")),
             pointer_offsets: Some(vec![PointerChainSegment::Symbol(String::from("Timer"))]),
+            project_item_type_id: None,
+            code_patch: None,
         }
         .execute(&engine_execution_context);
 
@@ -291,4 +422,56 @@ mod tests {
             &[PointerChainSegment::Symbol(String::from("Timer"))]
         );
     }
+
+    #[test]
+    fn create_patch_item_persists_code_patch_definition() {
+        let temp_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let project_file_path = temp_directory.path().join(Project::PROJECT_FILE);
+        let project_root_path = temp_directory.path().join(Project::PROJECT_DIR);
+        let project_root_ref = ProjectItemRef::new(project_root_path);
+        let project_info = ProjectInfo::new(project_file_path, None, ProjectManifest::default());
+        let project = Project::new(project_info, std::collections::HashMap::new(), project_root_ref);
+        let engine_unprivileged_state = create_engine_unprivileged_state(MockProjectSymbolsBindings::new());
+
+        *engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project()
+            .write()
+            .expect("Expected opened project write lock in test.") = Some(project);
+
+        let engine_execution_context: Arc<dyn EngineExecutionContext> = engine_unprivileged_state.clone();
+        let code_patch_definition = CodePatchDefinition::new(
+            0x1F2A,
+            String::from("game.exe"),
+            CodePatchKind::Replace {
+                assembly_source: String::from("nop"),
+            },
+        );
+        let project_items_create_response = ProjectItemsCreateRequest {
+            parent_directory_path: PathBuf::new(),
+            project_item_name: String::from("Infinite Ammo"),
+            is_directory: false,
+            address: None,
+            module_name: None,
+            data_type_id: None,
+            pointer_offsets: None,
+            project_item_type_id: None,
+            code_patch: Some(code_patch_definition.clone()),
+        }
+        .execute(&engine_execution_context);
+
+        assert!(project_items_create_response.success);
+
+        let loaded_project = Project::load_from_path(temp_directory.path()).expect("Expected project to load after patch create.");
+        let created_project_item = loaded_project
+            .get_project_items()
+            .values()
+            .find(|project_item| project_item.get_item_type().get_project_item_type_id() == ProjectItemTypePatch::PROJECT_ITEM_TYPE_ID)
+            .expect("Expected created patch item.");
+
+        assert_eq!(
+            ProjectItemTypePatch::get_field_code_patch_definition(created_project_item),
+            Some(code_patch_definition)
+        );
+    }
 }
@@ -2,7 +2,10 @@ use crate::command_executors::project_symbols::project_symbol_plugin_store::Engi
 use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
 use squalr_engine_api::{
     commands::{
-        memory::read::{memory_read_request::MemoryReadRequest, memory_read_response::MemoryReadResponse},
+        memory::{
+            query::{memory_query_request::MemoryQueryRequest, memory_query_response::MemoryQueryResponse},
+            read::{memory_read_request::MemoryReadRequest, memory_read_response::MemoryReadResponse},
+        },
         privileged_command_request::PrivilegedCommandRequest,
         privileged_command_response::TypedPrivilegedCommandResponse,
         project_symbols::execute_plugin_action::{
@@ -21,6 +24,7 @@ use squalr_engine_api::{
     },
 };
 use squalr_engine_session::plugins::plugin_registry::PluginRegistry;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::mpsc;
 use std::time::Duration;
@@ -136,6 +140,7 @@ impl ProcessMemoryStore for EngineSymbolTreeActionServices {
                 ContainerType::ArrayFixed(length),
             )]),
             suppress_logging: true,
+            value_transform: None,
         };
         let memory_read_command = memory_read_request.to_engine_command();
         let (memory_read_response_sender, memory_read_response_receiver) = mpsc::channel();
@@ -164,6 +169,39 @@ impl ProcessMemoryStore for EngineSymbolTreeActionServices {
 
         Ok(memory_read_response.valued_struct.get_bytes())
     }
+
+    fn resolve_module_file_path(
+        &self,
+        module_name: &str,
+    ) -> Result<Option<PathBuf>, String> {
+        let memory_query_command = MemoryQueryRequest::default().to_engine_command();
+        let (memory_query_response_sender, memory_query_response_receiver) = mpsc::channel();
+
+        self.engine_execution_context
+            .get_bindings()
+            .read()
+            .map_err(|error| format!("Failed to acquire engine bindings while querying process modules: {error}"))?
+            .dispatch_privileged_command(
+                memory_query_command,
+                Box::new(move |memory_query_response| {
+                    let conversion_result = MemoryQueryResponse::from_engine_response(memory_query_response);
+                    let _ = memory_query_response_sender.send(conversion_result);
+                }),
+            )
+            .map_err(|error| format!("Failed to dispatch module query for Symbol Tree plugin action: {error}"))?;
+
+        let memory_query_response = memory_query_response_receiver
+            .recv_timeout(Duration::from_secs(2))
+            .map_err(|error| format!("Timed out querying process modules for Symbol Tree plugin action: {error}"))?
+            .map_err(|_| String::from("Process module query returned an unexpected response type."))?;
+
+        Ok(memory_query_response
+            .modules
+            .iter()
+            .find(|module| module.get_module_name().eq_ignore_ascii_case(module_name))
+            .and_then(|module| module.get_module_path())
+            .map(PathBuf::from))
+    }
 }
 
 struct EngineSymbolTreeWindowStore;
@@ -180,7 +218,7 @@ impl SymbolTreeWindowStore for EngineSymbolTreeWindowStore {
 
 #[cfg(test)]
 mod tests {
-    use super::ProjectSymbolsExecutePluginActionRequest;
+    use super::{EngineSymbolTreeActionServices, ProjectSymbolsExecutePluginActionRequest};
     use crate::command_executors::project_symbols::test_support::{
         MockProjectSymbolsBindings, create_engine_unprivileged_state, create_project_with_symbol_catalog,
     };
@@ -188,11 +226,15 @@ mod tests {
     use squalr_engine_api::{
         commands::memory::read::{memory_read_request::MemoryReadRequest, memory_read_response::MemoryReadResponse},
         engine::engine_execution_context::EngineExecutionContext,
-        plugins::symbol_tree::symbol_tree_action::{SymbolTreeActionContext, SymbolTreeActionSelection},
+        plugins::symbol_tree::symbol_tree_action::{ProcessMemoryStore, SymbolTreeActionContext, SymbolTreeActionSelection},
         registries::symbols::symbol_registry::SymbolRegistry,
-        structures::projects::{project::Project, project_symbol_catalog::ProjectSymbolCatalog, project_symbol_module::ProjectSymbolModule},
+        structures::{
+            memory::normalized_module::NormalizedModule,
+            projects::{project::Project, project_symbol_catalog::ProjectSymbolCatalog, project_symbol_module::ProjectSymbolModule},
+        },
     };
     use squalr_engine_projects::project::serialization::serializable_project_file::SerializableProjectFile;
+    use std::path::PathBuf;
     use std::sync::Arc;
 
     #[test]
@@ -378,6 +420,22 @@ mod tests {
         assert_eq!(program_header_field_names, vec!["PT_NULL_00", "PT_NULL_01"]);
     }
 
+    #[test]
+    fn resolve_module_file_path_matches_queried_module_names() {
+        let engine_unprivileged_state = create_engine_unprivileged_state(MockProjectSymbolsBindings::new().with_memory_query_modules(vec![
+            NormalizedModule::new("squalr", 0x1000, 0x5000).with_module_path("/opt/squalr/squalr"),
+            NormalizedModule::new("libc.so.6", 0x8000, 0x2000),
+        ]));
+        let symbol_tree_action_services = EngineSymbolTreeActionServices::new(engine_unprivileged_state);
+
+        assert_eq!(
+            symbol_tree_action_services.resolve_module_file_path("SQUALR"),
+            Ok(Some(PathBuf::from("/opt/squalr/squalr")))
+        );
+        assert_eq!(symbol_tree_action_services.resolve_module_file_path("libc.so.6"), Ok(None));
+        assert_eq!(symbol_tree_action_services.resolve_module_file_path("missing"), Ok(None));
+    }
+
     fn create_test_pe_memory_read_response(memory_read_request: &MemoryReadRequest) -> MemoryReadResponse {
         let header_bytes = build_test_pe_header_bytes();
         create_memory_read_response_from_bytes(memory_read_request, &header_bytes)
@@ -0,0 +1 @@
+pub mod project_symbols_export_request_executor;
@@ -0,0 +1,206 @@
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::project_symbols::export::project_symbols_export_request::ProjectSymbolsExportRequest;
+use squalr_engine_api::commands::project_symbols::export::project_symbols_export_response::ProjectSymbolsExportResponse;
+use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::structures::projects::symbol_interchange::{
+    c_header::c_header_layout_exporter::CHeaderLayoutExporter, ghidra::ghidra_script_exporter::GhidraScriptExporter,
+    reclass::reclass_layout_exporter::ReClassLayoutExporter, symbol_catalog_export::SymbolCatalogExport, symbol_export_format::SymbolExportFormat,
+};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use zip::{CompressionMethod, ZipWriter, write::SimpleFileOptions};
+
+impl UnprivilegedCommandRequestExecutor for ProjectSymbolsExportRequest {
+    type ResponseType = ProjectSymbolsExportResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let opened_project = project_manager.get_opened_project();
+        let symbol_catalog_export = {
+            let opened_project_guard = match opened_project.read() {
+                Ok(opened_project_guard) => opened_project_guard,
+                Err(error) => {
+                    return ProjectSymbolsExportResponse {
+                        error: Some(format!("Failed to acquire opened project lock for project-symbols export command: {error}")),
+                        ..ProjectSymbolsExportResponse::default()
+                    };
+                }
+            };
+            let Some(opened_project) = opened_project_guard.as_ref() else {
+                return ProjectSymbolsExportResponse {
+                    error: Some(String::from("Cannot export symbol layouts without an opened project.")),
+                    ..ProjectSymbolsExportResponse::default()
+                };
+            };
+
+            SymbolCatalogExport::from_project_symbol_catalog(opened_project.get_project_info().get_project_symbol_catalog(), |data_type_ref| {
+                Some(engine_unprivileged_state.get_unit_size_in_bytes(data_type_ref)).filter(|unit_size_in_bytes| *unit_size_in_bytes > 0)
+            })
+        };
+        let exported_struct_layout_ids = symbol_catalog_export
+            .get_layouts()
+            .iter()
+            .map(|layout| layout.get_layout_id().to_string())
+            .collect::<Vec<_>>();
+        let export_result = match self.export_format {
+            SymbolExportFormat::CHeader => CHeaderLayoutExporter::export(&symbol_catalog_export, self.bitness),
+            SymbolExportFormat::ReClass => ReClassLayoutExporter::export(&symbol_catalog_export, self.bitness),
+            SymbolExportFormat::GhidraScript => GhidraScriptExporter::export(&symbol_catalog_export, self.bitness),
+        };
+        let warnings = export_result.get_warnings().to_vec();
+        let write_result = match self.export_format {
+            SymbolExportFormat::ReClass => write_reclass_archive(&self.output_file_path, export_result.get_contents()),
+            SymbolExportFormat::CHeader | SymbolExportFormat::GhidraScript => {
+                fs::write(&self.output_file_path, export_result.get_contents()).map_err(|error| error.to_string())
+            }
+        };
+
+        if let Err(error) = write_result {
+            return ProjectSymbolsExportResponse {
+                success: false,
+                output_file_path: None,
+                exported_struct_layout_ids: Vec::new(),
+                warnings,
+                error: Some(format!("Failed to write export file {}: {}", self.output_file_path.display(), error)),
+            };
+        }
+
+        ProjectSymbolsExportResponse {
+            success: true,
+            output_file_path: Some(self.output_file_path.clone()),
+            exported_struct_layout_ids,
+            warnings,
+            error: None,
+        }
+    }
+}
+
+/// ReClass.NET projects are zip archives holding a single `Data.xml` document.
+fn write_reclass_archive(
+    output_file_path: &Path,
+    data_document: &str,
+) -> Result<(), String> {
+    let output_file = File::create(output_file_path).map_err(|error| error.to_string())?;
+    let mut zip_writer = ZipWriter::new(output_file);
+
+    zip_writer
+        .start_file(
+            ReClassLayoutExporter::DATA_FILE_NAME,
+            SimpleFileOptions::default().compression_method(CompressionMethod::Deflated),
+        )
+        .map_err(|error| error.to_string())?;
+    zip_writer
+        .write_all(data_document.as_bytes())
+        .map_err(|error| error.to_string())?;
+    zip_writer.finish().map_err(|error| error.to_string())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectSymbolsExportRequest;
+    use crate::command_executors::project_symbols::test_support::{
+        MockProjectSymbolsBindings, create_engine_unprivileged_state, create_project_with_symbol_catalog,
+    };
+    use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+    use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+    use squalr_engine_api::registries::symbols::struct_layout_descriptor::StructLayoutDescriptor;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::data_values::container_type::ContainerType;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::projects::project_symbol_catalog::ProjectSymbolCatalog;
+    use squalr_engine_api::structures::projects::symbol_interchange::symbol_export_format::SymbolExportFormat;
+    use squalr_engine_api::structures::structs::{symbolic_field_definition::SymbolicFieldDefinition, symbolic_struct_definition::SymbolicStructDefinition};
+    use std::io::Read;
+    use std::sync::Arc;
+
+    fn create_engine_execution_context_with_player_layout(project_directory_path: &std::path::Path) -> Arc<dyn EngineExecutionContext> {
+        let project_symbol_catalog = ProjectSymbolCatalog::new(vec![StructLayoutDescriptor::new(
+            String::from("Player"),
+            SymbolicStructDefinition::new(
+                String::from("Player"),
+                vec![
+                    SymbolicFieldDefinition::new_named(String::from("health"), DataTypeRef::new("u32"), ContainerType::None),
+                    SymbolicFieldDefinition::new_named(String::from("speed"), DataTypeRef::new("f32"), ContainerType::None),
+                ],
+            ),
+        )]);
+        let project = create_project_with_symbol_catalog(project_directory_path, project_symbol_catalog);
+        let engine_unprivileged_state = create_engine_unprivileged_state(MockProjectSymbolsBindings::new());
+
+        *engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project()
+            .write()
+            .expect("Expected opened project write lock in test.") = Some(project);
+
+        engine_unprivileged_state
+    }
+
+    #[test]
+    fn export_request_writes_c_header() {
+        let temp_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let output_directory = tempfile::tempdir().expect("Expected a temporary output directory.");
+        let output_file_path = output_directory.path().join("symbols.h");
+        let engine_execution_context = create_engine_execution_context_with_player_layout(temp_directory.path());
+        let response = ProjectSymbolsExportRequest {
+            output_file_path: output_file_path.clone(),
+            export_format: SymbolExportFormat::CHeader,
+            bitness: Bitness::Bit64,
+        }
+        .execute(&engine_execution_context);
+
+        assert!(response.success, "{:?}", response.error);
+        assert_eq!(response.exported_struct_layout_ids, vec![String::from("Player")]);
+
+        let header_source = std::fs::read_to_string(&output_file_path).expect("Expected exported header to be readable.");
+
+        assert!(header_source.contains("/* 0x0000 */ uint32_t health;"));
+        assert!(header_source.contains("/* 0x0004 */ float speed;"));
+    }
+
+    #[test]
+    fn export_request_writes_reclass_archive() {
+        let temp_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let output_directory = tempfile::tempdir().expect("Expected a temporary output directory.");
+        let output_file_path = output_directory.path().join("symbols.rcnet");
+        let engine_execution_context = create_engine_execution_context_with_player_layout(temp_directory.path());
+        let response = ProjectSymbolsExportRequest {
+            output_file_path: output_file_path.clone(),
+            export_format: SymbolExportFormat::ReClass,
+            bitness: Bitness::Bit64,
+        }
+        .execute(&engine_execution_context);
+
+        assert!(response.success, "{:?}", response.error);
+
+        let archive_file = std::fs::File::open(&output_file_path).expect("Expected exported archive to exist.");
+        let mut zip_archive = zip::ZipArchive::new(archive_file).expect("Expected exported archive to be a zip file.");
+        let mut data_document = String::new();
+
+        zip_archive
+            .by_name("Data.xml")
+            .expect("Expected archive to contain Data.xml.")
+            .read_to_string(&mut data_document)
+            .expect("Expected Data.xml to be readable.");
+
+        assert!(data_document.contains("<node type=\"UInt32Node\" name=\"health\""));
+        assert!(data_document.contains("<node type=\"FloatNode\" name=\"speed\""));
+    }
+
+    #[test]
+    fn export_request_fails_without_opened_project() {
+        let engine_unprivileged_state = create_engine_unprivileged_state(MockProjectSymbolsBindings::new());
+        let engine_execution_context: Arc<dyn EngineExecutionContext> = engine_unprivileged_state.clone();
+        let response = ProjectSymbolsExportRequest::default().execute(&engine_execution_context);
+
+        assert!(!response.success);
+        assert!(response.error.is_some());
+    }
+}
@@ -0,0 +1 @@
+pub mod project_symbols_import_header_request_executor;
@@ -0,0 +1,193 @@
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use crate::services::projects::{
+    project_symbol_catalog_persistence::save_and_sync_project_symbol_catalog, project_symbol_layout_mutation::ProjectSymbolLayoutMutation,
+};
+use squalr_engine_api::commands::project_symbols::import_header::project_symbols_import_header_request::ProjectSymbolsImportHeaderRequest;
+use squalr_engine_api::commands::project_symbols::import_header::project_symbols_import_header_response::ProjectSymbolsImportHeaderResponse;
+use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::structures::projects::symbol_interchange::c_header::c_header_layout_importer::CHeaderLayoutImporter;
+use std::fs;
+use std::sync::Arc;
+
+impl UnprivilegedCommandRequestExecutor for ProjectSymbolsImportHeaderRequest {
+    type ResponseType = ProjectSymbolsImportHeaderResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let header_source_text = match fs::read_to_string(&self.header_file_path) {
+            Ok(header_source_text) => header_source_text,
+            Err(error) => {
+                return ProjectSymbolsImportHeaderResponse {
+                    error: Some(format!("Failed to read header file {}: {}", self.header_file_path.display(), error)),
+                    ..ProjectSymbolsImportHeaderResponse::default()
+                };
+            }
+        };
+        let import_result = match CHeaderLayoutImporter::import_source(&header_source_text, &self.to_import_options()) {
+            Ok(import_result) => import_result,
+            Err(error) => {
+                return ProjectSymbolsImportHeaderResponse {
+                    error: Some(format!("Failed to parse header file {}: {}", self.header_file_path.display(), error)),
+                    ..ProjectSymbolsImportHeaderResponse::default()
+                };
+            }
+        };
+        let warnings = import_result.get_warnings().to_vec();
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let opened_project = project_manager.get_opened_project();
+        let mut opened_project_guard = match opened_project.write() {
+            Ok(opened_project_guard) => opened_project_guard,
+            Err(error) => {
+                return ProjectSymbolsImportHeaderResponse {
+                    warnings,
+                    error: Some(format!(
+                        "Failed to acquire opened project lock for project-symbols import-header command: {error}"
+                    )),
+                    ..ProjectSymbolsImportHeaderResponse::default()
+                };
+            }
+        };
+        let Some(opened_project) = opened_project_guard.as_mut() else {
+            return ProjectSymbolsImportHeaderResponse {
+                warnings,
+                error: Some(String::from("Cannot import symbol layouts without an opened project.")),
+                ..ProjectSymbolsImportHeaderResponse::default()
+            };
+        };
+        let Some(project_directory_path) = opened_project.get_project_info().get_project_directory() else {
+            return ProjectSymbolsImportHeaderResponse {
+                warnings,
+                error: Some(String::from(
+                    "Failed to resolve opened project directory for project-symbols import-header command.",
+                )),
+                ..ProjectSymbolsImportHeaderResponse::default()
+            };
+        };
+
+        // Layouts are imported into a copy of the catalog, so that a layout failing partway through leaves the catalog untouched.
+        let mut project_symbol_catalog = opened_project
+            .get_project_info()
+            .get_project_symbol_catalog()
+            .clone();
+        let mut imported_struct_layout_ids = Vec::new();
+
+        for struct_layout_descriptor in import_result.take_struct_layout_descriptors() {
+            let struct_layout_id = struct_layout_descriptor.get_struct_layout_id().to_string();
+
+            if let Err(error) = ProjectSymbolLayoutMutation::upsert_struct_layout_descriptor(&mut project_symbol_catalog, None, struct_layout_descriptor) {
+                return ProjectSymbolsImportHeaderResponse {
+                    success: false,
+                    warnings,
+                    error: Some(format!("Failed to import symbol layout `{}`: {}", struct_layout_id, error)),
+                    ..ProjectSymbolsImportHeaderResponse::default()
+                };
+            }
+
+            imported_struct_layout_ids.push(struct_layout_id);
+        }
+
+        *opened_project
+            .get_project_info_mut()
+            .get_project_symbol_catalog_mut() = project_symbol_catalog;
+
+        if !save_and_sync_project_symbol_catalog(engine_unprivileged_state, opened_project, &project_directory_path) {
+            return ProjectSymbolsImportHeaderResponse {
+                success: false,
+                imported_struct_layout_ids,
+                warnings,
+                error: Some(String::from("Failed to save and sync project symbol catalog.")),
+            };
+        }
+
+        ProjectSymbolsImportHeaderResponse {
+            success: true,
+            imported_struct_layout_ids,
+            warnings,
+            error: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectSymbolsImportHeaderRequest;
+    use crate::command_executors::project_symbols::test_support::{
+        MockProjectSymbolsBindings, create_engine_unprivileged_state, create_project_with_symbol_catalog,
+    };
+    use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+    use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::projects::symbol_interchange::c_header::c_header_import_options::CHeaderDataModel;
+    use squalr_engine_api::structures::projects::{project::Project, project_symbol_catalog::ProjectSymbolCatalog};
+    use squalr_engine_projects::project::serialization::serializable_project_file::SerializableProjectFile;
+    use std::sync::Arc;
+
+    #[test]
+    fn import_header_request_persists_imported_layouts() {
+        let temp_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let header_directory = tempfile::tempdir().expect("Expected a temporary header directory.");
+        let header_file_path = header_directory.path().join("player.h");
+
+        std::fs::write(
+            &header_file_path,
+            "struct Weapon { int32_t ammo; }; typedef struct { struct Weapon *weapon; float health; } Player;",
+        )
+        .expect("Expected header file to be written.");
+
+        let project = create_project_with_symbol_catalog(temp_directory.path(), ProjectSymbolCatalog::default());
+        let engine_unprivileged_state = create_engine_unprivileged_state(MockProjectSymbolsBindings::new());
+
+        *engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project()
+            .write()
+            .expect("Expected opened project write lock in test.") = Some(project);
+
+        let engine_execution_context: Arc<dyn EngineExecutionContext> = engine_unprivileged_state.clone();
+        let response = ProjectSymbolsImportHeaderRequest {
+            header_file_path,
+            bitness: Bitness::Bit32,
+            data_model: CHeaderDataModel::Llp64,
+            layout_id_prefix: String::from("game"),
+        }
+        .execute(&engine_execution_context);
+
+        assert!(response.success, "{:?}", response.error);
+        assert_eq!(
+            response.imported_struct_layout_ids,
+            vec![String::from("game.Weapon"), String::from("game.Player")]
+        );
+
+        let loaded_project = Project::load_from_path(temp_directory.path()).expect("Expected imported project to load from disk.");
+        let player_descriptor = loaded_project
+            .get_project_info()
+            .get_project_symbol_catalog()
+            .get_struct_layout_descriptors()
+            .iter()
+            .find(|struct_layout_descriptor| struct_layout_descriptor.get_struct_layout_id() == "game.Player")
+            .expect("Expected imported player layout.");
+
+        assert_eq!(
+            player_descriptor
+                .get_struct_layout_definition()
+                .get_declared_size_in_bytes(),
+            Some(8)
+        );
+    }
+
+    #[test]
+    fn import_header_request_fails_for_missing_file() {
+        let engine_unprivileged_state = create_engine_unprivileged_state(MockProjectSymbolsBindings::new());
+        let engine_execution_context: Arc<dyn EngineExecutionContext> = engine_unprivileged_state.clone();
+        let response = ProjectSymbolsImportHeaderRequest {
+            header_file_path: std::path::PathBuf::from("/nonexistent/squalr/header.h"),
+            ..ProjectSymbolsImportHeaderRequest::default()
+        }
+        .execute(&engine_execution_context);
+
+        assert!(!response.success);
+        assert!(response.error.is_some());
+    }
+}
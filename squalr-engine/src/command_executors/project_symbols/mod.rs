@@ -4,10 +4,14 @@ pub mod delete;
 pub mod delete_layout;
 pub mod delete_resolver;
 pub mod execute_plugin_action;
+pub mod export;
+pub mod import_header;
 pub mod list;
 pub mod project_symbol_plugin_store;
 pub mod project_symbols_command_executor;
+pub mod references;
 pub mod rename;
+pub mod rename_layout;
 pub mod rename_module;
 #[cfg(test)]
 pub mod test_support;
@@ -46,14 +46,34 @@ impl UnprivilegedCommandExecutor for ProjectSymbolsCommand {
             } => project_symbols_execute_plugin_action_request
                 .execute(engine_unprivileged_state)
                 .to_engine_response(),
+            ProjectSymbolsCommand::Export {
+                project_symbols_export_request,
+            } => project_symbols_export_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
+            ProjectSymbolsCommand::ImportHeader {
+                project_symbols_import_header_request,
+            } => project_symbols_import_header_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
             ProjectSymbolsCommand::List { project_symbols_list_request } => project_symbols_list_request
                 .execute(engine_unprivileged_state)
                 .to_engine_response(),
+            ProjectSymbolsCommand::References {
+                project_symbols_references_request,
+            } => project_symbols_references_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
             ProjectSymbolsCommand::Rename {
                 project_symbols_rename_request,
             } => project_symbols_rename_request
                 .execute(engine_unprivileged_state)
                 .to_engine_response(),
+            ProjectSymbolsCommand::RenameLayout {
+                project_symbols_rename_layout_request,
+            } => project_symbols_rename_layout_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
             ProjectSymbolsCommand::RenameModule {
                 project_symbols_rename_module_request,
             } => project_symbols_rename_module_request
@@ -0,0 +1 @@
+pub mod project_symbols_references_request_executor;
@@ -0,0 +1,122 @@
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::project_symbols::references::project_symbols_references_request::ProjectSymbolsReferencesRequest;
+use squalr_engine_api::commands::project_symbols::references::project_symbols_references_response::ProjectSymbolsReferencesResponse;
+use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::structures::projects::symbol_references::{
+    project_symbol_reference_index::ProjectSymbolReferenceIndex, project_symbol_reference_target::ProjectSymbolReferenceTarget,
+};
+use std::sync::Arc;
+
+impl UnprivilegedCommandRequestExecutor for ProjectSymbolsReferencesRequest {
+    type ResponseType = ProjectSymbolsReferencesResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let opened_project = project_manager.get_opened_project();
+        let opened_project_guard = match opened_project.read() {
+            Ok(opened_project_guard) => opened_project_guard,
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock for project-symbols references command: {}", error);
+                return ProjectSymbolsReferencesResponse::default();
+            }
+        };
+        let Some(opened_project) = opened_project_guard.as_ref() else {
+            log::warn!("Cannot list symbol references without an opened project.");
+            return ProjectSymbolsReferencesResponse::default();
+        };
+        let project_symbol_catalog = opened_project.get_project_info().get_project_symbol_catalog();
+        let target = match ProjectSymbolReferenceTarget::resolve(project_symbol_catalog, &self.symbol) {
+            Ok(target) => target,
+            Err(error) => {
+                return ProjectSymbolsReferencesResponse {
+                    success: false,
+                    target: self.symbol.clone(),
+                    references: Vec::new(),
+                    error: Some(error),
+                };
+            }
+        };
+        let project_symbol_reference_index = ProjectSymbolReferenceIndex::build(project_symbol_catalog, opened_project.get_project_items());
+
+        ProjectSymbolsReferencesResponse {
+            success: true,
+            target: target.to_string(),
+            references: project_symbol_reference_index.get_references(&target).to_vec(),
+            error: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectSymbolsReferencesRequest;
+    use crate::command_executors::project_symbols::test_support::{
+        MockProjectSymbolsBindings, create_engine_unprivileged_state, create_project_with_symbol_catalog,
+    };
+    use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+    use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+    use squalr_engine_api::registries::symbols::struct_layout_descriptor::StructLayoutDescriptor;
+    use squalr_engine_api::structures::projects::{
+        project_symbol_catalog::ProjectSymbolCatalog, project_symbol_claim::ProjectSymbolClaim, project_symbol_module::ProjectSymbolModule,
+        project_symbol_module_field::ProjectSymbolModuleField,
+    };
+    use squalr_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+    use std::sync::Arc;
+
+    #[test]
+    fn references_request_lists_type_references_and_reports_unknown_symbols() {
+        let temp_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let mut symbol_module = ProjectSymbolModule::new(String::from("game.exe"), 0x200);
+        symbol_module
+            .get_fields_mut()
+            .push(ProjectSymbolModuleField::new(String::from("LocalPlayer"), 0x100, String::from("player*(u64)")));
+        let project_symbol_catalog = ProjectSymbolCatalog::new_with_modules_and_symbol_claims(
+            vec![symbol_module],
+            vec![StructLayoutDescriptor::new(
+                String::from("player"),
+                SymbolicStructDefinition::new(String::from("player"), Vec::new()),
+            )],
+            vec![ProjectSymbolClaim::new_absolute_address(
+                String::from("Players"),
+                0x1234,
+                String::from("player[4]"),
+            )],
+        );
+        let project = create_project_with_symbol_catalog(temp_directory.path(), project_symbol_catalog);
+        let engine_unprivileged_state = create_engine_unprivileged_state(MockProjectSymbolsBindings::new());
+
+        *engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project()
+            .write()
+            .expect("Expected opened project write lock in test.") = Some(project);
+
+        let engine_execution_context: Arc<dyn EngineExecutionContext> = engine_unprivileged_state.clone();
+        let references_response = ProjectSymbolsReferencesRequest {
+            symbol: String::from("player"),
+        }
+        .execute(&engine_execution_context);
+
+        assert!(references_response.success);
+        assert_eq!(references_response.target, "player");
+        assert_eq!(
+            references_response
+                .references
+                .iter()
+                .map(|reference| reference.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                String::from("module field module:game.exe:100: player*(u64)"),
+                String::from("symbol claim absolute:1234: player[4]"),
+            ]
+        );
+
+        let missing_references_response = ProjectSymbolsReferencesRequest { symbol: String::from("enemy") }.execute(&engine_execution_context);
+
+        assert!(!missing_references_response.success);
+        assert!(missing_references_response.error.is_some());
+    }
+}
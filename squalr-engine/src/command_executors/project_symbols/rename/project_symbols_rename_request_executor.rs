@@ -1,9 +1,13 @@
 use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
-use crate::services::projects::{project_symbol_catalog_persistence::save_and_sync_project_symbol_catalog, project_symbol_name_scope::ProjectSymbolNameScope};
+use crate::services::projects::{
+    project_symbol_catalog_persistence::save_and_sync_project_symbol_catalog, project_symbol_name_scope::ProjectSymbolNameScope,
+    project_symbol_reference_rename::ProjectSymbolReferenceRename,
+};
 use squalr_engine_api::commands::project_symbols::rename::project_symbols_rename_request::ProjectSymbolsRenameRequest;
 use squalr_engine_api::commands::project_symbols::rename::project_symbols_rename_response::ProjectSymbolsRenameResponse;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
 use squalr_engine_api::structures::projects::project_symbol_locator::ProjectSymbolLocator;
+use squalr_engine_api::structures::projects::symbol_references::project_symbol_reference_rewriter::ProjectSymbolReferenceRewriter;
 use std::sync::Arc;
 
 impl UnprivilegedCommandRequestExecutor for ProjectSymbolsRenameRequest {
@@ -37,58 +41,58 @@ impl UnprivilegedCommandRequestExecutor for ProjectSymbolsRenameRequest {
             return ProjectSymbolsRenameResponse::default();
         }
 
-        let project_symbol_catalog = opened_project
-            .get_project_info_mut()
-            .get_project_symbol_catalog_mut();
-        let did_rename = if let Some(symbol_claim) = project_symbol_catalog.find_symbol_claim(&self.symbol_locator_key) {
-            let display_name = ProjectSymbolNameScope::deduplicate_display_name(
+        let project_symbol_catalog = opened_project.get_project_info().get_project_symbol_catalog();
+        let display_name = if let Some(symbol_claim) = project_symbol_catalog.find_symbol_claim(&self.symbol_locator_key) {
+            ProjectSymbolNameScope::deduplicate_display_name(
                 project_symbol_catalog,
                 project_symbol_catalog.get_symbol_claims(),
                 symbol_claim.get_locator(),
                 trimmed_display_name,
                 Some(&self.symbol_locator_key),
-            );
-
-            if let Some(symbol_claim) = project_symbol_catalog.find_symbol_claim_mut(&self.symbol_locator_key) {
-                symbol_claim.set_display_name(display_name);
-                true
-            } else {
-                false
-            }
+            )
         } else if let Some((symbol_module, module_field)) = project_symbol_catalog.find_module_field(&self.symbol_locator_key) {
             let locator = ProjectSymbolLocator::new_module_offset(symbol_module.get_module_name().to_string(), module_field.get_offset());
-            let display_name = ProjectSymbolNameScope::deduplicate_display_name(
+
+            ProjectSymbolNameScope::deduplicate_display_name(
                 project_symbol_catalog,
                 project_symbol_catalog.get_symbol_claims(),
                 &locator,
                 trimmed_display_name,
                 Some(&self.symbol_locator_key),
-            );
-            if let Some(module_field) = project_symbol_catalog.find_module_field_mut(&self.symbol_locator_key) {
-                module_field.set_display_name(display_name);
-                true
-            } else {
-                false
-            }
+            )
         } else {
-            false
-        };
-
-        if !did_rename {
             log::warn!(
                 "Project-symbols rename request could not find symbol locator key '{}'.",
                 self.symbol_locator_key
             );
             return ProjectSymbolsRenameResponse::default();
-        }
+        };
+        let rename_edits = match ProjectSymbolReferenceRename::apply(opened_project, self.dry_run, |project_symbol_catalog, project_items| {
+            ProjectSymbolReferenceRewriter::rename_module_symbol(project_symbol_catalog, project_items, &self.symbol_locator_key, &display_name)
+        }) {
+            Ok(rename_edits) => rename_edits,
+            Err(error) => {
+                log::warn!("Project-symbols rename request failed: {}", error);
+                return ProjectSymbolsRenameResponse::default();
+            }
+        };
 
-        if !save_and_sync_project_symbol_catalog(engine_unprivileged_state, opened_project, &project_directory_path) {
-            return ProjectSymbolsRenameResponse::default();
+        if !self.dry_run && !rename_edits.is_empty() {
+            if !save_and_sync_project_symbol_catalog(engine_unprivileged_state, opened_project, &project_directory_path) {
+                return ProjectSymbolsRenameResponse::default();
+            }
+
+            if ProjectSymbolReferenceRename::edits_project_items(&rename_edits) {
+                drop(opened_project_guard);
+                project_manager.notify_project_items_changed();
+            }
         }
 
         ProjectSymbolsRenameResponse {
             success: true,
             symbol_locator_key: self.symbol_locator_key.clone(),
+            dry_run: self.dry_run,
+            edits: rename_edits,
         }
     }
 }
@@ -101,11 +105,21 @@ mod tests {
     };
     use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
     use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+    use squalr_engine_api::registries::symbols::symbolic_resolver_descriptor::SymbolicResolverDescriptor;
+    use squalr_engine_api::structures::data_values::pointer_scan_pointer_size::PointerScanPointerSize;
+    use squalr_engine_api::structures::memory::{pointer::Pointer, pointer_chain_segment::PointerChainSegment};
+    use squalr_engine_api::structures::projects::project_items::{
+        built_in_types::project_item_type_pointer::ProjectItemTypePointer, project_item_ref::ProjectItemRef,
+    };
     use squalr_engine_api::structures::projects::{
         project::Project, project_symbol_catalog::ProjectSymbolCatalog, project_symbol_claim::ProjectSymbolClaim, project_symbol_module::ProjectSymbolModule,
         project_symbol_module_field::ProjectSymbolModuleField,
     };
+    use squalr_engine_api::structures::structs::symbolic_resolver_definition::{
+        SymbolicResolverDefinition, SymbolicResolverNode, SymbolicResolverRelativeSymbolPath,
+    };
     use squalr_engine_projects::project::serialization::serializable_project_file::SerializableProjectFile;
+    use std::fs::{self, File};
     use std::sync::Arc;
 
     #[test]
@@ -134,6 +148,7 @@ mod tests {
         let project_symbols_rename_response = ProjectSymbolsRenameRequest {
             symbol_locator_key: String::from("absolute:1234"),
             display_name: String::from("Player Manager"),
+            dry_run: false,
         }
         .execute(&engine_execution_context);
 
@@ -180,6 +195,7 @@ mod tests {
         let project_symbols_rename_response = ProjectSymbolsRenameRequest {
             symbol_locator_key: String::from("module:game.exe:4"),
             display_name: String::from("Timer"),
+            dry_run: false,
         }
         .execute(&engine_execution_context);
 
@@ -195,4 +211,141 @@ mod tests {
         assert_eq!(module_fields[0].get_display_name(), "Timer");
         assert_eq!(module_fields[1].get_display_name(), "Timer_0");
     }
+
+    #[test]
+    fn rename_project_symbol_request_rewrites_references_and_supports_dry_run() {
+        let temp_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let mut symbol_module = ProjectSymbolModule::new(String::from("game.exe"), 0x100);
+        symbol_module
+            .get_fields_mut()
+            .push(ProjectSymbolModuleField::new(String::from("Health"), 0x40, String::from("u32")));
+        let project_symbol_catalog = ProjectSymbolCatalog::new_with_modules_resolvers_and_symbol_claims(
+            vec![symbol_module],
+            Vec::new(),
+            vec![SymbolicResolverDescriptor::new(
+                String::from("health_max"),
+                SymbolicResolverDefinition::new(SymbolicResolverNode::new_global_symbol_field(
+                    String::from("game.exe"),
+                    SymbolicResolverRelativeSymbolPath::from_dot_path("Health.max"),
+                )),
+            )],
+            Vec::new(),
+        );
+        let mut project = create_project_with_symbol_catalog(temp_directory.path(), project_symbol_catalog);
+        let project_item_ref = ProjectItemRef::new(
+            temp_directory
+                .path()
+                .join(Project::PROJECT_DIR)
+                .join("health_pointer.json"),
+        );
+        let project_item = ProjectItemTypePointer::new_project_item(
+            "Health Pointer",
+            &Pointer::new_with_size_and_segments(
+                0,
+                vec![
+                    PointerChainSegment::Symbol(String::from("Health")),
+                    PointerChainSegment::Offset(0x4),
+                ],
+                String::from("game.exe"),
+                PointerScanPointerSize::Pointer64,
+            ),
+            "",
+            "u32",
+        );
+
+        fs::create_dir_all(temp_directory.path().join(Project::PROJECT_DIR)).expect("Expected project item directory to be created.");
+        File::create(project_item_ref.get_project_item_path()).expect("Expected pointer project item file to be created.");
+        project
+            .get_project_items_mut()
+            .insert(project_item_ref.clone(), project_item);
+        project
+            .save_to_path(temp_directory.path(), true)
+            .expect("Expected test project to save.");
+
+        let engine_unprivileged_state = create_engine_unprivileged_state(MockProjectSymbolsBindings::new());
+
+        *engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project()
+            .write()
+            .expect("Expected opened project write lock in test.") = Some(project);
+
+        let engine_execution_context: Arc<dyn EngineExecutionContext> = engine_unprivileged_state.clone();
+        let dry_run_response = ProjectSymbolsRenameRequest {
+            symbol_locator_key: String::from("module:game.exe:40"),
+            display_name: String::from("Vitality"),
+            dry_run: true,
+        }
+        .execute(&engine_execution_context);
+
+        assert!(dry_run_response.success);
+        assert!(dry_run_response.dry_run);
+        assert_eq!(
+            dry_run_response
+                .edits
+                .iter()
+                .map(|rename_edit| rename_edit.get_new_text().to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                String::from("Vitality"),
+                String::from("game.exe!Vitality.max"),
+                String::from("game.exe: Vitality, 0x4"),
+            ]
+        );
+
+        let loaded_project = Project::load_from_path(temp_directory.path()).expect("Expected dry-run project to load from disk.");
+
+        assert_eq!(
+            loaded_project
+                .get_project_info()
+                .get_project_symbol_catalog()
+                .get_symbol_modules()[0]
+                .get_fields()[0]
+                .get_display_name(),
+            "Health"
+        );
+
+        let rename_response = ProjectSymbolsRenameRequest {
+            symbol_locator_key: String::from("module:game.exe:40"),
+            display_name: String::from("Vitality"),
+            dry_run: false,
+        }
+        .execute(&engine_execution_context);
+
+        assert!(rename_response.success);
+        assert_eq!(rename_response.edits, dry_run_response.edits);
+
+        let loaded_project = Project::load_from_path(temp_directory.path()).expect("Expected renamed project to load from disk.");
+        let loaded_project_symbol_catalog = loaded_project.get_project_info().get_project_symbol_catalog();
+
+        assert_eq!(
+            loaded_project_symbol_catalog.get_symbol_modules()[0].get_fields()[0].get_display_name(),
+            "Vitality"
+        );
+        assert_eq!(
+            loaded_project_symbol_catalog
+                .find_symbolic_resolver_descriptor("health_max")
+                .map(|symbolic_resolver_descriptor| symbolic_resolver_descriptor
+                    .get_resolver_definition()
+                    .get_root_node()
+                    .clone()),
+            Some(SymbolicResolverNode::new_global_symbol_field(
+                String::from("game.exe"),
+                SymbolicResolverRelativeSymbolPath::from_dot_path("Vitality.max"),
+            ))
+        );
+
+        let loaded_project_item = loaded_project
+            .get_project_items()
+            .get(&project_item_ref)
+            .expect("Expected pointer project item to load from disk.");
+
+        assert_eq!(
+            ProjectItemTypePointer::get_field_pointer_chain_segments(loaded_project_item),
+            vec![
+                PointerChainSegment::Symbol(String::from("Vitality")),
+                PointerChainSegment::Offset(0x4),
+            ]
+        );
+    }
 }
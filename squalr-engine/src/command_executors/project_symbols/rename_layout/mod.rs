@@ -0,0 +1 @@
+pub mod project_symbols_rename_layout_request_executor;
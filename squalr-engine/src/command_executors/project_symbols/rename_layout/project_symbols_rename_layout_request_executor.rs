@@ -0,0 +1,152 @@
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use crate::services::projects::{
+    project_symbol_catalog_persistence::save_and_sync_project_symbol_catalog, project_symbol_reference_rename::ProjectSymbolReferenceRename,
+};
+use squalr_engine_api::commands::project_symbols::rename_layout::project_symbols_rename_layout_request::ProjectSymbolsRenameLayoutRequest;
+use squalr_engine_api::commands::project_symbols::rename_layout::project_symbols_rename_layout_response::ProjectSymbolsRenameLayoutResponse;
+use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::structures::projects::symbol_references::project_symbol_reference_rewriter::ProjectSymbolReferenceRewriter;
+use std::sync::Arc;
+
+impl UnprivilegedCommandRequestExecutor for ProjectSymbolsRenameLayoutRequest {
+    type ResponseType = ProjectSymbolsRenameLayoutResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let opened_project = project_manager.get_opened_project();
+        let mut opened_project_guard = match opened_project.write() {
+            Ok(opened_project_guard) => opened_project_guard,
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock for project-symbols rename-layout command: {}", error);
+                return ProjectSymbolsRenameLayoutResponse::default();
+            }
+        };
+        let Some(opened_project) = opened_project_guard.as_mut() else {
+            log::warn!("Cannot rename symbol layouts without an opened project.");
+            return ProjectSymbolsRenameLayoutResponse::default();
+        };
+        let Some(project_directory_path) = opened_project.get_project_info().get_project_directory() else {
+            log::error!("Failed to resolve opened project directory for project-symbols rename-layout command.");
+            return ProjectSymbolsRenameLayoutResponse::default();
+        };
+        let rename_edits = match ProjectSymbolReferenceRename::apply(opened_project, self.dry_run, |project_symbol_catalog, project_items| {
+            ProjectSymbolReferenceRewriter::rename_struct_layout(project_symbol_catalog, project_items, &self.struct_layout_id, &self.new_struct_layout_id)
+        }) {
+            Ok(rename_edits) => rename_edits,
+            Err(error) => {
+                return ProjectSymbolsRenameLayoutResponse {
+                    success: false,
+                    struct_layout_id: self.struct_layout_id.clone(),
+                    dry_run: self.dry_run,
+                    edits: Vec::new(),
+                    error: Some(error),
+                };
+            }
+        };
+
+        if !self.dry_run && !rename_edits.is_empty() {
+            if !save_and_sync_project_symbol_catalog(engine_unprivileged_state, opened_project, &project_directory_path) {
+                return ProjectSymbolsRenameLayoutResponse::default();
+            }
+
+            if ProjectSymbolReferenceRename::edits_project_items(&rename_edits) {
+                drop(opened_project_guard);
+                project_manager.notify_project_items_changed();
+            }
+        }
+
+        ProjectSymbolsRenameLayoutResponse {
+            success: true,
+            struct_layout_id: self.new_struct_layout_id.trim().to_string(),
+            dry_run: self.dry_run,
+            edits: rename_edits,
+            error: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectSymbolsRenameLayoutRequest;
+    use crate::command_executors::project_symbols::test_support::{
+        MockProjectSymbolsBindings, create_engine_unprivileged_state, create_project_with_symbol_catalog,
+    };
+    use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+    use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+    use squalr_engine_api::registries::symbols::struct_layout_descriptor::StructLayoutDescriptor;
+    use squalr_engine_api::structures::projects::{project::Project, project_symbol_catalog::ProjectSymbolCatalog, project_symbol_claim::ProjectSymbolClaim};
+    use squalr_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+    use squalr_engine_projects::project::serialization::serializable_project_file::SerializableProjectFile;
+    use std::sync::Arc;
+
+    #[test]
+    fn rename_layout_request_retargets_claims_and_leaves_project_untouched_on_conflict() {
+        let temp_directory = tempfile::tempdir().expect("Expected a temporary directory.");
+        let project_symbol_catalog = ProjectSymbolCatalog::new_with_symbol_claims(
+            vec![
+                StructLayoutDescriptor::new(String::from("enemy"), SymbolicStructDefinition::new(String::from("enemy"), Vec::new())),
+                StructLayoutDescriptor::new(String::from("player"), SymbolicStructDefinition::new(String::from("player"), Vec::new())),
+            ],
+            vec![ProjectSymbolClaim::new_absolute_address(
+                String::from("Player"),
+                0x1234,
+                String::from("player*(u64)"),
+            )],
+        );
+        let project = create_project_with_symbol_catalog(temp_directory.path(), project_symbol_catalog);
+        let mock_project_symbols_bindings = MockProjectSymbolsBindings::new();
+        let captured_project_symbol_catalogs = mock_project_symbols_bindings.captured_project_symbol_catalogs();
+        let engine_unprivileged_state = create_engine_unprivileged_state(mock_project_symbols_bindings);
+
+        *engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project()
+            .write()
+            .expect("Expected opened project write lock in test.") = Some(project);
+
+        let engine_execution_context: Arc<dyn EngineExecutionContext> = engine_unprivileged_state.clone();
+        let conflicting_rename_layout_response = ProjectSymbolsRenameLayoutRequest {
+            struct_layout_id: String::from("player"),
+            new_struct_layout_id: String::from("enemy"),
+            dry_run: false,
+        }
+        .execute(&engine_execution_context);
+
+        assert!(!conflicting_rename_layout_response.success);
+        assert!(conflicting_rename_layout_response.error.is_some());
+        assert!(
+            captured_project_symbol_catalogs
+                .lock()
+                .expect("Expected captured catalogs lock in test.")
+                .is_empty()
+        );
+
+        let rename_layout_response = ProjectSymbolsRenameLayoutRequest {
+            struct_layout_id: String::from("player"),
+            new_struct_layout_id: String::from("actor"),
+            dry_run: false,
+        }
+        .execute(&engine_execution_context);
+
+        assert!(rename_layout_response.success);
+        assert_eq!(rename_layout_response.struct_layout_id, "actor");
+        assert_eq!(rename_layout_response.edits.len(), 2);
+
+        let loaded_project = Project::load_from_path(temp_directory.path()).expect("Expected renamed-layout project to load from disk.");
+        let loaded_project_symbol_catalog = loaded_project.get_project_info().get_project_symbol_catalog();
+
+        assert!(loaded_project_symbol_catalog.contains_struct_layout_id("actor"));
+        assert!(!loaded_project_symbol_catalog.contains_struct_layout_id("player"));
+        assert_eq!(loaded_project_symbol_catalog.get_symbol_claims()[0].get_struct_layout_id(), "actor*(u64)");
+        assert_eq!(
+            captured_project_symbol_catalogs
+                .lock()
+                .expect("Expected captured catalogs lock in test.")
+                .len(),
+            1
+        );
+    }
+}
@@ -1,8 +1,8 @@
 use crossbeam_channel::{Receiver, unbounded};
 use squalr_engine_api::commands::{
     memory::{
-        memory_command::MemoryCommand, read::memory_read_request::MemoryReadRequest, read::memory_read_response::MemoryReadResponse,
-        write::memory_write_request::MemoryWriteRequest, write::memory_write_response::MemoryWriteResponse,
+        memory_command::MemoryCommand, query::memory_query_response::MemoryQueryResponse, read::memory_read_request::MemoryReadRequest,
+        read::memory_read_response::MemoryReadResponse, write::memory_write_request::MemoryWriteRequest, write::memory_write_response::MemoryWriteResponse,
     },
     privileged_command::PrivilegedCommand,
     privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse},
@@ -14,6 +14,7 @@ use squalr_engine_api::engine::{
     engine_api_unprivileged_bindings::EngineApiUnprivilegedBindings, engine_binding_error::EngineBindingError, engine_event_envelope::EngineEventEnvelope,
     engine_execution_context::EngineExecutionContext,
 };
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
 use squalr_engine_api::structures::projects::{
     project::Project, project_info::ProjectInfo, project_items::built_in_types::project_item_type_directory::ProjectItemTypeDirectory,
     project_items::project_item_ref::ProjectItemRef, project_manifest::ProjectManifest, project_symbol_catalog::ProjectSymbolCatalog,
@@ -30,6 +31,7 @@ pub struct MockProjectSymbolsBindings {
     captured_project_symbol_catalogs: Arc<Mutex<Vec<ProjectSymbolCatalog>>>,
     captured_memory_write_requests: Arc<Mutex<Vec<MemoryWriteRequest>>>,
     memory_read_response_factory: Option<Arc<dyn Fn(&MemoryReadRequest) -> MemoryReadResponse + Send + Sync>>,
+    memory_query_modules: Option<Vec<NormalizedModule>>,
 }
 
 impl MockProjectSymbolsBindings {
@@ -38,6 +40,7 @@ impl MockProjectSymbolsBindings {
             captured_project_symbol_catalogs: Arc::new(Mutex::new(Vec::new())),
             captured_memory_write_requests: Arc::new(Mutex::new(Vec::new())),
             memory_read_response_factory: None,
+            memory_query_modules: None,
         }
     }
 
@@ -48,9 +51,18 @@ impl MockProjectSymbolsBindings {
             captured_project_symbol_catalogs: Arc::new(Mutex::new(Vec::new())),
             captured_memory_write_requests: Arc::new(Mutex::new(Vec::new())),
             memory_read_response_factory: Some(Arc::new(memory_read_response_factory)),
+            memory_query_modules: None,
         }
     }
 
+    pub fn with_memory_query_modules(
+        mut self,
+        memory_query_modules: Vec<NormalizedModule>,
+    ) -> Self {
+        self.memory_query_modules = Some(memory_query_modules);
+        self
+    }
+
     pub fn captured_project_symbol_catalogs(&self) -> Arc<Mutex<Vec<ProjectSymbolCatalog>>> {
         self.captured_project_symbol_catalogs.clone()
     }
@@ -91,6 +103,22 @@ impl EngineApiUnprivilegedBindings for MockProjectSymbolsBindings {
 
                 Ok(())
             }
+            PrivilegedCommand::Memory(MemoryCommand::Query { .. }) => {
+                let Some(memory_query_modules) = self.memory_query_modules.as_ref() else {
+                    return Err(EngineBindingError::unavailable("dispatching memory query in project-symbols tests"));
+                };
+
+                callback(
+                    MemoryQueryResponse {
+                        virtual_pages: Vec::new(),
+                        modules: memory_query_modules.clone(),
+                        success: true,
+                    }
+                    .to_engine_response(),
+                );
+
+                Ok(())
+            }
             PrivilegedCommand::Memory(MemoryCommand::Write { memory_write_request }) => {
                 let mut captured_memory_write_requests = self
                     .captured_memory_write_requests
@@ -7,7 +7,11 @@ use squalr_engine_api::commands::scan::element_scan::element_scan_response::Elem
 use squalr_engine_api::conversions::storage_size_conversions::StorageSizeConversions;
 use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
 use squalr_engine_api::registries::scan_rules::element_scan_rule_registry::ElementScanRuleRegistry;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::memory::endian::Endian;
 use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use squalr_engine_api::structures::pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize;
 use squalr_engine_api::structures::scanning::constraints::scan_constraint_builder::ScanConstraintBuilder;
 use squalr_engine_api::structures::scanning::constraints::scan_constraint_finalized::ScanConstraintFinalized;
 use squalr_engine_api::structures::scanning::memory_read_mode::MemoryReadMode;
@@ -28,7 +32,38 @@ impl PrivilegedCommandRequestExecutor for ElementScanRequest {
             .get_opened_process()
         {
             let snapshot = engine_privileged_state.get_snapshot();
-            let alignment = ScanSettingsStore::get_memory_alignment().unwrap_or(MemoryAlignment::Alignment1);
+            let value_transform = match engine_privileged_state.resolve_value_transform(self.value_transform.as_ref()) {
+                Ok(value_transform) => value_transform,
+                Err(error) => {
+                    log::error!("Unable to apply value transform to element scan: {}", error);
+                    return ElementScanResponse::default();
+                }
+            };
+            let native_pointer_size = engine_privileged_state
+                .get_os_providers()
+                .memory_query
+                .get_native_pointer_size(&process_info);
+            let data_type_refs = engine_privileged_state
+                .read_symbol_registry(|symbol_registry| resolve_native_endian_data_type_refs(&self.data_type_refs, native_pointer_size, symbol_registry));
+            let mut alignment = ScanSettingsStore::get_memory_alignment().unwrap_or(MemoryAlignment::Alignment1);
+
+            // Transforms decode naturally aligned elements, so values between element boundaries are never decoded.
+            if value_transform.is_some() {
+                let largest_unit_size = engine_privileged_state.read_symbol_registry(|symbol_registry| {
+                    data_type_refs
+                        .iter()
+                        .map(|data_type_ref| symbol_registry.get_unit_size_in_bytes(data_type_ref))
+                        .max()
+                        .unwrap_or(1)
+                });
+                let transform_alignment = MemoryAlignment::from(largest_unit_size.clamp(1, 8) as i32);
+
+                if (transform_alignment as u64) > (alignment as u64) {
+                    log::info!("Raising scan alignment to {:?} to decode transformed values.", transform_alignment);
+                    alignment = transform_alignment;
+                }
+            }
+
             let floating_point_tolerance = ScanSettingsStore::get_floating_point_tolerance();
             let memory_read_mode = ScanSettingsStore::get_memory_read_mode();
             let is_single_thread_scan = ScanSettingsStore::get_is_single_threaded_scan();
@@ -45,7 +80,7 @@ impl PrivilegedCommandRequestExecutor for ElementScanRequest {
             let scan_constraints_by_data_type = engine_privileged_state.read_symbol_registry(|symbol_registry| {
                 let scan_constraint_builder = ScanConstraintBuilder::new(symbol_registry, floating_point_tolerance);
 
-                self.data_type_refs
+                data_type_refs
                     .iter()
                     .map(|data_type_ref| {
                         // Deanonymize the initial anonymous scan constraints against the current data type.
@@ -87,10 +122,59 @@ impl PrivilegedCommandRequestExecutor for ElementScanRequest {
                 memory_read_mode,
                 is_single_thread_scan,
                 debug_perform_validation_scan,
-            );
+            )
+            .with_value_transform(value_transform);
             let memory_read_provider = engine_privileged_state.get_os_providers().memory_read.clone();
-            if memory_read_mode != MemoryReadMode::Skip {
-                SnapshotValueCollector::collect_values(process_info, snapshot.clone(), memory_read_provider, true);
+            match memory_read_mode {
+                MemoryReadMode::Skip => {}
+                MemoryReadMode::ReadBeforeScan => {
+                    SnapshotValueCollector::collect_values(process_info, snapshot.clone(), memory_read_provider, true);
+                }
+                MemoryReadMode::ReadWhileSuspended => {
+                    let thread_control_provider = engine_privileged_state
+                        .get_os_providers()
+                        .thread_control
+                        .clone();
+                    // Threads that were already held, such as those of a process launched suspended or threads the user
+                    // suspended, must stay held after the read. Only threads suspended here are resumed.
+                    let threads = thread_control_provider
+                        .get_threads(&process_info)
+                        .unwrap_or_default();
+                    let running_thread_ids: Vec<u32> = threads
+                        .iter()
+                        .filter(|thread_info| !thread_info.get_is_suspended())
+                        .map(|thread_info| thread_info.get_thread_id())
+                        .collect();
+                    let is_any_thread_suspended = running_thread_ids.len() < threads.len();
+                    let mut is_process_suspended = false;
+                    let mut suspended_thread_ids = Vec::new();
+
+                    if !is_any_thread_suspended {
+                        match thread_control_provider.suspend_process(&process_info) {
+                            Ok(()) => is_process_suspended = true,
+                            Err(error) => log::warn!("Failed to suspend the process before reading values, reading while it runs: {}", error),
+                        }
+                    } else {
+                        for thread_id in running_thread_ids {
+                            match thread_control_provider.suspend_thread(&process_info, thread_id) {
+                                Ok(()) => suspended_thread_ids.push(thread_id),
+                                Err(error) => log::warn!("Failed to suspend thread {} before reading values: {}", thread_id, error),
+                            }
+                        }
+                    }
+
+                    SnapshotValueCollector::collect_values(process_info.clone(), snapshot.clone(), memory_read_provider, true);
+
+                    if is_process_suspended && let Err(error) = thread_control_provider.resume_process(&process_info) {
+                        log::error!("Failed to resume the process after reading values: {}", error);
+                    }
+
+                    for thread_id in suspended_thread_ids {
+                        if let Err(error) = thread_control_provider.resume_thread(&process_info, thread_id) {
+                            log::error!("Failed to resume thread {} after reading values: {}", thread_id, error);
+                        }
+                    }
+                }
             }
 
             let scan_control = ScanControl::new(
@@ -101,7 +185,7 @@ impl PrivilegedCommandRequestExecutor for ElementScanRequest {
             );
             let scan_report = engine_privileged_state.read_symbol_registry(|symbol_registry| {
                 let scan_constraint_count = self.scan_constraints.len();
-                let data_type_count = self.data_type_refs.len();
+                let data_type_count = data_type_refs.len();
 
                 log::info!(
                     "Scanning snapshot with {} constraint(s) across {} data type(s)...",
@@ -150,9 +234,45 @@ impl PrivilegedCommandRequestExecutor for ElementScanRequest {
     }
 }
 
+/// Emulated big-endian systems store multi-byte values in big-endian order, so little-endian data types are swapped for their registered
+/// big-endian counterparts (for example `u32` becomes `u32be`).
+fn resolve_native_endian_data_type_refs(
+    data_type_refs: &[DataTypeRef],
+    native_pointer_size: Option<PointerScanPointerSize>,
+    symbol_registry: &SymbolRegistry,
+) -> Vec<DataTypeRef> {
+    if native_pointer_size.map(|native_pointer_size| native_pointer_size.get_endian()) != Some(Endian::Big) {
+        return data_type_refs.to_vec();
+    }
+
+    data_type_refs
+        .iter()
+        .map(|data_type_ref| {
+            let data_type_id = data_type_ref.get_data_type_id();
+            let big_endian_data_type_id = format!("{}be", data_type_id);
+
+            if data_type_id != data_type_ref.get_base_data_type_id() || symbol_registry.get_unit_size_in_bytes(data_type_ref) <= 1 {
+                return data_type_ref.clone();
+            }
+
+            match symbol_registry.get_data_type(&big_endian_data_type_id) {
+                Some(_) => {
+                    log::info!(
+                        "Scanning {} as {} to match the emulated system's byte order.",
+                        data_type_id,
+                        big_endian_data_type_id
+                    );
+                    DataTypeRef::new(&big_endian_data_type_id)
+                }
+                None => data_type_ref.clone(),
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{ElementScanner, ScanControl, SnapshotValueCollector};
+    use super::{ElementScanner, ScanControl, SnapshotValueCollector, resolve_native_endian_data_type_refs};
     use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
     use crate::engine_privileged_state::EnginePrivilegedState;
     use squalr_engine_api::commands::scan::collect_values::scan_collect_values_request::ScanCollectValuesRequest;
@@ -167,6 +287,7 @@ mod tests {
         data_types::{data_type_ref::DataTypeRef, floating_point_tolerance::FloatingPointTolerance},
         data_values::{anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat, container_type::ContainerType},
         memory::{bitness::Bitness, memory_alignment::MemoryAlignment, normalized_region::NormalizedRegion},
+        pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize,
         processes::{opened_process_info::OpenedProcessInfo, process_info::ProcessInfo},
         scanning::{
             comparisons::{
@@ -180,7 +301,7 @@ mod tests {
     use squalr_engine_session::os::engine_os_provider::{
         EngineOsProviders, MemoryQueryProvider, MemoryReadProvider, MemoryWriteProvider, ProcessQueryProvider,
     };
-    use squalr_engine_session::os::{PageRetrievalMode, ProcessQueryError, ProcessQueryOptions};
+    use squalr_engine_session::os::{PageRetrievalMode, ProcessLaunchOptions, ProcessQueryError, ProcessQueryOptions};
     use std::collections::HashMap;
     use std::sync::{Arc, RwLock};
 
@@ -234,6 +355,13 @@ mod tests {
             Err(ProcessQueryError::internal("open_process", "not used in element scan tests"))
         }
 
+        fn launch_process(
+            &self,
+            _process_launch_options: &ProcessLaunchOptions,
+        ) -> Result<OpenedProcessInfo, ProcessQueryError> {
+            Err(ProcessQueryError::internal("launch_process", "not used in element scan tests"))
+        }
+
         fn close_process(
             &self,
             _handle: u64,
@@ -725,6 +853,7 @@ mod tests {
                 None,
             )],
             data_type_refs: vec![data_type_ref],
+            value_transform: None,
         };
 
         write_region_bytes(&memory_bytes, &[0u8, 1u8, 7u8, 9u8]);
@@ -777,6 +906,7 @@ mod tests {
                 )),
             )],
             data_type_refs: vec![DataTypeRef::new("i32")],
+            value_transform: None,
         };
 
         write_i32_array_value(&memory_bytes, match_address, &[1, 2]);
@@ -816,6 +946,7 @@ mod tests {
                 )),
             )],
             data_type_refs: vec![DataTypeRef::new("i32")],
+            value_transform: None,
         };
 
         write_i32_array_value(&memory_bytes, match_address, &[1]);
@@ -915,6 +1046,7 @@ mod tests {
                 )),
             )],
             data_type_refs: vec![DataTypeRef::new("u8")],
+            value_transform: None,
         };
 
         write_region_bytes(&memory_bytes, &[0u8, 0u8, 1u8, 42u8, 55u8, 0u8]);
@@ -943,6 +1075,7 @@ mod tests {
                 )),
             )],
             data_type_refs: vec![DataTypeRef::new("u8")],
+            value_transform: None,
         };
 
         write_region_bytes(&memory_bytes, &[0u8, 0u8, 0u8, 0x01u8, 0x7Au8, 0x55u8, 0u8]);
@@ -971,6 +1104,7 @@ mod tests {
                 )),
             )],
             data_type_refs: vec![DataTypeRef::new("i_x86")],
+            value_transform: None,
         };
 
         write_region_bytes(&memory_bytes, &[0x00u8, 0xB8u8, 0x05u8, 0x00u8, 0x00u8, 0x00u8, 0x55u8, 0x00u8]);
@@ -1010,6 +1144,7 @@ mod tests {
                 )),
             )],
             data_type_refs: vec![DataTypeRef::new("i_arm64")],
+            value_transform: None,
         };
 
         write_region_bytes(
@@ -1054,6 +1189,7 @@ mod tests {
                 )),
             )],
             data_type_refs: vec![DataTypeRef::new("i_ppc32be")],
+            value_transform: None,
         };
 
         write_region_bytes(
@@ -1082,4 +1218,28 @@ mod tests {
             assert_eq!(instruction_display_value.get_anonymous_value_string(), "li r3, 5; blr");
         });
     }
+
+    #[test]
+    fn native_big_endian_systems_scan_big_endian_data_types() {
+        let symbol_registry = SymbolRegistry::new();
+        let data_type_refs = vec![
+            DataTypeRef::new("u32"),
+            DataTypeRef::new("u8"),
+            DataTypeRef::new("f32be"),
+        ];
+
+        let big_endian_data_type_refs = resolve_native_endian_data_type_refs(&data_type_refs, Some(PointerScanPointerSize::Pointer24be), &symbol_registry);
+        let little_endian_data_type_refs = resolve_native_endian_data_type_refs(&data_type_refs, Some(PointerScanPointerSize::Pointer24), &symbol_registry);
+
+        assert_eq!(
+            big_endian_data_type_refs,
+            vec![
+                DataTypeRef::new("u32be"),
+                DataTypeRef::new("u8"),
+                DataTypeRef::new("f32be"),
+            ]
+        );
+        assert_eq!(little_endian_data_type_refs, data_type_refs);
+        assert_eq!(resolve_native_endian_data_type_refs(&data_type_refs, None, &symbol_registry), data_type_refs);
+    }
 }
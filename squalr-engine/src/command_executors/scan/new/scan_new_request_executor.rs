@@ -1,10 +1,10 @@
 use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan::scan_initializer::query_scan_memory_pages;
 use crate::command_executors::snapshot_region_builder::merge_memory_regions_into_snapshot_regions;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
 use squalr_engine_api::commands::scan::new::scan_new_response::ScanNewResponse;
 use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
-use squalr_engine_session::settings::scan_settings_store::ScanSettingsStore;
 use std::sync::Arc;
 
 impl PrivilegedCommandRequestExecutor for ScanNewRequest {
@@ -48,11 +48,8 @@ impl PrivilegedCommandRequestExecutor for ScanNewRequest {
         // Best-effort to clear the freeze list.
         freeze_list_registry_guard.clear();
 
-        // Query all memory pages for the process from the OS.
-        let memory_pages = engine_privileged_state
-            .get_os_providers()
-            .memory_query
-            .get_memory_page_bounds(&opened_process_info, ScanSettingsStore::get_page_retrieval_mode());
+        // Query all memory pages for the process from the OS, narrowed to the pages written while tracking writes, if requested.
+        let memory_pages = query_scan_memory_pages(engine_privileged_state, &opened_process_info);
 
         // Attempt to merge any adjacent regions. This drastically simplifies the scanning process by eliminating edge case handling.
         // Additionally, we must track the page boundaries at which the merge took place.
@@ -1,8 +1,10 @@
-use crate::command_executors::snapshot_region_builder::merge_memory_regions_into_snapshot_regions;
+use crate::command_executors::snapshot_region_builder::{intersect_memory_regions, merge_memory_regions_into_snapshot_regions};
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
 use squalr_engine_api::structures::{
-    data_types::data_type_ref::DataTypeRef, memory::memory_alignment::MemoryAlignment, processes::opened_process_info::OpenedProcessInfo,
+    data_types::data_type_ref::DataTypeRef,
+    memory::{memory_alignment::MemoryAlignment, normalized_region::NormalizedRegion},
+    processes::opened_process_info::OpenedProcessInfo,
     snapshots::snapshot::Snapshot,
 };
 use squalr_engine_session::os::PageRetrievalMode;
@@ -17,15 +19,45 @@ pub fn ensure_snapshot_regions_for_scan(
         return;
     }
 
+    let memory_pages = query_scan_memory_pages(engine_privileged_state, opened_process_info);
+    let merged_snapshot_regions = merge_memory_regions_into_snapshot_regions(memory_pages);
+
+    if !merged_snapshot_regions.is_empty() {
+        snapshot.set_snapshot_regions(merged_snapshot_regions);
+    }
+}
+
+/// Queries the memory pages that a new scan reads. When collected writes narrowed the next scan, the pages are clipped to
+/// the written pages, and the narrowing is consumed.
+pub fn query_scan_memory_pages(
+    engine_privileged_state: &EnginePrivilegedState,
+    opened_process_info: &OpenedProcessInfo,
+) -> Vec<NormalizedRegion> {
     let memory_pages = engine_privileged_state
         .get_os_providers()
         .memory_query
         .get_memory_page_bounds(opened_process_info, effective_page_retrieval_mode());
-    let merged_snapshot_regions = merge_memory_regions_into_snapshot_regions(memory_pages);
+    let pending_scan_pages = match engine_privileged_state.get_pending_scan_pages().write() {
+        Ok(mut pending_scan_pages) => pending_scan_pages.take(),
+        Err(error) => {
+            log::error!("Failed to acquire write lock on pending scan pages: {}", error);
+            None
+        }
+    };
+    let Some(pending_scan_pages) = pending_scan_pages else {
+        return memory_pages;
+    };
+    let narrowed_memory_pages = intersect_memory_regions(memory_pages.clone(), &pending_scan_pages);
 
-    if !merged_snapshot_regions.is_empty() {
-        snapshot.set_snapshot_regions(merged_snapshot_regions);
+    if narrowed_memory_pages.is_empty() {
+        log::warn!("None of the written pages are still mapped, so the scan reads all pages instead.");
+
+        return memory_pages;
     }
+
+    log::info!("Narrowed the scan to {} written page(s).", narrowed_memory_pages.len());
+
+    narrowed_memory_pages
 }
 
 fn effective_page_retrieval_mode() -> PageRetrievalMode {
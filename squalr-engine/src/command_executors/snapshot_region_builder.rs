@@ -24,9 +24,42 @@ pub fn merge_memory_regions_into_snapshot_regions(memory_regions: Vec<Normalized
     merged_snapshot_regions
 }
 
+/// Clips memory regions to the parts that overlap the selected regions, such as the pages that a process was seen writing.
+/// The selected regions must not overlap each other.
+pub fn intersect_memory_regions(
+    memory_regions: Vec<NormalizedRegion>,
+    selected_regions: &[NormalizedRegion],
+) -> Vec<NormalizedRegion> {
+    let mut selected_regions = selected_regions.to_vec();
+    let mut intersected_regions = Vec::new();
+
+    selected_regions.sort_by_key(|selected_region| selected_region.get_base_address());
+
+    for memory_region in memory_regions {
+        let first_overlap_index = selected_regions.partition_point(|selected_region| selected_region.get_end_address() <= memory_region.get_base_address());
+
+        for selected_region in &selected_regions[first_overlap_index..] {
+            if selected_region.get_base_address() >= memory_region.get_end_address() {
+                break;
+            }
+
+            let base_address = memory_region
+                .get_base_address()
+                .max(selected_region.get_base_address());
+            let end_address = memory_region
+                .get_end_address()
+                .min(selected_region.get_end_address());
+
+            intersected_regions.push(NormalizedRegion::new(base_address, end_address - base_address));
+        }
+    }
+
+    intersected_regions
+}
+
 #[cfg(test)]
 mod tests {
-    use super::merge_memory_regions_into_snapshot_regions;
+    use super::{intersect_memory_regions, merge_memory_regions_into_snapshot_regions};
     use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 
     #[test]
@@ -50,4 +83,27 @@ mod tests {
     fn merge_memory_regions_into_snapshot_regions_returns_empty_when_no_regions_are_provided() {
         assert!(merge_memory_regions_into_snapshot_regions(Vec::new()).is_empty());
     }
+
+    #[test]
+    fn intersect_memory_regions_clips_regions_to_the_selected_pages() {
+        let intersected_regions = intersect_memory_regions(
+            vec![
+                NormalizedRegion::new(0x1000, 0x3000),
+                NormalizedRegion::new(0x8000, 0x1000),
+            ],
+            &[
+                NormalizedRegion::new(0x3000, 0x1000),
+                NormalizedRegion::new(0x0800, 0x1000),
+                NormalizedRegion::new(0x6000, 0x1000),
+            ],
+        );
+
+        assert_eq!(
+            intersected_regions,
+            vec![
+                NormalizedRegion::new(0x1000, 0x800),
+                NormalizedRegion::new(0x3000, 0x1000)
+            ]
+        );
+    }
 }
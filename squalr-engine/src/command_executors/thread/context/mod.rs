@@ -0,0 +1 @@
+pub mod thread_context_request_executor;
@@ -0,0 +1,43 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::thread::context::thread_context_request::ThreadContextRequest;
+use squalr_engine_api::commands::thread::context::thread_context_response::ThreadContextResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ThreadContextRequest {
+    type ResponseType = ThreadContextResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return ThreadContextResponse {
+                error: Some(String::from("No process is opened to read thread context from.")),
+                ..ThreadContextResponse::default()
+            };
+        };
+
+        match engine_privileged_state
+            .get_os_providers()
+            .thread_control
+            .get_thread_context(&process_info, self.thread_id)
+        {
+            Ok(register_snapshot) => ThreadContextResponse {
+                register_snapshot: Some(register_snapshot),
+                error: None,
+            },
+            Err(error) => {
+                log::error!("Failed to capture the context of thread {}: {}", self.thread_id, error);
+
+                ThreadContextResponse {
+                    error: Some(error.to_string()),
+                    ..ThreadContextResponse::default()
+                }
+            }
+        }
+    }
+}
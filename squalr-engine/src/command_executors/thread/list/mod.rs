@@ -0,0 +1 @@
+pub mod thread_list_request_executor;
@@ -0,0 +1,40 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::thread::list::thread_list_request::ThreadListRequest;
+use squalr_engine_api::commands::thread::list::thread_list_response::ThreadListResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ThreadListRequest {
+    type ResponseType = ThreadListResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return ThreadListResponse {
+                error: Some(String::from("No process is opened to list threads of.")),
+                ..ThreadListResponse::default()
+            };
+        };
+
+        match engine_privileged_state
+            .get_os_providers()
+            .thread_control
+            .get_threads(&process_info)
+        {
+            Ok(threads) => ThreadListResponse { threads, error: None },
+            Err(error) => {
+                log::error!("Failed to list threads: {}", error);
+
+                ThreadListResponse {
+                    error: Some(error.to_string()),
+                    ..ThreadListResponse::default()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod context;
+pub mod list;
+pub mod resume;
+pub mod suspend;
+pub mod thread_command_executor;
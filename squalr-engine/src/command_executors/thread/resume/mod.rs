@@ -0,0 +1 @@
+pub mod thread_resume_request_executor;
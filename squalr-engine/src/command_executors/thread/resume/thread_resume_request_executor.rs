@@ -0,0 +1,38 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::thread::resume::thread_resume_request::ThreadResumeRequest;
+use squalr_engine_api::commands::thread::resume::thread_resume_response::ThreadResumeResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ThreadResumeRequest {
+    type ResponseType = ThreadResumeResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return ThreadResumeResponse {
+                error: Some(String::from("No process is opened to resume threads of.")),
+                ..ThreadResumeResponse::default()
+            };
+        };
+        let thread_control = &engine_privileged_state.get_os_providers().thread_control;
+        let resume_result = match self.thread_id {
+            Some(thread_id) => thread_control.resume_thread(&process_info, thread_id),
+            None => thread_control.resume_process(&process_info),
+        };
+
+        // Threads are listed after the request either way, so callers can see which threads are still suspended.
+        let error = resume_result.err().map(|error| {
+            log::error!("Failed to resume threads: {}", error);
+            error.to_string()
+        });
+        let threads = thread_control.get_threads(&process_info).unwrap_or_default();
+
+        ThreadResumeResponse { threads, error }
+    }
+}
@@ -0,0 +1 @@
+pub mod thread_suspend_request_executor;
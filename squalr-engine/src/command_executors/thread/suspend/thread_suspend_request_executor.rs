@@ -0,0 +1,38 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::thread::suspend::thread_suspend_request::ThreadSuspendRequest;
+use squalr_engine_api::commands::thread::suspend::thread_suspend_response::ThreadSuspendResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ThreadSuspendRequest {
+    type ResponseType = ThreadSuspendResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return ThreadSuspendResponse {
+                error: Some(String::from("No process is opened to suspend threads of.")),
+                ..ThreadSuspendResponse::default()
+            };
+        };
+        let thread_control = &engine_privileged_state.get_os_providers().thread_control;
+        let suspend_result = match self.thread_id {
+            Some(thread_id) => thread_control.suspend_thread(&process_info, thread_id),
+            None => thread_control.suspend_process(&process_info),
+        };
+
+        // Threads are listed after the request either way, so callers can see which threads ended up suspended.
+        let error = suspend_result.err().map(|error| {
+            log::error!("Failed to suspend threads: {}", error);
+            error.to_string()
+        });
+        let threads = thread_control.get_threads(&process_info).unwrap_or_default();
+
+        ThreadSuspendResponse { threads, error }
+    }
+}
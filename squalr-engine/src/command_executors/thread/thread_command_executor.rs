@@ -0,0 +1,30 @@
+use crate::command_executors::privileged_command_executor::PrivilegedCommandExecutor;
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse};
+use squalr_engine_api::commands::thread::thread_command::ThreadCommand;
+use std::sync::Arc;
+
+impl PrivilegedCommandExecutor for ThreadCommand {
+    type ResponseType = PrivilegedCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
+        match self {
+            ThreadCommand::Context { thread_context_request } => thread_context_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ThreadCommand::List { thread_list_request } => thread_list_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ThreadCommand::Resume { thread_resume_request } => thread_resume_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ThreadCommand::Suspend { thread_suspend_request } => thread_suspend_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
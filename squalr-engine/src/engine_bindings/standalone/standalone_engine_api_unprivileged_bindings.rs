@@ -104,7 +104,7 @@ mod tests {
         structures::processes::{opened_process_info::OpenedProcessInfo, process_info::ProcessInfo},
     };
     use squalr_engine_session::os::{
-        ProcessQueryError, ProcessQueryOptions,
+        ProcessLaunchOptions, ProcessQueryError, ProcessQueryOptions,
         engine_os_provider::{EngineOsProviders, ProcessQueryProvider},
     };
     use std::sync::Arc;
@@ -130,6 +130,13 @@ mod tests {
             Err(ProcessQueryError::internal("open_process", "not implemented in no-op provider"))
         }
 
+        fn launch_process(
+            &self,
+            _process_launch_options: &ProcessLaunchOptions,
+        ) -> Result<OpenedProcessInfo, ProcessQueryError> {
+            Err(ProcessQueryError::internal("launch_process", "not implemented in no-op provider"))
+        }
+
         fn close_process(
             &self,
             _handle: u64,
@@ -84,6 +84,7 @@ mod tests {
     use crate::engine_mode::EngineMode;
     use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
     use squalr_engine_api::structures::processes::process_info::ProcessInfo;
+    use squalr_engine_session::os::ProcessLaunchOptions;
     use squalr_engine_session::os::ProcessQueryError;
     use squalr_engine_session::os::ProcessQueryOptions;
     use squalr_engine_session::os::engine_os_provider::{EngineOsProviders, ProcessQueryProvider};
@@ -110,6 +111,13 @@ mod tests {
             Err(ProcessQueryError::internal("open_process", "not implemented in test provider"))
         }
 
+        fn launch_process(
+            &self,
+            _process_launch_options: &ProcessLaunchOptions,
+        ) -> Result<OpenedProcessInfo, ProcessQueryError> {
+            Err(ProcessQueryError::internal("launch_process", "not implemented in test provider"))
+        }
+
         fn close_process(
             &self,
             _handle: u64,
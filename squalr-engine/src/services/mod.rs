@@ -1,3 +1,4 @@
 pub mod memory;
+pub mod patching;
 pub mod pointer_scans;
 pub mod projects;
@@ -0,0 +1,254 @@
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::patching::code_cave_padding::CodeCavePadding;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint::ScanConstraint;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint_finalized::ScanConstraintFinalized;
+use squalr_engine_api::structures::scanning::memory_read_mode::MemoryReadMode;
+use squalr_engine_api::structures::scanning::plans::element_scan::element_scan_plan::ElementScanPlan;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+use squalr_engine_scanning::element_scans::element_scanner::ElementScanner;
+use squalr_engine_scanning::scanners::scan_control::ScanControl;
+use std::collections::HashMap;
+
+/// The standard x86 no-operation encodings from one to eleven bytes, which compilers mix to pad between functions.
+const NO_OPERATION_ENCODINGS: [&[u8]; 11] = [
+    &[0x90],
+    &[0x66, 0x90],
+    &[0x0F, 0x1F, 0x00],
+    &[0x0F, 0x1F, 0x40, 0x00],
+    &[0x0F, 0x1F, 0x44, 0x00, 0x00],
+    &[0x66, 0x0F, 0x1F, 0x44, 0x00, 0x00],
+    &[0x0F, 0x1F, 0x80, 0x00, 0x00, 0x00, 0x00],
+    &[0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[0x66, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[0x66, 0x2E, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[0x66, 0x66, 0x2E, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+];
+
+/// Caves start on this boundary, leaving a gap after whatever code precedes the padding.
+const CODE_CAVE_ALIGNMENT: u64 = 0x10;
+
+/// The longest run of a repeated padding byte that is scanned for. Longer minimum sizes are applied to the merged runs
+/// instead of growing the pattern.
+const PADDING_PATTERN_LENGTH: u64 = 0x10;
+
+/// Finds the runs of padding of at least `minimum_size` bytes within the given code, as the aligned cave and its padding.
+pub fn find_padding_runs(
+    symbol_registry: &SymbolRegistry,
+    code_regions: &[(NormalizedRegion, Vec<u8>)],
+    minimum_size: u64,
+) -> Vec<(NormalizedRegion, CodeCavePadding)> {
+    let minimum_size = minimum_size.max(1);
+    let repeated_byte_length = minimum_size.min(PADDING_PATTERN_LENGTH) as usize;
+    let mut padding_runs = Vec::new();
+
+    for (code_cave_padding, padding_patterns) in [
+        (CodeCavePadding::Zero, vec![vec![0x00; repeated_byte_length]]),
+        (CodeCavePadding::Breakpoint, vec![vec![0xCC; repeated_byte_length]]),
+        // Each encoding is matched on its own, so that runs mixing encodings of different lengths are merged whole.
+        (
+            CodeCavePadding::NoOperation,
+            NO_OPERATION_ENCODINGS
+                .iter()
+                .map(|no_operation_encoding| no_operation_encoding.to_vec())
+                .collect(),
+        ),
+    ] {
+        let mut padding_matches: Vec<NormalizedRegion> = padding_patterns
+            .into_iter()
+            .flat_map(|padding_pattern| find_pattern_matches(symbol_registry, code_regions, padding_pattern))
+            .collect();
+        let mut merged_padding_matches: Vec<NormalizedRegion> = Vec::new();
+
+        padding_matches.sort_by_key(|padding_match| padding_match.get_base_address());
+
+        for padding_match in padding_matches {
+            match merged_padding_matches.last_mut() {
+                Some(merged_padding_match) if merged_padding_match.get_end_address() >= padding_match.get_base_address() => {
+                    merged_padding_match.set_end_address(
+                        merged_padding_match
+                            .get_end_address()
+                            .max(padding_match.get_end_address()),
+                    );
+                }
+                _ => merged_padding_matches.push(padding_match),
+            }
+        }
+
+        for merged_padding_match in merged_padding_matches {
+            let cave_address = merged_padding_match
+                .get_base_address()
+                .next_multiple_of(CODE_CAVE_ALIGNMENT);
+            let cave_size = merged_padding_match
+                .get_end_address()
+                .saturating_sub(cave_address);
+
+            if cave_size >= minimum_size {
+                padding_runs.push((NormalizedRegion::new(cave_address, cave_size), code_cave_padding));
+            }
+        }
+    }
+
+    padding_runs.sort_by_key(|(cave_region, _code_cave_padding)| cave_region.get_base_address());
+    padding_runs
+}
+
+/// Removes the given borrowed ranges from padding runs, keeping the aligned pieces that remain at least `minimum_size` bytes.
+pub fn remove_borrowed_ranges(
+    padding_runs: Vec<(NormalizedRegion, CodeCavePadding)>,
+    borrowed_regions: &[NormalizedRegion],
+    minimum_size: u64,
+) -> Vec<(NormalizedRegion, CodeCavePadding)> {
+    let minimum_size = minimum_size.max(1);
+    let mut borrowed_regions = borrowed_regions.to_vec();
+    let mut remaining_runs = Vec::new();
+
+    borrowed_regions.sort_by_key(|borrowed_region| borrowed_region.get_base_address());
+
+    for (padding_run, code_cave_padding) in padding_runs {
+        let mut piece_address = padding_run.get_base_address();
+        let mut push_piece = |piece_address: u64, piece_end_address: u64| {
+            let piece_address = piece_address.next_multiple_of(CODE_CAVE_ALIGNMENT);
+
+            if piece_end_address.saturating_sub(piece_address) >= minimum_size {
+                remaining_runs.push((NormalizedRegion::new(piece_address, piece_end_address - piece_address), code_cave_padding));
+            }
+        };
+
+        for borrowed_region in &borrowed_regions {
+            if borrowed_region.get_end_address() <= piece_address || borrowed_region.get_base_address() >= padding_run.get_end_address() {
+                continue;
+            }
+
+            push_piece(piece_address, borrowed_region.get_base_address());
+            piece_address = piece_address.max(borrowed_region.get_end_address());
+        }
+
+        push_piece(piece_address, padding_run.get_end_address());
+    }
+
+    remaining_runs
+}
+
+/// Finds every occurrence of a byte pattern within the given code with the byte array scanner, which reports overlapping
+/// occurrences as a single range.
+fn find_pattern_matches(
+    symbol_registry: &SymbolRegistry,
+    code_regions: &[(NormalizedRegion, Vec<u8>)],
+    pattern: Vec<u8>,
+) -> Vec<NormalizedRegion> {
+    let data_type_ref = DataTypeRef::new("u8");
+    let scan_constraint = ScanConstraint::new(
+        ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+        DataValue::new(data_type_ref.clone(), pattern),
+        FloatingPointTolerance::default(),
+    );
+    let element_scan_plan = ElementScanPlan::new(
+        HashMap::from([(data_type_ref, vec![ScanConstraintFinalized::new(symbol_registry, scan_constraint)])]),
+        MemoryAlignment::Alignment1,
+        FloatingPointTolerance::default(),
+        MemoryReadMode::Skip,
+        false,
+        false,
+    );
+    let mut snapshot = Snapshot::from_regions(
+        code_regions
+            .iter()
+            .map(|(code_region, code_bytes)| SnapshotRegion::from_bytes(code_region.get_base_address(), code_bytes.clone()))
+            .collect(),
+    );
+
+    ElementScanner::scan_snapshot(&mut snapshot, symbol_registry, &element_scan_plan, &ScanControl::default());
+
+    snapshot
+        .get_snapshot_regions()
+        .iter()
+        .flat_map(|snapshot_region| snapshot_region.get_scan_results().get_filter_collections())
+        .flat_map(|snapshot_region_filter_collection| snapshot_region_filter_collection.iter())
+        .map(|snapshot_region_filter| NormalizedRegion::new(snapshot_region_filter.get_base_address(), snapshot_region_filter.get_region_size()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_padding_runs, remove_borrowed_ranges};
+    use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+    use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+    use squalr_engine_api::structures::patching::code_cave_padding::CodeCavePadding;
+
+    #[test]
+    fn find_padding_runs_merges_whole_runs_and_aligns_them() {
+        let symbol_registry = SymbolRegistry::new();
+        let mut code_bytes = vec![0x48u8; 0x100];
+
+        // An int3 run longer than the scanned pattern, starting off alignment.
+        code_bytes[0x0C..0x50].fill(0xCC);
+        // A no-operation run that is too short once aligned.
+        code_bytes[0x62..0x88].fill(0x90);
+        // A zero run that is shorter than the minimum size.
+        code_bytes[0xA0..0xB0].fill(0x00);
+
+        let padding_runs = find_padding_runs(&symbol_registry, &[(NormalizedRegion::new(0x4000, 0x100), code_bytes)], 0x20);
+
+        assert_eq!(padding_runs, vec![(NormalizedRegion::new(0x4010, 0x40), CodeCavePadding::Breakpoint)]);
+    }
+
+    #[test]
+    fn remove_borrowed_ranges_keeps_the_pieces_around_borrowed_caves() {
+        let padding_runs = vec![
+            (NormalizedRegion::new(0x4000, 0x1000), CodeCavePadding::Breakpoint),
+            (NormalizedRegion::new(0x6000, 0x40), CodeCavePadding::Zero),
+        ];
+        let borrowed_regions = [
+            NormalizedRegion::new(0x4100, 0x23),
+            NormalizedRegion::new(0x4FE0, 0x20),
+            NormalizedRegion::new(0x6010, 0x10),
+        ];
+
+        assert_eq!(
+            remove_borrowed_ranges(padding_runs, &borrowed_regions, 0x20),
+            vec![
+                (NormalizedRegion::new(0x4000, 0x100), CodeCavePadding::Breakpoint),
+                (NormalizedRegion::new(0x4130, 0xEB0), CodeCavePadding::Breakpoint),
+                (NormalizedRegion::new(0x6020, 0x20), CodeCavePadding::Zero),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_padding_runs_joins_multi_byte_no_operations() {
+        let symbol_registry = SymbolRegistry::new();
+        let mut code_bytes = vec![0x48u8; 0x100];
+
+        // Five byte no-operations padding the gap after a function.
+        for no_operation_index in 0..12 {
+            let no_operation_offset = 0x0B + no_operation_index * 5;
+
+            code_bytes[no_operation_offset..no_operation_offset + 5].copy_from_slice(&[0x0F, 0x1F, 0x44, 0x00, 0x00]);
+        }
+
+        // A gap mixing encodings of different lengths.
+        code_bytes[0x80..0x8B].copy_from_slice(&[0x66, 0x66, 0x2E, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        code_bytes[0x8B..0x96].copy_from_slice(&[0x66, 0x66, 0x2E, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        code_bytes[0x96..0x9D].copy_from_slice(&[0x0F, 0x1F, 0x80, 0x00, 0x00, 0x00, 0x00]);
+        code_bytes[0x9D..0xA1].copy_from_slice(&[0x0F, 0x1F, 0x40, 0x00]);
+        code_bytes[0xA1..0xB3].fill(0x90);
+
+        let padding_runs = find_padding_runs(&symbol_registry, &[(NormalizedRegion::new(0x4000, 0x100), code_bytes)], 0x20);
+
+        assert_eq!(
+            padding_runs,
+            vec![
+                (NormalizedRegion::new(0x4010, 0x37), CodeCavePadding::NoOperation),
+                (NormalizedRegion::new(0x4080, 0x33), CodeCavePadding::NoOperation),
+            ]
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use squalr_engine_api::plugins::instruction_set::InstructionSet;
+
+/// The bytes a code patch writes, computed from the code currently at the patch address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodePatchPlan {
+    pub original_bytes: Vec<u8>,
+    pub patched_bytes: Vec<u8>,
+
+    /// The bytes to write at the start of the code cave, for detours.
+    pub code_cave_bytes: Vec<u8>,
+}
+
+/// Measures how many bytes must be overwritten so that a patch of `minimum_length` bytes ends on an instruction boundary.
+pub fn measure_overwritten_length(
+    instruction_set: &dyn InstructionSet,
+    code_bytes: &[u8],
+    minimum_length: usize,
+) -> Result<usize, String> {
+    let mut overwritten_length = 0usize;
+
+    for instruction_length in instruction_set.measure_instructions(code_bytes)? {
+        if overwritten_length >= minimum_length {
+            break;
+        }
+
+        overwritten_length += instruction_length;
+    }
+
+    if overwritten_length < minimum_length {
+        return Err(format!(
+            "Only {} bytes of whole instructions could be decoded, but the patch needs {}.",
+            overwritten_length, minimum_length
+        ));
+    }
+
+    Ok(overwritten_length)
+}
+
+/// Plans filling every instruction that overlaps the first `byte_count` bytes with no-operations.
+pub fn plan_nop_patch(
+    instruction_set: &dyn InstructionSet,
+    code_bytes: &[u8],
+    byte_count: usize,
+) -> Result<CodePatchPlan, String> {
+    if byte_count == 0 {
+        return Err(String::from("A no-operation patch must cover at least one byte."));
+    }
+
+    let overwritten_length = measure_overwritten_length(instruction_set, code_bytes, byte_count)?;
+
+    Ok(CodePatchPlan {
+        original_bytes: code_bytes[..overwritten_length].to_vec(),
+        patched_bytes: instruction_set.build_no_operation_fill(overwritten_length)?,
+        code_cave_bytes: Vec::new(),
+    })
+}
+
+/// Plans overwriting the instructions at `patch_address` with assembled instructions, padding the remainder of the last
+/// overwritten instruction with no-operations so that no partial instruction is left behind.
+pub fn plan_replace_patch(
+    instruction_set: &dyn InstructionSet,
+    code_bytes: &[u8],
+    patch_address: u64,
+    assembly_source: &str,
+) -> Result<CodePatchPlan, String> {
+    let assembled_bytes = instruction_set.assemble_at(assembly_source, patch_address)?;
+
+    if assembled_bytes.is_empty() {
+        return Err(String::from("The replacement instructions assembled to zero bytes."));
+    }
+
+    let overwritten_length = measure_overwritten_length(instruction_set, code_bytes, assembled_bytes.len())?;
+    let mut patched_bytes = assembled_bytes;
+    let padding_length = overwritten_length - patched_bytes.len();
+
+    patched_bytes.extend(instruction_set.build_no_operation_fill(padding_length)?);
+
+    Ok(CodePatchPlan {
+        original_bytes: code_bytes[..overwritten_length].to_vec(),
+        patched_bytes,
+        code_cave_bytes: Vec::new(),
+    })
+}
+
+/// Plans a detour that jumps from `patch_address` into a code cave at `code_cave_address`. The cave runs the assembled
+/// instructions, then the overwritten instructions relocated to the cave, then jumps back to the first untouched instruction.
+pub fn plan_detour_patch(
+    instruction_set: &dyn InstructionSet,
+    code_bytes: &[u8],
+    patch_address: u64,
+    assembly_source: &str,
+    code_cave_address: u64,
+) -> Result<CodePatchPlan, String> {
+    let hook_jump_bytes = instruction_set.build_jump(patch_address, code_cave_address)?;
+    let overwritten_length = measure_overwritten_length(instruction_set, code_bytes, hook_jump_bytes.len())?;
+    let overwritten_bytes = &code_bytes[..overwritten_length];
+    let mut code_cave_bytes = instruction_set.assemble_at(assembly_source, code_cave_address)?;
+    let relocated_address = code_cave_address.wrapping_add(code_cave_bytes.len() as u64);
+
+    code_cave_bytes.extend(instruction_set.relocate(overwritten_bytes, patch_address, relocated_address)?);
+
+    let return_jump_address = code_cave_address.wrapping_add(code_cave_bytes.len() as u64);
+    let return_address = patch_address.wrapping_add(overwritten_length as u64);
+
+    code_cave_bytes.extend(instruction_set.build_jump(return_jump_address, return_address)?);
+
+    let mut patched_bytes = hook_jump_bytes;
+    let padding_length = overwritten_length - patched_bytes.len();
+
+    patched_bytes.extend(instruction_set.build_no_operation_fill(padding_length)?);
+
+    Ok(CodePatchPlan {
+        original_bytes: overwritten_bytes.to_vec(),
+        patched_bytes,
+        code_cave_bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{plan_detour_patch, plan_nop_patch, plan_replace_patch};
+    use squalr_engine_api::plugins::instruction_set::InstructionSet;
+
+    /// A toy encoding where `0x01` is a one byte instruction, `0x03 a b` is a three byte instruction, `0x90` is a no-operation,
+    /// and `0xE9` followed by a little-endian u32 is an absolute jump. Relocation tags each byte with the relocation delta.
+    #[derive(Debug)]
+    struct TestInstructionSet {}
+
+    impl InstructionSet for TestInstructionSet {
+        fn get_instruction_set_id(&self) -> &str {
+            "test"
+        }
+
+        fn get_display_name(&self) -> &str {
+            "Test"
+        }
+
+        fn assemble(
+            &self,
+            assembly_source: &str,
+        ) -> Result<Vec<u8>, String> {
+            Ok(assembly_source
+                .split_whitespace()
+                .map(|token| u8::from_str_radix(token, 16).unwrap_or(0))
+                .collect())
+        }
+
+        fn disassemble(
+            &self,
+            _instruction_bytes: &[u8],
+        ) -> Result<String, String> {
+            Ok(String::new())
+        }
+
+        fn measure_instructions(
+            &self,
+            instruction_bytes: &[u8],
+        ) -> Result<Vec<usize>, String> {
+            let mut instruction_lengths = Vec::new();
+            let mut offset = 0usize;
+
+            while offset < instruction_bytes.len() {
+                let instruction_length = match instruction_bytes[offset] {
+                    0x03 => 3,
+                    0xE9 => 5,
+                    _ => 1,
+                };
+
+                if offset + instruction_length > instruction_bytes.len() {
+                    break;
+                }
+
+                instruction_lengths.push(instruction_length);
+                offset += instruction_length;
+            }
+
+            Ok(instruction_lengths)
+        }
+
+        fn relocate(
+            &self,
+            instruction_bytes: &[u8],
+            source_address: u64,
+            destination_address: u64,
+        ) -> Result<Vec<u8>, String> {
+            let relocation_delta = destination_address.wrapping_sub(source_address) as u8;
+
+            Ok(instruction_bytes
+                .iter()
+                .map(|instruction_byte| instruction_byte.wrapping_add(relocation_delta))
+                .collect())
+        }
+
+        fn build_jump(
+            &self,
+            _source_address: u64,
+            destination_address: u64,
+        ) -> Result<Vec<u8>, String> {
+            let mut jump_bytes = vec![0xE9];
+
+            jump_bytes.extend((destination_address as u32).to_le_bytes());
+
+            Ok(jump_bytes)
+        }
+
+        fn build_no_operation_fill(
+            &self,
+            byte_count: usize,
+        ) -> Result<Vec<u8>, String> {
+            Ok(vec![0x90; byte_count])
+        }
+    }
+
+    #[test]
+    fn nop_patch_extends_to_the_next_instruction_boundary() {
+        let code_patch_plan = plan_nop_patch(&TestInstructionSet {}, &[0x01, 0x03, 0xAA, 0xBB, 0x01], 2).expect("Expected a nop plan.");
+
+        assert_eq!(code_patch_plan.original_bytes, vec![0x01, 0x03, 0xAA, 0xBB]);
+        assert_eq!(code_patch_plan.patched_bytes, vec![0x90; 4]);
+    }
+
+    #[test]
+    fn replace_patch_pads_partially_overwritten_instructions() {
+        let code_patch_plan =
+            plan_replace_patch(&TestInstructionSet {}, &[0x03, 0xAA, 0xBB, 0x03, 0xCC, 0xDD], 0x1000, "01 01 01 01").expect("Expected a replace plan.");
+
+        assert_eq!(code_patch_plan.original_bytes, vec![0x03, 0xAA, 0xBB, 0x03, 0xCC, 0xDD]);
+        assert_eq!(code_patch_plan.patched_bytes, vec![0x01, 0x01, 0x01, 0x01, 0x90, 0x90]);
+    }
+
+    #[test]
+    fn replace_patch_fails_when_the_code_ends_mid_instruction() {
+        assert!(plan_replace_patch(&TestInstructionSet {}, &[0x01, 0x03, 0xAA], 0x1000, "01 01").is_err());
+    }
+
+    #[test]
+    fn detour_patch_relocates_overwritten_instructions_and_jumps_back() {
+        let code_patch_plan =
+            plan_detour_patch(&TestInstructionSet {}, &[0x03, 0x10, 0x20, 0x03, 0x30, 0x40, 0x01], 0x1000, "01", 0x1100).expect("Expected a detour plan.");
+
+        assert_eq!(code_patch_plan.original_bytes, vec![0x03, 0x10, 0x20, 0x03, 0x30, 0x40]);
+        assert_eq!(code_patch_plan.patched_bytes, vec![0xE9, 0x00, 0x11, 0x00, 0x00, 0x90]);
+        assert_eq!(
+            code_patch_plan.code_cave_bytes,
+            vec![
+                0x01, 0x04, 0x11, 0x21, 0x04, 0x31, 0x41, 0xE9, 0x06, 0x10, 0x00, 0x00
+            ]
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod code_cave_finder;
+pub mod code_patch_planner;
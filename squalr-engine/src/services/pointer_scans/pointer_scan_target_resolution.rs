@@ -52,9 +52,25 @@ pub fn resolve_pointer_size_for_process_bitness(
     requested_pointer_size: PointerScanPointerSize,
     address_space: PointerScanAddressSpace,
     process_info: &OpenedProcessInfo,
+    native_pointer_size: Option<PointerScanPointerSize>,
 ) -> PointerScanPointerSize {
     if matches!(address_space, PointerScanAddressSpace::GameMemory) {
-        return requested_pointer_size;
+        // Emulated systems have their own pointer width and byte order, which the memory view reports when it knows the console.
+        return match native_pointer_size {
+            Some(native_pointer_size) => {
+                if requested_pointer_size != native_pointer_size {
+                    log::info!(
+                        "Pointer scan requested {} but the emulated system uses {} pointers; using {} instead.",
+                        requested_pointer_size,
+                        native_pointer_size,
+                        native_pointer_size,
+                    );
+                }
+
+                native_pointer_size
+            }
+            None => requested_pointer_size,
+        };
     }
 
     let process_pointer_size = PointerScanPointerSize::from_process_bitness(process_info.get_bitness());
@@ -237,3 +253,46 @@ impl PointerScanTargetResolver {
         Ok(cloned_snapshot)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_pointer_size_for_process_bitness;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::pointer_scans::pointer_scan_address_space::PointerScanAddressSpace;
+    use squalr_engine_api::structures::pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+    #[test]
+    fn game_memory_pointer_size_prefers_native_pointer_size() {
+        let process_info = OpenedProcessInfo::new(7, String::from("retroarch.exe"), 42, Bitness::Bit64, None);
+
+        assert_eq!(
+            resolve_pointer_size_for_process_bitness(
+                PointerScanPointerSize::Pointer32,
+                PointerScanAddressSpace::GameMemory,
+                &process_info,
+                Some(PointerScanPointerSize::Pointer24be),
+            ),
+            PointerScanPointerSize::Pointer24be
+        );
+        assert_eq!(
+            resolve_pointer_size_for_process_bitness(PointerScanPointerSize::Pointer32be, PointerScanAddressSpace::GameMemory, &process_info, None),
+            PointerScanPointerSize::Pointer32be
+        );
+    }
+
+    #[test]
+    fn emulator_memory_pointer_size_ignores_native_pointer_size() {
+        let process_info = OpenedProcessInfo::new(7, String::from("retroarch.exe"), 42, Bitness::Bit64, None);
+
+        assert_eq!(
+            resolve_pointer_size_for_process_bitness(
+                PointerScanPointerSize::Pointer32,
+                PointerScanAddressSpace::EmulatorMemory,
+                &process_info,
+                Some(PointerScanPointerSize::Pointer24),
+            ),
+            PointerScanPointerSize::Pointer64
+        );
+    }
+}
@@ -1,12 +1,18 @@
 use squalr_engine_api::commands::memory::freeze::memory_freeze_request::MemoryFreezeRequest;
 use squalr_engine_api::commands::memory::freeze::memory_freeze_response::MemoryFreezeResponse;
 use squalr_engine_api::commands::memory::freeze::memory_freeze_target::MemoryFreezeTarget;
+use squalr_engine_api::commands::patch::apply::patch_apply_request::PatchApplyRequest;
+use squalr_engine_api::commands::patch::apply::patch_apply_response::PatchApplyResponse;
+use squalr_engine_api::commands::patch::undo::patch_undo_request::PatchUndoRequest;
+use squalr_engine_api::commands::patch::undo::patch_undo_response::PatchUndoResponse;
+use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
 use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
 use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::structures::patching::code_patch_definition::CodePatchDefinition;
 use squalr_engine_api::structures::projects::project_items::built_in_types::{
     project_item_type_address::ProjectItemTypeAddress, project_item_type_address_target::ProjectItemAddressTarget,
-    project_item_type_pointer::ProjectItemTypePointer,
+    project_item_type_patch::ProjectItemTypePatch, project_item_type_pointer::ProjectItemTypePointer,
 };
 use squalr_engine_api::structures::projects::project_items::{project_item::ProjectItem, project_item_ref::ProjectItemRef};
 use std::collections::{HashMap, HashSet};
@@ -19,6 +25,7 @@ use std::time::Duration;
 pub struct ProjectItemActivationChangeSet {
     pub has_activation_changes: bool,
     pub freeze_targets: Vec<MemoryFreezeTarget>,
+    pub code_patch_definitions: Vec<CodePatchDefinition>,
 }
 
 pub fn apply_project_item_activation(
@@ -47,6 +54,11 @@ pub fn apply_project_item_activation(
             if let Some(freeze_target) = create_memory_freeze_target(project_item) {
                 activation_change_set.freeze_targets.push(freeze_target);
             }
+            if let Some(code_patch_definition) = create_code_patch_definition(project_item) {
+                activation_change_set
+                    .code_patch_definitions
+                    .push(code_patch_definition);
+            }
         }
     }
 
@@ -122,6 +134,14 @@ pub fn create_memory_freeze_target(
     None
 }
 
+pub fn create_code_patch_definition(project_item: &ProjectItem) -> Option<CodePatchDefinition> {
+    if project_item.get_item_type().get_project_item_type_id() != ProjectItemTypePatch::PROJECT_ITEM_TYPE_ID {
+        return None;
+    }
+
+    ProjectItemTypePatch::get_field_code_patch_definition(project_item)
+}
+
 fn build_memory_freeze_target_from_address_target(
     address_target: &ProjectItemAddressTarget,
     data_type_id: String,
@@ -200,15 +220,83 @@ pub fn dispatch_memory_freeze_request(
     }
 }
 
+/// Applies activated patch items and undoes deactivated ones. Each patch is dispatched separately, so one that fails does
+/// not prevent the others from toggling.
+pub fn dispatch_code_patch_requests(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    code_patch_definitions: &[CodePatchDefinition],
+    is_activated: bool,
+) {
+    for code_patch_definition in code_patch_definitions {
+        let patch_error = if is_activated {
+            let patch_apply_request = PatchApplyRequest {
+                code_patch_definition: code_patch_definition.clone(),
+            };
+
+            dispatch_privileged_request_and_wait::<PatchApplyResponse>(engine_unprivileged_state, patch_apply_request.to_engine_command())
+                .and_then(|patch_apply_response| patch_apply_response.error.map_or(Ok(()), Err))
+        } else {
+            let patch_undo_request = PatchUndoRequest {
+                address: code_patch_definition.address,
+                module_name: code_patch_definition.module_name.clone(),
+            };
+
+            dispatch_privileged_request_and_wait::<PatchUndoResponse>(engine_unprivileged_state, patch_undo_request.to_engine_command())
+                .and_then(|patch_undo_response| patch_undo_response.error.map_or(Ok(()), Err))
+        };
+
+        if let Err(error) = patch_error {
+            log::warn!(
+                "Project-item activation could not {} the {} patch at 0x{:X}: {}",
+                if is_activated { "apply" } else { "undo" },
+                code_patch_definition.kind,
+                code_patch_definition.address,
+                error
+            );
+        }
+    }
+}
+
+fn dispatch_privileged_request_and_wait<ResponseType>(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    privileged_command: PrivilegedCommand,
+) -> Result<ResponseType, String>
+where
+    ResponseType: TypedPrivilegedCommandResponse + Send + 'static,
+{
+    let (response_sender, response_receiver) = mpsc::channel();
+    let dispatch_result = match engine_unprivileged_state.get_bindings().read() {
+        Ok(engine_bindings) => engine_bindings.dispatch_privileged_command(
+            privileged_command,
+            Box::new(move |engine_response| {
+                let conversion_result = ResponseType::from_engine_response(engine_response)
+                    .map_err(|unexpected_response| format!("Unexpected response variant for project-item activation: {:?}", unexpected_response));
+                let _ = response_sender.send(conversion_result);
+            }),
+        ),
+        Err(error) => return Err(format!("Failed to acquire engine bindings lock: {}", error)),
+    };
+
+    if let Err(error) = dispatch_result {
+        return Err(format!("Failed to dispatch request: {}", error));
+    }
+
+    match response_receiver.recv_timeout(Duration::from_secs(5)) {
+        Ok(conversion_result) => conversion_result,
+        Err(error) => Err(format!("Timed out waiting for response: {}", error)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{apply_project_item_activation, collect_project_item_paths_for_activation, create_memory_freeze_target};
+    use super::{apply_project_item_activation, collect_project_item_paths_for_activation, create_code_patch_definition, create_memory_freeze_target};
     use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
     use squalr_engine_api::structures::memory::pointer::Pointer;
+    use squalr_engine_api::structures::patching::{code_patch_definition::CodePatchDefinition, code_patch_kind::CodePatchKind};
     use squalr_engine_api::structures::pointer_scans::pointer_scan_pointer_size::PointerScanPointerSize;
     use squalr_engine_api::structures::projects::project_items::built_in_types::{
         project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory,
-        project_item_type_pointer::ProjectItemTypePointer,
+        project_item_type_patch::ProjectItemTypePatch, project_item_type_pointer::ProjectItemTypePointer,
     };
     use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
     use std::collections::HashMap;
@@ -323,4 +411,32 @@ mod tests {
         assert_eq!(freeze_target.pointer_offsets, vec![0x10, -0x8]);
         assert_eq!(freeze_target.pointer_size, PointerScanPointerSize::Pointer64);
     }
+
+    #[test]
+    fn apply_project_item_activation_collects_code_patch_definitions() {
+        let patch_item_ref = ProjectItemRef::new(PathBuf::from(r"C:\Project\Items\Infinite Ammo.json"));
+        let code_patch_definition = CodePatchDefinition::new(0x1F2A, String::from("game.exe"), CodePatchKind::Nop { byte_count: 2 });
+        let mut project_items = HashMap::from([(
+            patch_item_ref.clone(),
+            ProjectItemTypePatch::new_project_item("Infinite Ammo", &code_patch_definition),
+        )]);
+        let requested_project_item_paths = vec![
+            patch_item_ref
+                .get_project_item_path()
+                .to_string_lossy()
+                .into_owned(),
+        ];
+
+        let activation_change_set = apply_project_item_activation(&mut project_items, &requested_project_item_paths, true);
+
+        assert!(activation_change_set.freeze_targets.is_empty());
+        assert_eq!(activation_change_set.code_patch_definitions, vec![code_patch_definition]);
+    }
+
+    #[test]
+    fn create_code_patch_definition_skips_non_patch_project_items() {
+        let address_project_item = ProjectItemTypeAddress::new_project_item("Health", 0x579C, "winmine.exe", "", DataTypeU8::get_value_from_primitive(0));
+
+        assert!(create_code_patch_definition(&address_project_item).is_none());
+    }
 }
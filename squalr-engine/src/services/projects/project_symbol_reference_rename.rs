@@ -0,0 +1,44 @@
+use squalr_engine_api::structures::projects::{
+    project::Project, project_items::project_item::ProjectItem, project_items::project_item_ref::ProjectItemRef, project_symbol_catalog::ProjectSymbolCatalog,
+    symbol_references::project_symbol_reference::ProjectSymbolReferenceSource, symbol_references::project_symbol_rename_edit::ProjectSymbolRenameEdit,
+};
+use std::collections::HashMap;
+
+pub struct ProjectSymbolReferenceRename;
+
+impl ProjectSymbolReferenceRename {
+    /// Runs a reference-rewriting rename against copies of the opened project's symbol catalog and project items. The copies
+    /// replace the originals only when the rename succeeds outside of a dry run, so a failed rename leaves the project as it was.
+    pub fn apply(
+        opened_project: &mut Project,
+        dry_run: bool,
+        rename: impl FnOnce(&mut ProjectSymbolCatalog, &mut HashMap<ProjectItemRef, ProjectItem>) -> Result<Vec<ProjectSymbolRenameEdit>, String>,
+    ) -> Result<Vec<ProjectSymbolRenameEdit>, String> {
+        let mut project_symbol_catalog = opened_project
+            .get_project_info()
+            .get_project_symbol_catalog()
+            .clone();
+        let mut project_items = opened_project.get_project_items().clone();
+        let rename_edits = rename(&mut project_symbol_catalog, &mut project_items)?;
+
+        if dry_run {
+            return Ok(rename_edits);
+        }
+
+        *opened_project
+            .get_project_info_mut()
+            .get_project_symbol_catalog_mut() = project_symbol_catalog;
+
+        if Self::edits_project_items(&rename_edits) {
+            *opened_project.get_project_items_mut() = project_items;
+        }
+
+        Ok(rename_edits)
+    }
+
+    pub fn edits_project_items(rename_edits: &[ProjectSymbolRenameEdit]) -> bool {
+        rename_edits
+            .iter()
+            .any(|rename_edit| matches!(rename_edit.get_source(), ProjectSymbolReferenceSource::ProjectItem { .. }))
+    }
+}
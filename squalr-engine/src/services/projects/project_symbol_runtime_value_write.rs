@@ -70,6 +70,7 @@ pub fn build_project_symbol_runtime_value_write_request(
         address,
         module_name: write_plan_request.module_name.clone(),
         value: value_bytes,
+        value_transform: None,
     })
 }
 
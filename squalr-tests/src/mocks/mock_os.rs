@@ -1,12 +1,23 @@
 use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::debugging::{
+    breakpoint::Breakpoint, breakpoint_hit::BreakpointHit, breakpoint_mode::BreakpointMode, instruction_trace_stop_reason::InstructionTraceStopReason,
+    register_snapshot::RegisterSnapshot, register_value::RegisterValue, traced_instruction::TracedInstruction, watchpoint::Watchpoint,
+    watchpoint_access::WatchpointAccess, watchpoint_hit::WatchpointHit, watchpoint_size::WatchpointSize,
+};
+use squalr_engine_api::structures::memory::memory_protection::MemoryProtection;
 use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
 use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::processes::process_info::ProcessInfo;
+use squalr_engine_api::structures::processes::thread_info::ThreadInfo;
 use squalr_engine_api::structures::structs::valued_struct::ValuedStruct;
-use squalr_engine_session::os::engine_os_provider::{EngineOsProviders, MemoryQueryProvider, MemoryReadProvider, MemoryWriteProvider, ProcessQueryProvider};
-use squalr_engine_targets::{PageRetrievalMode, ProcessQueryError, ProcessQueryOptions};
+use squalr_engine_session::os::engine_os_provider::{
+    DebuggerError, DebuggerProvider, EngineOsProviders, MemoryAllocationError, MemoryAllocationProvider, MemoryQueryProvider, MemoryReadProvider,
+    MemoryWriteProvider, MemoryWriteTrackingError, MemoryWriteTrackingProvider, ProcessQueryProvider, ThreadControlError, ThreadControlProvider,
+};
+use squalr_engine_targets::{PageRetrievalMode, ProcessLaunchOptions, ProcessQueryError, ProcessQueryOptions};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 #[derive(Clone)]
 pub struct RecordedProcessQueryOptions {
@@ -18,18 +29,60 @@ pub struct RecordedProcessQueryOptions {
     pub limit: Option<u64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecordedThreadControlRequest {
+    SuspendThread(u32),
+    ResumeThread(u32),
+    SuspendProcess,
+    ResumeProcess,
+}
+
 #[derive(Default)]
 pub struct MockOsState {
     pub process_query_requests: Vec<RecordedProcessQueryOptions>,
     pub close_process_handles: Vec<u64>,
     pub open_process_requests: Vec<u32>,
+    pub launch_process_requests: Vec<ProcessLaunchOptions>,
     pub memory_read_addresses: Vec<u64>,
     pub memory_struct_read_addresses: Vec<u64>,
     pub memory_write_requests: Vec<(u64, Vec<u8>)>,
+    /// Addresses written with `write_bytes_ignoring_protection`. These writes are also recorded as write requests.
+    pub protection_ignoring_write_addresses: Vec<u64>,
+    pub memory_contents: Vec<(u64, Vec<u8>)>,
+    pub watchpoints: Vec<Watchpoint>,
+    pub watchpoint_hits: Vec<WatchpointHit>,
+    pub removed_watchpoint_ids: Vec<u64>,
+    pub breakpoints: Vec<Breakpoint>,
+    pub breakpoint_hits: Vec<BreakpointHit>,
+    pub allocated_regions: Vec<(NormalizedRegion, MemoryProtection)>,
+    pub freed_regions: Vec<NormalizedRegion>,
+    pub protected_regions: Vec<(NormalizedRegion, MemoryProtection)>,
+    pub thread_control_requests: Vec<RecordedThreadControlRequest>,
+    pub suspended_thread_ids: Vec<u32>,
+    pub is_process_suspended: bool,
+    /// Counts byte reads issued while the whole process was suspended.
+    pub memory_reads_while_suspended: usize,
+    pub threads: Vec<ThreadInfo>,
     pub processes: Vec<ProcessInfo>,
     pub opened_process_result: Option<OpenedProcessInfo>,
     pub modules: Vec<NormalizedModule>,
+    /// Exported functions resolved by `resolve_module_symbol`, as module name, symbol name and address.
+    pub module_symbols: Vec<(String, String, u64)>,
+    pub function_calls: Vec<(u64, Vec<u64>)>,
+    /// Return values of remote calls by function address. Functions without one return zero.
+    pub function_call_results: Vec<(u64, u64)>,
+    /// Instruction traces requested, as start address, end address, thread id and instruction budget.
+    pub instruction_trace_requests: Vec<(u64, Option<u64>, Option<u32>, u64)>,
+    /// Stops returned by `trace_instructions`. An empty list makes the trace time out waiting for the start address.
+    pub traced_instructions: Vec<TracedInstruction>,
+    pub instruction_trace_stop_reason: InstructionTraceStopReason,
     pub memory_pages: Vec<NormalizedRegion>,
+    /// Pages reported by `get_executable_page_bounds`, clipped to the queried range.
+    pub executable_pages: Vec<NormalizedRegion>,
+    /// Number of times write tracking was started.
+    pub write_tracking_begin_count: usize,
+    /// Pages reported by `get_written_pages`. Without any, write tracking is reported as unsupported.
+    pub written_pages: Option<Vec<NormalizedRegion>>,
     pub write_success: bool,
     pub read_success: bool,
 }
@@ -81,6 +134,31 @@ impl MockEngineOs {
         }
     }
 
+    pub fn set_module_symbol(
+        &self,
+        module_name: &str,
+        symbol_name: &str,
+        address: u64,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard
+                .module_symbols
+                .push((module_name.to_string(), symbol_name.to_string(), address));
+        }
+    }
+
+    pub fn set_function_call_result(
+        &self,
+        function_address: u64,
+        return_value: u64,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard
+                .function_call_results
+                .push((function_address, return_value));
+        }
+    }
+
     pub fn set_memory_pages(
         &self,
         memory_pages: Vec<NormalizedRegion>,
@@ -90,6 +168,35 @@ impl MockEngineOs {
         }
     }
 
+    pub fn set_executable_pages(
+        &self,
+        executable_pages: Vec<NormalizedRegion>,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard.executable_pages = executable_pages;
+        }
+    }
+
+    /// Backs reads at `address` with `bytes`, so executors that inspect process memory see real content.
+    pub fn set_memory_contents(
+        &self,
+        address: u64,
+        bytes: Vec<u8>,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard.memory_contents.push((address, bytes));
+        }
+    }
+
+    pub fn set_written_pages(
+        &self,
+        written_pages: Option<Vec<NormalizedRegion>>,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard.written_pages = written_pages;
+        }
+    }
+
     pub fn set_write_success(
         &self,
         write_success: bool,
@@ -108,13 +215,59 @@ impl MockEngineOs {
         }
     }
 
+    pub fn set_watchpoint_hits(
+        &self,
+        watchpoint_hits: Vec<WatchpointHit>,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard.watchpoint_hits = watchpoint_hits;
+        }
+    }
+
+    pub fn set_breakpoint_hits(
+        &self,
+        breakpoint_hits: Vec<BreakpointHit>,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard.breakpoint_hits = breakpoint_hits;
+        }
+    }
+
+    pub fn set_traced_instructions(
+        &self,
+        traced_instructions: Vec<TracedInstruction>,
+        instruction_trace_stop_reason: InstructionTraceStopReason,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard.traced_instructions = traced_instructions;
+            state_guard.instruction_trace_stop_reason = instruction_trace_stop_reason;
+        }
+    }
+
+    pub fn set_threads(
+        &self,
+        threads: Vec<ThreadInfo>,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard.threads = threads;
+        }
+    }
+
     pub fn create_providers(&self) -> EngineOsProviders {
         let process_provider = Arc::new(MockProcessQueryProvider { state: self.state.clone() });
         let memory_query_provider = Arc::new(MockMemoryQueryProvider { state: self.state.clone() });
         let memory_read_provider = Arc::new(MockMemoryReadProvider { state: self.state.clone() });
         let memory_write_provider = Arc::new(MockMemoryWriteProvider { state: self.state.clone() });
+        let debugger_provider = Arc::new(MockDebuggerProvider { state: self.state.clone() });
+        let memory_allocation_provider = Arc::new(MockMemoryAllocationProvider { state: self.state.clone() });
+        let thread_control_provider = Arc::new(MockThreadControlProvider { state: self.state.clone() });
+        let memory_write_tracking_provider = Arc::new(MockMemoryWriteTrackingProvider { state: self.state.clone() });
 
         EngineOsProviders::new(process_provider, memory_query_provider, memory_read_provider, memory_write_provider)
+            .with_debugger(debugger_provider)
+            .with_memory_allocation(memory_allocation_provider)
+            .with_thread_control(thread_control_provider)
+            .with_memory_write_tracking(memory_write_tracking_provider)
     }
 }
 
@@ -174,6 +327,34 @@ impl ProcessQueryProvider for MockProcessQueryProvider {
         }
     }
 
+    fn launch_process(
+        &self,
+        process_launch_options: &ProcessLaunchOptions,
+    ) -> Result<OpenedProcessInfo, ProcessQueryError> {
+        match self.state.lock() {
+            Ok(mut state_guard) => {
+                state_guard
+                    .launch_process_requests
+                    .push(process_launch_options.clone());
+
+                match state_guard.opened_process_result.clone() {
+                    Some(opened_process_info) => {
+                        state_guard.is_process_suspended = process_launch_options.is_suspended;
+                        Ok(opened_process_info)
+                    }
+                    None => Err(ProcessQueryError::launch_process_failed(
+                        process_launch_options.executable_path.display().to_string(),
+                        "No mocked opened process result configured",
+                    )),
+                }
+            }
+            Err(error) => Err(ProcessQueryError::internal(
+                "launch_process",
+                format!("Failed to lock mock process query provider: {}", error),
+            )),
+        }
+    }
+
     fn close_process(
         &self,
         handle: u64,
@@ -257,6 +438,42 @@ impl MemoryQueryProvider for MockMemoryQueryProvider {
             Err(_error) => Vec::new(),
         }
     }
+
+    fn get_executable_page_bounds(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: u64,
+    ) -> Vec<NormalizedRegion> {
+        match self.state.lock() {
+            Ok(state_guard) => state_guard
+                .executable_pages
+                .iter()
+                .filter_map(|executable_page| {
+                    let base_address = executable_page.get_base_address().max(start_address);
+                    let end_address = executable_page.get_end_address().min(end_address);
+
+                    (base_address < end_address).then(|| NormalizedRegion::new(base_address, end_address - base_address))
+                })
+                .collect(),
+            Err(_error) => Vec::new(),
+        }
+    }
+
+    fn resolve_module_symbol(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+        symbol_name: &str,
+    ) -> Option<u64> {
+        let state_guard = self.state.lock().ok()?;
+
+        state_guard
+            .module_symbols
+            .iter()
+            .find(|(module_name, module_symbol_name, _)| module_name == module.get_module_name() && module_symbol_name == symbol_name)
+            .map(|(_, _, address)| *address)
+    }
 }
 
 struct MockMemoryReadProvider {
@@ -297,11 +514,27 @@ impl MemoryReadProvider for MockMemoryReadProvider {
     fn read_bytes(
         &self,
         _process_info: &OpenedProcessInfo,
-        _address: u64,
-        _values: &mut [u8],
+        address: u64,
+        values: &mut [u8],
     ) -> bool {
         match self.state.lock() {
-            Ok(state_guard) => state_guard.read_success,
+            Ok(mut state_guard) => {
+                if state_guard.is_process_suspended {
+                    state_guard.memory_reads_while_suspended += 1;
+                }
+
+                for (content_address, content_bytes) in &state_guard.memory_contents {
+                    for (content_offset, content_byte) in content_bytes.iter().enumerate() {
+                        let value_offset = (content_address + content_offset as u64).wrapping_sub(address);
+
+                        if let Some(value) = values.get_mut(value_offset as usize) {
+                            *value = *content_byte;
+                        }
+                    }
+                }
+
+                state_guard.read_success
+            }
             Err(_error) => false,
         }
     }
@@ -328,4 +561,477 @@ impl MemoryWriteProvider for MockMemoryWriteProvider {
             Err(_error) => false,
         }
     }
+
+    fn write_bytes_ignoring_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        match self.state.lock() {
+            Ok(mut state_guard) => state_guard.protection_ignoring_write_addresses.push(address),
+            Err(_error) => return false,
+        }
+
+        self.write_bytes(process_info, address, values)
+    }
+}
+
+struct MockMemoryAllocationProvider {
+    state: Arc<Mutex<MockOsState>>,
+}
+
+impl MockMemoryAllocationProvider {
+    const PAGE_SIZE: u64 = 0x1000;
+    const ALLOCATION_BASE_ADDRESS: u64 = 0x7000_0000;
+
+    fn align_to_pages(
+        address: u64,
+        size: u64,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        if size == 0 {
+            return Err(MemoryAllocationError::invalid_range(address, size));
+        }
+
+        let base_address = address & !(Self::PAGE_SIZE - 1);
+        let end_address = (address + size).next_multiple_of(Self::PAGE_SIZE);
+
+        Ok(NormalizedRegion::new(base_address, end_address - base_address))
+    }
+}
+
+impl MemoryAllocationProvider for MockMemoryAllocationProvider {
+    fn allocate(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        preferred_address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        let mut state_guard = self
+            .state
+            .lock()
+            .map_err(|error| MemoryAllocationError::operation_failed("allocate", error.to_string()))?;
+        let base_address = if preferred_address == 0 {
+            Self::ALLOCATION_BASE_ADDRESS + state_guard.allocated_regions.len() as u64 * Self::PAGE_SIZE
+        } else {
+            preferred_address
+        };
+        let region = Self::align_to_pages(base_address, size)?;
+        state_guard.allocated_regions.push((region.clone(), protection));
+
+        Ok(region)
+    }
+
+    fn free(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        let region = Self::align_to_pages(address, size)?;
+        let mut state_guard = self
+            .state
+            .lock()
+            .map_err(|error| MemoryAllocationError::operation_failed("free", error.to_string()))?;
+        state_guard.freed_regions.push(region.clone());
+
+        Ok(region)
+    }
+
+    fn protect(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        protection: MemoryProtection,
+    ) -> Result<NormalizedRegion, MemoryAllocationError> {
+        let region = Self::align_to_pages(address, size)?;
+        let mut state_guard = self
+            .state
+            .lock()
+            .map_err(|error| MemoryAllocationError::operation_failed("protect", error.to_string()))?;
+        state_guard.protected_regions.push((region.clone(), protection));
+
+        Ok(region)
+    }
+}
+
+struct MockMemoryWriteTrackingProvider {
+    state: Arc<Mutex<MockOsState>>,
+}
+
+impl MemoryWriteTrackingProvider for MockMemoryWriteTrackingProvider {
+    fn begin_write_tracking(
+        &self,
+        _process_info: &OpenedProcessInfo,
+    ) -> Result<(), MemoryWriteTrackingError> {
+        match self.state.lock() {
+            Ok(mut state_guard) => {
+                state_guard.write_tracking_begin_count += 1;
+
+                match state_guard.written_pages {
+                    Some(_) => Ok(()),
+                    None => Err(MemoryWriteTrackingError::not_supported("mock write tracking is disabled")),
+                }
+            }
+            Err(error) => Err(MemoryWriteTrackingError::operation_failed("begin_write_tracking", error.to_string())),
+        }
+    }
+
+    fn get_written_pages(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        memory_regions: &[NormalizedRegion],
+    ) -> Result<Vec<NormalizedRegion>, MemoryWriteTrackingError> {
+        match self.state.lock() {
+            Ok(state_guard) => match &state_guard.written_pages {
+                Some(written_pages) => Ok(written_pages
+                    .iter()
+                    .filter(|written_page| {
+                        memory_regions.iter().any(|memory_region| {
+                            written_page.get_base_address() >= memory_region.get_base_address()
+                                && written_page.get_end_address() <= memory_region.get_end_address()
+                        })
+                    })
+                    .cloned()
+                    .collect()),
+                None => Err(MemoryWriteTrackingError::not_supported("mock write tracking is disabled")),
+            },
+            Err(error) => Err(MemoryWriteTrackingError::operation_failed("get_written_pages", error.to_string())),
+        }
+    }
+}
+
+struct MockThreadControlProvider {
+    state: Arc<Mutex<MockOsState>>,
+}
+
+impl MockThreadControlProvider {
+    fn record_request(
+        &self,
+        operation: &'static str,
+        thread_control_request: RecordedThreadControlRequest,
+    ) -> Result<(), ThreadControlError> {
+        let mut state_guard = self
+            .state
+            .lock()
+            .map_err(|error| ThreadControlError::operation_failed(operation, error.to_string()))?;
+
+        match &thread_control_request {
+            RecordedThreadControlRequest::SuspendThread(thread_id) => state_guard.suspended_thread_ids.push(*thread_id),
+            RecordedThreadControlRequest::ResumeThread(thread_id) => state_guard
+                .suspended_thread_ids
+                .retain(|suspended_thread_id| suspended_thread_id != thread_id),
+            RecordedThreadControlRequest::SuspendProcess => state_guard.is_process_suspended = true,
+            RecordedThreadControlRequest::ResumeProcess => {
+                state_guard.is_process_suspended = false;
+                state_guard.suspended_thread_ids.clear();
+            }
+        }
+
+        state_guard.thread_control_requests.push(thread_control_request);
+
+        Ok(())
+    }
+}
+
+impl ThreadControlProvider for MockThreadControlProvider {
+    fn get_threads(
+        &self,
+        _process_info: &OpenedProcessInfo,
+    ) -> Result<Vec<ThreadInfo>, ThreadControlError> {
+        let state_guard = self
+            .state
+            .lock()
+            .map_err(|error| ThreadControlError::operation_failed("list threads", error.to_string()))?;
+
+        Ok(state_guard
+            .threads
+            .iter()
+            .map(|thread_info| {
+                let is_suspended = state_guard.is_process_suspended
+                    || state_guard
+                        .suspended_thread_ids
+                        .contains(&thread_info.get_thread_id());
+
+                ThreadInfo::new(
+                    thread_info.get_thread_id(),
+                    thread_info.get_name().to_string(),
+                    thread_info.get_thread_state(),
+                    thread_info.get_user_time_ms(),
+                    thread_info.get_kernel_time_ms(),
+                    thread_info.get_stack_region().cloned(),
+                    is_suspended,
+                )
+            })
+            .collect())
+    }
+
+    fn suspend_thread(
+        &self,
+        process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<(), ThreadControlError> {
+        if !self
+            .get_threads(process_info)?
+            .iter()
+            .any(|thread_info| thread_info.get_thread_id() == thread_id)
+        {
+            return Err(ThreadControlError::unknown_thread(process_info.get_process_id_raw(), thread_id));
+        }
+
+        self.record_request("suspend thread", RecordedThreadControlRequest::SuspendThread(thread_id))
+    }
+
+    fn resume_thread(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<(), ThreadControlError> {
+        self.record_request("resume thread", RecordedThreadControlRequest::ResumeThread(thread_id))
+    }
+
+    fn suspend_process(
+        &self,
+        _process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError> {
+        self.record_request("suspend process", RecordedThreadControlRequest::SuspendProcess)
+    }
+
+    fn resume_process(
+        &self,
+        _process_info: &OpenedProcessInfo,
+    ) -> Result<(), ThreadControlError> {
+        self.record_request("resume process", RecordedThreadControlRequest::ResumeProcess)
+    }
+
+    fn get_thread_context(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        thread_id: u32,
+    ) -> Result<RegisterSnapshot, ThreadControlError> {
+        Ok(RegisterSnapshot::new(
+            thread_id,
+            0x40_1000,
+            0x7FFF_0000,
+            vec![
+                RegisterValue::new("rip".to_string(), 0x40_1000),
+                RegisterValue::new("rsp".to_string(), 0x7FFF_0000),
+            ],
+        ))
+    }
+}
+
+struct MockDebuggerProvider {
+    state: Arc<Mutex<MockOsState>>,
+}
+
+impl DebuggerProvider for MockDebuggerProvider {
+    fn set_watchpoint(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        watchpoint_size: WatchpointSize,
+        watchpoint_access: WatchpointAccess,
+    ) -> Result<Watchpoint, DebuggerError> {
+        if !watchpoint_size.is_aligned(address) {
+            return Err(DebuggerError::unaligned_watchpoint(address, watchpoint_size.get_size_in_bytes()));
+        }
+
+        let mut state_guard = self
+            .state
+            .lock()
+            .map_err(|error| DebuggerError::internal("set_watchpoint", error.to_string()))?;
+        let watchpoint = Watchpoint::new(
+            state_guard.watchpoints.len() as u64 + 1,
+            process_info.get_process_id_raw(),
+            address,
+            watchpoint_size,
+            watchpoint_access,
+        );
+        state_guard.watchpoints.push(watchpoint.clone());
+
+        Ok(watchpoint)
+    }
+
+    fn remove_watchpoint(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError> {
+        let watchpoint_hits = self.get_watchpoint_hits(watchpoint_id)?;
+
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard.removed_watchpoint_ids.push(watchpoint_id);
+        }
+
+        Ok(watchpoint_hits)
+    }
+
+    fn get_watchpoint_hits(
+        &self,
+        watchpoint_id: u64,
+    ) -> Result<Vec<WatchpointHit>, DebuggerError> {
+        let state_guard = self
+            .state
+            .lock()
+            .map_err(|error| DebuggerError::internal("get_watchpoint_hits", error.to_string()))?;
+        let is_watchpoint_active = state_guard
+            .watchpoints
+            .iter()
+            .any(|watchpoint| watchpoint.get_watchpoint_id() == watchpoint_id)
+            && !state_guard.removed_watchpoint_ids.contains(&watchpoint_id);
+
+        if !is_watchpoint_active {
+            return Err(DebuggerError::unknown_watchpoint(watchpoint_id));
+        }
+
+        Ok(state_guard.watchpoint_hits.clone())
+    }
+
+    fn get_watchpoints(&self) -> Vec<Watchpoint> {
+        match self.state.lock() {
+            Ok(state_guard) => state_guard.watchpoints.clone(),
+            Err(_error) => Vec::new(),
+        }
+    }
+
+    fn set_breakpoint(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        breakpoint_mode: BreakpointMode,
+    ) -> Result<Breakpoint, DebuggerError> {
+        let mut state_guard = self
+            .state
+            .lock()
+            .map_err(|error| DebuggerError::internal("set_breakpoint", error.to_string()))?;
+
+        if state_guard
+            .breakpoints
+            .iter()
+            .any(|breakpoint| breakpoint.get_address() == address)
+        {
+            return Err(DebuggerError::breakpoint_exists(address));
+        }
+
+        let breakpoint_id = state_guard
+            .breakpoints
+            .iter()
+            .map(Breakpoint::get_breakpoint_id)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let breakpoint = Breakpoint::new(breakpoint_id, process_info.get_process_id_raw(), address, breakpoint_mode);
+        state_guard.breakpoints.push(breakpoint.clone());
+
+        Ok(breakpoint)
+    }
+
+    fn remove_breakpoint(
+        &self,
+        breakpoint_id: u64,
+    ) -> Result<Breakpoint, DebuggerError> {
+        let mut state_guard = self
+            .state
+            .lock()
+            .map_err(|error| DebuggerError::internal("remove_breakpoint", error.to_string()))?;
+        let breakpoint_index = state_guard
+            .breakpoints
+            .iter()
+            .position(|breakpoint| breakpoint.get_breakpoint_id() == breakpoint_id)
+            .ok_or_else(|| DebuggerError::unknown_breakpoint(breakpoint_id))?;
+        let mut breakpoint = state_guard.breakpoints.remove(breakpoint_index);
+        breakpoint.disarm();
+
+        Ok(breakpoint)
+    }
+
+    fn get_breakpoint_hits(
+        &self,
+        breakpoint_id: u64,
+        after_hit_index: u64,
+    ) -> Result<(Breakpoint, Vec<BreakpointHit>), DebuggerError> {
+        let state_guard = self
+            .state
+            .lock()
+            .map_err(|error| DebuggerError::internal("get_breakpoint_hits", error.to_string()))?;
+        let breakpoint = state_guard
+            .breakpoints
+            .iter()
+            .find(|breakpoint| breakpoint.get_breakpoint_id() == breakpoint_id)
+            .cloned()
+            .ok_or_else(|| DebuggerError::unknown_breakpoint(breakpoint_id))?;
+        let breakpoint_hits = state_guard
+            .breakpoint_hits
+            .iter()
+            .filter(|breakpoint_hit| breakpoint_hit.get_breakpoint_id() == breakpoint_id && breakpoint_hit.get_hit_index() > after_hit_index)
+            .cloned()
+            .collect();
+
+        Ok((breakpoint, breakpoint_hits))
+    }
+
+    fn get_breakpoints(&self) -> Vec<Breakpoint> {
+        match self.state.lock() {
+            Ok(state_guard) => state_guard.breakpoints.clone(),
+            Err(_error) => Vec::new(),
+        }
+    }
+
+    fn call_function(
+        &self,
+        process_info: &OpenedProcessInfo,
+        function_address: u64,
+        arguments: &[u64],
+    ) -> Result<u64, DebuggerError> {
+        let mut state_guard = self
+            .state
+            .lock()
+            .map_err(|error| DebuggerError::internal("call_function", error.to_string()))?;
+        let is_traced = state_guard.is_process_suspended || !state_guard.suspended_thread_ids.is_empty() || !state_guard.breakpoints.is_empty();
+
+        if !is_traced {
+            return Err(DebuggerError::not_traced(process_info.get_process_id_raw()));
+        }
+
+        state_guard
+            .function_calls
+            .push((function_address, arguments.to_vec()));
+
+        Ok(state_guard
+            .function_call_results
+            .iter()
+            .find(|(result_function_address, _)| *result_function_address == function_address)
+            .map(|(_, return_value)| *return_value)
+            .unwrap_or(0))
+    }
+
+    fn trace_instructions(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: Option<u64>,
+        thread_id: Option<u32>,
+        instruction_budget: u64,
+        _start_timeout: Duration,
+    ) -> Result<(Vec<TracedInstruction>, InstructionTraceStopReason), DebuggerError> {
+        let mut state_guard = self
+            .state
+            .lock()
+            .map_err(|error| DebuggerError::internal("trace_instructions", error.to_string()))?;
+
+        state_guard
+            .instruction_trace_requests
+            .push((start_address, end_address, thread_id, instruction_budget));
+
+        if state_guard.traced_instructions.is_empty() {
+            return Err(DebuggerError::internal(
+                "trace_instructions",
+                format!("no thread executed 0x{:X} in time", start_address),
+            ));
+        }
+
+        Ok((state_guard.traced_instructions.clone(), state_guard.instruction_trace_stop_reason))
+    }
 }
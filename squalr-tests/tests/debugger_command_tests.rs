@@ -0,0 +1,390 @@
+use squalr_engine::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use squalr_engine::engine_mode::EngineMode;
+use squalr_engine::engine_privileged_state::{EnginePrivilegedState, create_engine_privileged_state_with_os_providers};
+use squalr_engine_api::commands::debugger::debugger_command::DebuggerCommand;
+use squalr_engine_api::commands::debugger::hits::debugger_hits_request::DebuggerHitsRequest;
+use squalr_engine_api::commands::debugger::list_breakpoints::debugger_list_breakpoints_request::DebuggerListBreakpointsRequest;
+use squalr_engine_api::commands::debugger::remove_breakpoint::debugger_remove_breakpoint_request::DebuggerRemoveBreakpointRequest;
+use squalr_engine_api::commands::debugger::set_breakpoint::debugger_set_breakpoint_request::DebuggerSetBreakpointRequest;
+use squalr_engine_api::commands::debugger::set_breakpoint::debugger_set_breakpoint_response::DebuggerSetBreakpointResponse;
+use squalr_engine_api::commands::debugger::trace::debugger_trace_request::DebuggerTraceRequest;
+use squalr_engine_api::commands::debugger::unwatch::debugger_unwatch_request::DebuggerUnwatchRequest;
+use squalr_engine_api::commands::debugger::watch::debugger_watch_request::DebuggerWatchRequest;
+use squalr_engine_api::commands::debugger::watch::debugger_watch_response::DebuggerWatchResponse;
+use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use squalr_engine_api::commands::project::list::project_list_response::ProjectListResponse;
+use squalr_engine_api::commands::unprivileged_command_response::TypedUnprivilegedCommandResponse;
+use squalr_engine_api::structures::debugging::breakpoint::Breakpoint;
+use squalr_engine_api::structures::debugging::breakpoint_hit::BreakpointHit;
+use squalr_engine_api::structures::debugging::breakpoint_mode::BreakpointMode;
+use squalr_engine_api::structures::debugging::instruction_trace::InstructionTrace;
+use squalr_engine_api::structures::debugging::instruction_trace_stop_reason::InstructionTraceStopReason;
+use squalr_engine_api::structures::debugging::register_snapshot::RegisterSnapshot;
+use squalr_engine_api::structures::debugging::register_value::RegisterValue;
+use squalr_engine_api::structures::debugging::traced_instruction::TracedInstruction;
+use squalr_engine_api::structures::debugging::watchpoint::Watchpoint;
+use squalr_engine_api::structures::debugging::watchpoint_access::WatchpointAccess;
+use squalr_engine_api::structures::debugging::watchpoint_hit::WatchpointHit;
+use squalr_engine_api::structures::debugging::watchpoint_size::WatchpointSize;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_tests::mocks::mock_engine_bindings::MockEngineBindings;
+use squalr_tests::mocks::mock_os::MockEngineOs;
+use std::sync::{Arc, Mutex};
+
+fn create_test_state() -> (MockEngineOs, Arc<EnginePrivilegedState>) {
+    let mock_engine_os = MockEngineOs::new();
+    let engine_os_providers = mock_engine_os.create_providers();
+    let engine_privileged_state = match create_engine_privileged_state_with_os_providers(EngineMode::Standalone, engine_os_providers) {
+        Ok(engine_privileged_state) => engine_privileged_state,
+        Err(error) => panic!("failed to create engine privileged state in test: {}", error),
+    };
+
+    (mock_engine_os, engine_privileged_state)
+}
+
+fn create_opened_process_info() -> OpenedProcessInfo {
+    OpenedProcessInfo::new(4242, "game".to_string(), 0x10, Bitness::Bit64, None)
+}
+
+#[test]
+fn debugger_watch_request_dispatches_watch_command_and_invokes_typed_callback() {
+    let watchpoint = Watchpoint::new(3, 4242, 0x2000, WatchpointSize::Byte4, WatchpointAccess::Write);
+    let bindings = MockEngineBindings::new(
+        DebuggerWatchResponse {
+            watchpoint: Some(watchpoint.clone()),
+            error: None,
+        }
+        .to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let dispatched_commands = bindings.get_dispatched_commands();
+    let received_watchpoint = Arc::new(Mutex::new(None));
+    let received_watchpoint_clone = received_watchpoint.clone();
+
+    DebuggerWatchRequest {
+        address: 0x2000,
+        module_name: String::new(),
+        watchpoint_size: WatchpointSize::Byte4,
+        watchpoint_access: WatchpointAccess::Write,
+    }
+    .send_unprivileged(&bindings, move |debugger_watch_response| {
+        if let Ok(mut received_watchpoint) = received_watchpoint_clone.lock() {
+            *received_watchpoint = debugger_watch_response.watchpoint;
+        }
+    });
+
+    assert_eq!(
+        *received_watchpoint
+            .lock()
+            .expect("watchpoint lock should be available"),
+        Some(watchpoint)
+    );
+
+    let dispatched_commands_guard = dispatched_commands
+        .lock()
+        .expect("command capture lock should be available");
+    assert!(matches!(
+        dispatched_commands_guard.as_slice(),
+        [PrivilegedCommand::Debugger(DebuggerCommand::Watch { .. })]
+    ));
+}
+
+#[test]
+fn debugger_watch_executor_resolves_module_address_and_reports_hits() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game", 0x40_0000, 0x1000)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let debugger_watch_response = DebuggerWatchRequest {
+        address: 0x10,
+        module_name: "game".to_string(),
+        watchpoint_size: WatchpointSize::Byte8,
+        watchpoint_access: WatchpointAccess::ReadWrite,
+    }
+    .execute(&engine_privileged_state);
+    let watchpoint = debugger_watch_response
+        .watchpoint
+        .expect("Expected the watchpoint to be set.");
+
+    assert_eq!(watchpoint.get_address(), 0x40_0010);
+    assert_eq!(watchpoint.get_process_id(), 4242);
+
+    let register_snapshot = RegisterSnapshot::new(7, 0x40_1008, 0x7FF0, vec![RegisterValue::new("rax".to_string(), 5)]);
+    mock_engine_os.set_watchpoint_hits(vec![WatchpointHit::new(0x40_1008, true, 12, register_snapshot)]);
+
+    let debugger_hits_response = DebuggerHitsRequest {
+        watchpoint_id: watchpoint.get_watchpoint_id(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(debugger_hits_response.error, None);
+    assert_eq!(debugger_hits_response.watchpoint_hits.len(), 1);
+    assert_eq!(debugger_hits_response.watchpoint_hits[0].get_hit_count(), 12);
+    assert_eq!(
+        debugger_hits_response.watchpoint_hits[0]
+            .get_last_register_snapshot()
+            .get_register("RAX"),
+        Some(5)
+    );
+
+    let debugger_unwatch_response = DebuggerUnwatchRequest {
+        watchpoint_id: watchpoint.get_watchpoint_id(),
+    }
+    .execute(&engine_privileged_state);
+    assert_eq!(debugger_unwatch_response.watchpoint_hits.len(), 1);
+
+    let debugger_hits_after_unwatch_response = DebuggerHitsRequest {
+        watchpoint_id: watchpoint.get_watchpoint_id(),
+    }
+    .execute(&engine_privileged_state);
+    assert!(debugger_hits_after_unwatch_response.error.is_some());
+}
+
+#[test]
+fn debugger_watch_executor_reports_errors_without_an_opened_process_or_with_unaligned_addresses() {
+    let (_mock_engine_os, engine_privileged_state) = create_test_state();
+    let debugger_watch_request = DebuggerWatchRequest {
+        address: 0x1002,
+        module_name: String::new(),
+        watchpoint_size: WatchpointSize::Byte4,
+        watchpoint_access: WatchpointAccess::Write,
+    };
+
+    let debugger_watch_response = debugger_watch_request.execute(&engine_privileged_state);
+    assert!(debugger_watch_response.watchpoint.is_none());
+    assert_eq!(debugger_watch_response.error.as_deref(), Some("No process is opened to watch."));
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let debugger_watch_response = debugger_watch_request.execute(&engine_privileged_state);
+    assert!(debugger_watch_response.watchpoint.is_none());
+    assert_eq!(
+        debugger_watch_response.error.as_deref(),
+        Some("Watchpoint address `0x1002` is not aligned to its 4-byte size.")
+    );
+}
+
+#[test]
+fn debugger_breakpoint_executors_set_list_and_remove_breakpoints() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game", 0x40_0000, 0x1000)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let debugger_set_breakpoint_request = DebuggerSetBreakpointRequest {
+        address: 0x120,
+        module_name: "game".to_string(),
+        breakpoint_mode: BreakpointMode::Trace { hit_limit: 2 },
+    };
+    let debugger_set_breakpoint_response = debugger_set_breakpoint_request.execute(&engine_privileged_state);
+    let breakpoint = debugger_set_breakpoint_response
+        .breakpoint
+        .expect("Expected the breakpoint to be set.");
+
+    assert_eq!(breakpoint.get_address(), 0x40_0120);
+    assert!(debugger_set_breakpoint_response.trackable_task_handle.is_some());
+    assert_eq!(
+        debugger_set_breakpoint_request
+            .execute(&engine_privileged_state)
+            .error
+            .as_deref(),
+        Some("A breakpoint is already set at `0x400120`.")
+    );
+
+    let register_snapshot = RegisterSnapshot::new(7, 0x40_0120, 0x7FF0, vec![RegisterValue::new("rdi".to_string(), 9)]);
+    mock_engine_os.set_breakpoint_hits(vec![BreakpointHit::new(
+        breakpoint.get_breakpoint_id(),
+        1,
+        register_snapshot,
+        vec![0x40_0120, 0x40_0400],
+    )]);
+
+    let debugger_list_breakpoints_response = DebuggerListBreakpointsRequest::default().execute(&engine_privileged_state);
+
+    assert_eq!(debugger_list_breakpoints_response.breakpoints, vec![breakpoint.clone()]);
+    assert_eq!(debugger_list_breakpoints_response.breakpoint_hits.len(), 1);
+    assert_eq!(
+        debugger_list_breakpoints_response.breakpoint_hits[0].get_stack_frames(),
+        &[0x40_0120, 0x40_0400]
+    );
+
+    let debugger_remove_breakpoint_request = DebuggerRemoveBreakpointRequest {
+        breakpoint_id: breakpoint.get_breakpoint_id(),
+    };
+    let removed_breakpoint = debugger_remove_breakpoint_request
+        .execute(&engine_privileged_state)
+        .breakpoint
+        .expect("Expected the breakpoint to be removed.");
+
+    assert!(!removed_breakpoint.get_is_armed());
+    assert!(
+        DebuggerListBreakpointsRequest::default()
+            .execute(&engine_privileged_state)
+            .breakpoints
+            .is_empty()
+    );
+    assert!(
+        debugger_remove_breakpoint_request
+            .execute(&engine_privileged_state)
+            .error
+            .is_some()
+    );
+}
+
+#[test]
+fn debugger_set_breakpoint_request_dispatches_set_breakpoint_command_and_invokes_typed_callback() {
+    let breakpoint = Breakpoint::new(1, 4242, 0x2000, BreakpointMode::Count);
+    let bindings = MockEngineBindings::new(
+        DebuggerSetBreakpointResponse {
+            breakpoint: Some(breakpoint.clone()),
+            trackable_task_handle: None,
+            error: None,
+        }
+        .to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let dispatched_commands = bindings.get_dispatched_commands();
+    let received_breakpoint = Arc::new(Mutex::new(None));
+    let received_breakpoint_clone = received_breakpoint.clone();
+
+    DebuggerSetBreakpointRequest {
+        address: 0x2000,
+        module_name: String::new(),
+        breakpoint_mode: BreakpointMode::Count,
+    }
+    .send_unprivileged(&bindings, move |debugger_set_breakpoint_response| {
+        if let Ok(mut received_breakpoint) = received_breakpoint_clone.lock() {
+            *received_breakpoint = debugger_set_breakpoint_response.breakpoint;
+        }
+    });
+
+    assert_eq!(
+        *received_breakpoint
+            .lock()
+            .expect("breakpoint lock should be available"),
+        Some(breakpoint)
+    );
+
+    let dispatched_commands_guard = dispatched_commands
+        .lock()
+        .expect("command capture lock should be available");
+    assert!(matches!(
+        dispatched_commands_guard.as_slice(),
+        [PrivilegedCommand::Debugger(DebuggerCommand::SetBreakpoint {
+            debugger_set_breakpoint_request
+        })] if debugger_set_breakpoint_request.breakpoint_mode == BreakpointMode::Count
+    ));
+}
+
+fn create_traced_instruction(
+    instruction_pointer: u64,
+    instruction_bytes: Vec<u8>,
+    rax: u64,
+) -> TracedInstruction {
+    let register_snapshot = RegisterSnapshot::new(
+        7,
+        instruction_pointer,
+        0x7FF0,
+        vec![
+            RegisterValue::new("rax".to_string(), rax),
+            RegisterValue::new("rcx".to_string(), 41),
+            RegisterValue::new("rip".to_string(), instruction_pointer),
+        ],
+    );
+
+    TracedInstruction::new(register_snapshot, instruction_bytes)
+}
+
+#[test]
+fn debugger_trace_executor_records_disassembly_and_register_writes_into_a_trace_file() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game", 0x40_0000, 0x2000)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+    // mov rax, rcx; add rax, 1; ret
+    mock_engine_os.set_traced_instructions(
+        vec![
+            create_traced_instruction(0x40_1000, vec![0x48, 0x89, 0xC8, 0x48, 0x83, 0xC0, 0x01], 0),
+            create_traced_instruction(0x40_1003, vec![0x48, 0x83, 0xC0, 0x01, 0xC3], 41),
+            create_traced_instruction(0x40_1007, vec![0xC3], 42),
+        ],
+        InstructionTraceStopReason::ReachedEndAddress,
+    );
+
+    let trace_file_path = std::env::temp_dir().join(format!("squalr-debugger-trace-{}.json", std::process::id()));
+    let debugger_trace_response = DebuggerTraceRequest {
+        start_address: 0x1000,
+        module_name: "game".to_string(),
+        end_address: Some(0x1007),
+        thread_id: Some(7),
+        instruction_budget: 0,
+        start_timeout_ms: 1000,
+        instruction_set_id: String::new(),
+        trace_file_path: Some(trace_file_path.clone()),
+    }
+    .execute(&engine_privileged_state);
+    let loaded_instruction_trace = InstructionTrace::load_from_file(&trace_file_path);
+    let _ = std::fs::remove_file(&trace_file_path);
+
+    assert_eq!(debugger_trace_response.error, None);
+
+    let instruction_trace = debugger_trace_response
+        .instruction_trace
+        .expect("Expected the trace to be recorded.");
+    let instruction_trace_requests = mock_engine_os
+        .get_state()
+        .lock()
+        .expect("mock state lock should be available")
+        .instruction_trace_requests
+        .clone();
+
+    assert_eq!(instruction_trace_requests, vec![(0x40_1000, Some(0x40_1007), Some(7), 1)]);
+    assert_eq!(instruction_trace.get_stop_address(), 0x40_1007);
+    assert_eq!(instruction_trace.get_steps().len(), 2);
+    assert_eq!(instruction_trace.get_steps()[0].get_instruction_bytes(), &[0x48, 0x89, 0xC8]);
+    assert!(
+        instruction_trace.get_steps()[0]
+            .get_disassembly()
+            .contains("mov")
+    );
+    assert_eq!(instruction_trace.find_last_write("RAX", 2), Some(1));
+    assert_eq!(instruction_trace.get_register_value("rax", 1), Some(41));
+    assert_eq!(loaded_instruction_trace.expect("Expected the trace file to load."), instruction_trace);
+}
+
+#[test]
+fn debugger_trace_executor_reports_a_start_address_that_was_never_reached() {
+    let (_mock_engine_os, engine_privileged_state) = create_test_state();
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let debugger_trace_response = DebuggerTraceRequest {
+        start_address: 0x40_1000,
+        module_name: String::new(),
+        end_address: None,
+        thread_id: None,
+        instruction_budget: 100,
+        start_timeout_ms: 10,
+        instruction_set_id: String::new(),
+        trace_file_path: None,
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(debugger_trace_response.instruction_trace.is_none());
+    assert!(
+        debugger_trace_response
+            .error
+            .is_some_and(|error| error.contains("0x401000"))
+    );
+}
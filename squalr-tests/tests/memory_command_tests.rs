@@ -1,19 +1,48 @@
+use squalr_engine::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use squalr_engine::engine_mode::EngineMode;
+use squalr_engine::engine_privileged_state::{EnginePrivilegedState, create_engine_privileged_state_with_os_providers};
 use squalr_engine_api::commands::command_line::parse_privileged_command;
+use squalr_engine_api::commands::memory::allocate::memory_allocate_request::MemoryAllocateRequest;
+use squalr_engine_api::commands::memory::collect_writes::memory_collect_writes_request::MemoryCollectWritesRequest;
+use squalr_engine_api::commands::memory::find_caves::memory_find_caves_request::MemoryFindCavesRequest;
+use squalr_engine_api::commands::memory::free::memory_free_request::MemoryFreeRequest;
 use squalr_engine_api::commands::memory::memory_command::MemoryCommand;
+use squalr_engine_api::commands::memory::protect::memory_protect_request::MemoryProtectRequest;
 use squalr_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
 use squalr_engine_api::commands::memory::read::memory_read_response::MemoryReadResponse;
+use squalr_engine_api::commands::memory::track_writes::memory_track_writes_request::MemoryTrackWritesRequest;
 use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
 use squalr_engine_api::commands::memory::write::memory_write_response::MemoryWriteResponse;
 use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
 use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
 use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
 use squalr_engine_api::commands::project::list::project_list_response::ProjectListResponse;
+use squalr_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
 use squalr_engine_api::commands::unprivileged_command_response::TypedUnprivilegedCommandResponse;
+use squalr_engine_api::plugins::value_transform::ValueTransformRef;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::memory_protection::MemoryProtection;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::patching::code_cave_padding::CodeCavePadding;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use squalr_tests::mocks::mock_engine_bindings::MockEngineBindings;
+use squalr_tests::mocks::mock_os::MockEngineOs;
+
+fn create_test_state() -> (MockEngineOs, Arc<EnginePrivilegedState>) {
+    let mock_engine_os = MockEngineOs::new();
+    let engine_os_providers = mock_engine_os.create_providers();
+    let engine_privileged_state = match create_engine_privileged_state_with_os_providers(EngineMode::Standalone, engine_os_providers) {
+        Ok(engine_privileged_state) => engine_privileged_state,
+        Err(error) => panic!("failed to create engine privileged state in test: {}", error),
+    };
+
+    (mock_engine_os, engine_privileged_state)
+}
 
 #[test]
 fn memory_write_request_dispatches_write_command_and_invokes_typed_callback() {
@@ -27,6 +56,7 @@ fn memory_write_request_dispatches_write_command_and_invokes_typed_callback() {
         address: 0x40,
         module_name: String::new(),
         value: vec![1, 2, 3],
+        value_transform: None,
     };
 
     let callback_invoked = Arc::new(AtomicBool::new(false));
@@ -71,6 +101,7 @@ fn memory_write_request_does_not_invoke_callback_when_response_variant_is_wrong(
         address: 0x88,
         module_name: "game.exe".to_string(),
         value: vec![9, 8, 7, 6],
+        value_transform: None,
     };
 
     let callback_invoked = Arc::new(AtomicBool::new(false));
@@ -117,6 +148,7 @@ fn memory_read_request_dispatches_read_command_and_invokes_typed_callback() {
         module_name: "kernel32.dll".to_string(),
         symbolic_struct_definition: SymbolicStructDefinition::new(String::new(), vec![]),
         suppress_logging: false,
+        value_transform: None,
     };
 
     let callback_invoked = Arc::new(AtomicBool::new(false));
@@ -163,6 +195,7 @@ fn memory_read_request_does_not_invoke_callback_when_response_variant_is_wrong()
         module_name: "game.exe".to_string(),
         symbolic_struct_definition: SymbolicStructDefinition::new(String::new(), vec![]),
         suppress_logging: false,
+        value_transform: None,
     };
 
     let callback_invoked = Arc::new(AtomicBool::new(false));
@@ -282,3 +315,302 @@ fn privileged_command_parser_rejects_memory_write_when_required_value_is_missing
     assert!(parse_result.is_ok());
     assert!(parse_result.expect("parser should not panic").is_err());
 }
+
+#[test]
+fn privileged_command_parser_accepts_memory_write_with_value_transform() {
+    let parsed_command_result = parse_privileged_command([
+        "squalr-cli",
+        "memory",
+        "write",
+        "--address",
+        "8192",
+        "-m",
+        "game.exe",
+        "--value-transform",
+        "xor_neighbor:-4",
+        "-v",
+        "100",
+    ]);
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Memory(MemoryCommand::Write { memory_write_request }) => {
+            assert_eq!(memory_write_request.value, vec![100]);
+            assert_eq!(memory_write_request.value_transform, Some(ValueTransformRef::new("xor_neighbor", -4)));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn memory_allocation_executors_allocate_protect_and_free_page_aligned_regions() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game", 0x40_0000, 0x4000)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(OpenedProcessInfo::new(4242, "game".to_string(), 0x10, Bitness::Bit64, None));
+
+    let memory_allocate_response = MemoryAllocateRequest {
+        size: 0x1800,
+        protection: MemoryProtection::READ_WRITE_EXECUTE,
+        preferred_address: 0,
+    }
+    .execute(&engine_privileged_state);
+    let allocated_region = memory_allocate_response
+        .region
+        .expect("Expected the allocation to succeed.");
+
+    assert_eq!(allocated_region.get_region_size(), 0x2000);
+
+    let memory_protect_response = MemoryProtectRequest {
+        address: 0x1010,
+        module_name: "game".to_string(),
+        size: 0x10,
+        protection: MemoryProtection::READ_EXECUTE,
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(memory_protect_response.region, Some(NormalizedRegion::new(0x40_1000, 0x1000)));
+
+    let memory_free_response = MemoryFreeRequest {
+        address: allocated_region.get_base_address(),
+        size: allocated_region.get_region_size(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(memory_free_response.region, Some(allocated_region.clone()));
+
+    let state = mock_engine_os.get_state();
+    let state_guard = state.lock().expect("mock state lock should be available");
+
+    assert_eq!(
+        state_guard.allocated_regions,
+        vec![(allocated_region.clone(), MemoryProtection::READ_WRITE_EXECUTE)]
+    );
+    assert_eq!(
+        state_guard.protected_regions,
+        vec![(NormalizedRegion::new(0x40_1000, 0x1000), MemoryProtection::READ_EXECUTE)]
+    );
+    assert_eq!(state_guard.freed_regions, vec![allocated_region]);
+}
+
+#[test]
+fn memory_allocation_executors_report_errors_without_an_opened_process_or_with_empty_ranges() {
+    let (_mock_engine_os, engine_privileged_state) = create_test_state();
+    let memory_allocate_response = MemoryAllocateRequest {
+        size: 0x1000,
+        protection: MemoryProtection::READ_WRITE,
+        preferred_address: 0,
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(memory_allocate_response.region.is_none());
+    assert_eq!(memory_allocate_response.error.as_deref(), Some("No process is opened to allocate memory in."));
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(OpenedProcessInfo::new(4242, "game".to_string(), 0x10, Bitness::Bit64, None));
+
+    let memory_free_response = MemoryFreeRequest { address: 0x7000_0000, size: 0 }.execute(&engine_privileged_state);
+
+    assert_eq!(
+        memory_free_response.error.as_deref(),
+        Some("Memory range at `0x70000000` with size `0` is invalid.")
+    );
+}
+
+fn get_snapshot_regions(engine_privileged_state: &EnginePrivilegedState) -> Vec<NormalizedRegion> {
+    let snapshot = engine_privileged_state.get_snapshot();
+    let snapshot_guard = snapshot.read().expect("Expected snapshot read lock.");
+
+    snapshot_guard
+        .get_snapshot_regions()
+        .iter()
+        .map(|snapshot_region| NormalizedRegion::new(snapshot_region.get_base_address(), snapshot_region.get_region_size()))
+        .collect()
+}
+
+#[test]
+fn memory_write_tracking_reports_changed_offsets_of_written_pages_and_narrows_the_next_scan() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(OpenedProcessInfo::new(4242, "game".to_string(), 0x10, Bitness::Bit64, None));
+    mock_engine_os.set_memory_pages(vec![
+        NormalizedRegion::new(0x1_0000, 0x3000),
+        NormalizedRegion::new(0x8_0000, 0x1000),
+    ]);
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game", 0x1_0000, 0x3000)]);
+    mock_engine_os.set_memory_contents(0x1_0000, vec![0u8; 0x3000]);
+    mock_engine_os.set_written_pages(Some(vec![
+        NormalizedRegion::new(0x1_1000, 0x1000),
+        NormalizedRegion::new(0x8_0000, 0x1000),
+    ]));
+
+    let memory_track_writes_response = MemoryTrackWritesRequest {
+        module_name: String::from("game"),
+        ..MemoryTrackWritesRequest::default()
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(memory_track_writes_response.error, None);
+    assert!(memory_track_writes_response.is_page_tracking_supported);
+    assert_eq!(memory_track_writes_response.tracked_byte_count, 0x3000);
+
+    mock_engine_os.set_memory_contents(0x1_1010, vec![7, 7]);
+
+    let memory_collect_writes_response = MemoryCollectWritesRequest { narrow_next_scan: true }.execute(&engine_privileged_state);
+
+    assert_eq!(memory_collect_writes_response.error, None);
+    assert_eq!(memory_collect_writes_response.written_pages.len(), 1);
+    assert!(memory_collect_writes_response.is_next_scan_narrowed);
+
+    let written_page = &memory_collect_writes_response.written_pages[0];
+
+    assert_eq!(written_page.get_page_region(), &NormalizedRegion::new(0x1_1000, 0x1000));
+    assert_eq!(written_page.get_module_name(), "game");
+    assert_eq!(written_page.get_module_offset(), 0x1000);
+    assert_eq!(written_page.get_changed_regions(), Some(&[NormalizedRegion::new(0x1_1010, 2)][..]));
+
+    ScanNewRequest {}.execute(&engine_privileged_state);
+
+    assert_eq!(get_snapshot_regions(&engine_privileged_state), vec![NormalizedRegion::new(0x1_1000, 0x1000)]);
+
+    // The narrowing only applies to the scan that follows the collected writes.
+    ScanNewRequest {}.execute(&engine_privileged_state);
+
+    assert_eq!(
+        get_snapshot_regions(&engine_privileged_state),
+        vec![
+            NormalizedRegion::new(0x1_0000, 0x3000),
+            NormalizedRegion::new(0x8_0000, 0x1000)
+        ]
+    );
+}
+
+#[test]
+fn memory_write_tracking_compares_page_contents_when_the_os_cannot_track_writes() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(OpenedProcessInfo::new(4242, "game".to_string(), 0x10, Bitness::Bit64, None));
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x2_0000, 0x2000)]);
+    mock_engine_os.set_memory_contents(0x2_0000, vec![0u8; 0x2000]);
+
+    let memory_track_writes_response = MemoryTrackWritesRequest::default().execute(&engine_privileged_state);
+
+    assert_eq!(memory_track_writes_response.error, None);
+    assert!(!memory_track_writes_response.is_page_tracking_supported);
+
+    mock_engine_os.set_memory_contents(0x2_1FFF, vec![9]);
+
+    let memory_collect_writes_response = MemoryCollectWritesRequest { narrow_next_scan: false }.execute(&engine_privileged_state);
+
+    assert_eq!(memory_collect_writes_response.error, None);
+    assert!(!memory_collect_writes_response.is_next_scan_narrowed);
+    assert_eq!(memory_collect_writes_response.written_pages.len(), 1);
+    assert_eq!(
+        memory_collect_writes_response.written_pages[0].get_page_region(),
+        &NormalizedRegion::new(0x2_1000, 0x1000)
+    );
+    assert_eq!(
+        memory_collect_writes_response.written_pages[0].get_changed_regions(),
+        Some(&[NormalizedRegion::new(0x2_1FFF, 1)][..])
+    );
+
+    let memory_collect_writes_response = MemoryCollectWritesRequest { narrow_next_scan: false }.execute(&engine_privileged_state);
+
+    assert_eq!(memory_collect_writes_response.error.as_deref(), Some("Write tracking has not been started."));
+}
+
+#[test]
+fn memory_find_caves_reports_executable_padding_ranked_by_size_and_distance() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let mut code_bytes = vec![0x48u8; 0x2000];
+
+    code_bytes[0x100..0x140].fill(0xCC);
+    code_bytes[0x800..0x880].fill(0x00);
+    // Five byte no-operations, ending with a four byte one.
+    for no_operation_offset in (0x1800..0x183C).step_by(5) {
+        code_bytes[no_operation_offset..no_operation_offset + 5].copy_from_slice(&[0x0F, 0x1F, 0x44, 0x00, 0x00]);
+    }
+    code_bytes[0x183C..0x1840].copy_from_slice(&[0x0F, 0x1F, 0x40, 0x00]);
+    // Too short to be reported.
+    code_bytes[0x1C00..0x1C10].fill(0xCC);
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(OpenedProcessInfo::new(4242, "game".to_string(), 0x10, Bitness::Bit64, None));
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game", 0x40_0000, 0x4000)]);
+    // Only the code section is executable, so the zeroes elsewhere in the module are not caves.
+    mock_engine_os.set_executable_pages(vec![NormalizedRegion::new(0x40_1000, 0x2000)]);
+    mock_engine_os.set_memory_contents(0x40_1000, code_bytes);
+
+    let memory_find_caves_response = MemoryFindCavesRequest {
+        module_name: "game".to_string(),
+        minimum_size: 0x20,
+        target_address: Some(0x40_2800),
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(memory_find_caves_response.error, None);
+
+    let code_caves: Vec<(u64, u64, u64, CodeCavePadding)> = memory_find_caves_response
+        .code_caves
+        .iter()
+        .map(|code_cave| {
+            (
+                code_cave.get_base_address(),
+                code_cave.get_size(),
+                code_cave.get_module_offset(),
+                code_cave.get_padding(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        code_caves,
+        vec![
+            (0x40_1800, 0x80, 0x1800, CodeCavePadding::Zero),
+            (0x40_2800, 0x40, 0x2800, CodeCavePadding::NoOperation),
+            (0x40_1100, 0x40, 0x1100, CodeCavePadding::Breakpoint),
+        ]
+    );
+
+    let memory_find_caves_response = MemoryFindCavesRequest {
+        module_name: "missing".to_string(),
+        minimum_size: 0x20,
+        target_address: None,
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(memory_find_caves_response.error.as_deref(), Some("Failed to resolve module `missing`."));
+}
+
+#[test]
+fn privileged_command_parser_accepts_memory_protect_with_module_and_protection() {
+    let parse_result = parse_privileged_command([
+        "squalr-cli",
+        "memory",
+        "protect",
+        "-a",
+        "0x20",
+        "-m",
+        "game",
+        "-s",
+        "0x100",
+        "-p",
+        "r-x",
+    ]);
+
+    match parse_result {
+        Ok(PrivilegedCommand::Memory(MemoryCommand::Protect { memory_protect_request })) => {
+            assert_eq!(memory_protect_request.address, 0x20);
+            assert_eq!(memory_protect_request.module_name, "game");
+            assert_eq!(memory_protect_request.size, 0x100);
+            assert_eq!(memory_protect_request.protection, MemoryProtection::READ_EXECUTE);
+        }
+        other => panic!("unexpected parse result: {other:?}"),
+    }
+}
@@ -5,6 +5,8 @@ use squalr_engine_api::commands::memory::read::memory_read_request::MemoryReadRe
 use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
 use squalr_engine_api::commands::process::close::process_close_request::ProcessCloseRequest;
 use squalr_engine_api::commands::process::icon::process_icon_request::ProcessIconRequest;
+use squalr_engine_api::commands::process::inject_library::process_inject_library_request::ProcessInjectLibraryRequest;
+use squalr_engine_api::commands::process::launch::process_launch_request::ProcessLaunchRequest;
 use squalr_engine_api::commands::process::list::process_list_request::ProcessListRequest;
 use squalr_engine_api::commands::process::open::process_open_request::ProcessOpenRequest;
 use squalr_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
@@ -17,6 +19,8 @@ use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
 use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
 use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
 use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::debugging::breakpoint::Breakpoint;
+use squalr_engine_api::structures::debugging::breakpoint_mode::BreakpointMode;
 use squalr_engine_api::structures::memory::bitness::Bitness;
 use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
 use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
@@ -24,6 +28,8 @@ use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::memory::pointer::Pointer;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::processes::process_info::ProcessInfo;
+use squalr_engine_api::structures::processes::thread_info::ThreadInfo;
+use squalr_engine_api::structures::processes::thread_state::ThreadState;
 use squalr_engine_api::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
 use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
 use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
@@ -178,6 +184,7 @@ fn memory_write_executor_uses_injected_module_resolution_and_writer() {
         address: 0x20,
         module_name: "game.exe".to_string(),
         value: vec![1, 2, 3, 4],
+        value_transform: None,
     };
 
     let memory_write_response = memory_write_request.execute(&engine_privileged_state);
@@ -206,6 +213,7 @@ fn memory_read_executor_uses_injected_module_resolution_and_reader() {
         module_name: "game.exe".to_string(),
         symbolic_struct_definition: SymbolicStructDefinition::new(String::new(), vec![]),
         suppress_logging: false,
+        value_transform: None,
     };
 
     let memory_read_response = memory_read_request.execute(&engine_privileged_state);
@@ -234,6 +242,7 @@ fn memory_read_executor_returns_failure_without_mutating_write_state_when_reader
         module_name: "game.exe".to_string(),
         symbolic_struct_definition: SymbolicStructDefinition::new(String::new(), vec![]),
         suppress_logging: false,
+        value_transform: None,
     };
 
     let memory_read_response = memory_read_request.execute(&engine_privileged_state);
@@ -362,6 +371,237 @@ fn process_open_executor_returns_none_when_process_handle_resolution_fails() {
     assert_eq!(state_guard.open_process_requests, vec![process_identifier]);
 }
 
+#[test]
+fn process_launch_executor_opens_the_launched_process() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+
+    mock_engine_os.set_opened_process_result(Some(OpenedProcessInfo::new(4242, "game.x86_64".to_string(), 4242, Bitness::Bit64, None)));
+
+    let process_launch_request = ProcessLaunchRequest {
+        executable_path: "/opt/game/game.x86_64".to_string(),
+        arguments: vec!["--windowed".to_string()],
+        environment: vec![("SDL_VIDEODRIVER".to_string(), "x11".to_string())],
+        is_suspended: true,
+    };
+    let process_launch_response = process_launch_request.execute(&engine_privileged_state);
+
+    assert!(process_launch_response.error.is_none());
+    assert_eq!(
+        engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .map(|opened_process_info| opened_process_info.get_process_id_raw()),
+        Some(4242)
+    );
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    assert_eq!(state_guard.launch_process_requests.len(), 1);
+    assert_eq!(
+        state_guard.launch_process_requests[0].executable_path,
+        std::path::PathBuf::from("/opt/game/game.x86_64")
+    );
+    assert_eq!(state_guard.launch_process_requests[0].arguments, vec!["--windowed".to_string()]);
+    assert_eq!(
+        state_guard.launch_process_requests[0].environment,
+        vec![("SDL_VIDEODRIVER".to_string(), "x11".to_string())]
+    );
+    assert!(state_guard.launch_process_requests[0].is_suspended);
+    assert!(state_guard.is_process_suspended);
+}
+
+#[test]
+fn process_launch_executor_keeps_the_opened_process_when_the_launch_fails() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+    mock_engine_os.set_opened_process_result(None);
+
+    let process_launch_response = ProcessLaunchRequest {
+        executable_path: "/opt/game/missing".to_string(),
+        ..Default::default()
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(process_launch_response.opened_process_info.is_none());
+    assert!(
+        process_launch_response
+            .error
+            .is_some_and(|error| error.contains("/opt/game/missing"))
+    );
+    assert_eq!(
+        engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .map(|opened_process_info| opened_process_info.get_process_id_raw()),
+        Some(std::process::id())
+    );
+}
+
+#[test]
+fn process_launch_executor_rejects_an_empty_executable_path() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+
+    let process_launch_response = ProcessLaunchRequest::default().execute(&engine_privileged_state);
+
+    assert!(process_launch_response.error.is_some());
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    assert!(state_guard.launch_process_requests.is_empty());
+}
+
+#[test]
+fn process_inject_library_executor_calls_dlopen_with_the_library_path() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let library_path = match std::env::current_exe() {
+        Ok(library_path) => library_path,
+        Err(error) => panic!("failed to resolve the test executable: {}", error),
+    };
+    let library_name = library_path
+        .file_name()
+        .and_then(|library_name| library_name.to_str())
+        .unwrap_or_default()
+        .to_string();
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+    mock_engine_os.set_threads(vec![ThreadInfo::new(
+        7,
+        "main".to_string(),
+        ThreadState::Sleeping,
+        0,
+        0,
+        None,
+        false,
+    )]);
+    mock_engine_os.set_modules(vec![
+        NormalizedModule::new("libc.so.6", 0x7000_0000, 0x20_0000),
+        NormalizedModule::new(&library_name, 0x7100_0000, 0x1000),
+    ]);
+    mock_engine_os.set_module_symbol("libc.so.6", "dlopen", 0x7000_1230);
+    mock_engine_os.set_function_call_result(0x7000_1230, 0x5555_0000);
+
+    if let Ok(mut state_guard) = mock_engine_os.get_state().lock() {
+        state_guard.is_process_suspended = true;
+    }
+
+    let process_inject_library_response = ProcessInjectLibraryRequest {
+        library_path: library_path.to_string_lossy().into_owned(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(process_inject_library_response.error.is_none());
+    assert_eq!(
+        process_inject_library_response
+            .injected_module
+            .map(|injected_module| injected_module.get_base_address()),
+        Some(0x7100_0000)
+    );
+    assert_eq!(process_inject_library_response.modules.len(), 2);
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    let path_address = state_guard.allocated_regions[0].0.get_base_address();
+    let mut expected_path_bytes = library_path
+        .canonicalize()
+        .unwrap_or(library_path)
+        .to_string_lossy()
+        .into_owned()
+        .into_bytes();
+    expected_path_bytes.push(0);
+
+    assert_eq!(state_guard.memory_write_requests, vec![(path_address, expected_path_bytes)]);
+    assert_eq!(state_guard.function_calls, vec![(0x7000_1230, vec![path_address, 2])]);
+    assert_eq!(state_guard.freed_regions.len(), 1);
+    assert_eq!(state_guard.freed_regions[0].get_base_address(), path_address);
+}
+
+#[test]
+fn process_inject_library_executor_reports_the_dynamic_loader_error() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let library_path = match std::env::current_exe() {
+        Ok(library_path) => library_path,
+        Err(error) => panic!("failed to resolve the test executable: {}", error),
+    };
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+    mock_engine_os.set_modules(vec![NormalizedModule::new("libc.so.6", 0x7000_0000, 0x20_0000)]);
+    mock_engine_os.set_module_symbol("libc.so.6", "dlopen", 0x7000_1230);
+    mock_engine_os.set_module_symbol("libc.so.6", "dlerror", 0x7000_4560);
+    mock_engine_os.set_function_call_result(0x7000_4560, 0x9000);
+    mock_engine_os.set_memory_contents(0x9000, b"undefined symbol: hook_init\0".to_vec());
+
+    if let Ok(mut state_guard) = mock_engine_os.get_state().lock() {
+        state_guard
+            .breakpoints
+            .push(Breakpoint::new(1, std::process::id(), 0x7000_0100, BreakpointMode::Count));
+    }
+
+    let process_inject_library_response = ProcessInjectLibraryRequest {
+        library_path: library_path.to_string_lossy().into_owned(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(process_inject_library_response.injected_module.is_none());
+    assert_eq!(
+        process_inject_library_response.error.as_deref(),
+        Some("`dlopen` failed: undefined symbol: hook_init.")
+    );
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    assert_eq!(state_guard.function_calls.len(), 2);
+    assert_eq!(state_guard.freed_regions.len(), 1);
+}
+
+#[test]
+fn process_inject_library_executor_refuses_untraced_processes() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+    mock_engine_os.set_modules(vec![NormalizedModule::new("libc.so.6", 0x7000_0000, 0x20_0000)]);
+    mock_engine_os.set_module_symbol("libc.so.6", "dlopen", 0x7000_1230);
+
+    let process_inject_library_response = ProcessInjectLibraryRequest {
+        library_path: "/tmp/hook.so".to_string(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(
+        process_inject_library_response
+            .error
+            .is_some_and(|error| error.contains("is not traced"))
+    );
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    assert!(state_guard.allocated_regions.is_empty());
+    assert!(state_guard.function_calls.is_empty());
+}
+
 #[test]
 fn scan_new_executor_uses_injected_memory_page_bounds() {
     let (mock_engine_os, engine_privileged_state) = create_test_state();
@@ -854,6 +1094,7 @@ fn memory_write_executor_returns_failure_without_freeze_mutation_when_writer_fai
         address: 0x20,
         module_name: "game.exe".to_string(),
         value: vec![1, 2, 3, 4],
+        value_transform: None,
     };
 
     let memory_write_response = memory_write_request.execute(&engine_privileged_state);
@@ -0,0 +1,345 @@
+use squalr_engine::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use squalr_engine::engine_mode::EngineMode;
+use squalr_engine::engine_privileged_state::{EnginePrivilegedState, create_engine_privileged_state_with_os_providers};
+use squalr_engine_api::commands::command_line::parse_privileged_command;
+use squalr_engine_api::commands::memory::find_caves::memory_find_caves_request::MemoryFindCavesRequest;
+use squalr_engine_api::commands::patch::apply::patch_apply_request::PatchApplyRequest;
+use squalr_engine_api::commands::patch::list::patch_list_request::PatchListRequest;
+use squalr_engine_api::commands::patch::patch_command::PatchCommand;
+use squalr_engine_api::commands::patch::undo::patch_undo_request::PatchUndoRequest;
+use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::patching::code_patch_definition::CodePatchDefinition;
+use squalr_engine_api::structures::patching::code_patch_kind::CodePatchKind;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::sync::Arc;
+
+use squalr_tests::mocks::mock_os::MockEngineOs;
+
+const MODULE_BASE_ADDRESS: u64 = 0x40_0000;
+const PATCH_OFFSET: u64 = 0x1000;
+const PATCH_ADDRESS: u64 = MODULE_BASE_ADDRESS + PATCH_OFFSET;
+
+fn create_test_state() -> (MockEngineOs, Arc<EnginePrivilegedState>) {
+    let mock_engine_os = MockEngineOs::new();
+    let engine_os_providers = mock_engine_os.create_providers();
+    let engine_privileged_state = match create_engine_privileged_state_with_os_providers(EngineMode::Standalone, engine_os_providers) {
+        Ok(engine_privileged_state) => engine_privileged_state,
+        Err(error) => panic!("failed to create engine privileged state in test: {}", error),
+    };
+
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game", MODULE_BASE_ADDRESS, 0x4000)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(OpenedProcessInfo::new(4242, "game".to_string(), 0x10, Bitness::Bit64, None));
+
+    (mock_engine_os, engine_privileged_state)
+}
+
+fn create_apply_request(code_patch_kind: CodePatchKind) -> PatchApplyRequest {
+    PatchApplyRequest {
+        code_patch_definition: CodePatchDefinition::new(PATCH_OFFSET, "game".to_string(), code_patch_kind),
+    }
+}
+
+fn take_memory_write_requests(mock_engine_os: &MockEngineOs) -> Vec<(u64, Vec<u8>)> {
+    let state = mock_engine_os.get_state();
+    let mut state_guard = state.lock().expect("mock state lock should be available");
+
+    std::mem::take(&mut state_guard.memory_write_requests)
+}
+
+#[test]
+fn patch_nop_pads_to_instruction_boundaries_and_undo_restores_original_bytes() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    // mov rax, rcx; add rax, 1; ret
+    let original_code = vec![0x48, 0x89, 0xC8, 0x48, 0x83, 0xC0, 0x01, 0xC3];
+    mock_engine_os.set_memory_contents(PATCH_ADDRESS, original_code.clone());
+
+    let patch_apply_response = create_apply_request(CodePatchKind::Nop { byte_count: 4 }).execute(&engine_privileged_state);
+    let code_patch = patch_apply_response
+        .code_patch
+        .expect("Expected the nop patch to apply.");
+
+    assert_eq!(code_patch.get_patch_address(), PATCH_ADDRESS);
+    assert_eq!(code_patch.get_original_bytes(), &original_code[..7]);
+    assert_eq!(
+        take_memory_write_requests(&mock_engine_os),
+        vec![(PATCH_ADDRESS, code_patch.get_patched_bytes().to_vec())]
+    );
+    assert_eq!(code_patch.get_patched_bytes().len(), 7);
+    // Code pages are usually read-only, so patches opt into writes that ignore page protection.
+    assert_eq!(
+        mock_engine_os
+            .get_state()
+            .lock()
+            .expect("mock state lock should be available")
+            .protection_ignoring_write_addresses,
+        vec![PATCH_ADDRESS]
+    );
+
+    let patch_undo_response = PatchUndoRequest {
+        address: PATCH_OFFSET + 2,
+        module_name: "game".to_string(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(patch_undo_response.error.is_none());
+    assert_eq!(take_memory_write_requests(&mock_engine_os), vec![(PATCH_ADDRESS, original_code[..7].to_vec())]);
+    assert!(
+        PatchListRequest::default()
+            .execute(&engine_privileged_state)
+            .code_patches
+            .is_empty()
+    );
+}
+
+#[test]
+fn patch_detour_writes_cave_before_hook_and_relocates_rip_relative_operands() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    // mov rax, [rip + 0x10]; ret
+    let original_code = vec![0x48, 0x8B, 0x05, 0x10, 0x00, 0x00, 0x00, 0xC3];
+    mock_engine_os.set_memory_contents(PATCH_ADDRESS, original_code.clone());
+
+    let patch_apply_response = create_apply_request(CodePatchKind::Detour {
+        assembly_source: "inc rax".to_string(),
+    })
+    .execute(&engine_privileged_state);
+    let code_patch = patch_apply_response
+        .code_patch
+        .expect("Expected the detour to apply.");
+    let code_cave = code_patch
+        .get_code_cave()
+        .expect("Expected the detour to own a code cave.")
+        .clone();
+    let code_cave_address = code_cave.get_base_address();
+    let memory_write_requests = take_memory_write_requests(&mock_engine_os);
+
+    assert_eq!(memory_write_requests.len(), 2);
+    assert_eq!(memory_write_requests[0].0, code_cave_address);
+    assert_eq!(memory_write_requests[1].0, PATCH_ADDRESS);
+
+    let hook_displacement = (code_cave_address as i64 - (PATCH_ADDRESS as i64 + 5)) as i32;
+    let mut expected_hook_bytes = vec![0xE9];
+    expected_hook_bytes.extend(hook_displacement.to_le_bytes());
+
+    assert_eq!(&memory_write_requests[1].1[..5], expected_hook_bytes.as_slice());
+    assert_eq!(memory_write_requests[1].1.len(), 7);
+
+    // The relocated load still reads from the original target, now relative to its position after `inc rax`.
+    let code_cave_bytes = &memory_write_requests[0].1;
+    let relocated_load_address = code_cave_address + 3;
+    let relocated_displacement = ((PATCH_ADDRESS + 7 + 0x10) as i64 - (relocated_load_address as i64 + 7)) as i32;
+    let mut expected_relocated_load = vec![0x48, 0x8B, 0x05];
+    expected_relocated_load.extend(relocated_displacement.to_le_bytes());
+
+    assert_eq!(&code_cave_bytes[..3], &[0x48, 0xFF, 0xC0]);
+    assert_eq!(&code_cave_bytes[3..10], expected_relocated_load.as_slice());
+    assert_eq!(code_cave_bytes[10], 0xE9);
+
+    let patch_undo_response = PatchUndoRequest {
+        address: PATCH_ADDRESS,
+        module_name: String::new(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(patch_undo_response.error.is_none());
+    assert_eq!(take_memory_write_requests(&mock_engine_os), vec![(PATCH_ADDRESS, original_code[..7].to_vec())]);
+
+    let state = mock_engine_os.get_state();
+    let state_guard = state.lock().expect("mock state lock should be available");
+
+    assert_eq!(state_guard.freed_regions, vec![code_cave]);
+}
+
+#[test]
+fn patch_detour_borrows_module_padding_before_allocating_and_undo_restores_it() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    // mov rax, rcx; add rax, 1; ret
+    let original_code = vec![0x48, 0x89, 0xC8, 0x48, 0x83, 0xC0, 0x01, 0xC3];
+    let mut padded_code = vec![0xC3u8; 0x1000];
+    let padding_address = MODULE_BASE_ADDRESS + 0x2200;
+
+    padded_code[0x200..0x300].fill(0xCC);
+    mock_engine_os.set_memory_contents(PATCH_ADDRESS, original_code.clone());
+    mock_engine_os.set_memory_contents(MODULE_BASE_ADDRESS + 0x2000, padded_code);
+    mock_engine_os.set_executable_pages(vec![NormalizedRegion::new(MODULE_BASE_ADDRESS + 0x2000, 0x1000)]);
+
+    let patch_apply_response = create_apply_request(CodePatchKind::Detour {
+        assembly_source: "inc rax".to_string(),
+    })
+    .execute(&engine_privileged_state);
+    let code_patch = patch_apply_response
+        .code_patch
+        .expect("Expected the detour to apply.");
+    let code_cave = code_patch
+        .get_code_cave()
+        .expect("Expected the detour to own a code cave.")
+        .clone();
+    let memory_write_requests = take_memory_write_requests(&mock_engine_os);
+
+    assert_eq!(code_cave.get_base_address(), padding_address);
+    assert_eq!(code_cave.get_region_size(), memory_write_requests[0].1.len() as u64);
+    assert_eq!(memory_write_requests[0].0, padding_address);
+    assert_eq!(
+        code_patch.get_code_cave_original_bytes(),
+        Some(vec![0xCC; code_cave.get_region_size() as usize].as_slice())
+    );
+
+    let hook_displacement = (padding_address as i64 - (PATCH_ADDRESS as i64 + 5)) as i32;
+    let mut expected_hook_bytes = vec![0xE9];
+    expected_hook_bytes.extend(hook_displacement.to_le_bytes());
+
+    assert_eq!(memory_write_requests[1].0, PATCH_ADDRESS);
+    assert_eq!(&memory_write_requests[1].1[..5], expected_hook_bytes.as_slice());
+
+    // The rest of the padding run is still offered as a cave, starting on the next boundary after the borrowed cave.
+    let remaining_cave_address = code_cave.get_end_address().next_multiple_of(0x10);
+    let memory_find_caves_response = MemoryFindCavesRequest {
+        module_name: String::from("game"),
+        minimum_size: 0x20,
+        target_address: None,
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(
+        memory_find_caves_response
+            .code_caves
+            .iter()
+            .map(|code_cave| (code_cave.get_base_address(), code_cave.get_size()))
+            .collect::<Vec<_>>(),
+        vec![(remaining_cave_address, padding_address + 0x100 - remaining_cave_address)]
+    );
+
+    let patch_undo_response = PatchUndoRequest {
+        address: PATCH_ADDRESS,
+        module_name: String::new(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(patch_undo_response.error.is_none());
+    assert_eq!(
+        take_memory_write_requests(&mock_engine_os),
+        vec![
+            (PATCH_ADDRESS, original_code[..7].to_vec()),
+            (padding_address, vec![0xCC; code_cave.get_region_size() as usize]),
+        ]
+    );
+
+    let state = mock_engine_os.get_state();
+    let state_guard = state.lock().expect("mock state lock should be available");
+
+    assert!(state_guard.allocated_regions.is_empty());
+    assert!(state_guard.freed_regions.is_empty());
+}
+
+#[test]
+fn patch_apply_rejects_patches_that_overlap_an_applied_patch() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_memory_contents(PATCH_ADDRESS, vec![0x48, 0x89, 0xC8, 0x48, 0x83, 0xC0, 0x01, 0xC3]);
+
+    assert!(
+        create_apply_request(CodePatchKind::Nop { byte_count: 1 })
+            .execute(&engine_privileged_state)
+            .code_patch
+            .is_some()
+    );
+
+    let mut overlapping_patch_apply_request = create_apply_request(CodePatchKind::Replace {
+        assembly_source: "nop".to_string(),
+    });
+    overlapping_patch_apply_request.code_patch_definition.address = PATCH_OFFSET + 2;
+
+    let overlapping_patch_apply_response = overlapping_patch_apply_request.execute(&engine_privileged_state);
+
+    assert!(overlapping_patch_apply_response.code_patch.is_none());
+    assert!(
+        overlapping_patch_apply_response
+            .error
+            .expect("Expected an overlap error.")
+            .contains("overlaps")
+    );
+    assert_eq!(
+        PatchListRequest::default()
+            .execute(&engine_privileged_state)
+            .code_patches
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn patch_apply_ignores_patches_applied_to_a_previously_opened_process() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_memory_contents(PATCH_ADDRESS, vec![0x48, 0x89, 0xC8, 0x48, 0x83, 0xC0, 0x01, 0xC3]);
+
+    assert!(
+        create_apply_request(CodePatchKind::Nop { byte_count: 1 })
+            .execute(&engine_privileged_state)
+            .code_patch
+            .is_some()
+    );
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(OpenedProcessInfo::new(4343, "game".to_string(), 0x20, Bitness::Bit64, None));
+
+    let patch_apply_response = create_apply_request(CodePatchKind::Nop { byte_count: 1 }).execute(&engine_privileged_state);
+
+    assert!(patch_apply_response.code_patch.is_some(), "{:?}", patch_apply_response.error);
+    assert_eq!(
+        PatchListRequest::default()
+            .execute(&engine_privileged_state)
+            .code_patches
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn patch_apply_does_not_record_patches_that_failed_to_write() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_memory_contents(PATCH_ADDRESS, vec![0x48, 0x89, 0xC8, 0x48, 0x83, 0xC0, 0x01, 0xC3]);
+    mock_engine_os.set_write_success(false);
+
+    let patch_apply_response = create_apply_request(CodePatchKind::Nop { byte_count: 1 }).execute(&engine_privileged_state);
+
+    assert!(patch_apply_response.code_patch.is_none());
+    assert!(
+        PatchListRequest::default()
+            .execute(&engine_privileged_state)
+            .code_patches
+            .is_empty()
+    );
+}
+
+#[test]
+fn privileged_command_parser_accepts_patch_detour_with_instructions() {
+    let parsed_command_result = parse_privileged_command([
+        "squalr-cli",
+        "patch",
+        "detour",
+        "-a",
+        "0x1F2A",
+        "-m",
+        "game.exe",
+        "-i",
+        "inc rax",
+    ]);
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Patch(PatchCommand::Apply { patch_apply_request }) => {
+            assert_eq!(patch_apply_request.code_patch_definition.address, 0x1F2A);
+            assert_eq!(patch_apply_request.code_patch_definition.module_name, "game.exe");
+            assert_eq!(
+                patch_apply_request.code_patch_definition.kind,
+                CodePatchKind::Detour {
+                    assembly_source: "inc rax".to_string()
+                }
+            );
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
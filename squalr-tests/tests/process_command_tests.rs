@@ -374,3 +374,80 @@ fn privileged_command_parser_rejects_process_open_with_invalid_process_id() {
     assert!(parse_result.is_ok());
     assert!(parse_result.expect("parser should not panic").is_err());
 }
+
+#[test]
+fn privileged_command_parser_accepts_process_launch_with_arguments_and_environment() {
+    let parse_result = std::panic::catch_unwind(|| {
+        parse_privileged_command([
+            "squalr-cli",
+            "process",
+            "launch",
+            "--env",
+            "SDL_VIDEODRIVER=x11",
+            "-e",
+            "WINEDEBUG=-all,+seh",
+            "--suspended",
+            "/opt/game/game.x86_64",
+            "--",
+            "--windowed",
+            "-level=2",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Process(ProcessCommand::Launch { process_launch_request }) => {
+            assert_eq!(process_launch_request.executable_path, "/opt/game/game.x86_64");
+            assert_eq!(process_launch_request.arguments, vec!["--windowed".to_string(), "-level=2".to_string()]);
+            assert_eq!(
+                process_launch_request.environment,
+                vec![
+                    ("SDL_VIDEODRIVER".to_string(), "x11".to_string()),
+                    ("WINEDEBUG".to_string(), "-all,+seh".to_string()),
+                ]
+            );
+            assert!(process_launch_request.is_suspended);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_rejects_process_launch_with_malformed_environment() {
+    let parse_result = std::panic::catch_unwind(|| {
+        parse_privileged_command([
+            "squalr-cli",
+            "process",
+            "launch",
+            "--env",
+            "NO_VALUE",
+            "/opt/game/game.x86_64",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+    assert!(parse_result.expect("parser should not panic").is_err());
+}
+
+#[test]
+fn privileged_command_parser_accepts_process_inject_library() {
+    let parse_result = std::panic::catch_unwind(|| parse_privileged_command(["squalr-cli", "process", "inject-library", "/tmp/hook.so"]));
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Process(ProcessCommand::InjectLibrary {
+            process_inject_library_request,
+        }) => {
+            assert_eq!(process_inject_library_request.library_path, "/tmp/hook.so");
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
@@ -379,6 +379,8 @@ fn project_items_create_request_dispatches_unprivileged_command_and_invokes_type
         module_name: None,
         data_type_id: None,
         pointer_offsets: None,
+        project_item_type_id: None,
+        code_patch: None,
     };
     let callback_invoked = Arc::new(AtomicBool::new(false));
     let callback_invoked_clone = callback_invoked.clone();
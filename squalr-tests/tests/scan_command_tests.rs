@@ -256,6 +256,7 @@ fn element_scan_request_dispatches_element_scan_command_and_invokes_typed_callba
             AnonymousScanConstraint::from_str("==").expect("scan constraint should parse"),
         ],
         data_type_refs: vec![DataTypeRef::new("i32"), DataTypeRef::new("f32")],
+        value_transform: None,
     };
     let callback_invoked = Arc::new(AtomicBool::new(false));
     let callback_invoked_clone = callback_invoked.clone();
@@ -294,6 +295,7 @@ fn element_scan_request_does_not_invoke_callback_when_response_variant_is_wrong(
     let element_scan_request = ElementScanRequest {
         scan_constraints: vec![AnonymousScanConstraint::from_str("==").expect("scan constraint should parse")],
         data_type_refs: vec![DataTypeRef::new("i32")],
+        value_transform: None,
     };
     let callback_invoked = Arc::new(AtomicBool::new(false));
     let callback_invoked_clone = callback_invoked.clone();
@@ -0,0 +1,308 @@
+use squalr_engine::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use squalr_engine::engine_mode::EngineMode;
+use squalr_engine::engine_privileged_state::{EnginePrivilegedState, create_engine_privileged_state_with_os_providers};
+use squalr_engine_api::commands::command_line::parse_privileged_command;
+use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
+use squalr_engine_api::commands::scan::element_scan::element_scan_request::ElementScanRequest;
+use squalr_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
+use squalr_engine_api::commands::thread::context::thread_context_request::ThreadContextRequest;
+use squalr_engine_api::commands::thread::list::thread_list_request::ThreadListRequest;
+use squalr_engine_api::commands::thread::resume::thread_resume_request::ThreadResumeRequest;
+use squalr_engine_api::commands::thread::suspend::thread_suspend_request::ThreadSuspendRequest;
+use squalr_engine_api::commands::thread::thread_command::ThreadCommand;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::processes::thread_info::ThreadInfo;
+use squalr_engine_api::structures::processes::thread_state::ThreadState;
+use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+use squalr_engine_api::structures::scanning::memory_read_mode::MemoryReadMode;
+use squalr_engine_session::settings::scan_settings_store::ScanSettingsStore;
+use squalr_tests::mocks::mock_os::{MockEngineOs, RecordedThreadControlRequest};
+use std::str::FromStr;
+use std::sync::Arc;
+
+const MAIN_THREAD_ID: u32 = 4242;
+const WORKER_THREAD_ID: u32 = 4243;
+
+fn create_test_state() -> (MockEngineOs, Arc<EnginePrivilegedState>) {
+    let mock_engine_os = MockEngineOs::new();
+    let engine_os_providers = mock_engine_os.create_providers();
+    let engine_privileged_state = match create_engine_privileged_state_with_os_providers(EngineMode::Standalone, engine_os_providers) {
+        Ok(engine_privileged_state) => engine_privileged_state,
+        Err(error) => panic!("failed to create engine privileged state in test: {}", error),
+    };
+
+    mock_engine_os.set_threads(vec![
+        ThreadInfo::new(
+            MAIN_THREAD_ID,
+            "game".to_string(),
+            ThreadState::Running,
+            120,
+            30,
+            Some(NormalizedRegion::new(0x7FFF_0000, 0x2_0000)),
+            false,
+        ),
+        ThreadInfo::new(WORKER_THREAD_ID, "worker".to_string(), ThreadState::Sleeping, 5, 1, None, false),
+    ]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(OpenedProcessInfo::new(MAIN_THREAD_ID, "game".to_string(), 0x10, Bitness::Bit64, None));
+
+    (mock_engine_os, engine_privileged_state)
+}
+
+fn take_thread_control_requests(mock_engine_os: &MockEngineOs) -> Vec<RecordedThreadControlRequest> {
+    let state = mock_engine_os.get_state();
+    let mut state_guard = state.lock().expect("mock state lock should be available");
+
+    std::mem::take(&mut state_guard.thread_control_requests)
+}
+
+/// Restores the persisted read mode, since scan settings are shared with every other test binary.
+struct MemoryReadModeGuard {
+    previous_memory_read_mode: MemoryReadMode,
+}
+
+impl MemoryReadModeGuard {
+    fn set(memory_read_mode: MemoryReadMode) -> Self {
+        let previous_memory_read_mode = ScanSettingsStore::get_memory_read_mode();
+        ScanSettingsStore::set_memory_read_mode(memory_read_mode);
+
+        Self { previous_memory_read_mode }
+    }
+}
+
+impl Drop for MemoryReadModeGuard {
+    fn drop(&mut self) {
+        ScanSettingsStore::set_memory_read_mode(self.previous_memory_read_mode);
+    }
+}
+
+#[test]
+fn thread_list_reports_threads_with_cpu_time_and_stack() {
+    let (_mock_engine_os, engine_privileged_state) = create_test_state();
+
+    let thread_list_response = ThreadListRequest {}.execute(&engine_privileged_state);
+
+    assert!(thread_list_response.error.is_none());
+    assert_eq!(thread_list_response.threads.len(), 2);
+    assert_eq!(thread_list_response.threads[0].get_name(), "game");
+    assert_eq!(thread_list_response.threads[0].get_cpu_time_ms(), 150);
+    assert_eq!(
+        thread_list_response.threads[0]
+            .get_stack_region()
+            .map(|stack_region| stack_region.get_base_address()),
+        Some(0x7FFF_0000)
+    );
+    assert_eq!(thread_list_response.threads[1].get_thread_state(), ThreadState::Sleeping);
+}
+
+#[test]
+fn thread_suspend_and_resume_target_a_single_thread_and_report_its_state() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+
+    let thread_suspend_response = ThreadSuspendRequest {
+        thread_id: Some(WORKER_THREAD_ID),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(thread_suspend_response.error.is_none());
+    assert!(!thread_suspend_response.threads[0].get_is_suspended());
+    assert!(thread_suspend_response.threads[1].get_is_suspended());
+
+    let thread_resume_response = ThreadResumeRequest {
+        thread_id: Some(WORKER_THREAD_ID),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(thread_resume_response.error.is_none());
+    assert!(
+        thread_resume_response
+            .threads
+            .iter()
+            .all(|thread_info| !thread_info.get_is_suspended())
+    );
+    assert_eq!(
+        take_thread_control_requests(&mock_engine_os),
+        vec![
+            RecordedThreadControlRequest::SuspendThread(WORKER_THREAD_ID),
+            RecordedThreadControlRequest::ResumeThread(WORKER_THREAD_ID),
+        ]
+    );
+}
+
+#[test]
+fn thread_suspend_without_thread_id_suspends_the_whole_process() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+
+    let thread_suspend_response = ThreadSuspendRequest { thread_id: None }.execute(&engine_privileged_state);
+
+    assert!(
+        thread_suspend_response
+            .threads
+            .iter()
+            .all(|thread_info| thread_info.get_is_suspended())
+    );
+    assert_eq!(
+        take_thread_control_requests(&mock_engine_os),
+        vec![RecordedThreadControlRequest::SuspendProcess]
+    );
+}
+
+#[test]
+fn thread_suspend_reports_unknown_threads() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+
+    let thread_suspend_response = ThreadSuspendRequest { thread_id: Some(1) }.execute(&engine_privileged_state);
+
+    assert!(thread_suspend_response.error.is_some());
+    assert_eq!(thread_suspend_response.threads.len(), 2);
+    assert!(take_thread_control_requests(&mock_engine_os).is_empty());
+}
+
+#[test]
+fn thread_context_returns_the_register_snapshot_of_the_thread() {
+    let (_mock_engine_os, engine_privileged_state) = create_test_state();
+
+    let thread_context_response = ThreadContextRequest { thread_id: WORKER_THREAD_ID }.execute(&engine_privileged_state);
+    let register_snapshot = thread_context_response
+        .register_snapshot
+        .expect("Expected a register snapshot.");
+
+    assert_eq!(register_snapshot.get_thread_id(), WORKER_THREAD_ID);
+    assert!(register_snapshot.get_register("rip").is_some());
+}
+
+#[test]
+fn thread_commands_require_an_opened_process() {
+    let (_mock_engine_os, engine_privileged_state) = create_test_state();
+    engine_privileged_state
+        .get_process_manager()
+        .clear_opened_process();
+
+    assert!(
+        ThreadListRequest {}
+            .execute(&engine_privileged_state)
+            .error
+            .is_some()
+    );
+    assert!(
+        ThreadSuspendRequest { thread_id: None }
+            .execute(&engine_privileged_state)
+            .error
+            .is_some()
+    );
+}
+
+#[test]
+fn element_scan_reads_values_while_the_process_is_suspended() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let _memory_read_mode_guard = MemoryReadModeGuard::set(MemoryReadMode::ReadWhileSuspended);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x1000, 0x1000)]);
+    mock_engine_os.set_memory_contents(0x1000, 1337i32.to_le_bytes().to_vec());
+
+    let _scan_new_response = ScanNewRequest {}.execute(&engine_privileged_state);
+    let _element_scan_response = ElementScanRequest {
+        scan_constraints: vec![AnonymousScanConstraint::from_str("==1337;dec;").expect("scan constraint should parse")],
+        data_type_refs: vec![DataTypeRef::new("i32")],
+        value_transform: None,
+    }
+    .execute(&engine_privileged_state);
+
+    let state = mock_engine_os.get_state();
+    let state_guard = state.lock().expect("mock state lock should be available");
+
+    assert_eq!(
+        state_guard.thread_control_requests,
+        vec![
+            RecordedThreadControlRequest::SuspendProcess,
+            RecordedThreadControlRequest::ResumeProcess
+        ]
+    );
+    assert!(state_guard.memory_reads_while_suspended > 0);
+    assert!(!state_guard.is_process_suspended);
+}
+
+#[test]
+fn element_scan_leaves_an_already_suspended_process_suspended() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let _memory_read_mode_guard = MemoryReadModeGuard::set(MemoryReadMode::ReadWhileSuspended);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x1000, 0x1000)]);
+    mock_engine_os.set_memory_contents(0x1000, 1337i32.to_le_bytes().to_vec());
+
+    let _thread_suspend_response = ThreadSuspendRequest { thread_id: None }.execute(&engine_privileged_state);
+    take_thread_control_requests(&mock_engine_os);
+
+    let _scan_new_response = ScanNewRequest {}.execute(&engine_privileged_state);
+    let _element_scan_response = ElementScanRequest {
+        scan_constraints: vec![AnonymousScanConstraint::from_str("==1337;dec;").expect("scan constraint should parse")],
+        data_type_refs: vec![DataTypeRef::new("i32")],
+        value_transform: None,
+    }
+    .execute(&engine_privileged_state);
+
+    let state = mock_engine_os.get_state();
+    let state_guard = state.lock().expect("mock state lock should be available");
+
+    assert!(state_guard.thread_control_requests.is_empty());
+    assert!(state_guard.memory_reads_while_suspended > 0);
+    assert!(state_guard.is_process_suspended);
+}
+
+#[test]
+fn element_scan_leaves_a_thread_suspended_by_the_user_suspended() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let _memory_read_mode_guard = MemoryReadModeGuard::set(MemoryReadMode::ReadWhileSuspended);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x1000, 0x1000)]);
+    mock_engine_os.set_memory_contents(0x1000, 1337i32.to_le_bytes().to_vec());
+
+    let _thread_suspend_response = ThreadSuspendRequest {
+        thread_id: Some(WORKER_THREAD_ID),
+    }
+    .execute(&engine_privileged_state);
+    take_thread_control_requests(&mock_engine_os);
+
+    let _scan_new_response = ScanNewRequest {}.execute(&engine_privileged_state);
+    let _element_scan_response = ElementScanRequest {
+        scan_constraints: vec![AnonymousScanConstraint::from_str("==1337;dec;").expect("scan constraint should parse")],
+        data_type_refs: vec![DataTypeRef::new("i32")],
+        value_transform: None,
+    }
+    .execute(&engine_privileged_state);
+
+    let state = mock_engine_os.get_state();
+    let state_guard = state.lock().expect("mock state lock should be available");
+
+    assert_eq!(
+        state_guard.thread_control_requests,
+        vec![
+            RecordedThreadControlRequest::SuspendThread(MAIN_THREAD_ID),
+            RecordedThreadControlRequest::ResumeThread(MAIN_THREAD_ID)
+        ]
+    );
+    assert_eq!(state_guard.suspended_thread_ids, vec![WORKER_THREAD_ID]);
+    assert!(!state_guard.is_process_suspended);
+}
+
+#[test]
+fn privileged_command_parser_accepts_thread_subcommands() {
+    let parsed_suspend_command = parse_privileged_command(["squalr-cli", "threads", "suspend", "--thread", "4243"]);
+
+    match parsed_suspend_command.expect("command should parse successfully") {
+        PrivilegedCommand::Thread(ThreadCommand::Suspend { thread_suspend_request }) => {
+            assert_eq!(thread_suspend_request.thread_id, Some(WORKER_THREAD_ID));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+
+    let parsed_context_command = parse_privileged_command(["squalr-cli", "threads", "ctx", "-t", "4242"]);
+
+    match parsed_context_command.expect("command should parse successfully") {
+        PrivilegedCommand::Thread(ThreadCommand::Context { thread_context_request }) => {
+            assert_eq!(thread_context_request.thread_id, MAIN_THREAD_ID);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
@@ -12,6 +12,7 @@ use ratatui::text::Line;
 use ratatui::widgets::{Block, Paragraph};
 use squalr_engine::engine_mode::EngineMode;
 use squalr_engine::squalr_engine::SqualrEngine;
+use squalr_engine_api::events::debugger::breakpoint_updated::breakpoint_updated_event::BreakpointUpdatedEvent;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use std::io::{self, IsTerminal, Stdout};
 use std::path::Path;
@@ -66,6 +67,8 @@ pub struct AppShell {
     pub plugins_changed_update_counter: Arc<AtomicU64>,
     pub consumed_plugins_changed_update_counter: u64,
     pub has_registered_plugins_changed_listener: bool,
+    pub pending_breakpoint_updated_events: Arc<RwLock<Vec<BreakpointUpdatedEvent>>>,
+    pub has_registered_breakpoint_updated_listener: bool,
     pub last_scan_results_periodic_refresh_time: Option<Instant>,
     pub last_process_list_auto_refresh_attempt_time: Option<Instant>,
     pub last_project_list_auto_refresh_attempt_time: Option<Instant>,
@@ -100,6 +103,8 @@ impl AppShell {
             plugins_changed_update_counter: Arc::new(AtomicU64::new(0)),
             consumed_plugins_changed_update_counter: 0,
             has_registered_plugins_changed_listener: false,
+            pending_breakpoint_updated_events: Arc::new(RwLock::new(Vec::new())),
+            has_registered_breakpoint_updated_listener: false,
             last_scan_results_periodic_refresh_time: None,
             last_process_list_auto_refresh_attempt_time: None,
             last_project_list_auto_refresh_attempt_time: None,
@@ -1,6 +1,7 @@
 use super::app_shell::AppShell;
 use squalr_engine::squalr_engine::SqualrEngine;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::events::debugger::breakpoint_updated::breakpoint_updated_event::BreakpointUpdatedEvent;
 use squalr_engine_api::events::plugins::changed::plugins_changed_event::PluginsChangedEvent;
 use squalr_engine_api::events::process::changed::process_changed_event::ProcessChangedEvent;
 use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
@@ -19,6 +20,7 @@ impl AppShell {
         self.register_scan_results_updated_listener_if_needed(squalr_engine);
         self.register_process_changed_listener_if_needed(squalr_engine);
         self.register_plugins_changed_listener_if_needed(squalr_engine);
+        self.register_breakpoint_updated_listener_if_needed(squalr_engine);
         let did_synchronize_opened_process = self.synchronize_opened_process_from_engine_event_if_pending();
         if did_synchronize_opened_process {
             self.invalidate_plugins_for_process_change();
@@ -54,6 +56,7 @@ impl AppShell {
         }
 
         let _ = self.sync_memory_viewer_on_tick(squalr_engine);
+        self.apply_pending_breakpoint_updated_events();
         let _ = self.sync_code_viewer_on_tick(squalr_engine);
         self.refresh_settings_on_tick_if_eligible(squalr_engine);
         self.refresh_plugins_on_tick_if_eligible(squalr_engine);
@@ -181,6 +184,29 @@ impl AppShell {
         self.has_registered_plugins_changed_listener = true;
     }
 
+    pub(super) fn register_breakpoint_updated_listener_if_needed(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+    ) {
+        if self.has_registered_breakpoint_updated_listener {
+            return;
+        }
+
+        let Some(engine_unprivileged_state) = squalr_engine.get_engine_unprivileged_state().as_ref() else {
+            return;
+        };
+        let pending_breakpoint_updated_events = self.pending_breakpoint_updated_events.clone();
+        engine_unprivileged_state.listen_for_engine_event::<BreakpointUpdatedEvent>(move |breakpoint_updated_event| {
+            if let Ok(mut pending_breakpoint_updated_events_guard) = pending_breakpoint_updated_events.write() {
+                pending_breakpoint_updated_events_guard.push(breakpoint_updated_event.clone());
+            } else {
+                log::error!("Failed to acquire breakpoint event lock in TUI breakpoint listener.");
+            }
+        });
+
+        self.has_registered_breakpoint_updated_listener = true;
+    }
+
     pub(super) fn synchronize_opened_process_from_engine_event_if_pending(&mut self) -> bool {
         let latest_process_changed_update_counter = self.process_changed_update_counter.load(Ordering::Relaxed);
         if latest_process_changed_update_counter == self.consumed_process_changed_update_counter {
@@ -1,8 +1,11 @@
 use super::app_shell::AppShell;
 use anyhow::Result;
 use squalr_engine::squalr_engine::SqualrEngine;
+use squalr_engine_api::commands::debugger::remove_breakpoint::debugger_remove_breakpoint_request::DebuggerRemoveBreakpointRequest;
+use squalr_engine_api::commands::debugger::set_breakpoint::debugger_set_breakpoint_request::DebuggerSetBreakpointRequest;
 use squalr_engine_api::commands::memory::query::memory_query_request::MemoryQueryRequest;
 use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::structures::debugging::breakpoint_mode::BreakpointMode;
 use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
 use squalr_engine_session::engine_unprivileged_state::EngineUnprivilegedState;
 use std::sync::{Arc, mpsc};
@@ -107,11 +110,125 @@ impl AppShell {
         &mut self,
         engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
     ) {
+        // Breakpoints belong to the previous process, so they are removed rather than left tracing in the background.
+        for breakpoint_id in self.app_state.code_viewer_pane_state.breakpoint_ids() {
+            DebuggerRemoveBreakpointRequest { breakpoint_id }.send(engine_unprivileged_state, |_debugger_remove_breakpoint_response| {});
+        }
+
         self.app_state
             .code_viewer_pane_state
             .clear_for_process_change(engine_unprivileged_state);
     }
 
+    /// Sets a breakpoint on the selected instruction, or removes the breakpoint already there.
+    pub(super) fn toggle_code_viewer_breakpoint(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+        breakpoint_mode: BreakpointMode,
+    ) {
+        let Some(selected_instruction_address) = self
+            .app_state
+            .code_viewer_pane_state
+            .selected_instruction_address()
+        else {
+            self.app_state.code_viewer_pane_state.status_message = String::from("No instruction is selected for a breakpoint.");
+            return;
+        };
+        let Some(engine_unprivileged_state) = squalr_engine.get_engine_unprivileged_state().as_ref() else {
+            self.app_state.code_viewer_pane_state.status_message = String::from("No unprivileged engine state is available for breakpoints.");
+            return;
+        };
+
+        if let Some(breakpoint) = self
+            .app_state
+            .code_viewer_pane_state
+            .get_breakpoint_at(selected_instruction_address)
+        {
+            let debugger_remove_breakpoint_request = DebuggerRemoveBreakpointRequest {
+                breakpoint_id: breakpoint.get_breakpoint_id(),
+            };
+            let (response_sender, response_receiver) = mpsc::sync_channel(1);
+            let request_dispatched = debugger_remove_breakpoint_request.send(engine_unprivileged_state, move |debugger_remove_breakpoint_response| {
+                let _ = response_sender.send(debugger_remove_breakpoint_response);
+            });
+            if !request_dispatched {
+                self.app_state.code_viewer_pane_state.status_message = String::from("Failed to dispatch breakpoint removal request.");
+                return;
+            }
+
+            match response_receiver.recv_timeout(Duration::from_secs(3)) {
+                Ok(debugger_remove_breakpoint_response) => {
+                    // A failed removal means the engine no longer knows the breakpoint, so it is dropped either way.
+                    self.app_state
+                        .code_viewer_pane_state
+                        .apply_removed_breakpoint(selected_instruction_address);
+                    self.app_state.code_viewer_pane_state.status_message = match debugger_remove_breakpoint_response.error {
+                        Some(error) => format!("Failed to remove breakpoint: {}", error),
+                        None => format!("Removed breakpoint at 0x{:X}.", selected_instruction_address),
+                    };
+                }
+                Err(receive_error) => {
+                    self.app_state.code_viewer_pane_state.status_message = format!("Timed out waiting for breakpoint removal response: {}", receive_error);
+                }
+            }
+
+            return;
+        }
+
+        let debugger_set_breakpoint_request = DebuggerSetBreakpointRequest {
+            address: selected_instruction_address,
+            module_name: String::new(),
+            breakpoint_mode,
+        };
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        let request_dispatched = debugger_set_breakpoint_request.send(engine_unprivileged_state, move |debugger_set_breakpoint_response| {
+            let _ = response_sender.send(debugger_set_breakpoint_response);
+        });
+        if !request_dispatched {
+            self.app_state.code_viewer_pane_state.status_message = String::from("Failed to dispatch breakpoint request.");
+            return;
+        }
+
+        match response_receiver.recv_timeout(Duration::from_secs(3)) {
+            Ok(debugger_set_breakpoint_response) => match (debugger_set_breakpoint_response.breakpoint, debugger_set_breakpoint_response.error) {
+                (Some(breakpoint), _) => {
+                    self.app_state.code_viewer_pane_state.status_message = format!(
+                        "Set {} breakpoint {} at 0x{:X}.",
+                        breakpoint.get_breakpoint_mode(),
+                        breakpoint.get_breakpoint_id(),
+                        breakpoint.get_address()
+                    );
+                    self.app_state
+                        .code_viewer_pane_state
+                        .apply_set_breakpoint(breakpoint);
+                }
+                (None, error) => {
+                    self.app_state.code_viewer_pane_state.status_message =
+                        format!("Failed to set breakpoint: {}", error.unwrap_or_else(|| String::from("unknown error")));
+                }
+            },
+            Err(receive_error) => {
+                self.app_state.code_viewer_pane_state.status_message = format!("Timed out waiting for breakpoint response: {}", receive_error);
+            }
+        }
+    }
+
+    pub(super) fn apply_pending_breakpoint_updated_events(&mut self) {
+        let breakpoint_updated_events = match self.pending_breakpoint_updated_events.write() {
+            Ok(mut pending_breakpoint_updated_events_guard) => std::mem::take(&mut *pending_breakpoint_updated_events_guard),
+            Err(error) => {
+                log::error!("Failed to acquire pending breakpoint event lock: {}", error);
+                return;
+            }
+        };
+
+        for breakpoint_updated_event in &breakpoint_updated_events {
+            self.app_state
+                .code_viewer_pane_state
+                .apply_breakpoint_updated_event(breakpoint_updated_event);
+        }
+    }
+
     pub(super) fn sync_code_viewer_on_tick(
         &mut self,
         squalr_engine: &mut SqualrEngine,
@@ -466,6 +466,8 @@ impl AppShell {
             module_name: None,
             data_type_id: None,
             pointer_offsets: None,
+            project_item_type_id: None,
+            code_patch: None,
         };
         let (response_sender, response_receiver) = mpsc::sync_channel(1);
         project_items_create_request.send(engine_unprivileged_state, move |project_items_create_response| {
@@ -1364,6 +1366,7 @@ impl AppShell {
             address,
             module_name,
             value: edited_data_value.get_value_bytes().clone(),
+            value_transform: None,
         })
     }
 
@@ -237,6 +237,7 @@ impl AppShell {
                 .app_state
                 .element_scanner_pane_state
                 .selected_data_type_refs(),
+            value_transform: None,
         };
 
         let (response_sender, response_receiver) = mpsc::sync_channel(1);
@@ -6,6 +6,7 @@ use crate::views::process_selector::pane_state::ProcessSelectorInputMode;
 use crate::views::project_explorer::pane_state::{ProjectExplorerFocusTarget, ProjectSelectorInputMode};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use squalr_engine::squalr_engine::SqualrEngine;
+use squalr_engine_api::structures::debugging::breakpoint_mode::BreakpointMode;
 
 impl AppShell {
     pub(super) fn handle_focused_pane_event(
@@ -932,6 +933,8 @@ impl AppShell {
                 .app_state
                 .code_viewer_pane_state
                 .page_down_instructions(process_bitness),
+            KeyCode::Char('b') => self.toggle_code_viewer_breakpoint(squalr_engine, BreakpointMode::default()),
+            KeyCode::Char('B') => self.toggle_code_viewer_breakpoint(squalr_engine, BreakpointMode::Count),
             _ => {}
         }
     }
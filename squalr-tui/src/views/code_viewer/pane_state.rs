@@ -3,10 +3,12 @@ use crate::views::code_viewer::summary::build_code_viewer_summary_lines;
 use crate::views::entry_row_viewport::build_selection_relative_viewport_range;
 use squalr_engine_api::{
     conversions::storage_size_conversions::StorageSizeConversions,
+    events::debugger::breakpoint_updated::breakpoint_updated_event::BreakpointUpdatedEvent,
     plugins::memory_view::PageRetrievalMode,
     structures::{
         data_types::{built_in_types::u8::data_type_u8::DataTypeU8, data_type_ref::DataTypeRef},
         data_values::container_type::ContainerType,
+        debugging::{breakpoint::Breakpoint, breakpoint_hit::BreakpointHit},
         memory::{bitness::Bitness, normalized_module::NormalizedModule, normalized_region::NormalizedRegion},
         structs::{symbolic_field_definition::SymbolicFieldDefinition, symbolic_struct_definition::SymbolicStructDefinition},
     },
@@ -78,6 +80,8 @@ pub struct CodeViewerPaneState {
     unreadable_page_base_addresses: HashSet<u64>,
     viewport_start_address: Option<u64>,
     selected_instruction_address: Option<u64>,
+    breakpoints_by_address: BTreeMap<u64, Breakpoint>,
+    last_breakpoint_hits_by_id: HashMap<u64, BreakpointHit>,
 }
 
 impl CodeViewerPaneState {
@@ -147,11 +151,17 @@ impl CodeViewerPaneState {
                 continue;
             };
 
-            let marker_text = if instruction_index == selected_instruction_index {
+            let breakpoint = self
+                .breakpoints_by_address
+                .get(&disassembled_instruction.address);
+            let mut marker_text = if instruction_index == selected_instruction_index {
                 ">".to_string()
             } else {
                 String::new()
             };
+            if breakpoint.is_some() {
+                marker_text.push('*');
+            }
             let instruction_bytes = disassembled_instruction
                 .bytes
                 .iter()
@@ -162,11 +172,20 @@ impl CodeViewerPaneState {
                 "0x{:016X}  {:<24}  {}",
                 disassembled_instruction.address, instruction_bytes, disassembled_instruction.text
             );
-            let secondary_text = Some(if let Some(branch_target_address) = disassembled_instruction.branch_target_address {
+            let mut secondary_text = if let Some(branch_target_address) = disassembled_instruction.branch_target_address {
                 format!("branch=0x{:X} | len={}", branch_target_address, disassembled_instruction.length)
             } else {
                 format!("len={}", disassembled_instruction.length)
-            });
+            };
+            if let Some(breakpoint) = breakpoint {
+                secondary_text.push_str(&format!(
+                    " | bp={} hits={}{}",
+                    breakpoint.get_breakpoint_mode(),
+                    breakpoint.get_hit_count(),
+                    if breakpoint.get_is_armed() { "" } else { " disarmed" }
+                ));
+            }
+            let secondary_text = Some(secondary_text);
 
             if instruction_index == selected_instruction_index {
                 entry_rows.push(PaneEntryRow::selected(marker_text, primary_text, secondary_text));
@@ -217,9 +236,93 @@ impl CodeViewerPaneState {
         self.unreadable_page_base_addresses.clear();
         self.viewport_start_address = None;
         self.selected_instruction_address = None;
+        self.breakpoints_by_address.clear();
+        self.last_breakpoint_hits_by_id.clear();
         engine_unprivileged_state.set_virtual_snapshot_queries(Self::VIRTUAL_SNAPSHOT_ID, Self::SNAPSHOT_REFRESH_INTERVAL, Vec::new());
     }
 
+    pub fn get_breakpoint_at(
+        &self,
+        address: u64,
+    ) -> Option<&Breakpoint> {
+        self.breakpoints_by_address.get(&address)
+    }
+
+    pub fn breakpoint_ids(&self) -> Vec<u64> {
+        self.breakpoints_by_address
+            .values()
+            .map(Breakpoint::get_breakpoint_id)
+            .collect()
+    }
+
+    pub fn breakpoint_count(&self) -> usize {
+        self.breakpoints_by_address.len()
+    }
+
+    /// Gets the most recent hit of the breakpoint at the selected instruction, if any.
+    pub fn selected_breakpoint_last_hit(&self) -> Option<&BreakpointHit> {
+        let breakpoint = self.get_breakpoint_at(self.selected_instruction_address?)?;
+
+        self.last_breakpoint_hits_by_id
+            .get(&breakpoint.get_breakpoint_id())
+    }
+
+    pub fn apply_set_breakpoint(
+        &mut self,
+        breakpoint: Breakpoint,
+    ) {
+        // A streamed update may already have arrived for this breakpoint with a newer hit count.
+        let is_known_breakpoint = self
+            .breakpoints_by_address
+            .get(&breakpoint.get_address())
+            .is_some_and(|known_breakpoint| known_breakpoint.get_breakpoint_id() == breakpoint.get_breakpoint_id());
+
+        if !is_known_breakpoint {
+            self.breakpoints_by_address
+                .insert(breakpoint.get_address(), breakpoint);
+        }
+    }
+
+    pub fn apply_removed_breakpoint(
+        &mut self,
+        address: u64,
+    ) {
+        if let Some(breakpoint) = self.breakpoints_by_address.remove(&address) {
+            self.last_breakpoint_hits_by_id
+                .remove(&breakpoint.get_breakpoint_id());
+        }
+    }
+
+    pub fn apply_breakpoint_updated_event(
+        &mut self,
+        breakpoint_updated_event: &BreakpointUpdatedEvent,
+    ) {
+        let breakpoint = &breakpoint_updated_event.breakpoint;
+
+        if breakpoint_updated_event.is_removed {
+            if self
+                .get_breakpoint_at(breakpoint.get_address())
+                .is_some_and(|known_breakpoint| known_breakpoint.get_breakpoint_id() == breakpoint.get_breakpoint_id())
+            {
+                self.apply_removed_breakpoint(breakpoint.get_address());
+            }
+
+            return;
+        }
+
+        self.breakpoints_by_address
+            .insert(breakpoint.get_address(), breakpoint.clone());
+
+        if let Some(last_breakpoint_hit) = breakpoint_updated_event
+            .breakpoint_hits
+            .iter()
+            .max_by_key(|breakpoint_hit| breakpoint_hit.get_hit_index())
+        {
+            self.last_breakpoint_hits_by_id
+                .insert(breakpoint.get_breakpoint_id(), last_breakpoint_hit.clone());
+        }
+    }
+
     pub fn build_visible_chunk_queries(&self) -> Vec<VirtualSnapshotQuery> {
         let Some(current_page) = self.current_page() else {
             return Vec::new();
@@ -652,6 +755,8 @@ impl Default for CodeViewerPaneState {
             unreadable_page_base_addresses: HashSet::new(),
             viewport_start_address: None,
             selected_instruction_address: None,
+            breakpoints_by_address: BTreeMap::new(),
+            last_breakpoint_hits_by_id: HashMap::new(),
         }
     }
 }
@@ -659,6 +764,10 @@ impl Default for CodeViewerPaneState {
 #[cfg(test)]
 mod tests {
     use super::CodeViewerPaneState;
+    use squalr_engine_api::events::debugger::breakpoint_updated::breakpoint_updated_event::BreakpointUpdatedEvent;
+    use squalr_engine_api::structures::debugging::{
+        breakpoint::Breakpoint, breakpoint_hit::BreakpointHit, breakpoint_mode::BreakpointMode, register_snapshot::RegisterSnapshot,
+    };
     use squalr_engine_api::structures::memory::{bitness::Bitness, normalized_module::NormalizedModule, normalized_region::NormalizedRegion};
 
     #[test]
@@ -719,4 +828,59 @@ mod tests {
         assert!(!visible_row_entries.is_empty());
         assert!(visible_row_entries[0].primary_text.contains("nop"));
     }
+
+    #[test]
+    fn breakpoint_updates_mark_rows_and_track_last_hit() {
+        let mut code_viewer_pane_state = CodeViewerPaneState {
+            virtual_pages: vec![NormalizedRegion::new(0x1000, 0x100)],
+            viewport_start_address: Some(0x1000),
+            selected_instruction_address: Some(0x1001),
+            last_visible_row_capacity: 4,
+            ..CodeViewerPaneState::default()
+        };
+        code_viewer_pane_state
+            .page_caches_by_base_address
+            .entry(0x1000)
+            .or_default()
+            .cache_chunk(0, vec![0x90, 0xC3, 0x00, 0x00]);
+
+        let mut breakpoint = Breakpoint::new(7, 1, 0x1001, BreakpointMode::Count);
+        code_viewer_pane_state.apply_set_breakpoint(breakpoint.clone());
+        breakpoint.record_hit();
+        code_viewer_pane_state.apply_breakpoint_updated_event(&BreakpointUpdatedEvent {
+            breakpoint: breakpoint.clone(),
+            breakpoint_hits: vec![BreakpointHit::new(
+                7,
+                1,
+                RegisterSnapshot::default(),
+                vec![0x1001],
+            )],
+            is_removed: false,
+        });
+
+        let visible_row_entries = code_viewer_pane_state.visible_row_entries(Some(Bitness::Bit32));
+
+        assert_eq!(visible_row_entries[1].marker_text, ">*");
+        assert!(
+            visible_row_entries[1]
+                .secondary_text
+                .as_deref()
+                .is_some_and(|secondary_text| secondary_text.contains("hits=1"))
+        );
+        assert_eq!(
+            code_viewer_pane_state
+                .selected_breakpoint_last_hit()
+                .map(BreakpointHit::get_hit_index),
+            Some(1)
+        );
+
+        code_viewer_pane_state.apply_breakpoint_updated_event(&BreakpointUpdatedEvent {
+            breakpoint,
+            breakpoint_hits: Vec::new(),
+            is_removed: true,
+        });
+
+        assert_eq!(code_viewer_pane_state.breakpoint_count(), 0);
+        assert!(code_viewer_pane_state.selected_breakpoint_last_hit().is_none());
+    }
 }
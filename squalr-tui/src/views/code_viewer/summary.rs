@@ -6,7 +6,7 @@ pub fn build_code_viewer_summary_lines(
     process_bitness: Option<Bitness>,
 ) -> Vec<String> {
     let mut summary_lines = vec![
-        String::from("[ACT] r refresh pages | [/] page | Home/End first/last page | Up/Down instruction | PgUp/PgDn jump."),
+        String::from("[ACT] r refresh pages | [/] page | Home/End first/last page | Up/Down instruction | PgUp/PgDn jump | b/B trace/count breakpoint."),
         format!(
             "[PAGE] index={} / {} | instructions={} | selected={} | bitness={} | loading={}.",
             code_viewer_pane_state.current_page_index.saturating_add(1),
@@ -23,6 +23,7 @@ pub fn build_code_viewer_summary_lines(
             code_viewer_pane_state.last_visible_row_capacity
         ),
         format!("[STAT] {}.", code_viewer_pane_state.stats_string),
+        build_breakpoint_summary_line(code_viewer_pane_state),
         format!("[INFO] {}.", code_viewer_pane_state.status_message),
     ];
 
@@ -33,6 +34,43 @@ pub fn build_code_viewer_summary_lines(
     summary_lines
 }
 
+fn build_breakpoint_summary_line(code_viewer_pane_state: &CodeViewerPaneState) -> String {
+    let selected_breakpoint = code_viewer_pane_state
+        .selected_instruction_address()
+        .and_then(|address| code_viewer_pane_state.get_breakpoint_at(address));
+    let Some(selected_breakpoint) = selected_breakpoint else {
+        return format!("[BRK] breakpoints={}.", code_viewer_pane_state.breakpoint_count());
+    };
+    let mut breakpoint_summary_line = format!(
+        "[BRK] breakpoints={} | id={} | mode={} | hits={} | armed={}",
+        code_viewer_pane_state.breakpoint_count(),
+        selected_breakpoint.get_breakpoint_id(),
+        selected_breakpoint.get_breakpoint_mode(),
+        selected_breakpoint.get_hit_count(),
+        selected_breakpoint.get_is_armed()
+    );
+
+    if let Some(last_breakpoint_hit) = code_viewer_pane_state.selected_breakpoint_last_hit() {
+        let stack_frames = last_breakpoint_hit
+            .get_stack_frames()
+            .iter()
+            .map(|stack_frame| format!("0x{:X}", stack_frame))
+            .collect::<Vec<_>>()
+            .join(" <- ");
+
+        breakpoint_summary_line.push_str(&format!(
+            " | last=#{} thread={} | stack={}",
+            last_breakpoint_hit.get_hit_index(),
+            last_breakpoint_hit.get_register_snapshot().get_thread_id(),
+            if stack_frames.is_empty() { String::from("none") } else { stack_frames }
+        ));
+    }
+
+    breakpoint_summary_line.push('.');
+
+    breakpoint_summary_line
+}
+
 fn option_hex(address: Option<u64>) -> String {
     address
         .map(|address| format!("0x{:X}", address))
@@ -635,7 +635,11 @@ impl SettingsPaneState {
         current_mode: MemoryReadMode,
         move_forward: bool,
     ) -> MemoryReadMode {
-        let all_modes = [MemoryReadMode::Skip, MemoryReadMode::ReadBeforeScan];
+        let all_modes = [
+            MemoryReadMode::Skip,
+            MemoryReadMode::ReadBeforeScan,
+            MemoryReadMode::ReadWhileSuspended,
+        ];
         let current_position = all_modes
             .iter()
             .position(|memory_read_mode| *memory_read_mode == current_mode)
@@ -249,6 +249,7 @@ fn memory_read_mode_label(memory_read_mode: MemoryReadMode) -> &'static str {
     match memory_read_mode {
         MemoryReadMode::Skip => "skip",
         MemoryReadMode::ReadBeforeScan => "before_scan",
+        MemoryReadMode::ReadWhileSuspended => "while_suspended",
     }
 }
 
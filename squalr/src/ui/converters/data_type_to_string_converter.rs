@@ -14,6 +14,7 @@ use std::str::FromStr;
 const DATA_TYPE_ID_I_X86: &str = "i_x86";
 const DATA_TYPE_ID_I_X64: &str = "i_x64";
 const DATA_TYPE_ID_I_ARM: &str = "i_arm";
+const DATA_TYPE_ID_I_THUMB: &str = "i_thumb";
 const DATA_TYPE_ID_I_ARM64: &str = "i_arm64";
 
 pub struct DataTypeToStringConverter {}
@@ -59,6 +60,7 @@ impl DataTypeToStringConverter {
             DATA_TYPE_ID_I_X86 => String::from("i_x86"),
             DATA_TYPE_ID_I_X64 => String::from("i_x64"),
             DATA_TYPE_ID_I_ARM => String::from("i_arm"),
+            DATA_TYPE_ID_I_THUMB => String::from("i_thumb"),
             DATA_TYPE_ID_I_ARM64 => String::from("i_arm64"),
             _ => base_data_type_id,
         };
@@ -20,15 +20,15 @@ use crate::{
     },
 };
 use eframe::egui::{
-    Align, Align2, Color32, CursorIcon, Direction, Key, Layout, Pos2, Rect, Response, RichText, ScrollArea, Sense, Spinner, Stroke, Ui, UiBuilder, Widget,
-    pos2, vec2,
+    Align, Align2, Color32, CursorIcon, Direction, Key, Layout, Pos2, Rect, Response, RichText, ScrollArea, Sense, Spinner, Stroke, TextEdit, Ui, UiBuilder,
+    Widget, pos2, vec2,
 };
 use epaint::{Color32 as EpaintColor32, CornerRadius};
 use squalr_engine_api::{
     commands::privileged_command_request::PrivilegedCommandRequest,
     commands::unprivileged_command_request::UnprivilegedCommandRequest,
     dependency_injection::dependency::Dependency,
-    events::process::changed::process_changed_event::ProcessChangedEvent,
+    events::{debugger::breakpoint_updated::breakpoint_updated_event::BreakpointUpdatedEvent, process::changed::process_changed_event::ProcessChangedEvent},
     structures::{
         data_types::{built_in_types::u64::data_type_u64::DataTypeU64, data_type_ref::DataTypeRef},
         memory::bitness::Bitness,
@@ -74,6 +74,7 @@ impl CodeViewerView {
     const MINIMUM_BYTES_COLUMN_WIDTH: f32 = 72.0;
     const MINIMUM_TEXT_COLUMN_WIDTH: f32 = 220.0;
     const TEXT_LEFT_PADDING: f32 = 6.0;
+    const ANNOTATION_LEFT_PADDING: f32 = 12.0;
     const ADDRESS_TEXT_RIGHT_PADDING: f32 = 8.0;
     const ROW_TEXT_TOP_PADDING: f32 = 4.0;
     const INLINE_EDIT_MAX_WIDTH: f32 = 256.0;
@@ -82,6 +83,7 @@ impl CodeViewerView {
     const BRANCH_LANE_RIGHT_PADDING: f32 = 8.0;
     const MAX_BRANCH_LANES: usize = 5;
     const CONTEXT_MENU_WIDTH: f32 = 220.0;
+    const TRACE_REGISTER_INPUT_WIDTH: f32 = 56.0;
     const EDIT_WARNING_BUTTON_WIDTH: f32 = 128.0;
 
     pub fn new(app_context: Arc<AppContext>) -> Self {
@@ -104,10 +106,21 @@ impl CodeViewerView {
 
         CodeViewerViewData::refresh_memory_pages(instance.code_viewer_view_data.clone(), instance.app_context.engine_unprivileged_state.clone());
         instance.listen_for_process_change();
+        instance.listen_for_breakpoint_updates();
 
         instance
     }
 
+    fn listen_for_breakpoint_updates(&self) {
+        let code_viewer_view_data = self.code_viewer_view_data.clone();
+
+        self.app_context
+            .engine_unprivileged_state
+            .listen_for_engine_event::<BreakpointUpdatedEvent>(move |breakpoint_updated_event| {
+                CodeViewerViewData::apply_breakpoint_updated_event(code_viewer_view_data.clone(), breakpoint_updated_event);
+            });
+    }
+
     fn listen_for_process_change(&self) {
         let engine_unprivileged_state = self.app_context.engine_unprivileged_state.clone();
         let code_viewer_view_data = self.code_viewer_view_data.clone();
@@ -455,6 +468,25 @@ impl CodeViewerView {
         }
     }
 
+    /// Traces from the clicked instruction, or across the selection when the click is inside one, stopping at its last instruction.
+    fn resolve_trace_range(
+        context_menu_address: u64,
+        selected_instruction_addresses: &HashSet<u64>,
+    ) -> (u64, Option<u64>) {
+        if selected_instruction_addresses.contains(&context_menu_address) && selected_instruction_addresses.len() > 1 {
+            let start_address = selected_instruction_addresses
+                .iter()
+                .min()
+                .copied()
+                .unwrap_or(context_menu_address);
+            let end_address = selected_instruction_addresses.iter().max().copied();
+
+            (start_address, end_address)
+        } else {
+            (context_menu_address, None)
+        }
+    }
+
     fn dispatch_instruction_write(
         &self,
         instruction_write_plan: CodeViewerInstructionWritePlan,
@@ -468,6 +500,7 @@ impl CodeViewerView {
             address: write_start_address,
             module_name: String::new(),
             value: instruction_write_plan.written_bytes,
+            value_transform: None,
         };
 
         memory_write_request.send(&engine_unprivileged_state, move |memory_write_response| {
@@ -483,10 +516,7 @@ impl CodeViewerView {
     }
 
     fn instruction_edit_data_type_ref(&self) -> DataTypeRef {
-        DataTypeRef::new(match self.get_process_bitness().unwrap_or(Bitness::Bit64) {
-            Bitness::Bit32 => "i_x86",
-            Bitness::Bit64 => "i_x64",
-        })
+        DataTypeRef::new(CodeViewerViewData::get_active_instruction_set(self.code_viewer_view_data.clone(), self.get_process_bitness()).get_data_type_id())
     }
 
     fn build_context_menu_edit_label(
@@ -535,7 +565,17 @@ impl CodeViewerView {
             Sense::click(),
         );
         if breakpoint_response.clicked() {
-            CodeViewerViewData::toggle_breakpoint_address(self.code_viewer_view_data.clone(), instruction_line.address);
+            CodeViewerViewData::toggle_breakpoint_address(
+                self.code_viewer_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                instruction_line.address,
+            );
+        }
+
+        if breakpoint_response.hovered()
+            && let Some(breakpoint_tooltip) = CodeViewerViewData::get_breakpoint_tooltip(self.code_viewer_view_data.clone(), instruction_line.address)
+        {
+            breakpoint_response.on_hover_text(breakpoint_tooltip);
         }
 
         if row_response.clicked() {
@@ -587,10 +627,19 @@ impl CodeViewerView {
             user_interface.scroll_to_rect(row_rect, Some(Align::Center));
         }
 
-        if CodeViewerViewData::has_breakpoint_address(self.code_viewer_view_data.clone(), instruction_line.address) {
-            user_interface
-                .painter()
-                .circle_filled(Self::get_breakpoint_center(row_rect), Self::BREAKPOINT_RADIUS, theme.error_red);
+        // Breakpoints that reached their hit limit are drawn hollow until removed.
+        if let Some(breakpoint) = CodeViewerViewData::get_breakpoint_at_address(self.code_viewer_view_data.clone(), instruction_line.address) {
+            if breakpoint.get_is_armed() {
+                user_interface
+                    .painter()
+                    .circle_filled(Self::get_breakpoint_center(row_rect), Self::BREAKPOINT_RADIUS, theme.error_red);
+            } else {
+                user_interface.painter().circle_stroke(
+                    Self::get_breakpoint_center(row_rect),
+                    Self::BREAKPOINT_RADIUS,
+                    Stroke::new(1.5, theme.error_red),
+                );
+            }
         }
 
         user_interface
@@ -633,23 +682,36 @@ impl CodeViewerView {
                 self.render_instruction_text_edit_contents(user_interface, column_layout.text_rect, instruction_edit_state);
             }
         } else {
-            user_interface
+            let text_painter = user_interface
                 .painter()
-                .with_clip_rect(column_layout.text_rect.intersect(user_interface.clip_rect()))
-                .text(
+                .with_clip_rect(column_layout.text_rect.intersect(user_interface.clip_rect()));
+            let instruction_text_rect = text_painter.text(
+                pos2(
+                    column_layout.text_rect.min.x + Self::TEXT_LEFT_PADDING,
+                    row_rect.min.y + Self::ROW_TEXT_TOP_PADDING,
+                ),
+                Align2::LEFT_TOP,
+                &instruction_line.text,
+                theme.font_library.font_ubuntu_mono_bold.font_normal.clone(),
+                if instruction_line.is_control_flow {
+                    theme.background_control_info
+                } else {
+                    theme.foreground
+                },
+            );
+
+            if let Some(annotation) = &instruction_line.annotation {
+                text_painter.text(
                     pos2(
-                        column_layout.text_rect.min.x + Self::TEXT_LEFT_PADDING,
+                        instruction_text_rect.max.x + Self::ANNOTATION_LEFT_PADDING,
                         row_rect.min.y + Self::ROW_TEXT_TOP_PADDING,
                     ),
                     Align2::LEFT_TOP,
-                    &instruction_line.text,
+                    annotation,
                     theme.font_library.font_ubuntu_mono_bold.font_normal.clone(),
-                    if instruction_line.is_control_flow {
-                        theme.background_control_info
-                    } else {
-                        theme.foreground
-                    },
+                    theme.foreground_preview,
                 );
+            }
         }
 
         row_rect
@@ -857,6 +919,7 @@ impl Widget for CodeViewerView {
         let theme = &self.app_context.theme;
         let code_viewer_has_keyboard_focus = CodeViewerViewData::has_keyboard_focus(self.code_viewer_view_data.clone());
         CodeViewerViewData::clear_stale_request_state_if_needed(self.code_viewer_view_data.clone());
+        CodeViewerViewData::poll_watchpoint_hits(self.code_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
         user_interface
             .ctx()
             .request_repaint_after(CodeViewerViewData::SNAPSHOT_REFRESH_INTERVAL);
@@ -908,10 +971,9 @@ impl Widget for CodeViewerView {
 
                 toolbar_user_interface.add_space(12.0);
                 toolbar_user_interface.label(
-                    RichText::new(match self.get_process_bitness().unwrap_or(Bitness::Bit64) {
-                        Bitness::Bit32 => "x86 code",
-                        Bitness::Bit64 => "x64 code",
-                    })
+                    RichText::new(
+                        CodeViewerViewData::get_active_instruction_set(self.code_viewer_view_data.clone(), self.get_process_bitness()).get_header_label(),
+                    )
                     .font(theme.font_library.font_noto_sans.font_normal.clone())
                     .color(theme.foreground_preview),
                 );
@@ -958,6 +1020,136 @@ impl Widget for CodeViewerView {
                     CodeViewerViewData::seek_to_input_address(self.code_viewer_view_data.clone());
                 }
 
+                if let Some(breakpoint_error_text) = CodeViewerViewData::get_breakpoint_error_text(self.code_viewer_view_data.clone()) {
+                    toolbar_user_interface.add_space(12.0);
+                    toolbar_user_interface.label(
+                        RichText::new(breakpoint_error_text)
+                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                            .color(theme.error_red),
+                    );
+                }
+
+                if let Some(watchpoint_status_text) = CodeViewerViewData::get_watchpoint_status_text(self.code_viewer_view_data.clone()) {
+                    toolbar_user_interface.add_space(12.0);
+                    toolbar_user_interface.label(
+                        RichText::new(watchpoint_status_text)
+                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                            .color(theme.foreground_preview),
+                    );
+                    toolbar_user_interface.add_space(6.0);
+                    let stop_watchpoint_button = toolbar_user_interface.add_sized(
+                        vec2(36.0, Self::TOOLBAR_ROW_HEIGHT),
+                        Button::new_from_theme(theme)
+                            .background_color(Color32::TRANSPARENT)
+                            .with_tooltip_text("Stop watching the address."),
+                    );
+                    IconDraw::draw(
+                        &toolbar_user_interface,
+                        stop_watchpoint_button.rect,
+                        &theme.icon_library.icon_handle_navigation_stop,
+                    );
+
+                    if stop_watchpoint_button.clicked() {
+                        CodeViewerViewData::stop_watchpoint(self.code_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+                    }
+                }
+
+                if let Some(trace_status_text) = CodeViewerViewData::get_trace_status_text(self.code_viewer_view_data.clone()) {
+                    toolbar_user_interface.add_space(12.0);
+                    toolbar_user_interface.label(
+                        RichText::new(trace_status_text)
+                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                            .color(theme.foreground_preview),
+                    );
+
+                    if CodeViewerViewData::has_instruction_trace(self.code_viewer_view_data.clone()) {
+                        toolbar_user_interface.add_space(6.0);
+                        let previous_step_button = toolbar_user_interface.add_sized(
+                            vec2(36.0, Self::TOOLBAR_ROW_HEIGHT),
+                            Button::new_from_theme(theme)
+                                .background_color(Color32::TRANSPARENT)
+                                .with_tooltip_text("Previous traced instruction."),
+                        );
+                        IconDraw::draw(
+                            &toolbar_user_interface,
+                            previous_step_button.rect,
+                            &theme.icon_library.icon_handle_navigation_left_arrow_small,
+                        );
+                        let next_step_button = toolbar_user_interface.add_sized(
+                            vec2(36.0, Self::TOOLBAR_ROW_HEIGHT),
+                            Button::new_from_theme(theme)
+                                .background_color(Color32::TRANSPARENT)
+                                .with_tooltip_text("Next traced instruction."),
+                        );
+                        IconDraw::draw(
+                            &toolbar_user_interface,
+                            next_step_button.rect,
+                            &theme.icon_library.icon_handle_navigation_right_arrow_small,
+                        );
+
+                        if previous_step_button.clicked() {
+                            CodeViewerViewData::move_trace_step(self.code_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone(), -1);
+                        }
+
+                        if next_step_button.clicked() {
+                            CodeViewerViewData::move_trace_step(self.code_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone(), 1);
+                        }
+
+                        toolbar_user_interface.add_space(6.0);
+                        let mut should_find_last_register_write = false;
+
+                        if let Some(mut code_viewer_view_data) = self
+                            .code_viewer_view_data
+                            .write("Code viewer toolbar trace register input")
+                        {
+                            let register_query_response = toolbar_user_interface.add_sized(
+                                vec2(Self::TRACE_REGISTER_INPUT_WIDTH, Self::TOOLBAR_ROW_HEIGHT),
+                                TextEdit::singleline(code_viewer_view_data.get_trace_register_query_mut())
+                                    .hint_text("rax")
+                                    .vertical_align(Align::Center)
+                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                    .background_color(theme.background_primary)
+                                    .text_color(theme.foreground),
+                            );
+
+                            should_find_last_register_write =
+                                register_query_response.lost_focus() && toolbar_user_interface.input(|input_state| input_state.key_pressed(Key::Enter));
+                        }
+
+                        let last_write_button = toolbar_user_interface.add_sized(
+                            vec2(36.0, Self::TOOLBAR_ROW_HEIGHT),
+                            Button::new_from_theme(theme)
+                                .background_color(Color32::TRANSPARENT)
+                                .with_tooltip_text("Step back to the instruction that last wrote this register."),
+                        );
+                        IconDraw::draw(&toolbar_user_interface, last_write_button.rect, &theme.icon_library.icon_handle_navigation_undo);
+
+                        if should_find_last_register_write || last_write_button.clicked() {
+                            CodeViewerViewData::find_last_register_write(
+                                self.code_viewer_view_data.clone(),
+                                self.app_context.engine_unprivileged_state.clone(),
+                            );
+                        }
+                    }
+
+                    toolbar_user_interface.add_space(6.0);
+                    let close_trace_button = toolbar_user_interface.add_sized(
+                        vec2(36.0, Self::TOOLBAR_ROW_HEIGHT),
+                        Button::new_from_theme(theme)
+                            .background_color(Color32::TRANSPARENT)
+                            .with_tooltip_text("Close the trace."),
+                    );
+                    IconDraw::draw(
+                        &toolbar_user_interface,
+                        close_trace_button.rect,
+                        &theme.icon_library.icon_handle_navigation_cancel,
+                    );
+
+                    if close_trace_button.clicked() {
+                        CodeViewerViewData::clear_trace(self.code_viewer_view_data.clone());
+                    }
+                }
+
                 let footer_height = self.code_viewer_footer_view.get_height();
                 let content_rect = user_interface
                     .available_rect_before_wrap()
@@ -1059,6 +1251,9 @@ impl Widget for CodeViewerView {
                             .request_virtual_snapshot_refresh(CodeViewerViewData::WINDOW_VIRTUAL_SNAPSHOT_ID);
 
                         visible_instruction_lines = CodeViewerViewData::build_instruction_lines(self.code_viewer_view_data.clone(), process_bitness);
+                        CodeViewerViewData::annotate_watchpoint_hits(self.code_viewer_view_data.clone(), &mut visible_instruction_lines);
+                        CodeViewerViewData::annotate_breakpoint_hits(self.code_viewer_view_data.clone(), &mut visible_instruction_lines);
+                        CodeViewerViewData::annotate_trace_steps(self.code_viewer_view_data.clone(), &mut visible_instruction_lines);
                         let pending_scroll_address = CodeViewerViewData::take_pending_scroll_address(self.code_viewer_view_data.clone());
                         let scroll_target_address = CodeViewerViewData::resolve_scroll_target_address(pending_scroll_address, &visible_instruction_lines);
                         if !visible_instruction_lines.is_empty() {
@@ -1170,6 +1365,8 @@ impl Widget for CodeViewerView {
                         CodeViewerViewData::get_selected_instruction_addresses(self.code_viewer_view_data.clone(), &visible_instruction_lines);
                     let add_action_label = self.build_context_menu_add_label(context_menu_address, &selected_instruction_addresses);
                     let edit_action_label = self.build_context_menu_edit_label(context_menu_address, &selected_instruction_addresses);
+                    let (trace_start_address, trace_end_address) = Self::resolve_trace_range(context_menu_address, &selected_instruction_addresses);
+                    let trace_action_label = if trace_end_address.is_some() { "Trace Selection" } else { "Trace From Here" };
 
                     ContextMenu::new(
                         self.app_context.clone(),
@@ -1207,6 +1404,25 @@ impl Widget for CodeViewerView {
                                 self.dispatch_add_instructions_to_project(context_menu_address, &visible_instruction_lines);
                                 *should_close = true;
                             }
+
+                            if user_interface
+                                .add(ToolbarMenuItemView::new(
+                                    self.app_context.clone(),
+                                    trace_action_label,
+                                    "code_viewer_ctx_trace",
+                                    &None,
+                                    Self::CONTEXT_MENU_WIDTH,
+                                ))
+                                .clicked()
+                            {
+                                CodeViewerViewData::start_trace(
+                                    self.code_viewer_view_data.clone(),
+                                    self.app_context.engine_unprivileged_state.clone(),
+                                    trace_start_address,
+                                    trace_end_address,
+                                );
+                                *should_close = true;
+                            }
                         },
                     )
                     .width(Self::CONTEXT_MENU_WIDTH)
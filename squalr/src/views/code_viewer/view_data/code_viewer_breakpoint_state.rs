@@ -0,0 +1,235 @@
+use squalr_engine_api::events::debugger::breakpoint_updated::breakpoint_updated_event::BreakpointUpdatedEvent;
+use squalr_engine_api::structures::debugging::{breakpoint::Breakpoint, breakpoint_hit::BreakpointHit};
+use std::collections::{HashMap, HashSet};
+
+/// Tracks the software breakpoints shown in the code viewer gutter, along with the most recent hit of each.
+#[derive(Clone, Debug, Default)]
+pub struct CodeViewerBreakpointState {
+    breakpoints_by_address: HashMap<u64, Breakpoint>,
+    last_hits_by_breakpoint_id: HashMap<u64, BreakpointHit>,
+    pending_addresses: HashSet<u64>,
+    error: Option<String>,
+}
+
+impl CodeViewerBreakpointState {
+    pub fn get_breakpoint(
+        &self,
+        address: u64,
+    ) -> Option<&Breakpoint> {
+        self.breakpoints_by_address.get(&address)
+    }
+
+    pub fn get_last_hit(
+        &self,
+        breakpoint_id: u64,
+    ) -> Option<&BreakpointHit> {
+        self.last_hits_by_breakpoint_id.get(&breakpoint_id)
+    }
+
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Marks a set or remove request as in flight, returning false if one is already pending for the address.
+    pub fn begin_request(
+        &mut self,
+        address: u64,
+    ) -> bool {
+        self.error = None;
+
+        self.pending_addresses.insert(address)
+    }
+
+    pub fn apply_set_result(
+        &mut self,
+        address: u64,
+        breakpoint: Option<Breakpoint>,
+        error: Option<String>,
+    ) {
+        self.pending_addresses.remove(&address);
+        self.error = error;
+
+        // A streamed update may arrive before the response, and carries newer hit counts.
+        if let Some(breakpoint) = breakpoint {
+            self.breakpoints_by_address
+                .entry(address)
+                .and_modify(|known_breakpoint| {
+                    if known_breakpoint.get_breakpoint_id() != breakpoint.get_breakpoint_id() {
+                        *known_breakpoint = breakpoint.clone();
+                    }
+                })
+                .or_insert(breakpoint);
+        }
+    }
+
+    pub fn apply_remove_result(
+        &mut self,
+        address: u64,
+        error: Option<String>,
+    ) {
+        self.pending_addresses.remove(&address);
+
+        // A failed removal means the engine no longer knows the breakpoint, so it is dropped either way.
+        if let Some(breakpoint) = self.breakpoints_by_address.remove(&address) {
+            self.last_hits_by_breakpoint_id
+                .remove(&breakpoint.get_breakpoint_id());
+        }
+
+        self.error = error;
+    }
+
+    /// Applies a streamed breakpoint update. Updates for breakpoints set elsewhere, such as from the CLI, are shown as well.
+    pub fn apply_event(
+        &mut self,
+        breakpoint_updated_event: &BreakpointUpdatedEvent,
+    ) {
+        let breakpoint = &breakpoint_updated_event.breakpoint;
+        let breakpoint_id = breakpoint.get_breakpoint_id();
+
+        if breakpoint_updated_event.is_removed {
+            if self
+                .breakpoints_by_address
+                .get(&breakpoint.get_address())
+                .is_some_and(|known_breakpoint| known_breakpoint.get_breakpoint_id() == breakpoint_id)
+            {
+                self.breakpoints_by_address.remove(&breakpoint.get_address());
+            }
+
+            self.last_hits_by_breakpoint_id.remove(&breakpoint_id);
+
+            return;
+        }
+
+        self.breakpoints_by_address
+            .insert(breakpoint.get_address(), breakpoint.clone());
+
+        if let Some(last_hit) = breakpoint_updated_event
+            .breakpoint_hits
+            .iter()
+            .max_by_key(|breakpoint_hit| breakpoint_hit.get_hit_index())
+        {
+            self.last_hits_by_breakpoint_id
+                .insert(breakpoint_id, last_hit.clone());
+        }
+    }
+
+    /// Forgets every breakpoint, returning them so that they can be removed from the engine.
+    pub fn clear(&mut self) -> Vec<Breakpoint> {
+        std::mem::take(self)
+            .breakpoints_by_address
+            .into_values()
+            .collect()
+    }
+
+    pub fn format_hit_annotation(
+        &self,
+        address: u64,
+    ) -> Option<String> {
+        let breakpoint = self.get_breakpoint(address)?;
+
+        if breakpoint.get_hit_count() == 0 {
+            return None;
+        }
+
+        Some(format!("; hits {}", breakpoint.get_hit_count()))
+    }
+
+    /// Describes a breakpoint and the registers and stack of its most recent hit.
+    pub fn format_tooltip(
+        &self,
+        address: u64,
+    ) -> Option<String> {
+        let breakpoint = self.get_breakpoint(address)?;
+        let mut tooltip_lines = vec![format!(
+            "Breakpoint {} ({}): {} hits{}",
+            breakpoint.get_breakpoint_id(),
+            breakpoint.get_breakpoint_mode(),
+            breakpoint.get_hit_count(),
+            if breakpoint.get_is_armed() { "" } else { ", disarmed" }
+        )];
+
+        if let Some(last_hit) = self.get_last_hit(breakpoint.get_breakpoint_id()) {
+            let register_snapshot = last_hit.get_register_snapshot();
+
+            tooltip_lines.push(format!("Hit #{} on thread {}", last_hit.get_hit_index(), register_snapshot.get_thread_id()));
+            tooltip_lines.extend(
+                register_snapshot
+                    .get_registers()
+                    .iter()
+                    .map(|register_value| format!("{} = 0x{:X}", register_value.get_name(), register_value.get_value())),
+            );
+            tooltip_lines.extend(
+                register_snapshot
+                    .get_vector_registers()
+                    .iter()
+                    .map(|vector_register_value| format!("{} = {}", vector_register_value.get_name(), vector_register_value.format_hex())),
+            );
+
+            if !last_hit.get_stack_frames().is_empty() {
+                tooltip_lines.push(String::from("Stack:"));
+                tooltip_lines.extend(
+                    last_hit
+                        .get_stack_frames()
+                        .iter()
+                        .map(|stack_frame| format!("  0x{:X}", stack_frame)),
+                );
+            }
+        }
+
+        Some(tooltip_lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodeViewerBreakpointState;
+    use squalr_engine_api::events::debugger::breakpoint_updated::breakpoint_updated_event::BreakpointUpdatedEvent;
+    use squalr_engine_api::structures::debugging::{
+        breakpoint::Breakpoint, breakpoint_hit::BreakpointHit, breakpoint_mode::BreakpointMode, register_snapshot::RegisterSnapshot,
+    };
+
+    #[test]
+    fn breakpoint_events_track_hits_and_removal() {
+        let mut breakpoint = Breakpoint::new(4, 1, 0x1000, BreakpointMode::Trace { hit_limit: 2 });
+        let mut breakpoint_state = CodeViewerBreakpointState::default();
+
+        assert!(breakpoint_state.begin_request(0x1000));
+        assert!(!breakpoint_state.begin_request(0x1000));
+        breakpoint_state.apply_set_result(0x1000, Some(breakpoint.clone()), None);
+        assert_eq!(breakpoint_state.format_hit_annotation(0x1000), None);
+
+        breakpoint.record_hit();
+        breakpoint.record_hit();
+        breakpoint.disarm();
+        breakpoint_state.apply_event(&BreakpointUpdatedEvent {
+            breakpoint: breakpoint.clone(),
+            breakpoint_hits: vec![
+                BreakpointHit::new(4, 1, RegisterSnapshot::default(), vec![0x1000]),
+                BreakpointHit::new(4, 2, RegisterSnapshot::default(), vec![0x1000, 0x2000]),
+            ],
+            is_removed: false,
+        });
+
+        assert_eq!(breakpoint_state.format_hit_annotation(0x1000).as_deref(), Some("; hits 2"));
+        assert_eq!(
+            breakpoint_state
+                .get_last_hit(4)
+                .map(BreakpointHit::get_hit_index),
+            Some(2)
+        );
+        assert!(
+            breakpoint_state
+                .format_tooltip(0x1000)
+                .is_some_and(|tooltip| tooltip.contains("disarmed") && tooltip.contains("0x2000"))
+        );
+
+        breakpoint_state.apply_event(&BreakpointUpdatedEvent {
+            breakpoint,
+            breakpoint_hits: Vec::new(),
+            is_removed: true,
+        });
+
+        assert!(breakpoint_state.get_breakpoint(0x1000).is_none());
+        assert!(breakpoint_state.get_last_hit(4).is_none());
+    }
+}
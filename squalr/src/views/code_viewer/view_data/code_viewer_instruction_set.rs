@@ -0,0 +1,147 @@
+use squalr_engine_api::{plugins::instruction_set::InstructionSet, structures::memory::bitness::Bitness};
+use squalr_plugin_instructions_arm::{
+    Arm32InstructionSet, Arm64InstructionSet, ArmDisassembledInstruction, ArmInstructionMode, ArmInstructionModeMap, DataTypeInstructionArm,
+    DataTypeInstructionArm64, DataTypeInstructionThumb, ThumbInstructionSet,
+};
+use squalr_plugin_instructions_x86::{DisassembledInstruction, X64InstructionSet, X86InstructionSet};
+
+/// The instruction set the code viewer decodes and assembles with at a given address.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CodeViewerInstructionSet {
+    X86,
+    X64,
+    Arm,
+    Thumb,
+    Arm64,
+}
+
+impl CodeViewerInstructionSet {
+    /// Resolves the instruction set for a process. ARM hosts decode 32-bit processes as ARM or Thumb depending on the
+    /// interworking state at the address being viewed; all other hosts decode x86 or x64 by process bitness.
+    pub fn for_process(
+        process_bitness: Option<Bitness>,
+        is_arm_target: bool,
+        arm_instruction_mode: ArmInstructionMode,
+    ) -> Self {
+        match (process_bitness.unwrap_or(Bitness::Bit64), is_arm_target) {
+            (Bitness::Bit32, false) => Self::X86,
+            (Bitness::Bit64, false) => Self::X64,
+            (Bitness::Bit32, true) => match arm_instruction_mode {
+                ArmInstructionMode::Arm => Self::Arm,
+                ArmInstructionMode::Thumb => Self::Thumb,
+            },
+            (Bitness::Bit64, true) => Self::Arm64,
+        }
+    }
+
+    pub fn get_data_type_id(&self) -> &'static str {
+        match self {
+            Self::X86 => "i_x86",
+            Self::X64 => "i_x64",
+            Self::Arm => DataTypeInstructionArm::DATA_TYPE_ID,
+            Self::Thumb => DataTypeInstructionThumb::DATA_TYPE_ID,
+            Self::Arm64 => DataTypeInstructionArm64::DATA_TYPE_ID,
+        }
+    }
+
+    pub fn get_header_label(&self) -> &'static str {
+        match self {
+            Self::X86 => "x86 code",
+            Self::X64 => "x64 code",
+            Self::Arm => "ARM code",
+            Self::Thumb => "Thumb code",
+            Self::Arm64 => "ARM64 code",
+        }
+    }
+
+    pub fn create_instruction_set(&self) -> Box<dyn InstructionSet> {
+        match self {
+            Self::X86 => Box::new(X86InstructionSet::new()),
+            Self::X64 => Box::new(X64InstructionSet::new()),
+            Self::Arm => Box::new(Arm32InstructionSet::new()),
+            Self::Thumb => Box::new(ThumbInstructionSet::new()),
+            Self::Arm64 => Box::new(Arm64InstructionSet::new()),
+        }
+    }
+
+    /// Decodes a block of instructions at their absolute addresses. ARM listings also record the mode of direct call
+    /// targets, and are converted to the shared listing row type with their interworking annotation attached.
+    pub fn disassemble_block(
+        &self,
+        instruction_bytes: &[u8],
+        base_address: u64,
+        arm_instruction_mode_map: &mut ArmInstructionModeMap,
+    ) -> Result<Vec<DisassembledInstruction>, String> {
+        let arm_instructions = match self {
+            Self::X86 => return X86InstructionSet::new().disassemble_block(instruction_bytes, base_address),
+            Self::X64 => return X64InstructionSet::new().disassemble_block(instruction_bytes, base_address),
+            Self::Arm => Arm32InstructionSet::new().disassemble_block(instruction_bytes, base_address)?,
+            Self::Thumb => ThumbInstructionSet::new().disassemble_block(instruction_bytes, base_address)?,
+            Self::Arm64 => Arm64InstructionSet::new().disassemble_block(instruction_bytes, base_address)?,
+        };
+
+        arm_instruction_mode_map.record_call_targets(&arm_instructions);
+
+        Ok(arm_instructions
+            .into_iter()
+            .map(Self::convert_arm_instruction)
+            .collect())
+    }
+
+    fn convert_arm_instruction(arm_instruction: ArmDisassembledInstruction) -> DisassembledInstruction {
+        let annotation = arm_instruction.get_interworking_annotation();
+
+        DisassembledInstruction {
+            address: arm_instruction.address,
+            length: arm_instruction.length,
+            bytes: arm_instruction.bytes,
+            text: arm_instruction.text,
+            branch_target_address: arm_instruction.branch_target_address,
+            is_control_flow: arm_instruction.is_control_flow,
+            annotation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodeViewerInstructionSet;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_plugin_instructions_arm::{ArmInstructionMode, ArmInstructionModeMap};
+
+    #[test]
+    fn for_process_selects_x86_family_on_non_arm_hosts() {
+        assert_eq!(
+            CodeViewerInstructionSet::for_process(Some(Bitness::Bit32), false, ArmInstructionMode::Thumb),
+            CodeViewerInstructionSet::X86
+        );
+        assert_eq!(
+            CodeViewerInstructionSet::for_process(None, false, ArmInstructionMode::Arm),
+            CodeViewerInstructionSet::X64
+        );
+    }
+
+    #[test]
+    fn for_process_selects_arm_mode_on_arm_hosts() {
+        assert_eq!(
+            CodeViewerInstructionSet::for_process(Some(Bitness::Bit32), true, ArmInstructionMode::Thumb),
+            CodeViewerInstructionSet::Thumb
+        );
+        assert_eq!(
+            CodeViewerInstructionSet::for_process(Some(Bitness::Bit64), true, ArmInstructionMode::Thumb),
+            CodeViewerInstructionSet::Arm64
+        );
+    }
+
+    #[test]
+    fn disassemble_block_annotates_interworking_calls_and_records_targets() {
+        let mut arm_instruction_mode_map = ArmInstructionModeMap::new();
+        let instruction_lines = CodeViewerInstructionSet::Arm
+            .disassemble_block(&[0x02, 0x00, 0x00, 0xFA], 0x1000, &mut arm_instruction_mode_map)
+            .expect("Expected ARM block to disassemble.");
+
+        assert_eq!(instruction_lines[0].text, "blx 0x1010");
+        assert_eq!(instruction_lines[0].annotation.as_deref(), Some("→ Thumb"));
+        assert_eq!(arm_instruction_mode_map.resolve_mode(0x1010), ArmInstructionMode::Thumb);
+    }
+}
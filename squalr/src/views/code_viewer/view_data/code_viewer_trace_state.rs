@@ -0,0 +1,235 @@
+use squalr_engine_api::structures::debugging::instruction_trace::InstructionTrace;
+
+/// Tracks the instruction trace browsed in the code viewer, along with the step that is currently inspected.
+#[derive(Clone, Debug, Default)]
+pub struct CodeViewerTraceState {
+    instruction_trace: Option<InstructionTrace>,
+    current_step_index: usize,
+    error: Option<String>,
+    is_request_pending: bool,
+    pub register_query: String,
+}
+
+impl CodeViewerTraceState {
+    pub fn get_instruction_trace(&self) -> Option<&InstructionTrace> {
+        self.instruction_trace.as_ref()
+    }
+
+    pub fn get_current_step_index(&self) -> usize {
+        self.current_step_index
+    }
+
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn is_request_pending(&self) -> bool {
+        self.is_request_pending
+    }
+
+    pub fn begin_request(&mut self) {
+        *self = Self {
+            is_request_pending: true,
+            register_query: std::mem::take(&mut self.register_query),
+            ..Self::default()
+        };
+    }
+
+    /// Applies a recorded trace, returning the address of its first step so the viewer can focus it.
+    pub fn apply_trace_result(
+        &mut self,
+        instruction_trace: Option<InstructionTrace>,
+        error: Option<String>,
+    ) -> Option<u64> {
+        self.is_request_pending = false;
+        self.error = error;
+        self.current_step_index = 0;
+        self.instruction_trace = instruction_trace;
+
+        self.get_current_step_address()
+    }
+
+    /// Moves to the step that is the given number of steps away, returning its address.
+    pub fn move_step(
+        &mut self,
+        step_offset: isize,
+    ) -> Option<u64> {
+        let step_count = self.instruction_trace.as_ref()?.get_steps().len();
+
+        if step_count == 0 {
+            return None;
+        }
+
+        self.error = None;
+        self.current_step_index = self
+            .current_step_index
+            .saturating_add_signed(step_offset)
+            .min(step_count - 1);
+
+        self.get_current_step_address()
+    }
+
+    /// Moves back to the step that last wrote the queried register before the current step, returning its address. When
+    /// no earlier step wrote it, the register still held its value from the start of the trace and the step is unchanged.
+    pub fn move_to_last_register_write(&mut self) -> Option<u64> {
+        let register_name = self.register_query.trim();
+
+        if register_name.is_empty() {
+            return None;
+        }
+
+        let last_write_step_index = self
+            .instruction_trace
+            .as_ref()?
+            .find_last_write(register_name, self.current_step_index);
+
+        match last_write_step_index {
+            Some(last_write_step_index) => {
+                self.error = None;
+                self.current_step_index = last_write_step_index;
+
+                self.get_current_step_address()
+            }
+            None => {
+                self.error = Some(format!(
+                    "No traced instruction wrote {} before step {}.",
+                    register_name, self.current_step_index
+                ));
+
+                None
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Describes how often a traced instruction ran, and for the current step, which registers it wrote.
+    pub fn format_step_annotation(
+        &self,
+        address: u64,
+    ) -> Option<String> {
+        let instruction_trace = self.instruction_trace.as_ref()?;
+        let step_indices = instruction_trace.find_steps_at_address(address);
+
+        if step_indices.is_empty() {
+            return None;
+        }
+
+        let mut step_annotation = format!("; traced x{}", step_indices.len());
+
+        if step_indices.contains(&self.current_step_index) {
+            let current_step = &instruction_trace.get_steps()[self.current_step_index];
+
+            step_annotation.push_str(&format!(" [step {}]", self.current_step_index));
+
+            for register_value in current_step.get_register_writes() {
+                step_annotation.push_str(&format!(" {}=0x{:X}", register_value.get_name(), register_value.get_value()));
+            }
+        }
+
+        Some(step_annotation)
+    }
+
+    /// Describes the trace for the toolbar, or `None` when no trace is browsed.
+    pub fn format_status_text(&self) -> Option<String> {
+        if self.is_request_pending {
+            return Some(String::from("Waiting for a thread to reach the trace start..."));
+        }
+
+        let instruction_trace = match (&self.instruction_trace, &self.error) {
+            (Some(instruction_trace), _) => instruction_trace,
+            (None, Some(error)) => return Some(format!("Trace failed: {}", error)),
+            (None, None) => return None,
+        };
+        let mut status_text = format!(
+            "Trace of thread {}: step {} of {}, {}",
+            instruction_trace.get_thread_id(),
+            self.current_step_index,
+            instruction_trace.get_steps().len(),
+            instruction_trace.get_stop_reason()
+        );
+
+        if let Some(error) = &self.error {
+            status_text.push_str(&format!(" ({})", error));
+        }
+
+        Some(status_text)
+    }
+
+    fn get_current_step_address(&self) -> Option<u64> {
+        self.instruction_trace
+            .as_ref()?
+            .get_steps()
+            .get(self.current_step_index)
+            .map(|step| step.get_instruction_pointer())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodeViewerTraceState;
+    use squalr_engine_api::structures::debugging::{
+        instruction_trace::InstructionTrace, instruction_trace_stop_reason::InstructionTraceStopReason, register_snapshot::RegisterSnapshot,
+        register_value::RegisterValue, traced_instruction::TracedInstruction,
+    };
+    use squalr_plugin_instructions_x86::X64InstructionSet;
+
+    fn create_traced_instruction(
+        instruction_pointer: u64,
+        instruction_bytes: Vec<u8>,
+        rax: u64,
+    ) -> TracedInstruction {
+        let register_snapshot = RegisterSnapshot::new(
+            3,
+            instruction_pointer,
+            0x7FF0,
+            vec![
+                RegisterValue::new(String::from("rax"), rax),
+                RegisterValue::new(String::from("rcx"), 5),
+            ],
+        );
+
+        TracedInstruction::new(register_snapshot, instruction_bytes)
+    }
+
+    fn create_loop_trace() -> InstructionTrace {
+        // add rax, 1; nop; add rax, 1; nop
+        InstructionTrace::record(
+            42,
+            &X64InstructionSet::new(),
+            &[
+                create_traced_instruction(0x1000, vec![0x48, 0x83, 0xC0, 0x01], 0),
+                create_traced_instruction(0x1004, vec![0x90], 1),
+                create_traced_instruction(0x1000, vec![0x48, 0x83, 0xC0, 0x01], 1),
+                create_traced_instruction(0x1004, vec![0x90], 2),
+                create_traced_instruction(0x1005, vec![0xC3], 2),
+            ],
+            InstructionTraceStopReason::ReachedEndAddress,
+        )
+    }
+
+    #[test]
+    fn last_register_write_steps_back_to_the_instruction_that_wrote_it() {
+        let mut trace_state = CodeViewerTraceState::default();
+
+        assert_eq!(trace_state.apply_trace_result(Some(create_loop_trace()), None), Some(0x1000));
+        assert_eq!(trace_state.move_step(3), Some(0x1004));
+        assert_eq!(trace_state.move_step(8), Some(0x1004));
+        assert_eq!(trace_state.get_current_step_index(), 3);
+
+        trace_state.register_query = String::from("RAX");
+
+        assert_eq!(trace_state.move_to_last_register_write(), Some(0x1000));
+        assert_eq!(trace_state.get_current_step_index(), 2);
+        assert_eq!(trace_state.format_step_annotation(0x1000).as_deref(), Some("; traced x2 [step 2] rax=0x2"));
+        assert_eq!(trace_state.move_to_last_register_write(), Some(0x1000));
+        assert_eq!(trace_state.get_current_step_index(), 0);
+        assert_eq!(trace_state.move_to_last_register_write(), None);
+        assert_eq!(trace_state.get_current_step_index(), 0);
+        assert!(trace_state.get_error().is_some());
+        assert_eq!(trace_state.format_step_annotation(0x1004).as_deref(), Some("; traced x2"));
+        assert_eq!(trace_state.format_step_annotation(0x1005), None);
+    }
+}
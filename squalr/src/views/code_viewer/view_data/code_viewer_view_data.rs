@@ -1,17 +1,29 @@
-use crate::ui::geometry::safe_clamp_ord;
+use crate::{
+    ui::geometry::safe_clamp_ord,
+    views::code_viewer::view_data::{
+        code_viewer_breakpoint_state::CodeViewerBreakpointState, code_viewer_instruction_set::CodeViewerInstructionSet,
+        code_viewer_trace_state::CodeViewerTraceState, code_viewer_watchpoint_state::CodeViewerWatchpointState,
+    },
+};
 use eframe::egui::Pos2;
 use squalr_engine_api::{
     commands::{
+        debugger::{
+            hits::debugger_hits_request::DebuggerHitsRequest, remove_breakpoint::debugger_remove_breakpoint_request::DebuggerRemoveBreakpointRequest,
+            set_breakpoint::debugger_set_breakpoint_request::DebuggerSetBreakpointRequest, trace::debugger_trace_request::DebuggerTraceRequest,
+            unwatch::debugger_unwatch_request::DebuggerUnwatchRequest, watch::debugger_watch_request::DebuggerWatchRequest,
+        },
         memory::query::{memory_query_request::MemoryQueryRequest, memory_query_response::MemoryQueryResponse},
         privileged_command_request::PrivilegedCommandRequest,
         project_items::create::project_items_create_request::ProjectItemsCreateRequest,
     },
     dependency_injection::dependency::Dependency,
-    plugins::instruction_set::InstructionSet,
+    events::debugger::breakpoint_updated::breakpoint_updated_event::BreakpointUpdatedEvent,
     plugins::memory_view::PageRetrievalMode,
     structures::{
         data_types::{built_in_types::u8::data_type_u8::DataTypeU8, data_type_ref::DataTypeRef},
         data_values::{anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat, container_type::ContainerType},
+        debugging::{breakpoint::Breakpoint, breakpoint_mode::BreakpointMode, watchpoint_access::WatchpointAccess, watchpoint_size::WatchpointSize},
         memory::{
             address_display::{format_absolute_address, format_module_address},
             bitness::Bitness,
@@ -25,7 +37,8 @@ use squalr_engine_session::{
     engine_unprivileged_state::EngineUnprivilegedState,
     virtual_snapshots::{virtual_snapshot::VirtualSnapshot, virtual_snapshot_query::VirtualSnapshotQuery},
 };
-use squalr_plugin_instructions_x86::{DisassembledInstruction, X64InstructionSet, X86InstructionSet};
+use squalr_plugin_instructions_arm::{ArmInstructionMode, ArmInstructionModeMap};
+use squalr_plugin_instructions_x86::DisassembledInstruction;
 use std::{
     collections::{BTreeMap, HashMap, HashSet},
     ops::Range,
@@ -98,10 +111,13 @@ pub struct CodeViewerViewData {
     selected_instruction_range: Option<CodeViewerInstructionSelectionRange>,
     is_drag_selecting_instruction_range: bool,
     viewport_start_address: Option<u64>,
-    breakpoint_addresses: HashSet<u64>,
+    breakpoint_state: CodeViewerBreakpointState,
     context_menu_address: Option<u64>,
     context_menu_position: Option<Pos2>,
     instruction_edit_state: Option<CodeViewerInstructionEditState>,
+    arm_instruction_mode_map: ArmInstructionModeMap,
+    watchpoint_state: CodeViewerWatchpointState,
+    trace_state: CodeViewerTraceState,
     pub go_to_address_input: AnonymousValueString,
     pub bytes_text_splitter_ratio: f32,
     has_keyboard_focus: bool,
@@ -155,6 +171,9 @@ impl CodeViewerViewData {
     pub const DEFAULT_BYTES_TEXT_SPLITTER_RATIO: f32 = 0.32;
     pub const SNAPSHOT_REFRESH_INTERVAL: Duration = Duration::from_millis(500);
     pub const REQUEST_STALE_TIMEOUT_MS: u64 = 10_000;
+    pub const IS_ARM_TARGET: bool = cfg!(any(target_arch = "arm", target_arch = "aarch64"));
+    pub const TRACE_INSTRUCTION_BUDGET: u64 = 10_000;
+    pub const TRACE_START_TIMEOUT_MS: u64 = 30_000;
 
     pub fn new() -> Self {
         Self {
@@ -176,10 +195,13 @@ impl CodeViewerViewData {
             selected_instruction_range: None,
             is_drag_selecting_instruction_range: false,
             viewport_start_address: None,
-            breakpoint_addresses: HashSet::new(),
+            breakpoint_state: CodeViewerBreakpointState::default(),
             context_menu_address: None,
             context_menu_position: None,
             instruction_edit_state: None,
+            arm_instruction_mode_map: ArmInstructionModeMap::new(),
+            watchpoint_state: CodeViewerWatchpointState::default(),
+            trace_state: CodeViewerTraceState::default(),
             go_to_address_input: AnonymousValueString::new(String::new(), AnonymousValueStringFormat::Hexadecimal, ContainerType::None),
             bytes_text_splitter_ratio: Self::DEFAULT_BYTES_TEXT_SPLITTER_RATIO,
             has_keyboard_focus: false,
@@ -302,12 +324,334 @@ impl CodeViewerViewData {
             code_viewer_view_data.context_menu_address = None;
             code_viewer_view_data.context_menu_position = None;
             code_viewer_view_data.has_keyboard_focus = false;
+            code_viewer_view_data.arm_instruction_mode_map.clear();
+            code_viewer_view_data.trace_state.clear();
             code_viewer_view_data.complete_memory_pages_request();
         }
 
+        Self::stop_watchpoint(code_viewer_view_data.clone(), engine_unprivileged_state.clone());
+        Self::clear_breakpoints(code_viewer_view_data, engine_unprivileged_state.clone());
         engine_unprivileged_state.set_virtual_snapshot_queries(Self::WINDOW_VIRTUAL_SNAPSHOT_ID, Self::SNAPSHOT_REFRESH_INTERVAL, Vec::new());
     }
 
+    /// Places a hardware watchpoint over the start of the given range, replacing any watchpoint this viewer already owns.
+    pub fn start_watchpoint(
+        code_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        address: u64,
+        byte_count: u64,
+        watchpoint_access: WatchpointAccess,
+    ) {
+        Self::stop_watchpoint(code_viewer_view_data.clone(), engine_unprivileged_state.clone());
+
+        match code_viewer_view_data.write("Code viewer start watchpoint") {
+            Some(mut code_viewer_view_data) => code_viewer_view_data.watchpoint_state.begin_request(),
+            None => return,
+        }
+
+        let debugger_watch_request = DebuggerWatchRequest {
+            address,
+            module_name: String::new(),
+            watchpoint_size: WatchpointSize::largest_aligned_for(address, byte_count.max(1)),
+            watchpoint_access,
+        };
+        let code_viewer_view_data_for_response = code_viewer_view_data.clone();
+        let did_dispatch = debugger_watch_request.send(&engine_unprivileged_state, move |debugger_watch_response| {
+            if let Some(mut code_viewer_view_data) = code_viewer_view_data_for_response.write("Code viewer apply watchpoint") {
+                code_viewer_view_data
+                    .watchpoint_state
+                    .apply_watch_result(debugger_watch_response.watchpoint, debugger_watch_response.error);
+            }
+        });
+
+        if !did_dispatch {
+            if let Some(mut code_viewer_view_data) = code_viewer_view_data.write("Code viewer watchpoint dispatch failure") {
+                code_viewer_view_data
+                    .watchpoint_state
+                    .apply_watch_result(None, Some(String::from("Failed to dispatch the watchpoint request.")));
+            }
+        }
+    }
+
+    pub fn stop_watchpoint(
+        code_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let watchpoint = match code_viewer_view_data.write("Code viewer stop watchpoint") {
+            Some(mut code_viewer_view_data) => code_viewer_view_data.watchpoint_state.clear(),
+            None => return,
+        };
+
+        if let Some(watchpoint) = watchpoint {
+            let debugger_unwatch_request = DebuggerUnwatchRequest {
+                watchpoint_id: watchpoint.get_watchpoint_id(),
+            };
+
+            debugger_unwatch_request.send(&engine_unprivileged_state, |_debugger_unwatch_response| {});
+        }
+    }
+
+    /// Collects new hits for the active watchpoint, focusing the viewer on the first instruction that touches the address.
+    pub fn poll_watchpoint_hits(
+        code_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let watchpoint_id = match code_viewer_view_data.write("Code viewer poll watchpoint hits") {
+            Some(mut code_viewer_view_data) => code_viewer_view_data
+                .watchpoint_state
+                .try_begin_poll(Instant::now(), Self::SNAPSHOT_REFRESH_INTERVAL),
+            None => return,
+        };
+        let Some(watchpoint_id) = watchpoint_id else {
+            return;
+        };
+        let debugger_hits_request = DebuggerHitsRequest { watchpoint_id };
+        let code_viewer_view_data_for_response = code_viewer_view_data.clone();
+        let engine_unprivileged_state_for_response = engine_unprivileged_state.clone();
+        let did_dispatch = debugger_hits_request.send(&engine_unprivileged_state, move |debugger_hits_response| {
+            let focus_address = match code_viewer_view_data_for_response.write("Code viewer apply watchpoint hits") {
+                Some(mut code_viewer_view_data) => {
+                    // Ignore responses for a watchpoint that was replaced while the poll was in flight.
+                    if code_viewer_view_data
+                        .watchpoint_state
+                        .get_watchpoint()
+                        .map(|watchpoint| watchpoint.get_watchpoint_id())
+                        != Some(watchpoint_id)
+                    {
+                        return;
+                    }
+
+                    code_viewer_view_data
+                        .watchpoint_state
+                        .apply_hits(debugger_hits_response.watchpoint_hits, debugger_hits_response.error)
+                }
+                None => return,
+            };
+
+            if let Some(focus_address) = focus_address {
+                Self::request_focus_address(
+                    code_viewer_view_data_for_response,
+                    engine_unprivileged_state_for_response,
+                    focus_address,
+                    String::new(),
+                );
+            }
+        });
+
+        if !did_dispatch {
+            if let Some(mut code_viewer_view_data) = code_viewer_view_data.write("Code viewer watchpoint hits dispatch failure") {
+                code_viewer_view_data
+                    .watchpoint_state
+                    .fail_request(String::from("Failed to dispatch the watchpoint hits request."));
+            }
+        }
+    }
+
+    /// Appends watchpoint hit counts to the annotations of the instructions that touched the watched address.
+    pub fn annotate_watchpoint_hits(
+        code_viewer_view_data: Dependency<Self>,
+        instruction_lines: &mut [DisassembledInstruction],
+    ) {
+        let Some(code_viewer_view_data) = code_viewer_view_data.read("Code viewer annotate watchpoint hits") else {
+            return;
+        };
+        let Some(watchpoint) = code_viewer_view_data.watchpoint_state.get_watchpoint() else {
+            return;
+        };
+        let access_label = Self::format_watchpoint_access_plural(watchpoint.get_watchpoint_access());
+        let hit_counts_by_instruction_address = code_viewer_view_data
+            .watchpoint_state
+            .map_hit_counts_to_instructions(instruction_lines);
+
+        for instruction_line in instruction_lines.iter_mut() {
+            if let Some(hit_count) = hit_counts_by_instruction_address.get(&instruction_line.address) {
+                let hit_annotation = format!("; {} x{}", access_label, hit_count);
+
+                instruction_line.annotation = Some(match instruction_line.annotation.take() {
+                    Some(annotation) => format!("{} {}", annotation, hit_annotation),
+                    None => hit_annotation,
+                });
+            }
+        }
+    }
+
+    /// Describes the active watchpoint for the toolbar, or `None` when nothing is being watched.
+    pub fn get_watchpoint_status_text(code_viewer_view_data: Dependency<Self>) -> Option<String> {
+        let code_viewer_view_data = code_viewer_view_data.read("Code viewer watchpoint status")?;
+        let watchpoint_state = &code_viewer_view_data.watchpoint_state;
+
+        if let Some(error) = watchpoint_state.get_error() {
+            return Some(format!("Watchpoint failed: {}", error));
+        }
+
+        match watchpoint_state.get_watchpoint() {
+            Some(watchpoint) => Some(format!(
+                "Watching {} ({}): {} hits",
+                format_absolute_address(watchpoint.get_address()),
+                Self::format_watchpoint_access_plural(watchpoint.get_watchpoint_access()),
+                watchpoint_state.get_total_hit_count()
+            )),
+            None if watchpoint_state.is_request_pending() => Some(String::from("Placing watchpoint...")),
+            None => None,
+        }
+    }
+
+    pub fn format_watchpoint_access_plural(watchpoint_access: WatchpointAccess) -> &'static str {
+        match watchpoint_access {
+            WatchpointAccess::Write => "writes",
+            WatchpointAccess::ReadWrite => "accesses",
+        }
+    }
+
+    pub fn get_watchpoint_access(code_viewer_view_data: Dependency<Self>) -> Option<WatchpointAccess> {
+        code_viewer_view_data
+            .read("Code viewer watchpoint access")
+            .and_then(|code_viewer_view_data| {
+                code_viewer_view_data
+                    .watchpoint_state
+                    .get_watchpoint()
+                    .map(|watchpoint| watchpoint.get_watchpoint_access())
+            })
+    }
+
+    /// Records the instructions that the next thread to reach the start address executes, up to the end address if given.
+    pub fn start_trace(
+        code_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        start_address: u64,
+        end_address: Option<u64>,
+    ) {
+        match code_viewer_view_data.write("Code viewer start trace") {
+            Some(mut code_viewer_view_data) => code_viewer_view_data.trace_state.begin_request(),
+            None => return,
+        }
+
+        let debugger_trace_request = DebuggerTraceRequest {
+            start_address,
+            module_name: String::new(),
+            end_address,
+            thread_id: None,
+            instruction_budget: Self::TRACE_INSTRUCTION_BUDGET,
+            start_timeout_ms: Self::TRACE_START_TIMEOUT_MS,
+            instruction_set_id: String::new(),
+            trace_file_path: None,
+        };
+        let code_viewer_view_data_for_response = code_viewer_view_data.clone();
+        let engine_unprivileged_state_for_response = engine_unprivileged_state.clone();
+        let did_dispatch = debugger_trace_request.send(&engine_unprivileged_state, move |debugger_trace_response| {
+            let focus_address = match code_viewer_view_data_for_response.write("Code viewer apply trace") {
+                Some(mut code_viewer_view_data) => {
+                    // Ignore traces that finished after the viewer was cleared.
+                    if !code_viewer_view_data.trace_state.is_request_pending() {
+                        return;
+                    }
+
+                    code_viewer_view_data
+                        .trace_state
+                        .apply_trace_result(debugger_trace_response.instruction_trace, debugger_trace_response.error)
+                }
+                None => return,
+            };
+
+            if let Some(focus_address) = focus_address {
+                Self::request_focus_address(
+                    code_viewer_view_data_for_response,
+                    engine_unprivileged_state_for_response,
+                    focus_address,
+                    String::new(),
+                );
+            }
+        });
+
+        if !did_dispatch {
+            if let Some(mut code_viewer_view_data) = code_viewer_view_data.write("Code viewer trace dispatch failure") {
+                code_viewer_view_data
+                    .trace_state
+                    .apply_trace_result(None, Some(String::from("Failed to dispatch the trace request.")));
+            }
+        }
+    }
+
+    pub fn clear_trace(code_viewer_view_data: Dependency<Self>) {
+        if let Some(mut code_viewer_view_data) = code_viewer_view_data.write("Code viewer clear trace") {
+            code_viewer_view_data.trace_state.clear();
+        }
+    }
+
+    /// Steps through the browsed trace, focusing the instruction of the new step.
+    pub fn move_trace_step(
+        code_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        step_offset: isize,
+    ) {
+        let focus_address = match code_viewer_view_data.write("Code viewer move trace step") {
+            Some(mut code_viewer_view_data) => code_viewer_view_data.trace_state.move_step(step_offset),
+            None => return,
+        };
+
+        if let Some(focus_address) = focus_address {
+            Self::request_focus_address(code_viewer_view_data, engine_unprivileged_state, focus_address, String::new());
+        }
+    }
+
+    /// Steps back to the instruction that last wrote the queried register, focusing it.
+    pub fn find_last_register_write(
+        code_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let focus_address = match code_viewer_view_data.write("Code viewer find last register write") {
+            Some(mut code_viewer_view_data) => code_viewer_view_data.trace_state.move_to_last_register_write(),
+            None => return,
+        };
+
+        if let Some(focus_address) = focus_address {
+            Self::request_focus_address(code_viewer_view_data, engine_unprivileged_state, focus_address, String::new());
+        }
+    }
+
+    /// Appends the execution count of each traced instruction, and the register writes of the current step.
+    pub fn annotate_trace_steps(
+        code_viewer_view_data: Dependency<Self>,
+        instruction_lines: &mut [DisassembledInstruction],
+    ) {
+        let Some(code_viewer_view_data) = code_viewer_view_data.read("Code viewer annotate trace steps") else {
+            return;
+        };
+
+        for instruction_line in instruction_lines.iter_mut() {
+            if let Some(step_annotation) = code_viewer_view_data
+                .trace_state
+                .format_step_annotation(instruction_line.address)
+            {
+                instruction_line.annotation = Some(match instruction_line.annotation.take() {
+                    Some(annotation) => format!("{} {}", annotation, step_annotation),
+                    None => step_annotation,
+                });
+            }
+        }
+    }
+
+    pub fn get_trace_status_text(code_viewer_view_data: Dependency<Self>) -> Option<String> {
+        code_viewer_view_data
+            .read("Code viewer trace status")
+            .and_then(|code_viewer_view_data| code_viewer_view_data.trace_state.format_status_text())
+    }
+
+    pub fn get_trace_register_query_mut(&mut self) -> &mut String {
+        &mut self.trace_state.register_query
+    }
+
+    pub fn has_instruction_trace(code_viewer_view_data: Dependency<Self>) -> bool {
+        code_viewer_view_data
+            .read("Code viewer has instruction trace")
+            .is_some_and(|code_viewer_view_data| {
+                code_viewer_view_data
+                    .trace_state
+                    .get_instruction_trace()
+                    .is_some()
+            })
+    }
+
     pub fn navigate_first_page(code_viewer_view_data: Dependency<Self>) {
         Self::set_page_index(code_viewer_view_data, 0);
     }
@@ -542,14 +886,30 @@ impl CodeViewerViewData {
             return Vec::new();
         }
 
-        let decode_result = match process_bitness.unwrap_or(Bitness::Bit64) {
-            Bitness::Bit32 => X86InstructionSet::new().disassemble_block(&cached_bytes, decode_start_address),
-            Bitness::Bit64 => X64InstructionSet::new().disassemble_block(&cached_bytes, decode_start_address),
-        };
-
-        let Ok(decoded_instructions) = decode_result else {
+        let Some(mut code_viewer_view_data) = code_viewer_view_data.write("Code viewer decode instruction lines") else {
             return Vec::new();
         };
+        let decode_end_address_exclusive = decode_start_address.saturating_add(cached_bytes.len() as u64);
+        let instruction_set_segments =
+            code_viewer_view_data.resolve_instruction_set_segments(process_bitness, decode_start_address..decode_end_address_exclusive);
+        let mut decoded_instructions = Vec::new();
+
+        // Each segment decodes independently, since ARM and Thumb code interleave without any marker in the bytes.
+        for (segment_address_range, instruction_set) in instruction_set_segments {
+            let segment_start_offset = segment_address_range.start.saturating_sub(decode_start_address) as usize;
+            let segment_end_offset = segment_address_range.end.saturating_sub(decode_start_address) as usize;
+            let decode_result = instruction_set.disassemble_block(
+                &cached_bytes[segment_start_offset..segment_end_offset],
+                segment_address_range.start,
+                &mut code_viewer_view_data.arm_instruction_mode_map,
+            );
+
+            let Ok(segment_instructions) = decode_result else {
+                return Vec::new();
+            };
+
+            decoded_instructions.extend(segment_instructions);
+        }
 
         decoded_instructions
             .into_iter()
@@ -557,6 +917,27 @@ impl CodeViewerViewData {
             .collect()
     }
 
+    /// Resolves the instruction set used for the current edit, selection, or viewport, in that order of priority.
+    pub fn get_active_instruction_set(
+        code_viewer_view_data: Dependency<Self>,
+        process_bitness: Option<Bitness>,
+    ) -> CodeViewerInstructionSet {
+        code_viewer_view_data
+            .read("Code viewer active instruction set")
+            .map(|code_viewer_view_data| {
+                let active_address = code_viewer_view_data
+                    .instruction_edit_state
+                    .as_ref()
+                    .map(|instruction_edit_state| instruction_edit_state.start_address)
+                    .or_else(|| code_viewer_view_data.resolve_selected_instruction_address())
+                    .or(code_viewer_view_data.viewport_start_address)
+                    .unwrap_or_default();
+
+                code_viewer_view_data.resolve_instruction_set(process_bitness, active_address)
+            })
+            .unwrap_or_else(|| CodeViewerInstructionSet::for_process(process_bitness, Self::IS_ARM_TARGET, ArmInstructionMode::Arm))
+    }
+
     pub fn take_pending_scroll_address(code_viewer_view_data: Dependency<Self>) -> Option<u64> {
         let mut code_viewer_view_data = code_viewer_view_data.write("Code viewer take pending scroll address")?;
         code_viewer_view_data.pending_scroll_address.take()
@@ -786,10 +1167,12 @@ impl CodeViewerViewData {
         let Some(mut code_viewer_view_data) = code_viewer_view_data.write("Code viewer evaluate instruction edit commit") else {
             return None;
         };
+        let instruction_set = code_viewer_view_data
+            .resolve_instruction_edit_instruction_set(process_bitness)?
+            .create_instruction_set();
         let Some(instruction_edit_state) = code_viewer_view_data.instruction_edit_state.as_mut() else {
             return None;
         };
-        let instruction_set = Self::create_instruction_set_for_process_bitness(process_bitness);
         let assembled_bytes = match instruction_set.assemble(
             instruction_edit_state
                 .edit_value
@@ -838,6 +1221,9 @@ impl CodeViewerViewData {
         let Some(mut code_viewer_view_data) = code_viewer_view_data.write("Code viewer accept instruction fill with nops") else {
             return None;
         };
+        let instruction_set = code_viewer_view_data
+            .resolve_instruction_edit_instruction_set(process_bitness)?
+            .create_instruction_set();
         let Some(instruction_edit_state) = code_viewer_view_data.instruction_edit_state.as_mut() else {
             return None;
         };
@@ -848,7 +1234,6 @@ impl CodeViewerViewData {
         else {
             return None;
         };
-        let instruction_set = Self::create_instruction_set_for_process_bitness(process_bitness);
         let nop_fill_bytes = match instruction_set.build_no_operation_fill(remaining_byte_count) {
             Ok(nop_fill_bytes) => nop_fill_bytes,
             Err(error) => {
@@ -926,7 +1311,9 @@ impl CodeViewerViewData {
                     .map(|instruction_line| (instruction_line.address, (instruction_line.length as u64).max(1)))
             })?;
         let (project_item_address, project_item_module_name) = code_viewer_view_data.resolve_project_item_address(selection_start_address);
-        let instruction_data_type_id = Self::instruction_data_type_id_for_process_bitness(process_bitness);
+        let instruction_data_type_id = code_viewer_view_data
+            .resolve_instruction_set(process_bitness, selection_start_address)
+            .get_data_type_id();
         let resolved_data_type_id = if selected_byte_count > 1 {
             format!("{}[{}]", instruction_data_type_id, selected_byte_count)
         } else {
@@ -941,28 +1328,155 @@ impl CodeViewerViewData {
             module_name: Some(project_item_module_name),
             data_type_id: Some(resolved_data_type_id),
             pointer_offsets: None,
+            project_item_type_id: None,
+            code_patch: None,
         })
     }
 
+    /// Sets a trace breakpoint at the address, or removes the breakpoint already there.
     pub fn toggle_breakpoint_address(
         code_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
         address: u64,
     ) {
-        if let Some(mut code_viewer_view_data) = code_viewer_view_data.write("Code viewer toggle breakpoint") {
-            if !code_viewer_view_data.breakpoint_addresses.insert(address) {
-                code_viewer_view_data.breakpoint_addresses.remove(&address);
+        let existing_breakpoint = match code_viewer_view_data.write("Code viewer toggle breakpoint") {
+            Some(mut code_viewer_view_data) => {
+                if !code_viewer_view_data.breakpoint_state.begin_request(address) {
+                    return;
+                }
+
+                code_viewer_view_data
+                    .breakpoint_state
+                    .get_breakpoint(address)
+                    .cloned()
+            }
+            None => return,
+        };
+        let code_viewer_view_data_for_response = code_viewer_view_data.clone();
+        let did_dispatch = match existing_breakpoint {
+            Some(breakpoint) => {
+                let debugger_remove_breakpoint_request = DebuggerRemoveBreakpointRequest {
+                    breakpoint_id: breakpoint.get_breakpoint_id(),
+                };
+
+                debugger_remove_breakpoint_request.send(&engine_unprivileged_state, move |debugger_remove_breakpoint_response| {
+                    if let Some(mut code_viewer_view_data) = code_viewer_view_data_for_response.write("Code viewer apply breakpoint removal") {
+                        code_viewer_view_data
+                            .breakpoint_state
+                            .apply_remove_result(address, debugger_remove_breakpoint_response.error);
+                    }
+                })
+            }
+            None => {
+                let debugger_set_breakpoint_request = DebuggerSetBreakpointRequest {
+                    address,
+                    module_name: String::new(),
+                    breakpoint_mode: BreakpointMode::default(),
+                };
+
+                debugger_set_breakpoint_request.send(&engine_unprivileged_state, move |debugger_set_breakpoint_response| {
+                    if let Some(mut code_viewer_view_data) = code_viewer_view_data_for_response.write("Code viewer apply breakpoint") {
+                        code_viewer_view_data.breakpoint_state.apply_set_result(
+                            address,
+                            debugger_set_breakpoint_response.breakpoint,
+                            debugger_set_breakpoint_response.error,
+                        );
+                    }
+                })
             }
+        };
+
+        if !did_dispatch && let Some(mut code_viewer_view_data) = code_viewer_view_data.write("Code viewer breakpoint dispatch failure") {
+            code_viewer_view_data
+                .breakpoint_state
+                .apply_set_result(address, None, Some(String::from("Failed to dispatch the breakpoint request.")));
         }
     }
 
-    pub fn has_breakpoint_address(
+    pub fn get_breakpoint_at_address(
         code_viewer_view_data: Dependency<Self>,
         address: u64,
-    ) -> bool {
+    ) -> Option<Breakpoint> {
         code_viewer_view_data
             .read("Code viewer breakpoint lookup")
-            .map(|code_viewer_view_data| code_viewer_view_data.breakpoint_addresses.contains(&address))
-            .unwrap_or(false)
+            .and_then(|code_viewer_view_data| {
+                code_viewer_view_data
+                    .breakpoint_state
+                    .get_breakpoint(address)
+                    .cloned()
+            })
+    }
+
+    pub fn get_breakpoint_tooltip(
+        code_viewer_view_data: Dependency<Self>,
+        address: u64,
+    ) -> Option<String> {
+        code_viewer_view_data
+            .read("Code viewer breakpoint tooltip")
+            .and_then(|code_viewer_view_data| code_viewer_view_data.breakpoint_state.format_tooltip(address))
+    }
+
+    pub fn get_breakpoint_error_text(code_viewer_view_data: Dependency<Self>) -> Option<String> {
+        code_viewer_view_data
+            .read("Code viewer breakpoint error")
+            .and_then(|code_viewer_view_data| {
+                code_viewer_view_data
+                    .breakpoint_state
+                    .get_error()
+                    .map(|error| format!("Breakpoint failed: {}", error))
+            })
+    }
+
+    pub fn apply_breakpoint_updated_event(
+        code_viewer_view_data: Dependency<Self>,
+        breakpoint_updated_event: &BreakpointUpdatedEvent,
+    ) {
+        if let Some(mut code_viewer_view_data) = code_viewer_view_data.write("Code viewer apply breakpoint update") {
+            code_viewer_view_data
+                .breakpoint_state
+                .apply_event(breakpoint_updated_event);
+        }
+    }
+
+    /// Removes every breakpoint shown in the viewer from the engine.
+    pub fn clear_breakpoints(
+        code_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let breakpoints = match code_viewer_view_data.write("Code viewer clear breakpoints") {
+            Some(mut code_viewer_view_data) => code_viewer_view_data.breakpoint_state.clear(),
+            None => return,
+        };
+
+        for breakpoint in breakpoints {
+            let debugger_remove_breakpoint_request = DebuggerRemoveBreakpointRequest {
+                breakpoint_id: breakpoint.get_breakpoint_id(),
+            };
+
+            debugger_remove_breakpoint_request.send(&engine_unprivileged_state, |_debugger_remove_breakpoint_response| {});
+        }
+    }
+
+    /// Appends the hit count of each breakpoint to its instruction.
+    pub fn annotate_breakpoint_hits(
+        code_viewer_view_data: Dependency<Self>,
+        instruction_lines: &mut [DisassembledInstruction],
+    ) {
+        let Some(code_viewer_view_data) = code_viewer_view_data.read("Code viewer annotate breakpoint hits") else {
+            return;
+        };
+
+        for instruction_line in instruction_lines.iter_mut() {
+            if let Some(hit_annotation) = code_viewer_view_data
+                .breakpoint_state
+                .format_hit_annotation(instruction_line.address)
+            {
+                instruction_line.annotation = Some(match instruction_line.annotation.take() {
+                    Some(annotation) => format!("{} {}", annotation, hit_annotation),
+                    None => hit_annotation,
+                });
+            }
+        }
     }
 
     pub fn clear_selection(code_viewer_view_data: Dependency<Self>) {
@@ -1087,17 +1601,51 @@ impl CodeViewerViewData {
         }
     }
 
-    fn instruction_data_type_id_for_process_bitness(process_bitness: Option<Bitness>) -> &'static str {
-        match process_bitness.unwrap_or(Bitness::Bit64) {
-            Bitness::Bit32 => "i_x86",
-            Bitness::Bit64 => "i_x64",
-        }
+    fn resolve_instruction_set(
+        &self,
+        process_bitness: Option<Bitness>,
+        address: u64,
+    ) -> CodeViewerInstructionSet {
+        CodeViewerInstructionSet::for_process(process_bitness, Self::IS_ARM_TARGET, self.arm_instruction_mode_map.resolve_mode(address))
     }
 
-    fn create_instruction_set_for_process_bitness(process_bitness: Option<Bitness>) -> Box<dyn InstructionSet> {
-        match process_bitness.unwrap_or(Bitness::Bit64) {
-            Bitness::Bit32 => Box::new(X86InstructionSet::new()),
-            Bitness::Bit64 => Box::new(X64InstructionSet::new()),
+    fn resolve_instruction_edit_instruction_set(
+        &self,
+        process_bitness: Option<Bitness>,
+    ) -> Option<CodeViewerInstructionSet> {
+        self.instruction_edit_state
+            .as_ref()
+            .map(|instruction_edit_state| self.resolve_instruction_set(process_bitness, instruction_edit_state.start_address))
+    }
+
+    fn resolve_instruction_set_segments(
+        &self,
+        process_bitness: Option<Bitness>,
+        address_range: Range<u64>,
+    ) -> Vec<(Range<u64>, CodeViewerInstructionSet)> {
+        self.arm_instruction_mode_map
+            .resolve_mode_segments(address_range)
+            .into_iter()
+            .map(|(segment_address_range, instruction_mode)| {
+                (
+                    segment_address_range,
+                    CodeViewerInstructionSet::for_process(process_bitness, Self::IS_ARM_TARGET, instruction_mode),
+                )
+            })
+            .collect()
+    }
+
+    /// On ARM targets, code addresses from symbols and pointers may carry the Thumb interworking bit. The bit is
+    /// recorded as a mode hint and stripped so the viewer lands on the instruction itself.
+    fn resolve_interworking_address(
+        &mut self,
+        address: u64,
+    ) -> u64 {
+        if Self::IS_ARM_TARGET {
+            self.arm_instruction_mode_map
+                .record_interworking_address(address)
+        } else {
+            address
         }
     }
 
@@ -1324,6 +1872,7 @@ impl CodeViewerViewData {
 
             return false;
         };
+        let focus_address = self.resolve_interworking_address(focus_address);
 
         let Some((page_index, resolved_address)) = Self::resolve_nearest_page_index_and_address(&self.virtual_pages, focus_address) else {
             self.pending_focus_request = None;
@@ -1357,6 +1906,7 @@ impl CodeViewerViewData {
         &mut self,
         target_address: u64,
     ) -> Option<u64> {
+        let target_address = self.resolve_interworking_address(target_address);
         let (page_index, resolved_address) = Self::resolve_nearest_page_index_and_address(&self.virtual_pages, target_address)?;
         self.current_page_index = page_index.min(self.cached_last_page_index);
 
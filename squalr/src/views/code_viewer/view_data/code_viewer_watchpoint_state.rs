@@ -0,0 +1,205 @@
+use squalr_engine_api::structures::debugging::{watchpoint::Watchpoint, watchpoint_hit::WatchpointHit};
+use squalr_plugin_instructions_x86::DisassembledInstruction;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+/// Tracks the "find what writes / accesses" watchpoint whose hits are shown in the code viewer.
+#[derive(Clone, Debug, Default)]
+pub struct CodeViewerWatchpointState {
+    watchpoint: Option<Watchpoint>,
+    watchpoint_hits: Vec<WatchpointHit>,
+    error: Option<String>,
+    is_request_pending: bool,
+    last_polled_at: Option<Instant>,
+    has_focused_first_hit: bool,
+}
+
+impl CodeViewerWatchpointState {
+    pub fn get_watchpoint(&self) -> Option<&Watchpoint> {
+        self.watchpoint.as_ref()
+    }
+
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn is_request_pending(&self) -> bool {
+        self.is_request_pending
+    }
+
+    pub fn get_total_hit_count(&self) -> u64 {
+        self.watchpoint_hits
+            .iter()
+            .map(WatchpointHit::get_hit_count)
+            .sum()
+    }
+
+    pub fn begin_request(&mut self) {
+        self.is_request_pending = true;
+    }
+
+    /// Marks a hit poll as started if a watchpoint is active and the poll interval has elapsed.
+    pub fn try_begin_poll(
+        &mut self,
+        now: Instant,
+        poll_interval: Duration,
+    ) -> Option<u64> {
+        let watchpoint_id = self.watchpoint.as_ref()?.get_watchpoint_id();
+
+        if self.is_request_pending
+            || self
+                .last_polled_at
+                .is_some_and(|last_polled_at| now.duration_since(last_polled_at) < poll_interval)
+        {
+            return None;
+        }
+
+        self.is_request_pending = true;
+        self.last_polled_at = Some(now);
+
+        Some(watchpoint_id)
+    }
+
+    pub fn apply_watch_result(
+        &mut self,
+        watchpoint: Option<Watchpoint>,
+        error: Option<String>,
+    ) {
+        *self = Self {
+            watchpoint,
+            error,
+            ..Self::default()
+        };
+    }
+
+    /// Applies polled hits, returning the trap address of the first hit if the viewer has not been focused on one yet.
+    pub fn apply_hits(
+        &mut self,
+        watchpoint_hits: Vec<WatchpointHit>,
+        error: Option<String>,
+    ) -> Option<u64> {
+        self.is_request_pending = false;
+        self.error = error;
+
+        if self.error.is_some() {
+            return None;
+        }
+
+        self.watchpoint_hits = watchpoint_hits;
+
+        if self.has_focused_first_hit {
+            return None;
+        }
+
+        let first_hit = self
+            .watchpoint_hits
+            .iter()
+            .max_by_key(|watchpoint_hit| watchpoint_hit.get_hit_count())?;
+        self.has_focused_first_hit = true;
+
+        Some(first_hit.get_trap_address())
+    }
+
+    pub fn fail_request(
+        &mut self,
+        error: String,
+    ) {
+        self.is_request_pending = false;
+        self.error = Some(error);
+    }
+
+    pub fn clear(&mut self) -> Option<Watchpoint> {
+        std::mem::take(self).watchpoint
+    }
+
+    /// Resolves the instruction that performed the access. x86 reports the address after the accessing instruction, so
+    /// the hit belongs to the decoded instruction that ends at the trap address.
+    pub fn resolve_accessing_instruction_address(
+        watchpoint_hit: &WatchpointHit,
+        instruction_lines: &[DisassembledInstruction],
+    ) -> Option<u64> {
+        let trap_address = watchpoint_hit.get_trap_address();
+
+        if !watchpoint_hit.get_is_trap_after_access() {
+            return instruction_lines
+                .iter()
+                .find(|instruction_line| instruction_line.address == trap_address)
+                .map(|instruction_line| instruction_line.address);
+        }
+
+        instruction_lines
+            .iter()
+            .find(|instruction_line| {
+                instruction_line
+                    .address
+                    .saturating_add(instruction_line.length as u64)
+                    == trap_address
+            })
+            .map(|instruction_line| instruction_line.address)
+    }
+
+    /// Maps hit counts onto the decoded instructions that performed the accesses.
+    pub fn map_hit_counts_to_instructions(
+        &self,
+        instruction_lines: &[DisassembledInstruction],
+    ) -> HashMap<u64, u64> {
+        let mut hit_counts_by_instruction_address = HashMap::new();
+
+        for watchpoint_hit in &self.watchpoint_hits {
+            if let Some(instruction_address) = Self::resolve_accessing_instruction_address(watchpoint_hit, instruction_lines) {
+                *hit_counts_by_instruction_address
+                    .entry(instruction_address)
+                    .or_insert(0) += watchpoint_hit.get_hit_count();
+            }
+        }
+
+        hit_counts_by_instruction_address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodeViewerWatchpointState;
+    use squalr_engine_api::structures::debugging::{register_snapshot::RegisterSnapshot, watchpoint_hit::WatchpointHit};
+    use squalr_plugin_instructions_x86::DisassembledInstruction;
+
+    fn create_instruction_line(
+        address: u64,
+        length: usize,
+    ) -> DisassembledInstruction {
+        DisassembledInstruction {
+            address,
+            length,
+            bytes: vec![0x90; length],
+            text: String::from("nop"),
+            branch_target_address: None,
+            is_control_flow: false,
+            annotation: None,
+        }
+    }
+
+    #[test]
+    fn trap_after_hits_map_to_the_instruction_ending_at_the_trap_address() {
+        let instruction_lines = vec![
+            create_instruction_line(0x1000, 3),
+            create_instruction_line(0x1003, 6),
+            create_instruction_line(0x1009, 1),
+        ];
+        let mut watchpoint_state = CodeViewerWatchpointState::default();
+        let focus_address = watchpoint_state.apply_hits(
+            vec![
+                WatchpointHit::new(0x1009, true, 5, RegisterSnapshot::default()),
+                WatchpointHit::new(0x1003, false, 2, RegisterSnapshot::default()),
+            ],
+            None,
+        );
+        let hit_counts_by_instruction_address = watchpoint_state.map_hit_counts_to_instructions(&instruction_lines);
+
+        assert_eq!(focus_address, Some(0x1009));
+        assert_eq!(hit_counts_by_instruction_address.get(&0x1003), Some(&7));
+        assert_eq!(watchpoint_state.get_total_hit_count(), 7);
+        assert_eq!(watchpoint_state.apply_hits(Vec::new(), None), None);
+    }
+}
@@ -1 +1,5 @@
+pub mod code_viewer_breakpoint_state;
+pub mod code_viewer_instruction_set;
+pub mod code_viewer_trace_state;
 pub mod code_viewer_view_data;
+pub mod code_viewer_watchpoint_state;
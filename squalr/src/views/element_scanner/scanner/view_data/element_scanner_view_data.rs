@@ -220,6 +220,7 @@ impl ElementScannerViewData {
         let element_scan_request = ElementScanRequest {
             scan_constraints,
             data_type_refs,
+            value_transform: None,
         };
 
         element_scanner_view_data.view_state = ElementScannerViewState::ScanInProgress;
@@ -9,6 +9,7 @@ use crate::{
             toolbar_menu::toolbar_menu_item_view::ToolbarMenuItemView,
         },
     },
+    views::code_viewer::{code_viewer_view::CodeViewerView, view_data::code_viewer_view_data::CodeViewerViewData},
     views::memory_viewer::{
         memory_viewer_footer_view::MemoryViewerFooterView,
         memory_viewer_interpretation_panel_view::MemoryViewerInterpretationPanelView,
@@ -27,7 +28,10 @@ use squalr_engine_api::{
     },
     dependency_injection::dependency::Dependency,
     events::process::changed::process_changed_event::ProcessChangedEvent,
-    structures::data_types::{built_in_types::u64::data_type_u64::DataTypeU64, data_type_ref::DataTypeRef},
+    structures::{
+        data_types::{built_in_types::u64::data_type_u64::DataTypeU64, data_type_ref::DataTypeRef},
+        debugging::watchpoint_access::WatchpointAccess,
+    },
 };
 use std::{sync::Arc, time::Duration};
 
@@ -36,6 +40,7 @@ pub struct MemoryViewerView {
     app_context: Arc<AppContext>,
     memory_viewer_view_data: Dependency<MemoryViewerViewData>,
     project_hierarchy_view_data: Dependency<ProjectHierarchyViewData>,
+    code_viewer_view_data: Dependency<CodeViewerViewData>,
     memory_viewer_footer_view: MemoryViewerFooterView,
     memory_viewer_interpretation_panel_view: MemoryViewerInterpretationPanelView,
 }
@@ -85,12 +90,16 @@ impl MemoryViewerView {
         let project_hierarchy_view_data = app_context
             .dependency_container
             .get_dependency::<ProjectHierarchyViewData>();
+        let code_viewer_view_data = app_context
+            .dependency_container
+            .get_dependency::<CodeViewerViewData>();
         let instance = Self {
             memory_viewer_footer_view: MemoryViewerFooterView::new(app_context.clone()),
             memory_viewer_interpretation_panel_view: MemoryViewerInterpretationPanelView::new(app_context.clone()),
             app_context,
             memory_viewer_view_data,
             project_hierarchy_view_data,
+            code_viewer_view_data,
         };
 
         instance.listen_for_process_change();
@@ -411,6 +420,7 @@ impl MemoryViewerView {
             address: write_start_address,
             module_name: String::new(),
             value: written_bytes,
+            value_transform: None,
         };
 
         memory_write_request.send(&engine_unprivileged_state, move |memory_write_response| {
@@ -442,6 +452,48 @@ impl MemoryViewerView {
         });
     }
 
+    /// Watches the selection (or the clicked byte) and opens the code viewer, which lists the instructions that touch it.
+    fn dispatch_find_what_accesses(
+        &self,
+        context_menu_address: u64,
+        watchpoint_access: WatchpointAccess,
+    ) {
+        let (watch_address, watch_byte_count) = match MemoryViewerViewData::get_selected_address_bounds(self.memory_viewer_view_data.clone()) {
+            Some((selection_start_address, selection_end_address))
+                if context_menu_address >= selection_start_address && context_menu_address <= selection_end_address =>
+            {
+                (
+                    selection_start_address,
+                    selection_end_address
+                        .saturating_sub(selection_start_address)
+                        .saturating_add(1),
+                )
+            }
+            _ => (context_menu_address, 1),
+        };
+
+        CodeViewerViewData::start_watchpoint(
+            self.code_viewer_view_data.clone(),
+            self.app_context.engine_unprivileged_state.clone(),
+            watch_address,
+            watch_byte_count,
+            watchpoint_access,
+        );
+
+        match self.app_context.docking_manager.write() {
+            Ok(mut docking_manager) => {
+                docking_manager.set_window_visibility(CodeViewerView::WINDOW_ID, true);
+                docking_manager.select_tab_by_window_id(CodeViewerView::WINDOW_ID);
+            }
+            Err(error) => {
+                log::error!(
+                    "Failed to acquire docking manager while opening the code viewer from the Memory Viewer: {}",
+                    error
+                );
+            }
+        }
+    }
+
     fn build_context_menu_add_label(
         &self,
         context_menu_address: u64,
@@ -1156,6 +1208,33 @@ impl Widget for MemoryViewerView {
                                 self.dispatch_add_address_to_project(context_menu_address);
                                 *should_close = true;
                             }
+
+                            for (watchpoint_access, label, item_id) in [
+                                (
+                                    WatchpointAccess::Write,
+                                    "Find What Writes to This Address",
+                                    "memory_viewer_ctx_find_what_writes",
+                                ),
+                                (
+                                    WatchpointAccess::ReadWrite,
+                                    "Find What Accesses This Address",
+                                    "memory_viewer_ctx_find_what_accesses",
+                                ),
+                            ] {
+                                if user_interface
+                                    .add(ToolbarMenuItemView::new(
+                                        self.app_context.clone(),
+                                        label,
+                                        item_id,
+                                        &None,
+                                        Self::CONTEXT_MENU_WIDTH,
+                                    ))
+                                    .clicked()
+                                {
+                                    self.dispatch_find_what_accesses(context_menu_address, watchpoint_access);
+                                    *should_close = true;
+                                }
+                            }
                         },
                     )
                     .width(Self::CONTEXT_MENU_WIDTH)
@@ -671,6 +671,8 @@ impl MemoryViewerViewData {
             module_name: Some(project_item_module_name),
             data_type_id: Some(resolved_data_type_id),
             pointer_offsets: None,
+            project_item_type_id: None,
+            code_patch: None,
         })
     }
 
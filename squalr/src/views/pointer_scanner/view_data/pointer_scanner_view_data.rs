@@ -1258,6 +1258,8 @@ impl PointerScannerViewData {
             module_name: Some(String::new()),
             data_type_id: Some(pointer_scanner_view_data_guard.get_target_data_type_id()),
             pointer_offsets: None,
+            project_item_type_id: None,
+            code_patch: None,
         })
     }
 
@@ -1283,6 +1285,8 @@ impl PointerScannerViewData {
             module_name: Some(String::new()),
             data_type_id: Some(pointer_scanner_view_data_guard.get_target_data_type_id()),
             pointer_offsets: None,
+            project_item_type_id: None,
+            code_patch: None,
         })
     }
 
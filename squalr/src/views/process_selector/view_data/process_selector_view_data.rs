@@ -111,12 +111,20 @@ impl ProcessSelectorViewData {
             ProcessResponse::Open { process_open_response } => {
                 Self::set_opened_process_info(process_selector_view_data, &app_context, process_open_response.opened_process_info.clone());
             }
+            ProcessResponse::Launch { process_launch_response } => {
+                // A failed launch leaves the previously opened process in place.
+                if process_launch_response.opened_process_info.is_some() {
+                    Self::set_opened_process_info(process_selector_view_data, &app_context, process_launch_response.opened_process_info.clone());
+                }
+            }
             ProcessResponse::Close { process_close_response } => {
                 Self::set_opened_process_info(process_selector_view_data, &app_context, process_close_response.process_info.clone());
             }
             ProcessResponse::Icon { process_icon_response } => {
                 Self::apply_process_icon_response(process_selector_view_data, &app_context, process_icon_response.process_icons.clone());
             }
+            // Injecting a library leaves the opened process unchanged.
+            ProcessResponse::InjectLibrary { .. } => {}
         }
     }
 
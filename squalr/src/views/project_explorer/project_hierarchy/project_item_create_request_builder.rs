@@ -26,6 +26,8 @@ impl ProjectItemCreateRequestBuilder {
                 module_name: None,
                 data_type_id: None,
                 pointer_offsets: None,
+                project_item_type_id: None,
+                code_patch: None,
             },
             ProjectHierarchyCreateItemKind::Address => ProjectItemsCreateRequest {
                 parent_directory_path,
@@ -35,6 +37,8 @@ impl ProjectItemCreateRequestBuilder {
                 module_name: Some(String::new()),
                 data_type_id: None,
                 pointer_offsets: None,
+                project_item_type_id: None,
+                code_patch: None,
             },
         }
     }
@@ -580,6 +580,7 @@ impl StructViewerView {
             module_name: module_name.to_string(),
             symbolic_struct_definition,
             suppress_logging: true,
+            value_transform: None,
         };
         let (memory_read_response_sender, memory_read_response_receiver) = std::sync::mpsc::channel();
         let memory_read_command = memory_read_request.to_engine_command();
@@ -112,6 +112,7 @@ impl SymbolTreeCommandDispatcher {
         let project_symbols_rename_request = ProjectSymbolsRenameRequest {
             symbol_locator_key: symbol_locator_key.to_string(),
             display_name,
+            dry_run: false,
         };
 
         project_symbols_rename_request.send(&self.app_context.engine_unprivileged_state, |_project_symbols_rename_response| {});